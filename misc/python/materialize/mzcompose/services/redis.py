@@ -0,0 +1,37 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+from materialize.mzcompose.service import (
+    Service,
+)
+
+
+class Redis(Service):
+    """
+    A Redis server, used to test Redis sinks.
+    """
+
+    def __init__(
+        self,
+        name: str = "redis",
+        image: str = "redis:7.2.3",
+        port: int = 6379,
+    ) -> None:
+        super().__init__(
+            name=name,
+            config={
+                "image": image,
+                "ports": [port],
+                "healthcheck": {
+                    "test": ["CMD", "redis-cli", "ping"],
+                    "interval": "1s",
+                    "start_period": "30s",
+                },
+            },
+        )
@@ -32,18 +32,19 @@ use mz_catalog::builtin::{
     BUILTIN_PREFIXES, MZ_INTROSPECTION_CLUSTER,
 };
 use mz_catalog::durable::{
-    test_bootstrap_args, DurableCatalogState, OpenableDurableCatalogState, StashConfig, Transaction,
+    test_bootstrap_args, DurableCatalogState, OpenableDurableCatalogState, StashConfig,
+    Transaction, VacuumOptions, VacuumReport,
 };
 use mz_catalog::memory::error::{AmbiguousRename, Error, ErrorKind};
 use mz_catalog::memory::objects::{
     CatalogEntry, CatalogItem, Cluster, ClusterConfig, ClusterReplica, ClusterReplicaProcessStatus,
     ClusterVariant, Connection, DataSourceDesc, Database, Func, Index, MaterializedView, Role,
-    Schema, Sink, Source, Type, View,
+    Schema, ServiceAccount, Sink, Source, Type, View,
 };
 use mz_catalog::SYSTEM_CONN_ID;
 use mz_compute_types::dataflows::DataflowDescription;
 use mz_controller::clusters::{
-    ClusterEvent, ManagedReplicaLocation, ReplicaConfig, ReplicaLocation,
+    ClusterEvent, ManagedReplicaLocation, ReplicaAllocation, ReplicaConfig, ReplicaLocation,
 };
 use mz_controller_types::{ClusterId, ReplicaId};
 use mz_expr::OptimizedMirRelationExpr;
@@ -79,7 +80,8 @@ use mz_sql::plan::{
 };
 use mz_sql::session::user::{MZ_SUPPORT_ROLE_ID, MZ_SYSTEM_ROLE_ID, SUPPORT_USER, SYSTEM_USER};
 use mz_sql::session::vars::{
-    ConnectionCounter, OwnedVarInput, SystemVars, Var, VarInput, PERSIST_TXN_TABLES,
+    ConnectionCounter, OwnedVarInput, SystemVars, Var, VarInput, ITEM_TRASH_RETENTION,
+    PERSIST_TXN_TABLES,
 };
 use mz_sql::{plan, rbac, DEFAULT_SCHEMA};
 use mz_sql_parser::ast::{
@@ -92,6 +94,7 @@ use mz_transform::dataflow::DataflowMetainfo;
 
 // DO NOT add any more imports from `crate` outside of `crate::catalog`.
 pub use crate::catalog::builtin_table_updates::BuiltinTableUpdate;
+use crate::catalog::config::allocation_into_durable;
 pub use crate::catalog::config::{AwsPrincipalContext, ClusterReplicaSizeMap, Config, StateConfig};
 pub use crate::catalog::open::BuiltinMigrationMetadata;
 pub use crate::catalog::state::CatalogState;
@@ -929,6 +932,10 @@ impl Catalog {
         self.state.try_get_role_by_name(role_name)
     }
 
+    pub fn try_get_service_account(&self, name: &str) -> Option<&ServiceAccount> {
+        self.state.try_get_service_account(name)
+    }
+
     /// Creates a new schema in the `Catalog` for temporary items
     /// indicated by the TEMPORARY or TEMP keywords.
     pub fn create_temporary_schema(
@@ -1156,7 +1163,11 @@ impl Catalog {
         let mut builtin_table_updates = vec![];
         let mut audit_events = vec![];
         let mut storage = self.storage().await;
+        // Refuse to commit if another environment has opened the catalog since we did, rather
+        // than overwriting its changes.
+        let epoch = storage.epoch();
         let mut tx = storage.transaction().await?;
+        tx.set_expected_epoch(epoch);
         // Prepare a candidate catalog state.
         let mut state = self.state.clone();
 
@@ -1198,6 +1209,80 @@ impl Catalog {
         })
     }
 
+    /// Vacuums the durable catalog as described by `options`.
+    ///
+    /// Returns the builtin table updates that reflect the vacuum, along with a report of what was
+    /// vacuumed.
+    pub async fn vacuum(
+        &mut self,
+        options: VacuumOptions,
+    ) -> Result<(Vec<BuiltinTableUpdate>, VacuumReport), AdapterError> {
+        let mut storage = self.storage().await;
+        let epoch = storage.epoch();
+        let mut tx = storage.transaction().await?;
+        tx.set_expected_epoch(epoch);
+        let report = tx.vacuum(options).await?;
+        tx.commit()
+            .await
+            .unwrap_or_terminate("catalog storage transaction commit must succeed");
+        drop(storage);
+
+        let mut builtin_table_updates = Vec::new();
+        for event in &report.pruned_audit_log_events {
+            let mut update = self.state.pack_audit_log_update(event)?;
+            update.diff = -1;
+            builtin_table_updates.push(update);
+        }
+        for event in &report.removed_storage_usage_events {
+            let mut update = self.state.pack_storage_usage_update(event)?;
+            update.diff = -1;
+            builtin_table_updates.push(update);
+        }
+        for event in &report.storage_usage_rollups {
+            builtin_table_updates.push(self.state.pack_storage_usage_update(event)?);
+        }
+        info!(
+            "vacuumed catalog: pruned {} audit log events, rolled up {} storage usage events into {}, purged {} items from the trash, removed {} settings, reclaimed {} bytes",
+            report.pruned_audit_log_events.len(),
+            report.removed_storage_usage_events.len(),
+            report.storage_usage_rollups.len(),
+            report.purged_trashed_items.len(),
+            report.removed_settings.len(),
+            report.reclaimed_bytes,
+        );
+        Ok((builtin_table_updates, report))
+    }
+
+    /// Removes `dropped_clusters` from `tx`, along with their replicas and
+    /// introspection source indexes.
+    ///
+    /// `dropped_clusters` maps each cluster to the introspection source indexes
+    /// that were retracted for it from the in-memory catalog, which must agree
+    /// with what the durable catalog cascades.
+    fn remove_dropped_clusters(
+        tx: &mut Transaction<'_>,
+        dropped_clusters: BTreeMap<ClusterId, BTreeSet<GlobalId>>,
+    ) -> Result<(), AdapterError> {
+        if dropped_clusters.is_empty() {
+            return Ok(());
+        }
+        let (ids, log_indexes): (BTreeSet<_>, Vec<_>) = dropped_clusters.into_iter().unzip();
+        let removed = tx.remove_clusters(ids)?;
+        mz_ore::soft_assert!(
+            removed.replicas.is_empty(),
+            "replicas must be dropped before their cluster: {:?}",
+            removed.replicas
+        );
+        let retracted: BTreeSet<_> = log_indexes.into_iter().flatten().collect();
+        let cascaded: BTreeSet<_> = removed
+            .introspection_source_indexes
+            .into_iter()
+            .map(|index| index.index_id)
+            .collect();
+        mz_ore::soft_assert_eq!(retracted, cascaded, "transaction and state out of sync");
+        Ok(())
+    }
+
     #[tracing::instrument(name = "catalog::transact_inner", level = "debug", skip_all)]
     fn transact_inner(
         oracle_write_ts: mz_repr::Timestamp,
@@ -1236,7 +1321,24 @@ impl Catalog {
             }
         }
 
+        // Role, cluster, and cluster replica updates are batched and written to
+        // the transaction once all ops have been applied, as updating them one
+        // at a time is linear in the number of such objects.
+        let mut updated_roles = BTreeSet::new();
+        let mut updated_clusters = BTreeSet::new();
+        let mut updated_cluster_replicas = BTreeSet::new();
+        // Likewise for dropped roles and clusters. Dropped clusters map to the
+        // introspection source indexes the in-memory catalog retracted for them.
+        let mut dropped_roles = BTreeSet::new();
+        let mut dropped_clusters = BTreeMap::new();
+        // Items created by consecutive ops are inserted into the transaction
+        // together, as creating a source can create hundreds of subsources.
+        let mut created_items = Vec::new();
+
         for op in ops {
+            if !matches!(op, Op::CreateItem { .. }) {
+                tx.insert_items(std::mem::take(&mut created_items))?;
+            }
             match op {
                 Op::AlterRole {
                     id,
@@ -1251,7 +1353,7 @@ impl Catalog {
                     let existing_role = state.get_role_mut(&id);
                     existing_role.attributes = attributes;
                     existing_role.vars = vars;
-                    tx.update_role(id, existing_role.clone().into())?;
+                    updated_roles.insert(id);
                     if let Some(builtin_update) = state.pack_role_update(id, 1) {
                         builtin_table_updates.push(builtin_update);
                     }
@@ -1282,6 +1384,38 @@ impl Catalog {
                     id,
                     cluster,
                 )?,
+                Op::AlterColumnMask { id, column, action } => match action {
+                    plan::ColumnMaskAction::Set { expr, role_ids } => {
+                        for role_id in role_ids {
+                            tx.set_column_mask(id, column.clone(), role_id, Some(expr.clone()))?;
+                            state.column_masks.set_column_mask(
+                                id,
+                                column.clone(),
+                                role_id,
+                                Some(expr.clone()),
+                            );
+                        }
+                    }
+                    plan::ColumnMaskAction::Drop {
+                        role_ids: Some(role_ids),
+                    } => {
+                        for role_id in role_ids {
+                            tx.set_column_mask(id, column.clone(), role_id, None)?;
+                            state
+                                .column_masks
+                                .set_column_mask(id, column.clone(), role_id, None);
+                        }
+                    }
+                    plan::ColumnMaskAction::Drop { role_ids: None } => {
+                        let deleted = tx.remove_column_masks(id, &column);
+                        let dropped = state.column_masks.remove_column_masks(id, &column);
+                        mz_ore::soft_assert_eq!(
+                            deleted.len(),
+                            dropped.len(),
+                            "transaction and state out of sync"
+                        );
+                    }
+                },
                 Op::AlterSink { id, cluster_config } => {
                     use mz_sql::ast::Value;
                     use mz_sql_parser::ast::CreateSinkOptionName::*;
@@ -1663,6 +1797,9 @@ impl Catalog {
                             ErrorKind::ReservedRoleName(name),
                         )));
                     }
+                    // The new role may reuse the name of a role dropped earlier in this
+                    // transaction, so that role must be removed first.
+                    tx.remove_roles(std::mem::take(&mut dropped_roles))?;
                     let membership = RoleMembership::new();
                     let vars = RoleVars::default();
                     let id = tx.insert_user_role(
@@ -1701,6 +1838,63 @@ impl Catalog {
                         builtin_table_updates.push(builtin_update);
                     }
                 }
+                Op::CreateServiceAccount {
+                    name,
+                    role_id,
+                    token_secret_id,
+                    scopes,
+                    expires_at,
+                } => {
+                    let id = tx.insert_service_account(
+                        name.clone(),
+                        role_id,
+                        token_secret_id,
+                        scopes.clone(),
+                        expires_at,
+                    )?;
+                    let service_account = ServiceAccount {
+                        id,
+                        name,
+                        role_id,
+                        token_secret_id,
+                        scopes,
+                        expires_at,
+                    };
+                    state.add_to_audit_log(
+                        oracle_write_ts,
+                        session,
+                        tx,
+                        builtin_table_updates,
+                        audit_events,
+                        EventType::Create,
+                        ObjectType::ServiceAccount,
+                        service_account_audit_details(&service_account),
+                    )?;
+                    info!("create service account {}", service_account.name);
+                    builtin_table_updates
+                        .push(state.pack_service_account_update(&service_account, 1));
+                    state
+                        .service_accounts
+                        .insert(service_account.name.clone(), service_account);
+                }
+                Op::CreateClusterReplicaSize { name, allocation } => {
+                    if state.cluster_replica_sizes.0.contains_key(&name) {
+                        return Err(AdapterError::Catalog(Error::new(ErrorKind::Sql(
+                            SqlCatalogError::ClusterReplicaSizeAlreadyExists(name),
+                        ))));
+                    }
+                    tx.insert_cluster_replica_size(allocation_into_durable(
+                        name.clone(),
+                        &allocation,
+                    ))?;
+                    info!("create cluster replica size {name}");
+                    builtin_table_updates.extend(state.pack_replica_size_update(
+                        &name,
+                        &allocation,
+                        1,
+                    ));
+                    state.cluster_replica_sizes.0.insert(name, allocation);
+                }
                 Op::CreateCluster {
                     id,
                     name,
@@ -1714,6 +1908,9 @@ impl Catalog {
                             ErrorKind::ReservedClusterName(name),
                         )));
                     }
+                    // The new cluster may reuse the name of a cluster dropped earlier in
+                    // this transaction, so that cluster must be removed first.
+                    Self::remove_dropped_clusters(tx, std::mem::take(&mut dropped_clusters))?;
                     let owner_privileges = vec![rbac::owner_privilege(
                         mz_sql::catalog::ObjectType::Cluster,
                         owner_id,
@@ -1932,16 +2129,14 @@ impl Catalog {
                                 )));
                             }
                         }
-                        let schema_id = name.qualifiers.schema_spec.clone().into();
-                        let serialized_item = item.to_serialized();
-                        tx.insert_item(
+                        created_items.push(mz_catalog::durable::Item {
                             id,
-                            schema_id,
-                            &name.item,
-                            serialized_item,
+                            schema_id: name.qualifiers.schema_spec.clone().into(),
+                            name: name.item.clone(),
+                            create_sql: item.to_serialized(),
                             owner_id,
-                            privileges.clone(),
-                        )?;
+                            privileges: privileges.clone(),
+                        });
                     }
 
                     if Self::should_audit_log_item(&item) {
@@ -2036,6 +2231,60 @@ impl Catalog {
                     });
                     builtin_table_updates.extend(updates);
 
+                    // Drop any associated tags.
+                    let deleted: Vec<_> = tx
+                        .drop_tags(comment_id)?
+                        .into_iter()
+                        .map(|tag| (tag.object_id, tag.name, tag.value))
+                        .collect();
+                    let dropped = state.tags.drop_tags(comment_id);
+                    mz_ore::soft_assert_eq!(deleted, dropped, "transaction and state out of sync");
+
+                    let updates = dropped
+                        .into_iter()
+                        .map(|(id, name, value)| state.pack_tag_update(id, &name, &value, -1));
+                    builtin_table_updates.extend(updates);
+
+                    // Drop any associated column masks. The durable masks are dropped along with
+                    // the item or role.
+                    match &id {
+                        ObjectId::Item(item_id) => state.column_masks.drop_item(*item_id),
+                        ObjectId::Role(role_id) => state.column_masks.drop_role(*role_id),
+                        _ => {}
+                    }
+
+                    // Drop any service accounts that authenticate as the role or whose token is
+                    // stored in the secret. The durable service accounts are dropped along with
+                    // the item or role.
+                    let dropped_service_accounts: Vec<_> = state
+                        .service_accounts
+                        .values()
+                        .filter(|service_account| match &id {
+                            ObjectId::Item(item_id) => service_account.token_secret_id == *item_id,
+                            ObjectId::Role(role_id) => service_account.role_id == *role_id,
+                            _ => false,
+                        })
+                        .map(|service_account| service_account.name.clone())
+                        .collect();
+                    for name in dropped_service_accounts {
+                        let service_account = state
+                            .service_accounts
+                            .remove(&name)
+                            .expect("service account exists");
+                        builtin_table_updates
+                            .push(state.pack_service_account_update(&service_account, -1));
+                        state.add_to_audit_log(
+                            oracle_write_ts,
+                            session,
+                            tx,
+                            builtin_table_updates,
+                            audit_events,
+                            EventType::Drop,
+                            ObjectType::ServiceAccount,
+                            service_account_audit_details(&service_account),
+                        )?;
+                    }
+
                     // Drop the object.
                     match id {
                         ObjectId::Database(id) => {
@@ -2084,6 +2333,15 @@ impl Catalog {
                                     ErrorKind::ReadOnlySystemSchema(full_name.to_string()),
                                 )));
                             }
+                            // All of the items in the schema must have already been dropped, e.g.
+                            // by `DROP SCHEMA ... CASCADE`.
+                            if !tx.get_schema_items(schema_id).is_empty() {
+                                let name = schema.name();
+                                let full_name = state.resolve_full_schema_name(name);
+                                return Err(AdapterError::Catalog(Error::new(
+                                    ErrorKind::SchemaNotEmpty(full_name.to_string()),
+                                )));
+                            }
                             tx.remove_schema(&database_id, &schema_id)?;
                             builtin_table_updates.push(state.pack_schema_update(
                                 &database_spec,
@@ -2119,7 +2377,7 @@ impl Catalog {
                         ObjectId::Role(id) => {
                             let name = state.get_role(&id).name().to_string();
                             state.ensure_not_reserved_role(&id)?;
-                            tx.remove_role(&name)?;
+                            dropped_roles.insert(id);
                             if let Some(builtin_update) = state.pack_role_update(id, -1) {
                                 builtin_table_updates.push(builtin_update);
                             }
@@ -2148,7 +2406,8 @@ impl Catalog {
                                     ErrorKind::ReadOnlyCluster(name.clone()),
                                 )));
                             }
-                            tx.remove_cluster(id)?;
+                            dropped_clusters
+                                .insert(id, cluster.log_indexes.values().copied().collect());
                             builtin_table_updates.push(state.pack_cluster_update(name, -1));
                             if let Some(linked_object_id) = cluster.linked_object_id {
                                 builtin_table_updates.push(state.pack_cluster_link_update(
@@ -2269,7 +2528,13 @@ impl Catalog {
                                 )));
                             }
                             if !entry.item().is_temporary() {
-                                tx.remove_item(id)?;
+                                // Views don't hold on to any resources, so they can be kept in
+                                // the trash to be restored with `UNDROP`.
+                                if let CatalogItem::View(_) = entry.item() {
+                                    tx.drop_item(id, oracle_write_ts.into())?;
+                                } else {
+                                    tx.remove_item(id)?;
+                                }
                             }
 
                             builtin_table_updates.extend(state.pack_item_update(id, -1));
@@ -2295,6 +2560,97 @@ impl Catalog {
                         }
                     }
                 }
+                Op::UndropObject { oid, name } => {
+                    let conn_id = session.map(|session| session.conn_id());
+                    // Restore the most recently dropped item of that name.
+                    let schema_id: SchemaId = name.qualifiers.schema_spec.clone().into();
+                    let Some(trashed) = tx
+                        .get_trashed_items()
+                        .filter(|trashed| {
+                            trashed.item.schema_id == schema_id && trashed.item.name == name.item
+                        })
+                        .max_by_key(|trashed| trashed.dropped_at)
+                    else {
+                        let full_name = state.resolve_full_name(&name, conn_id);
+                        return Err(AdapterError::Catalog(Error::new(ErrorKind::Sql(
+                            SqlCatalogError::UnknownItem(full_name.to_string()),
+                        ))));
+                    };
+                    let item = tx.undrop_item(trashed.item.id)?;
+                    let catalog_item = state.deserialize_item(item.id, item.create_sql)?;
+                    // Only views are moved to the trash, see `Op::DropObject`.
+                    if !matches!(catalog_item, CatalogItem::View(_)) {
+                        return Err(AdapterError::Internal(format!(
+                            "cannot undrop {}: only views can be restored",
+                            item.id
+                        )));
+                    }
+
+                    let full_name = state.resolve_full_name(&name, conn_id);
+                    state.add_to_audit_log(
+                        oracle_write_ts,
+                        session,
+                        tx,
+                        builtin_table_updates,
+                        audit_events,
+                        EventType::Create,
+                        catalog_type_to_audit_object_type(catalog_item.typ()),
+                        EventDetails::IdFullNameV1(IdFullNameV1 {
+                            id: item.id.to_string(),
+                            name: Self::full_name_detail(&full_name),
+                        }),
+                    )?;
+                    info!("undrop {} {}", catalog_item.typ(), full_name);
+                    state.insert_item(
+                        item.id,
+                        oid,
+                        name,
+                        catalog_item,
+                        item.owner_id,
+                        PrivilegeMap::from_mz_acl_items(item.privileges),
+                    );
+                    builtin_table_updates.extend(state.pack_item_update(item.id, 1));
+                }
+                Op::DropServiceAccount { name } => {
+                    let service_account: ServiceAccount = tx.remove_service_account(&name)?.into();
+                    state.service_accounts.remove(&name);
+                    builtin_table_updates
+                        .push(state.pack_service_account_update(&service_account, -1));
+                    state.add_to_audit_log(
+                        oracle_write_ts,
+                        session,
+                        tx,
+                        builtin_table_updates,
+                        audit_events,
+                        EventType::Drop,
+                        ObjectType::ServiceAccount,
+                        service_account_audit_details(&service_account),
+                    )?;
+                    info!("drop service account {name}");
+                }
+                Op::DropClusterReplicaSize { name } => {
+                    tx.remove_cluster_replica_size(&name)?;
+                    if let Some(cluster) = state
+                        .clusters_by_id
+                        .values()
+                        .find(|cluster| cluster_uses_replica_size(cluster, &name))
+                    {
+                        return Err(AdapterError::Catalog(Error::new(
+                            ErrorKind::ClusterReplicaSizeInUse {
+                                size: name,
+                                cluster_name: cluster.name.clone(),
+                            },
+                        )));
+                    }
+                    if let Some(allocation) = state.cluster_replica_sizes.0.remove(&name) {
+                        builtin_table_updates.extend(state.pack_replica_size_update(
+                            &name,
+                            &allocation,
+                            -1,
+                        ));
+                    }
+                    info!("drop cluster replica size {name}");
+                }
                 Op::GrantRole {
                     role_id,
                     member_id,
@@ -2314,7 +2670,7 @@ impl Catalog {
                     }
                     let member_role = state.get_role_mut(&member_id);
                     member_role.membership.map.insert(role_id, grantor_id);
-                    tx.update_role(member_id, member_role.clone().into())?;
+                    updated_roles.insert(member_id);
                     builtin_table_updates
                         .push(state.pack_role_members_update(role_id, member_id, 1));
 
@@ -2348,7 +2704,7 @@ impl Catalog {
                         .push(state.pack_role_members_update(role_id, member_id, -1));
                     let member_role = state.get_role_mut(&member_id);
                     member_role.membership.map.remove(&role_id);
-                    tx.update_role(member_id, member_role.clone().into())?;
+                    updated_roles.insert(member_id);
 
                     state.add_to_audit_log(
                         oracle_write_ts,
@@ -2390,7 +2746,7 @@ impl Catalog {
                                     .push(state.pack_cluster_update(&cluster_name, -1));
                                 let cluster = state.get_cluster_mut(*id);
                                 update_privilege_fn(&mut cluster.privileges);
-                                tx.update_cluster(*id, cluster.clone().into())?;
+                                updated_clusters.insert(*id);
                                 builtin_table_updates
                                     .push(state.pack_cluster_update(&cluster_name, 1));
                             }
@@ -2571,6 +2927,9 @@ impl Catalog {
                             ErrorKind::ReservedClusterName(to_name),
                         )));
                     }
+                    // The new name may belong to a cluster dropped earlier in this
+                    // transaction, so that cluster must be removed first.
+                    Self::remove_dropped_clusters(tx, std::mem::take(&mut dropped_clusters))?;
                     tx.rename_cluster(id, &name, &to_name)?;
                     builtin_table_updates.push(state.pack_cluster_update(&name, -1));
                     state.rename_cluster(id, to_name.clone());
@@ -2635,6 +2994,25 @@ impl Catalog {
                     )?;
                     info!("rename cluster replica {name} to {to_name}");
                 }
+                Op::UpdateClusterReplicaDraining {
+                    cluster_id,
+                    replica_id,
+                    name,
+                    draining,
+                } => {
+                    if cluster_id.is_system()
+                        && !session
+                            .map(|session| session.user().is_internal())
+                            .unwrap_or(false)
+                    {
+                        return Err(AdapterError::Catalog(Error::new(
+                            ErrorKind::ReadOnlyCluster(name.cluster.into_string()),
+                        )));
+                    }
+                    tx.update_cluster_replica_draining(replica_id, &name, draining)?;
+                    state.set_cluster_replica_draining(cluster_id, replica_id, draining);
+                    info!("set draining of cluster replica {name} to {draining}");
+                }
                 Op::RenameItem {
                     id,
                     to_name,
@@ -2748,6 +3126,66 @@ impl Catalog {
                         Self::update_item(state, builtin_table_updates, id, to_name, to_item)?;
                     }
                 }
+                Op::RenameDatabase {
+                    database_id,
+                    new_name,
+                } => {
+                    let cur_name = state.get_database(&database_id).name().to_string();
+                    if database_id.is_system() {
+                        return Err(AdapterError::Catalog(Error::new(
+                            ErrorKind::ReadOnlyDatabase(cur_name),
+                        )));
+                    }
+
+                    // Items refer to one another by their fully qualified names, so every item in
+                    // the database and everything that depends on one of them must be updated.
+                    let mut ids = BTreeSet::new();
+                    for schema in state.get_database(&database_id).schemas_by_id.values() {
+                        for item_id in schema.items.values() {
+                            ids.insert(*item_id);
+                            ids.extend(state.get_entry(item_id).referenced_by());
+                        }
+                    }
+                    let mut updates = Vec::new();
+                    for id in ids {
+                        let entry = state.get_entry(&id);
+                        let mut new_entry = entry.clone();
+                        new_entry.item = entry.item.rename_database_refs(&cur_name, &new_name);
+                        if !new_entry.item().is_temporary() {
+                            tx.update_item(id, new_entry.clone().into())?;
+                        }
+                        builtin_table_updates.extend(state.pack_item_update(id, -1));
+                        updates.push((id, entry.name().clone(), new_entry.item));
+                    }
+
+                    builtin_table_updates
+                        .push(state.pack_database_update(state.get_database(&database_id), -1));
+                    state.add_to_audit_log(
+                        oracle_write_ts,
+                        session,
+                        tx,
+                        builtin_table_updates,
+                        audit_events,
+                        EventType::Alter,
+                        mz_audit_log::ObjectType::Database,
+                        EventDetails::RenameDatabaseV1(mz_audit_log::RenameDatabaseV1 {
+                            id: database_id.to_string(),
+                            old_name: cur_name.clone(),
+                            new_name: new_name.clone(),
+                        }),
+                    )?;
+
+                    tx.rename_database(database_id, &new_name)?;
+                    state.get_database_mut(&database_id).name = new_name.clone();
+                    state.database_by_name.remove(&cur_name);
+                    state.database_by_name.insert(new_name, database_id);
+                    builtin_table_updates
+                        .push(state.pack_database_update(state.get_database(&database_id), 1));
+
+                    for (id, name, item) in updates {
+                        Self::update_item(state, builtin_table_updates, id, name, item)?;
+                    }
+                }
                 Op::RenameSchema {
                     database_spec,
                     schema_spec,
@@ -2863,8 +3301,7 @@ impl Catalog {
                     let schema = state.get_schema_mut(&database_spec, &schema_spec, conn_id);
                     let old_name = schema.name().schema.clone();
                     schema.name.schema = new_name.clone();
-                    let new_schema = schema.clone().into_durable_schema(database_spec.id());
-                    tx.update_schema(schema_id, new_schema)?;
+                    tx.rename_schema(schema_id, &new_name)?;
 
                     // Update the references to this schema.
                     match (&database_spec, &schema_spec) {
@@ -2927,7 +3364,7 @@ impl Catalog {
                                 new_owner,
                             );
                             cluster.owner_id = new_owner;
-                            tx.update_cluster(*id, cluster.clone().into())?;
+                            updated_clusters.insert(*id);
                             builtin_table_updates.push(state.pack_cluster_update(&cluster_name, 1));
                         }
                         ObjectId::ClusterReplica((cluster_id, replica_id)) => {
@@ -2957,7 +3394,7 @@ impl Catalog {
                                 .replica_mut(*replica_id)
                                 .expect("catalog out of sync");
                             replica.owner_id = new_owner;
-                            tx.update_cluster_replica(*replica_id, replica.clone().into())?;
+                            updated_cluster_replicas.insert((*cluster_id, *replica_id));
                             builtin_table_updates.extend(state.pack_cluster_replica_update(
                                 *cluster_id,
                                 &replica_name,
@@ -3052,10 +3489,22 @@ impl Catalog {
                     builtin_table_updates.push(state.pack_cluster_update(&name, -1));
                     let cluster = state.get_cluster_mut(id);
                     cluster.config = config;
-                    tx.update_cluster(id, cluster.clone().into())?;
+                    updated_clusters.insert(id);
                     builtin_table_updates.push(state.pack_cluster_update(&name, 1));
                     info!("update cluster {}", name);
                 }
+                Op::LogScheduledClusterChange { details } => {
+                    state.add_to_audit_log(
+                        oracle_write_ts,
+                        session,
+                        tx,
+                        builtin_table_updates,
+                        audit_events,
+                        EventType::Alter,
+                        ObjectType::Cluster,
+                        EventDetails::ScheduledClusterChangeV1(details),
+                    )?;
+                }
                 Op::UpdateClusterReplicaStatus { event } => {
                     builtin_table_updates.push(state.pack_cluster_replica_status_update(
                         event.cluster_id,
@@ -3125,16 +3574,36 @@ impl Catalog {
                         collection_timestamp,
                     )?;
                 }
+                Op::LogSessionEvent {
+                    event_type,
+                    user,
+                    details,
+                } => {
+                    state.add_user_event_to_audit_log(
+                        oracle_write_ts,
+                        Some(user),
+                        tx,
+                        builtin_table_updates,
+                        audit_events,
+                        event_type,
+                        ObjectType::Session,
+                        details,
+                    )?;
+                }
                 Op::UpdateSystemConfiguration { name, value } => {
                     Self::update_system_configuration(state, tx, &name, value.borrow())?;
                 }
                 Op::ResetSystemConfiguration { name } => {
                     state.remove_system_configuration(&name)?;
                     tx.remove_system_config(&name);
+                    if name == ITEM_TRASH_RETENTION.name() {
+                        Self::mirror_item_trash_retention(state, tx)?;
+                    }
                 }
                 Op::ResetAllSystemConfiguration => {
                     state.clear_system_configuration();
                     tx.clear_system_configs();
+                    Self::mirror_item_trash_retention(state, tx)?;
                 }
                 Op::UpdateRotatedKeys {
                     id,
@@ -3176,6 +3645,40 @@ impl Catalog {
                 }
             };
         }
+
+        tx.insert_items(created_items)?;
+        tx.remove_roles(dropped_roles)?;
+        Self::remove_dropped_clusters(tx, dropped_clusters)?;
+        // Objects dropped later in the transaction no longer need updating.
+        let updated_roles = updated_roles
+            .into_iter()
+            .filter_map(|id| {
+                state
+                    .try_get_role(&id)
+                    .map(|role| (id, role.clone().into()))
+            })
+            .collect();
+        tx.update_roles(updated_roles)?;
+        let updated_clusters = updated_clusters
+            .into_iter()
+            .filter_map(|id| {
+                state
+                    .try_get_cluster(id)
+                    .map(|cluster| (id, cluster.clone().into()))
+            })
+            .collect();
+        tx.update_clusters(updated_clusters)?;
+        let updated_cluster_replicas = updated_cluster_replicas
+            .into_iter()
+            .filter_map(|(cluster_id, replica_id)| {
+                state
+                    .try_get_cluster(cluster_id)
+                    .and_then(|cluster| cluster.replica(replica_id))
+                    .map(|replica| (replica_id, replica.clone().into()))
+            })
+            .collect();
+        tx.update_cluster_replicas(updated_cluster_replicas)?;
+
         Ok(())
     }
 
@@ -3294,6 +3797,20 @@ impl Catalog {
         if name == PERSIST_TXN_TABLES.name() {
             tx.set_persist_txn_tables(state.system_configuration.persist_txn_tables())?;
         }
+        if name == ITEM_TRASH_RETENTION.name() {
+            Self::mirror_item_trash_retention(state, tx)?;
+        }
+        Ok(())
+    }
+
+    /// Mirrors the `item_trash_retention` "system var" into the catalog storage
+    /// "config" collection, which determines whether dropped items are moved to
+    /// the trash.
+    fn mirror_item_trash_retention(
+        state: &CatalogState,
+        tx: &mut Transaction,
+    ) -> Result<(), AdapterError> {
+        tx.set_item_trash_retention(state.system_configuration.item_trash_retention())?;
         Ok(())
     }
 
@@ -3640,6 +4157,39 @@ pub fn is_public_role(name: &str) -> bool {
     name == &*PUBLIC_ROLE_NAME
 }
 
+/// Returns the audit log details of `service_account`.
+/// Reports whether `cluster` or any of its replicas refers to the cluster
+/// replica size named `size`.
+fn cluster_uses_replica_size(cluster: &Cluster, size: &str) -> bool {
+    if let ClusterVariant::Managed(managed) = &cluster.config.variant {
+        if managed.size == size
+            || managed
+                .schedule
+                .iter()
+                .any(|entry| entry.size.as_deref() == Some(size))
+        {
+            return true;
+        }
+    }
+    cluster.replicas().any(|replica| {
+        matches!(
+            &replica.config.location,
+            ReplicaLocation::Managed(location) if location.size == size
+        )
+    })
+}
+
+fn service_account_audit_details(service_account: &ServiceAccount) -> EventDetails {
+    EventDetails::ServiceAccountV1(mz_audit_log::ServiceAccountV1 {
+        id: service_account.id.to_string(),
+        name: service_account.name.clone(),
+        role_id: service_account.role_id.to_string(),
+        token_secret_id: service_account.token_secret_id.to_string(),
+        scopes: service_account.scopes.clone(),
+        expires_at: service_account.expires_at,
+    })
+}
+
 pub(crate) fn catalog_type_to_audit_object_type(sql_type: SqlCatalogItemType) -> ObjectType {
     object_type_to_audit_object_type(sql_type.into())
 }
@@ -3705,6 +4255,11 @@ pub enum Op {
         id: GlobalId,
         cluster: ClusterId,
     },
+    AlterColumnMask {
+        id: GlobalId,
+        column: String,
+        action: plan::ColumnMaskAction,
+    },
     AlterSink {
         id: GlobalId,
         cluster_config: plan::SourceSinkClusterConfig,
@@ -3763,7 +4318,28 @@ pub enum Op {
         sub_component: Option<usize>,
         comment: Option<String>,
     },
+    CreateClusterReplicaSize {
+        name: String,
+        allocation: ReplicaAllocation,
+    },
+    CreateServiceAccount {
+        name: String,
+        role_id: RoleId,
+        token_secret_id: GlobalId,
+        scopes: Vec<String>,
+        expires_at: Option<EpochMillis>,
+    },
     DropObject(ObjectId),
+    DropClusterReplicaSize {
+        name: String,
+    },
+    DropServiceAccount {
+        name: String,
+    },
+    UndropObject {
+        oid: u32,
+        name: QualifiedItemName,
+    },
     GrantRole {
         role_id: RoleId,
         member_id: RoleId,
@@ -3781,11 +4357,21 @@ pub enum Op {
         name: QualifiedReplica,
         to_name: String,
     },
+    UpdateClusterReplicaDraining {
+        cluster_id: ClusterId,
+        replica_id: ReplicaId,
+        name: QualifiedReplica,
+        draining: bool,
+    },
     RenameItem {
         id: GlobalId,
         current_full_name: FullItemName,
         to_name: String,
     },
+    RenameDatabase {
+        database_id: DatabaseId,
+        new_name: String,
+    },
     RenameSchema {
         database_spec: ResolvedDatabaseSpecifier,
         schema_spec: SchemaSpecifier,
@@ -3816,6 +4402,10 @@ pub enum Op {
         name: String,
         config: ClusterConfig,
     },
+    /// Records a change that a cluster's schedule made to its configuration.
+    LogScheduledClusterChange {
+        details: mz_audit_log::ScheduledClusterChangeV1,
+    },
     UpdateClusterReplicaStatus {
         event: ClusterEvent,
     },
@@ -3829,6 +4419,11 @@ pub enum Op {
         size_bytes: u64,
         collection_timestamp: EpochMillis,
     },
+    LogSessionEvent {
+        event_type: EventType,
+        user: String,
+        details: EventDetails,
+    },
     UpdateSystemConfiguration {
         name: String,
         value: OwnedVarInput,
@@ -4370,6 +4965,13 @@ impl SessionCatalog for ConnCatalog<'_> {
         let comment_id = self.state.get_comment_id(ObjectId::Item(*id));
         self.state.comments.get_object_comments(comment_id)
     }
+
+    fn get_item_column_masks(
+        &self,
+        id: &GlobalId,
+    ) -> Option<&BTreeMap<String, BTreeMap<RoleId, String>>> {
+        self.state.column_masks.get_item_masks(id)
+    }
 }
 
 #[cfg(test)]
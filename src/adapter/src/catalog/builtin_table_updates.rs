@@ -13,19 +13,20 @@ use bytesize::ByteSize;
 use chrono::{DateTime, Utc};
 use mz_audit_log::{EventDetails, EventType, ObjectType, VersionedEvent, VersionedStorageUsage};
 use mz_catalog::builtin::{
-    MZ_AGGREGATES, MZ_ARRAY_TYPES, MZ_AUDIT_EVENTS, MZ_AWS_PRIVATELINK_CONNECTIONS, MZ_BASE_TYPES,
-    MZ_CLUSTERS, MZ_CLUSTER_LINKS, MZ_CLUSTER_REPLICAS, MZ_CLUSTER_REPLICA_METRICS,
-    MZ_CLUSTER_REPLICA_SIZES, MZ_CLUSTER_REPLICA_STATUSES, MZ_COLUMNS, MZ_COMMENTS, MZ_CONNECTIONS,
-    MZ_DATABASES, MZ_DEFAULT_PRIVILEGES, MZ_EGRESS_IPS, MZ_FUNCTIONS, MZ_INDEXES, MZ_INDEX_COLUMNS,
-    MZ_INTERNAL_CLUSTER_REPLICAS, MZ_KAFKA_CONNECTIONS, MZ_KAFKA_SINKS, MZ_KAFKA_SOURCES,
-    MZ_LIST_TYPES, MZ_MAP_TYPES, MZ_MATERIALIZED_VIEWS, MZ_OBJECT_DEPENDENCIES, MZ_OPERATORS,
-    MZ_POSTGRES_SOURCES, MZ_PSEUDO_TYPES, MZ_ROLES, MZ_ROLE_MEMBERS, MZ_SCHEMAS, MZ_SECRETS,
-    MZ_SESSIONS, MZ_SINKS, MZ_SOURCES, MZ_SSH_TUNNEL_CONNECTIONS, MZ_STORAGE_USAGE_BY_SHARD,
+    MZ_AGGREGATES, MZ_ARRAY_TYPES, MZ_AUDIT_EVENTS, MZ_AWS_PRIVATELINK_CONNECTIONS,
+    MZ_BACKGROUND_PURIFICATIONS, MZ_BASE_TYPES, MZ_CLUSTERS, MZ_CLUSTER_LINKS, MZ_CLUSTER_REPLICAS,
+    MZ_CLUSTER_REPLICA_METRICS, MZ_CLUSTER_REPLICA_SIZES, MZ_CLUSTER_REPLICA_STATUSES, MZ_COLUMNS,
+    MZ_COMMENTS, MZ_CONNECTIONS, MZ_DATABASES, MZ_DEFAULT_PRIVILEGES, MZ_EGRESS_IPS, MZ_FUNCTIONS,
+    MZ_INDEXES, MZ_INDEX_COLUMNS, MZ_INTERNAL_CLUSTER_REPLICAS, MZ_KAFKA_CONNECTIONS,
+    MZ_KAFKA_SINKS, MZ_KAFKA_SOURCES, MZ_LIST_TYPES, MZ_MAP_TYPES, MZ_MATERIALIZED_VIEWS,
+    MZ_OBJECT_DEPENDENCIES, MZ_OBJECT_TAGS, MZ_OPERATORS, MZ_POSTGRES_SOURCES, MZ_PSEUDO_TYPES,
+    MZ_ROLES, MZ_ROLE_MEMBERS, MZ_SCHEMAS, MZ_SECRETS, MZ_SERVICE_ACCOUNTS, MZ_SESSIONS, MZ_SINKS,
+    MZ_SOURCES, MZ_SSH_TUNNEL_CONNECTIONS, MZ_STORAGE_USAGE_BY_SCHEMA, MZ_STORAGE_USAGE_BY_SHARD,
     MZ_SUBSCRIPTIONS, MZ_SYSTEM_PRIVILEGES, MZ_TABLES, MZ_TYPES, MZ_TYPE_PG_METADATA, MZ_VIEWS,
     MZ_WEBHOOKS_SOURCES,
 };
 use mz_catalog::memory::error::{Error, ErrorKind};
-use mz_catalog::memory::objects::Table;
+use mz_catalog::memory::objects::{ServiceAccount, Table};
 use mz_catalog::SYSTEM_CONN_ID;
 use mz_controller::clusters::{
     ClusterStatus, ManagedReplicaAvailabilityZones, ManagedReplicaLocation, ProcessId,
@@ -36,7 +37,9 @@ use mz_expr::MirScalarExpr;
 use mz_orchestrator::{CpuLimit, DiskLimit, MemoryLimit, NotReadyReason, ServiceProcessMetrics};
 use mz_ore::cast::CastFrom;
 use mz_ore::collections::CollectionExt;
+use mz_ore::now::EpochMillis;
 use mz_repr::adt::array::ArrayDimension;
+use mz_repr::adt::interval::Interval;
 use mz_repr::adt::jsonb::Jsonb;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem, PrivilegeMap};
 use mz_repr::role_id::RoleId;
@@ -58,6 +61,7 @@ use crate::catalog::{
     AwsPrincipalContext, CatalogItem, CatalogState, ClusterVariant, Connection, DataSourceDesc,
     Database, DefaultPrivilegeObject, Func, Index, MaterializedView, Sink, Type, View,
 };
+use crate::coord::background_purification::BackgroundPurification;
 use crate::coord::ConnMeta;
 use crate::subscribe::ActiveSubscribe;
 
@@ -144,6 +148,20 @@ impl CatalogState {
             RoleId::Public => None,
             id => {
                 let role = self.get_role(&id);
+                let connection_limit = role.attributes.connection_limit.map(|limit| {
+                    i32::try_from(limit).expect("connection limits are planned from an i32")
+                });
+                let statement_timeout = role.attributes.statement_timeout.map(|timeout| {
+                    let micros = i64::try_from(timeout.as_micros()).expect("must fit");
+                    Interval::new(0, 0, micros)
+                });
+                let valid_until = role.attributes.valid_until.map(|valid_until| {
+                    Datum::TimestampTz(
+                        mz_ore::now::to_datetime(valid_until)
+                            .try_into()
+                            .expect("must fit"),
+                    )
+                });
                 Some(BuiltinTableUpdate {
                     id: self.resolve_builtin_table(&MZ_ROLES),
                     row: Row::pack_slice(&[
@@ -151,6 +169,10 @@ impl CatalogState {
                         Datum::UInt32(role.oid),
                         Datum::String(&role.name),
                         Datum::from(role.attributes.inherit),
+                        Datum::from(connection_limit),
+                        Datum::from(statement_timeout),
+                        valid_until.unwrap_or(Datum::Null),
+                        Datum::from(role.attributes.password_verifier.is_some()),
                     ]),
                     diff,
                 })
@@ -832,6 +854,11 @@ impl CatalogState {
                     diff,
                 });
             }
+            StorageSinkConnection::Postgres(_)
+            | StorageSinkConnection::S3(_)
+            | StorageSinkConnection::Http(_)
+            | StorageSinkConnection::Redis(_)
+            | StorageSinkConnection::Iceberg(_) => {}
         };
 
         let create_stmt = mz_sql::parse::parse(&sink.create_sql)
@@ -1261,6 +1288,34 @@ impl CatalogState {
         Ok(BuiltinTableUpdate { id, row, diff: 1 })
     }
 
+    pub fn pack_storage_usage_by_schema_update(
+        &self,
+        database_spec: &ResolvedDatabaseSpecifier,
+        schema_spec: &SchemaSpecifier,
+        size_bytes: u64,
+        collection_timestamp: EpochMillis,
+        diff: Diff,
+    ) -> BuiltinTableUpdate {
+        let database_id = match database_spec {
+            ResolvedDatabaseSpecifier::Ambient => None,
+            ResolvedDatabaseSpecifier::Id(id) => Some(id.to_string()),
+        };
+        BuiltinTableUpdate {
+            id: self.resolve_builtin_table(&MZ_STORAGE_USAGE_BY_SCHEMA),
+            row: Row::pack_slice(&[
+                Datum::from(database_id.as_deref()),
+                Datum::String(&schema_spec.to_string()),
+                Datum::UInt64(size_bytes),
+                Datum::TimestampTz(
+                    mz_ore::now::to_datetime(collection_timestamp)
+                        .try_into()
+                        .expect("must fit"),
+                ),
+            ]),
+            diff,
+        }
+    }
+
     pub fn pack_egress_ip_update(&self, ip: &Ipv4Addr) -> Result<BuiltinTableUpdate, Error> {
         let id = self.resolve_builtin_table(&MZ_EGRESS_IPS);
         let row = Row::pack_slice(&[Datum::String(&ip.to_string())]);
@@ -1299,47 +1354,53 @@ impl CatalogState {
     }
 
     pub fn pack_all_replica_size_updates(&self) -> Vec<BuiltinTableUpdate> {
-        let id = self.resolve_builtin_table(&MZ_CLUSTER_REPLICA_SIZES);
-        let updates = self
-            .cluster_replica_sizes
+        self.cluster_replica_sizes
             .0
             .iter()
-            .filter(|(_, a)| !a.disabled)
-            .map(
-                |(
-                    size,
-                    ReplicaAllocation {
-                        memory_limit,
-                        cpu_limit,
-                        disk_limit,
-                        scale,
-                        workers,
-                        credits_per_hour,
-                        disabled: _,
-                    },
-                )| {
-                    // Just invent something when the limits are `None`,
-                    // which only happens in non-prod environments (tests, process orchestrator, etc.)
-                    let cpu_limit = cpu_limit.unwrap_or(CpuLimit::MAX);
-                    let MemoryLimit(ByteSize(memory_bytes)) =
-                        (*memory_limit).unwrap_or(MemoryLimit::MAX);
-                    let DiskLimit(ByteSize(disk_bytes)) =
-                        (*disk_limit).unwrap_or(DiskLimit::ARBITRARY);
-                    let row = Row::pack_slice(&[
-                        size.as_str().into(),
-                        u64::from(*scale).into(),
-                        u64::cast_from(*workers).into(),
-                        cpu_limit.as_nanocpus().into(),
-                        memory_bytes.into(),
-                        // TODO(guswynn): disk size will be filled in later.
-                        disk_bytes.into(),
-                        (*credits_per_hour).into(),
-                    ]);
-                    BuiltinTableUpdate { id, row, diff: 1 }
-                },
-            )
-            .collect();
-        updates
+            .filter_map(|(size, allocation)| self.pack_replica_size_update(size, allocation, 1))
+            .collect()
+    }
+
+    /// Packs the `mz_cluster_replica_sizes` update for `size`, or returns `None` if the size is
+    /// disabled and so not reported.
+    pub fn pack_replica_size_update(
+        &self,
+        size: &str,
+        allocation: &ReplicaAllocation,
+        diff: Diff,
+    ) -> Option<BuiltinTableUpdate> {
+        let ReplicaAllocation {
+            memory_limit,
+            cpu_limit,
+            disk_limit,
+            scale,
+            workers,
+            credits_per_hour,
+            disabled,
+        } = allocation;
+        if *disabled {
+            return None;
+        }
+        // Just invent something when the limits are `None`,
+        // which only happens in non-prod environments (tests, process orchestrator, etc.)
+        let cpu_limit = cpu_limit.unwrap_or(CpuLimit::MAX);
+        let MemoryLimit(ByteSize(memory_bytes)) = (*memory_limit).unwrap_or(MemoryLimit::MAX);
+        let DiskLimit(ByteSize(disk_bytes)) = (*disk_limit).unwrap_or(DiskLimit::ARBITRARY);
+        let row = Row::pack_slice(&[
+            size.into(),
+            u64::from(*scale).into(),
+            u64::cast_from(*workers).into(),
+            cpu_limit.as_nanocpus().into(),
+            memory_bytes.into(),
+            // TODO(guswynn): disk size will be filled in later.
+            disk_bytes.into(),
+            (*credits_per_hour).into(),
+        ]);
+        Some(BuiltinTableUpdate {
+            id: self.resolve_builtin_table(&MZ_CLUSTER_REPLICA_SIZES),
+            row,
+            diff,
+        })
     }
 
     pub fn pack_subscribe_update(
@@ -1371,6 +1432,34 @@ impl CatalogState {
         }
     }
 
+    pub fn pack_background_purification_update(
+        &self,
+        id: u64,
+        purification: &BackgroundPurification,
+        diff: Diff,
+    ) -> BuiltinTableUpdate {
+        let started_dt = mz_ore::now::to_datetime(purification.started_at);
+        let finished_dt = purification.finished_at.map(mz_ore::now::to_datetime);
+        BuiltinTableUpdate {
+            id: self.resolve_builtin_table(&MZ_BACKGROUND_PURIFICATIONS),
+            row: Row::pack_slice(&[
+                Datum::UInt64(id),
+                Datum::UInt32(purification.conn_id.unhandled()),
+                Datum::String(&purification.redacted_sql),
+                Datum::String(purification.status.as_str()),
+                Datum::from(purification.status.error()),
+                Datum::TimestampTz(started_dt.try_into().expect("must fit")),
+                match finished_dt {
+                    Some(finished_dt) => {
+                        Datum::TimestampTz(finished_dt.try_into().expect("must fit"))
+                    }
+                    None => Datum::Null,
+                },
+            ]),
+            diff,
+        }
+    }
+
     pub fn pack_session_update(&self, conn: &ConnMeta, diff: Diff) -> BuiltinTableUpdate {
         let connect_dt = mz_ore::now::to_datetime(conn.connected_at());
         BuiltinTableUpdate {
@@ -1454,28 +1543,7 @@ impl CatalogState {
         comment: &str,
         diff: Diff,
     ) -> BuiltinTableUpdate {
-        // Use the audit log representation so it's easier to join against.
-        let object_type = mz_sql::catalog::ObjectType::from(object_id);
-        let audit_type = super::object_type_to_audit_object_type(object_type);
-        let object_type_str = audit_type.to_string();
-
-        let object_id_str = match object_id {
-            CommentObjectId::Table(global_id)
-            | CommentObjectId::View(global_id)
-            | CommentObjectId::MaterializedView(global_id)
-            | CommentObjectId::Source(global_id)
-            | CommentObjectId::Sink(global_id)
-            | CommentObjectId::Index(global_id)
-            | CommentObjectId::Func(global_id)
-            | CommentObjectId::Connection(global_id)
-            | CommentObjectId::Secret(global_id)
-            | CommentObjectId::Type(global_id) => global_id.to_string(),
-            CommentObjectId::Role(role_id) => role_id.to_string(),
-            CommentObjectId::Database(database_id) => database_id.to_string(),
-            CommentObjectId::Schema((_, schema_id)) => schema_id.to_string(),
-            CommentObjectId::Cluster(cluster_id) => cluster_id.to_string(),
-            CommentObjectId::ClusterReplica((_, replica_id)) => replica_id.to_string(),
-        };
+        let (object_id_str, object_type_str) = comment_object_strings(object_id);
         let column_pos_datum = match column_pos {
             Some(pos) => {
                 // TODO(parkmycar): https://github.com/MaterializeInc/materialize/issues/22246.
@@ -1498,6 +1566,60 @@ impl CatalogState {
         }
     }
 
+    pub fn pack_tag_update(
+        &self,
+        object_id: CommentObjectId,
+        name: &str,
+        value: &str,
+        diff: Diff,
+    ) -> BuiltinTableUpdate {
+        let (object_id_str, object_type_str) = comment_object_strings(object_id);
+        BuiltinTableUpdate {
+            id: self.resolve_builtin_table(&MZ_OBJECT_TAGS),
+            row: Row::pack_slice(&[
+                Datum::String(&object_id_str),
+                Datum::String(&object_type_str),
+                Datum::String(name),
+                Datum::String(value),
+            ]),
+            diff,
+        }
+    }
+
+    pub fn pack_service_account_update(
+        &self,
+        service_account: &ServiceAccount,
+        diff: Diff,
+    ) -> BuiltinTableUpdate {
+        let mut row = Row::default();
+        let mut packer = row.packer();
+        packer.extend([
+            Datum::UInt64(service_account.id),
+            Datum::String(&service_account.name),
+            Datum::String(&service_account.role_id.to_string()),
+            Datum::String(&service_account.token_secret_id.to_string()),
+        ]);
+        packer.push_list(
+            service_account
+                .scopes
+                .iter()
+                .map(|scope| Datum::String(scope)),
+        );
+        match service_account.expires_at {
+            Some(expires_at) => packer.push(Datum::TimestampTz(
+                mz_ore::now::to_datetime(expires_at)
+                    .try_into()
+                    .expect("must fit"),
+            )),
+            None => packer.push(Datum::Null),
+        }
+        BuiltinTableUpdate {
+            id: self.resolve_builtin_table(&MZ_SERVICE_ACCOUNTS),
+            row,
+            diff,
+        }
+    }
+
     pub fn pack_webhook_source_update(
         &self,
         source_id: GlobalId,
@@ -1521,3 +1643,30 @@ impl CatalogState {
         }
     }
 }
+
+/// Returns the ID and the type of `object_id` as they are shown in builtin tables.
+fn comment_object_strings(object_id: CommentObjectId) -> (String, String) {
+    // Use the audit log representation so it's easier to join against.
+    let object_type = mz_sql::catalog::ObjectType::from(object_id);
+    let audit_type = super::object_type_to_audit_object_type(object_type);
+    let object_type_str = audit_type.to_string();
+
+    let object_id_str = match object_id {
+        CommentObjectId::Table(global_id)
+        | CommentObjectId::View(global_id)
+        | CommentObjectId::MaterializedView(global_id)
+        | CommentObjectId::Source(global_id)
+        | CommentObjectId::Sink(global_id)
+        | CommentObjectId::Index(global_id)
+        | CommentObjectId::Func(global_id)
+        | CommentObjectId::Connection(global_id)
+        | CommentObjectId::Secret(global_id)
+        | CommentObjectId::Type(global_id) => global_id.to_string(),
+        CommentObjectId::Role(role_id) => role_id.to_string(),
+        CommentObjectId::Database(database_id) => database_id.to_string(),
+        CommentObjectId::Schema((_, schema_id)) => schema_id.to_string(),
+        CommentObjectId::Cluster(cluster_id) => cluster_id.to_string(),
+        CommentObjectId::ClusterReplica((_, replica_id)) => replica_id.to_string(),
+    };
+    (object_id_str, object_type_str)
+}
@@ -17,7 +17,7 @@ use mz_build_info::BuildInfo;
 use mz_catalog;
 use mz_cloud_resources::AwsExternalIdPrefix;
 use mz_controller::clusters::ReplicaAllocation;
-use mz_orchestrator::MemoryLimit;
+use mz_orchestrator::{CpuLimit, DiskLimit, MemoryLimit};
 use mz_ore::cast::CastFrom;
 use mz_ore::metrics::MetricsRegistry;
 use mz_repr::GlobalId;
@@ -90,6 +90,46 @@ impl ClusterReplicaSizeMap {
     }
 }
 
+/// Converts a cluster replica size stored in the durable catalog into the
+/// allocation it describes.
+pub fn allocation_from_durable(
+    size: &mz_catalog::durable::ClusterReplicaSize,
+) -> ReplicaAllocation {
+    ReplicaAllocation {
+        memory_limit: size.memory_limit.map(|bytes| MemoryLimit(ByteSize(bytes))),
+        cpu_limit: size
+            .cpu_limit
+            .map(|millicpus| CpuLimit::from_millicpus(usize::cast_from(millicpus))),
+        disk_limit: size.disk_limit.map(|bytes| DiskLimit(ByteSize(bytes))),
+        scale: size.scale,
+        workers: usize::cast_from(size.workers),
+        credits_per_hour: mz_repr::strconv::parse_numeric(&size.credits_per_hour)
+            .expect("durable credits per hour are valid numerics")
+            .0,
+        disabled: size.disabled,
+    }
+}
+
+/// Converts the allocation of the cluster replica size named `name` into its
+/// durable catalog representation.
+pub fn allocation_into_durable(
+    name: String,
+    allocation: &ReplicaAllocation,
+) -> mz_catalog::durable::ClusterReplicaSize {
+    mz_catalog::durable::ClusterReplicaSize {
+        name,
+        memory_limit: allocation.memory_limit.map(|limit| limit.0.as_u64()),
+        cpu_limit: allocation
+            .cpu_limit
+            .map(|limit| u64::cast_from(limit.as_millicpus())),
+        disk_limit: allocation.disk_limit.map(|limit| limit.0.as_u64()),
+        scale: allocation.scale,
+        workers: u64::cast_from(allocation.workers),
+        credits_per_hour: allocation.credits_per_hour.to_string(),
+        disabled: allocation.disabled,
+    }
+}
+
 impl Default for ClusterReplicaSizeMap {
     // Used for testing and local purposes. This default value should not be used in production.
     //
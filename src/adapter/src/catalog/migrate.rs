@@ -216,22 +216,22 @@ fn mz_support_read_progress_sources(
     conn_catalog: &ConnCatalog,
 ) -> Result<(), anyhow::Error> {
     let mut updated_items = BTreeMap::new();
-    for mut item in txn.loaded_items() {
+    let progress_sources =
+        txn.get_items_filtered(|k, _| conn_catalog.get_item(&k.id()).is_progress_source());
+    for mut item in progress_sources {
         let catalog_item = conn_catalog.get_item(&item.id);
-        if catalog_item.is_progress_source() {
-            let privileges = catalog_item.privileges();
-            match privileges.get_acl_item(&MZ_SUPPORT_ROLE_ID, &catalog_item.owner_id()) {
-                Some(acl_item) if acl_item.acl_mode.contains(AclMode::SELECT) => {}
-                _ => {
-                    let mut new_privileges = privileges.clone();
-                    new_privileges.grant(MzAclItem {
-                        grantee: MZ_SUPPORT_ROLE_ID,
-                        grantor: catalog_item.owner_id(),
-                        acl_mode: AclMode::SELECT,
-                    });
-                    item.privileges = new_privileges.into_all_values().collect();
-                    updated_items.insert(item.id, item);
-                }
+        let privileges = catalog_item.privileges();
+        match privileges.get_acl_item(&MZ_SUPPORT_ROLE_ID, &catalog_item.owner_id()) {
+            Some(acl_item) if acl_item.acl_mode.contains(AclMode::SELECT) => {}
+            _ => {
+                let mut new_privileges = privileges.clone();
+                new_privileges.grant(MzAclItem {
+                    grantee: MZ_SUPPORT_ROLE_ID,
+                    grantor: catalog_item.owner_id(),
+                    acl_mode: AclMode::SELECT,
+                });
+                item.privileges = new_privileges.into_all_values().collect();
+                updated_items.insert(item.id, item);
             }
         }
     }
@@ -28,18 +28,21 @@ use mz_catalog::durable::objects::{
     IntrospectionSourceIndex, SystemObjectDescription, SystemObjectMapping,
     SystemObjectUniqueIdentifier,
 };
-use mz_catalog::durable::{Transaction, SYSTEM_CLUSTER_ID_ALLOC_KEY, SYSTEM_REPLICA_ID_ALLOC_KEY};
+use mz_catalog::durable::{
+    Item, Transaction, NAMESPACED_ID_ALLOCATORS, SYSTEM_CLUSTER_ID_ALLOC_KEY,
+    SYSTEM_REPLICA_ID_ALLOC_KEY,
+};
 use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
-    CatalogEntry, CatalogItem, CommentsMap, DataSourceDesc, Database, DefaultPrivileges, Func, Log,
-    Role, Schema, Source, Table, Type,
+    CatalogEntry, CatalogItem, ColumnMasksMap, CommentsMap, DataSourceDesc, Database,
+    DefaultPrivileges, Func, Log, Role, Schema, ServiceAccount, Source, Table, TagsMap, Type,
 };
 use mz_catalog::SYSTEM_CONN_ID;
 use mz_cluster_client::ReplicaId;
 use mz_compute_client::controller::ComputeReplicaConfig;
 use mz_compute_client::logging::LogVariant;
 use mz_controller::clusters::{ReplicaConfig, ReplicaLogging};
-use mz_controller_types::ClusterId;
+use mz_controller_types::{ClusterId, ClusterPriority};
 use mz_ore::cast::CastFrom;
 use mz_ore::collections::CollectionExt;
 use mz_ore::now::to_datetime;
@@ -66,7 +69,7 @@ use mz_ssh_util::keys::SshKeyPairSet;
 use mz_storage_types::sources::Timeline;
 
 // DO NOT add any more imports from `crate` outside of `crate::catalog`.
-use crate::catalog::config::StateConfig;
+use crate::catalog::config::{allocation_from_durable, StateConfig};
 use crate::catalog::{
     is_reserved_name, migrate, BuiltinTableUpdate, Catalog, CatalogPlans, CatalogState, Config,
 };
@@ -257,6 +260,9 @@ impl Catalog {
                 default_privileges: DefaultPrivileges::default(),
                 system_privileges: PrivilegeMap::default(),
                 comments: CommentsMap::default(),
+                tags: TagsMap::default(),
+                column_masks: ColumnMasksMap::default(),
+                service_accounts: BTreeMap::new(),
             };
 
             let is_read_only = storage.is_read_only();
@@ -424,8 +430,10 @@ impl Catalog {
             // TODO: Move this into the catalog constructor.
             txn.set_connection_timeout(state.system_config().crdb_connect_timeout());
 
-            // Add any new builtin Clusters or Cluster Replicas that may be newly defined.
+            // Add any new ID allocators, builtin Clusters, or Cluster Replicas that may be newly
+            // defined.
             if !is_read_only {
+                add_new_id_allocators_migration(&mut txn)?;
                 add_new_builtin_clusters_migration(&mut txn)?;
                 add_new_builtin_cluster_replicas_migration(
                     &mut txn,
@@ -444,6 +452,37 @@ impl Catalog {
                     .comments
                     .update_comment(object_id, sub_component, Some(comment));
             }
+            for mz_catalog::durable::Tag {
+                object_id,
+                name,
+                value,
+            } in txn.get_tags()
+            {
+                state.tags.set_tag(object_id, name, Some(value));
+            }
+            for mz_catalog::durable::ColumnMask {
+                item_id,
+                column,
+                role_id,
+                expr,
+            } in txn.get_column_masks()
+            {
+                state
+                    .column_masks
+                    .set_column_mask(item_id, column, role_id, Some(expr));
+            }
+            for service_account in txn.get_service_accounts() {
+                state.service_accounts.insert(
+                    service_account.name.clone(),
+                    ServiceAccount::from(service_account),
+                );
+            }
+            // Sizes created with `CREATE CLUSTER REPLICA SIZE` live alongside the
+            // sizes configured at startup.
+            for size in txn.get_cluster_replica_sizes() {
+                let allocation = allocation_from_durable(&size);
+                state.cluster_replica_sizes.0.insert(size.name, allocation);
+            }
 
             Catalog::load_builtin_types(&mut state, &mut txn)?;
 
@@ -737,6 +776,7 @@ impl Catalog {
                 name,
                 config,
                 owner_id,
+                draining,
             } in replicas
             {
                 let logging = ReplicaLogging {
@@ -761,9 +801,11 @@ impl Catalog {
                     name: name.clone(),
                     config: config.clone().into(),
                     owner_id: owner_id.clone(),
+                    draining,
                 });
 
                 state.insert_cluster_replica(cluster_id, name, replica_id, config, owner_id);
+                state.set_cluster_replica_draining(cluster_id, replica_id, draining);
             }
             txn.set_replicas(allocated_replicas)?;
 
@@ -974,6 +1016,16 @@ impl Catalog {
                     1,
                 ));
             }
+            for (id, name, value) in catalog.state.tags.iter() {
+                builtin_table_updates.push(catalog.state.pack_tag_update(id, name, value, 1));
+            }
+            for service_account in catalog.state.service_accounts.values() {
+                builtin_table_updates.push(
+                    catalog
+                        .state
+                        .pack_service_account_update(service_account, 1),
+                );
+            }
             for (_id, role) in &catalog.state.roles_by_id {
                 if let Some(builtin_update) = catalog.state.pack_role_update(role.id, 1) {
                     builtin_table_updates.push(builtin_update);
@@ -1058,11 +1110,25 @@ impl Catalog {
             // To avoid reading over storage_usage events multiple times, do both
             // the table updates and delete calculations in a single read over the
             // data.
-            let storage_usage_events = catalog
+            let mut storage_usage_events = catalog
                 .storage()
                 .await
                 .get_and_prune_storage_usage(config.storage_usage_retention_period, boot_ts)
                 .await?;
+            if let Some(rollup_age) = catalog.system_config().storage_usage_rollup_age() {
+                let mut storage = catalog.storage().await;
+                // Read-only catalogs cannot commit the rollups, so they report the raw events.
+                if !storage.is_read_only() {
+                    let before_ts = u64::from(boot_ts)
+                        .saturating_sub(u64::try_from(rollup_age.as_millis()).unwrap_or(u64::MAX));
+                    let mut tx = storage.transaction().await?;
+                    let rollups = tx.compact_storage_usage(before_ts).await?;
+                    if !rollups.is_empty() {
+                        tx.commit().await?;
+                        storage_usage_events = storage.get_storage_usage().await?;
+                    }
+                }
+            }
             for event in storage_usage_events {
                 builtin_table_updates.push(catalog.state.pack_storage_usage_update(&event)?);
             }
@@ -1455,19 +1521,22 @@ impl Catalog {
         migration_metadata: &mut BuiltinMigrationMetadata,
     ) -> Result<(), Error> {
         txn.remove_items(migration_metadata.user_drop_ops.drain(..).collect())?;
-        for (id, schema_id, name) in migration_metadata.user_create_ops.drain(..) {
-            let entry = state.get_entry(&id);
-            let item = entry.item();
-            let serialized_item = item.to_serialized();
-            txn.insert_item(
-                id,
-                schema_id,
-                &name,
-                serialized_item,
-                entry.owner_id().clone(),
-                entry.privileges().all_values_owned().collect(),
-            )?;
-        }
+        let items = migration_metadata
+            .user_create_ops
+            .drain(..)
+            .map(|(id, schema_id, name)| {
+                let entry = state.get_entry(&id);
+                Item {
+                    id,
+                    schema_id,
+                    name,
+                    create_sql: entry.item().to_serialized(),
+                    owner_id: entry.owner_id().clone(),
+                    privileges: entry.privileges().all_values_owned().collect(),
+                }
+            })
+            .collect();
+        txn.insert_items(items)?;
         txn.update_system_object_mappings(std::mem::take(
             &mut migration_metadata.migrated_system_object_mappings,
         ))?;
@@ -1720,6 +1789,17 @@ impl Catalog {
     }
 }
 
+fn add_new_id_allocators_migration(
+    txn: &mut Transaction<'_>,
+) -> Result<(), mz_catalog::durable::CatalogError> {
+    for (name, next_id) in NAMESPACED_ID_ALLOCATORS {
+        if txn.ensure_id_allocator(name, *next_id)? {
+            info!("created id allocator {name}");
+        }
+    }
+    Ok(())
+}
+
 fn add_new_builtin_clusters_migration(
     txn: &mut mz_catalog::durable::Transaction<'_>,
 ) -> Result<(), mz_catalog::durable::CatalogError> {
@@ -1737,6 +1817,9 @@ fn add_new_builtin_clusters_migration(
                 mz_catalog::durable::ClusterConfig {
                     // TODO: Should builtin clusters be managed or unmanaged?
                     variant: mz_catalog::durable::ClusterVariant::Unmanaged,
+                    priority: ClusterPriority::default(),
+                    memory_quota: None,
+                    resize: None,
                 },
             )?;
         }
@@ -1792,6 +1875,7 @@ pub(crate) fn builtin_cluster_replica_config(
     mz_catalog::durable::ReplicaConfig {
         location: mz_catalog::durable::ReplicaLocation::Managed {
             availability_zone: None,
+            availability_zone_constraints: vec![],
             billed_as: None,
             disk: false,
             internal: false,
@@ -1816,7 +1900,7 @@ mod builtin_migration_tests {
     use itertools::Itertools;
     use mz_catalog::memory::objects::Table;
 
-    use mz_controller_types::ClusterId;
+    use mz_controller_types::{ClusterId, ClusterPriority};
     use mz_expr::MirRelationExpr;
     use mz_ore::now::NOW_ZERO;
 
@@ -30,8 +30,9 @@ use mz_catalog::builtin::{Builtin, BuiltinCluster, BuiltinLog, BuiltinSource, Bu
 use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
     CatalogEntry, CatalogItem, Cluster, ClusterConfig, ClusterReplica, ClusterReplicaProcessStatus,
-    CommentsMap, Connection, DataSourceDesc, Database, DefaultPrivileges, Index, MaterializedView,
-    Role, Schema, Secret, Sink, Source, Table, Type, View,
+    ColumnMasksMap, CommentsMap, Connection, DataSourceDesc, Database, DefaultPrivileges, Index,
+    MaterializedView, Role, Schema, Secret, ServiceAccount, Sink, Source, Table, TagsMap, Type,
+    View,
 };
 use mz_catalog::{LINKED_CLUSTER_REPLICA_NAME, SYSTEM_CONN_ID};
 use mz_controller::clusters::{
@@ -68,7 +69,9 @@ use mz_sql::plan::{
     Plan, PlanContext,
 };
 use mz_sql::session::user::MZ_SYSTEM_ROLE_ID;
-use mz_sql::session::vars::{SystemVars, Var, VarInput, DEFAULT_DATABASE_NAME};
+use mz_sql::session::vars::{
+    SystemVars, Var, VarInput, DEFAULT_DATABASE_NAME, DEFAULT_SOURCE_SINK_CLUSTER,
+};
 use mz_sql::{plan, rbac};
 use mz_sql_parser::ast::QualifiedReplica;
 use mz_storage_types::connections::inline::{
@@ -128,6 +131,9 @@ pub struct CatalogState {
     pub(super) default_privileges: DefaultPrivileges,
     pub(super) system_privileges: PrivilegeMap,
     pub(super) comments: CommentsMap,
+    pub(super) tags: TagsMap,
+    pub(super) column_masks: ColumnMasksMap,
+    pub(super) service_accounts: BTreeMap<String, ServiceAccount>,
 }
 
 fn skip_temp_items<S>(
@@ -182,6 +188,9 @@ impl CatalogState {
             default_privileges: Default::default(),
             system_privileges: Default::default(),
             comments: Default::default(),
+            tags: Default::default(),
+            column_masks: Default::default(),
+            service_accounts: Default::default(),
         }
     }
 
@@ -681,6 +690,10 @@ impl CatalogState {
             .map(|id| &self.roles_by_id[id])
     }
 
+    pub(super) fn try_get_service_account(&self, name: &str) -> Option<&ServiceAccount> {
+        self.service_accounts.get(name)
+    }
+
     pub(super) fn get_role_mut(&mut self, id: &RoleId) -> &mut Role {
         self.roles_by_id.get_mut(id).expect("catalog out of sync")
     }
@@ -816,6 +829,12 @@ impl CatalogState {
         // 2. After this step, feature flag configuration must not be
         //    overridden.
         session_catalog.system_vars_mut().enable_for_item_parsing();
+        // Sources and sinks record the cluster they were created in, so the
+        // default cluster must not be applied to them when re-planning.
+        session_catalog
+            .system_vars_mut()
+            .reset(DEFAULT_SOURCE_SINK_CLUSTER.name())
+            .expect("known to exist");
 
         let stmt = mz_sql::parse::parse(&create_sql)?.into_element().ast;
         let (stmt, resolved_ids) = mz_sql::names::resolve(&session_catalog, stmt)?;
@@ -1298,6 +1317,7 @@ impl CatalogState {
                 .collect(),
             config,
             owner_id,
+            draining: false,
         };
         let cluster = self
             .clusters_by_id
@@ -1319,6 +1339,19 @@ impl CatalogState {
         cluster.rename_replica(replica_id, to_name);
     }
 
+    /// Sets whether the specified cluster replica is draining.
+    ///
+    /// Panics if the cluster or replica does not exist.
+    pub(super) fn set_cluster_replica_draining(
+        &mut self,
+        cluster_id: ClusterId,
+        replica_id: ReplicaId,
+        draining: bool,
+    ) {
+        let replica = self.get_cluster_replica_mut(cluster_id, replica_id);
+        replica.draining = draining;
+    }
+
     /// Inserts or updates the status of the specified cluster replica process.
     ///
     /// Panics if the cluster or replica does not exist.
@@ -1925,6 +1958,7 @@ impl CatalogState {
             mz_catalog::durable::ReplicaLocation::Managed {
                 size,
                 availability_zone,
+                availability_zone_constraints,
                 disk,
                 billed_as,
                 internal,
@@ -1954,6 +1988,7 @@ impl CatalogState {
                         }
                         (None, None) => ManagedReplicaAvailabilityZones::FromReplica(None),
                     },
+                    availability_zone_constraints,
                     size,
                     disk,
                     billed_as,
@@ -2038,7 +2073,31 @@ impl CatalogState {
         details: EventDetails,
     ) -> Result<(), Error> {
         let user = session.map(|session| session.user().name.to_string());
+        self.add_user_event_to_audit_log(
+            oracle_write_ts,
+            user,
+            tx,
+            builtin_table_updates,
+            audit_events,
+            event_type,
+            object_type,
+            details,
+        )
+    }
 
+    /// Like [`CatalogState::add_to_audit_log`], but attributes the event to `user` rather than to
+    /// the user of a session.
+    pub(crate) fn add_user_event_to_audit_log(
+        &self,
+        oracle_write_ts: mz_repr::Timestamp,
+        user: Option<String>,
+        tx: &mut mz_catalog::durable::Transaction,
+        builtin_table_updates: &mut Vec<BuiltinTableUpdate>,
+        audit_events: &mut Vec<VersionedEvent>,
+        event_type: EventType,
+        object_type: ObjectType,
+        details: EventDetails,
+    ) -> Result<(), Error> {
         // unsafe_mock_audit_event_timestamp can only be set to Some when running in unsafe mode.
 
         let occurred_at = match self
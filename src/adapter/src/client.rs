@@ -10,6 +10,7 @@
 use std::collections::BTreeMap;
 use std::fmt::{Debug, Display, Formatter};
 use std::future::Future;
+use std::net::IpAddr;
 use std::pin::{self};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -44,8 +45,8 @@ use uuid::Uuid;
 
 use crate::catalog::Catalog;
 use crate::command::{
-    Canceled, CatalogDump, CatalogSnapshot, Command, ExecuteResponse, GetVariablesResponse,
-    Response,
+    AuthenticateServiceAccountResponse, Canceled, CatalogDump, CatalogSnapshot, Command,
+    ExecuteResponse, GetVariablesResponse, Response,
 };
 use crate::coord::{Coordinator, ExecuteContextExtra};
 use crate::error::AdapterError;
@@ -158,6 +159,7 @@ impl Client {
         let conn_id = session.conn_id().clone();
         let secret_key = session.secret_key();
         let uuid = session.uuid();
+        let client_ip = session.client_ip();
         let application_name = session.application_name().into();
         let notice_tx = session.retain_notice_transmitter();
 
@@ -169,6 +171,7 @@ impl Client {
             conn_id,
             secret_key,
             uuid,
+            client_ip,
             application_name,
             notice_tx,
         });
@@ -269,6 +272,23 @@ Issue a SQL query to get started. Need help?
         });
     }
 
+    /// Records that a client failed to authenticate as `user`, for the reason described by
+    /// `reason`.
+    pub fn login_failure(
+        &self,
+        user: String,
+        client_ip: Option<IpAddr>,
+        application_name: String,
+        reason: String,
+    ) {
+        self.send(Command::LoginFailure {
+            user,
+            client_ip,
+            application_name,
+            reason,
+        });
+    }
+
     /// Executes a single SQL statement that returns rows as the
     /// `mz_support` user.
     pub async fn introspection_execute_one(&self, sql: &str) -> Result<Vec<Row>, anyhow::Error> {
@@ -340,6 +360,41 @@ Issue a SQL query to get started. Need help?
         response
     }
 
+    /// Returns the password verifier of the role named `role_name`, or `None` if the role does
+    /// not exist or has no password.
+    pub async fn role_password_verifier(&self, role_name: &str) -> Option<String> {
+        let (tx, rx) = oneshot::channel();
+        self.send(Command::CatalogSnapshot { tx });
+        let CatalogSnapshot { catalog } = rx.await.expect("sender dropped");
+        catalog
+            .try_get_role_by_name(role_name)?
+            .attributes
+            .password_verifier
+            .clone()
+    }
+
+    /// Reports whether a service account named `name` exists.
+    pub async fn service_account_exists(&self, name: &str) -> bool {
+        let (tx, rx) = oneshot::channel();
+        self.send(Command::CatalogSnapshot { tx });
+        let CatalogSnapshot { catalog } = rx.await.expect("sender dropped");
+        catalog.try_get_service_account(name).is_some()
+    }
+
+    /// Authenticates as the service account named `name` using `token`, returning the role that
+    /// the service account acts as and the scopes that its token is limited to.
+    pub async fn authenticate_service_account(
+        &self,
+        name: String,
+        token: String,
+    ) -> Result<AuthenticateServiceAccountResponse, AdapterError> {
+        let (tx, rx) = oneshot::channel();
+        self.send(Command::AuthenticateServiceAccount { name, token, tx });
+        rx.await.map_err(|_| {
+            AdapterError::Internal("failed to receive authentication response".to_string())
+        })?
+    }
+
     #[instrument(level = "debug", skip_all)]
     fn send(&self, cmd: Command) {
         self.inner_cmd_tx
@@ -631,6 +686,16 @@ impl SessionClient {
             })
     }
 
+    /// Vacuums the durable catalog according to the current system configuration, returning a
+    /// report of what was vacuumed.
+    ///
+    /// No authorization is performed, so access to this function must be limited to internal
+    /// servers or superusers.
+    pub async fn vacuum_catalog(&mut self) -> Result<VacuumReport, AdapterError> {
+        self.send_without_session(|tx| Command::VacuumCatalog { tx })
+            .await
+    }
+
     /// Tells the coordinator a statement has finished execution, in the cases
     /// where we have no other reason to communicate with the coordinator.
     pub fn retire_execute(
@@ -763,6 +828,8 @@ impl SessionClient {
                 Command::Execute { .. } => typ = Some("execute"),
                 Command::AppendWebhook { .. } => typ = Some("webhook"),
                 Command::Startup { .. }
+                | Command::LoginFailure { .. }
+                | Command::AuthenticateServiceAccount { .. }
                 | Command::CatalogSnapshot { .. }
                 | Command::Commit { .. }
                 | Command::CancelRequest { .. }
@@ -771,7 +838,8 @@ impl SessionClient {
                 | Command::SetSystemVars { .. }
                 | Command::Terminate { .. }
                 | Command::RetireExecute { .. }
-                | Command::CheckConsistency { .. } => {}
+                | Command::CheckConsistency { .. }
+                | Command::VacuumCatalog { .. } => {}
             };
             cmd
         });
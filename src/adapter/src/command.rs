@@ -10,6 +10,7 @@
 use std::any::Any;
 use std::collections::{BTreeMap, BTreeSet};
 use std::future::Future;
+use std::net::IpAddr;
 use std::pin::Pin;
 use std::sync::Arc;
 
@@ -18,6 +19,7 @@ use derivative::Derivative;
 use enum_kinds::EnumKind;
 use futures::future::BoxFuture;
 use mz_adapter_types::connection::{ConnectionId, ConnectionIdType};
+use mz_catalog::durable::VacuumReport;
 use mz_ore::collections::CollectionExt;
 use mz_ore::soft_assert;
 use mz_ore::tracing::OpenTelemetryContext;
@@ -29,7 +31,9 @@ use mz_sql::catalog::ObjectType;
 use mz_sql::plan::{ExecuteTimeout, Plan, PlanKind};
 use mz_sql::session::user::User;
 use mz_sql::session::vars::{OwnedVarInput, Var};
-use mz_sql_parser::ast::{AlterObjectRenameStatement, AlterOwnerStatement, DropObjectsStatement};
+use mz_sql_parser::ast::{
+    AlterObjectRenameStatement, AlterOwnerStatement, DropObjectsStatement, UndropObjectStatement,
+};
 use tokio::sync::{mpsc, oneshot, watch};
 use uuid::Uuid;
 
@@ -62,10 +66,27 @@ pub enum Command {
         conn_id: ConnectionId,
         secret_key: u32,
         uuid: Uuid,
+        client_ip: Option<IpAddr>,
         application_name: String,
         notice_tx: mpsc::UnboundedSender<AdapterNotice>,
     },
 
+    /// Records that a client failed to authenticate as `user`.
+    LoginFailure {
+        user: String,
+        client_ip: Option<IpAddr>,
+        application_name: String,
+        reason: String,
+    },
+
+    /// Authenticates a client as the service account `name` using `token`, responding with the
+    /// role and scopes of the service account.
+    AuthenticateServiceAccount {
+        name: String,
+        token: String,
+        tx: oneshot::Sender<Result<AuthenticateServiceAccountResponse, AdapterError>>,
+    },
+
     Execute {
         portal_name: String,
         session: Session,
@@ -127,6 +148,10 @@ pub enum Command {
     CheckConsistency {
         tx: oneshot::Sender<Result<(), CoordinatorInconsistencies>>,
     },
+
+    VacuumCatalog {
+        tx: oneshot::Sender<Result<VacuumReport, AdapterError>>,
+    },
 }
 
 impl Command {
@@ -135,6 +160,8 @@ impl Command {
             Command::Execute { session, .. } | Command::Commit { session, .. } => Some(session),
             Command::CancelRequest { .. }
             | Command::Startup { .. }
+            | Command::LoginFailure { .. }
+            | Command::AuthenticateServiceAccount { .. }
             | Command::CatalogSnapshot { .. }
             | Command::PrivilegedCancelRequest { .. }
             | Command::AppendWebhook { .. }
@@ -142,7 +169,8 @@ impl Command {
             | Command::GetSystemVars { .. }
             | Command::SetSystemVars { .. }
             | Command::RetireExecute { .. }
-            | Command::CheckConsistency { .. } => None,
+            | Command::CheckConsistency { .. }
+            | Command::VacuumCatalog { .. } => None,
         }
     }
 
@@ -151,6 +179,8 @@ impl Command {
             Command::Execute { session, .. } | Command::Commit { session, .. } => Some(session),
             Command::CancelRequest { .. }
             | Command::Startup { .. }
+            | Command::LoginFailure { .. }
+            | Command::AuthenticateServiceAccount { .. }
             | Command::CatalogSnapshot { .. }
             | Command::PrivilegedCancelRequest { .. }
             | Command::AppendWebhook { .. }
@@ -158,7 +188,8 @@ impl Command {
             | Command::GetSystemVars { .. }
             | Command::SetSystemVars { .. }
             | Command::RetireExecute { .. }
-            | Command::CheckConsistency { .. } => None,
+            | Command::CheckConsistency { .. }
+            | Command::VacuumCatalog { .. } => None,
         }
     }
 }
@@ -197,6 +228,15 @@ impl Transmittable for StartupResponse {
     }
 }
 
+/// The response to [`Client::authenticate_service_account`](crate::Client::authenticate_service_account).
+#[derive(Debug)]
+pub struct AuthenticateServiceAccountResponse {
+    /// The name of the role that the service account acts as.
+    pub role_name: String,
+    /// The scopes that the service account's token is limited to.
+    pub scopes: BTreeSet<String>,
+}
+
 /// The response to [`SessionClient::dump_catalog`](crate::SessionClient::dump_catalog).
 #[derive(Debug, Clone)]
 pub struct CatalogDump(String);
@@ -294,10 +334,14 @@ pub enum ExecuteResponse {
     CreatedCluster,
     /// The requested cluster replica was created.
     CreatedClusterReplica,
+    /// The requested cluster replica size was created.
+    CreatedClusterReplicaSize,
     /// The requested index was created.
     CreatedIndex,
     /// The requested secret was created.
     CreatedSecret,
+    /// The requested service account was created.
+    CreatedServiceAccount,
     /// The requested sink was created.
     CreatedSink,
     /// The requested source was created.
@@ -322,10 +366,14 @@ pub enum ExecuteResponse {
     DiscardedTemp,
     /// All state associated with the session has been discarded.
     DiscardedAll,
+    /// The requested cluster replica size was dropped.
+    DroppedClusterReplicaSize,
     /// The requested object was dropped.
     DroppedObject(ObjectType),
     /// The requested objects were dropped.
     DroppedOwned,
+    /// The requested service account was dropped.
+    DroppedServiceAccount,
     /// The provided query was empty.
     EmptyQuery,
     /// Fetch results from a cursor.
@@ -386,6 +434,8 @@ pub enum ExecuteResponse {
         /// Session parameters that changed because the transaction ended.
         params: BTreeMap<&'static str, String>,
     },
+    /// The requested object was restored.
+    UndroppedObject(ObjectType),
     /// The specified number of rows were updated in the requested table.
     Updated(usize),
     /// A connection was validated.
@@ -416,6 +466,9 @@ impl TryFrom<&Statement<Raw>> for ExecuteResponse {
             Statement::DropObjects(DropObjectsStatement { object_type, .. }) => {
                 ExecuteResponse::DroppedObject((*object_type).into())
             }
+            Statement::UndropObject(UndropObjectStatement { object_type, .. }) => {
+                ExecuteResponse::UndroppedObject((*object_type).into())
+            }
             Statement::AlterObjectRename(AlterObjectRenameStatement { object_type, .. })
             | Statement::AlterOwner(AlterOwnerStatement { object_type, .. }) => {
                 ExecuteResponse::AlteredObject((*object_type).into())
@@ -463,8 +516,14 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::CreatedClusterReplica => {
                 Ok(ExecuteResponse::CreatedClusterReplica)
             }
+            ExecuteResponseKind::CreatedClusterReplicaSize => {
+                Ok(ExecuteResponse::CreatedClusterReplicaSize)
+            }
             ExecuteResponseKind::CreatedIndex => Ok(ExecuteResponse::CreatedIndex),
             ExecuteResponseKind::CreatedSecret => Ok(ExecuteResponse::CreatedSecret),
+            ExecuteResponseKind::CreatedServiceAccount => {
+                Ok(ExecuteResponse::CreatedServiceAccount)
+            }
             ExecuteResponseKind::CreatedSink => Ok(ExecuteResponse::CreatedSink),
             ExecuteResponseKind::CreatedSource => Ok(ExecuteResponse::CreatedSource),
             ExecuteResponseKind::CreatedTable => Ok(ExecuteResponse::CreatedTable),
@@ -479,8 +538,14 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::Deleted => Err(()),
             ExecuteResponseKind::DiscardedTemp => Ok(ExecuteResponse::DiscardedTemp),
             ExecuteResponseKind::DiscardedAll => Ok(ExecuteResponse::DiscardedAll),
+            ExecuteResponseKind::DroppedClusterReplicaSize => {
+                Ok(ExecuteResponse::DroppedClusterReplicaSize)
+            }
             ExecuteResponseKind::DroppedObject => Err(()),
             ExecuteResponseKind::DroppedOwned => Ok(ExecuteResponse::DroppedOwned),
+            ExecuteResponseKind::DroppedServiceAccount => {
+                Ok(ExecuteResponse::DroppedServiceAccount)
+            }
             ExecuteResponseKind::EmptyQuery => Ok(ExecuteResponse::EmptyQuery),
             ExecuteResponseKind::Fetch => Err(()),
             ExecuteResponseKind::GrantedPrivilege => Ok(ExecuteResponse::GrantedPrivilege),
@@ -497,6 +562,7 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::Subscribing => Err(()),
             ExecuteResponseKind::TransactionCommitted => Err(()),
             ExecuteResponseKind::TransactionRolledBack => Err(()),
+            ExecuteResponseKind::UndroppedObject => Err(()),
             ExecuteResponseKind::Updated => Err(()),
             ExecuteResponseKind::ValidatedConnection => Ok(ExecuteResponse::ValidatedConnection),
             ExecuteResponseKind::SendingRowsImmediate => Err(()),
@@ -524,8 +590,10 @@ impl ExecuteResponse {
             CreatedRole => Some("CREATE ROLE".into()),
             CreatedCluster { .. } => Some("CREATE CLUSTER".into()),
             CreatedClusterReplica { .. } => Some("CREATE CLUSTER REPLICA".into()),
+            CreatedClusterReplicaSize => Some("CREATE CLUSTER REPLICA SIZE".into()),
             CreatedIndex { .. } => Some("CREATE INDEX".into()),
             CreatedSecret { .. } => Some("CREATE SECRET".into()),
+            CreatedServiceAccount => Some("CREATE SERVICE ACCOUNT".into()),
             CreatedSink { .. } => Some("CREATE SINK".into()),
             CreatedSource { .. } => Some("CREATE SOURCE".into()),
             CreatedTable { .. } => Some("CREATE TABLE".into()),
@@ -538,8 +606,10 @@ impl ExecuteResponse {
             Deleted(n) => Some(format!("DELETE {}", n)),
             DiscardedTemp => Some("DISCARD TEMP".into()),
             DiscardedAll => Some("DISCARD ALL".into()),
+            DroppedClusterReplicaSize => Some("DROP CLUSTER REPLICA SIZE".into()),
             DroppedObject(o) => Some(format!("DROP {o}")),
             DroppedOwned => Some("DROP OWNED".into()),
+            DroppedServiceAccount => Some("DROP SERVICE ACCOUNT".into()),
             EmptyQuery => None,
             Fetch { .. } => None,
             GrantedPrivilege => Some("GRANT".into()),
@@ -566,6 +636,7 @@ impl ExecuteResponse {
             Subscribing { .. } => None,
             TransactionCommitted { .. } => Some("COMMIT".into()),
             TransactionRolledBack { .. } => Some("ROLLBACK".into()),
+            UndroppedObject(o) => Some(format!("UNDROP {o}")),
             Updated(n) => Some(format!("UPDATE {}", n)),
             ValidatedConnection => Some("VALIDATE CONNECTION".into()),
         }
@@ -583,11 +654,13 @@ impl ExecuteResponse {
             AlterClusterRename
             | AlterClusterSwap
             | AlterCluster
+            | AlterClusterReplica
             | AlterClusterReplicaRename
             | AlterOwner
             | AlterItemRename
             | AlterItemSwap
             | AlterNoop
+            | AlterDatabaseRename
             | AlterSchemaRename
             | AlterSchemaSwap
             | AlterSecret
@@ -599,6 +672,7 @@ impl ExecuteResponse {
             }
             AlterDefaultPrivileges => vec![AlteredDefaultPrivileges],
             AlterSetCluster => vec![AlteredObject],
+            AlterColumnMask => vec![AlteredObject],
             AlterIndexSetOptions | AlterIndexResetOptions => {
                 vec![AlteredObject, AlteredIndexLogicalCompaction]
             }
@@ -616,8 +690,10 @@ impl ExecuteResponse {
             CreateRole => vec![CreatedRole],
             CreateCluster => vec![CreatedCluster],
             CreateClusterReplica => vec![CreatedClusterReplica],
+            CreateClusterReplicaSize => vec![CreatedClusterReplicaSize],
             CreateSource | CreateSources => vec![CreatedSource],
             CreateSecret => vec![CreatedSecret],
+            CreateServiceAccount => vec![CreatedServiceAccount],
             CreateSink => vec![CreatedSink],
             CreateTable => vec![CreatedTable],
             CreateView => vec![CreatedView],
@@ -628,11 +704,15 @@ impl ExecuteResponse {
             Declare => vec![DeclaredCursor],
             DiscardTemp => vec![DiscardedTemp],
             DiscardAll => vec![DiscardedAll],
+            DropClusterReplicaSize => vec![DroppedClusterReplicaSize],
             DropObjects => vec![DroppedObject],
             DropOwned => vec![DroppedOwned],
+            DropServiceAccount => vec![DroppedServiceAccount],
+            UndropObject => vec![UndroppedObject],
             PlanKind::EmptyQuery => vec![ExecuteResponseKind::EmptyQuery],
             ExplainPlan | ExplainTimestamp | Select | ShowAllVariables | ShowCreate
-            | ShowColumns | ShowVariable | InspectShard | ExplainSinkSchema => {
+            | ShowColumns | ShowVariable | InspectShard | ExplainSinkSchema
+            | ExplainCreateSource => {
                 vec![CopyTo, SendingRows, SendingRowsImmediate]
             }
             Execute | ReadThenWrite => vec![
@@ -67,7 +67,7 @@
 //!
 
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
-use std::net::Ipv4Addr;
+use std::net::{IpAddr, Ipv4Addr};
 use std::num::NonZeroI64;
 use std::ops::Neg;
 use std::str::FromStr;
@@ -106,9 +106,9 @@ use mz_repr::role_id::RoleId;
 use mz_repr::{GlobalId, Timestamp};
 use mz_secrets::cache::CachingSecretsReader;
 use mz_secrets::{SecretsController, SecretsReader};
-use mz_sql::ast::{CreateSubsourceStatement, Raw, Statement};
+use mz_sql::ast::{Raw, Statement};
 use mz_sql::catalog::EnvironmentId;
-use mz_sql::names::{Aug, ResolvedIds};
+use mz_sql::names::{ResolvedDatabaseSpecifier, ResolvedIds, SchemaSpecifier};
 use mz_sql::plan::{CopyFormat, CreateConnectionPlan, Params, QueryWhen};
 use mz_sql::rbac::UnauthorizedError;
 use mz_sql::session::user::{RoleMetadata, User};
@@ -137,9 +137,14 @@ use crate::client::{Client, Handle};
 use crate::command::{Canceled, Command, ExecuteResponse};
 use crate::config::{SynchronizedParameters, SystemParameterFrontend, SystemParameterSyncConfig};
 use crate::coord::appends::{Deferred, GroupCommitPermit, PendingWriteTxn};
+use crate::coord::background_purification::BackgroundPurification;
 use crate::coord::dataflows::dataflow_import_id_bundle;
 use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::peek::PendingPeek;
+use crate::coord::purification_cache::{
+    PurificationCache, PurificationCacheKey, PurifiedStatement,
+};
+use crate::coord::purification_pool::PurificationPool;
 use crate::coord::read_policy::ReadCapability;
 use crate::coord::timeline::{TimelineContext, TimelineState, WriteTimestamp};
 use crate::coord::timestamp_oracle::catalog_oracle::CatalogTimestampPersistence;
@@ -160,10 +165,15 @@ use mz_catalog::builtin::BUILTINS;
 use mz_catalog::durable::DurableCatalogState;
 
 pub(crate) mod dataflows;
+use self::session_audit_log::SessionAuditLog;
 use self::statement_logging::{StatementLogging, StatementLoggingId};
 
+pub(crate) mod background_purification;
 pub(crate) mod id_bundle;
 pub(crate) mod peek;
+pub(crate) mod purification_cache;
+pub(crate) mod purification_pool;
+pub(crate) mod session_audit_log;
 pub(crate) mod statement_logging;
 pub(crate) mod timeline;
 pub(crate) mod timestamp_oracle;
@@ -210,6 +220,10 @@ pub enum Message<T = mz_repr::Timestamp> {
     LinearizeReads(Vec<PendingReadTxn>),
     StorageUsageFetch,
     StorageUsageUpdate(ShardsUsageReferenced),
+    CatalogVacuum,
+    RoleExpiration,
+    ClusterSchedule,
+    ClusterResize,
     RealTimeRecencyTimestamp {
         conn_id: ConnectionId,
         real_time_recency_ts: Timestamp,
@@ -235,7 +249,13 @@ pub enum Message<T = mz_repr::Timestamp> {
         stage: PeekStage,
     },
     DrainStatementLog,
+    DrainSessionAuditLog,
     PrivateLinkVpcEndpointEvents(BTreeMap<GlobalId, VpcEndpointEvent>),
+    /// Reports the result of a `CREATE SOURCE ... IN BACKGROUND` job.
+    BackgroundPurificationFinished {
+        id: u64,
+        result: Result<(), AdapterError>,
+    },
 }
 
 impl Message {
@@ -245,6 +265,10 @@ impl Message {
             Message::Command(_, msg) => match msg {
                 Command::CatalogSnapshot { .. } => "command-catalog_snapshot",
                 Command::Startup { .. } => "command-startup",
+                Command::LoginFailure { .. } => "command-login_failure",
+                Command::AuthenticateServiceAccount { .. } => {
+                    "command-authenticate_service_account"
+                }
                 Command::Execute { .. } => "command-execute",
                 Command::Commit { .. } => "command-commit",
                 Command::CancelRequest { .. } => "command-cancel_request",
@@ -255,6 +279,7 @@ impl Message {
                 Command::Terminate { .. } => "command-terminate",
                 Command::RetireExecute { .. } => "command-retire_execute",
                 Command::CheckConsistency { .. } => "command-check_consistency",
+                Command::VacuumCatalog { .. } => "command-vacuum_catalog",
             },
             Message::ControllerReady => "controller_ready",
             Message::PurifiedStatementReady(_) => "purified_statement_ready",
@@ -268,6 +293,10 @@ impl Message {
             Message::LinearizeReads(_) => "linearize_reads",
             Message::StorageUsageFetch => "storage_usage_fetch",
             Message::StorageUsageUpdate(_) => "storage_usage_update",
+            Message::CatalogVacuum => "catalog_vacuum",
+            Message::RoleExpiration => "role_expiration",
+            Message::ClusterSchedule => "cluster_schedule",
+            Message::ClusterResize => "cluster_resize",
             Message::RealTimeRecencyTimestamp { .. } => "real_time_recency_timestamp",
             Message::RetireExecute { .. } => "retire_execute",
             Message::ExecuteSingleStatementTransaction { .. } => {
@@ -275,8 +304,10 @@ impl Message {
             }
             Message::PeekStageReady { .. } => "peek_stage_ready",
             Message::DrainStatementLog => "drain_statement_log",
+            Message::DrainSessionAuditLog => "drain_session_audit_log",
             Message::AlterConnectionValidationReady(..) => "alter_connection_validation_ready",
             Message::PrivateLinkVpcEndpointEvents(_) => "private_link_vpc_endpoint_events",
+            Message::BackgroundPurificationFinished { .. } => "background_purification_finished",
         }
     }
 }
@@ -290,13 +321,12 @@ pub struct BackgroundWorkResult<T> {
     pub params: Params,
     pub resolved_ids: ResolvedIds,
     pub original_stmt: Statement<Raw>,
+    /// The key under which to cache the result, if it may be cached.
+    pub cache_key: Option<PurificationCacheKey>,
     pub otel_ctx: OpenTelemetryContext,
 }
 
-pub type PurifiedStatementReady = BackgroundWorkResult<(
-    Vec<(GlobalId, CreateSubsourceStatement<Aug>)>,
-    Statement<Aug>,
-)>;
+pub type PurifiedStatementReady = BackgroundWorkResult<PurifiedStatement>;
 
 #[derive(Derivative)]
 #[derivative(Debug)]
@@ -579,6 +609,8 @@ pub struct ConnMeta {
     application_name: String,
     uuid: Uuid,
     conn_id: ConnectionId,
+    /// The IP address of the client, if known.
+    client_ip: Option<IpAddr>,
 
     /// Sinks that will need to be dropped when the current transaction, if
     /// any, is cleared.
@@ -994,6 +1026,19 @@ pub struct Coordinator {
     /// A map from active subscribes to the subscribe description.
     active_subscribes: BTreeMap<GlobalId, ActiveSubscribe>,
 
+    /// A map from IDs of `CREATE SOURCE ... IN BACKGROUND` jobs to their
+    /// description.
+    background_purifications: BTreeMap<u64, BackgroundPurification>,
+    /// The ID to assign to the next background purification.
+    next_background_purification_id: u64,
+    /// Recently purified statements that may be reused by retries.
+    purification_cache: PurificationCache,
+    /// Limits how many statements are purified concurrently.
+    purification_pool: PurificationPool,
+    /// The time at which cluster schedules were last applied, if they have
+    /// been applied since the coordinator started.
+    cluster_schedules_applied_at: Option<EpochMillis>,
+
     /// Serializes accesses to write critical sections.
     write_lock: Arc<tokio::sync::Mutex<()>>,
     /// Holds plans deferred due to write lock.
@@ -1032,6 +1077,13 @@ pub struct Coordinator {
     storage_usage_client: StorageUsageClient,
     /// The interval at which to collect storage usage information.
     storage_usage_collection_interval: Duration,
+    /// The storage usage of each schema as of the most recent storage usage
+    /// collection, and the time of that collection. Intended for inclusion in
+    /// `mz_storage_usage_by_schema`.
+    storage_usage_by_schema: Option<(
+        EpochMillis,
+        BTreeMap<(ResolvedDatabaseSpecifier, SchemaSpecifier), u64>,
+    )>,
 
     /// Segment analytics client.
     #[derivative(Debug = "ignore")]
@@ -1049,6 +1101,9 @@ pub struct Coordinator {
     /// Data used by the statement logging feature.
     statement_logging: StatementLogging,
 
+    /// Session events that are waiting to be recorded in the audit log.
+    session_audit_log: SessionAuditLog,
+
     /// Limit for how many conncurrent webhook requests we allow.
     webhook_concurrency_limit: WebhookConcurrencyLimiter,
 
@@ -1115,6 +1170,7 @@ impl Coordinator {
                     cluster_id: instance.id,
                     replica_id: replica.replica_id,
                     role,
+                    priority: instance.config.priority,
                     config: replica.config.clone(),
                 });
             }
@@ -1415,6 +1471,17 @@ impl Coordinator {
             self.initialize_read_policies(&policies, Some(ts)).await;
         }
 
+        // Replicas that were draining before the restart maintain all dataflows installed above,
+        // but don't install any new ones.
+        for instance in self.catalog.clusters() {
+            for replica in instance.replicas().filter(|replica| replica.draining) {
+                self.controller
+                    .compute
+                    .set_replica_draining(instance.id, replica.replica_id, true)
+                    .unwrap_or_terminate("cannot fail to set replica draining");
+            }
+        }
+
         debug!("coordinator init: announcing completion of initialization to controller");
         // Announce the completion of initialization.
         self.controller.initialization_complete();
@@ -2046,8 +2113,13 @@ impl Coordinator {
             });
 
             self.schedule_storage_usage_collection().await;
+            self.schedule_catalog_vacuum();
+            self.schedule_role_expiration();
+            self.schedule_cluster_schedule();
+            self.schedule_cluster_resize();
             self.spawn_privatelink_vpc_endpoints_watch_task();
             self.spawn_statement_logging_task();
+            self.spawn_session_audit_log_task();
             flags::tracing_config(self.catalog.system_config()).apply(&self.tracing_handle);
 
             // Report if the handling of a single message takes longer than this threshold.
@@ -2350,6 +2422,13 @@ pub fn serve(
 
         let metrics = Metrics::register_into(&metrics_registry);
         let metrics_clone = metrics.clone();
+        let purification_pool = PurificationPool::new(
+            catalog.system_config().max_concurrent_purifications(),
+            catalog
+                .system_config()
+                .max_concurrent_purifications_per_role(),
+            metrics.clone(),
+        );
         let timestamp_oracle_metrics =
             Arc::new(timestamp_oracle::metrics::Metrics::new(&metrics_registry));
         let segment_client_clone = segment_client.clone();
@@ -2424,6 +2503,11 @@ pub fn serve(
                     client_pending_peeks: BTreeMap::new(),
                     pending_real_time_recency_timestamp: BTreeMap::new(),
                     active_subscribes: BTreeMap::new(),
+                    background_purifications: BTreeMap::new(),
+                    next_background_purification_id: 1,
+                    purification_cache: PurificationCache::default(),
+                    purification_pool,
+                    cluster_schedules_applied_at: None,
                     write_lock: Arc::new(tokio::sync::Mutex::new(())),
                     write_lock_wait_group: VecDeque::new(),
                     pending_writes: Vec::new(),
@@ -2434,11 +2518,13 @@ pub fn serve(
                     transient_replica_metadata: BTreeMap::new(),
                     storage_usage_client,
                     storage_usage_collection_interval,
+                    storage_usage_by_schema: None,
                     segment_client,
                     metrics,
                     timestamp_oracle_metrics,
                     tracing_handle,
                     statement_logging: StatementLogging::new(),
+                    session_audit_log: SessionAuditLog::new(),
                     webhook_concurrency_limit,
                     timestamp_oracle_impl,
                     timestamp_oracle_url,
@@ -0,0 +1,285 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Support for `CREATE SOURCE ... IN BACKGROUND`, which purifies and creates
+//! the source as a tracked background job rather than blocking the session
+//! that issued the statement.
+//!
+//! Each job executes the statement in a session forked from the issuing
+//! session and is reported in `mz_internal.mz_background_purifications` until
+//! the issuing session terminates.
+
+use mz_adapter_types::connection::ConnectionId;
+use mz_ore::now::EpochMillis;
+use mz_ore::task;
+use mz_sql::ast::{Raw, Statement};
+use mz_sql::session::vars::ENABLE_BACKGROUND_PURIFICATION;
+use mz_sql_parser::ast::display::AstDisplay;
+use tokio::sync::oneshot;
+use uuid::Uuid;
+
+use crate::command::{ExecuteResponse, Response};
+use crate::coord::{Coordinator, ExecuteContext, ExecuteContextExtra, Message};
+use crate::error::AdapterError;
+use crate::session::Session;
+use crate::util::ClientTransmitter;
+use crate::AdapterNotice;
+
+/// A `CREATE SOURCE` statement whose purification runs in the background.
+#[derive(Debug)]
+pub struct BackgroundPurification {
+    /// The connection id of the session that issued the statement.
+    pub conn_id: ConnectionId,
+    /// The unique ID of the session that executes the statement.
+    pub session_uuid: Uuid,
+    /// The statement, with all literals redacted.
+    pub redacted_sql: String,
+    /// The current status of the job.
+    pub status: BackgroundPurificationStatus,
+    /// The time at which the job was started.
+    pub started_at: EpochMillis,
+    /// The time at which the job finished, if it has.
+    pub finished_at: Option<EpochMillis>,
+    /// Whether the statement has finished executing. A canceled job may still
+    /// be purifying.
+    pub completed: bool,
+    /// Whether the issuing session has terminated, in which case the job is no
+    /// longer reported and is forgotten once it completes.
+    pub session_ended: bool,
+}
+
+/// The status of a [`BackgroundPurification`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackgroundPurificationStatus {
+    Running,
+    Succeeded,
+    Failed(String),
+    Canceled,
+}
+
+impl BackgroundPurificationStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BackgroundPurificationStatus::Running => "running",
+            BackgroundPurificationStatus::Succeeded => "succeeded",
+            BackgroundPurificationStatus::Failed(_) => "failed",
+            BackgroundPurificationStatus::Canceled => "canceled",
+        }
+    }
+
+    /// Returns the error that caused the job to fail, if any.
+    pub fn error(&self) -> Option<&str> {
+        match self {
+            BackgroundPurificationStatus::Failed(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Coordinator {
+    /// Moves the execution of `stmt`, a `CREATE SOURCE ... IN BACKGROUND`
+    /// statement, into a background job.
+    ///
+    /// Responds to `ctx` immediately and returns the context in which the
+    /// statement should continue to execute, or `None` if the job could not be
+    /// started, in which case `ctx` has been retired with an error.
+    pub(crate) async fn begin_background_purification(
+        &mut self,
+        ctx: ExecuteContext,
+        stmt: &mut Statement<Raw>,
+    ) -> Option<ExecuteContext> {
+        if let Err(e) =
+            ENABLE_BACKGROUND_PURIFICATION.enabled(Some(self.catalog().system_config()), None, None)
+        {
+            ctx.retire(Err(e.into()));
+            return None;
+        }
+
+        // The statement is executed as a regular `CREATE SOURCE` from here
+        // on, so that re-purification does not start another job.
+        if let Statement::CreateSource(create_source) = stmt {
+            create_source.in_background = false;
+        }
+
+        let id = self.next_background_purification_id;
+        self.next_background_purification_id += 1;
+
+        let mut session = ctx.session().fork_for_background();
+        session.start_transaction_single_stmt(self.now_datetime());
+        let purification = BackgroundPurification {
+            conn_id: ctx.session().conn_id().clone(),
+            session_uuid: session.uuid(),
+            redacted_sql: stmt.to_ast_string_redacted(),
+            status: BackgroundPurificationStatus::Running,
+            started_at: self.now(),
+            finished_at: None,
+            completed: false,
+            session_ended: false,
+        };
+        let update =
+            self.catalog()
+                .state()
+                .pack_background_purification_update(id, &purification, 1);
+        self.background_purifications.insert(id, purification);
+        let builtin_update_notify = self.builtin_table_update().execute(vec![update]).await;
+        // Make the job visible before responding, so that the client can
+        // immediately observe it.
+        builtin_update_notify.await;
+
+        // The job's response is never seen by a client. Instead, wait for it
+        // elsewhere and report it back to the coordinator.
+        let (tx, rx) = oneshot::channel();
+        let tx = ClientTransmitter::new(tx, self.internal_cmd_tx.clone());
+        let job_ctx = ExecuteContext::from_parts(
+            tx,
+            self.internal_cmd_tx.clone(),
+            session,
+            ExecuteContextExtra::default(),
+        );
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| format!("background_purification:{id}"), async move {
+            let Ok(Response { result, .. }) = rx.await else {
+                // Coordinator went away.
+                return;
+            };
+            // It is not an error for the job to complete after `internal_cmd_rx` is dropped.
+            let result = internal_cmd_tx.send(Message::BackgroundPurificationFinished {
+                id,
+                result: result.map(|_| ()),
+            });
+            if let Err(e) = result {
+                tracing::warn!("internal_cmd_rx dropped before we could send: {:?}", e);
+            }
+        });
+
+        ctx.session()
+            .add_notice(AdapterNotice::BackgroundPurificationStarted { id });
+        ctx.retire(Ok(ExecuteResponse::CreatedSource));
+
+        Some(job_ctx)
+    }
+
+    /// Reports whether `session` executes a background job.
+    pub(crate) fn is_background_purification(&self, session: &Session) -> bool {
+        self.background_purifications
+            .values()
+            .any(|purification| purification.session_uuid == session.uuid())
+    }
+
+    /// Reports whether the background job that `session` executes for, if
+    /// any, has been canceled, in which case its statement must not proceed.
+    pub(crate) fn background_purification_canceled(&self, session: &Session) -> bool {
+        self.background_purifications.values().any(|purification| {
+            purification.session_uuid == session.uuid()
+                && purification.status == BackgroundPurificationStatus::Canceled
+        })
+    }
+
+    /// Records the result of the background job `id`.
+    pub(crate) async fn finish_background_purification(
+        &mut self,
+        id: u64,
+        result: Result<(), AdapterError>,
+    ) {
+        let now = self.now();
+        let Some(purification) = self.background_purifications.get_mut(&id) else {
+            return;
+        };
+        purification.completed = true;
+        if purification.session_ended {
+            self.background_purifications.remove(&id);
+            return;
+        }
+        if purification.status != BackgroundPurificationStatus::Running {
+            // The job was canceled, which has already been reported.
+            return;
+        }
+
+        let mut updates =
+            vec![self
+                .catalog
+                .state()
+                .pack_background_purification_update(id, purification, -1)];
+        purification.status = match result {
+            Ok(()) => BackgroundPurificationStatus::Succeeded,
+            Err(e) => BackgroundPurificationStatus::Failed(e.to_string()),
+        };
+        purification.finished_at = Some(now);
+        updates.push(
+            self.catalog
+                .state()
+                .pack_background_purification_update(id, purification, 1),
+        );
+        self.builtin_table_update().blocking(updates).await;
+    }
+
+    /// Cancels the background job `id`, if it is running, and reports whether
+    /// it was.
+    ///
+    /// A job that has not yet created its source never will; a job that is
+    /// already creating its source runs to completion.
+    pub(crate) fn cancel_background_purification(&mut self, id: u64) -> bool {
+        let now = self.now();
+        let Some(purification) = self.background_purifications.get_mut(&id) else {
+            return false;
+        };
+        if purification.session_ended
+            || purification.status != BackgroundPurificationStatus::Running
+        {
+            return false;
+        }
+        let mut updates =
+            vec![self
+                .catalog
+                .state()
+                .pack_background_purification_update(id, purification, -1)];
+        purification.status = BackgroundPurificationStatus::Canceled;
+        purification.finished_at = Some(now);
+        updates.push(
+            self.catalog
+                .state()
+                .pack_background_purification_update(id, purification, 1),
+        );
+        let _builtin_update_notify = self.builtin_table_update().defer(updates);
+        true
+    }
+
+    /// Cancels and stops reporting all background jobs issued by `conn_id`,
+    /// whose session has terminated.
+    pub(crate) fn end_background_purifications(&mut self, conn_id: &ConnectionId) {
+        let ids: Vec<_> = self
+            .background_purifications
+            .iter()
+            .filter(|(_, purification)| purification.conn_id == *conn_id)
+            .map(|(id, _)| *id)
+            .collect();
+        for id in ids {
+            self.cancel_background_purification(id);
+        }
+
+        let mut updates = vec![];
+        self.background_purifications.retain(|id, purification| {
+            if purification.conn_id != *conn_id || purification.session_ended {
+                return true;
+            }
+            updates.push(self.catalog.state().pack_background_purification_update(
+                *id,
+                purification,
+                -1,
+            ));
+            // Canceled jobs may still be purifying. Remember them until they
+            // complete, so that they do not go on to create their source.
+            purification.session_ended = true;
+            !purification.completed
+        });
+        if !updates.is_empty() {
+            let _builtin_update_notify = self.builtin_table_update().defer(updates);
+        }
+    }
+}
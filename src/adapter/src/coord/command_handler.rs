@@ -12,19 +12,24 @@
 
 use std::any::Any;
 use std::collections::BTreeSet;
+use std::net::IpAddr;
 use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
 use futures::future::LocalBoxFuture;
 use futures::FutureExt;
 use mz_adapter_types::connection::{ConnectionId, ConnectionIdType};
+use mz_audit_log::{EventDetails, EventType, SessionV1};
 use mz_catalog::memory::objects::{CatalogItem, DataSourceDesc, Source};
 use mz_compute_client::protocol::response::PeekResponse;
+use mz_ore::cast::CastFrom;
 use mz_ore::task;
 use mz_ore::tracing::OpenTelemetryContext;
 use mz_repr::role_id::RoleId;
+use mz_secrets::SecretsReader;
 use mz_sql::ast::{
-    CopyRelation, CopyStatement, InsertSource, Query, Raw, SetExpr, Statement, SubscribeStatement,
+    CopyRelation, CopyStatement, CreateSourceStatement, InsertSource, Query, Raw, SetExpr,
+    Statement, SubscribeStatement,
 };
 use mz_sql::catalog::RoleAttributes;
 use mz_sql::names::{PartialItemName, ResolvedIds};
@@ -35,18 +40,21 @@ use mz_sql::rbac;
 use mz_sql::rbac::CREATE_ITEM_USAGE;
 use mz_sql::session::user::User;
 use mz_sql::session::vars::{
-    EndTransactionAction, OwnedVarInput, Var, STATEMENT_LOGGING_SAMPLE_RATE,
+    EndTransactionAction, OwnedVarInput, Var, STATEMENT_LOGGING_SAMPLE_RATE, STATEMENT_TIMEOUT,
 };
 use opentelemetry::trace::TraceContextExt;
+use subtle::ConstantTimeEq;
 use tokio::sync::{mpsc, oneshot, watch};
-use tracing::{debug_span, Instrument};
+use tracing::{debug_span, warn, Instrument};
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 
 use crate::command::{
-    Canceled, CatalogSnapshot, Command, ExecuteResponse, GetVariablesResponse, StartupResponse,
+    AuthenticateServiceAccountResponse, Canceled, CatalogSnapshot, Command, ExecuteResponse,
+    GetVariablesResponse, StartupResponse,
 };
 use crate::coord::appends::{Deferred, PendingWriteTxn};
 use crate::coord::peek::PendingPeek;
+use crate::coord::purification_pool::PurificationPriority;
 use crate::coord::{ConnMeta, Coordinator, Message, PendingTxn, PurifiedStatementReady};
 use crate::error::AdapterError;
 use crate::notice::AdapterNotice;
@@ -74,6 +82,7 @@ impl Coordinator {
                     conn_id,
                     secret_key,
                     uuid,
+                    client_ip,
                     application_name,
                     notice_tx,
                 } => {
@@ -86,12 +95,26 @@ impl Coordinator {
                         conn_id,
                         secret_key,
                         uuid,
+                        client_ip,
                         application_name,
                         notice_tx,
                     )
                     .await;
                 }
 
+                Command::LoginFailure {
+                    user,
+                    client_ip,
+                    application_name,
+                    reason,
+                } => {
+                    self.log_login_failure(user, client_ip, application_name, reason);
+                }
+
+                Command::AuthenticateServiceAccount { name, token, tx } => {
+                    self.handle_authenticate_service_account(name, token, tx);
+                }
+
                 Command::Execute {
                     portal_name,
                     session,
@@ -212,6 +235,10 @@ impl Coordinator {
                 Command::CheckConsistency { tx } => {
                     let _ = tx.send(self.check_consistency());
                 }
+
+                Command::VacuumCatalog { tx } => {
+                    let _ = tx.send(self.catalog_vacuum().await);
+                }
             }
         }
         .instrument(debug_span!("handle_command"))
@@ -227,6 +254,7 @@ impl Coordinator {
         conn_id: ConnectionId,
         secret_key: u32,
         uuid: uuid::Uuid,
+        client_ip: Option<IpAddr>,
         application_name: String,
         notice_tx: mpsc::UnboundedSender<AdapterNotice>,
     ) {
@@ -243,6 +271,17 @@ impl Coordinator {
                     STATEMENT_LOGGING_SAMPLE_RATE.name().to_string(),
                     Box::new(default),
                 ));
+                // Variables set with `ALTER ROLE ... SET` take precedence over the role's
+                // attributes.
+                if let Some(timeout) = self
+                    .catalog()
+                    .get_role(&role_id)
+                    .attributes
+                    .statement_timeout
+                {
+                    session_defaults
+                        .push((STATEMENT_TIMEOUT.name().to_string(), Box::new(timeout)));
+                }
                 let role_defaults = self
                     .catalog()
                     .get_role(&role_id)
@@ -255,6 +294,13 @@ impl Coordinator {
                     .active_sessions
                     .with_label_values(&[session_type])
                     .inc();
+                let user_name = user.name.clone();
+                let details = EventDetails::SessionV1(SessionV1 {
+                    session_id: uuid.to_string(),
+                    connection_id: conn_id.to_string(),
+                    client_ip: client_ip.map(|ip| ip.to_string()),
+                    application_name: application_name.clone(),
+                });
                 let conn = ConnMeta {
                     cancel_tx,
                     secret_key,
@@ -265,11 +311,13 @@ impl Coordinator {
                     application_name,
                     uuid,
                     conn_id: conn_id.clone(),
+                    client_ip,
                     authenticated_role: role_id,
                 };
                 let update = self.catalog().state().pack_session_update(&conn, 1);
                 self.begin_session_for_statement_logging(&conn);
                 self.active_conns.insert(conn_id.clone(), conn);
+                self.log_session_event(EventType::Login, user_name, details);
 
                 // Note: Do NOT await the notify here, we pass this back to whatever requested the
                 // startup to prevent blocking the Coordinator on a builtin table update.
@@ -288,13 +336,19 @@ impl Coordinator {
                     self.handle_terminate(conn_id).await;
                 }
             }
-            Err(_) => {
+            Err(e) => {
                 // Error during startup or sending to adapter, cleanup possible state created by
                 // handle_startup_inner. A user may have been created and it can stay; no need to
                 // delete it.
                 self.catalog_mut()
                     .drop_temporary_schema(&conn_id)
                     .unwrap_or_terminate("unable to drop temporary schema");
+
+                self.log_login_failure(user.name, client_ip, application_name, e.to_string());
+
+                // Communicate the error back to the client. No need to handle failures to send
+                // the error back; we've already cleaned up all necessary state.
+                let _ = tx.send(Err(e));
             }
         }
     }
@@ -317,11 +371,24 @@ impl Coordinator {
             };
             self.sequence_create_role_for_startup(plan).await?;
         }
-        let role_id = self
+        let role = self
             .catalog()
             .try_get_role_by_name(&user.name)
-            .expect("created above")
-            .id;
+            .expect("created above");
+        let role_id = role.id;
+        if role.attributes.is_expired(self.now()) {
+            return Err(AdapterError::RoleExpired(role.name.clone()));
+        }
+        if let Some(limit) = role.attributes.connection_limit {
+            let current = self
+                .active_conns
+                .values()
+                .filter(|conn| conn.authenticated_role == role_id)
+                .count();
+            if current >= usize::cast_from(limit) {
+                return Err(AdapterError::TooManyRoleConnections(role.name.clone()));
+            }
+        }
         self.catalog_mut()
             .create_temporary_schema(conn_id, role_id)?;
         Ok(role_id)
@@ -372,6 +439,18 @@ impl Coordinator {
             return ctx.retire(Err(err));
         }
 
+        // Sessions of a role that expired after they connected may not run any more statements.
+        let authenticated_role = session.role_metadata().authenticated;
+        if let Some(role) = self.catalog().try_get_role(&authenticated_role) {
+            if role.attributes.is_expired(self.now()) {
+                let err = AdapterError::RoleExpired(role.name.clone());
+                let extra = outer_context.unwrap_or_else(Default::default);
+                let ctx =
+                    ExecuteContext::from_parts(tx, self.internal_cmd_tx.clone(), session, extra);
+                return ctx.retire(Err(err));
+            }
+        }
+
         // The reference to `portal` can't outlive `session`, which we
         // use to construct the context, so scope the reference to this block where we
         // get everything we need from the portal for later.
@@ -433,7 +512,7 @@ impl Coordinator {
     #[tracing::instrument(level = "debug", skip(self, ctx))]
     pub(crate) async fn handle_execute_inner(
         &mut self,
-        stmt: Statement<Raw>,
+        mut stmt: Statement<Raw>,
         params: Params,
         mut ctx: ExecuteContext,
     ) {
@@ -497,6 +576,7 @@ impl Coordinator {
                     | Statement::ExplainPlan(_)
                     | Statement::ExplainTimestamp(_)
                     | Statement::ExplainSinkSchema(_)
+                    | Statement::ExplainCreateSource(_)
                     | Statement::Fetch(_)
                     | Statement::Prepare(_)
                     | Statement::Rollback(_)
@@ -542,10 +622,12 @@ impl Coordinator {
 
                     // Statements below must by run singly (in Started).
                     Statement::AlterCluster(_)
+                    | Statement::AlterClusterReplica(_)
                     | Statement::AlterConnection(_)
                     | Statement::AlterDefaultPrivileges(_)
                     | Statement::AlterIndex(_)
                     | Statement::AlterSetCluster(_)
+                    | Statement::AlterColumnMask(_)
                     | Statement::AlterOwner(_)
                     | Statement::AlterRole(_)
                     | Statement::AlterSecret(_)
@@ -556,6 +638,7 @@ impl Coordinator {
                     | Statement::AlterSystemSet(_)
                     | Statement::CreateCluster(_)
                     | Statement::CreateClusterReplica(_)
+                    | Statement::CreateClusterReplicaSize(_)
                     | Statement::CreateConnection(_)
                     | Statement::CreateDatabase(_)
                     | Statement::CreateIndex(_)
@@ -563,6 +646,7 @@ impl Coordinator {
                     | Statement::CreateRole(_)
                     | Statement::CreateSchema(_)
                     | Statement::CreateSecret(_)
+                    | Statement::CreateServiceAccount(_)
                     | Statement::CreateSink(_)
                     | Statement::CreateSource(_)
                     | Statement::CreateSubsource(_)
@@ -571,8 +655,11 @@ impl Coordinator {
                     | Statement::CreateView(_)
                     | Statement::CreateWebhookSource(_)
                     | Statement::Delete(_)
+                    | Statement::DropClusterReplicaSize(_)
                     | Statement::DropObjects(_)
                     | Statement::DropOwned(_)
+                    | Statement::DropServiceAccount(_)
+                    | Statement::UndropObject(_)
                     | Statement::GrantPrivileges(_)
                     | Statement::GrantRole(_)
                     | Statement::Insert(_)
@@ -611,6 +698,19 @@ impl Coordinator {
             }
         }
 
+        // `CREATE SOURCE ... IN BACKGROUND` continues to execute in a
+        // background job, on behalf of the session.
+        if let Statement::CreateSource(CreateSourceStatement {
+            in_background: true,
+            ..
+        }) = &stmt
+        {
+            ctx = match self.begin_background_purification(ctx, &mut stmt).await {
+                Some(job_ctx) => job_ctx,
+                None => return,
+            };
+        }
+
         let catalog = self.catalog();
         let catalog = catalog.for_session(ctx.session());
         let original_stmt = stmt.clone();
@@ -625,13 +725,53 @@ impl Coordinator {
         // If we add special handling for more types of `Statement`s, we'll need to ensure similar verification
         // occurs.
         match stmt {
-            // `CREATE SOURCE` statements must be purified off the main
-            // coordinator thread of control.
-            stmt @ (Statement::CreateSource(_)
-            | Statement::AlterSource(_)
-            | Statement::CreateSink(_)) => {
+            // Statements that require purification, like `CREATE SOURCE`, must
+            // be purified off the main coordinator thread of control.
+            stmt if mz_sql::pure::requires_purification(&stmt) => {
+                // Retries of a recently purified statement reuse its
+                // purification rather than repeating its external calls.
+                let cache_key = self.purification_cache_key(ctx.session(), &stmt);
+                if let Some(result) = cache_key
+                    .as_ref()
+                    .and_then(|key| self.cached_purification(key))
+                {
+                    let catalog = self.catalog().for_session(ctx.session());
+                    if let Err(e) = rbac::check_usage(
+                        &catalog,
+                        ctx.session().role_metadata(),
+                        ctx.session().vars(),
+                        &resolved_ids,
+                        &CREATE_ITEM_USAGE,
+                    ) {
+                        return ctx.retire(Err(e.into()));
+                    }
+
+                    // It is not an error for purification to complete after `internal_cmd_rx` is dropped.
+                    let result = self.internal_cmd_tx.send(Message::PurifiedStatementReady(
+                        PurifiedStatementReady {
+                            ctx,
+                            result: Ok(result),
+                            params,
+                            resolved_ids,
+                            original_stmt,
+                            cache_key: None,
+                            otel_ctx: OpenTelemetryContext::obtain(),
+                        },
+                    ));
+                    if let Err(e) = result {
+                        tracing::warn!("internal_cmd_rx dropped before we could send: {:?}", e);
+                    }
+                    return;
+                }
+
                 let internal_cmd_tx = self.internal_cmd_tx.clone();
                 let conn_id = ctx.session().conn_id().clone();
+                let purification_pool = self.purification_pool.clone();
+                let priority = if self.is_background_purification(ctx.session()) {
+                    PurificationPriority::Background
+                } else {
+                    PurificationPriority::Interactive
+                };
                 let catalog = self.owned_catalog();
                 let now = self.now();
                 let connection_context = self.connection_context().clone();
@@ -652,6 +792,11 @@ impl Coordinator {
                         return ctx.retire(Err(e.into()));
                     }
 
+                    // Limits are enforced on behalf of the authenticated role, which
+                    // cannot be changed with `SET ROLE`.
+                    let _permit = purification_pool
+                        .acquire(ctx.session().role_metadata().authenticated_role, priority)
+                        .await;
                     let result =
                         mz_sql::pure::purify_statement(catalog, now, stmt, connection_context)
                             .await
@@ -664,6 +809,7 @@ impl Coordinator {
                             params,
                             resolved_ids,
                             original_stmt,
+                            cache_key,
                             otel_ctx,
                         },
                     ));
@@ -807,6 +953,7 @@ impl Coordinator {
             .with_label_values(&[session_type])
             .dec();
         self.cancel_pending_peeks(conn.conn_id());
+        self.end_background_purifications(conn.conn_id());
         self.end_session_for_statement_logging(conn.uuid());
 
         // Queue the builtin table update, but do not wait for it to complete. We explicitly do
@@ -814,6 +961,59 @@ impl Coordinator {
         // closed at once, which occurs regularly in some workflows.
         let update = self.catalog().state().pack_session_update(&conn, -1);
         let _builtin_update_notify = self.builtin_table_update().defer(vec![update]);
+
+        let details = EventDetails::SessionV1(SessionV1 {
+            session_id: conn.uuid().to_string(),
+            connection_id: conn.conn_id().to_string(),
+            client_ip: conn.client_ip.map(|ip| ip.to_string()),
+            application_name: conn.application_name().to_string(),
+        });
+        self.log_session_event(EventType::Logout, conn.user().name.clone(), details);
+    }
+
+    /// Validates `token` against the token secret of the service account named `name`.
+    ///
+    /// The secret is read off the main coordinator thread, so the response is sent on `tx` from
+    /// a separate task.
+    fn handle_authenticate_service_account(
+        &mut self,
+        name: String,
+        token: String,
+        tx: oneshot::Sender<Result<AuthenticateServiceAccountResponse, AdapterError>>,
+    ) {
+        let Some(service_account) = self.catalog().try_get_service_account(&name) else {
+            let _ = tx.send(Err(AdapterError::ServiceAccountInvalidToken(name)));
+            return;
+        };
+        if service_account
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= self.now())
+        {
+            let _ = tx.send(Err(AdapterError::ServiceAccountExpired(name)));
+            return;
+        }
+        let role_name = self
+            .catalog()
+            .get_role(&service_account.role_id)
+            .name
+            .clone();
+        let scopes = service_account.scopes.iter().cloned().collect();
+        let token_secret_id = service_account.token_secret_id;
+        let secrets_reader = self.caching_secrets_reader.clone();
+        let task_name = format!("authenticate_service_account:{name}");
+        task::spawn(|| task_name, async move {
+            let result = match secrets_reader.read(token_secret_id).await {
+                Ok(secret) if bool::from(secret.ct_eq(token.as_bytes())) => {
+                    Ok(AuthenticateServiceAccountResponse { role_name, scopes })
+                }
+                Ok(_) => Err(AdapterError::ServiceAccountInvalidToken(name)),
+                Err(err) => {
+                    warn!("failed to read token secret of service account {name}: {err}");
+                    Err(AdapterError::ServiceAccountInvalidToken(name))
+                }
+            };
+            let _ = tx.send(result);
+        });
     }
 
     #[tracing::instrument(level = "debug", skip(self, tx))]
@@ -67,6 +67,15 @@ impl ComputeInstanceSnapshot {
             })
     }
 
+    /// Returns a snapshot of the compute instance `id`, which the controller
+    /// does not know about yet and which therefore contains no collections.
+    pub fn new_without_collections(id: ComputeInstanceId) -> Self {
+        ComputeInstanceSnapshot {
+            instance_id: id,
+            collections: BTreeSet::new(),
+        }
+    }
+
     /// Return the ID of this compute instance.
     pub fn instance_id(&self) -> ComputeInstanceId {
         self.instance_id
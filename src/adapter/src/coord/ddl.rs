@@ -14,6 +14,7 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Arc;
 use std::time::Duration;
 
+use differential_dataflow::lattice::Lattice;
 use fail::fail_point;
 use futures::Future;
 use maplit::{btreemap, btreeset};
@@ -46,6 +47,7 @@ use mz_storage_types::controller::StorageError;
 use mz_storage_types::sinks::SinkAsOf;
 use mz_storage_types::sources::GenericSourceConnection;
 use serde_json::json;
+use timely::progress::Antichain;
 use tracing::{event, warn, Level};
 
 use crate::catalog::{CatalogState, Op, TransactionResult};
@@ -222,6 +224,7 @@ impl Coordinator {
         let mut update_jemalloc_profiling_config = false;
         let mut update_default_arrangement_merge_options = false;
         let mut update_http_config = false;
+        let mut update_purification_config = false;
         let mut log_indexes_to_drop = Vec::new();
 
         for op in &ops {
@@ -319,6 +322,7 @@ impl Coordinator {
                     update_default_arrangement_merge_options |=
                         name == vars::DEFAULT_ARRANGEMENT_EXERT_PROPORTIONALITY.name();
                     update_http_config |= vars::is_http_config_var(name);
+                    update_purification_config |= vars::is_purification_config_var(name);
                 }
                 catalog::Op::ResetAllSystemConfiguration => {
                     // Assume they all need to be updated.
@@ -333,6 +337,7 @@ impl Coordinator {
                     update_jemalloc_profiling_config = true;
                     update_default_arrangement_merge_options = true;
                     update_http_config = true;
+                    update_purification_config = true;
                 }
                 _ => (),
             }
@@ -608,6 +613,9 @@ impl Coordinator {
             if update_http_config {
                 self.update_http_config();
             }
+            if update_purification_config {
+                self.update_purification_config();
+            }
         }
         .await;
 
@@ -910,6 +918,14 @@ impl Coordinator {
             .set_limit(webhook_request_limit);
     }
 
+    fn update_purification_config(&mut self) {
+        let system_config = self.catalog().system_config();
+        self.purification_pool.set_limits(
+            system_config.max_concurrent_purifications(),
+            system_config.max_concurrent_purifications_per_role(),
+        );
+    }
+
     pub(crate) async fn create_storage_export(
         &mut self,
         id: GlobalId,
@@ -933,9 +949,20 @@ impl Coordinator {
             storage_ids: btreeset! {sink.from},
             compute_ids: btreemap! {},
         };
-        let min_as_of = self.least_valid_read(&id_bundle);
+        let mut as_of = self.least_valid_read(&id_bundle);
+        if !sink.with_snapshot {
+            // A sink without a snapshot must only emit the changes that occur
+            // after its creation, so we advance the as_of to the latest
+            // complete time of the sinked collection. Reading strictly beyond
+            // this time skips both the snapshot and any historical changes
+            // still retained by the collection.
+            let upper = self.least_valid_write(&id_bundle);
+            as_of.join_assign(&Antichain::from_elem(
+                Coordinator::largest_not_in_advance_of_upper(&upper),
+            ));
+        }
         let as_of = SinkAsOf {
-            frontier: min_as_of,
+            frontier: as_of,
             strict: !sink.with_snapshot,
         };
 
@@ -1072,6 +1099,16 @@ impl Coordinator {
                         | CatalogItem::Func(_) => {}
                     }
                 }
+                // Only views can be restored, which are not limited other than
+                // by the number of objects per schema.
+                Op::UndropObject { name, .. } => {
+                    *new_objects_per_schema
+                        .entry((
+                            name.qualifiers.database_spec.clone(),
+                            name.qualifiers.schema_spec.clone(),
+                        ))
+                        .or_insert(0) += 1;
+                }
                 Op::DropObject(id) => match id {
                     ObjectId::Cluster(_) => {
                         new_clusters -= 1;
@@ -1167,23 +1204,32 @@ impl Coordinator {
                 Op::AlterRole { .. }
                 | Op::AlterSink { .. }
                 | Op::AlterSetCluster { .. }
+                | Op::AlterColumnMask { .. }
                 | Op::AlterSource { .. }
                 | Op::UpdatePrivilege { .. }
                 | Op::UpdateDefaultPrivilege { .. }
                 | Op::GrantRole { .. }
                 | Op::RenameCluster { .. }
                 | Op::RenameClusterReplica { .. }
+                | Op::UpdateClusterReplicaDraining { .. }
                 | Op::RenameItem { .. }
+                | Op::RenameDatabase { .. }
                 | Op::RenameSchema { .. }
                 | Op::UpdateOwner { .. }
                 | Op::RevokeRole { .. }
                 | Op::UpdateClusterConfig { .. }
+                | Op::LogScheduledClusterChange { .. }
                 | Op::UpdateClusterReplicaStatus { .. }
                 | Op::UpdateStorageUsage { .. }
+                | Op::LogSessionEvent { .. }
                 | Op::UpdateSystemConfiguration { .. }
                 | Op::ResetSystemConfiguration { .. }
                 | Op::ResetAllSystemConfiguration { .. }
                 | Op::UpdateRotatedKeys { .. }
+                | Op::CreateServiceAccount { .. }
+                | Op::DropServiceAccount { .. }
+                | Op::CreateClusterReplicaSize { .. }
+                | Op::DropClusterReplicaSize { .. }
                 | Op::Comment { .. } => {}
             }
         }
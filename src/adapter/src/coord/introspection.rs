@@ -69,9 +69,11 @@ pub fn auto_run_on_introspection<'a, 's, 'p>(
         | Plan::CreateRole(_)
         | Plan::CreateCluster(_)
         | Plan::CreateClusterReplica(_)
+        | Plan::CreateClusterReplicaSize(_)
         | Plan::CreateSource(_)
         | Plan::CreateSources(_)
         | Plan::CreateSecret(_)
+        | Plan::CreateServiceAccount(_)
         | Plan::CreateSink(_)
         | Plan::CreateTable(_)
         | Plan::CreateView(_)
@@ -81,8 +83,11 @@ pub fn auto_run_on_introspection<'a, 's, 'p>(
         | Plan::Comment(_)
         | Plan::DiscardTemp
         | Plan::DiscardAll
+        | Plan::DropClusterReplicaSize(_)
         | Plan::DropObjects(_)
         | Plan::DropOwned(_)
+        | Plan::DropServiceAccount(_)
+        | Plan::UndropObject(_)
         | Plan::EmptyQuery
         | Plan::ShowAllVariables
         | Plan::ShowCreate(_)
@@ -98,10 +103,12 @@ pub fn auto_run_on_introspection<'a, 's, 'p>(
         | Plan::CopyFrom(_)
         | Plan::ExplainPlan(_)
         | Plan::ExplainSinkSchema(_)
+        | Plan::ExplainCreateSource(_)
         | Plan::Insert(_)
         | Plan::AlterNoop(_)
         | Plan::AlterClusterRename(_)
         | Plan::AlterClusterSwap(_)
+        | Plan::AlterClusterReplica(_)
         | Plan::AlterClusterReplicaRename(_)
         | Plan::AlterCluster(_)
         | Plan::AlterIndexSetOptions(_)
@@ -111,8 +118,10 @@ pub fn auto_run_on_introspection<'a, 's, 'p>(
         | Plan::AlterSource(_)
         | Plan::PurifiedAlterSource { .. }
         | Plan::AlterSetCluster(_)
+        | Plan::AlterColumnMask(_)
         | Plan::AlterItemRename(_)
         | Plan::AlterItemSwap(_)
+        | Plan::AlterDatabaseRename(_)
         | Plan::AlterSchemaRename(_)
         | Plan::AlterSchemaSwap(_)
         | Plan::AlterSecret(_)
@@ -16,32 +16,73 @@ use std::time::{Duration, Instant};
 use futures::future::LocalBoxFuture;
 use futures::FutureExt;
 use mz_adapter_types::connection::ConnectionId;
+use mz_catalog::durable::{VacuumOptions, VacuumReport, OBSOLETE_SETTINGS};
 use mz_controller::clusters::ClusterEvent;
 use mz_controller::ControllerResponse;
 use mz_ore::now::EpochMillis;
 use mz_ore::task;
 use mz_persist_client::usage::ShardsUsageReferenced;
-use mz_sql::ast::Statement;
-use mz_sql::names::ResolvedIds;
-use mz_sql::plan::{CreateSourcePlans, Plan};
+use mz_persist_client::ShardId;
+use mz_repr::GlobalId;
+use mz_sql::ast::display::AstDisplay;
+use mz_sql::ast::{CreateSubsourceStatement, Statement};
+use mz_sql::names::{Aug, ResolvedDatabaseSpecifier, ResolvedIds, SchemaSpecifier};
+use mz_sql::plan::{CreateSourcePlans, Params, Plan};
 use mz_storage_types::controller::CollectionMetadata;
 use opentelemetry::trace::TraceContextExt;
 use rand::{rngs, Rng, SeedableRng};
-use tracing::{event, warn, Instrument, Level};
+use tracing::{debug, event, warn, Instrument, Level};
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 
-use crate::command::Command;
+use crate::command::{Command, ExecuteResponse};
 use crate::coord::appends::Deferred;
 use crate::coord::{
-    Coordinator, CreateConnectionValidationReady, Message, PeekStage, PeekStageFinish,
-    PendingReadTxn, PlanValidity, PurifiedStatementReady, RealTimeRecencyContext,
+    Coordinator, CreateConnectionValidationReady, ExecuteContext, Message, PeekStage,
+    PeekStageFinish, PendingReadTxn, PlanValidity, PurifiedStatementReady, RealTimeRecencyContext,
 };
+use crate::error::AdapterError;
 use crate::session::Session;
 use crate::util::{ComputeSinkId, ResultExt};
 use crate::{catalog, AdapterNotice, TimestampContext};
 
 use super::AlterConnectionValidationReady;
 
+/// How often the catalog is vacuumed according to the `audit_log_retention_period`,
+/// `audit_log_max_events`, `storage_usage_rollup_age` and `item_trash_retention` system
+/// variables.
+const CATALOG_VACUUM_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// How often the sessions of roles that are past their `VALID UNTIL` time are disabled.
+const ROLE_EXPIRATION_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How often to apply the schedules of managed clusters.
+const CLUSTER_SCHEDULE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How often to check whether in-progress cluster resizes can cut over.
+const CLUSTER_RESIZE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Returns the shards of a storage collection.
+fn collection_shards(metadata: &CollectionMetadata) -> impl Iterator<Item = ShardId> {
+    let CollectionMetadata {
+        data_shard,
+        remap_shard,
+        status_shard,
+        // No wildcards, to improve the odds that the addition of a
+        // new shard type results in a compiler error here.
+        //
+        // ATTENTION: If you add a new type of shard that is
+        // associated with a collection, almost surely you should
+        // return it below, so that its usage is recorded in the
+        // `mz_storage_usage_by_shard` table.
+        persist_location: _,
+        relation_desc: _,
+        txns_shard: _,
+    } = metadata;
+    [*remap_shard, *status_shard, Some(*data_shard)]
+        .into_iter()
+        .flatten()
+}
+
 impl Coordinator {
     /// BOXED FUTURE: As of Nov 2023 the returned Future from this function was 74KB. This would
     /// get stored on the stack which is bad for runtime performance, and blow up our stack usage.
@@ -113,6 +154,18 @@ impl Coordinator {
                 Message::StorageUsageUpdate(sizes) => {
                     self.storage_usage_update(sizes).await;
                 }
+                Message::CatalogVacuum => {
+                    self.scheduled_catalog_vacuum().await;
+                }
+                Message::RoleExpiration => {
+                    self.scheduled_role_expiration();
+                }
+                Message::ClusterSchedule => {
+                    self.scheduled_cluster_schedule().await;
+                }
+                Message::ClusterResize => {
+                    self.scheduled_cluster_resize().await;
+                }
                 Message::RealTimeRecencyTimestamp {
                     conn_id,
                     real_time_recency_ts,
@@ -154,9 +207,15 @@ impl Coordinator {
                 Message::DrainStatementLog => {
                     self.drain_statement_log().await;
                 }
+                Message::DrainSessionAuditLog => {
+                    self.drain_session_audit_log().await;
+                }
                 Message::PrivateLinkVpcEndpointEvents(events) => {
                     self.write_privatelink_status_updates(events).await;
                 }
+                Message::BackgroundPurificationFinished { id, result } => {
+                    self.finish_background_purification(id, result).await;
+                }
             }
         }
         .instrument(span)
@@ -176,25 +235,7 @@ impl Coordinator {
             // A collection is dropped if its read capability has been advanced
             // to the empty antichain.
             .filter(|(_id, collection)| !collection.read_capabilities.is_empty())
-            .flat_map(|(_id, collection)| {
-                let CollectionMetadata {
-                    data_shard,
-                    remap_shard,
-                    status_shard,
-                    // No wildcards, to improve the odds that the addition of a
-                    // new shard type results in a compiler error here.
-                    //
-                    // ATTENTION: If you add a new type of shard that is
-                    // associated with a collection, almost surely you should
-                    // return it below, so that its usage is recorded in the
-                    // `mz_storage_usage_by_shard` table.
-                    persist_location: _,
-                    relation_desc: _,
-                    txns_shard: _,
-                } = &collection.collection_metadata;
-                [*remap_shard, *status_shard, Some(*data_shard)].into_iter()
-            })
-            .filter_map(|shard| shard)
+            .flat_map(|(_id, collection)| collection_shards(&collection.collection_metadata))
             .collect();
 
         let collection_metric = self
@@ -225,6 +266,30 @@ impl Coordinator {
         // timestamp in the past.
         let collection_timestamp: EpochMillis = self.get_local_write_ts().await.timestamp.into();
 
+        // Attribute the usage of each shard to the schema of the collection
+        // that it belongs to.
+        let shard_collections: BTreeMap<_, _> = self
+            .controller
+            .storage
+            .collections()
+            .flat_map(|(id, collection)| {
+                collection_shards(&collection.collection_metadata).map(move |shard| (shard, *id))
+            })
+            .collect();
+        let mut by_schema = BTreeMap::new();
+        for (shard_id, shard_usage) in &shards_usage.by_shard {
+            let Some(entry) = shard_collections
+                .get(shard_id)
+                .and_then(|id| self.catalog().try_get_entry(id))
+            else {
+                continue;
+            };
+            let qualifiers = &entry.name().qualifiers;
+            *by_schema
+                .entry((qualifiers.database_spec, qualifiers.schema_spec))
+                .or_default() += shard_usage.size_bytes();
+        }
+
         let mut ops = vec![];
         for (shard_id, shard_usage) in shards_usage.by_shard {
             ops.push(catalog::Op::UpdateStorageUsage {
@@ -234,12 +299,43 @@ impl Coordinator {
             });
         }
 
-        if let Err(err) = self.catalog_transact(None::<&Session>, ops).await {
-            tracing::warn!("Failed to update storage metrics: {:?}", err);
+        match self.catalog_transact(None::<&Session>, ops).await {
+            Ok(()) => self.update_storage_usage_by_schema(by_schema, collection_timestamp),
+            Err(err) => tracing::warn!("Failed to update storage metrics: {:?}", err),
         }
         self.schedule_storage_usage_collection().await;
     }
 
+    /// Replaces the contents of `mz_storage_usage_by_schema` with `by_schema`,
+    /// the storage usage of each schema as of `collection_timestamp`.
+    fn update_storage_usage_by_schema(
+        &mut self,
+        by_schema: BTreeMap<(ResolvedDatabaseSpecifier, SchemaSpecifier), u64>,
+        collection_timestamp: EpochMillis,
+    ) {
+        let prev = self.storage_usage_by_schema.take();
+        let state = self.catalog().state();
+        let pack = |(collection_timestamp, by_schema): &(EpochMillis, BTreeMap<_, u64>), diff| {
+            by_schema
+                .iter()
+                .map(|((database_spec, schema_spec), size_bytes)| {
+                    state.pack_storage_usage_by_schema_update(
+                        database_spec,
+                        schema_spec,
+                        *size_bytes,
+                        *collection_timestamp,
+                        diff,
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        let next = (collection_timestamp, by_schema);
+        let mut updates = prev.map(|prev| pack(&prev, -1)).unwrap_or_default();
+        updates.extend(pack(&next, 1));
+        self.storage_usage_by_schema = Some(next);
+        self.builtin_table_update().background(updates);
+    }
+
     pub async fn schedule_storage_usage_collection(&self) {
         // Instead of using an `tokio::timer::Interval`, we calculate the time until the next
         // usage collection and wait for that amount of time. This is so we can keep the intervals
@@ -290,6 +386,110 @@ impl Coordinator {
         });
     }
 
+    /// Vacuums the catalog according to the current system configuration.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub(crate) async fn catalog_vacuum(&mut self) -> Result<VacuumReport, AdapterError> {
+        let config = self.catalog().system_config();
+        let retention_period = config.audit_log_retention_period();
+        let max_events = config.audit_log_max_events();
+        let rollup_age = config.storage_usage_rollup_age();
+        let now: EpochMillis = self.peek_local_write_ts().await.into();
+        let before = |age: Duration| {
+            now.saturating_sub(EpochMillis::try_from(age.as_millis()).unwrap_or(EpochMillis::MAX))
+        };
+        let options = VacuumOptions {
+            audit_log_before_ts: retention_period.map(before).unwrap_or(0),
+            audit_log_max_events: max_events,
+            storage_usage_rollup_before_ts: rollup_age.map(before),
+            item_trash_purge_ts: Some(now),
+            obsolete_settings: OBSOLETE_SETTINGS
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+        };
+        let (updates, report) = self.catalog_mut().vacuum(options).await?;
+        self.builtin_table_update().background(updates);
+        Ok(report)
+    }
+
+    async fn scheduled_catalog_vacuum(&mut self) {
+        if let Err(err) = self.catalog_vacuum().await {
+            warn!("Failed to vacuum catalog: {:?}", err);
+        }
+        self.schedule_catalog_vacuum();
+    }
+
+    pub fn schedule_catalog_vacuum(&self) {
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| "catalog_vacuum", async move {
+            tokio::time::sleep(CATALOG_VACUUM_INTERVAL).await;
+            if internal_cmd_tx.send(Message::CatalogVacuum).is_err() {
+                // If sending fails, the main thread has shutdown.
+            }
+        });
+    }
+
+    /// Disables the sessions of roles that are past their `VALID UNTIL` time by canceling their
+    /// in-flight work. New logins and statements of expired roles are rejected on their own.
+    fn scheduled_role_expiration(&mut self) {
+        let now = self.now();
+        let expired_conns: Vec<_> = self
+            .active_conns
+            .iter()
+            .filter(|(_, conn)| {
+                self.catalog()
+                    .try_get_role(&conn.authenticated_role)
+                    .map_or(false, |role| role.attributes.is_expired(now))
+            })
+            .map(|(conn_id, _)| conn_id.clone())
+            .collect();
+        for conn_id in expired_conns {
+            debug!("canceling work of connection {conn_id} because its role has expired");
+            self.handle_privileged_cancel(conn_id);
+        }
+        self.schedule_role_expiration();
+    }
+
+    pub fn schedule_role_expiration(&self) {
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| "role_expiration", async move {
+            tokio::time::sleep(ROLE_EXPIRATION_INTERVAL).await;
+            if internal_cmd_tx.send(Message::RoleExpiration).is_err() {
+                // If sending fails, the main thread has shutdown.
+            }
+        });
+    }
+
+    async fn scheduled_cluster_schedule(&mut self) {
+        self.apply_cluster_schedules().await;
+        self.schedule_cluster_schedule();
+    }
+
+    pub fn schedule_cluster_schedule(&self) {
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| "cluster_schedule", async move {
+            tokio::time::sleep(CLUSTER_SCHEDULE_INTERVAL).await;
+            if internal_cmd_tx.send(Message::ClusterSchedule).is_err() {
+                // If sending fails, the main thread has shutdown.
+            }
+        });
+    }
+
+    async fn scheduled_cluster_resize(&mut self) {
+        self.advance_cluster_resizes().await;
+        self.schedule_cluster_resize();
+    }
+
+    pub fn schedule_cluster_resize(&self) {
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| "cluster_resize", async move {
+            tokio::time::sleep(CLUSTER_RESIZE_INTERVAL).await;
+            if internal_cmd_tx.send(Message::ClusterResize).is_err() {
+                // If sending fails, the main thread has shutdown.
+            }
+        });
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     async fn message_command(&mut self, cmd: Command) {
         event!(Level::TRACE, cmd = format!("{:?}", cmd));
@@ -354,6 +554,53 @@ impl Coordinator {
         }
     }
 
+    /// Plans the statements that purifying an `EXPLAIN CREATE SOURCE`
+    /// generated, which validates them like `CREATE SOURCE` would, and returns
+    /// them to the client.
+    ///
+    /// Unlike `CREATE SOURCE`, this allocates no IDs for the subsources, which
+    /// keep the transient IDs that purification assigned them.
+    async fn explain_purified_create_source(
+        &mut self,
+        ctx: ExecuteContext,
+        subsource_stmts: Vec<(GlobalId, CreateSubsourceStatement<Aug>)>,
+        stmt: Statement<Aug>,
+        params: Params,
+    ) {
+        let mut statements = vec![];
+        for (_, subsource_stmt) in subsource_stmts {
+            statements.push(subsource_stmt.to_ast_string());
+            let resolved_ids = mz_sql::names::visit_dependencies(&subsource_stmt);
+            if let Err(e) = self.plan_statement(
+                ctx.session(),
+                Statement::CreateSubsource(subsource_stmt),
+                &params,
+                &resolved_ids,
+            ) {
+                return ctx.retire(Err(e));
+            }
+        }
+
+        let resolved_ids = mz_sql::names::visit_dependencies(&stmt);
+        match self.plan_statement(ctx.session(), stmt, &params, &resolved_ids) {
+            Ok(Plan::ExplainCreateSource(mut plan)) => {
+                // Planning only knows about the `CREATE SOURCE` statement,
+                // which the subsources precede.
+                plan.statements.splice(0..0, statements);
+                self.sequence_plan(
+                    ctx,
+                    Plan::ExplainCreateSource(plan),
+                    ResolvedIds(BTreeSet::new()),
+                )
+                .await;
+            }
+            Ok(_) => unreachable!(
+                "planning EXPLAIN CREATE SOURCE must result in a Plan::ExplainCreateSource"
+            ),
+            Err(e) => ctx.retire(Err(e)),
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self, ctx))]
     async fn message_purified_statement_ready(
         &mut self,
@@ -363,11 +610,18 @@ impl Coordinator {
             params,
             resolved_ids,
             original_stmt,
+            cache_key,
             otel_ctx,
         }: PurifiedStatementReady,
     ) {
         otel_ctx.attach_as_parent();
 
+        // A background job that was canceled while purifying must not go on to
+        // create its source.
+        if self.background_purification_canceled(ctx.session()) {
+            return ctx.retire(Ok(ExecuteResponse::Canceled));
+        }
+
         // Ensure that all dependencies still exist after purification, as a
         // `DROP CONNECTION` may have sneaked in. If any have gone missing, we
         // repurify the original statement. This will either produce a nice
@@ -390,6 +644,16 @@ impl Coordinator {
             Err(e) => return ctx.retire(Err(e)),
         };
 
+        if let Some(cache_key) = cache_key {
+            self.cache_purification(cache_key, (subsource_stmts.clone(), stmt.clone()));
+        }
+
+        if let Statement::ExplainCreateSource(_) = &stmt {
+            return self
+                .explain_purified_create_source(ctx, subsource_stmts, stmt, params)
+                .await;
+        }
+
         let mut create_source_plans: Vec<CreateSourcePlans> = vec![];
         let mut id_allocation = BTreeMap::new();
 
@@ -473,6 +737,22 @@ impl Coordinator {
 
                 self.sequence_plan(ctx, plan, resolved_ids).await
             }
+            Ok(plan @ Plan::AlterSink(_)) => {
+                assert!(
+                    create_source_plans.is_empty(),
+                    "ALTER SINK does not generate source plans"
+                );
+
+                self.sequence_plan(ctx, plan, resolved_ids).await
+            }
+            Ok(plan @ Plan::AlterConnection(_)) => {
+                assert!(
+                    create_source_plans.is_empty(),
+                    "ALTER CONNECTION does not generate source plans"
+                );
+
+                self.sequence_plan(ctx, plan, resolved_ids).await
+            }
             Ok(p) => {
                 unreachable!("{:?} is not purified", p)
             }
@@ -0,0 +1,221 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A short-lived cache of purified `CREATE SOURCE ... IF NOT EXISTS`
+//! statements.
+//!
+//! Clients commonly retry such statements after a network error, which would
+//! otherwise repeat all of the external calls that purification makes. The
+//! cache is keyed by the statement and the catalog revision it was purified
+//! against, so any change to the catalog invalidates all entries.
+//!
+//! Secrets are not part of the catalog, so `ALTER SECRET` does not change the
+//! catalog revision. It instead clears the cache explicitly, as purification
+//! may have used the secret to connect to an external system.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use mz_ore::now::EpochMillis;
+use mz_repr::role_id::RoleId;
+use mz_repr::GlobalId;
+use mz_sql::ast::display::AstDisplay;
+use mz_sql::ast::{CreateSourceStatement, CreateSubsourceStatement, Statement};
+use mz_sql::names::Aug;
+
+use crate::coord::Coordinator;
+use crate::session::Session;
+
+/// How long a purified statement remains cached.
+const PURIFICATION_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// The result of purifying a statement.
+pub type PurifiedStatement = (
+    Vec<(GlobalId, CreateSubsourceStatement<Aug>)>,
+    Statement<Aug>,
+);
+
+/// Identifies a purified statement in the purification cache.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PurificationCacheKey {
+    /// The catalog revision that the statement was purified against.
+    transient_revision: u64,
+    /// The normalized text of the statement.
+    stmt: String,
+    /// The session state that influences the purification of the statement.
+    database: String,
+    search_path: Vec<String>,
+    role_id: RoleId,
+}
+
+/// A cached purified statement.
+#[derive(Debug)]
+struct CachedPurification {
+    result: PurifiedStatement,
+    /// The time at which the statement was purified.
+    purified_at: EpochMillis,
+}
+
+impl CachedPurification {
+    fn expired(&self, now: EpochMillis) -> bool {
+        Duration::from_millis(now.saturating_sub(self.purified_at)) >= PURIFICATION_CACHE_TTL
+    }
+}
+
+/// Recently purified statements that may be reused by retries.
+#[derive(Debug, Default)]
+pub(crate) struct PurificationCache {
+    entries: BTreeMap<PurificationCacheKey, CachedPurification>,
+}
+
+impl PurificationCache {
+    /// Returns the purification cached under `key` as of `now`, if it has not
+    /// expired.
+    fn get(&mut self, key: &PurificationCacheKey, now: EpochMillis) -> Option<PurifiedStatement> {
+        self.evict_expired(now);
+        self.entries.get(key).map(|cached| cached.result.clone())
+    }
+
+    /// Caches the purification `result` under `key`, as of `now`.
+    fn insert(&mut self, key: PurificationCacheKey, result: PurifiedStatement, now: EpochMillis) {
+        self.evict_expired(now);
+        // Entries for older catalog revisions can never be hit again.
+        self.entries
+            .retain(|cached_key, _| cached_key.transient_revision >= key.transient_revision);
+        self.entries.insert(
+            key,
+            CachedPurification {
+                result,
+                purified_at: now,
+            },
+        );
+    }
+
+    /// Forgets all cached purifications.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    fn evict_expired(&mut self, now: EpochMillis) {
+        self.entries.retain(|_, cached| !cached.expired(now));
+    }
+}
+
+impl Coordinator {
+    /// Returns the key under which the purification of `stmt` is cached, or
+    /// `None` if its purification must not be cached.
+    ///
+    /// Only `CREATE SOURCE ... IF NOT EXISTS` statements are cached, as
+    /// retrying them is idempotent.
+    pub(crate) fn purification_cache_key(
+        &self,
+        session: &Session,
+        stmt: &Statement<Aug>,
+    ) -> Option<PurificationCacheKey> {
+        let Statement::CreateSource(CreateSourceStatement {
+            if_not_exists: true,
+            ..
+        }) = stmt
+        else {
+            return None;
+        };
+
+        // Purification resolves the names of the source and its subsources
+        // against the session's database and search path.
+        Some(PurificationCacheKey {
+            transient_revision: self.catalog().transient_revision(),
+            stmt: stmt.to_ast_string_stable(),
+            database: session.vars().database().to_string(),
+            search_path: session
+                .vars()
+                .search_path()
+                .iter()
+                .map(|schema| schema.as_str().to_string())
+                .collect(),
+            role_id: *session.current_role_id(),
+        })
+    }
+
+    /// Returns the cached purification for `key`, if it has not expired.
+    pub(crate) fn cached_purification(
+        &mut self,
+        key: &PurificationCacheKey,
+    ) -> Option<PurifiedStatement> {
+        let now = self.now();
+        self.purification_cache.get(key, now)
+    }
+
+    /// Caches the purification `result` under `key`.
+    pub(crate) fn cache_purification(
+        &mut self,
+        key: PurificationCacheKey,
+        result: PurifiedStatement,
+    ) {
+        let now = self.now();
+        self.purification_cache.insert(key, result, now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_repr::role_id::RoleId;
+    use mz_sql::ast::{DiscardStatement, DiscardTarget, Statement};
+
+    use super::{PurificationCache, PurificationCacheKey, PurifiedStatement};
+
+    fn key(stmt: &str, transient_revision: u64) -> PurificationCacheKey {
+        PurificationCacheKey {
+            transient_revision,
+            stmt: stmt.into(),
+            database: "materialize".into(),
+            search_path: vec!["public".into()],
+            role_id: RoleId::User(1),
+        }
+    }
+
+    fn result() -> PurifiedStatement {
+        (
+            vec![],
+            Statement::Discard(DiscardStatement {
+                target: DiscardTarget::All,
+            }),
+        )
+    }
+
+    #[mz_ore::test]
+    fn test_purification_cache() {
+        let ttl = u64::try_from(super::PURIFICATION_CACHE_TTL.as_millis()).unwrap();
+        let mut cache = PurificationCache::default();
+        cache.insert(key("CREATE SOURCE a", 1), result(), 0);
+
+        // Retries of the same statement hit.
+        assert_eq!(cache.get(&key("CREATE SOURCE a", 1), 1), Some(result()));
+        // Other statements do not.
+        assert_eq!(cache.get(&key("CREATE SOURCE b", 1), 1), None);
+        // Nor does the same statement under another role.
+        let mut other_role = key("CREATE SOURCE a", 1);
+        other_role.role_id = RoleId::User(2);
+        assert_eq!(cache.get(&other_role, 1), None);
+
+        // Changing the catalog invalidates the entry.
+        assert_eq!(cache.get(&key("CREATE SOURCE a", 2), 1), None);
+        cache.insert(key("CREATE SOURCE b", 2), result(), 1);
+        assert!(!cache.entries.contains_key(&key("CREATE SOURCE a", 1)));
+
+        // Entries expire after the TTL.
+        assert_eq!(cache.get(&key("CREATE SOURCE b", 2), ttl), Some(result()));
+        assert_eq!(cache.get(&key("CREATE SOURCE b", 2), ttl + 1), None);
+        assert!(cache.entries.is_empty());
+
+        // Clearing the cache forgets all entries.
+        cache.insert(key("CREATE SOURCE a", 2), result(), 0);
+        cache.clear();
+        assert_eq!(cache.get(&key("CREATE SOURCE a", 2), 1), None);
+    }
+}
@@ -0,0 +1,273 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Limits how many statements are purified concurrently.
+//!
+//! Purification makes calls to external systems, which can take a long time.
+//! To keep many concurrent `CREATE SOURCE` statements from exhausting the
+//! resources of the coordinator, statements must acquire a permit from the
+//! [`PurificationPool`] before being purified. Permits are granted in order of
+//! [`PurificationPriority`], and then in order of arrival, subject to both a
+//! global and a per-role concurrency limit.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use mz_repr::role_id::RoleId;
+use tokio::sync::oneshot;
+
+use crate::metrics::Metrics;
+
+/// The priority with which a statement is purified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PurificationPriority {
+    /// Purification on behalf of a `CREATE SOURCE ... IN BACKGROUND` job.
+    Background,
+    /// Purification that a client is waiting on.
+    Interactive,
+}
+
+impl PurificationPriority {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PurificationPriority::Background => "background",
+            PurificationPriority::Interactive => "interactive",
+        }
+    }
+}
+
+/// Manages how many statements we purify at once.
+#[derive(Debug, Clone)]
+pub struct PurificationPool {
+    state: Arc<Mutex<PoolState>>,
+}
+
+#[derive(Debug)]
+struct PoolState {
+    /// The maximum number of concurrent purifications.
+    limit: usize,
+    /// The maximum number of concurrent purifications per role.
+    role_limit: usize,
+    /// The number of running purifications per role.
+    running: BTreeMap<RoleId, usize>,
+    /// The statements waiting to be purified, ordered by decreasing priority
+    /// and then by arrival.
+    queue: BTreeMap<(Reverse<PurificationPriority>, u64), Waiter>,
+    /// The sequence number to assign to the next waiter.
+    next_seq: u64,
+    metrics: Metrics,
+}
+
+#[derive(Debug)]
+struct Waiter {
+    role_id: RoleId,
+    enqueued_at: Instant,
+    tx: oneshot::Sender<PurificationPermit>,
+}
+
+impl PoolState {
+    fn total_running(&self) -> usize {
+        self.running.values().sum()
+    }
+}
+
+/// Allows its holder to purify a statement. The permit is returned to its
+/// [`PurificationPool`] when dropped.
+#[derive(Debug)]
+pub struct PurificationPermit {
+    state: Arc<Mutex<PoolState>>,
+    role_id: RoleId,
+    priority: PurificationPriority,
+}
+
+impl Drop for PurificationPermit {
+    fn drop(&mut self) {
+        {
+            let mut state = self.state.lock().expect("lock poisoned");
+            if let Some(running) = state.running.get_mut(&self.role_id) {
+                *running -= 1;
+                if *running == 0 {
+                    state.running.remove(&self.role_id);
+                }
+            }
+            state
+                .metrics
+                .active_purifications
+                .with_label_values(&[self.priority.as_str()])
+                .dec();
+        }
+        PurificationPool::dispatch(&self.state);
+    }
+}
+
+impl PurificationPool {
+    pub fn new(limit: usize, role_limit: usize, metrics: Metrics) -> Self {
+        let state = PoolState {
+            limit: limit.max(1),
+            role_limit: role_limit.max(1),
+            running: BTreeMap::new(),
+            queue: BTreeMap::new(),
+            next_seq: 0,
+            metrics,
+        };
+        PurificationPool {
+            state: Arc::new(Mutex::new(state)),
+        }
+    }
+
+    /// Updates the global and per-role limits of how many statements can be
+    /// purified at once. Purifications that are already running are not
+    /// affected.
+    pub fn set_limits(&self, limit: usize, role_limit: usize) {
+        {
+            let mut state = self.state.lock().expect("lock poisoned");
+            state.limit = limit.max(1);
+            state.role_limit = role_limit.max(1);
+        }
+        Self::dispatch(&self.state);
+    }
+
+    /// Waits for a permit to purify a statement on behalf of `role_id`.
+    pub async fn acquire(
+        &self,
+        role_id: RoleId,
+        priority: PurificationPriority,
+    ) -> PurificationPermit {
+        let rx = {
+            let mut state = self.state.lock().expect("lock poisoned");
+            let (tx, rx) = oneshot::channel();
+            let seq = state.next_seq;
+            state.next_seq += 1;
+            state.queue.insert(
+                (Reverse(priority), seq),
+                Waiter {
+                    role_id,
+                    enqueued_at: Instant::now(),
+                    tx,
+                },
+            );
+            state
+                .metrics
+                .purification_queue_depth
+                .with_label_values(&[priority.as_str()])
+                .inc();
+            rx
+        };
+        Self::dispatch(&self.state);
+        rx.await.expect("waiters are never dropped")
+    }
+
+    /// Grants permits to waiting statements, for as long as the limits allow.
+    fn dispatch(state: &Arc<Mutex<PoolState>>) {
+        // Permits of waiters that stopped waiting. These must only be dropped
+        // once the lock is released, as dropping them returns them to the pool.
+        let mut abandoned = vec![];
+        {
+            let mut guard = state.lock().expect("lock poisoned");
+            let pool = &mut *guard;
+            let mut granted = vec![];
+            for (key, waiter) in &pool.queue {
+                if pool.total_running() >= pool.limit {
+                    break;
+                }
+                let running = pool.running.entry(waiter.role_id).or_default();
+                if *running < pool.role_limit {
+                    *running += 1;
+                    granted.push(*key);
+                }
+            }
+            for key @ (Reverse(priority), _) in granted {
+                let waiter = pool.queue.remove(&key).expect("waiter exists");
+                pool.metrics
+                    .purification_queue_depth
+                    .with_label_values(&[priority.as_str()])
+                    .dec();
+                pool.metrics
+                    .purification_queue_wait_seconds
+                    .with_label_values(&[priority.as_str()])
+                    .observe(waiter.enqueued_at.elapsed().as_secs_f64());
+                pool.metrics
+                    .active_purifications
+                    .with_label_values(&[priority.as_str()])
+                    .inc();
+                let permit = PurificationPermit {
+                    state: Arc::clone(state),
+                    role_id: waiter.role_id,
+                    priority,
+                };
+                if let Err(permit) = waiter.tx.send(permit) {
+                    abandoned.push(permit);
+                }
+            }
+            pool.running.retain(|_, running| *running > 0);
+        }
+        drop(abandoned);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::FutureExt;
+    use mz_ore::metrics::MetricsRegistry;
+    use mz_repr::role_id::RoleId;
+
+    use super::{PurificationPool, PurificationPriority};
+    use crate::metrics::Metrics;
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn smoke_test_purification_pool() {
+        let metrics = Metrics::register_into(&MetricsRegistry::new());
+        let pool = PurificationPool::new(2, 1, metrics);
+        let (alice, bob, carol) = (RoleId::User(1), RoleId::User(2), RoleId::User(3));
+
+        let alice_permit = pool.acquire(alice, PurificationPriority::Interactive).await;
+
+        // Alice is at her limit.
+        let mut alice_waiter = Box::pin(pool.acquire(alice, PurificationPriority::Interactive));
+        assert!((&mut alice_waiter).now_or_never().is_none());
+
+        // Bob is not, and the pool is not full.
+        let bob_permit = pool.acquire(bob, PurificationPriority::Interactive).await;
+
+        // The pool is full.
+        let mut carol_background = Box::pin(pool.acquire(carol, PurificationPriority::Background));
+        let mut bob_waiter = Box::pin(pool.acquire(bob, PurificationPriority::Interactive));
+        assert!((&mut carol_background).now_or_never().is_none());
+        assert!((&mut bob_waiter).now_or_never().is_none());
+
+        // Releasing Alice's permit admits her waiting purification, which
+        // arrived first.
+        drop(alice_permit);
+        let alice_permit = (&mut alice_waiter).now_or_never().expect("acquired");
+        assert!((&mut carol_background).now_or_never().is_none());
+
+        // Bob's interactive purification is preferred over Carol's background
+        // purification.
+        drop(bob_permit);
+        let bob_permit = (&mut bob_waiter).now_or_never().expect("acquired");
+        assert!((&mut carol_background).now_or_never().is_none());
+
+        // Abandoned waiters do not hold on to their permit.
+        let mut bob_abandoned = Box::pin(pool.acquire(bob, PurificationPriority::Interactive));
+        assert!((&mut bob_abandoned).now_or_never().is_none());
+        drop(bob_abandoned);
+        drop(bob_permit);
+        let _carol_permit = (&mut carol_background).now_or_never().expect("acquired");
+
+        // Raising the limits admits more purifications.
+        let mut alice_waiter = Box::pin(pool.acquire(alice, PurificationPriority::Interactive));
+        assert!((&mut alice_waiter).now_or_never().is_none());
+        pool.set_limits(3, 2);
+        let _alice_permit_2 = (&mut alice_waiter).now_or_never().expect("acquired");
+        drop(alice_permit);
+    }
+}
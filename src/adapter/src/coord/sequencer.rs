@@ -107,6 +107,12 @@ impl Coordinator {
                 return ctx.retire(Err(e));
             }
 
+            if let Err(e) =
+                rbac::check_service_account_scopes(ctx.session().service_account_scopes(), &plan)
+            {
+                return ctx.retire(Err(e.into()));
+            }
+
             if let Err(e) = rbac::check_plan(
                 &session_catalog,
                 &self
@@ -116,6 +122,11 @@ impl Coordinator {
                         (conn_id.unhandled(), *conn_meta.authenticated_role_id())
                     })
                     .collect(),
+                &self
+                    .background_purifications
+                    .iter()
+                    .map(|(id, purification)| (*id, purification.conn_id.unhandled()))
+                    .collect(),
                 ctx.session().role_metadata(),
                 ctx.session().vars(),
                 &plan,
@@ -170,6 +181,18 @@ impl Coordinator {
                     }
                     ctx.retire(result);
                 }
+                Plan::CreateServiceAccount(plan) => {
+                    let result = self
+                        .sequence_create_service_account(ctx.session(), plan)
+                        .await;
+                    ctx.retire(result);
+                }
+                Plan::CreateClusterReplicaSize(plan) => {
+                    let result = self
+                        .sequence_create_cluster_replica_size(ctx.session(), plan)
+                        .await;
+                    ctx.retire(result);
+                }
                 Plan::CreateCluster(plan) => {
                     let result = self.sequence_create_cluster(ctx.session(), plan).await;
                     ctx.retire(result);
@@ -229,6 +252,22 @@ impl Coordinator {
                     let result = self.sequence_drop_owned(ctx.session_mut(), plan).await;
                     ctx.retire(result);
                 }
+                Plan::DropClusterReplicaSize(plan) => {
+                    let result = self
+                        .sequence_drop_cluster_replica_size(ctx.session_mut(), plan)
+                        .await;
+                    ctx.retire(result);
+                }
+                Plan::DropServiceAccount(plan) => {
+                    let result = self
+                        .sequence_drop_service_account(ctx.session_mut(), plan)
+                        .await;
+                    ctx.retire(result);
+                }
+                Plan::UndropObject(plan) => {
+                    let result = self.sequence_undrop_object(ctx.session(), plan).await;
+                    ctx.retire(result);
+                }
                 Plan::EmptyQuery => {
                     ctx.retire(Ok(ExecuteResponse::EmptyQuery));
                 }
@@ -333,6 +372,10 @@ impl Coordinator {
                     let result = self.sequence_explain_schema(plan);
                     ctx.retire(result);
                 }
+                Plan::ExplainCreateSource(plan) => {
+                    let result = self.sequence_explain_create_source(plan);
+                    ctx.retire(result);
+                }
                 Plan::ExplainTimestamp(plan) => {
                     self.sequence_explain_timestamp(ctx, plan, target_cluster)
                         .await;
@@ -362,6 +405,12 @@ impl Coordinator {
                         .await;
                     ctx.retire(result);
                 }
+                Plan::AlterClusterReplica(plan) => {
+                    let result = self
+                        .sequence_alter_cluster_replica(ctx.session(), plan)
+                        .await;
+                    ctx.retire(result);
+                }
                 Plan::AlterClusterReplicaRename(plan) => {
                     let result = self
                         .sequence_alter_cluster_replica_rename(ctx.session(), plan)
@@ -375,6 +424,10 @@ impl Coordinator {
                     let result = self.sequence_alter_set_cluster(ctx.session(), plan).await;
                     ctx.retire(result);
                 }
+                Plan::AlterColumnMask(plan) => {
+                    let result = self.sequence_alter_column_mask(ctx.session(), plan).await;
+                    ctx.retire(result);
+                }
                 Plan::AlterItemRename(plan) => {
                     let result = self
                         .sequence_alter_item_rename(ctx.session_mut(), plan)
@@ -386,6 +439,12 @@ impl Coordinator {
                     // planning.
                     ctx.retire(Err(AdapterError::Unsupported("ALTER ... SWAP ...")));
                 }
+                Plan::AlterDatabaseRename(plan) => {
+                    let result = self
+                        .sequence_alter_database_rename(ctx.session_mut(), plan)
+                        .await;
+                    ctx.retire(result);
+                }
                 Plan::AlterSchemaRename(plan) => {
                     let result = self
                         .sequence_alter_schema_rename(ctx.session_mut(), plan)
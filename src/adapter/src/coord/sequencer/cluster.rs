@@ -12,39 +12,117 @@
 use mz_adapter_types::compaction::DEFAULT_LOGICAL_COMPACTION_WINDOW_TS;
 use std::collections::BTreeSet;
 
-use mz_catalog::memory::objects::{ClusterConfig, ClusterVariant, ClusterVariantManaged};
+use mz_catalog::memory::objects::{
+    CatalogItem, Cluster, ClusterConfig, ClusterResize, ClusterVariant, ClusterVariantManaged,
+};
 use mz_compute_client::controller::ComputeReplicaConfig;
 use mz_controller::clusters::{
     CreateReplicaConfig, ManagedReplicaAvailabilityZones, ManagedReplicaLocation, ReplicaConfig,
     ReplicaLocation, ReplicaLogging,
 };
-use mz_controller_types::{ClusterId, ReplicaId, DEFAULT_REPLICA_LOGGING_INTERVAL};
+use mz_controller_types::{
+    ClusterId, ClusterPriority, ReplicaAvailabilityZoneConstraint, ReplicaId,
+    DEFAULT_REPLICA_LOGGING_INTERVAL,
+};
 use mz_ore::cast::CastFrom;
+use mz_ore::collections::CollectionExt;
+use mz_ore::str::{separated, StrExt};
 use mz_repr::role_id::RoleId;
-use mz_sql::catalog::{CatalogCluster, CatalogItem, CatalogItemType, ObjectType, SessionCatalog};
+use mz_repr::GlobalId;
+use mz_sql::catalog::{
+    CatalogCluster, CatalogItem as SqlCatalogItem, CatalogItemType, ObjectType, SessionCatalog,
+};
 use mz_sql::names::{ObjectId, QualifiedItemName};
 use mz_sql::plan::{
-    AlterClusterPlan, AlterClusterRenamePlan, AlterClusterReplicaRenamePlan, AlterClusterSwapPlan,
-    AlterOptionParameter, ComputeReplicaIntrospectionConfig, CreateClusterManagedPlan,
-    CreateClusterPlan, CreateClusterReplicaPlan, CreateClusterUnmanagedPlan, CreateClusterVariant,
-    PlanClusterOption,
+    AlterClusterPlan, AlterClusterRenamePlan, AlterClusterReplicaPlan,
+    AlterClusterReplicaRenamePlan, AlterClusterSwapPlan, AlterOptionParameter,
+    ClusterScheduleEntry, ComputeReplicaIntrospectionConfig, CreateClusterLikePlan,
+    CreateClusterManagedPlan, CreateClusterPlan, CreateClusterReplicaPlan,
+    CreateClusterUnmanagedPlan, CreateClusterVariant, PlanClusterOption,
 };
 use mz_sql::session::vars::{SystemVars, Var, MAX_REPLICAS_PER_CLUSTER};
+use mz_sql_parser::ast::display::AstDisplay;
+use mz_sql_parser::ast::{Ident, QualifiedReplica};
+use mz_storage_client::controller::{CollectionDescription, DataSource, DataSourceOther};
 
 use crate::catalog::Op;
+use crate::coord::dataflows::{dataflow_import_id_bundle, ComputeInstanceSnapshot};
+use crate::coord::timestamp_selection::TimestampProvider;
 use crate::coord::Coordinator;
+use crate::optimize::{self, Optimize};
 use crate::session::Session;
+use crate::util::ResultExt;
 use crate::{catalog, AdapterError, ExecuteResponse};
 
+/// An index or materialized view that `CREATE CLUSTER ... LIKE` cloned into
+/// the new cluster, whose dataflow is installed once the cluster exists.
+enum ClonedClusterItem {
+    Index {
+        id: GlobalId,
+        global_mir_plan: optimize::index::GlobalMirPlan,
+        global_lir_plan: optimize::index::GlobalLirPlan,
+    },
+    MaterializedView {
+        id: GlobalId,
+        global_mir_plan: optimize::materialized_view::GlobalMirPlan,
+        global_lir_plan: optimize::materialized_view::GlobalLirPlan,
+    },
+}
+
 impl Coordinator {
     #[tracing::instrument(level = "debug", skip(self))]
     pub(super) async fn sequence_create_cluster(
         &mut self,
         session: &Session,
-        CreateClusterPlan { name, variant }: CreateClusterPlan,
+        CreateClusterPlan {
+            name,
+            variant,
+            priority,
+            memory_quota,
+        }: CreateClusterPlan,
     ) -> Result<ExecuteResponse, AdapterError> {
         tracing::debug!("sequence_create_cluster");
 
+        // A cluster created `LIKE` another cluster is a managed cluster with the
+        // other cluster's configuration.
+        let (variant, cloned_items, priority, memory_quota) = match variant {
+            CreateClusterVariant::Like(CreateClusterLikePlan { cluster_id, items }) => {
+                let plan = self.cluster_like_managed_plan(cluster_id)?;
+                let config = &self.catalog().get_cluster(cluster_id).config;
+                (
+                    CreateClusterVariant::Managed(plan),
+                    items,
+                    config.priority,
+                    config.memory_quota,
+                )
+            }
+            variant => (variant, vec![], priority, memory_quota),
+        };
+
+        match &variant {
+            CreateClusterVariant::Managed(plan) => self
+                .ensure_managed_cluster_within_memory_quota(
+                    &name,
+                    memory_quota,
+                    &plan.size,
+                    plan.replication_factor,
+                    &plan.schedule,
+                )?,
+            CreateClusterVariant::Unmanaged(plan) => self.ensure_within_memory_quota(
+                &name,
+                memory_quota,
+                plan.replicas.iter().filter_map(|(_, config)| match config {
+                    mz_sql::plan::ReplicaConfig::Managed {
+                        size,
+                        internal: false,
+                        ..
+                    } => Some(size.as_str()),
+                    _ => None,
+                }),
+            )?,
+            CreateClusterVariant::Like(_) => unreachable!("LIKE resolved to a managed cluster"),
+        }
+
         let id = self.catalog_mut().allocate_user_cluster_id().await?;
         // The catalog items for the introspection sources are shared between all replicas
         // of a compute instance, so we create them unconditionally during instance creation.
@@ -68,12 +146,17 @@ impl Coordinator {
                     idle_arrangement_merge_effort: plan.compute.idle_arrangement_merge_effort,
                     replication_factor: plan.replication_factor,
                     disk: plan.disk,
+                    schedule: plan.schedule.clone(),
                 })
             }
             CreateClusterVariant::Unmanaged(_) => ClusterVariant::Unmanaged,
+            CreateClusterVariant::Like(_) => unreachable!("LIKE resolved to a managed cluster"),
         };
         let config = ClusterConfig {
             variant: cluster_variant,
+            priority,
+            memory_quota,
+            resize: None,
         };
         let ops = vec![catalog::Op::CreateCluster {
             id,
@@ -86,13 +169,246 @@ impl Coordinator {
 
         match variant {
             CreateClusterVariant::Managed(plan) => {
-                self.sequence_create_managed_cluster(session, plan, id, ops)
+                self.sequence_create_managed_cluster(session, plan, id, ops, cloned_items)
                     .await
             }
             CreateClusterVariant::Unmanaged(plan) => {
                 self.sequence_create_unmanaged_cluster(session, plan, id, ops)
                     .await
             }
+            CreateClusterVariant::Like(_) => unreachable!("LIKE resolved to a managed cluster"),
+        }
+    }
+
+    /// Returns the plan to create a managed cluster with the configuration of
+    /// the managed cluster `cluster_id`.
+    fn cluster_like_managed_plan(
+        &self,
+        cluster_id: ClusterId,
+    ) -> Result<CreateClusterManagedPlan, AdapterError> {
+        let cluster = self.catalog().get_cluster(cluster_id);
+        let ClusterVariant::Managed(config) = &cluster.config.variant else {
+            coord_bail!(
+                "cannot create a cluster LIKE unmanaged cluster {}",
+                cluster.name.quoted()
+            );
+        };
+        Ok(CreateClusterManagedPlan {
+            replication_factor: config.replication_factor,
+            size: config.size.clone(),
+            availability_zones: config.availability_zones.clone(),
+            compute: mz_sql::plan::ComputeReplicaConfig {
+                introspection: config.logging.interval.map(|interval| {
+                    ComputeReplicaIntrospectionConfig {
+                        debugging: config.logging.log_logging,
+                        interval,
+                    }
+                }),
+                idle_arrangement_merge_effort: config.idle_arrangement_merge_effort,
+            },
+            disk: config.disk,
+            schedule: config.schedule.clone(),
+        })
+    }
+
+    /// Pushes onto `ops` the operations that clone the indexes and materialized
+    /// views `items` into the new cluster `cluster_id`, and returns the
+    /// optimized plans of their dataflows.
+    ///
+    /// Each clone is named after the item it clones, made unique by a numeric
+    /// suffix, and keeps the item's owner. The dataflows are optimized as if
+    /// the new cluster contained no indexes, so read their inputs from storage.
+    async fn clone_cluster_items(
+        &mut self,
+        session: &Session,
+        cluster_id: ClusterId,
+        items: Vec<GlobalId>,
+        ops: &mut Vec<Op>,
+    ) -> Result<Vec<ClonedClusterItem>, AdapterError> {
+        let compute_instance = ComputeInstanceSnapshot::new_without_collections(cluster_id);
+        let optimizer_config = optimize::OptimizerConfig::from(self.catalog().system_config());
+
+        let mut cloned_items = vec![];
+        for item_id in items {
+            let entry = self.catalog().get_entry(&item_id);
+            let name = self
+                .catalog()
+                .for_session(session)
+                .find_available_name(entry.name().clone());
+            let owner_id = *entry.owner_id();
+            let item = entry.item().clone();
+
+            let create_stmt = |create_sql: &str| {
+                let mut stmt = mz_sql::parse::parse(create_sql)
+                    .expect("invalid create sql persisted to catalog")
+                    .into_element()
+                    .ast;
+                mz_sql::ast::transform::create_stmt_rename(&mut stmt, name.item.clone());
+                mz_sql::ast::transform::create_stmt_set_cluster(&mut stmt, cluster_id);
+                stmt.to_ast_string_stable()
+            };
+
+            let id = self.catalog_mut().allocate_user_id().await?;
+            let (item, cloned_item) = match item {
+                CatalogItem::Index(index) => {
+                    // Build an optimizer for this INDEX.
+                    let mut optimizer = optimize::index::Optimizer::new(
+                        self.owned_catalog(),
+                        compute_instance.clone(),
+                        id,
+                        optimizer_config.clone(),
+                    );
+
+                    // MIR ⇒ MIR optimization (global)
+                    let index_plan = optimize::index::Index::new(&name, &index.on, &index.keys);
+                    let global_mir_plan = optimizer.optimize(index_plan)?;
+                    // MIR ⇒ LIR lowering and LIR ⇒ LIR optimization (global)
+                    let global_lir_plan = optimizer.optimize(global_mir_plan.clone())?;
+
+                    let index = mz_catalog::memory::objects::Index {
+                        create_sql: create_stmt(&index.create_sql),
+                        cluster_id,
+                        ..index
+                    };
+                    let cloned_item = ClonedClusterItem::Index {
+                        id,
+                        global_mir_plan,
+                        global_lir_plan,
+                    };
+                    (CatalogItem::Index(index), cloned_item)
+                }
+                CatalogItem::MaterializedView(mv) => {
+                    let internal_view_id = self.allocate_transient_id()?;
+                    let debug_name = self.catalog().resolve_full_name(&name, None).to_string();
+
+                    // Build an optimizer for this MATERIALIZED VIEW.
+                    let mut optimizer = optimize::materialized_view::Optimizer::new(
+                        self.owned_catalog(),
+                        compute_instance.clone(),
+                        id,
+                        internal_view_id,
+                        mv.desc.iter_names().cloned().collect(),
+                        mv.non_null_assertions.clone(),
+                        debug_name,
+                        optimizer_config.clone(),
+                    );
+
+                    // MIR ⇒ MIR optimization (global)
+                    let global_mir_plan = optimizer.optimize(mv.optimized_expr.clone())?;
+                    // MIR ⇒ LIR lowering and LIR ⇒ LIR optimization (global)
+                    let global_lir_plan = optimizer.optimize(global_mir_plan.clone())?;
+
+                    let mv = mz_catalog::memory::objects::MaterializedView {
+                        create_sql: create_stmt(&mv.create_sql),
+                        cluster_id,
+                        ..mv
+                    };
+                    let cloned_item = ClonedClusterItem::MaterializedView {
+                        id,
+                        global_mir_plan,
+                        global_lir_plan,
+                    };
+                    (CatalogItem::MaterializedView(mv), cloned_item)
+                }
+                item => coord_bail!("cannot clone {} into a cluster", item.typ()),
+            };
+
+            ops.push(catalog::Op::CreateItem {
+                id,
+                oid: self.catalog_mut().allocate_oid()?,
+                name,
+                item,
+                owner_id,
+            });
+            cloned_items.push(cloned_item);
+        }
+        Ok(cloned_items)
+    }
+
+    /// Installs the dataflow of `cloned_item`, which was cloned into the
+    /// cluster `cluster_id`.
+    async fn ship_cloned_cluster_item(
+        &mut self,
+        session: &Session,
+        cluster_id: ClusterId,
+        cloned_item: ClonedClusterItem,
+    ) {
+        match cloned_item {
+            ClonedClusterItem::Index {
+                id,
+                global_mir_plan,
+                global_lir_plan,
+            } => {
+                // Save plan structures.
+                self.catalog_mut()
+                    .set_optimized_plan(id, global_mir_plan.df_desc().clone());
+                self.catalog_mut()
+                    .set_physical_plan(id, global_lir_plan.df_desc().clone());
+                self.catalog_mut()
+                    .set_dataflow_metainfo(id, global_lir_plan.df_meta().clone());
+
+                // Emit notices.
+                self.emit_optimizer_notices(session, &global_lir_plan.df_meta().optimizer_notices);
+
+                let mut df_desc = global_lir_plan.unapply().0;
+
+                // Timestamp selection
+                let id_bundle = dataflow_import_id_bundle(&df_desc, cluster_id);
+                let since = self.least_valid_read(&id_bundle);
+                df_desc.set_as_of(since);
+
+                self.ship_dataflow(df_desc, cluster_id).await;
+            }
+            ClonedClusterItem::MaterializedView {
+                id,
+                global_mir_plan,
+                global_lir_plan,
+            } => {
+                // Save plan structures.
+                self.catalog_mut()
+                    .set_optimized_plan(id, global_mir_plan.df_desc().clone());
+                self.catalog_mut()
+                    .set_physical_plan(id, global_lir_plan.df_desc().clone());
+                self.catalog_mut()
+                    .set_dataflow_metainfo(id, global_lir_plan.df_meta().clone());
+
+                // Emit notices.
+                self.emit_optimizer_notices(session, &global_lir_plan.df_meta().optimizer_notices);
+
+                let output_desc = global_lir_plan.desc().clone();
+                let mut df_desc = global_lir_plan.unapply().0;
+
+                // Timestamp selection
+                let id_bundle = dataflow_import_id_bundle(&df_desc, cluster_id);
+                let since = self.least_valid_read(&id_bundle);
+                df_desc.set_as_of(since.clone());
+
+                // Announce the creation of the materialized view source.
+                self.controller
+                    .storage
+                    .create_collections(
+                        None,
+                        vec![(
+                            id,
+                            CollectionDescription {
+                                desc: output_desc,
+                                data_source: DataSource::Other(DataSourceOther::Compute),
+                                since: Some(since),
+                                status_collection_id: None,
+                            },
+                        )],
+                    )
+                    .await
+                    .unwrap_or_terminate("cannot fail to append");
+
+                self.initialize_storage_read_policies(
+                    vec![id],
+                    Some(DEFAULT_LOGICAL_COMPACTION_WINDOW_TS),
+                )
+                .await;
+
+                self.ship_dataflow(df_desc, cluster_id).await;
+            }
         }
     }
 
@@ -106,9 +422,11 @@ impl Coordinator {
             replication_factor,
             size,
             disk,
+            schedule,
         }: CreateClusterManagedPlan,
         cluster_id: ClusterId,
         mut ops: Vec<catalog::Op>,
+        cloned_items: Vec<GlobalId>,
     ) -> Result<ExecuteResponse, AdapterError> {
         tracing::debug!("sequence_create_managed_cluster");
 
@@ -120,6 +438,7 @@ impl Coordinator {
             .allowed_cluster_replica_sizes();
         self.catalog
             .ensure_valid_replica_size(allowed_replica_sizes, &size)?;
+        self.ensure_valid_schedule(&schedule)?;
 
         // Eagerly validate the `max_replicas_per_cluster` limit.
         // `catalog_transact` will do this validation too, but allocating
@@ -152,9 +471,21 @@ impl Coordinator {
             )?;
         }
 
-        self.catalog_transact(Some(session), ops).await?;
-
-        self.create_cluster(cluster_id).await;
+        let cloned_items = self
+            .clone_cluster_items(session, cluster_id, cloned_items, &mut ops)
+            .await?;
+
+        // The cluster and its cloned items are created in a single catalog
+        // transaction, so either all of them exist or none of them do.
+        self.catalog_transact_with_side_effects(Some(session), ops, |coord| async move {
+            coord.create_cluster(cluster_id).await;
+            for cloned_item in cloned_items {
+                coord
+                    .ship_cloned_cluster_item(session, cluster_id, cloned_item)
+                    .await;
+            }
+        })
+        .await?;
 
         Ok(ExecuteResponse::CreatedCluster)
     }
@@ -173,6 +504,7 @@ impl Coordinator {
     ) -> Result<(), AdapterError> {
         let location = mz_catalog::durable::ReplicaLocation::Managed {
             availability_zone: None,
+            availability_zone_constraints: vec![],
             billed_as: None,
             disk,
             internal: false,
@@ -229,6 +561,170 @@ impl Coordinator {
         Ok(())
     }
 
+    /// Returns the availability zone of a new replica of `cluster_id` that
+    /// satisfies `constraints`, given the `availability_zone` that the replica
+    /// requests, if any.
+    ///
+    /// The replicas that `constraints` refer to must have a specific
+    /// availability zone. If the new replica does not request one, the first
+    /// availability zone that satisfies `constraints` is chosen, so that the
+    /// constraints hold regardless of how the orchestrator places replicas.
+    fn resolve_replica_availability_zone(
+        &self,
+        cluster_id: ClusterId,
+        availability_zone: Option<String>,
+        constraints: &[ReplicaAvailabilityZoneConstraint],
+    ) -> Result<Option<String>, AdapterError> {
+        if constraints.is_empty() {
+            return Ok(availability_zone);
+        }
+
+        let cluster = self.catalog().get_cluster(cluster_id);
+        let mut required = BTreeSet::new();
+        let mut excluded = BTreeSet::new();
+        for constraint in constraints {
+            let (ReplicaAvailabilityZoneConstraint::Affinity(replica_id)
+            | ReplicaAvailabilityZoneConstraint::AntiAffinity(replica_id)) = constraint;
+            let Some(replica) = cluster.replica(*replica_id) else {
+                coord_bail!(
+                    "replica {replica_id} of cluster {} no longer exists",
+                    cluster.name.quoted()
+                );
+            };
+            let az = match &replica.config.location {
+                ReplicaLocation::Managed(ManagedReplicaLocation {
+                    availability_zones: ManagedReplicaAvailabilityZones::FromReplica(Some(az)),
+                    ..
+                }) => az.clone(),
+                _ => coord_bail!(
+                    "cannot constrain the availability zone of a replica relative to \
+                    replica {}, which has no AVAILABILITY ZONE",
+                    replica.name.quoted()
+                ),
+            };
+            match constraint {
+                ReplicaAvailabilityZoneConstraint::Affinity(_) => required.insert(az),
+                ReplicaAvailabilityZoneConstraint::AntiAffinity(_) => excluded.insert(az),
+            };
+        }
+        if required.len() > 1 {
+            coord_bail!(
+                "AVAILABILITY ZONE AFFINITY refers to replicas in different availability \
+                zones: {}",
+                separated(", ", required.iter().map(|az| az.quoted()))
+            );
+        }
+
+        let satisfies = |az: &String| required.iter().all(|r| r == az) && !excluded.contains(az);
+        match availability_zone {
+            Some(az) if satisfies(&az) => Ok(Some(az)),
+            Some(az) => coord_bail!(
+                "AVAILABILITY ZONE {} violates the replica's availability zone constraints",
+                az.quoted()
+            ),
+            None => {
+                let candidates = match required.first() {
+                    Some(az) => vec![az.clone()],
+                    None => self.catalog().state().availability_zones().to_vec(),
+                };
+                match candidates.into_iter().find(|az| satisfies(az)) {
+                    Some(az) => Ok(Some(az)),
+                    None => coord_bail!(
+                        "no availability zone satisfies the replica's availability zone \
+                        constraints"
+                    ),
+                }
+            }
+        }
+    }
+
+    /// Validates the sizes that the entries of `schedule` switch to.
+    fn ensure_valid_schedule(&self, schedule: &[ClusterScheduleEntry]) -> Result<(), AdapterError> {
+        let allowed_replica_sizes = &self
+            .catalog()
+            .system_config()
+            .allowed_cluster_replica_sizes();
+        for size in schedule.iter().filter_map(|entry| entry.size.as_ref()) {
+            self.catalog
+                .ensure_valid_replica_size(allowed_replica_sizes, size)?;
+        }
+        Ok(())
+    }
+
+    /// Ensures that replicas of the sizes `sizes` fit within `memory_quota`,
+    /// the memory quota of the cluster `cluster_name`, if it has one.
+    ///
+    /// Each replica counts the memory limit of its size once per process.
+    /// Sizes without a memory limit cannot be used in clusters with a memory
+    /// quota. Unknown sizes are ignored, as they are rejected elsewhere.
+    fn ensure_within_memory_quota<'a>(
+        &self,
+        cluster_name: &str,
+        memory_quota: Option<u64>,
+        sizes: impl IntoIterator<Item = &'a str>,
+    ) -> Result<(), AdapterError> {
+        let Some(memory_quota) = memory_quota else {
+            return Ok(());
+        };
+        let mut required: u64 = 0;
+        for size in sizes {
+            let Some(allocation) = self.catalog().cluster_replica_sizes().0.get(size) else {
+                continue;
+            };
+            let Some(memory_limit) = allocation.memory_limit else {
+                coord_bail!(
+                    "cluster replica size {} has no memory limit, so cannot be used in \
+                    cluster {}, which has a MEMORY QUOTA",
+                    size.quoted(),
+                    cluster_name.quoted()
+                );
+            };
+            required = required.saturating_add(
+                memory_limit
+                    .0
+                    .as_u64()
+                    .saturating_mul(u64::from(allocation.scale)),
+            );
+        }
+        if required > memory_quota {
+            return Err(AdapterError::ClusterMemoryQuotaExceeded {
+                cluster_name: cluster_name.to_string(),
+                memory_quota,
+                required,
+            });
+        }
+        Ok(())
+    }
+
+    /// Ensures that the replicas of a managed cluster fit within the cluster's
+    /// memory quota, both as configured and under every entry of its
+    /// `schedule`.
+    fn ensure_managed_cluster_within_memory_quota(
+        &self,
+        cluster_name: &str,
+        memory_quota: Option<u64>,
+        size: &str,
+        replication_factor: u32,
+        schedule: &[ClusterScheduleEntry],
+    ) -> Result<(), AdapterError> {
+        let scheduled = schedule.iter().map(|entry| {
+            (
+                entry.size.as_deref().unwrap_or(size),
+                entry.replication_factor.unwrap_or(replication_factor),
+            )
+        });
+        for (size, replication_factor) in
+            std::iter::once((size, replication_factor)).chain(scheduled)
+        {
+            self.ensure_within_memory_quota(
+                cluster_name,
+                memory_quota,
+                std::iter::repeat(size).take(usize::cast_from(replication_factor)),
+            )?;
+        }
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     pub(super) async fn sequence_create_unmanaged_cluster(
         &mut self,
@@ -286,6 +782,7 @@ impl Coordinator {
                 }
                 mz_sql::plan::ReplicaConfig::Managed {
                     availability_zone,
+                    availability_zone_constraints,
                     billed_as,
                     compute,
                     disk,
@@ -294,6 +791,7 @@ impl Coordinator {
                 } => {
                     let location = mz_catalog::durable::ReplicaLocation::Managed {
                         availability_zone,
+                        availability_zone_constraints,
                         billed_as,
                         disk,
                         internal,
@@ -410,6 +908,7 @@ impl Coordinator {
             }
             mz_sql::plan::ReplicaConfig::Managed {
                 availability_zone,
+                availability_zone_constraints,
                 billed_as,
                 compute,
                 disk,
@@ -423,8 +922,14 @@ impl Coordinator {
                     }
                     None => None,
                 };
+                let availability_zone = self.resolve_replica_availability_zone(
+                    cluster_id,
+                    availability_zone,
+                    &availability_zone_constraints,
+                )?;
                 let location = mz_catalog::durable::ReplicaLocation::Managed {
                     availability_zone,
+                    availability_zone_constraints,
                     billed_as,
                     disk,
                     internal,
@@ -462,6 +967,19 @@ impl Coordinator {
 
         let cluster = self.catalog().get_cluster(cluster_id);
 
+        if let ReplicaLocation::Managed(ManagedReplicaLocation {
+            internal: false,
+            size,
+            ..
+        }) = &config.location
+        {
+            self.ensure_within_memory_quota(
+                &cluster.name,
+                cluster.config.memory_quota,
+                quota_replica_sizes(cluster).chain([size.as_str()]),
+            )?;
+        }
+
         if let ReplicaLocation::Managed(ManagedReplicaLocation {
             internal,
             billed_as,
@@ -516,6 +1034,7 @@ impl Coordinator {
                 cluster_id,
                 replica_id,
                 role,
+                priority: cluster.config.priority,
                 config: replica_config,
             });
         }
@@ -565,6 +1084,7 @@ impl Coordinator {
                     idle_arrangement_merge_effort: None,
                     replication_factor: 1,
                     disk,
+                    schedule: vec![],
                 });
             }
         }
@@ -577,6 +1097,7 @@ impl Coordinator {
                 idle_arrangement_merge_effort,
                 replication_factor,
                 disk,
+                schedule,
             }) => {
                 use AlterOptionParameter::*;
                 match &options.size {
@@ -614,6 +1135,14 @@ impl Coordinator {
                     Reset => *replication_factor = 1,
                     Unchanged => {}
                 }
+                match &options.schedule {
+                    Set(s) => {
+                        self.ensure_valid_schedule(s)?;
+                        *schedule = s.clone();
+                    }
+                    Reset => *schedule = vec![],
+                    Unchanged => {}
+                }
                 if !matches!(options.replicas, Unchanged) {
                     coord_bail!("Cannot change REPLICAS of managed clusters");
                 }
@@ -640,62 +1169,152 @@ impl Coordinator {
                 if !matches!(options.replication_factor, Unchanged) {
                     coord_bail!("Cannot change REPLICATION FACTOR of unmanaged clusters");
                 }
+                if !matches!(options.schedule, Unchanged) {
+                    coord_bail!("Cannot change SCHEDULE of unmanaged clusters");
+                }
+            }
+        }
+
+        match &options.priority {
+            AlterOptionParameter::Set(priority) => new_config.priority = *priority,
+            AlterOptionParameter::Reset => new_config.priority = ClusterPriority::default(),
+            AlterOptionParameter::Unchanged => {}
+        }
+        match &options.memory_quota {
+            AlterOptionParameter::Set(memory_quota) => {
+                new_config.memory_quota = Some(*memory_quota)
             }
+            AlterOptionParameter::Reset => new_config.memory_quota = None,
+            AlterOptionParameter::Unchanged => {}
         }
 
         if new_config == config {
             return Ok(ExecuteResponse::AlteredObject(ObjectType::Cluster));
         }
 
-        match (&config.variant, new_config.variant) {
+        // Changing between managed and unmanaged keeps the replicas of the
+        // cluster, so only a managed cluster's replicas can change here.
+        let cluster = self.catalog().get_cluster(cluster_id);
+        match (&config.variant, &new_config.variant) {
+            (Managed(_), Managed(managed)) => self.ensure_managed_cluster_within_memory_quota(
+                &cluster.name,
+                new_config.memory_quota,
+                &managed.size,
+                managed.replication_factor,
+                &managed.schedule,
+            )?,
+            _ => self.ensure_within_memory_quota(
+                &cluster.name,
+                new_config.memory_quota,
+                quota_replica_sizes(cluster),
+            )?,
+        }
+
+        let ClusterConfig {
+            variant: new_variant,
+            priority,
+            memory_quota,
+            resize: _,
+        } = new_config;
+        match (&config.variant, new_variant) {
             (Managed(config), Managed(new_config)) => {
                 self.sequence_alter_cluster_managed_to_managed(
-                    session, cluster_id, config, new_config,
+                    Some(session),
+                    cluster_id,
+                    config,
+                    new_config,
+                    priority,
+                    memory_quota,
+                    vec![],
                 )
                 .await?;
             }
             (Unmanaged, Managed(new_config)) => {
                 self.sequence_alter_cluster_unmanaged_to_managed(
-                    session, cluster_id, new_config, options,
+                    session,
+                    cluster_id,
+                    new_config,
+                    priority,
+                    memory_quota,
+                    options,
                 )
                 .await?;
             }
             (Managed(_), Unmanaged) => {
-                self.sequence_alter_cluster_managed_to_unmanaged(session, cluster_id)
-                    .await?;
+                self.sequence_alter_cluster_managed_to_unmanaged(
+                    session,
+                    cluster_id,
+                    priority,
+                    memory_quota,
+                )
+                .await?;
             }
             (Unmanaged, Unmanaged) => {
                 self.sequence_alter_cluster_unmanaged_to_unmanaged(
                     session,
                     cluster_id,
+                    priority,
+                    memory_quota,
                     options.replicas,
-                )?;
+                )
+                .await?;
             }
         }
 
         Ok(ExecuteResponse::AlteredObject(ObjectType::Cluster))
     }
 
+    /// Reconfigures the managed cluster `cluster_id` from `config` to
+    /// `new_config`, with the given `priority` and `memory_quota`, transacting
+    /// `ops` alongside the reconfiguration.
+    ///
+    /// If graceful reconfiguration is enabled, replacing the replicas of the
+    /// cluster starts a resize: the new replicas are provisioned alongside the
+    /// existing ones, which are only dropped once the new replicas have caught
+    /// up. See [`Coordinator::advance_cluster_resizes`].
     async fn sequence_alter_cluster_managed_to_managed(
         &mut self,
-        session: &Session,
+        session: Option<&Session>,
         cluster_id: ClusterId,
         config: &ClusterVariantManaged,
         new_config: ClusterVariantManaged,
+        priority: ClusterPriority,
+        memory_quota: Option<u64>,
+        mut ops: Vec<catalog::Op>,
     ) -> Result<(), AdapterError> {
         let cluster = self.catalog.get_cluster(cluster_id);
         let name = cluster.name().to_string();
         let owner_id = cluster.owner_id();
-        let mut ops = vec![];
+
+        // A resize in progress carries on if the reconfiguration leaves the
+        // replicas of the cluster as they are. Otherwise, it is rolled back,
+        // and the reconfiguration starts from the replicas the cluster had
+        // before the resize.
+        let mut config = config.clone();
+        let mut resize = cluster.config.resize.clone();
+        if let Some(in_progress) = &resize {
+            if managed_replicas_differ(&config, &new_config)
+                || config.replication_factor != new_config.replication_factor
+            {
+                ops.extend(cluster_resize_rollback_ops(cluster, in_progress));
+                config = in_progress.old_config.clone();
+                resize = None;
+            }
+        }
+        let is_storage_cluster = cluster.bound_objects.iter().any(|id| {
+            let entry = self.catalog().get_entry(id);
+            entry.is_source() || entry.is_sink()
+        });
 
         let (
             ClusterVariantManaged {
-                size,
+                size: _,
                 replication_factor,
-                availability_zones,
-                logging,
-                idle_arrangement_merge_effort,
-                disk,
+                availability_zones: _,
+                logging: _,
+                idle_arrangement_merge_effort: _,
+                disk: _,
+                schedule: _,
             },
             ClusterVariantManaged {
                 size: new_size,
@@ -704,6 +1323,7 @@ impl Coordinator {
                 logging: new_logging,
                 idle_arrangement_merge_effort: new_idle_arrangement_merge_effort,
                 disk: new_disk,
+                schedule: _,
             },
         ) = (&config, &new_config);
 
@@ -713,6 +1333,11 @@ impl Coordinator {
             .allowed_cluster_replica_sizes();
         self.catalog
             .ensure_valid_replica_size(allowed_replica_sizes, new_size)?;
+        self.ensure_within_memory_quota(
+            &name,
+            memory_quota,
+            std::iter::repeat(new_size.as_str()).take(usize::cast_from(*new_replication_factor)),
+        )?;
 
         let mut create_cluster_replicas = vec![];
 
@@ -740,12 +1365,57 @@ impl Coordinator {
             )?;
         }
 
-        if new_size != size
-            || new_availability_zones != availability_zones
-            || new_idle_arrangement_merge_effort != idle_arrangement_merge_effort
-            || new_logging != logging
-            || new_disk != disk
-        {
+        let replace_replicas = managed_replicas_differ(&config, &new_config);
+        let graceful = self
+            .catalog()
+            .system_config()
+            .enable_graceful_cluster_reconfiguration()
+            && *replication_factor > 0
+            && *new_replication_factor > 0
+            // Sources and sinks run on at most one replica, so their clusters
+            // cannot run the old and new replicas side by side.
+            && !is_storage_cluster;
+
+        if replace_replicas && graceful {
+            self.ensure_valid_azs(new_availability_zones.iter())?;
+
+            // The old and new replicas run side by side until cutover.
+            self.validate_resource_limit(
+                usize::cast_from(*replication_factor),
+                i64::from(*new_replication_factor),
+                SystemVars::max_replicas_per_cluster,
+                "cluster replica",
+                MAX_REPLICAS_PER_CLUSTER.name(),
+            )?;
+
+            let old_replicas = (0..*replication_factor)
+                .map(managed_cluster_replica_name)
+                .filter_map(|name| cluster.replica_id(&name))
+                .collect();
+            let mut new_replicas = BTreeSet::new();
+            for name in (0..*new_replication_factor).map(pending_cluster_replica_name) {
+                let id = self.catalog_mut().allocate_user_replica_id().await?;
+                self.create_managed_cluster_replica_op(
+                    cluster_id,
+                    id,
+                    name,
+                    &compute,
+                    new_size,
+                    &mut ops,
+                    Some(new_availability_zones.as_ref()),
+                    *new_disk,
+                    owner_id,
+                )?;
+                create_cluster_replicas.push((cluster_id, id));
+                new_replicas.insert(id);
+            }
+            resize = Some(ClusterResize {
+                old_replicas,
+                new_replicas,
+                cutover_frontier: self.get_local_read_ts().await,
+                old_config: config.clone(),
+            });
+        } else if replace_replicas {
             self.ensure_valid_azs(new_availability_zones.iter())?;
 
             // tear down all replicas, create new ones
@@ -813,19 +1483,187 @@ impl Coordinator {
         ops.push(catalog::Op::UpdateClusterConfig {
             id: cluster_id,
             name,
-            config: ClusterConfig { variant },
+            config: ClusterConfig {
+                variant,
+                priority,
+                memory_quota,
+                resize,
+            },
         });
 
-        self.catalog_transact(Some(session), ops).await?;
+        self.catalog_transact(session, ops).await?;
         self.create_cluster_replicas(&create_cluster_replicas).await;
         Ok(())
     }
 
+    /// Resizes each managed cluster with a schedule to the size and
+    /// replication factor of the schedule entry that took effect since the
+    /// schedules were last applied, recording an audit event for every change.
+    ///
+    /// Entries are applied only when they take effect, so a manual change to a
+    /// scheduled setting lasts until the next entry takes effect. The first
+    /// time schedules are applied after the coordinator starts, the entries
+    /// currently in effect are applied, as they may have taken effect while the
+    /// coordinator was down.
+    pub(crate) async fn apply_cluster_schedules(&mut self) {
+        if !self.catalog().system_config().enable_cluster_schedules() {
+            return;
+        }
+        let now = self.now();
+        let since = self.cluster_schedules_applied_at.replace(now);
+        let mut changes = vec![];
+        for cluster in self.catalog().user_clusters() {
+            let ClusterVariant::Managed(config) = &cluster.config.variant else {
+                continue;
+            };
+            let entry = match since {
+                Some(since) => ClusterScheduleEntry::newly_active(&config.schedule, since, now),
+                None => ClusterScheduleEntry::active(&config.schedule, now),
+            };
+            let Some(entry) = entry else {
+                continue;
+            };
+            let mut new_config = config.clone();
+            if let Some(size) = &entry.size {
+                new_config.size = size.clone();
+            }
+            if let Some(replication_factor) = entry.replication_factor {
+                new_config.replication_factor = replication_factor;
+            }
+            if new_config == *config {
+                continue;
+            }
+            let is_storage_cluster = cluster.bound_objects.iter().any(|id| {
+                let entry = self.catalog().get_entry(id);
+                entry.is_source() || entry.is_sink()
+            });
+            if is_storage_cluster && new_config.replication_factor > 1 {
+                tracing::warn!(
+                    "not applying schedule of cluster {}: cannot schedule more than one \
+                    replica of a cluster containing sources or sinks",
+                    cluster.name
+                );
+                continue;
+            }
+            let details = mz_audit_log::ScheduledClusterChangeV1 {
+                cluster_id: cluster.id.to_string(),
+                cluster_name: cluster.name.clone(),
+                scheduled_at: entry.time_of_day(),
+                old_size: config.size.clone(),
+                new_size: new_config.size.clone(),
+                old_replication_factor: config.replication_factor,
+                new_replication_factor: new_config.replication_factor,
+            };
+            changes.push((
+                cluster.id,
+                config.clone(),
+                new_config,
+                cluster.config.priority,
+                cluster.config.memory_quota,
+                details,
+            ));
+        }
+
+        for (cluster_id, config, new_config, priority, memory_quota, details) in changes {
+            let ops = vec![catalog::Op::LogScheduledClusterChange { details }];
+            if let Err(e) = self
+                .sequence_alter_cluster_managed_to_managed(
+                    None,
+                    cluster_id,
+                    &config,
+                    new_config,
+                    priority,
+                    memory_quota,
+                    ops,
+                )
+                .await
+            {
+                tracing::warn!("failed to apply schedule of cluster {cluster_id}: {e}");
+            }
+        }
+    }
+
+    /// Advances the in-progress resizes of managed clusters.
+    ///
+    /// A resize cuts over once each of its new replicas has caught up to its
+    /// cutover frontier: the old replicas are dropped, and the new replicas
+    /// take over their names. A resize is rolled back if any of its new
+    /// replicas no longer exists, which drops the remaining new replicas and
+    /// restores the configuration of the cluster before the resize.
+    ///
+    /// Resizes are recorded in the catalog, so after a restart they are
+    /// resumed or rolled back by the same rules.
+    pub(crate) async fn advance_cluster_resizes(&mut self) {
+        let mut changes = vec![];
+        for cluster in self.catalog().user_clusters() {
+            let Some(resize) = &cluster.config.resize else {
+                continue;
+            };
+            let mut config = cluster.config.clone();
+            config.resize = None;
+            let mut ops = vec![];
+            if resize
+                .new_replicas
+                .iter()
+                .any(|replica_id| cluster.replica(*replica_id).is_none())
+            {
+                ops.extend(cluster_resize_rollback_ops(cluster, resize));
+                config.variant = ClusterVariant::Managed(resize.old_config.clone());
+            } else if resize.new_replicas.iter().all(|replica_id| {
+                self.controller
+                    .compute
+                    .replica_caught_up(cluster.id, *replica_id, &resize.cutover_frontier)
+                    .unwrap_or(false)
+            }) {
+                for replica_id in &resize.old_replicas {
+                    if cluster.replica(*replica_id).is_some() {
+                        ops.push(catalog::Op::DropObject(ObjectId::ClusterReplica((
+                            cluster.id,
+                            *replica_id,
+                        ))));
+                    }
+                }
+                for replica_id in &resize.new_replicas {
+                    let replica = cluster.replica(*replica_id).expect("checked above");
+                    let Some(to_name) = replica.name.strip_suffix(PENDING_REPLICA_NAME_SUFFIX)
+                    else {
+                        continue;
+                    };
+                    ops.push(catalog::Op::RenameClusterReplica {
+                        cluster_id: cluster.id,
+                        replica_id: *replica_id,
+                        name: QualifiedReplica {
+                            cluster: Ident::new_unchecked(cluster.name.clone()),
+                            replica: Ident::new_unchecked(replica.name.clone()),
+                        },
+                        to_name: to_name.to_string(),
+                    });
+                }
+            } else {
+                continue;
+            }
+            ops.push(catalog::Op::UpdateClusterConfig {
+                id: cluster.id,
+                name: cluster.name.clone(),
+                config,
+            });
+            changes.push((cluster.id, ops));
+        }
+
+        for (cluster_id, ops) in changes {
+            if let Err(e) = self.catalog_transact(None, ops).await {
+                tracing::warn!("failed to advance resize of cluster {cluster_id}: {e}");
+            }
+        }
+    }
+
     async fn sequence_alter_cluster_unmanaged_to_managed(
         &mut self,
         session: &Session,
         cluster_id: ClusterId,
         mut new_config: ClusterVariantManaged,
+        priority: ClusterPriority,
+        memory_quota: Option<u64>,
         options: PlanClusterOption,
     ) -> Result<(), AdapterError> {
         let cluster = self.catalog.get_cluster(cluster_id);
@@ -838,6 +1676,7 @@ impl Coordinator {
             logging: _,
             idle_arrangement_merge_effort: _,
             disk: new_disk,
+            schedule: _,
         } = &mut new_config;
 
         // Validate replication factor parameter
@@ -957,7 +1796,12 @@ impl Coordinator {
         ops.push(catalog::Op::UpdateClusterConfig {
             id: cluster_id,
             name: cluster_name,
-            config: ClusterConfig { variant },
+            config: ClusterConfig {
+                variant,
+                priority,
+                memory_quota,
+                resize: None,
+            },
         });
 
         self.catalog_transact(Some(session), ops).await?;
@@ -968,28 +1812,62 @@ impl Coordinator {
         &mut self,
         session: &Session,
         cluster_id: ClusterId,
+        priority: ClusterPriority,
+        memory_quota: Option<u64>,
     ) -> Result<(), AdapterError> {
         let cluster = self.catalog().get_cluster(cluster_id);
         let mut ops = vec![];
 
+        // Unmanaged clusters cannot be resized, so a resize in progress is
+        // rolled back, leaving the replicas the cluster had before the resize.
+        if let Some(resize) = &cluster.config.resize {
+            ops.extend(cluster_resize_rollback_ops(cluster, resize));
+        }
+
         let variant = ClusterVariant::Unmanaged;
         ops.push(catalog::Op::UpdateClusterConfig {
             id: cluster_id,
             name: cluster.name().to_string(),
-            config: ClusterConfig { variant },
+            config: ClusterConfig {
+                variant,
+                priority,
+                memory_quota,
+                resize: None,
+            },
         });
 
         self.catalog_transact(Some(session), ops).await?;
         Ok(())
     }
 
-    fn sequence_alter_cluster_unmanaged_to_unmanaged(
-        &self,
-        _session: &Session,
-        _cluster_id: ClusterId,
-        _replicas: AlterOptionParameter<Vec<(String, mz_sql::plan::ReplicaConfig)>>,
+    /// Changes the priority and memory quota of the unmanaged cluster
+    /// `cluster_id`, the only configuration of unmanaged clusters that can be
+    /// altered.
+    async fn sequence_alter_cluster_unmanaged_to_unmanaged(
+        &mut self,
+        session: &Session,
+        cluster_id: ClusterId,
+        priority: ClusterPriority,
+        memory_quota: Option<u64>,
+        replicas: AlterOptionParameter<Vec<(String, mz_sql::plan::ReplicaConfig)>>,
     ) -> Result<(), AdapterError> {
-        coord_bail!("Cannot alter unmanaged cluster");
+        if !matches!(replicas, AlterOptionParameter::Unchanged) {
+            coord_bail!("Cannot alter unmanaged cluster");
+        }
+
+        let cluster = self.catalog().get_cluster(cluster_id);
+        let op = catalog::Op::UpdateClusterConfig {
+            id: cluster_id,
+            name: cluster.name().to_string(),
+            config: ClusterConfig {
+                variant: ClusterVariant::Unmanaged,
+                priority,
+                memory_quota,
+                resize: None,
+            },
+        };
+        self.catalog_transact(Some(session), vec![op]).await?;
+        Ok(())
     }
 
     pub(super) async fn sequence_alter_cluster_rename(
@@ -1073,6 +1951,40 @@ impl Coordinator {
         }
     }
 
+    pub(super) async fn sequence_alter_cluster_replica(
+        &mut self,
+        session: &Session,
+        AlterClusterReplicaPlan {
+            cluster_id,
+            replica_id,
+            name,
+            draining,
+        }: AlterClusterReplicaPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let draining = match draining {
+            AlterOptionParameter::Set(draining) => draining,
+            AlterOptionParameter::Reset => false,
+            AlterOptionParameter::Unchanged => {
+                return Ok(ExecuteResponse::AlteredObject(ObjectType::ClusterReplica))
+            }
+        };
+
+        let op = catalog::Op::UpdateClusterReplicaDraining {
+            cluster_id,
+            replica_id,
+            name,
+            draining,
+        };
+        self.catalog_transact(Some(session), vec![op]).await?;
+
+        self.controller
+            .compute
+            .set_replica_draining(cluster_id, replica_id, draining)
+            .unwrap_or_terminate("cannot fail to set replica draining");
+
+        Ok(ExecuteResponse::AlteredObject(ObjectType::ClusterReplica))
+    }
+
     /// Determine whether we can create a compute item in the specified cluster.
     ///
     /// Returns `Ok` if the item can be created, and an error otherwise.
@@ -1110,6 +2022,58 @@ impl Coordinator {
     }
 }
 
+/// Returns the sizes of the replicas of `cluster` that count towards its memory
+/// quota: all replicas with a managed location, except internal ones.
+fn quota_replica_sizes(cluster: &Cluster) -> impl Iterator<Item = &str> {
+    cluster
+        .replicas()
+        .filter_map(|replica| match &replica.config.location {
+            ReplicaLocation::Managed(location) if !location.internal => {
+                Some(location.size.as_str())
+            }
+            _ => None,
+        })
+}
+
 fn managed_cluster_replica_name(index: u32) -> String {
     format!("r{}", index + 1)
 }
+
+/// The suffix of the names of replicas that a resize provisions, which the
+/// replicas drop at cutover.
+const PENDING_REPLICA_NAME_SUFFIX: &str = "-pending";
+
+/// The name of a replica that a resize provisions, which takes the name
+/// [`managed_cluster_replica_name`] of the same `index` at cutover.
+fn pending_cluster_replica_name(index: u32) -> String {
+    format!(
+        "{}{PENDING_REPLICA_NAME_SUFFIX}",
+        managed_cluster_replica_name(index)
+    )
+}
+
+/// Reports whether changing a managed cluster from `config` to `new_config`
+/// requires replacing all of its replicas.
+fn managed_replicas_differ(
+    config: &ClusterVariantManaged,
+    new_config: &ClusterVariantManaged,
+) -> bool {
+    config.size != new_config.size
+        || config.availability_zones != new_config.availability_zones
+        || config.idle_arrangement_merge_effort != new_config.idle_arrangement_merge_effort
+        || config.logging != new_config.logging
+        || config.disk != new_config.disk
+}
+
+/// Returns the ops that roll back the in-progress `resize` of `cluster`, which
+/// drop the replicas that the resize provisioned.
+fn cluster_resize_rollback_ops(cluster: &Cluster, resize: &ClusterResize) -> Vec<catalog::Op> {
+    resize
+        .new_replicas
+        .iter()
+        .filter(|replica_id| cluster.replica(**replica_id).is_some())
+        .map(|replica_id| {
+            catalog::Op::DropObject(ObjectId::ClusterReplica((cluster.id, *replica_id)))
+        })
+        .collect()
+}
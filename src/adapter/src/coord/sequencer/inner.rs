@@ -20,6 +20,7 @@ use futures::future::BoxFuture;
 use itertools::Itertools;
 use maplit::{btreemap, btreeset};
 use mz_cloud_resources::VpcEndpointConfig;
+use mz_controller::clusters::ReplicaAllocation;
 use mz_controller_types::{ClusterId, ReplicaId};
 use mz_expr::{
     permutation_for_arrangement, CollectionPlan, MirScalarExpr, OptimizedMirRelationExpr,
@@ -54,9 +55,9 @@ use mz_catalog::memory::objects::{
     CatalogItem, Cluster, Connection, DataSourceDesc, Secret, Sink, Source, Table, Type, View,
 };
 use mz_sql::plan::{
-    AlterConnectionAction, AlterConnectionPlan, AlterOptionParameter, ExplainSinkSchemaPlan,
-    Explainee, Index, IndexOption, MaterializedView, MutationKind, Params, Plan,
-    PlannedAlterRoleOption, PlannedRoleVariable, QueryWhen, SideEffectingFunc,
+    AlterConnectionAction, AlterConnectionPlan, AlterOptionParameter, ExplainCreateSourcePlan,
+    ExplainSinkSchemaPlan, Explainee, Index, IndexOption, MaterializedView, MutationKind, Params,
+    Plan, PlannedAlterRoleOption, PlannedRoleVariable, QueryWhen, SideEffectingFunc,
     SourceSinkClusterConfig, UpdatePrivilege, VariableValue,
 };
 use mz_sql::session::vars::{
@@ -493,7 +494,7 @@ impl Coordinator {
             Ok(_) => Ok(ExecuteResponse::CreatedDatabase),
             Err(AdapterError::Catalog(mz_catalog::memory::error::Error {
                 kind:
-                    mz_catalog::memory::error::ErrorKind::Sql(CatalogError::DatabaseAlreadyExists(_)),
+                    mz_catalog::memory::error::ErrorKind::Sql(CatalogError::DatabaseAlreadyExists(..)),
             })) if plan.if_not_exists => {
                 session.add_notice(AdapterNotice::DatabaseAlreadyExists { name: plan.name });
                 Ok(ExecuteResponse::CreatedDatabase)
@@ -519,7 +520,7 @@ impl Coordinator {
             Ok(_) => Ok(ExecuteResponse::CreatedSchema),
             Err(AdapterError::Catalog(mz_catalog::memory::error::Error {
                 kind:
-                    mz_catalog::memory::error::ErrorKind::Sql(CatalogError::SchemaAlreadyExists(_)),
+                    mz_catalog::memory::error::ErrorKind::Sql(CatalogError::SchemaAlreadyExists(..)),
             })) if plan.if_not_exists => {
                 session.add_notice(AdapterNotice::SchemaAlreadyExists {
                     name: plan.schema_name,
@@ -547,6 +548,117 @@ impl Coordinator {
             .map(|_| ExecuteResponse::CreatedRole)
     }
 
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(super) async fn sequence_create_service_account(
+        &mut self,
+        session: &Session,
+        plan::CreateServiceAccountPlan {
+            name,
+            role_id,
+            token_secret_id,
+            scopes,
+            expires_at,
+        }: plan::CreateServiceAccountPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let op = catalog::Op::CreateServiceAccount {
+            name,
+            role_id,
+            token_secret_id,
+            scopes,
+            expires_at,
+        };
+        self.catalog_transact(Some(session), vec![op])
+            .await
+            .map(|_| ExecuteResponse::CreatedServiceAccount)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(super) async fn sequence_drop_service_account(
+        &mut self,
+        session: &mut Session,
+        plan::DropServiceAccountPlan { name, if_exists }: plan::DropServiceAccountPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let op = catalog::Op::DropServiceAccount { name: name.clone() };
+        match self.catalog_transact(Some(session), vec![op]).await {
+            Ok(_) => Ok(ExecuteResponse::DroppedServiceAccount),
+            Err(AdapterError::Catalog(mz_catalog::memory::error::Error {
+                kind:
+                    mz_catalog::memory::error::ErrorKind::Sql(CatalogError::UnknownServiceAccount(_)),
+            })) if if_exists => {
+                session.add_notice(AdapterNotice::ServiceAccountDoesNotExist { name });
+                Ok(ExecuteResponse::DroppedServiceAccount)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(super) async fn sequence_undrop_object(
+        &mut self,
+        session: &Session,
+        plan::UndropObjectPlan { object_type, name }: plan::UndropObjectPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let oid = self.catalog_mut().allocate_oid()?;
+        let op = catalog::Op::UndropObject { oid, name };
+        self.catalog_transact(Some(session), vec![op])
+            .await
+            .map(|_| ExecuteResponse::UndroppedObject(object_type))
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(super) async fn sequence_create_cluster_replica_size(
+        &mut self,
+        session: &Session,
+        plan::CreateClusterReplicaSizePlan {
+            name,
+            memory_limit,
+            cpu_limit,
+            disk_limit,
+            scale,
+            workers,
+            credits_per_hour,
+            disabled,
+        }: plan::CreateClusterReplicaSizePlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let op = catalog::Op::CreateClusterReplicaSize {
+            name,
+            allocation: ReplicaAllocation {
+                memory_limit,
+                cpu_limit,
+                disk_limit,
+                scale,
+                workers,
+                credits_per_hour,
+                disabled,
+            },
+        };
+        self.catalog_transact(Some(session), vec![op])
+            .await
+            .map(|_| ExecuteResponse::CreatedClusterReplicaSize)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(super) async fn sequence_drop_cluster_replica_size(
+        &mut self,
+        session: &mut Session,
+        plan::DropClusterReplicaSizePlan { name, if_exists }: plan::DropClusterReplicaSizePlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let op = catalog::Op::DropClusterReplicaSize { name: name.clone() };
+        match self.catalog_transact(Some(session), vec![op]).await {
+            Ok(_) => Ok(ExecuteResponse::DroppedClusterReplicaSize),
+            Err(AdapterError::Catalog(mz_catalog::memory::error::Error {
+                kind:
+                    mz_catalog::memory::error::ErrorKind::Sql(CatalogError::UnknownClusterReplicaSize(
+                        _,
+                    )),
+            })) if if_exists => {
+                session.add_notice(AdapterNotice::ClusterReplicaSizeDoesNotExist { name });
+                Ok(ExecuteResponse::DroppedClusterReplicaSize)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     pub(super) async fn sequence_create_table(
         &mut self,
@@ -1765,6 +1877,18 @@ impl Coordinator {
         ])]))
     }
 
+    pub(super) fn sequence_explain_create_source(
+        &mut self,
+        ExplainCreateSourcePlan { statements, .. }: ExplainCreateSourcePlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        Ok(Self::send_immediate_rows(
+            statements
+                .iter()
+                .map(|statement| Row::pack_slice(&[Datum::String(statement)]))
+                .collect(),
+        ))
+    }
+
     pub(super) fn sequence_show_all_variables(
         &mut self,
         session: &Session,
@@ -2157,6 +2281,15 @@ impl Coordinator {
                 };
                 Ok(Self::send_immediate_rows(vec![Row::pack_slice(&[res])]))
             }
+            SideEffectingFunc::CancelBackgroundPurification { id } => {
+                // check_plan already verified role membership.
+                let res = if self.cancel_background_purification(id) {
+                    Datum::True
+                } else {
+                    Datum::False
+                };
+                Ok(Self::send_immediate_rows(vec![Row::pack_slice(&[res])]))
+            }
         }
     }
 
@@ -4493,6 +4626,20 @@ impl Coordinator {
         });
     }
 
+    pub(super) async fn sequence_alter_column_mask(
+        &mut self,
+        session: &Session,
+        plan: plan::AlterColumnMaskPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let op = catalog::Op::AlterColumnMask {
+            id: plan.id,
+            column: plan.column.as_str().to_string(),
+            action: plan.action,
+        };
+        self.catalog_transact(Some(session), vec![op]).await?;
+        Ok(ExecuteResponse::AlteredObject(plan.object_type))
+    }
+
     pub(super) async fn sequence_alter_item_rename(
         &mut self,
         session: &mut Session,
@@ -4533,6 +4680,28 @@ impl Coordinator {
         }
     }
 
+    pub(super) async fn sequence_alter_database_rename(
+        &mut self,
+        session: &mut Session,
+        plan::AlterDatabaseRenamePlan {
+            id,
+            name: _,
+            to_name,
+        }: plan::AlterDatabaseRenamePlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let op = catalog::Op::RenameDatabase {
+            database_id: id,
+            new_name: to_name,
+        };
+        match self
+            .catalog_transact_with_ddl_transaction(session, vec![op])
+            .await
+        {
+            Ok(()) => Ok(ExecuteResponse::AlteredObject(ObjectType::Database)),
+            Err(err) => Err(err),
+        }
+    }
+
     pub(super) async fn sequence_alter_schema_swap(
         &mut self,
         session: &mut Session,
@@ -4644,11 +4813,7 @@ impl Coordinator {
 
         // Apply our updates.
         match option {
-            PlannedAlterRoleOption::Attributes(attrs) => {
-                if let Some(inherit) = attrs.inherit {
-                    attributes.inherit = inherit;
-                }
-            }
+            PlannedAlterRoleOption::Attributes(attrs) => attributes.apply(attrs),
             PlannedAlterRoleOption::Variable(variable) => {
                 // Get the variable to make sure it's valid and visible.
                 session
@@ -4700,6 +4865,9 @@ impl Coordinator {
         let payload = self.extract_secret(session, &mut secret_as)?;
 
         self.secrets_controller.ensure(id, &payload).await?;
+        // Purifications may have used the old secret, and the catalog
+        // revision does not reflect its change.
+        self.purification_cache.clear();
 
         Ok(ExecuteResponse::AlteredObject(ObjectType::Secret))
     }
@@ -4707,23 +4875,156 @@ impl Coordinator {
     pub(super) async fn sequence_alter_sink(
         &mut self,
         session: &Session,
-        plan::AlterSinkPlan { id, size }: plan::AlterSinkPlan,
+        plan::AlterSinkPlan { id, action }: plan::AlterSinkPlan,
     ) -> Result<ExecuteResponse, AdapterError> {
-        let cluster_config = alter_storage_cluster_config(size);
-        if let Some(cluster_config) = cluster_config {
-            let mut ops = self.alter_linked_cluster_ops(id, &cluster_config).await?;
-            ops.push(catalog::Op::AlterSink {
-                id,
-                cluster_config: cluster_config.clone(),
-            });
-            self.catalog_transact(Some(session), ops).await?;
+        match action {
+            plan::AlterSinkAction::Resize(size) => {
+                let cluster_config = alter_storage_cluster_config(size);
+                if let Some(cluster_config) = cluster_config {
+                    let mut ops = self.alter_linked_cluster_ops(id, &cluster_config).await?;
+                    ops.push(catalog::Op::AlterSink {
+                        id,
+                        cluster_config: cluster_config.clone(),
+                    });
+                    self.catalog_transact(Some(session), ops).await?;
 
-            self.maybe_alter_linked_cluster(id).await;
+                    self.maybe_alter_linked_cluster(id).await;
+                }
+            }
+            plan::AlterSinkAction::ChangeRelation { from } => {
+                self.sequence_alter_sink_from(session, id, from).await?;
+            }
         }
 
         Ok(ExecuteResponse::AlteredObject(ObjectType::Sink))
     }
 
+    /// Repoints the sink `id` at the relation `from`, re-planning the sink's
+    /// definition against the new relation and swapping its dependency in a
+    /// single catalog transaction.
+    async fn sequence_alter_sink_from(
+        &mut self,
+        session: &Session,
+        id: GlobalId,
+        from: GlobalId,
+    ) -> Result<(), AdapterError> {
+        const ALTER_SINK: &str = "ALTER SINK...SET FROM";
+
+        let cur_entry = self.catalog().get_entry(&id);
+        let cur_sink = match cur_entry.item() {
+            CatalogItem::Sink(sink) => sink.clone(),
+            _ => unreachable!("must be sink"),
+        };
+
+        let create_sql_to_stmt_deps = |coord: &Coordinator, create_sink_sql| {
+            // Parse statement.
+            let create_sink_stmt = match mz_sql::parse::parse(create_sink_sql)
+                .expect("invalid create sql persisted to catalog")
+                .into_element()
+                .ast
+            {
+                Statement::CreateSink(stmt) => stmt,
+                _ => unreachable!("proved type is sink"),
+            };
+
+            let catalog = coord.catalog().for_system_session();
+
+            // Resolve items in statement
+            mz_sql::names::resolve(&catalog, create_sink_stmt)
+                .map_err(|e| AdapterError::internal(ALTER_SINK, e))
+        };
+
+        // Rewrite the sink's definition to read from the new relation, and
+        // re-resolve it so that its dependencies reflect the new relation.
+        let (mut create_sink_stmt, _) = create_sql_to_stmt_deps(self, cur_entry.create_sql())?;
+        let from_entry = self.catalog().get_entry(&from);
+        create_sink_stmt.from = ResolvedItemName::Item {
+            id: from,
+            qualifiers: from_entry.name().qualifiers.clone(),
+            full_name: self
+                .catalog()
+                .resolve_full_name(from_entry.name(), from_entry.conn_id()),
+            print_id: true,
+        };
+        let (create_sink_stmt, resolved_ids) =
+            create_sql_to_stmt_deps(self, &create_sink_stmt.to_ast_string_stable())?;
+
+        // Open a new catalog, which we will use to re-plan our statement
+        // against the new relation.
+        let mut catalog = self.catalog().for_system_session();
+        catalog.mark_id_unresolvable_for_replanning(id);
+
+        // Re-define our sink in terms of the amended statement
+        let plan = match mz_sql::plan::plan(
+            None,
+            &catalog,
+            Statement::CreateSink(create_sink_stmt),
+            &Params::empty(),
+            &resolved_ids,
+        )
+        .map_err(|e| AdapterError::internal(ALTER_SINK, e))?
+        {
+            Plan::CreateSink(plan) => plan,
+            _ => unreachable!("create sink plan is only valid response"),
+        };
+
+        let sink = Sink {
+            create_sql: plan.sink.create_sql,
+            from: plan.sink.from,
+            connection: plan.sink.connection,
+            envelope: plan.sink.envelope,
+            // The sink has already emitted its snapshot, if any.
+            with_snapshot: cur_sink.with_snapshot,
+            resolved_ids,
+            // Use the same cluster ID.
+            cluster_id: cur_sink.cluster_id,
+        };
+
+        let from_desc = from_entry
+            .desc(
+                &self
+                    .catalog()
+                    .resolve_full_name(from_entry.name(), from_entry.conn_id()),
+            )
+            .expect("sinks can only be built on items with descs")
+            .into_owned();
+        let connection = sink
+            .connection
+            .clone()
+            .into_inline_connection(self.catalog().state());
+
+        // Validate that the new relation is a persist collection we can export
+        // from at the sink's current frontier.
+        self.controller
+            .storage
+            .check_alter_export_from(id, from, &connection)
+            .map_err(|e| match e {
+                StorageError::IdentifierMissing(_) => AdapterError::Unstructured(anyhow!(
+                    "{} is a {}, which cannot be exported as a sink",
+                    from_entry.name().item,
+                    from_entry.item().typ(),
+                )),
+                e => AdapterError::Storage(e),
+            })?;
+
+        // Redefine sink.
+        let ops = vec![catalog::Op::UpdateItem {
+            id,
+            name: cur_entry.name().clone(),
+            to_item: CatalogItem::Sink(sink),
+        }];
+
+        self.catalog_transact(Some(session), ops).await?;
+
+        self.controller
+            .storage
+            .alter_export_from(id, from, from_desc, connection)
+            .await
+            .expect("altering export after txn must succeed");
+
+        Ok(())
+    }
+
     pub(super) async fn sequence_alter_connection(
         &mut self,
         ctx: ExecuteContext,
@@ -13,7 +13,7 @@ use mz_catalog::memory::objects::{ClusterConfig, ClusterVariant};
 use mz_catalog::LINKED_CLUSTER_REPLICA_NAME;
 use mz_compute_client::controller::ComputeReplicaConfig;
 use mz_controller::clusters::{ReplicaAllocation, ReplicaConfig, ReplicaLogging};
-use mz_controller_types::{ClusterId, DEFAULT_REPLICA_LOGGING_INTERVAL};
+use mz_controller_types::{ClusterId, ClusterPriority, DEFAULT_REPLICA_LOGGING_INTERVAL};
 use mz_repr::role_id::RoleId;
 use mz_repr::GlobalId;
 use mz_sql::catalog::CatalogCluster;
@@ -61,6 +61,9 @@ impl Coordinator {
             owner_id: *session.current_role_id(),
             config: ClusterConfig {
                 variant: ClusterVariant::Unmanaged,
+                priority: ClusterPriority::default(),
+                memory_quota: None,
+                resize: None,
             },
         });
         self.create_linked_cluster_replica_op(id, size, disk, ops, *session.current_role_id())
@@ -81,6 +84,7 @@ impl Coordinator {
         let location = mz_catalog::durable::ReplicaLocation::Managed {
             size: size.to_string(),
             availability_zone: None,
+            availability_zone_constraints: vec![],
             disk,
             billed_as: None,
             internal: false,
@@ -0,0 +1,304 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Recording of logins, failed logins, and logouts in the audit log.
+//!
+//! Writing to the audit log requires a durable catalog transaction. To keep session startup and
+//! termination from waiting on one, session events are buffered and periodically written to the
+//! audit log in a single transaction.
+//!
+//! Failed logins are recorded at most once per [`LOGIN_FAILURE_WINDOW`] for each client IP
+//! address and user, so that a client repeatedly failing to authenticate can't flood the audit
+//! log. Failures that are not recorded individually are aggregated into a single event once the
+//! window closes.
+
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+use std::time::Duration;
+
+use mz_audit_log::{EventDetails, EventType, LoginFailureV1};
+use mz_ore::now::EpochMillis;
+use mz_ore::task::spawn;
+use tokio::time::MissedTickBehavior;
+use tracing::warn;
+
+use crate::catalog;
+use crate::coord::{Coordinator, Message};
+
+/// How often buffered session events are written to the audit log.
+const SESSION_AUDIT_LOG_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The minimum time between two failed login events for the same client IP address and user.
+const LOGIN_FAILURE_WINDOW: Duration = Duration::from_secs(60);
+
+#[derive(Debug)]
+pub(crate) struct SessionAuditLog {
+    /// Session events that have not yet been written to the audit log.
+    pending_events: Vec<catalog::Op>,
+    /// The failed logins of each client IP address and user within the current window.
+    login_failures: BTreeMap<(Option<IpAddr>, String), LoginFailures>,
+}
+
+/// The failed logins of a client IP address and user within a [`LOGIN_FAILURE_WINDOW`].
+#[derive(Debug)]
+struct LoginFailures {
+    /// When the window started, i.e. when the first failed login was recorded.
+    started_at: EpochMillis,
+    /// The number of failed logins after the first, which were not recorded.
+    suppressed: u64,
+    /// The details of the most recent failed login.
+    last: LoginFailureV1,
+}
+
+impl SessionAuditLog {
+    pub(crate) fn new() -> Self {
+        Self {
+            pending_events: Vec::new(),
+            login_failures: BTreeMap::new(),
+        }
+    }
+
+    /// Buffers a failed login of `user` from `client_ip` at `now`, unless another failed login
+    /// of the same user from the same address was buffered within the current window.
+    ///
+    /// Returns whether the failed login was buffered.
+    fn record_login_failure(
+        &mut self,
+        now: EpochMillis,
+        user: String,
+        client_ip: Option<IpAddr>,
+        details: LoginFailureV1,
+    ) -> bool {
+        self.close_login_failure_windows(now);
+        match self.login_failures.entry((client_ip, user.clone())) {
+            Entry::Occupied(mut entry) => {
+                let failures = entry.get_mut();
+                failures.suppressed += 1;
+                failures.last = details;
+                false
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(LoginFailures {
+                    started_at: now,
+                    suppressed: 0,
+                    last: details.clone(),
+                });
+                self.pending_events.push(catalog::Op::LogSessionEvent {
+                    event_type: EventType::LoginFailure,
+                    user,
+                    details: EventDetails::LoginFailureV1(details),
+                });
+                true
+            }
+        }
+    }
+
+    /// Records an aggregate event for each window that has closed as of `now`, and forgets
+    /// about those windows.
+    fn close_login_failure_windows(&mut self, now: EpochMillis) {
+        let window = EpochMillis::try_from(LOGIN_FAILURE_WINDOW.as_millis())
+            .expect("window must fit into u64");
+        let closed: Vec<_> = self
+            .login_failures
+            .iter()
+            .filter(|(_, failures)| now.saturating_sub(failures.started_at) >= window)
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in closed {
+            let failures = self.login_failures.remove(&key).expect("known to exist");
+            if failures.suppressed > 0 {
+                let (_, user) = key;
+                let reason = format!(
+                    "{} further failed logins since {}; last reason: {}",
+                    failures.suppressed,
+                    mz_ore::now::to_datetime(failures.started_at),
+                    failures.last.reason,
+                );
+                self.pending_events.push(catalog::Op::LogSessionEvent {
+                    event_type: EventType::LoginFailure,
+                    user,
+                    details: EventDetails::LoginFailureV1(LoginFailureV1 {
+                        reason,
+                        ..failures.last
+                    }),
+                });
+            }
+        }
+    }
+}
+
+impl Coordinator {
+    pub(crate) fn spawn_session_audit_log_task(&self) {
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        spawn(|| "session_audit_log", async move {
+            let mut interval = tokio::time::interval(SESSION_AUDIT_LOG_FLUSH_INTERVAL);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            loop {
+                interval.tick().await;
+                let _ = internal_cmd_tx.send(Message::DrainSessionAuditLog);
+            }
+        });
+    }
+
+    /// Whether the `audit_log_session_events` system variable is enabled.
+    fn session_audit_log_enabled(&self) -> bool {
+        self.catalog()
+            .state()
+            .system_config()
+            .audit_log_session_events()
+    }
+
+    /// Buffers a login or logout of `user` to be recorded in the audit log, if the
+    /// `audit_log_session_events` system variable is enabled.
+    pub(crate) fn log_session_event(
+        &mut self,
+        event_type: EventType,
+        user: String,
+        details: EventDetails,
+    ) {
+        if !self.session_audit_log_enabled() {
+            return;
+        }
+        self.session_audit_log
+            .pending_events
+            .push(catalog::Op::LogSessionEvent {
+                event_type,
+                user,
+                details,
+            });
+    }
+
+    /// Buffers a failed login of `user` to be recorded in the audit log, if the
+    /// `audit_log_session_events` system variable is enabled.
+    ///
+    /// Only the first failed login of each client IP address and user within a
+    /// [`LOGIN_FAILURE_WINDOW`] is recorded individually.
+    pub(crate) fn log_login_failure(
+        &mut self,
+        user: String,
+        client_ip: Option<IpAddr>,
+        application_name: String,
+        reason: String,
+    ) {
+        if !self.session_audit_log_enabled() {
+            return;
+        }
+        let now = self.now();
+        let details = LoginFailureV1 {
+            client_ip: client_ip.map(|ip| ip.to_string()),
+            application_name,
+            reason,
+        };
+        let recorded = self
+            .session_audit_log
+            .record_login_failure(now, user, client_ip, details);
+        if !recorded {
+            self.metrics.suppressed_login_failure_events.inc();
+        }
+    }
+
+    /// Writes all buffered session events to the audit log in a single transaction.
+    ///
+    /// Failing to record the events does not affect any session, so errors are only logged.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(crate) async fn drain_session_audit_log(&mut self) {
+        let now = self.now();
+        self.session_audit_log.close_login_failure_windows(now);
+        let ops = std::mem::take(&mut self.session_audit_log.pending_events);
+        if ops.is_empty() {
+            return;
+        }
+        if let Err(err) = self.catalog_transact_conn(None, ops).await {
+            warn!("failed to record session events in the audit log: {err}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use mz_audit_log::{EventDetails, EventType, LoginFailureV1};
+
+    use super::{SessionAuditLog, LOGIN_FAILURE_WINDOW};
+    use crate::catalog;
+
+    fn failure(reason: &str) -> LoginFailureV1 {
+        LoginFailureV1 {
+            client_ip: Some("10.0.0.1".into()),
+            application_name: "app".into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Returns the user and reason of each buffered failed login.
+    fn drain(audit_log: &mut SessionAuditLog) -> Vec<(String, String)> {
+        std::mem::take(&mut audit_log.pending_events)
+            .into_iter()
+            .map(|op| match op {
+                catalog::Op::LogSessionEvent {
+                    event_type: EventType::LoginFailure,
+                    user,
+                    details: EventDetails::LoginFailureV1(details),
+                } => (user, details.reason),
+                op => panic!("unexpected op: {op:?}"),
+            })
+            .collect()
+    }
+
+    #[mz_ore::test]
+    fn test_login_failures_are_aggregated() {
+        let window = u64::try_from(LOGIN_FAILURE_WINDOW.as_millis()).unwrap();
+        let ip = Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)));
+        let other_ip = Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)));
+        let mut audit_log = SessionAuditLog::new();
+
+        // The first failure of each address and user is recorded.
+        assert!(audit_log.record_login_failure(0, "alice".into(), ip, failure("a")));
+        assert!(audit_log.record_login_failure(1, "alice".into(), other_ip, failure("b")));
+        assert!(audit_log.record_login_failure(2, "bob".into(), ip, failure("c")));
+        assert_eq!(
+            drain(&mut audit_log),
+            vec![
+                ("alice".to_string(), "a".to_string()),
+                ("alice".to_string(), "b".to_string()),
+                ("bob".to_string(), "c".to_string()),
+            ]
+        );
+
+        // Further failures within the window are not.
+        assert!(!audit_log.record_login_failure(3, "alice".into(), ip, failure("d")));
+        assert!(!audit_log.record_login_failure(4, "alice".into(), ip, failure("e")));
+        assert!(drain(&mut audit_log).is_empty());
+
+        // Once the window closes, the suppressed failures are recorded as one event, and the
+        // next failure is recorded again.
+        audit_log.close_login_failure_windows(window);
+        let events = drain(&mut audit_log);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].0, "alice");
+        assert!(
+            events[0].1.starts_with("2 further failed logins since"),
+            "{}",
+            events[0].1
+        );
+        assert!(events[0].1.ends_with("last reason: e"), "{}", events[0].1);
+        assert!(audit_log.record_login_failure(window + 1, "alice".into(), ip, failure("f")));
+        assert_eq!(
+            drain(&mut audit_log),
+            vec![("alice".to_string(), "f".to_string())]
+        );
+
+        // Windows without suppressed failures close without an event.
+        audit_log.close_login_failure_windows(window * 3);
+        assert!(drain(&mut audit_log).is_empty());
+        assert!(audit_log.login_failures.is_empty());
+    }
+}
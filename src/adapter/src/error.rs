@@ -12,6 +12,7 @@ use std::error::Error;
 use std::fmt;
 use std::num::TryFromIntError;
 
+use bytesize::ByteSize;
 use dec::TryFromDecimalError;
 use itertools::Itertools;
 use mz_compute_client::controller::error as compute_error;
@@ -98,6 +99,13 @@ pub enum AdapterError {
     BadItemInStorageCluster {
         cluster_name: String,
     },
+    /// The replicas of a cluster would be provisioned with more memory than
+    /// the cluster's memory quota allows.
+    ClusterMemoryQuotaExceeded {
+        cluster_name: String,
+        memory_quota: u64,
+        required: u64,
+    },
     /// Expression violated a column's constraint
     ConstraintViolation(NotNullViolation),
     /// Transaction cluster was dropped in the middle of a transaction.
@@ -138,8 +146,14 @@ pub enum AdapterError {
     },
     /// Result size of a query is too large.
     ResultSize(String),
+    /// The role is past its `VALID UNTIL` time.
+    RoleExpired(String),
     /// The specified feature is not permitted in safe mode.
     SafeModeViolation(String),
+    /// The service account is past its `VALID UNTIL` time.
+    ServiceAccountExpired(String),
+    /// The token presented for a service account is invalid.
+    ServiceAccountInvalidToken(String),
     /// Waiting on a query timed out.
     ///
     /// Note this differs slightly from PG's implementation/semantics.
@@ -150,6 +164,8 @@ pub enum AdapterError {
     IdleInTransactionSessionTimeout,
     /// The transaction is in single-subscribe mode.
     SubscribeOnlyTransaction,
+    /// The role has as many connections as its connection limit allows.
+    TooManyRoleConnections(String),
     /// An error occurred in the MIR stage of the optimizer.
     Transform(TransformError),
     /// A query depends on items which are not allowed to be referenced from the current cluster.
@@ -350,6 +366,10 @@ impl AdapterError {
             AdapterError::NoClusterReplicasAvailable(_) => {
                 Some("You can create cluster replicas using CREATE CLUSTER REPLICA".into())
             }
+            AdapterError::ClusterMemoryQuotaExceeded { .. } => Some(
+                "Use a smaller SIZE or REPLICATION FACTOR, or raise the cluster's MEMORY QUOTA."
+                    .into(),
+            ),
             AdapterError::UnmaterializableFunction(UnmaterializableFunc::CurrentTimestamp) => {
                 Some("Try using `mz_now()` here instead.".into())
             }
@@ -391,6 +411,7 @@ impl AdapterError {
             AdapterError::AbsurdSubscribeBounds { .. } => SqlState::DATA_EXCEPTION,
             AdapterError::AmbiguousSystemColumnReference => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::BadItemInStorageCluster { .. } => SqlState::FEATURE_NOT_SUPPORTED,
+            AdapterError::ClusterMemoryQuotaExceeded { .. } => SqlState::INSUFFICIENT_RESOURCES,
             AdapterError::Catalog(_) => SqlState::INTERNAL_ERROR,
             AdapterError::ChangedPlan => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::DuplicateCursor(_) => SqlState::DUPLICATE_CURSOR,
@@ -438,8 +459,12 @@ impl AdapterError {
             AdapterError::RelationOutsideTimeDomain { .. } => SqlState::INVALID_TRANSACTION_STATE,
             AdapterError::ResourceExhaustion { .. } => SqlState::INSUFFICIENT_RESOURCES,
             AdapterError::ResultSize(_) => SqlState::OUT_OF_MEMORY,
+            AdapterError::RoleExpired(_) => SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
             AdapterError::SafeModeViolation(_) => SqlState::INTERNAL_ERROR,
+            AdapterError::ServiceAccountExpired(_) => SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
+            AdapterError::ServiceAccountInvalidToken(_) => SqlState::INVALID_PASSWORD,
             AdapterError::SubscribeOnlyTransaction => SqlState::INVALID_TRANSACTION_STATE,
+            AdapterError::TooManyRoleConnections(_) => SqlState::TOO_MANY_CONNECTIONS,
             AdapterError::Transform(_) => SqlState::INTERNAL_ERROR,
             AdapterError::UnallowedOnCluster { .. } => {
                 SqlState::S_R_E_PROHIBITED_SQL_STATEMENT_ATTEMPTED
@@ -526,6 +551,17 @@ impl fmt::Display for AdapterError {
             AdapterError::BadItemInStorageCluster { .. } => f.write_str(
                 "cannot create this kind of item in a cluster that contains sources or sinks",
             ),
+            AdapterError::ClusterMemoryQuotaExceeded {
+                cluster_name,
+                memory_quota,
+                required,
+            } => write!(
+                f,
+                "cluster {} would require {} of memory, which exceeds its MEMORY QUOTA of {}",
+                cluster_name.quoted(),
+                ByteSize(*required).to_string_as(true),
+                ByteSize(*memory_quota).to_string_as(true),
+            ),
             AdapterError::InvalidClusterReplicaAz { az, expected: _ } => {
                 write!(f, "unknown cluster replica availability zone {az}",)
             }
@@ -613,12 +649,24 @@ impl fmt::Display for AdapterError {
                 )
             }
             AdapterError::ResultSize(e) => write!(f, "{e}"),
+            AdapterError::RoleExpired(role_name) => {
+                write!(f, "role {} has expired", role_name.quoted())
+            }
             AdapterError::SafeModeViolation(feature) => {
                 write!(f, "cannot create {} in safe mode", feature)
             }
+            AdapterError::ServiceAccountExpired(name) => {
+                write!(f, "service account {} has expired", name.quoted())
+            }
+            AdapterError::ServiceAccountInvalidToken(name) => {
+                write!(f, "invalid token for service account {}", name.quoted())
+            }
             AdapterError::SubscribeOnlyTransaction => {
                 f.write_str("SUBSCRIBE in transactions must be the only read statement")
             }
+            AdapterError::TooManyRoleConnections(role_name) => {
+                write!(f, "too many connections for role {}", role_name.quoted())
+            }
             AdapterError::Transform(e) => e.fmt(f),
             AdapterError::UncallableFunction { func, context } => {
                 write!(f, "cannot call {} in {}", func, context)
@@ -126,7 +126,8 @@ pub mod webhook;
 
 pub use crate::client::{Client, Handle, SessionClient};
 pub use crate::command::{
-    Canceled, ExecuteResponse, ExecuteResponseKind, RowsFuture, StartupResponse,
+    AuthenticateServiceAccountResponse, Canceled, ExecuteResponse, ExecuteResponseKind, RowsFuture,
+    StartupResponse,
 };
 pub use crate::coord::id_bundle::CollectionIdBundle;
 pub use crate::coord::peek::PeekResponseUnary;
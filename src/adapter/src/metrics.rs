@@ -13,7 +13,7 @@ use mz_ore::stats::{histogram_milliseconds_buckets, histogram_seconds_buckets};
 use mz_sql::ast::{AstInfo, Statement, StatementKind, SubscribeOutput};
 use mz_sql::session::user::User;
 use mz_sql_parser::ast::statement_kind_label_value;
-use prometheus::{HistogramVec, IntCounterVec, IntGaugeVec};
+use prometheus::{HistogramVec, IntCounter, IntCounterVec, IntGaugeVec};
 
 #[derive(Debug, Clone)]
 pub struct Metrics {
@@ -35,6 +35,10 @@ pub struct Metrics {
     pub optimization_notices: IntCounterVec,
     pub append_table_duration_seconds: HistogramVec,
     pub webhook_validation_reduce_failures: IntCounterVec,
+    pub purification_queue_depth: IntGaugeVec,
+    pub purification_queue_wait_seconds: HistogramVec,
+    pub active_purifications: IntGaugeVec,
+    pub suppressed_login_failure_events: IntCounter,
 }
 
 impl Metrics {
@@ -131,7 +135,27 @@ impl Metrics {
                 name: "mz_webhook_validation_reduce_failures",
                 help: "Count of how many times we've failed to reduce a webhook source's CHECK statement.",
                 var_labels: ["reason"],
-            ))
+            )),
+            purification_queue_depth: registry.register(metric!(
+                name: "mz_purification_queue_depth",
+                help: "The number of statements waiting to be purified.",
+                var_labels: ["priority"],
+            )),
+            purification_queue_wait_seconds: registry.register(metric!(
+                name: "mz_purification_queue_wait_seconds",
+                help: "The number of seconds statements waited to be purified.",
+                var_labels: ["priority"],
+                buckets: histogram_seconds_buckets(0.000_128, 32.0),
+            )),
+            active_purifications: registry.register(metric!(
+                name: "mz_active_purifications",
+                help: "The number of statements that are being purified.",
+                var_labels: ["priority"],
+            )),
+            suppressed_login_failure_events: registry.register(metric!(
+                name: "mz_suppressed_login_failure_events",
+                help: "The number of failed logins that were aggregated rather than recorded individually in the audit log.",
+            )),
         }
     }
 }
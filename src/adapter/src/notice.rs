@@ -50,6 +50,12 @@ pub enum AdapterNotice {
     ClusterDoesNotExist {
         name: String,
     },
+    ServiceAccountDoesNotExist {
+        name: String,
+    },
+    ClusterReplicaSizeDoesNotExist {
+        name: String,
+    },
     NoResolvableSearchPathSchema {
         search_path: Vec<String>,
     },
@@ -126,6 +132,9 @@ pub enum AdapterNotice {
         log_names: Vec<String>,
     },
     Welcome(String),
+    BackgroundPurificationStarted {
+        id: u64,
+    },
 }
 
 impl AdapterNotice {
@@ -149,6 +158,8 @@ impl AdapterNotice {
             AdapterNotice::ObjectAlreadyExists { .. } => Severity::Notice,
             AdapterNotice::DatabaseDoesNotExist { .. } => Severity::Notice,
             AdapterNotice::ClusterDoesNotExist { .. } => Severity::Notice,
+            AdapterNotice::ServiceAccountDoesNotExist { .. } => Severity::Notice,
+            AdapterNotice::ClusterReplicaSizeDoesNotExist { .. } => Severity::Notice,
             AdapterNotice::NoResolvableSearchPathSchema { .. } => Severity::Notice,
             AdapterNotice::ExistingTransactionInProgress => Severity::Warning,
             AdapterNotice::ExplicitTransactionControlInImplicitTransaction => Severity::Warning,
@@ -179,6 +190,8 @@ impl AdapterNotice {
             AdapterNotice::PlanNotice(notice) => match notice {
                 PlanNotice::ObjectDoesNotExist { .. } => Severity::Notice,
                 PlanNotice::UpsertSinkKeyNotEnforced { .. } => Severity::Warning,
+                PlanNotice::TlsCertificateExpiring { .. } => Severity::Warning,
+                PlanNotice::KafkaSinkTopicConfigMismatch { .. } => Severity::Warning,
             },
             AdapterNotice::UnknownSessionDatabase(_) => Severity::Notice,
             AdapterNotice::OptimizerNotice { .. } => Severity::Notice,
@@ -186,6 +199,7 @@ impl AdapterNotice {
             AdapterNotice::DroppedInUseIndex { .. } => Severity::Notice,
             AdapterNotice::PerReplicaLogRead { .. } => Severity::Notice,
             AdapterNotice::Welcome(_) => Severity::Notice,
+            AdapterNotice::BackgroundPurificationStarted { .. } => Severity::Notice,
         }
     }
 
@@ -229,6 +243,7 @@ impl AdapterNotice {
             ),
             AdapterNotice::OptimizerNotice { notice: _, hint } => Some(hint.clone()),
             AdapterNotice::DroppedInUseIndex(..) => Some("To free up the resources used by the index, recreate all the above-mentioned objects.".into()),
+            AdapterNotice::BackgroundPurificationStarted { id } => Some(format!("Check the progress of the purification in mz_internal.mz_background_purifications, or cancel it with mz_internal.mz_cancel_background_purification({id}).")),
             _ => None
         }
     }
@@ -242,6 +257,8 @@ impl AdapterNotice {
             AdapterNotice::ObjectAlreadyExists { .. } => SqlState::DUPLICATE_OBJECT,
             AdapterNotice::DatabaseDoesNotExist { .. } => SqlState::WARNING,
             AdapterNotice::ClusterDoesNotExist { .. } => SqlState::WARNING,
+            AdapterNotice::ServiceAccountDoesNotExist { .. } => SqlState::WARNING,
+            AdapterNotice::ClusterReplicaSizeDoesNotExist { .. } => SqlState::WARNING,
             AdapterNotice::NoResolvableSearchPathSchema { .. } => SqlState::WARNING,
             AdapterNotice::ExistingTransactionInProgress => SqlState::ACTIVE_SQL_TRANSACTION,
             AdapterNotice::ExplicitTransactionControlInImplicitTransaction => {
@@ -268,6 +285,8 @@ impl AdapterNotice {
             AdapterNotice::PlanNotice(plan) => match plan {
                 PlanNotice::ObjectDoesNotExist { .. } => SqlState::UNDEFINED_OBJECT,
                 PlanNotice::UpsertSinkKeyNotEnforced { .. } => SqlState::WARNING,
+                PlanNotice::TlsCertificateExpiring { .. } => SqlState::WARNING,
+                PlanNotice::KafkaSinkTopicConfigMismatch { .. } => SqlState::WARNING,
             },
             AdapterNotice::UnknownSessionDatabase(_) => SqlState::SUCCESSFUL_COMPLETION,
             AdapterNotice::OptimizerNotice { .. } => SqlState::SUCCESSFUL_COMPLETION,
@@ -275,6 +294,7 @@ impl AdapterNotice {
             AdapterNotice::WebhookSourceCreated { .. } => SqlState::WARNING,
             AdapterNotice::PerReplicaLogRead { .. } => SqlState::WARNING,
             AdapterNotice::Welcome(_) => SqlState::SUCCESSFUL_COMPLETION,
+            AdapterNotice::BackgroundPurificationStarted { .. } => SqlState::SUCCESSFUL_COMPLETION,
         }
     }
 }
@@ -303,6 +323,20 @@ impl fmt::Display for AdapterNotice {
             AdapterNotice::ClusterDoesNotExist { name } => {
                 write!(f, "cluster {} does not exist", name.quoted())
             }
+            AdapterNotice::ServiceAccountDoesNotExist { name } => {
+                write!(
+                    f,
+                    "service account {} does not exist, skipping",
+                    name.quoted()
+                )
+            }
+            AdapterNotice::ClusterReplicaSizeDoesNotExist { name } => {
+                write!(
+                    f,
+                    "cluster replica size {} does not exist, skipping",
+                    name.quoted()
+                )
+            }
             AdapterNotice::NoResolvableSearchPathSchema { search_path } => {
                 write!(
                     f,
@@ -426,6 +460,9 @@ impl fmt::Display for AdapterNotice {
                 write!(f, "Queried introspection relations: {}. Unlike other objects in Materialize, results from querying these objects depend on the current values of the `cluster` and `cluster_replica` session variables.", log_names.join(", "))
             }
             AdapterNotice::Welcome(message) => message.fmt(f),
+            AdapterNotice::BackgroundPurificationStarted { id } => {
+                write!(f, "source will be created by background purification {id}")
+            }
         }
     }
 }
@@ -14,6 +14,7 @@
 use std::collections::btree_map::Entry;
 use std::collections::{BTreeMap, BTreeSet};
 use std::mem;
+use std::net::IpAddr;
 use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
@@ -35,7 +36,7 @@ pub use mz_sql::session::vars::{
     EndTransactionAction, SessionVars, DEFAULT_DATABASE_NAME, SERVER_MAJOR_VERSION,
     SERVER_MINOR_VERSION, SERVER_PATCH_VERSION,
 };
-use mz_sql::session::vars::{IsolationLevel, VarInput};
+use mz_sql::session::vars::{IsolationLevel, VarInput, DATABASE_VAR_NAME};
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::TransactionIsolationLevel;
 use mz_storage_types::sources::Timeline;
@@ -64,6 +65,11 @@ pub struct Session<T = mz_repr::Timestamp> {
     /// A globally unique identifier for the session. Not to be confused
     /// with `conn_id`, which may be reused.
     uuid: Uuid,
+    /// The IP address of the client, if known.
+    client_ip: Option<IpAddr>,
+    /// The scopes that the session is limited to, if it belongs to a service account whose token
+    /// is limited to scopes.
+    service_account_scopes: BTreeSet<String>,
     prepared_statements: BTreeMap<String, PreparedStatement>,
     portals: BTreeMap<String, Portal>,
     transaction: TransactionStatus<T>,
@@ -162,6 +168,39 @@ impl<T: TimestampManipulation> Session<T> {
         dummy
     }
 
+    /// Creates a new session that executes a statement in the background on
+    /// behalf of this session, e.g. `CREATE SOURCE ... IN BACKGROUND`.
+    ///
+    /// The new session shares this session's connection, user, and role
+    /// metadata, and resolves names against the same database, search path, and
+    /// cluster. It has its own transaction and notice state.
+    pub(crate) fn fork_for_background(&self) -> Session<T> {
+        let vars = self.vars();
+        let mut session =
+            Self::new_internal(vars.build_info(), self.conn_id.clone(), vars.user().clone());
+        session.role_metadata = self.role_metadata.clone();
+
+        let search_path: Vec<_> = vars
+            .search_path()
+            .iter()
+            .map(|schema| schema.as_str().to_string())
+            .collect();
+        let background_vars = session.vars_mut();
+        background_vars
+            .set(
+                None,
+                DATABASE_VAR_NAME.as_str(),
+                VarInput::Flat(vars.database()),
+                false,
+            )
+            .expect("copying database succeeds");
+        background_vars
+            .set(None, "search_path", VarInput::SqlSet(&search_path), false)
+            .expect("copying search_path succeeds");
+        background_vars.set_cluster(vars.cluster().to_string());
+        session
+    }
+
     fn new_internal(
         build_info: &'static BuildInfo,
         conn_id: ConnectionId,
@@ -176,6 +215,8 @@ impl<T: TimestampManipulation> Session<T> {
         Session {
             conn_id,
             uuid: Uuid::new_v4(),
+            client_ip: None,
+            service_account_scopes: BTreeSet::new(),
             transaction: TransactionStatus::Default,
             pcx: None,
             prepared_statements: BTreeMap::new(),
@@ -201,6 +242,27 @@ impl<T: TimestampManipulation> Session<T> {
         self.secret_key
     }
 
+    /// Returns the IP address of the client, if known.
+    pub fn client_ip(&self) -> Option<IpAddr> {
+        self.client_ip
+    }
+
+    /// Sets the IP address of the client.
+    pub fn set_client_ip(&mut self, client_ip: Option<IpAddr>) {
+        self.client_ip = client_ip;
+    }
+
+    /// Returns the scopes that the session is limited to. An empty set places no restriction on
+    /// the session.
+    pub fn service_account_scopes(&self) -> &BTreeSet<String> {
+        &self.service_account_scopes
+    }
+
+    /// Limits the session to the scopes of the service account that it authenticated as.
+    pub fn set_service_account_scopes(&mut self, scopes: BTreeSet<String>) {
+        self.service_account_scopes = scopes;
+    }
+
     /// Returns the current transaction's PlanContext. Panics if there is not a
     /// current transaction.
     pub fn pcx(&self) -> &PlanContext {
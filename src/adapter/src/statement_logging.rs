@@ -173,8 +173,10 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::CreatedRole
             | ExecuteResponse::CreatedCluster
             | ExecuteResponse::CreatedClusterReplica
+            | ExecuteResponse::CreatedClusterReplicaSize
             | ExecuteResponse::CreatedIndex
             | ExecuteResponse::CreatedSecret
+            | ExecuteResponse::CreatedServiceAccount
             | ExecuteResponse::CreatedSink
             | ExecuteResponse::CreatedSource
             | ExecuteResponse::CreatedTable
@@ -187,8 +189,10 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::Deleted(_)
             | ExecuteResponse::DiscardedTemp
             | ExecuteResponse::DiscardedAll
+            | ExecuteResponse::DroppedClusterReplicaSize
             | ExecuteResponse::DroppedObject(_)
             | ExecuteResponse::DroppedOwned
+            | ExecuteResponse::DroppedServiceAccount
             | ExecuteResponse::EmptyQuery
             | ExecuteResponse::GrantedPrivilege
             | ExecuteResponse::GrantedRole
@@ -202,6 +206,7 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::StartedTransaction
             | ExecuteResponse::TransactionCommitted { .. }
             | ExecuteResponse::TransactionRolledBack { .. }
+            | ExecuteResponse::UndroppedObject(_)
             | ExecuteResponse::Updated(_)
             | ExecuteResponse::ValidatedConnection { .. } => {
                 StatementEndedExecutionReason::Success {
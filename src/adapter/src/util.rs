@@ -10,7 +10,8 @@
 use std::fmt::Debug;
 
 use mz_compute_client::controller::error::{
-    CollectionUpdateError, DataflowCreationError, InstanceMissing, PeekError, SubscribeTargetError,
+    CollectionUpdateError, DataflowCreationError, InstanceMissing, PeekError, ReplicaDrainError,
+    SubscribeTargetError,
 };
 use mz_controller_types::ClusterId;
 use mz_ore::tracing::OpenTelemetryContext;
@@ -378,6 +379,14 @@ impl ShouldHalt for PeekError {
     }
 }
 
+impl ShouldHalt for ReplicaDrainError {
+    fn should_halt(&self) -> bool {
+        match self {
+            ReplicaDrainError::InstanceMissing(_) | ReplicaDrainError::ReplicaMissing(_) => false,
+        }
+    }
+}
+
 impl ShouldHalt for SubscribeTargetError {
     fn should_halt(&self) -> bool {
         match self {
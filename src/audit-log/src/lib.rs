@@ -140,6 +140,27 @@ impl VersionedEvent {
             VersionedEvent::V1(ev) => ev.id,
         }
     }
+
+    /// Returns the time that the event occurred at.
+    pub fn occurred_at(&self) -> EpochMillis {
+        match self {
+            VersionedEvent::V1(ev) => ev.occurred_at,
+        }
+    }
+
+    /// Returns the user that caused the event, if any.
+    pub fn user(&self) -> Option<&str> {
+        match self {
+            VersionedEvent::V1(ev) => ev.user.as_deref(),
+        }
+    }
+
+    /// Returns the ID of the object that the event is about, if any.
+    pub fn object_id(&self) -> Option<&str> {
+        match self {
+            VersionedEvent::V1(ev) => ev.details.object_id(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
@@ -150,6 +171,9 @@ pub enum EventType {
     Alter,
     Grant,
     Revoke,
+    Login,
+    LoginFailure,
+    Logout,
 }
 
 impl EventType {
@@ -160,6 +184,9 @@ impl EventType {
             EventType::Alter => "Altered",
             EventType::Grant => "Granted",
             EventType::Revoke => "Revoked",
+            EventType::Login => "Logged In",
+            EventType::LoginFailure => "Failed Login",
+            EventType::Logout => "Logged Out",
         }
     }
 }
@@ -179,6 +206,8 @@ pub enum ObjectType {
     Role,
     Secret,
     Schema,
+    ServiceAccount,
+    Session,
     Sink,
     Source,
     System,
@@ -200,6 +229,8 @@ impl ObjectType {
             ObjectType::Role => "Role",
             ObjectType::Schema => "Schema",
             ObjectType::Secret => "Secret",
+            ObjectType::ServiceAccount => "Service Account",
+            ObjectType::Session => "Session",
             ObjectType::Sink => "Sink",
             ObjectType::Source => "Source",
             ObjectType::System => "System",
@@ -238,6 +269,11 @@ pub enum EventDetails {
     SchemaV2(SchemaV2),
     UpdateItemV1(UpdateItemV1),
     RenameSchemaV1(RenameSchemaV1),
+    RenameDatabaseV1(RenameDatabaseV1),
+    SessionV1(SessionV1),
+    LoginFailureV1(LoginFailureV1),
+    ServiceAccountV1(ServiceAccountV1),
+    ScheduledClusterChangeV1(ScheduledClusterChangeV1),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
@@ -417,6 +453,13 @@ pub struct RenameSchemaV1 {
     pub new_name: String,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct RenameDatabaseV1 {
+    pub id: String,
+    pub old_name: String,
+    pub new_name: String,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
 pub struct UpdateItemV1 {
     pub id: String,
@@ -424,7 +467,80 @@ pub struct UpdateItemV1 {
     pub name: FullNameV1,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct SessionV1 {
+    pub session_id: String,
+    pub connection_id: String,
+    pub client_ip: Option<String>,
+    pub application_name: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct LoginFailureV1 {
+    pub client_ip: Option<String>,
+    pub application_name: String,
+    pub reason: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct ServiceAccountV1 {
+    pub id: String,
+    pub name: String,
+    pub role_id: String,
+    pub token_secret_id: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<EpochMillis>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct ScheduledClusterChangeV1 {
+    pub cluster_id: String,
+    pub cluster_name: String,
+    pub scheduled_at: String,
+    pub old_size: String,
+    pub new_size: String,
+    pub old_replication_factor: u32,
+    pub new_replication_factor: u32,
+}
+
 impl EventDetails {
+    /// Returns the ID of the object that the event is about, if any.
+    ///
+    /// Cluster replica events are about the replica, and role membership events are about the
+    /// role that membership is granted in. Default privileges and sessions are not objects, so
+    /// their events have no object ID.
+    pub fn object_id(&self) -> Option<&str> {
+        match self {
+            EventDetails::CreateClusterReplicaV1(v) => v.replica_id.as_deref(),
+            EventDetails::DropClusterReplicaV1(v) => v.replica_id.as_deref(),
+            EventDetails::RenameClusterReplicaV1(v) => Some(&v.replica_id),
+            EventDetails::CreateSourceSinkV1(v) => Some(&v.id),
+            EventDetails::CreateSourceSinkV2(v) => Some(&v.id),
+            EventDetails::AlterSetClusterV1(v) => Some(&v.id),
+            EventDetails::AlterSourceSinkV1(v) => Some(&v.id),
+            EventDetails::GrantRoleV1(v) => Some(&v.role_id),
+            EventDetails::GrantRoleV2(v) => Some(&v.role_id),
+            EventDetails::RevokeRoleV1(v) => Some(&v.role_id),
+            EventDetails::RevokeRoleV2(v) => Some(&v.role_id),
+            EventDetails::UpdatePrivilegeV1(v) => Some(&v.object_id),
+            EventDetails::AlterDefaultPrivilegeV1(_) => None,
+            EventDetails::UpdateOwnerV1(v) => Some(&v.object_id),
+            EventDetails::IdFullNameV1(v) => Some(&v.id),
+            EventDetails::RenameClusterV1(v) => Some(&v.id),
+            EventDetails::RenameItemV1(v) => Some(&v.id),
+            EventDetails::IdNameV1(v) => Some(&v.id),
+            EventDetails::SchemaV1(v) => Some(&v.id),
+            EventDetails::SchemaV2(v) => Some(&v.id),
+            EventDetails::UpdateItemV1(v) => Some(&v.id),
+            EventDetails::RenameSchemaV1(v) => Some(&v.id),
+            EventDetails::RenameDatabaseV1(v) => Some(&v.id),
+            EventDetails::SessionV1(_) => None,
+            EventDetails::LoginFailureV1(_) => None,
+            EventDetails::ServiceAccountV1(v) => Some(&v.id),
+            EventDetails::ScheduledClusterChangeV1(v) => Some(&v.cluster_id),
+        }
+    }
+
     pub fn as_json(&self) -> serde_json::Value {
         match self {
             EventDetails::CreateClusterReplicaV1(v) => {
@@ -443,6 +559,7 @@ impl EventDetails {
             EventDetails::SchemaV1(v) => serde_json::to_value(v).expect("must serialize"),
             EventDetails::SchemaV2(v) => serde_json::to_value(v).expect("must serialize"),
             EventDetails::RenameSchemaV1(v) => serde_json::to_value(v).expect("must serialize"),
+            EventDetails::RenameDatabaseV1(v) => serde_json::to_value(v).expect("must serialize"),
             EventDetails::CreateSourceSinkV1(v) => serde_json::to_value(v).expect("must serialize"),
             EventDetails::CreateSourceSinkV2(v) => serde_json::to_value(v).expect("must serialize"),
             EventDetails::AlterSourceSinkV1(v) => serde_json::to_value(v).expect("must serialize"),
@@ -457,6 +574,12 @@ impl EventDetails {
             }
             EventDetails::UpdateOwnerV1(v) => serde_json::to_value(v).expect("must serialize"),
             EventDetails::UpdateItemV1(v) => serde_json::to_value(v).expect("must serialize"),
+            EventDetails::SessionV1(v) => serde_json::to_value(v).expect("must serialize"),
+            EventDetails::LoginFailureV1(v) => serde_json::to_value(v).expect("must serialize"),
+            EventDetails::ServiceAccountV1(v) => serde_json::to_value(v).expect("must serialize"),
+            EventDetails::ScheduledClusterChangeV1(v) => {
+                serde_json::to_value(v).expect("must serialize")
+            }
         }
     }
 }
@@ -93,11 +93,12 @@ use mz_adapter::catalog::{Catalog, ClusterReplicaSizeMap, StateConfig};
 use mz_build_info::{build_info, BuildInfo};
 use mz_catalog::durable::debug::{
     AuditLogCollection, ClusterCollection, ClusterIntrospectionSourceIndexCollection,
-    ClusterReplicaCollection, Collection, CollectionTrace, CollectionType, CommentCollection,
-    ConfigCollection, DatabaseCollection, DebugCatalogState, DefaultPrivilegeCollection,
-    IdAllocatorCollection, ItemCollection, RoleCollection, SchemaCollection, SettingCollection,
+    ClusterReplicaCollection, ClusterReplicaSizeCollection, Collection, CollectionTrace,
+    CollectionType, ColumnMaskCollection, CommentCollection, ConfigCollection, DatabaseCollection,
+    DebugCatalogState, DefaultPrivilegeCollection, DependencyCollection, IdAllocatorCollection,
+    ItemCollection, RoleCollection, SchemaCollection, ServiceAccountCollection, SettingCollection,
     StorageUsageCollection, SystemConfigurationCollection, SystemItemMappingCollection,
-    SystemPrivilegeCollection, TimestampCollection, Trace,
+    SystemPrivilegeCollection, TagCollection, TimestampCollection, Trace, TrashedItemCollection,
 };
 use mz_catalog::durable::{
     persist_backed_catalog_state, stash_backed_catalog_state, BootstrapArgs,
@@ -282,6 +283,8 @@ macro_rules! for_collection {
     ($collection_type:expr, $fn:ident $(, $arg:expr)*) => {
         match $collection_type {
             CollectionType::AuditLog => $fn::<AuditLogCollection>($($arg),*).await?,
+            CollectionType::ClusterReplicaSizes => $fn::<ClusterReplicaSizeCollection>($($arg),*).await?,
+            CollectionType::ColumnMasks => $fn::<ColumnMaskCollection>($($arg),*).await?,
             CollectionType::ComputeInstance => $fn::<ClusterCollection>($($arg),*).await?,
             CollectionType::ComputeIntrospectionSourceIndex => $fn::<ClusterIntrospectionSourceIndexCollection>($($arg),*).await?,
             CollectionType::ComputeReplicas => $fn::<ClusterReplicaCollection>($($arg),*).await?,
@@ -289,16 +292,20 @@ macro_rules! for_collection {
             CollectionType::Config => $fn::<ConfigCollection>($($arg),*).await?,
             CollectionType::Database => $fn::<DatabaseCollection>($($arg),*).await?,
             CollectionType::DefaultPrivileges => $fn::<DefaultPrivilegeCollection>($($arg),*).await?,
+            CollectionType::Dependencies => $fn::<DependencyCollection>($($arg),*).await?,
             CollectionType::IdAlloc => $fn::<IdAllocatorCollection>($($arg),*).await?,
             CollectionType::Item => $fn::<ItemCollection>($($arg),*).await?,
             CollectionType::Role => $fn::<RoleCollection>($($arg),*).await?,
             CollectionType::Schema => $fn::<SchemaCollection>($($arg),*).await?,
+            CollectionType::ServiceAccounts => $fn::<ServiceAccountCollection>($($arg),*).await?,
             CollectionType::Setting => $fn::<SettingCollection>($($arg),*).await?,
             CollectionType::StorageUsage => $fn::<StorageUsageCollection>($($arg),*).await?,
             CollectionType::SystemConfiguration => $fn::<SystemConfigurationCollection>($($arg),*).await?,
             CollectionType::SystemGidMapping => $fn::<SystemItemMappingCollection>($($arg),*).await?,
             CollectionType::SystemPrivileges => $fn::<SystemPrivilegeCollection>($($arg),*).await?,
+            CollectionType::Tags => $fn::<TagCollection>($($arg),*).await?,
             CollectionType::Timestamp => $fn::<TimestampCollection>($($arg),*).await?,
+            CollectionType::TrashedItems => $fn::<TrashedItemCollection>($($arg),*).await?,
         }
     };
 }
@@ -389,40 +396,52 @@ async fn dump(
         clusters,
         introspection_sources,
         cluster_replicas,
+        cluster_replica_sizes,
+        column_masks,
         comments,
         configs,
         databases,
         default_privileges,
+        dependencies,
         id_allocator,
         items,
         roles,
         schemas,
+        service_accounts,
         settings,
         storage_usage,
         system_object_mappings,
         system_configurations,
         system_privileges,
+        tags,
         timestamps,
+        trashed_items,
     } = openable_state.trace().await?;
 
     dump_col(&mut data, audit_log);
     dump_col(&mut data, clusters);
     dump_col(&mut data, introspection_sources);
     dump_col(&mut data, cluster_replicas);
+    dump_col(&mut data, cluster_replica_sizes);
+    dump_col(&mut data, column_masks);
     dump_col(&mut data, comments);
     dump_col(&mut data, configs);
     dump_col(&mut data, databases);
     dump_col(&mut data, default_privileges);
+    dump_col(&mut data, dependencies);
     dump_col(&mut data, id_allocator);
     dump_col(&mut data, items);
     dump_col(&mut data, roles);
     dump_col(&mut data, schemas);
+    dump_col(&mut data, service_accounts);
     dump_col(&mut data, settings);
     dump_col(&mut data, storage_usage);
     dump_col(&mut data, system_configurations);
     dump_col(&mut data, system_object_mappings);
     dump_col(&mut data, system_privileges);
+    dump_col(&mut data, tags);
     dump_col(&mut data, timestamps);
+    dump_col(&mut data, trashed_items);
 
     writeln!(&mut target, "{data:#?}")?;
     Ok(())
@@ -217,6 +217,7 @@ fn main() -> anyhow::Result<()> {
         .enum_attribute("ResolvedDatabaseSpecifier.value", ATTR)
         .enum_attribute("CommentKey.object", ATTR)
         .enum_attribute("CommentKey.sub_component", ATTR)
+        .enum_attribute("TagKey.object", ATTR)
         .enum_attribute("ResolvedDatabaseSpecifier.spec", ATTR)
         .enum_attribute("SchemaSpecifier.spec", ATTR)
         .enum_attribute("RoleVars.Entry.val", ATTR)
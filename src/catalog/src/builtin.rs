@@ -1901,7 +1901,8 @@ pub static MZ_COMPUTE_HYDRATION_STATUS: Lazy<BuiltinSource> = Lazy::new(|| Built
     desc: RelationDesc::empty()
         .with_column("object_id", ScalarType::String.nullable(false))
         .with_column("replica_id", ScalarType::String.nullable(false))
-        .with_column("hydrated", ScalarType::Bool.nullable(false)),
+        .with_column("hydrated", ScalarType::Bool.nullable(false))
+        .with_column("hydration_time", ScalarType::Interval.nullable(true)),
     is_retained_metrics_object: false,
     sensitivity: DataSensitivity::Public,
 });
@@ -2204,7 +2205,11 @@ pub static MZ_ROLES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         .with_column("id", ScalarType::String.nullable(false))
         .with_column("oid", ScalarType::Oid.nullable(false))
         .with_column("name", ScalarType::String.nullable(false))
-        .with_column("inherit", ScalarType::Bool.nullable(false)),
+        .with_column("inherit", ScalarType::Bool.nullable(false))
+        .with_column("connection_limit", ScalarType::Int32.nullable(true))
+        .with_column("statement_timeout", ScalarType::Interval.nullable(true))
+        .with_column("valid_until", ScalarType::TimestampTz.nullable(true))
+        .with_column("has_password", ScalarType::Bool.nullable(false)),
     is_retained_metrics_object: false,
     sensitivity: DataSensitivity::Public,
 });
@@ -2600,6 +2605,21 @@ pub static MZ_STORAGE_USAGE_BY_SHARD: Lazy<BuiltinTable> = Lazy::new(|| BuiltinT
     sensitivity: DataSensitivity::Public,
 });
 
+pub static MZ_STORAGE_USAGE_BY_SCHEMA: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_storage_usage_by_schema",
+    schema: MZ_INTERNAL_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("database_id", ScalarType::String.nullable(true))
+        .with_column("schema_id", ScalarType::String.nullable(false))
+        .with_column("size_bytes", ScalarType::UInt64.nullable(false))
+        .with_column(
+            "collection_timestamp",
+            ScalarType::TimestampTz { precision: None }.nullable(false),
+        ),
+    is_retained_metrics_object: false,
+    sensitivity: DataSensitivity::Public,
+});
+
 pub static MZ_EGRESS_IPS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     name: "mz_egress_ips",
     schema: MZ_CATALOG_SCHEMA,
@@ -2692,6 +2712,27 @@ pub static MZ_SUBSCRIPTIONS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     sensitivity: DataSensitivity::Public,
 });
 
+pub static MZ_BACKGROUND_PURIFICATIONS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_background_purifications",
+    schema: MZ_INTERNAL_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("id", ScalarType::UInt64.nullable(false))
+        .with_column("session_id", ScalarType::UInt32.nullable(false))
+        .with_column("redacted_sql", ScalarType::String.nullable(false))
+        .with_column("status", ScalarType::String.nullable(false))
+        .with_column("error", ScalarType::String.nullable(true))
+        .with_column(
+            "started_at",
+            ScalarType::TimestampTz { precision: None }.nullable(false),
+        )
+        .with_column(
+            "finished_at",
+            ScalarType::TimestampTz { precision: None }.nullable(true),
+        ),
+    is_retained_metrics_object: false,
+    sensitivity: DataSensitivity::Public,
+});
+
 pub static MZ_SESSIONS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     name: "mz_sessions",
     schema: MZ_INTERNAL_SCHEMA,
@@ -2740,6 +2781,42 @@ pub static MZ_COMMENTS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     sensitivity: DataSensitivity::Public,
 });
 
+pub static MZ_OBJECT_TAGS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_object_tags",
+    schema: MZ_INTERNAL_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("id", ScalarType::String.nullable(false))
+        .with_column("object_type", ScalarType::String.nullable(false))
+        .with_column("name", ScalarType::String.nullable(false))
+        .with_column("value", ScalarType::String.nullable(false)),
+    is_retained_metrics_object: false,
+    sensitivity: DataSensitivity::Public,
+});
+
+pub static MZ_SERVICE_ACCOUNTS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_service_accounts",
+    schema: MZ_INTERNAL_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("id", ScalarType::UInt64.nullable(false))
+        .with_column("name", ScalarType::String.nullable(false))
+        .with_column("role_id", ScalarType::String.nullable(false))
+        .with_column("token_secret_id", ScalarType::String.nullable(false))
+        .with_column(
+            "scopes",
+            ScalarType::List {
+                element_type: Box::new(ScalarType::String),
+                custom_id: None,
+            }
+            .nullable(false),
+        )
+        .with_column(
+            "expires_at",
+            ScalarType::TimestampTz { precision: None }.nullable(true),
+        ),
+    is_retained_metrics_object: false,
+    sensitivity: DataSensitivity::Public,
+});
+
 pub static MZ_WEBHOOKS_SOURCES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     name: "mz_webhook_sources",
     schema: MZ_INTERNAL_SCHEMA,
@@ -2813,6 +2890,20 @@ GROUP BY object_id, collection_timestamp",
     sensitivity: DataSensitivity::Public,
 });
 
+pub const MZ_STORAGE_USAGE_BY_DATABASE: BuiltinView = BuiltinView {
+    name: "mz_storage_usage_by_database",
+    schema: MZ_INTERNAL_SCHEMA,
+    column_defs: Some("database_id, size_bytes, collection_timestamp"),
+    sql: "
+SELECT
+    database_id,
+    sum(size_bytes)::uint8,
+    collection_timestamp
+FROM mz_internal.mz_storage_usage_by_schema
+GROUP BY database_id, collection_timestamp",
+    sensitivity: DataSensitivity::Public,
+};
+
 pub const MZ_RELATIONS: BuiltinView = BuiltinView {
     name: "mz_relations",
     schema: MZ_CATALOG_SCHEMA,
@@ -4932,14 +5023,17 @@ SELECT
     NULL::pg_catalog.bool AS rolcanlogin,
     -- MZ doesn't support replication in the same way Postgres does
     false AS rolreplication,
-    -- MZ doesn't how row level security
+    -- MZ doesn't support row level security
     false AS rolbypassrls,
-    -- MZ doesn't have a connection limit
-    -1 AS rolconnlimit,
-    -- MZ doesn't have role passwords
-    NULL::pg_catalog.text AS rolpassword,
-    -- MZ doesn't have role passwords
-    NULL::pg_catalog.timestamptz AS rolvaliduntil
+    -- As in PostgreSQL, -1 means that the role's connections are not limited.
+    COALESCE(r.connection_limit, -1) AS rolconnlimit,
+    -- Unlike PostgreSQL, pg_authid is readable by every role, so the password verifier is masked
+    -- and only shows whether the role has a password.
+    CASE
+        WHEN r.has_password THEN '********'::pg_catalog.text
+        ELSE NULL::pg_catalog.text
+    END AS rolpassword,
+    r.valid_until AS rolvaliduntil
 FROM mz_catalog.mz_roles r",
     sensitivity: DataSensitivity::Public,
 };
@@ -5388,6 +5482,82 @@ WHERE
     sensitivity: DataSensitivity::Public,
 };
 
+pub const MZ_SHOW_EFFECTIVE_PRIVILEGES: BuiltinView = BuiltinView {
+    name: "mz_show_effective_privileges",
+    schema: MZ_INTERNAL_SCHEMA,
+    column_defs: None,
+    sql: r#"
+WITH MUTUALLY RECURSIVE
+    -- Each user role paired with itself and every role it is a direct or
+    -- indirect member of.
+    role_closure (role_id text, member_of text) AS (
+        SELECT id, id FROM mz_catalog.mz_roles WHERE id NOT LIKE 's%'
+        UNION
+        SELECT role_closure.role_id, role_members.role_id
+        FROM role_closure
+        JOIN mz_catalog.mz_role_members role_members ON role_closure.member_of = role_members.member
+    ),
+    -- Each user role paired with the name of every grantee whose privileges
+    -- it holds.
+    grantees (role_id text, grantee text) AS (
+        SELECT role_closure.role_id, member_of.name
+        FROM role_closure
+        JOIN mz_catalog.mz_roles member_of ON role_closure.member_of = member_of.id
+        UNION ALL
+        SELECT id, 'PUBLIC'
+        FROM mz_catalog.mz_roles
+        WHERE id NOT LIKE 's%'
+    )
+SELECT
+    roles.name AS role,
+    privileges.grantor,
+    privileges.grantee,
+    privileges.database,
+    privileges.schema,
+    privileges.name,
+    privileges.object_type,
+    privileges.privilege_type,
+    'grant' AS source
+FROM grantees
+JOIN mz_catalog.mz_roles roles ON grantees.role_id = roles.id
+JOIN mz_internal.mz_show_all_privileges privileges ON privileges.grantee = grantees.grantee
+UNION ALL
+-- Default privileges are granted on objects that are created in the future,
+-- so they don't name an object.
+SELECT
+    roles.name AS role,
+    defaults.object_owner AS grantor,
+    defaults.grantee,
+    defaults.database,
+    defaults.schema,
+    NULL::pg_catalog.text AS name,
+    defaults.object_type,
+    defaults.privilege_type,
+    'default' AS source
+FROM grantees
+JOIN mz_catalog.mz_roles roles ON grantees.role_id = roles.id
+JOIN mz_internal.mz_show_default_privileges defaults ON defaults.grantee = grantees.grantee
+UNION ALL
+-- Superusers hold every privilege on every object. We determine superuser
+-- status each time a role logs in, so it's only known for mz_system, which is
+-- always a superuser, and for the current role.
+SELECT
+    roles.name AS role,
+    NULL::pg_catalog.text AS grantor,
+    roles.name AS grantee,
+    NULL::pg_catalog.text AS database,
+    NULL::pg_catalog.text AS schema,
+    NULL::pg_catalog.text AS name,
+    'system' AS object_type,
+    'SUPERUSER' AS privilege_type,
+    'superuser' AS source
+FROM mz_catalog.mz_roles roles
+WHERE
+    roles.name = 'mz_system'
+    OR (roles.name = current_role AND mz_catalog.mz_is_superuser())"#,
+    sensitivity: DataSensitivity::Public,
+};
+
 pub const MZ_SHOW_DEFAULT_PRIVILEGES: BuiltinView = BuiltinView {
     name: "mz_show_default_privileges",
     schema: MZ_INTERNAL_SCHEMA,
@@ -6107,13 +6277,17 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Table(&MZ_INTERNAL_CLUSTER_REPLICAS),
         Builtin::Table(&MZ_AUDIT_EVENTS),
         Builtin::Table(&MZ_STORAGE_USAGE_BY_SHARD),
+        Builtin::Table(&MZ_STORAGE_USAGE_BY_SCHEMA),
         Builtin::Table(&MZ_EGRESS_IPS),
         Builtin::Table(&MZ_AWS_PRIVATELINK_CONNECTIONS),
         Builtin::Table(&MZ_SUBSCRIPTIONS),
+        Builtin::Table(&MZ_BACKGROUND_PURIFICATIONS),
         Builtin::Table(&MZ_SESSIONS),
         Builtin::Table(&MZ_DEFAULT_PRIVILEGES),
         Builtin::Table(&MZ_SYSTEM_PRIVILEGES),
         Builtin::Table(&MZ_COMMENTS),
+        Builtin::Table(&MZ_OBJECT_TAGS),
+        Builtin::Table(&MZ_SERVICE_ACCOUNTS),
         Builtin::Table(&MZ_WEBHOOKS_SOURCES),
         Builtin::View(&MZ_RELATIONS),
         Builtin::View(&MZ_OBJECTS),
@@ -6235,6 +6409,7 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::View(&MZ_SHOW_MY_OBJECT_PRIVILEGES),
         Builtin::View(&MZ_SHOW_ALL_PRIVILEGES),
         Builtin::View(&MZ_SHOW_ALL_MY_PRIVILEGES),
+        Builtin::View(&MZ_SHOW_EFFECTIVE_PRIVILEGES),
         Builtin::View(&MZ_SHOW_DEFAULT_PRIVILEGES),
         Builtin::View(&MZ_SHOW_MY_DEFAULT_PRIVILEGES),
         Builtin::Source(&MZ_SINK_STATUS_HISTORY),
@@ -6253,6 +6428,7 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Source(&MZ_SOURCE_STATISTICS),
         Builtin::Source(&MZ_SINK_STATISTICS),
         Builtin::View(&MZ_STORAGE_USAGE),
+        Builtin::View(&MZ_STORAGE_USAGE_BY_DATABASE),
         Builtin::Source(&MZ_FRONTIERS),
         Builtin::View(&MZ_GLOBAL_FRONTIERS),
         Builtin::Source(&MZ_COMPUTE_DEPENDENCIES),
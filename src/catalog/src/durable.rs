@@ -11,6 +11,7 @@
 
 use async_trait::async_trait;
 use mz_storage_types::controller::PersistTxnTablesImpl;
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::num::NonZeroI64;
 use std::sync::Arc;
@@ -20,27 +21,33 @@ use uuid::Uuid;
 use mz_stash::DebugStashFactory;
 
 use crate::durable::debug::{DebugCatalogState, Trace};
-pub use crate::durable::error::{CatalogError, DurableCatalogError};
+pub use crate::durable::error::{CatalogError, CommitConflict, DurableCatalogError};
+use crate::durable::export::CatalogBundle;
 pub use crate::durable::impls::persist::metrics::Metrics;
 use crate::durable::impls::persist::UnopenedPersistCatalogState;
 use crate::durable::impls::shadow::OpenableShadowCatalogState;
 use crate::durable::impls::stash::{OpenableConnection, TestOpenableConnection};
 pub use crate::durable::impls::stash::{
     StashConfig, ALL_COLLECTIONS, AUDIT_LOG_COLLECTION, CLUSTER_COLLECTION,
-    CLUSTER_INTROSPECTION_SOURCE_INDEX_COLLECTION, CLUSTER_REPLICA_COLLECTION, COMMENTS_COLLECTION,
+    CLUSTER_INTROSPECTION_SOURCE_INDEX_COLLECTION, CLUSTER_REPLICA_COLLECTION,
+    CLUSTER_REPLICA_SIZES_COLLECTION, COLUMN_MASKS_COLLECTION, COMMENTS_COLLECTION,
     CONFIG_COLLECTION, DATABASES_COLLECTION, DEFAULT_PRIVILEGES_COLLECTION,
-    ID_ALLOCATOR_COLLECTION, ITEM_COLLECTION, ROLES_COLLECTION, SCHEMAS_COLLECTION,
-    SETTING_COLLECTION, STORAGE_USAGE_COLLECTION, SYSTEM_CONFIGURATION_COLLECTION,
-    SYSTEM_GID_MAPPING_COLLECTION, SYSTEM_PRIVILEGES_COLLECTION, TIMESTAMP_COLLECTION,
+    DEPENDENCIES_COLLECTION, ID_ALLOCATOR_COLLECTION, ITEM_COLLECTION, ROLES_COLLECTION,
+    SCHEMAS_COLLECTION, SERVICE_ACCOUNTS_COLLECTION, SETTING_COLLECTION, STORAGE_USAGE_COLLECTION,
+    SYSTEM_CONFIGURATION_COLLECTION, SYSTEM_GID_MAPPING_COLLECTION, SYSTEM_PRIVILEGES_COLLECTION,
+    TAGS_COLLECTION, TIMESTAMP_COLLECTION, TRASHED_ITEMS_COLLECTION,
 };
 use crate::durable::objects::Snapshot;
 pub use crate::durable::objects::{
-    Cluster, ClusterConfig, ClusterReplica, ClusterVariant, ClusterVariantManaged, Comment,
-    Database, DefaultPrivilege, Item, ReplicaConfig, ReplicaLocation, Role, Schema,
-    SystemConfiguration, SystemObjectMapping, TimelineTimestamp,
+    Cluster, ClusterConfig, ClusterReplica, ClusterReplicaSize, ClusterResize, ClusterVariant,
+    ClusterVariantManaged, ColumnMask, Comment, Database, DefaultPrivilege, Dependency, Item,
+    ItemKey, ItemValue, ReplicaConfig, ReplicaLocation, Role, Schema, ServiceAccount,
+    SystemConfiguration, SystemObjectMapping, Tag, TimelineTimestamp, TrashedItem,
+};
+pub use crate::durable::transaction::{
+    RemovedClusterObjects, Transaction, TransactionBatch, TransactionSavepoint, VacuumOptions,
+    VacuumReport,
 };
-pub use crate::durable::transaction::Transaction;
-use crate::durable::transaction::TransactionBatch;
 use mz_audit_log::{VersionedEvent, VersionedStorageUsage};
 use mz_controller_types::{ClusterId, ReplicaId};
 use mz_ore::collections::CollectionExt;
@@ -52,8 +59,10 @@ use mz_storage_types::sources::Timeline;
 
 pub mod debug;
 mod error;
+pub mod export;
 mod impls;
 pub mod initialize;
+pub mod migration;
 pub mod objects;
 mod transaction;
 mod upgrade;
@@ -69,8 +78,23 @@ pub const USER_REPLICA_ID_ALLOC_KEY: &str = "replica";
 pub const SYSTEM_REPLICA_ID_ALLOC_KEY: &str = "system_replica";
 pub const AUDIT_LOG_ID_ALLOC_KEY: &str = "auditlog";
 pub const STORAGE_USAGE_ID_ALLOC_KEY: &str = "storage_usage";
+pub const SERVICE_ACCOUNT_ID_ALLOC_KEY: &str = "service_account";
 pub(crate) const CATALOG_CONTENT_VERSION_KEY: &str = "catalog_content_version";
 
+/// Additional ID allocators, along with the first ID that each allocates.
+///
+/// Add an allocator here to give a new namespace of objects its own IDs, rather than carving them
+/// out of an existing allocator such as [`SYSTEM_ITEM_ALLOC_KEY`]. The allocators are created
+/// when a catalog is initialized, and added to existing catalogs with
+/// [`Transaction::ensure_id_allocator`] when they are opened.
+pub const NAMESPACED_ID_ALLOCATORS: &[(&str, u64)] = &[(SERVICE_ACCOUNT_ID_ALLOC_KEY, 1)];
+
+/// The names of settings that were written by earlier versions, but are no longer read. They can
+/// be removed with [`Transaction::vacuum`].
+///
+/// Add a setting here when removing the last code that reads it.
+pub const OBSOLETE_SETTINGS: &[&str] = &[];
+
 #[derive(Clone, Debug)]
 pub struct BootstrapArgs {
     pub default_cluster_replica_size: String,
@@ -79,6 +103,62 @@ pub struct BootstrapArgs {
 
 pub type Epoch = NonZeroI64;
 
+/// Selects a page of audit log events.
+///
+/// Events are returned in order of ID. To get the next page, set `after_id` to the ID of the last
+/// event of the current page.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AuditLogFilter {
+    /// Only select events that occurred at or after this time.
+    pub start: Option<EpochMillis>,
+    /// Only select events that occurred before this time.
+    pub end: Option<EpochMillis>,
+    /// Only select events caused by this user.
+    pub user: Option<String>,
+    /// Only select events about the object with this ID.
+    pub object_id: Option<String>,
+    /// Only select events with an ID greater than this ID.
+    pub after_id: Option<u64>,
+    /// The maximum number of events to select.
+    pub limit: Option<usize>,
+}
+
+impl AuditLogFilter {
+    /// Reports whether `event` matches the filter, ignoring `limit`.
+    pub fn matches(&self, event: &VersionedEvent) -> bool {
+        let occurred_at = event.occurred_at();
+        self.start.map_or(true, |start| start <= occurred_at)
+            && self.end.map_or(true, |end| occurred_at < end)
+            && self
+                .user
+                .as_ref()
+                .map_or(true, |user| event.user() == Some(user.as_str()))
+            && self.object_id.as_ref().map_or(true, |object_id| {
+                event.object_id() == Some(object_id.as_str())
+            })
+            && self
+                .after_id
+                .map_or(true, |after_id| after_id < event.sortable_id())
+    }
+
+    /// Returns the page of `events` selected by the filter, sorted by ID.
+    ///
+    /// `events` may be in any order. At most `limit` events are held in memory at once.
+    pub(crate) fn page(
+        &self,
+        events: impl IntoIterator<Item = VersionedEvent>,
+    ) -> Vec<VersionedEvent> {
+        let mut page = BTreeMap::new();
+        for event in events.into_iter().filter(|event| self.matches(event)) {
+            page.insert(event.sortable_id(), event);
+            if self.limit.is_some_and(|limit| page.len() > limit) {
+                page.pop_last();
+            }
+        }
+        page.into_values().collect()
+    }
+}
+
 /// An API for opening a durable catalog state.
 ///
 /// If a catalog is not opened, then resources should be release via [`Self::expire`].
@@ -164,6 +244,20 @@ pub trait ReadOnlyDurableCatalogState: Debug + Send {
     /// Results are guaranteed to be sorted by ID.
     async fn get_audit_logs(&mut self) -> Result<Vec<VersionedEvent>, CatalogError>;
 
+    /// Get the page of audit log events selected by `filter`, without loading all audit log
+    /// events into memory at once.
+    ///
+    /// Results are guaranteed to be sorted by ID.
+    async fn get_audit_logs_filtered(
+        &mut self,
+        filter: &AuditLogFilter,
+    ) -> Result<Vec<VersionedEvent>, CatalogError>;
+
+    /// Get all storage usage events, without pruning any of them.
+    ///
+    /// Results are guaranteed to be sorted by ID.
+    async fn get_storage_usage(&mut self) -> Result<Vec<VersionedStorageUsage>, CatalogError>;
+
     /// Get the next ID of `id_type`, without allocating it.
     async fn get_next_id(&mut self, id_type: &str) -> Result<u64, CatalogError>;
 
@@ -191,6 +285,13 @@ pub trait ReadOnlyDurableCatalogState: Debug + Send {
 
     /// Get a snapshot of the catalog.
     async fn snapshot(&mut self) -> Result<Snapshot, CatalogError>;
+
+    /// Get a snapshot of the catalog as a versioned bundle, which can be
+    /// serialized with [`CatalogBundle::to_json`].
+    async fn export(&mut self) -> Result<CatalogBundle, CatalogError> {
+        let snapshot = self.snapshot().await?;
+        Ok(snapshot.into())
+    }
 }
 
 /// A read-write API for the durable catalog state.
@@ -20,11 +20,13 @@ use crate::durable::impls::persist::{StateUpdateKind, UnopenedPersistCatalogStat
 use crate::durable::objects::serialization::proto;
 use crate::durable::{
     CatalogError, AUDIT_LOG_COLLECTION, CLUSTER_COLLECTION,
-    CLUSTER_INTROSPECTION_SOURCE_INDEX_COLLECTION, CLUSTER_REPLICA_COLLECTION, COMMENTS_COLLECTION,
+    CLUSTER_INTROSPECTION_SOURCE_INDEX_COLLECTION, CLUSTER_REPLICA_COLLECTION,
+    CLUSTER_REPLICA_SIZES_COLLECTION, COLUMN_MASKS_COLLECTION, COMMENTS_COLLECTION,
     CONFIG_COLLECTION, DATABASES_COLLECTION, DEFAULT_PRIVILEGES_COLLECTION,
-    ID_ALLOCATOR_COLLECTION, ITEM_COLLECTION, ROLES_COLLECTION, SCHEMAS_COLLECTION,
-    SETTING_COLLECTION, STORAGE_USAGE_COLLECTION, SYSTEM_CONFIGURATION_COLLECTION,
-    SYSTEM_GID_MAPPING_COLLECTION, SYSTEM_PRIVILEGES_COLLECTION, TIMESTAMP_COLLECTION,
+    DEPENDENCIES_COLLECTION, ID_ALLOCATOR_COLLECTION, ITEM_COLLECTION, ROLES_COLLECTION,
+    SCHEMAS_COLLECTION, SERVICE_ACCOUNTS_COLLECTION, SETTING_COLLECTION, STORAGE_USAGE_COLLECTION,
+    SYSTEM_CONFIGURATION_COLLECTION, SYSTEM_GID_MAPPING_COLLECTION, SYSTEM_PRIVILEGES_COLLECTION,
+    TAGS_COLLECTION, TIMESTAMP_COLLECTION, TRASHED_ITEMS_COLLECTION,
 };
 
 /// The contents of the catalog are logically separated into separate [`Collection`]s, which
@@ -63,6 +65,8 @@ pub trait Collection {
 #[serde(rename_all = "snake_case")]
 pub enum CollectionType {
     AuditLog,
+    ClusterReplicaSizes,
+    ColumnMasks,
     ComputeInstance,
     ComputeIntrospectionSourceIndex,
     ComputeReplicas,
@@ -70,16 +74,20 @@ pub enum CollectionType {
     Config,
     Database,
     DefaultPrivileges,
+    Dependencies,
     IdAlloc,
     Item,
     Role,
     Schema,
+    ServiceAccounts,
     Setting,
     StorageUsage,
     SystemConfiguration,
     SystemGidMapping,
     SystemPrivileges,
+    Tags,
     Timestamp,
+    TrashedItems,
 }
 
 derive_display_from_serialize!(CollectionType);
@@ -167,6 +175,24 @@ collection_impl!({
     stash_collection: CLUSTER_REPLICA_COLLECTION,
     persist_update: StateUpdateKind::ClusterReplica,
 });
+collection_impl!({
+    name: ClusterReplicaSizeCollection,
+    key: proto::ClusterReplicaSizeKey,
+    value: proto::ClusterReplicaSizeValue,
+    collection_type: CollectionType::ClusterReplicaSizes,
+    trace_field: cluster_replica_sizes,
+    stash_collection: CLUSTER_REPLICA_SIZES_COLLECTION,
+    persist_update: StateUpdateKind::ClusterReplicaSize,
+});
+collection_impl!({
+    name: ColumnMaskCollection,
+    key: proto::ColumnMaskKey,
+    value: proto::ColumnMaskValue,
+    collection_type: CollectionType::ColumnMasks,
+    trace_field: column_masks,
+    stash_collection: COLUMN_MASKS_COLLECTION,
+    persist_update: StateUpdateKind::ColumnMask,
+});
 collection_impl!({
     name: CommentCollection,
     key: proto::CommentKey,
@@ -203,6 +229,15 @@ collection_impl!({
     stash_collection: DEFAULT_PRIVILEGES_COLLECTION,
     persist_update: StateUpdateKind::DefaultPrivilege,
 });
+collection_impl!({
+    name: DependencyCollection,
+    key: proto::DependencyKey,
+    value: proto::DependencyValue,
+    collection_type: CollectionType::Dependencies,
+    trace_field: dependencies,
+    stash_collection: DEPENDENCIES_COLLECTION,
+    persist_update: StateUpdateKind::Dependency,
+});
 collection_impl!({
     name: IdAllocatorCollection,
     key: proto::IdAllocKey,
@@ -239,6 +274,15 @@ collection_impl!({
     stash_collection: SCHEMAS_COLLECTION,
     persist_update: StateUpdateKind::Schema,
 });
+collection_impl!({
+    name: ServiceAccountCollection,
+    key: proto::ServiceAccountKey,
+    value: proto::ServiceAccountValue,
+    collection_type: CollectionType::ServiceAccounts,
+    trace_field: service_accounts,
+    stash_collection: SERVICE_ACCOUNTS_COLLECTION,
+    persist_update: StateUpdateKind::ServiceAccount,
+});
 collection_impl!({
     name: SettingCollection,
     key: proto::SettingKey,
@@ -284,6 +328,15 @@ collection_impl!({
     stash_collection: SYSTEM_PRIVILEGES_COLLECTION,
     persist_update: StateUpdateKind::SystemPrivilege,
 });
+collection_impl!({
+    name: TagCollection,
+    key: proto::TagKey,
+    value: proto::TagValue,
+    collection_type: CollectionType::Tags,
+    trace_field: tags,
+    stash_collection: TAGS_COLLECTION,
+    persist_update: StateUpdateKind::Tag,
+});
 collection_impl!({
     name: TimestampCollection,
     key: proto::TimestampKey,
@@ -293,6 +346,15 @@ collection_impl!({
     stash_collection: TIMESTAMP_COLLECTION,
     persist_update: StateUpdateKind::Timestamp,
 });
+collection_impl!({
+    name: TrashedItemCollection,
+    key: proto::TrashedItemKey,
+    value: proto::TrashedItemValue,
+    collection_type: CollectionType::TrashedItems,
+    trace_field: trashed_items,
+    stash_collection: TRASHED_ITEMS_COLLECTION,
+    persist_update: StateUpdateKind::TrashedItem,
+});
 
 /// A trace of timestamped diffs for a particular [`Collection`].
 ///
@@ -316,20 +378,26 @@ pub struct Trace {
     pub clusters: CollectionTrace<ClusterCollection>,
     pub introspection_sources: CollectionTrace<ClusterIntrospectionSourceIndexCollection>,
     pub cluster_replicas: CollectionTrace<ClusterReplicaCollection>,
+    pub cluster_replica_sizes: CollectionTrace<ClusterReplicaSizeCollection>,
+    pub column_masks: CollectionTrace<ColumnMaskCollection>,
     pub comments: CollectionTrace<CommentCollection>,
     pub configs: CollectionTrace<ConfigCollection>,
     pub databases: CollectionTrace<DatabaseCollection>,
     pub default_privileges: CollectionTrace<DefaultPrivilegeCollection>,
+    pub dependencies: CollectionTrace<DependencyCollection>,
     pub id_allocator: CollectionTrace<IdAllocatorCollection>,
     pub items: CollectionTrace<ItemCollection>,
     pub roles: CollectionTrace<RoleCollection>,
     pub schemas: CollectionTrace<SchemaCollection>,
+    pub service_accounts: CollectionTrace<ServiceAccountCollection>,
     pub settings: CollectionTrace<SettingCollection>,
     pub storage_usage: CollectionTrace<StorageUsageCollection>,
     pub system_object_mappings: CollectionTrace<SystemItemMappingCollection>,
     pub system_configurations: CollectionTrace<SystemConfigurationCollection>,
     pub system_privileges: CollectionTrace<SystemPrivilegeCollection>,
+    pub tags: CollectionTrace<TagCollection>,
     pub timestamps: CollectionTrace<TimestampCollection>,
+    pub trashed_items: CollectionTrace<TrashedItemCollection>,
 }
 
 impl Trace {
@@ -339,20 +407,26 @@ impl Trace {
             clusters: CollectionTrace::new(),
             introspection_sources: CollectionTrace::new(),
             cluster_replicas: CollectionTrace::new(),
+            cluster_replica_sizes: CollectionTrace::new(),
+            column_masks: CollectionTrace::new(),
             comments: CollectionTrace::new(),
             configs: CollectionTrace::new(),
             databases: CollectionTrace::new(),
             default_privileges: CollectionTrace::new(),
+            dependencies: CollectionTrace::new(),
             id_allocator: CollectionTrace::new(),
             items: CollectionTrace::new(),
             roles: CollectionTrace::new(),
             schemas: CollectionTrace::new(),
+            service_accounts: CollectionTrace::new(),
             settings: CollectionTrace::new(),
             storage_usage: CollectionTrace::new(),
             system_object_mappings: CollectionTrace::new(),
             system_configurations: CollectionTrace::new(),
             system_privileges: CollectionTrace::new(),
+            tags: CollectionTrace::new(),
             timestamps: CollectionTrace::new(),
+            trashed_items: CollectionTrace::new(),
         }
     }
 }
@@ -7,10 +7,13 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
 
+use itertools::Itertools;
 use mz_proto::TryFromProtoError;
 use mz_sql::catalog::CatalogError as SqlCatalogError;
+use mz_sql::session::vars::VarError;
+use mz_stash::TableError;
 use mz_stash_types::{InternalStashError, StashError};
 
 #[derive(Debug, thiserror::Error)]
@@ -27,6 +30,12 @@ impl From<StashError> for CatalogError {
     }
 }
 
+impl<K> From<TableError<K>> for CatalogError {
+    fn from(e: TableError<K>) -> Self {
+        Self::Durable(StashError::from(e).into())
+    }
+}
+
 impl From<TryFromProtoError> for CatalogError {
     fn from(e: TryFromProtoError) -> Self {
         Self::Durable(e.into())
@@ -39,6 +48,13 @@ pub enum DurableCatalogError {
     /// Catalog has been fenced by another writer.
     #[error("{0}")]
     Fence(String),
+    /// A transaction could not be committed because the catalog was concurrently changed. Lists
+    /// the keys written by the transaction that were also concurrently changed.
+    #[error(
+        "catalog transaction conflicts with concurrent changes{}",
+        .0.iter().map(|conflict| format!("; {conflict}")).join("")
+    )]
+    Conflict(Vec<CommitConflict>),
     /// The persisted catalog's version is too old for the current catalog to migrate.
     #[error(
         "incompatible Catalog version {found_version}, minimum: {min_catalog_version}, current: {catalog_version}"
@@ -57,6 +73,34 @@ pub enum DurableCatalogError {
     /// Unable to serialize/deserialize Protobuf message.
     #[error("proto: {0}")]
     Proto(TryFromProtoError),
+    /// The changes of a transaction violate the uniqueness constraint of a collection.
+    #[error("uniqueness violation in {collection}: {field} conflicts with {existing_key}")]
+    UniquenessViolation {
+        /// The name of the collection, e.g. `items`.
+        collection: &'static str,
+        /// The field that two values of the collection may not share, e.g. `name`.
+        field: &'static str,
+        /// A human readable description of the key of the existing entry that conflicts.
+        existing_key: String,
+    },
+    /// The `create_sql` of an item is invalid.
+    #[error("invalid create sql: {0}")]
+    InvalidCreateSql(String),
+    /// An applied catalog migration is not registered.
+    #[error("unknown catalog migration version {0}")]
+    UnknownMigration(u64),
+    /// An ID range lease does not exist.
+    #[error("unknown ID range lease '{0}'")]
+    UnknownIdRangeLease(String),
+    /// An ID range lease already exists.
+    #[error("ID range lease '{0}' already exists")]
+    IdRangeLeaseAlreadyExists(String),
+    /// All of the IDs of an ID range lease have been allocated.
+    #[error("ID range lease '{0}' is exhausted")]
+    IdRangeLeaseExhausted(String),
+    /// A system configuration value that would fail to load at boot.
+    #[error("invalid system configuration: {0}")]
+    InvalidSystemConfiguration(VarError),
     /// Misc errors from the Stash implementation.
     ///
     /// Once the Stash implementation is removed we can remove this variant.
@@ -69,7 +113,9 @@ impl DurableCatalogError {
     /// or a retry is not safe due to an indeterminate state).
     pub fn is_unrecoverable(&self) -> bool {
         match self {
-            DurableCatalogError::Fence(_) | DurableCatalogError::NotWritable(_) => true,
+            DurableCatalogError::Fence(_)
+            | DurableCatalogError::Conflict(_)
+            | DurableCatalogError::NotWritable(_) => true,
             DurableCatalogError::MiscStash(e) => e.is_unrecoverable(),
             _ => false,
         }
@@ -93,6 +139,21 @@ impl DurableCatalogError {
     }
 }
 
+/// A key that was written by a transaction and concurrently changed by another writer.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CommitConflict {
+    /// The name of the collection that the key belongs to, e.g. `items`.
+    pub collection: &'static str,
+    /// A human readable description of the key, e.g. `u123`.
+    pub key: String,
+}
+
+impl fmt::Display for CommitConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} changed", self.collection, self.key)
+    }
+}
+
 impl From<StashError> for DurableCatalogError {
     fn from(e: StashError) -> Self {
         // We're not really supposed to look at the inner error, but we'll make an exception here.
@@ -0,0 +1,533 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Export and import of the durable catalog state as a versioned JSON bundle.
+//!
+//! Bundles are meant for backups, support bundles, and cloning environments.
+//! Every collection of a [`Snapshot`] is written as a list of key-value
+//! entries, encoded with the protos of the catalog version that wrote them.
+//! The password verifiers of roles are never exported.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use itertools::Itertools;
+use mz_controller_types::ClusterId;
+use mz_ore::cast::CastFrom;
+use mz_proto::{RustType, TryFromProtoError};
+use mz_repr::adt::mz_acl_item::MzAclItem;
+use mz_repr::role_id::RoleId;
+use mz_repr::GlobalId;
+use mz_sql::ast::display::AstDisplay;
+use mz_sql::catalog::{CatalogError as SqlCatalogError, RoleMembership};
+use mz_sql::names::{CommentObjectId, ResolvedDatabaseSpecifier, SchemaSpecifier};
+use serde::{Deserialize, Serialize};
+
+use crate::durable::objects::serialization::proto;
+use crate::durable::objects::{
+    parse_create_sql, Comment, Database, DurableType, Item, ReferenceCollector, Role, Schema,
+    Snapshot, Tag,
+};
+use crate::durable::upgrade::CATALOG_VERSION;
+use crate::durable::{CatalogError, Transaction};
+
+/// The version of the bundle format written by [`CatalogBundle::to_json`].
+///
+/// This must be bumped whenever the structure of [`CatalogBundle`] changes.
+/// Changes to the catalog protos are instead tracked by
+/// [`CatalogBundle::catalog_version`].
+pub const CATALOG_BUNDLE_VERSION: u64 = 1;
+
+/// An error that can occur while reading a [`CatalogBundle`].
+#[derive(Debug, thiserror::Error)]
+pub enum BundleError {
+    /// The bundle is not valid JSON or does not have the expected structure.
+    #[error("invalid catalog bundle: {0}")]
+    Json(#[from] serde_json::Error),
+    /// The bundle was written by an incompatible version.
+    #[error(
+        "incompatible catalog bundle: bundle version {bundle_version}, catalog version {catalog_version}; expected bundle version {CATALOG_BUNDLE_VERSION}, catalog version {CATALOG_VERSION}"
+    )]
+    IncompatibleVersion {
+        bundle_version: u64,
+        catalog_version: u64,
+    },
+}
+
+/// A single entry of a catalog collection.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleEntry<K, V> {
+    pub key: K,
+    pub value: V,
+}
+
+/// A serializable copy of the full durable catalog state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatalogBundle {
+    /// The version of the bundle format.
+    pub bundle_version: u64,
+    /// The catalog version whose protos were used to encode the entries.
+    pub catalog_version: u64,
+    pub databases: Vec<BundleEntry<proto::DatabaseKey, proto::DatabaseValue>>,
+    pub schemas: Vec<BundleEntry<proto::SchemaKey, proto::SchemaValue>>,
+    pub roles: Vec<BundleEntry<proto::RoleKey, proto::RoleValue>>,
+    pub items: Vec<BundleEntry<proto::ItemKey, proto::ItemValue>>,
+    pub comments: Vec<BundleEntry<proto::CommentKey, proto::CommentValue>>,
+    pub clusters: Vec<BundleEntry<proto::ClusterKey, proto::ClusterValue>>,
+    pub cluster_replicas: Vec<BundleEntry<proto::ClusterReplicaKey, proto::ClusterReplicaValue>>,
+    pub introspection_sources: Vec<
+        BundleEntry<
+            proto::ClusterIntrospectionSourceIndexKey,
+            proto::ClusterIntrospectionSourceIndexValue,
+        >,
+    >,
+    pub id_allocator: Vec<BundleEntry<proto::IdAllocKey, proto::IdAllocValue>>,
+    pub configs: Vec<BundleEntry<proto::ConfigKey, proto::ConfigValue>>,
+    pub settings: Vec<BundleEntry<proto::SettingKey, proto::SettingValue>>,
+    pub timestamps: Vec<BundleEntry<proto::TimestampKey, proto::TimestampValue>>,
+    pub system_object_mappings: Vec<BundleEntry<proto::GidMappingKey, proto::GidMappingValue>>,
+    pub system_configurations:
+        Vec<BundleEntry<proto::ServerConfigurationKey, proto::ServerConfigurationValue>>,
+    pub default_privileges:
+        Vec<BundleEntry<proto::DefaultPrivilegesKey, proto::DefaultPrivilegesValue>>,
+    pub system_privileges:
+        Vec<BundleEntry<proto::SystemPrivilegesKey, proto::SystemPrivilegesValue>>,
+    pub tags: Vec<BundleEntry<proto::TagKey, proto::TagValue>>,
+    pub dependencies: Vec<BundleEntry<proto::DependencyKey, proto::DependencyValue>>,
+    pub trashed_items: Vec<BundleEntry<proto::TrashedItemKey, proto::TrashedItemValue>>,
+    pub column_masks: Vec<BundleEntry<proto::ColumnMaskKey, proto::ColumnMaskValue>>,
+    pub service_accounts: Vec<BundleEntry<proto::ServiceAccountKey, proto::ServiceAccountValue>>,
+    pub cluster_replica_sizes:
+        Vec<BundleEntry<proto::ClusterReplicaSizeKey, proto::ClusterReplicaSizeValue>>,
+}
+
+impl CatalogBundle {
+    /// Serializes the bundle as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("serialization cannot fail")
+    }
+
+    /// Deserializes a bundle from JSON, checking that it was written by a
+    /// compatible version.
+    pub fn from_json(json: &str) -> Result<CatalogBundle, BundleError> {
+        let bundle: CatalogBundle = serde_json::from_str(json)?;
+        if bundle.bundle_version != CATALOG_BUNDLE_VERSION
+            || bundle.catalog_version != CATALOG_VERSION
+        {
+            return Err(BundleError::IncompatibleVersion {
+                bundle_version: bundle.bundle_version,
+                catalog_version: bundle.catalog_version,
+            });
+        }
+        Ok(bundle)
+    }
+}
+
+impl From<Snapshot> for CatalogBundle {
+    fn from(snapshot: Snapshot) -> Self {
+        let Snapshot {
+            databases,
+            schemas,
+            roles,
+            items,
+            comments,
+            clusters,
+            cluster_replicas,
+            introspection_sources,
+            id_allocator,
+            configs,
+            settings,
+            timestamps,
+            system_object_mappings,
+            system_configurations,
+            default_privileges,
+            system_privileges,
+            tags,
+            dependencies,
+            trashed_items,
+            column_masks,
+            service_accounts,
+            cluster_replica_sizes,
+        } = snapshot;
+        CatalogBundle {
+            bundle_version: CATALOG_BUNDLE_VERSION,
+            catalog_version: CATALOG_VERSION,
+            databases: into_entries(databases),
+            schemas: into_entries(schemas),
+            roles: into_entries(roles)
+                .into_iter()
+                .map(strip_password_verifier)
+                .collect(),
+            items: into_entries(items),
+            comments: into_entries(comments),
+            clusters: into_entries(clusters),
+            cluster_replicas: into_entries(cluster_replicas),
+            introspection_sources: into_entries(introspection_sources),
+            id_allocator: into_entries(id_allocator),
+            configs: into_entries(configs),
+            settings: into_entries(settings),
+            timestamps: into_entries(timestamps),
+            system_object_mappings: into_entries(system_object_mappings),
+            system_configurations: into_entries(system_configurations),
+            default_privileges: into_entries(default_privileges),
+            system_privileges: into_entries(system_privileges),
+            tags: into_entries(tags),
+            dependencies: into_entries(dependencies),
+            trashed_items: into_entries(trashed_items),
+            column_masks: into_entries(column_masks),
+            service_accounts: into_entries(service_accounts),
+            cluster_replica_sizes: into_entries(cluster_replica_sizes),
+        }
+    }
+}
+
+impl From<CatalogBundle> for Snapshot {
+    fn from(bundle: CatalogBundle) -> Self {
+        let CatalogBundle {
+            bundle_version: _,
+            catalog_version: _,
+            databases,
+            schemas,
+            roles,
+            items,
+            comments,
+            clusters,
+            cluster_replicas,
+            introspection_sources,
+            id_allocator,
+            configs,
+            settings,
+            timestamps,
+            system_object_mappings,
+            system_configurations,
+            default_privileges,
+            system_privileges,
+            tags,
+            dependencies,
+            trashed_items,
+            column_masks,
+            service_accounts,
+            cluster_replica_sizes,
+        } = bundle;
+        Snapshot {
+            databases: from_entries(databases),
+            schemas: from_entries(schemas),
+            roles: from_entries(roles),
+            items: from_entries(items),
+            comments: from_entries(comments),
+            clusters: from_entries(clusters),
+            cluster_replicas: from_entries(cluster_replicas),
+            introspection_sources: from_entries(introspection_sources),
+            id_allocator: from_entries(id_allocator),
+            configs: from_entries(configs),
+            settings: from_entries(settings),
+            timestamps: from_entries(timestamps),
+            system_object_mappings: from_entries(system_object_mappings),
+            system_configurations: from_entries(system_configurations),
+            default_privileges: from_entries(default_privileges),
+            system_privileges: from_entries(system_privileges),
+            tags: from_entries(tags),
+            dependencies: from_entries(dependencies),
+            trashed_items: from_entries(trashed_items),
+            column_masks: from_entries(column_masks),
+            service_accounts: from_entries(service_accounts),
+            cluster_replica_sizes: from_entries(cluster_replica_sizes),
+        }
+    }
+}
+
+/// Removes the password verifier of a role, so that password hashes never
+/// leave the environment in a bundle. Imported roles have no password.
+fn strip_password_verifier(
+    mut entry: BundleEntry<proto::RoleKey, proto::RoleValue>,
+) -> BundleEntry<proto::RoleKey, proto::RoleValue> {
+    if let Some(attributes) = &mut entry.value.attributes {
+        attributes.password_verifier = None;
+    }
+    entry
+}
+
+fn into_entries<K, V>(map: BTreeMap<K, V>) -> Vec<BundleEntry<K, V>> {
+    map.into_iter()
+        .map(|(key, value)| BundleEntry { key, value })
+        .collect()
+}
+
+fn from_entries<K: Ord, V>(entries: Vec<BundleEntry<K, V>>) -> BTreeMap<K, V> {
+    entries
+        .into_iter()
+        .map(|BundleEntry { key, value }| (key, value))
+        .collect()
+}
+
+/// Replays the roles, databases, schemas, items, comments, and tags of `bundle` into `tx`.
+///
+/// User items are assigned new ids from the user item id allocator, and all references to them
+/// are rewritten accordingly. User roles, databases, and schemas are matched by name against the
+/// ones that already exist in `tx`, e.g. the default `materialize` database, and are otherwise
+/// created with newly allocated ids. Clusters are not imported, so every cluster referenced by an
+/// imported object must already exist with the same id.
+///
+/// Returns the new id of every imported item, keyed by its id in the bundle.
+///
+/// Returns an error if any object in the bundle references an object that exists neither in the
+/// bundle nor in `tx`. On error, there still may be some objects inserted in the transaction, so
+/// it should not be committed.
+pub fn import_bundle(
+    tx: &mut Transaction<'_>,
+    bundle: CatalogBundle,
+) -> Result<BTreeMap<GlobalId, GlobalId>, CatalogError> {
+    let snapshot = Snapshot::from(bundle);
+    let roles: Vec<Role> = from_protos(snapshot.roles)?;
+    let databases: Vec<Database> = from_protos(snapshot.databases)?;
+    let schemas: Vec<Schema> = from_protos(snapshot.schemas)?;
+    let mut items: Vec<Item> = from_protos(snapshot.items)?;
+    let comments: Vec<Comment> = from_protos(snapshot.comments)?;
+    let tags: Vec<Tag> = from_protos(snapshot.tags)?;
+
+    // Roles are inserted without their memberships, which may reference roles that haven't been
+    // inserted yet.
+    let existing_roles: BTreeMap<_, _> = tx.get_roles().map(|role| (role.name, role.id)).collect();
+    let mut role_ids = BTreeMap::new();
+    let mut inserted_roles = Vec::new();
+    for role in roles.into_iter().filter(|role| role.id.is_user()) {
+        let id = match existing_roles.get(&role.name) {
+            Some(id) => *id,
+            None => {
+                let id = tx.insert_user_role(
+                    role.name.clone(),
+                    role.attributes.clone(),
+                    RoleMembership::new(),
+                    role.vars.clone(),
+                )?;
+                inserted_roles.push(Role { id, ..role.clone() });
+                id
+            }
+        };
+        role_ids.insert(role.id, id);
+    }
+    let known_roles: BTreeSet<_> = tx.get_roles().map(|role| role.id).collect();
+    let map_role = |id: RoleId| -> Result<RoleId, CatalogError> {
+        match role_ids.get(&id) {
+            Some(id) => Ok(*id),
+            // Non-user roles are builtin, so they keep their ids.
+            None if !id.is_user() && known_roles.contains(&id) => Ok(id),
+            None => Err(SqlCatalogError::UnknownRole(id.to_string()).into()),
+        }
+    };
+    let map_privileges = |privileges: Vec<MzAclItem>| -> Result<Vec<MzAclItem>, CatalogError> {
+        privileges
+            .into_iter()
+            .map(|privilege| {
+                Ok(MzAclItem {
+                    grantee: map_role(privilege.grantee)?,
+                    grantor: map_role(privilege.grantor)?,
+                    acl_mode: privilege.acl_mode,
+                })
+            })
+            .collect()
+    };
+
+    let mut updated_roles = BTreeMap::new();
+    for mut role in inserted_roles {
+        role.membership.map = role
+            .membership
+            .map
+            .into_iter()
+            .map(|(role_id, grantor_id)| Ok((map_role(role_id)?, map_role(grantor_id)?)))
+            .collect::<Result<_, CatalogError>>()?;
+        updated_roles.insert(role.id, role);
+    }
+    tx.update_roles(updated_roles)?;
+
+    let existing_databases: BTreeMap<_, _> = tx
+        .get_databases()
+        .map(|database| (database.name, database.id))
+        .collect();
+    let mut database_ids = BTreeMap::new();
+    for database in databases {
+        let id = match existing_databases.get(&database.name) {
+            Some(id) => *id,
+            None => tx.insert_user_database(
+                &database.name,
+                map_role(database.owner_id)?,
+                map_privileges(database.privileges)?,
+            )?,
+        };
+        database_ids.insert(database.id, id);
+    }
+
+    let existing_schemas: BTreeMap<_, _> = tx
+        .get_schemas()
+        .map(|schema| ((schema.database_id, schema.name), schema.id))
+        .collect();
+    let mut schema_ids = BTreeMap::new();
+    for schema in schemas {
+        let database_id = match schema.database_id {
+            Some(id) => Some(
+                *database_ids
+                    .get(&id)
+                    .ok_or_else(|| SqlCatalogError::UnknownDatabase(id.to_string()))?,
+            ),
+            None => None,
+        };
+        let id = match (
+            existing_schemas.get(&(database_id, schema.name.clone())),
+            database_id,
+        ) {
+            (Some(id), _) => *id,
+            (None, Some(database_id)) => tx.insert_user_schema(
+                database_id,
+                &schema.name,
+                map_role(schema.owner_id)?,
+                map_privileges(schema.privileges)?,
+            )?,
+            // Schemas outside of any database are builtin, so they must already exist.
+            (None, None) => return Err(SqlCatalogError::UnknownSchema(schema.name).into()),
+        };
+        schema_ids.insert(schema.id, id);
+    }
+
+    // System items are builtin, so only user items are imported. New ids are allocated in the
+    // order of the old ones, so that items are still created after their dependencies.
+    items.retain(|item| item.id.is_user());
+    items.sort_by_key(|item| item.id);
+    let new_ids = tx.allocate_user_item_ids(u64::cast_from(items.len()))?;
+    let item_ids: BTreeMap<_, _> = items.iter().map(|item| item.id).zip_eq(new_ids).collect();
+    let known_items: BTreeSet<_> = tx
+        .get_system_items()
+        .map(|mapping| mapping.unique_identifier.id)
+        .collect();
+    let map_item = |id: GlobalId| -> Result<GlobalId, CatalogError> {
+        match item_ids.get(&id) {
+            Some(id) => Ok(*id),
+            None if known_items.contains(&id) => Ok(id),
+            None => Err(SqlCatalogError::UnknownItem(id.to_string()).into()),
+        }
+    };
+    let known_clusters: BTreeSet<_> = tx.get_clusters().map(|cluster| cluster.id).collect();
+    let known_replicas: BTreeSet<_> = tx
+        .get_cluster_replicas()
+        .map(|replica| (replica.cluster_id, replica.replica_id))
+        .collect();
+    let check_cluster = |id: ClusterId| -> Result<ClusterId, CatalogError> {
+        if known_clusters.contains(&id) {
+            Ok(id)
+        } else {
+            Err(SqlCatalogError::UnknownCluster(id.to_string()).into())
+        }
+    };
+
+    let mut new_items = Vec::with_capacity(items.len());
+    for item in items {
+        let mut create_stmt = parse_create_sql(&item.create_sql)?;
+        let references = ReferenceCollector::collect(&create_stmt)?;
+        for id in references.items {
+            map_item(id)?;
+        }
+        for id in references.clusters {
+            check_cluster(id)?;
+        }
+        mz_sql::ast::transform::create_stmt_replace_ids(&mut create_stmt, &item_ids);
+        let schema_id = *schema_ids
+            .get(&item.schema_id)
+            .ok_or_else(|| SqlCatalogError::UnknownSchema(item.schema_id.to_string()))?;
+        new_items.push(Item {
+            id: map_item(item.id)?,
+            schema_id,
+            name: item.name,
+            create_sql: create_stmt.to_ast_string_stable(),
+            owner_id: map_role(item.owner_id)?,
+            privileges: map_privileges(item.privileges)?,
+        });
+    }
+    tx.insert_items(new_items)?;
+
+    let map_object = |object_id: CommentObjectId| -> Result<CommentObjectId, CatalogError> {
+        let object_id = match object_id {
+            CommentObjectId::Table(id) => CommentObjectId::Table(map_item(id)?),
+            CommentObjectId::View(id) => CommentObjectId::View(map_item(id)?),
+            CommentObjectId::MaterializedView(id) => {
+                CommentObjectId::MaterializedView(map_item(id)?)
+            }
+            CommentObjectId::Source(id) => CommentObjectId::Source(map_item(id)?),
+            CommentObjectId::Sink(id) => CommentObjectId::Sink(map_item(id)?),
+            CommentObjectId::Index(id) => CommentObjectId::Index(map_item(id)?),
+            CommentObjectId::Func(id) => CommentObjectId::Func(map_item(id)?),
+            CommentObjectId::Connection(id) => CommentObjectId::Connection(map_item(id)?),
+            CommentObjectId::Type(id) => CommentObjectId::Type(map_item(id)?),
+            CommentObjectId::Secret(id) => CommentObjectId::Secret(map_item(id)?),
+            CommentObjectId::Role(id) => CommentObjectId::Role(map_role(id)?),
+            CommentObjectId::Database(id) => CommentObjectId::Database(
+                *database_ids
+                    .get(&id)
+                    .ok_or_else(|| SqlCatalogError::UnknownDatabase(id.to_string()))?,
+            ),
+            CommentObjectId::Schema((database_spec, schema_spec)) => {
+                let database_spec = match database_spec {
+                    ResolvedDatabaseSpecifier::Ambient => ResolvedDatabaseSpecifier::Ambient,
+                    ResolvedDatabaseSpecifier::Id(id) => ResolvedDatabaseSpecifier::Id(
+                        *database_ids
+                            .get(&id)
+                            .ok_or_else(|| SqlCatalogError::UnknownDatabase(id.to_string()))?,
+                    ),
+                };
+                let schema_spec = match schema_spec {
+                    SchemaSpecifier::Temporary => SchemaSpecifier::Temporary,
+                    SchemaSpecifier::Id(id) => SchemaSpecifier::Id(
+                        *schema_ids
+                            .get(&id)
+                            .ok_or_else(|| SqlCatalogError::UnknownSchema(id.to_string()))?,
+                    ),
+                };
+                CommentObjectId::Schema((database_spec, schema_spec))
+            }
+            CommentObjectId::Cluster(id) => CommentObjectId::Cluster(check_cluster(id)?),
+            CommentObjectId::ClusterReplica((cluster_id, replica_id)) => {
+                if !known_replicas.contains(&(cluster_id, replica_id)) {
+                    return Err(SqlCatalogError::UnknownClusterReplica(format!(
+                        "{cluster_id}.{replica_id}"
+                    ))
+                    .into());
+                }
+                CommentObjectId::ClusterReplica((cluster_id, replica_id))
+            }
+        };
+        Ok(object_id)
+    };
+
+    for comment in comments {
+        tx.update_comment(
+            map_object(comment.object_id)?,
+            comment.sub_component,
+            Some(comment.comment),
+        )?;
+    }
+    for tag in tags {
+        tx.set_tag(map_object(tag.object_id)?, tag.name, Some(tag.value))?;
+    }
+
+    Ok(item_ids)
+}
+
+fn from_protos<KP, VP, K, V, T>(protos: BTreeMap<KP, VP>) -> Result<Vec<T>, TryFromProtoError>
+where
+    K: RustType<KP>,
+    V: RustType<VP>,
+    T: DurableType<K, V>,
+{
+    protos
+        .into_iter()
+        .map(|(key, value)| {
+            Ok(T::from_key_value(
+                K::from_proto(key)?,
+                V::from_proto(value)?,
+            ))
+        })
+        .collect()
+}
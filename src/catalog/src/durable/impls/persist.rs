@@ -10,7 +10,7 @@
 pub(crate) mod metrics;
 pub(crate) mod state_update;
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Debug;
 use std::future::Future;
 use std::str::FromStr;
@@ -22,6 +22,7 @@ use differential_dataflow::lattice::Lattice;
 use futures::{FutureExt, StreamExt};
 use itertools::Itertools;
 use mz_audit_log::{VersionedEvent, VersionedStorageUsage};
+use mz_ore::cast::CastLossy;
 use mz_ore::collections::CollectionExt;
 use mz_ore::metrics::MetricsFutureExt;
 use mz_ore::now::EpochMillis;
@@ -54,8 +55,9 @@ use crate::durable::objects::{AuditLogKey, Config, DurableType, Snapshot, Storag
 use crate::durable::transaction::TransactionBatch;
 use crate::durable::upgrade::persist::upgrade;
 use crate::durable::{
-    initialize, BootstrapArgs, CatalogError, DurableCatalogError, DurableCatalogState, Epoch,
-    OpenableDurableCatalogState, ReadOnlyDurableCatalogState, TimelineTimestamp, Transaction,
+    initialize, AuditLogFilter, BootstrapArgs, CatalogError, CommitConflict, DurableCatalogError,
+    DurableCatalogState, Epoch, OpenableDurableCatalogState, ReadOnlyDurableCatalogState,
+    TimelineTimestamp, Transaction,
 };
 
 /// New-type used to represent timestamps in persist.
@@ -225,6 +227,7 @@ impl UnopenedPersistCatalogState {
             shard_id: self.shard_id,
             upper: Timestamp::minimum(),
             epoch: current_epoch,
+            durable_epoch: current_epoch,
             // Initialize empty in-memory state.
             snapshot: Snapshot::empty(),
             metrics: self.metrics,
@@ -249,7 +252,7 @@ impl UnopenedPersistCatalogState {
         };
 
         if read_only {
-            let (txn_batch, _) = txn.into_parts();
+            let (txn_batch, _) = txn.into_parts()?;
             // The upper here doesn't matter because we are only apply the updates in memory.
             let updates = StateUpdate::from_txn_batch(txn_batch, catalog.upper);
             catalog.apply_updates(updates)?;
@@ -565,6 +568,8 @@ pub struct PersistCatalogState {
     upper: Timestamp,
     /// The epoch of this catalog.
     epoch: Epoch,
+    /// The epoch stored in the persist shard, as of `upper`.
+    durable_epoch: Epoch,
     /// A cache of the entire catalogs state.
     snapshot: Snapshot,
     /// Metrics for the persist catalog.
@@ -581,10 +586,18 @@ impl PersistCatalogState {
     /// iff the current global upper of the catalog is `current_upper`.
     async fn compare_and_append(
         &mut self,
-        updates: Vec<StateUpdate>,
+        updates: &[StateUpdate],
         current_upper: Timestamp,
         next_upper: Timestamp,
     ) -> Result<(), CatalogError> {
+        let updates: Vec<_> = updates
+            .iter()
+            .map(|update| StateUpdate {
+                kind: StateUpdateKindBinary::from(&update.kind),
+                ts: update.ts,
+                diff: update.diff,
+            })
+            .collect();
         compare_and_append(&mut self.write_handle, updates, current_upper, next_upper).await
     }
 
@@ -635,6 +648,15 @@ impl PersistCatalogState {
 
     #[tracing::instrument(level = "debug", skip(self))]
     async fn sync_inner(&mut self, target_upper: Timestamp) -> Result<(), CatalogError> {
+        let updates = self.fetch_updates(target_upper).await;
+        self.apply_updates(updates)?;
+
+        Ok(())
+    }
+
+    /// Listen for all updates up to `target_upper` and advance the catalog's upper, without
+    /// applying the updates to the in memory catalog cache.
+    async fn fetch_updates(&mut self, target_upper: Timestamp) -> Vec<StateUpdate> {
         let mut updates = Vec::new();
 
         while self.upper < target_upper {
@@ -662,9 +684,39 @@ impl PersistCatalogState {
             }
         }
 
-        self.apply_updates(updates)?;
+        updates
+    }
 
-        Ok(())
+    /// Returns an error describing which of the keys written by `updates` were concurrently
+    /// changed by another writer, after syncing the catalog up to `target_upper`.
+    ///
+    /// Returns a fence error instead if the concurrent changes include a new epoch.
+    #[tracing::instrument(level = "debug", skip(self, updates))]
+    async fn commit_conflict(
+        &mut self,
+        updates: &[StateUpdate],
+        target_upper: Timestamp,
+    ) -> CatalogError {
+        let concurrent_updates = self.fetch_updates(target_upper).await;
+        let changed: BTreeSet<_> = concurrent_updates
+            .iter()
+            .filter_map(|update| update.kind.collection_key())
+            .collect();
+        if let Err(err) = self.apply_updates(concurrent_updates) {
+            return err.into();
+        }
+        let conflicts: BTreeSet<_> = updates
+            .iter()
+            .filter_map(|update| update.kind.collection_key())
+            .filter(|collection_key| changed.contains(collection_key))
+            .collect();
+        DurableCatalogError::Conflict(
+            conflicts
+                .into_iter()
+                .map(|(collection, key)| CommitConflict { collection, key })
+                .collect(),
+        )
+        .into()
     }
 
     /// Applies [`StateUpdate`]s to the in memory catalog cache.
@@ -736,7 +788,13 @@ impl PersistCatalogState {
                 StateUpdateKind::DefaultPrivilege(key, value) => {
                     apply(&mut self.snapshot.default_privileges, key, value, diff);
                 }
+                StateUpdateKind::Dependency(key, value) => {
+                    apply(&mut self.snapshot.dependencies, key, value, diff);
+                }
                 StateUpdateKind::Epoch(epoch) => {
+                    if diff == 1 {
+                        self.durable_epoch = epoch;
+                    }
                     if epoch > self.epoch {
                         soft_assert_eq!(diff, 1);
                         return Err(DurableCatalogError::Fence(format!(
@@ -775,9 +833,24 @@ impl PersistCatalogState {
                 StateUpdateKind::SystemPrivilege(key, value) => {
                     apply(&mut self.snapshot.system_privileges, key, value, diff);
                 }
+                StateUpdateKind::Tag(key, value) => {
+                    apply(&mut self.snapshot.tags, key, value, diff);
+                }
                 StateUpdateKind::Timestamp(key, value) => {
                     apply(&mut self.snapshot.timestamps, key, value, diff);
                 }
+                StateUpdateKind::TrashedItem(key, value) => {
+                    apply(&mut self.snapshot.trashed_items, key, value, diff);
+                }
+                StateUpdateKind::ColumnMask(key, value) => {
+                    apply(&mut self.snapshot.column_masks, key, value, diff);
+                }
+                StateUpdateKind::ServiceAccount(key, value) => {
+                    apply(&mut self.snapshot.service_accounts, key, value, diff);
+                }
+                StateUpdateKind::ClusterReplicaSize(key, value) => {
+                    apply(&mut self.snapshot.cluster_replica_sizes, key, value, diff);
+                }
             }
         }
 
@@ -861,6 +934,58 @@ impl ReadOnlyDurableCatalogState for PersistCatalogState {
         Ok(audit_logs)
     }
 
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn get_audit_logs_filtered(
+        &mut self,
+        filter: &AuditLogFilter,
+    ) -> Result<Vec<VersionedEvent>, CatalogError> {
+        self.sync_to_current_upper().await?;
+        // Filter the events as they're decoded, so that only the selected page is held in memory.
+        let events = self
+            .persist_snapshot()
+            .await
+            .filter_map(
+                |StateUpdate {
+                     kind,
+                     ts: _,
+                     diff: _,
+                 }| match kind {
+                    StateUpdateKind::AuditLog(key, ()) => Some(key),
+                    _ => None,
+                },
+            )
+            .map(RustType::from_proto)
+            .map_ok(|key: AuditLogKey| key.event);
+        Ok(itertools::process_results(events, |events| {
+            filter.page(events)
+        })?)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn get_storage_usage(&mut self) -> Result<Vec<VersionedStorageUsage>, CatalogError> {
+        self.sync_to_current_upper().await?;
+        // Like audit logs, storage usage events are not cached in memory, so we go
+        // back to persist and grab everything again.
+        let mut storage_usage: Vec<_> = self
+            .persist_snapshot()
+            .await
+            .filter_map(
+                |StateUpdate {
+                     kind,
+                     ts: _,
+                     diff: _,
+                 }| match kind {
+                    StateUpdateKind::StorageUsage(key, ()) => Some(key),
+                    _ => None,
+                },
+            )
+            .map(RustType::from_proto)
+            .map_ok(|key: StorageUsageKey| key.metric)
+            .collect::<Result<_, _>>()?;
+        storage_usage.sort_by(|a, b| a.sortable_id().cmp(&b.sortable_id()));
+        Ok(storage_usage)
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     async fn get_next_id(&mut self, id_type: &str) -> Result<u64, CatalogError> {
         let key = proto::IdAllocKey {
@@ -938,6 +1063,12 @@ impl DurableCatalogState for PersistCatalogState {
             catalog: &mut PersistCatalogState,
             txn_batch: TransactionBatch,
         ) -> Result<(), CatalogError> {
+            // The compare and append below only succeeds if the shard is still at
+            // `catalog.upper`, so the epoch stored as of that upper is the epoch that the
+            // transaction is committed at. If another catalog has written a new epoch since, the
+            // compare and append fails and we report the fence instead.
+            txn_batch.check_epoch(catalog.durable_epoch)?;
+
             // If the transaction is empty then we don't error, even in read-only mode. This matches the
             // semantics that the stash uses.
             if !txn_batch.is_empty() && catalog.is_read_only() {
@@ -954,9 +1085,16 @@ impl DurableCatalogState for PersistCatalogState {
             debug!("committing updates: {updates:?}");
 
             if matches!(catalog.mode, Mode::Writable) {
-                catalog
-                    .compare_and_append(updates, current_upper, next_upper)
-                    .await?;
+                if let Err(err) = catalog
+                    .compare_and_append(&updates, current_upper, next_upper)
+                    .await
+                {
+                    // Another writer changed the catalog, report which of our updates conflicted
+                    // with its changes.
+                    debug!("failed to commit catalog transaction: {err}");
+                    let upper = catalog.current_upper().await;
+                    return Err(catalog.commit_conflict(&updates, upper).await);
+                }
                 debug!(
                     "commit successful, upper advanced from {current_upper:?} to {next_upper:?}",
                 );
@@ -968,6 +1106,17 @@ impl DurableCatalogState for PersistCatalogState {
             Ok(())
         }
         self.metrics.transaction_commits.inc();
+        for (collection, count) in txn_batch.update_counts() {
+            if count > 0 {
+                self.metrics
+                    .transaction_pending_updates
+                    .with_label_values(&[collection])
+                    .observe(f64::cast_lossy(count));
+            }
+        }
+        self.metrics
+            .transaction_batch_size_bytes
+            .observe(f64::cast_lossy(txn_batch.encoded_len()));
         let counter = self.metrics.transaction_commit_latency_seconds.clone();
         commit_transaction_inner(self, txn_batch)
             .wall_time()
@@ -1277,6 +1426,12 @@ impl Trace {
                         .values
                         .push(((k, v), ts.to_string(), diff))
                 }
+                StateUpdateKind::Dependency(k, v) => {
+                    trace
+                        .dependencies
+                        .values
+                        .push(((k, v), ts.to_string(), diff))
+                }
                 StateUpdateKind::Epoch(_) => {
                     // Epoch not included in trace.
                 }
@@ -1322,9 +1477,34 @@ impl Trace {
                         .values
                         .push(((k, v), ts.to_string(), diff))
                 }
+                StateUpdateKind::Tag(k, v) => {
+                    trace.tags.values.push(((k, v), ts.to_string(), diff))
+                }
                 StateUpdateKind::Timestamp(k, v) => {
                     trace.timestamps.values.push(((k, v), ts.to_string(), diff))
                 }
+                StateUpdateKind::TrashedItem(k, v) => {
+                    trace
+                        .trashed_items
+                        .values
+                        .push(((k, v), ts.to_string(), diff))
+                }
+                StateUpdateKind::ColumnMask(k, v) => {
+                    trace
+                        .column_masks
+                        .values
+                        .push(((k, v), ts.to_string(), diff))
+                }
+                StateUpdateKind::ServiceAccount(k, v) => {
+                    trace
+                        .service_accounts
+                        .values
+                        .push(((k, v), ts.to_string(), diff))
+                }
+                StateUpdateKind::ClusterReplicaSize(k, v) => trace
+                    .cluster_replica_sizes
+                    .values
+                    .push(((k, v), ts.to_string(), diff)),
             }
         }
         trace
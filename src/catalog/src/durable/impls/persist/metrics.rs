@@ -11,13 +11,16 @@
 
 use mz_ore::metric;
 use mz_ore::metrics::{IntCounter, MetricsRegistry};
-use prometheus::Counter;
+use mz_ore::stats::HISTOGRAM_BYTE_BUCKETS;
+use prometheus::{Counter, Histogram, HistogramVec};
 
 #[derive(Debug, Clone)]
 pub struct Metrics {
     pub transactions_started: IntCounter,
     pub transaction_commits: IntCounter,
     pub transaction_commit_latency_seconds: Counter,
+    pub transaction_pending_updates: HistogramVec,
+    pub transaction_batch_size_bytes: Histogram,
     pub snapshots_taken: IntCounter,
     pub snapshot_latency_seconds: Counter,
     pub syncs: IntCounter,
@@ -40,6 +43,17 @@ impl Metrics {
                 name: "mz_catalog_transaction_commit_latency_seconds",
                 help: "Total latency for committing a durable catalog transactions.",
             )),
+            transaction_pending_updates: registry.register(metric!(
+                name: "mz_catalog_transaction_pending_updates",
+                help: "The number of updates to each collection in committed transactions that modify it.",
+                var_labels: ["collection"],
+                buckets: prometheus::exponential_buckets(1.0, 4.0, 12).expect("valid buckets"),
+            )),
+            transaction_batch_size_bytes: registry.register(metric!(
+                name: "mz_catalog_transaction_batch_size_bytes",
+                help: "The serialized size of the updates in committed transactions.",
+                buckets: HISTOGRAM_BYTE_BUCKETS.to_vec(),
+            )),
             snapshots_taken: registry.register(metric!(
                 name: "mz_catalog_snapshots_taken",
                 help: "Count of snapshots taken.",
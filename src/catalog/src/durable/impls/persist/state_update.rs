@@ -7,7 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
 
 use mz_persist_types::codec_impls::{SimpleDecoder, SimpleEncoder, SimpleSchema};
 use mz_persist_types::dyn_struct::{ColumnsMut, ColumnsRef, DynStructCfg};
@@ -19,6 +19,10 @@ use prost::Message;
 
 use crate::durable::impls::persist::Timestamp;
 use crate::durable::objects::serialization::proto;
+use crate::durable::objects::{
+    ClusterKey, ClusterReplicaKey, ColumnMaskKey, DatabaseKey, ItemKey, RoleKey, SchemaKey,
+    ServiceAccountKey, TrashedItemKey,
+};
 use crate::durable::transaction::TransactionBatch;
 use crate::durable::Epoch;
 
@@ -92,10 +96,18 @@ impl StateUpdate {
             system_configurations,
             default_privileges,
             system_privileges,
+            tags,
+            dependencies,
+            trashed_items,
+            column_masks,
+            service_accounts,
+            cluster_replica_sizes,
             audit_log_updates,
             storage_usage_updates,
             // Persist implementation does not use the connection timeout.
             connection_timeout: _,
+            // Checked before the batch is converted.
+            expected_epoch: _,
         } = txn_batch;
         let databases = from_batch(databases, ts, StateUpdateKind::Database);
         let schemas = from_batch(schemas, ts, StateUpdateKind::Schema);
@@ -123,6 +135,16 @@ impl StateUpdate {
         let default_privileges =
             from_batch(default_privileges, ts, StateUpdateKind::DefaultPrivilege);
         let system_privileges = from_batch(system_privileges, ts, StateUpdateKind::SystemPrivilege);
+        let tags = from_batch(tags, ts, StateUpdateKind::Tag);
+        let dependencies = from_batch(dependencies, ts, StateUpdateKind::Dependency);
+        let trashed_items = from_batch(trashed_items, ts, StateUpdateKind::TrashedItem);
+        let column_masks = from_batch(column_masks, ts, StateUpdateKind::ColumnMask);
+        let service_accounts = from_batch(service_accounts, ts, StateUpdateKind::ServiceAccount);
+        let cluster_replica_sizes = from_batch(
+            cluster_replica_sizes,
+            ts,
+            StateUpdateKind::ClusterReplicaSize,
+        );
         let audit_logs = from_batch(audit_log_updates, ts, StateUpdateKind::AuditLog);
         let storage_usage_updates =
             from_batch(storage_usage_updates, ts, StateUpdateKind::StorageUsage);
@@ -143,6 +165,12 @@ impl StateUpdate {
             .chain(system_configurations)
             .chain(default_privileges)
             .chain(system_privileges)
+            .chain(tags)
+            .chain(dependencies)
+            .chain(trashed_items)
+            .chain(column_masks)
+            .chain(service_accounts)
+            .chain(cluster_replica_sizes)
             .chain(audit_logs)
             .chain(storage_usage_updates)
             .collect()
@@ -174,6 +202,7 @@ pub enum StateUpdateKind {
     Config(proto::ConfigKey, proto::ConfigValue),
     Database(proto::DatabaseKey, proto::DatabaseValue),
     DefaultPrivilege(proto::DefaultPrivilegesKey, proto::DefaultPrivilegesValue),
+    Dependency(proto::DependencyKey, proto::DependencyValue),
     Epoch(Epoch),
     IdAllocator(proto::IdAllocKey, proto::IdAllocValue),
     IntrospectionSourceIndex(
@@ -191,7 +220,88 @@ pub enum StateUpdateKind {
     ),
     SystemObjectMapping(proto::GidMappingKey, proto::GidMappingValue),
     SystemPrivilege(proto::SystemPrivilegesKey, proto::SystemPrivilegesValue),
+    Tag(proto::TagKey, proto::TagValue),
     Timestamp(proto::TimestampKey, proto::TimestampValue),
+    TrashedItem(proto::TrashedItemKey, proto::TrashedItemValue),
+    ColumnMask(proto::ColumnMaskKey, proto::ColumnMaskValue),
+    ServiceAccount(proto::ServiceAccountKey, proto::ServiceAccountValue),
+    ClusterReplicaSize(proto::ClusterReplicaSizeKey, proto::ClusterReplicaSizeValue),
+}
+
+impl StateUpdateKind {
+    /// Returns the name of the collection that this update belongs to, matching the names used by
+    /// [`TransactionBatch::update_counts`], along with a human readable description of its key.
+    ///
+    /// Returns `None` for epoch updates, which don't belong to a keyed collection.
+    pub(crate) fn collection_key(&self) -> Option<(&'static str, String)> {
+        /// Describes `key` by the ID extracted by `id`, falling back to the debug representation
+        /// of the raw proto if it can't be decoded.
+        fn describe<P, R, D>(key: &P, id: impl FnOnce(R) -> D) -> String
+        where
+            P: ProtoType<R> + Clone + Debug,
+            D: Display,
+        {
+            match key.clone().into_rust() {
+                Ok(key) => id(key).to_string(),
+                Err(_) => format!("{key:?}"),
+            }
+        }
+
+        let collection_key = match self {
+            StateUpdateKind::AuditLog(key, ()) => ("audit_log", format!("{key:?}")),
+            StateUpdateKind::Cluster(key, _) => {
+                ("clusters", describe(key, |key: ClusterKey| key.id))
+            }
+            StateUpdateKind::ClusterReplica(key, _) => (
+                "cluster_replicas",
+                describe(key, |key: ClusterReplicaKey| key.id),
+            ),
+            StateUpdateKind::Comment(key, _) => ("comments", format!("{key:?}")),
+            StateUpdateKind::Config(key, _) => ("configs", key.key.clone()),
+            StateUpdateKind::Database(key, _) => {
+                ("databases", describe(key, |key: DatabaseKey| key.id))
+            }
+            StateUpdateKind::DefaultPrivilege(key, _) => ("default_privileges", format!("{key:?}")),
+            StateUpdateKind::Dependency(key, _) => ("dependencies", format!("{key:?}")),
+            StateUpdateKind::Epoch(_) => return None,
+            StateUpdateKind::IdAllocator(key, _) => ("id_allocator", key.name.clone()),
+            StateUpdateKind::IntrospectionSourceIndex(key, _) => {
+                ("introspection_sources", format!("{key:?}"))
+            }
+            StateUpdateKind::Item(key, _) => ("items", describe(key, |key: ItemKey| key.gid)),
+            StateUpdateKind::Role(key, _) => ("roles", describe(key, |key: RoleKey| key.id)),
+            StateUpdateKind::Schema(key, _) => ("schemas", describe(key, |key: SchemaKey| key.id)),
+            StateUpdateKind::Setting(key, _) => ("settings", key.name.clone()),
+            StateUpdateKind::StorageUsage(key, ()) => ("storage_usage", format!("{key:?}")),
+            StateUpdateKind::SystemConfiguration(key, _) => {
+                ("system_configurations", key.name.clone())
+            }
+            StateUpdateKind::SystemObjectMapping(key, _) => {
+                ("system_gid_mapping", format!("{key:?}"))
+            }
+            StateUpdateKind::SystemPrivilege(key, _) => ("system_privileges", format!("{key:?}")),
+            StateUpdateKind::Tag(key, _) => ("tags", format!("{key:?}")),
+            StateUpdateKind::Timestamp(key, _) => ("timestamps", key.id.clone()),
+            StateUpdateKind::TrashedItem(key, _) => (
+                "trashed_items",
+                describe(key, |key: TrashedItemKey| key.gid),
+            ),
+            StateUpdateKind::ColumnMask(key, _) => (
+                "column_masks",
+                describe(key, |key: ColumnMaskKey| {
+                    format!("{}.{} for {}", key.item_id, key.column, key.role_id)
+                }),
+            ),
+            StateUpdateKind::ServiceAccount(key, _) => (
+                "service_accounts",
+                describe(key, |key: ServiceAccountKey| key.id),
+            ),
+            StateUpdateKind::ClusterReplicaSize(key, _) => {
+                ("cluster_replica_sizes", key.name.clone())
+            }
+        };
+        Some(collection_key)
+    }
 }
 
 impl RustType<proto::StateUpdateKind> for StateUpdateKind {
@@ -243,6 +353,14 @@ impl RustType<proto::StateUpdateKind> for StateUpdateKind {
                         },
                     )
                 }
+                StateUpdateKind::Dependency(key, value) => {
+                    proto::state_update_kind::Kind::Dependency(
+                        proto::state_update_kind::Dependency {
+                            key: Some(key.clone()),
+                            value: Some(value.clone()),
+                        },
+                    )
+                }
                 StateUpdateKind::Epoch(epoch) => {
                     proto::state_update_kind::Kind::Epoch(proto::state_update_kind::Epoch {
                         epoch: epoch.get(),
@@ -317,12 +435,50 @@ impl RustType<proto::StateUpdateKind> for StateUpdateKind {
                         },
                     )
                 }
+                StateUpdateKind::Tag(key, value) => {
+                    proto::state_update_kind::Kind::Tag(proto::state_update_kind::Tag {
+                        key: Some(key.clone()),
+                        value: Some(value.clone()),
+                    })
+                }
                 StateUpdateKind::Timestamp(key, value) => {
                     proto::state_update_kind::Kind::Timestamp(proto::state_update_kind::Timestamp {
                         key: Some(key.clone()),
                         value: Some(value.clone()),
                     })
                 }
+                StateUpdateKind::TrashedItem(key, value) => {
+                    proto::state_update_kind::Kind::TrashedItem(
+                        proto::state_update_kind::TrashedItem {
+                            key: Some(key.clone()),
+                            value: Some(value.clone()),
+                        },
+                    )
+                }
+                StateUpdateKind::ColumnMask(key, value) => {
+                    proto::state_update_kind::Kind::ColumnMask(
+                        proto::state_update_kind::ColumnMask {
+                            key: Some(key.clone()),
+                            value: Some(value.clone()),
+                        },
+                    )
+                }
+                StateUpdateKind::ServiceAccount(key, value) => {
+                    proto::state_update_kind::Kind::ServiceAccount(
+                        proto::state_update_kind::ServiceAccount {
+                            key: Some(key.clone()),
+                            value: Some(value.clone()),
+                        },
+                    )
+                }
+                StateUpdateKind::ClusterReplicaSize(key, value) => {
+                    proto::state_update_kind::Kind::ClusterReplicaSize(
+                        proto::state_update_kind::ClusterReplicaSize {
+                            key: Some(key.clone()),
+                            value: Some(value.clone()),
+                        },
+                    )
+                }
             }),
         }
     }
@@ -409,6 +565,16 @@ impl RustType<proto::StateUpdateKind> for StateUpdateKind {
                         )
                     })?,
                 ),
+                proto::state_update_kind::Kind::Dependency(
+                    proto::state_update_kind::Dependency { key, value },
+                ) => StateUpdateKind::Dependency(
+                    key.ok_or_else(|| {
+                        TryFromProtoError::missing_field("state_update_kind::Dependency::key")
+                    })?,
+                    value.ok_or_else(|| {
+                        TryFromProtoError::missing_field("state_update_kind::Dependency::value")
+                    })?,
+                ),
                 proto::state_update_kind::Kind::Epoch(proto::state_update_kind::Epoch {
                     epoch,
                 }) => StateUpdateKind::Epoch(Epoch::new(epoch).ok_or_else(|| {
@@ -527,6 +693,17 @@ impl RustType<proto::StateUpdateKind> for StateUpdateKind {
                         )
                     })?,
                 ),
+                proto::state_update_kind::Kind::Tag(proto::state_update_kind::Tag {
+                    key,
+                    value,
+                }) => StateUpdateKind::Tag(
+                    key.ok_or_else(|| {
+                        TryFromProtoError::missing_field("state_update_kind::Tag::key")
+                    })?,
+                    value.ok_or_else(|| {
+                        TryFromProtoError::missing_field("state_update_kind::Tag::value")
+                    })?,
+                ),
                 proto::state_update_kind::Kind::Timestamp(
                     proto::state_update_kind::Timestamp { key, value },
                 ) => StateUpdateKind::Timestamp(
@@ -537,6 +714,50 @@ impl RustType<proto::StateUpdateKind> for StateUpdateKind {
                         TryFromProtoError::missing_field("state_update_kind::Timestamp::value")
                     })?,
                 ),
+                proto::state_update_kind::Kind::TrashedItem(
+                    proto::state_update_kind::TrashedItem { key, value },
+                ) => StateUpdateKind::TrashedItem(
+                    key.ok_or_else(|| {
+                        TryFromProtoError::missing_field("state_update_kind::TrashedItem::key")
+                    })?,
+                    value.ok_or_else(|| {
+                        TryFromProtoError::missing_field("state_update_kind::TrashedItem::value")
+                    })?,
+                ),
+                proto::state_update_kind::Kind::ColumnMask(
+                    proto::state_update_kind::ColumnMask { key, value },
+                ) => StateUpdateKind::ColumnMask(
+                    key.ok_or_else(|| {
+                        TryFromProtoError::missing_field("state_update_kind::ColumnMask::key")
+                    })?,
+                    value.ok_or_else(|| {
+                        TryFromProtoError::missing_field("state_update_kind::ColumnMask::value")
+                    })?,
+                ),
+                proto::state_update_kind::Kind::ServiceAccount(
+                    proto::state_update_kind::ServiceAccount { key, value },
+                ) => StateUpdateKind::ServiceAccount(
+                    key.ok_or_else(|| {
+                        TryFromProtoError::missing_field("state_update_kind::ServiceAccount::key")
+                    })?,
+                    value.ok_or_else(|| {
+                        TryFromProtoError::missing_field("state_update_kind::ServiceAccount::value")
+                    })?,
+                ),
+                proto::state_update_kind::Kind::ClusterReplicaSize(
+                    proto::state_update_kind::ClusterReplicaSize { key, value },
+                ) => StateUpdateKind::ClusterReplicaSize(
+                    key.ok_or_else(|| {
+                        TryFromProtoError::missing_field(
+                            "state_update_kind::ClusterReplicaSize::key",
+                        )
+                    })?,
+                    value.ok_or_else(|| {
+                        TryFromProtoError::missing_field(
+                            "state_update_kind::ClusterReplicaSize::value",
+                        )
+                    })?,
+                ),
             },
         )
     }
@@ -551,6 +772,12 @@ pub struct StateUpdateKindSchema;
 
 impl From<StateUpdateKind> for StateUpdateKindBinary {
     fn from(value: StateUpdateKind) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&StateUpdateKind> for StateUpdateKindBinary {
+    fn from(value: &StateUpdateKind) -> Self {
         Self(value.into_proto().encode_to_vec())
     }
 }
@@ -31,8 +31,9 @@ use crate::durable::objects::{
 };
 use crate::durable::transaction::TransactionBatch;
 use crate::durable::{
-    BootstrapArgs, CatalogError, DurableCatalogState, Epoch, OpenableDurableCatalogState,
-    ReadOnlyDurableCatalogState, Transaction, STORAGE_USAGE_ID_ALLOC_KEY,
+    AuditLogFilter, BootstrapArgs, CatalogError, DurableCatalogState, Epoch,
+    OpenableDurableCatalogState, ReadOnlyDurableCatalogState, Transaction,
+    STORAGE_USAGE_ID_ALLOC_KEY,
 };
 
 macro_rules! compare_and_return {
@@ -323,6 +324,29 @@ impl ReadOnlyDurableCatalogState for ShadowCatalogState {
         compare_and_return_async!(self, get_audit_logs)
     }
 
+    async fn get_audit_logs_filtered(
+        &mut self,
+        filter: &AuditLogFilter,
+    ) -> Result<Vec<VersionedEvent>, CatalogError> {
+        compare_and_return_async!(self, get_audit_logs_filtered, filter)
+    }
+
+    async fn get_storage_usage(&mut self) -> Result<Vec<VersionedStorageUsage>, CatalogError> {
+        if self.is_read_only() {
+            // Read-only catalogs cannot fix storage usage so we must ignore them. See
+            // `Self::fix_storage_usage`.
+            let stash_storage_usage = self.stash.get_storage_usage().await?;
+            let persist_storage_usage = self.persist.get_storage_usage().await?;
+            if stash_storage_usage.len() >= persist_storage_usage.len() {
+                Ok(stash_storage_usage)
+            } else {
+                Ok(persist_storage_usage)
+            }
+        } else {
+            compare_and_return_async!(self, get_storage_usage)
+        }
+    }
+
     async fn get_next_id(&mut self, id_type: &str) -> Result<u64, CatalogError> {
         if self.is_read_only() && id_type == STORAGE_USAGE_ID_ALLOC_KEY {
             // Read-only catalogs cannot fix storage usage so we must ignore them. See
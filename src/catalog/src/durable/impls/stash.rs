@@ -29,7 +29,7 @@ use mz_proto::{ProtoType, RustType, TryFromProtoError};
 use mz_repr::Timestamp;
 use mz_sql::catalog::CatalogError as SqlCatalogError;
 use mz_stash::{AppendBatch, DebugStashFactory, Diff, Stash, StashFactory, TypedCollection};
-use mz_stash_types::StashError;
+use mz_stash_types::{InternalStashError, StashError};
 use mz_storage_types::sources::Timeline;
 
 use crate::durable::debug::{Collection, CollectionTrace, Trace};
@@ -44,8 +44,9 @@ use crate::durable::objects::{
 use crate::durable::transaction::{Transaction, TransactionBatch};
 use crate::durable::upgrade::stash::upgrade;
 use crate::durable::{
-    initialize, BootstrapArgs, CatalogError, DebugCatalogState, DurableCatalogError,
-    DurableCatalogState, Epoch, OpenableDurableCatalogState, ReadOnlyDurableCatalogState,
+    initialize, AuditLogFilter, BootstrapArgs, CatalogError, DebugCatalogState,
+    DurableCatalogError, DurableCatalogState, Epoch, OpenableDurableCatalogState,
+    ReadOnlyDurableCatalogState,
 };
 
 pub const SETTING_COLLECTION: TypedCollection<proto::SettingKey, proto::SettingValue> =
@@ -68,6 +69,24 @@ pub const ITEM_COLLECTION: TypedCollection<proto::ItemKey, proto::ItemValue> =
     TypedCollection::new("item");
 pub const COMMENTS_COLLECTION: TypedCollection<proto::CommentKey, proto::CommentValue> =
     TypedCollection::new("comments");
+pub const TAGS_COLLECTION: TypedCollection<proto::TagKey, proto::TagValue> =
+    TypedCollection::new("tags");
+pub const DEPENDENCIES_COLLECTION: TypedCollection<proto::DependencyKey, proto::DependencyValue> =
+    TypedCollection::new("dependencies");
+pub const TRASHED_ITEMS_COLLECTION: TypedCollection<
+    proto::TrashedItemKey,
+    proto::TrashedItemValue,
+> = TypedCollection::new("trashed_items");
+pub const COLUMN_MASKS_COLLECTION: TypedCollection<proto::ColumnMaskKey, proto::ColumnMaskValue> =
+    TypedCollection::new("column_masks");
+pub const SERVICE_ACCOUNTS_COLLECTION: TypedCollection<
+    proto::ServiceAccountKey,
+    proto::ServiceAccountValue,
+> = TypedCollection::new("service_accounts");
+pub const CLUSTER_REPLICA_SIZES_COLLECTION: TypedCollection<
+    proto::ClusterReplicaSizeKey,
+    proto::ClusterReplicaSizeValue,
+> = TypedCollection::new("cluster_replica_sizes");
 pub const TIMESTAMP_COLLECTION: TypedCollection<proto::TimestampKey, proto::TimestampValue> =
     TypedCollection::new("timestamp");
 pub const SYSTEM_CONFIGURATION_COLLECTION: TypedCollection<
@@ -289,6 +308,12 @@ impl OpenableDurableCatalogState for OpenableConnection {
             system_object_mappings,
             system_configurations,
             system_privileges,
+            tags,
+            dependencies,
+            trashed_items,
+            column_masks,
+            service_accounts,
+            cluster_replica_sizes,
         ): (
             Vec<((proto::AuditLogKey, ()), _, _)>,
             Vec<((proto::ClusterKey, proto::ClusterValue), _, _)>,
@@ -330,6 +355,16 @@ impl OpenableDurableCatalogState for OpenableConnection {
                 _,
                 _,
             )>,
+            Vec<((proto::TagKey, proto::TagValue), _, _)>,
+            Vec<((proto::DependencyKey, proto::DependencyValue), _, _)>,
+            Vec<((proto::TrashedItemKey, proto::TrashedItemValue), _, _)>,
+            Vec<((proto::ColumnMaskKey, proto::ColumnMaskValue), _, _)>,
+            Vec<((proto::ServiceAccountKey, proto::ServiceAccountValue), _, _)>,
+            Vec<(
+                (proto::ClusterReplicaSizeKey, proto::ClusterReplicaSizeValue),
+                _,
+                _,
+            )>,
         ) = stash
             .with_transaction(|tx| {
                 Box::pin(async move {
@@ -359,6 +394,15 @@ impl OpenableDurableCatalogState for OpenableConnection {
                                 .await?
                         ),
                         tx.iter(tx.collection(SYSTEM_PRIVILEGES_COLLECTION.name()).await?),
+                        tx.iter(tx.collection(TAGS_COLLECTION.name()).await?),
+                        tx.iter(tx.collection(DEPENDENCIES_COLLECTION.name()).await?),
+                        tx.iter(tx.collection(TRASHED_ITEMS_COLLECTION.name()).await?),
+                        tx.iter(tx.collection(COLUMN_MASKS_COLLECTION.name()).await?),
+                        tx.iter(tx.collection(SERVICE_ACCOUNTS_COLLECTION.name()).await?),
+                        tx.iter(
+                            tx.collection(CLUSTER_REPLICA_SIZES_COLLECTION.name())
+                                .await?
+                        ),
                     )
                 })
             })
@@ -383,6 +427,12 @@ impl OpenableDurableCatalogState for OpenableConnection {
             system_object_mappings: stringify(system_object_mappings),
             system_configurations: stringify(system_configurations),
             system_privileges: stringify(system_privileges),
+            tags: stringify(tags),
+            dependencies: stringify(dependencies),
+            trashed_items: stringify(trashed_items),
+            column_masks: stringify(column_masks),
+            service_accounts: stringify(service_accounts),
+            cluster_replica_sizes: stringify(cluster_replica_sizes),
         })
     }
 
@@ -580,6 +630,34 @@ impl ReadOnlyDurableCatalogState for Connection {
         Ok(logs)
     }
 
+    #[tracing::instrument(level = "info", skip_all)]
+    async fn get_audit_logs_filtered(
+        &mut self,
+        filter: &AuditLogFilter,
+    ) -> Result<Vec<VersionedEvent>, CatalogError> {
+        let entries = AUDIT_LOG_COLLECTION.peek_one(&mut self.stash).await?;
+        let events = entries
+            .into_keys()
+            .map(AuditLogKey::from_proto)
+            .map_ok(|e| e.event);
+        Ok(itertools::process_results(events, |events| {
+            filter.page(events)
+        })?)
+    }
+
+    #[tracing::instrument(level = "info", skip_all)]
+    async fn get_storage_usage(&mut self) -> Result<Vec<VersionedStorageUsage>, CatalogError> {
+        let entries = STORAGE_USAGE_COLLECTION.peek_one(&mut self.stash).await?;
+        let mut events: Vec<_> = entries
+            .into_keys()
+            .map(StorageUsageKey::from_proto)
+            .map_ok(|key| key.metric)
+            .collect::<Result<_, _>>()?;
+        events.sort_by(|a, b| a.sortable_id().cmp(&b.sortable_id()));
+
+        Ok(events)
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     async fn get_next_id(&mut self, id_type: &str) -> Result<u64, CatalogError> {
         ID_ALLOCATOR_COLLECTION
@@ -635,6 +713,12 @@ impl ReadOnlyDurableCatalogState for Connection {
             system_configurations,
             default_privileges,
             system_privileges,
+            tags,
+            dependencies,
+            trashed_items,
+            column_masks,
+            service_accounts,
+            cluster_replica_sizes,
         ): (
             BTreeMap<proto::DatabaseKey, proto::DatabaseValue>,
             BTreeMap<proto::SchemaKey, proto::SchemaValue>,
@@ -655,6 +739,12 @@ impl ReadOnlyDurableCatalogState for Connection {
             BTreeMap<proto::ServerConfigurationKey, proto::ServerConfigurationValue>,
             BTreeMap<proto::DefaultPrivilegesKey, proto::DefaultPrivilegesValue>,
             BTreeMap<proto::SystemPrivilegesKey, proto::SystemPrivilegesValue>,
+            BTreeMap<proto::TagKey, proto::TagValue>,
+            BTreeMap<proto::DependencyKey, proto::DependencyValue>,
+            BTreeMap<proto::TrashedItemKey, proto::TrashedItemValue>,
+            BTreeMap<proto::ColumnMaskKey, proto::ColumnMaskValue>,
+            BTreeMap<proto::ServiceAccountKey, proto::ServiceAccountValue>,
+            BTreeMap<proto::ClusterReplicaSizeKey, proto::ClusterReplicaSizeValue>,
         ) = self
             .stash
             .with_transaction(|tx| {
@@ -683,6 +773,15 @@ impl ReadOnlyDurableCatalogState for Connection {
                         ),
                         tx.peek_one(tx.collection(DEFAULT_PRIVILEGES_COLLECTION.name()).await?),
                         tx.peek_one(tx.collection(SYSTEM_PRIVILEGES_COLLECTION.name()).await?),
+                        tx.peek_one(tx.collection(TAGS_COLLECTION.name()).await?),
+                        tx.peek_one(tx.collection(DEPENDENCIES_COLLECTION.name()).await?),
+                        tx.peek_one(tx.collection(TRASHED_ITEMS_COLLECTION.name()).await?),
+                        tx.peek_one(tx.collection(COLUMN_MASKS_COLLECTION.name()).await?),
+                        tx.peek_one(tx.collection(SERVICE_ACCOUNTS_COLLECTION.name()).await?),
+                        tx.peek_one(
+                            tx.collection(CLUSTER_REPLICA_SIZES_COLLECTION.name())
+                                .await?
+                        ),
                     )
                 })
             })
@@ -705,6 +804,12 @@ impl ReadOnlyDurableCatalogState for Connection {
             system_configurations,
             default_privileges,
             system_privileges,
+            tags,
+            dependencies,
+            trashed_items,
+            column_masks,
+            service_accounts,
+            cluster_replica_sizes,
         })
     }
 }
@@ -778,6 +883,15 @@ impl DurableCatalogState for Connection {
                 .stash
                 .with_transaction(move |tx| {
                     Box::pin(async move {
+                        // Read the epoch in the same stash transaction that writes the batch,
+                        // so that we check the epoch that the batch is committed at.
+                        if txn_batch.expected_epoch.is_some() {
+                            let epoch = tx.epoch().await?;
+                            txn_batch
+                                .check_epoch(epoch)
+                                .map_err(|e| InternalStashError::Fence(e.to_string()))?;
+                        }
+
                         let mut batches = Vec::new();
 
                         add_batch(
@@ -908,6 +1022,54 @@ impl DurableCatalogState for Connection {
                             is_initialized,
                         )
                         .await?;
+                        add_batch(
+                            &tx,
+                            &mut batches,
+                            &TAGS_COLLECTION,
+                            &txn_batch.tags,
+                            is_initialized,
+                        )
+                        .await?;
+                        add_batch(
+                            &tx,
+                            &mut batches,
+                            &DEPENDENCIES_COLLECTION,
+                            &txn_batch.dependencies,
+                            is_initialized,
+                        )
+                        .await?;
+                        add_batch(
+                            &tx,
+                            &mut batches,
+                            &TRASHED_ITEMS_COLLECTION,
+                            &txn_batch.trashed_items,
+                            is_initialized,
+                        )
+                        .await?;
+                        add_batch(
+                            &tx,
+                            &mut batches,
+                            &COLUMN_MASKS_COLLECTION,
+                            &txn_batch.column_masks,
+                            is_initialized,
+                        )
+                        .await?;
+                        add_batch(
+                            &tx,
+                            &mut batches,
+                            &SERVICE_ACCOUNTS_COLLECTION,
+                            &txn_batch.service_accounts,
+                            is_initialized,
+                        )
+                        .await?;
+                        add_batch(
+                            &tx,
+                            &mut batches,
+                            &CLUSTER_REPLICA_SIZES_COLLECTION,
+                            &txn_batch.cluster_replica_sizes,
+                            is_initialized,
+                        )
+                        .await?;
                         add_batch(
                             &tx,
                             &mut batches,
@@ -1076,20 +1238,26 @@ pub const ALL_COLLECTIONS: &[&str] = &[
     CLUSTER_COLLECTION.name(),
     CLUSTER_INTROSPECTION_SOURCE_INDEX_COLLECTION.name(),
     CLUSTER_REPLICA_COLLECTION.name(),
+    CLUSTER_REPLICA_SIZES_COLLECTION.name(),
+    COLUMN_MASKS_COLLECTION.name(),
     COMMENTS_COLLECTION.name(),
     CONFIG_COLLECTION.name(),
     DATABASES_COLLECTION.name(),
     DEFAULT_PRIVILEGES_COLLECTION.name(),
+    DEPENDENCIES_COLLECTION.name(),
     ID_ALLOCATOR_COLLECTION.name(),
     ITEM_COLLECTION.name(),
     ROLES_COLLECTION.name(),
     SCHEMAS_COLLECTION.name(),
+    SERVICE_ACCOUNTS_COLLECTION.name(),
     SETTING_COLLECTION.name(),
     STORAGE_USAGE_COLLECTION.name(),
     SYSTEM_CONFIGURATION_COLLECTION.name(),
     SYSTEM_GID_MAPPING_COLLECTION.name(),
     SYSTEM_PRIVILEGES_COLLECTION.name(),
+    TAGS_COLLECTION.name(),
     TIMESTAMP_COLLECTION.name(),
+    TRASHED_ITEMS_COLLECTION.name(),
 ];
 
 /// A [`TestOpenableConnection`] represent a struct capable of opening a debug connection to the
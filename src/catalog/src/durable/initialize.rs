@@ -12,7 +12,7 @@ use std::time::Duration;
 use itertools::max;
 use mz_audit_log::{EventV1, VersionedEvent};
 use mz_controller::clusters::ReplicaLogging;
-use mz_controller_types::{ClusterId, ReplicaId};
+use mz_controller_types::{ClusterId, ClusterPriority, ReplicaId};
 use mz_ore::now::EpochMillis;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem};
 use mz_repr::role_id::RoleId;
@@ -32,9 +32,9 @@ use crate::durable::upgrade::CATALOG_VERSION;
 use crate::durable::{
     BootstrapArgs, CatalogError, ClusterConfig, ClusterVariant, ClusterVariantManaged,
     DefaultPrivilege, ReplicaConfig, ReplicaLocation, Role, Schema, Transaction,
-    AUDIT_LOG_ID_ALLOC_KEY, DATABASE_ID_ALLOC_KEY, SCHEMA_ID_ALLOC_KEY, STORAGE_USAGE_ID_ALLOC_KEY,
-    SYSTEM_CLUSTER_ID_ALLOC_KEY, SYSTEM_REPLICA_ID_ALLOC_KEY, USER_CLUSTER_ID_ALLOC_KEY,
-    USER_REPLICA_ID_ALLOC_KEY, USER_ROLE_ID_ALLOC_KEY,
+    AUDIT_LOG_ID_ALLOC_KEY, DATABASE_ID_ALLOC_KEY, NAMESPACED_ID_ALLOCATORS, SCHEMA_ID_ALLOC_KEY,
+    STORAGE_USAGE_ID_ALLOC_KEY, SYSTEM_CLUSTER_ID_ALLOC_KEY, SYSTEM_REPLICA_ID_ALLOC_KEY,
+    USER_CLUSTER_ID_ALLOC_KEY, USER_REPLICA_ID_ALLOC_KEY, USER_ROLE_ID_ALLOC_KEY,
 };
 
 /// The key used within the "config" collection stores the deploy generation.
@@ -51,6 +51,10 @@ pub(crate) const SYSTEM_CONFIG_SYNCED_KEY: &str = "system_config_synced";
 /// Darkly is available.
 pub(crate) const PERSIST_TXN_TABLES: &str = "persist_txn_tables";
 
+/// The key used within the "config" collection that stores how long dropped items are kept in the
+/// trash, in milliseconds. If absent or zero, dropped items are removed immediately.
+pub(crate) const ITEM_TRASH_RETENTION_KEY: &str = "item_trash_retention";
+
 const USER_ID_ALLOC_KEY: &str = "user";
 const SYSTEM_ID_ALLOC_KEY: &str = "system";
 
@@ -125,7 +129,13 @@ pub async fn initialize(
         ),
         (AUDIT_LOG_ID_ALLOC_KEY.to_string(), DEFAULT_ALLOCATOR_ID),
         (STORAGE_USAGE_ID_ALLOC_KEY.to_string(), DEFAULT_ALLOCATOR_ID),
-    ] {
+    ]
+    .into_iter()
+    .chain(
+        NAMESPACED_ID_ALLOCATORS
+            .iter()
+            .map(|(name, next_id)| (name.to_string(), *next_id)),
+    ) {
         tx.insert_id_allocator(name, next_id)?;
     }
 
@@ -619,7 +629,11 @@ fn default_cluster_config(args: &BootstrapArgs) -> ClusterConfig {
             },
             idle_arrangement_merge_effort: None,
             disk: false,
+            schedule: vec![],
         }),
+        priority: ClusterPriority::default(),
+        memory_quota: None,
+        resize: None,
     }
 }
 
@@ -629,6 +643,7 @@ fn default_replica_config(args: &BootstrapArgs) -> ReplicaConfig {
         location: ReplicaLocation::Managed {
             size: args.default_cluster_replica_size.to_string(),
             availability_zone: None,
+            availability_zone_constraints: vec![],
             disk: false,
             internal: false,
             billed_as: None,
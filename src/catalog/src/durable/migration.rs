@@ -0,0 +1,162 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Versioned migrations of the contents of the durable catalog.
+//!
+//! Unlike upgrades, which change the format that the durable catalog is stored in, migrations
+//! change the contents of the durable catalog through a [`Transaction`]. Every applied migration
+//! is durably recorded along with the time that it was applied, so that it is only ever applied
+//! once and can later be rolled back.
+
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+use mz_ore::now::EpochMillis;
+
+use crate::durable::{CatalogError, DurableCatalogError, Transaction};
+
+/// A function that applies or reverts a [`Migration`].
+pub type MigrationFn = fn(&mut Transaction<'_>) -> Result<(), CatalogError>;
+
+/// A versioned change to the contents of the durable catalog.
+#[derive(Debug, Clone)]
+pub struct Migration {
+    /// The version of the migration. Migrations are applied in increasing order of version and
+    /// rolled back in decreasing order of version.
+    pub version: u64,
+    /// A short description of the migration.
+    pub name: &'static str,
+    /// Applies the migration.
+    pub up: MigrationFn,
+    /// Reverts the changes made by `up`.
+    pub down: MigrationFn,
+}
+
+/// A migration that has been applied to the durable catalog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedMigration {
+    pub version: u64,
+    pub name: String,
+    /// The time that the migration was applied at.
+    pub applied_at: EpochMillis,
+}
+
+/// An ordered set of [`Migration`]s.
+#[derive(Debug, Clone)]
+pub struct Migrations {
+    migrations: BTreeMap<u64, Migration>,
+}
+
+impl Migrations {
+    /// Registers `migrations`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if two migrations have the same version.
+    pub fn new(migrations: impl IntoIterator<Item = Migration>) -> Migrations {
+        let mut by_version = BTreeMap::new();
+        for migration in migrations {
+            let version = migration.version;
+            let prev = by_version.insert(version, migration);
+            assert!(
+                prev.is_none(),
+                "duplicate catalog migration version {version}"
+            );
+        }
+        Migrations {
+            migrations: by_version,
+        }
+    }
+
+    /// Returns the migrations that have not been applied in `tx`, in the order that they would be
+    /// applied.
+    pub fn pending(&self, tx: &Transaction) -> Vec<&Migration> {
+        let applied = tx.get_applied_migrations();
+        self.migrations
+            .values()
+            .filter(|migration| !applied.contains_key(&migration.version))
+            .collect()
+    }
+
+    /// Applies all pending migrations to `tx` in increasing order of version, and records them as
+    /// applied at `now`. Returns the versions of the applied migrations.
+    ///
+    /// If `dry_run` is true, then all changes made by the migrations are discarded from `tx` once
+    /// they have all succeeded. If any migration fails, then all changes made by this call are
+    /// discarded from `tx`.
+    pub fn migrate(
+        &self,
+        tx: &mut Transaction,
+        now: EpochMillis,
+        dry_run: bool,
+    ) -> Result<Vec<u64>, CatalogError> {
+        let pending = self.pending(tx);
+        run(tx, dry_run, |tx| {
+            let mut versions = Vec::with_capacity(pending.len());
+            for migration in pending {
+                (migration.up)(tx)?;
+                tx.insert_applied_migration(AppliedMigration {
+                    version: migration.version,
+                    name: migration.name.to_string(),
+                    applied_at: now,
+                })?;
+                versions.push(migration.version);
+            }
+            Ok(versions)
+        })
+    }
+
+    /// Rolls back all applied migrations with a version greater than `version` from `tx` in
+    /// decreasing order of version. Returns the versions of the rolled back migrations.
+    ///
+    /// Returns an error if an applied migration that needs to be rolled back is not registered.
+    ///
+    /// If `dry_run` is true, then all changes made by the migrations are discarded from `tx` once
+    /// they have all succeeded. If any migration fails, then all changes made by this call are
+    /// discarded from `tx`.
+    pub fn rollback(
+        &self,
+        tx: &mut Transaction,
+        version: u64,
+        dry_run: bool,
+    ) -> Result<Vec<u64>, CatalogError> {
+        let applied = tx.get_applied_migrations();
+        run(tx, dry_run, |tx| {
+            let mut versions = Vec::new();
+            for applied_version in applied
+                .range((Bound::Excluded(version), Bound::Unbounded))
+                .map(|(version, _)| *version)
+                .rev()
+            {
+                let migration = self
+                    .migrations
+                    .get(&applied_version)
+                    .ok_or(DurableCatalogError::UnknownMigration(applied_version))?;
+                (migration.down)(tx)?;
+                tx.remove_applied_migration(applied_version)?;
+                versions.push(applied_version);
+            }
+            Ok(versions)
+        })
+    }
+}
+
+/// Runs `f` against `tx`, discarding its changes if `dry_run` is true or if it fails.
+fn run<T>(
+    tx: &mut Transaction,
+    dry_run: bool,
+    f: impl FnOnce(&mut Transaction) -> Result<T, CatalogError>,
+) -> Result<T, CatalogError> {
+    let savepoint = tx.savepoint();
+    let res = f(tx);
+    if dry_run || res.is_err() {
+        tx.rollback_to_savepoint(savepoint);
+    }
+    res
+}
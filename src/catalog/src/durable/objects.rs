@@ -9,23 +9,31 @@
 
 pub mod serialization;
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use mz_audit_log::{VersionedEvent, VersionedStorageUsage};
 use mz_controller::clusters::ReplicaLogging;
-use mz_controller_types::{ClusterId, ReplicaId};
+use mz_controller_types::{
+    ClusterId, ClusterPriority, ReplicaAvailabilityZoneConstraint, ReplicaId,
+};
+use mz_ore::collections::CollectionExt;
+use mz_ore::now::EpochMillis;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem};
 use mz_repr::role_id::RoleId;
 use mz_repr::GlobalId;
+use mz_sql::ast::visit::Visit;
+use mz_sql::ast::{Raw, RawClusterName, RawItemName, Statement};
 use mz_sql::catalog::{
     CatalogItemType, DefaultPrivilegeAclItem, DefaultPrivilegeObject, ObjectType, RoleAttributes,
     RoleMembership, RoleVars,
 };
 use mz_sql::names::{CommentObjectId, DatabaseId, SchemaId};
+use mz_sql::plan::ClusterScheduleEntry;
 use mz_storage_types::sources::Timeline;
 use proptest_derive::Arbitrary;
 
 use crate::durable::objects::serialization::proto;
+use crate::durable::DurableCatalogError;
 
 // Structs used to pass information to outside modules.
 
@@ -185,6 +193,17 @@ impl DurableType<ClusterKey, ClusterValue> for Cluster {
 #[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord)]
 pub struct ClusterConfig {
     pub variant: ClusterVariant,
+    pub priority: ClusterPriority,
+    pub memory_quota: Option<u64>,
+    pub resize: Option<ClusterResize>,
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord)]
+pub struct ClusterResize {
+    pub old_replicas: BTreeSet<ReplicaId>,
+    pub new_replicas: BTreeSet<ReplicaId>,
+    pub cutover_frontier: mz_repr::Timestamp,
+    pub old_config: ClusterVariantManaged,
 }
 
 #[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord)]
@@ -201,6 +220,7 @@ pub struct ClusterVariantManaged {
     pub idle_arrangement_merge_effort: Option<u32>,
     pub replication_factor: u32,
     pub disk: bool,
+    pub schedule: Vec<ClusterScheduleEntry>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -259,6 +279,7 @@ pub struct ClusterReplica {
     pub name: String,
     pub config: ReplicaConfig,
     pub owner_id: RoleId,
+    pub draining: bool,
 }
 
 impl DurableType<ClusterReplicaKey, ClusterReplicaValue> for ClusterReplica {
@@ -272,6 +293,7 @@ impl DurableType<ClusterReplicaKey, ClusterReplicaValue> for ClusterReplica {
                 name: self.name,
                 config: self.config,
                 owner_id: self.owner_id,
+                draining: self.draining,
             },
         )
     }
@@ -283,6 +305,7 @@ impl DurableType<ClusterReplicaKey, ClusterReplicaValue> for ClusterReplica {
             name: value.name,
             config: value.config,
             owner_id: value.owner_id,
+            draining: value.draining,
         }
     }
 }
@@ -320,6 +343,8 @@ pub enum ReplicaLocation {
         size: String,
         /// `Some(az)` if the AZ was specified by the user and must be respected;
         availability_zone: Option<String>,
+        /// Constraints on the AZ relative to other replicas of the cluster.
+        availability_zone_constraints: Vec<ReplicaAvailabilityZoneConstraint>,
         disk: bool,
         internal: bool,
         billed_as: Option<String>,
@@ -349,6 +374,7 @@ impl From<mz_controller::clusters::ReplicaLocation> for ReplicaLocation {
                     allocation: _,
                     size,
                     availability_zones,
+                    availability_zone_constraints,
                     disk,
                     billed_as,
                     internal,
@@ -364,6 +390,7 @@ impl From<mz_controller::clusters::ReplicaLocation> for ReplicaLocation {
                     } else {
                         None
                     },
+                availability_zone_constraints,
                 disk,
                 internal,
                 billed_as,
@@ -536,6 +563,288 @@ impl DurableType<CommentKey, CommentValue> for Comment {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tag {
+    pub object_id: CommentObjectId,
+    pub name: String,
+    pub value: String,
+}
+
+impl DurableType<TagKey, TagValue> for Tag {
+    fn into_key_value(self) -> (TagKey, TagValue) {
+        (
+            TagKey {
+                object_id: self.object_id,
+                name: self.name,
+            },
+            TagValue { value: self.value },
+        )
+    }
+
+    fn from_key_value(key: TagKey, value: TagValue) -> Self {
+        Self {
+            object_id: key.object_id,
+            name: key.name,
+            value: value.value,
+        }
+    }
+}
+
+/// An edge from an item to an item that it depends on.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Dependency {
+    pub object_id: GlobalId,
+    pub dependency_id: GlobalId,
+}
+
+impl DurableType<DependencyKey, DependencyValue> for Dependency {
+    fn into_key_value(self) -> (DependencyKey, DependencyValue) {
+        (
+            DependencyKey {
+                object_id: self.object_id,
+                dependency_id: self.dependency_id,
+            },
+            DependencyValue {},
+        )
+    }
+
+    fn from_key_value(key: DependencyKey, _value: DependencyValue) -> Self {
+        Self {
+            object_id: key.object_id,
+            dependency_id: key.dependency_id,
+        }
+    }
+}
+
+/// An item that has been dropped, but that can be restored until it is purged from the trash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashedItem {
+    pub item: Item,
+    /// The time at which the item was dropped.
+    pub dropped_at: EpochMillis,
+}
+
+impl DurableType<TrashedItemKey, TrashedItemValue> for TrashedItem {
+    fn into_key_value(self) -> (TrashedItemKey, TrashedItemValue) {
+        let (key, value) = self.item.into_key_value();
+        (
+            TrashedItemKey { gid: key.gid },
+            TrashedItemValue {
+                item: value,
+                dropped_at: self.dropped_at,
+            },
+        )
+    }
+
+    fn from_key_value(key: TrashedItemKey, value: TrashedItemValue) -> Self {
+        Self {
+            item: Item::from_key_value(ItemKey { gid: key.gid }, value.item),
+            dropped_at: value.dropped_at,
+        }
+    }
+}
+
+/// An expression that replaces the values of a column of an item when it is queried by a role.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnMask {
+    pub item_id: GlobalId,
+    pub column: String,
+    pub role_id: RoleId,
+    /// The SQL of the masking expression, with all names resolved.
+    pub expr: String,
+}
+
+impl DurableType<ColumnMaskKey, ColumnMaskValue> for ColumnMask {
+    fn into_key_value(self) -> (ColumnMaskKey, ColumnMaskValue) {
+        (
+            ColumnMaskKey {
+                item_id: self.item_id,
+                column: self.column,
+                role_id: self.role_id,
+            },
+            ColumnMaskValue { expr: self.expr },
+        )
+    }
+
+    fn from_key_value(key: ColumnMaskKey, value: ColumnMaskValue) -> Self {
+        Self {
+            item_id: key.item_id,
+            column: key.column,
+            role_id: key.role_id,
+            expr: value.expr,
+        }
+    }
+}
+
+/// A non-human identity that authenticates as a role with a token, rather than with the
+/// credentials of a person.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceAccount {
+    pub id: u64,
+    pub name: String,
+    /// The role that the service account authenticates as.
+    pub role_id: RoleId,
+    /// The secret that holds the service account's token.
+    pub token_secret_id: GlobalId,
+    /// The scopes that the token is restricted to. An empty list places no restriction.
+    pub scopes: Vec<String>,
+    /// The time after which the token is no longer accepted, if any.
+    pub expires_at: Option<EpochMillis>,
+}
+
+impl DurableType<ServiceAccountKey, ServiceAccountValue> for ServiceAccount {
+    fn into_key_value(self) -> (ServiceAccountKey, ServiceAccountValue) {
+        (
+            ServiceAccountKey { id: self.id },
+            ServiceAccountValue {
+                name: self.name,
+                role_id: self.role_id,
+                token_secret_id: self.token_secret_id,
+                scopes: self.scopes,
+                expires_at: self.expires_at,
+            },
+        )
+    }
+
+    fn from_key_value(key: ServiceAccountKey, value: ServiceAccountValue) -> Self {
+        Self {
+            id: key.id,
+            name: value.name,
+            role_id: value.role_id,
+            token_secret_id: value.token_secret_id,
+            scopes: value.scopes,
+            expires_at: value.expires_at,
+        }
+    }
+}
+
+/// A named set of resources that cluster replicas can be provisioned with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClusterReplicaSize {
+    pub name: String,
+    /// The memory limit for each process, in bytes.
+    pub memory_limit: Option<u64>,
+    /// The CPU limit for each process, in millicpus.
+    pub cpu_limit: Option<u64>,
+    /// The disk limit for each process, in bytes.
+    pub disk_limit: Option<u64>,
+    /// The number of processes.
+    pub scale: u16,
+    /// The number of worker threads in each process.
+    pub workers: u64,
+    /// The number of credits per hour that the replica consumes, as a decimal string.
+    pub credits_per_hour: String,
+    /// Whether new replicas of this size may be created.
+    pub disabled: bool,
+}
+
+impl DurableType<ClusterReplicaSizeKey, ClusterReplicaSizeValue> for ClusterReplicaSize {
+    fn into_key_value(self) -> (ClusterReplicaSizeKey, ClusterReplicaSizeValue) {
+        (
+            ClusterReplicaSizeKey { name: self.name },
+            ClusterReplicaSizeValue {
+                memory_limit: self.memory_limit,
+                cpu_limit: self.cpu_limit,
+                disk_limit: self.disk_limit,
+                scale: self.scale,
+                workers: self.workers,
+                credits_per_hour: self.credits_per_hour,
+                disabled: self.disabled,
+            },
+        )
+    }
+
+    fn from_key_value(key: ClusterReplicaSizeKey, value: ClusterReplicaSizeValue) -> Self {
+        Self {
+            name: key.name,
+            memory_limit: value.memory_limit,
+            cpu_limit: value.cpu_limit,
+            disk_limit: value.disk_limit,
+            scale: value.scale,
+            workers: value.workers,
+            credits_per_hour: value.credits_per_hour,
+            disabled: value.disabled,
+        }
+    }
+}
+
+/// Returns the ids of the items that an item depends on, which are the items that its
+/// `create_sql` references by id.
+///
+/// Returns an error if `create_sql` is not a single statement or references an invalid id.
+pub(crate) fn item_dependencies(
+    create_sql: &str,
+) -> Result<BTreeSet<GlobalId>, DurableCatalogError> {
+    let create_stmt = parse_create_sql(create_sql)?;
+    Ok(ReferenceCollector::collect(&create_stmt)?.items)
+}
+
+/// Parses the `create_sql` of an item.
+pub(crate) fn parse_create_sql(create_sql: &str) -> Result<Statement<Raw>, DurableCatalogError> {
+    let stmts = mz_sql::parse::parse(create_sql)
+        .map_err(|e| DurableCatalogError::InvalidCreateSql(e.error.to_string()))?;
+    if stmts.len() != 1 {
+        return Err(DurableCatalogError::InvalidCreateSql(format!(
+            "expected a single statement, found {}",
+            stmts.len()
+        )));
+    }
+    Ok(stmts.into_element().ast)
+}
+
+/// Collects the ids of the items and clusters that a statement references.
+#[derive(Default)]
+pub(crate) struct ReferenceCollector {
+    pub(crate) items: BTreeSet<GlobalId>,
+    pub(crate) clusters: BTreeSet<ClusterId>,
+    /// A description of the first reference that is not a valid id, if any.
+    invalid: Option<String>,
+}
+
+impl ReferenceCollector {
+    /// Collects the references of `stmt`.
+    ///
+    /// Returns an error if `stmt` references an item or cluster by an invalid id.
+    pub(crate) fn collect(stmt: &Statement<Raw>) -> Result<Self, DurableCatalogError> {
+        let mut references = ReferenceCollector::default();
+        references.visit_statement(stmt);
+        match references.invalid.take() {
+            Some(invalid) => Err(DurableCatalogError::InvalidCreateSql(invalid)),
+            None => Ok(references),
+        }
+    }
+}
+
+impl<'ast> Visit<'ast, Raw> for ReferenceCollector {
+    fn visit_item_name(&mut self, item_name: &'ast RawItemName) {
+        if let RawItemName::Id(id, _) = item_name {
+            match id.parse() {
+                Ok(id) => {
+                    self.items.insert(id);
+                }
+                Err(_) => {
+                    self.invalid
+                        .get_or_insert_with(|| format!("invalid item id {id}"));
+                }
+            }
+        }
+    }
+
+    fn visit_cluster_name(&mut self, cluster_name: &'ast RawClusterName) {
+        if let RawClusterName::Resolved(id) = cluster_name {
+            match id.parse() {
+                Ok(id) => {
+                    self.clusters.insert(id);
+                }
+                Err(_) => {
+                    self.invalid
+                        .get_or_insert_with(|| format!("invalid cluster id {id}"));
+                }
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct IdAlloc {
     pub name: String,
@@ -697,6 +1006,13 @@ pub struct Snapshot {
         BTreeMap<proto::ServerConfigurationKey, proto::ServerConfigurationValue>,
     pub default_privileges: BTreeMap<proto::DefaultPrivilegesKey, proto::DefaultPrivilegesValue>,
     pub system_privileges: BTreeMap<proto::SystemPrivilegesKey, proto::SystemPrivilegesValue>,
+    pub tags: BTreeMap<proto::TagKey, proto::TagValue>,
+    pub dependencies: BTreeMap<proto::DependencyKey, proto::DependencyValue>,
+    pub trashed_items: BTreeMap<proto::TrashedItemKey, proto::TrashedItemValue>,
+    pub column_masks: BTreeMap<proto::ColumnMaskKey, proto::ColumnMaskValue>,
+    pub service_accounts: BTreeMap<proto::ServiceAccountKey, proto::ServiceAccountValue>,
+    pub cluster_replica_sizes:
+        BTreeMap<proto::ClusterReplicaSizeKey, proto::ClusterReplicaSizeValue>,
 }
 
 impl Snapshot {
@@ -718,6 +1034,12 @@ impl Snapshot {
             system_configurations: BTreeMap::new(),
             default_privileges: BTreeMap::new(),
             system_privileges: BTreeMap::new(),
+            tags: BTreeMap::new(),
+            dependencies: BTreeMap::new(),
+            trashed_items: BTreeMap::new(),
+            column_masks: BTreeMap::new(),
+            service_accounts: BTreeMap::new(),
+            cluster_replica_sizes: BTreeMap::new(),
         }
     }
 
@@ -739,6 +1061,12 @@ impl Snapshot {
             system_configurations,
             default_privileges,
             system_privileges,
+            tags,
+            dependencies,
+            trashed_items,
+            column_masks,
+            service_accounts,
+            cluster_replica_sizes,
         } = self;
         databases.is_empty()
             && schemas.is_empty()
@@ -756,6 +1084,12 @@ impl Snapshot {
             && system_configurations.is_empty()
             && default_privileges.is_empty()
             && system_privileges.is_empty()
+            && tags.is_empty()
+            && dependencies.is_empty()
+            && trashed_items.is_empty()
+            && column_masks.is_empty()
+            && service_accounts.is_empty()
+            && cluster_replica_sizes.is_empty()
     }
 }
 
@@ -828,6 +1162,7 @@ pub struct ClusterReplicaValue {
     pub(crate) name: String,
     pub(crate) config: ReplicaConfig,
     pub(crate) owner_id: RoleId,
+    pub(crate) draining: bool,
 }
 
 #[derive(Clone, Copy, Debug, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
@@ -860,6 +1195,12 @@ pub struct ItemKey {
     pub(crate) gid: GlobalId,
 }
 
+impl ItemKey {
+    pub fn id(&self) -> GlobalId {
+        self.gid
+    }
+}
+
 #[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Arbitrary)]
 pub struct ItemValue {
     pub(crate) schema_id: SchemaId,
@@ -869,6 +1210,28 @@ pub struct ItemValue {
     pub(crate) privileges: Vec<MzAclItem>,
 }
 
+impl ItemValue {
+    pub fn schema_id(&self) -> SchemaId {
+        self.schema_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn create_sql(&self) -> &str {
+        &self.create_sql
+    }
+
+    pub fn owner_id(&self) -> RoleId {
+        self.owner_id
+    }
+
+    pub fn privileges(&self) -> &[MzAclItem] {
+        &self.privileges
+    }
+}
+
 #[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord)]
 pub struct CommentKey {
     pub(crate) object_id: CommentObjectId,
@@ -880,6 +1243,79 @@ pub struct CommentValue {
     pub(crate) comment: String,
 }
 
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord)]
+pub struct TagKey {
+    pub(crate) object_id: CommentObjectId,
+    pub(crate) name: String,
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Arbitrary)]
+pub struct TagValue {
+    pub(crate) value: String,
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Arbitrary)]
+pub struct DependencyKey {
+    pub(crate) object_id: GlobalId,
+    pub(crate) dependency_id: GlobalId,
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Arbitrary)]
+pub struct DependencyValue {}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Arbitrary)]
+pub struct TrashedItemKey {
+    pub(crate) gid: GlobalId,
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Arbitrary)]
+pub struct TrashedItemValue {
+    pub(crate) item: ItemValue,
+    pub(crate) dropped_at: EpochMillis,
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Arbitrary)]
+pub struct ColumnMaskKey {
+    pub(crate) item_id: GlobalId,
+    pub(crate) column: String,
+    pub(crate) role_id: RoleId,
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Arbitrary)]
+pub struct ColumnMaskValue {
+    pub(crate) expr: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialOrd, PartialEq, Eq, Ord, Arbitrary)]
+pub struct ServiceAccountKey {
+    pub(crate) id: u64,
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Arbitrary)]
+pub struct ServiceAccountValue {
+    pub(crate) name: String,
+    pub(crate) role_id: RoleId,
+    pub(crate) token_secret_id: GlobalId,
+    pub(crate) scopes: Vec<String>,
+    pub(crate) expires_at: Option<EpochMillis>,
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord)]
+pub struct ClusterReplicaSizeKey {
+    pub(crate) name: String,
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord)]
+pub struct ClusterReplicaSizeValue {
+    pub(crate) memory_limit: Option<u64>,
+    pub(crate) cpu_limit: Option<u64>,
+    pub(crate) disk_limit: Option<u64>,
+    pub(crate) scale: u16,
+    pub(crate) workers: u64,
+    pub(crate) credits_per_hour: String,
+    pub(crate) disabled: bool,
+}
+
 #[derive(Clone, PartialOrd, PartialEq, Eq, Ord, Hash, Debug)]
 pub struct RoleKey {
     pub(crate) id: RoleId,
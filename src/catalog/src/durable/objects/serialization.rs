@@ -12,13 +12,14 @@
 use mz_audit_log::{
     AlterDefaultPrivilegeV1, AlterSetClusterV1, AlterSourceSinkV1, CreateClusterReplicaV1,
     CreateSourceSinkV1, CreateSourceSinkV2, DropClusterReplicaV1, EventDetails, EventType, EventV1,
-    FullNameV1, GrantRoleV1, GrantRoleV2, IdFullNameV1, IdNameV1, RenameClusterReplicaV1,
-    RenameClusterV1, RenameItemV1, RenameSchemaV1, RevokeRoleV1, RevokeRoleV2, SchemaV1, SchemaV2,
-    StorageUsageV1, UpdateItemV1, UpdateOwnerV1, UpdatePrivilegeV1, VersionedEvent,
+    FullNameV1, GrantRoleV1, GrantRoleV2, IdFullNameV1, IdNameV1, LoginFailureV1,
+    RenameClusterReplicaV1, RenameClusterV1, RenameDatabaseV1, RenameItemV1, RenameSchemaV1,
+    RevokeRoleV1, RevokeRoleV2, ScheduledClusterChangeV1, SchemaV1, SchemaV2, ServiceAccountV1,
+    SessionV1, StorageUsageV1, UpdateItemV1, UpdateOwnerV1, UpdatePrivilegeV1, VersionedEvent,
     VersionedStorageUsage,
 };
 use mz_compute_client::controller::ComputeReplicaLogging;
-use mz_controller_types::ReplicaId;
+use mz_controller_types::{ClusterPriority, ReplicaAvailabilityZoneConstraint, ReplicaId};
 use mz_ore::cast::CastFrom;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem};
@@ -28,6 +29,7 @@ use mz_sql::catalog::{CatalogItemType, ObjectType, RoleAttributes, RoleMembershi
 use mz_sql::names::{
     CommentObjectId, DatabaseId, ResolvedDatabaseSpecifier, SchemaId, SchemaSpecifier,
 };
+use mz_sql::plan::ClusterScheduleEntry;
 use mz_sql::session::vars::OwnedVarInput;
 use mz_storage_types::instances::StorageInstanceId;
 use prost::Message;
@@ -36,15 +38,18 @@ use std::time::Duration;
 use crate::durable::impls::persist::state_update::StateUpdateKindBinary;
 use crate::durable::objects::{
     AuditLogKey, ClusterIntrospectionSourceIndexKey, ClusterIntrospectionSourceIndexValue,
-    ClusterKey, ClusterReplicaKey, ClusterReplicaValue, ClusterValue, CommentKey, CommentValue,
+    ClusterKey, ClusterReplicaKey, ClusterReplicaSizeKey, ClusterReplicaSizeValue,
+    ClusterReplicaValue, ClusterValue, ColumnMaskKey, ColumnMaskValue, CommentKey, CommentValue,
     ConfigKey, ConfigValue, DatabaseKey, DatabaseValue, DefaultPrivilegesKey,
-    DefaultPrivilegesValue, GidMappingKey, GidMappingValue, IdAllocKey, IdAllocValue, ItemKey,
-    ItemValue, RoleKey, RoleValue, SchemaKey, SchemaValue, ServerConfigurationKey,
-    ServerConfigurationValue, SettingKey, SettingValue, StorageUsageKey, SystemPrivilegesKey,
-    SystemPrivilegesValue, TimestampKey, TimestampValue,
+    DefaultPrivilegesValue, DependencyKey, DependencyValue, GidMappingKey, GidMappingValue,
+    IdAllocKey, IdAllocValue, ItemKey, ItemValue, RoleKey, RoleValue, SchemaKey, SchemaValue,
+    ServerConfigurationKey, ServerConfigurationValue, ServiceAccountKey, ServiceAccountValue,
+    SettingKey, SettingValue, StorageUsageKey, SystemPrivilegesKey, SystemPrivilegesValue, TagKey,
+    TagValue, TimestampKey, TimestampValue, TrashedItemKey, TrashedItemValue,
 };
 use crate::durable::{
-    ClusterConfig, ClusterVariant, ClusterVariantManaged, ReplicaConfig, ReplicaLocation,
+    ClusterConfig, ClusterResize, ClusterVariant, ClusterVariantManaged, ReplicaConfig,
+    ReplicaLocation,
 };
 
 pub mod proto {
@@ -69,12 +74,68 @@ impl RustType<proto::ClusterConfig> for ClusterConfig {
     fn into_proto(&self) -> proto::ClusterConfig {
         proto::ClusterConfig {
             variant: Some(self.variant.into_proto()),
+            priority: self.priority.into_proto().into(),
+            memory_quota: self.memory_quota,
+            resize: self.resize.into_proto(),
         }
     }
 
     fn from_proto(proto: proto::ClusterConfig) -> Result<Self, TryFromProtoError> {
+        let priority = proto::ClusterPriority::from_i32(proto.priority)
+            .ok_or_else(|| TryFromProtoError::unknown_enum_variant("ClusterPriority"))?;
         Ok(Self {
             variant: proto.variant.into_rust_if_some("ClusterConfig::variant")?,
+            priority: priority.into_rust()?,
+            memory_quota: proto.memory_quota,
+            resize: proto.resize.into_rust()?,
+        })
+    }
+}
+
+impl RustType<proto::cluster_config::Resize> for ClusterResize {
+    fn into_proto(&self) -> proto::cluster_config::Resize {
+        proto::cluster_config::Resize {
+            old_replicas: self.old_replicas.iter().map(|id| id.into_proto()).collect(),
+            new_replicas: self.new_replicas.iter().map(|id| id.into_proto()).collect(),
+            cutover_frontier: Some(self.cutover_frontier.into_proto()),
+            old_config: Some(self.old_config.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: proto::cluster_config::Resize) -> Result<Self, TryFromProtoError> {
+        Ok(ClusterResize {
+            old_replicas: proto
+                .old_replicas
+                .into_iter()
+                .map(|id| id.into_rust())
+                .collect::<Result<_, _>>()?,
+            new_replicas: proto
+                .new_replicas
+                .into_iter()
+                .map(|id| id.into_rust())
+                .collect::<Result<_, _>>()?,
+            cutover_frontier: proto
+                .cutover_frontier
+                .into_rust_if_some("Resize::cutover_frontier")?,
+            old_config: proto.old_config.into_rust_if_some("Resize::old_config")?,
+        })
+    }
+}
+
+impl RustType<proto::ClusterPriority> for ClusterPriority {
+    fn into_proto(&self) -> proto::ClusterPriority {
+        match self {
+            ClusterPriority::Low => proto::ClusterPriority::Low,
+            ClusterPriority::Normal => proto::ClusterPriority::Normal,
+            ClusterPriority::High => proto::ClusterPriority::High,
+        }
+    }
+
+    fn from_proto(proto: proto::ClusterPriority) -> Result<Self, TryFromProtoError> {
+        Ok(match proto {
+            proto::ClusterPriority::Low => ClusterPriority::Low,
+            proto::ClusterPriority::Normal => ClusterPriority::Normal,
+            proto::ClusterPriority::High => ClusterPriority::High,
         })
     }
 }
@@ -82,22 +143,9 @@ impl RustType<proto::ClusterConfig> for ClusterConfig {
 impl RustType<proto::cluster_config::Variant> for ClusterVariant {
     fn into_proto(&self) -> proto::cluster_config::Variant {
         match self {
-            ClusterVariant::Managed(ClusterVariantManaged {
-                size,
-                availability_zones,
-                logging,
-                idle_arrangement_merge_effort,
-                replication_factor,
-                disk,
-            }) => proto::cluster_config::Variant::Managed(proto::cluster_config::ManagedCluster {
-                size: size.to_string(),
-                availability_zones: availability_zones.clone(),
-                logging: Some(logging.into_proto()),
-                idle_arrangement_merge_effort: idle_arrangement_merge_effort
-                    .map(|effort| proto::ReplicaMergeEffort { effort }),
-                replication_factor: *replication_factor,
-                disk: *disk,
-            }),
+            ClusterVariant::Managed(managed) => {
+                proto::cluster_config::Variant::Managed(managed.into_proto())
+            }
             ClusterVariant::Unmanaged => proto::cluster_config::Variant::Unmanaged(proto::Empty {}),
         }
     }
@@ -106,23 +154,70 @@ impl RustType<proto::cluster_config::Variant> for ClusterVariant {
         match proto {
             proto::cluster_config::Variant::Unmanaged(_) => Ok(Self::Unmanaged),
             proto::cluster_config::Variant::Managed(managed) => {
-                Ok(Self::Managed(ClusterVariantManaged {
-                    size: managed.size,
-                    availability_zones: managed.availability_zones,
-                    logging: managed
-                        .logging
-                        .into_rust_if_some("ManagedCluster::logging")?,
-                    idle_arrangement_merge_effort: managed
-                        .idle_arrangement_merge_effort
-                        .map(|e| e.effort),
-                    replication_factor: managed.replication_factor,
-                    disk: managed.disk,
-                }))
+                Ok(Self::Managed(managed.into_rust()?))
             }
         }
     }
 }
 
+impl RustType<proto::cluster_config::ManagedCluster> for ClusterVariantManaged {
+    fn into_proto(&self) -> proto::cluster_config::ManagedCluster {
+        let ClusterVariantManaged {
+            size,
+            availability_zones,
+            logging,
+            idle_arrangement_merge_effort,
+            replication_factor,
+            disk,
+            schedule,
+        } = self;
+        proto::cluster_config::ManagedCluster {
+            size: size.to_string(),
+            availability_zones: availability_zones.clone(),
+            logging: Some(logging.into_proto()),
+            idle_arrangement_merge_effort: idle_arrangement_merge_effort
+                .map(|effort| proto::ReplicaMergeEffort { effort }),
+            replication_factor: *replication_factor,
+            disk: *disk,
+            schedule: schedule.into_proto(),
+        }
+    }
+
+    fn from_proto(
+        managed: proto::cluster_config::ManagedCluster,
+    ) -> Result<Self, TryFromProtoError> {
+        Ok(ClusterVariantManaged {
+            size: managed.size,
+            availability_zones: managed.availability_zones,
+            logging: managed
+                .logging
+                .into_rust_if_some("ManagedCluster::logging")?,
+            idle_arrangement_merge_effort: managed.idle_arrangement_merge_effort.map(|e| e.effort),
+            replication_factor: managed.replication_factor,
+            disk: managed.disk,
+            schedule: managed.schedule.into_rust()?,
+        })
+    }
+}
+
+impl RustType<proto::cluster_config::ScheduleEntry> for ClusterScheduleEntry {
+    fn into_proto(&self) -> proto::cluster_config::ScheduleEntry {
+        proto::cluster_config::ScheduleEntry {
+            minute_of_day: self.minute_of_day,
+            size: self.size.clone(),
+            replication_factor: self.replication_factor,
+        }
+    }
+
+    fn from_proto(proto: proto::cluster_config::ScheduleEntry) -> Result<Self, TryFromProtoError> {
+        Ok(ClusterScheduleEntry {
+            minute_of_day: proto.minute_of_day,
+            size: proto.size,
+            replication_factor: proto.replication_factor,
+        })
+    }
+}
+
 impl RustType<proto::ReplicaConfig> for ReplicaConfig {
     fn into_proto(&self) -> proto::ReplicaConfig {
         proto::ReplicaConfig {
@@ -166,12 +261,14 @@ impl RustType<proto::replica_config::Location> for ReplicaLocation {
             ReplicaLocation::Managed {
                 size,
                 availability_zone,
+                availability_zone_constraints,
                 disk,
                 billed_as,
                 internal,
             } => proto::replica_config::Location::Managed(proto::replica_config::ManagedLocation {
                 size: size.to_string(),
                 availability_zone: availability_zone.clone(),
+                availability_zone_constraints: availability_zone_constraints.into_proto(),
                 disk: *disk,
                 billed_as: billed_as.clone(),
                 internal: *internal,
@@ -192,6 +289,9 @@ impl RustType<proto::replica_config::Location> for ReplicaLocation {
             }
             proto::replica_config::Location::Managed(location) => Ok(ReplicaLocation::Managed {
                 availability_zone: location.availability_zone,
+                availability_zone_constraints: location
+                    .availability_zone_constraints
+                    .into_rust()?,
                 billed_as: location.billed_as,
                 disk: location.disk,
                 internal: location.internal,
@@ -201,6 +301,38 @@ impl RustType<proto::replica_config::Location> for ReplicaLocation {
     }
 }
 
+impl RustType<proto::replica_config::AvailabilityZoneConstraint>
+    for ReplicaAvailabilityZoneConstraint
+{
+    fn into_proto(&self) -> proto::replica_config::AvailabilityZoneConstraint {
+        use proto::replica_config::availability_zone_constraint::Constraint;
+        let constraint = match self {
+            ReplicaAvailabilityZoneConstraint::Affinity(id) => {
+                Constraint::Affinity(id.into_proto())
+            }
+            ReplicaAvailabilityZoneConstraint::AntiAffinity(id) => {
+                Constraint::AntiAffinity(id.into_proto())
+            }
+        };
+        proto::replica_config::AvailabilityZoneConstraint {
+            constraint: Some(constraint),
+        }
+    }
+
+    fn from_proto(
+        proto: proto::replica_config::AvailabilityZoneConstraint,
+    ) -> Result<Self, TryFromProtoError> {
+        use proto::replica_config::availability_zone_constraint::Constraint;
+        match proto.constraint {
+            Some(Constraint::Affinity(id)) => Ok(Self::Affinity(id.into_rust()?)),
+            Some(Constraint::AntiAffinity(id)) => Ok(Self::AntiAffinity(id.into_rust()?)),
+            None => Err(TryFromProtoError::missing_field(
+                "AvailabilityZoneConstraint::constraint",
+            )),
+        }
+    }
+}
+
 impl RustType<proto::SettingKey> for SettingKey {
     fn into_proto(&self) -> proto::SettingKey {
         proto::SettingKey {
@@ -382,6 +514,7 @@ impl RustType<proto::ClusterReplicaValue> for ClusterReplicaValue {
             name: self.name.to_string(),
             config: Some(self.config.into_proto()),
             owner_id: Some(self.owner_id.into_proto()),
+            draining: self.draining,
         }
     }
 
@@ -397,6 +530,7 @@ impl RustType<proto::ClusterReplicaValue> for ClusterReplicaValue {
             owner_id: proto
                 .owner_id
                 .into_rust_if_some("ClusterReplicaValue::owner_id")?,
+            draining: proto.draining,
         })
     }
 }
@@ -562,6 +696,198 @@ impl RustType<proto::CommentValue> for CommentValue {
     }
 }
 
+impl RustType<proto::TagKey> for TagKey {
+    fn into_proto(&self) -> proto::TagKey {
+        proto::TagKey {
+            object: Some(self.object_id.into_proto()),
+            name: self.name.clone(),
+        }
+    }
+
+    fn from_proto(proto: proto::TagKey) -> Result<Self, TryFromProtoError> {
+        Ok(TagKey {
+            object_id: proto.object.into_rust_if_some("TagKey::object")?,
+            name: proto.name,
+        })
+    }
+}
+
+impl RustType<proto::TagValue> for TagValue {
+    fn into_proto(&self) -> proto::TagValue {
+        proto::TagValue {
+            value: self.value.clone(),
+        }
+    }
+
+    fn from_proto(proto: proto::TagValue) -> Result<Self, TryFromProtoError> {
+        Ok(TagValue { value: proto.value })
+    }
+}
+
+impl RustType<proto::DependencyKey> for DependencyKey {
+    fn into_proto(&self) -> proto::DependencyKey {
+        proto::DependencyKey {
+            object_id: Some(self.object_id.into_proto()),
+            dependency_id: Some(self.dependency_id.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: proto::DependencyKey) -> Result<Self, TryFromProtoError> {
+        Ok(DependencyKey {
+            object_id: proto
+                .object_id
+                .into_rust_if_some("DependencyKey::object_id")?,
+            dependency_id: proto
+                .dependency_id
+                .into_rust_if_some("DependencyKey::dependency_id")?,
+        })
+    }
+}
+
+impl RustType<proto::DependencyValue> for DependencyValue {
+    fn into_proto(&self) -> proto::DependencyValue {
+        proto::DependencyValue {}
+    }
+
+    fn from_proto(_proto: proto::DependencyValue) -> Result<Self, TryFromProtoError> {
+        Ok(DependencyValue {})
+    }
+}
+
+impl RustType<proto::TrashedItemKey> for TrashedItemKey {
+    fn into_proto(&self) -> proto::TrashedItemKey {
+        proto::TrashedItemKey {
+            gid: Some(self.gid.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: proto::TrashedItemKey) -> Result<Self, TryFromProtoError> {
+        Ok(TrashedItemKey {
+            gid: proto.gid.into_rust_if_some("TrashedItemKey::gid")?,
+        })
+    }
+}
+
+impl RustType<proto::TrashedItemValue> for TrashedItemValue {
+    fn into_proto(&self) -> proto::TrashedItemValue {
+        proto::TrashedItemValue {
+            item: Some(self.item.into_proto()),
+            dropped_at: self.dropped_at,
+        }
+    }
+
+    fn from_proto(proto: proto::TrashedItemValue) -> Result<Self, TryFromProtoError> {
+        Ok(TrashedItemValue {
+            item: proto.item.into_rust_if_some("TrashedItemValue::item")?,
+            dropped_at: proto.dropped_at,
+        })
+    }
+}
+
+impl RustType<proto::ColumnMaskKey> for ColumnMaskKey {
+    fn into_proto(&self) -> proto::ColumnMaskKey {
+        proto::ColumnMaskKey {
+            item_id: Some(self.item_id.into_proto()),
+            column: self.column.clone(),
+            role_id: Some(self.role_id.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: proto::ColumnMaskKey) -> Result<Self, TryFromProtoError> {
+        Ok(ColumnMaskKey {
+            item_id: proto.item_id.into_rust_if_some("ColumnMaskKey::item_id")?,
+            column: proto.column,
+            role_id: proto.role_id.into_rust_if_some("ColumnMaskKey::role_id")?,
+        })
+    }
+}
+
+impl RustType<proto::ColumnMaskValue> for ColumnMaskValue {
+    fn into_proto(&self) -> proto::ColumnMaskValue {
+        proto::ColumnMaskValue {
+            expr: self.expr.clone(),
+        }
+    }
+
+    fn from_proto(proto: proto::ColumnMaskValue) -> Result<Self, TryFromProtoError> {
+        Ok(ColumnMaskValue { expr: proto.expr })
+    }
+}
+
+impl RustType<proto::ServiceAccountKey> for ServiceAccountKey {
+    fn into_proto(&self) -> proto::ServiceAccountKey {
+        proto::ServiceAccountKey { id: self.id }
+    }
+
+    fn from_proto(proto: proto::ServiceAccountKey) -> Result<Self, TryFromProtoError> {
+        Ok(ServiceAccountKey { id: proto.id })
+    }
+}
+
+impl RustType<proto::ServiceAccountValue> for ServiceAccountValue {
+    fn into_proto(&self) -> proto::ServiceAccountValue {
+        proto::ServiceAccountValue {
+            name: self.name.clone(),
+            role_id: Some(self.role_id.into_proto()),
+            token_secret_id: Some(self.token_secret_id.into_proto()),
+            scopes: self.scopes.clone(),
+            expires_at: self.expires_at,
+        }
+    }
+
+    fn from_proto(proto: proto::ServiceAccountValue) -> Result<Self, TryFromProtoError> {
+        Ok(ServiceAccountValue {
+            name: proto.name,
+            role_id: proto
+                .role_id
+                .into_rust_if_some("ServiceAccountValue::role_id")?,
+            token_secret_id: proto
+                .token_secret_id
+                .into_rust_if_some("ServiceAccountValue::token_secret_id")?,
+            scopes: proto.scopes,
+            expires_at: proto.expires_at,
+        })
+    }
+}
+
+impl RustType<proto::ClusterReplicaSizeKey> for ClusterReplicaSizeKey {
+    fn into_proto(&self) -> proto::ClusterReplicaSizeKey {
+        proto::ClusterReplicaSizeKey {
+            name: self.name.clone(),
+        }
+    }
+
+    fn from_proto(proto: proto::ClusterReplicaSizeKey) -> Result<Self, TryFromProtoError> {
+        Ok(ClusterReplicaSizeKey { name: proto.name })
+    }
+}
+
+impl RustType<proto::ClusterReplicaSizeValue> for ClusterReplicaSizeValue {
+    fn into_proto(&self) -> proto::ClusterReplicaSizeValue {
+        proto::ClusterReplicaSizeValue {
+            memory_limit: self.memory_limit,
+            cpu_limit: self.cpu_limit,
+            disk_limit: self.disk_limit,
+            scale: self.scale.into_proto(),
+            workers: self.workers,
+            credits_per_hour: self.credits_per_hour.clone(),
+            disabled: self.disabled,
+        }
+    }
+
+    fn from_proto(proto: proto::ClusterReplicaSizeValue) -> Result<Self, TryFromProtoError> {
+        Ok(ClusterReplicaSizeValue {
+            memory_limit: proto.memory_limit,
+            cpu_limit: proto.cpu_limit,
+            disk_limit: proto.disk_limit,
+            scale: proto.scale.into_rust()?,
+            workers: proto.workers,
+            credits_per_hour: proto.credits_per_hour,
+            disabled: proto.disabled,
+        })
+    }
+}
+
 impl RustType<proto::RoleKey> for RoleKey {
     fn into_proto(&self) -> proto::RoleKey {
         proto::RoleKey {
@@ -899,6 +1225,10 @@ impl RustType<proto::RoleAttributes> for RoleAttributes {
     fn into_proto(&self) -> proto::RoleAttributes {
         proto::RoleAttributes {
             inherit: self.inherit,
+            password_verifier: self.password_verifier.clone(),
+            connection_limit: self.connection_limit,
+            statement_timeout: self.statement_timeout.into_proto(),
+            valid_until: self.valid_until,
         }
     }
 
@@ -906,6 +1236,10 @@ impl RustType<proto::RoleAttributes> for RoleAttributes {
         let mut attributes = RoleAttributes::new();
 
         attributes.inherit = proto.inherit;
+        attributes.password_verifier = proto.password_verifier;
+        attributes.connection_limit = proto.connection_limit;
+        attributes.statement_timeout = proto.statement_timeout.into_rust()?;
+        attributes.valid_until = proto.valid_until;
 
         Ok(attributes)
     }
@@ -1291,6 +1625,124 @@ impl RustType<proto::comment_key::Object> for CommentObjectId {
     }
 }
 
+impl RustType<proto::tag_key::Object> for CommentObjectId {
+    fn into_proto(&self) -> proto::tag_key::Object {
+        match self {
+            CommentObjectId::Table(global_id) => {
+                proto::tag_key::Object::Table(global_id.into_proto())
+            }
+            CommentObjectId::View(global_id) => {
+                proto::tag_key::Object::View(global_id.into_proto())
+            }
+            CommentObjectId::MaterializedView(global_id) => {
+                proto::tag_key::Object::MaterializedView(global_id.into_proto())
+            }
+            CommentObjectId::Source(global_id) => {
+                proto::tag_key::Object::Source(global_id.into_proto())
+            }
+            CommentObjectId::Sink(global_id) => {
+                proto::tag_key::Object::Sink(global_id.into_proto())
+            }
+            CommentObjectId::Index(global_id) => {
+                proto::tag_key::Object::Index(global_id.into_proto())
+            }
+            CommentObjectId::Func(global_id) => {
+                proto::tag_key::Object::Func(global_id.into_proto())
+            }
+            CommentObjectId::Connection(global_id) => {
+                proto::tag_key::Object::Connection(global_id.into_proto())
+            }
+            CommentObjectId::Type(global_id) => {
+                proto::tag_key::Object::Type(global_id.into_proto())
+            }
+            CommentObjectId::Secret(global_id) => {
+                proto::tag_key::Object::Secret(global_id.into_proto())
+            }
+            CommentObjectId::Role(role_id) => proto::tag_key::Object::Role(role_id.into_proto()),
+            CommentObjectId::Database(database_id) => {
+                proto::tag_key::Object::Database(database_id.into_proto())
+            }
+            CommentObjectId::Schema((database, schema)) => {
+                proto::tag_key::Object::Schema(proto::ResolvedSchema {
+                    database: Some(database.into_proto()),
+                    schema: Some(schema.into_proto()),
+                })
+            }
+            CommentObjectId::Cluster(cluster_id) => {
+                proto::tag_key::Object::Cluster(cluster_id.into_proto())
+            }
+            CommentObjectId::ClusterReplica((cluster_id, replica_id)) => {
+                let cluster_replica_id = proto::ClusterReplicaId {
+                    cluster_id: Some(cluster_id.into_proto()),
+                    replica_id: Some(replica_id.into_proto()),
+                };
+                proto::tag_key::Object::ClusterReplica(cluster_replica_id)
+            }
+        }
+    }
+
+    fn from_proto(proto: proto::tag_key::Object) -> Result<Self, TryFromProtoError> {
+        let id = match proto {
+            proto::tag_key::Object::Table(global_id) => {
+                CommentObjectId::Table(global_id.into_rust()?)
+            }
+            proto::tag_key::Object::View(global_id) => {
+                CommentObjectId::View(global_id.into_rust()?)
+            }
+            proto::tag_key::Object::MaterializedView(global_id) => {
+                CommentObjectId::MaterializedView(global_id.into_rust()?)
+            }
+            proto::tag_key::Object::Source(global_id) => {
+                CommentObjectId::Source(global_id.into_rust()?)
+            }
+            proto::tag_key::Object::Sink(global_id) => {
+                CommentObjectId::Sink(global_id.into_rust()?)
+            }
+            proto::tag_key::Object::Index(global_id) => {
+                CommentObjectId::Index(global_id.into_rust()?)
+            }
+            proto::tag_key::Object::Func(global_id) => {
+                CommentObjectId::Func(global_id.into_rust()?)
+            }
+            proto::tag_key::Object::Connection(global_id) => {
+                CommentObjectId::Connection(global_id.into_rust()?)
+            }
+            proto::tag_key::Object::Type(global_id) => {
+                CommentObjectId::Type(global_id.into_rust()?)
+            }
+            proto::tag_key::Object::Secret(global_id) => {
+                CommentObjectId::Secret(global_id.into_rust()?)
+            }
+            proto::tag_key::Object::Role(role_id) => CommentObjectId::Role(role_id.into_rust()?),
+            proto::tag_key::Object::Database(database_id) => {
+                CommentObjectId::Database(database_id.into_rust()?)
+            }
+            proto::tag_key::Object::Schema(resolved_schema) => {
+                let database = resolved_schema
+                    .database
+                    .into_rust_if_some("ResolvedSchema::database")?;
+                let schema = resolved_schema
+                    .schema
+                    .into_rust_if_some("ResolvedSchema::schema")?;
+                CommentObjectId::Schema((database, schema))
+            }
+            proto::tag_key::Object::Cluster(cluster_id) => {
+                CommentObjectId::Cluster(cluster_id.into_rust()?)
+            }
+            proto::tag_key::Object::ClusterReplica(cluster_replica_id) => {
+                let cluster_id = cluster_replica_id
+                    .cluster_id
+                    .into_rust_if_some("ClusterReplicaId::cluster_id")?;
+                let replica_id = cluster_replica_id
+                    .replica_id
+                    .into_rust_if_some("ClusterReplicaId::replica_id")?;
+                CommentObjectId::ClusterReplica((cluster_id, replica_id))
+            }
+        };
+        Ok(id)
+    }
+}
+
 impl RustType<proto::EpochMillis> for u64 {
     fn into_proto(&self) -> proto::EpochMillis {
         proto::EpochMillis { millis: *self }
@@ -1358,6 +1810,9 @@ impl RustType<proto::audit_log_event_v1::EventType> for EventType {
             EventType::Alter => proto::audit_log_event_v1::EventType::Alter,
             EventType::Grant => proto::audit_log_event_v1::EventType::Grant,
             EventType::Revoke => proto::audit_log_event_v1::EventType::Revoke,
+            EventType::Login => proto::audit_log_event_v1::EventType::Login,
+            EventType::LoginFailure => proto::audit_log_event_v1::EventType::LoginFailure,
+            EventType::Logout => proto::audit_log_event_v1::EventType::Logout,
         }
     }
 
@@ -1368,6 +1823,9 @@ impl RustType<proto::audit_log_event_v1::EventType> for EventType {
             proto::audit_log_event_v1::EventType::Alter => Ok(EventType::Alter),
             proto::audit_log_event_v1::EventType::Grant => Ok(EventType::Grant),
             proto::audit_log_event_v1::EventType::Revoke => Ok(EventType::Revoke),
+            proto::audit_log_event_v1::EventType::Login => Ok(EventType::Login),
+            proto::audit_log_event_v1::EventType::LoginFailure => Ok(EventType::LoginFailure),
+            proto::audit_log_event_v1::EventType::Logout => Ok(EventType::Logout),
             proto::audit_log_event_v1::EventType::Unknown => Err(
                 TryFromProtoError::unknown_enum_variant("EventType::Unknown"),
             ),
@@ -1407,6 +1865,10 @@ impl RustType<proto::audit_log_event_v1::ObjectType> for mz_audit_log::ObjectTyp
             mz_audit_log::ObjectType::Role => proto::audit_log_event_v1::ObjectType::Role,
             mz_audit_log::ObjectType::Secret => proto::audit_log_event_v1::ObjectType::Secret,
             mz_audit_log::ObjectType::Schema => proto::audit_log_event_v1::ObjectType::Schema,
+            mz_audit_log::ObjectType::ServiceAccount => {
+                proto::audit_log_event_v1::ObjectType::ServiceAccount
+            }
+            mz_audit_log::ObjectType::Session => proto::audit_log_event_v1::ObjectType::Session,
             mz_audit_log::ObjectType::Sink => proto::audit_log_event_v1::ObjectType::Sink,
             mz_audit_log::ObjectType::Source => proto::audit_log_event_v1::ObjectType::Source,
             mz_audit_log::ObjectType::System => proto::audit_log_event_v1::ObjectType::System,
@@ -1436,6 +1898,10 @@ impl RustType<proto::audit_log_event_v1::ObjectType> for mz_audit_log::ObjectTyp
             proto::audit_log_event_v1::ObjectType::Role => Ok(mz_audit_log::ObjectType::Role),
             proto::audit_log_event_v1::ObjectType::Secret => Ok(mz_audit_log::ObjectType::Secret),
             proto::audit_log_event_v1::ObjectType::Schema => Ok(mz_audit_log::ObjectType::Schema),
+            proto::audit_log_event_v1::ObjectType::ServiceAccount => {
+                Ok(mz_audit_log::ObjectType::ServiceAccount)
+            }
+            proto::audit_log_event_v1::ObjectType::Session => Ok(mz_audit_log::ObjectType::Session),
             proto::audit_log_event_v1::ObjectType::Sink => Ok(mz_audit_log::ObjectType::Sink),
             proto::audit_log_event_v1::ObjectType::Source => Ok(mz_audit_log::ObjectType::Source),
             proto::audit_log_event_v1::ObjectType::System => Ok(mz_audit_log::ObjectType::System),
@@ -1929,6 +2395,26 @@ impl RustType<proto::audit_log_event_v1::RenameSchemaV1> for RenameSchemaV1 {
     }
 }
 
+impl RustType<proto::audit_log_event_v1::RenameDatabaseV1> for RenameDatabaseV1 {
+    fn into_proto(&self) -> proto::audit_log_event_v1::RenameDatabaseV1 {
+        proto::audit_log_event_v1::RenameDatabaseV1 {
+            id: self.id.to_string(),
+            old_name: self.old_name.clone(),
+            new_name: self.new_name.clone(),
+        }
+    }
+
+    fn from_proto(
+        proto: proto::audit_log_event_v1::RenameDatabaseV1,
+    ) -> Result<Self, TryFromProtoError> {
+        Ok(RenameDatabaseV1 {
+            id: proto.id,
+            old_name: proto.old_name,
+            new_name: proto.new_name,
+        })
+    }
+}
+
 impl RustType<proto::audit_log_event_v1::UpdateItemV1> for UpdateItemV1 {
     fn into_proto(&self) -> proto::audit_log_event_v1::UpdateItemV1 {
         proto::audit_log_event_v1::UpdateItemV1 {
@@ -1947,6 +2433,100 @@ impl RustType<proto::audit_log_event_v1::UpdateItemV1> for UpdateItemV1 {
     }
 }
 
+impl RustType<proto::audit_log_event_v1::SessionV1> for SessionV1 {
+    fn into_proto(&self) -> proto::audit_log_event_v1::SessionV1 {
+        proto::audit_log_event_v1::SessionV1 {
+            session_id: self.session_id.clone(),
+            connection_id: self.connection_id.clone(),
+            client_ip: self.client_ip.clone(),
+            application_name: self.application_name.clone(),
+        }
+    }
+
+    fn from_proto(proto: proto::audit_log_event_v1::SessionV1) -> Result<Self, TryFromProtoError> {
+        Ok(SessionV1 {
+            session_id: proto.session_id,
+            connection_id: proto.connection_id,
+            client_ip: proto.client_ip,
+            application_name: proto.application_name,
+        })
+    }
+}
+
+impl RustType<proto::audit_log_event_v1::LoginFailureV1> for LoginFailureV1 {
+    fn into_proto(&self) -> proto::audit_log_event_v1::LoginFailureV1 {
+        proto::audit_log_event_v1::LoginFailureV1 {
+            client_ip: self.client_ip.clone(),
+            application_name: self.application_name.clone(),
+            reason: self.reason.clone(),
+        }
+    }
+
+    fn from_proto(
+        proto: proto::audit_log_event_v1::LoginFailureV1,
+    ) -> Result<Self, TryFromProtoError> {
+        Ok(LoginFailureV1 {
+            client_ip: proto.client_ip,
+            application_name: proto.application_name,
+            reason: proto.reason,
+        })
+    }
+}
+
+impl RustType<proto::audit_log_event_v1::ServiceAccountV1> for ServiceAccountV1 {
+    fn into_proto(&self) -> proto::audit_log_event_v1::ServiceAccountV1 {
+        proto::audit_log_event_v1::ServiceAccountV1 {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            role_id: self.role_id.clone(),
+            token_secret_id: self.token_secret_id.clone(),
+            scopes: self.scopes.clone(),
+            expires_at: self.expires_at,
+        }
+    }
+
+    fn from_proto(
+        proto: proto::audit_log_event_v1::ServiceAccountV1,
+    ) -> Result<Self, TryFromProtoError> {
+        Ok(ServiceAccountV1 {
+            id: proto.id,
+            name: proto.name,
+            role_id: proto.role_id,
+            token_secret_id: proto.token_secret_id,
+            scopes: proto.scopes,
+            expires_at: proto.expires_at,
+        })
+    }
+}
+
+impl RustType<proto::audit_log_event_v1::ScheduledClusterChangeV1> for ScheduledClusterChangeV1 {
+    fn into_proto(&self) -> proto::audit_log_event_v1::ScheduledClusterChangeV1 {
+        proto::audit_log_event_v1::ScheduledClusterChangeV1 {
+            cluster_id: self.cluster_id.clone(),
+            cluster_name: self.cluster_name.clone(),
+            scheduled_at: self.scheduled_at.clone(),
+            old_size: self.old_size.clone(),
+            new_size: self.new_size.clone(),
+            old_replication_factor: self.old_replication_factor,
+            new_replication_factor: self.new_replication_factor,
+        }
+    }
+
+    fn from_proto(
+        proto: proto::audit_log_event_v1::ScheduledClusterChangeV1,
+    ) -> Result<Self, TryFromProtoError> {
+        Ok(ScheduledClusterChangeV1 {
+            cluster_id: proto.cluster_id,
+            cluster_name: proto.cluster_name,
+            scheduled_at: proto.scheduled_at,
+            old_size: proto.old_size,
+            new_size: proto.new_size,
+            old_replication_factor: proto.old_replication_factor,
+            new_replication_factor: proto.new_replication_factor,
+        })
+    }
+}
+
 impl RustType<proto::audit_log_event_v1::Details> for EventDetails {
     fn into_proto(&self) -> proto::audit_log_event_v1::Details {
         use proto::audit_log_event_v1::Details::*;
@@ -1981,7 +2561,14 @@ impl RustType<proto::audit_log_event_v1::Details> for EventDetails {
             EventDetails::SchemaV1(details) => SchemaV1(details.into_proto()),
             EventDetails::SchemaV2(details) => SchemaV2(details.into_proto()),
             EventDetails::RenameSchemaV1(details) => RenameSchemaV1(details.into_proto()),
+            EventDetails::RenameDatabaseV1(details) => RenameDatabaseV1(details.into_proto()),
             EventDetails::UpdateItemV1(details) => UpdateItemV1(details.into_proto()),
+            EventDetails::SessionV1(details) => SessionV1(details.into_proto()),
+            EventDetails::LoginFailureV1(details) => LoginFailureV1(details.into_proto()),
+            EventDetails::ServiceAccountV1(details) => ServiceAccountV1(details.into_proto()),
+            EventDetails::ScheduledClusterChangeV1(details) => {
+                ScheduledClusterChangeV1(details.into_proto())
+            }
         }
     }
 
@@ -2022,7 +2609,14 @@ impl RustType<proto::audit_log_event_v1::Details> for EventDetails {
             SchemaV1(details) => Ok(EventDetails::SchemaV1(details.into_rust()?)),
             SchemaV2(details) => Ok(EventDetails::SchemaV2(details.into_rust()?)),
             RenameSchemaV1(details) => Ok(EventDetails::RenameSchemaV1(details.into_rust()?)),
+            RenameDatabaseV1(details) => Ok(EventDetails::RenameDatabaseV1(details.into_rust()?)),
             UpdateItemV1(details) => Ok(EventDetails::UpdateItemV1(details.into_rust()?)),
+            SessionV1(details) => Ok(EventDetails::SessionV1(details.into_rust()?)),
+            LoginFailureV1(details) => Ok(EventDetails::LoginFailureV1(details.into_rust()?)),
+            ServiceAccountV1(details) => Ok(EventDetails::ServiceAccountV1(details.into_rust()?)),
+            ScheduledClusterChangeV1(details) => {
+                Ok(EventDetails::ScheduledClusterChangeV1(details.into_rust()?))
+            }
         }
     }
 }
@@ -9,46 +9,75 @@
 
 use derivative::Derivative;
 use itertools::Itertools;
-use mz_audit_log::{VersionedEvent, VersionedStorageUsage};
+use mz_audit_log::{StorageUsageV1, VersionedEvent, VersionedStorageUsage};
 use mz_controller_types::{ClusterId, ReplicaId};
 use mz_ore::collections::CollectionExt;
+use mz_ore::now::EpochMillis;
 use mz_ore::soft_assert;
 use mz_proto::RustType;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem};
 use mz_repr::role_id::RoleId;
 use mz_repr::{Diff, GlobalId};
 use mz_sql::catalog::{
-    CatalogError as SqlCatalogError, ObjectType, RoleAttributes, RoleMembership, RoleVars,
+    CatalogError as SqlCatalogError, ExistingObject, ObjectType, RoleAttributes, RoleMembership,
+    RoleVars,
 };
 use mz_sql::names::{CommentObjectId, DatabaseId, SchemaId};
 use mz_sql::session::user::MZ_SYSTEM_ROLE_ID;
+use mz_sql::session::vars::{SystemVars, VarInput};
 use mz_sql_parser::ast::QualifiedReplica;
-use mz_stash::TableTransaction;
+use mz_stash::{TableError, TableSavepoint, TableTransaction};
 use mz_storage_types::controller::PersistTxnTablesImpl;
 use mz_storage_types::sources::Timeline;
+use once_cell::sync::Lazy;
+use prost::Message;
 use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+use std::iter;
+use std::ops::Range;
 use std::time::Duration;
 
 use crate::builtin::BuiltinLog;
-use crate::durable::initialize::{PERSIST_TXN_TABLES, SYSTEM_CONFIG_SYNCED_KEY};
+use crate::durable::initialize::{
+    ITEM_TRASH_RETENTION_KEY, PERSIST_TXN_TABLES, SYSTEM_CONFIG_SYNCED_KEY,
+};
+use crate::durable::migration::AppliedMigration;
 use crate::durable::objects::serialization::proto;
 use crate::durable::objects::{
-    AuditLogKey, Cluster, ClusterConfig, ClusterIntrospectionSourceIndexKey,
+    item_dependencies, AuditLogKey, Cluster, ClusterConfig, ClusterIntrospectionSourceIndexKey,
     ClusterIntrospectionSourceIndexValue, ClusterKey, ClusterReplica, ClusterReplicaKey,
-    ClusterReplicaValue, ClusterValue, CommentKey, CommentValue, Config, ConfigKey, ConfigValue,
-    Database, DatabaseKey, DatabaseValue, DefaultPrivilegesKey, DefaultPrivilegesValue,
-    DurableType, GidMappingKey, GidMappingValue, IdAllocKey, IdAllocValue,
-    IntrospectionSourceIndex, Item, ItemKey, ItemValue, ReplicaConfig, Role, RoleKey, RoleValue,
-    Schema, SchemaKey, SchemaValue, ServerConfigurationKey, ServerConfigurationValue, SettingKey,
-    SettingValue, StorageUsageKey, SystemObjectMapping, SystemPrivilegesKey, SystemPrivilegesValue,
-    TimestampKey, TimestampValue,
+    ClusterReplicaSize, ClusterReplicaSizeKey, ClusterReplicaSizeValue, ClusterReplicaValue,
+    ClusterValue, ColumnMask, ColumnMaskKey, ColumnMaskValue, CommentKey, CommentValue, Config,
+    ConfigKey, ConfigValue, Database, DatabaseKey, DatabaseValue, DefaultPrivilegesKey,
+    DefaultPrivilegesValue, Dependency, DependencyKey, DependencyValue, DurableType, GidMappingKey,
+    GidMappingValue, IdAllocKey, IdAllocValue, IntrospectionSourceIndex, Item, ItemKey, ItemValue,
+    ReplicaConfig, Role, RoleKey, RoleValue, Schema, SchemaKey, SchemaValue,
+    ServerConfigurationKey, ServerConfigurationValue, ServiceAccount, ServiceAccountKey,
+    ServiceAccountValue, SettingKey, SettingValue, StorageUsageKey, SystemObjectMapping,
+    SystemPrivilegesKey, SystemPrivilegesValue, Tag, TagKey, TagValue, TimestampKey,
+    TimestampValue, TrashedItem, TrashedItemKey, TrashedItemValue,
 };
 use crate::durable::{
-    CatalogError, Comment, DefaultPrivilege, DurableCatalogState, Snapshot, SystemConfiguration,
-    TimelineTimestamp, CATALOG_CONTENT_VERSION_KEY, DATABASE_ID_ALLOC_KEY, SCHEMA_ID_ALLOC_KEY,
+    CatalogError, Comment, DefaultPrivilege, DurableCatalogError, DurableCatalogState, Epoch,
+    Snapshot, SystemConfiguration, TimelineTimestamp, CATALOG_CONTENT_VERSION_KEY,
+    DATABASE_ID_ALLOC_KEY, SCHEMA_ID_ALLOC_KEY, SERVICE_ACCOUNT_ID_ALLOC_KEY,
     SYSTEM_ITEM_ALLOC_KEY, USER_ITEM_ALLOC_KEY, USER_ROLE_ID_ALLOC_KEY,
 };
 
+/// The interval that storage usage events are rolled up into by
+/// [`Transaction::compact_storage_usage`].
+const MILLIS_PER_DAY: EpochMillis = 24 * 60 * 60 * 1000;
+
+/// The prefix of the names of the settings that record applied migrations.
+const APPLIED_MIGRATION_KEY_PREFIX: &str = "applied_migration.";
+
+/// The prefix of the names of the settings that record leased ID ranges.
+const ID_RANGE_LEASE_KEY_PREFIX: &str = "id_range_lease.";
+
+/// The system configuration parameters that values are validated against in
+/// [`Transaction::upsert_system_config`].
+static DEFAULT_SYSTEM_VARS: Lazy<SystemVars> = Lazy::new(SystemVars::default);
+
 /// A [`Transaction`] batches multiple catalog operations together and commits them atomically.
 #[derive(Derivative)]
 #[derivative(Debug, PartialEq)]
@@ -56,15 +85,26 @@ pub struct Transaction<'a> {
     #[derivative(Debug = "ignore")]
     #[derivative(PartialEq = "ignore")]
     durable_catalog: &'a mut dyn DurableCatalogState,
-    databases: TableTransaction<DatabaseKey, DatabaseValue>,
-    schemas: TableTransaction<SchemaKey, SchemaValue>,
-    items: TableTransaction<ItemKey, ItemValue>,
-    comments: TableTransaction<CommentKey, CommentValue>,
-    roles: TableTransaction<RoleKey, RoleValue>,
-    clusters: TableTransaction<ClusterKey, ClusterValue>,
-    cluster_replicas: TableTransaction<ClusterReplicaKey, ClusterReplicaValue>,
-    introspection_sources:
-        TableTransaction<ClusterIntrospectionSourceIndexKey, ClusterIntrospectionSourceIndexValue>,
+    // Indexed by name.
+    databases: TableTransaction<DatabaseKey, DatabaseValue, String>,
+    // Indexed by database.
+    schemas: TableTransaction<SchemaKey, SchemaValue, Option<DatabaseId>>,
+    // Indexed by schema.
+    items: TableTransaction<ItemKey, ItemValue, SchemaId>,
+    // Indexed by the commented object, see `comment_index_key`.
+    comments: TableTransaction<CommentKey, CommentValue, CommentObjectId>,
+    // Indexed by name.
+    roles: TableTransaction<RoleKey, RoleValue, String>,
+    // Indexed by name.
+    clusters: TableTransaction<ClusterKey, ClusterValue, String>,
+    // Indexed by cluster.
+    cluster_replicas: TableTransaction<ClusterReplicaKey, ClusterReplicaValue, ClusterId>,
+    // Indexed by cluster.
+    introspection_sources: TableTransaction<
+        ClusterIntrospectionSourceIndexKey,
+        ClusterIntrospectionSourceIndexValue,
+        ClusterId,
+    >,
     id_allocator: TableTransaction<IdAllocKey, IdAllocValue>,
     configs: TableTransaction<ConfigKey, ConfigValue>,
     settings: TableTransaction<SettingKey, SettingValue>,
@@ -73,11 +113,22 @@ pub struct Transaction<'a> {
     system_configurations: TableTransaction<ServerConfigurationKey, ServerConfigurationValue>,
     default_privileges: TableTransaction<DefaultPrivilegesKey, DefaultPrivilegesValue>,
     system_privileges: TableTransaction<SystemPrivilegesKey, SystemPrivilegesValue>,
+    // Indexed by the tagged object, see `comment_index_key`.
+    tags: TableTransaction<TagKey, TagValue, CommentObjectId>,
+    // Indexed by the dependent item.
+    dependencies: TableTransaction<DependencyKey, DependencyValue, GlobalId>,
+    trashed_items: TableTransaction<TrashedItemKey, TrashedItemValue>,
+    // Indexed by the masked item.
+    column_masks: TableTransaction<ColumnMaskKey, ColumnMaskValue, GlobalId>,
+    // Indexed by name.
+    service_accounts: TableTransaction<ServiceAccountKey, ServiceAccountValue, String>,
+    cluster_replica_sizes: TableTransaction<ClusterReplicaSizeKey, ClusterReplicaSizeValue>,
     // Don't make this a table transaction so that it's not read into the
     // in-memory cache.
     audit_log_updates: Vec<(proto::AuditLogKey, (), i64)>,
     storage_usage_updates: Vec<(proto::StorageUsageKey, (), i64)>,
     connection_timeout: Option<Duration>,
+    expected_epoch: Option<Epoch>,
 }
 
 impl<'a> Transaction<'a> {
@@ -100,36 +151,96 @@ impl<'a> Transaction<'a> {
             system_configurations,
             default_privileges,
             system_privileges,
+            tags,
+            dependencies,
+            trashed_items,
+            column_masks,
+            service_accounts,
+            cluster_replica_sizes,
         }: Snapshot,
     ) -> Result<Transaction, CatalogError> {
         Ok(Transaction {
             durable_catalog,
-            databases: TableTransaction::new(databases, |a: &DatabaseValue, b| a.name == b.name)?,
-            schemas: TableTransaction::new(schemas, |a: &SchemaValue, b| {
-                a.database_id == b.database_id && a.name == b.name
-            })?,
-            items: TableTransaction::new(items, |a: &ItemValue, b| {
-                a.schema_id == b.schema_id && a.name == b.name
-            })?,
-            comments: TableTransaction::new(comments, |_a, _b| false)?,
-            roles: TableTransaction::new(roles, |a: &RoleValue, b| a.name == b.name)?,
-            clusters: TableTransaction::new(clusters, |a: &ClusterValue, b| a.name == b.name)?,
-            cluster_replicas: TableTransaction::new(
+            databases: TableTransaction::new_with_index(
+                databases,
+                |a: &DatabaseValue, b| (a.name == b.name).then_some("name"),
+                |_k, v| v.name.clone(),
+            )?,
+            schemas: TableTransaction::new_with_index(
+                schemas,
+                |a: &SchemaValue, b| {
+                    (a.database_id == b.database_id && a.name == b.name).then_some("name")
+                },
+                |_k, v| v.database_id,
+            )?,
+            items: TableTransaction::new_with_index(
+                items,
+                |a: &ItemValue, b| {
+                    (a.schema_id == b.schema_id && a.name == b.name).then_some("name")
+                },
+                |_k, v| v.schema_id,
+            )?,
+            comments: TableTransaction::new_with_index(
+                comments,
+                |_a, _b| None,
+                |k: &CommentKey, _v| comment_index_key(k.object_id),
+            )?,
+            roles: TableTransaction::new_with_index(
+                roles,
+                |a: &RoleValue, b| (a.name == b.name).then_some("name"),
+                |_k, v| v.name.clone(),
+            )?,
+            clusters: TableTransaction::new_with_index(
+                clusters,
+                |a: &ClusterValue, b| (a.name == b.name).then_some("name"),
+                |_k, v| v.name.clone(),
+            )?,
+            cluster_replicas: TableTransaction::new_with_index(
                 cluster_replicas,
-                |a: &ClusterReplicaValue, b| a.cluster_id == b.cluster_id && a.name == b.name,
+                |a: &ClusterReplicaValue, b| {
+                    (a.cluster_id == b.cluster_id && a.name == b.name).then_some("name")
+                },
+                |_k, v| v.cluster_id,
+            )?,
+            introspection_sources: TableTransaction::new_with_index(
+                introspection_sources,
+                |_a, _b| None,
+                |k: &ClusterIntrospectionSourceIndexKey, _v| k.cluster_id,
+            )?,
+            id_allocator: TableTransaction::new(id_allocator, |_a, _b| None)?,
+            configs: TableTransaction::new(configs, |_a, _b| None)?,
+            settings: TableTransaction::new(settings, |_a, _b| None)?,
+            timestamps: TableTransaction::new(timestamps, |_a, _b| None)?,
+            system_gid_mapping: TableTransaction::new(system_object_mappings, |_a, _b| None)?,
+            system_configurations: TableTransaction::new(system_configurations, |_a, _b| None)?,
+            default_privileges: TableTransaction::new(default_privileges, |_a, _b| None)?,
+            system_privileges: TableTransaction::new(system_privileges, |_a, _b| None)?,
+            tags: TableTransaction::new_with_index(
+                tags,
+                |_a, _b| None,
+                |k: &TagKey, _v| comment_index_key(k.object_id),
+            )?,
+            dependencies: TableTransaction::new_with_index(
+                dependencies,
+                |_a, _b| None,
+                |k: &DependencyKey, _v| k.object_id,
+            )?,
+            trashed_items: TableTransaction::new(trashed_items, |_a, _b| None)?,
+            column_masks: TableTransaction::new_with_index(
+                column_masks,
+                |_a, _b| None,
+                |k: &ColumnMaskKey, _v| k.item_id,
             )?,
-            introspection_sources: TableTransaction::new(introspection_sources, |_a, _b| false)?,
-            id_allocator: TableTransaction::new(id_allocator, |_a, _b| false)?,
-            configs: TableTransaction::new(configs, |_a, _b| false)?,
-            settings: TableTransaction::new(settings, |_a, _b| false)?,
-            timestamps: TableTransaction::new(timestamps, |_a, _b| false)?,
-            system_gid_mapping: TableTransaction::new(system_object_mappings, |_a, _b| false)?,
-            system_configurations: TableTransaction::new(system_configurations, |_a, _b| false)?,
-            default_privileges: TableTransaction::new(default_privileges, |_a, _b| false)?,
-            system_privileges: TableTransaction::new(system_privileges, |_a, _b| false)?,
+            service_accounts: TableTransaction::new_with_index(
+                service_accounts,
+                |a: &ServiceAccountValue, b| (a.name == b.name).then_some("name"),
+                |_k, v| v.name.clone(),
+            )?,
+            cluster_replica_sizes: TableTransaction::new(cluster_replica_sizes, |_a, _b| None)?,
             audit_log_updates: Vec::new(),
             storage_usage_updates: Vec::new(),
             connection_timeout: None,
+            expected_epoch: None,
         })
     }
 
@@ -142,6 +253,49 @@ impl<'a> Transaction<'a> {
         items
     }
 
+    /// Returns the items viewable in the current transaction for which `f` returns true, sorted
+    /// by ID.
+    ///
+    /// Unlike [`Self::loaded_items`], only the items that pass `f` are cloned.
+    pub fn get_items_filtered(&self, f: impl Fn(&ItemKey, &ItemValue) -> bool) -> Vec<Item> {
+        let mut items = Vec::new();
+        self.items.for_values(|k, v| {
+            if f(k, v) {
+                items.push(Item::from_key_value(k.clone(), v.clone()));
+            }
+        });
+        items.sort_by_key(|Item { id, .. }| *id);
+        items
+    }
+
+    /// Returns the items viewable in the current transaction that belong to `schema_id`, sorted
+    /// by ID.
+    ///
+    /// Runtime is linear with respect to the number of items in `schema_id`, rather than the
+    /// total number of items.
+    pub fn get_schema_items(&self, schema_id: SchemaId) -> Vec<Item> {
+        self.get_schema_items_filtered(schema_id, |_, _| true)
+    }
+
+    /// Returns the items viewable in the current transaction that belong to `schema_id` and for
+    /// which `f` returns true, sorted by ID.
+    ///
+    /// Runtime is linear with respect to the number of items in `schema_id`, rather than the
+    /// total number of items.
+    pub fn get_schema_items_filtered(
+        &self,
+        schema_id: SchemaId,
+        f: impl Fn(&ItemKey, &ItemValue) -> bool,
+    ) -> Vec<Item> {
+        let mut items = Vec::new();
+        self.items.for_values_by_index(&schema_id, |k, v| {
+            if f(k, v) {
+                items.push(Item::from_key_value(k.clone(), v.clone()));
+            }
+        });
+        items
+    }
+
     pub fn insert_audit_log_event(&mut self, event: VersionedEvent) {
         self.audit_log_updates
             .push((AuditLogKey { event }.into_proto(), (), 1));
@@ -181,7 +335,11 @@ impl<'a> Transaction<'a> {
             },
         ) {
             Ok(_) => Ok(()),
-            Err(_) => Err(SqlCatalogError::DatabaseAlreadyExists(database_name.to_owned()).into()),
+            Err(err) => Err(SqlCatalogError::DatabaseAlreadyExists(
+                database_name.to_owned(),
+                self.existing_database(&err.into_existing_key()),
+            )
+            .into()),
         }
     }
 
@@ -235,7 +393,11 @@ impl<'a> Transaction<'a> {
             },
         ) {
             Ok(_) => Ok(()),
-            Err(_) => Err(SqlCatalogError::SchemaAlreadyExists(schema_name).into()),
+            Err(err) => Err(SqlCatalogError::SchemaAlreadyExists(
+                schema_name,
+                self.existing_schema(&err.into_existing_key()),
+            )
+            .into()),
         }
     }
 
@@ -270,10 +432,65 @@ impl<'a> Transaction<'a> {
             },
         ) {
             Ok(_) => Ok(()),
-            Err(_) => Err(SqlCatalogError::RoleAlreadyExists(name).into()),
+            Err(err) => Err(SqlCatalogError::RoleAlreadyExists(
+                name,
+                self.existing_role(&err.into_existing_key()),
+            )
+            .into()),
         }
     }
 
+    /// Describes database `key`, which conflicts with a database that is being written.
+    fn existing_database(&self, key: &DatabaseKey) -> Option<ExistingObject> {
+        let value = self.databases.get(key)?;
+        Some(ExistingObject {
+            id: key.id.to_string(),
+            database: None,
+            owner: Some(self.role_name(value.owner_id)),
+        })
+    }
+
+    /// Describes schema `key`, which conflicts with a schema that is being written.
+    fn existing_schema(&self, key: &SchemaKey) -> Option<ExistingObject> {
+        let value = self.schemas.get(key)?;
+        Some(ExistingObject {
+            id: key.id.to_string(),
+            database: value.database_id.map(|id| {
+                self.databases
+                    .get(&DatabaseKey { id })
+                    .map_or_else(|| id.to_string(), |database| database.name.clone())
+            }),
+            owner: Some(self.role_name(value.owner_id)),
+        })
+    }
+
+    /// Describes role `key`, which conflicts with a role that is being written.
+    fn existing_role(&self, key: &RoleKey) -> Option<ExistingObject> {
+        self.roles.get(key)?;
+        Some(ExistingObject {
+            id: key.id.to_string(),
+            database: None,
+            owner: None,
+        })
+    }
+
+    /// Describes cluster `key`, which conflicts with a cluster that is being written.
+    fn existing_cluster(&self, key: &ClusterKey) -> Option<ExistingObject> {
+        let value = self.clusters.get(key)?;
+        Some(ExistingObject {
+            id: key.id.to_string(),
+            database: None,
+            owner: Some(self.role_name(value.owner_id)),
+        })
+    }
+
+    /// Returns the name of role `id`, or its ID if the role does not exist.
+    fn role_name(&self, id: RoleId) -> String {
+        self.roles
+            .get(&RoleKey { id })
+            .map_or_else(|| id.to_string(), |role| role.name.clone())
+    }
+
     /// Panics if any introspection source id is not a system id
     pub fn insert_user_cluster(
         &mut self,
@@ -326,7 +543,7 @@ impl<'a> Transaction<'a> {
         privileges: Vec<MzAclItem>,
         config: ClusterConfig,
     ) -> Result<(), CatalogError> {
-        if let Err(_) = self.clusters.insert(
+        if let Err(err) = self.clusters.insert(
             ClusterKey { id: cluster_id },
             ClusterValue {
                 name: cluster_name.to_string(),
@@ -336,7 +553,11 @@ impl<'a> Transaction<'a> {
                 config,
             },
         ) {
-            return Err(SqlCatalogError::ClusterAlreadyExists(cluster_name.to_owned()).into());
+            return Err(SqlCatalogError::ClusterAlreadyExists(
+                cluster_name.to_owned(),
+                self.existing_cluster(&err.into_existing_key()),
+            )
+            .into());
         };
 
         for (builtin, index_id) in introspection_source_indexes {
@@ -361,22 +582,12 @@ impl<'a> Transaction<'a> {
         cluster_to_name: &str,
     ) -> Result<(), CatalogError> {
         let key = ClusterKey { id: cluster_id };
-
-        match self.clusters.update(|k, v| {
-            if *k == key {
-                let mut value = v.clone();
-                value.name = cluster_to_name.to_string();
-                Some(value)
-            } else {
-                None
-            }
-        })? {
-            0 => Err(SqlCatalogError::UnknownCluster(cluster_name.to_string()).into()),
-            1 => Ok(()),
-            n => panic!(
-                "Expected to update single cluster {cluster_name} ({cluster_id}), updated {n}"
-            ),
-        }
+        let Some(mut value) = self.clusters.get(&key).cloned() else {
+            return Err(SqlCatalogError::UnknownCluster(cluster_name.to_string()).into());
+        };
+        value.name = cluster_to_name.to_string();
+        self.clusters.update_by_key(key, value)?;
+        Ok(())
     }
 
     pub fn check_migration_has_run(&mut self, name: String) -> Result<bool, CatalogError> {
@@ -397,6 +608,59 @@ impl<'a> Transaction<'a> {
         Ok(())
     }
 
+    /// Returns all migrations that have been applied, keyed by version.
+    ///
+    /// See [`crate::durable::migration`].
+    pub fn get_applied_migrations(&self) -> BTreeMap<u64, AppliedMigration> {
+        self.settings
+            .items()
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let version = key.name.strip_prefix(APPLIED_MIGRATION_KEY_PREFIX)?;
+                let version = version.parse().expect("invalid applied migration version");
+                let (applied_at, name) = value
+                    .value
+                    .split_once(':')
+                    .expect("invalid applied migration");
+                let applied_at = applied_at.parse().expect("invalid applied migration time");
+                Some((
+                    version,
+                    AppliedMigration {
+                        version,
+                        name: name.to_string(),
+                        applied_at,
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    /// Records `migration` as applied.
+    pub(crate) fn insert_applied_migration(
+        &mut self,
+        migration: AppliedMigration,
+    ) -> Result<(), CatalogError> {
+        let AppliedMigration {
+            version,
+            name,
+            applied_at,
+        } = migration;
+        self.settings.insert(
+            SettingKey {
+                name: format!("{APPLIED_MIGRATION_KEY_PREFIX}{version}"),
+            },
+            SettingValue {
+                value: format!("{applied_at}:{name}"),
+            },
+        )?;
+        Ok(())
+    }
+
+    /// Removes the record of the migration with `version` being applied.
+    pub(crate) fn remove_applied_migration(&mut self, version: u64) -> Result<(), CatalogError> {
+        self.set_setting(format!("{APPLIED_MIGRATION_KEY_PREFIX}{version}"), None)
+    }
+
     pub fn rename_cluster_replica(
         &mut self,
         replica_id: ReplicaId,
@@ -404,22 +668,28 @@ impl<'a> Transaction<'a> {
         replica_to_name: &str,
     ) -> Result<(), CatalogError> {
         let key = ClusterReplicaKey { id: replica_id };
+        let Some(mut value) = self.cluster_replicas.get(&key).cloned() else {
+            return Err(SqlCatalogError::UnknownClusterReplica(replica_name.to_string()).into());
+        };
+        value.name = replica_to_name.to_string();
+        self.cluster_replicas.update_by_key(key, value)?;
+        Ok(())
+    }
 
-        match self.cluster_replicas.update(|k, v| {
-            if *k == key {
-                let mut value = v.clone();
-                value.name = replica_to_name.to_string();
-                Some(value)
-            } else {
-                None
-            }
-        })? {
-            0 => Err(SqlCatalogError::UnknownClusterReplica(replica_name.to_string()).into()),
-            1 => Ok(()),
-            n => panic!(
-                "Expected to update single cluster replica {replica_name} ({replica_id}), updated {n}"
-            ),
-        }
+    /// Updates whether the cluster replica with `replica_id` is draining.
+    pub fn update_cluster_replica_draining(
+        &mut self,
+        replica_id: ReplicaId,
+        replica_name: &QualifiedReplica,
+        draining: bool,
+    ) -> Result<(), CatalogError> {
+        let key = ClusterReplicaKey { id: replica_id };
+        let Some(mut value) = self.cluster_replicas.get(&key).cloned() else {
+            return Err(SqlCatalogError::UnknownClusterReplica(replica_name.to_string()).into());
+        };
+        value.draining = draining;
+        self.cluster_replicas.update_by_key(key, value)?;
+        Ok(())
     }
 
     pub fn insert_cluster_replica(
@@ -437,6 +707,7 @@ impl<'a> Transaction<'a> {
                 name: replica_name.into(),
                 config,
                 owner_id,
+                draining: false,
             },
         ) {
             let cluster = self
@@ -490,6 +761,7 @@ impl<'a> Transaction<'a> {
         owner_id: RoleId,
         privileges: Vec<MzAclItem>,
     ) -> Result<(), CatalogError> {
+        let dependencies = item_dependencies(&create_sql)?;
         match self.items.insert(
             ItemKey { gid: id },
             ItemValue {
@@ -500,11 +772,55 @@ impl<'a> Transaction<'a> {
                 privileges,
             },
         ) {
-            Ok(_) => Ok(()),
+            Ok(_) => self.set_item_dependencies(id, dependencies),
             Err(_) => Err(SqlCatalogError::ItemAlreadyExists(id, item_name.to_owned()).into()),
         }
     }
 
+    /// Inserts all items in `items` into the transaction.
+    ///
+    /// Returns an error if any item's id or name is already taken, in which case no items are
+    /// inserted.
+    ///
+    /// Runtime is logarithmic with respect to the total number of items in the catalog for each
+    /// item, and unlike calling [`Self::insert_item`] for each item, uniqueness is only verified
+    /// once.
+    pub fn insert_items(&mut self, items: Vec<Item>) -> Result<(), CatalogError> {
+        if items.is_empty() {
+            return Ok(());
+        }
+        let kvs: Vec<_> = items
+            .iter()
+            .cloned()
+            .map(DurableType::into_key_value)
+            .collect();
+        let Err(err) = self.items.insert_many(kvs) else {
+            for item in &items {
+                self.set_item_dependencies(item.id, item_dependencies(&item.create_sql)?)?;
+            }
+            return Ok(());
+        };
+
+        // Report the first item that conflicts with an existing item or an earlier item in
+        // `items`.
+        let mut ids = BTreeSet::new();
+        let mut names = BTreeSet::new();
+        for item in items {
+            let mut name_taken = false;
+            self.items.for_values_by_index(&item.schema_id, |_, value| {
+                name_taken |= value.name == item.name
+            });
+            if name_taken
+                || self.items.get(&ItemKey { gid: item.id }).is_some()
+                || !ids.insert(item.id)
+                || !names.insert((item.schema_id, item.name.clone()))
+            {
+                return Err(SqlCatalogError::ItemAlreadyExists(item.id, item.name).into());
+            }
+        }
+        Err(err.into())
+    }
+
     pub fn insert_timestamp(
         &mut self,
         timeline: Timeline,
@@ -530,6 +846,12 @@ impl<'a> Transaction<'a> {
         key: String,
         amount: u64,
     ) -> Result<Vec<u64>, CatalogError> {
+        Ok(self.increment_id_by(key, amount)?.collect())
+    }
+
+    /// Advances the `key` allocator by `amount` and returns the range of IDs that were skipped
+    /// over.
+    fn increment_id_by(&mut self, key: String, amount: u64) -> Result<Range<u64>, CatalogError> {
         let current_id = self
             .id_allocator
             .items()
@@ -548,7 +870,108 @@ impl<'a> Transaction<'a> {
                 next_id: current_id
             })
         );
-        Ok((current_id..next_id).collect())
+        Ok(current_id..next_id)
+    }
+
+    /// Reserves `amount` IDs of the `key` allocator and leases them to `region`.
+    ///
+    /// Leased IDs are never allocated by the `key` allocator or by any other lease, so
+    /// environments that allocate IDs only from their own leases never allocate colliding IDs.
+    /// IDs are allocated from the lease with [`Self::get_and_increment_leased_id_by`].
+    ///
+    /// Returns the leased range of IDs, or an error if `region` already holds a lease of IDs of
+    /// the `key` allocator.
+    pub fn lease_id_range(
+        &mut self,
+        key: &str,
+        region: &str,
+        amount: u64,
+    ) -> Result<Range<u64>, CatalogError> {
+        let name = id_range_lease_name(key, region);
+        if self.get_id_range_leases(key).contains_key(region) {
+            return Err(DurableCatalogError::IdRangeLeaseAlreadyExists(name).into());
+        }
+        let range = self.increment_id_by(key.to_string(), amount)?;
+        self.insert_id_allocator(name.clone(), range.start)?;
+        self.settings.insert(
+            SettingKey {
+                name: format!("{ID_RANGE_LEASE_KEY_PREFIX}{name}"),
+            },
+            SettingValue {
+                value: format!("{}:{}", range.start, range.end),
+            },
+        )?;
+        Ok(range)
+    }
+
+    /// Returns the ranges of IDs of the `key` allocator that are leased, keyed by region.
+    pub fn get_id_range_leases(&self, key: &str) -> BTreeMap<String, Range<u64>> {
+        let prefix = format!("{ID_RANGE_LEASE_KEY_PREFIX}{key}.");
+        self.settings
+            .items()
+            .into_iter()
+            .filter_map(|(setting_key, value)| {
+                let region = setting_key.name.strip_prefix(&prefix)?;
+                let (start, end) = value.value.split_once(':').expect("invalid ID range lease");
+                let start = start.parse().expect("invalid ID range lease start");
+                let end = end.parse().expect("invalid ID range lease end");
+                Some((region.to_string(), start..end))
+            })
+            .collect()
+    }
+
+    /// Allocates `amount` IDs from the lease of IDs of the `key` allocator held by `region`.
+    ///
+    /// Returns an error if `region` does not hold a lease of IDs of the `key` allocator, or if
+    /// fewer than `amount` IDs of the lease are unallocated.
+    pub fn get_and_increment_leased_id_by(
+        &mut self,
+        key: &str,
+        region: &str,
+        amount: u64,
+    ) -> Result<Vec<u64>, CatalogError> {
+        let name = id_range_lease_name(key, region);
+        let Some(lease) = self.get_id_range_leases(key).remove(region) else {
+            return Err(DurableCatalogError::UnknownIdRangeLease(name).into());
+        };
+        let next_id = self
+            .id_allocator
+            .get(&IdAllocKey { name: name.clone() })
+            .unwrap_or_else(|| panic!("{name} id allocator missing"))
+            .next_id;
+        if lease.end - next_id < amount {
+            return Err(DurableCatalogError::IdRangeLeaseExhausted(name).into());
+        }
+        self.get_and_increment_id_by(name, amount)
+    }
+
+    /// Allocates `amount` user [`GlobalId`]s from the lease of user item IDs held by `region`.
+    ///
+    /// See [`Self::get_and_increment_leased_id_by`].
+    pub fn allocate_leased_user_item_ids(
+        &mut self,
+        region: &str,
+        amount: u64,
+    ) -> Result<Vec<GlobalId>, CatalogError> {
+        Ok(self
+            .get_and_increment_leased_id_by(USER_ITEM_ALLOC_KEY, region, amount)?
+            .into_iter()
+            .map(GlobalId::User)
+            .collect())
+    }
+
+    /// Releases the lease of IDs of the `key` allocator held by `region`.
+    ///
+    /// The unallocated IDs of the lease are not returned to the `key` allocator, and so are never
+    /// allocated.
+    pub fn release_id_range_lease(&mut self, key: &str, region: &str) -> Result<(), CatalogError> {
+        let name = id_range_lease_name(key, region);
+        if !self.get_id_range_leases(key).contains_key(region) {
+            return Err(DurableCatalogError::UnknownIdRangeLease(name).into());
+        }
+        self.set_setting(format!("{ID_RANGE_LEASE_KEY_PREFIX}{name}"), None)?;
+        self.id_allocator.set(IdAllocKey { name }, None)?;
+        Ok(())
     }
 
     pub fn allocate_system_item_ids(&mut self, amount: u64) -> Result<Vec<GlobalId>, CatalogError> {
@@ -567,11 +990,10 @@ impl<'a> Transaction<'a> {
             .collect())
     }
 
-    pub(crate) fn insert_id_allocator(
-        &mut self,
-        name: String,
-        next_id: u64,
-    ) -> Result<(), CatalogError> {
+    /// Creates the `name` allocator, which allocates IDs starting at `next_id`.
+    ///
+    /// Returns an error if the allocator already exists.
+    pub fn insert_id_allocator(&mut self, name: String, next_id: u64) -> Result<(), CatalogError> {
         match self
             .id_allocator
             .insert(IdAllocKey { name: name.clone() }, IdAllocValue { next_id })
@@ -581,6 +1003,21 @@ impl<'a> Transaction<'a> {
         }
     }
 
+    /// Creates the `name` allocator, which allocates IDs starting at `next_id`, if it does not
+    /// already exist.
+    ///
+    /// Returns true if the allocator was created.
+    pub fn ensure_id_allocator(&mut self, name: &str, next_id: u64) -> Result<bool, CatalogError> {
+        let key = IdAllocKey {
+            name: name.to_string(),
+        };
+        if self.id_allocator.get(&key).is_some() {
+            return Ok(false);
+        }
+        self.insert_id_allocator(key.name, next_id)?;
+        Ok(true)
+    }
+
     pub fn remove_database(&mut self, id: &DatabaseId) -> Result<(), CatalogError> {
         let prev = self.databases.set(DatabaseKey { id: *id }, None)?;
         if prev.is_some() {
@@ -607,8 +1044,14 @@ impl<'a> Transaction<'a> {
         }
     }
 
+    /// Removes role `name` from the transaction.
+    ///
+    /// Returns an error if `name` is not found.
+    ///
+    /// Runtime is logarithmic with respect to the total number of roles in the catalog.
     pub fn remove_role(&mut self, name: &str) -> Result<(), CatalogError> {
-        let roles = self.roles.delete(|_k, v| v.name == name);
+        let keys = self.roles.items_by_index(&name.to_string()).into_keys();
+        let roles = self.roles.delete_by_keys(keys);
         assert!(
             roles.iter().all(|(k, _)| k.id.is_user()),
             "cannot delete non-user roles"
@@ -616,38 +1059,294 @@ impl<'a> Transaction<'a> {
         let n = roles.len();
         assert!(n <= 1);
         if n == 1 {
+            let role_ids = roles.into_iter().map(|(k, _)| k.id).collect();
+            self.remove_role_column_masks(&role_ids);
+            self.remove_role_service_accounts(&role_ids);
             Ok(())
         } else {
             Err(SqlCatalogError::UnknownRole(name.to_owned()).into())
         }
     }
 
-    pub fn remove_cluster(&mut self, id: ClusterId) -> Result<(), CatalogError> {
-        let deleted = self.clusters.delete(|k, _v| k.id == id);
-        if deleted.is_empty() {
-            Err(SqlCatalogError::UnknownCluster(id.to_string()).into())
-        } else {
-            assert_eq!(deleted.len(), 1);
-            // Cascade delete introspection sources and cluster replicas.
-            //
-            // TODO(benesch): this doesn't seem right. Cascade deletions should
-            // be entirely the domain of the higher catalog layer, not the
-            // storage layer.
-            self.cluster_replicas.delete(|_k, v| v.cluster_id == id);
-            self.introspection_sources
-                .delete(|k, _v| k.cluster_id == id);
+    /// Removes all roles in `ids` from the transaction.
+    ///
+    /// Returns an error if any id in `ids` is not found.
+    ///
+    /// NOTE: On error, there still may be some roles removed from the transaction. It is
+    /// up to the called to either abort the transaction or commit.
+    pub fn remove_roles(&mut self, ids: BTreeSet<RoleId>) -> Result<(), CatalogError> {
+        let roles = self
+            .roles
+            .delete_by_keys(ids.iter().map(|id| RoleKey { id: *id }));
+        assert!(
+            roles.iter().all(|(k, _)| k.id.is_user()),
+            "cannot delete non-user roles"
+        );
+        self.remove_role_column_masks(&ids);
+        self.remove_role_service_accounts(&ids);
+        if roles.len() == ids.len() {
             Ok(())
+        } else {
+            let removed_ids = roles.into_iter().map(|(k, _)| k.id).collect();
+            let mut unknown = ids.difference(&removed_ids);
+            Err(SqlCatalogError::UnknownRole(unknown.join(", ")).into())
+        }
+    }
+
+    /// Removes cluster `id` from the transaction, along with its replicas and introspection
+    /// source indexes.
+    ///
+    /// Returns an error if `id` is not found.
+    ///
+    /// Runtime is logarithmic with respect to the total number of clusters in the catalog.
+    pub fn remove_cluster(&mut self, id: ClusterId) -> Result<(), CatalogError> {
+        self.remove_clusters(BTreeSet::from([id]))?;
+        Ok(())
+    }
+
+    /// Removes all clusters in `ids` from the transaction, along with their replicas and
+    /// introspection source indexes.
+    ///
+    /// Returns the replicas and introspection source indexes that were removed along with the
+    /// clusters, or an error if any id in `ids` is not found.
+    ///
+    /// NOTE: On error, there still may be some clusters removed from the transaction. It is
+    /// up to the called to either abort the transaction or commit.
+    pub fn remove_clusters(
+        &mut self,
+        ids: BTreeSet<ClusterId>,
+    ) -> Result<RemovedClusterObjects, CatalogError> {
+        let clusters = self
+            .clusters
+            .delete_by_keys(ids.iter().map(|id| ClusterKey { id: *id }));
+        if clusters.len() != ids.len() {
+            let removed_ids = clusters.into_iter().map(|(k, _)| k.id).collect();
+            let mut unknown = ids.difference(&removed_ids);
+            return Err(SqlCatalogError::UnknownCluster(unknown.join(", ")).into());
         }
+
+        // Cascade delete introspection sources and cluster replicas.
+        //
+        // TODO(benesch): this doesn't seem right. Cascade deletions should
+        // be entirely the domain of the higher catalog layer, not the
+        // storage layer.
+        let replica_keys: Vec<_> = ids
+            .iter()
+            .flat_map(|id| self.cluster_replicas.items_by_index(id).into_keys())
+            .collect();
+        let replicas = self
+            .cluster_replicas
+            .delete_by_keys(replica_keys)
+            .into_iter()
+            .map(|(k, v)| ClusterReplica::from_key_value(k, v))
+            .collect();
+        let introspection_source_keys: Vec<_> = ids
+            .iter()
+            .flat_map(|id| self.introspection_sources.items_by_index(id).into_keys())
+            .collect();
+        let introspection_source_indexes = self
+            .introspection_sources
+            .delete_by_keys(introspection_source_keys)
+            .into_iter()
+            .map(|(k, v)| IntrospectionSourceIndex::from_key_value(k, v))
+            .collect();
+        Ok(RemovedClusterObjects {
+            replicas,
+            introspection_source_indexes,
+        })
     }
 
     pub fn remove_cluster_replica(&mut self, id: ReplicaId) -> Result<(), CatalogError> {
-        let deleted = self.cluster_replicas.delete(|k, _v| k.id == id);
-        if deleted.len() == 1 {
-            Ok(())
+        match self
+            .cluster_replicas
+            .delete_by_key(ClusterReplicaKey { id })
+        {
+            Some(_) => Ok(()),
+            None => Err(SqlCatalogError::UnknownClusterReplica(id.to_string()).into()),
+        }
+    }
+
+    /// Removes all audit log events that occurred before `before_ts` from the transaction, along
+    /// with all but the newest `max_events` events, if set. Events inserted by this transaction
+    /// are never removed.
+    ///
+    /// Returns the removed events.
+    pub async fn prune_audit_log(
+        &mut self,
+        before_ts: EpochMillis,
+        max_events: Option<usize>,
+    ) -> Result<Vec<VersionedEvent>, CatalogError> {
+        // Sorted by id, and so oldest first.
+        let events = self.durable_catalog.get_audit_logs().await?;
+        let excess = match max_events {
+            Some(max_events) => events.len().saturating_sub(max_events),
+            None => 0,
+        };
+        let pruned: Vec<_> = events
+            .into_iter()
+            .enumerate()
+            .filter(|(i, event)| {
+                let occurred_at = match event {
+                    VersionedEvent::V1(event) => event.occurred_at,
+                };
+                *i < excess || occurred_at < before_ts
+            })
+            .map(|(_, event)| event)
+            .collect();
+        self.audit_log_updates.extend(pruned.iter().map(|event| {
+            (
+                AuditLogKey {
+                    event: event.clone(),
+                }
+                .into_proto(),
+                (),
+                -1,
+            )
+        }));
+        Ok(pruned)
+    }
+
+    /// Returns all storage usage events that have been committed to the durable catalog, sorted
+    /// by ID. Events inserted by this transaction are not included.
+    pub async fn get_storage_usage(&mut self) -> Result<Vec<VersionedStorageUsage>, CatalogError> {
+        self.durable_catalog.get_storage_usage().await
+    }
+
+    /// Rolls up all storage usage events collected before `before_ts` into daily aggregates.
+    ///
+    /// The events of each shard are grouped by the day in which they were collected, and each
+    /// group is replaced by a single event recording the peak size of the shard during that day.
+    /// The aggregate keeps the smallest ID of its group and is timestamped at the start of the
+    /// day. Days that have already been rolled up are left untouched, so compacting is
+    /// idempotent. Events inserted by this transaction are never compacted.
+    ///
+    /// Returns the aggregate events that were added to the transaction.
+    pub async fn compact_storage_usage(
+        &mut self,
+        before_ts: EpochMillis,
+    ) -> Result<Vec<VersionedStorageUsage>, CatalogError> {
+        let (_removed, rollups) = self.roll_up_storage_usage(before_ts).await?;
+        Ok(rollups)
+    }
+
+    /// Like [`Self::compact_storage_usage`], but also returns the events that were replaced by
+    /// the aggregates.
+    async fn roll_up_storage_usage(
+        &mut self,
+        before_ts: EpochMillis,
+    ) -> Result<(Vec<VersionedStorageUsage>, Vec<VersionedStorageUsage>), CatalogError> {
+        let events = self.durable_catalog.get_storage_usage().await?;
+        let mut days: BTreeMap<_, Vec<StorageUsageV1>> = BTreeMap::new();
+        for event in events {
+            let VersionedStorageUsage::V1(usage) = event;
+            if usage.collection_timestamp >= before_ts {
+                continue;
+            }
+            let day = usage.collection_timestamp - usage.collection_timestamp % MILLIS_PER_DAY;
+            days.entry((usage.shard_id.clone(), day))
+                .or_default()
+                .push(usage);
+        }
+
+        let mut removed = Vec::new();
+        let mut rollups = Vec::new();
+        for ((shard_id, day), usages) in days {
+            if let [usage] = usages.as_slice() {
+                if usage.collection_timestamp == day {
+                    continue;
+                }
+            }
+            let id = usages
+                .iter()
+                .map(|usage| usage.id)
+                .min()
+                .expect("non-empty");
+            let size_bytes = usages
+                .iter()
+                .map(|usage| usage.size_bytes)
+                .max()
+                .expect("non-empty");
+            let usages: Vec<_> = usages.into_iter().map(VersionedStorageUsage::V1).collect();
+            self.remove_storage_usage_events(usages.clone());
+            removed.extend(usages);
+            let rollup = VersionedStorageUsage::new(id, shard_id, size_bytes, day);
+            self.insert_storage_usage_event(rollup.clone());
+            rollups.push(rollup);
+        }
+        Ok((removed, rollups))
+    }
+
+    /// Removes audit log events, rolls up storage usage events, purges the item trash and removes
+    /// settings from the transaction, as described by `options`, to reclaim space in the durable
+    /// catalog.
+    ///
+    /// Returns what was removed and added.
+    pub async fn vacuum(&mut self, options: VacuumOptions) -> Result<VacuumReport, CatalogError> {
+        let VacuumOptions {
+            audit_log_before_ts,
+            audit_log_max_events,
+            storage_usage_rollup_before_ts,
+            item_trash_purge_ts,
+            obsolete_settings,
+        } = options;
+        let audit_log_start = self.audit_log_updates.len();
+        let storage_usage_start = self.storage_usage_updates.len();
+
+        // Avoid reading the entire audit log when nothing can be pruned.
+        let pruned_audit_log_events = if audit_log_before_ts > 0 || audit_log_max_events.is_some() {
+            self.prune_audit_log(audit_log_before_ts, audit_log_max_events)
+                .await?
         } else {
-            assert!(deleted.is_empty());
-            Err(SqlCatalogError::UnknownClusterReplica(id.to_string()).into())
+            Vec::new()
+        };
+        let (removed_storage_usage_events, storage_usage_rollups) =
+            match storage_usage_rollup_before_ts {
+                Some(before_ts) => self.roll_up_storage_usage(before_ts).await?,
+                None => (Vec::new(), Vec::new()),
+            };
+        let purged_trashed_items = match item_trash_purge_ts {
+            Some(now) => self.purge_item_trash(now),
+            None => Vec::new(),
+        };
+        let mut removed_settings = Vec::new();
+        let mut reclaimed_bytes = 0;
+        for trashed_item in &purged_trashed_items {
+            let (key, value) = trashed_item.clone().into_key_value();
+            reclaimed_bytes += key.into_proto().encoded_len() + value.into_proto().encoded_len();
+        }
+        for name in obsolete_settings {
+            let key = SettingKey { name };
+            if let Some(value) = self.settings.set(key.clone(), None)? {
+                reclaimed_bytes +=
+                    key.into_proto().encoded_len() + value.into_proto().encoded_len();
+                removed_settings.push(key.name);
+            }
+        }
+
+        let mut added_bytes = 0;
+        for (key, (), diff) in &self.audit_log_updates[audit_log_start..] {
+            if *diff < 0 {
+                reclaimed_bytes += key.encoded_len();
+            } else {
+                added_bytes += key.encoded_len();
+            }
+        }
+        for (key, (), diff) in &self.storage_usage_updates[storage_usage_start..] {
+            if *diff < 0 {
+                reclaimed_bytes += key.encoded_len();
+            } else {
+                added_bytes += key.encoded_len();
+            }
         }
+
+        Ok(VacuumReport {
+            pruned_audit_log_events,
+            removed_storage_usage_events,
+            storage_usage_rollups,
+            purged_trashed_items,
+            removed_settings,
+            reclaimed_bytes: reclaimed_bytes.saturating_sub(added_bytes),
+        })
     }
 
     /// Removes all storage usage events in `events` from the transaction.
@@ -662,11 +1361,13 @@ impl<'a> Transaction<'a> {
     ///
     /// Returns an error if `id` is not found.
     ///
-    /// Runtime is linear with respect to the total number of items in the catalog.
-    /// DO NOT call this function in a loop, use [`Self::remove_items`] instead.
+    /// Runtime is logarithmic with respect to the total number of items in the catalog.
     pub fn remove_item(&mut self, id: GlobalId) -> Result<(), CatalogError> {
         let prev = self.items.set(ItemKey { gid: id }, None)?;
         if prev.is_some() {
+            self.remove_item_dependencies(id);
+            self.remove_item_column_masks(id);
+            self.remove_secret_service_accounts(id);
             Ok(())
         } else {
             Err(SqlCatalogError::UnknownItem(id.to_string()).into())
@@ -680,7 +1381,15 @@ impl<'a> Transaction<'a> {
     /// NOTE: On error, there still may be some items removed from the transaction. It is
     /// up to the called to either abort the transaction or commit.
     pub fn remove_items(&mut self, ids: BTreeSet<GlobalId>) -> Result<(), CatalogError> {
-        let n = self.items.delete(|k, _v| ids.contains(&k.gid)).len();
+        let n = self
+            .items
+            .delete_by_keys(ids.iter().map(|gid| ItemKey { gid: *gid }))
+            .len();
+        for id in &ids {
+            self.remove_item_dependencies(*id);
+            self.remove_item_column_masks(*id);
+            self.remove_secret_service_accounts(*id);
+        }
         if n == ids.len() {
             Ok(())
         } else {
@@ -690,30 +1399,111 @@ impl<'a> Transaction<'a> {
         }
     }
 
-    /// Updates item `id` in the transaction to `item_name` and `item`.
+    /// Drops item `id` from the transaction at time `dropped_at`.
     ///
-    /// Returns an error if `id` is not found.
+    /// If the item trash is enabled, see [`Self::set_item_trash_retention`], then the item is
+    /// moved to the trash, from which it can be restored with [`Self::undrop_item`] until it is
+    /// purged with [`Self::purge_item_trash`]. Otherwise, this is equivalent to
+    /// [`Self::remove_item`].
     ///
-    /// Runtime is linear with respect to the total number of items in the catalog.
-    /// DO NOT call this function in a loop, use [`Self::update_items`] instead.
-    pub fn update_item(&mut self, id: GlobalId, item: Item) -> Result<(), CatalogError> {
-        let n = self.items.update(|k, v| {
-            if k.gid == id {
-                let item = item.clone();
-                // Schema IDs cannot change.
-                assert_eq!(item.schema_id, v.schema_id);
-                let (_, new_value) = item.into_key_value();
-                Some(new_value)
-            } else {
-                None
-            }
-        })?;
-        assert!(n <= 1);
-        if n == 1 {
-            Ok(())
-        } else {
-            Err(SqlCatalogError::UnknownItem(id.to_string()).into())
+    /// Returns an error if `id` is not found.
+    pub fn drop_item(&mut self, id: GlobalId, dropped_at: EpochMillis) -> Result<(), CatalogError> {
+        let key = ItemKey { gid: id };
+        let Some(item) = self.items.get(&key).cloned() else {
+            return Err(SqlCatalogError::UnknownItem(id.to_string()).into());
+        };
+        self.remove_item(id)?;
+        if self.get_item_trash_retention().is_some() {
+            self.trashed_items.set(
+                TrashedItemKey { gid: id },
+                Some(TrashedItemValue { item, dropped_at }),
+            )?;
         }
+        Ok(())
+    }
+
+    /// Restores item `id` from the trash, and returns the restored item.
+    ///
+    /// Returns an error if `id` is not in the trash, if the schema or any of the user items that
+    /// the item depends on no longer exist, or if the item's name has since been taken.
+    pub fn undrop_item(&mut self, id: GlobalId) -> Result<Item, CatalogError> {
+        let key = TrashedItemKey { gid: id };
+        let Some(value) = self.trashed_items.get(&key).cloned() else {
+            return Err(SqlCatalogError::UnknownItem(id.to_string()).into());
+        };
+        let item = Item::from_key_value(ItemKey { gid: id }, value.item);
+        if self
+            .schemas
+            .get(&SchemaKey { id: item.schema_id })
+            .is_none()
+        {
+            return Err(SqlCatalogError::UnknownSchema(item.schema_id.to_string()).into());
+        }
+        // Builtin items are not stored in the items collection, and can't be dropped.
+        if let Some(dependency_id) =
+            item_dependencies(&item.create_sql)?
+                .into_iter()
+                .find(|dependency_id| {
+                    dependency_id.is_user()
+                        && self
+                            .items
+                            .get(&ItemKey {
+                                gid: *dependency_id,
+                            })
+                            .is_none()
+                })
+        {
+            return Err(SqlCatalogError::UnknownItem(dependency_id.to_string()).into());
+        }
+        // Items in the trash don't prevent their roles from being dropped, so the item can only
+        // be restored if its owner and the roles of its privileges still exist.
+        if let Some(role_id) = iter::once(item.owner_id)
+            .chain(
+                item.privileges
+                    .iter()
+                    .flat_map(|privilege| [privilege.grantee, privilege.grantor]),
+            )
+            .find(|role_id| {
+                !role_id.is_public() && self.roles.get(&RoleKey { id: *role_id }).is_none()
+            })
+        {
+            return Err(SqlCatalogError::UnknownRole(role_id.to_string()).into());
+        }
+        self.insert_item(
+            item.id,
+            item.schema_id,
+            &item.name,
+            item.create_sql.clone(),
+            item.owner_id,
+            item.privileges.clone(),
+        )?;
+        self.trashed_items.delete_by_key(key);
+        Ok(item)
+    }
+
+    /// Permanently removes all items from the trash that were dropped at least the trash retention
+    /// before `now`, or all items if the trash is disabled. Returns the removed items.
+    pub fn purge_item_trash(&mut self, now: EpochMillis) -> Vec<TrashedItem> {
+        let cutoff = match self.get_item_trash_retention() {
+            Some(retention) => {
+                now.saturating_sub(u64::try_from(retention.as_millis()).unwrap_or(u64::MAX))
+            }
+            None => EpochMillis::MAX,
+        };
+        self.trashed_items
+            .delete(|_k, v| v.dropped_at <= cutoff)
+            .into_iter()
+            .map(|(k, v)| DurableType::from_key_value(k, v))
+            .collect()
+    }
+
+    /// Updates item `id` in the transaction to `item_name` and `item`.
+    ///
+    /// Returns an error if `id` is not found.
+    ///
+    /// Runtime is logarithmic with respect to the total number of items in the catalog.
+    pub fn update_item(&mut self, id: GlobalId, item: Item) -> Result<(), CatalogError> {
+        self.update_items(BTreeMap::from([(id, item)]))
     }
 
     /// Updates all items with ids matching the keys of `items` in the transaction, to the
@@ -724,18 +1514,24 @@ impl<'a> Transaction<'a> {
     /// NOTE: On error, there still may be some items updated in the transaction. It is
     /// up to the called to either abort the transaction or commit.
     pub fn update_items(&mut self, items: BTreeMap<GlobalId, Item>) -> Result<(), CatalogError> {
-        let n = self.items.update(|k, v| {
-            if let Some(item) = items.get(&k.gid) {
-                // Schema IDs cannot change.
-                assert_eq!(item.schema_id, v.schema_id);
+        let kvs = items
+            .iter()
+            .map(|(id, item)| {
+                let key = ItemKey { gid: *id };
+                if let Some(v) = self.items.get(&key) {
+                    // Schema IDs cannot change.
+                    assert_eq!(item.schema_id, v.schema_id);
+                }
                 let (_, new_value) = item.clone().into_key_value();
-                Some(new_value)
-            } else {
-                None
-            }
-        })?;
+                (key, new_value)
+            })
+            .collect();
+        let n = self.items.update_by_keys(kvs)?;
         let n = usize::try_from(n).expect("Must be positive and fit in usize");
         if n == items.len() {
+            for (id, item) in &items {
+                self.set_item_dependencies(*id, item_dependencies(&item.create_sql)?)?;
+            }
             Ok(())
         } else {
             let update_ids: BTreeSet<_> = items.into_keys().collect();
@@ -745,31 +1541,236 @@ impl<'a> Transaction<'a> {
         }
     }
 
+    /// Replaces the recorded dependencies of item `id` with `dependencies`.
+    fn set_item_dependencies(
+        &mut self,
+        id: GlobalId,
+        dependencies: BTreeSet<GlobalId>,
+    ) -> Result<(), CatalogError> {
+        self.remove_item_dependencies(id);
+        let kvs = dependencies.into_iter().map(|dependency_id| {
+            let dependency = Dependency {
+                object_id: id,
+                dependency_id,
+            };
+            dependency.into_key_value()
+        });
+        self.dependencies.insert_many(kvs)?;
+        Ok(())
+    }
+
+    /// Removes the recorded dependencies of item `id`.
+    fn remove_item_dependencies(&mut self, id: GlobalId) {
+        let keys = self.dependencies.items_by_index(&id).into_keys();
+        self.dependencies.delete_by_keys(keys);
+    }
+
+    /// Sets the mask of `column` of item `item_id` for role `role_id` to `expr`, or removes the
+    /// mask if `expr` is `None`. Returns the previous mask, if any.
+    pub fn set_column_mask(
+        &mut self,
+        item_id: GlobalId,
+        column: String,
+        role_id: RoleId,
+        expr: Option<String>,
+    ) -> Result<Option<String>, CatalogError> {
+        let key = ColumnMaskKey {
+            item_id,
+            column,
+            role_id,
+        };
+        let value = expr.map(|expr| ColumnMaskValue { expr });
+        let prev = self.column_masks.set(key, value)?;
+        Ok(prev.map(|prev| prev.expr))
+    }
+
+    /// Removes the masks of `column` of item `item_id` for all roles, and returns the removed
+    /// masks.
+    pub fn remove_column_masks(&mut self, item_id: GlobalId, column: &str) -> Vec<ColumnMask> {
+        let keys = self
+            .column_masks
+            .items_by_index(&item_id)
+            .into_keys()
+            .filter(|k| k.column == column);
+        self.column_masks
+            .delete_by_keys(keys)
+            .into_iter()
+            .map(|(k, v)| DurableType::from_key_value(k, v))
+            .collect()
+    }
+
+    /// Removes the masks of all columns of item `id`.
+    fn remove_item_column_masks(&mut self, id: GlobalId) {
+        let keys = self.column_masks.items_by_index(&id).into_keys();
+        self.column_masks.delete_by_keys(keys);
+    }
+
+    /// Removes the masks of all columns for the roles in `role_ids`.
+    fn remove_role_column_masks(&mut self, role_ids: &BTreeSet<RoleId>) {
+        let keys = self
+            .column_masks
+            .items()
+            .into_keys()
+            .filter(|k| role_ids.contains(&k.role_id));
+        self.column_masks.delete_by_keys(keys);
+    }
+
+    /// Inserts a service account named `name` that authenticates as `role_id` with the token
+    /// stored in secret `token_secret_id`, and returns its ID.
+    ///
+    /// Returns an error if a service account named `name` already exists.
+    pub fn insert_service_account(
+        &mut self,
+        name: String,
+        role_id: RoleId,
+        token_secret_id: GlobalId,
+        scopes: Vec<String>,
+        expires_at: Option<EpochMillis>,
+    ) -> Result<u64, CatalogError> {
+        let id = self.get_and_increment_id(SERVICE_ACCOUNT_ID_ALLOC_KEY.to_string())?;
+        match self.service_accounts.insert(
+            ServiceAccountKey { id },
+            ServiceAccountValue {
+                name: name.clone(),
+                role_id,
+                token_secret_id,
+                scopes,
+                expires_at,
+            },
+        ) {
+            Ok(_) => Ok(id),
+            Err(_) => Err(SqlCatalogError::ServiceAccountAlreadyExists(name).into()),
+        }
+    }
+
+    /// Removes the service account named `name` from the transaction, and returns it.
+    ///
+    /// Returns an error if `name` is not found.
+    pub fn remove_service_account(&mut self, name: &str) -> Result<ServiceAccount, CatalogError> {
+        let keys = self
+            .service_accounts
+            .items_by_index(&name.to_string())
+            .into_keys();
+        self.service_accounts
+            .delete_by_keys(keys)
+            .into_iter()
+            .map(|(k, v)| DurableType::from_key_value(k, v))
+            .next()
+            .ok_or_else(|| SqlCatalogError::UnknownServiceAccount(name.to_owned()).into())
+    }
+
+    /// Removes the service accounts that authenticate as the roles in `role_ids`.
+    fn remove_role_service_accounts(&mut self, role_ids: &BTreeSet<RoleId>) {
+        let keys = self
+            .service_accounts
+            .items()
+            .into_iter()
+            .filter(|(_, v)| role_ids.contains(&v.role_id))
+            .map(|(k, _)| k);
+        self.service_accounts.delete_by_keys(keys);
+    }
+
+    /// Removes the service accounts whose token is stored in secret `id`.
+    fn remove_secret_service_accounts(&mut self, id: GlobalId) {
+        let keys = self
+            .service_accounts
+            .items()
+            .into_iter()
+            .filter(|(_, v)| v.token_secret_id == id)
+            .map(|(k, _)| k);
+        self.service_accounts.delete_by_keys(keys);
+    }
+
+    /// Inserts the cluster replica size `size`.
+    ///
+    /// Returns an error if a size with the same name already exists.
+    pub fn insert_cluster_replica_size(
+        &mut self,
+        size: ClusterReplicaSize,
+    ) -> Result<(), CatalogError> {
+        let name = size.name.clone();
+        let (key, value) = size.into_key_value();
+        match self.cluster_replica_sizes.insert(key, value) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SqlCatalogError::ClusterReplicaSizeAlreadyExists(name).into()),
+        }
+    }
+
+    /// Removes the cluster replica size named `name` from the transaction, and returns it.
+    ///
+    /// Returns an error if `name` is not found.
+    pub fn remove_cluster_replica_size(
+        &mut self,
+        name: &str,
+    ) -> Result<ClusterReplicaSize, CatalogError> {
+        let key = ClusterReplicaSizeKey {
+            name: name.to_string(),
+        };
+        match self.cluster_replica_sizes.set(key.clone(), None)? {
+            Some(value) => Ok(DurableType::from_key_value(key, value)),
+            None => Err(SqlCatalogError::UnknownClusterReplicaSize(name.to_owned()).into()),
+        }
+    }
+
     /// Updates role `id` in the transaction to `role`.
     ///
     /// Returns an error if `id` is not found.
     ///
-    /// Runtime is linear with respect to the total number of items in the catalog.
-    /// DO NOT call this function in a loop, implement and use some `Self::update_roles` instead.
-    /// You should model it after [`Self::update_items`].
+    /// Runtime is logarithmic with respect to the total number of roles in the catalog.
     pub fn update_role(&mut self, id: RoleId, role: Role) -> Result<(), CatalogError> {
-        let n = self.roles.update(move |k, _v| {
-            if k.id == id {
-                let role = role.clone();
-                let (_, new_value) = role.into_key_value();
-                Some(new_value)
-            } else {
-                None
-            }
-        })?;
-        assert!(n <= 1);
-        if n == 1 {
+        let (_, new_value) = role.into_key_value();
+        if self.roles.update_by_key(RoleKey { id }, new_value)? {
             Ok(())
         } else {
             Err(SqlCatalogError::UnknownItem(id.to_string()).into())
         }
     }
 
+    /// Updates all roles with ids matching the keys of `roles` in the transaction, to the
+    /// corresponding value in `roles`.
+    ///
+    /// Returns an error if any id in `roles` is not found.
+    ///
+    /// NOTE: On error, there still may be some roles updated in the transaction. It is
+    /// up to the called to either abort the transaction or commit.
+    pub fn update_roles(&mut self, roles: BTreeMap<RoleId, Role>) -> Result<(), CatalogError> {
+        let kvs = roles
+            .iter()
+            .map(|(id, role)| {
+                let (_, new_value) = role.clone().into_key_value();
+                (RoleKey { id: *id }, new_value)
+            })
+            .collect();
+        let n = self.roles.update_by_keys(kvs)?;
+        let n = usize::try_from(n).expect("Must be positive and fit in usize");
+        if n == roles.len() {
+            Ok(())
+        } else {
+            let update_ids: BTreeSet<_> = roles.into_keys().collect();
+            let role_ids: BTreeSet<_> = self.roles.items().keys().map(|k| k.id).collect();
+            let mut unknown = update_ids.difference(&role_ids);
+            Err(SqlCatalogError::UnknownItem(unknown.join(", ")).into())
+        }
+    }
+
+    /// Sets the password of role `id` to `password`, or removes the role's password if `password`
+    /// is `None`. Only a salted verifier of the password is stored.
+    ///
+    /// Returns an error if `id` is not found.
+    pub fn set_role_password(
+        &mut self,
+        id: RoleId,
+        password: Option<&str>,
+    ) -> Result<(), CatalogError> {
+        let key = RoleKey { id };
+        let Some(mut value) = self.roles.get(&key).cloned() else {
+            return Err(SqlCatalogError::UnknownRole(id.to_string()).into());
+        };
+        value.attributes.set_password(password);
+        self.roles.update_by_key(key, value)?;
+        Ok(())
+    }
+
     /// Updates persisted mapping from system objects to global IDs and fingerprints. Each element
     /// of `mappings` should be (old-global-id, new-system-object-mapping).
     ///
@@ -799,19 +1800,10 @@ impl<'a> Transaction<'a> {
     ///
     /// Returns an error if `id` is not found.
     ///
-    /// Runtime is linear with respect to the total number of clusters in the catalog.
-    /// DO NOT call this function in a loop.
+    /// Runtime is logarithmic with respect to the total number of clusters in the catalog.
     pub fn update_cluster(&mut self, id: ClusterId, cluster: Cluster) -> Result<(), CatalogError> {
-        let n = self.clusters.update(|k, _v| {
-            if k.id == id {
-                let (_, new_value) = cluster.clone().into_key_value();
-                Some(new_value)
-            } else {
-                None
-            }
-        })?;
-        assert!(n <= 1);
-        if n == 1 {
+        let (_, new_value) = cluster.into_key_value();
+        if self.clusters.update_by_key(ClusterKey { id }, new_value)? {
             Ok(())
         } else {
             Err(SqlCatalogError::UnknownCluster(id.to_string()).into())
@@ -822,84 +1814,184 @@ impl<'a> Transaction<'a> {
     ///
     /// Returns an error if `replica_id` is not found.
     ///
-    /// Runtime is linear with respect to the total number of cluster replicas in the catalog.
-    /// DO NOT call this function in a loop.
+    /// Runtime is logarithmic with respect to the total number of cluster replicas in the
+    /// catalog.
     pub fn update_cluster_replica(
         &mut self,
         replica_id: ReplicaId,
         replica: ClusterReplica,
     ) -> Result<(), CatalogError> {
-        let n = self.cluster_replicas.update(|k, _v| {
-            if k.id == replica_id {
-                let (_, new_value) = replica.clone().into_key_value();
-                Some(new_value)
-            } else {
-                None
-            }
-        })?;
-        assert!(n <= 1);
-        if n == 1 {
+        let (_, new_value) = replica.into_key_value();
+        if self
+            .cluster_replicas
+            .update_by_key(ClusterReplicaKey { id: replica_id }, new_value)?
+        {
             Ok(())
         } else {
             Err(SqlCatalogError::UnknownClusterReplica(replica_id.to_string()).into())
         }
     }
 
+    /// Updates all clusters with ids matching the keys of `clusters` in the transaction, to the
+    /// corresponding value in `clusters`.
+    ///
+    /// Returns an error if any id in `clusters` is not found.
+    ///
+    /// NOTE: On error, there still may be some clusters updated in the transaction. It is
+    /// up to the called to either abort the transaction or commit.
+    pub fn update_clusters(
+        &mut self,
+        clusters: BTreeMap<ClusterId, Cluster>,
+    ) -> Result<(), CatalogError> {
+        let kvs = clusters
+            .iter()
+            .map(|(id, cluster)| {
+                let (_, new_value) = cluster.clone().into_key_value();
+                (ClusterKey { id: *id }, new_value)
+            })
+            .collect();
+        let n = self.clusters.update_by_keys(kvs)?;
+        let n = usize::try_from(n).expect("Must be positive and fit in usize");
+        if n == clusters.len() {
+            Ok(())
+        } else {
+            let update_ids: BTreeSet<_> = clusters.into_keys().collect();
+            let cluster_ids: BTreeSet<_> = self.clusters.items().keys().map(|k| k.id).collect();
+            let mut unknown = update_ids.difference(&cluster_ids);
+            Err(SqlCatalogError::UnknownCluster(unknown.join(", ")).into())
+        }
+    }
+
+    /// Updates all cluster replicas with ids matching the keys of `replicas` in the transaction,
+    /// to the corresponding value in `replicas`.
+    ///
+    /// Returns an error if any id in `replicas` is not found.
+    ///
+    /// NOTE: On error, there still may be some cluster replicas updated in the transaction. It
+    /// is up to the called to either abort the transaction or commit.
+    pub fn update_cluster_replicas(
+        &mut self,
+        replicas: BTreeMap<ReplicaId, ClusterReplica>,
+    ) -> Result<(), CatalogError> {
+        let kvs = replicas
+            .iter()
+            .map(|(id, replica)| {
+                let (_, new_value) = replica.clone().into_key_value();
+                (ClusterReplicaKey { id: *id }, new_value)
+            })
+            .collect();
+        let n = self.cluster_replicas.update_by_keys(kvs)?;
+        let n = usize::try_from(n).expect("Must be positive and fit in usize");
+        if n == replicas.len() {
+            Ok(())
+        } else {
+            let update_ids: BTreeSet<_> = replicas.into_keys().collect();
+            let replica_ids: BTreeSet<_> =
+                self.cluster_replicas.items().keys().map(|k| k.id).collect();
+            let mut unknown = update_ids.difference(&replica_ids);
+            Err(SqlCatalogError::UnknownClusterReplica(unknown.join(", ")).into())
+        }
+    }
+
     /// Updates database `id` in the transaction to `database`.
     ///
     /// Returns an error if `id` is not found.
     ///
-    /// Runtime is linear with respect to the total number of databases in the catalog.
-    /// DO NOT call this function in a loop.
+    /// Runtime is logarithmic with respect to the total number of databases in the catalog.
     pub fn update_database(
         &mut self,
         id: DatabaseId,
         database: Database,
     ) -> Result<(), CatalogError> {
-        let n = self.databases.update(|k, _v| {
-            if id == k.id {
-                let (_, new_value) = database.clone().into_key_value();
-                Some(new_value)
-            } else {
-                None
-            }
-        })?;
-        assert!(n <= 1);
-        if n == 1 {
+        let (_, new_value) = database.into_key_value();
+        if self
+            .databases
+            .update_by_key(DatabaseKey { id }, new_value)?
+        {
             Ok(())
         } else {
             Err(SqlCatalogError::UnknownDatabase(id.to_string()).into())
         }
     }
 
+    /// Renames database `id` to `new_name`.
+    ///
+    /// Returns an error if `id` is not found or if another database is already named `new_name`.
+    pub fn rename_database(&mut self, id: DatabaseId, new_name: &str) -> Result<(), CatalogError> {
+        if let Some(existing_key) = self
+            .databases
+            .items_by_index(&new_name.to_string())
+            .into_keys()
+            .find(|k| k.id != id)
+        {
+            return Err(SqlCatalogError::DatabaseAlreadyExists(
+                new_name.to_string(),
+                self.existing_database(&existing_key),
+            )
+            .into());
+        }
+
+        let key = DatabaseKey { id };
+        let Some(mut value) = self.databases.get(&key).cloned() else {
+            return Err(SqlCatalogError::UnknownDatabase(id.to_string()).into());
+        };
+        value.name = new_name.to_string();
+        self.databases.update_by_key(key, value)?;
+        Ok(())
+    }
+
     /// Updates schema `schema_id` in the transaction to `schema`.
     ///
     /// Returns an error if `schema_id` is not found.
     ///
-    /// Runtime is linear with respect to the total number of schemas in the catalog.
-    /// DO NOT call this function in a loop.
+    /// Runtime is logarithmic with respect to the total number of schemas in the catalog.
     pub fn update_schema(
         &mut self,
         schema_id: SchemaId,
         schema: Schema,
     ) -> Result<(), CatalogError> {
-        let n = self.schemas.update(|k, _v| {
-            if schema_id == k.id {
-                let schema = schema.clone();
-                let (_, new_value) = schema.clone().into_key_value();
-                Some(new_value)
-            } else {
-                None
-            }
-        })?;
-        assert!(n <= 1);
-        if n == 1 {
+        let (_, new_value) = schema.into_key_value();
+        if self
+            .schemas
+            .update_by_key(SchemaKey { id: schema_id }, new_value)?
+        {
             Ok(())
         } else {
             Err(SqlCatalogError::UnknownSchema(schema_id.to_string()).into())
         }
     }
 
+    /// Renames schema `schema_id` to `new_name`.
+    ///
+    /// Returns an error if `schema_id` is not found or if another schema in the same database is
+    /// already named `new_name`.
+    pub fn rename_schema(
+        &mut self,
+        schema_id: SchemaId,
+        new_name: &str,
+    ) -> Result<(), CatalogError> {
+        let key = SchemaKey { id: schema_id };
+        let Some(mut value) = self.schemas.get(&key).cloned() else {
+            return Err(SqlCatalogError::UnknownSchema(schema_id.to_string()).into());
+        };
+        if let Some((existing_key, _)) = self
+            .schemas
+            .items_by_index(&value.database_id)
+            .into_iter()
+            .find(|(k, v)| k.id != schema_id && v.name == new_name)
+        {
+            return Err(SqlCatalogError::SchemaAlreadyExists(
+                new_name.to_string(),
+                self.existing_schema(&existing_key),
+            )
+            .into());
+        }
+
+        value.name = new_name.to_string();
+        self.schemas.update_by_key(key, value)?;
+        Ok(())
+    }
+
     /// Set persisted default privilege.
     ///
     /// DO NOT call this function in a loop, use [`Self::set_default_privileges`] instead.
@@ -939,6 +2031,18 @@ impl<'a> Transaction<'a> {
         Ok(())
     }
 
+    /// Removes all default privileges where `role_id` is either the role that the default
+    /// privileges are defined for or the grantee. Returns the removed default privileges.
+    ///
+    /// Runtime is linear with respect to the total number of default privileges in the catalog.
+    pub fn remove_default_privileges_for_role(&mut self, role_id: RoleId) -> Vec<DefaultPrivilege> {
+        self.default_privileges
+            .delete(|k, _v| k.role_id == role_id || k.grantee == role_id)
+            .into_iter()
+            .map(|(k, v)| DefaultPrivilege::from_key_value(k, v))
+            .collect()
+    }
+
     /// Set persisted system privilege.
     ///
     /// DO NOT call this function in a loop, use [`Self::set_system_privileges`] instead.
@@ -1016,6 +2120,8 @@ impl<'a> Transaction<'a> {
     }
 
     /// Set persisted timestamp.
+    ///
+    /// DO NOT call this function in a loop, use [`Self::set_timestamps`] instead.
     pub fn set_timestamp(
         &mut self,
         timeline: Timeline,
@@ -1027,6 +2133,20 @@ impl<'a> Transaction<'a> {
         Ok(())
     }
 
+    /// Set persisted timestamps.
+    pub fn set_timestamps(
+        &mut self,
+        timestamps: Vec<TimelineTimestamp>,
+    ) -> Result<(), CatalogError> {
+        let timestamps = timestamps
+            .into_iter()
+            .map(DurableType::into_key_value)
+            .map(|(k, v)| (k, Some(v)))
+            .collect();
+        self.timestamps.set_many(timestamps)?;
+        Ok(())
+    }
+
     /// Set persisted replica.
     pub fn set_replicas(&mut self, replicas: Vec<ClusterReplica>) -> Result<(), CatalogError> {
         let replicas = replicas
@@ -1059,6 +2179,41 @@ impl<'a> Transaction<'a> {
         Ok(())
     }
 
+    /// Sets how long dropped items are kept in the trash, see [`Self::drop_item`]. If `retention`
+    /// is `None`, then the trash is disabled and dropped items are removed immediately. Items that
+    /// are already in the trash are kept until they are purged.
+    pub fn set_item_trash_retention(
+        &mut self,
+        retention: Option<Duration>,
+    ) -> Result<(), CatalogError> {
+        match retention {
+            Some(retention) => self.set_config(
+                ITEM_TRASH_RETENTION_KEY.into(),
+                u64::try_from(retention.as_millis()).unwrap_or(u64::MAX),
+            ),
+            None => {
+                self.configs.set(
+                    ConfigKey {
+                        key: ITEM_TRASH_RETENTION_KEY.into(),
+                    },
+                    None,
+                )?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns how long dropped items are kept in the trash, or `None` if the trash is disabled.
+    pub fn get_item_trash_retention(&self) -> Option<Duration> {
+        self.configs
+            .get(&ConfigKey {
+                key: ITEM_TRASH_RETENTION_KEY.into(),
+            })
+            .map(|value| value.value)
+            .filter(|millis| *millis > 0)
+            .map(Duration::from_millis)
+    }
+
     /// Updates the catalog `system_config_synced` "config" value to true.
     pub fn set_system_config_synced_once(&mut self) -> Result<(), CatalogError> {
         self.set_config(SYSTEM_CONFIG_SYNCED_KEY.into(), 1)
@@ -1084,7 +2239,16 @@ impl<'a> Transaction<'a> {
         &mut self,
         object_id: CommentObjectId,
     ) -> Result<Vec<(CommentObjectId, Option<usize>, String)>, CatalogError> {
-        let deleted = self.comments.delete(|k, _v| k.object_id == object_id);
+        // Comments on cluster replicas are indexed by their cluster, and dropped along with the
+        // cluster's comments.
+        let keys = self
+            .comments
+            .items_by_index(&comment_index_key(object_id))
+            .into_keys()
+            .filter(|k| {
+                matches!(object_id, CommentObjectId::Cluster(_)) || k.object_id == object_id
+            });
+        let deleted = self.comments.delete_by_keys(keys);
         let deleted = deleted
             .into_iter()
             .map(|(k, v)| (k.object_id, k.sub_component, v.comment))
@@ -1092,8 +2256,48 @@ impl<'a> Transaction<'a> {
         Ok(deleted)
     }
 
+    /// Sets the tag `name` of `object_id` to `value`, or removes the tag if `value` is `None`.
+    /// Returns the previous value of the tag, if any.
+    pub fn set_tag(
+        &mut self,
+        object_id: CommentObjectId,
+        name: String,
+        value: Option<String>,
+    ) -> Result<Option<String>, CatalogError> {
+        let key = TagKey { object_id, name };
+        let value = value.map(|value| TagValue { value });
+        let prev = self.tags.set(key, value)?;
+        Ok(prev.map(|prev| prev.value))
+    }
+
+    /// Removes all tags of `object_id`, and returns the removed tags.
+    ///
+    /// Like comments, the tags of cluster replicas are also removed along with their cluster's.
+    pub fn drop_tags(&mut self, object_id: CommentObjectId) -> Result<Vec<Tag>, CatalogError> {
+        let keys = self
+            .tags
+            .items_by_index(&comment_index_key(object_id))
+            .into_keys()
+            .filter(|k| {
+                matches!(object_id, CommentObjectId::Cluster(_)) || k.object_id == object_id
+            });
+        let deleted = self.tags.delete_by_keys(keys);
+        let deleted = deleted
+            .into_iter()
+            .map(|(k, v)| DurableType::from_key_value(k, v))
+            .collect();
+        Ok(deleted)
+    }
+
     /// Upserts persisted system configuration `name` to `value`.
+    ///
+    /// Returns an error if `name` is not a known system configuration
+    /// parameter, or if `value` is not a valid value for it. Such a value would
+    /// otherwise be durably written and then fail to load at boot.
     pub fn upsert_system_config(&mut self, name: &str, value: String) -> Result<(), CatalogError> {
+        DEFAULT_SYSTEM_VARS
+            .check(name, VarInput::Flat(&value))
+            .map_err(DurableCatalogError::InvalidSystemConfiguration)?;
         let key = ServerConfigurationKey {
             name: name.to_string(),
         };
@@ -1191,6 +2395,76 @@ impl<'a> Transaction<'a> {
             .map(|(k, v)| DurableType::from_key_value(k, v))
     }
 
+    pub fn get_dependencies(&self) -> impl Iterator<Item = Dependency> {
+        self.dependencies
+            .items()
+            .clone()
+            .into_iter()
+            .map(|(k, v)| DurableType::from_key_value(k, v))
+    }
+
+    /// Returns the ids of the items that item `id` depends on.
+    pub fn get_item_dependencies(&self, id: GlobalId) -> BTreeSet<GlobalId> {
+        self.dependencies
+            .items_by_index(&id)
+            .into_keys()
+            .map(|k| k.dependency_id)
+            .collect()
+    }
+
+    /// Returns the ids of the items that depend on item `id`.
+    ///
+    /// Runtime is linear with respect to the total number of dependencies in the catalog.
+    pub fn get_item_dependents(&self, id: GlobalId) -> BTreeSet<GlobalId> {
+        let mut dependents = BTreeSet::new();
+        self.dependencies.for_values(|k, _v| {
+            if k.dependency_id == id {
+                dependents.insert(k.object_id);
+            }
+        });
+        dependents
+    }
+
+    pub fn get_tags(&self) -> impl Iterator<Item = Tag> {
+        self.tags
+            .items()
+            .clone()
+            .into_iter()
+            .map(|(k, v)| DurableType::from_key_value(k, v))
+    }
+
+    pub fn get_column_masks(&self) -> impl Iterator<Item = ColumnMask> {
+        self.column_masks
+            .items()
+            .clone()
+            .into_iter()
+            .map(|(k, v)| DurableType::from_key_value(k, v))
+    }
+
+    pub fn get_service_accounts(&self) -> impl Iterator<Item = ServiceAccount> {
+        self.service_accounts
+            .items()
+            .clone()
+            .into_iter()
+            .map(|(k, v)| DurableType::from_key_value(k, v))
+    }
+
+    pub fn get_cluster_replica_sizes(&self) -> impl Iterator<Item = ClusterReplicaSize> {
+        self.cluster_replica_sizes
+            .items()
+            .clone()
+            .into_iter()
+            .map(|(k, v)| DurableType::from_key_value(k, v))
+    }
+
+    pub fn get_trashed_items(&self) -> impl Iterator<Item = TrashedItem> {
+        self.trashed_items
+            .items()
+            .clone()
+            .into_iter()
+            .map(|(k, v)| DurableType::from_key_value(k, v))
+    }
+
     pub fn get_system_configurations(&self) -> impl Iterator<Item = SystemConfiguration> {
         self.system_configurations
             .items()
@@ -1220,9 +2494,8 @@ impl<'a> Transaction<'a> {
         cluster_id: ClusterId,
     ) -> BTreeMap<String, GlobalId> {
         self.introspection_sources
-            .items()
+            .items_by_index(&cluster_id)
             .into_iter()
-            .filter(|(k, _v)| k.cluster_id == cluster_id)
             .map(|(k, v)| (k.name, GlobalId::System(v.index_id)))
             .collect()
     }
@@ -1239,7 +2512,293 @@ impl<'a> Transaction<'a> {
         self.connection_timeout = Some(timeout);
     }
 
-    pub(crate) fn into_parts(self) -> (TransactionBatch, &'a mut dyn DurableCatalogState) {
+    /// Requires the durable catalog to still be at `epoch` when the transaction is committed.
+    ///
+    /// If the durable catalog's epoch is different at commit time, for example because the caller
+    /// has been deposed by another writer, then committing fails with
+    /// [`DurableCatalogError::Fence`] without writing anything.
+    pub fn set_expected_epoch(&mut self, epoch: Epoch) {
+        self.expected_epoch = Some(epoch);
+    }
+
+    /// Returns a snapshot of all pending changes in the transaction, which can later be restored
+    /// with [`Self::rollback_to_savepoint`].
+    pub fn savepoint(&self) -> TransactionSavepoint {
+        TransactionSavepoint {
+            databases: self.databases.savepoint(),
+            schemas: self.schemas.savepoint(),
+            items: self.items.savepoint(),
+            comments: self.comments.savepoint(),
+            roles: self.roles.savepoint(),
+            clusters: self.clusters.savepoint(),
+            cluster_replicas: self.cluster_replicas.savepoint(),
+            introspection_sources: self.introspection_sources.savepoint(),
+            id_allocator: self.id_allocator.savepoint(),
+            configs: self.configs.savepoint(),
+            settings: self.settings.savepoint(),
+            timestamps: self.timestamps.savepoint(),
+            system_gid_mapping: self.system_gid_mapping.savepoint(),
+            system_configurations: self.system_configurations.savepoint(),
+            default_privileges: self.default_privileges.savepoint(),
+            system_privileges: self.system_privileges.savepoint(),
+            tags: self.tags.savepoint(),
+            dependencies: self.dependencies.savepoint(),
+            trashed_items: self.trashed_items.savepoint(),
+            column_masks: self.column_masks.savepoint(),
+            service_accounts: self.service_accounts.savepoint(),
+            cluster_replica_sizes: self.cluster_replica_sizes.savepoint(),
+            audit_log_updates: self.audit_log_updates.len(),
+            storage_usage_updates: self.storage_usage_updates.len(),
+            connection_timeout: self.connection_timeout,
+        }
+    }
+
+    /// Discards all changes made to the transaction since `savepoint` was taken.
+    ///
+    /// Savepoints taken after `savepoint` must not be rolled back to afterwards.
+    pub fn rollback_to_savepoint(&mut self, savepoint: TransactionSavepoint) {
+        let TransactionSavepoint {
+            databases,
+            schemas,
+            items,
+            comments,
+            roles,
+            clusters,
+            cluster_replicas,
+            introspection_sources,
+            id_allocator,
+            configs,
+            settings,
+            timestamps,
+            system_gid_mapping,
+            system_configurations,
+            default_privileges,
+            system_privileges,
+            tags,
+            dependencies,
+            trashed_items,
+            column_masks,
+            service_accounts,
+            cluster_replica_sizes,
+            audit_log_updates,
+            storage_usage_updates,
+            connection_timeout,
+        } = savepoint;
+        self.databases.rollback_to_savepoint(databases);
+        self.schemas.rollback_to_savepoint(schemas);
+        self.items.rollback_to_savepoint(items);
+        self.comments.rollback_to_savepoint(comments);
+        self.roles.rollback_to_savepoint(roles);
+        self.clusters.rollback_to_savepoint(clusters);
+        self.cluster_replicas
+            .rollback_to_savepoint(cluster_replicas);
+        self.introspection_sources
+            .rollback_to_savepoint(introspection_sources);
+        self.id_allocator.rollback_to_savepoint(id_allocator);
+        self.configs.rollback_to_savepoint(configs);
+        self.settings.rollback_to_savepoint(settings);
+        self.timestamps.rollback_to_savepoint(timestamps);
+        self.system_gid_mapping
+            .rollback_to_savepoint(system_gid_mapping);
+        self.system_configurations
+            .rollback_to_savepoint(system_configurations);
+        self.default_privileges
+            .rollback_to_savepoint(default_privileges);
+        self.system_privileges
+            .rollback_to_savepoint(system_privileges);
+        self.tags.rollback_to_savepoint(tags);
+        self.dependencies.rollback_to_savepoint(dependencies);
+        self.trashed_items.rollback_to_savepoint(trashed_items);
+        self.column_masks.rollback_to_savepoint(column_masks);
+        self.service_accounts
+            .rollback_to_savepoint(service_accounts);
+        self.cluster_replica_sizes
+            .rollback_to_savepoint(cluster_replica_sizes);
+        self.audit_log_updates.truncate(audit_log_updates);
+        self.storage_usage_updates.truncate(storage_usage_updates);
+        self.connection_timeout = connection_timeout;
+    }
+
+    /// Returns the changes that committing the transaction would apply, without committing it.
+    ///
+    /// Returns an error if the pending changes violate any uniqueness constraint.
+    pub fn validate(&self) -> Result<TransactionBatch, CatalogError> {
+        self.databases.verify()?;
+        self.schemas.verify()?;
+        self.items.verify()?;
+        self.comments.verify()?;
+        self.roles.verify()?;
+        self.clusters.verify()?;
+        self.cluster_replicas.verify()?;
+        self.introspection_sources.verify()?;
+        self.id_allocator.verify()?;
+        self.configs.verify()?;
+        self.settings.verify()?;
+        self.timestamps.verify()?;
+        self.system_gid_mapping.verify()?;
+        self.system_configurations.verify()?;
+        self.default_privileges.verify()?;
+        self.system_privileges.verify()?;
+        self.tags.verify()?;
+        self.dependencies.verify()?;
+        self.trashed_items.verify()?;
+        self.column_masks.verify()?;
+        self.service_accounts.verify()?;
+        self.cluster_replica_sizes.verify()?;
+        Ok(TransactionBatch {
+            databases: self.databases.peek_pending(),
+            schemas: self.schemas.peek_pending(),
+            items: self.items.peek_pending(),
+            comments: self.comments.peek_pending(),
+            roles: self.roles.peek_pending(),
+            clusters: self.clusters.peek_pending(),
+            cluster_replicas: self.cluster_replicas.peek_pending(),
+            introspection_sources: self.introspection_sources.peek_pending(),
+            id_allocator: self.id_allocator.peek_pending(),
+            configs: self.configs.peek_pending(),
+            settings: self.settings.peek_pending(),
+            timestamps: self.timestamps.peek_pending(),
+            system_gid_mapping: self.system_gid_mapping.peek_pending(),
+            system_configurations: self.system_configurations.peek_pending(),
+            default_privileges: self.default_privileges.peek_pending(),
+            system_privileges: self.system_privileges.peek_pending(),
+            tags: self.tags.peek_pending(),
+            dependencies: self.dependencies.peek_pending(),
+            trashed_items: self.trashed_items.peek_pending(),
+            column_masks: self.column_masks.peek_pending(),
+            service_accounts: self.service_accounts.peek_pending(),
+            cluster_replica_sizes: self.cluster_replica_sizes.peek_pending(),
+            audit_log_updates: self.audit_log_updates.clone(),
+            storage_usage_updates: self.storage_usage_updates.clone(),
+            connection_timeout: self.connection_timeout,
+            expected_epoch: self.expected_epoch,
+        })
+    }
+
+    /// Returns the number of pending changes to each collection, keyed by collection name.
+    ///
+    /// Changes to tables are counted per key, so a key that was updated counts once.
+    pub fn pending_updates(&self) -> BTreeMap<&'static str, usize> {
+        BTreeMap::from([
+            ("databases", self.databases.pending_len()),
+            ("schemas", self.schemas.pending_len()),
+            ("items", self.items.pending_len()),
+            ("comments", self.comments.pending_len()),
+            ("roles", self.roles.pending_len()),
+            ("clusters", self.clusters.pending_len()),
+            ("cluster_replicas", self.cluster_replicas.pending_len()),
+            (
+                "introspection_sources",
+                self.introspection_sources.pending_len(),
+            ),
+            ("id_allocator", self.id_allocator.pending_len()),
+            ("configs", self.configs.pending_len()),
+            ("settings", self.settings.pending_len()),
+            ("timestamps", self.timestamps.pending_len()),
+            ("system_gid_mapping", self.system_gid_mapping.pending_len()),
+            (
+                "system_configurations",
+                self.system_configurations.pending_len(),
+            ),
+            ("default_privileges", self.default_privileges.pending_len()),
+            ("system_privileges", self.system_privileges.pending_len()),
+            ("tags", self.tags.pending_len()),
+            ("dependencies", self.dependencies.pending_len()),
+            ("trashed_items", self.trashed_items.pending_len()),
+            ("column_masks", self.column_masks.pending_len()),
+            ("service_accounts", self.service_accounts.pending_len()),
+            (
+                "cluster_replica_sizes",
+                self.cluster_replica_sizes.pending_len(),
+            ),
+            ("audit_log", self.audit_log_updates.len()),
+            ("storage_usage", self.storage_usage_updates.len()),
+        ])
+    }
+
+    /// Defers checking the uniqueness constraints of subsequent changes until the transaction is
+    /// committed. This allows the constraints to be temporarily violated within the transaction,
+    /// e.g. to atomically swap the names of two items.
+    pub fn defer_uniqueness_checks(&mut self) {
+        self.databases.defer_verification();
+        self.schemas.defer_verification();
+        self.items.defer_verification();
+        self.comments.defer_verification();
+        self.roles.defer_verification();
+        self.clusters.defer_verification();
+        self.cluster_replicas.defer_verification();
+        self.introspection_sources.defer_verification();
+        self.id_allocator.defer_verification();
+        self.configs.defer_verification();
+        self.settings.defer_verification();
+        self.timestamps.defer_verification();
+        self.system_gid_mapping.defer_verification();
+        self.system_configurations.defer_verification();
+        self.default_privileges.defer_verification();
+        self.system_privileges.defer_verification();
+        self.tags.defer_verification();
+        self.dependencies.defer_verification();
+        self.trashed_items.defer_verification();
+        self.column_masks.defer_verification();
+        self.service_accounts.defer_verification();
+        self.cluster_replica_sizes.defer_verification();
+    }
+
+    /// Returns an error if the pending changes violate any uniqueness constraint whose check was
+    /// deferred by [`Self::defer_uniqueness_checks`].
+    fn verify_deferred(&mut self) -> Result<(), CatalogError> {
+        fn verify<K, V, I>(
+            table: &mut TableTransaction<K, V, I>,
+            collection: &'static str,
+        ) -> Result<(), CatalogError>
+        where
+            K: Ord + Eq + Clone + Debug,
+            V: Ord + Clone,
+            I: Ord + Clone,
+        {
+            table.verify_deferred().map_err(|err| match err {
+                TableError::UniquenessViolation {
+                    field,
+                    existing_key,
+                } => DurableCatalogError::UniquenessViolation {
+                    collection,
+                    field,
+                    existing_key: format!("{existing_key:?}"),
+                }
+                .into(),
+                err @ TableError::DuplicateKey(_) => err.into(),
+            })
+        }
+
+        verify(&mut self.databases, "databases")?;
+        verify(&mut self.schemas, "schemas")?;
+        verify(&mut self.items, "items")?;
+        verify(&mut self.comments, "comments")?;
+        verify(&mut self.roles, "roles")?;
+        verify(&mut self.clusters, "clusters")?;
+        verify(&mut self.cluster_replicas, "cluster_replicas")?;
+        verify(&mut self.introspection_sources, "introspection_sources")?;
+        verify(&mut self.id_allocator, "id_allocator")?;
+        verify(&mut self.configs, "configs")?;
+        verify(&mut self.settings, "settings")?;
+        verify(&mut self.timestamps, "timestamps")?;
+        verify(&mut self.system_gid_mapping, "system_gid_mapping")?;
+        verify(&mut self.system_configurations, "system_configurations")?;
+        verify(&mut self.default_privileges, "default_privileges")?;
+        verify(&mut self.system_privileges, "system_privileges")?;
+        verify(&mut self.tags, "tags")?;
+        verify(&mut self.dependencies, "dependencies")?;
+        verify(&mut self.trashed_items, "trashed_items")?;
+        verify(&mut self.column_masks, "column_masks")?;
+        verify(&mut self.service_accounts, "service_accounts")?;
+        verify(&mut self.cluster_replica_sizes, "cluster_replica_sizes")?;
+        Ok(())
+    }
+
+    pub(crate) fn into_parts(
+        mut self,
+    ) -> Result<(TransactionBatch, &'a mut dyn DurableCatalogState), CatalogError> {
+        self.verify_deferred()?;
         let txn_batch = TransactionBatch {
             databases: self.databases.pending(),
             schemas: self.schemas.pending(),
@@ -1257,11 +2816,18 @@ impl<'a> Transaction<'a> {
             system_configurations: self.system_configurations.pending(),
             default_privileges: self.default_privileges.pending(),
             system_privileges: self.system_privileges.pending(),
+            tags: self.tags.pending(),
+            dependencies: self.dependencies.pending(),
+            trashed_items: self.trashed_items.pending(),
+            column_masks: self.column_masks.pending(),
+            service_accounts: self.service_accounts.pending(),
+            cluster_replica_sizes: self.cluster_replica_sizes.pending(),
             audit_log_updates: self.audit_log_updates,
             storage_usage_updates: self.storage_usage_updates,
             connection_timeout: self.connection_timeout,
+            expected_epoch: self.expected_epoch,
         };
-        (txn_batch, self.durable_catalog)
+        Ok((txn_batch, self.durable_catalog))
     }
 
     /// Commits the storage transaction to durable storage. Any error returned indicates the catalog may be
@@ -1270,11 +2836,102 @@ impl<'a> Transaction<'a> {
     /// that errors can bubble up during initialization.
     #[tracing::instrument(level = "debug", skip_all)]
     pub async fn commit(self) -> Result<(), CatalogError> {
-        let (txn_batch, durable_catalog) = self.into_parts();
+        let (txn_batch, durable_catalog) = self.into_parts()?;
         durable_catalog.commit_transaction(txn_batch).await
     }
 }
 
+/// Returns the key that comments on `object_id` are indexed by, which is the object itself unless
+/// its comments are dropped along with another object's.
+fn comment_index_key(object_id: CommentObjectId) -> CommentObjectId {
+    match object_id {
+        CommentObjectId::ClusterReplica((cluster_id, _)) => CommentObjectId::Cluster(cluster_id),
+        object_id => object_id,
+    }
+}
+
+/// Returns the name of the lease of IDs of the `key` allocator held by `region`, which is also the
+/// name of the allocator that the IDs of the lease are allocated by.
+fn id_range_lease_name(key: &str, region: &str) -> String {
+    format!("{key}.{region}")
+}
+
+/// The objects that [`Transaction::remove_clusters`] removed along with their clusters.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RemovedClusterObjects {
+    /// The replicas of the removed clusters.
+    pub replicas: Vec<ClusterReplica>,
+    /// The introspection source indexes of the removed clusters.
+    pub introspection_source_indexes: Vec<IntrospectionSourceIndex>,
+}
+
+/// Describes what [`Transaction::vacuum`] removes from the durable catalog.
+#[derive(Debug, Clone, Default)]
+pub struct VacuumOptions {
+    /// Audit log events that occurred before this time are removed.
+    pub audit_log_before_ts: EpochMillis,
+    /// All but the newest `audit_log_max_events` audit log events are removed, if set.
+    pub audit_log_max_events: Option<usize>,
+    /// Storage usage events collected before this time are rolled up into daily aggregates, if
+    /// set.
+    pub storage_usage_rollup_before_ts: Option<EpochMillis>,
+    /// The item trash is purged as of this time, if set, see [`Transaction::purge_item_trash`].
+    pub item_trash_purge_ts: Option<EpochMillis>,
+    /// The names of the settings to remove.
+    pub obsolete_settings: BTreeSet<String>,
+}
+
+/// The changes made by [`Transaction::vacuum`].
+#[derive(Debug, Clone, Default)]
+pub struct VacuumReport {
+    /// The removed audit log events.
+    pub pruned_audit_log_events: Vec<VersionedEvent>,
+    /// The storage usage events that were replaced by daily aggregates.
+    pub removed_storage_usage_events: Vec<VersionedStorageUsage>,
+    /// The daily aggregates that replaced `removed_storage_usage_events`.
+    pub storage_usage_rollups: Vec<VersionedStorageUsage>,
+    /// The items that were purged from the trash.
+    pub purged_trashed_items: Vec<TrashedItem>,
+    /// The names of the removed settings.
+    pub removed_settings: Vec<String>,
+    /// The size of the removed updates when serialized as protobuf, net of the size of the added
+    /// updates, in bytes.
+    pub reclaimed_bytes: usize,
+}
+
+/// A snapshot of the pending changes of a [`Transaction`], taken with [`Transaction::savepoint`].
+#[derive(Debug, Clone)]
+pub struct TransactionSavepoint {
+    databases: TableSavepoint<DatabaseKey, DatabaseValue>,
+    schemas: TableSavepoint<SchemaKey, SchemaValue>,
+    items: TableSavepoint<ItemKey, ItemValue>,
+    comments: TableSavepoint<CommentKey, CommentValue>,
+    roles: TableSavepoint<RoleKey, RoleValue>,
+    clusters: TableSavepoint<ClusterKey, ClusterValue>,
+    cluster_replicas: TableSavepoint<ClusterReplicaKey, ClusterReplicaValue>,
+    introspection_sources:
+        TableSavepoint<ClusterIntrospectionSourceIndexKey, ClusterIntrospectionSourceIndexValue>,
+    id_allocator: TableSavepoint<IdAllocKey, IdAllocValue>,
+    configs: TableSavepoint<ConfigKey, ConfigValue>,
+    settings: TableSavepoint<SettingKey, SettingValue>,
+    timestamps: TableSavepoint<TimestampKey, TimestampValue>,
+    system_gid_mapping: TableSavepoint<GidMappingKey, GidMappingValue>,
+    system_configurations: TableSavepoint<ServerConfigurationKey, ServerConfigurationValue>,
+    default_privileges: TableSavepoint<DefaultPrivilegesKey, DefaultPrivilegesValue>,
+    system_privileges: TableSavepoint<SystemPrivilegesKey, SystemPrivilegesValue>,
+    tags: TableSavepoint<TagKey, TagValue>,
+    dependencies: TableSavepoint<DependencyKey, DependencyValue>,
+    trashed_items: TableSavepoint<TrashedItemKey, TrashedItemValue>,
+    column_masks: TableSavepoint<ColumnMaskKey, ColumnMaskValue>,
+    service_accounts: TableSavepoint<ServiceAccountKey, ServiceAccountValue>,
+    cluster_replica_sizes: TableSavepoint<ClusterReplicaSizeKey, ClusterReplicaSizeValue>,
+    // The audit log and storage usage updates are append-only, so only their lengths need to be
+    // remembered.
+    audit_log_updates: usize,
+    storage_usage_updates: usize,
+    connection_timeout: Option<Duration>,
+}
+
 /// Describes a set of changes to apply as the result of a catalog transaction.
 #[derive(Debug, Clone)]
 pub struct TransactionBatch {
@@ -1310,9 +2967,20 @@ pub struct TransactionBatch {
         proto::SystemPrivilegesValue,
         Diff,
     )>,
+    pub(crate) tags: Vec<(proto::TagKey, proto::TagValue, Diff)>,
+    pub(crate) dependencies: Vec<(proto::DependencyKey, proto::DependencyValue, Diff)>,
+    pub(crate) trashed_items: Vec<(proto::TrashedItemKey, proto::TrashedItemValue, Diff)>,
+    pub(crate) column_masks: Vec<(proto::ColumnMaskKey, proto::ColumnMaskValue, Diff)>,
+    pub(crate) service_accounts: Vec<(proto::ServiceAccountKey, proto::ServiceAccountValue, Diff)>,
+    pub(crate) cluster_replica_sizes: Vec<(
+        proto::ClusterReplicaSizeKey,
+        proto::ClusterReplicaSizeValue,
+        Diff,
+    )>,
     pub(crate) audit_log_updates: Vec<(proto::AuditLogKey, (), Diff)>,
     pub(crate) storage_usage_updates: Vec<(proto::StorageUsageKey, (), Diff)>,
     pub(crate) connection_timeout: Option<Duration>,
+    pub(crate) expected_epoch: Option<Epoch>,
 }
 
 impl TransactionBatch {
@@ -1334,10 +3002,17 @@ impl TransactionBatch {
             system_configurations,
             default_privileges,
             system_privileges,
+            tags,
+            dependencies,
+            trashed_items,
+            column_masks,
+            service_accounts,
+            cluster_replica_sizes,
             audit_log_updates,
             storage_usage_updates,
-            // This doesn't get written down anywhere.
+            // These don't get written down anywhere.
             connection_timeout: _,
+            expected_epoch: _,
         } = self;
 
         databases.is_empty()
@@ -1356,7 +3031,157 @@ impl TransactionBatch {
             && system_configurations.is_empty()
             && default_privileges.is_empty()
             && system_privileges.is_empty()
+            && tags.is_empty()
+            && dependencies.is_empty()
+            && trashed_items.is_empty()
+            && column_masks.is_empty()
+            && service_accounts.is_empty()
+            && cluster_replica_sizes.is_empty()
             && audit_log_updates.is_empty()
             && storage_usage_updates.is_empty()
     }
+
+    /// Returns the number of updates to each collection, keyed by collection name.
+    pub fn update_counts(&self) -> BTreeMap<&'static str, usize> {
+        let TransactionBatch {
+            databases,
+            schemas,
+            items,
+            comments,
+            roles,
+            clusters,
+            cluster_replicas,
+            introspection_sources,
+            id_allocator,
+            configs,
+            settings,
+            timestamps,
+            system_gid_mapping,
+            system_configurations,
+            default_privileges,
+            system_privileges,
+            tags,
+            dependencies,
+            trashed_items,
+            column_masks,
+            service_accounts,
+            cluster_replica_sizes,
+            audit_log_updates,
+            storage_usage_updates,
+            connection_timeout: _,
+            expected_epoch: _,
+        } = self;
+
+        BTreeMap::from([
+            ("databases", databases.len()),
+            ("schemas", schemas.len()),
+            ("items", items.len()),
+            ("comments", comments.len()),
+            ("roles", roles.len()),
+            ("clusters", clusters.len()),
+            ("cluster_replicas", cluster_replicas.len()),
+            ("introspection_sources", introspection_sources.len()),
+            ("id_allocator", id_allocator.len()),
+            ("configs", configs.len()),
+            ("settings", settings.len()),
+            ("timestamps", timestamps.len()),
+            ("system_gid_mapping", system_gid_mapping.len()),
+            ("system_configurations", system_configurations.len()),
+            ("default_privileges", default_privileges.len()),
+            ("system_privileges", system_privileges.len()),
+            ("tags", tags.len()),
+            ("dependencies", dependencies.len()),
+            ("trashed_items", trashed_items.len()),
+            ("column_masks", column_masks.len()),
+            ("service_accounts", service_accounts.len()),
+            ("cluster_replica_sizes", cluster_replica_sizes.len()),
+            ("audit_log", audit_log_updates.len()),
+            ("storage_usage", storage_usage_updates.len()),
+        ])
+    }
+
+    /// Returns the total size of the updates when serialized as protobuf, in bytes.
+    pub fn encoded_len(&self) -> usize {
+        fn encoded_len<K: Message, V: Message>(updates: &[(K, V, Diff)]) -> usize {
+            updates
+                .iter()
+                .map(|(k, v, _diff)| k.encoded_len() + v.encoded_len())
+                .sum()
+        }
+
+        let TransactionBatch {
+            databases,
+            schemas,
+            items,
+            comments,
+            roles,
+            clusters,
+            cluster_replicas,
+            introspection_sources,
+            id_allocator,
+            configs,
+            settings,
+            timestamps,
+            system_gid_mapping,
+            system_configurations,
+            default_privileges,
+            system_privileges,
+            tags,
+            dependencies,
+            trashed_items,
+            column_masks,
+            service_accounts,
+            cluster_replica_sizes,
+            audit_log_updates,
+            storage_usage_updates,
+            connection_timeout: _,
+            expected_epoch: _,
+        } = self;
+
+        encoded_len(databases)
+            + encoded_len(schemas)
+            + encoded_len(items)
+            + encoded_len(comments)
+            + encoded_len(roles)
+            + encoded_len(clusters)
+            + encoded_len(cluster_replicas)
+            + encoded_len(introspection_sources)
+            + encoded_len(id_allocator)
+            + encoded_len(configs)
+            + encoded_len(settings)
+            + encoded_len(timestamps)
+            + encoded_len(system_gid_mapping)
+            + encoded_len(system_configurations)
+            + encoded_len(default_privileges)
+            + encoded_len(system_privileges)
+            + encoded_len(tags)
+            + encoded_len(dependencies)
+            + encoded_len(trashed_items)
+            + encoded_len(column_masks)
+            + encoded_len(service_accounts)
+            + encoded_len(cluster_replica_sizes)
+            + audit_log_updates
+                .iter()
+                .map(|(k, (), _diff)| k.encoded_len())
+                .sum::<usize>()
+            + storage_usage_updates
+                .iter()
+                .map(|(k, (), _diff)| k.encoded_len())
+                .sum::<usize>()
+    }
+
+    /// Returns an error if the transaction expects a durable catalog epoch other than `epoch`.
+    ///
+    /// `epoch` must be the epoch that the durable catalog has at the time the batch is committed,
+    /// not the epoch that the committing catalog was opened with.
+    pub(crate) fn check_epoch(&self, epoch: Epoch) -> Result<(), DurableCatalogError> {
+        match self.expected_epoch {
+            Some(expected_epoch) if expected_epoch != epoch => {
+                Err(DurableCatalogError::Fence(format!(
+                    "current catalog epoch {epoch} does not match expected epoch {expected_epoch}"
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
 }
@@ -71,14 +71,17 @@ macro_rules! objects {
         }
     }
 
-objects!(v42, v43, v44, v45);
+objects!(
+    v42, v43, v44, v45, v46, v47, v48, v49, v50, v51, v52, v53, v54, v55, v56, v57, v58, v59, v60,
+    v61
+);
 
 /// The current version of the `Catalog`.
 ///
 /// We will initialize new `Catalog`es with this version, and migrate existing `Catalog`es to this
 /// version. Whenever the `Catalog` changes, e.g. the protobufs we serialize in the `Catalog`
 /// change, we need to bump this version.
-pub(crate) const CATALOG_VERSION: u64 = 45;
+pub(crate) const CATALOG_VERSION: u64 = 61;
 
 /// The minimum `Catalog` version number that we support migrating from.
 ///
@@ -105,6 +108,22 @@ pub(crate) mod stash {
     mod v42_to_v43;
     mod v43_to_v44;
     mod v44_to_v45;
+    mod v45_to_v46;
+    mod v46_to_v47;
+    mod v47_to_v48;
+    mod v48_to_v49;
+    mod v49_to_v50;
+    mod v50_to_v51;
+    mod v51_to_v52;
+    mod v52_to_v53;
+    mod v53_to_v54;
+    mod v54_to_v55;
+    mod v55_to_v56;
+    mod v56_to_v57;
+    mod v57_to_v58;
+    mod v58_to_v59;
+    mod v59_to_v60;
+    mod v60_to_v61;
 
     #[tracing::instrument(name = "stash::upgrade", level = "debug", skip_all)]
     pub(crate) async fn upgrade(stash: &mut Stash) -> Result<(), StashError> {
@@ -131,6 +150,22 @@ pub(crate) mod stash {
                             42 => v42_to_v43::upgrade(),
                             43 => v43_to_v44::upgrade(),
                             44 => v44_to_v45::upgrade(&tx).await?,
+                            45 => v45_to_v46::upgrade(),
+                            46 => v46_to_v47::upgrade(&tx).await?,
+                            47 => v47_to_v48::upgrade(&tx).await?,
+                            48 => v48_to_v49::upgrade(&tx).await?,
+                            49 => v49_to_v50::upgrade(),
+                            50 => v50_to_v51::upgrade(&tx).await?,
+                            51 => v51_to_v52::upgrade(),
+                            52 => v52_to_v53::upgrade(),
+                            53 => v53_to_v54::upgrade(),
+                            54 => v54_to_v55::upgrade(&tx).await?,
+                            55 => v55_to_v56::upgrade(),
+                            56 => v56_to_v57::upgrade(),
+                            57 => v57_to_v58::upgrade(&tx).await?,
+                            58 => v58_to_v59::upgrade(),
+                            59 => v59_to_v60::upgrade(),
+                            60 => v60_to_v61::upgrade(),
 
                             // Up-to-date, no migration needed!
                             CATALOG_VERSION => return Ok(CATALOG_VERSION),
@@ -206,6 +241,22 @@ pub(crate) mod persist {
     mod v42_to_v43;
     mod v43_to_v44;
     mod v44_to_v45;
+    mod v45_to_v46;
+    mod v46_to_v47;
+    mod v47_to_v48;
+    mod v48_to_v49;
+    mod v49_to_v50;
+    mod v50_to_v51;
+    mod v51_to_v52;
+    mod v52_to_v53;
+    mod v53_to_v54;
+    mod v54_to_v55;
+    mod v55_to_v56;
+    mod v56_to_v57;
+    mod v57_to_v58;
+    mod v58_to_v59;
+    mod v59_to_v60;
+    mod v60_to_v61;
 
     /// Describes a single action to take during a migration from `V1` to `V2`.
     #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -327,6 +378,150 @@ pub(crate) mod persist {
                     )
                     .await
                 }
+                45 => {
+                    run_versioned_upgrade(
+                        unopened_catalog_state,
+                        upper,
+                        version,
+                        v45_to_v46::upgrade,
+                    )
+                    .await
+                }
+                46 => {
+                    run_versioned_upgrade(
+                        unopened_catalog_state,
+                        upper,
+                        version,
+                        v46_to_v47::upgrade,
+                    )
+                    .await
+                }
+                47 => {
+                    run_versioned_upgrade(
+                        unopened_catalog_state,
+                        upper,
+                        version,
+                        v47_to_v48::upgrade,
+                    )
+                    .await
+                }
+                48 => {
+                    run_versioned_upgrade(
+                        unopened_catalog_state,
+                        upper,
+                        version,
+                        v48_to_v49::upgrade,
+                    )
+                    .await
+                }
+                49 => {
+                    run_versioned_upgrade(
+                        unopened_catalog_state,
+                        upper,
+                        version,
+                        v49_to_v50::upgrade,
+                    )
+                    .await
+                }
+                50 => {
+                    run_versioned_upgrade(
+                        unopened_catalog_state,
+                        upper,
+                        version,
+                        v50_to_v51::upgrade,
+                    )
+                    .await
+                }
+                51 => {
+                    run_versioned_upgrade(
+                        unopened_catalog_state,
+                        upper,
+                        version,
+                        v51_to_v52::upgrade,
+                    )
+                    .await
+                }
+                52 => {
+                    run_versioned_upgrade(
+                        unopened_catalog_state,
+                        upper,
+                        version,
+                        v52_to_v53::upgrade,
+                    )
+                    .await
+                }
+                53 => {
+                    run_versioned_upgrade(
+                        unopened_catalog_state,
+                        upper,
+                        version,
+                        v53_to_v54::upgrade,
+                    )
+                    .await
+                }
+                54 => {
+                    run_versioned_upgrade(
+                        unopened_catalog_state,
+                        upper,
+                        version,
+                        v54_to_v55::upgrade,
+                    )
+                    .await
+                }
+                55 => {
+                    run_versioned_upgrade(
+                        unopened_catalog_state,
+                        upper,
+                        version,
+                        v55_to_v56::upgrade,
+                    )
+                    .await
+                }
+                56 => {
+                    run_versioned_upgrade(
+                        unopened_catalog_state,
+                        upper,
+                        version,
+                        v56_to_v57::upgrade,
+                    )
+                    .await
+                }
+                57 => {
+                    run_versioned_upgrade(
+                        unopened_catalog_state,
+                        upper,
+                        version,
+                        v57_to_v58::upgrade,
+                    )
+                    .await
+                }
+                58 => {
+                    run_versioned_upgrade(
+                        unopened_catalog_state,
+                        upper,
+                        version,
+                        v58_to_v59::upgrade,
+                    )
+                    .await
+                }
+                59 => {
+                    run_versioned_upgrade(
+                        unopened_catalog_state,
+                        upper,
+                        version,
+                        v59_to_v60::upgrade,
+                    )
+                    .await
+                }
+                60 => {
+                    run_versioned_upgrade(
+                        unopened_catalog_state,
+                        upper,
+                        version,
+                        v60_to_v61::upgrade,
+                    )
+                    .await
+                }
 
                 // Up-to-date, no migration needed!
                 CATALOG_VERSION => Ok((CATALOG_VERSION, upper)),
@@ -0,0 +1,18 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use crate::durable::upgrade::persist::MigrationAction;
+use crate::durable::upgrade::{objects_v45 as v45, objects_v46 as v46};
+
+/// No-op migration for adding the `RenameDatabaseV1` audit log event.
+pub fn upgrade(
+    _snapshot: Vec<v45::StateUpdateKind>,
+) -> Vec<MigrationAction<v45::StateUpdateKind, v46::StateUpdateKind>> {
+    Vec::new()
+}
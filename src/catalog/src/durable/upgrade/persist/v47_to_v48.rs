@@ -0,0 +1,137 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use mz_repr::GlobalId;
+
+use crate::durable::objects::item_dependencies;
+use crate::durable::upgrade::persist::MigrationAction;
+use crate::durable::upgrade::{objects_v47 as v47, objects_v48 as v48};
+
+/// Backfill the new `dependencies` collection from the `create_sql` of every existing item.
+pub fn upgrade(
+    snapshot: Vec<v47::StateUpdateKind>,
+) -> Vec<MigrationAction<v47::StateUpdateKind, v48::StateUpdateKind>> {
+    let mut migrations = Vec::new();
+
+    for update in snapshot {
+        let v47::state_update_kind::Kind::Item(item) = update.kind.expect("missing field") else {
+            continue;
+        };
+        let object_id = item.key.expect("missing field").gid.expect("missing field");
+        let v47::catalog_item::Value::V1(v47::catalog_item::V1 { create_sql }) = item
+            .value
+            .expect("missing field")
+            .definition
+            .expect("missing field")
+            .value
+            .expect("missing field");
+
+        let dependencies =
+            item_dependencies(&create_sql).expect("invalid create sql in catalog item");
+        for dependency_id in dependencies {
+            let insertion = v48::StateUpdateKind {
+                kind: Some(v48::state_update_kind::Kind::Dependency(
+                    v48::state_update_kind::Dependency {
+                        key: Some(v48::DependencyKey {
+                            object_id: Some(upgrade_global_id(object_id.clone())),
+                            dependency_id: Some(global_id_to_proto(dependency_id)),
+                        }),
+                        value: Some(v48::DependencyValue {}),
+                    },
+                )),
+            };
+            migrations.push(MigrationAction::Insert(insertion));
+        }
+    }
+
+    migrations
+}
+
+fn upgrade_global_id(id: v47::GlobalId) -> v48::GlobalId {
+    let value = match id.value.expect("missing field") {
+        v47::global_id::Value::System(id) => v48::global_id::Value::System(id),
+        v47::global_id::Value::User(id) => v48::global_id::Value::User(id),
+        v47::global_id::Value::Transient(id) => v48::global_id::Value::Transient(id),
+        v47::global_id::Value::Explain(v47::Empty {}) => {
+            v48::global_id::Value::Explain(v48::Empty {})
+        }
+    };
+    v48::GlobalId { value: Some(value) }
+}
+
+fn global_id_to_proto(id: GlobalId) -> v48::GlobalId {
+    let value = match id {
+        GlobalId::System(id) => v48::global_id::Value::System(id),
+        GlobalId::User(id) => v48::global_id::Value::User(id),
+        GlobalId::Transient(id) => v48::global_id::Value::Transient(id),
+        GlobalId::Explain => v48::global_id::Value::Explain(v48::Empty {}),
+    };
+    v48::GlobalId { value: Some(value) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: u64, create_sql: &str) -> v47::StateUpdateKind {
+        v47::StateUpdateKind {
+            kind: Some(v47::state_update_kind::Kind::Item(
+                v47::state_update_kind::Item {
+                    key: Some(v47::ItemKey {
+                        gid: Some(v47::GlobalId {
+                            value: Some(v47::global_id::Value::User(id)),
+                        }),
+                    }),
+                    value: Some(v47::ItemValue {
+                        schema_id: None,
+                        name: format!("i{id}"),
+                        definition: Some(v47::CatalogItem {
+                            value: Some(v47::catalog_item::Value::V1(v47::catalog_item::V1 {
+                                create_sql: create_sql.to_string(),
+                            })),
+                        }),
+                        owner_id: None,
+                        privileges: Vec::new(),
+                    }),
+                },
+            )),
+        }
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
+    fn smoke_test_dependencies_migration() {
+        let snapshot = vec![
+            item(1, "CREATE TABLE t (a int)"),
+            item(
+                2,
+                "CREATE VIEW v AS SELECT a FROM [u1 AS materialize.public.t]",
+            ),
+        ];
+
+        let actions = upgrade(snapshot);
+
+        let expected = vec![MigrationAction::Insert(v48::StateUpdateKind {
+            kind: Some(v48::state_update_kind::Kind::Dependency(
+                v48::state_update_kind::Dependency {
+                    key: Some(v48::DependencyKey {
+                        object_id: Some(v48::GlobalId {
+                            value: Some(v48::global_id::Value::User(2)),
+                        }),
+                        dependency_id: Some(v48::GlobalId {
+                            value: Some(v48::global_id::Value::User(1)),
+                        }),
+                    }),
+                    value: Some(v48::DependencyValue {}),
+                },
+            )),
+        })];
+        assert_eq!(actions, expected);
+    }
+}
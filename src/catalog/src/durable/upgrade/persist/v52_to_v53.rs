@@ -0,0 +1,18 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use crate::durable::upgrade::persist::MigrationAction;
+use crate::durable::upgrade::{objects_v52 as v52, objects_v53 as v53};
+
+/// No-op migration for adding session events to the audit log.
+pub fn upgrade(
+    _snapshot: Vec<v52::StateUpdateKind>,
+) -> Vec<MigrationAction<v52::StateUpdateKind, v53::StateUpdateKind>> {
+    Vec::new()
+}
@@ -0,0 +1,18 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use crate::durable::upgrade::persist::MigrationAction;
+use crate::durable::upgrade::{objects_v56 as v56, objects_v57 as v57};
+
+/// No-op migration for adding replica availability zone constraints.
+pub fn upgrade(
+    _snapshot: Vec<v56::StateUpdateKind>,
+) -> Vec<MigrationAction<v56::StateUpdateKind, v57::StateUpdateKind>> {
+    Vec::new()
+}
@@ -0,0 +1,18 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use crate::durable::upgrade::persist::MigrationAction;
+use crate::durable::upgrade::{objects_v58 as v58, objects_v59 as v59};
+
+/// No-op migration for adding cluster priorities and memory quotas.
+pub fn upgrade(
+    _snapshot: Vec<v58::StateUpdateKind>,
+) -> Vec<MigrationAction<v58::StateUpdateKind, v59::StateUpdateKind>> {
+    Vec::new()
+}
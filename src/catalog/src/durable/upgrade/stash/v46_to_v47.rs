@@ -0,0 +1,22 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use mz_stash::{Transaction, TypedCollection};
+use mz_stash_types::StashError;
+
+use crate::durable::upgrade::objects_v47 as v47;
+
+const TAGS_COLLECTION: TypedCollection<v47::TagKey, v47::TagValue> = TypedCollection::new("tags");
+
+/// Initializes the new `tags` collection.
+pub async fn upgrade(tx: &Transaction<'_>) -> Result<(), StashError> {
+    TAGS_COLLECTION
+        .migrate_to::<v47::TagKey, v47::TagValue>(tx, |_| Vec::new())
+        .await
+}
@@ -0,0 +1,80 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use mz_repr::GlobalId;
+use mz_stash::upgrade::MigrationAction;
+use mz_stash::{Transaction, TypedCollection};
+use mz_stash_types::StashError;
+
+use crate::durable::objects::item_dependencies;
+use crate::durable::upgrade::{objects_v47 as v47, objects_v48 as v48};
+
+const ITEM_COLLECTION: TypedCollection<v47::ItemKey, v47::ItemValue> = TypedCollection::new("item");
+const DEPENDENCIES_COLLECTION: TypedCollection<v48::DependencyKey, v48::DependencyValue> =
+    TypedCollection::new("dependencies");
+
+/// Backfill the new `dependencies` collection from the `create_sql` of every existing item.
+pub async fn upgrade(tx: &Transaction<'_>) -> Result<(), StashError> {
+    let mut dependencies = Vec::new();
+
+    ITEM_COLLECTION
+        .migrate_to::<v47::ItemKey, v47::ItemValue>(tx, |entries| {
+            for (key, value) in entries {
+                let object_id = upgrade_global_id(key.gid.clone().expect("missing field"));
+                let v47::catalog_item::Value::V1(v47::catalog_item::V1 { create_sql }) = value
+                    .definition
+                    .as_ref()
+                    .expect("missing field")
+                    .value
+                    .as_ref()
+                    .expect("missing field");
+                let dependencies =
+                    item_dependencies(create_sql).expect("invalid create sql in catalog item");
+                for dependency_id in dependencies {
+                    dependencies.push(v48::DependencyKey {
+                        object_id: Some(object_id.clone()),
+                        dependency_id: Some(global_id_to_proto(dependency_id)),
+                    });
+                }
+            }
+            Vec::new()
+        })
+        .await?;
+
+    DEPENDENCIES_COLLECTION
+        .migrate_to(tx, |_| {
+            dependencies
+                .into_iter()
+                .map(|key| MigrationAction::Insert(key, v48::DependencyValue {}))
+                .collect()
+        })
+        .await
+}
+
+fn upgrade_global_id(id: v47::GlobalId) -> v48::GlobalId {
+    let value = match id.value.expect("missing field") {
+        v47::global_id::Value::System(id) => v48::global_id::Value::System(id),
+        v47::global_id::Value::User(id) => v48::global_id::Value::User(id),
+        v47::global_id::Value::Transient(id) => v48::global_id::Value::Transient(id),
+        v47::global_id::Value::Explain(v47::Empty {}) => {
+            v48::global_id::Value::Explain(v48::Empty {})
+        }
+    };
+    v48::GlobalId { value: Some(value) }
+}
+
+fn global_id_to_proto(id: GlobalId) -> v48::GlobalId {
+    let value = match id {
+        GlobalId::System(id) => v48::global_id::Value::System(id),
+        GlobalId::User(id) => v48::global_id::Value::User(id),
+        GlobalId::Transient(id) => v48::global_id::Value::Transient(id),
+        GlobalId::Explain => v48::global_id::Value::Explain(v48::Empty {}),
+    };
+    v48::GlobalId { value: Some(value) }
+}
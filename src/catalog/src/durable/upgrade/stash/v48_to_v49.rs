@@ -0,0 +1,23 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use mz_stash::{Transaction, TypedCollection};
+use mz_stash_types::StashError;
+
+use crate::durable::upgrade::objects_v49 as v49;
+
+const TRASHED_ITEMS_COLLECTION: TypedCollection<v49::TrashedItemKey, v49::TrashedItemValue> =
+    TypedCollection::new("trashed_items");
+
+/// Initializes the new `trashed_items` collection.
+pub async fn upgrade(tx: &Transaction<'_>) -> Result<(), StashError> {
+    TRASHED_ITEMS_COLLECTION
+        .migrate_to::<v49::TrashedItemKey, v49::TrashedItemValue>(tx, |_| Vec::new())
+        .await
+}
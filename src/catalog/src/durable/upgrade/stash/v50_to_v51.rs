@@ -0,0 +1,23 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use mz_stash::{Transaction, TypedCollection};
+use mz_stash_types::StashError;
+
+use crate::durable::upgrade::objects_v51 as v51;
+
+const COLUMN_MASKS_COLLECTION: TypedCollection<v51::ColumnMaskKey, v51::ColumnMaskValue> =
+    TypedCollection::new("column_masks");
+
+/// Initializes the new `column_masks` collection.
+pub async fn upgrade(tx: &Transaction<'_>) -> Result<(), StashError> {
+    COLUMN_MASKS_COLLECTION
+        .migrate_to::<v51::ColumnMaskKey, v51::ColumnMaskValue>(tx, |_| Vec::new())
+        .await
+}
@@ -0,0 +1,25 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use mz_stash::{Transaction, TypedCollection};
+use mz_stash_types::StashError;
+
+use crate::durable::upgrade::objects_v55 as v55;
+
+const SERVICE_ACCOUNTS_COLLECTION: TypedCollection<
+    v55::ServiceAccountKey,
+    v55::ServiceAccountValue,
+> = TypedCollection::new("service_accounts");
+
+/// Initializes the new `service_accounts` collection.
+pub async fn upgrade(tx: &Transaction<'_>) -> Result<(), StashError> {
+    SERVICE_ACCOUNTS_COLLECTION
+        .migrate_to::<v55::ServiceAccountKey, v55::ServiceAccountValue>(tx, |_| Vec::new())
+        .await
+}
@@ -0,0 +1,25 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use mz_stash::{Transaction, TypedCollection};
+use mz_stash_types::StashError;
+
+use crate::durable::upgrade::objects_v58 as v58;
+
+const CLUSTER_REPLICA_SIZES_COLLECTION: TypedCollection<
+    v58::ClusterReplicaSizeKey,
+    v58::ClusterReplicaSizeValue,
+> = TypedCollection::new("cluster_replica_sizes");
+
+/// Initializes the new `cluster_replica_sizes` collection.
+pub async fn upgrade(tx: &Transaction<'_>) -> Result<(), StashError> {
+    CLUSTER_REPLICA_SIZES_COLLECTION
+        .migrate_to::<v58::ClusterReplicaSizeKey, v58::ClusterReplicaSizeValue>(tx, |_| Vec::new())
+        .await
+}
@@ -81,6 +81,8 @@ pub enum ErrorKind {
     },
     #[error("cluster '{0}' is managed and cannot be directly modified")]
     ManagedCluster(String),
+    #[error("cluster replica size '{size}' is in use by cluster '{cluster_name}'")]
+    ClusterReplicaSizeInUse { size: String, cluster_name: String },
 }
 
 impl Error {
@@ -103,6 +105,7 @@ impl Error {
             ErrorKind::ReservedClusterName(_) => {
                 Some("The prefixes \"mz_\" and \"pg_\" are reserved for system clusters.".into())
             }
+            ErrorKind::Sql(e) => e.detail(),
             _ => None,
         }
     }
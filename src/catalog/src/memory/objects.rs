@@ -25,9 +25,10 @@ use mz_compute_client::logging::LogVariant;
 use mz_controller::clusters::{
     ClusterRole, ClusterStatus, ProcessId, ReplicaConfig, ReplicaLogging,
 };
-use mz_controller_types::{ClusterId, ReplicaId};
+use mz_controller_types::{ClusterId, ClusterPriority, ReplicaId};
 use mz_expr::{CollectionPlan, MirScalarExpr, OptimizedMirRelationExpr};
 use mz_ore::collections::CollectionExt;
+use mz_ore::now::EpochMillis;
 use mz_repr::adt::mz_acl_item::{AclMode, PrivilegeMap};
 use mz_repr::role_id::RoleId;
 use mz_repr::{GlobalId, RelationDesc};
@@ -44,8 +45,8 @@ use mz_sql::names::{
     ResolvedDatabaseSpecifier, ResolvedIds, SchemaId, SchemaSpecifier,
 };
 use mz_sql::plan::{
-    CreateSourcePlan, HirRelationExpr, Ingestion as PlanIngestion, WebhookHeaders,
-    WebhookValidation,
+    ClusterScheduleEntry, CreateSourcePlan, HirRelationExpr, Ingestion as PlanIngestion,
+    WebhookHeaders, WebhookValidation,
 };
 use mz_sql::rbac;
 use mz_sql::session::vars::OwnedVarInput;
@@ -140,6 +141,29 @@ impl From<Role> for durable::Role {
     }
 }
 
+#[derive(Debug, Serialize, Clone)]
+pub struct ServiceAccount {
+    pub id: u64,
+    pub name: String,
+    pub role_id: RoleId,
+    pub token_secret_id: GlobalId,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<EpochMillis>,
+}
+
+impl From<durable::ServiceAccount> for ServiceAccount {
+    fn from(service_account: durable::ServiceAccount) -> ServiceAccount {
+        ServiceAccount {
+            id: service_account.id,
+            name: service_account.name,
+            role_id: service_account.role_id,
+            token_secret_id: service_account.token_secret_id,
+            scopes: service_account.scopes,
+            expires_at: service_account.expires_at,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Clone)]
 pub struct Cluster {
     pub name: String,
@@ -269,6 +293,8 @@ pub struct ClusterReplica {
     #[serde(skip)]
     pub process_status: BTreeMap<ProcessId, ClusterReplicaProcessStatus>,
     pub owner_id: RoleId,
+    /// Whether the replica is draining, i.e., new dataflows are not installed on it.
+    pub draining: bool,
 }
 
 impl ClusterReplica {
@@ -302,6 +328,7 @@ impl From<ClusterReplica> for durable::ClusterReplica {
             name: replica.name,
             config: replica.config.into(),
             owner_id: replica.owner_id,
+            draining: replica.draining,
         }
     }
 }
@@ -945,6 +972,80 @@ impl CatalogItem {
         }
     }
 
+    /// Returns a clone of `self` with all references to the database
+    /// `cur_database_name` renamed to `new_database_name`.
+    pub fn rename_database_refs(
+        &self,
+        cur_database_name: &str,
+        new_database_name: &str,
+    ) -> CatalogItem {
+        let do_rewrite = |create_sql: String| -> String {
+            let mut create_stmt = mz_sql::parse::parse(&create_sql)
+                .expect("invalid create sql persisted to catalog")
+                .into_element()
+                .ast;
+
+            // Rename all references to cur_database_name.
+            mz_sql::ast::transform::create_stmt_rename_database_refs(
+                &mut create_stmt,
+                cur_database_name,
+                new_database_name,
+            );
+
+            create_stmt.to_ast_string_stable()
+        };
+
+        match self {
+            CatalogItem::Table(i) => {
+                let mut i = i.clone();
+                i.create_sql = i.create_sql.map(do_rewrite);
+                CatalogItem::Table(i)
+            }
+            CatalogItem::Log(i) => CatalogItem::Log(i.clone()),
+            CatalogItem::Source(i) => {
+                let mut i = i.clone();
+                i.create_sql = i.create_sql.map(do_rewrite);
+                CatalogItem::Source(i)
+            }
+            CatalogItem::Sink(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql);
+                CatalogItem::Sink(i)
+            }
+            CatalogItem::View(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql);
+                CatalogItem::View(i)
+            }
+            CatalogItem::MaterializedView(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql);
+                CatalogItem::MaterializedView(i)
+            }
+            CatalogItem::Index(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql);
+                CatalogItem::Index(i)
+            }
+            CatalogItem::Secret(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql);
+                CatalogItem::Secret(i)
+            }
+            CatalogItem::Connection(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql);
+                CatalogItem::Connection(i)
+            }
+            CatalogItem::Type(i) => {
+                let mut i = i.clone();
+                i.create_sql = i.create_sql.map(do_rewrite);
+                CatalogItem::Type(i)
+            }
+            CatalogItem::Func(i) => CatalogItem::Func(i.clone()),
+        }
+    }
+
     /// Returns a clone of `self` with all instances of `from` renamed to `to`
     /// (with the option of including the item's own name) or errors if request
     /// is ambiguous.
@@ -1483,18 +1584,32 @@ impl CommentsMap {
     ///
     /// Generally there is one comment for a given [`CommentObjectId`], but in the case of
     /// relations you can also have comments on the individual columns. Dropping the comments for a
-    /// relation will also drop all of the comments on any columns.
+    /// relation will also drop all of the comments on any columns. Likewise, dropping the comments
+    /// for a cluster will also drop all of the comments on its replicas.
     pub fn drop_comments(
         &mut self,
         object_id: CommentObjectId,
     ) -> Vec<(CommentObjectId, Option<usize>, String)> {
-        match self.map.remove(&object_id) {
-            None => Vec::new(),
-            Some(comments) => comments
-                .into_iter()
-                .map(|(sub_comp, comment)| (object_id, sub_comp, comment))
-                .collect(),
+        let mut object_ids = vec![object_id];
+        if let CommentObjectId::Cluster(cluster_id) = object_id {
+            object_ids.extend(self.map.keys().copied().filter(
+                |id| matches!(id, CommentObjectId::ClusterReplica((id, _)) if *id == cluster_id),
+            ));
         }
+
+        object_ids
+            .into_iter()
+            .filter_map(|object_id| {
+                self.map
+                    .remove(&object_id)
+                    .map(|comments| (object_id, comments))
+            })
+            .flat_map(|(object_id, comments)| {
+                comments
+                    .into_iter()
+                    .map(move |(sub_comp, comment)| (object_id, sub_comp, comment))
+            })
+            .collect()
     }
 
     pub fn iter(&self) -> impl Iterator<Item = (CommentObjectId, Option<usize>, &str)> {
@@ -1541,6 +1656,183 @@ impl Serialize for CommentsMap {
     }
 }
 
+/// The tags of catalog objects, keyed by object and then by tag name.
+#[derive(Debug, Clone, Default)]
+pub struct TagsMap {
+    map: BTreeMap<CommentObjectId, BTreeMap<String, String>>,
+}
+
+impl TagsMap {
+    /// Sets the tag `name` of `object_id` to `value`, or removes it if `value` is `None`. Returns
+    /// the previous value of the tag, if there was one.
+    pub fn set_tag(
+        &mut self,
+        object_id: CommentObjectId,
+        name: String,
+        value: Option<String>,
+    ) -> Option<String> {
+        let object_tags = self.map.entry(object_id).or_default();
+        let prev = match value {
+            Some(value) => object_tags.insert(name, value),
+            None => object_tags.remove(&name),
+        };
+        if object_tags.is_empty() {
+            self.map.remove(&object_id);
+        }
+        prev
+    }
+
+    /// Remove all tags for `object_id` from the map.
+    ///
+    /// Like comments, dropping the tags for a cluster also drops the tags of its replicas.
+    pub fn drop_tags(
+        &mut self,
+        object_id: CommentObjectId,
+    ) -> Vec<(CommentObjectId, String, String)> {
+        let mut object_ids = vec![object_id];
+        if let CommentObjectId::Cluster(cluster_id) = object_id {
+            object_ids.extend(self.map.keys().copied().filter(
+                |id| matches!(id, CommentObjectId::ClusterReplica((id, _)) if *id == cluster_id),
+            ));
+        }
+
+        object_ids
+            .into_iter()
+            .filter_map(|object_id| self.map.remove(&object_id).map(|tags| (object_id, tags)))
+            .flat_map(|(object_id, tags)| {
+                tags.into_iter()
+                    .map(move |(name, value)| (object_id, name, value))
+            })
+            .collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (CommentObjectId, &str, &str)> {
+        self.map.iter().flat_map(|(id, tags)| {
+            tags.iter()
+                .map(|(name, value)| (*id, name.as_str(), value.as_str()))
+        })
+    }
+}
+
+impl Serialize for TagsMap {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let tag_count = self.map.values().map(|tags| tags.len()).sum();
+
+        let mut seq = serializer.serialize_seq(Some(tag_count))?;
+        for (object_id, name, value) in self.iter() {
+            seq.serialize_element(&(format!("{object_id:?}"), name, value))?;
+        }
+        seq.end()
+    }
+}
+
+/// The masks of the columns of catalog items, keyed by item, then by column name, and then by the
+/// role that the mask applies to.
+#[derive(Debug, Clone, Default)]
+pub struct ColumnMasksMap {
+    map: BTreeMap<GlobalId, BTreeMap<String, BTreeMap<RoleId, String>>>,
+}
+
+impl ColumnMasksMap {
+    /// Sets the mask of `column` of item `item_id` for role `role_id` to `expr`, or removes the
+    /// mask if `expr` is `None`. Returns the previous mask, if there was one.
+    pub fn set_column_mask(
+        &mut self,
+        item_id: GlobalId,
+        column: String,
+        role_id: RoleId,
+        expr: Option<String>,
+    ) -> Option<String> {
+        let item_masks = self.map.entry(item_id).or_default();
+        let column_masks = item_masks.entry(column.clone()).or_default();
+        let prev = match expr {
+            Some(expr) => column_masks.insert(role_id, expr),
+            None => column_masks.remove(&role_id),
+        };
+        if column_masks.is_empty() {
+            item_masks.remove(&column);
+        }
+        if item_masks.is_empty() {
+            self.map.remove(&item_id);
+        }
+        prev
+    }
+
+    /// Removes the masks of `column` of item `item_id` for all roles, and returns the removed
+    /// masks.
+    pub fn remove_column_masks(
+        &mut self,
+        item_id: GlobalId,
+        column: &str,
+    ) -> Vec<(RoleId, String)> {
+        let Some(item_masks) = self.map.get_mut(&item_id) else {
+            return Vec::new();
+        };
+        let removed = item_masks.remove(column).unwrap_or_default();
+        if item_masks.is_empty() {
+            self.map.remove(&item_id);
+        }
+        removed.into_iter().collect()
+    }
+
+    /// Removes the masks of all columns of item `item_id`.
+    pub fn drop_item(&mut self, item_id: GlobalId) {
+        self.map.remove(&item_id);
+    }
+
+    /// Removes the masks of all columns for role `role_id`.
+    pub fn drop_role(&mut self, role_id: RoleId) {
+        self.map.retain(|_, item_masks| {
+            item_masks.retain(|_, column_masks| {
+                column_masks.remove(&role_id);
+                !column_masks.is_empty()
+            });
+            !item_masks.is_empty()
+        });
+    }
+
+    /// Returns the masks of the columns of item `item_id`, keyed by column name and then by role.
+    pub fn get_item_masks(
+        &self,
+        item_id: &GlobalId,
+    ) -> Option<&BTreeMap<String, BTreeMap<RoleId, String>>> {
+        self.map.get(item_id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (GlobalId, &str, RoleId, &str)> {
+        self.map.iter().flat_map(|(item_id, item_masks)| {
+            item_masks.iter().flat_map(move |(column, column_masks)| {
+                column_masks.iter().map(move |(role_id, expr)| {
+                    (*item_id, column.as_str(), *role_id, expr.as_str())
+                })
+            })
+        })
+    }
+}
+
+impl Serialize for ColumnMasksMap {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mask_count = self
+            .map
+            .values()
+            .flat_map(|item_masks| item_masks.values())
+            .map(|column_masks| column_masks.len())
+            .sum();
+
+        let mut seq = serializer.serialize_seq(Some(mask_count))?;
+        for (item_id, column, role_id, expr) in self.iter() {
+            seq.serialize_element(&(item_id.to_string(), column, role_id.to_string(), expr))?;
+        }
+        seq.end()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Default)]
 pub struct DefaultPrivileges {
     #[serde(serialize_with = "mz_ore::serde::map_key_to_string")]
@@ -1716,12 +2008,22 @@ impl DefaultPrivileges {
 #[derive(Clone, Debug, Deserialize, Serialize, PartialOrd, PartialEq, Eq, Ord)]
 pub struct ClusterConfig {
     pub variant: ClusterVariant,
+    /// The scheduling priority of the cluster's replicas.
+    pub priority: ClusterPriority,
+    /// The maximum amount of memory, in bytes, that the cluster's replicas
+    /// may be provisioned with in total, if any.
+    pub memory_quota: Option<u64>,
+    /// The resize of the cluster that is in progress, if any.
+    pub resize: Option<ClusterResize>,
 }
 
 impl From<ClusterConfig> for durable::ClusterConfig {
     fn from(config: ClusterConfig) -> Self {
         Self {
             variant: config.variant.into(),
+            priority: config.priority,
+            memory_quota: config.memory_quota,
+            resize: config.resize.map(Into::into),
         }
     }
 }
@@ -1730,6 +2032,54 @@ impl From<durable::ClusterConfig> for ClusterConfig {
     fn from(config: durable::ClusterConfig) -> Self {
         Self {
             variant: config.variant.into(),
+            priority: config.priority,
+            memory_quota: config.memory_quota,
+            resize: config.resize.map(Into::into),
+        }
+    }
+}
+
+/// An in-progress resize of a managed cluster.
+///
+/// A resize provisions the replicas of the cluster's new configuration
+/// alongside its existing replicas, and only drops the existing replicas once
+/// the new ones have caught up. The resize is recorded durably, so that a
+/// restart in the middle of it resumes or rolls it back rather than orphaning
+/// replicas.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialOrd, PartialEq, Eq, Ord)]
+pub struct ClusterResize {
+    /// The replicas the cluster had before the resize, which are dropped at
+    /// cutover.
+    pub old_replicas: BTreeSet<ReplicaId>,
+    /// The replicas provisioned by the resize, which replace `old_replicas` at
+    /// cutover.
+    pub new_replicas: BTreeSet<ReplicaId>,
+    /// The frontier that the new replicas must have computed every collection
+    /// on the cluster to before cutover.
+    pub cutover_frontier: mz_repr::Timestamp,
+    /// The configuration of the cluster before the resize, which is restored
+    /// if the resize is rolled back.
+    pub old_config: ClusterVariantManaged,
+}
+
+impl From<ClusterResize> for durable::ClusterResize {
+    fn from(resize: ClusterResize) -> Self {
+        Self {
+            old_replicas: resize.old_replicas,
+            new_replicas: resize.new_replicas,
+            cutover_frontier: resize.cutover_frontier,
+            old_config: resize.old_config.into(),
+        }
+    }
+}
+
+impl From<durable::ClusterResize> for ClusterResize {
+    fn from(resize: durable::ClusterResize) -> Self {
+        Self {
+            old_replicas: resize.old_replicas,
+            new_replicas: resize.new_replicas,
+            cutover_frontier: resize.cutover_frontier,
+            old_config: resize.old_config.into(),
         }
     }
 }
@@ -1742,6 +2092,7 @@ pub struct ClusterVariantManaged {
     pub idle_arrangement_merge_effort: Option<u32>,
     pub replication_factor: u32,
     pub disk: bool,
+    pub schedule: Vec<ClusterScheduleEntry>,
 }
 
 impl From<ClusterVariantManaged> for durable::ClusterVariantManaged {
@@ -1753,6 +2104,7 @@ impl From<ClusterVariantManaged> for durable::ClusterVariantManaged {
             idle_arrangement_merge_effort: managed.idle_arrangement_merge_effort,
             replication_factor: managed.replication_factor,
             disk: managed.disk,
+            schedule: managed.schedule,
         }
     }
 }
@@ -1766,6 +2118,7 @@ impl From<durable::ClusterVariantManaged> for ClusterVariantManaged {
             idle_arrangement_merge_effort: managed.idle_arrangement_merge_effort,
             replication_factor: managed.replication_factor,
             disk: managed.disk,
+            schedule: managed.schedule,
         }
     }
 }
@@ -75,14 +75,14 @@
 #![warn(clippy::from_over_into)]
 // END LINT CONFIG
 
-use mz_catalog::durable::debug::SettingCollection;
+use mz_catalog::durable::debug::{IdAllocatorCollection, SettingCollection};
 use mz_catalog::durable::objects::serialization::proto;
 use mz_catalog::durable::{
     test_bootstrap_args, test_persist_backed_catalog_state, test_stash_backed_catalog_state,
-    CatalogError, DurableCatalogError, OpenableDurableCatalogState,
+    CatalogError, CommitConflict, DurableCatalogError, OpenableDurableCatalogState,
 };
 use mz_ore::collections::CollectionExt;
-use mz_ore::now::NOW_ZERO;
+use mz_ore::now::{NOW_ZERO, SYSTEM_TIME};
 use mz_persist_client::PersistClient;
 use mz_stash::DebugStashFactory;
 use uuid::Uuid;
@@ -237,3 +237,57 @@ async fn test_debug(
     differential_dataflow::consolidation::consolidate_updates(&mut settings);
     assert_eq!(settings.len(), 0);
 }
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_commit_conflict() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    let debug_openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    txn.insert_id_allocator("namespace".to_string(), 100)
+        .unwrap();
+    txn.commit().await.unwrap();
+
+    // Concurrently change the allocator without fencing the catalog.
+    let mut debug_state = Box::new(debug_openable_state).open_debug().await.unwrap();
+    debug_state
+        .edit::<IdAllocatorCollection>(
+            proto::IdAllocKey {
+                name: "namespace".to_string(),
+            },
+            proto::IdAllocValue { next_id: 200 },
+        )
+        .await
+        .unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    assert_eq!(
+        txn.get_and_increment_id("namespace".to_string()).unwrap(),
+        100
+    );
+    let err = txn.commit().await.unwrap_err();
+    let CatalogError::Durable(DurableCatalogError::Conflict(conflicts)) = &err else {
+        panic!("unexpected err: {err:?}");
+    };
+    assert_eq!(
+        conflicts,
+        &vec![CommitConflict {
+            collection: "id_allocator",
+            key: "namespace".to_string(),
+        }]
+    );
+    assert_eq!(
+        err.to_string(),
+        "catalog transaction conflicts with concurrent changes; id_allocator: namespace changed"
+    );
+    Box::new(state).expire().await;
+}
@@ -80,21 +80,40 @@ use mz_audit_log::{
     CreateClusterReplicaV1, EventDetails, EventType, EventV1, IdNameV1, StorageUsageV1,
     VersionedEvent, VersionedStorageUsage,
 };
-use mz_catalog::durable::objects::{DurableType, IdAlloc};
+use mz_catalog::builtin::MZ_DATAFLOW_OPERATORS_PER_WORKER;
+use mz_catalog::durable::export::{
+    import_bundle, BundleError, CatalogBundle, CATALOG_BUNDLE_VERSION,
+};
+use mz_catalog::durable::migration::{AppliedMigration, Migration, Migrations};
+use mz_catalog::durable::objects::serialization::proto;
+use mz_catalog::durable::objects::{
+    ClusterConfig, ClusterResize, ClusterVariant, ClusterVariantManaged, DurableType, IdAlloc,
+    ReplicaConfig, ReplicaLocation, Snapshot,
+};
 use mz_catalog::durable::{
     test_bootstrap_args, test_persist_backed_catalog_state, test_stash_backed_catalog_state,
-    CatalogError, DurableCatalogError, Item, OpenableDurableCatalogState, TimelineTimestamp,
-    USER_ITEM_ALLOC_KEY,
+    AuditLogFilter, CatalogError, Cluster, ClusterReplica, ClusterReplicaSize, ColumnMask,
+    DurableCatalogError, Epoch, Item, OpenableDurableCatalogState, Role, ServiceAccount,
+    TimelineTimestamp, Transaction, TrashedItem, VacuumOptions, USER_ITEM_ALLOC_KEY,
 };
+use mz_controller::clusters::ReplicaLogging;
+use mz_controller_types::{ClusterId, ClusterPriority, ReplicaId};
 use mz_ore::collections::CollectionExt;
 use mz_ore::now::SYSTEM_TIME;
 use mz_persist_client::PersistClient;
+use mz_pgwire_common::scram::ScramVerifier;
 use mz_proto::RustType;
+use mz_repr::adt::mz_acl_item::AclMode;
 use mz_repr::role_id::RoleId;
 use mz_repr::GlobalId;
-use mz_sql::names::SchemaId;
+use mz_sql::catalog::{
+    CatalogError as SqlCatalogError, ObjectType, RoleAttributes, RoleMembership, RoleVars,
+};
+use mz_sql::names::{CommentObjectId, DatabaseId, SchemaId};
+use mz_sql::session::user::MZ_SYSTEM_ROLE_ID;
 use mz_stash::DebugStashFactory;
 use mz_storage_types::sources::Timeline;
+use std::collections::{BTreeMap, BTreeSet};
 use std::time::Duration;
 use uuid::Uuid;
 
@@ -216,6 +235,136 @@ async fn test_get_and_prune_storage_usage(openable_state: impl OpenableDurableCa
     Box::new(state).expire().await;
 }
 
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_stash_compact_storage_usage() {
+    let debug_factory = DebugStashFactory::new().await;
+    let openable_state = test_stash_backed_catalog_state(&debug_factory);
+    test_compact_storage_usage(openable_state).await;
+    debug_factory.drop().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_compact_storage_usage() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_compact_storage_usage(openable_state).await;
+}
+
+async fn test_compact_storage_usage(openable_state: impl OpenableDurableCatalogState) {
+    const DAY: u64 = 24 * 60 * 60 * 1000;
+    let events = vec![
+        VersionedStorageUsage::new(1, Some("a".to_string()), 10, 1000),
+        VersionedStorageUsage::new(2, Some("a".to_string()), 30, 2000),
+        VersionedStorageUsage::new(3, Some("b".to_string()), 5, 1000),
+        VersionedStorageUsage::new(4, Some("a".to_string()), 7, DAY + 1000),
+    ];
+
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    for event in &events {
+        txn.insert_storage_usage_event(event.clone());
+    }
+    txn.commit().await.unwrap();
+    assert_eq!(state.get_storage_usage().await.unwrap(), events);
+
+    // Only the first day is rolled up, keeping the peak size of each shard.
+    let mut txn = state.transaction().await.unwrap();
+    let rollups = txn.compact_storage_usage(DAY).await.unwrap();
+    let expected_rollups = vec![
+        VersionedStorageUsage::new(1, Some("a".to_string()), 30, 0),
+        VersionedStorageUsage::new(3, Some("b".to_string()), 5, 0),
+    ];
+    assert_eq!(rollups, expected_rollups);
+    txn.commit().await.unwrap();
+
+    let mut expected_events = expected_rollups;
+    expected_events.push(events[3].clone());
+    assert_eq!(state.get_storage_usage().await.unwrap(), expected_events);
+
+    // Compacting again is a no-op.
+    let mut txn = state.transaction().await.unwrap();
+    let rollups = txn.compact_storage_usage(DAY).await.unwrap();
+    assert_eq!(rollups, Vec::new());
+    txn.commit().await.unwrap();
+    assert_eq!(state.get_storage_usage().await.unwrap(), expected_events);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_vacuum() {
+    const DAY: u64 = 24 * 60 * 60 * 1000;
+    let events = vec![
+        VersionedStorageUsage::new(1, Some("a".to_string()), 10, 1000),
+        VersionedStorageUsage::new(2, Some("a".to_string()), 30, 2000),
+        VersionedStorageUsage::new(3, Some("a".to_string()), 7, DAY + 1000),
+    ];
+
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    for event in &events {
+        txn.insert_storage_usage_event(event.clone());
+    }
+    txn.mark_migration_has_run("obsolete".to_string()).unwrap();
+    txn.mark_migration_has_run("current".to_string()).unwrap();
+    txn.commit().await.unwrap();
+
+    // Vacuuming with the default options removes nothing.
+    let mut txn = state.transaction().await.unwrap();
+    let report = txn.vacuum(VacuumOptions::default()).await.unwrap();
+    assert!(report.pruned_audit_log_events.is_empty());
+    assert!(report.removed_storage_usage_events.is_empty());
+    assert!(report.storage_usage_rollups.is_empty());
+    assert!(report.purged_trashed_items.is_empty());
+    assert!(report.removed_settings.is_empty());
+    assert_eq!(report.reclaimed_bytes, 0);
+    drop(txn);
+
+    let mut txn = state.transaction().await.unwrap();
+    let report = txn
+        .vacuum(VacuumOptions {
+            storage_usage_rollup_before_ts: Some(DAY),
+            obsolete_settings: BTreeSet::from(["obsolete".to_string(), "unknown".to_string()]),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(report.removed_storage_usage_events, events[..2].to_vec());
+    assert_eq!(
+        report.storage_usage_rollups,
+        vec![VersionedStorageUsage::new(1, Some("a".to_string()), 30, 0)]
+    );
+    assert_eq!(report.removed_settings, vec!["obsolete".to_string()]);
+    assert!(report.reclaimed_bytes > 0);
+    txn.commit().await.unwrap();
+
+    assert_eq!(
+        state.get_storage_usage().await.unwrap(),
+        vec![
+            VersionedStorageUsage::new(1, Some("a".to_string()), 30, 0),
+            events[2].clone(),
+        ]
+    );
+    let mut txn = state.transaction().await.unwrap();
+    assert!(!txn.check_migration_has_run("obsolete".to_string()).unwrap());
+    assert!(txn.check_migration_has_run("current".to_string()).unwrap());
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
 #[mz_ore::test(tokio::test)]
 #[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
 async fn test_stash_timestamps() {
@@ -263,193 +412,2582 @@ async fn test_timestamps(openable_state: impl OpenableDurableCatalogState) {
         .collect::<Result<_, _>>()
         .unwrap();
     assert!(snapshot_timeline_timestamps.contains(&timeline_timestamp));
-    Box::new(state).expire().await;
-}
 
-#[mz_ore::test(tokio::test)]
-#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
-async fn test_stash_allocate_id() {
-    let debug_factory = DebugStashFactory::new().await;
-    let openable_state = test_stash_backed_catalog_state(&debug_factory);
-    test_allocate_id(openable_state).await;
-    debug_factory.drop().await;
+    let timeline_timestamps = vec![
+        TimelineTimestamp {
+            timeline: Timeline::User("Mars".to_string()),
+            ts: mz_repr::Timestamp::new(43),
+        },
+        TimelineTimestamp {
+            timeline: Timeline::User("Venus".to_string()),
+            ts: mz_repr::Timestamp::new(7),
+        },
+    ];
+    let mut txn = state.transaction().await.unwrap();
+    txn.set_timestamps(timeline_timestamps.clone()).unwrap();
+    txn.commit().await.unwrap();
+    let persisted_timestamps = state.get_timestamps().await.unwrap();
+    for timeline_timestamp in &timeline_timestamps {
+        assert!(persisted_timestamps.contains(timeline_timestamp));
+    }
+    assert!(!persisted_timestamps.contains(&timeline_timestamp));
+    Box::new(state).expire().await;
 }
 
 #[mz_ore::test(tokio::test)]
 #[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
-async fn test_persist_allocate_id() {
+async fn test_persist_export() {
     let persist_client = PersistClient::new_for_tests().await;
     let organization_id = Uuid::new_v4();
     let openable_state =
         test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
-    test_allocate_id(openable_state).await;
-}
-
-async fn test_allocate_id(openable_state: impl OpenableDurableCatalogState) {
-    let id_type = USER_ITEM_ALLOC_KEY;
     let mut state = Box::new(openable_state)
         .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
         .await
         .unwrap();
 
-    let start_id = state.get_next_id(id_type).await.unwrap();
-    let ids = state.allocate_id(id_type, 3).await.unwrap();
-    assert_eq!(ids, (start_id..(start_id + 3)).collect::<Vec<_>>());
+    let bundle = state.export().await.unwrap();
+    assert_eq!(bundle.bundle_version, CATALOG_BUNDLE_VERSION);
+    let json = bundle.to_json();
+    let imported = CatalogBundle::from_json(&json).unwrap();
+    assert_eq!(imported, bundle);
+    assert_eq!(Snapshot::from(imported), state.snapshot().await.unwrap());
 
-    let snapshot_id_allocs: Vec<_> = state
-        .snapshot()
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value["bundle_version"] = serde_json::json!(CATALOG_BUNDLE_VERSION + 1);
+    let err = CatalogBundle::from_json(&value.to_string()).unwrap_err();
+    assert!(matches!(err, BundleError::IncompatibleVersion { .. }));
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_insert_items() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
         .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let schema_id = txn
+        .get_schemas()
+        .find(|schema| schema.name == "public")
         .unwrap()
-        .id_allocator
-        .into_iter()
-        .map(RustType::from_proto)
-        .map_ok(|(k, v)| IdAlloc::from_key_value(k, v))
-        .collect::<Result<_, _>>()
+        .id;
+    let ids = txn.allocate_user_item_ids(4).unwrap();
+    let item = |id: GlobalId, name: &str| Item {
+        id,
+        schema_id,
+        name: name.to_string(),
+        create_sql: format!("CREATE TABLE materialize.public.{name} (a int4)"),
+        owner_id: MZ_SYSTEM_ROLE_ID,
+        privileges: vec![],
+    };
+    txn.insert_items(vec![item(ids[0], "a"), item(ids[1], "b")])
         .unwrap();
-    assert!(snapshot_id_allocs.contains(&IdAlloc {
-        name: id_type.to_string(),
-        next_id: start_id + 3,
-    }));
+
+    // Conflicts with an existing item.
+    let err = txn
+        .insert_items(vec![item(ids[2], "c"), item(ids[3], "a")])
+        .unwrap_err();
+    assert!(
+        matches!(
+            &err,
+            CatalogError::Catalog(SqlCatalogError::ItemAlreadyExists(id, name))
+                if *id == ids[3] && name == "a"
+        ),
+        "unexpected err: {err:?}"
+    );
+    // Conflicts within the inserted items.
+    let err = txn
+        .insert_items(vec![item(ids[2], "c"), item(ids[3], "c")])
+        .unwrap_err();
+    assert!(
+        matches!(
+            &err,
+            CatalogError::Catalog(SqlCatalogError::ItemAlreadyExists(id, name))
+                if *id == ids[3] && name == "c"
+        ),
+        "unexpected err: {err:?}"
+    );
+    txn.commit().await.unwrap();
+
+    // Failed inserts don't insert any items.
+    let txn = state.transaction().await.unwrap();
+    let mut names: Vec<_> = txn
+        .loaded_items()
+        .into_iter()
+        .filter(|item| item.schema_id == schema_id)
+        .map(|item| item.name)
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["a", "b"]);
+    drop(txn);
     Box::new(state).expire().await;
 }
 
 #[mz_ore::test(tokio::test)]
 #[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
-async fn test_stash_audit_logs() {
-    let debug_factory = DebugStashFactory::new().await;
-    let openable_state = test_stash_backed_catalog_state(&debug_factory);
-    test_audit_logs(openable_state).await;
-    debug_factory.drop().await;
+async fn test_persist_get_items_filtered() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let schema_id = txn
+        .get_schemas()
+        .find(|schema| schema.name == "public")
+        .unwrap()
+        .id;
+    let ids = txn.allocate_user_item_ids(3).unwrap();
+    let item = |id: GlobalId, name: &str| Item {
+        id,
+        schema_id,
+        name: name.to_string(),
+        create_sql: format!("CREATE TABLE materialize.public.{name} (a int4)"),
+        owner_id: MZ_SYSTEM_ROLE_ID,
+        privileges: vec![],
+    };
+    txn.insert_items(vec![
+        item(ids[2], "c"),
+        item(ids[0], "a"),
+        item(ids[1], "b"),
+    ])
+    .unwrap();
+
+    let names =
+        |items: Vec<Item>| -> Vec<String> { items.into_iter().map(|item| item.name).collect() };
+    assert_eq!(
+        names(txn.get_items_filtered(|_, v| v.schema_id() == schema_id)),
+        vec!["a", "b", "c"]
+    );
+    assert_eq!(
+        names(txn.get_items_filtered(|k, _| k.id() == ids[1])),
+        vec!["b"]
+    );
+    assert_eq!(names(txn.get_schema_items(schema_id)), vec!["a", "b", "c"]);
+    assert_eq!(
+        names(txn.get_schema_items_filtered(schema_id, |_, v| v.name() != "b")),
+        vec!["a", "c"]
+    );
+    assert!(txn.get_schema_items(SchemaId::User(u64::MAX)).is_empty());
+
+    // The schema index is kept up to date as items are removed.
+    txn.remove_item(ids[1]).unwrap();
+    assert_eq!(names(txn.get_schema_items(schema_id)), vec!["a", "c"]);
+    txn.remove_items([ids[0], ids[2]].into()).unwrap();
+    assert!(txn.get_schema_items(schema_id).is_empty());
+    drop(txn);
+    Box::new(state).expire().await;
 }
 
 #[mz_ore::test(tokio::test)]
 #[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
-async fn test_persist_audit_logs() {
+async fn test_persist_ensure_id_allocator() {
     let persist_client = PersistClient::new_for_tests().await;
-    let organization_id = Uuid::new_v4();
     let openable_state =
-        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
-    test_audit_logs(openable_state).await;
-}
-
-async fn test_audit_logs(openable_state: impl OpenableDurableCatalogState) {
-    let audit_logs = [
-        VersionedEvent::V1(EventV1 {
-            id: 100,
-            event_type: EventType::Create,
-            object_type: mz_audit_log::ObjectType::ClusterReplica,
-            details: EventDetails::CreateClusterReplicaV1(CreateClusterReplicaV1 {
-                cluster_id: "1".to_string(),
-                cluster_name: "foo".to_string(),
-                replica_id: Some("1".to_string()),
-                replica_name: "bar".to_string(),
-                logical_size: "1".to_string(),
-                disk: false,
-                billed_as: None,
-                internal: false,
-            }),
-            user: Some("joe".to_string()),
-            occurred_at: 100,
-        }),
-        VersionedEvent::V1(EventV1 {
-            id: 200,
-            event_type: EventType::Drop,
-            object_type: mz_audit_log::ObjectType::View,
-            details: EventDetails::IdNameV1(IdNameV1 {
-                id: "2".to_string(),
-                name: "v".to_string(),
-            }),
-            user: Some("mike".to_string()),
-            occurred_at: 200,
-        }),
-    ];
-
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
     let mut state = Box::new(openable_state)
         .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
         .await
         .unwrap();
     let mut txn = state.transaction().await.unwrap();
-    for audit_log in &audit_logs {
-        txn.insert_audit_log_event(audit_log.clone());
-    }
+    assert!(txn.ensure_id_allocator("namespace", 100).unwrap());
+    assert!(!txn.ensure_id_allocator("namespace", 200).unwrap());
+    assert_eq!(
+        txn.get_and_increment_id_by("namespace".to_string(), 2)
+            .unwrap(),
+        vec![100, 101]
+    );
+    let err = txn
+        .insert_id_allocator("namespace".to_string(), 300)
+        .unwrap_err();
+    assert!(
+        matches!(
+            &err,
+            CatalogError::Catalog(SqlCatalogError::IdAllocatorAlreadyExists(name))
+                if name == "namespace"
+        ),
+        "unexpected err: {err:?}"
+    );
     txn.commit().await.unwrap();
 
-    let persisted_audit_logs = state.get_audit_logs().await.unwrap();
-    for audit_log in &audit_logs {
-        assert!(persisted_audit_logs.contains(audit_log));
-    }
+    // Ensuring an existing allocator doesn't reset it.
+    let mut txn = state.transaction().await.unwrap();
+    assert!(!txn.ensure_id_allocator("namespace", 100).unwrap());
+    assert_eq!(
+        txn.get_and_increment_id("namespace".to_string()).unwrap(),
+        102
+    );
+    drop(txn);
     Box::new(state).expire().await;
 }
 
 #[mz_ore::test(tokio::test)]
 #[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
-async fn test_stash_items() {
-    let debug_factory = DebugStashFactory::new().await;
-    let openable_state = test_stash_backed_catalog_state(&debug_factory);
-    test_items(openable_state).await;
-    debug_factory.drop().await;
+async fn test_persist_defer_uniqueness_checks() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let schema_id = txn
+        .get_schemas()
+        .find(|schema| schema.name == "public")
+        .unwrap()
+        .id;
+    let ids = txn.allocate_user_item_ids(2).unwrap();
+    let item = |id: GlobalId, name: &str| Item {
+        id,
+        schema_id,
+        name: name.to_string(),
+        create_sql: format!("CREATE TABLE materialize.public.{name} (a int4)"),
+        owner_id: MZ_SYSTEM_ROLE_ID,
+        privileges: vec![],
+    };
+    txn.insert_items(vec![item(ids[0], "a"), item(ids[1], "b")])
+        .unwrap();
+    txn.commit().await.unwrap();
+
+    // Swapping the names of two items requires deferring the uniqueness checks.
+    let mut txn = state.transaction().await.unwrap();
+    assert!(txn.update_item(ids[0], item(ids[0], "b")).is_err());
+    txn.defer_uniqueness_checks();
+    txn.update_item(ids[0], item(ids[0], "b")).unwrap();
+    txn.update_item(ids[1], item(ids[1], "a")).unwrap();
+    txn.commit().await.unwrap();
+    let txn = state.transaction().await.unwrap();
+    let names: BTreeMap<_, _> = txn
+        .get_schema_items(schema_id)
+        .into_iter()
+        .map(|item| (item.id, item.name))
+        .collect();
+    assert_eq!(
+        names,
+        BTreeMap::from([(ids[0], "b".to_string()), (ids[1], "a".to_string())])
+    );
+    drop(txn);
+
+    // Violations that remain are reported on commit.
+    let mut txn = state.transaction().await.unwrap();
+    txn.defer_uniqueness_checks();
+    txn.update_item(ids[0], item(ids[0], "a")).unwrap();
+    let err = txn.commit().await.unwrap_err();
+    assert!(
+        matches!(
+            err,
+            CatalogError::Durable(DurableCatalogError::UniquenessViolation {
+                collection: "items",
+                field: "name",
+                ref existing_key,
+            }) if existing_key.contains(&format!("{:?}", ids[1]))
+        ),
+        "unexpected err: {err:?}"
+    );
+    Box::new(state).expire().await;
 }
 
 #[mz_ore::test(tokio::test)]
 #[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
-async fn test_persist_items() {
+async fn test_persist_tags() {
     let persist_client = PersistClient::new_for_tests().await;
-    let organization_id = Uuid::new_v4();
     let openable_state =
-        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
-    test_items(openable_state).await;
-}
-
-async fn test_items(openable_state: impl OpenableDurableCatalogState) {
-    let items = [
-        Item {
-            id: GlobalId::User(100),
-            schema_id: SchemaId::User(1),
-            name: "foo".to_string(),
-            create_sql: "CREATE VIEW v AS SELECT 1".to_string(),
-            owner_id: RoleId::User(1),
-            privileges: vec![],
-        },
-        Item {
-            id: GlobalId::User(200),
-            schema_id: SchemaId::User(1),
-            name: "bar".to_string(),
-            create_sql: "CREATE MATERIALIZED VIEW mv AS SELECT 2".to_string(),
-            owner_id: RoleId::User(2),
-            privileges: vec![],
-        },
-    ];
-
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
     let mut state = Box::new(openable_state)
         .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
         .await
         .unwrap();
+    let cluster = CommentObjectId::Cluster(ClusterId::User(1));
+    let replica = CommentObjectId::ClusterReplica((ClusterId::User(1), ReplicaId::User(1)));
+    let other_cluster = CommentObjectId::Cluster(ClusterId::User(2));
+    let tags = |txn: &Transaction| -> BTreeSet<_> {
+        txn.get_tags()
+            .map(|tag| (tag.object_id, tag.name, tag.value))
+            .collect()
+    };
+    let tag = |object_id, name: &str, value: &str| (object_id, name.to_string(), value.to_string());
+
     let mut txn = state.transaction().await.unwrap();
-    for item in &items {
-        txn.insert_item(
-            item.id,
-            item.schema_id,
-            &item.name,
-            item.create_sql.clone(),
-            item.owner_id,
-            item.privileges.clone(),
-        )
+    let prev = txn
+        .set_tag(cluster, "owner".to_string(), Some("alice".to_string()))
         .unwrap();
-    }
+    assert_eq!(prev, None);
+    txn.set_tag(cluster, "env".to_string(), Some("prod".to_string()))
+        .unwrap();
+    txn.set_tag(replica, "owner".to_string(), Some("bob".to_string()))
+        .unwrap();
+    txn.set_tag(
+        other_cluster,
+        "owner".to_string(),
+        Some("carol".to_string()),
+    )
+    .unwrap();
     txn.commit().await.unwrap();
 
-    let snapshot_items: Vec<_> = state
-        .snapshot()
-        .await
+    // Setting a tag returns its previous value, and setting it to `None` removes it.
+    let mut txn = state.transaction().await.unwrap();
+    let prev = txn
+        .set_tag(cluster, "owner".to_string(), Some("dave".to_string()))
+        .unwrap();
+    assert_eq!(prev, Some("alice".to_string()));
+    let prev = txn.set_tag(cluster, "env".to_string(), None).unwrap();
+    assert_eq!(prev, Some("prod".to_string()));
+    txn.commit().await.unwrap();
+    let txn = state.transaction().await.unwrap();
+    assert_eq!(
+        tags(&txn),
+        BTreeSet::from([
+            tag(cluster, "owner", "dave"),
+            tag(replica, "owner", "bob"),
+            tag(other_cluster, "owner", "carol"),
+        ])
+    );
+    drop(txn);
+
+    // Dropping the tags of a cluster also drops the tags of its replicas.
+    let mut txn = state.transaction().await.unwrap();
+    let dropped: BTreeSet<_> = txn
+        .drop_tags(cluster)
         .unwrap()
-        .items
         .into_iter()
-        .map(RustType::from_proto)
-        .map_ok(|(k, v)| Item::from_key_value(k, v))
-        .collect::<Result<_, _>>()
-        .unwrap();
-    for item in &items {
+        .map(|tag| (tag.object_id, tag.name, tag.value))
+        .collect();
+    assert_eq!(
+        dropped,
+        BTreeSet::from([tag(cluster, "owner", "dave"), tag(replica, "owner", "bob")])
+    );
+    txn.commit().await.unwrap();
+    let txn = state.transaction().await.unwrap();
+    assert_eq!(
+        tags(&txn),
+        BTreeSet::from([tag(other_cluster, "owner", "carol")])
+    );
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_dependencies() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let schema_id = txn
+        .get_schemas()
+        .find(|schema| schema.name == "public")
+        .unwrap()
+        .id;
+    let ids = txn.allocate_user_item_ids(3).unwrap();
+    let (t1, t2, v) = (ids[0], ids[1], ids[2]);
+    let item = |id: GlobalId, name: &str, create_sql: String| Item {
+        id,
+        schema_id,
+        name: name.to_string(),
+        create_sql,
+        owner_id: MZ_SYSTEM_ROLE_ID,
+        privileges: vec![],
+    };
+    let table = |id: GlobalId, name: &str| {
+        item(
+            id,
+            name,
+            format!("CREATE TABLE materialize.public.{name} (a int4)"),
+        )
+    };
+    let view = |references: &[(GlobalId, &str)]| {
+        let from = references
+            .iter()
+            .map(|(id, name)| format!("[{id} AS materialize.public.{name}]"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        item(
+            v,
+            "v",
+            format!("CREATE VIEW materialize.public.v AS SELECT 1 FROM {from}"),
+        )
+    };
+    txn.insert_items(vec![table(t1, "t1"), table(t2, "t2")])
+        .unwrap();
+    let Item {
+        id,
+        schema_id,
+        name,
+        create_sql,
+        owner_id,
+        privileges,
+    } = view(&[(t1, "t1")]);
+    txn.insert_item(id, schema_id, &name, create_sql, owner_id, privileges)
+        .unwrap();
+    txn.commit().await.unwrap();
+
+    let txn = state.transaction().await.unwrap();
+    assert_eq!(txn.get_item_dependencies(v), BTreeSet::from([t1]));
+    assert_eq!(txn.get_item_dependents(t1), BTreeSet::from([v]));
+    assert_eq!(txn.get_item_dependents(t2), BTreeSet::new());
+    drop(txn);
+
+    // Updating an item recomputes its dependencies.
+    let mut txn = state.transaction().await.unwrap();
+    txn.update_item(v, view(&[(t1, "t1"), (t2, "t2")])).unwrap();
+    txn.commit().await.unwrap();
+    let txn = state.transaction().await.unwrap();
+    assert_eq!(txn.get_item_dependencies(v), BTreeSet::from([t1, t2]));
+    assert_eq!(txn.get_item_dependents(t2), BTreeSet::from([v]));
+    drop(txn);
+
+    // Removing an item removes its dependencies.
+    let mut txn = state.transaction().await.unwrap();
+    txn.remove_item(v).unwrap();
+    txn.commit().await.unwrap();
+    let txn = state.transaction().await.unwrap();
+    assert_eq!(txn.get_dependencies().count(), 0);
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_id_range_leases() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let east = txn.lease_id_range(USER_ITEM_ALLOC_KEY, "east", 10).unwrap();
+    let west = txn.lease_id_range(USER_ITEM_ALLOC_KEY, "west", 10).unwrap();
+    assert_eq!(east.end, west.start);
+    let err = txn
+        .lease_id_range(USER_ITEM_ALLOC_KEY, "east", 10)
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            CatalogError::Durable(DurableCatalogError::IdRangeLeaseAlreadyExists(_))
+        ),
+        "unexpected err: {err:?}"
+    );
+    // The leased IDs are never allocated by the allocator that they were leased from.
+    let id = txn.allocate_user_item_ids(1).unwrap().into_element();
+    assert_eq!(id, GlobalId::User(west.end));
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    assert_eq!(
+        txn.get_id_range_leases(USER_ITEM_ALLOC_KEY),
+        BTreeMap::from([
+            ("east".to_string(), east.clone()),
+            ("west".to_string(), west)
+        ])
+    );
+    let ids = txn.allocate_leased_user_item_ids("east", 4).unwrap();
+    assert_eq!(
+        ids,
+        (east.start..east.start + 4)
+            .map(GlobalId::User)
+            .collect::<Vec<_>>()
+    );
+    let err = txn.allocate_leased_user_item_ids("east", 7).unwrap_err();
+    assert!(
+        matches!(
+            err,
+            CatalogError::Durable(DurableCatalogError::IdRangeLeaseExhausted(_))
+        ),
+        "unexpected err: {err:?}"
+    );
+    let ids = txn.allocate_leased_user_item_ids("east", 6).unwrap();
+    assert_eq!(ids.last(), Some(&GlobalId::User(east.end - 1)));
+
+    txn.release_id_range_lease(USER_ITEM_ALLOC_KEY, "east")
+        .unwrap();
+    assert!(!txn
+        .get_id_range_leases(USER_ITEM_ALLOC_KEY)
+        .contains_key("east"));
+    let err = txn.allocate_leased_user_item_ids("east", 1).unwrap_err();
+    assert!(
+        matches!(
+            err,
+            CatalogError::Durable(DurableCatalogError::UnknownIdRangeLease(_))
+        ),
+        "unexpected err: {err:?}"
+    );
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_import() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let schema_id = txn
+        .get_schemas()
+        .find(|schema| schema.name == "public")
+        .unwrap()
+        .id;
+    let ids = txn.allocate_user_item_ids(2).unwrap();
+    let (table_id, view_id) = (ids[0], ids[1]);
+    txn.insert_item(
+        table_id,
+        schema_id,
+        "t",
+        "CREATE TABLE materialize.public.t (a int4)".to_string(),
+        MZ_SYSTEM_ROLE_ID,
+        vec![],
+    )
+    .unwrap();
+    txn.insert_item(
+        view_id,
+        schema_id,
+        "v",
+        format!("CREATE VIEW materialize.public.v AS SELECT a FROM [{table_id} AS materialize.public.t]"),
+        MZ_SYSTEM_ROLE_ID,
+        vec![],
+    )
+    .unwrap();
+    txn.commit().await.unwrap();
+    let bundle = state.export().await.unwrap();
+    Box::new(state).expire().await;
+
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    // Make sure that the imported items can't keep their ids.
+    txn.allocate_user_item_ids(10).unwrap();
+    let item_ids = import_bundle(&mut txn, bundle).unwrap();
+    txn.commit().await.unwrap();
+
+    let new_table_id = item_ids[&table_id];
+    let new_view_id = item_ids[&view_id];
+    assert_ne!(new_table_id, table_id);
+    let items: Vec<_> = state
+        .snapshot()
+        .await
+        .unwrap()
+        .items
+        .into_iter()
+        .map(RustType::from_proto)
+        .map_ok(|(k, v)| Item::from_key_value(k, v))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    let view = items.iter().find(|item| item.id == new_view_id).unwrap();
+    assert_eq!(view.name, "v");
+    assert!(view.create_sql.contains(&format!("[{new_table_id} AS ")));
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_import_unknown_reference() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let schema_id = txn
+        .get_schemas()
+        .find(|schema| schema.name == "public")
+        .unwrap()
+        .id;
+    let view_id = txn.allocate_user_item_ids(1).unwrap().into_element();
+    txn.insert_item(
+        view_id,
+        schema_id,
+        "v",
+        "CREATE VIEW materialize.public.v AS SELECT a FROM [u999 AS materialize.public.t]"
+            .to_string(),
+        MZ_SYSTEM_ROLE_ID,
+        vec![],
+    )
+    .unwrap();
+    txn.commit().await.unwrap();
+    let bundle = state.export().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    let err = import_bundle(&mut txn, bundle).unwrap_err();
+    assert!(matches!(
+        err,
+        CatalogError::Catalog(SqlCatalogError::UnknownItem(id)) if id == "u999"
+    ));
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_import_corrupt_bundle() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let schema_id = txn
+        .get_schemas()
+        .find(|schema| schema.name == "public")
+        .unwrap()
+        .id;
+    let table_id = txn.allocate_user_item_ids(1).unwrap().into_element();
+    txn.insert_item(
+        table_id,
+        schema_id,
+        "t",
+        "CREATE TABLE materialize.public.t (a int4)".to_string(),
+        MZ_SYSTEM_ROLE_ID,
+        vec![],
+    )
+    .unwrap();
+    txn.commit().await.unwrap();
+    let bundle = state.export().await.unwrap();
+
+    let corrupt = |create_sql: &str| {
+        let mut bundle = bundle.clone();
+        for entry in &mut bundle.items {
+            entry.value.definition = Some(proto::CatalogItem {
+                value: Some(proto::catalog_item::Value::V1(proto::catalog_item::V1 {
+                    create_sql: create_sql.to_string(),
+                })),
+            });
+        }
+        bundle
+    };
+
+    for create_sql in [
+        // Not a statement.
+        "CREATE TABLE",
+        // More than one statement.
+        "CREATE TABLE materialize.public.t (a int4); CREATE TABLE materialize.public.u (a int4)",
+        // A reference by an invalid id.
+        "CREATE VIEW materialize.public.v AS SELECT a FROM [x1 AS materialize.public.t]",
+    ] {
+        let mut txn = state.transaction().await.unwrap();
+        let err = import_bundle(&mut txn, corrupt(create_sql)).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                CatalogError::Durable(DurableCatalogError::InvalidCreateSql(_))
+            ),
+            "unexpected err for {create_sql:?}: {err:?}"
+        );
+    }
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_rename_schema() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let public = txn
+        .get_schemas()
+        .find(|schema| schema.name == "public")
+        .unwrap();
+    let database_id = public.database_id.unwrap();
+    let schema_id = txn
+        .insert_user_schema(database_id, "s", MZ_SYSTEM_ROLE_ID, vec![])
+        .unwrap();
+    let other_database_id = txn
+        .insert_user_database("other", MZ_SYSTEM_ROLE_ID, vec![])
+        .unwrap();
+    txn.insert_user_schema(other_database_id, "t", MZ_SYSTEM_ROLE_ID, vec![])
+        .unwrap();
+
+    let role_name = |txn: &Transaction, id: RoleId| {
+        txn.get_roles()
+            .find(|role| role.id == id)
+            .map_or_else(|| id.to_string(), |role| role.name)
+    };
+
+    // Names must be unique within the database. The error describes the existing schema.
+    let err = txn.rename_schema(schema_id, "public").unwrap_err();
+    let CatalogError::Catalog(err @ SqlCatalogError::SchemaAlreadyExists(..)) = err else {
+        panic!("unexpected err: {err:?}");
+    };
+    assert_eq!(err.to_string(), "schema 'public' already exists");
+    assert_eq!(
+        err.detail(),
+        Some(format!(
+            "The existing schema has ID {}, is in database 'materialize', is owned by role '{}'.",
+            public.id,
+            role_name(&txn, public.owner_id),
+        ))
+    );
+    let err = txn
+        .insert_user_database("other", MZ_SYSTEM_ROLE_ID, vec![])
+        .unwrap_err();
+    let CatalogError::Catalog(err @ SqlCatalogError::DatabaseAlreadyExists(..)) = err else {
+        panic!("unexpected err: {err:?}");
+    };
+    assert_eq!(err.to_string(), "database 'other' already exists");
+    assert_eq!(
+        err.detail(),
+        Some(format!(
+            "The existing database has ID {other_database_id}, is owned by role '{}'.",
+            role_name(&txn, MZ_SYSTEM_ROLE_ID),
+        ))
+    );
+    // But may be shared across databases.
+    txn.rename_schema(schema_id, "t").unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    let schema = txn
+        .get_schemas()
+        .find(|schema| schema.id == schema_id)
+        .unwrap();
+    assert_eq!(schema.name, "t");
+    assert_eq!(schema.database_id, Some(database_id));
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_remove_roles() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let role_ids: BTreeSet<_> = ["joe", "mike", "sally"]
+        .into_iter()
+        .map(|name| {
+            txn.insert_user_role(
+                name.to_string(),
+                RoleAttributes::new(),
+                RoleMembership::new(),
+                RoleVars::default(),
+            )
+            .unwrap()
+        })
+        .collect();
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    let unknown_id = RoleId::User(u64::MAX);
+    let err = txn.remove_roles(BTreeSet::from([unknown_id])).unwrap_err();
+    assert!(matches!(
+        err,
+        CatalogError::Catalog(SqlCatalogError::UnknownRole(id)) if id == unknown_id.to_string()
+    ));
+    drop(txn);
+
+    let mut txn = state.transaction().await.unwrap();
+    txn.remove_roles(role_ids.clone()).unwrap();
+    txn.commit().await.unwrap();
+
+    let txn = state.transaction().await.unwrap();
+    assert!(txn.get_roles().all(|role| !role_ids.contains(&role.id)));
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_set_role_password() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let role_id = txn
+        .insert_user_role(
+            "joe".to_string(),
+            RoleAttributes::new(),
+            RoleMembership::new(),
+            RoleVars::default(),
+        )
+        .unwrap();
+    let unknown_id = RoleId::User(u64::MAX);
+    let err = txn
+        .set_role_password(unknown_id, Some("password"))
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        CatalogError::Catalog(SqlCatalogError::UnknownRole(id)) if id == unknown_id.to_string()
+    ));
+    txn.set_role_password(role_id, Some("password")).unwrap();
+    txn.commit().await.unwrap();
+
+    let get_verifier = |txn: &Transaction| {
+        txn.get_roles()
+            .find(|role| role.id == role_id)
+            .unwrap()
+            .attributes
+            .password_verifier
+    };
+    let mut txn = state.transaction().await.unwrap();
+    let verifier: ScramVerifier = get_verifier(&txn).unwrap().parse().unwrap();
+    assert!(verifier.verify("password"));
+    assert!(!verifier.verify("wrong"));
+    drop(txn);
+
+    // Password verifiers are not exported.
+    let bundle = state.export().await.unwrap();
+    let role = bundle
+        .roles
+        .iter()
+        .find(|entry| entry.value.name == "joe")
+        .unwrap();
+    assert_eq!(
+        role.value.attributes.as_ref().unwrap().password_verifier,
+        None
+    );
+
+    let mut txn = state.transaction().await.unwrap();
+    txn.set_role_password(role_id, None).unwrap();
+    txn.commit().await.unwrap();
+
+    let txn = state.transaction().await.unwrap();
+    assert_eq!(get_verifier(&txn), None);
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_role_resource_attributes() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut attributes = RoleAttributes::new();
+    attributes.connection_limit = Some(3);
+    attributes.statement_timeout = Some(Duration::from_secs(30));
+    attributes.valid_until = Some(1_700_000_000_000);
+    let mut txn = state.transaction().await.unwrap();
+    let role_id = txn
+        .insert_user_role(
+            "joe".to_string(),
+            attributes.clone(),
+            RoleMembership::new(),
+            RoleVars::default(),
+        )
+        .unwrap();
+    txn.commit().await.unwrap();
+
+    let get_role = |txn: &Transaction| txn.get_roles().find(|role| role.id == role_id).unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let mut role = get_role(&txn);
+    assert_eq!(role.attributes, attributes);
+    role.attributes.connection_limit = None;
+    role.attributes.statement_timeout = None;
+    role.attributes.valid_until = None;
+    txn.update_role(role_id, role).unwrap();
+    txn.commit().await.unwrap();
+
+    let txn = state.transaction().await.unwrap();
+    assert_eq!(get_role(&txn).attributes, RoleAttributes::new());
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_update_roles() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let role_ids: Vec<_> = ["joe", "mike"]
+        .into_iter()
+        .map(|name| {
+            txn.insert_user_role(
+                name.to_string(),
+                RoleAttributes::new(),
+                RoleMembership::new(),
+                RoleVars::default(),
+            )
+            .unwrap()
+        })
+        .collect();
+    txn.commit().await.unwrap();
+
+    let get_roles = |txn: &Transaction| -> BTreeMap<_, _> {
+        txn.get_roles()
+            .filter(|role| role_ids.contains(&role.id))
+            .map(|role| (role.id, role))
+            .collect()
+    };
+    let with_connection_limit = |roles: &BTreeMap<RoleId, Role>, limit| -> BTreeMap<_, _> {
+        roles
+            .iter()
+            .map(|(id, role)| {
+                let mut role = role.clone();
+                role.attributes.connection_limit = Some(limit);
+                (*id, role)
+            })
+            .collect()
+    };
+
+    // Updating an unknown role fails.
+    let mut txn = state.transaction().await.unwrap();
+    let unknown_id = RoleId::User(u64::MAX);
+    let mut roles = with_connection_limit(&get_roles(&txn), 1);
+    let mut unknown = roles[&role_ids[0]].clone();
+    unknown.id = unknown_id;
+    roles.insert(unknown_id, unknown);
+    let err = txn.update_roles(roles).unwrap_err();
+    assert!(matches!(
+        err,
+        CatalogError::Catalog(SqlCatalogError::UnknownItem(id)) if id == unknown_id.to_string()
+    ));
+    drop(txn);
+
+    // Updating the same roles twice in one transaction keeps the last update.
+    let mut txn = state.transaction().await.unwrap();
+    let roles = get_roles(&txn);
+    txn.update_roles(with_connection_limit(&roles, 1)).unwrap();
+    txn.update_roles(with_connection_limit(&roles, 2)).unwrap();
+    assert_eq!(get_roles(&txn), with_connection_limit(&roles, 2));
+    txn.commit().await.unwrap();
+
+    let txn = state.transaction().await.unwrap();
+    let roles = get_roles(&txn);
+    assert_eq!(roles.len(), 2);
+    assert!(roles
+        .values()
+        .all(|role| role.attributes.connection_limit == Some(2)));
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_remove_default_privileges_for_role() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let (joe, mike, sally) = (RoleId::User(1), RoleId::User(2), RoleId::User(3));
+    let default_privileges = |txn: &Transaction| -> BTreeSet<_> {
+        txn.get_default_privileges()
+            .map(|privilege| (privilege.object.role_id, privilege.acl_item.grantee))
+            .filter(|(role_id, grantee)| role_id.is_user() || grantee.is_user())
+            .collect()
+    };
+
+    let mut txn = state.transaction().await.unwrap();
+    for (role_id, grantee) in [(joe, mike), (mike, joe), (mike, sally), (sally, sally)] {
+        txn.set_default_privilege(
+            role_id,
+            None,
+            None,
+            ObjectType::Table,
+            grantee,
+            Some(AclMode::SELECT),
+        )
+        .unwrap();
+    }
+    txn.commit().await.unwrap();
+
+    // Default privileges are removed whether the role defined them or was granted them.
+    let mut txn = state.transaction().await.unwrap();
+    let removed: BTreeSet<_> = txn
+        .remove_default_privileges_for_role(joe)
+        .into_iter()
+        .map(|privilege| (privilege.object.role_id, privilege.acl_item.grantee))
+        .collect();
+    assert_eq!(removed, BTreeSet::from([(joe, mike), (mike, joe)]));
+    txn.commit().await.unwrap();
+    let txn = state.transaction().await.unwrap();
+    assert_eq!(
+        default_privileges(&txn),
+        BTreeSet::from([(mike, sally), (sally, sally)])
+    );
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_connection_and_secret_default_privileges() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let (owner, grantee) = (RoleId::User(1), RoleId::User(2));
+    let database_id = DatabaseId::User(1);
+    let schema_id = SchemaId::User(1);
+    let default_privileges = |txn: &Transaction| -> BTreeSet<_> {
+        txn.get_default_privileges()
+            .filter(|privilege| privilege.object.role_id == owner)
+            .map(|privilege| {
+                (
+                    privilege.object.object_type,
+                    privilege.object.schema_id,
+                    privilege.acl_item.acl_mode,
+                )
+            })
+            .collect()
+    };
+
+    let mut txn = state.transaction().await.unwrap();
+    for object_type in [ObjectType::Connection, ObjectType::Secret] {
+        txn.set_default_privilege(
+            owner,
+            Some(database_id),
+            Some(schema_id),
+            object_type,
+            grantee,
+            Some(AclMode::USAGE),
+        )
+        .unwrap();
+    }
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    assert_eq!(
+        default_privileges(&txn),
+        BTreeSet::from([
+            (ObjectType::Connection, Some(schema_id), AclMode::USAGE),
+            (ObjectType::Secret, Some(schema_id), AclMode::USAGE),
+        ])
+    );
+    txn.set_default_privilege(
+        owner,
+        Some(database_id),
+        Some(schema_id),
+        ObjectType::Secret,
+        grantee,
+        None,
+    )
+    .unwrap();
+    txn.commit().await.unwrap();
+
+    let txn = state.transaction().await.unwrap();
+    assert_eq!(
+        default_privileges(&txn),
+        BTreeSet::from([(ObjectType::Connection, Some(schema_id), AclMode::USAGE)])
+    );
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_remove_clusters() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let logging = ReplicaLogging {
+        log_logging: false,
+        interval: None,
+    };
+    let cluster_id = ClusterId::User(u64::MAX);
+    let replica_id = ReplicaId::User(u64::MAX);
+    let index_id = GlobalId::System(u64::MAX);
+    let mut txn = state.transaction().await.unwrap();
+    txn.insert_user_cluster(
+        cluster_id,
+        "c",
+        None,
+        vec![(&MZ_DATAFLOW_OPERATORS_PER_WORKER, index_id)],
+        MZ_SYSTEM_ROLE_ID,
+        Vec::new(),
+        ClusterConfig {
+            variant: ClusterVariant::Unmanaged,
+            priority: ClusterPriority::default(),
+            memory_quota: None,
+            resize: None,
+        },
+    )
+    .unwrap();
+    txn.insert_cluster_replica(
+        cluster_id,
+        replica_id,
+        "r",
+        ReplicaConfig {
+            location: ReplicaLocation::Managed {
+                size: "1".to_string(),
+                availability_zone: None,
+                availability_zone_constraints: vec![],
+                disk: false,
+                internal: false,
+                billed_as: None,
+            },
+            logging,
+            idle_arrangement_merge_effort: None,
+        },
+        MZ_SYSTEM_ROLE_ID,
+    )
+    .unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    let unknown_id = ClusterId::User(u64::MAX - 1);
+    let err = txn
+        .remove_clusters(BTreeSet::from([cluster_id, unknown_id]))
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        CatalogError::Catalog(SqlCatalogError::UnknownCluster(id)) if id == unknown_id.to_string()
+    ));
+    drop(txn);
+
+    let mut txn = state.transaction().await.unwrap();
+    let removed = txn.remove_clusters(BTreeSet::from([cluster_id])).unwrap();
+    assert_eq!(
+        removed
+            .replicas
+            .into_iter()
+            .map(|replica| replica.replica_id)
+            .collect::<Vec<_>>(),
+        vec![replica_id]
+    );
+    assert_eq!(
+        removed
+            .introspection_source_indexes
+            .into_iter()
+            .map(|index| index.index_id)
+            .collect::<Vec<_>>(),
+        vec![index_id]
+    );
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    assert!(txn.get_clusters().all(|cluster| cluster.id != cluster_id));
+    assert!(txn
+        .get_cluster_replicas()
+        .all(|replica| replica.cluster_id != cluster_id));
+    assert!(txn.get_introspection_source_indexes(cluster_id).is_empty());
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_update_cluster_config() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let cluster_id = ClusterId::User(u64::MAX);
+    let mut txn = state.transaction().await.unwrap();
+    txn.insert_user_cluster(
+        cluster_id,
+        "c",
+        None,
+        Vec::new(),
+        MZ_SYSTEM_ROLE_ID,
+        Vec::new(),
+        ClusterConfig {
+            variant: ClusterVariant::Unmanaged,
+            priority: ClusterPriority::default(),
+            memory_quota: None,
+            resize: None,
+        },
+    )
+    .unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    let mut cluster = txn
+        .get_clusters()
+        .find(|cluster| cluster.id == cluster_id)
+        .unwrap();
+    cluster.config.priority = ClusterPriority::Low;
+    cluster.config.memory_quota = Some(1 << 30);
+    txn.update_cluster(cluster_id, cluster).unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    let cluster = txn
+        .get_clusters()
+        .find(|cluster| cluster.id == cluster_id)
+        .unwrap();
+    assert_eq!(cluster.config.priority, ClusterPriority::Low);
+    assert_eq!(cluster.config.memory_quota, Some(1 << 30));
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_update_clusters() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let cluster_ids = [ClusterId::User(u64::MAX - 1), ClusterId::User(u64::MAX)];
+    let replica_ids = [ReplicaId::User(u64::MAX - 1), ReplicaId::User(u64::MAX)];
+    let mut txn = state.transaction().await.unwrap();
+    for (i, (cluster_id, replica_id)) in cluster_ids.into_iter().zip(replica_ids).enumerate() {
+        txn.insert_user_cluster(
+            cluster_id,
+            &format!("c{i}"),
+            None,
+            Vec::new(),
+            MZ_SYSTEM_ROLE_ID,
+            Vec::new(),
+            ClusterConfig {
+                variant: ClusterVariant::Unmanaged,
+                priority: ClusterPriority::default(),
+                memory_quota: None,
+                resize: None,
+            },
+        )
+        .unwrap();
+        txn.insert_cluster_replica(
+            cluster_id,
+            replica_id,
+            "r",
+            ReplicaConfig {
+                location: ReplicaLocation::Managed {
+                    size: "1".to_string(),
+                    availability_zone: None,
+                    availability_zone_constraints: vec![],
+                    disk: false,
+                    internal: false,
+                    billed_as: None,
+                },
+                logging: ReplicaLogging {
+                    log_logging: false,
+                    interval: None,
+                },
+                idle_arrangement_merge_effort: None,
+            },
+            MZ_SYSTEM_ROLE_ID,
+        )
+        .unwrap();
+    }
+    txn.commit().await.unwrap();
+
+    let get_clusters = |txn: &Transaction| -> BTreeMap<_, _> {
+        txn.get_clusters()
+            .filter(|cluster| cluster_ids.contains(&cluster.id))
+            .map(|cluster| (cluster.id, cluster))
+            .collect()
+    };
+    let get_replicas = |txn: &Transaction| -> BTreeMap<_, _> {
+        txn.get_cluster_replicas()
+            .filter(|replica| replica_ids.contains(&replica.replica_id))
+            .map(|replica| (replica.replica_id, replica))
+            .collect()
+    };
+    let with_memory_quota = |clusters: &BTreeMap<ClusterId, Cluster>, quota| -> BTreeMap<_, _> {
+        clusters
+            .iter()
+            .map(|(id, cluster)| {
+                let mut cluster = cluster.clone();
+                cluster.config.memory_quota = Some(quota);
+                (*id, cluster)
+            })
+            .collect()
+    };
+    let with_name = |replicas: &BTreeMap<ReplicaId, ClusterReplica>, name: &str| {
+        replicas
+            .iter()
+            .map(|(id, replica)| {
+                let mut replica = replica.clone();
+                replica.name = name.to_string();
+                (*id, replica)
+            })
+            .collect::<BTreeMap<_, _>>()
+    };
+
+    // Updating an unknown cluster or replica fails.
+    let mut txn = state.transaction().await.unwrap();
+    let unknown_cluster_id = ClusterId::User(u64::MAX - 2);
+    let mut clusters = get_clusters(&txn);
+    let mut unknown_cluster = clusters[&cluster_ids[0]].clone();
+    unknown_cluster.id = unknown_cluster_id;
+    clusters.insert(unknown_cluster_id, unknown_cluster);
+    let err = txn.update_clusters(clusters).unwrap_err();
+    assert!(matches!(
+        err,
+        CatalogError::Catalog(SqlCatalogError::UnknownCluster(id))
+            if id == unknown_cluster_id.to_string()
+    ));
+    let unknown_replica_id = ReplicaId::User(u64::MAX - 2);
+    let mut replicas = get_replicas(&txn);
+    let mut unknown_replica = replicas[&replica_ids[0]].clone();
+    unknown_replica.replica_id = unknown_replica_id;
+    replicas.insert(unknown_replica_id, unknown_replica);
+    let err = txn.update_cluster_replicas(replicas).unwrap_err();
+    assert!(matches!(
+        err,
+        CatalogError::Catalog(SqlCatalogError::UnknownClusterReplica(id))
+            if id == unknown_replica_id.to_string()
+    ));
+    drop(txn);
+
+    // Updating the same clusters and replicas twice in one transaction keeps the last update.
+    let mut txn = state.transaction().await.unwrap();
+    let clusters = get_clusters(&txn);
+    txn.update_clusters(with_memory_quota(&clusters, 1 << 20))
+        .unwrap();
+    txn.update_clusters(with_memory_quota(&clusters, 1 << 30))
+        .unwrap();
+    assert_eq!(get_clusters(&txn), with_memory_quota(&clusters, 1 << 30));
+    let replicas = get_replicas(&txn);
+    txn.update_cluster_replicas(with_name(&replicas, "r1"))
+        .unwrap();
+    txn.update_cluster_replicas(with_name(&replicas, "r2"))
+        .unwrap();
+    assert_eq!(get_replicas(&txn), with_name(&replicas, "r2"));
+    txn.commit().await.unwrap();
+
+    let txn = state.transaction().await.unwrap();
+    let clusters = get_clusters(&txn);
+    assert_eq!(clusters.len(), 2);
+    assert!(clusters
+        .values()
+        .all(|cluster| cluster.config.memory_quota == Some(1 << 30)));
+    let replicas = get_replicas(&txn);
+    assert_eq!(replicas.len(), 2);
+    assert!(replicas.values().all(|replica| replica.name == "r2"));
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_update_cluster_resize() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let cluster_id = ClusterId::User(u64::MAX);
+    let managed = |size: &str| ClusterVariantManaged {
+        size: size.to_string(),
+        availability_zones: Vec::new(),
+        logging: ReplicaLogging {
+            log_logging: false,
+            interval: None,
+        },
+        idle_arrangement_merge_effort: None,
+        replication_factor: 1,
+        disk: false,
+        schedule: Vec::new(),
+    };
+    let mut txn = state.transaction().await.unwrap();
+    txn.insert_user_cluster(
+        cluster_id,
+        "c",
+        None,
+        Vec::new(),
+        MZ_SYSTEM_ROLE_ID,
+        Vec::new(),
+        ClusterConfig {
+            variant: ClusterVariant::Managed(managed("1")),
+            priority: ClusterPriority::default(),
+            memory_quota: None,
+            resize: None,
+        },
+    )
+    .unwrap();
+    txn.commit().await.unwrap();
+
+    let resize = ClusterResize {
+        old_replicas: BTreeSet::from([ReplicaId::User(1)]),
+        new_replicas: BTreeSet::from([ReplicaId::User(2)]),
+        cutover_frontier: 42.into(),
+        old_config: managed("1"),
+    };
+    let mut txn = state.transaction().await.unwrap();
+    let mut cluster = txn
+        .get_clusters()
+        .find(|cluster| cluster.id == cluster_id)
+        .unwrap();
+    cluster.config.variant = ClusterVariant::Managed(managed("2"));
+    cluster.config.resize = Some(resize.clone());
+    txn.update_cluster(cluster_id, cluster).unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    let cluster = txn
+        .get_clusters()
+        .find(|cluster| cluster.id == cluster_id)
+        .unwrap();
+    assert_eq!(
+        cluster.config.variant,
+        ClusterVariant::Managed(managed("2"))
+    );
+    assert_eq!(cluster.config.resize, Some(resize));
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_drop_cluster_comments() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let cluster_id = ClusterId::User(1);
+    let other_cluster_id = ClusterId::User(2);
+    let replica = CommentObjectId::ClusterReplica((cluster_id, ReplicaId::User(1)));
+    let other_replica = CommentObjectId::ClusterReplica((other_cluster_id, ReplicaId::User(2)));
+    let mut txn = state.transaction().await.unwrap();
+    txn.update_comment(
+        CommentObjectId::Cluster(cluster_id),
+        None,
+        Some("c".to_string()),
+    )
+    .unwrap();
+    txn.update_comment(replica, None, Some("r".to_string()))
+        .unwrap();
+    txn.update_comment(other_replica, None, Some("o".to_string()))
+        .unwrap();
+    txn.commit().await.unwrap();
+
+    // Dropping the comments on a replica leaves its cluster's comments.
+    let mut txn = state.transaction().await.unwrap();
+    assert_eq!(
+        txn.drop_comments(replica).unwrap(),
+        vec![(replica, None, "r".to_string())]
+    );
+    drop(txn);
+
+    // Dropping the comments on a cluster drops the comments on its replicas.
+    let mut txn = state.transaction().await.unwrap();
+    assert_eq!(
+        txn.drop_comments(CommentObjectId::Cluster(cluster_id))
+            .unwrap(),
+        vec![
+            (CommentObjectId::Cluster(cluster_id), None, "c".to_string()),
+            (replica, None, "r".to_string()),
+        ]
+    );
+    txn.commit().await.unwrap();
+
+    let txn = state.transaction().await.unwrap();
+    assert_eq!(
+        txn.get_comments()
+            .map(|comment| comment.object_id)
+            .collect::<Vec<_>>(),
+        vec![other_replica]
+    );
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_upsert_system_config() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    for (name, value) in [
+        ("enable_rbac_checks", "maybe"),
+        ("max_tables", "-1"),
+        ("max_tables", "many"),
+        ("no_such_parameter", "1"),
+    ] {
+        let err = txn
+            .upsert_system_config(name, value.to_string())
+            .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                CatalogError::Durable(DurableCatalogError::InvalidSystemConfiguration(_))
+            ),
+            "{name}={value}: {err:?}"
+        );
+    }
+    txn.upsert_system_config("enable_rbac_checks", "off".to_string())
+        .unwrap();
+    txn.upsert_system_config("max_tables", "42".to_string())
+        .unwrap();
+    txn.commit().await.unwrap();
+
+    let txn = state.transaction().await.unwrap();
+    let configs: Vec<_> = txn
+        .get_system_configurations()
+        .filter(|config| config.name == "enable_rbac_checks" || config.name == "max_tables")
+        .map(|config| (config.name, config.value))
+        .collect();
+    assert_eq!(
+        configs,
+        vec![
+            ("enable_rbac_checks".to_string(), "off".to_string()),
+            ("max_tables".to_string(), "42".to_string()),
+        ]
+    );
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_expected_epoch() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let epoch = state.epoch();
+    let comment = CommentObjectId::Cluster(ClusterId::User(1));
+
+    // A transaction expecting a different epoch is fenced without writing anything.
+    let mut txn = state.transaction().await.unwrap();
+    txn.set_expected_epoch(Epoch::new(epoch.get() + 1).expect("known to be non-zero"));
+    txn.update_comment(comment, None, Some("c".to_string()))
+        .unwrap();
+    let err = txn.commit().await.unwrap_err();
+    assert!(
+        matches!(err, CatalogError::Durable(DurableCatalogError::Fence(_))),
+        "unexpected err: {err:?}"
+    );
+    let txn = state.transaction().await.unwrap();
+    assert_eq!(txn.get_comments().count(), 0);
+    drop(txn);
+
+    let mut txn = state.transaction().await.unwrap();
+    txn.set_expected_epoch(epoch);
+    txn.update_comment(comment, None, Some("c".to_string()))
+        .unwrap();
+    txn.commit().await.unwrap();
+    let txn = state.transaction().await.unwrap();
+    assert_eq!(txn.get_comments().count(), 1);
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_stash_expected_epoch_fenced() {
+    let debug_factory = DebugStashFactory::new().await;
+    let openable_state1 = test_stash_backed_catalog_state(&debug_factory);
+    let openable_state2 = test_stash_backed_catalog_state(&debug_factory);
+    test_expected_epoch_fenced(openable_state1, openable_state2).await;
+    debug_factory.drop().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_expected_epoch_fenced() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state1 =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    let openable_state2 = test_persist_backed_catalog_state(persist_client, organization_id).await;
+    test_expected_epoch_fenced(openable_state1, openable_state2).await;
+}
+
+async fn test_expected_epoch_fenced(
+    openable_state1: impl OpenableDurableCatalogState,
+    openable_state2: impl OpenableDurableCatalogState,
+) {
+    let comment = CommentObjectId::Cluster(ClusterId::User(1));
+    let mut state1 = Box::new(openable_state1)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let epoch1 = state1.epoch();
+
+    // Start a transaction in the first catalog, then open the second catalog before committing it.
+    let mut txn = state1.transaction().await.unwrap();
+    txn.set_expected_epoch(epoch1);
+    txn.update_comment(comment, None, Some("first".to_string()))
+        .unwrap();
+    let mut state2 = Box::new(openable_state2)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let epoch2 = state2.epoch();
+    assert!(epoch2 > epoch1);
+
+    // The first catalog has been fenced out.
+    let err = txn.commit().await.unwrap_err();
+    assert!(
+        matches!(err, CatalogError::Durable(DurableCatalogError::Fence(_))),
+        "unexpected err: {err:?}"
+    );
+
+    // The second catalog does not accept transactions that expect the first catalog's epoch.
+    let mut txn = state2.transaction().await.unwrap();
+    assert_eq!(txn.get_comments().count(), 0);
+    txn.set_expected_epoch(epoch1);
+    txn.update_comment(comment, None, Some("second".to_string()))
+        .unwrap();
+    let err = txn.commit().await.unwrap_err();
+    assert!(
+        matches!(err, CatalogError::Durable(DurableCatalogError::Fence(_))),
+        "unexpected err: {err:?}"
+    );
+
+    let mut txn = state2.transaction().await.unwrap();
+    txn.set_expected_epoch(epoch2);
+    txn.update_comment(comment, None, Some("second".to_string()))
+        .unwrap();
+    txn.commit().await.unwrap();
+    let txn = state2.transaction().await.unwrap();
+    let comments: Vec<_> = txn.get_comments().map(|comment| comment.comment).collect();
+    assert_eq!(comments, vec!["second".to_string()]);
+    drop(txn);
+
+    Box::new(state1).expire().await;
+    Box::new(state2).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_migrations() {
+    fn comment_cluster(tx: &mut Transaction<'_>, id: u64, comment: Option<&str>) {
+        tx.update_comment(
+            CommentObjectId::Cluster(ClusterId::User(id)),
+            None,
+            comment.map(|comment| comment.to_string()),
+        )
+        .unwrap();
+    }
+    fn comments(tx: &Transaction<'_>) -> Vec<String> {
+        tx.get_comments().map(|comment| comment.comment).collect()
+    }
+
+    let migrations = Migrations::new([
+        Migration {
+            version: 1,
+            name: "comment_one",
+            up: |tx| {
+                comment_cluster(tx, 1, Some("one"));
+                Ok(())
+            },
+            down: |tx| {
+                comment_cluster(tx, 1, None);
+                Ok(())
+            },
+        },
+        Migration {
+            version: 2,
+            name: "comment_two",
+            up: |tx| {
+                comment_cluster(tx, 2, Some("two"));
+                Ok(())
+            },
+            down: |tx| {
+                comment_cluster(tx, 2, None);
+                Ok(())
+            },
+        },
+    ]);
+    let failing_migrations = Migrations::new([Migration {
+        version: 3,
+        name: "fail",
+        up: |tx| {
+            comment_cluster(tx, 3, Some("three"));
+            Err(SqlCatalogError::UnknownCluster("fail".to_string()).into())
+        },
+        down: |_tx| Ok(()),
+    }]);
+
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+
+    // A dry run applies nothing.
+    let mut txn = state.transaction().await.unwrap();
+    assert_eq!(migrations.pending(&txn).len(), 2);
+    assert_eq!(migrations.migrate(&mut txn, 10, true).unwrap(), vec![1, 2]);
+    assert_eq!(migrations.pending(&txn).len(), 2);
+    assert!(comments(&txn).is_empty());
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    assert_eq!(migrations.migrate(&mut txn, 10, false).unwrap(), vec![1, 2]);
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    assert_eq!(comments(&txn), vec!["one", "two"]);
+    assert_eq!(
+        txn.get_applied_migrations()
+            .into_values()
+            .collect::<Vec<_>>(),
+        vec![
+            AppliedMigration {
+                version: 1,
+                name: "comment_one".to_string(),
+                applied_at: 10,
+            },
+            AppliedMigration {
+                version: 2,
+                name: "comment_two".to_string(),
+                applied_at: 10,
+            },
+        ]
+    );
+    // Applied migrations are not applied again.
+    assert!(migrations.pending(&txn).is_empty());
+    assert_eq!(
+        migrations.migrate(&mut txn, 20, false).unwrap(),
+        Vec::<u64>::new()
+    );
+
+    // A failed migration leaves the transaction unchanged.
+    failing_migrations.migrate(&mut txn, 20, false).unwrap_err();
+    assert_eq!(comments(&txn), vec!["one", "two"]);
+    assert_eq!(txn.get_applied_migrations().len(), 2);
+    // Migrations that aren't registered can't be rolled back.
+    let err = failing_migrations.rollback(&mut txn, 0, false).unwrap_err();
+    assert!(
+        matches!(
+            err,
+            CatalogError::Durable(DurableCatalogError::UnknownMigration(2))
+        ),
+        "unexpected err: {err:?}"
+    );
+
+    assert_eq!(migrations.rollback(&mut txn, 1, true).unwrap(), vec![2]);
+    assert_eq!(comments(&txn), vec!["one", "two"]);
+    assert_eq!(migrations.rollback(&mut txn, 0, false).unwrap(), vec![2, 1]);
+    txn.commit().await.unwrap();
+
+    let txn = state.transaction().await.unwrap();
+    assert!(comments(&txn).is_empty());
+    assert!(txn.get_applied_migrations().is_empty());
+    assert_eq!(migrations.pending(&txn).len(), 2);
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_pending_updates() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), Uuid::new_v4()).await;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    assert!(txn.pending_updates().values().all(|count| *count == 0));
+    let batch = txn.validate().unwrap();
+    assert!(batch.update_counts().values().all(|count| *count == 0));
+    assert_eq!(batch.encoded_len(), 0);
+
+    let role_id = txn
+        .insert_user_role(
+            "joe".to_string(),
+            RoleAttributes::new(),
+            RoleMembership::new(),
+            RoleVars::default(),
+        )
+        .unwrap();
+    assert_eq!(txn.pending_updates()["roles"], 1);
+    let batch = txn.validate().unwrap();
+    assert_eq!(batch.update_counts()["roles"], 1);
+    assert!(batch.encoded_len() > 0);
+    txn.commit().await.unwrap();
+
+    // Updating an existing role retracts the old value and inserts the new one.
+    let mut txn = state.transaction().await.unwrap();
+    let mut role = txn
+        .get_roles()
+        .find(|role| role.id == role_id)
+        .expect("role exists");
+    role.name = "mike".to_string();
+    txn.update_role(role_id, role).unwrap();
+    assert_eq!(txn.pending_updates()["roles"], 1);
+    assert_eq!(txn.validate().unwrap().update_counts()["roles"], 2);
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_stash_allocate_id() {
+    let debug_factory = DebugStashFactory::new().await;
+    let openable_state = test_stash_backed_catalog_state(&debug_factory);
+    test_allocate_id(openable_state).await;
+    debug_factory.drop().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_allocate_id() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_allocate_id(openable_state).await;
+}
+
+async fn test_allocate_id(openable_state: impl OpenableDurableCatalogState) {
+    let id_type = USER_ITEM_ALLOC_KEY;
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+
+    let start_id = state.get_next_id(id_type).await.unwrap();
+    let ids = state.allocate_id(id_type, 3).await.unwrap();
+    assert_eq!(ids, (start_id..(start_id + 3)).collect::<Vec<_>>());
+
+    let snapshot_id_allocs: Vec<_> = state
+        .snapshot()
+        .await
+        .unwrap()
+        .id_allocator
+        .into_iter()
+        .map(RustType::from_proto)
+        .map_ok(|(k, v)| IdAlloc::from_key_value(k, v))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert!(snapshot_id_allocs.contains(&IdAlloc {
+        name: id_type.to_string(),
+        next_id: start_id + 3,
+    }));
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_stash_audit_logs() {
+    let debug_factory = DebugStashFactory::new().await;
+    let openable_state = test_stash_backed_catalog_state(&debug_factory);
+    test_audit_logs(openable_state).await;
+    debug_factory.drop().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_audit_logs() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_audit_logs(openable_state).await;
+}
+
+async fn test_audit_logs(openable_state: impl OpenableDurableCatalogState) {
+    let audit_logs = [
+        VersionedEvent::V1(EventV1 {
+            id: 100,
+            event_type: EventType::Create,
+            object_type: mz_audit_log::ObjectType::ClusterReplica,
+            details: EventDetails::CreateClusterReplicaV1(CreateClusterReplicaV1 {
+                cluster_id: "1".to_string(),
+                cluster_name: "foo".to_string(),
+                replica_id: Some("1".to_string()),
+                replica_name: "bar".to_string(),
+                logical_size: "1".to_string(),
+                disk: false,
+                billed_as: None,
+                internal: false,
+            }),
+            user: Some("joe".to_string()),
+            occurred_at: 100,
+        }),
+        VersionedEvent::V1(EventV1 {
+            id: 200,
+            event_type: EventType::Drop,
+            object_type: mz_audit_log::ObjectType::View,
+            details: EventDetails::IdNameV1(IdNameV1 {
+                id: "2".to_string(),
+                name: "v".to_string(),
+            }),
+            user: Some("mike".to_string()),
+            occurred_at: 200,
+        }),
+    ];
+
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    for audit_log in &audit_logs {
+        txn.insert_audit_log_event(audit_log.clone());
+    }
+    txn.commit().await.unwrap();
+
+    let persisted_audit_logs = state.get_audit_logs().await.unwrap();
+    for audit_log in &audit_logs {
+        assert!(persisted_audit_logs.contains(audit_log));
+    }
+
+    for (filter, expected) in [
+        (
+            AuditLogFilter {
+                start: Some(150),
+                ..Default::default()
+            },
+            &audit_logs[1..],
+        ),
+        (
+            AuditLogFilter {
+                end: Some(150),
+                ..Default::default()
+            },
+            &audit_logs[..1],
+        ),
+        (
+            AuditLogFilter {
+                user: Some("mike".to_string()),
+                ..Default::default()
+            },
+            &audit_logs[1..],
+        ),
+        (
+            AuditLogFilter {
+                object_id: Some("1".to_string()),
+                ..Default::default()
+            },
+            &audit_logs[..1],
+        ),
+        (
+            AuditLogFilter {
+                user: Some("joe".to_string()),
+                object_id: Some("2".to_string()),
+                ..Default::default()
+            },
+            &audit_logs[..0],
+        ),
+    ] {
+        let filtered = state.get_audit_logs_filtered(&filter).await.unwrap();
+        assert_eq!(filtered, expected, "filter: {filter:?}");
+    }
+
+    // Page through the events one at a time.
+    let mut filter = AuditLogFilter {
+        limit: Some(1),
+        ..Default::default()
+    };
+    let mut pages = Vec::new();
+    loop {
+        let page = state.get_audit_logs_filtered(&filter).await.unwrap();
+        let Some(last) = page.last() else {
+            break;
+        };
+        assert_eq!(page.len(), 1);
+        filter.after_id = Some(last.sortable_id());
+        pages.extend(page);
+    }
+    assert_eq!(pages, audit_logs);
+
+    let mut txn = state.transaction().await.unwrap();
+    let pruned = txn.prune_audit_log(150, None).await.unwrap();
+    assert_eq!(pruned, vec![audit_logs[0].clone()]);
+    txn.commit().await.unwrap();
+    let persisted_audit_logs = state.get_audit_logs().await.unwrap();
+    assert!(!persisted_audit_logs.contains(&audit_logs[0]));
+    assert!(persisted_audit_logs.contains(&audit_logs[1]));
+
+    let mut txn = state.transaction().await.unwrap();
+    let pruned = txn.prune_audit_log(0, Some(0)).await.unwrap();
+    assert_eq!(pruned, persisted_audit_logs);
+    txn.commit().await.unwrap();
+    assert!(state.get_audit_logs().await.unwrap().is_empty());
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_stash_items() {
+    let debug_factory = DebugStashFactory::new().await;
+    let openable_state = test_stash_backed_catalog_state(&debug_factory);
+    test_items(openable_state).await;
+    debug_factory.drop().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_items() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_items(openable_state).await;
+}
+
+async fn test_items(openable_state: impl OpenableDurableCatalogState) {
+    let items = [
+        Item {
+            id: GlobalId::User(100),
+            schema_id: SchemaId::User(1),
+            name: "foo".to_string(),
+            create_sql: "CREATE VIEW v AS SELECT 1".to_string(),
+            owner_id: RoleId::User(1),
+            privileges: vec![],
+        },
+        Item {
+            id: GlobalId::User(200),
+            schema_id: SchemaId::User(1),
+            name: "bar".to_string(),
+            create_sql: "CREATE MATERIALIZED VIEW mv AS SELECT 2".to_string(),
+            owner_id: RoleId::User(2),
+            privileges: vec![],
+        },
+    ];
+
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    for item in &items {
+        txn.insert_item(
+            item.id,
+            item.schema_id,
+            &item.name,
+            item.create_sql.clone(),
+            item.owner_id,
+            item.privileges.clone(),
+        )
+        .unwrap();
+    }
+    txn.commit().await.unwrap();
+
+    let snapshot_items: Vec<_> = state
+        .snapshot()
+        .await
+        .unwrap()
+        .items
+        .into_iter()
+        .map(RustType::from_proto)
+        .map_ok(|(k, v)| Item::from_key_value(k, v))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    for item in &items {
         assert!(snapshot_items.contains(item));
     }
     Box::new(state).expire().await;
 }
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_stash_undrop_item() {
+    let debug_factory = DebugStashFactory::new().await;
+    let openable_state = test_stash_backed_catalog_state(&debug_factory);
+    test_undrop_item(openable_state).await;
+    debug_factory.drop().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_undrop_item() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_undrop_item(openable_state).await;
+}
+
+async fn test_undrop_item(openable_state: impl OpenableDurableCatalogState) {
+    let view = Item {
+        id: GlobalId::User(100),
+        schema_id: SchemaId::User(3),
+        name: "v".to_string(),
+        create_sql: "CREATE VIEW v AS SELECT 1".to_string(),
+        owner_id: RoleId::User(1),
+        privileges: vec![],
+    };
+    let insert_view = |txn: &mut Transaction| {
+        txn.insert_item(
+            view.id,
+            view.schema_id,
+            &view.name,
+            view.create_sql.clone(),
+            view.owner_id,
+            view.privileges.clone(),
+        )
+        .unwrap();
+    };
+    let trashed_view = |dropped_at| TrashedItem {
+        item: view.clone(),
+        dropped_at,
+    };
+
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let owner_id = txn
+        .insert_user_role(
+            "joe".to_string(),
+            RoleAttributes::new(),
+            RoleMembership::new(),
+            RoleVars::default(),
+        )
+        .unwrap();
+    assert_eq!(owner_id, view.owner_id);
+    // Without a retention, dropped items are removed immediately.
+    assert_eq!(txn.get_item_trash_retention(), None);
+    insert_view(&mut txn);
+    txn.drop_item(view.id, 1).unwrap();
+    assert_eq!(txn.get_trashed_items().count(), 0);
+    let err = txn.undrop_item(view.id).unwrap_err();
+    assert!(
+        matches!(err, CatalogError::Catalog(SqlCatalogError::UnknownItem(_))),
+        "unexpected err: {err:?}"
+    );
+
+    txn.set_item_trash_retention(Some(Duration::from_millis(10)))
+        .unwrap();
+    insert_view(&mut txn);
+    txn.drop_item(view.id, 1).unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    assert_eq!(
+        txn.get_item_trash_retention(),
+        Some(Duration::from_millis(10))
+    );
+    assert_eq!(
+        txn.get_trashed_items().collect::<Vec<_>>(),
+        vec![trashed_view(1)]
+    );
+    assert!(txn
+        .get_items_filtered(|_k, v| v.name() == view.name)
+        .is_empty());
+    assert_eq!(txn.undrop_item(view.id).unwrap(), view);
+    assert_eq!(txn.get_trashed_items().count(), 0);
+    assert_eq!(
+        txn.get_items_filtered(|_k, v| v.name() == view.name),
+        vec![view.clone()]
+    );
+
+    // Items can't be restored over an item with the same name.
+    txn.drop_item(view.id, 5).unwrap();
+    txn.insert_item(
+        GlobalId::User(101),
+        view.schema_id,
+        &view.name,
+        view.create_sql.clone(),
+        view.owner_id,
+        view.privileges.clone(),
+    )
+    .unwrap();
+    let err = txn.undrop_item(view.id).unwrap_err();
+    assert!(
+        matches!(
+            err,
+            CatalogError::Catalog(SqlCatalogError::ItemAlreadyExists(..))
+        ),
+        "unexpected err: {err:?}"
+    );
+    assert_eq!(
+        txn.get_trashed_items().collect::<Vec<_>>(),
+        vec![trashed_view(5)]
+    );
+
+    // Items are purged once they have been in the trash for the retention, which the vacuum
+    // takes care of.
+    assert_eq!(txn.purge_item_trash(14), Vec::new());
+    let report = txn
+        .vacuum(VacuumOptions {
+            item_trash_purge_ts: Some(15),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(report.purged_trashed_items, vec![trashed_view(5)]);
+    assert!(report.reclaimed_bytes > 0);
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    assert_eq!(txn.get_trashed_items().count(), 0);
+
+    // Items can't be restored once their owner has been dropped.
+    txn.remove_item(GlobalId::User(101)).unwrap();
+    insert_view(&mut txn);
+    txn.drop_item(view.id, 20).unwrap();
+    txn.remove_role("joe").unwrap();
+    let err = txn.undrop_item(view.id).unwrap_err();
+    assert!(
+        matches!(err, CatalogError::Catalog(SqlCatalogError::UnknownRole(_))),
+        "unexpected err: {err:?}"
+    );
+    assert_eq!(
+        txn.get_trashed_items().collect::<Vec<_>>(),
+        vec![trashed_view(20)]
+    );
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_stash_column_masks() {
+    let debug_factory = DebugStashFactory::new().await;
+    let openable_state = test_stash_backed_catalog_state(&debug_factory);
+    test_column_masks(openable_state).await;
+    debug_factory.drop().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_column_masks() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_column_masks(openable_state).await;
+}
+
+async fn test_column_masks(openable_state: impl OpenableDurableCatalogState) {
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let table_id = GlobalId::User(100);
+    txn.insert_item(
+        table_id,
+        SchemaId::User(3),
+        "t",
+        "CREATE TABLE t (a int, b text)".to_string(),
+        RoleId::User(1),
+        vec![],
+    )
+    .unwrap();
+    let mut insert_role = |name: &str| {
+        txn.insert_user_role(
+            name.to_string(),
+            RoleAttributes::new(),
+            RoleMembership::new(),
+            RoleVars::default(),
+        )
+        .unwrap()
+    };
+    let joe = insert_role("joe");
+    let mike = insert_role("mike");
+    let mask = |column: &str, role_id, expr: &str| ColumnMask {
+        item_id: table_id,
+        column: column.to_string(),
+        role_id,
+        expr: expr.to_string(),
+    };
+
+    assert_eq!(
+        txn.set_column_mask(table_id, "a".to_string(), joe, Some("0".to_string()))
+            .unwrap(),
+        None
+    );
+    assert_eq!(
+        txn.set_column_mask(table_id, "a".to_string(), joe, Some("-1".to_string()))
+            .unwrap(),
+        Some("0".to_string())
+    );
+    txn.set_column_mask(table_id, "a".to_string(), mike, Some("1".to_string()))
+        .unwrap();
+    txn.set_column_mask(table_id, "b".to_string(), mike, Some("'x'".to_string()))
+        .unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    assert_eq!(
+        txn.get_column_masks().collect::<Vec<_>>(),
+        vec![
+            mask("a", joe, "-1"),
+            mask("a", mike, "1"),
+            mask("b", mike, "'x'"),
+        ]
+    );
+
+    // Removing the masks of a column removes them for all roles.
+    assert_eq!(
+        txn.remove_column_masks(table_id, "a"),
+        vec![mask("a", joe, "-1"), mask("a", mike, "1")]
+    );
+    txn.set_column_mask(table_id, "a".to_string(), joe, Some("0".to_string()))
+        .unwrap();
+
+    // Dropping a role drops its masks.
+    txn.remove_role("mike").unwrap();
+    assert_eq!(
+        txn.get_column_masks().collect::<Vec<_>>(),
+        vec![mask("a", joe, "0")]
+    );
+
+    // Dropping an item drops its masks.
+    txn.remove_item(table_id).unwrap();
+    assert_eq!(txn.get_column_masks().count(), 0);
+    txn.commit().await.unwrap();
+
+    let txn = state.transaction().await.unwrap();
+    assert_eq!(txn.get_column_masks().count(), 0);
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_stash_service_accounts() {
+    let debug_factory = DebugStashFactory::new().await;
+    let openable_state = test_stash_backed_catalog_state(&debug_factory);
+    test_service_accounts(openable_state).await;
+    debug_factory.drop().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_service_accounts() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_service_accounts(openable_state).await;
+}
+
+async fn test_service_accounts(openable_state: impl OpenableDurableCatalogState) {
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let mut txn = state.transaction().await.unwrap();
+    let mut insert_secret = |id, name: &str| {
+        txn.insert_item(
+            id,
+            SchemaId::User(3),
+            name,
+            format!("CREATE SECRET {name} AS '********'"),
+            RoleId::User(1),
+            vec![],
+        )
+        .unwrap();
+    };
+    let secret1 = GlobalId::User(100);
+    let secret2 = GlobalId::User(101);
+    insert_secret(secret1, "s1");
+    insert_secret(secret2, "s2");
+    let mut insert_role = |name: &str| {
+        txn.insert_user_role(
+            name.to_string(),
+            RoleAttributes::new(),
+            RoleMembership::new(),
+            RoleVars::default(),
+        )
+        .unwrap()
+    };
+    let joe = insert_role("joe");
+    let mike = insert_role("mike");
+    let service_account = |id, name: &str, role_id, token_secret_id| ServiceAccount {
+        id,
+        name: name.to_string(),
+        role_id,
+        token_secret_id,
+        scopes: vec!["read".to_string()],
+        expires_at: Some(1_000),
+    };
+    let mut insert_service_account = |name: &str, role_id, token_secret_id| {
+        txn.insert_service_account(
+            name.to_string(),
+            role_id,
+            token_secret_id,
+            vec!["read".to_string()],
+            Some(1_000),
+        )
+    };
+
+    assert_eq!(insert_service_account("a", joe, secret1).unwrap(), 1);
+    assert_eq!(insert_service_account("b", mike, secret1).unwrap(), 2);
+    assert_eq!(insert_service_account("c", joe, secret2).unwrap(), 3);
+    let err = insert_service_account("a", mike, secret2).unwrap_err();
+    assert!(
+        matches!(
+            &err,
+            CatalogError::Catalog(SqlCatalogError::ServiceAccountAlreadyExists(name))
+                if name == "a"
+        ),
+        "unexpected err: {err:?}"
+    );
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    assert_eq!(
+        txn.get_service_accounts().collect::<Vec<_>>(),
+        vec![
+            service_account(1, "a", joe, secret1),
+            service_account(2, "b", mike, secret1),
+            service_account(3, "c", joe, secret2),
+        ]
+    );
+
+    assert_eq!(
+        txn.remove_service_account("a").unwrap(),
+        service_account(1, "a", joe, secret1)
+    );
+    let err = txn.remove_service_account("a").unwrap_err();
+    assert!(
+        matches!(
+            &err,
+            CatalogError::Catalog(SqlCatalogError::UnknownServiceAccount(name)) if name == "a"
+        ),
+        "unexpected err: {err:?}"
+    );
+
+    // Dropping a role drops the service accounts that authenticate as it.
+    txn.remove_role("mike").unwrap();
+    assert_eq!(
+        txn.get_service_accounts().collect::<Vec<_>>(),
+        vec![service_account(3, "c", joe, secret2)]
+    );
+
+    // Dropping a secret drops the service accounts whose token it stores.
+    txn.remove_item(secret2).unwrap();
+    assert_eq!(txn.get_service_accounts().count(), 0);
+    txn.commit().await.unwrap();
+
+    let txn = state.transaction().await.unwrap();
+    assert_eq!(txn.get_service_accounts().count(), 0);
+    drop(txn);
+    Box::new(state).expire().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_stash_cluster_replica_sizes() {
+    let debug_factory = DebugStashFactory::new().await;
+    let openable_state = test_stash_backed_catalog_state(&debug_factory);
+    test_cluster_replica_sizes(openable_state).await;
+    debug_factory.drop().await;
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_persist_cluster_replica_sizes() {
+    let persist_client = PersistClient::new_for_tests().await;
+    let organization_id = Uuid::new_v4();
+    let openable_state =
+        test_persist_backed_catalog_state(persist_client.clone(), organization_id).await;
+    test_cluster_replica_sizes(openable_state).await;
+}
+
+async fn test_cluster_replica_sizes(openable_state: impl OpenableDurableCatalogState) {
+    let mut state = Box::new(openable_state)
+        .open(SYSTEM_TIME(), &test_bootstrap_args(), None)
+        .await
+        .unwrap();
+    let size = |name: &str, workers| ClusterReplicaSize {
+        name: name.to_string(),
+        memory_limit: Some(4 << 30),
+        cpu_limit: Some(1_000),
+        disk_limit: None,
+        scale: 1,
+        workers,
+        credits_per_hour: "0.5".to_string(),
+        disabled: false,
+    };
+
+    let mut txn = state.transaction().await.unwrap();
+    txn.insert_cluster_replica_size(size("small", 1)).unwrap();
+    txn.insert_cluster_replica_size(size("large", 4)).unwrap();
+    let err = txn
+        .insert_cluster_replica_size(size("small", 2))
+        .unwrap_err();
+    assert!(
+        matches!(
+            &err,
+            CatalogError::Catalog(SqlCatalogError::ClusterReplicaSizeAlreadyExists(name))
+                if name == "small"
+        ),
+        "unexpected err: {err:?}"
+    );
+    txn.commit().await.unwrap();
+
+    let mut txn = state.transaction().await.unwrap();
+    assert_eq!(
+        txn.get_cluster_replica_sizes().collect::<Vec<_>>(),
+        vec![size("large", 4), size("small", 1)]
+    );
+    assert_eq!(
+        txn.remove_cluster_replica_size("small").unwrap(),
+        size("small", 1)
+    );
+    let err = txn.remove_cluster_replica_size("small").unwrap_err();
+    assert!(
+        matches!(
+            &err,
+            CatalogError::Catalog(SqlCatalogError::UnknownClusterReplicaSize(name))
+                if name == "small"
+        ),
+        "unexpected err: {err:?}"
+    );
+    txn.commit().await.unwrap();
+
+    let txn = state.transaction().await.unwrap();
+    assert_eq!(
+        txn.get_cluster_replica_sizes().collect::<Vec<_>>(),
+        vec![size("large", 4)]
+    );
+    drop(txn);
+    Box::new(state).expire().await;
+}
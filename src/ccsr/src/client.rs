@@ -10,20 +10,24 @@
 use std::collections::BTreeSet;
 use std::error::Error;
 use std::fmt;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use anyhow::bail;
 use reqwest::{Method, Url};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
-use crate::config::Auth;
+use crate::config::{Auth, Oauth};
 
 /// An API client for a Confluent-compatible schema registry.
 pub struct Client {
     inner: reqwest::Client,
     url: Arc<dyn Fn() -> Url + Send + Sync + 'static>,
     auth: Option<Auth>,
+    oauth: Option<Oauth>,
+    /// The most recently obtained OAuth access token, if any.
+    oauth_token: Mutex<Option<OauthToken>>,
 }
 
 impl fmt::Debug for Client {
@@ -32,6 +36,7 @@ impl fmt::Debug for Client {
             .field("inner", &self.inner)
             .field("url", &"...")
             .field("auth", &self.auth)
+            .field("oauth", &self.oauth)
             .finish()
     }
 }
@@ -41,14 +46,25 @@ impl Client {
         inner: reqwest::Client,
         url: Arc<dyn Fn() -> Url + Send + Sync + 'static>,
         auth: Option<Auth>,
+        oauth: Option<Oauth>,
     ) -> Result<Self, anyhow::Error> {
         if url().cannot_be_a_base() {
             bail!("cannot construct a CCSR client with a cannot-be-a-base URL");
         }
-        Ok(Client { inner, url, auth })
+        Ok(Client {
+            inner,
+            url,
+            auth,
+            oauth,
+            oauth_token: Mutex::new(None),
+        })
     }
 
-    fn make_request<P>(&self, method: Method, path: P) -> reqwest::RequestBuilder
+    async fn make_request<P>(
+        &self,
+        method: Method,
+        path: P,
+    ) -> Result<reqwest::RequestBuilder, UnhandledError>
     where
         P: IntoIterator,
         P::Item: AsRef<str>,
@@ -63,12 +79,74 @@ impl Client {
         if let Some(auth) = &self.auth {
             request = request.basic_auth(&auth.username, auth.password.as_ref());
         }
-        request
+        if let Some(oauth) = &self.oauth {
+            let token = self.oauth_token(oauth).await?;
+            request = request.bearer_auth(token);
+        }
+        Ok(request)
+    }
+
+    /// Returns an OAuth access token, requesting a new one from the token
+    /// endpoint if no token has been obtained yet or the last one is due to be
+    /// refreshed.
+    async fn oauth_token(&self, oauth: &Oauth) -> Result<String, UnhandledError> {
+        if let Some(token) = &*self.oauth_token.lock().expect("lock poisoned") {
+            if Instant::now() < token.refresh_at {
+                return Ok(token.access_token.clone());
+            }
+        }
+
+        let mut form = vec![("grant_type", "client_credentials")];
+        if let Some(scope) = &oauth.scope {
+            form.push(("scope", scope.as_str()));
+        }
+        let res = self
+            .inner
+            .post(oauth.token_endpoint.clone())
+            .basic_auth(&oauth.client_id, Some(&oauth.client_secret))
+            .form(&form)
+            .send()
+            .await?;
+        let status = res.status();
+        if !status.is_success() {
+            return Err(UnhandledError::Api {
+                code: i32::from(status.as_u16()),
+                message: "unable to obtain OAuth access token".into(),
+            });
+        }
+        let res: TokenResponse = res.json().await?;
+
+        // Refresh tokens halfway through their lifetime, so that requests
+        // issued just before a token expires don't race its expiry. Token
+        // endpoints are not required to report a lifetime, in which case we
+        // assume the common default of one hour.
+        let lifetime = Duration::from_secs(res.expires_in.unwrap_or(3600));
+        let token = OauthToken {
+            access_token: res.access_token,
+            refresh_at: Instant::now() + lifetime / 2,
+        };
+        let access_token = token.access_token.clone();
+        *self.oauth_token.lock().expect("lock poisoned") = Some(token);
+        Ok(access_token)
+    }
+
+    /// Obtains an OAuth access token, if the client is configured to use OAuth
+    /// authentication.
+    ///
+    /// Requests made by the client obtain access tokens automatically. This
+    /// method is useful for validating the client's OAuth credentials up front.
+    pub async fn authenticate(&self) -> Result<(), AuthenticateError> {
+        if let Some(oauth) = &self.oauth {
+            self.oauth_token(oauth).await?;
+        }
+        Ok(())
     }
 
     /// Gets the schema with the associated ID.
     pub async fn get_schema_by_id(&self, id: i32) -> Result<Schema, GetByIdError> {
-        let req = self.make_request(Method::GET, &["schemas", "ids", &id.to_string()]);
+        let req = self
+            .make_request(Method::GET, &["schemas", "ids", &id.to_string()])
+            .await?;
         let res: GetByIdResponse = send_request(req).await?;
         Ok(Schema {
             id,
@@ -83,7 +161,29 @@ impl Client {
 
     /// Gets the latest version of the specified subject.
     pub async fn get_subject(&self, subject: &str) -> Result<Subject, GetBySubjectError> {
-        let req = self.make_request(Method::GET, &["subjects", subject, "versions", "latest"]);
+        let req = self
+            .make_request(Method::GET, &["subjects", subject, "versions", "latest"])
+            .await?;
+        Self::get_subject_inner(req).await
+    }
+
+    /// Like [`Client::get_subject`], but also considers versions of the subject
+    /// that have been soft deleted.
+    ///
+    /// A subject whose versions have all been soft deleted is otherwise
+    /// indistinguishable from a subject that does not exist.
+    pub async fn get_subject_including_deleted(
+        &self,
+        subject: &str,
+    ) -> Result<Subject, GetBySubjectError> {
+        let req = self
+            .make_request(Method::GET, &["subjects", subject, "versions", "latest"])
+            .await?
+            .query(&[("deleted", "true")]);
+        Self::get_subject_inner(req).await
+    }
+
+    async fn get_subject_inner(req: reqwest::RequestBuilder) -> Result<Subject, GetBySubjectError> {
         let res: GetBySubjectResponse = send_request(req).await?;
         Ok(Subject {
             schema: Schema {
@@ -103,7 +203,17 @@ impl Client {
         &self,
         subject: &str,
     ) -> Result<(Subject, Vec<Subject>), GetBySubjectError> {
-        self.get_subject_and_references_by_version(subject, "latest".to_owned())
+        self.get_subject_and_references_by_version(subject, "latest".to_owned(), false)
+            .await
+    }
+
+    /// Like [`Client::get_subject_and_references`], but also considers versions
+    /// of the subjects that have been soft deleted.
+    pub async fn get_subject_and_references_including_deleted(
+        &self,
+        subject: &str,
+    ) -> Result<(Subject, Vec<Subject>), GetBySubjectError> {
+        self.get_subject_and_references_by_version(subject, "latest".to_owned(), true)
             .await
     }
 
@@ -114,12 +224,18 @@ impl Client {
         &self,
         subject: &str,
         version: String,
+        include_deleted: bool,
     ) -> Result<(Subject, Vec<Subject>), GetBySubjectError> {
         let mut subjects = vec![];
         let mut seen = BTreeSet::new();
         let mut subjects_queue = vec![(subject.to_owned(), version)];
         while let Some((subject, version)) = subjects_queue.pop() {
-            let req = self.make_request(Method::GET, &["subjects", &subject, "versions", &version]);
+            let mut req = self
+                .make_request(Method::GET, &["subjects", &subject, "versions", &version])
+                .await?;
+            if include_deleted {
+                req = req.query(&[("deleted", "true")]);
+            }
             let res: GetBySubjectResponse = send_request(req).await?;
             subjects.push(Subject {
                 schema: Schema {
@@ -157,7 +273,9 @@ impl Client {
         schema_type: SchemaType,
         references: &[SchemaReference],
     ) -> Result<i32, PublishError> {
-        let req = self.make_request(Method::POST, &["subjects", subject, "versions"]);
+        let req = self
+            .make_request(Method::POST, &["subjects", subject, "versions"])
+            .await?;
         let req = req.json(&PublishRequest {
             schema,
             schema_type,
@@ -167,9 +285,43 @@ impl Client {
         Ok(res.id)
     }
 
+    /// Reports whether `schema` could be published as a new version of
+    /// `subject`, according to the subject's compatibility requirements.
+    pub async fn check_compatibility(
+        &self,
+        subject: &str,
+        schema: &str,
+        schema_type: SchemaType,
+        references: &[SchemaReference],
+    ) -> Result<bool, CompatibilityError> {
+        let req = self
+            .make_request(
+                Method::POST,
+                &["compatibility", "subjects", subject, "versions", "latest"],
+            )
+            .await?;
+        let req = req.json(&PublishRequest {
+            schema,
+            schema_type,
+            references,
+        });
+        let res: CompatibilityResponse = send_request(req).await?;
+        Ok(res.is_compatible)
+    }
+
     /// Lists the names of all subjects that the schema registry is aware of.
     pub async fn list_subjects(&self) -> Result<Vec<String>, ListError> {
-        let req = self.make_request(Method::GET, &["subjects"]);
+        let req = self.make_request(Method::GET, &["subjects"]).await?;
+        Ok(send_request(req).await?)
+    }
+
+    /// Like [`Client::list_subjects`], but also includes subjects that have
+    /// been soft deleted.
+    pub async fn list_subjects_including_deleted(&self) -> Result<Vec<String>, ListError> {
+        let req = self
+            .make_request(Method::GET, &["subjects"])
+            .await?
+            .query(&[("deleted", "true")]);
         Ok(send_request(req).await?)
     }
 
@@ -180,7 +332,9 @@ impl Client {
     /// be registered under the same subject. It does not allow the schema ID
     /// to be reused.
     pub async fn delete_subject(&self, subject: &str) -> Result<(), DeleteError> {
-        let req = self.make_request(Method::DELETE, &["subjects", subject]);
+        let req = self
+            .make_request(Method::DELETE, &["subjects", subject])
+            .await?;
         let _res: Vec<i32> = send_request(req).await?;
         Ok(())
     }
@@ -193,10 +347,12 @@ impl Client {
         &self,
         id: i32,
     ) -> Result<(Subject, Vec<Subject>), GetBySubjectError> {
-        let req = self.make_request(
-            Method::GET,
-            &["schemas", "ids", &id.to_string(), "versions"],
-        );
+        let req = self
+            .make_request(
+                Method::GET,
+                &["schemas", "ids", &id.to_string(), "versions"],
+            )
+            .await?;
         let res: Vec<SubjectVersion> = send_request(req).await?;
 
         // NOTE NOTE NOTE
@@ -215,6 +371,7 @@ impl Client {
                 self.get_subject_and_references_by_version(
                     &first.subject,
                     first.version.to_string(),
+                    false,
                 )
                 .await
             }
@@ -264,7 +421,7 @@ impl SchemaType {
 }
 
 /// A schema stored by a schema registry.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Schema {
     /// The ID of the schema.
     pub id: i32,
@@ -273,7 +430,7 @@ pub struct Schema {
 }
 
 /// A subject stored by a schema registry.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Subject {
     /// The version of the schema.
     pub version: i32,
@@ -483,6 +640,61 @@ impl fmt::Display for PublishError {
     }
 }
 
+#[derive(Debug, Deserialize)]
+struct CompatibilityResponse {
+    is_compatible: bool,
+}
+
+/// Errors for compatibility checks.
+#[derive(Debug)]
+pub enum CompatibilityError {
+    /// The specified subject does not exist.
+    SubjectNotFound,
+    /// The provided schema was invalid.
+    InvalidSchema { message: String },
+    /// The underlying HTTP transport failed.
+    Transport(reqwest::Error),
+    /// An internal server error occurred.
+    Server { code: i32, message: String },
+}
+
+impl From<UnhandledError> for CompatibilityError {
+    fn from(err: UnhandledError) -> CompatibilityError {
+        match err {
+            UnhandledError::Transport(err) => CompatibilityError::Transport(err),
+            UnhandledError::Api { code, message } => match code {
+                40401 => CompatibilityError::SubjectNotFound,
+                42201 => CompatibilityError::InvalidSchema { message },
+                _ => CompatibilityError::Server { code, message },
+            },
+        }
+    }
+}
+
+impl Error for CompatibilityError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CompatibilityError::SubjectNotFound
+            | CompatibilityError::InvalidSchema { .. }
+            | CompatibilityError::Server { .. } => None,
+            CompatibilityError::Transport(err) => Some(err),
+        }
+    }
+}
+
+impl fmt::Display for CompatibilityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CompatibilityError::SubjectNotFound => write!(f, "subject not found"),
+            CompatibilityError::InvalidSchema { message } => write!(f, "{}", message),
+            CompatibilityError::Transport(err) => write!(f, "transport: {}", err),
+            CompatibilityError::Server { code, message } => {
+                write!(f, "server error {}: {}", code, message)
+            }
+        }
+    }
+}
+
 /// Errors for list operations.
 #[derive(Debug)]
 pub enum ListError {
@@ -563,6 +775,57 @@ impl fmt::Display for DeleteError {
     }
 }
 
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+}
+
+/// An OAuth access token, along with the time at which it should be replaced.
+#[derive(Debug)]
+struct OauthToken {
+    access_token: String,
+    refresh_at: Instant,
+}
+
+/// Errors for authentication operations.
+#[derive(Debug)]
+pub enum AuthenticateError {
+    /// The underlying HTTP transport failed.
+    Transport(reqwest::Error),
+    /// The token endpoint rejected the request.
+    Server { code: i32, message: String },
+}
+
+impl From<UnhandledError> for AuthenticateError {
+    fn from(err: UnhandledError) -> AuthenticateError {
+        match err {
+            UnhandledError::Transport(err) => AuthenticateError::Transport(err),
+            UnhandledError::Api { code, message } => AuthenticateError::Server { code, message },
+        }
+    }
+}
+
+impl Error for AuthenticateError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AuthenticateError::Server { .. } => None,
+            AuthenticateError::Transport(err) => Some(err),
+        }
+    }
+}
+
+impl fmt::Display for AuthenticateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AuthenticateError::Transport(err) => write!(f, "transport: {}", err),
+            AuthenticateError::Server { code, message } => {
+                write!(f, "server error {}: {}", code, message)
+            }
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct ErrorResponse {
     error_code: i32,
@@ -13,6 +13,7 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
 
+use reqwest::Proxy;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
@@ -25,6 +26,16 @@ pub struct Auth {
     pub password: Option<String>,
 }
 
+/// OAuth 2.0 client credentials, which are exchanged for bearer tokens at the
+/// token endpoint.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Oauth {
+    pub token_endpoint: Url,
+    pub client_id: String,
+    pub client_secret: String,
+    pub scope: Option<String>,
+}
+
 /// Configuration for a `Client`.
 #[derive(Clone)]
 pub struct ClientConfig {
@@ -32,6 +43,8 @@ pub struct ClientConfig {
     root_certs: Vec<Certificate>,
     identity: Option<Identity>,
     auth: Option<Auth>,
+    oauth: Option<Oauth>,
+    proxy: Option<Proxy>,
     dns_overrides: BTreeMap<String, Vec<SocketAddr>>,
 }
 
@@ -42,6 +55,8 @@ impl fmt::Debug for ClientConfig {
             .field("root_certs", &self.root_certs)
             .field("identity", &self.identity)
             .field("auth", &self.auth)
+            .field("oauth", &self.oauth)
+            .field("proxy", &self.proxy)
             .field("dns_overrides", &self.dns_overrides)
             .finish()
     }
@@ -56,6 +71,8 @@ impl ClientConfig {
             root_certs: Vec::new(),
             identity: None,
             auth: None,
+            oauth: None,
+            proxy: None,
             dns_overrides: BTreeMap::new(),
         }
     }
@@ -81,6 +98,22 @@ impl ClientConfig {
         self
     }
 
+    /// Enables OAuth 2.0 bearer token authentication.
+    ///
+    /// The client obtains access tokens from the token endpoint using the
+    /// client credentials grant, and refreshes them as they near expiry.
+    pub fn oauth(mut self, oauth: Oauth) -> ClientConfig {
+        self.oauth = Some(oauth);
+        self
+    }
+
+    /// Routes all requests, including requests for OAuth access tokens, through
+    /// the specified HTTP(S) proxy.
+    pub fn proxy(mut self, proxy: Proxy) -> ClientConfig {
+        self.proxy = Some(proxy);
+        self
+    }
+
     /// Overrides DNS resolution for specific domains to the provided IP
     /// addresses.
     ///
@@ -114,6 +147,10 @@ impl ClientConfig {
             builder = builder.identity(ident.into());
         }
 
+        if let Some(proxy) = self.proxy {
+            builder = builder.proxy(proxy);
+        }
+
         for (domain, addrs) in self.dns_overrides {
             builder = builder.resolve_to_addrs(&domain, &addrs);
         }
@@ -124,6 +161,6 @@ impl ClientConfig {
             .build()
             .unwrap();
 
-        Client::new(inner, self.url, self.auth)
+        Client::new(inner, self.url, self.auth, self.oauth)
     }
 }
@@ -110,5 +110,5 @@ mod config;
 pub mod tls;
 
 pub use client::*;
-pub use config::ClientConfig;
+pub use config::{ClientConfig, Oauth};
 pub use reqwest::Proxy;
@@ -49,13 +49,14 @@ use mz_storage_client::controller::{IntrospectionType, ReadPolicy, StorageContro
 use serde::{Deserialize, Serialize};
 use timely::progress::frontier::{AntichainRef, MutableAntichain};
 use timely::progress::{Antichain, Timestamp};
+use timely::PartialOrder;
 use tracing::warn;
 use uuid::Uuid;
 
 use crate::controller::error::{
     CollectionLookupError, CollectionMissing, CollectionUpdateError, DataflowCreationError,
-    InstanceExists, InstanceMissing, PeekError, ReplicaCreationError, ReplicaDropError,
-    SubscribeTargetError,
+    InstanceExists, InstanceMissing, PeekError, ReplicaCreationError, ReplicaDrainError,
+    ReplicaDropError, SubscribeTargetError,
 };
 use crate::controller::instance::{ActiveInstance, Instance};
 use crate::controller::replica::ReplicaConfig;
@@ -245,6 +246,53 @@ where
             .collect()
     }
 
+    /// Reports whether all collections installed on the given replica are hydrated.
+    ///
+    /// Graceful reconfiguration uses this to decide when a new replica has caught up and the
+    /// replicas it replaces can be dropped.
+    pub fn replica_hydrated(
+        &self,
+        instance_id: ComputeInstanceId,
+        replica_id: ReplicaId,
+    ) -> Result<bool, InstanceMissing> {
+        let instance = self.instance(instance_id)?;
+        Ok(instance.replica_exists(replica_id)
+            && instance
+                .collections_iter()
+                .all(|(_, collection)| !collection.unhydrated_replicas.contains_key(&replica_id)))
+    }
+
+    /// Reports whether the given replica is hydrated and has computed all collections installed
+    /// on it beyond `frontier`, or as far as any replica has computed them.
+    ///
+    /// Graceful reconfiguration uses this to decide when a new replica has caught up with the
+    /// replicas it replaces, which makes it safe to drop them.
+    pub fn replica_caught_up(
+        &self,
+        instance_id: ComputeInstanceId,
+        replica_id: ReplicaId,
+        frontier: &T,
+    ) -> Result<bool, InstanceMissing>
+    where
+        T: Timestamp,
+    {
+        let instance = self.instance(instance_id)?;
+        Ok(instance.replica_exists(replica_id)
+            && instance.collections_iter().all(|(_, collection)| {
+                !collection.unhydrated_replicas.contains_key(&replica_id)
+                    && collection
+                        .replica_write_frontiers
+                        .get(&replica_id)
+                        .map_or(true, |upper| {
+                            !upper.less_equal(frontier)
+                                || PartialOrder::less_equal(
+                                    &collection.write_frontier(),
+                                    &upper.borrow(),
+                                )
+                        })
+            }))
+    }
+
     /// Returns the write frontier for each collection installed on each replica.
     pub fn replica_write_frontiers(&self) -> BTreeMap<(GlobalId, ReplicaId), Antichain<T>> {
         let mut result = BTreeMap::new();
@@ -394,6 +442,21 @@ where
             .set_subscribe_target_replica(subscribe_id, target_replica)?;
         Ok(())
     }
+
+    /// Set whether the identified replica is draining.
+    ///
+    /// Draining replicas keep maintaining the dataflows they have already installed, but new
+    /// non-transient dataflows are not installed on them.
+    pub fn set_replica_draining(
+        &mut self,
+        instance_id: ComputeInstanceId,
+        replica_id: ReplicaId,
+        draining: bool,
+    ) -> Result<(), ReplicaDrainError> {
+        self.instance_mut(instance_id)?
+            .set_replica_draining(replica_id, draining)?;
+        Ok(())
+    }
 }
 
 /// A wrapper around a [`ComputeController`] with a live connection to a storage controller.
@@ -676,6 +739,12 @@ pub struct CollectionState<T> {
     write_frontier: Antichain<T>,
     /// The write frontiers reported by individual replicas.
     replica_write_frontiers: BTreeMap<ReplicaId, Antichain<T>>,
+    /// The frontiers at which replicas started computing this collection, for replicas on which
+    /// the collection is not yet hydrated.
+    ///
+    /// A collection is hydrated on a replica once the replica reports a write frontier beyond the
+    /// frontier it started computing the collection at.
+    unhydrated_replicas: BTreeMap<ReplicaId, Antichain<T>>,
 }
 
 impl<T> CollectionState<T> {
@@ -694,6 +763,14 @@ impl<T> CollectionState<T> {
         self.write_frontier.borrow()
     }
 
+    /// Reports whether this collection is hydrated on the given replica.
+    ///
+    /// Returns `false` if the replica does not maintain this collection.
+    pub fn hydrated_on(&self, replica_id: ReplicaId) -> bool {
+        self.replica_write_frontiers.contains_key(&replica_id)
+            && !self.unhydrated_replicas.contains_key(&replica_id)
+    }
+
     /// Reports the IDs of the dependencies of this collection.
     fn dependency_ids(&self) -> impl Iterator<Item = GlobalId> + '_ {
         let compute = self.compute_dependencies.iter().copied();
@@ -721,6 +798,7 @@ impl<T: Timestamp> CollectionState<T> {
             compute_dependencies,
             write_frontier: Antichain::from_elem(Timestamp::minimum()),
             replica_write_frontiers: BTreeMap::new(),
+            unhydrated_replicas: BTreeMap::new(),
         }
     }
 
@@ -109,6 +109,27 @@ impl From<instance::ReplicaMissing> for ReplicaDropError {
     }
 }
 
+/// Errors arising when changing whether a compute replica is draining.
+#[derive(Error, Debug)]
+pub enum ReplicaDrainError {
+    #[error("instance does not exist: {0}")]
+    InstanceMissing(ComputeInstanceId),
+    #[error("replica does not exist: {0}")]
+    ReplicaMissing(ReplicaId),
+}
+
+impl From<InstanceMissing> for ReplicaDrainError {
+    fn from(error: InstanceMissing) -> Self {
+        Self::InstanceMissing(error.0)
+    }
+}
+
+impl From<instance::ReplicaMissing> for ReplicaDrainError {
+    fn from(error: instance::ReplicaMissing) -> Self {
+        Self::ReplicaMissing(error.0)
+    }
+}
+
 /// Errors arising during dataflow creation.
 #[derive(Error, Debug)]
 pub enum DataflowCreationError {
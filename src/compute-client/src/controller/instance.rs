@@ -150,6 +150,20 @@ pub(super) struct Instance<T> {
     history: ComputeCommandHistory<UIntGauge, T>,
     /// IDs of replicas that have failed and require rehydration.
     failed_replicas: BTreeSet<ReplicaId>,
+    /// IDs of replicas that are draining.
+    ///
+    /// Draining replicas keep maintaining the dataflows they have already installed, but new
+    /// non-transient dataflows are not installed on them.
+    draining_replicas: BTreeSet<ReplicaId>,
+    /// IDs of collections that are not installed on a replica, keyed by replica ID.
+    ///
+    /// New entries are added for dataflows created while a replica is draining, and for dataflows
+    /// that depend on collections not installed on a replica. Commands referencing these
+    /// collections are not sent to the replica, and its frontiers are not tracked for them.
+    ///
+    /// Entries are removed when the collection is removed, or when the replica stops draining and
+    /// is rehydrated.
+    uninstalled_collections: BTreeMap<ReplicaId, BTreeSet<GlobalId>>,
     /// Sender for responses to be delivered.
     response_tx: crossbeam_channel::Sender<ComputeControllerResponse<T>>,
     /// Sender for introspection updates to be recorded.
@@ -188,6 +202,17 @@ impl<T> Instance<T> {
     fn remove_collection(&mut self, id: GlobalId) {
         self.report_dependency_updates(id, -1);
         self.collections.remove(&id);
+
+        for collection_ids in self.uninstalled_collections.values_mut() {
+            collection_ids.remove(&id);
+        }
+    }
+
+    /// Returns whether the identified collection is installed on the identified replica.
+    fn collection_installed(&self, replica_id: ReplicaId, id: GlobalId) -> bool {
+        self.uninstalled_collections
+            .get(&replica_id)
+            .map_or(true, |ids| !ids.contains(&id))
     }
 
     /// Enqueue the given response for delivery to the controller clients.
@@ -235,6 +260,11 @@ impl<T> Instance<T> {
         self.replicas.keys().copied()
     }
 
+    /// Returns whether the identified replica is draining.
+    pub fn replica_draining(&self, id: ReplicaId) -> bool {
+        self.draining_replicas.contains(&id)
+    }
+
     /// Return the IDs of pending peeks targeting the specified replica.
     fn peeks_targeting(
         &self,
@@ -346,6 +376,8 @@ where
             subscribes: Default::default(),
             history,
             failed_replicas: Default::default(),
+            draining_replicas: Default::default(),
+            uninstalled_collections: Default::default(),
             response_tx,
             introspection_tx,
             envd_epoch,
@@ -403,6 +435,11 @@ where
 
         // Clone the command for each active replica.
         for (id, replica) in self.replicas.iter_mut() {
+            // Skip commands referencing collections not installed on the replica.
+            if !command_applies(&cmd, self.uninstalled_collections.get(id)) {
+                continue;
+            }
+
             // If sending the command fails, the replica requires rehydration.
             if replica.send(cmd.clone()).is_err() {
                 self.failed_replicas.insert(*id);
@@ -509,6 +546,49 @@ where
         }
 
         subscribe.target_replica = Some(target_replica);
+
+        // A subscribe that is not installed on its target replica will never be served. We
+        // produce an error to inform upstream, consistent with targeted subscribes whose replica
+        // is dropped.
+        if !self.collection_installed(target_replica, id) {
+            let subscribe = self.subscribes.remove(&id).expect("subscribe must exist");
+            let response = ComputeControllerResponse::SubscribeResponse(
+                id,
+                SubscribeResponse::Batch(SubscribeBatch {
+                    lower: subscribe.frontier.clone(),
+                    upper: subscribe.frontier,
+                    updates: Err("target replica is draining".into()),
+                }),
+            );
+            self.deliver_response(response);
+        }
+
+        Ok(())
+    }
+
+    /// Set whether the identified replica is draining.
+    ///
+    /// Draining replicas keep maintaining the dataflows they have already installed, but new
+    /// non-transient dataflows are not installed on them. When a replica stops draining, it is
+    /// rehydrated to install the dataflows that were created in the meantime.
+    pub fn set_replica_draining(
+        &mut self,
+        replica_id: ReplicaId,
+        draining: bool,
+    ) -> Result<(), ReplicaMissing> {
+        if !self.replica_exists(replica_id) {
+            return Err(ReplicaMissing(replica_id));
+        }
+
+        if draining {
+            self.draining_replicas.insert(replica_id);
+        } else if self.draining_replicas.remove(&replica_id) {
+            let uninstalled = self.uninstalled_collections.remove(&replica_id);
+            if uninstalled.map_or(false, |ids| !ids.is_empty()) {
+                self.failed_replicas.insert(replica_id);
+            }
+        }
+
         Ok(())
     }
 }
@@ -541,14 +621,26 @@ where
         // Initialize frontier tracking for the new replica
         // and clean up any dropped collections that we can
         let mut updates = Vec::new();
+        let uninstalled = self.compute.uninstalled_collections.get(&id);
         for (compute_id, collection) in &mut self.compute.collections {
             // Skip log collections not maintained by this replica.
             if collection.log_collection && !log_ids.contains(compute_id) {
                 continue;
             }
+            // Skip collections not installed on this replica.
+            if uninstalled.map_or(false, |ids| ids.contains(compute_id)) {
+                continue;
+            }
 
-            let read_frontier = collection.read_frontier();
-            updates.push((*compute_id, read_frontier.to_owned()));
+            let read_frontier = collection.read_frontier().to_owned();
+            // The replica starts computing the collection at its current read frontier. Collections
+            // that are already dropped never need hydrating.
+            if !read_frontier.is_empty() {
+                collection
+                    .unhydrated_replicas
+                    .insert(id, read_frontier.clone());
+            }
+            updates.push((*compute_id, read_frontier));
         }
         self.update_write_frontiers(id, &updates);
 
@@ -567,7 +659,11 @@ where
         self.compute.history.reduce();
 
         // Replay the commands at the client, creating new dataflow identifiers.
+        let uninstalled = self.compute.uninstalled_collections.get(&id);
         for command in self.compute.history.iter() {
+            if !command_applies(command, uninstalled) {
+                continue;
+            }
             if replica.send(command.clone()).is_err() {
                 // We swallow the error here. On the next send, we will fail again, and
                 // restart the connection as well as this rehydration.
@@ -591,6 +687,8 @@ where
             .ok_or(ReplicaMissing(id))?;
 
         self.compute.failed_replicas.remove(&id);
+        self.compute.draining_replicas.remove(&id);
+        self.compute.uninstalled_collections.remove(&id);
 
         // Remove frontier tracking for this replica.
         self.remove_write_frontiers(id);
@@ -653,7 +751,18 @@ where
     /// Panics if the specified replica does not exist.
     fn rehydrate_replica(&mut self, id: ReplicaId) {
         let config = self.compute.replicas[&id].config.clone();
+        let draining = self.compute.replica_draining(id);
+        let uninstalled = self.compute.uninstalled_collections.get(&id).cloned();
         self.remove_replica(id).expect("replica must exist");
+
+        // Rehydration must not install collections the replica was not maintaining before.
+        if draining {
+            self.compute.draining_replicas.insert(id);
+        }
+        if let Some(ids) = uninstalled {
+            self.compute.uninstalled_collections.insert(id, ids);
+        }
+
         let result = self.add_replica(id, config);
 
         match result {
@@ -753,6 +862,30 @@ where
             .collect();
         self.update_read_capabilities(&mut compute_read_updates);
 
+        // Determine the replicas the dataflow is not installed on: Draining replicas don't install
+        // new non-transient dataflows, and no replica can install a dataflow that imports indexes
+        // it doesn't maintain.
+        let is_transient = dataflow.is_transient();
+        let uninstalled_replicas: BTreeSet<_> = self
+            .compute
+            .replica_ids()
+            .filter(|replica_id| {
+                let draining = !is_transient && self.compute.replica_draining(*replica_id);
+                draining
+                    || dataflow
+                        .index_imports
+                        .keys()
+                        .any(|id| !self.compute.collection_installed(*replica_id, *id))
+            })
+            .collect();
+        for replica_id in &uninstalled_replicas {
+            self.compute
+                .uninstalled_collections
+                .entry(*replica_id)
+                .or_default()
+                .extend(dataflow.export_ids());
+        }
+
         // Install collection state for each of the exports.
         let mut updates = Vec::new();
         for export_id in dataflow.export_ids() {
@@ -766,10 +899,20 @@ where
             );
             updates.push((export_id, replica_write_frontier.clone()));
         }
-        // Initialize tracking of replica frontiers.
-        let replica_ids: Vec<_> = self.compute.replica_ids().collect();
+        // Initialize tracking of replica frontiers and hydration.
+        let replica_ids: Vec<_> = self
+            .compute
+            .replica_ids()
+            .filter(|id| !uninstalled_replicas.contains(id))
+            .collect();
         for replica_id in replica_ids {
             self.update_write_frontiers(replica_id, &updates);
+            for export_id in dataflow.export_ids() {
+                let collection = self.compute.collection_mut(export_id).expect("just added");
+                collection
+                    .unhydrated_replicas
+                    .insert(replica_id, as_of.clone());
+            }
         }
 
         // Initialize tracking of subscribes.
@@ -904,6 +1047,20 @@ where
             if !self.compute.replica_exists(target) {
                 return Err(PeekError::ReplicaMissing(target));
             }
+
+            // A peek of an index that is not installed on its target replica will never be
+            // served. We produce an error to inform the caller instead.
+            let index_peek = matches!(peek_target, PeekTarget::Index { .. });
+            if index_peek && !self.compute.collection_installed(target, id) {
+                let response = PeekResponse::Error("target replica is draining".into());
+                self.compute
+                    .deliver_response(ComputeControllerResponse::PeekResponse(
+                        uuid,
+                        response,
+                        OpenTelemetryContext::obtain(),
+                    ));
+                return Ok(());
+            }
         }
 
         // Install a compaction hold on `id` at `timestamp`.
@@ -1056,6 +1213,14 @@ where
                 );
             }
 
+            // The collection is hydrated once the replica reports progress beyond the frontier
+            // it started computing the collection at.
+            if let Some(as_of) = collection.unhydrated_replicas.get(&replica_id) {
+                if PartialOrder::less_than(as_of, new_upper) {
+                    collection.unhydrated_replicas.remove(&replica_id);
+                }
+            }
+
             if new_upper.is_empty() {
                 dropped_collection_ids.push(*id);
             }
@@ -1123,6 +1288,7 @@ where
         let mut dropped_collection_ids = Vec::new();
         for (id, collection) in self.compute.collections.iter_mut() {
             let last_upper = collection.replica_write_frontiers.remove(&replica_id);
+            collection.unhydrated_replicas.remove(&replica_id);
 
             if let Some(frontier) = last_upper {
                 dropped_collection_ids.push(*id);
@@ -1455,3 +1621,28 @@ impl<T: Timestamp> ActiveSubscribe<T> {
         }
     }
 }
+
+/// Returns whether the given command should be sent to a replica that doesn't have the
+/// `uninstalled` collections installed.
+fn command_applies<T>(cmd: &ComputeCommand<T>, uninstalled: Option<&BTreeSet<GlobalId>>) -> bool {
+    let Some(uninstalled) = uninstalled else {
+        return true;
+    };
+
+    match cmd {
+        ComputeCommand::CreateDataflow(dataflow) => {
+            !dataflow.export_ids().any(|id| uninstalled.contains(&id))
+        }
+        ComputeCommand::AllowCompaction { id, .. } => !uninstalled.contains(id),
+        ComputeCommand::Peek(Peek {
+            target: PeekTarget::Index { id },
+            ..
+        }) => !uninstalled.contains(id),
+        ComputeCommand::CreateTimely { .. }
+        | ComputeCommand::CreateInstance(_)
+        | ComputeCommand::InitializationComplete
+        | ComputeCommand::UpdateConfiguration(_)
+        | ComputeCommand::Peek(_)
+        | ComputeCommand::CancelPeek { .. } => true,
+    }
+}
@@ -377,14 +377,16 @@ where
             return;
         }
 
+        let hydration_time = collection.created_at.elapsed();
+
         // Update metrics if we are maintaining them for this collection.
         if let Some(metrics) = &collection.metrics {
-            let duration = collection.created_at.elapsed().as_secs_f64();
+            let duration = hydration_time.as_secs_f64();
             metrics.initial_output_duration_seconds.set(duration);
         }
 
         // Set the hydration flag.
-        collection.hydration_flag.set();
+        collection.hydration_flag.set(hydration_time);
     }
 }
 
@@ -414,6 +416,8 @@ struct HydrationFlag {
     replica_id: ReplicaId,
     collection_id: GlobalId,
     hydrated: bool,
+    /// The time it took to hydrate the collection, once it is hydrated.
+    hydration_time: Option<Duration>,
     introspection_tx: crossbeam_channel::Sender<IntrospectionUpdates>,
 }
 
@@ -428,6 +432,7 @@ impl HydrationFlag {
             replica_id,
             collection_id,
             hydrated: false,
+            hydration_time: None,
             introspection_tx,
         };
 
@@ -437,14 +442,15 @@ impl HydrationFlag {
         self_
     }
 
-    /// Mark the collection as hydrated and update introspection.
-    fn set(&mut self) {
+    /// Mark the collection as hydrated after `hydration_time` and update introspection.
+    fn set(&mut self, hydration_time: Duration) {
         if self.hydrated {
             return; // nothing to do
         }
 
         let retraction = self.row();
         self.hydrated = true;
+        self.hydration_time = Some(hydration_time);
         let insertion = self.row();
 
         self.send(vec![(retraction, -1), (insertion, 1)]);
@@ -455,6 +461,10 @@ impl HydrationFlag {
             Datum::String(&self.collection_id.to_string()),
             Datum::String(&self.replica_id.to_string()),
             Datum::from(self.hydrated),
+            Datum::from(
+                self.hydration_time
+                    .and_then(|time| chrono::Duration::from_std(time).ok()),
+            ),
         ])
     }
 
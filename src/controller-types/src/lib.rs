@@ -77,6 +77,8 @@
 
 //! Shared types for the `mz-controller` crate
 
+use serde::{Deserialize, Serialize};
+
 /// Identifies a cluster.
 pub type ClusterId = mz_compute_types::ComputeInstanceId;
 
@@ -84,3 +86,49 @@ pub type ClusterId = mz_compute_types::ComputeInstanceId;
 pub type ReplicaId = mz_cluster_client::ReplicaId;
 
 pub use mz_compute_types::DEFAULT_COMPUTE_REPLICA_LOGGING_INTERVAL as DEFAULT_REPLICA_LOGGING_INTERVAL;
+
+/// A constraint on the availability zone of a cluster replica, relative to
+/// another replica of the same cluster.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ReplicaAvailabilityZoneConstraint {
+    /// The replica must run in the same availability zone as the given replica.
+    Affinity(ReplicaId),
+    /// The replica must not run in the same availability zone as the given
+    /// replica.
+    AntiAffinity(ReplicaId),
+}
+
+/// The scheduling priority of the replicas of a cluster.
+///
+/// On shared infrastructure, the replicas of higher-priority clusters are
+/// scheduled ahead of, and may preempt, the replicas of lower-priority
+/// clusters.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum ClusterPriority {
+    /// The priority of ad-hoc and development clusters.
+    Low,
+    /// The default priority.
+    #[default]
+    Normal,
+    /// The priority of production clusters.
+    High,
+}
+
+impl ClusterPriority {
+    /// Returns the name of the priority, as used in SQL.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClusterPriority::Low => "low",
+            ClusterPriority::Normal => "normal",
+            ClusterPriority::High => "high",
+        }
+    }
+}
+
+impl std::fmt::Display for ClusterPriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
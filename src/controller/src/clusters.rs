@@ -22,10 +22,12 @@ use mz_compute_client::controller::{ComputeReplicaConfig, ComputeReplicaLogging}
 use mz_compute_client::logging::LogVariant;
 use mz_compute_client::service::{ComputeClient, ComputeGrpcClient};
 use mz_compute_types::ComputeInstanceId;
-use mz_controller_types::{ClusterId, ReplicaId};
+use mz_controller_types::{
+    ClusterId, ClusterPriority, ReplicaAvailabilityZoneConstraint, ReplicaId,
+};
 use mz_orchestrator::{
     CpuLimit, DiskLimit, LabelSelectionLogic, LabelSelector, MemoryLimit, Service, ServiceConfig,
-    ServiceEvent, ServicePort,
+    ServiceEvent, ServicePort, ServicePriority,
 };
 use mz_ore::halt;
 use mz_ore::task::AbortOnDropHandle;
@@ -252,6 +254,11 @@ pub struct ManagedReplicaLocation {
     /// is an empty list if none are specified
     #[serde(skip)]
     pub availability_zones: ManagedReplicaAvailabilityZones,
+    /// Constraints on the availability zone of the replica relative to the
+    /// other replicas of its cluster, which were resolved into the replica's
+    /// specific `AVAILABILITY ZONE` when the replica was created.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub availability_zone_constraints: Vec<ReplicaAvailabilityZoneConstraint>,
     /// Whether the replica needs scratch disk space.
     pub disk: bool,
 }
@@ -285,6 +292,8 @@ pub struct CreateReplicaConfig {
     pub cluster_id: ClusterId,
     pub replica_id: ReplicaId,
     pub role: ClusterRole,
+    /// The scheduling priority of the replica's cluster.
+    pub priority: ClusterPriority,
     pub config: ReplicaConfig,
 }
 
@@ -347,6 +356,7 @@ where
                     cluster_id,
                     replica_id,
                     role,
+                    priority,
                     config,
                 } = config;
 
@@ -386,7 +396,7 @@ where
                     ReplicaLocation::Managed(m) => {
                         let workers = m.allocation.workers;
                         let (service, metrics_task_join_handle) = this
-                            .provision_replica(cluster_id, replica_id, role, m)
+                            .provision_replica(cluster_id, replica_id, role, priority, m)
                             .await?;
                         let storage_location = ClusterReplicaLocation {
                             ctl_addrs: service.addresses("storagectl"),
@@ -577,6 +587,7 @@ where
         cluster_id: ClusterId,
         replica_id: ReplicaId,
         role: ClusterRole,
+        priority: ClusterPriority,
         location: ManagedReplicaLocation,
     ) -> Result<(Box<dyn Service>, AbortOnDropHandle<()>), anyhow::Error> {
         let service_name = generate_replica_service_name(cluster_id, replica_id);
@@ -698,6 +709,11 @@ where
                     }],
                     disk_limit: location.allocation.disk_limit,
                     disk: location.disk,
+                    priority: match priority {
+                        ClusterPriority::Low => ServicePriority::Low,
+                        ClusterPriority::Normal => ServicePriority::Normal,
+                        ClusterPriority::High => ServicePriority::High,
+                    },
                 },
             )
             .await?;
@@ -272,6 +272,14 @@ pub struct Args {
     /// Name of a non-default Kubernetes scheduler, if any.
     #[structopt(long, env = "ORCHESTRATOR_KUBERNETES_SCHEDULER_NAME")]
     orchestrator_kubernetes_scheduler_name: Option<String>,
+    /// Name of the Kubernetes priority class to assign to the replicas of
+    /// low-priority clusters, if any.
+    #[structopt(long, env = "ORCHESTRATOR_KUBERNETES_LOW_PRIORITY_CLASS_NAME")]
+    orchestrator_kubernetes_low_priority_class_name: Option<String>,
+    /// Name of the Kubernetes priority class to assign to the replicas of
+    /// high-priority clusters, if any.
+    #[structopt(long, env = "ORCHESTRATOR_KUBERNETES_HIGH_PRIORITY_CLASS_NAME")]
+    orchestrator_kubernetes_high_priority_class_name: Option<String>,
     /// Labels to apply to all services created by the Kubernetes orchestrator
     /// in the form `KEY=VALUE`.
     #[structopt(long, env = "ORCHESTRATOR_KUBERNETES_SERVICE_LABEL")]
@@ -729,6 +737,10 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
                     .block_on(KubernetesOrchestrator::new(KubernetesOrchestratorConfig {
                         context: args.orchestrator_kubernetes_context.clone(),
                         scheduler_name: args.orchestrator_kubernetes_scheduler_name,
+                        low_priority_class_name: args
+                            .orchestrator_kubernetes_low_priority_class_name,
+                        high_priority_class_name: args
+                            .orchestrator_kubernetes_high_priority_class_name,
                         service_labels: args
                             .orchestrator_kubernetes_service_label
                             .into_iter()
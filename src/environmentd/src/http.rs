@@ -429,6 +429,10 @@ impl InternalHttpServer {
                 "/api/coordinator/check",
                 routing::get(catalog::handle_coordinator_check),
             )
+            .route(
+                "/api/catalog/vacuum",
+                routing::post(catalog::handle_catalog_vacuum),
+            )
             .route(
                 "/internal-console",
                 routing::get(|| async { Redirect::temporary("/internal-console/") }),
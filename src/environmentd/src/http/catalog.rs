@@ -38,3 +38,20 @@ pub async fn handle_coordinator_check(mut client: AuthedClient) -> impl IntoResp
     };
     (TypedHeader(ContentType::json()), response.to_string())
 }
+
+pub async fn handle_catalog_vacuum(mut client: AuthedClient) -> impl IntoResponse {
+    match client.client.vacuum_catalog().await {
+        Ok(report) => {
+            let response = serde_json::json!({
+                "pruned_audit_log_events": report.pruned_audit_log_events.len(),
+                "removed_storage_usage_events": report.removed_storage_usage_events.len(),
+                "storage_usage_rollups": report.storage_usage_rollups.len(),
+                "purged_trashed_items": report.purged_trashed_items.len(),
+                "removed_settings": report.removed_settings,
+                "reclaimed_bytes": report.reclaimed_bytes,
+            });
+            Ok((TypedHeader(ContentType::json()), response.to_string()))
+        }
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
@@ -1102,9 +1102,11 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::CreatedRole
         | ExecuteResponse::CreatedCluster { .. }
         | ExecuteResponse::CreatedClusterReplica { .. }
+        | ExecuteResponse::CreatedClusterReplicaSize
         | ExecuteResponse::CreatedTable { .. }
         | ExecuteResponse::CreatedIndex { .. }
         | ExecuteResponse::CreatedSecret { .. }
+        | ExecuteResponse::CreatedServiceAccount
         | ExecuteResponse::CreatedSource { .. }
         | ExecuteResponse::CreatedSink { .. }
         | ExecuteResponse::CreatedView { .. }
@@ -1115,8 +1117,10 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::Deleted(_)
         | ExecuteResponse::DiscardedTemp
         | ExecuteResponse::DiscardedAll
+        | ExecuteResponse::DroppedClusterReplicaSize
         | ExecuteResponse::DroppedObject(_)
         | ExecuteResponse::DroppedOwned
+        | ExecuteResponse::DroppedServiceAccount
         | ExecuteResponse::EmptyQuery
         | ExecuteResponse::GrantedPrivilege
         | ExecuteResponse::GrantedRole
@@ -1127,6 +1131,7 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::AlteredDefaultPrivileges
         | ExecuteResponse::RevokedRole
         | ExecuteResponse::StartedTransaction { .. }
+        | ExecuteResponse::UndroppedObject(_)
         | ExecuteResponse::Updated(_)
         | ExecuteResponse::AlteredObject(_)
         | ExecuteResponse::AlteredIndexLogicalCompaction
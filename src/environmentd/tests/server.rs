@@ -1873,6 +1873,130 @@ fn test_http_options_param() {
         .contains(r#"startup setting not_a_session_var not set"#));
 }
 
+#[mz_ore::test]
+#[cfg_attr(miri, ignore)] // too slow
+fn test_session_audit_events() {
+    let server = test_util::TestHarness::default().start_blocking();
+
+    let mut mz_client = server
+        .pg_config_internal()
+        .user(&SYSTEM_USER.name)
+        .connect(postgres::NoTls)
+        .unwrap();
+    mz_client
+        .batch_execute("ALTER SYSTEM SET audit_log_session_events = true")
+        .unwrap();
+    mz_client
+        .batch_execute("CREATE ROLE limited CONNECTION LIMIT 0")
+        .unwrap();
+
+    let client = server
+        .pg_config()
+        .application_name("audited")
+        .connect(postgres::NoTls)
+        .unwrap();
+    client.close().unwrap();
+
+    // Repeated failed logins of the same user from the same address are only recorded once.
+    for _ in 0..3 {
+        let err = server
+            .pg_config()
+            .user("limited")
+            .application_name("audited")
+            .connect(postgres::NoTls)
+            .unwrap_err();
+        assert_eq!(err.code(), Some(&SqlState::TOO_MANY_CONNECTIONS));
+    }
+
+    // Session events are written to the audit log in batches, so wait for them.
+    Retry::default()
+        .max_tries(10)
+        .retry(|_state| {
+            let mut events: Vec<(String, String, String)> = mz_client
+                .query(
+                    "SELECT event_type, \"user\", details->>'application_name'
+                    FROM mz_catalog.mz_audit_events
+                    WHERE object_type = 'session'",
+                    &[],
+                )
+                .unwrap()
+                .into_iter()
+                .map(|row| (row.get(0), row.get(1), row.get(2)))
+                .collect();
+            // The logout and the failed login may be recorded in either order.
+            events.sort();
+            let expected: Vec<_> = [
+                ("login", "materialize", "audited"),
+                ("login-failure", "limited", "audited"),
+                ("logout", "materialize", "audited"),
+            ]
+            .into_iter()
+            .map(|(event_type, user, application_name)| {
+                (
+                    event_type.to_string(),
+                    user.to_string(),
+                    application_name.to_string(),
+                )
+            })
+            .collect();
+            if events == expected {
+                Ok(())
+            } else {
+                Err(format!("unexpected session events: {events:?}"))
+            }
+        })
+        .unwrap();
+}
+
+#[mz_ore::test]
+#[cfg_attr(miri, ignore)] // too slow
+fn test_role_valid_until() {
+    let server = test_util::TestHarness::default().start_blocking();
+
+    let mut mz_client = server
+        .pg_config_internal()
+        .user(&SYSTEM_USER.name)
+        .connect(postgres::NoTls)
+        .unwrap();
+    mz_client
+        .batch_execute("CREATE ROLE expired VALID UNTIL '2000-01-01 00:00:00+00'")
+        .unwrap();
+    mz_client
+        .batch_execute("CREATE ROLE contractor VALID UNTIL 'infinity'")
+        .unwrap();
+
+    let err = server
+        .pg_config()
+        .user("expired")
+        .connect(postgres::NoTls)
+        .unwrap_err();
+    assert_eq!(
+        err.code(),
+        Some(&SqlState::INVALID_AUTHORIZATION_SPECIFICATION)
+    );
+
+    let mut contractor_client = server
+        .pg_config()
+        .user("contractor")
+        .connect(postgres::NoTls)
+        .unwrap();
+    contractor_client.batch_execute("SELECT 1").unwrap();
+
+    // Sessions that were established before the role expired may not run any more statements.
+    mz_client
+        .batch_execute("ALTER ROLE contractor VALID UNTIL '2000-01-01 00:00:00+00'")
+        .unwrap();
+    let err = contractor_client.batch_execute("SELECT 1").unwrap_err();
+    assert_eq!(
+        err.code(),
+        Some(&SqlState::INVALID_AUTHORIZATION_SPECIFICATION)
+    );
+    assert_eq!(
+        err.as_db_error().unwrap().message(),
+        "role \"contractor\" has expired"
+    );
+}
+
 #[mz_ore::test]
 #[cfg_attr(miri, ignore)] // too slow
 fn test_max_connections_on_all_interfaces() {
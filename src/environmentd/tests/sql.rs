@@ -450,6 +450,62 @@ fn test_subscribe_negative_diffs() {
     assert_eq!(row.get::<_, i64>("count"), 2);
 }
 
+#[mz_ore::test]
+fn test_subscribe_column_masks() {
+    let server = test_util::TestHarness::default().start_blocking();
+    let mut client_writes = server.connect(postgres::NoTls).unwrap();
+
+    client_writes
+        .batch_execute(
+            "CREATE ROLE analyst;
+             CREATE TABLE t (name text, ssn text);
+             CREATE VIEW v AS SELECT name, ssn FROM t;
+             GRANT SELECT ON t, v TO analyst;
+             ALTER TABLE t ALTER COLUMN ssn SET MASK '***' FOR ROLE analyst;",
+        )
+        .unwrap();
+
+    let mut client_reads = server
+        .pg_config()
+        .user("analyst")
+        .connect(postgres::NoTls)
+        .unwrap();
+    client_reads
+        .batch_execute(
+            "BEGIN;
+         DECLARE c CURSOR FOR SUBSCRIBE t;",
+        )
+        .unwrap();
+
+    client_writes
+        .batch_execute("INSERT INTO t VALUES ('alice', '123-45-6789')")
+        .unwrap();
+    let row = client_reads.query_one("FETCH ALL c", &[]).unwrap();
+
+    assert_eq!(row.get::<_, i64>("mz_diff"), 1);
+    assert_eq!(row.get::<_, String>("name"), "alice");
+    assert_eq!(row.get::<_, String>("ssn"), "***");
+    client_reads.batch_execute("ROLLBACK").unwrap();
+
+    // The view is planned on behalf of its owner, so reading it would bypass the mask.
+    for query in ["SELECT * FROM v", "SUBSCRIBE v"] {
+        let e = client_reads
+            .batch_execute(query)
+            .expect_err("reading the view should fail");
+        let e = e
+            .as_db_error()
+            .unwrap_or_else(|| panic!("expect db error: {}", e));
+        assert_eq!(
+            e.message(),
+            "cannot read materialize.public.v because it depends on materialize.public.t, which has masked columns"
+        );
+    }
+
+    // Roles without masks read the view as usual.
+    let row = client_writes.query_one("SELECT ssn FROM v", &[]).unwrap();
+    assert_eq!(row.get::<_, String>("ssn"), "123-45-6789");
+}
+
 #[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
 async fn test_empty_subscribe_notice() {
     let server = test_util::TestHarness::default()
@@ -3226,6 +3282,144 @@ fn test_max_connections() {
         .collect_vec();
 }
 
+#[mz_ore::test]
+fn test_role_connection_limit() {
+    mz_ore::test::init_logging();
+    let server = test_util::TestHarness::default().start_blocking();
+
+    let mut mz_client = server
+        .pg_config_internal()
+        .user(&SYSTEM_USER.name)
+        .connect(postgres::NoTls)
+        .unwrap();
+    mz_client
+        .batch_execute("CREATE ROLE limited CONNECTION LIMIT 2")
+        .unwrap();
+
+    let connect = || server.pg_config().user("limited").connect(postgres::NoTls);
+
+    {
+        let mut clients = (0..2)
+            .map(|_| {
+                let mut client = connect().unwrap();
+                client.batch_execute("SELECT 1").unwrap();
+                client
+            })
+            .collect_vec();
+
+        let e = connect().map(|_| ()).expect_err("connect should fail");
+        let e = e
+            .as_db_error()
+            .unwrap_or_else(|| panic!("expect db error: {}", e));
+        assert_eq!(e.code(), &SqlState::TOO_MANY_CONNECTIONS);
+        assert_eq!(e.message(), r#"too many connections for role "limited""#);
+
+        // The limit does not apply to other roles, and rejected connections
+        // do not count towards it.
+        let mut client = server.connect(postgres::NoTls).unwrap();
+        client.batch_execute("SELECT 1").unwrap();
+        clients[0]
+            .batch_execute("SELECT 1")
+            .expect("existing connections are unaffected");
+    }
+
+    // After a client disconnects we can connect once the server notices the close.
+    Retry::default()
+        .max_tries(10)
+        .retry(|_state| {
+            let mut client = connect().map_err(|_| ())?;
+            client.batch_execute("SELECT 1").unwrap();
+            Ok::<_, ()>(())
+        })
+        .unwrap();
+
+    // Removing the limit allows any number of connections.
+    mz_client
+        .batch_execute("ALTER ROLE limited CONNECTION LIMIT -1")
+        .unwrap();
+    let _clients = (0..3)
+        .map(|_| {
+            let mut client = connect().unwrap();
+            client.batch_execute("SELECT 1").unwrap();
+            client
+        })
+        .collect_vec();
+}
+
+#[mz_ore::test]
+fn test_service_account_scopes() {
+    mz_ore::test::init_logging();
+    let server = test_util::TestHarness::default().start_blocking();
+
+    let mut mz_client = server
+        .pg_config_internal()
+        .user(&SYSTEM_USER.name)
+        .connect(postgres::NoTls)
+        .unwrap();
+    mz_client
+        .batch_execute(
+            "CREATE ROLE automation;
+             CREATE TABLE t (a int);
+             GRANT SELECT, INSERT ON t TO automation;
+             CREATE SECRET reader_token AS 'reader';
+             CREATE SECRET unscoped_token AS 'unscoped';
+             CREATE SERVICE ACCOUNT reader FOR ROLE automation
+                TOKEN SECRET reader_token SCOPES ('read');
+             CREATE SERVICE ACCOUNT unscoped FOR ROLE automation
+                TOKEN SECRET unscoped_token;",
+        )
+        .unwrap();
+
+    let e = mz_client
+        .batch_execute(
+            "CREATE SERVICE ACCOUNT bad FOR ROLE automation TOKEN SECRET reader_token SCOPES ('admin')",
+        )
+        .unwrap_err();
+    assert_eq!(
+        e.as_db_error().unwrap().message(),
+        r#"invalid service account scope "admin"; valid scopes are: read, write, ddl"#
+    );
+
+    // A token limited to the read scope can query, but not write or run DDL.
+    let mut reader = server
+        .pg_config()
+        .user("reader")
+        .password("reader")
+        .connect(postgres::NoTls)
+        .unwrap();
+    reader.batch_execute("SELECT * FROM t").unwrap();
+    for (statement, action) in [
+        ("INSERT INTO t VALUES (1)", "insert"),
+        ("CREATE TABLE u (a int)", "create table"),
+    ] {
+        let e = reader.batch_execute(statement).unwrap_err();
+        let e = e
+            .as_db_error()
+            .unwrap_or_else(|| panic!("expect db error: {}", e));
+        assert_eq!(e.code(), &SqlState::INSUFFICIENT_PRIVILEGE);
+        assert_eq!(e.message(), format!("permission denied to {action}"));
+    }
+
+    // Transaction control is allowed in any scope.
+    reader
+        .batch_execute("BEGIN; SELECT * FROM t; COMMIT;")
+        .unwrap();
+
+    // A token without scopes is only limited by the privileges of its role.
+    let mut unscoped = server
+        .pg_config()
+        .user("unscoped")
+        .password("unscoped")
+        .connect(postgres::NoTls)
+        .unwrap();
+    unscoped.batch_execute("INSERT INTO t VALUES (1)").unwrap();
+    let count: i64 = unscoped
+        .query_one("SELECT count(*) FROM t", &[])
+        .unwrap()
+        .get(0);
+    assert_eq!(count, 1);
+}
+
 #[mz_ore::test]
 fn test_pg_cancel_backend() {
     mz_ore::test::init_logging();
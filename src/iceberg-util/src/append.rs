@@ -0,0 +1,143 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Appending data files to Iceberg tables.
+
+use std::collections::BTreeMap;
+
+use anyhow::anyhow;
+use mz_ore::now::SYSTEM_TIME;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::catalog::Catalog;
+use crate::manifest::{self, ManifestFile};
+use crate::metadata::Snapshot;
+use crate::IcebergError;
+
+/// The maximum number of times a commit is attempted when it conflicts with
+/// other writers.
+const MAX_COMMIT_ATTEMPTS: usize = 5;
+
+/// A Parquet file that has been written to the location of a table.
+#[derive(Debug, Clone)]
+pub struct DataFile {
+    pub path: String,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+}
+
+/// Appends `file` to a table in a new snapshot, whose summary contains
+/// `properties` in addition to the standard ones.
+///
+/// The table must be unpartitioned. If other writers commit to the table
+/// concurrently, the commit is retried on top of their snapshots.
+pub async fn append_data_file(
+    catalog: &Catalog,
+    namespace: &str,
+    name: &str,
+    file: &DataFile,
+    properties: BTreeMap<String, String>,
+) -> Result<Snapshot, IcebergError> {
+    let mut table = catalog.load_table(namespace, name).await?;
+    let io = catalog.io();
+    let location = table.metadata.location.trim_end_matches('/').to_string();
+
+    // The manifest doesn't depend on the parent snapshot, so it is reused
+    // across attempts.
+    let schema = table
+        .metadata
+        .current_schema()
+        .ok_or_else(|| anyhow!("table has no current schema"))?;
+    let manifest = manifest::encode_manifest(
+        &serde_json::to_string(schema).map_err(anyhow::Error::from)?,
+        schema.schema_id,
+        table.metadata.default_spec_id,
+        &file.path,
+        file.record_count,
+        file.file_size_in_bytes,
+    )?;
+    let manifest_path = format!("{}/metadata/{}-m0.avro", location, Uuid::new_v4());
+    let manifest_length = i64::try_from(manifest.len()).expect("manifest fits in i64");
+    io.write(&manifest_path, manifest).await?;
+
+    let mut attempt = 1;
+    loop {
+        let parent = table.metadata.current_snapshot();
+        let entries = match parent {
+            Some(parent) => {
+                let contents = io.read(&parent.manifest_list).await?;
+                manifest::decode_manifest_list(&contents)?
+            }
+            None => vec![],
+        };
+
+        // Snapshot IDs only need to be unique within the table.
+        let snapshot_id = rand::random::<i64>().checked_abs().unwrap_or(1).max(1);
+        let sequence_number = table.metadata.last_sequence_number + 1;
+        let manifest_list = manifest::encode_manifest_list(
+            snapshot_id,
+            parent.map(|p| p.snapshot_id),
+            entries,
+            &ManifestFile {
+                manifest_path: manifest_path.clone(),
+                manifest_length,
+                partition_spec_id: table.metadata.default_spec_id,
+                sequence_number,
+                added_snapshot_id: snapshot_id,
+                added_rows_count: file.record_count,
+            },
+        )?;
+        let manifest_list_path = format!(
+            "{}/metadata/snap-{}-{}-{}.avro",
+            location,
+            snapshot_id,
+            attempt,
+            Uuid::new_v4()
+        );
+        io.write(&manifest_list_path, manifest_list).await?;
+
+        let mut summary = properties.clone();
+        summary.insert("operation".into(), "append".into());
+        summary.insert("added-data-files".into(), "1".into());
+        summary.insert("added-records".into(), file.record_count.to_string());
+        summary.insert(
+            "added-files-size".into(),
+            file.file_size_in_bytes.to_string(),
+        );
+        let snapshot = Snapshot {
+            snapshot_id,
+            parent_snapshot_id: parent.map(|p| p.snapshot_id),
+            sequence_number,
+            timestamp_ms: i64::try_from((SYSTEM_TIME)()).expect("timestamp fits in i64"),
+            manifest_list: manifest_list_path,
+            summary,
+            schema_id: Some(table.metadata.current_schema_id),
+        };
+
+        match catalog.commit_snapshot(&table, snapshot.clone()).await {
+            Ok(()) => {
+                info!(
+                    "committed snapshot {} to {}.{}",
+                    snapshot_id, namespace, name
+                );
+                return Ok(snapshot);
+            }
+            Err(IcebergError::CommitConflict) if attempt < MAX_COMMIT_ATTEMPTS => {
+                warn!(
+                    "commit to {}.{} conflicted, retrying (attempt {})",
+                    namespace, name, attempt
+                );
+                attempt += 1;
+                table = catalog.load_table(namespace, name).await?;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
@@ -0,0 +1,446 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Iceberg catalogs.
+//!
+//! A catalog maps table names to the location of their current metadata and
+//! arbitrates concurrent commits. REST catalogs apply commits themselves, while
+//! for AWS Glue the new metadata file is written here and Glue only swaps the
+//! pointer to it.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Context};
+use aws_sdk_glue::types::TableInput;
+use aws_types::sdk_config::SdkConfig;
+use mz_ore::error::ErrorExt;
+use reqwest::{Method, StatusCode, Url};
+use serde_json::{json, Value};
+use tracing::info;
+
+use crate::io::FileIo;
+use crate::metadata::{Snapshot, TableMetadata, MAIN_BRANCH};
+use crate::IcebergError;
+
+/// The Glue table parameter that holds the location of the current metadata
+/// file of an Iceberg table.
+const GLUE_METADATA_LOCATION: &str = "metadata_location";
+
+/// The Glue table parameter that holds the location of the previous metadata
+/// file of an Iceberg table.
+const GLUE_PREVIOUS_METADATA_LOCATION: &str = "previous_metadata_location";
+
+/// The Glue table parameter that identifies Iceberg tables.
+const GLUE_TABLE_TYPE: &str = "table_type";
+
+/// A catalog of Iceberg tables.
+#[derive(Debug)]
+pub struct Catalog {
+    inner: CatalogInner,
+    io: FileIo,
+}
+
+#[derive(Debug)]
+enum CatalogInner {
+    Rest {
+        client: reqwest::Client,
+        url: Url,
+        token: Option<String>,
+        /// The prefix of the paths of tables, from the catalog's configuration.
+        prefix: Option<String>,
+    },
+    Glue {
+        client: aws_sdk_glue::Client,
+    },
+}
+
+/// An Iceberg table, as of when it was loaded from its catalog.
+#[derive(Debug, Clone)]
+pub struct Table {
+    pub namespace: String,
+    pub name: String,
+    pub metadata: TableMetadata,
+    /// The location of the metadata file that `metadata` was read from, if
+    /// the catalog reported it.
+    pub metadata_location: Option<String>,
+    /// The Glue table, whose version is checked when committing.
+    glue_table: Option<aws_sdk_glue::types::Table>,
+}
+
+impl Catalog {
+    /// Connects to the REST catalog at `url`, authenticating with `token` if
+    /// it is present.
+    ///
+    /// This fetches the catalog's configuration, so it fails if the catalog
+    /// is unreachable or rejects the token.
+    pub async fn rest(url: &str, token: Option<String>, io: FileIo) -> Result<Self, IcebergError> {
+        let url = Url::parse(url).context("parsing catalog URL")?;
+        if url.cannot_be_a_base() {
+            Err(anyhow!("invalid catalog URL: {}", url))?;
+        }
+        let mut catalog = Catalog {
+            inner: CatalogInner::Rest {
+                client: reqwest::Client::new(),
+                url,
+                token,
+                prefix: None,
+            },
+            io,
+        };
+
+        let config = catalog
+            .rest_request(Method::GET, &["config".into()], None)
+            .await?
+            .ok_or_else(|| anyhow!("catalog has no configuration endpoint"))?;
+        let config_prefix = config
+            .pointer("/overrides/prefix")
+            .or_else(|| config.pointer("/defaults/prefix"))
+            .and_then(|prefix| prefix.as_str());
+        if let (Some(config_prefix), CatalogInner::Rest { prefix, .. }) =
+            (config_prefix, &mut catalog.inner)
+        {
+            info!("using REST catalog prefix {}", config_prefix);
+            *prefix = Some(config_prefix.to_string());
+        }
+        Ok(catalog)
+    }
+
+    /// Returns a catalog backed by AWS Glue.
+    pub fn glue(sdk_config: &SdkConfig, io: FileIo) -> Self {
+        Catalog {
+            inner: CatalogInner::Glue {
+                client: aws_sdk_glue::Client::new(sdk_config),
+            },
+            io,
+        }
+    }
+
+    /// Returns the `FileIo` with which the catalog reads and writes files.
+    pub fn io(&self) -> &FileIo {
+        &self.io
+    }
+
+    /// Loads the current metadata of a table.
+    ///
+    /// Namespaces with multiple levels are separated by dots.
+    pub async fn load_table(&self, namespace: &str, name: &str) -> Result<Table, IcebergError> {
+        match &self.inner {
+            CatalogInner::Rest { prefix, .. } => {
+                let path = table_path(prefix.as_deref(), namespace, name);
+                let response = self
+                    .rest_request(Method::GET, &path, None)
+                    .await?
+                    .ok_or_else(|| IcebergError::TableNotFound {
+                        namespace: namespace.into(),
+                        name: name.into(),
+                    })?;
+                let metadata = response
+                    .get("metadata")
+                    .cloned()
+                    .ok_or_else(|| anyhow!("catalog response has no table metadata"))?;
+                Ok(Table {
+                    namespace: namespace.into(),
+                    name: name.into(),
+                    metadata: TableMetadata::from_json(metadata)?,
+                    metadata_location: response
+                        .get("metadata-location")
+                        .and_then(|l| l.as_str())
+                        .map(|l| l.to_string()),
+                    glue_table: None,
+                })
+            }
+            CatalogInner::Glue { client } => {
+                let not_iceberg = || IcebergError::NotIcebergTable {
+                    namespace: namespace.into(),
+                    name: name.into(),
+                };
+                let response = client
+                    .get_table()
+                    .database_name(namespace)
+                    .name(name)
+                    .send()
+                    .await
+                    .map_err(|e| {
+                        if e.as_service_error()
+                            .map_or(false, |e| e.is_entity_not_found_exception())
+                        {
+                            IcebergError::TableNotFound {
+                                namespace: namespace.into(),
+                                name: name.into(),
+                            }
+                        } else {
+                            anyhow!("{}", e.display_with_causes())
+                                .context("loading table from Glue")
+                                .into()
+                        }
+                    })?;
+                let glue_table = response
+                    .table()
+                    .ok_or_else(|| anyhow!("Glue returned no table"))?
+                    .clone();
+                let parameters = glue_table.parameters().ok_or_else(not_iceberg)?;
+                let is_iceberg = parameters
+                    .get(GLUE_TABLE_TYPE)
+                    .map_or(false, |t| t.eq_ignore_ascii_case("iceberg"));
+                let location = parameters.get(GLUE_METADATA_LOCATION);
+                let Some(location) = location.filter(|_| is_iceberg) else {
+                    return Err(not_iceberg());
+                };
+                let contents = self.io.read(location).await?;
+                let json = serde_json::from_slice(&contents).context("parsing table metadata")?;
+                Ok(Table {
+                    namespace: namespace.into(),
+                    name: name.into(),
+                    metadata: TableMetadata::from_json(json)?,
+                    metadata_location: Some(location.clone()),
+                    glue_table: Some(glue_table),
+                })
+            }
+        }
+    }
+
+    /// Commits `snapshot` as the new head of the main branch of `table`.
+    ///
+    /// The commit fails with [`IcebergError::CommitConflict`] if the table has
+    /// changed since it was loaded.
+    pub async fn commit_snapshot(
+        &self,
+        table: &Table,
+        snapshot: Snapshot,
+    ) -> Result<(), IcebergError> {
+        match &self.inner {
+            CatalogInner::Rest { prefix, .. } => {
+                let body = json!({
+                    "requirements": [
+                        {
+                            "type": "assert-table-uuid",
+                            "uuid": table.metadata.table_uuid,
+                        },
+                        {
+                            "type": "assert-ref-snapshot-id",
+                            "ref": MAIN_BRANCH,
+                            "snapshot-id": table.metadata.current_snapshot().map(|s| s.snapshot_id),
+                        },
+                    ],
+                    "updates": [
+                        {
+                            "action": "add-snapshot",
+                            "snapshot": snapshot,
+                        },
+                        {
+                            "action": "set-snapshot-ref",
+                            "ref-name": MAIN_BRANCH,
+                            "type": "branch",
+                            "snapshot-id": snapshot.snapshot_id,
+                        },
+                    ],
+                });
+                let path = table_path(prefix.as_deref(), &table.namespace, &table.name);
+                self.rest_request(Method::POST, &path, Some(body))
+                    .await?
+                    .ok_or_else(|| IcebergError::TableNotFound {
+                        namespace: table.namespace.clone(),
+                        name: table.name.clone(),
+                    })?;
+                Ok(())
+            }
+            CatalogInner::Glue { client } => {
+                let glue_table = table
+                    .glue_table
+                    .as_ref()
+                    .expect("tables loaded from Glue have a Glue table");
+                let previous_location = table
+                    .metadata_location
+                    .clone()
+                    .expect("tables loaded from Glue have a metadata location");
+
+                let mut metadata = table.metadata.clone();
+                metadata.add_snapshot(snapshot, Some(previous_location.clone()));
+                let location = format!(
+                    "{}/metadata/{:05}-{}.metadata.json",
+                    metadata.location.trim_end_matches('/'),
+                    next_metadata_version(&previous_location),
+                    uuid::Uuid::new_v4()
+                );
+                let contents = serde_json::to_vec(&metadata).context("encoding table metadata")?;
+                self.io.write(&location, contents).await?;
+
+                let mut parameters: HashMap<_, _> =
+                    glue_table.parameters().cloned().unwrap_or_default();
+                parameters.insert(GLUE_METADATA_LOCATION.into(), location);
+                parameters.insert(GLUE_PREVIOUS_METADATA_LOCATION.into(), previous_location);
+                let input = TableInput::builder()
+                    .name(&table.name)
+                    .set_description(glue_table.description().map(|d| d.to_string()))
+                    .set_owner(glue_table.owner().map(|o| o.to_string()))
+                    .retention(glue_table.retention())
+                    .set_storage_descriptor(glue_table.storage_descriptor().cloned())
+                    .set_partition_keys(glue_table.partition_keys().map(|k| k.to_vec()))
+                    .set_table_type(glue_table.table_type().map(|t| t.to_string()))
+                    .set_parameters(Some(parameters))
+                    .build();
+
+                // Glue only applies the update if the table is still at the
+                // version that was loaded.
+                client
+                    .update_table()
+                    .database_name(&table.namespace)
+                    .table_input(input)
+                    .set_version_id(glue_table.version_id().map(|v| v.to_string()))
+                    .send()
+                    .await
+                    .map_err(|e| {
+                        if e.as_service_error()
+                            .map_or(false, |e| e.is_concurrent_modification_exception())
+                        {
+                            IcebergError::CommitConflict
+                        } else {
+                            anyhow!("{}", e.display_with_causes())
+                                .context("updating table in Glue")
+                                .into()
+                        }
+                    })?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Sends a request to a REST catalog and returns the JSON response, or
+    /// `None` if the requested resource does not exist.
+    ///
+    /// `path` is relative to the version of the API.
+    async fn rest_request(
+        &self,
+        method: Method,
+        path: &[String],
+        body: Option<Value>,
+    ) -> Result<Option<Value>, IcebergError> {
+        let CatalogInner::Rest {
+            client, url, token, ..
+        } = &self.inner
+        else {
+            panic!("rest_request called on a non-REST catalog");
+        };
+        let mut url = url.clone();
+        url.path_segments_mut()
+            .expect("checked when the catalog was created")
+            .pop_if_empty()
+            .push("v1")
+            .extend(path);
+
+        let mut request = client.request(method, url);
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| anyhow!("{}", e.display_with_causes()))
+            .context("sending request to catalog")?;
+
+        let status = response.status();
+        match status {
+            StatusCode::NOT_FOUND => Ok(None),
+            StatusCode::CONFLICT => Err(IcebergError::CommitConflict),
+            StatusCode::NO_CONTENT => Ok(Some(Value::Null)),
+            status if status.is_success() => {
+                let json = response
+                    .json()
+                    .await
+                    .context("reading response from catalog")?;
+                Ok(Some(json))
+            }
+            status => {
+                // Catalogs describe errors in an `ErrorModel` object.
+                let message = response
+                    .json::<Value>()
+                    .await
+                    .ok()
+                    .and_then(|error| {
+                        error
+                            .pointer("/error/message")
+                            .and_then(|m| m.as_str())
+                            .map(|m| m.to_string())
+                    })
+                    .unwrap_or_default();
+                let error = if message.is_empty() {
+                    anyhow!("catalog responded with status {}", status)
+                } else {
+                    anyhow!("catalog responded with status {}: {}", status, message)
+                };
+                Err(error.into())
+            }
+        }
+    }
+}
+
+/// Returns the segments of the REST path of a table, relative to the version
+/// of the API.
+///
+/// The levels of a namespace are separated by the unit separator character.
+fn table_path(prefix: Option<&str>, namespace: &str, name: &str) -> Vec<String> {
+    let mut path: Vec<String> = prefix
+        .into_iter()
+        .flat_map(|prefix| prefix.split('/'))
+        .map(|segment| segment.to_string())
+        .collect();
+    path.extend([
+        "namespaces".into(),
+        namespace.replace('.', "\u{1f}"),
+        "tables".into(),
+        name.into(),
+    ]);
+    path
+}
+
+/// Returns the version number of the metadata file that follows the one at
+/// `location`.
+///
+/// Metadata files are conventionally named `<version>-<uuid>.metadata.json`.
+/// Files that don't follow the convention are treated as version zero.
+fn next_metadata_version(location: &str) -> u64 {
+    let file_name = location.rsplit('/').next().unwrap_or_default();
+    let version = file_name
+        .split_once('-')
+        .and_then(|(version, _)| version.parse::<u64>().ok())
+        .unwrap_or(0);
+    version + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_next_metadata_version() {
+        assert_eq!(
+            next_metadata_version("s3://b/t/metadata/00007-0a1b.metadata.json"),
+            8
+        );
+        assert_eq!(
+            next_metadata_version("s3://b/t/metadata/v3.metadata.json"),
+            1
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_table_path() {
+        assert_eq!(
+            table_path(None, "a.b", "t"),
+            ["namespaces", "a\u{1f}b", "tables", "t"].map(String::from)
+        );
+        assert_eq!(
+            table_path(Some("catalogs/prod"), "db", "t"),
+            ["catalogs", "prod", "namespaces", "db", "tables", "t"].map(String::from)
+        );
+    }
+}
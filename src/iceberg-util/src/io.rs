@@ -0,0 +1,105 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Reading and writing the files of Iceberg tables.
+
+use anyhow::{anyhow, bail, Context};
+use aws_sdk_s3::primitives::ByteStream;
+use aws_types::sdk_config::SdkConfig;
+use mz_ore::error::ErrorExt;
+
+/// Reads and writes files in the object storage of Iceberg tables.
+///
+/// Only tables stored in S3 are supported. Files are addressed by the URIs
+/// that Iceberg stores in its metadata, e.g. `s3://bucket/path/to/file`.
+#[derive(Debug, Clone)]
+pub struct FileIo {
+    client: aws_sdk_s3::Client,
+}
+
+impl FileIo {
+    /// Creates a `FileIo` that accesses S3 with the given SDK config.
+    pub fn new(sdk_config: &SdkConfig) -> Self {
+        FileIo {
+            client: mz_aws_s3_util::new_client(sdk_config),
+        }
+    }
+
+    /// Reads the contents of the file at `uri`.
+    pub async fn read(&self, uri: &str) -> Result<Vec<u8>, anyhow::Error> {
+        let (bucket, key) = parse_s3_uri(uri)?;
+        let object = self
+            .client
+            .get_object()
+            .bucket(bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(|e| anyhow!("{}", e.display_with_causes()))
+            .with_context(|| format!("reading {}", uri))?;
+        let bytes = object
+            .body
+            .collect()
+            .await
+            .with_context(|| format!("reading {}", uri))?;
+        Ok(bytes.into_bytes().to_vec())
+    }
+
+    /// Writes `contents` to the file at `uri`, replacing any existing file.
+    pub async fn write(&self, uri: &str, contents: Vec<u8>) -> Result<(), anyhow::Error> {
+        let (bucket, key) = parse_s3_uri(uri)?;
+        self.client
+            .put_object()
+            .bucket(bucket)
+            .key(key)
+            .body(ByteStream::from(contents))
+            .send()
+            .await
+            .map_err(|e| anyhow!("{}", e.display_with_causes()))
+            .with_context(|| format!("writing {}", uri))?;
+        Ok(())
+    }
+}
+
+/// Splits an S3 URI into its bucket and key.
+///
+/// The `s3a` and `s3n` schemes that Hadoop-based writers use are accepted as
+/// aliases of `s3`.
+fn parse_s3_uri(uri: &str) -> Result<(&str, &str), anyhow::Error> {
+    let Some((scheme, path)) = uri.split_once("://") else {
+        bail!("invalid file URI: {}", uri);
+    };
+    if !matches!(scheme, "s3" | "s3a" | "s3n") {
+        bail!(
+            "unsupported file URI scheme {}: only S3 is supported",
+            scheme
+        );
+    }
+    match path.split_once('/') {
+        Some((bucket, key)) if !bucket.is_empty() && !key.is_empty() => Ok((bucket, key)),
+        _ => bail!("invalid S3 URI: {}", uri),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_s3_uri;
+
+    #[mz_ore::test]
+    fn test_parse_s3_uri() {
+        assert_eq!(
+            parse_s3_uri("s3://bucket/warehouse/db/t/data/f.parquet").unwrap(),
+            ("bucket", "warehouse/db/t/data/f.parquet")
+        );
+        assert_eq!(parse_s3_uri("s3a://b/k").unwrap(), ("b", "k"));
+        assert!(parse_s3_uri("gs://b/k").is_err());
+        assert!(parse_s3_uri("s3://bucket").is_err());
+        assert!(parse_s3_uri("s3:///key").is_err());
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+// BEGIN LINT CONFIG
+// DO NOT EDIT. Automatically generated by bin/gen-lints.
+// Have complaints about the noise? See the note in misc/python/materialize/cli/gen-lints.py first.
+#![allow(unknown_lints)]
+#![allow(clippy::style)]
+#![allow(clippy::complexity)]
+#![allow(clippy::large_enum_variant)]
+#![allow(clippy::mutable_key_type)]
+#![allow(clippy::stable_sort_primitive)]
+#![allow(clippy::map_entry)]
+#![allow(clippy::box_default)]
+#![allow(clippy::drain_collect)]
+#![warn(clippy::bool_comparison)]
+#![warn(clippy::clone_on_ref_ptr)]
+#![warn(clippy::no_effect)]
+#![warn(clippy::unnecessary_unwrap)]
+#![warn(clippy::dbg_macro)]
+#![warn(clippy::todo)]
+#![warn(clippy::wildcard_dependencies)]
+#![warn(clippy::zero_prefixed_literal)]
+#![warn(clippy::borrowed_box)]
+#![warn(clippy::deref_addrof)]
+#![warn(clippy::double_must_use)]
+#![warn(clippy::double_parens)]
+#![warn(clippy::extra_unused_lifetimes)]
+#![warn(clippy::needless_borrow)]
+#![warn(clippy::needless_question_mark)]
+#![warn(clippy::needless_return)]
+#![warn(clippy::redundant_pattern)]
+#![warn(clippy::redundant_slicing)]
+#![warn(clippy::redundant_static_lifetimes)]
+#![warn(clippy::single_component_path_imports)]
+#![warn(clippy::unnecessary_cast)]
+#![warn(clippy::useless_asref)]
+#![warn(clippy::useless_conversion)]
+#![warn(clippy::builtin_type_shadow)]
+#![warn(clippy::duplicate_underscore_argument)]
+#![warn(clippy::double_neg)]
+#![warn(clippy::unnecessary_mut_passed)]
+#![warn(clippy::wildcard_in_or_patterns)]
+#![warn(clippy::crosspointer_transmute)]
+#![warn(clippy::excessive_precision)]
+#![warn(clippy::overflow_check_conditional)]
+#![warn(clippy::as_conversions)]
+#![warn(clippy::match_overlapping_arm)]
+#![warn(clippy::zero_divided_by_zero)]
+#![warn(clippy::must_use_unit)]
+#![warn(clippy::suspicious_assignment_formatting)]
+#![warn(clippy::suspicious_else_formatting)]
+#![warn(clippy::suspicious_unary_op_formatting)]
+#![warn(clippy::mut_mutex_lock)]
+#![warn(clippy::print_literal)]
+#![warn(clippy::same_item_push)]
+#![warn(clippy::useless_format)]
+#![warn(clippy::write_literal)]
+#![warn(clippy::redundant_closure)]
+#![warn(clippy::redundant_closure_call)]
+#![warn(clippy::unnecessary_lazy_evaluations)]
+#![warn(clippy::partialeq_ne_impl)]
+#![warn(clippy::redundant_field_names)]
+#![warn(clippy::transmutes_expressible_as_ptr_casts)]
+#![warn(clippy::unused_async)]
+#![warn(clippy::disallowed_methods)]
+#![warn(clippy::disallowed_macros)]
+#![warn(clippy::disallowed_types)]
+#![warn(clippy::from_over_into)]
+// END LINT CONFIG
+
+//! Apache Iceberg utilities.
+//!
+//! This crate implements the small subset of the [Iceberg table spec] that
+//! Materialize needs to append data files to existing tables: reading and
+//! writing table metadata through a REST or AWS Glue catalog, and writing the
+//! manifests that reference new data files. Only format version 2 tables are
+//! supported.
+//!
+//! [Iceberg table spec]: https://iceberg.apache.org/spec/
+
+mod append;
+mod catalog;
+mod io;
+mod manifest;
+mod metadata;
+
+pub use append::{append_data_file, DataFile};
+pub use catalog::{Catalog, Table};
+pub use io::FileIo;
+pub use metadata::{
+    MetadataLogEntry, NestedField, PartitionSpec, Schema, Snapshot, SnapshotLogEntry,
+    SnapshotReference, TableMetadata, MAIN_BRANCH,
+};
+
+/// An error interacting with an Iceberg table.
+#[derive(Debug, thiserror::Error)]
+pub enum IcebergError {
+    /// The table does not exist in the catalog.
+    #[error("table {namespace}.{name} does not exist")]
+    TableNotFound { namespace: String, name: String },
+    /// The table is not an Iceberg table, e.g. a plain Glue table.
+    #[error("table {namespace}.{name} is not an Iceberg table")]
+    NotIcebergTable { namespace: String, name: String },
+    /// The table uses a format version other than 2.
+    #[error("table uses Iceberg format version {0}, but only version 2 is supported")]
+    UnsupportedFormatVersion(u64),
+    /// Another writer committed to the table since its metadata was loaded.
+    #[error("table was modified concurrently")]
+    CommitConflict,
+    /// Any other error.
+    #[error(transparent)]
+    Generic(#[from] anyhow::Error),
+}
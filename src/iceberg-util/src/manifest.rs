@@ -0,0 +1,321 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Iceberg manifests and manifest lists.
+//!
+//! Both are Avro object container files. Iceberg readers resolve their fields
+//! by the `field-id` attributes of the writer schema, which `mz_avro` does not
+//! preserve, so the container is written here with the schema verbatim and
+//! only the records are encoded by `mz_avro`.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use anyhow::Context;
+use mz_avro::types::Value;
+use mz_avro::Schema;
+use once_cell::sync::Lazy;
+
+/// The schema of the entries of a manifest of a data file in an unpartitioned
+/// table.
+///
+/// Optional fields that Materialize never sets, like column statistics, are
+/// omitted.
+const MANIFEST_ENTRY_SCHEMA: &str = r#"{
+    "type": "record",
+    "name": "manifest_entry",
+    "fields": [
+        {"name": "status", "type": "int", "field-id": 0},
+        {"name": "snapshot_id", "type": ["null", "long"], "default": null, "field-id": 1},
+        {"name": "sequence_number", "type": ["null", "long"], "default": null, "field-id": 3},
+        {"name": "file_sequence_number", "type": ["null", "long"], "default": null, "field-id": 4},
+        {"name": "data_file", "field-id": 2, "type": {
+            "type": "record",
+            "name": "r2",
+            "fields": [
+                {"name": "content", "type": "int", "field-id": 134},
+                {"name": "file_path", "type": "string", "field-id": 100},
+                {"name": "file_format", "type": "string", "field-id": 101},
+                {"name": "partition", "field-id": 102, "type": {
+                    "type": "record",
+                    "name": "r102",
+                    "fields": []
+                }},
+                {"name": "record_count", "type": "long", "field-id": 103},
+                {"name": "file_size_in_bytes", "type": "long", "field-id": 104}
+            ]
+        }}
+    ]
+}"#;
+
+/// The schema of the entries of a manifest list.
+///
+/// This is also used as the reader schema for the manifest lists of existing
+/// snapshots, which drops the optional partition summaries and key metadata
+/// of their entries.
+const MANIFEST_FILE_SCHEMA: &str = r#"{
+    "type": "record",
+    "name": "manifest_file",
+    "fields": [
+        {"name": "manifest_path", "type": "string", "field-id": 500},
+        {"name": "manifest_length", "type": "long", "field-id": 501},
+        {"name": "partition_spec_id", "type": "int", "field-id": 502},
+        {"name": "content", "type": "int", "field-id": 517},
+        {"name": "sequence_number", "type": "long", "field-id": 515},
+        {"name": "min_sequence_number", "type": "long", "field-id": 516},
+        {"name": "added_snapshot_id", "type": "long", "field-id": 503},
+        {"name": "added_files_count", "type": "int", "field-id": 504},
+        {"name": "existing_files_count", "type": "int", "field-id": 505},
+        {"name": "deleted_files_count", "type": "int", "field-id": 506},
+        {"name": "added_rows_count", "type": "long", "field-id": 512},
+        {"name": "existing_rows_count", "type": "long", "field-id": 513},
+        {"name": "deleted_rows_count", "type": "long", "field-id": 514}
+    ]
+}"#;
+
+static MANIFEST_ENTRY: Lazy<Schema> =
+    Lazy::new(|| Schema::from_str(MANIFEST_ENTRY_SCHEMA).expect("valid schema"));
+
+static MANIFEST_FILE: Lazy<Schema> =
+    Lazy::new(|| Schema::from_str(MANIFEST_FILE_SCHEMA).expect("valid schema"));
+
+/// The status of a manifest entry whose file was added in its snapshot.
+const STATUS_ADDED: i32 = 1;
+
+/// The content type of manifests and data files that contain rows, rather than
+/// deletes.
+const CONTENT_DATA: i32 = 0;
+
+/// The description of a manifest in a manifest list.
+#[derive(Clone, Debug)]
+pub struct ManifestFile {
+    pub manifest_path: String,
+    pub manifest_length: i64,
+    pub partition_spec_id: i32,
+    pub sequence_number: i64,
+    pub added_snapshot_id: i64,
+    pub added_rows_count: i64,
+}
+
+/// Encodes a manifest that adds a single data file to an unpartitioned table.
+///
+/// The snapshot ID and sequence number of the entry are left unset, so that
+/// they are inherited from the manifest list entry of the snapshot that
+/// commits it. This lets the manifest be reused if the commit has to be
+/// retried.
+pub fn encode_manifest(
+    schema_json: &str,
+    schema_id: i32,
+    spec_id: i32,
+    file_path: &str,
+    record_count: i64,
+    file_size_in_bytes: i64,
+) -> Result<Vec<u8>, anyhow::Error> {
+    let null = || union(0, Value::Null);
+    let entry = Value::Record(vec![
+        ("status".into(), Value::Int(STATUS_ADDED)),
+        ("snapshot_id".into(), null()),
+        ("sequence_number".into(), null()),
+        ("file_sequence_number".into(), null()),
+        (
+            "data_file".into(),
+            Value::Record(vec![
+                ("content".into(), Value::Int(CONTENT_DATA)),
+                ("file_path".into(), Value::String(file_path.into())),
+                ("file_format".into(), Value::String("PARQUET".into())),
+                ("partition".into(), Value::Record(vec![])),
+                ("record_count".into(), Value::Long(record_count)),
+                ("file_size_in_bytes".into(), Value::Long(file_size_in_bytes)),
+            ]),
+        ),
+    ]);
+    let metadata = BTreeMap::from([
+        ("schema", schema_json.to_string()),
+        ("schema-id", schema_id.to_string()),
+        ("partition-spec", "[]".to_string()),
+        ("partition-spec-id", spec_id.to_string()),
+        ("format-version", "2".to_string()),
+        ("content", "data".to_string()),
+    ]);
+    encode_container(
+        MANIFEST_ENTRY_SCHEMA,
+        &MANIFEST_ENTRY,
+        metadata,
+        vec![entry],
+    )
+}
+
+/// Reads the entries of an existing manifest list.
+///
+/// The entries are returned as Avro values that can be passed back to
+/// [`encode_manifest_list`].
+pub fn decode_manifest_list(contents: &[u8]) -> Result<Vec<Value>, anyhow::Error> {
+    let reader =
+        mz_avro::Reader::with_schema(&MANIFEST_FILE, contents).context("reading manifest list")?;
+    let entries = reader
+        .collect::<Result<Vec<_>, _>>()
+        .context("reading manifest list")?;
+    Ok(entries)
+}
+
+/// Encodes the manifest list of a snapshot that consists of the manifests of
+/// its parent, as returned by [`decode_manifest_list`], and one new manifest.
+pub fn encode_manifest_list(
+    snapshot_id: i64,
+    parent_snapshot_id: Option<i64>,
+    mut entries: Vec<Value>,
+    new: &ManifestFile,
+) -> Result<Vec<u8>, anyhow::Error> {
+    entries.push(Value::Record(vec![
+        (
+            "manifest_path".into(),
+            Value::String(new.manifest_path.clone()),
+        ),
+        ("manifest_length".into(), Value::Long(new.manifest_length)),
+        (
+            "partition_spec_id".into(),
+            Value::Int(new.partition_spec_id),
+        ),
+        ("content".into(), Value::Int(CONTENT_DATA)),
+        ("sequence_number".into(), Value::Long(new.sequence_number)),
+        (
+            "min_sequence_number".into(),
+            Value::Long(new.sequence_number),
+        ),
+        (
+            "added_snapshot_id".into(),
+            Value::Long(new.added_snapshot_id),
+        ),
+        ("added_files_count".into(), Value::Int(1)),
+        ("existing_files_count".into(), Value::Int(0)),
+        ("deleted_files_count".into(), Value::Int(0)),
+        ("added_rows_count".into(), Value::Long(new.added_rows_count)),
+        ("existing_rows_count".into(), Value::Long(0)),
+        ("deleted_rows_count".into(), Value::Long(0)),
+    ]));
+    let mut metadata = BTreeMap::from([
+        ("snapshot-id", snapshot_id.to_string()),
+        ("sequence-number", new.sequence_number.to_string()),
+        ("format-version", "2".to_string()),
+    ]);
+    metadata.insert(
+        "parent-snapshot-id",
+        parent_snapshot_id.map_or_else(|| "null".to_string(), |id| id.to_string()),
+    );
+    encode_container(MANIFEST_FILE_SCHEMA, &MANIFEST_FILE, metadata, entries)
+}
+
+/// Returns the value of variant `index` of a union with two variants, the
+/// first of which is null.
+fn union(index: usize, inner: Value) -> Value {
+    Value::Union {
+        index,
+        inner: Box::new(inner),
+        n_variants: 2,
+        null_variant: Some(0),
+    }
+}
+
+/// Encodes `records` as an uncompressed Avro object container file, with
+/// `schema_json` as the writer schema and `metadata` as additional metadata.
+fn encode_container(
+    schema_json: &str,
+    schema: &Schema,
+    metadata: BTreeMap<&str, String>,
+    records: Vec<Value>,
+) -> Result<Vec<u8>, anyhow::Error> {
+    let mut file = b"Obj\x01".to_vec();
+
+    let mut entries = vec![("avro.schema", schema_json.as_bytes().to_vec())];
+    entries.push(("avro.codec", b"null".to_vec()));
+    entries.extend(metadata.iter().map(|(k, v)| (*k, v.as_bytes().to_vec())));
+    encode_long(&mut file, i64::try_from(entries.len()).expect("small"));
+    for (key, value) in entries {
+        encode_bytes(&mut file, key.as_bytes());
+        encode_bytes(&mut file, &value);
+    }
+    encode_long(&mut file, 0);
+
+    let sync_marker = *uuid::Uuid::new_v4().as_bytes();
+    file.extend_from_slice(&sync_marker);
+
+    let mut block = vec![];
+    let count = records.len();
+    for record in records {
+        mz_avro::write_avro_datum(schema, record, &mut block)?;
+    }
+    encode_long(&mut file, i64::try_from(count).expect("small"));
+    encode_bytes(&mut file, &block);
+    file.extend_from_slice(&sync_marker);
+
+    Ok(file)
+}
+
+/// Appends `n` in Avro's zig-zag variable-length encoding.
+fn encode_long(buf: &mut Vec<u8>, n: i64) {
+    let mut z = u64::from_ne_bytes(((n << 1) ^ (n >> 63)).to_ne_bytes());
+    loop {
+        if z & !0x7f == 0 {
+            buf.push(u8::try_from(z).expect("less than 0x80"));
+            return;
+        }
+        buf.push(u8::try_from((z & 0x7f) | 0x80).expect("less than 0x100"));
+        z >>= 7;
+    }
+}
+
+/// Appends `bytes` prefixed by their length.
+fn encode_bytes(buf: &mut Vec<u8>, bytes: &[u8]) {
+    encode_long(buf, i64::try_from(bytes.len()).expect("small"));
+    buf.extend_from_slice(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_manifest_list_roundtrip() {
+        let manifest = |path: &str, sequence_number| ManifestFile {
+            manifest_path: path.into(),
+            manifest_length: 100,
+            partition_spec_id: 0,
+            sequence_number,
+            added_snapshot_id: sequence_number,
+            added_rows_count: 10,
+        };
+        let first = encode_manifest_list(1, None, vec![], &manifest("m1.avro", 1)).unwrap();
+        let entries = decode_manifest_list(&first).unwrap();
+        assert_eq!(entries.len(), 1);
+
+        let second = encode_manifest_list(2, Some(1), entries, &manifest("m2.avro", 2)).unwrap();
+        let entries = decode_manifest_list(&second).unwrap();
+        let paths: Vec<_> = entries
+            .iter()
+            .map(|entry| match entry {
+                Value::Record(fields) => fields[0].1.clone(),
+                _ => panic!("unexpected value {:?}", entry),
+            })
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                Value::String("m1.avro".into()),
+                Value::String("m2.avro".into())
+            ]
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_encode_manifest() {
+        let manifest = encode_manifest("{}", 0, 0, "s3://b/data/f.parquet", 10, 1024).unwrap();
+        let reader = mz_avro::Reader::new(manifest.as_slice()).unwrap();
+        assert_eq!(reader.count(), 1);
+    }
+}
@@ -0,0 +1,308 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Iceberg table metadata.
+//!
+//! Only the fields that Materialize reads or writes are modeled. All other
+//! fields are kept in `other` maps, so that rewriting the metadata preserves
+//! them.
+
+use std::collections::BTreeMap;
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::IcebergError;
+
+/// The name of the branch that holds the current state of a table.
+pub const MAIN_BRANCH: &str = "main";
+
+/// The metadata of an Iceberg table, as stored in its metadata files.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct TableMetadata {
+    pub format_version: u64,
+    pub table_uuid: String,
+    pub location: String,
+    pub last_sequence_number: i64,
+    pub last_updated_ms: i64,
+    pub current_schema_id: i32,
+    pub schemas: Vec<Schema>,
+    pub default_spec_id: i32,
+    pub partition_specs: Vec<PartitionSpec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub snapshots: Vec<Snapshot>,
+    #[serde(default)]
+    pub snapshot_log: Vec<SnapshotLogEntry>,
+    #[serde(default)]
+    pub metadata_log: Vec<MetadataLogEntry>,
+    #[serde(default)]
+    pub properties: BTreeMap<String, String>,
+    #[serde(default)]
+    pub refs: BTreeMap<String, SnapshotReference>,
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+impl TableMetadata {
+    /// Parses table metadata from its JSON representation.
+    pub fn from_json(json: Value) -> Result<Self, IcebergError> {
+        // Check the version first, so that older tables get a better error
+        // than a missing field.
+        let version = json.get("format-version").and_then(|v| v.as_u64());
+        match version {
+            Some(2) => (),
+            Some(version) => return Err(IcebergError::UnsupportedFormatVersion(version)),
+            None => Err(anyhow::anyhow!("table metadata has no format version"))?,
+        }
+        let metadata = serde_json::from_value(json).context("parsing table metadata")?;
+        Ok(metadata)
+    }
+
+    /// Returns the current schema of the table.
+    pub fn current_schema(&self) -> Option<&Schema> {
+        self.schemas
+            .iter()
+            .find(|schema| schema.schema_id == self.current_schema_id)
+    }
+
+    /// Reports whether new data files are written with a partition spec
+    /// that has any fields.
+    pub fn is_partitioned(&self) -> bool {
+        self.partition_specs
+            .iter()
+            .find(|spec| spec.spec_id == self.default_spec_id)
+            .map_or(false, |spec| !spec.fields.is_empty())
+    }
+
+    /// Returns the snapshot at the head of the main branch, if any.
+    pub fn current_snapshot(&self) -> Option<&Snapshot> {
+        let id = match self.refs.get(MAIN_BRANCH) {
+            Some(reference) => reference.snapshot_id,
+            // Writers use -1 to indicate that there is no snapshot.
+            None => self.current_snapshot_id.filter(|id| *id != -1)?,
+        };
+        self.snapshot(id)
+    }
+
+    /// Returns the snapshot with the given ID, if it exists.
+    pub fn snapshot(&self, id: i64) -> Option<&Snapshot> {
+        self.snapshots
+            .iter()
+            .find(|snapshot| snapshot.snapshot_id == id)
+    }
+
+    /// Returns the snapshots of the main branch, starting with the current
+    /// one and following parents until one has expired.
+    pub fn ancestry(&self) -> impl Iterator<Item = &Snapshot> {
+        std::iter::successors(self.current_snapshot(), |snapshot| {
+            self.snapshot(snapshot.parent_snapshot_id?)
+        })
+    }
+
+    /// Makes `snapshot` the head of the main branch, as a catalog does when it
+    /// commits an `add-snapshot` and a `set-snapshot-ref` update.
+    ///
+    /// `previous_location` is the location of the metadata file from which
+    /// this metadata was read, if any.
+    pub fn add_snapshot(&mut self, snapshot: Snapshot, previous_location: Option<String>) {
+        if let Some(metadata_file) = previous_location {
+            self.metadata_log.push(MetadataLogEntry {
+                metadata_file,
+                timestamp_ms: self.last_updated_ms,
+            });
+        }
+        self.last_sequence_number = snapshot.sequence_number;
+        self.last_updated_ms = snapshot.timestamp_ms;
+        self.current_snapshot_id = Some(snapshot.snapshot_id);
+        self.snapshot_log.push(SnapshotLogEntry {
+            snapshot_id: snapshot.snapshot_id,
+            timestamp_ms: snapshot.timestamp_ms,
+        });
+        self.refs.insert(
+            MAIN_BRANCH.into(),
+            SnapshotReference::branch(snapshot.snapshot_id),
+        );
+        self.snapshots.push(snapshot);
+    }
+}
+
+/// A schema of an Iceberg table.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Schema {
+    pub schema_id: i32,
+    pub fields: Vec<NestedField>,
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+/// A field of a schema.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NestedField {
+    pub id: i32,
+    pub name: String,
+    pub required: bool,
+    /// The type of the field, which is a string for primitive types, e.g.
+    /// `"long"` or `"decimal(10, 2)"`, and an object for nested types.
+    #[serde(rename = "type")]
+    pub field_type: Value,
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+/// A partition spec of an Iceberg table.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PartitionSpec {
+    pub spec_id: i32,
+    pub fields: Vec<Value>,
+}
+
+/// A snapshot of an Iceberg table.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Snapshot {
+    pub snapshot_id: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_snapshot_id: Option<i64>,
+    pub sequence_number: i64,
+    pub timestamp_ms: i64,
+    pub manifest_list: String,
+    pub summary: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<i32>,
+}
+
+/// A named reference to a snapshot.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct SnapshotReference {
+    pub snapshot_id: i64,
+    #[serde(rename = "type")]
+    pub ref_type: String,
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+impl SnapshotReference {
+    /// Returns a reference to a branch whose head is `snapshot_id`.
+    pub fn branch(snapshot_id: i64) -> Self {
+        SnapshotReference {
+            snapshot_id,
+            ref_type: "branch".into(),
+            other: BTreeMap::new(),
+        }
+    }
+}
+
+/// An entry of the snapshot log of a table.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct SnapshotLogEntry {
+    pub snapshot_id: i64,
+    pub timestamp_ms: i64,
+}
+
+/// An entry of the metadata log of a table.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MetadataLogEntry {
+    pub metadata_file: String,
+    pub timestamp_ms: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn metadata() -> Value {
+        json!({
+            "format-version": 2,
+            "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+            "location": "s3://bucket/warehouse/db/t",
+            "last-sequence-number": 1,
+            "last-updated-ms": 1602638573590_i64,
+            "last-column-id": 2,
+            "current-schema-id": 0,
+            "schemas": [{
+                "type": "struct",
+                "schema-id": 0,
+                "fields": [
+                    {"id": 1, "name": "a", "required": false, "type": "long"},
+                    {"id": 2, "name": "b", "required": false, "type": "string"},
+                ],
+            }],
+            "default-spec-id": 0,
+            "partition-specs": [{"spec-id": 0, "fields": []}],
+            "current-snapshot-id": 10,
+            "snapshots": [{
+                "snapshot-id": 10,
+                "sequence-number": 1,
+                "timestamp-ms": 1602638573590_i64,
+                "manifest-list": "s3://bucket/warehouse/db/t/metadata/snap-10.avro",
+                "summary": {"operation": "append"},
+                "schema-id": 0,
+            }],
+            "properties": {"owner": "root"},
+        })
+    }
+
+    #[mz_ore::test]
+    fn test_roundtrip_preserves_unknown_fields() {
+        let json = metadata();
+        let metadata = TableMetadata::from_json(json.clone()).unwrap();
+        assert_eq!(metadata.other.get("last-column-id"), Some(&json!(2)));
+        let roundtripped = serde_json::to_value(&metadata).unwrap();
+        assert_eq!(roundtripped["schemas"], json["schemas"]);
+        assert_eq!(roundtripped["last-column-id"], json!(2));
+    }
+
+    #[mz_ore::test]
+    fn test_add_snapshot() {
+        let mut metadata = TableMetadata::from_json(metadata()).unwrap();
+        assert!(!metadata.is_partitioned());
+        assert_eq!(metadata.current_snapshot().unwrap().snapshot_id, 10);
+
+        let snapshot = Snapshot {
+            snapshot_id: 11,
+            parent_snapshot_id: Some(10),
+            sequence_number: 2,
+            timestamp_ms: 1602638580000,
+            manifest_list: "s3://bucket/warehouse/db/t/metadata/snap-11.avro".into(),
+            summary: BTreeMap::new(),
+            schema_id: Some(0),
+        };
+        metadata.add_snapshot(snapshot, Some("s3://bucket/v1.metadata.json".into()));
+        assert_eq!(metadata.last_sequence_number, 2);
+        assert_eq!(
+            metadata
+                .ancestry()
+                .map(|s| s.snapshot_id)
+                .collect::<Vec<_>>(),
+            vec![11, 10]
+        );
+        assert_eq!(metadata.metadata_log.len(), 1);
+    }
+
+    #[mz_ore::test]
+    fn test_unsupported_version() {
+        let mut json = metadata();
+        json["format-version"] = json!(1);
+        assert!(matches!(
+            TableMetadata::from_json(json),
+            Err(IcebergError::UnsupportedFormatVersion(1))
+        ));
+    }
+}
@@ -112,6 +112,22 @@ fn encode_message_unchecked(
     buf
 }
 
+/// Like [`encode_message_unchecked`], but returns an error rather than
+/// panicking or producing an invalid message if `row` cannot be represented by
+/// `schema`.
+fn encode_message(
+    schema_id: i32,
+    row: Row,
+    schema: &Schema,
+    columns: &[(ColumnName, ColumnType)],
+) -> Result<Vec<u8>, anyhow::Error> {
+    let mut buf = vec![];
+    encode_avro_header(&mut buf, schema_id);
+    let value = try_encode_datums_as_avro(row.iter(), columns)?;
+    mz_avro::write_avro_datum(schema, value, &mut buf)?;
+    Ok(buf)
+}
+
 #[derive(Debug, Default)]
 pub struct AvroSchemaOptions {
     /// Optional avro fullname on the generated key schema.
@@ -122,6 +138,10 @@ pub struct AvroSchemaOptions {
     pub set_null_defaults: bool,
     /// Boolean flag to indicate debezium envelope
     pub is_debezium: bool,
+    /// Boolean flag to indicate the full debezium envelope, which includes
+    /// the `source`, `op`, and `ts_ms` fields. Only meaningful with
+    /// `is_debezium`.
+    pub is_debezium_full: bool,
     /// The global ID of the item in the sink. This is used
     /// to lookup corresponding documentation for objects and fields
     /// in the `value_doc_options` and `key_doc_options`.
@@ -163,6 +183,7 @@ impl AvroSchemaGenerator {
         value_desc: RelationDesc,
         AvroSchemaOptions {
             is_debezium,
+            is_debezium_full,
             avro_value_fullname,
             avro_key_fullname,
             set_null_defaults,
@@ -174,6 +195,9 @@ impl AvroSchemaGenerator {
         let mut value_columns = column_names_and_types(value_desc);
         if is_debezium {
             value_columns = envelopes::dbz_envelope(value_columns);
+            if is_debezium_full {
+                value_columns.extend(envelopes::dbz_source_columns());
+            }
             // With DEBEZIUM envelope the message is wrapped into "before" and "after"
             // with `DBZ_ROW_TYPE_ID` instead of `sink_from`.
             // Replacing comments for the columns and type in `sink_from` to `DBZ_ROW_TYPE_ID`.
@@ -305,6 +329,18 @@ impl AvroEncoder {
         let columns = self.schema_generator.value_columns();
         encode_message_unchecked(schema_id, row, schema, columns)
     }
+
+    pub fn encode_key(&self, schema_id: i32, row: Row) -> Result<Vec<u8>, anyhow::Error> {
+        let schema = self.schema_generator.key_writer_schema().unwrap();
+        let columns = self.schema_generator.key_columns().unwrap();
+        encode_message(schema_id, row, schema, columns)
+    }
+
+    pub fn encode_value(&self, schema_id: i32, row: Row) -> Result<Vec<u8>, anyhow::Error> {
+        let schema = self.schema_generator.value_writer_schema();
+        let columns = self.schema_generator.value_columns();
+        encode_message(schema_id, row, schema, columns)
+    }
 }
 
 impl Encode for AvroEncoder {
@@ -319,6 +355,14 @@ impl Encode for AvroEncoder {
     fn encode_value_unchecked(&self, row: Row) -> Vec<u8> {
         self.encode_value_unchecked(self.value_schema_id, row)
     }
+
+    fn encode_key(&self, row: Row) -> Result<Vec<u8>, anyhow::Error> {
+        self.encode_key(self.key_schema_id.unwrap(), row)
+    }
+
+    fn encode_value(&self, row: Row) -> Result<Vec<u8>, anyhow::Error> {
+        self.encode_value(self.value_schema_id, row)
+    }
 }
 
 /// Encodes a sequence of `Datum` as Avro (key and value), using supplied column names and types.
@@ -338,16 +382,45 @@ where
     v
 }
 
+/// Like [`encode_datums_as_avro`], but returns an error if any datum cannot be
+/// represented in Avro.
+pub fn try_encode_datums_as_avro<'a, I>(
+    datums: I,
+    names_types: &[(ColumnName, ColumnType)],
+) -> Result<Value, anyhow::Error>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let value_fields = names_types
+        .iter()
+        .zip_eq(datums)
+        .map(|((name, typ), datum)| {
+            let name = name.as_str().to_owned();
+            Ok((name, TypedDatum::new(datum, typ).try_avro()?))
+        })
+        .collect::<Result<Vec<(String, Value)>, anyhow::Error>>()?;
+    Ok(Value::Record(value_fields))
+}
+
 impl<'a> mz_avro::types::ToAvro for TypedDatum<'a> {
     fn avro(self) -> Value {
+        self.try_avro().expect("datum representable in avro")
+    }
+}
+
+impl<'a> TypedDatum<'a> {
+    /// Converts the datum to an Avro value, or returns an error if it cannot
+    /// be represented in Avro, e.g. because a numeric value exceeds the
+    /// precision of its column once rescaled.
+    pub fn try_avro(self) -> Result<Value, anyhow::Error> {
         let TypedDatum { datum, typ } = self;
         if typ.nullable && datum.is_null() {
-            Value::Union {
+            Ok(Value::Union {
                 index: 0,
                 inner: Box::new(Value::Null),
                 n_variants: 2,
                 null_variant: Some(0),
-            }
+            })
         } else {
             let mut val = match &typ.scalar_type {
                 ScalarType::AclItem => Value::String(datum.unwrap_acl_item().to_string()),
@@ -374,7 +447,7 @@ impl<'a> mz_avro::types::ToAvro for TypedDatum<'a> {
                     let (unscaled, precision, scale) = match max_scale {
                         Some(max_scale) => {
                             // Values must be rescaled to resaturate trailing zeroes
-                            numeric::rescale(&mut d, max_scale.into_u8()).unwrap();
+                            numeric::rescale(&mut d, max_scale.into_u8())?;
                             (
                                 numeric::numeric_to_twos_complement_be(d).to_vec(),
                                 NUMERIC_DATUM_MAX_PRECISION,
@@ -450,9 +523,9 @@ impl<'a> mz_avro::types::ToAvro for TypedDatum<'a> {
                                     scalar_type: ty.unwrap_collection_element_type().clone(),
                                 },
                             )
-                            .avro()
+                            .try_avro()
                         })
-                        .collect();
+                        .collect::<Result<_, _>>()?;
                     Value::Array(values)
                 }
                 ScalarType::Map { value_type, .. } => {
@@ -467,10 +540,10 @@ impl<'a> mz_avro::types::ToAvro for TypedDatum<'a> {
                                     scalar_type: (**value_type).clone(),
                                 },
                             )
-                            .avro();
-                            (key.to_string(), value)
+                            .try_avro()?;
+                            Ok((key.to_string(), value))
                         })
-                        .collect();
+                        .collect::<Result<_, anyhow::Error>>()?;
                     Value::Map(elements)
                 }
                 ScalarType::Record { fields, .. } => {
@@ -481,10 +554,10 @@ impl<'a> mz_avro::types::ToAvro for TypedDatum<'a> {
                         .map(|((name, typ), datum)| {
                             let name = name.to_string();
                             let datum = TypedDatum::new(datum, typ);
-                            let value = datum.avro();
-                            (name, value)
+                            let value = datum.try_avro()?;
+                            Ok((name, value))
                         })
-                        .collect();
+                        .collect::<Result<_, anyhow::Error>>()?;
                     Value::Record(fields)
                 }
                 ScalarType::MzTimestamp => Value::String(datum.unwrap_mz_timestamp().to_string()),
@@ -499,7 +572,7 @@ impl<'a> mz_avro::types::ToAvro for TypedDatum<'a> {
                     null_variant: Some(0),
                 };
             }
-            val
+            Ok(val)
         }
     }
 }
@@ -17,6 +17,18 @@ pub trait Encode {
     fn encode_key_unchecked(&self, row: Row) -> Vec<u8>;
 
     fn encode_value_unchecked(&self, row: Row) -> Vec<u8>;
+
+    /// Like `encode_key_unchecked`, but returns an error if `row` cannot be
+    /// represented in the format.
+    fn encode_key(&self, row: Row) -> Result<Vec<u8>, anyhow::Error> {
+        Ok(self.encode_key_unchecked(row))
+    }
+
+    /// Like `encode_value_unchecked`, but returns an error if `row` cannot be
+    /// represented in the format.
+    fn encode_value(&self, row: Row) -> Result<Vec<u8>, anyhow::Error> {
+        Ok(self.encode_value_unchecked(row))
+    }
 }
 
 /// Bundled information sufficient to encode Datums.
@@ -132,11 +132,13 @@ where
 // the sink is created.
 pub(crate) const TRANSACTION_TYPE_ID: GlobalId = GlobalId::Transient(1);
 pub(crate) const DBZ_ROW_TYPE_ID: GlobalId = GlobalId::Transient(2);
+pub(crate) const DBZ_SOURCE_TYPE_ID: GlobalId = GlobalId::Transient(3);
 
 pub static ENVELOPE_CUSTOM_NAMES: Lazy<BTreeMap<GlobalId, String>> = Lazy::new(|| {
     btreemap! {
         TRANSACTION_TYPE_ID => "transaction".into(),
         DBZ_ROW_TYPE_ID => "row".into(),
+        DBZ_SOURCE_TYPE_ID => "source".into(),
     }
 });
 
@@ -153,6 +155,82 @@ pub(crate) fn dbz_envelope(
     vec![("before".into(), row.clone()), ("after".into(), row)]
 }
 
+/// The columns that the full Debezium envelope appends to those of
+/// [`dbz_envelope`]: the `source` block describing the origin of a change, the
+/// `op` code of the change, and the `ts_ms` time of the change.
+pub(crate) fn dbz_source_columns() -> Vec<(ColumnName, ColumnType)> {
+    let text = ScalarType::String.nullable(false);
+    let ts_ms = ScalarType::Int64.nullable(false);
+    let source = ColumnType {
+        nullable: false,
+        scalar_type: ScalarType::Record {
+            fields: vec![
+                ("connector".into(), text.clone()),
+                ("name".into(), text.clone()),
+                ("ts_ms".into(), ts_ms.clone()),
+                ("snapshot".into(), text.clone()),
+                ("db".into(), text.clone()),
+                ("schema".into(), text.clone()),
+                ("table".into(), text.clone()),
+            ],
+            custom_id: Some(DBZ_SOURCE_TYPE_ID),
+        },
+    };
+    vec![
+        ("source".into(), source),
+        ("op".into(), text),
+        ("ts_ms".into(), ts_ms),
+    ]
+}
+
+/// The `source` block of a change in the full Debezium envelope.
+#[derive(Debug)]
+pub struct DbzSource<'a> {
+    /// The logical name of the system that emitted the change.
+    pub name: &'a str,
+    /// The database, schema, and name of the relation that changed.
+    pub db: &'a str,
+    pub schema: &'a str,
+    pub table: &'a str,
+    /// The time of the change, in milliseconds since the Unix epoch.
+    pub ts_ms: i64,
+    /// Whether the change is part of the initial snapshot of the relation.
+    pub snapshot: bool,
+}
+
+/// Returns the Debezium `op` code of a change from `before` to `after`.
+///
+/// Insertions that are part of a snapshot are reported as reads, like
+/// Debezium does.
+pub fn dbz_op(has_before: bool, has_after: bool, snapshot: bool) -> &'static str {
+    match (has_before, has_after) {
+        (_, false) => "d",
+        (true, true) => "u",
+        (false, true) if snapshot => "r",
+        (false, true) => "c",
+    }
+}
+
+/// Packs the fields of the full Debezium envelope that follow those packed by
+/// [`dbz_format`].
+pub fn dbz_source_format(rp: &mut RowPacker, source: &DbzSource, op: &str) {
+    rp.push_list_with(|rp| {
+        rp.push(Datum::String("materialize"));
+        rp.push(Datum::String(source.name));
+        rp.push(Datum::Int64(source.ts_ms));
+        rp.push(Datum::String(if source.snapshot {
+            "true"
+        } else {
+            "false"
+        }));
+        rp.push(Datum::String(source.db));
+        rp.push(Datum::String(source.schema));
+        rp.push(Datum::String(source.table));
+    });
+    rp.push(Datum::String(op));
+    rp.push(Datum::Int64(source.ts_ms));
+}
+
 pub fn dbz_format(rp: &mut RowPacker, dp: DiffPair<Row>) {
     if let Some(before) = dp.before {
         rp.push_list_with(|rp| rp.extend_by_row(&before));
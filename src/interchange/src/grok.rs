@@ -0,0 +1,271 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Grok patterns.
+//!
+//! A grok pattern is a regular expression that may reference named,
+//! predefined subpatterns via `%{SYNTAX}`, `%{SYNTAX:field}`, or
+//! `%{SYNTAX:field:type}`. Every reference that names a field becomes a
+//! column of the decoded relation. This module expands grok patterns into
+//! plain regular expressions that the `regex` crate understands.
+
+use std::collections::BTreeSet;
+
+use anyhow::{anyhow, bail};
+use mz_repr::adt::regex::Regex;
+use mz_repr::{RelationDesc, ScalarType};
+
+/// The maximum depth to which subpattern references are expanded.
+const MAX_EXPANSION_DEPTH: usize = 32;
+
+/// The predefined subpatterns that grok patterns may reference.
+///
+/// These follow the patterns that ship with Logstash, adjusted where
+/// necessary to avoid lookaround assertions, which the `regex` crate does not
+/// support.
+const PATTERNS: &[(&str, &str)] = &[
+    ("USERNAME", r"[a-zA-Z0-9._-]+"),
+    ("USER", r"%{USERNAME}"),
+    ("INT", r"[+-]?[0-9]+"),
+    ("BASE10NUM", r"[+-]?(?:[0-9]+(?:\.[0-9]*)?|\.[0-9]+)"),
+    ("NUMBER", r"%{BASE10NUM}"),
+    ("BASE16NUM", r"[+-]?(?:0[xX])?[0-9A-Fa-f]+"),
+    ("POSINT", r"\b[1-9][0-9]*\b"),
+    ("NONNEGINT", r"\b[0-9]+\b"),
+    ("WORD", r"\b\w+\b"),
+    ("NOTSPACE", r"\S+"),
+    ("SPACE", r"\s*"),
+    ("DATA", r".*?"),
+    ("GREEDYDATA", r".*"),
+    ("QUOTEDSTRING", r#""(?:[^"\\]|\\.)*"|'(?:[^'\\]|\\.)*'"#),
+    (
+        "UUID",
+        r"[A-Fa-f0-9]{8}-(?:[A-Fa-f0-9]{4}-){3}[A-Fa-f0-9]{12}",
+    ),
+    (
+        "IPV4",
+        r"(?:(?:25[0-5]|2[0-4][0-9]|1[0-9]{2}|[1-9]?[0-9])\.){3}(?:25[0-5]|2[0-4][0-9]|1[0-9]{2}|[1-9]?[0-9])",
+    ),
+    ("IPV6", r"(?:[0-9A-Fa-f]{0,4}:){2,7}[0-9A-Fa-f]{0,4}"),
+    ("IP", r"%{IPV6}|%{IPV4}"),
+    (
+        "HOSTNAME",
+        r"\b[0-9A-Za-z][0-9A-Za-z-]{0,62}(?:\.[0-9A-Za-z][0-9A-Za-z-]{0,62})*\.?",
+    ),
+    ("IPORHOST", r"%{IP}|%{HOSTNAME}"),
+    ("HOSTPORT", r"%{IPORHOST}:%{POSINT}"),
+    ("PATH", r"(?:/[\w_%!$@:.,+~-]*)+"),
+    ("URIPROTO", r"[A-Za-z][A-Za-z0-9+\-.]+"),
+    ("URIHOST", r"%{IPORHOST}(?::%{POSINT})?"),
+    ("URIPATH", r"(?:/[A-Za-z0-9$.+!*'(){},~:;=@#%&_\-]*)+"),
+    ("URIPARAM", r"\?[A-Za-z0-9$.+!*'|(){},~@#%&/=:;_?\-\[\]<>]*"),
+    ("URIPATHPARAM", r"%{URIPATH}(?:%{URIPARAM})?"),
+    (
+        "URI",
+        r"%{URIPROTO}://(?:%{USER}(?::[^@]*)?@)?(?:%{URIHOST})?(?:%{URIPATHPARAM})?",
+    ),
+    (
+        "MONTH",
+        r"\b(?:[Jj]an(?:uary)?|[Ff]eb(?:ruary)?|[Mm]ar(?:ch)?|[Aa]pr(?:il)?|[Mm]ay|[Jj]un(?:e)?|[Jj]ul(?:y)?|[Aa]ug(?:ust)?|[Ss]ep(?:tember)?|[Oo]ct(?:ober)?|[Nn]ov(?:ember)?|[Dd]ec(?:ember)?)\b",
+    ),
+    ("MONTHNUM", r"0?[1-9]|1[0-2]"),
+    ("MONTHDAY", r"0[1-9]|[12][0-9]|3[01]|[1-9]"),
+    (
+        "DAY",
+        r"Mon(?:day)?|Tue(?:sday)?|Wed(?:nesday)?|Thu(?:rsday)?|Fri(?:day)?|Sat(?:urday)?|Sun(?:day)?",
+    ),
+    ("YEAR", r"(?:\d\d){1,2}"),
+    ("HOUR", r"2[0123]|[01]?[0-9]"),
+    ("MINUTE", r"[0-5][0-9]"),
+    ("SECOND", r"(?:[0-5]?[0-9]|60)(?:[:.,][0-9]+)?"),
+    ("TIME", r"%{HOUR}:%{MINUTE}(?::%{SECOND})?"),
+    ("DATE_US", r"%{MONTHNUM}[/-]%{MONTHDAY}[/-]%{YEAR}"),
+    ("DATE_EU", r"%{MONTHDAY}[./-]%{MONTHNUM}[./-]%{YEAR}"),
+    ("DATE", r"%{DATE_US}|%{DATE_EU}"),
+    ("DATESTAMP", r"%{DATE}[- ]%{TIME}"),
+    ("ISO8601_TIMEZONE", r"Z|[+-]%{HOUR}(?::?%{MINUTE})"),
+    (
+        "TIMESTAMP_ISO8601",
+        r"%{YEAR}-%{MONTHNUM}-%{MONTHDAY}[T ]%{HOUR}:?%{MINUTE}(?::?%{SECOND})?(?:%{ISO8601_TIMEZONE})?",
+    ),
+    ("HTTPDATE", r"%{MONTHDAY}/%{MONTH}/%{YEAR}:%{TIME} %{INT}"),
+    ("SYSLOGTIMESTAMP", r"%{MONTH} +%{MONTHDAY} %{TIME}"),
+    (
+        "LOGLEVEL",
+        r"[Aa]lert|ALERT|[Tt]race|TRACE|[Dd]ebug|DEBUG|[Nn]otice|NOTICE|[Ii]nfo|INFO|[Ww]arn(?:ing)?|WARN(?:ING)?|[Ee]rr(?:or)?|ERR(?:OR)?|[Cc]rit(?:ical)?|CRIT(?:ICAL)?|[Ff]atal|FATAL|[Ss]evere|SEVERE|[Ee]merg(?:ency)?|EMERG(?:ENCY)?",
+    ),
+];
+
+/// A grok pattern that has been expanded into a regular expression.
+#[derive(Debug, Clone)]
+pub struct GrokPattern {
+    /// The expanded regular expression.
+    ///
+    /// The subexpression for the `i`th field is captured by the group named
+    /// [`field_group_name(i)`](field_group_name).
+    pub regex: Regex,
+    /// The columns derived from the named fields of the pattern, in the order
+    /// in which they appear.
+    pub desc: RelationDesc,
+}
+
+/// Returns the name of the capture group that holds the `i`th field of an
+/// expanded grok pattern.
+pub fn field_group_name(i: usize) -> String {
+    format!("grok{}", i)
+}
+
+/// Expands a grok pattern into a regular expression and derives the columns
+/// described by its named fields.
+///
+/// Fields whose type is `int` or `float` produce `int8` and `float8` columns,
+/// respectively; all other fields produce `text` columns. All columns are
+/// nullable, since a field within an optional part of the pattern need not
+/// match.
+pub fn compile(pattern: &str) -> Result<GrokPattern, anyhow::Error> {
+    let mut fields = vec![];
+    let regex = expand(pattern, &mut fields, 0)?;
+    if fields.is_empty() {
+        bail!("grok pattern must name at least one field, as in %{{WORD:name}}");
+    }
+    let mut seen = BTreeSet::new();
+    for (name, _) in &fields {
+        if !seen.insert(name.as_str()) {
+            bail!("grok field {} specified more than once", name);
+        }
+    }
+    let regex = Regex::new(regex, false)
+        .map_err(|e| anyhow!("grok pattern expands to invalid regular expression: {}", e))?;
+    let desc = fields
+        .into_iter()
+        .fold(RelationDesc::empty(), |desc, (name, ty)| {
+            desc.with_column(name, ty.nullable(true))
+        });
+    Ok(GrokPattern { regex, desc })
+}
+
+/// Replaces each `%{...}` reference in `pattern` with the regular expression
+/// it refers to, recording each named field in `fields`.
+fn expand(
+    pattern: &str,
+    fields: &mut Vec<(String, ScalarType)>,
+    depth: usize,
+) -> Result<String, anyhow::Error> {
+    if depth > MAX_EXPANSION_DEPTH {
+        bail!("grok pattern references nested too deeply");
+    }
+    let mut out = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+    while let Some(start) = rest.find("%{") {
+        out.push_str(&rest[..start]);
+        let reference = &rest[start + 2..];
+        let end = reference
+            .find('}')
+            .ok_or_else(|| anyhow!("unterminated grok pattern reference: %{{{}", reference))?;
+        let mut parts = reference[..end].splitn(3, ':');
+        let syntax = parts.next().unwrap_or("");
+        let field = parts.next();
+        let ty = parts.next();
+        let (_, definition) = PATTERNS
+            .iter()
+            .find(|(name, _)| *name == syntax)
+            .ok_or_else(|| anyhow!("unknown grok pattern: {}", syntax))?;
+        let expansion = expand(definition, fields, depth + 1)?;
+        match field {
+            None | Some("") => {
+                if ty.is_some() {
+                    bail!(
+                        "grok pattern reference %{{{}}} has a type but no field name",
+                        &reference[..end]
+                    );
+                }
+                out.push_str(&format!("(?:{})", expansion));
+            }
+            Some(field) => {
+                let ty = match ty {
+                    None | Some("string") => ScalarType::String,
+                    Some("int") => ScalarType::Int64,
+                    Some("float") => ScalarType::Float64,
+                    Some(ty) => bail!(
+                        "unknown grok field type {}; expected int, float, or string",
+                        ty
+                    ),
+                };
+                out.push_str(&format!(
+                    "(?P<{}>{})",
+                    field_group_name(fields.len()),
+                    expansion
+                ));
+                fields.push((field.to_string(), ty));
+            }
+        }
+        rest = &reference[end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_repr::ColumnName;
+
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_compile() {
+        let grok = compile(
+            r"%{IPORHOST:client} %{WORD:method} %{URIPATHPARAM:request} %{INT:status:int} %{NUMBER:duration:float}",
+        )
+        .unwrap();
+        let columns: Vec<_> = grok
+            .desc
+            .iter()
+            .map(|(name, ty)| (name.clone(), ty.scalar_type.clone()))
+            .collect();
+        assert_eq!(
+            columns,
+            vec![
+                (ColumnName::from("client"), ScalarType::String),
+                (ColumnName::from("method"), ScalarType::String),
+                (ColumnName::from("request"), ScalarType::String),
+                (ColumnName::from("status"), ScalarType::Int64),
+                (ColumnName::from("duration"), ScalarType::Float64),
+            ]
+        );
+
+        let captures = grok
+            .regex
+            .captures("10.0.0.1 GET /index.html?a=b 200 0.043")
+            .unwrap();
+        let fields: Vec<_> = (0..5)
+            .map(|i| &captures[field_group_name(i).as_str()])
+            .collect();
+        assert_eq!(
+            fields,
+            vec!["10.0.0.1", "GET", "/index.html?a=b", "200", "0.043"]
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_compile_errors() {
+        for (pattern, err) in [
+            ("%{WORD}", "grok pattern must name at least one field"),
+            ("%{NOPE:x}", "unknown grok pattern: NOPE"),
+            ("%{WORD:x:date}", "unknown grok field type date"),
+            (
+                "%{WORD:x} %{INT:x}",
+                "grok field x specified more than once",
+            ),
+            ("%{WORD:x", "unterminated grok pattern reference"),
+        ] {
+            let e = compile(pattern).unwrap_err().to_string();
+            assert!(e.starts_with(err), "{}: {}", pattern, e);
+        }
+    }
+}
@@ -44,6 +44,13 @@ impl JsonEncoder {
         }
     }
 
+    /// Extends the value of a Debezium encoder with the `source`, `op`, and
+    /// `ts_ms` fields of the full Debezium envelope.
+    pub fn with_debezium_full(mut self) -> Self {
+        self.value_columns.extend(envelopes::dbz_source_columns());
+        self
+    }
+
     pub fn encode_row(
         &self,
         row: mz_repr::Row,
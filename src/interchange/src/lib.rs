@@ -83,5 +83,6 @@ pub mod avro;
 pub mod confluent;
 pub mod encode;
 pub mod envelopes;
+pub mod grok;
 pub mod json;
 pub mod protobuf;
@@ -0,0 +1,81 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Helpers for authenticating with Amazon MSK clusters using AWS IAM.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Context};
+use aws_credential_types::provider::ProvideCredentials;
+use aws_sigv4::http_request::{
+    sign, SignableRequest, SignatureLocation, SigningParams, SigningSettings,
+};
+use aws_types::SdkConfig;
+use url::Url;
+
+/// How long the tokens generated by [`generate_auth_token`] are valid for.
+const TOKEN_LIFETIME: Duration = Duration::from_secs(900);
+
+/// Generates a token that authenticates with an Amazon MSK cluster via the
+/// `OAUTHBEARER` SASL mechanism using the credentials in `sdk_config`.
+///
+/// The token is a presigned URL for the `kafka-cluster:Connect` action, as
+/// described by the [MSK IAM SASL signer]. Returns the token and the time at
+/// which it expires, in milliseconds since the Unix epoch.
+///
+/// [MSK IAM SASL signer]: https://github.com/aws/aws-msk-iam-sasl-signer-python
+pub async fn generate_auth_token(sdk_config: &SdkConfig) -> Result<(String, i64), anyhow::Error> {
+    let region = sdk_config
+        .region()
+        .ok_or_else(|| anyhow!("AWS connection does not specify a region"))?;
+    let credentials = sdk_config
+        .credentials_provider()
+        .ok_or_else(|| anyhow!("AWS connection does not specify credentials"))?
+        .provide_credentials()
+        .await
+        .context("loading AWS credentials")?;
+
+    let mut url = Url::parse(&format!("https://kafka.{region}.amazonaws.com/"))?;
+    url.query_pairs_mut()
+        .append_pair("Action", "kafka-cluster:Connect");
+
+    let mut settings = SigningSettings::default();
+    settings.signature_location = SignatureLocation::QueryParams;
+    settings.expires_in = Some(TOKEN_LIFETIME);
+    let now = SystemTime::now();
+    let mut params = SigningParams::builder()
+        .access_key(credentials.access_key_id())
+        .secret_key(credentials.secret_access_key())
+        .region(region.as_ref())
+        .service_name("kafka-cluster")
+        .time(now)
+        .settings(settings);
+    params.set_security_token(credentials.session_token());
+    let params = params.build().context("building AWS signing parameters")?;
+
+    let mut request = http::Request::builder()
+        .method("GET")
+        .uri(url.as_str())
+        .body("")
+        .context("building MSK authentication request")?;
+    let (instructions, _signature) = sign(SignableRequest::from(&request), &params)
+        .context("signing MSK authentication request")?
+        .into_parts();
+    instructions.apply_to_request(&mut request);
+
+    let mut url = Url::parse(&request.uri().to_string())?;
+    url.query_pairs_mut()
+        .append_pair("User-Agent", "materialize");
+    let token = base64::encode_config(url.as_str(), base64::URL_SAFE_NO_PAD);
+
+    let expires_at = (now + TOKEN_LIFETIME).duration_since(UNIX_EPOCH)?;
+    let expires_at = i64::try_from(expires_at.as_millis())?;
+
+    Ok((token, expires_at))
+}
@@ -18,6 +18,7 @@ use std::sync::Mutex;
 use std::time::Duration;
 
 use anyhow::{anyhow, Context};
+use aws_types::SdkConfig;
 use crossbeam::channel::{unbounded, Receiver, Sender};
 use mz_ore::collections::CollectionExt;
 use mz_ore::error::ErrorExt;
@@ -34,6 +35,9 @@ use rdkafka::{ClientContext, Statistics, TopicPartitionList};
 use tokio::runtime::Handle;
 use tracing::{debug, error, info, warn, Level};
 
+use crate::aws;
+use crate::oauth::{self, OauthClientCredentials};
+
 /// A reasonable default timeout when fetching metadata or partitions.
 pub const DEFAULT_FETCH_METADATA_TIMEOUT: Duration = Duration::from_secs(10);
 
@@ -274,6 +278,8 @@ pub struct TunnelingClientContext<C> {
     inner: C,
     rewrites: Arc<Mutex<BTreeMap<BrokerAddr, BrokerRewriteHandle>>>,
     default_tunnel: Option<SshTunnelConfig>,
+    aws_config: Option<SdkConfig>,
+    oauth_credentials: Option<OauthClientCredentials>,
     ssh_tunnel_manager: SshTunnelManager,
     runtime: Handle,
 }
@@ -289,6 +295,8 @@ impl<C> TunnelingClientContext<C> {
             inner,
             rewrites: Arc::new(Mutex::new(BTreeMap::new())),
             default_tunnel: None,
+            aws_config: None,
+            oauth_credentials: None,
             ssh_tunnel_manager,
             runtime,
         }
@@ -302,6 +310,19 @@ impl<C> TunnelingClientContext<C> {
         self.default_tunnel = Some(tunnel);
     }
 
+    /// Sets the AWS configuration whose credentials sign the tokens of the
+    /// `OAUTHBEARER` SASL mechanism, for authenticating with Amazon MSK
+    /// clusters using AWS IAM.
+    pub fn set_aws_config(&mut self, aws_config: SdkConfig) {
+        self.aws_config = Some(aws_config);
+    }
+
+    /// Sets the client credentials with which tokens for the `OAUTHBEARER`
+    /// SASL mechanism are requested from an OAuth authorization server.
+    pub fn set_oauth_credentials(&mut self, oauth_credentials: OauthClientCredentials) {
+        self.oauth_credentials = Some(oauth_credentials);
+    }
+
     /// Adds an SSH tunnel for a specific broker.
     ///
     /// Overrides the existing SSH tunnel or rewrite for this broker, if any.
@@ -378,7 +399,10 @@ impl<C> ClientContext for TunnelingClientContext<C>
 where
     C: ClientContext,
 {
-    const ENABLE_REFRESH_OAUTH_TOKEN: bool = C::ENABLE_REFRESH_OAUTH_TOKEN;
+    // Tokens are generated for the AWS configuration or OAuth client
+    // credentials, if any, so the refresh callback must always be registered.
+    // librdkafka only invokes it for the `OAUTHBEARER` SASL mechanism.
+    const ENABLE_REFRESH_OAUTH_TOKEN: bool = true;
 
     fn rewrite_broker_addr(&self, addr: BrokerAddr) -> BrokerAddr {
         let return_rewrite = |rewrite: &BrokerRewriteHandle| -> BrokerAddr {
@@ -504,7 +528,27 @@ where
         &self,
         oauthbearer_config: Option<&str>,
     ) -> Result<OAuthToken, Box<dyn Error>> {
-        self.inner.generate_oauth_token(oauthbearer_config)
+        if let Some(aws_config) = &self.aws_config {
+            let (token, lifetime_ms) = self
+                .runtime
+                .block_on(aws::generate_auth_token(aws_config))?;
+            Ok(OAuthToken {
+                token,
+                principal_name: String::new(),
+                lifetime_ms,
+            })
+        } else if let Some(oauth_credentials) = &self.oauth_credentials {
+            let (token, lifetime_ms) = self
+                .runtime
+                .block_on(oauth::fetch_token(oauth_credentials))?;
+            Ok(OAuthToken {
+                token,
+                principal_name: oauth_credentials.client_id.clone(),
+                lifetime_ms,
+            })
+        } else {
+            self.inner.generate_oauth_token(oauthbearer_config)
+        }
     }
 }
 
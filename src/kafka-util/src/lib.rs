@@ -80,4 +80,6 @@
 #![warn(missing_docs)]
 
 pub mod admin;
+pub mod aws;
 pub mod client;
+pub mod oauth;
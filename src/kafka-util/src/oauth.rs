@@ -0,0 +1,83 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Helpers for authenticating with Kafka clusters using OAuth.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Context};
+use serde::Deserialize;
+
+/// How long tokens are assumed to be valid for if the authorization server
+/// does not say.
+const DEFAULT_TOKEN_LIFETIME: Duration = Duration::from_secs(3600);
+
+/// The client credentials with which [`fetch_token`] requests tokens from an
+/// OAuth authorization server.
+#[derive(Clone)]
+pub struct OauthClientCredentials {
+    /// The URL of the authorization server's token endpoint.
+    pub token_endpoint: String,
+    /// The client ID.
+    pub client_id: String,
+    /// The client secret.
+    pub client_secret: String,
+    /// The scope to request, if any.
+    pub scope: Option<String>,
+}
+
+/// A successful response from a token endpoint, per [RFC 6749 § 5.1].
+///
+/// [RFC 6749 § 5.1]: https://datatracker.ietf.org/doc/html/rfc6749#section-5.1
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+}
+
+/// Requests a token from the authorization server of `credentials` using the
+/// OAuth 2.0 client credentials grant.
+///
+/// Returns the token and the time at which it expires, in milliseconds since
+/// the Unix epoch.
+pub async fn fetch_token(
+    credentials: &OauthClientCredentials,
+) -> Result<(String, i64), anyhow::Error> {
+    let mut form = vec![("grant_type", "client_credentials")];
+    if let Some(scope) = &credentials.scope {
+        form.push(("scope", scope.as_str()));
+    }
+
+    let now = SystemTime::now();
+    let response = reqwest::Client::new()
+        .post(&credentials.token_endpoint)
+        .basic_auth(&credentials.client_id, Some(&credentials.client_secret))
+        .form(&form)
+        .send()
+        .await
+        .context("requesting OAuth token")?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        bail!("OAuth token endpoint responded with status {status}: {body}");
+    }
+    let response: TokenResponse = response
+        .json()
+        .await
+        .context("decoding OAuth token response")?;
+
+    let lifetime = response
+        .expires_in
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_TOKEN_LIFETIME);
+    let expires_at = (now + lifetime).duration_since(UNIX_EPOCH)?;
+    let expires_at = i64::try_from(expires_at.as_millis())?;
+
+    Ok((response.access_token, expires_at))
+}
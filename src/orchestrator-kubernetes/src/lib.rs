@@ -108,7 +108,7 @@ use mz_cloud_resources::AwsExternalIdPrefix;
 use mz_orchestrator::{
     scheduling_config::*, DiskLimit, LabelSelectionLogic, LabelSelector as MzLabelSelector,
     NamespacedOrchestrator, NotReadyReason, Orchestrator, Service, ServiceConfig, ServiceEvent,
-    ServiceProcessMetrics, ServiceStatus,
+    ServicePriority, ServiceProcessMetrics, ServiceStatus,
 };
 use serde::Deserialize;
 use sha2::{Digest, Sha256};
@@ -129,6 +129,12 @@ pub struct KubernetesOrchestratorConfig {
     pub context: String,
     /// The name of a non-default Kubernetes scheduler to use, if any.
     pub scheduler_name: Option<String>,
+    /// The name of the Kubernetes priority class to assign to services with a
+    /// low priority, if any.
+    pub low_priority_class_name: Option<String>,
+    /// The name of the Kubernetes priority class to assign to services with a
+    /// high priority, if any.
+    pub high_priority_class_name: Option<String>,
     /// Labels to install on every service created by the orchestrator.
     pub service_labels: BTreeMap<String, String>,
     /// Node selector to install on every service created by the orchestrator.
@@ -766,6 +772,7 @@ impl NamespacedOrchestrator for NamespacedKubernetesOrchestrator {
             replicas_selector,
             disk,
             disk_limit,
+            priority,
         }: ServiceConfig<'_>,
     ) -> Result<Box<dyn Service>, anyhow::Error> {
         // This is extremely cheap to clone, so just look into the lock once.
@@ -1253,6 +1260,13 @@ impl NamespacedOrchestrator for NamespacedKubernetesOrchestrator {
                 security_context,
                 node_selector: Some(node_selector),
                 scheduler_name: self.config.scheduler_name.clone(),
+                // Services with the normal priority use the cluster's default
+                // priority class.
+                priority_class_name: match priority {
+                    ServicePriority::Low => self.config.low_priority_class_name.clone(),
+                    ServicePriority::Normal => None,
+                    ServicePriority::High => self.config.high_priority_class_name.clone(),
+                },
                 service_account: self.config.service_account.clone(),
                 affinity: Some(Affinity {
                     pod_anti_affinity: anti_affinity,
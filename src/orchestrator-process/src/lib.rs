@@ -433,6 +433,9 @@ impl NamespacedOrchestrator for NamespacedProcessOrchestrator {
             replicas_selector: _,
             disk,
             disk_limit: _,
+            // Priorities are meaningless without contention for shared
+            // resources, so are ignored by the process orchestrator.
+            priority: _,
         }: ServiceConfig<'_>,
     ) -> Result<Box<dyn Service>, anyhow::Error> {
         let full_id = format!("{}-{}", self.namespace, id);
@@ -293,6 +293,22 @@ pub struct ServiceConfig<'a> {
     pub disk: bool,
     /// The maximum amount of scratch disk space that the service is allowed to consume.
     pub disk_limit: Option<DiskLimit>,
+    /// The scheduling priority of the service, relative to other services.
+    ///
+    /// The orchestrator backend may or may not actually implement priorities.
+    pub priority: ServicePriority,
+}
+
+/// The scheduling priority of a service.
+///
+/// When resources are scarce, services with a higher priority are scheduled
+/// before, and may preempt, services with a lower priority.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ServicePriority {
+    Low,
+    #[default]
+    Normal,
+    High,
 }
 
 /// A named port associated with a service.
@@ -368,3 +368,4 @@ pub const FUNC_CONSTANT_TIME_EQ_STRING_OID: u32 = 16_645;
 pub const FUNC_TIMEZONE_OFFSET: u32 = 16_646;
 pub const FUNC_PRETTY_SQL: u32 = 16_647;
 pub const FUNC_PRETTY_SQL_NOWIDTH: u32 = 16_648;
+pub const FUNC_MZ_CANCEL_BACKGROUND_PURIFICATION_OID: u32 = 16_649;
@@ -308,6 +308,16 @@ impl<'a> Cursor<'a> {
         Ok(val)
     }
 
+    /// Returns the next `n` bytes, advancing the cursor by `n` bytes.
+    pub fn read_bytes(&mut self, n: usize) -> Result<&'a [u8], io::Error> {
+        if self.buf.len() < n {
+            return Err(input_err("not enough buffer for bytes"));
+        }
+        let val = &self.buf[..n];
+        self.advance(n);
+        Ok(val)
+    }
+
     /// Reads the next 16-bit format code, advancing the cursor by two bytes.
     pub fn read_format(&mut self) -> Result<Format, io::Error> {
         Format::try_from(self.read_i16()?)
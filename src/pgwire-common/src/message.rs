@@ -182,6 +182,17 @@ pub enum FrontendMessage {
     Password {
         password: String,
     },
+
+    /// The client's first message in a SASL exchange.
+    SaslInitialResponse {
+        mechanism: String,
+        data: Vec<u8>,
+    },
+
+    /// A subsequent client message in a SASL exchange.
+    SaslResponse {
+        data: Vec<u8>,
+    },
 }
 
 impl FrontendMessage {
@@ -202,6 +213,8 @@ impl FrontendMessage {
             FrontendMessage::CopyDone => "copy_done",
             FrontendMessage::CopyFail(_) => "copy_fail",
             FrontendMessage::Password { .. } => "password",
+            FrontendMessage::SaslInitialResponse { .. } => "sasl_initial_response",
+            FrontendMessage::SaslResponse { .. } => "sasl_response",
         }
     }
 }
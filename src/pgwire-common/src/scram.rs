@@ -0,0 +1,353 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! The SCRAM-SHA-256 SASL authentication mechanism.
+//!
+//! See [RFC 5802] and [RFC 7677] for the specification, and "[SASL
+//! Authentication][pg]" in the PostgreSQL reference for how it is used in
+//! pgwire. Channel binding is not supported.
+//!
+//! [RFC 5802]: https://datatracker.ietf.org/doc/html/rfc5802
+//! [RFC 7677]: https://datatracker.ietf.org/doc/html/rfc7677
+//! [pg]: https://www.postgresql.org/docs/current/sasl-authentication.html
+
+use std::fmt;
+use std::str::{self, FromStr};
+
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+/// The name of the SCRAM-SHA-256 SASL mechanism.
+pub const SCRAM_SHA_256: &str = "SCRAM-SHA-256";
+
+/// The number of iterations that passwords are hashed with, which matches
+/// PostgreSQL's default.
+pub const DEFAULT_ITERATIONS: u32 = 4096;
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 18;
+
+/// An error during a SCRAM exchange.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ScramError {
+    /// A message or verifier is malformed.
+    #[error("malformed SCRAM {0}")]
+    Malformed(&'static str),
+    /// The client requested channel binding.
+    #[error("SCRAM channel binding is not supported")]
+    ChannelBindingUnsupported,
+    /// The client failed to prove that it knows the password.
+    #[error("invalid SCRAM proof")]
+    InvalidProof,
+}
+
+/// A salted SCRAM-SHA-256 verifier of a password, from which the password
+/// itself can't be recovered.
+///
+/// Verifiers are displayed and parsed in the same format that PostgreSQL
+/// stores them in: `SCRAM-SHA-256$<iterations>:<salt>$<StoredKey>:<ServerKey>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScramVerifier {
+    iterations: u32,
+    salt: Vec<u8>,
+    stored_key: [u8; 32],
+    server_key: [u8; 32],
+}
+
+impl ScramVerifier {
+    /// Hashes `password` with a random salt.
+    pub fn generate(password: &str) -> ScramVerifier {
+        let mut salt = [0; SALT_LEN];
+        rand::thread_rng().fill_bytes(&mut salt);
+        ScramVerifier::new(password, &salt, DEFAULT_ITERATIONS)
+    }
+
+    /// Hashes `password` with `salt` and `iterations`.
+    ///
+    /// Unlike PostgreSQL, the password is not normalized with SASLprep, so
+    /// passwords that contain non-ASCII characters must be sent by clients
+    /// exactly as they were set.
+    pub fn new(password: &str, salt: &[u8], iterations: u32) -> ScramVerifier {
+        let salted_password = hi(password.as_bytes(), salt, iterations);
+        ScramVerifier {
+            iterations,
+            salt: salt.to_vec(),
+            stored_key: sha256(&hmac(&salted_password, b"Client Key")),
+            server_key: hmac(&salted_password, b"Server Key"),
+        }
+    }
+
+    /// Reports whether `password` is the password of this verifier.
+    ///
+    /// The keys are compared in constant time, so that the time taken does not
+    /// reveal how much of them matched.
+    pub fn verify(&self, password: &str) -> bool {
+        let other = ScramVerifier::new(password, &self.salt, self.iterations);
+        let keys_match =
+            self.stored_key.ct_eq(&other.stored_key) & self.server_key.ct_eq(&other.server_key);
+        bool::from(keys_match)
+    }
+}
+
+impl fmt::Display for ScramVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{SCRAM_SHA_256}${}:{}${}:{}",
+            self.iterations,
+            base64::encode(&self.salt),
+            base64::encode(self.stored_key),
+            base64::encode(self.server_key),
+        )
+    }
+}
+
+impl FromStr for ScramVerifier {
+    type Err = ScramError;
+
+    fn from_str(s: &str) -> Result<ScramVerifier, ScramError> {
+        let parse = || {
+            let s = s.strip_prefix(SCRAM_SHA_256)?.strip_prefix('$')?;
+            let (params, keys) = s.split_once('$')?;
+            let (iterations, salt) = params.split_once(':')?;
+            let (stored_key, server_key) = keys.split_once(':')?;
+            Some(ScramVerifier {
+                iterations: iterations.parse().ok()?,
+                salt: base64::decode(salt).ok()?,
+                stored_key: base64::decode(stored_key).ok()?.try_into().ok()?,
+                server_key: base64::decode(server_key).ok()?.try_into().ok()?,
+            })
+        };
+        parse().ok_or(ScramError::Malformed("verifier"))
+    }
+}
+
+/// The server side of a SCRAM-SHA-256 exchange.
+///
+/// An exchange is started with [`ScramServer::start`] when the client sends
+/// its first message, and finished with [`ScramServer::finish`] when the client
+/// sends its final message.
+#[derive(Debug)]
+pub struct ScramServer {
+    verifier: ScramVerifier,
+    gs2_header: String,
+    client_first_bare: String,
+    server_first: String,
+    nonce: String,
+}
+
+impl ScramServer {
+    /// Starts an exchange in which the client must prove that it knows the
+    /// password of `verifier`.
+    ///
+    /// Returns the exchange and the server's first message.
+    pub fn start(
+        verifier: ScramVerifier,
+        client_first: &[u8],
+    ) -> Result<(ScramServer, String), ScramError> {
+        let mut server_nonce = [0; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut server_nonce);
+        Self::start_with_nonce(verifier, client_first, &base64::encode(server_nonce))
+    }
+
+    fn start_with_nonce(
+        verifier: ScramVerifier,
+        client_first: &[u8],
+        server_nonce: &str,
+    ) -> Result<(ScramServer, String), ScramError> {
+        let malformed = ScramError::Malformed("client-first-message");
+        let client_first = str::from_utf8(client_first).map_err(|_| malformed.clone())?;
+
+        let (channel_binding, rest) = client_first.split_once(',').ok_or(malformed.clone())?;
+        match channel_binding {
+            "n" | "y" => (),
+            _ if channel_binding.starts_with("p=") => {
+                return Err(ScramError::ChannelBindingUnsupported)
+            }
+            _ => return Err(malformed),
+        }
+        let (_authzid, client_first_bare) = rest.split_once(',').ok_or(malformed.clone())?;
+        let gs2_header = &client_first[..client_first.len() - client_first_bare.len()];
+
+        // Like PostgreSQL, the user name is ignored in favor of the user name
+        // from the startup message.
+        let mut attrs = client_first_bare.split(',');
+        attrs
+            .next()
+            .and_then(|attr| attr.strip_prefix("n="))
+            .ok_or(malformed.clone())?;
+        let client_nonce = attrs
+            .next()
+            .and_then(|attr| attr.strip_prefix("r="))
+            .filter(|nonce| !nonce.is_empty())
+            .ok_or(malformed)?;
+
+        let nonce = format!("{client_nonce}{server_nonce}");
+        let server_first = format!(
+            "r={nonce},s={},i={}",
+            base64::encode(&verifier.salt),
+            verifier.iterations
+        );
+        let server = ScramServer {
+            verifier,
+            gs2_header: gs2_header.to_string(),
+            client_first_bare: client_first_bare.to_string(),
+            server_first: server_first.clone(),
+            nonce,
+        };
+        Ok((server, server_first))
+    }
+
+    /// Finishes the exchange.
+    ///
+    /// Returns the server's final message if the client proved that it knows
+    /// the password, or an error otherwise.
+    pub fn finish(self, client_final: &[u8]) -> Result<String, ScramError> {
+        let malformed = ScramError::Malformed("client-final-message");
+        let client_final = str::from_utf8(client_final).map_err(|_| malformed.clone())?;
+
+        let (client_final_without_proof, proof) =
+            client_final.rsplit_once(",p=").ok_or(malformed.clone())?;
+        let mut attrs = client_final_without_proof.split(',');
+        let channel_binding = attrs
+            .next()
+            .and_then(|attr| attr.strip_prefix("c="))
+            .and_then(|attr| base64::decode(attr).ok())
+            .ok_or(malformed.clone())?;
+        if channel_binding != self.gs2_header.as_bytes() {
+            return Err(malformed);
+        }
+        let nonce = attrs
+            .next()
+            .and_then(|attr| attr.strip_prefix("r="))
+            .ok_or(malformed.clone())?;
+        if nonce != self.nonce {
+            return Err(malformed);
+        }
+        let proof: [u8; 32] = base64::decode(proof)
+            .ok()
+            .and_then(|proof| proof.try_into().ok())
+            .ok_or(malformed)?;
+
+        let auth_message = format!(
+            "{},{},{}",
+            self.client_first_bare, self.server_first, client_final_without_proof
+        );
+        let client_signature = hmac(&self.verifier.stored_key, auth_message.as_bytes());
+        let mut client_key = proof;
+        for (b, s) in client_key.iter_mut().zip(client_signature) {
+            *b ^= s;
+        }
+        if !bool::from(sha256(&client_key).ct_eq(&self.verifier.stored_key)) {
+            return Err(ScramError::InvalidProof);
+        }
+
+        let server_signature = hmac(&self.verifier.server_key, auth_message.as_bytes());
+        Ok(format!("v={}", base64::encode(server_signature)))
+    }
+}
+
+/// The `Hi` function from RFC 5802, which is PBKDF2 with HMAC-SHA-256 as the
+/// pseudorandom function and an output of a single block.
+fn hi(password: &[u8], salt: &[u8], iterations: u32) -> [u8; 32] {
+    let mut salt_and_index = salt.to_vec();
+    salt_and_index.extend_from_slice(&1u32.to_be_bytes());
+    let mut u = hmac(password, &salt_and_index);
+    let mut result = u;
+    for _ in 1..iterations {
+        u = hmac(password, &u);
+        for (r, u) in result.iter_mut().zip(u) {
+            *r ^= u;
+        }
+    }
+    result
+}
+
+fn hmac(key: &[u8], message: &[u8]) -> [u8; 32] {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key size");
+    mac.update(message);
+    let mut out = [0; 32];
+    out.copy_from_slice(&mac.finalize().into_bytes());
+    out
+}
+
+fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut out = [0; 32];
+    out.copy_from_slice(&Sha256::digest(data));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example exchange from RFC 7677.
+    const PASSWORD: &str = "pencil";
+    const SALT: &str = "W22ZaJ0SNY7soEsUEjb6gQ==";
+    const CLIENT_FIRST: &str = "n,,n=user,r=rOprNGfwEbeRWgbNEkqO";
+    const SERVER_NONCE: &str = "%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0";
+    const SERVER_FIRST: &str =
+        "r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0,s=W22ZaJ0SNY7soEsUEjb6gQ==,i=4096";
+    const CLIENT_FINAL: &str = "c=biws,r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0,\
+        p=dHzbZapWIk4jUhN+Ute9ytag9zjfMHgsqmmiz7AndVQ=";
+    const SERVER_FINAL: &str = "v=6rriTRBi23WpRR/wtup+mMhUZUn/dB5nLTJRsjl95G4=";
+
+    fn verifier(password: &str) -> ScramVerifier {
+        ScramVerifier::new(password, &base64::decode(SALT).unwrap(), DEFAULT_ITERATIONS)
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn test_exchange() {
+        let (server, server_first) = ScramServer::start_with_nonce(
+            verifier(PASSWORD),
+            CLIENT_FIRST.as_bytes(),
+            SERVER_NONCE,
+        )
+        .unwrap();
+        assert_eq!(server_first, SERVER_FIRST);
+        assert_eq!(
+            server.finish(CLIENT_FINAL.as_bytes()).unwrap(),
+            SERVER_FINAL
+        );
+
+        let (server, _) =
+            ScramServer::start_with_nonce(verifier("wrong"), CLIENT_FIRST.as_bytes(), SERVER_NONCE)
+                .unwrap();
+        assert_eq!(
+            server.finish(CLIENT_FINAL.as_bytes()),
+            Err(ScramError::InvalidProof)
+        );
+
+        assert_eq!(
+            ScramServer::start(verifier(PASSWORD), b"p=tls-server-end-point,,n=user,r=abc")
+                .unwrap_err(),
+            ScramError::ChannelBindingUnsupported
+        );
+        assert_eq!(
+            ScramServer::start(verifier(PASSWORD), b"n,,n=user,r=").unwrap_err(),
+            ScramError::Malformed("client-first-message")
+        );
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn test_verifier() {
+        let verifier = ScramVerifier::generate(PASSWORD);
+        assert!(verifier.verify(PASSWORD));
+        assert!(!verifier.verify("wrong"));
+        assert_eq!(verifier.to_string().parse::<ScramVerifier>(), Ok(verifier));
+        assert_eq!(
+            "md5abc".parse::<ScramVerifier>(),
+            Err(ScramError::Malformed("verifier"))
+        );
+    }
+}
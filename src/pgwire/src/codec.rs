@@ -122,6 +122,15 @@ where
         self.inner.flush().await
     }
 
+    /// Sets the kind of password message that the client is expected to send
+    /// next.
+    ///
+    /// All password messages share the same type byte, so they can only be
+    /// told apart by the authentication method that the server requested.
+    pub fn set_password_message_kind(&mut self, kind: PasswordMessageKind) {
+        self.inner.get_mut().codec_mut().password_message_kind = kind;
+    }
+
     /// Injects state that affects how certain backend messages are encoded.
     ///
     /// Specifically, the encoding of `BackendMessage::DataRow` depends upon the
@@ -192,9 +201,21 @@ where
     }
 }
 
+/// The kind of password message that the client is expected to send.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordMessageKind {
+    /// A `PasswordMessage`, in response to `AuthenticationCleartextPassword`.
+    Password,
+    /// A `SASLInitialResponse`, in response to `AuthenticationSASL`.
+    SaslInitialResponse,
+    /// A `SASLResponse`, in response to `AuthenticationSASLContinue`.
+    SaslResponse,
+}
+
 struct Codec {
     decode_state: DecodeState,
     encode_state: Vec<(mz_pgrepr::Type, mz_pgwire_common::Format)>,
+    password_message_kind: PasswordMessageKind,
 }
 
 impl Codec {
@@ -203,6 +224,7 @@ impl Codec {
         Codec {
             decode_state: DecodeState::Head,
             encode_state: vec![],
+            password_message_kind: PasswordMessageKind::Password,
         }
     }
 }
@@ -221,6 +243,9 @@ impl Encoder<BackendMessage> for Codec {
         let byte = match &msg {
             BackendMessage::AuthenticationOk => b'R',
             BackendMessage::AuthenticationCleartextPassword => b'R',
+            BackendMessage::AuthenticationSasl(_) => b'R',
+            BackendMessage::AuthenticationSaslContinue(_) => b'R',
+            BackendMessage::AuthenticationSaslFinal(_) => b'R',
             BackendMessage::RowDescription(_) => b'T',
             BackendMessage::DataRow(_) => b'D',
             BackendMessage::CommandComplete { .. } => b'C',
@@ -278,6 +303,21 @@ impl Encoder<BackendMessage> for Codec {
             BackendMessage::AuthenticationCleartextPassword => {
                 dst.put_u32(3);
             }
+            BackendMessage::AuthenticationSasl(mechanisms) => {
+                dst.put_u32(10);
+                for mechanism in mechanisms {
+                    dst.put_string(mechanism);
+                }
+                dst.put_u8(b'\0');
+            }
+            BackendMessage::AuthenticationSaslContinue(data) => {
+                dst.put_u32(11);
+                dst.put_slice(&data);
+            }
+            BackendMessage::AuthenticationSaslFinal(data) => {
+                dst.put_u32(12);
+                dst.put_slice(&data);
+            }
             BackendMessage::RowDescription(fields) => {
                 dst.put_length_i16(fields.len())?;
                 for f in &fields {
@@ -468,7 +508,15 @@ impl Decoder for Codec {
                         b'X' => decode_terminate(buf)?,
 
                         // Authentication.
-                        b'p' => decode_password(buf)?,
+                        b'p' => match self.password_message_kind {
+                            PasswordMessageKind::Password => decode_password(buf)?,
+                            PasswordMessageKind::SaslInitialResponse => {
+                                decode_sasl_initial_response(buf)?
+                            }
+                            PasswordMessageKind::SaslResponse => {
+                                decode_sasl_response(buf, frame_len)?
+                            }
+                        },
 
                         // Copy from flow.
                         b'f' => decode_copy_fail(buf)?,
@@ -503,6 +551,22 @@ fn decode_password(mut buf: Cursor) -> Result<FrontendMessage, io::Error> {
     })
 }
 
+fn decode_sasl_initial_response(mut buf: Cursor) -> Result<FrontendMessage, io::Error> {
+    let mechanism = buf.read_cstr()?.to_owned();
+    // A length of -1 indicates that there is no initial response.
+    let data = match usize::try_from(buf.read_i32()?) {
+        Ok(len) => buf.read_bytes(len)?.to_vec(),
+        Err(_) => vec![],
+    };
+    Ok(FrontendMessage::SaslInitialResponse { mechanism, data })
+}
+
+fn decode_sasl_response(mut buf: Cursor, frame_len: usize) -> Result<FrontendMessage, io::Error> {
+    Ok(FrontendMessage::SaslResponse {
+        data: buf.read_bytes(frame_len)?.to_vec(),
+    })
+}
+
 fn decode_query(mut buf: Cursor) -> Result<FrontendMessage, io::Error> {
     Ok(FrontendMessage::Query {
         sql: buf.read_cstr()?.to_string(),
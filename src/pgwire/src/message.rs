@@ -19,6 +19,9 @@ use mz_repr::{ColumnName, RelationDesc};
 pub enum BackendMessage {
     AuthenticationOk,
     AuthenticationCleartextPassword,
+    AuthenticationSasl(Vec<&'static str>),
+    AuthenticationSaslContinue(Vec<u8>),
+    AuthenticationSaslFinal(Vec<u8>),
     CommandComplete {
         tag: String,
     },
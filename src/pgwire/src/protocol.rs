@@ -10,6 +10,7 @@
 use std::collections::BTreeMap;
 use std::convert::TryFrom;
 use std::future::Future;
+use std::net::IpAddr;
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 use std::{cmp, iter, mem};
@@ -23,8 +24,8 @@ use mz_adapter::session::{
 };
 use mz_adapter::statement_logging::StatementEndedExecutionReason;
 use mz_adapter::{
-    AdapterError, AdapterNotice, ExecuteContextExtra, ExecuteResponse, PeekResponseUnary,
-    RowsFuture,
+    AdapterError, AdapterNotice, AuthenticateServiceAccountResponse, ExecuteContextExtra,
+    ExecuteResponse, PeekResponseUnary, RowsFuture,
 };
 use mz_frontegg_auth::{
     Authentication as FronteggAuthentication, ExchangePasswordForTokenResponse,
@@ -33,6 +34,7 @@ use mz_ore::cast::CastFrom;
 use mz_ore::netio::AsyncReady;
 use mz_ore::str::StrExt;
 use mz_pgcopy::CopyFormatParams;
+use mz_pgwire_common::scram::{ScramError, ScramServer, ScramVerifier, SCRAM_SHA_256};
 use mz_pgwire_common::{ErrorResponse, Format, FrontendMessage, Severity, VERSIONS, VERSION_3};
 use mz_repr::user::ExternalUserMetadata;
 use mz_repr::{Datum, GlobalId, RelationDesc, RelationType, Row, RowArena, ScalarType};
@@ -51,7 +53,7 @@ use tokio::time::{self};
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use tracing::{debug, debug_span, instrument, warn, Instrument};
 
-use crate::codec::FramedConn;
+use crate::codec::{FramedConn, PasswordMessageKind};
 use crate::message::{self, BackendMessage};
 
 /// Reports whether the given stream begins with a pgwire handshake.
@@ -93,6 +95,8 @@ pub struct RunParams<'a, A> {
     pub internal: bool,
     /// Global connection limit and count
     pub active_connection_count: Arc<Mutex<ConnectionCounter>>,
+    /// The IP address of the client, if known.
+    pub client_ip: Option<IpAddr>,
 }
 
 /// Runs a pgwire connection to completion.
@@ -115,6 +119,7 @@ pub async fn run<'a, A>(
         frontegg,
         internal,
         active_connection_count,
+        client_ip,
     }: RunParams<'a, A>,
 ) -> Result<(), io::Error>
 where
@@ -130,6 +135,10 @@ where
     }
 
     let user = params.remove("user").unwrap_or_else(String::new);
+    let application_name = params
+        .get("application_name")
+        .cloned()
+        .unwrap_or_else(String::new);
 
     if internal {
         // The internal server can only be used to connect to the internal users.
@@ -169,7 +178,9 @@ where
             }
         };
 
-        let auth_response = frontegg.exchange_password_for_token(&password, user).await;
+        let auth_response = frontegg
+            .exchange_password_for_token(&password, user.clone())
+            .await;
         match auth_response {
             Ok(result) => {
                 let ExchangePasswordForTokenResponse {
@@ -215,6 +226,7 @@ where
             }
             Err(err) => {
                 warn!(?err, "pgwire connection failed authentication");
+                adapter_client.login_failure(user, client_ip, application_name, err.to_string());
                 return conn
                     .send(ErrorResponse::fatal(
                         SqlState::INVALID_PASSWORD,
@@ -223,7 +235,72 @@ where
                     .await;
             }
         }
+    } else if adapter_client.service_account_exists(&user).await {
+        // Service accounts authenticate with their token in place of a password, and then act as
+        // the role that they were created for.
+        conn.send(BackendMessage::AuthenticationCleartextPassword)
+            .await?;
+        conn.flush().await?;
+        let token = match conn.recv().await? {
+            Some(FrontendMessage::Password { password }) => password,
+            _ => {
+                return conn
+                    .send(ErrorResponse::fatal(
+                        SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
+                        "expected Password message",
+                    ))
+                    .await
+            }
+        };
+        let AuthenticateServiceAccountResponse { role_name, scopes } = match adapter_client
+            .authenticate_service_account(user.clone(), token)
+            .await
+        {
+            Ok(response) => response,
+            Err(err) => {
+                warn!(
+                    ?err,
+                    "pgwire connection failed service account authentication"
+                );
+                adapter_client.login_failure(user, client_ip, application_name, err.to_string());
+                return conn.send(err.into_response(Severity::Fatal)).await;
+            }
+        };
+        let mut session = adapter_client.new_session(
+            conn.conn_id().clone(),
+            User {
+                name: role_name,
+                external_metadata: None,
+            },
+        );
+        session.set_service_account_scopes(scopes);
+        // Service account expiration is only checked at login, so is_expired never resolves.
+        let is_expired = pending().right_future();
+        (session, is_expired)
     } else {
+        // Without frontegg, roles that have a password must authenticate with it, while all other
+        // roles are trusted.
+        if let Some(verifier) = adapter_client.role_password_verifier(&user).await {
+            let res = match verifier.parse() {
+                Ok(verifier) => authenticate_scram(conn, verifier).await?,
+                Err(err) => Err(err),
+            };
+            if let Err(err) = res {
+                warn!(?err, "pgwire connection failed password authentication");
+                adapter_client.login_failure(
+                    user.clone(),
+                    client_ip,
+                    application_name,
+                    err.to_string(),
+                );
+                return conn
+                    .send(ErrorResponse::fatal(
+                        SqlState::INVALID_PASSWORD,
+                        format!("password authentication failed for user {}", user.quoted()),
+                    ))
+                    .await;
+            }
+        }
         let session = adapter_client.new_session(
             conn.conn_id().clone(),
             User {
@@ -236,6 +313,8 @@ where
         (session, is_expired)
     };
 
+    session.set_client_ip(client_ip);
+
     for (name, value) in params {
         let settings = match name.as_str() {
             "options" => match parse_options(&value) {
@@ -337,6 +416,58 @@ where
     }
 }
 
+/// Performs a SCRAM-SHA-256 exchange with the client to check that it knows the password that
+/// `verifier` was derived from.
+///
+/// The outer error is an I/O error on the connection, while the inner error describes why the
+/// client failed to authenticate.
+async fn authenticate_scram<A>(
+    conn: &mut FramedConn<A>,
+    verifier: ScramVerifier,
+) -> Result<Result<(), ScramError>, io::Error>
+where
+    A: AsyncRead + AsyncWrite + Unpin,
+{
+    conn.set_password_message_kind(PasswordMessageKind::SaslInitialResponse);
+    conn.send(BackendMessage::AuthenticationSasl(vec![SCRAM_SHA_256]))
+        .await?;
+    conn.flush().await?;
+    let client_first = match conn.recv().await? {
+        Some(FrontendMessage::SaslInitialResponse { mechanism, data })
+            if mechanism == SCRAM_SHA_256 =>
+        {
+            data
+        }
+        _ => return Ok(Err(ScramError::Malformed("client-first-message"))),
+    };
+    let (server, server_first) = match ScramServer::start(verifier, &client_first) {
+        Ok(res) => res,
+        Err(err) => return Ok(Err(err)),
+    };
+
+    conn.set_password_message_kind(PasswordMessageKind::SaslResponse);
+    conn.send(BackendMessage::AuthenticationSaslContinue(
+        server_first.into_bytes(),
+    ))
+    .await?;
+    conn.flush().await?;
+    let client_final = match conn.recv().await? {
+        Some(FrontendMessage::SaslResponse { data }) => data,
+        _ => return Ok(Err(ScramError::Malformed("client-final-message"))),
+    };
+    conn.set_password_message_kind(PasswordMessageKind::Password);
+
+    let server_final = match server.finish(&client_final) {
+        Ok(server_final) => server_final,
+        Err(err) => return Ok(Err(err)),
+    };
+    conn.send(BackendMessage::AuthenticationSaslFinal(
+        server_final.into_bytes(),
+    ))
+    .await?;
+    Ok(Ok(()))
+}
+
 /// Returns (name, value) session settings pairs from an options value.
 ///
 /// From Postgres, see pg_split_opts in postinit.c and process_postgres_switches
@@ -578,7 +709,9 @@ where
             Some(FrontendMessage::CopyData(_))
             | Some(FrontendMessage::CopyDone)
             | Some(FrontendMessage::CopyFail(_))
-            | Some(FrontendMessage::Password { .. }) => State::Drain,
+            | Some(FrontendMessage::Password { .. })
+            | Some(FrontendMessage::SaslInitialResponse { .. })
+            | Some(FrontendMessage::SaslResponse { .. }) => State::Drain,
             None => State::Done,
         };
 
@@ -1697,6 +1830,7 @@ where
             | ExecuteResponse::AlteredSystemConfiguration
             | ExecuteResponse::CreatedCluster { .. }
             | ExecuteResponse::CreatedClusterReplica { .. }
+            | ExecuteResponse::CreatedClusterReplicaSize
             | ExecuteResponse::CreatedConnection { .. }
             | ExecuteResponse::CreatedDatabase { .. }
             | ExecuteResponse::CreatedIndex { .. }
@@ -1704,6 +1838,7 @@ where
             | ExecuteResponse::CreatedRole
             | ExecuteResponse::CreatedSchema { .. }
             | ExecuteResponse::CreatedSecret { .. }
+            | ExecuteResponse::CreatedServiceAccount
             | ExecuteResponse::CreatedSink { .. }
             | ExecuteResponse::CreatedSource { .. }
             | ExecuteResponse::CreatedTable { .. }
@@ -1715,8 +1850,10 @@ where
             | ExecuteResponse::Deleted(..)
             | ExecuteResponse::DiscardedAll
             | ExecuteResponse::DiscardedTemp
+            | ExecuteResponse::DroppedClusterReplicaSize
             | ExecuteResponse::DroppedObject(_)
             | ExecuteResponse::DroppedOwned
+            | ExecuteResponse::DroppedServiceAccount
             | ExecuteResponse::GrantedPrivilege
             | ExecuteResponse::GrantedRole
             | ExecuteResponse::Inserted(..)
@@ -1726,6 +1863,7 @@ where
             | ExecuteResponse::RevokedPrivilege
             | ExecuteResponse::RevokedRole
             | ExecuteResponse::StartedTransaction { .. }
+            | ExecuteResponse::UndroppedObject(_)
             | ExecuteResponse::Updated(..)
             | ExecuteResponse::ValidatedConnection => {
                 command_complete!()
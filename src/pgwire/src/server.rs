@@ -9,6 +9,7 @@
 
 use std::fmt;
 use std::future::Future;
+use std::net::IpAddr;
 use std::pin::Pin;
 use std::sync::{Arc, Mutex};
 
@@ -70,10 +71,11 @@ impl mz_server_core::Server for Server {
     const NAME: &'static str = "pgwire";
 
     fn handle_connection(&self, conn: TcpStream) -> ConnectionHandler {
+        let client_ip = conn.peer_addr().ok().map(|addr| addr.ip());
         // Using fully-qualified syntax means we won't accidentally call
         // ourselves (i.e., silently infinitely recurse) if the name or type of
         // `crate::Server::handle_connection` changes.
-        Box::pin(crate::Server::handle_connection(self, conn))
+        Box::pin(crate::Server::handle_connection(self, conn, client_ip))
     }
 }
 
@@ -94,6 +96,7 @@ impl Server {
     pub fn handle_connection<A>(
         &self,
         conn: A,
+        client_ip: Option<IpAddr>,
     ) -> impl Future<Output = Result<(), anyhow::Error>> + 'static + Send
     where
         A: AsyncRead + AsyncWrite + AsyncReady + Send + Sync + Unpin + fmt::Debug + 'static,
@@ -136,6 +139,7 @@ impl Server {
                                     frontegg: frontegg.as_ref(),
                                     internal,
                                     active_connection_count,
+                                    client_ip,
                                 })
                                 .await?;
                                 conn.flush().await?;
@@ -0,0 +1,84 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Helpers for authenticating with Amazon RDS and Aurora databases using AWS
+//! IAM.
+
+use std::time::{Duration, SystemTime};
+
+use anyhow::{anyhow, Context};
+use aws_credential_types::provider::ProvideCredentials;
+use aws_sigv4::http_request::{
+    sign, SignableRequest, SignatureLocation, SigningParams, SigningSettings,
+};
+use aws_types::SdkConfig;
+use url::Url;
+
+/// How long the tokens generated by [`generate_auth_token`] are valid for.
+///
+/// RDS does not accept tokens that are valid for longer than 15 minutes.
+const TOKEN_LIFETIME: Duration = Duration::from_secs(900);
+
+/// Generates a token that authenticates `user` with the RDS or Aurora database
+/// at `host` and `port` using the credentials in `sdk_config`.
+///
+/// The token is a presigned URL for the `rds-db:connect` action, stripped of
+/// its scheme, and is presented to the database as the user's password. Tokens
+/// are only checked when a connection is established, so connections outlive
+/// the tokens used to open them.
+pub async fn generate_auth_token(
+    sdk_config: &SdkConfig,
+    host: &str,
+    port: u16,
+    user: &str,
+) -> Result<String, anyhow::Error> {
+    let region = sdk_config
+        .region()
+        .ok_or_else(|| anyhow!("AWS connection does not specify a region"))?;
+    let credentials = sdk_config
+        .credentials_provider()
+        .ok_or_else(|| anyhow!("AWS connection does not specify credentials"))?
+        .provide_credentials()
+        .await
+        .context("loading AWS credentials")?;
+
+    let mut url = Url::parse(&format!("https://{host}:{port}/"))?;
+    url.query_pairs_mut()
+        .append_pair("Action", "connect")
+        .append_pair("DBUser", user);
+
+    let mut settings = SigningSettings::default();
+    settings.signature_location = SignatureLocation::QueryParams;
+    settings.expires_in = Some(TOKEN_LIFETIME);
+    let mut params = SigningParams::builder()
+        .access_key(credentials.access_key_id())
+        .secret_key(credentials.secret_access_key())
+        .region(region.as_ref())
+        .service_name("rds-db")
+        .time(SystemTime::now())
+        .settings(settings);
+    params.set_security_token(credentials.session_token());
+    let params = params.build().context("building AWS signing parameters")?;
+
+    let mut request = http::Request::builder()
+        .method("GET")
+        .uri(url.as_str())
+        .body("")
+        .context("building RDS authentication request")?;
+    let (instructions, _signature) = sign(SignableRequest::from(&request), &params)
+        .context("signing RDS authentication request")?
+        .into_parts();
+    instructions.apply_to_request(&mut request);
+
+    let token = request.uri().to_string();
+    let token = token
+        .strip_prefix("https://")
+        .expect("signed URL uses the scheme it was built with");
+    Ok(token.into())
+}
@@ -88,6 +88,8 @@ macro_rules! bail_generic {
     };
 }
 
+#[cfg(feature = "tunnel")]
+pub mod aws;
 #[cfg(feature = "replication")]
 pub mod replication;
 #[cfg(feature = "replication")]
@@ -99,7 +101,7 @@ pub mod desc;
 #[cfg(feature = "schemas")]
 pub mod schemas;
 #[cfg(feature = "schemas")]
-pub use schemas::{get_schemas, publication_info};
+pub use schemas::{get_schemas, publication_info, publication_info_filtered, table_info};
 #[cfg(feature = "tunnel")]
 pub mod tunnel;
 #[cfg(feature = "tunnel")]
@@ -7,20 +7,20 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use futures::future::try_join_all;
 use mz_ssh_util::tunnel_manager::SshTunnelManager;
 use tokio_postgres::types::Oid;
+use tokio_postgres::Client;
 
 use crate::desc::{PostgresColumnDesc, PostgresKeyDesc, PostgresSchemaDesc, PostgresTableDesc};
 use crate::{Config, PostgresError};
 
-pub async fn get_schemas(
-    ssh_tunnel_manager: &SshTunnelManager,
-    config: &Config,
-) -> Result<Vec<PostgresSchemaDesc>, PostgresError> {
-    let client = config
-        .connect("postgres_schemas", ssh_tunnel_manager)
-        .await?;
+/// The maximum number of connections [`publication_info`] and
+/// [`publication_info_filtered`] use to fetch the schemas of a publication's
+/// tables concurrently.
+const PUBLICATION_INFO_MAX_CONNECTIONS: usize = 8;
 
+pub async fn get_schemas(client: &Client) -> Result<Vec<PostgresSchemaDesc>, PostgresError> {
     Ok(client
         .query("SELECT oid, nspname, nspowner FROM pg_namespace", &[])
         .await?
@@ -55,6 +55,56 @@ pub async fn publication_info(
         .connect("postgres_publication_info", ssh_tunnel_manager)
         .await?;
 
+    let tables = publication_tables(&client, publication, oid_filter).await?;
+    table_descs(ssh_tunnel_manager, config, &client, tables).await
+}
+
+/// Like [`publication_info`], but only fetches schema information for the
+/// tables for which `table_filter`, when passed the table's schema and name,
+/// returns `true`.
+///
+/// Queries are issued over `client`, which must be connected using `config`,
+/// and only publications with many tables open further connections.
+///
+/// Returns `None` if the publication contains no tables at all, which allows
+/// callers to distinguish an empty publication from one in which no table
+/// passes the filter.
+///
+/// # Errors
+///
+/// See [`publication_info`].
+pub async fn publication_info_filtered<F>(
+    ssh_tunnel_manager: &SshTunnelManager,
+    config: &Config,
+    client: &Client,
+    publication: &str,
+    table_filter: F,
+) -> Result<Option<Vec<PostgresTableDesc>>, PostgresError>
+where
+    F: Fn(&str, &str) -> bool,
+{
+    let tables = publication_tables(client, publication, None).await?;
+    if tables.is_empty() {
+        return Ok(None);
+    }
+
+    let tables = tables
+        .into_iter()
+        .filter(|(_, namespace, name)| table_filter(namespace, name))
+        .collect();
+    Ok(Some(
+        table_descs(ssh_tunnel_manager, config, client, tables).await?,
+    ))
+}
+
+/// Lists the oid, schema name, and table name of the tables that are part of
+/// `publication`, optionally restricted to the table identified by
+/// `oid_filter`.
+async fn publication_tables(
+    client: &Client,
+    publication: &str,
+    oid_filter: Option<u32>,
+) -> Result<Vec<(Oid, String, String)>, PostgresError> {
     client
         .query(
             "SELECT oid FROM pg_publication WHERE pubname = $1",
@@ -64,7 +114,7 @@ pub async fn publication_info(
         .get(0)
         .ok_or_else(|| anyhow::anyhow!("publication {:?} does not exist", publication))?;
 
-    let tables = client
+    Ok(client
         .query(
             "SELECT
                 c.oid, p.schemaname, p.tablename
@@ -78,130 +128,210 @@ pub async fn publication_info(
                 AND ($2::oid IS NULL OR c.oid = $2::oid)",
             &[&publication, &oid_filter],
         )
+        .await?
+        .into_iter()
+        .map(|row| (row.get("oid"), row.get("schemaname"), row.get("tablename")))
+        .collect())
+}
+
+/// Fetches the schemas of `tables`, which are identified by their oid, schema
+/// name, and table name, returning them in the same order.
+///
+/// Fetching each table's schema requires several round trips, which adds up
+/// for publications with many tables. The tables are spread over a bounded
+/// number of connections, the first of which is `client`, and their schemas
+/// fetched concurrently.
+async fn table_descs(
+    ssh_tunnel_manager: &SshTunnelManager,
+    config: &Config,
+    client: &Client,
+    tables: Vec<(Oid, String, String)>,
+) -> Result<Vec<PostgresTableDesc>, PostgresError> {
+    let connections = tables.len().clamp(1, PUBLICATION_INFO_MAX_CONNECTIONS);
+    let additional_clients = try_join_all(
+        (1..connections).map(|_| config.connect("postgres_publication_info", ssh_tunnel_manager)),
+    )
+    .await?;
+    let mut clients = vec![client];
+    clients.extend(additional_clients.iter());
+
+    let mut assignments = vec![vec![]; clients.len()];
+    for (i, table) in tables.into_iter().enumerate() {
+        assignments[i % clients.len()].push((i, table));
+    }
+
+    let table_infos = try_join_all(clients.into_iter().zip(assignments).map(
+        |(client, assignment)| async move {
+            let mut table_infos = vec![];
+            for (i, (oid, namespace, name)) in assignment {
+                table_infos.push((i, table_desc(client, oid, namespace, name).await?));
+            }
+            Ok::<_, PostgresError>(table_infos)
+        },
+    ))
+    .await?;
+
+    let mut table_infos: Vec<_> = table_infos.into_iter().flatten().collect();
+    table_infos.sort_by_key(|(i, _)| *i);
+    Ok(table_infos.into_iter().map(|(_, info)| info).collect())
+}
+
+/// Fetches schema information for the upstream table `schema.table`, or `None`
+/// if no such table exists.
+pub async fn table_info(
+    ssh_tunnel_manager: &SshTunnelManager,
+    config: &Config,
+    schema: &str,
+    table: &str,
+) -> Result<Option<PostgresTableDesc>, PostgresError> {
+    let client = config
+        .connect("postgres_table_info", ssh_tunnel_manager)
+        .await?;
+
+    let row = client
+        .query_opt(
+            "SELECT c.oid
+            FROM
+                pg_catalog.pg_class AS c
+                JOIN pg_namespace AS n ON c.relnamespace = n.oid
+            WHERE
+                n.nspname = $1
+                AND c.relname = $2
+                AND c.relkind IN ('r', 'p')",
+            &[&schema, &table],
+        )
         .await?;
 
-    let mut table_infos = vec![];
-    for row in tables {
-        let oid = row.get("oid");
-
-        let columns = client
-            .query(
-                "SELECT
-                        a.attname AS name,
-                        a.atttypid AS typoid,
-                        a.attnum AS colnum,
-                        a.atttypmod AS typmod,
-                        a.attnotnull AS not_null,
-                        b.oid IS NOT NULL AS primary_key
-                    FROM pg_catalog.pg_attribute a
-                    LEFT JOIN pg_catalog.pg_constraint b
-                        ON a.attrelid = b.conrelid
-                        AND b.contype = 'p'
-                        AND a.attnum = ANY (b.conkey)
-                    WHERE a.attnum > 0::pg_catalog.int2
-                        AND NOT a.attisdropped
-                        AND a.attrelid = $1
-                    ORDER BY a.attnum",
-                &[&oid],
-            )
-            .await?
-            .into_iter()
-            .map(|row| {
-                let name: String = row.get("name");
-                let type_oid = row.get("typoid");
-                let col_num = Some(
-                    row.get::<_, i16>("colnum")
-                        .try_into()
-                        .expect("non-negative values"),
-                );
-                let type_mod: i32 = row.get("typmod");
-                let not_null: bool = row.get("not_null");
-                Ok(PostgresColumnDesc {
-                    name,
-                    col_num,
-                    type_oid,
-                    type_mod,
-                    nullable: !not_null,
-                })
+    match row {
+        Some(row) => Ok(Some(
+            table_desc(&client, row.get("oid"), schema.into(), table.into()).await?,
+        )),
+        None => Ok(None),
+    }
+}
+
+/// Fetches the columns and keys of the table identified by `oid`.
+async fn table_desc(
+    client: &Client,
+    oid: Oid,
+    namespace: String,
+    name: String,
+) -> Result<PostgresTableDesc, PostgresError> {
+    let columns = client
+        .query(
+            "SELECT
+                    a.attname AS name,
+                    a.atttypid AS typoid,
+                    a.attnum AS colnum,
+                    a.atttypmod AS typmod,
+                    a.attnotnull AS not_null,
+                    b.oid IS NOT NULL AS primary_key
+                FROM pg_catalog.pg_attribute a
+                LEFT JOIN pg_catalog.pg_constraint b
+                    ON a.attrelid = b.conrelid
+                    AND b.contype = 'p'
+                    AND a.attnum = ANY (b.conkey)
+                WHERE a.attnum > 0::pg_catalog.int2
+                    AND NOT a.attisdropped
+                    AND a.attrelid = $1
+                ORDER BY a.attnum",
+            &[&oid],
+        )
+        .await?
+        .into_iter()
+        .map(|row| {
+            let name: String = row.get("name");
+            let type_oid = row.get("typoid");
+            let col_num = Some(
+                row.get::<_, i16>("colnum")
+                    .try_into()
+                    .expect("non-negative values"),
+            );
+            let type_mod: i32 = row.get("typmod");
+            let not_null: bool = row.get("not_null");
+            Ok(PostgresColumnDesc {
+                name,
+                col_num,
+                type_oid,
+                type_mod,
+                nullable: !not_null,
             })
-            .collect::<Result<Vec<_>, PostgresError>>()?;
-
-        // PG 15 adds UNIQUE NULLS NOT DISTINCT, which would let us use `UNIQUE` constraints over
-        // nullable columns as keys; i.e. aligns a PG index's NULL handling with an arrangement's
-        // keys. For more info, see https://www.postgresql.org/about/featurematrix/detail/392/
-        let pg_15_plus_keys = "
-        SELECT
-            pg_constraint.oid,
-            pg_constraint.conkey,
-            pg_constraint.conname,
-            pg_constraint.contype = 'p' AS is_primary,
-            pg_index.indnullsnotdistinct AS nulls_not_distinct
-        FROM
-            pg_constraint
-                JOIN
-                    pg_index
-                    ON pg_index.indexrelid = pg_constraint.conindid
-        WHERE
-            pg_constraint.conrelid = $1
-                AND
-            pg_constraint.contype =ANY (ARRAY['p', 'u']);";
-
-        // As above but for versions of PG without indnullsnotdistinct.
-        let pg_14_minus_keys = "
-        SELECT
-            pg_constraint.oid,
-            pg_constraint.conkey,
-            pg_constraint.conname,
-            pg_constraint.contype = 'p' AS is_primary,
-            false AS nulls_not_distinct
-        FROM pg_constraint
-        WHERE
-            pg_constraint.conrelid = $1
-                AND
-            pg_constraint.contype =ANY (ARRAY['p', 'u']);";
-
-        let keys = match client.query(pg_15_plus_keys, &[&oid]).await {
-            Ok(keys) => keys,
-            Err(e)
-                // PG versions prior to 15 do not contain this column.
-                if e.to_string()
-                    == "db error: ERROR: column pg_index.indnullsnotdistinct does not exist" =>
-            {
-                client.query(pg_14_minus_keys, &[&oid]).await?
+        })
+        .collect::<Result<Vec<_>, PostgresError>>()?;
+
+    // PG 15 adds UNIQUE NULLS NOT DISTINCT, which would let us use `UNIQUE` constraints over
+    // nullable columns as keys; i.e. aligns a PG index's NULL handling with an arrangement's
+    // keys. For more info, see https://www.postgresql.org/about/featurematrix/detail/392/
+    let pg_15_plus_keys = "
+    SELECT
+        pg_constraint.oid,
+        pg_constraint.conkey,
+        pg_constraint.conname,
+        pg_constraint.contype = 'p' AS is_primary,
+        pg_index.indnullsnotdistinct AS nulls_not_distinct
+    FROM
+        pg_constraint
+            JOIN
+                pg_index
+                ON pg_index.indexrelid = pg_constraint.conindid
+    WHERE
+        pg_constraint.conrelid = $1
+            AND
+        pg_constraint.contype =ANY (ARRAY['p', 'u']);";
+
+    // As above but for versions of PG without indnullsnotdistinct.
+    let pg_14_minus_keys = "
+    SELECT
+        pg_constraint.oid,
+        pg_constraint.conkey,
+        pg_constraint.conname,
+        pg_constraint.contype = 'p' AS is_primary,
+        false AS nulls_not_distinct
+    FROM pg_constraint
+    WHERE
+        pg_constraint.conrelid = $1
+            AND
+        pg_constraint.contype =ANY (ARRAY['p', 'u']);";
+
+    let keys = match client.query(pg_15_plus_keys, &[&oid]).await {
+        Ok(keys) => keys,
+        Err(e)
+            // PG versions prior to 15 do not contain this column.
+            if e.to_string()
+                == "db error: ERROR: column pg_index.indnullsnotdistinct does not exist" =>
+        {
+            client.query(pg_14_minus_keys, &[&oid]).await?
+        }
+        e => e?,
+    };
+
+    let keys = keys
+        .into_iter()
+        .map(|row| {
+            let oid: u32 = row.get("oid");
+            let cols: Vec<i16> = row.get("conkey");
+            let name: String = row.get("conname");
+            let is_primary: bool = row.get("is_primary");
+            let nulls_not_distinct: bool = row.get("nulls_not_distinct");
+            let cols = cols
+                .into_iter()
+                .map(|col| u16::try_from(col).expect("non-negative colnums"))
+                .collect();
+            PostgresKeyDesc {
+                oid,
+                name,
+                cols,
+                is_primary,
+                nulls_not_distinct,
             }
-            e => e?,
-        };
-
-        let keys = keys
-            .into_iter()
-            .map(|row| {
-                let oid: u32 = row.get("oid");
-                let cols: Vec<i16> = row.get("conkey");
-                let name: String = row.get("conname");
-                let is_primary: bool = row.get("is_primary");
-                let nulls_not_distinct: bool = row.get("nulls_not_distinct");
-                let cols = cols
-                    .into_iter()
-                    .map(|col| u16::try_from(col).expect("non-negative colnums"))
-                    .collect();
-                PostgresKeyDesc {
-                    oid,
-                    name,
-                    cols,
-                    is_primary,
-                    nulls_not_distinct,
-                }
-            })
-            .collect();
-
-        table_infos.push(PostgresTableDesc {
-            oid,
-            namespace: row.get("schemaname"),
-            name: row.get("tablename"),
-            columns,
-            keys,
-        });
-    }
+        })
+        .collect();
 
-    Ok(table_infos)
+    Ok(PostgresTableDesc {
+        oid,
+        namespace,
+        name,
+        columns,
+        keys,
+    })
 }
@@ -9,6 +9,7 @@
 
 use std::time::Duration;
 
+use aws_types::SdkConfig;
 use mz_ore::option::OptionExt;
 use mz_ore::task;
 use mz_repr::GlobalId;
@@ -87,11 +88,17 @@ pub const DEFAULT_SNAPSHOT_STATEMENT_TIMEOUT: Duration = Duration::ZERO;
 pub struct Config {
     inner: tokio_postgres::Config,
     tunnel: TunnelConfig,
+    aws_auth: Option<SdkConfig>,
 }
 
 impl Config {
     pub fn new(inner: tokio_postgres::Config, tunnel: TunnelConfig) -> Result<Self, PostgresError> {
-        let config = Self { inner, tunnel }.tcp_timeouts(TcpTimeoutConfig::default());
+        let config = Self {
+            inner,
+            tunnel,
+            aws_auth: None,
+        }
+        .tcp_timeouts(TcpTimeoutConfig::default());
 
         // Early validate that the configuration contains only a single TCP
         // server.
@@ -119,6 +126,16 @@ impl Config {
         self
     }
 
+    /// Authenticates with AWS IAM, using the credentials in `sdk_config`,
+    /// rather than with a static password.
+    ///
+    /// IAM authentication tokens are short-lived, so a new token is generated
+    /// each time a connection is established.
+    pub fn aws_auth(mut self, sdk_config: SdkConfig) -> Config {
+        self.aws_auth = Some(sdk_config);
+        self
+    }
+
     /// Connects to the configured PostgreSQL database.
     pub async fn connect(
         &self,
@@ -196,6 +213,15 @@ impl Config {
         let mut postgres_config = self.inner.clone();
         configure(&mut postgres_config);
 
+        if let Some(sdk_config) = &self.aws_auth {
+            let (host, port) = self.address()?;
+            let Some(user) = self.get_user() else {
+                bail_generic!("AWS IAM authentication requires a user");
+            };
+            let token = crate::aws::generate_auth_token(sdk_config, host, port, user).await?;
+            postgres_config.password(token);
+        }
+
         let mut tls = mz_tls_util::make_tls(&postgres_config).map_err(|tls_err| match tls_err {
             mz_tls_util::TlsError::Generic(e) => PostgresError::Generic(e),
             mz_tls_util::TlsError::OpenSsl(e) => PostgresError::PostgresSsl(e),
@@ -497,6 +497,14 @@ impl RelationDesc {
         self.iter_names().filter(|n| n.is_similar(name))
     }
 
+    /// Reports whether the columns of this relation are the columns of `other`
+    /// followed by zero or more additional columns.
+    ///
+    /// Keys are not considered.
+    pub fn extends(&self, other: &RelationDesc) -> bool {
+        self.arity() >= other.arity() && other.iter().zip(self.iter()).all(|(o, s)| o == s)
+    }
+
     /// Finds a column by name.
     ///
     /// Returns the index and type of the column named `name`. If no column with
@@ -171,6 +171,8 @@ pub enum CsrConfigOptionName<T: AstInfo> {
     AvroValueFullname,
     NullDefaults,
     AvroDocOn(AvroDocOn<T>),
+    IncludeDeletedSubjects,
+    UnknownEnumSymbol,
 }
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct AvroDocOn<T: AstInfo> {
@@ -218,6 +220,8 @@ impl<T: AstInfo> AstDisplay for CsrConfigOptionName<T> {
             CsrConfigOptionName::AvroValueFullname => f.write_str("AVRO VALUE FULLNAME"),
             CsrConfigOptionName::NullDefaults => f.write_str("NULL DEFAULTS"),
             CsrConfigOptionName::AvroDocOn(doc_on) => f.write_node(doc_on),
+            CsrConfigOptionName::IncludeDeletedSubjects => f.write_str("INCLUDE DELETED SUBJECTS"),
+            CsrConfigOptionName::UnknownEnumSymbol => f.write_str("UNKNOWN ENUM SYMBOL"),
         }
     }
 }
@@ -406,12 +410,17 @@ pub enum Format<T: AstInfo> {
     Avro(AvroSchema<T>),
     Protobuf(ProtobufSchema<T>),
     Regex(String),
+    Grok(String),
     Csv {
         columns: CsvColumns,
         delimiter: char,
+        encoding: Option<String>,
     },
     Json,
-    Text,
+    Text {
+        encoding: Option<String>,
+    },
+    Parquet,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -529,7 +538,7 @@ impl Envelope {
             Envelope::None => false,
             // TODO[btv] - Adjust this if we change Dbz semantics
             // (why is this a parser-level concept, anyway? Should it be moved?)
-            Envelope::Debezium(DbzMode::Plain) => false,
+            Envelope::Debezium(DbzMode::Plain | DbzMode::Full) => false,
             Envelope::Upsert => false,
             Envelope::CdcV2 => true,
         }
@@ -606,7 +615,16 @@ impl<T: AstInfo> AstDisplay for Format<T> {
                 f.write_node(&display::escape_single_quote_string(regex));
                 f.write_str("'");
             }
-            Self::Csv { columns, delimiter } => {
+            Self::Grok(pattern) => {
+                f.write_str("GROK '");
+                f.write_node(&display::escape_single_quote_string(pattern));
+                f.write_str("'");
+            }
+            Self::Csv {
+                columns,
+                delimiter,
+                encoding,
+            } => {
                 f.write_str("CSV WITH ");
                 f.write_node(columns);
 
@@ -615,9 +633,22 @@ impl<T: AstInfo> AstDisplay for Format<T> {
                     f.write_node(&display::escape_single_quote_string(&delimiter.to_string()));
                     f.write_str("'");
                 }
+                if let Some(encoding) = encoding {
+                    f.write_str(" ENCODING '");
+                    f.write_node(&display::escape_single_quote_string(encoding));
+                    f.write_str("'");
+                }
             }
             Self::Json => f.write_str("JSON"),
-            Self::Text => f.write_str("TEXT"),
+            Self::Text { encoding } => {
+                f.write_str("TEXT");
+                if let Some(encoding) = encoding {
+                    f.write_str(" ENCODING '");
+                    f.write_node(&display::escape_single_quote_string(encoding));
+                    f.write_str("'");
+                }
+            }
+            Self::Parquet => f.write_str("PARQUET"),
         }
     }
 }
@@ -625,18 +656,21 @@ impl_display_t!(Format);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum DbzMode {
-    /// There is now only one `DEBEZIUM` envelope,
-    /// which has upsert semantics in sources and classic
-    /// semantics in sinks.
+    /// The `DEBEZIUM` envelope, which has upsert semantics in sources and
+    /// classic semantics in sinks.
     Plain,
+    /// `DEBEZIUM FULL`, which is only supported in sinks and additionally
+    /// emits the `source`, `op`, and `ts_ms` fields of Debezium's wire format.
+    Full,
 }
 
 impl AstDisplay for DbzMode {
-    fn fmt<W: fmt::Write>(&self, _f: &mut AstFormatter<W>) {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
             // We interpret the bare keyword `DEBEZIUM` as debezium upsert, so don't
             // display anything here.
             Self::Plain => {}
+            Self::Full => f.write_str(" FULL"),
         }
     }
 }
@@ -670,15 +704,29 @@ impl_display_t!(DbzTxMetadataOption);
 pub enum ConnectionOptionName {
     AccessKeyId,
     AvailabilityZones,
+    AwsConnection,
     AwsPrivatelink,
     Broker,
+    BrokerAddressTtl,
+    BrokerReconnectBackoff,
     Brokers,
     Database,
+    DefaultGroupIdPrefix,
+    DefaultTopicMetadataRefreshIntervalMs,
+    EgressAllowlist,
     Endpoint,
     Host,
+    Hosts,
+    OauthClientId,
+    OauthClientSecret,
+    OauthScope,
+    OauthTokenEndpoint,
     Password,
     Port,
     ProgressTopic,
+    ProxyPassword,
+    ProxyUrl,
+    ProxyUser,
     Region,
     RoleArn,
     SaslMechanisms,
@@ -702,15 +750,31 @@ impl AstDisplay for ConnectionOptionName {
         f.write_str(match self {
             ConnectionOptionName::AccessKeyId => "ACCESS KEY ID",
             ConnectionOptionName::AvailabilityZones => "AVAILABILITY ZONES",
+            ConnectionOptionName::AwsConnection => "AWS CONNECTION",
             ConnectionOptionName::AwsPrivatelink => "AWS PRIVATELINK",
             ConnectionOptionName::Broker => "BROKER",
+            ConnectionOptionName::BrokerAddressTtl => "BROKER ADDRESS TTL",
+            ConnectionOptionName::BrokerReconnectBackoff => "BROKER RECONNECT BACKOFF",
             ConnectionOptionName::Brokers => "BROKERS",
             ConnectionOptionName::Database => "DATABASE",
+            ConnectionOptionName::DefaultGroupIdPrefix => "DEFAULT GROUP ID PREFIX",
+            ConnectionOptionName::DefaultTopicMetadataRefreshIntervalMs => {
+                "DEFAULT TOPIC METADATA REFRESH INTERVAL MS"
+            }
+            ConnectionOptionName::EgressAllowlist => "EGRESS ALLOWLIST",
             ConnectionOptionName::Endpoint => "ENDPOINT",
             ConnectionOptionName::Host => "HOST",
+            ConnectionOptionName::Hosts => "HOSTS",
+            ConnectionOptionName::OauthClientId => "OAUTH CLIENT ID",
+            ConnectionOptionName::OauthClientSecret => "OAUTH CLIENT SECRET",
+            ConnectionOptionName::OauthScope => "OAUTH SCOPE",
+            ConnectionOptionName::OauthTokenEndpoint => "OAUTH TOKEN ENDPOINT",
             ConnectionOptionName::Password => "PASSWORD",
             ConnectionOptionName::Port => "PORT",
             ConnectionOptionName::ProgressTopic => "PROGRESS TOPIC",
+            ConnectionOptionName::ProxyPassword => "PROXY PASSWORD",
+            ConnectionOptionName::ProxyUrl => "PROXY URL",
+            ConnectionOptionName::ProxyUser => "PROXY USER",
             ConnectionOptionName::Region => "REGION",
             ConnectionOptionName::RoleArn => "ROLE ARN",
             ConnectionOptionName::SaslMechanisms => "SASL MECHANISMS",
@@ -821,8 +885,14 @@ impl_display_t!(CreateConnectionOption);
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum KafkaConfigOptionName {
     CompressionType,
+    DeadLetterTopic,
+    DeleteMode,
     GroupIdPrefix,
+    MaxBytesPerSecond,
+    MaxMessagesPerSecond,
     Topic,
+    TopicAllowlist,
+    TopicFromColumn,
     TopicMetadataRefreshIntervalMs,
     StartTimestamp,
     StartOffset,
@@ -830,14 +900,24 @@ pub enum KafkaConfigOptionName {
     ReplicationFactor,
     RetentionMs,
     RetentionBytes,
+    ProgressTopic,
+    ProgressTopicReplicationFactor,
+    TransactionalIdPrefix,
+    TransactionTimeoutMs,
 }
 
 impl AstDisplay for KafkaConfigOptionName {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         f.write_str(match self {
             KafkaConfigOptionName::CompressionType => "COMPRESSION TYPE",
+            KafkaConfigOptionName::DeadLetterTopic => "DEAD LETTER TOPIC",
+            KafkaConfigOptionName::DeleteMode => "DELETE MODE",
             KafkaConfigOptionName::GroupIdPrefix => "GROUP ID PREFIX",
+            KafkaConfigOptionName::MaxBytesPerSecond => "MAX BYTES PER SECOND",
+            KafkaConfigOptionName::MaxMessagesPerSecond => "MAX MESSAGES PER SECOND",
             KafkaConfigOptionName::Topic => "TOPIC",
+            KafkaConfigOptionName::TopicAllowlist => "TOPIC ALLOWLIST",
+            KafkaConfigOptionName::TopicFromColumn => "TOPIC FROM COLUMN",
             KafkaConfigOptionName::TopicMetadataRefreshIntervalMs => {
                 "TOPIC METADATA REFRESH INTERVAL MS"
             }
@@ -847,6 +927,12 @@ impl AstDisplay for KafkaConfigOptionName {
             KafkaConfigOptionName::ReplicationFactor => "REPLICATION FACTOR",
             KafkaConfigOptionName::RetentionBytes => "RETENTION BYTES",
             KafkaConfigOptionName::RetentionMs => "RETENTION MS",
+            KafkaConfigOptionName::ProgressTopic => "PROGRESS TOPIC",
+            KafkaConfigOptionName::ProgressTopicReplicationFactor => {
+                "PROGRESS TOPIC REPLICATION FACTOR"
+            }
+            KafkaConfigOptionName::TransactionalIdPrefix => "TRANSACTIONAL ID PREFIX",
+            KafkaConfigOptionName::TransactionTimeoutMs => "TRANSACTION TIMEOUT MS",
         })
     }
 }
@@ -1058,24 +1144,330 @@ pub enum CreateSinkConnection<T: AstInfo> {
     Kafka {
         connection: KafkaConnection<T>,
         key: Option<KafkaSinkKey>,
+        headers: Option<KafkaSinkHeaders>,
+    },
+    Postgres {
+        /// The postgres connection.
+        connection: T::ItemName,
+        options: Vec<PgSinkConfigOption<T>>,
+        key: Option<KafkaSinkKey>,
+    },
+    S3 {
+        /// The AWS connection.
+        connection: T::ItemName,
+        options: Vec<S3SinkConfigOption<T>>,
+    },
+    Http {
+        options: Vec<HttpSinkConfigOption<T>>,
+        key: Option<KafkaSinkKey>,
+    },
+    Redis {
+        options: Vec<RedisSinkConfigOption<T>>,
+        key: Option<KafkaSinkKey>,
+    },
+    Iceberg {
+        /// The AWS connection.
+        connection: T::ItemName,
+        options: Vec<IcebergSinkConfigOption<T>>,
     },
 }
 
 impl<T: AstInfo> AstDisplay for CreateSinkConnection<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
-            CreateSinkConnection::Kafka { connection, key } => {
+            CreateSinkConnection::Kafka {
+                connection,
+                key,
+                headers,
+            } => {
                 f.write_str("KAFKA ");
                 f.write_node(connection);
                 if let Some(key) = key.as_ref() {
                     f.write_node(key);
                 }
+                if let Some(headers) = headers.as_ref() {
+                    f.write_node(headers);
+                }
+            }
+            CreateSinkConnection::Postgres {
+                connection,
+                options,
+                key,
+            } => {
+                f.write_str("POSTGRES CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+                if let Some(key) = key.as_ref() {
+                    f.write_node(key);
+                }
+            }
+            CreateSinkConnection::S3 {
+                connection,
+                options,
+            } => {
+                f.write_str("S3 CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+            }
+            CreateSinkConnection::Http { options, key } => {
+                f.write_str("HTTP (");
+                f.write_node(&display::comma_separated(options));
+                f.write_str(")");
+                if let Some(key) = key.as_ref() {
+                    f.write_node(key);
+                }
+            }
+            CreateSinkConnection::Redis { options, key } => {
+                f.write_str("REDIS (");
+                f.write_node(&display::comma_separated(options));
+                f.write_str(")");
+                if let Some(key) = key.as_ref() {
+                    f.write_node(key);
+                }
+            }
+            CreateSinkConnection::Iceberg {
+                connection,
+                options,
+            } => {
+                f.write_str("ICEBERG CONNECTION ");
+                f.write_node(connection);
+                f.write_str(" (");
+                f.write_node(&display::comma_separated(options));
+                f.write_str(")");
             }
         }
     }
 }
 impl_display_t!(CreateSinkConnection);
 
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PgSinkConfigOptionName {
+    /// The upstream table to write to, as `'[schema.]table'`
+    Table,
+    /// Whether to create the upstream table if it does not exist
+    CreateTable,
+    /// How to apply updates to the upstream table
+    WriteMode,
+}
+
+impl AstDisplay for PgSinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            PgSinkConfigOptionName::Table => "TABLE",
+            PgSinkConfigOptionName::CreateTable => "CREATE TABLE",
+            PgSinkConfigOptionName::WriteMode => "WRITE MODE",
+        })
+    }
+}
+impl_display!(PgSinkConfigOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in a `CREATE SINK ... INTO POSTGRES CONNECTION ...` statement.
+pub struct PgSinkConfigOption<T: AstInfo> {
+    pub name: PgSinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for PgSinkConfigOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(PgSinkConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum S3SinkConfigOptionName {
+    /// The bucket to write to
+    Bucket,
+    /// The key prefix under which to write files
+    Prefix,
+    /// The size, in bytes, at which to start a new file
+    MaxFileSize,
+    /// The longest time a file may remain open before starting a new one
+    RotationInterval,
+}
+
+impl AstDisplay for S3SinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            S3SinkConfigOptionName::Bucket => "BUCKET",
+            S3SinkConfigOptionName::Prefix => "PREFIX",
+            S3SinkConfigOptionName::MaxFileSize => "MAX FILE SIZE",
+            S3SinkConfigOptionName::RotationInterval => "ROTATION INTERVAL",
+        })
+    }
+}
+impl_display!(S3SinkConfigOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in a `CREATE SINK ... INTO S3 CONNECTION ...` statement.
+pub struct S3SinkConfigOption<T: AstInfo> {
+    pub name: S3SinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for S3SinkConfigOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(S3SinkConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HttpSinkConfigOptionName {
+    /// The endpoint to which to POST batches
+    Url,
+    /// A header to send with each request
+    Header(String),
+    /// The maximum number of changes in each request
+    BatchSize,
+    /// The number of times to retry a failed request
+    MaxRetries,
+    /// The time to wait before the first retry of a failed request
+    RetryBackoff,
+}
+
+impl AstDisplay for HttpSinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            HttpSinkConfigOptionName::Url => f.write_str("URL"),
+            HttpSinkConfigOptionName::Header(name) => {
+                f.write_str("HEADER '");
+                f.write_node(&display::escape_single_quote_string(name));
+                f.write_str("'");
+            }
+            HttpSinkConfigOptionName::BatchSize => f.write_str("BATCH SIZE"),
+            HttpSinkConfigOptionName::MaxRetries => f.write_str("MAX RETRIES"),
+            HttpSinkConfigOptionName::RetryBackoff => f.write_str("RETRY BACKOFF"),
+        }
+    }
+}
+impl_display!(HttpSinkConfigOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in a `CREATE SINK ... INTO HTTP ...` statement.
+pub struct HttpSinkConfigOption<T: AstInfo> {
+    pub name: HttpSinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for HttpSinkConfigOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(HttpSinkConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RedisSinkConfigOptionName {
+    /// The `redis://` or `rediss://` URL of the server
+    Url,
+    /// The password with which to authenticate
+    Password,
+    /// The prefix of the names of all keys the sink maintains
+    KeyPrefix,
+    /// Whether to store each row as a string or a hash
+    DataStructure,
+}
+
+impl AstDisplay for RedisSinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            RedisSinkConfigOptionName::Url => "URL",
+            RedisSinkConfigOptionName::Password => "PASSWORD",
+            RedisSinkConfigOptionName::KeyPrefix => "KEY PREFIX",
+            RedisSinkConfigOptionName::DataStructure => "DATA STRUCTURE",
+        })
+    }
+}
+impl_display!(RedisSinkConfigOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in a `CREATE SINK ... INTO REDIS ...` statement.
+pub struct RedisSinkConfigOption<T: AstInfo> {
+    pub name: RedisSinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for RedisSinkConfigOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(RedisSinkConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IcebergSinkConfigOptionName {
+    /// The kind of catalog that tracks the table, `glue` or `rest`
+    Catalog,
+    /// The URL of a REST catalog
+    CatalogUrl,
+    /// The bearer token with which to authenticate to a REST catalog
+    CatalogToken,
+    /// The namespace of the table
+    Namespace,
+    /// The name of the table
+    Table,
+    /// How often to commit new data to the table
+    CommitInterval,
+}
+
+impl AstDisplay for IcebergSinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            IcebergSinkConfigOptionName::Catalog => "CATALOG",
+            IcebergSinkConfigOptionName::CatalogUrl => "CATALOG URL",
+            IcebergSinkConfigOptionName::CatalogToken => "CATALOG TOKEN",
+            IcebergSinkConfigOptionName::Namespace => "NAMESPACE",
+            IcebergSinkConfigOptionName::Table => "TABLE",
+            IcebergSinkConfigOptionName::CommitInterval => "COMMIT INTERVAL",
+        })
+    }
+}
+impl_display!(IcebergSinkConfigOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in a `CREATE SINK ... INTO ICEBERG CONNECTION ...` statement.
+pub struct IcebergSinkConfigOption<T: AstInfo> {
+    pub name: IcebergSinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for IcebergSinkConfigOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(IcebergSinkConfigOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct KafkaSinkKey {
     pub key_columns: Vec<Ident>,
@@ -1093,6 +1485,23 @@ impl AstDisplay for KafkaSinkKey {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KafkaSinkHeaders {
+    pub header_columns: Vec<Ident>,
+    pub exclude_from_value: bool,
+}
+
+impl AstDisplay for KafkaSinkHeaders {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(" HEADERS FROM (");
+        f.write_node(&display::comma_separated(&self.header_columns));
+        f.write_str(")");
+        if self.exclude_from_value {
+            f.write_str(" EXCLUDE FROM VALUE");
+        }
+    }
+}
+
 /// A table-level constraint, specified in a `CREATE TABLE` or an
 /// `ALTER TABLE ADD <constraint>` statement.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
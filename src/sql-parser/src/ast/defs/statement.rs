@@ -58,14 +58,18 @@ pub enum Statement<T: AstInfo> {
     CreateRole(CreateRoleStatement),
     CreateCluster(CreateClusterStatement<T>),
     CreateClusterReplica(CreateClusterReplicaStatement<T>),
+    CreateClusterReplicaSize(CreateClusterReplicaSizeStatement<T>),
     CreateSecret(CreateSecretStatement<T>),
+    CreateServiceAccount(CreateServiceAccountStatement<T>),
     AlterCluster(AlterClusterStatement<T>),
+    AlterClusterReplica(AlterClusterReplicaStatement<T>),
     AlterOwner(AlterOwnerStatement<T>),
     AlterObjectRename(AlterObjectRenameStatement),
     AlterObjectSwap(AlterObjectSwapStatement),
     AlterIndex(AlterIndexStatement<T>),
     AlterSecret(AlterSecretStatement<T>),
     AlterSetCluster(AlterSetClusterStatement<T>),
+    AlterColumnMask(AlterColumnMaskStatement<T>),
     AlterSink(AlterSinkStatement<T>),
     AlterSource(AlterSourceStatement<T>),
     AlterSystemSet(AlterSystemSetStatement),
@@ -76,6 +80,9 @@ pub enum Statement<T: AstInfo> {
     Discard(DiscardStatement),
     DropObjects(DropObjectsStatement),
     DropOwned(DropOwnedStatement<T>),
+    DropClusterReplicaSize(DropClusterReplicaSizeStatement),
+    DropServiceAccount(DropServiceAccountStatement),
+    UndropObject(UndropObjectStatement),
     SetVariable(SetVariableStatement),
     ResetVariable(ResetVariableStatement),
     Show(ShowStatement<T>),
@@ -87,6 +94,7 @@ pub enum Statement<T: AstInfo> {
     ExplainPlan(ExplainPlanStatement<T>),
     ExplainTimestamp(ExplainTimestampStatement<T>),
     ExplainSinkSchema(ExplainSinkSchemaStatement<T>),
+    ExplainCreateSource(ExplainCreateSourceStatement<T>),
     Declare(DeclareStatement<T>),
     Fetch(FetchStatement<T>),
     Close(CloseStatement),
@@ -125,15 +133,19 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::CreateIndex(stmt) => f.write_node(stmt),
             Statement::CreateRole(stmt) => f.write_node(stmt),
             Statement::CreateSecret(stmt) => f.write_node(stmt),
+            Statement::CreateServiceAccount(stmt) => f.write_node(stmt),
             Statement::CreateType(stmt) => f.write_node(stmt),
             Statement::CreateCluster(stmt) => f.write_node(stmt),
             Statement::CreateClusterReplica(stmt) => f.write_node(stmt),
+            Statement::CreateClusterReplicaSize(stmt) => f.write_node(stmt),
             Statement::AlterCluster(stmt) => f.write_node(stmt),
+            Statement::AlterClusterReplica(stmt) => f.write_node(stmt),
             Statement::AlterOwner(stmt) => f.write_node(stmt),
             Statement::AlterObjectRename(stmt) => f.write_node(stmt),
             Statement::AlterObjectSwap(stmt) => f.write_node(stmt),
             Statement::AlterIndex(stmt) => f.write_node(stmt),
             Statement::AlterSetCluster(stmt) => f.write_node(stmt),
+            Statement::AlterColumnMask(stmt) => f.write_node(stmt),
             Statement::AlterSecret(stmt) => f.write_node(stmt),
             Statement::AlterSink(stmt) => f.write_node(stmt),
             Statement::AlterSource(stmt) => f.write_node(stmt),
@@ -145,6 +157,9 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Discard(stmt) => f.write_node(stmt),
             Statement::DropObjects(stmt) => f.write_node(stmt),
             Statement::DropOwned(stmt) => f.write_node(stmt),
+            Statement::DropClusterReplicaSize(stmt) => f.write_node(stmt),
+            Statement::DropServiceAccount(stmt) => f.write_node(stmt),
+            Statement::UndropObject(stmt) => f.write_node(stmt),
             Statement::SetVariable(stmt) => f.write_node(stmt),
             Statement::ResetVariable(stmt) => f.write_node(stmt),
             Statement::Show(stmt) => f.write_node(stmt),
@@ -156,6 +171,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::ExplainPlan(stmt) => f.write_node(stmt),
             Statement::ExplainTimestamp(stmt) => f.write_node(stmt),
             Statement::ExplainSinkSchema(stmt) => f.write_node(stmt),
+            Statement::ExplainCreateSource(stmt) => f.write_node(stmt),
             Statement::Declare(stmt) => f.write_node(stmt),
             Statement::Close(stmt) => f.write_node(stmt),
             Statement::Fetch(stmt) => f.write_node(stmt),
@@ -199,14 +215,18 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::CreateRole => "create_role",
         StatementKind::CreateCluster => "create_cluster",
         StatementKind::CreateClusterReplica => "create_cluster_replica",
+        StatementKind::CreateClusterReplicaSize => "create_cluster_replica_size",
         StatementKind::CreateSecret => "create_secret",
+        StatementKind::CreateServiceAccount => "create_service_account",
         StatementKind::AlterCluster => "alter_cluster",
+        StatementKind::AlterClusterReplica => "alter_cluster_replica",
         StatementKind::AlterObjectRename => "alter_object_rename",
         StatementKind::AlterObjectSwap => "alter_object_swap",
         StatementKind::AlterIndex => "alter_index",
         StatementKind::AlterRole => "alter_role",
         StatementKind::AlterSecret => "alter_secret",
         StatementKind::AlterSetCluster => "alter_set_cluster",
+        StatementKind::AlterColumnMask => "alter_column_mask",
         StatementKind::AlterSink => "alter_sink",
         StatementKind::AlterSource => "alter_source",
         StatementKind::AlterSystemSet => "alter_system_set",
@@ -217,6 +237,9 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::Discard => "discard",
         StatementKind::DropObjects => "drop_objects",
         StatementKind::DropOwned => "drop_owned",
+        StatementKind::DropClusterReplicaSize => "drop_cluster_replica_size",
+        StatementKind::DropServiceAccount => "drop_service_account",
+        StatementKind::UndropObject => "undrop_object",
         StatementKind::SetVariable => "set_variable",
         StatementKind::ResetVariable => "reset_variable",
         StatementKind::Show => "show",
@@ -228,6 +251,7 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::ExplainPlan => "explain_plan",
         StatementKind::ExplainTimestamp => "explain_timestamp",
         StatementKind::ExplainSinkSchema => "explain_sink_schema",
+        StatementKind::ExplainCreateSource => "explain_create_source",
         StatementKind::Declare => "declare",
         StatementKind::Fetch => "fetch",
         StatementKind::Close => "close",
@@ -925,6 +949,9 @@ pub struct CreateSourceStatement<T: AstInfo> {
     pub with_options: Vec<CreateSourceOption<T>>,
     pub referenced_subsources: Option<ReferencedSubsources<T>>,
     pub progress_subsource: Option<DeferredItemName<T>>,
+    /// Whether purification should run as a background job rather than
+    /// blocking the session.
+    pub in_background: bool,
 }
 
 impl<T: AstInfo> AstDisplay for CreateSourceStatement<T> {
@@ -979,6 +1006,10 @@ impl<T: AstInfo> AstDisplay for CreateSourceStatement<T> {
             f.write_node(&display::comma_separated(&self.with_options));
             f.write_str(")");
         }
+
+        if self.in_background {
+            f.write_str(" IN BACKGROUND");
+        }
     }
 }
 impl_display_t!(CreateSourceStatement);
@@ -1317,6 +1348,67 @@ impl<T: AstInfo> AstDisplay for AlterSetClusterStatement<T> {
 }
 impl_display_t!(AlterSetClusterStatement);
 
+/// `ALTER ... ALTER COLUMN ... { SET | DROP } MASK ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterColumnMaskStatement<T: AstInfo> {
+    pub object_type: ObjectType,
+    pub if_exists: bool,
+    pub name: UnresolvedItemName,
+    pub column: Ident,
+    pub action: AlterColumnMaskAction<T>,
+}
+
+impl<T: AstInfo> AstDisplay for AlterColumnMaskStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER ");
+        f.write_node(&self.object_type);
+
+        if self.if_exists {
+            f.write_str(" IF EXISTS");
+        }
+
+        f.write_str(" ");
+        f.write_node(&self.name);
+        f.write_str(" ALTER COLUMN ");
+        f.write_node(&self.column);
+        f.write_str(" ");
+        f.write_node(&self.action);
+    }
+}
+impl_display_t!(AlterColumnMaskStatement);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AlterColumnMaskAction<T: AstInfo> {
+    /// `SET MASK <expr> FOR ROLE <roles>`
+    Set {
+        expr: Expr<T>,
+        roles: Vec<T::RoleName>,
+    },
+    /// `DROP MASK [FOR ROLE <roles>]`, where no roles means all roles.
+    Drop { roles: Vec<T::RoleName> },
+}
+
+impl<T: AstInfo> AstDisplay for AlterColumnMaskAction<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            AlterColumnMaskAction::Set { expr, roles } => {
+                f.write_str("SET MASK ");
+                f.write_node(expr);
+                f.write_str(" FOR ROLE ");
+                f.write_node(&display::comma_separated(roles));
+            }
+            AlterColumnMaskAction::Drop { roles } => {
+                f.write_str("DROP MASK");
+                if !roles.is_empty() {
+                    f.write_str(" FOR ROLE ");
+                    f.write_node(&display::comma_separated(roles));
+                }
+            }
+        }
+    }
+}
+impl_display_t!(AlterColumnMaskAction);
+
 /// `CREATE TABLE`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateTableStatement<T: AstInfo> {
@@ -1456,6 +1548,50 @@ impl AstDisplay for CreateRoleStatement {
 }
 impl_display!(CreateRoleStatement);
 
+/// `CREATE SERVICE ACCOUNT ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateServiceAccountStatement<T: AstInfo> {
+    /// The name of the service account.
+    pub name: Ident,
+    /// The role that the service account acts as.
+    pub role: T::RoleName,
+    /// The secret that holds the service account's token.
+    pub token_secret: T::ItemName,
+    /// The scopes that the service account's token is limited to.
+    pub scopes: Vec<String>,
+    /// The `VALID UNTIL` option, if specified.
+    pub valid_until: Option<String>,
+}
+
+impl<T: AstInfo> AstDisplay for CreateServiceAccountStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE SERVICE ACCOUNT ");
+        f.write_node(&self.name);
+        f.write_str(" FOR ROLE ");
+        f.write_node(&self.role);
+        f.write_str(" TOKEN SECRET ");
+        f.write_node(&self.token_secret);
+        if !self.scopes.is_empty() {
+            f.write_str(" SCOPES (");
+            for (i, scope) in self.scopes.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ");
+                }
+                f.write_str("'");
+                f.write_node(&display::escape_single_quote_string(scope));
+                f.write_str("'");
+            }
+            f.write_str(")");
+        }
+        if let Some(valid_until) = &self.valid_until {
+            f.write_str(" VALID UNTIL '");
+            f.write_node(&display::escape_single_quote_string(valid_until));
+            f.write_str("'");
+        }
+    }
+}
+impl_display_t!(CreateServiceAccountStatement);
+
 /// Attributes that can be attached to roles.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum RoleAttribute {
@@ -1463,6 +1599,14 @@ pub enum RoleAttribute {
     Inherit,
     /// The `NOINHERIT` option.
     NoInherit,
+    /// The `PASSWORD` option, where `None` is `PASSWORD NULL`.
+    Password(Option<String>),
+    /// The `CONNECTION LIMIT` option.
+    ConnectionLimit(i64),
+    /// The `STATEMENT TIMEOUT` option, where `None` is `STATEMENT TIMEOUT NULL`.
+    StatementTimeout(Option<String>),
+    /// The `VALID UNTIL` option, where `None` is `VALID UNTIL NULL`.
+    ValidUntil(Option<String>),
     // The following are not supported, but included to give helpful error messages.
     Login,
     NoLogin,
@@ -1491,6 +1635,33 @@ impl AstDisplay for RoleAttribute {
             RoleAttribute::NoCreateDB => f.write_str("NOCREATEDB"),
             RoleAttribute::CreateRole => f.write_str("CREATEROLE"),
             RoleAttribute::NoCreateRole => f.write_str("NOCREATEROLE"),
+            RoleAttribute::Password(None) => f.write_str("PASSWORD NULL"),
+            RoleAttribute::Password(Some(password)) => {
+                f.write_str("PASSWORD ");
+                if f.redacted() {
+                    f.write_str("'<REDACTED>'");
+                } else {
+                    f.write_str("'");
+                    f.write_node(&display::escape_single_quote_string(password));
+                    f.write_str("'");
+                }
+            }
+            RoleAttribute::ConnectionLimit(limit) => {
+                f.write_str("CONNECTION LIMIT ");
+                f.write_str(limit);
+            }
+            RoleAttribute::StatementTimeout(None) => f.write_str("STATEMENT TIMEOUT NULL"),
+            RoleAttribute::StatementTimeout(Some(timeout)) => {
+                f.write_str("STATEMENT TIMEOUT '");
+                f.write_node(&display::escape_single_quote_string(timeout));
+                f.write_str("'");
+            }
+            RoleAttribute::ValidUntil(None) => f.write_str("VALID UNTIL NULL"),
+            RoleAttribute::ValidUntil(Some(valid_until)) => {
+                f.write_str("VALID UNTIL '");
+                f.write_node(&display::escape_single_quote_string(valid_until));
+                f.write_str("'");
+            }
         }
     }
 }
@@ -1601,10 +1772,16 @@ pub enum ClusterOptionName {
     IdleArrangementMergeEffort,
     /// The `MANAGED` option.
     Managed,
+    /// The `MEMORY QUOTA [=] <value>` option.
+    MemoryQuota,
+    /// The `PRIORITY [=] <value>` option.
+    Priority,
     /// The `REPLICAS` option.
     Replicas,
     /// The `REPLICATION FACTOR` option.
     ReplicationFactor,
+    /// The `SCHEDULE` option.
+    Schedule,
     /// The `SIZE` option.
     Size,
 }
@@ -1620,8 +1797,11 @@ impl AstDisplay for ClusterOptionName {
             ClusterOptionName::IntrospectionDebugging => f.write_str("INTROSPECTION DEBUGGING"),
             ClusterOptionName::IntrospectionInterval => f.write_str("INTROSPECTION INTERVAL"),
             ClusterOptionName::Managed => f.write_str("MANAGED"),
+            ClusterOptionName::MemoryQuota => f.write_str("MEMORY QUOTA"),
+            ClusterOptionName::Priority => f.write_str("PRIORITY"),
             ClusterOptionName::Replicas => f.write_str("REPLICAS"),
             ClusterOptionName::ReplicationFactor => f.write_str("REPLICATION FACTOR"),
+            ClusterOptionName::Schedule => f.write_str("SCHEDULE"),
             ClusterOptionName::Size => f.write_str("SIZE"),
         }
     }
@@ -1651,12 +1831,18 @@ pub struct CreateClusterStatement<T: AstInfo> {
     pub name: Ident,
     /// The comma-separated options.
     pub options: Vec<ClusterOption<T>>,
+    /// The `LIKE` clause, if the cluster clones another cluster.
+    pub like: Option<CreateClusterLike>,
 }
 
 impl<T: AstInfo> AstDisplay for CreateClusterStatement<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         f.write_str("CREATE CLUSTER ");
         f.write_node(&self.name);
+        if let Some(like) = &self.like {
+            f.write_str(" ");
+            f.write_node(like);
+        }
         if !self.options.is_empty() {
             f.write_str(" (");
             f.write_node(&display::comma_separated(&self.options));
@@ -1666,6 +1852,44 @@ impl<T: AstInfo> AstDisplay for CreateClusterStatement<T> {
 }
 impl_display_t!(CreateClusterStatement);
 
+/// `LIKE <cluster> [INCLUDING <objects> ...]`, as used in `CREATE CLUSTER`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateClusterLike {
+    /// Name of the cloned cluster.
+    pub cluster: Ident,
+    /// The objects of the cloned cluster to clone along with its configuration.
+    pub including: Vec<CreateClusterLikeObjects>,
+}
+
+impl AstDisplay for CreateClusterLike {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("LIKE ");
+        f.write_node(&self.cluster);
+        for objects in &self.including {
+            f.write_str(" INCLUDING ");
+            f.write_node(objects);
+        }
+    }
+}
+impl_display!(CreateClusterLike);
+
+/// The kinds of objects that `CREATE CLUSTER ... LIKE` can clone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CreateClusterLikeObjects {
+    Indexes,
+    MaterializedViews,
+}
+
+impl AstDisplay for CreateClusterLikeObjects {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            CreateClusterLikeObjects::Indexes => f.write_str("INDEXES"),
+            CreateClusterLikeObjects::MaterializedViews => f.write_str("MATERIALIZED VIEWS"),
+        }
+    }
+}
+impl_display!(CreateClusterLikeObjects);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ReplicaDefinition<T: AstInfo> {
     /// Name of the created replica.
@@ -1686,6 +1910,26 @@ impl<T: AstInfo> AstDisplay for ReplicaDefinition<T> {
 }
 impl_display_t!(ReplicaDefinition);
 
+/// An entry of the `SCHEDULE` option of a cluster.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ClusterScheduleDefinition<T: AstInfo> {
+    /// The time of day, in UTC, at which the entry takes effect.
+    pub at: String,
+    /// The options to apply to the cluster when the entry takes effect.
+    pub options: Vec<ClusterOption<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for ClusterScheduleDefinition<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("AT '");
+        f.write_node(&display::escape_single_quote_string(&self.at));
+        f.write_str("' (");
+        f.write_node(&display::comma_separated(&self.options));
+        f.write_str(")");
+    }
+}
+impl_display_t!(ClusterScheduleDefinition);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AlterClusterAction<T: AstInfo> {
     SetOptions(Vec<ClusterOption<T>>),
@@ -1727,6 +1971,78 @@ impl<T: AstInfo> AstDisplay for AlterClusterStatement<T> {
 }
 impl_display_t!(AlterClusterStatement);
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AlterClusterReplicaOptionName {
+    /// The `DRAINING [[=] <enabled>]` option.
+    Draining,
+}
+
+impl AstDisplay for AlterClusterReplicaOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            AlterClusterReplicaOptionName::Draining => f.write_str("DRAINING"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// An option in an `ALTER CLUSTER REPLICA` statement.
+pub struct AlterClusterReplicaOption<T: AstInfo> {
+    pub name: AlterClusterReplicaOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for AlterClusterReplicaOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AlterClusterReplicaAction<T: AstInfo> {
+    SetOptions(Vec<AlterClusterReplicaOption<T>>),
+    ResetOptions(Vec<AlterClusterReplicaOptionName>),
+}
+
+/// `ALTER CLUSTER REPLICA .. SET ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterClusterReplicaStatement<T: AstInfo> {
+    /// The `IF EXISTS` option.
+    pub if_exists: bool,
+    /// Name of the altered replica.
+    pub name: QualifiedReplica,
+    /// The action.
+    pub action: AlterClusterReplicaAction<T>,
+}
+
+impl<T: AstInfo> AstDisplay for AlterClusterReplicaStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER CLUSTER REPLICA ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" ");
+        match &self.action {
+            AlterClusterReplicaAction::SetOptions(options) => {
+                f.write_str("SET (");
+                f.write_node(&display::comma_separated(options));
+                f.write_str(")");
+            }
+            AlterClusterReplicaAction::ResetOptions(options) => {
+                f.write_str("RESET (");
+                f.write_node(&display::comma_separated(options));
+                f.write_str(")");
+            }
+        }
+    }
+}
+impl_display_t!(AlterClusterReplicaStatement);
+
 /// `CREATE CLUSTER REPLICA ..`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateClusterReplicaStatement<T: AstInfo> {
@@ -1757,6 +2073,10 @@ pub enum ReplicaOptionName {
     Size,
     /// The `AVAILABILITY ZONE [[=] <id>]` option.
     AvailabilityZone,
+    /// The `AVAILABILITY ZONE AFFINITY [=] (<replica>, ...)` option.
+    AvailabilityZoneAffinity,
+    /// The `AVAILABILITY ZONE ANTI AFFINITY [=] (<replica>, ...)` option.
+    AvailabilityZoneAntiAffinity,
     /// The `STORAGE ADDRESSES` option.
     StorageAddresses,
     /// The `STORAGECTL ADDRESSES` option.
@@ -1785,6 +2105,12 @@ impl AstDisplay for ReplicaOptionName {
             ReplicaOptionName::BilledAs => f.write_str("BILLED AS"),
             ReplicaOptionName::Size => f.write_str("SIZE"),
             ReplicaOptionName::AvailabilityZone => f.write_str("AVAILABILITY ZONE"),
+            ReplicaOptionName::AvailabilityZoneAffinity => {
+                f.write_str("AVAILABILITY ZONE AFFINITY")
+            }
+            ReplicaOptionName::AvailabilityZoneAntiAffinity => {
+                f.write_str("AVAILABILITY ZONE ANTI AFFINITY")
+            }
             ReplicaOptionName::StorageAddresses => f.write_str("STORAGE ADDRESSES"),
             ReplicaOptionName::StoragectlAddresses => f.write_str("STORAGECTL ADDRESSES"),
             ReplicaOptionName::ComputectlAddresses => f.write_str("COMPUTECTL ADDRESSES"),
@@ -1818,6 +2144,75 @@ impl<T: AstInfo> AstDisplay for ReplicaOption<T> {
     }
 }
 
+/// `CREATE CLUSTER REPLICA SIZE ..`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateClusterReplicaSizeStatement<T: AstInfo> {
+    /// The name of the size.
+    pub name: String,
+    /// The comma-separated options.
+    pub options: Vec<ClusterReplicaSizeOption<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for CreateClusterReplicaSizeStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE CLUSTER REPLICA SIZE '");
+        f.write_node(&display::escape_single_quote_string(&self.name));
+        f.write_str("' (");
+        f.write_node(&display::comma_separated(&self.options));
+        f.write_str(")");
+    }
+}
+impl_display_t!(CreateClusterReplicaSizeStatement);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ClusterReplicaSizeOptionName {
+    /// The `CPU LIMIT [=] <value>` option.
+    CpuLimit,
+    /// The `CREDITS PER HOUR [=] <value>` option.
+    CreditsPerHour,
+    /// The `DISABLED [[=] <enabled>]` option.
+    Disabled,
+    /// The `DISK LIMIT [=] <value>` option.
+    DiskLimit,
+    /// The `MEMORY LIMIT [=] <value>` option.
+    MemoryLimit,
+    /// The `SCALE [=] <value>` option.
+    Scale,
+    /// The `WORKERS [=] <value>` option.
+    Workers,
+}
+
+impl AstDisplay for ClusterReplicaSizeOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            ClusterReplicaSizeOptionName::CpuLimit => f.write_str("CPU LIMIT"),
+            ClusterReplicaSizeOptionName::CreditsPerHour => f.write_str("CREDITS PER HOUR"),
+            ClusterReplicaSizeOptionName::Disabled => f.write_str("DISABLED"),
+            ClusterReplicaSizeOptionName::DiskLimit => f.write_str("DISK LIMIT"),
+            ClusterReplicaSizeOptionName::MemoryLimit => f.write_str("MEMORY LIMIT"),
+            ClusterReplicaSizeOptionName::Scale => f.write_str("SCALE"),
+            ClusterReplicaSizeOptionName::Workers => f.write_str("WORKERS"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// An option in a `CREATE CLUSTER REPLICA SIZE` statement.
+pub struct ClusterReplicaSizeOption<T: AstInfo> {
+    pub name: ClusterReplicaSizeOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for ClusterReplicaSizeOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+
 /// `CREATE TYPE .. AS <TYPE>`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CreateTypeAs<T: AstInfo> {
@@ -2017,6 +2412,7 @@ impl_display_t!(AlterIndexStatement);
 pub enum AlterSinkAction<T: AstInfo> {
     SetOptions(Vec<CreateSinkOption<T>>),
     ResetOptions(Vec<CreateSinkOptionName>),
+    ChangeRelation(T::ItemName),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -2046,6 +2442,10 @@ impl<T: AstInfo> AstDisplay for AlterSinkStatement<T> {
                 f.write_node(&display::comma_separated(options));
                 f.write_str(")");
             }
+            AlterSinkAction::ChangeRelation(from) => {
+                f.write_str("SET FROM ");
+                f.write_node(from);
+            }
         }
     }
 }
@@ -2188,6 +2588,7 @@ impl_display_t!(AlterSecretStatement);
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AlterConnectionAction<T: AstInfo> {
     RotateKeys,
+    RotateSecrets(Vec<ConnectionOption<T>>),
     SetOption(ConnectionOption<T>),
     DropOption(ConnectionOptionName),
 }
@@ -2196,6 +2597,11 @@ impl<T: AstInfo> AstDisplay for AlterConnectionAction<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
             AlterConnectionAction::RotateKeys => f.write_str("ROTATE KEYS"),
+            AlterConnectionAction::RotateSecrets(options) => {
+                f.write_str("ROTATE SECRETS (");
+                f.write_node(&display::comma_separated(options));
+                f.write_str(")");
+            }
             AlterConnectionAction::SetOption(option) => {
                 f.write_str("SET (");
                 f.write_node(option);
@@ -2382,6 +2788,67 @@ impl AstDisplay for DropObjectsStatement {
 }
 impl_display!(DropObjectsStatement);
 
+/// `UNDROP`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UndropObjectStatement {
+    /// The type of the object to restore: VIEW, etc.
+    pub object_type: ObjectType,
+    /// The name that the object had when it was dropped.
+    pub name: UnresolvedItemName,
+}
+
+impl AstDisplay for UndropObjectStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("UNDROP ");
+        f.write_node(&self.object_type);
+        f.write_str(" ");
+        f.write_node(&self.name);
+    }
+}
+impl_display!(UndropObjectStatement);
+
+/// `DROP CLUSTER REPLICA SIZE ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DropClusterReplicaSizeStatement {
+    /// An optional `IF EXISTS` clause.
+    pub if_exists: bool,
+    /// The name of the size.
+    pub name: String,
+}
+
+impl AstDisplay for DropClusterReplicaSizeStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("DROP CLUSTER REPLICA SIZE ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_str("'");
+        f.write_node(&display::escape_single_quote_string(&self.name));
+        f.write_str("'");
+    }
+}
+impl_display!(DropClusterReplicaSizeStatement);
+
+/// `DROP SERVICE ACCOUNT ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DropServiceAccountStatement {
+    /// An optional `IF EXISTS` clause.
+    pub if_exists: bool,
+    /// The name of the service account.
+    pub name: Ident,
+}
+
+impl AstDisplay for DropServiceAccountStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("DROP SERVICE ACCOUNT ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+    }
+}
+impl_display!(DropServiceAccountStatement);
+
 /// `DROP OWNED BY ...`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DropOwnedStatement<T: AstInfo> {
@@ -2532,6 +2999,12 @@ pub enum ShowObjectType<T: AstInfo> {
     RoleMembership {
         role: Option<T::RoleName>,
     },
+    EffectivePrivileges {
+        role: T::RoleName,
+        object_type: SystemObjectType,
+        /// `None` if and only if `object_type` is [`SystemObjectType::System`].
+        object_name: Option<T::ObjectName>,
+    },
 }
 /// `SHOW <object>S`
 ///
@@ -2573,6 +3046,7 @@ impl<T: AstInfo> AstDisplay for ShowObjectsStatement<T> {
             ShowObjectType::Privileges { .. } => "PRIVILEGES",
             ShowObjectType::DefaultPrivileges { .. } => "DEFAULT PRIVILEGES",
             ShowObjectType::RoleMembership { .. } => "ROLE MEMBERSHIP",
+            ShowObjectType::EffectivePrivileges { .. } => "EFFECTIVE PRIVILEGES",
         });
 
         if let ShowObjectType::Index { on_object, .. } = &self.object_type {
@@ -2647,6 +3121,22 @@ impl<T: AstInfo> AstDisplay for ShowObjectsStatement<T> {
             f.write_node(role);
         }
 
+        if let ShowObjectType::EffectivePrivileges {
+            role,
+            object_type,
+            object_name,
+        } = &self.object_type
+        {
+            f.write_str(" FOR ");
+            f.write_node(role);
+            f.write_str(" ON ");
+            f.write_node(object_type);
+            if let Some(object_name) = object_name {
+                f.write_str(" ");
+                f.write_node(object_name);
+            }
+        }
+
         if let Some(filter) = &self.filter {
             f.write_str(" ");
             f.write_node(filter);
@@ -2978,6 +3468,20 @@ impl<T: AstInfo> AstDisplay for ExplainSinkSchemaStatement<T> {
 }
 impl_display_t!(ExplainSinkSchemaStatement);
 
+/// `EXPLAIN CREATE SOURCE ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExplainCreateSourceStatement<T: AstInfo> {
+    pub statement: CreateSourceStatement<T>,
+}
+
+impl<T: AstInfo> AstDisplay for ExplainCreateSourceStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("EXPLAIN ");
+        f.write_node(&self.statement);
+    }
+}
+impl_display_t!(ExplainCreateSourceStatement);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ExplainTimestampStatement<T: AstInfo> {
     pub format: ExplainFormat,
@@ -3128,6 +3632,7 @@ pub enum WithOptionValue<T: AstInfo> {
     Sequence(Vec<WithOptionValue<T>>),
     // Special cases.
     ClusterReplicas(Vec<ReplicaDefinition<T>>),
+    ClusterSchedule(Vec<ClusterScheduleDefinition<T>>),
     ConnectionKafkaBroker(KafkaBroker<T>),
 }
 
@@ -3143,7 +3648,8 @@ impl<T: AstInfo> AstDisplay for WithOptionValue<T> {
                 WithOptionValue::DataType(_)
                 | WithOptionValue::Item(_)
                 | WithOptionValue::UnresolvedItemName(_)
-                | WithOptionValue::ClusterReplicas(_) => {
+                | WithOptionValue::ClusterReplicas(_)
+                | WithOptionValue::ClusterSchedule(_) => {
                     // These do not need redaction.
                 }
                 WithOptionValue::Secret(_)
@@ -3176,6 +3682,11 @@ impl<T: AstInfo> AstDisplay for WithOptionValue<T> {
                 f.write_node(&display::comma_separated(replicas));
                 f.write_str(")");
             }
+            WithOptionValue::ClusterSchedule(entries) => {
+                f.write_str("(");
+                f.write_node(&display::comma_separated(entries));
+                f.write_str(")");
+            }
             WithOptionValue::ConnectionKafkaBroker(broker) => {
                 f.write_node(broker);
             }
@@ -380,6 +380,9 @@ impl<'a> Parser<'a> {
                     .parse_discard()
                     .map_parser_err(StatementKind::Discard)?),
                 Token::Keyword(DROP) => Ok(self.parse_drop()?),
+                Token::Keyword(UNDROP) => Ok(self
+                    .parse_undrop_object()
+                    .map_parser_err(StatementKind::UndropObject)?),
                 Token::Keyword(DELETE) => {
                     Ok(self.parse_delete().map_parser_err(StatementKind::Delete)?)
                 }
@@ -1825,7 +1828,12 @@ impl<'a> Parser<'a> {
                 .map_parser_err(StatementKind::CreateRole)
         } else if self.peek_keyword(CLUSTER) {
             self.next_token();
-            if self.peek_keyword(REPLICA) {
+            // `CLUSTER REPLICA SIZE` could also be the start of the name of a
+            // replica of a cluster named `size`, which is followed by a dot.
+            if self.peek_keywords(&[REPLICA, SIZE]) && self.peek_nth_token(2) != Some(Token::Dot) {
+                self.parse_create_cluster_replica_size()
+                    .map_parser_err(StatementKind::CreateClusterReplicaSize)
+            } else if self.peek_keyword(REPLICA) {
                 self.parse_create_cluster_replica()
                     .map_parser_err(StatementKind::CreateClusterReplica)
             } else {
@@ -1853,6 +1861,9 @@ impl<'a> Parser<'a> {
         } else if self.peek_keyword(CONNECTION) {
             self.parse_create_connection()
                 .map_parser_err(StatementKind::CreateConnection)
+        } else if self.peek_keywords(&[SERVICE, ACCOUNT]) {
+            self.parse_create_service_account()
+                .map_parser_err(StatementKind::CreateServiceAccount)
         } else if self.peek_keywords(&[MATERIALIZED, VIEW])
             || self.peek_keywords(&[OR, REPLACE, MATERIALIZED, VIEW])
         {
@@ -1914,6 +1925,11 @@ impl<'a> Parser<'a> {
         } else if self.parse_keyword(REGEX) {
             let regex = self.parse_literal_string()?;
             Format::Regex(regex)
+        } else if self.parse_keyword(GROK) {
+            let pattern = self.parse_literal_string()?;
+            Format::Grok(pattern)
+        } else if self.parse_keyword(PARQUET) {
+            Format::Parquet
         } else if self.parse_keyword(CSV) {
             self.expect_keyword(WITH)?;
             let columns = if self.parse_keyword(HEADER) || self.parse_keyword(HEADERS) {
@@ -1934,23 +1950,37 @@ impl<'a> Parser<'a> {
             } else {
                 ','
             };
-            Format::Csv { columns, delimiter }
+            let encoding = self.parse_format_encoding()?;
+            Format::Csv {
+                columns,
+                delimiter,
+                encoding,
+            }
         } else if self.parse_keyword(JSON) {
             Format::Json
         } else if self.parse_keyword(TEXT) {
-            Format::Text
+            let encoding = self.parse_format_encoding()?;
+            Format::Text { encoding }
         } else if self.parse_keyword(BYTES) {
             Format::Bytes
         } else {
             return self.expected(
                 self.peek_pos(),
-                "AVRO, PROTOBUF, REGEX, CSV, JSON, TEXT, or BYTES",
+                "AVRO, PROTOBUF, REGEX, GROK, CSV, JSON, TEXT, PARQUET, or BYTES",
                 self.peek_token(),
             );
         };
         Ok(format)
     }
 
+    fn parse_format_encoding(&mut self) -> Result<Option<String>, ParserError> {
+        if self.parse_keyword(ENCODING) {
+            Ok(Some(self.parse_literal_string()?))
+        } else {
+            Ok(None)
+        }
+    }
+
     fn parse_avro_schema(&mut self) -> Result<AvroSchema<Raw>, ParserError> {
         let avro_schema = if self.parse_keywords(&[CONFLUENT, SCHEMA, REGISTRY]) {
             let csr_connection = self.parse_csr_connection_avro()?;
@@ -2033,46 +2063,55 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_csr_config_option(&mut self) -> Result<CsrConfigOption<Raw>, ParserError> {
-        let name = match self.expect_one_of_keywords(&[AVRO, NULL, KEY, VALUE, DOC])? {
-            AVRO => {
-                let name = match self.expect_one_of_keywords(&[KEY, VALUE])? {
-                    KEY => CsrConfigOptionName::AvroKeyFullname,
-                    VALUE => CsrConfigOptionName::AvroValueFullname,
-                    _ => unreachable!(),
-                };
-                self.expect_keyword(FULLNAME)?;
-                name
-            }
-            NULL => {
-                self.expect_keyword(DEFAULTS)?;
-                CsrConfigOptionName::NullDefaults
-            }
-            KEY => {
-                self.expect_keywords(&[DOC, ON])?;
-                let doc_on_identifier = self.parse_avro_doc_on_option_name()?;
-                CsrConfigOptionName::AvroDocOn(AvroDocOn {
-                    identifier: doc_on_identifier,
-                    for_schema: DocOnSchema::KeyOnly,
-                })
-            }
-            VALUE => {
-                self.expect_keywords(&[DOC, ON])?;
-                let doc_on_identifier = self.parse_avro_doc_on_option_name()?;
-                CsrConfigOptionName::AvroDocOn(AvroDocOn {
-                    identifier: doc_on_identifier,
-                    for_schema: DocOnSchema::ValueOnly,
-                })
-            }
-            DOC => {
-                self.expect_keyword(ON)?;
-                let doc_on_identifier = self.parse_avro_doc_on_option_name()?;
-                CsrConfigOptionName::AvroDocOn(AvroDocOn {
-                    identifier: doc_on_identifier,
-                    for_schema: DocOnSchema::All,
-                })
-            }
-            _ => unreachable!(),
-        };
+        let name =
+            match self.expect_one_of_keywords(&[AVRO, NULL, KEY, VALUE, DOC, INCLUDE, UNKNOWN])? {
+                AVRO => {
+                    let name = match self.expect_one_of_keywords(&[KEY, VALUE])? {
+                        KEY => CsrConfigOptionName::AvroKeyFullname,
+                        VALUE => CsrConfigOptionName::AvroValueFullname,
+                        _ => unreachable!(),
+                    };
+                    self.expect_keyword(FULLNAME)?;
+                    name
+                }
+                NULL => {
+                    self.expect_keyword(DEFAULTS)?;
+                    CsrConfigOptionName::NullDefaults
+                }
+                KEY => {
+                    self.expect_keywords(&[DOC, ON])?;
+                    let doc_on_identifier = self.parse_avro_doc_on_option_name()?;
+                    CsrConfigOptionName::AvroDocOn(AvroDocOn {
+                        identifier: doc_on_identifier,
+                        for_schema: DocOnSchema::KeyOnly,
+                    })
+                }
+                VALUE => {
+                    self.expect_keywords(&[DOC, ON])?;
+                    let doc_on_identifier = self.parse_avro_doc_on_option_name()?;
+                    CsrConfigOptionName::AvroDocOn(AvroDocOn {
+                        identifier: doc_on_identifier,
+                        for_schema: DocOnSchema::ValueOnly,
+                    })
+                }
+                DOC => {
+                    self.expect_keyword(ON)?;
+                    let doc_on_identifier = self.parse_avro_doc_on_option_name()?;
+                    CsrConfigOptionName::AvroDocOn(AvroDocOn {
+                        identifier: doc_on_identifier,
+                        for_schema: DocOnSchema::All,
+                    })
+                }
+                INCLUDE => {
+                    self.expect_keywords(&[DELETED, SUBJECTS])?;
+                    CsrConfigOptionName::IncludeDeletedSubjects
+                }
+                UNKNOWN => {
+                    self.expect_keywords(&[ENUM, SYMBOL])?;
+                    CsrConfigOptionName::UnknownEnumSymbol
+                }
+                _ => unreachable!(),
+            };
         Ok(CsrConfigOption {
             name,
             value: self.parse_optional_option_value()?,
@@ -2180,9 +2219,13 @@ impl<'a> Parser<'a> {
         let envelope = if self.parse_keyword(NONE) {
             Envelope::None
         } else if self.parse_keyword(DEBEZIUM) {
-            // In Platform, `DEBEZIUM UPSERT` is the only available option.
-            // Revisit this if we ever change that.
-            let debezium_mode = DbzMode::Plain;
+            // In Platform, `DEBEZIUM UPSERT` is the only available option for
+            // sources. Sinks may additionally request the full wire format.
+            let debezium_mode = if self.parse_keyword(FULL) {
+                DbzMode::Full
+            } else {
+                DbzMode::Plain
+            };
             Envelope::Debezium(debezium_mode)
         } else if self.parse_keyword(UPSERT) {
             Envelope::Upsert
@@ -2356,26 +2399,57 @@ impl<'a> Parser<'a> {
     fn parse_kafka_config_option(&mut self) -> Result<KafkaConfigOption<Raw>, ParserError> {
         let name = match self.expect_one_of_keywords(&[
             COMPRESSION,
+            DEAD,
+            DELETE,
             GROUP,
+            MAX,
             PARTITION,
+            PROGRESS,
             REPLICATION,
             RETENTION,
             SNAPSHOT,
             START,
             TOPIC,
+            TRANSACTION,
+            TRANSACTIONAL,
         ])? {
             COMPRESSION => {
                 self.expect_keyword(TYPE)?;
                 KafkaConfigOptionName::CompressionType
             }
+            DEAD => {
+                self.expect_keywords(&[LETTER, TOPIC])?;
+                KafkaConfigOptionName::DeadLetterTopic
+            }
+            DELETE => {
+                self.expect_keyword(MODE)?;
+                KafkaConfigOptionName::DeleteMode
+            }
             GROUP => {
                 self.expect_keywords(&[ID, PREFIX])?;
                 KafkaConfigOptionName::GroupIdPrefix
             }
+            MAX => {
+                let name = match self.expect_one_of_keywords(&[BYTES, MESSAGES])? {
+                    BYTES => KafkaConfigOptionName::MaxBytesPerSecond,
+                    MESSAGES => KafkaConfigOptionName::MaxMessagesPerSecond,
+                    _ => unreachable!(),
+                };
+                self.expect_keywords(&[PER, SECOND])?;
+                name
+            }
             PARTITION => {
                 self.expect_keyword(COUNT)?;
                 KafkaConfigOptionName::PartitionCount
             }
+            PROGRESS => {
+                self.expect_keyword(TOPIC)?;
+                if self.parse_keywords(&[REPLICATION, FACTOR]) {
+                    KafkaConfigOptionName::ProgressTopicReplicationFactor
+                } else {
+                    KafkaConfigOptionName::ProgressTopic
+                }
+            }
             REPLICATION => {
                 self.expect_keyword(FACTOR)?;
                 KafkaConfigOptionName::ReplicationFactor
@@ -2389,10 +2463,22 @@ impl<'a> Parser<'a> {
                 if self.parse_keyword(METADATA) {
                     self.expect_keywords(&[REFRESH, INTERVAL, MS])?;
                     KafkaConfigOptionName::TopicMetadataRefreshIntervalMs
+                } else if self.parse_keywords(&[FROM, COLUMN]) {
+                    KafkaConfigOptionName::TopicFromColumn
+                } else if self.parse_keyword(ALLOWLIST) {
+                    KafkaConfigOptionName::TopicAllowlist
                 } else {
                     KafkaConfigOptionName::Topic
                 }
             }
+            TRANSACTION => {
+                self.expect_keywords(&[TIMEOUT, MS])?;
+                KafkaConfigOptionName::TransactionTimeoutMs
+            }
+            TRANSACTIONAL => {
+                self.expect_keywords(&[ID, PREFIX])?;
+                KafkaConfigOptionName::TransactionalIdPrefix
+            }
             START => match self.expect_one_of_keywords(&[OFFSET, TIMESTAMP])? {
                 OFFSET => KafkaConfigOptionName::StartOffset,
                 TIMESTAMP => KafkaConfigOptionName::StartTimestamp,
@@ -2415,11 +2501,16 @@ impl<'a> Parser<'a> {
                 BROKER,
                 BROKERS,
                 DATABASE,
+                DEFAULT,
+                EGRESS,
                 ENDPOINT,
                 HOST,
+                HOSTS,
+                OAUTH,
                 PASSWORD,
                 PORT,
                 PROGRESS,
+                PROXY,
                 REGION,
                 ROLE,
                 SASL,
@@ -2441,21 +2532,65 @@ impl<'a> Parser<'a> {
                     self.expect_keyword(ZONES)?;
                     ConnectionOptionName::AvailabilityZones
                 }
-                AWS => {
-                    self.expect_keyword(PRIVATELINK)?;
-                    ConnectionOptionName::AwsPrivatelink
+                AWS => match self.expect_one_of_keywords(&[CONNECTION, PRIVATELINK])? {
+                    CONNECTION => ConnectionOptionName::AwsConnection,
+                    PRIVATELINK => ConnectionOptionName::AwsPrivatelink,
+                    _ => unreachable!(),
+                },
+                BROKER => {
+                    if self.parse_keywords(&[ADDRESS, TTL]) {
+                        ConnectionOptionName::BrokerAddressTtl
+                    } else if self.parse_keywords(&[RECONNECT, BACKOFF]) {
+                        ConnectionOptionName::BrokerReconnectBackoff
+                    } else {
+                        ConnectionOptionName::Broker
+                    }
                 }
-                BROKER => ConnectionOptionName::Broker,
                 BROKERS => ConnectionOptionName::Brokers,
                 DATABASE => ConnectionOptionName::Database,
+                DEFAULT => match self.expect_one_of_keywords(&[GROUP, TOPIC])? {
+                    GROUP => {
+                        self.expect_keywords(&[ID, PREFIX])?;
+                        ConnectionOptionName::DefaultGroupIdPrefix
+                    }
+                    TOPIC => {
+                        self.expect_keywords(&[METADATA, REFRESH, INTERVAL, MS])?;
+                        ConnectionOptionName::DefaultTopicMetadataRefreshIntervalMs
+                    }
+                    _ => unreachable!(),
+                },
+                EGRESS => {
+                    self.expect_keyword(ALLOWLIST)?;
+                    ConnectionOptionName::EgressAllowlist
+                }
                 ENDPOINT => ConnectionOptionName::Endpoint,
                 HOST => ConnectionOptionName::Host,
+                HOSTS => ConnectionOptionName::Hosts,
+                OAUTH => match self.expect_one_of_keywords(&[CLIENT, SCOPE, TOKEN])? {
+                    CLIENT => match self.expect_one_of_keywords(&[ID, SECRET])? {
+                        ID => ConnectionOptionName::OauthClientId,
+                        SECRET => ConnectionOptionName::OauthClientSecret,
+                        _ => unreachable!(),
+                    },
+                    SCOPE => ConnectionOptionName::OauthScope,
+                    TOKEN => {
+                        self.expect_keyword(ENDPOINT)?;
+                        ConnectionOptionName::OauthTokenEndpoint
+                    }
+                    _ => unreachable!(),
+                },
                 PASSWORD => ConnectionOptionName::Password,
                 PORT => ConnectionOptionName::Port,
                 PROGRESS => {
                     self.expect_keyword(TOPIC)?;
                     ConnectionOptionName::ProgressTopic
                 }
+                PROXY => match self.expect_one_of_keywords(&[PASSWORD, URL, USER, USERNAME])? {
+                    PASSWORD => ConnectionOptionName::ProxyPassword,
+                    URL => ConnectionOptionName::ProxyUrl,
+                    USER | USERNAME => ConnectionOptionName::ProxyUser,
+                    _ => unreachable!(),
+                },
                 SECURITY => {
                     self.expect_keyword(PROTOCOL)?;
                     ConnectionOptionName::SecurityProtocol
@@ -2505,6 +2640,12 @@ impl<'a> Parser<'a> {
 
     fn parse_connection_option_unified(&mut self) -> Result<ConnectionOption<Raw>, ParserError> {
         let name = match self.parse_connection_option_name()? {
+            ConnectionOptionName::AwsConnection => {
+                return Ok(ConnectionOption {
+                    name: ConnectionOptionName::AwsConnection,
+                    value: Some(self.parse_object_option_value()?),
+                });
+            }
             ConnectionOptionName::AwsPrivatelink => {
                 return Ok(ConnectionOption {
                     name: ConnectionOptionName::AwsPrivatelink,
@@ -2664,6 +2805,8 @@ impl<'a> Parser<'a> {
             vec![]
         };
 
+        let in_background = self.parse_keywords(&[IN, BACKGROUND]);
+
         Ok(Statement::CreateSource(CreateSourceStatement {
             name,
             in_cluster,
@@ -2677,6 +2820,7 @@ impl<'a> Parser<'a> {
             referenced_subsources,
             progress_subsource,
             with_options,
+            in_background,
         }))
     }
 
@@ -2779,7 +2923,7 @@ impl<'a> Parser<'a> {
         // sources, and the user gets better errors if we reject the formats here.
         let body_format = match self.expect_one_of_keywords(&[JSON, TEXT, BYTES])? {
             JSON => Format::Json,
-            TEXT => Format::Text,
+            TEXT => Format::Text { encoding: None },
             BYTES => Format::Bytes,
             _ => unreachable!(),
         };
@@ -3110,33 +3254,238 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_create_sink_connection(&mut self) -> Result<CreateSinkConnection<Raw>, ParserError> {
-        self.expect_keyword(KAFKA)?;
-        self.expect_keyword(CONNECTION)?;
+        match self.expect_one_of_keywords(&[KAFKA, POSTGRES, S3, HTTP, REDIS, ICEBERG])? {
+            KAFKA => {
+                self.expect_keyword(CONNECTION)?;
 
-        let connection = self.parse_kafka_connection_reference()?;
+                let connection = self.parse_kafka_connection_reference()?;
 
-        // one token of lookahead:
-        // * `KEY (` means we're parsing a list of columns for the key
-        // * `KEY FORMAT` means there is no key, we'll parse a KeyValueFormat later
-        let key =
-            if self.peek_keyword(KEY) && self.peek_nth_token(1) != Some(Token::Keyword(FORMAT)) {
-                let _ = self.expect_keyword(KEY);
-                let key_columns = self.parse_parenthesized_column_list(Mandatory)?;
+                let key = self.parse_sink_key()?;
+                let headers = self.parse_sink_headers()?;
+                Ok(CreateSinkConnection::Kafka {
+                    connection,
+                    key,
+                    headers,
+                })
+            }
+            POSTGRES => {
+                self.expect_keyword(CONNECTION)?;
+                let connection = self.parse_raw_name()?;
 
-                let not_enforced = if self.peek_keywords(&[NOT, ENFORCED]) {
-                    let _ = self.expect_keywords(&[NOT, ENFORCED])?;
-                    true
+                let options = if self.consume_token(&Token::LParen) {
+                    let options =
+                        self.parse_comma_separated(Parser::parse_pg_sink_connection_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
                 } else {
-                    false
+                    vec![]
                 };
-                Some(KafkaSinkKey {
-                    key_columns,
-                    not_enforced,
+
+                let key = self.parse_sink_key()?;
+                Ok(CreateSinkConnection::Postgres {
+                    connection,
+                    options,
+                    key,
+                })
+            }
+            S3 => {
+                self.expect_keyword(CONNECTION)?;
+                let connection = self.parse_raw_name()?;
+
+                let options = if self.consume_token(&Token::LParen) {
+                    let options =
+                        self.parse_comma_separated(Parser::parse_s3_sink_connection_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
+                } else {
+                    vec![]
+                };
+
+                Ok(CreateSinkConnection::S3 {
+                    connection,
+                    options,
+                })
+            }
+            HTTP => {
+                self.expect_token(&Token::LParen)?;
+                let options =
+                    self.parse_comma_separated(Parser::parse_http_sink_connection_option)?;
+                self.expect_token(&Token::RParen)?;
+
+                let key = self.parse_sink_key()?;
+                Ok(CreateSinkConnection::Http { options, key })
+            }
+            REDIS => {
+                self.expect_token(&Token::LParen)?;
+                let options =
+                    self.parse_comma_separated(Parser::parse_redis_sink_connection_option)?;
+                self.expect_token(&Token::RParen)?;
+
+                let key = self.parse_sink_key()?;
+                Ok(CreateSinkConnection::Redis { options, key })
+            }
+            ICEBERG => {
+                self.expect_keyword(CONNECTION)?;
+                let connection = self.parse_raw_name()?;
+
+                self.expect_token(&Token::LParen)?;
+                let options =
+                    self.parse_comma_separated(Parser::parse_iceberg_sink_connection_option)?;
+                self.expect_token(&Token::RParen)?;
+
+                Ok(CreateSinkConnection::Iceberg {
+                    connection,
+                    options,
                 })
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn parse_sink_key(&mut self) -> Result<Option<KafkaSinkKey>, ParserError> {
+        // one token of lookahead:
+        // * `KEY (` means we're parsing a list of columns for the key
+        // * `KEY FORMAT` means there is no key, we'll parse a KeyValueFormat later
+        if self.peek_keyword(KEY) && self.peek_nth_token(1) != Some(Token::Keyword(FORMAT)) {
+            let _ = self.expect_keyword(KEY);
+            let key_columns = self.parse_parenthesized_column_list(Mandatory)?;
+
+            let not_enforced = if self.peek_keywords(&[NOT, ENFORCED]) {
+                let _ = self.expect_keywords(&[NOT, ENFORCED])?;
+                true
             } else {
-                None
+                false
             };
-        Ok(CreateSinkConnection::Kafka { connection, key })
+            Ok(Some(KafkaSinkKey {
+                key_columns,
+                not_enforced,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn parse_sink_headers(&mut self) -> Result<Option<KafkaSinkHeaders>, ParserError> {
+        if self.parse_keywords(&[HEADERS, FROM]) {
+            let header_columns = self.parse_parenthesized_column_list(Mandatory)?;
+            let exclude_from_value = self.parse_keywords(&[EXCLUDE, FROM, VALUE]);
+            Ok(Some(KafkaSinkHeaders {
+                header_columns,
+                exclude_from_value,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn parse_pg_sink_connection_option(&mut self) -> Result<PgSinkConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[CREATE, TABLE, WRITE])? {
+            CREATE => {
+                self.expect_keyword(TABLE)?;
+                PgSinkConfigOptionName::CreateTable
+            }
+            TABLE => PgSinkConfigOptionName::Table,
+            WRITE => {
+                self.expect_keyword(MODE)?;
+                PgSinkConfigOptionName::WriteMode
+            }
+            _ => unreachable!(),
+        };
+        Ok(PgSinkConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_s3_sink_connection_option(&mut self) -> Result<S3SinkConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[BUCKET, MAX, PREFIX, ROTATION])? {
+            BUCKET => S3SinkConfigOptionName::Bucket,
+            MAX => {
+                self.expect_keywords(&[FILE, SIZE])?;
+                S3SinkConfigOptionName::MaxFileSize
+            }
+            PREFIX => S3SinkConfigOptionName::Prefix,
+            ROTATION => {
+                self.expect_keyword(INTERVAL)?;
+                S3SinkConfigOptionName::RotationInterval
+            }
+            _ => unreachable!(),
+        };
+        Ok(S3SinkConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_http_sink_connection_option(
+        &mut self,
+    ) -> Result<HttpSinkConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[BATCH, HEADER, MAX, RETRY, URL])? {
+            BATCH => {
+                self.expect_keyword(SIZE)?;
+                HttpSinkConfigOptionName::BatchSize
+            }
+            HEADER => HttpSinkConfigOptionName::Header(self.parse_literal_string()?),
+            MAX => {
+                self.expect_keyword(RETRIES)?;
+                HttpSinkConfigOptionName::MaxRetries
+            }
+            RETRY => {
+                self.expect_keyword(BACKOFF)?;
+                HttpSinkConfigOptionName::RetryBackoff
+            }
+            URL => HttpSinkConfigOptionName::Url,
+            _ => unreachable!(),
+        };
+        Ok(HttpSinkConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_redis_sink_connection_option(
+        &mut self,
+    ) -> Result<RedisSinkConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[DATA, KEY, PASSWORD, URL])? {
+            DATA => {
+                self.expect_keyword(STRUCTURE)?;
+                RedisSinkConfigOptionName::DataStructure
+            }
+            KEY => {
+                self.expect_keyword(PREFIX)?;
+                RedisSinkConfigOptionName::KeyPrefix
+            }
+            PASSWORD => RedisSinkConfigOptionName::Password,
+            URL => RedisSinkConfigOptionName::Url,
+            _ => unreachable!(),
+        };
+        Ok(RedisSinkConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_iceberg_sink_connection_option(
+        &mut self,
+    ) -> Result<IcebergSinkConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[CATALOG, COMMIT, NAMESPACE, TABLE])? {
+            CATALOG => match self.parse_one_of_keywords(&[TOKEN, URL]) {
+                Some(TOKEN) => IcebergSinkConfigOptionName::CatalogToken,
+                Some(URL) => IcebergSinkConfigOptionName::CatalogUrl,
+                _ => IcebergSinkConfigOptionName::Catalog,
+            },
+            COMMIT => {
+                self.expect_keyword(INTERVAL)?;
+                IcebergSinkConfigOptionName::CommitInterval
+            }
+            NAMESPACE => IcebergSinkConfigOptionName::Namespace,
+            TABLE => IcebergSinkConfigOptionName::Table,
+            _ => unreachable!(),
+        };
+        Ok(IcebergSinkConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
     }
 
     fn parse_create_view(&mut self) -> Result<Statement<Raw>, ParserError> {
@@ -3329,11 +3678,11 @@ impl<'a> Parser<'a> {
         self.expect_keyword(ROLE)?;
         let name = self.parse_identifier()?;
         let _ = self.parse_keyword(WITH);
-        let options = self.parse_role_attributes();
+        let options = self.parse_role_attributes()?;
         Ok(Statement::CreateRole(CreateRoleStatement { name, options }))
     }
 
-    fn parse_role_attributes(&mut self) -> Vec<RoleAttribute> {
+    fn parse_role_attributes(&mut self) -> Result<Vec<RoleAttribute>, ParserError> {
         let mut options = vec![];
         loop {
             match self.parse_one_of_keywords(&[
@@ -3349,6 +3698,10 @@ impl<'a> Parser<'a> {
                 NOCREATEDB,
                 CREATEROLE,
                 NOCREATEROLE,
+                PASSWORD,
+                CONNECTION,
+                STATEMENT,
+                VALID,
             ]) {
                 None => break,
                 Some(SUPERUSER) => options.push(RoleAttribute::SuperUser),
@@ -3363,10 +3716,40 @@ impl<'a> Parser<'a> {
                 Some(NOCREATEDB) => options.push(RoleAttribute::NoCreateDB),
                 Some(CREATEROLE) => options.push(RoleAttribute::CreateRole),
                 Some(NOCREATEROLE) => options.push(RoleAttribute::NoCreateRole),
+                Some(PASSWORD) => {
+                    let password = if self.parse_keyword(NULL) {
+                        None
+                    } else {
+                        Some(self.parse_literal_string()?)
+                    };
+                    options.push(RoleAttribute::Password(password));
+                }
+                Some(CONNECTION) => {
+                    self.expect_keyword(LIMIT)?;
+                    options.push(RoleAttribute::ConnectionLimit(self.parse_literal_int()?));
+                }
+                Some(STATEMENT) => {
+                    self.expect_keyword(TIMEOUT)?;
+                    let timeout = if self.parse_keyword(NULL) {
+                        None
+                    } else {
+                        Some(self.parse_literal_string()?)
+                    };
+                    options.push(RoleAttribute::StatementTimeout(timeout));
+                }
+                Some(VALID) => {
+                    self.expect_keyword(UNTIL)?;
+                    let valid_until = if self.parse_keyword(NULL) {
+                        None
+                    } else {
+                        Some(self.parse_literal_string()?)
+                    };
+                    options.push(RoleAttribute::ValidUntil(valid_until));
+                }
                 Some(_) => unreachable!(),
             }
         }
-        options
+        Ok(options)
     }
 
     fn parse_create_secret(&mut self) -> Result<Statement<Raw>, ParserError> {
@@ -3382,6 +3765,37 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn parse_create_service_account(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keywords(&[SERVICE, ACCOUNT])?;
+        let name = self.parse_identifier()?;
+        self.expect_keywords(&[FOR, ROLE])?;
+        let role = self.parse_identifier()?;
+        self.expect_keywords(&[TOKEN, SECRET])?;
+        let token_secret = self.parse_raw_name()?;
+        let scopes = if self.parse_keyword(SCOPES) {
+            self.expect_token(&Token::LParen)?;
+            let scopes = self.parse_comma_separated(Parser::parse_literal_string)?;
+            self.expect_token(&Token::RParen)?;
+            scopes
+        } else {
+            Vec::new()
+        };
+        let valid_until = if self.parse_keywords(&[VALID, UNTIL]) {
+            Some(self.parse_literal_string()?)
+        } else {
+            None
+        };
+        Ok(Statement::CreateServiceAccount(
+            CreateServiceAccountStatement {
+                name,
+                role,
+                token_secret,
+                scopes,
+                valid_until,
+            },
+        ))
+    }
+
     fn parse_create_type(&mut self) -> Result<Statement<Raw>, ParserError> {
         self.expect_keyword(TYPE)?;
         let name = self.parse_item_name()?;
@@ -3447,6 +3861,26 @@ impl<'a> Parser<'a> {
 
     fn parse_create_cluster(&mut self) -> Result<Statement<Raw>, ParserError> {
         let name = self.parse_identifier()?;
+        if self.parse_keyword(LIKE) {
+            let cluster = self.parse_identifier()?;
+            let mut including = vec![];
+            while self.parse_keyword(INCLUDING) {
+                let objects = match self.expect_one_of_keywords(&[INDEXES, MATERIALIZED])? {
+                    INDEXES => CreateClusterLikeObjects::Indexes,
+                    MATERIALIZED => {
+                        self.expect_keyword(VIEWS)?;
+                        CreateClusterLikeObjects::MaterializedViews
+                    }
+                    _ => unreachable!(),
+                };
+                including.push(objects);
+            }
+            return Ok(Statement::CreateCluster(CreateClusterStatement {
+                name,
+                options: vec![],
+                like: Some(CreateClusterLike { cluster, including }),
+            }));
+        }
         // For historical reasons, the parentheses around the options can be
         // omitted.
         let paren = self.consume_token(&Token::LParen);
@@ -3457,6 +3891,7 @@ impl<'a> Parser<'a> {
         Ok(Statement::CreateCluster(CreateClusterStatement {
             name,
             options,
+            like: None,
         }))
     }
 
@@ -3467,8 +3902,11 @@ impl<'a> Parser<'a> {
             IDLE,
             INTROSPECTION,
             MANAGED,
+            MEMORY,
+            PRIORITY,
             REPLICAS,
             REPLICATION,
+            SCHEDULE,
             SIZE,
         ])?;
         let name = match option {
@@ -3487,11 +3925,17 @@ impl<'a> Parser<'a> {
                 _ => unreachable!(),
             },
             MANAGED => ClusterOptionName::Managed,
+            MEMORY => {
+                self.expect_keyword(QUOTA)?;
+                ClusterOptionName::MemoryQuota
+            }
+            PRIORITY => ClusterOptionName::Priority,
             REPLICAS => ClusterOptionName::Replicas,
             REPLICATION => {
                 self.expect_keyword(FACTOR)?;
                 ClusterOptionName::ReplicationFactor
             }
+            SCHEDULE => ClusterOptionName::Schedule,
             SIZE => ClusterOptionName::Size,
             _ => unreachable!(),
         };
@@ -3504,6 +3948,9 @@ impl<'a> Parser<'a> {
         if name == ClusterOptionName::Replicas {
             return self.parse_cluster_option_replicas();
         }
+        if name == ClusterOptionName::Schedule {
+            return self.parse_cluster_option_schedule();
+        }
 
         let value = self.parse_optional_option_value()?;
         Ok(ClusterOption { name, value })
@@ -3530,6 +3977,24 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_cluster_option_schedule(&mut self) -> Result<ClusterOption<Raw>, ParserError> {
+        let _ = self.consume_token(&Token::Eq);
+        self.expect_token(&Token::LParen)?;
+        let entries = self.parse_comma_separated(|parser| {
+            parser.expect_keyword(AT)?;
+            let at = parser.parse_literal_string()?;
+            parser.expect_token(&Token::LParen)?;
+            let options = parser.parse_comma_separated(Parser::parse_cluster_option)?;
+            parser.expect_token(&Token::RParen)?;
+            Ok(ClusterScheduleDefinition { at, options })
+        })?;
+        self.expect_token(&Token::RParen)?;
+        Ok(ClusterOption {
+            name: ClusterOptionName::Schedule,
+            value: Some(WithOptionValue::ClusterSchedule(entries)),
+        })
+    }
+
     fn parse_replica_option(&mut self) -> Result<ReplicaOption<Raw>, ParserError> {
         let name = match self.expect_one_of_keywords(&[
             AVAILABILITY,
@@ -3547,7 +4012,14 @@ impl<'a> Parser<'a> {
         ])? {
             AVAILABILITY => {
                 self.expect_keyword(ZONE)?;
-                ReplicaOptionName::AvailabilityZone
+                if self.parse_keyword(AFFINITY) {
+                    ReplicaOptionName::AvailabilityZoneAffinity
+                } else if self.parse_keyword(ANTI) {
+                    self.expect_keyword(AFFINITY)?;
+                    ReplicaOptionName::AvailabilityZoneAntiAffinity
+                } else {
+                    ReplicaOptionName::AvailabilityZone
+                }
             }
             BILLED => {
                 self.expect_keyword(AS)?;
@@ -3588,6 +4060,63 @@ impl<'a> Parser<'a> {
         Ok(ReplicaOption { name, value })
     }
 
+    fn parse_create_cluster_replica_size(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keywords(&[REPLICA, SIZE])?;
+        let name = self.parse_literal_string()?;
+        self.expect_token(&Token::LParen)?;
+        let options = self.parse_comma_separated(Parser::parse_cluster_replica_size_option)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(Statement::CreateClusterReplicaSize(
+            CreateClusterReplicaSizeStatement { name, options },
+        ))
+    }
+
+    fn parse_alter_cluster_replica_option_name(
+        &mut self,
+    ) -> Result<AlterClusterReplicaOptionName, ParserError> {
+        self.expect_keyword(DRAINING)?;
+        Ok(AlterClusterReplicaOptionName::Draining)
+    }
+
+    fn parse_alter_cluster_replica_option(
+        &mut self,
+    ) -> Result<AlterClusterReplicaOption<Raw>, ParserError> {
+        let name = self.parse_alter_cluster_replica_option_name()?;
+        let value = self.parse_optional_option_value()?;
+        Ok(AlterClusterReplicaOption { name, value })
+    }
+
+    fn parse_cluster_replica_size_option(
+        &mut self,
+    ) -> Result<ClusterReplicaSizeOption<Raw>, ParserError> {
+        let name = match self
+            .expect_one_of_keywords(&[CPU, CREDITS, DISABLED, DISK, MEMORY, SCALE, WORKERS])?
+        {
+            CPU => {
+                self.expect_keyword(LIMIT)?;
+                ClusterReplicaSizeOptionName::CpuLimit
+            }
+            CREDITS => {
+                self.expect_keywords(&[PER, HOUR])?;
+                ClusterReplicaSizeOptionName::CreditsPerHour
+            }
+            DISABLED => ClusterReplicaSizeOptionName::Disabled,
+            DISK => {
+                self.expect_keyword(LIMIT)?;
+                ClusterReplicaSizeOptionName::DiskLimit
+            }
+            MEMORY => {
+                self.expect_keyword(LIMIT)?;
+                ClusterReplicaSizeOptionName::MemoryLimit
+            }
+            SCALE => ClusterReplicaSizeOptionName::Scale,
+            WORKERS => ClusterReplicaSizeOptionName::Workers,
+            _ => unreachable!(),
+        };
+        let value = self.parse_optional_option_value()?;
+        Ok(ClusterReplicaSizeOption { name, value })
+    }
+
     fn parse_create_cluster_replica(&mut self) -> Result<Statement<Raw>, ParserError> {
         self.next_token();
         let of_cluster = self.parse_identifier()?;
@@ -3688,6 +4217,14 @@ impl<'a> Parser<'a> {
         if self.parse_keyword(OWNED) {
             self.parse_drop_owned()
                 .map_parser_err(StatementKind::DropOwned)
+        } else if self.peek_keywords(&[CLUSTER, REPLICA, SIZE])
+            && self.peek_nth_token(3) != Some(Token::Dot)
+        {
+            self.parse_drop_cluster_replica_size()
+                .map_parser_err(StatementKind::DropClusterReplicaSize)
+        } else if self.parse_keywords(&[SERVICE, ACCOUNT]) {
+            self.parse_drop_service_account()
+                .map_parser_err(StatementKind::DropServiceAccount)
         } else {
             self.parse_drop_objects()
                 .map_parser_err(StatementKind::DropObjects)
@@ -3771,6 +4308,40 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse an `UNDROP` statement, assuming that the `UNDROP` token has
+    /// already been consumed.
+    fn parse_undrop_object(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let object_type = self.expect_object_type()?;
+        match object_type {
+            ObjectType::Table
+            | ObjectType::View
+            | ObjectType::MaterializedView
+            | ObjectType::Source
+            | ObjectType::Sink
+            | ObjectType::Index
+            | ObjectType::Type
+            | ObjectType::Secret
+            | ObjectType::Connection => {
+                let name = self.parse_item_name()?;
+                Ok(Statement::UndropObject(UndropObjectStatement {
+                    object_type,
+                    name,
+                }))
+            }
+            ObjectType::Role
+            | ObjectType::Cluster
+            | ObjectType::ClusterReplica
+            | ObjectType::Database
+            | ObjectType::Schema
+            | ObjectType::Func
+            | ObjectType::Subsource => parser_err!(
+                self,
+                self.peek_prev_pos(),
+                format!("Unsupported UNDROP on {object_type}")
+            ),
+        }
+    }
+
     fn parse_drop_clusters(&mut self, if_exists: bool) -> Result<Statement<Raw>, ParserError> {
         let names = self.parse_comma_separated(|parser| {
             Ok(UnresolvedObjectName::Cluster(parser.parse_identifier()?))
@@ -3804,6 +4375,24 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn parse_drop_cluster_replica_size(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keywords(&[CLUSTER, REPLICA, SIZE])?;
+        let if_exists = self.parse_if_exists()?;
+        let name = self.parse_literal_string()?;
+        Ok(Statement::DropClusterReplicaSize(
+            DropClusterReplicaSizeStatement { if_exists, name },
+        ))
+    }
+
+    fn parse_drop_service_account(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let if_exists = self.parse_if_exists()?;
+        let name = self.parse_identifier()?;
+        Ok(Statement::DropServiceAccount(DropServiceAccountStatement {
+            if_exists,
+            name,
+        }))
+    }
+
     fn parse_drop_owned(&mut self) -> Result<Statement<Raw>, ParserError> {
         self.expect_keyword(BY)?;
         let role_names = self.parse_comma_separated(Parser::parse_identifier)?;
@@ -4135,11 +4724,11 @@ impl<'a> Parser<'a> {
                 }))
             }
             ObjectType::Cluster => self.parse_alter_cluster(object_type),
-            ObjectType::ClusterReplica => {
+            ObjectType::ClusterReplica => self.parse_alter_cluster_replica(object_type),
+            ObjectType::Database => {
                 let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
-                let name = UnresolvedObjectName::ClusterReplica(
-                    self.parse_cluster_replica_name()
-                        .map_no_statement_parser_err()?,
+                let name = UnresolvedObjectName::Database(
+                    self.parse_database_name().map_no_statement_parser_err()?,
                 );
                 let action = self
                     .expect_one_of_keywords(&[OWNER, RENAME])
@@ -4171,19 +4760,35 @@ impl<'a> Parser<'a> {
                     _ => unreachable!(),
                 }
             }
-            ObjectType::Database => {
-                let if_exists = self
-                    .parse_if_exists()
-                    .map_parser_err(StatementKind::AlterOwner)?;
-                let name = UnresolvedObjectName::Database(
-                    self.parse_database_name()
-                        .map_parser_err(StatementKind::AlterOwner)?,
-                );
-                self.expect_keywords(&[OWNER, TO])
+            ObjectType::Schema => self.parse_alter_schema(object_type),
+            ObjectType::Func | ObjectType::Subsource => parser_err!(
+                self,
+                self.peek_prev_pos(),
+                format!("Unsupported ALTER on {object_type}")
+            )
+            .map_no_statement_parser_err(),
+        }
+    }
+
+    fn parse_alter_cluster_replica(
+        &mut self,
+        object_type: ObjectType,
+    ) -> Result<Statement<Raw>, ParserStatementError> {
+        let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
+        let name = self
+            .parse_cluster_replica_name()
+            .map_no_statement_parser_err()?;
+        let action = self
+            .expect_one_of_keywords(&[OWNER, RENAME, RESET, SET])
+            .map_no_statement_parser_err()?;
+        match action {
+            OWNER => {
+                self.expect_keyword(TO)
                     .map_parser_err(StatementKind::AlterOwner)?;
                 let new_owner = self
                     .parse_identifier()
                     .map_parser_err(StatementKind::AlterOwner)?;
+                let name = UnresolvedObjectName::ClusterReplica(name);
                 Ok(Statement::AlterOwner(AlterOwnerStatement {
                     object_type,
                     if_exists,
@@ -4191,13 +4796,53 @@ impl<'a> Parser<'a> {
                     new_owner,
                 }))
             }
-            ObjectType::Schema => self.parse_alter_schema(object_type),
-            ObjectType::Func | ObjectType::Subsource => parser_err!(
-                self,
-                self.peek_prev_pos(),
-                format!("Unsupported ALTER on {object_type}")
-            )
-            .map_no_statement_parser_err(),
+            RENAME => {
+                self.expect_keyword(TO)
+                    .map_parser_err(StatementKind::AlterObjectRename)?;
+                let to_item_name = self
+                    .parse_identifier()
+                    .map_parser_err(StatementKind::AlterObjectRename)?;
+                let name = UnresolvedObjectName::ClusterReplica(name);
+                Ok(Statement::AlterObjectRename(AlterObjectRenameStatement {
+                    object_type,
+                    if_exists,
+                    name,
+                    to_item_name,
+                }))
+            }
+            RESET => {
+                self.expect_token(&Token::LParen)
+                    .map_parser_err(StatementKind::AlterClusterReplica)?;
+                let names = self
+                    .parse_comma_separated(Parser::parse_alter_cluster_replica_option_name)
+                    .map_parser_err(StatementKind::AlterClusterReplica)?;
+                self.expect_token(&Token::RParen)
+                    .map_parser_err(StatementKind::AlterClusterReplica)?;
+                Ok(Statement::AlterClusterReplica(
+                    AlterClusterReplicaStatement {
+                        if_exists,
+                        name,
+                        action: AlterClusterReplicaAction::ResetOptions(names),
+                    },
+                ))
+            }
+            SET => {
+                self.expect_token(&Token::LParen)
+                    .map_parser_err(StatementKind::AlterClusterReplica)?;
+                let options = self
+                    .parse_comma_separated(Parser::parse_alter_cluster_replica_option)
+                    .map_parser_err(StatementKind::AlterClusterReplica)?;
+                self.expect_token(&Token::RParen)
+                    .map_parser_err(StatementKind::AlterClusterReplica)?;
+                Ok(Statement::AlterClusterReplica(
+                    AlterClusterReplicaStatement {
+                        if_exists,
+                        name,
+                        action: AlterClusterReplicaAction::SetOptions(options),
+                    },
+                ))
+            }
+            _ => unreachable!(),
         }
     }
 
@@ -4605,6 +5250,16 @@ impl<'a> Parser<'a> {
                     {
                         return result;
                     }
+                    if self.parse_keyword(FROM) {
+                        let from = self
+                            .parse_raw_name()
+                            .map_parser_err(StatementKind::AlterSink)?;
+                        return Ok(Statement::AlterSink(AlterSinkStatement {
+                            sink_name: name,
+                            if_exists,
+                            action: AlterSinkAction::ChangeRelation(from),
+                        }));
+                    }
                     self.expect_token(&Token::LParen)
                         .map_parser_err(StatementKind::AlterSink)?;
                     let set_options = self
@@ -4758,10 +5413,17 @@ impl<'a> Parser<'a> {
 
     fn parse_alter_connection_action(&mut self) -> Result<AlterConnectionAction<Raw>, ParserError> {
         let r = match self.expect_one_of_keywords(&[ROTATE, SET, RESET, DROP])? {
-            ROTATE => {
-                self.expect_keyword(KEYS)?;
-                AlterConnectionAction::RotateKeys
-            }
+            ROTATE => match self.expect_one_of_keywords(&[KEYS, SECRETS])? {
+                KEYS => AlterConnectionAction::RotateKeys,
+                SECRETS => {
+                    self.expect_token(&Token::LParen)?;
+                    let options =
+                        self.parse_comma_separated(Parser::parse_connection_option_unified)?;
+                    self.expect_token(&Token::RParen)?;
+                    AlterConnectionAction::RotateSecrets(options)
+                }
+                _ => unreachable!(),
+            },
             SET => {
                 self.expect_token(&Token::LParen)?;
                 let option = self.parse_connection_option_unified()?;
@@ -4811,7 +5473,7 @@ impl<'a> Parser<'a> {
             }
             Some(WITH) | None => {
                 let _ = self.parse_keyword(WITH);
-                let attrs = self.parse_role_attributes();
+                let attrs = self.parse_role_attributes()?;
                 AlterRoleOption::Attributes(attrs)
             }
             Some(k) => unreachable!("unmatched keyword: {k}"),
@@ -4894,7 +5556,7 @@ impl<'a> Parser<'a> {
         let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
         let name = self.parse_item_name().map_no_statement_parser_err()?;
         let action = self
-            .expect_one_of_keywords(&[SET, RENAME, OWNER])
+            .expect_one_of_keywords(&[SET, RENAME, OWNER, ALTER])
             .map_no_statement_parser_err()?;
         match action {
             RENAME => {
@@ -4910,6 +5572,9 @@ impl<'a> Parser<'a> {
                 }))
             }
             SET => self.parse_alter_set_cluster(if_exists, name, object_type),
+            ALTER => self
+                .parse_alter_column_mask(if_exists, name, object_type)
+                .map_parser_err(StatementKind::AlterColumnMask),
             OWNER => {
                 self.expect_keyword(TO).map_no_statement_parser_err()?;
                 let new_owner = self
@@ -5017,6 +5682,44 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    /// Parses `[COLUMN] name { SET | DROP } MASK ...` fragments into an
+    /// [`AlterColumnMaskStatement`].
+    fn parse_alter_column_mask(
+        &mut self,
+        if_exists: bool,
+        name: UnresolvedItemName,
+        object_type: ObjectType,
+    ) -> Result<Statement<Raw>, ParserError> {
+        let _ = self.parse_keyword(COLUMN);
+        let column = self.parse_identifier()?;
+        let action = match self.expect_one_of_keywords(&[SET, DROP])? {
+            SET => {
+                self.expect_keyword(MASK)?;
+                let expr = self.parse_expr()?;
+                self.expect_keywords(&[FOR, ROLE])?;
+                let roles = self.parse_comma_separated(Parser::parse_identifier)?;
+                AlterColumnMaskAction::Set { expr, roles }
+            }
+            DROP => {
+                self.expect_keyword(MASK)?;
+                let roles = if self.parse_keywords(&[FOR, ROLE]) {
+                    self.parse_comma_separated(Parser::parse_identifier)?
+                } else {
+                    Vec::new()
+                };
+                AlterColumnMaskAction::Drop { roles }
+            }
+            _ => unreachable!(),
+        };
+        Ok(Statement::AlterColumnMask(AlterColumnMaskStatement {
+            object_type,
+            if_exists,
+            name,
+            column,
+            action,
+        }))
+    }
+
     /// Parse a copy statement
     fn parse_copy(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
         let relation = if self.consume_token(&Token::LParen) {
@@ -6352,6 +7055,8 @@ impl<'a> Parser<'a> {
             self.parse_show_privileges()
         } else if self.parse_keywords(&[DEFAULT, PRIVILEGES]) {
             self.parse_show_default_privileges()
+        } else if self.parse_keywords(&[EFFECTIVE, PRIVILEGES]) {
+            self.parse_show_effective_privileges()
         } else if self.parse_keyword(ROLE) {
             self.expect_keyword(MEMBERSHIP)?;
             let role = if self.parse_keyword(FOR) {
@@ -6473,6 +7178,28 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn parse_show_effective_privileges(&mut self) -> Result<ShowStatement<Raw>, ParserError> {
+        self.expect_keyword(FOR)?;
+        let role = self.parse_identifier()?;
+        self.expect_keyword(ON)?;
+        let (object_type, object_name) = if self.parse_keyword(SYSTEM) {
+            (SystemObjectType::System, None)
+        } else {
+            let object_type = self.expect_grant_revoke_object_type("SHOW EFFECTIVE PRIVILEGES")?;
+            let object_name = self.parse_object_name(object_type)?;
+            (SystemObjectType::Object(object_type), Some(object_name))
+        };
+        Ok(ShowStatement::ShowObjects(ShowObjectsStatement {
+            object_type: ShowObjectType::EffectivePrivileges {
+                role,
+                object_type,
+                object_name,
+            },
+            from: None,
+            filter: self.parse_show_statement_filter()?,
+        }))
+    }
+
     fn parse_inspect(&mut self) -> Result<ShowStatement<Raw>, ParserError> {
         self.expect_keyword(SHARD)?;
         let id = self.parse_literal_string()?;
@@ -7032,6 +7759,9 @@ impl<'a> Parser<'a> {
         } else if self.peek_keyword(KEY) || self.peek_keyword(VALUE) {
             self.parse_explain_schema()
                 .map_parser_err(StatementKind::ExplainSinkSchema)
+        } else if self.peek_keywords(&[CREATE, SOURCE]) {
+            self.parse_explain_create_source()
+                .map_parser_err(StatementKind::ExplainCreateSource)
         } else {
             self.parse_explain_plan()
                 .map_parser_err(StatementKind::ExplainPlan)
@@ -7206,6 +7936,30 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse an `EXPLAIN CREATE SOURCE` statement, assuming that the `EXPLAIN`
+    /// token has already been consumed.
+    fn parse_explain_create_source(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(CREATE)?;
+        let start = self.peek_pos();
+        match self.parse_create_source()? {
+            Statement::CreateSource(statement) if statement.in_background => {
+                parser_err!(
+                    self,
+                    start,
+                    "EXPLAIN CREATE SOURCE does not support IN BACKGROUND"
+                )
+            }
+            Statement::CreateSource(statement) => Ok(Statement::ExplainCreateSource(
+                ExplainCreateSourceStatement { statement },
+            )),
+            _ => parser_err!(
+                self,
+                start,
+                "EXPLAIN CREATE SOURCE does not support webhook sources"
+            ),
+        }
+    }
+
     /// Parse a `DECLARE` statement, assuming that the `DECLARE` token
     /// has already been consumed.
     fn parse_declare(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
@@ -11,6 +11,7 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 
+use mz_controller_types::ClusterId;
 use mz_ore::str::StrExt;
 use mz_repr::GlobalId;
 
@@ -20,7 +21,7 @@ use crate::ast::{
     AstInfo, CreateConnectionStatement, CreateIndexStatement, CreateMaterializedViewStatement,
     CreateSecretStatement, CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement,
     CreateTableStatement, CreateViewStatement, CreateWebhookSourceStatement, Expr, Ident, Query,
-    Raw, RawItemName, Statement, UnresolvedItemName, ViewDefinition,
+    Raw, RawClusterName, RawItemName, Statement, UnresolvedItemName, ViewDefinition,
 };
 use crate::names::FullItemName;
 
@@ -127,6 +128,89 @@ impl<'a, 'ast> VisitMut<'ast, Raw> for CreateSqlRewriteSchema<'a> {
     }
 }
 
+/// Given a [`Statement`] rewrites all references of the database name `cur_database` to
+/// `new_database`.
+pub fn create_stmt_rename_database_refs(
+    create_stmt: &mut Statement<Raw>,
+    cur_database: &str,
+    new_database: &str,
+) {
+    match create_stmt {
+        stmt @ Statement::CreateConnection(_)
+        | stmt @ Statement::CreateDatabase(_)
+        | stmt @ Statement::CreateSchema(_)
+        | stmt @ Statement::CreateWebhookSource(_)
+        | stmt @ Statement::CreateSource(_)
+        | stmt @ Statement::CreateSubsource(_)
+        | stmt @ Statement::CreateSink(_)
+        | stmt @ Statement::CreateView(_)
+        | stmt @ Statement::CreateMaterializedView(_)
+        | stmt @ Statement::CreateTable(_)
+        | stmt @ Statement::CreateIndex(_)
+        | stmt @ Statement::CreateType(_)
+        | stmt @ Statement::CreateSecret(_) => {
+            let mut visitor = CreateSqlRewriteDatabase {
+                cur_database,
+                new_database,
+            };
+            visitor.visit_statement_mut(stmt);
+        }
+        stmt => {
+            unreachable!("Internal error: only catalog items need to update item refs. {stmt:?}")
+        }
+    }
+}
+
+struct CreateSqlRewriteDatabase<'a> {
+    cur_database: &'a str,
+    new_database: &'a str,
+}
+
+impl<'a> CreateSqlRewriteDatabase<'a> {
+    fn maybe_rewrite_idents(&self, name: &mut [Ident]) {
+        // Only fully qualified names specify a database, so unlike schema renames, there are no
+        // ambiguous references to bail on.
+        if let [database, _schema, _item] = name {
+            if database.as_str() == self.cur_database {
+                *database = Ident::new_unchecked(self.new_database);
+            }
+        }
+    }
+}
+
+impl<'a, 'ast> VisitMut<'ast, Raw> for CreateSqlRewriteDatabase<'a> {
+    fn visit_expr_mut(&mut self, e: &'ast mut Expr<Raw>) {
+        match e {
+            Expr::Identifier(id) => {
+                // The last ID component is a column name that should not be
+                // considered in the rewrite.
+                let i = id.len() - 1;
+                self.maybe_rewrite_idents(&mut id[..i]);
+            }
+            Expr::QualifiedWildcard(id) => {
+                self.maybe_rewrite_idents(id);
+            }
+            _ => visit_mut::visit_expr_mut(self, e),
+        }
+    }
+
+    fn visit_unresolved_item_name_mut(
+        &mut self,
+        unresolved_item_name: &'ast mut UnresolvedItemName,
+    ) {
+        self.maybe_rewrite_idents(&mut unresolved_item_name.0);
+    }
+
+    fn visit_item_name_mut(
+        &mut self,
+        item_name: &'ast mut <mz_sql_parser::ast::Raw as AstInfo>::ItemName,
+    ) {
+        match item_name {
+            RawItemName::Name(n) | RawItemName::Id(_, n) => self.maybe_rewrite_idents(&mut n.0),
+        }
+    }
+}
+
 /// Changes the `name` used in an item's `CREATE` statement. To complete a
 /// rename operation, you must also call `create_stmt_rename_refs` on all dependent
 /// items.
@@ -160,6 +244,20 @@ pub fn create_stmt_rename(create_stmt: &mut Statement<Raw>, to_item_name: String
     }
 }
 
+/// Changes the cluster in which the item that an index's or materialized
+/// view's `CREATE` statement describes is maintained.
+pub fn create_stmt_set_cluster(create_stmt: &mut Statement<Raw>, cluster_id: ClusterId) {
+    match create_stmt {
+        Statement::CreateIndex(CreateIndexStatement { in_cluster, .. })
+        | Statement::CreateMaterializedView(CreateMaterializedViewStatement {
+            in_cluster, ..
+        }) => {
+            *in_cluster = Some(RawClusterName::Resolved(cluster_id.to_string()));
+        }
+        item => unreachable!("Internal error: only compute items have clusters {item:?}"),
+    }
+}
+
 /// Updates all references of `from_name` in `create_stmt` to `to_name` or
 /// errors if request is ambiguous.
 ///
@@ -26,6 +26,7 @@ use mz_controller_types::{ClusterId, ReplicaId};
 use mz_expr::MirScalarExpr;
 use mz_ore::now::{EpochMillis, NowFn};
 use mz_ore::str::StrExt;
+use mz_pgwire_common::scram::ScramVerifier;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem, PrivilegeMap};
 use mz_repr::explain::ExprHumanizer;
 use mz_repr::role_id::RoleId;
@@ -328,6 +329,13 @@ pub trait SessionCatalog: fmt::Debug + ExprHumanizer + Send + Sync + ConnectionR
 
     /// Returns the associated comments for the given `id`
     fn get_item_comments(&self, id: &GlobalId) -> Option<&BTreeMap<Option<usize>, String>>;
+
+    /// Returns the column masks for the given `id`, keyed by column name and then by the role
+    /// that each mask applies to.
+    fn get_item_column_masks(
+        &self,
+        id: &GlobalId,
+    ) -> Option<&BTreeMap<String, BTreeMap<RoleId, String>>>;
 }
 
 /// Configuration associated with a catalog.
@@ -412,6 +420,17 @@ pub trait CatalogSchema {
 pub struct RoleAttributes {
     /// Indicates whether the role has inheritance of privileges.
     pub inherit: bool,
+    /// The SCRAM-SHA-256 verifier of the role's password, if the role has a password. See
+    /// [`ScramVerifier`].
+    pub password_verifier: Option<String>,
+    /// The maximum number of concurrent connections of the role, if the number is limited.
+    pub connection_limit: Option<u32>,
+    /// The default `statement_timeout` of the role's sessions, if it differs from the system
+    /// default.
+    pub statement_timeout: Option<Duration>,
+    /// The time after which the role can no longer log in, in milliseconds since the Unix epoch,
+    /// if the role expires.
+    pub valid_until: Option<EpochMillis>,
     // Force use of constructor.
     _private: (),
 }
@@ -421,6 +440,10 @@ impl RoleAttributes {
     pub const fn new() -> RoleAttributes {
         RoleAttributes {
             inherit: true,
+            password_verifier: None,
+            connection_limit: None,
+            statement_timeout: None,
+            valid_until: None,
             _private: (),
         }
     }
@@ -435,15 +458,52 @@ impl RoleAttributes {
     pub const fn is_inherit(&self) -> bool {
         self.inherit
     }
+
+    /// Sets the role's password to `password`, or removes it if `password` is `None`. Only a
+    /// salted verifier of the password is stored.
+    pub fn set_password(&mut self, password: Option<&str>) {
+        self.password_verifier =
+            password.map(|password| ScramVerifier::generate(password).to_string());
+    }
+
+    /// Returns whether the role has expired as of `now`.
+    pub fn is_expired(&self, now: EpochMillis) -> bool {
+        self.valid_until
+            .map_or(false, |valid_until| valid_until <= now)
+    }
+
+    /// Updates the attributes that are specified in `planned`, leaving the others unchanged.
+    pub fn apply(&mut self, planned: PlannedRoleAttributes) {
+        let PlannedRoleAttributes {
+            inherit,
+            password,
+            connection_limit,
+            statement_timeout,
+            valid_until,
+        } = planned;
+        if let Some(inherit) = inherit {
+            self.inherit = inherit;
+        }
+        if let Some(password) = password {
+            self.set_password(password.as_deref());
+        }
+        if let Some(connection_limit) = connection_limit {
+            self.connection_limit = connection_limit;
+        }
+        if let Some(statement_timeout) = statement_timeout {
+            self.statement_timeout = statement_timeout;
+        }
+        if let Some(valid_until) = valid_until {
+            self.valid_until = valid_until;
+        }
+    }
 }
 
 impl From<PlannedRoleAttributes> for RoleAttributes {
-    fn from(PlannedRoleAttributes { inherit }: PlannedRoleAttributes) -> RoleAttributes {
-        let default_attributes = RoleAttributes::new();
-        RoleAttributes {
-            inherit: inherit.unwrap_or(default_attributes.inherit),
-            _private: (),
-        }
+    fn from(planned: PlannedRoleAttributes) -> RoleAttributes {
+        let mut attributes = RoleAttributes::new();
+        attributes.apply(planned);
+        attributes
     }
 }
 
@@ -1072,22 +1132,30 @@ impl Error for InvalidCloudProviderError {}
 pub enum CatalogError {
     /// Unknown database.
     UnknownDatabase(String),
-    /// Database already exists.
-    DatabaseAlreadyExists(String),
+    /// Database already exists. Describes the existing database, if known.
+    DatabaseAlreadyExists(String, Option<ExistingObject>),
     /// Unknown schema.
     UnknownSchema(String),
-    /// Schema already exists.
-    SchemaAlreadyExists(String),
+    /// Schema already exists. Describes the existing schema, if known.
+    SchemaAlreadyExists(String, Option<ExistingObject>),
     /// Unknown role.
     UnknownRole(String),
-    /// Role already exists.
-    RoleAlreadyExists(String),
+    /// Role already exists. Describes the existing role, if known.
+    RoleAlreadyExists(String, Option<ExistingObject>),
+    /// Unknown service account.
+    UnknownServiceAccount(String),
+    /// Service account already exists.
+    ServiceAccountAlreadyExists(String),
     /// Unknown cluster.
     UnknownCluster(String),
-    /// Cluster already exists.
-    ClusterAlreadyExists(String),
+    /// Cluster already exists. Describes the existing cluster, if known.
+    ClusterAlreadyExists(String, Option<ExistingObject>),
     /// Unknown cluster replica.
     UnknownClusterReplica(String),
+    /// Unknown cluster replica size.
+    UnknownClusterReplicaSize(String),
+    /// Cluster replica size already exists.
+    ClusterReplicaSizeAlreadyExists(String),
     /// Duplicate Replica. #[error("cannot create multiple replicas named '{0}' on cluster '{1}'")]
     DuplicateReplica(String, String),
     /// Unknown item.
@@ -1135,18 +1203,28 @@ impl fmt::Display for CatalogError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::UnknownDatabase(name) => write!(f, "unknown database '{}'", name),
-            Self::DatabaseAlreadyExists(name) => write!(f, "database '{name}' already exists"),
+            Self::DatabaseAlreadyExists(name, _) => write!(f, "database '{name}' already exists"),
             Self::UnknownFunction { name, .. } => write!(f, "function \"{}\" does not exist", name),
             Self::UnknownConnection(name) => write!(f, "connection \"{}\" does not exist", name),
             Self::UnknownSchema(name) => write!(f, "unknown schema '{}'", name),
-            Self::SchemaAlreadyExists(name) => write!(f, "schema '{name}' already exists"),
+            Self::SchemaAlreadyExists(name, _) => write!(f, "schema '{name}' already exists"),
             Self::UnknownRole(name) => write!(f, "unknown role '{}'", name),
-            Self::RoleAlreadyExists(name) => write!(f, "role '{name}' already exists"),
+            Self::RoleAlreadyExists(name, _) => write!(f, "role '{name}' already exists"),
+            Self::UnknownServiceAccount(name) => write!(f, "unknown service account '{name}'"),
+            Self::ServiceAccountAlreadyExists(name) => {
+                write!(f, "service account '{name}' already exists")
+            }
             Self::UnknownCluster(name) => write!(f, "unknown cluster '{}'", name),
-            Self::ClusterAlreadyExists(name) => write!(f, "cluster '{name}' already exists"),
+            Self::ClusterAlreadyExists(name, _) => write!(f, "cluster '{name}' already exists"),
             Self::UnknownClusterReplica(name) => {
                 write!(f, "unknown cluster replica '{}'", name)
             }
+            Self::UnknownClusterReplicaSize(name) => {
+                write!(f, "unknown cluster replica size '{name}'")
+            }
+            Self::ClusterReplicaSizeAlreadyExists(name) => {
+                write!(f, "cluster replica size '{name}' already exists")
+            }
             Self::DuplicateReplica(replica_name, cluster_name) => write!(f, "cannot create multiple replicas named '{replica_name}' on cluster '{cluster_name}'"),
             Self::UnknownItem(name) => write!(f, "unknown catalog item '{}'", name),
             Self::ItemAlreadyExists(_gid, name) => write!(f, "catalog item '{name}' already exists"),
@@ -1190,10 +1268,52 @@ impl CatalogError {
             _ => None,
         }
     }
+
+    /// Returns any applicable details for [`CatalogError`].
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            CatalogError::DatabaseAlreadyExists(_, Some(existing)) => {
+                Some(existing.describe("database"))
+            }
+            CatalogError::SchemaAlreadyExists(_, Some(existing)) => {
+                Some(existing.describe("schema"))
+            }
+            CatalogError::RoleAlreadyExists(_, Some(existing)) => Some(existing.describe("role")),
+            CatalogError::ClusterAlreadyExists(_, Some(existing)) => {
+                Some(existing.describe("cluster"))
+            }
+            _ => None,
+        }
+    }
 }
 
 impl Error for CatalogError {}
 
+/// The existing object that conflicts with an object that is being created or renamed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExistingObject {
+    /// The ID of the existing object.
+    pub id: String,
+    /// The name of the database that contains the existing object, if any.
+    pub database: Option<String>,
+    /// The name of the role that owns the existing object, if any.
+    pub owner: Option<String>,
+}
+
+impl ExistingObject {
+    fn describe(&self, object_type: &str) -> String {
+        let mut detail = format!("The existing {object_type} has ID {}", self.id);
+        if let Some(database) = &self.database {
+            detail.push_str(&format!(", is in database '{database}'"));
+        }
+        if let Some(owner) = &self.owner {
+            detail.push_str(&format!(", is owned by role '{owner}'"));
+        }
+        detail.push('.');
+        detail
+    }
+}
+
 /// Provides a method of generating a 3-layer catalog on the fly, and then
 /// resolving objects within it.
 pub(crate) struct ErsatzCatalog<'a, T>(
@@ -33,7 +33,9 @@ use crate::plan::expr::{
 };
 use crate::plan::query::{self, ExprContext, QueryContext};
 use crate::plan::scope::Scope;
-use crate::plan::side_effecting_func::PG_CATALOG_SEF_BUILTINS;
+use crate::plan::side_effecting_func::{
+    SideEffectingFuncImpl, MZ_INTERNAL_SEF_BUILTINS, PG_CATALOG_SEF_BUILTINS,
+};
 use crate::plan::transform_ast;
 use crate::plan::typeconv::{self, CastContext};
 use crate::session::vars::{self, ENABLE_TIME_AT_TIME_ZONE};
@@ -3373,12 +3375,24 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         }
     };
 
-    // Add side-effecting functions, which are defined in a separate module
-    // using a restricted set of function definition features (e.g., no
-    // overloads) to make them easier to plan.
-    for sef_builtin in PG_CATALOG_SEF_BUILTINS.values() {
+    insert_sef_builtins(&mut builtins, PG_CATALOG_SEF_BUILTINS.values());
+
+    builtins
+});
+
+/// Adds side-effecting functions to `builtins`.
+///
+/// Side-effecting functions are defined in a separate module using a restricted
+/// set of function definition features (e.g., no overloads) to make them easier
+/// to plan.
+fn insert_sef_builtins<'a>(
+    builtins: &mut BTreeMap<&'static str, Func>,
+    sef_builtins: impl Iterator<Item = &'a SideEffectingFuncImpl>,
+) {
+    for sef_builtin in sef_builtins {
+        let name = sef_builtin.name;
         builtins.insert(
-            sef_builtin.name,
+            name,
             Func::Scalar(vec![FuncImpl {
                 oid: sef_builtin.oid,
                 params: ParamList::Exact(
@@ -3391,15 +3405,13 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
                 return_type: ReturnType::scalar(ParamType::from(
                     sef_builtin.return_type.scalar_type.clone(),
                 )),
-                op: Operation::variadic(|_ecx, _e| {
-                    bail_unsupported!(format!("{} in this position", sef_builtin.name))
+                op: Operation::variadic(move |_ecx, _e| {
+                    bail_unsupported!(format!("{} in this position", name))
                 }),
             }]),
         );
     }
-
-    builtins
-});
+}
 
 pub static INFORMATION_SCHEMA_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
     use ParamType::*;
@@ -3746,7 +3758,7 @@ pub static MZ_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
 pub static MZ_INTERNAL_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
     use ParamType::*;
     use ScalarBaseType::*;
-    builtins! {
+    let mut builtins = builtins! {
         "aclitem_grantor" => Scalar {
             params!(AclItem) => UnaryFunc::AclItemGrantor(func::AclItemGrantor) => Oid, oid::FUNC_ACL_ITEM_GRANTOR_OID;
         },
@@ -3968,7 +3980,11 @@ pub static MZ_INTERNAL_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(
         "mz_validate_role_privilege" => Scalar {
             params!(String) => UnaryFunc::MzValidateRolePrivilege(func::MzValidateRolePrivilege) => Bool, oid::FUNC_MZ_VALIDATE_ROLE_PRIVILEGE_OID;
         }
-    }
+    };
+
+    insert_sef_builtins(&mut builtins, MZ_INTERNAL_SEF_BUILTINS.values());
+
+    builtins
 });
 
 pub static MZ_UNSAFE_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
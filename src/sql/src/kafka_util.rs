@@ -16,9 +16,9 @@ use anyhow::bail;
 use mz_kafka_util::client::DEFAULT_FETCH_METADATA_TIMEOUT;
 use mz_ore::task;
 use mz_sql_parser::ast::display::AstDisplay;
-use mz_sql_parser::ast::{AstInfo, KafkaConfigOption, KafkaConfigOptionName};
+use mz_sql_parser::ast::{AstInfo, Ident, KafkaConfigOption, KafkaConfigOptionName};
 use mz_storage_types::connections::StringOrSecret;
-use mz_storage_types::sinks::KafkaSinkCompressionType;
+use mz_storage_types::sinks::{KafkaSinkCompressionType, KafkaSinkDeleteMode};
 use rdkafka::consumer::{BaseConsumer, Consumer, ConsumerContext};
 use rdkafka::{Offset, TopicPartitionList};
 use tokio::time::Duration;
@@ -46,8 +46,14 @@ pub fn validate_options_for_context<T: AstInfo>(
     for KafkaConfigOption { name, .. } in options {
         let limited_to_context = match name {
             CompressionType => Some(Sink),
+            DeadLetterTopic => Some(Sink),
+            DeleteMode => Some(Sink),
             GroupIdPrefix => None,
+            MaxBytesPerSecond => Some(Sink),
+            MaxMessagesPerSecond => Some(Sink),
             Topic => None,
+            TopicAllowlist => Some(Sink),
+            TopicFromColumn => Some(Sink),
             TopicMetadataRefreshIntervalMs => None,
             StartTimestamp => Some(Source),
             StartOffset => Some(Source),
@@ -55,6 +61,10 @@ pub fn validate_options_for_context<T: AstInfo>(
             ReplicationFactor => Some(Sink),
             RetentionBytes => Some(Sink),
             RetentionMs => Some(Sink),
+            ProgressTopic => Some(Sink),
+            ProgressTopicReplicationFactor => Some(Sink),
+            TransactionalIdPrefix => Some(Sink),
+            TransactionTimeoutMs => Some(Sink),
         };
         if limited_to_context.is_some() && limited_to_context != Some(context) {
             bail!(
@@ -78,15 +88,25 @@ generate_extracted_config!(
         KafkaSinkCompressionType,
         Default(KafkaSinkCompressionType::None)
     ),
+    (DeadLetterTopic, String),
+    (DeleteMode, KafkaSinkDeleteMode),
     (GroupIdPrefix, String),
+    (MaxBytesPerSecond, u32),
+    (MaxMessagesPerSecond, u32),
     (Topic, String),
+    (TopicAllowlist, Vec<String>),
+    (TopicFromColumn, Ident),
     (TopicMetadataRefreshIntervalMs, i32),
     (StartTimestamp, i64),
     (StartOffset, Vec<i64>),
     (PartitionCount, i32, Default(-1)),
     (ReplicationFactor, i32, Default(-1)),
     (RetentionBytes, i64),
-    (RetentionMs, i64)
+    (RetentionMs, i64),
+    (ProgressTopic, String),
+    (ProgressTopicReplicationFactor, i32, Default(-1)),
+    (TransactionalIdPrefix, String),
+    (TransactionTimeoutMs, i32)
 );
 
 impl TryFromValue<Value> for KafkaSinkCompressionType {
@@ -117,6 +137,32 @@ impl ImpliedValue for KafkaSinkCompressionType {
     }
 }
 
+impl TryFromValue<Value> for KafkaSinkDeleteMode {
+    fn try_from_value(v: Value) -> Result<Self, PlanError> {
+        match v {
+            Value::String(v) => match v.to_lowercase().as_str() {
+                "tombstone" => Ok(KafkaSinkDeleteMode::Tombstone),
+                "record" => Ok(KafkaSinkDeleteMode::Record),
+                "drop" => Ok(KafkaSinkDeleteMode::Drop),
+                // The caller will add context, resulting in an error like
+                // "invalid DELETE MODE: <bad-delete-mode>".
+                _ => sql_bail!("{}", v),
+            },
+            _ => sql_bail!("delete mode must be a string"),
+        }
+    }
+
+    fn name() -> String {
+        "Kafka sink delete mode".to_string()
+    }
+}
+
+impl ImpliedValue for KafkaSinkDeleteMode {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide a delete mode value")
+    }
+}
+
 /// The config options we expect to pass along when connecting to librdkafka.
 ///
 /// Note that these are meant to be disjoint from the options we permit being
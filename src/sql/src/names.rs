@@ -1736,6 +1736,12 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
                     .map(|r| self.fold_replica_definition(r))
                     .collect(),
             ),
+            ClusterSchedule(entries) => ClusterSchedule(
+                entries
+                    .into_iter()
+                    .map(|e| self.fold_cluster_schedule_definition(e))
+                    .collect(),
+            ),
             ConnectionKafkaBroker(broker) => ConnectionKafkaBroker(self.fold_kafka_broker(broker)),
         }
     }
@@ -272,9 +272,11 @@ pub fn create_statement(
             with_options: _,
             referenced_subsources: _,
             progress_subsource: _,
+            in_background,
         }) => {
             *name = allocate_name(name)?;
             *if_not_exists = false;
+            *in_background = false;
         }
 
         Statement::CreateSubsource(CreateSubsourceStatement {
@@ -33,11 +33,15 @@ use std::time::Duration;
 use chrono::{DateTime, Utc};
 use enum_kinds::EnumKind;
 use maplit::btreeset;
-use mz_controller_types::{ClusterId, ReplicaId};
+use mz_controller_types::{
+    ClusterId, ClusterPriority, ReplicaAvailabilityZoneConstraint, ReplicaId,
+};
 use mz_expr::{CollectionPlan, ColumnOrder, MirRelationExpr, MirScalarExpr, RowSetFinishing};
-use mz_ore::now::{self, NOW_ZERO};
+use mz_orchestrator::{CpuLimit, DiskLimit, MemoryLimit};
+use mz_ore::now::{self, EpochMillis, NOW_ZERO};
 use mz_pgcopy::CopyFormatParams;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem};
+use mz_repr::adt::numeric::Numeric;
 use mz_repr::explain::{ExplainConfig, ExplainFormat};
 use mz_repr::role_id::RoleId;
 use mz_repr::{ColumnName, Diff, GlobalId, RelationDesc, Row, ScalarType};
@@ -59,8 +63,8 @@ use crate::catalog::{
     RoleAttributes,
 };
 use crate::names::{
-    Aug, CommentObjectId, FullItemName, ObjectId, QualifiedItemName, ResolvedDatabaseSpecifier,
-    ResolvedIds, SchemaSpecifier, SystemObjectId,
+    Aug, CommentObjectId, DatabaseId, FullItemName, ObjectId, QualifiedItemName,
+    ResolvedDatabaseSpecifier, ResolvedIds, SchemaSpecifier, SystemObjectId,
 };
 
 pub(crate) mod error;
@@ -103,9 +107,11 @@ pub enum Plan {
     CreateRole(CreateRolePlan),
     CreateCluster(CreateClusterPlan),
     CreateClusterReplica(CreateClusterReplicaPlan),
+    CreateClusterReplicaSize(CreateClusterReplicaSizePlan),
     CreateSource(CreateSourcePlan),
     CreateSources(Vec<CreateSourcePlans>),
     CreateSecret(CreateSecretPlan),
+    CreateServiceAccount(CreateServiceAccountPlan),
     CreateSink(CreateSinkPlan),
     CreateTable(CreateTablePlan),
     CreateView(CreateViewPlan),
@@ -117,6 +123,9 @@ pub enum Plan {
     DiscardAll,
     DropObjects(DropObjectsPlan),
     DropOwned(DropOwnedPlan),
+    DropClusterReplicaSize(DropClusterReplicaSizePlan),
+    DropServiceAccount(DropServiceAccountPlan),
+    UndropObject(UndropObjectPlan),
     EmptyQuery,
     ShowAllVariables,
     ShowCreate(ShowCreatePlan),
@@ -135,6 +144,7 @@ pub enum Plan {
     ExplainPlan(ExplainPlanPlan),
     ExplainTimestamp(ExplainTimestampPlan),
     ExplainSinkSchema(ExplainSinkSchemaPlan),
+    ExplainCreateSource(ExplainCreateSourcePlan),
     Insert(InsertPlan),
     AlterCluster(AlterClusterPlan),
     AlterClusterSwap(AlterClusterSwapPlan),
@@ -142,6 +152,7 @@ pub enum Plan {
     AlterIndexSetOptions(AlterIndexSetOptionsPlan),
     AlterIndexResetOptions(AlterIndexResetOptionsPlan),
     AlterSetCluster(AlterSetClusterPlan),
+    AlterColumnMask(AlterColumnMaskPlan),
     AlterSink(AlterSinkPlan),
     AlterConnection(AlterConnectionPlan),
     AlterSource(AlterSourcePlan),
@@ -152,9 +163,11 @@ pub enum Plan {
         subsources: Vec<CreateSourcePlans>,
     },
     AlterClusterRename(AlterClusterRenamePlan),
+    AlterClusterReplica(AlterClusterReplicaPlan),
     AlterClusterReplicaRename(AlterClusterReplicaRenamePlan),
     AlterItemRename(AlterItemRenamePlan),
     AlterItemSwap(AlterItemSwapPlan),
+    AlterDatabaseRename(AlterDatabaseRenamePlan),
     AlterSchemaRename(AlterSchemaRenamePlan),
     AlterSchemaSwap(AlterSchemaSwapPlan),
     AlterSecret(AlterSecretPlan),
@@ -189,6 +202,12 @@ impl Plan {
             StatementKind::AlterCluster => {
                 vec![PlanKind::AlterNoop, PlanKind::AlterCluster]
             }
+            StatementKind::AlterClusterReplica => {
+                vec![PlanKind::AlterNoop, PlanKind::AlterClusterReplica]
+            }
+            StatementKind::AlterColumnMask => {
+                vec![PlanKind::AlterNoop, PlanKind::AlterColumnMask]
+            }
             StatementKind::AlterConnection => vec![PlanKind::AlterNoop, PlanKind::AlterConnection],
             StatementKind::AlterDefaultPrivileges => vec![PlanKind::AlterDefaultPrivileges],
             StatementKind::AlterIndex => vec![
@@ -201,6 +220,7 @@ impl Plan {
                     PlanKind::AlterClusterRename,
                     PlanKind::AlterClusterReplicaRename,
                     PlanKind::AlterItemRename,
+                    PlanKind::AlterDatabaseRename,
                     PlanKind::AlterSchemaRename,
                     PlanKind::AlterNoop,
                 ]
@@ -234,6 +254,7 @@ impl Plan {
             StatementKind::Copy => vec![PlanKind::CopyFrom, PlanKind::Select, PlanKind::Subscribe],
             StatementKind::CreateCluster => vec![PlanKind::CreateCluster],
             StatementKind::CreateClusterReplica => vec![PlanKind::CreateClusterReplica],
+            StatementKind::CreateClusterReplicaSize => vec![PlanKind::CreateClusterReplicaSize],
             StatementKind::CreateConnection => vec![PlanKind::CreateConnection],
             StatementKind::CreateDatabase => vec![PlanKind::CreateDatabase],
             StatementKind::CreateIndex => vec![PlanKind::CreateIndex],
@@ -241,6 +262,7 @@ impl Plan {
             StatementKind::CreateRole => vec![PlanKind::CreateRole],
             StatementKind::CreateSchema => vec![PlanKind::CreateSchema],
             StatementKind::CreateSecret => vec![PlanKind::CreateSecret],
+            StatementKind::CreateServiceAccount => vec![PlanKind::CreateServiceAccount],
             StatementKind::CreateSink => vec![PlanKind::CreateSink],
             StatementKind::CreateSource
             | StatementKind::CreateSubsource
@@ -256,10 +278,14 @@ impl Plan {
             StatementKind::Discard => vec![PlanKind::DiscardAll, PlanKind::DiscardTemp],
             StatementKind::DropObjects => vec![PlanKind::DropObjects],
             StatementKind::DropOwned => vec![PlanKind::DropOwned],
+            StatementKind::DropClusterReplicaSize => vec![PlanKind::DropClusterReplicaSize],
+            StatementKind::DropServiceAccount => vec![PlanKind::DropServiceAccount],
+            StatementKind::UndropObject => vec![PlanKind::UndropObject],
             StatementKind::Execute => vec![PlanKind::Execute],
             StatementKind::ExplainPlan => vec![PlanKind::ExplainPlan],
             StatementKind::ExplainTimestamp => vec![PlanKind::ExplainTimestamp],
             StatementKind::ExplainSinkSchema => vec![PlanKind::ExplainSinkSchema],
+            StatementKind::ExplainCreateSource => vec![PlanKind::ExplainCreateSource],
             StatementKind::Fetch => vec![PlanKind::Fetch],
             StatementKind::GrantPrivileges => vec![PlanKind::GrantPrivileges],
             StatementKind::GrantRole => vec![PlanKind::GrantRole],
@@ -298,9 +324,11 @@ impl Plan {
             Plan::CreateRole(_) => "create role",
             Plan::CreateCluster(_) => "create cluster",
             Plan::CreateClusterReplica(_) => "create cluster replica",
+            Plan::CreateClusterReplicaSize(_) => "create cluster replica size",
             Plan::CreateSource(_) => "create source",
             Plan::CreateSources(_) => "create source",
             Plan::CreateSecret(_) => "create secret",
+            Plan::CreateServiceAccount(_) => "create service account",
             Plan::CreateSink(_) => "create sink",
             Plan::CreateTable(_) => "create table",
             Plan::CreateView(_) => "create view",
@@ -328,6 +356,9 @@ impl Plan {
                 ObjectType::Func => "drop function",
             },
             Plan::DropOwned(_) => "drop owned",
+            Plan::DropClusterReplicaSize(_) => "drop cluster replica size",
+            Plan::DropServiceAccount(_) => "drop service account",
+            Plan::UndropObject(_) => "undrop object",
             Plan::EmptyQuery => "do nothing",
             Plan::ShowAllVariables => "show all variables",
             Plan::ShowCreate(_) => "show create",
@@ -346,6 +377,7 @@ impl Plan {
             Plan::ExplainPlan(_) => "explain plan",
             Plan::ExplainTimestamp(_) => "explain timestamp",
             Plan::ExplainSinkSchema(_) => "explain schema",
+            Plan::ExplainCreateSource(_) => "explain create source",
             Plan::Insert(_) => "insert",
             Plan::AlterNoop(plan) => match plan.object_type {
                 ObjectType::Table => "alter table",
@@ -367,8 +399,10 @@ impl Plan {
             Plan::AlterCluster(_) => "alter cluster",
             Plan::AlterClusterRename(_) => "alter cluster rename",
             Plan::AlterClusterSwap(_) => "alter cluster swap",
+            Plan::AlterClusterReplica(_) => "alter cluster replica",
             Plan::AlterClusterReplicaRename(_) => "alter cluster replica rename",
             Plan::AlterSetCluster(_) => "alter set cluster",
+            Plan::AlterColumnMask(_) => "alter column mask",
             Plan::AlterIndexSetOptions(_) => "alter index",
             Plan::AlterIndexResetOptions(_) => "alter index",
             Plan::AlterSink(_) => "alter sink",
@@ -376,6 +410,7 @@ impl Plan {
             Plan::AlterSource(_) | Plan::PurifiedAlterSource { .. } => "alter source",
             Plan::AlterItemRename(_) => "rename item",
             Plan::AlterItemSwap(_) => "swap item",
+            Plan::AlterDatabaseRename(_) => "alter rename database",
             Plan::AlterSchemaRename(_) => "alter rename schema",
             Plan::AlterSchemaSwap(_) => "alter swap schema",
             Plan::AlterSecret(_) => "alter secret",
@@ -479,12 +514,27 @@ pub struct CreateRolePlan {
 pub struct CreateClusterPlan {
     pub name: String,
     pub variant: CreateClusterVariant,
+    pub priority: ClusterPriority,
+    /// The maximum amount of memory, in bytes, that the replicas of the
+    /// cluster may be provisioned with in total, if any.
+    pub memory_quota: Option<u64>,
 }
 
 #[derive(Debug)]
 pub enum CreateClusterVariant {
     Managed(CreateClusterManagedPlan),
     Unmanaged(CreateClusterUnmanagedPlan),
+    Like(CreateClusterLikePlan),
+}
+
+/// A plan for `CREATE CLUSTER ... LIKE`.
+#[derive(Debug)]
+pub struct CreateClusterLikePlan {
+    /// The managed cluster whose configuration is cloned.
+    pub cluster_id: ClusterId,
+    /// The indexes and materialized views of the cluster that are cloned into
+    /// the new cluster.
+    pub items: Vec<GlobalId>,
 }
 
 #[derive(Debug)]
@@ -499,6 +549,65 @@ pub struct CreateClusterManagedPlan {
     pub availability_zones: Vec<String>,
     pub compute: ComputeReplicaConfig,
     pub disk: bool,
+    pub schedule: Vec<ClusterScheduleEntry>,
+}
+
+/// An entry of the schedule of a managed cluster, which resizes the cluster
+/// every day at the entry's time.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, Ord, PartialEq, Eq)]
+pub struct ClusterScheduleEntry {
+    /// The time of day at which the entry takes effect, in minutes after
+    /// midnight UTC.
+    pub minute_of_day: u32,
+    /// The size to give the cluster, if the entry changes it.
+    pub size: Option<String>,
+    /// The replication factor to give the cluster, if the entry changes it.
+    pub replication_factor: Option<u32>,
+}
+
+impl ClusterScheduleEntry {
+    const MILLIS_PER_MINUTE: u64 = 60 * 1000;
+    const MINUTES_PER_DAY: u64 = 24 * 60;
+
+    /// Returns the entry of `schedule`, which must be sorted by time of day,
+    /// that is in effect at `now`: the last entry at or before the time of day
+    /// of `now`, or else the last entry of the previous day.
+    pub fn active(schedule: &[ClusterScheduleEntry], now: EpochMillis) -> Option<&Self> {
+        let minute_of_day = (now / Self::MILLIS_PER_MINUTE) % Self::MINUTES_PER_DAY;
+        schedule
+            .iter()
+            .rev()
+            .find(|entry| u64::from(entry.minute_of_day) <= minute_of_day)
+            .or_else(|| schedule.last())
+    }
+
+    /// Returns the entry of `schedule`, which must be sorted by time of day,
+    /// that is in effect at `now`, if it took effect after `since`.
+    pub fn newly_active(
+        schedule: &[ClusterScheduleEntry],
+        since: EpochMillis,
+        now: EpochMillis,
+    ) -> Option<&Self> {
+        let entry = Self::active(schedule, now)?;
+        let millis_per_day = Self::MINUTES_PER_DAY * Self::MILLIS_PER_MINUTE;
+        let mut took_effect_at =
+            now - now % millis_per_day + u64::from(entry.minute_of_day) * Self::MILLIS_PER_MINUTE;
+        if took_effect_at > now {
+            // The entry took effect on the previous day.
+            took_effect_at = took_effect_at.saturating_sub(millis_per_day);
+        }
+        (took_effect_at > since).then_some(entry)
+    }
+
+    /// Returns the time of day at which the entry takes effect, formatted as
+    /// `HH:MM`.
+    pub fn time_of_day(&self) -> String {
+        format!(
+            "{:02}:{:02}",
+            self.minute_of_day / 60,
+            self.minute_of_day % 60
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -508,6 +617,25 @@ pub struct CreateClusterReplicaPlan {
     pub config: ReplicaConfig,
 }
 
+#[derive(Debug)]
+pub struct CreateClusterReplicaSizePlan {
+    pub name: String,
+    /// The memory limit for each process of replicas of the size.
+    pub memory_limit: Option<MemoryLimit>,
+    /// The CPU limit for each process of replicas of the size.
+    pub cpu_limit: Option<CpuLimit>,
+    /// The disk limit for each process of replicas of the size.
+    pub disk_limit: Option<DiskLimit>,
+    /// The number of processes of replicas of the size.
+    pub scale: u16,
+    /// The number of worker threads of replicas of the size.
+    pub workers: usize,
+    /// The number of credits per hour that replicas of the size consume.
+    pub credits_per_hour: Numeric,
+    /// Whether new replicas of the size may be created.
+    pub disabled: bool,
+}
+
 /// Configuration of introspection for a cluster replica.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialOrd, Ord, PartialEq, Eq)]
 pub struct ComputeReplicaIntrospectionConfig {
@@ -536,6 +664,7 @@ pub enum ReplicaConfig {
     Managed {
         size: String,
         availability_zone: Option<String>,
+        availability_zone_constraints: Vec<ReplicaAvailabilityZoneConstraint>,
         compute: ComputeReplicaConfig,
         disk: bool,
         internal: bool,
@@ -625,6 +754,18 @@ pub struct CreateSecretPlan {
     pub if_not_exists: bool,
 }
 
+#[derive(Debug)]
+pub struct CreateServiceAccountPlan {
+    pub name: String,
+    /// The role that the service account acts as.
+    pub role_id: RoleId,
+    /// The secret that holds the service account's token.
+    pub token_secret_id: GlobalId,
+    pub scopes: Vec<String>,
+    /// The expiration time of the service account, in milliseconds since the Unix epoch, if any.
+    pub expires_at: Option<EpochMillis>,
+}
+
 #[derive(Debug)]
 pub struct CreateSinkPlan {
     pub name: QualifiedItemName,
@@ -706,6 +847,26 @@ pub struct DropOwnedPlan {
     pub default_privilege_revokes: Vec<(DefaultPrivilegeObject, DefaultPrivilegeAclItem)>,
 }
 
+#[derive(Debug)]
+pub struct DropClusterReplicaSizePlan {
+    pub name: String,
+    pub if_exists: bool,
+}
+
+#[derive(Debug)]
+pub struct DropServiceAccountPlan {
+    pub name: String,
+    pub if_exists: bool,
+}
+
+#[derive(Debug)]
+pub struct UndropObjectPlan {
+    /// The type of the object to restore.
+    pub object_type: ObjectType,
+    /// The name that the object had when it was dropped.
+    pub name: QualifiedItemName,
+}
+
 #[derive(Debug)]
 pub struct ShowVariablePlan {
     pub name: String,
@@ -957,6 +1118,13 @@ pub struct ExplainSinkSchemaPlan {
     pub json_schema: String,
 }
 
+#[derive(Debug)]
+pub struct ExplainCreateSourcePlan {
+    pub name: QualifiedItemName,
+    /// The purified statements that `CREATE SOURCE` would execute, in order.
+    pub statements: Vec<String>,
+}
+
 #[derive(Debug)]
 pub struct SendDiffsPlan {
     pub id: GlobalId,
@@ -995,6 +1163,27 @@ pub struct AlterSetClusterPlan {
     pub set_cluster: ClusterId,
 }
 
+#[derive(Debug)]
+pub struct AlterColumnMaskPlan {
+    pub id: GlobalId,
+    pub object_type: ObjectType,
+    pub column: ColumnName,
+    pub action: ColumnMaskAction,
+}
+
+/// A change to the masks of a single column.
+#[derive(Debug, Clone)]
+pub enum ColumnMaskAction {
+    /// Masks the column with the SQL expression `expr` for each of `role_ids`.
+    Set {
+        expr: String,
+        role_ids: BTreeSet<RoleId>,
+    },
+    /// Removes the masks of the column for each of `role_ids`, or for all roles if `role_ids` is
+    /// `None`.
+    Drop { role_ids: Option<BTreeSet<RoleId>> },
+}
+
 #[derive(Debug)]
 pub struct AlterIndexSetOptionsPlan {
     pub id: GlobalId,
@@ -1015,10 +1204,16 @@ pub enum AlterOptionParameter<T = String> {
     Unchanged,
 }
 
+#[derive(Debug)]
+pub enum AlterSinkAction {
+    Resize(AlterOptionParameter),
+    ChangeRelation { from: GlobalId },
+}
+
 #[derive(Debug)]
 pub struct AlterSinkPlan {
     pub id: GlobalId,
-    pub size: AlterOptionParameter,
+    pub action: AlterSinkAction,
 }
 
 #[derive(Debug)]
@@ -1070,6 +1265,14 @@ pub struct AlterClusterRenamePlan {
     pub to_name: String,
 }
 
+#[derive(Debug)]
+pub struct AlterClusterReplicaPlan {
+    pub cluster_id: ClusterId,
+    pub replica_id: ReplicaId,
+    pub name: QualifiedReplica,
+    pub draining: AlterOptionParameter<bool>,
+}
+
 #[derive(Debug)]
 pub struct AlterClusterReplicaRenamePlan {
     pub cluster_id: ClusterId,
@@ -1086,6 +1289,13 @@ pub struct AlterItemRenamePlan {
     pub object_type: ObjectType,
 }
 
+#[derive(Debug)]
+pub struct AlterDatabaseRenamePlan {
+    pub id: DatabaseId,
+    pub name: String,
+    pub to_name: String,
+}
+
 #[derive(Debug)]
 pub struct AlterSchemaRenamePlan {
     pub cur_schema_spec: (ResolvedDatabaseSpecifier, SchemaSpecifier),
@@ -1561,6 +1771,9 @@ pub struct PlanClusterOption {
     pub replication_factor: AlterOptionParameter<u32>,
     pub size: AlterOptionParameter,
     pub disk: AlterOptionParameter<bool>,
+    pub schedule: AlterOptionParameter<Vec<ClusterScheduleEntry>>,
+    pub priority: AlterOptionParameter<ClusterPriority>,
+    pub memory_quota: AlterOptionParameter<u64>,
 }
 
 impl Default for PlanClusterOption {
@@ -1575,6 +1788,9 @@ impl Default for PlanClusterOption {
             replication_factor: AlterOptionParameter::Unchanged,
             size: AlterOptionParameter::Unchanged,
             disk: AlterOptionParameter::Unchanged,
+            schedule: AlterOptionParameter::Unchanged,
+            priority: AlterOptionParameter::Unchanged,
+            memory_quota: AlterOptionParameter::Unchanged,
         }
     }
 }
@@ -1616,3 +1832,54 @@ impl PlanContext {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ClusterScheduleEntry;
+
+    #[mz_ore::test]
+    fn test_cluster_schedule_newly_active() {
+        const MINUTE: u64 = 60 * 1000;
+        const DAY: u64 = 24 * 60 * MINUTE;
+        let entry = |minute_of_day, size: &str| ClusterScheduleEntry {
+            minute_of_day,
+            size: Some(size.into()),
+            replication_factor: None,
+        };
+        // Scale up at 08:00 and down at 20:00.
+        let schedule = vec![entry(8 * 60, "large"), entry(20 * 60, "small")];
+        let at = |day: u64, minute_of_day: u64| day * DAY + minute_of_day * MINUTE;
+
+        // Entries take effect once, on the first check after their time.
+        assert_eq!(
+            ClusterScheduleEntry::newly_active(&schedule, at(1, 7 * 60 + 59), at(1, 8 * 60)),
+            Some(&schedule[0])
+        );
+        assert_eq!(
+            ClusterScheduleEntry::newly_active(&schedule, at(1, 20 * 60 - 1), at(1, 20 * 60 + 1)),
+            Some(&schedule[1])
+        );
+
+        // Later checks leave the cluster alone, so a manual change made after
+        // 08:00 lasts until 20:00, and one made after 20:00 until 08:00 the
+        // next day.
+        for minute_of_day in [8 * 60 + 1, 12 * 60, 20 * 60 - 1] {
+            assert_eq!(
+                ClusterScheduleEntry::newly_active(
+                    &schedule,
+                    at(1, minute_of_day - 1),
+                    at(1, minute_of_day)
+                ),
+                None
+            );
+        }
+        assert_eq!(
+            ClusterScheduleEntry::newly_active(&schedule, at(1, 23 * 60), at(2, 60)),
+            None
+        );
+        assert_eq!(
+            ClusterScheduleEntry::newly_active(&schedule, at(2, 7 * 60), at(2, 8 * 60 + 1)),
+            Some(&schedule[0])
+        );
+    }
+}
@@ -37,9 +37,11 @@ use crate::names::{PartialItemName, ResolvedItemName};
 use crate::plan::plan_utils::JoinSide;
 use crate::plan::scope::ScopeItem;
 use crate::pure::error::{
-    CsrPurificationError, KafkaSinkPurificationError, KafkaSourcePurificationError,
-    LoadGeneratorSourcePurificationError, PgSourcePurificationError,
-    TestScriptSourcePurificationError,
+    CsrPurificationError, EgressPolicyError, HttpSinkPurificationError,
+    IcebergSinkPurificationError, KafkaSinkPurificationError, KafkaSourcePurificationError,
+    LoadGeneratorSourcePurificationError, PgSinkPurificationError, PgSourcePurificationError,
+    RedisSinkPurificationError, S3SinkPurificationError, TestScriptSourcePurificationError,
+    TlsCertificateError,
 };
 use crate::session::vars::VarError;
 
@@ -221,12 +223,23 @@ pub enum PlanError {
         requested: Duration,
     },
     InvalidGroupSizeHints,
+    InvalidKafkaSinkHeaderColumnType {
+        column: String,
+        ty: String,
+    },
     PgSourcePurification(PgSourcePurificationError),
     KafkaSourcePurification(KafkaSourcePurificationError),
     KafkaSinkPurification(KafkaSinkPurificationError),
+    PgSinkPurification(PgSinkPurificationError),
+    S3SinkPurification(S3SinkPurificationError),
+    HttpSinkPurification(HttpSinkPurificationError),
+    RedisSinkPurification(RedisSinkPurificationError),
+    IcebergSinkPurification(IcebergSinkPurificationError),
     TestScriptSourcePurification(TestScriptSourcePurificationError),
     LoadGeneratorSourcePurification(LoadGeneratorSourcePurificationError),
     CsrPurification(CsrPurificationError),
+    EgressPolicy(EgressPolicyError),
+    TlsCertificate(TlsCertificateError),
     MissingName(CatalogItemType),
     // TODO(benesch): eventually all errors should be structured.
     Unstructured(String),
@@ -273,13 +286,25 @@ impl PlanError {
                 ))
             }
             Self::VarError(e) => e.detail(),
+            Self::Catalog(e) => e.detail(),
             Self::InternalFunctionCall => Some("This function is for the internal use of the database system and cannot be called directly.".into()),
+            Self::InvalidKafkaSinkHeaderColumnType { .. } => Some(
+                "Header values must be text or bytea. Cast the column in the sinked relation."
+                    .into(),
+            ),
             Self::PgSourcePurification(e) => e.detail(),
             Self::KafkaSourcePurification(e) => e.detail(),
             Self::TestScriptSourcePurification(e) => e.detail(),
             Self::LoadGeneratorSourcePurification(e) => e.detail(),
             Self::CsrPurification(e) => e.detail(),
+            Self::EgressPolicy(e) => e.detail(),
+            Self::TlsCertificate(e) => e.detail(),
             Self::KafkaSinkPurification(e) => e.detail(),
+            Self::PgSinkPurification(e) => e.detail(),
+            Self::S3SinkPurification(e) => e.detail(),
+            Self::HttpSinkPurification(e) => e.detail(),
+            Self::RedisSinkPurification(e) => e.detail(),
+            Self::IcebergSinkPurification(e) => e.detail(),
             _ => None,
         }
     }
@@ -356,7 +381,14 @@ impl PlanError {
             Self::TestScriptSourcePurification(e) => e.hint(),
             Self::LoadGeneratorSourcePurification(e) => e.hint(),
             Self::CsrPurification(e) => e.hint(),
+            Self::EgressPolicy(e) => e.hint(),
+            Self::TlsCertificate(e) => e.hint(),
             Self::KafkaSinkPurification(e) => e.hint(),
+            Self::PgSinkPurification(e) => e.hint(),
+            Self::S3SinkPurification(e) => e.hint(),
+            Self::HttpSinkPurification(e) => e.hint(),
+            Self::RedisSinkPurification(e) => e.hint(),
+            Self::IcebergSinkPurification(e) => e.hint(),
             Self::UnknownColumn { table, similar, .. } => {
                 let suffix = "Make sure to surround case sensitive names in double quotes.";
                 match &similar[..] {
@@ -587,12 +619,22 @@ impl fmt::Display for PlanError {
             Self::InvalidGroupSizeHints => f.write_str("EXPECTED GROUP SIZE cannot be provided \
                 simultaneously with any of AGGREGATE INPUT GROUP SIZE, DISTINCT ON INPUT GROUP SIZE, \
                 or LIMIT INPUT GROUP SIZE"),
+            Self::InvalidKafkaSinkHeaderColumnType { column, ty } => {
+                write!(f, "HEADERS FROM column {} has unsupported type {ty}", column.quoted())
+            }
             Self::PgSourcePurification(e) => write!(f, "POSTGRES source validation: {}", e),
             Self::KafkaSourcePurification(e) => write!(f, "KAFKA source validation: {}", e),
             Self::TestScriptSourcePurification(e) => write!(f, "TEST SCRIPT source validation: {}", e),
             Self::LoadGeneratorSourcePurification(e) => write!(f, "LOAD GENERATOR source validation: {}", e),
             Self::KafkaSinkPurification(e) => write!(f, "KAFKA sink validation: {}", e),
+            Self::PgSinkPurification(e) => write!(f, "POSTGRES sink validation: {}", e),
+            Self::S3SinkPurification(e) => write!(f, "S3 sink validation: {}", e),
+            Self::HttpSinkPurification(e) => write!(f, "HTTP sink validation: {}", e),
+            Self::RedisSinkPurification(e) => write!(f, "REDIS sink validation: {}", e),
+            Self::IcebergSinkPurification(e) => write!(f, "ICEBERG sink validation: {}", e),
             Self::CsrPurification(e) => write!(f, "CONFLUENT SCHEMA REGISTRY validation: {}", e),
+            Self::EgressPolicy(e) => write!(f, "egress policy validation: {}", e),
+            Self::TlsCertificate(e) => write!(f, "TLS certificate validation: {}", e),
             Self::MangedReplicaName(name) => {
                 write!(f, "{name} is reserved for replicas of managed clusters")
             }
@@ -714,12 +756,54 @@ impl From<KafkaSinkPurificationError> for PlanError {
     }
 }
 
+impl From<PgSinkPurificationError> for PlanError {
+    fn from(e: PgSinkPurificationError) -> Self {
+        PlanError::PgSinkPurification(e)
+    }
+}
+
+impl From<S3SinkPurificationError> for PlanError {
+    fn from(e: S3SinkPurificationError) -> Self {
+        PlanError::S3SinkPurification(e)
+    }
+}
+
+impl From<HttpSinkPurificationError> for PlanError {
+    fn from(e: HttpSinkPurificationError) -> Self {
+        PlanError::HttpSinkPurification(e)
+    }
+}
+
+impl From<RedisSinkPurificationError> for PlanError {
+    fn from(e: RedisSinkPurificationError) -> Self {
+        PlanError::RedisSinkPurification(e)
+    }
+}
+
+impl From<IcebergSinkPurificationError> for PlanError {
+    fn from(e: IcebergSinkPurificationError) -> Self {
+        PlanError::IcebergSinkPurification(e)
+    }
+}
+
 impl From<CsrPurificationError> for PlanError {
     fn from(e: CsrPurificationError) -> Self {
         PlanError::CsrPurification(e)
     }
 }
 
+impl From<EgressPolicyError> for PlanError {
+    fn from(e: EgressPolicyError) -> Self {
+        PlanError::EgressPolicy(e)
+    }
+}
+
+impl From<TlsCertificateError> for PlanError {
+    fn from(e: TlsCertificateError) -> Self {
+        PlanError::TlsCertificate(e)
+    }
+}
+
 impl From<TestScriptSourcePurificationError> for PlanError {
     fn from(e: TestScriptSourcePurificationError) -> Self {
         PlanError::TestScriptSourcePurification(e)
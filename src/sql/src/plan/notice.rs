@@ -37,6 +37,18 @@ pub enum PlanNotice {
         key: Vec<ColumnName>,
         name: String,
     },
+    TlsCertificateExpiring {
+        connection: String,
+        subject: String,
+        not_after: String,
+        expired: bool,
+    },
+    KafkaSinkTopicConfigMismatch {
+        topic: String,
+        setting: String,
+        expected: String,
+        actual: String,
+    },
 }
 
 impl PlanNotice {
@@ -64,6 +76,14 @@ impl PlanNotice {
             PlanNotice::UpsertSinkKeyNotEnforced { .. } => {
                 Some("See: https://materialize.com/s/sink-key-selection".into())
             }
+            PlanNotice::TlsCertificateExpiring { .. } => {
+                Some("Replace the certificate using ALTER CONNECTION.".into())
+            }
+            PlanNotice::KafkaSinkTopicConfigMismatch { .. } => Some(
+                "Sinks do not alter the configuration of existing topics. \
+                Omit the option or change the topic's configuration to match."
+                    .into(),
+            ),
             _ => None,
         }
     }
@@ -83,6 +103,30 @@ impl fmt::Display for PlanNotice {
             PlanNotice::UpsertSinkKeyNotEnforced { .. } => {
                 write!(f, "upsert key not validated to be unique")
             }
+            PlanNotice::TlsCertificateExpiring {
+                connection,
+                subject,
+                not_after,
+                expired,
+            } => {
+                let verb = if *expired { "expired" } else { "expires" };
+                write!(
+                    f,
+                    "TLS certificate {subject} of {connection} {verb} at {not_after}"
+                )
+            }
+            PlanNotice::KafkaSinkTopicConfigMismatch {
+                topic,
+                setting,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "topic {} has {setting} {actual}, but the sink specifies {expected}",
+                    topic.quoted()
+                )
+            }
         }
     }
 }
@@ -602,11 +602,18 @@ pub fn plan_mutation_query_inner(
             table_name.full_name_str()
         );
     }
+    // Mutations rewrite entire rows, so they must not observe masked values.
+    let desc = item.desc(&qcx.scx.catalog.resolve_full_name(item.name()))?;
+    if !qcx.column_masks(&id, &desc).is_empty() {
+        sql_bail!(
+            "cannot mutate table '{}' with masked columns",
+            table_name.full_name_str()
+        );
+    }
 
     // Derive structs for operation from validated table
     let (mut get, scope) = qcx.resolve_table_name(table_name)?;
     let scope = plan_table_alias(scope, alias.as_ref())?;
-    let desc = item.desc(&qcx.scx.catalog.resolve_full_name(item.name()))?;
     let relation_type = qcx.relation_type(&get);
 
     if using.is_empty() {
@@ -1100,6 +1107,29 @@ pub fn plan_default_expr(
     Ok(hir)
 }
 
+/// Plans the masking expression `expr` of a column of type `target_ty` of the relation described
+/// by `desc`. The expression may refer to any of the relation's columns.
+pub fn plan_column_mask_expr(
+    scx: &StatementContext,
+    expr: &Expr<Aug>,
+    desc: &RelationDesc,
+    target_ty: &ScalarType,
+) -> Result<HirScalarExpr, PlanError> {
+    let qcx = QueryContext::root(scx, QueryLifetime::OneShot);
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "column mask",
+        scope: &Scope::from_source(None, desc.iter_names().cloned()),
+        relation_type: desc.typ(),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+    let hir = plan_expr(ecx, expr)?.cast_to(ecx, CastContext::Assignment, target_ty)?;
+    Ok(hir)
+}
+
 pub fn plan_params<'a>(
     scx: &'a StatementContext,
     params: Vec<Expr<Aug>>,
@@ -5988,6 +6018,15 @@ impl<'a> QueryContext<'a> {
                     id: Id::Global(item.id()),
                     typ: desc.typ().clone(),
                 };
+                let expr = self.mask_columns(&id, &desc, expr)?;
+                if let Some(masked_id) = self.masked_dependency(&id) {
+                    let masked = self.scx.get_item(&masked_id);
+                    sql_bail!(
+                        "cannot read {} because it depends on {}, which has masked columns",
+                        self.scx.catalog.resolve_full_name(item.name()),
+                        self.scx.catalog.resolve_full_name(masked.name())
+                    );
+                }
 
                 let scope = Scope::from_source(Some(name), desc.iter_names().cloned());
 
@@ -6009,6 +6048,109 @@ impl<'a> QueryContext<'a> {
         }
     }
 
+    /// Returns the masking expressions that apply to the active role for the columns of the item
+    /// `id`, which is described by `desc`, keyed by column index.
+    ///
+    /// If several of the roles that the active role is a member of have a mask for a column, the
+    /// mask of the role with the smallest ID applies.
+    fn column_masks<'b>(&'b self, id: &GlobalId, desc: &RelationDesc) -> BTreeMap<usize, &'b str> {
+        let Some(item_masks) = self.scx.catalog.get_item_column_masks(id) else {
+            return BTreeMap::new();
+        };
+        let role_ids = self
+            .scx
+            .catalog
+            .collect_role_membership(self.scx.catalog.active_role_id());
+        desc.iter_names()
+            .enumerate()
+            .filter_map(|(i, name)| {
+                item_masks
+                    .get(name.as_str())?
+                    .iter()
+                    .find(|(role_id, _)| role_ids.contains(role_id))
+                    .map(|(_, mask)| (i, mask.as_str()))
+            })
+            .collect()
+    }
+
+    /// Returns whether any column of the item `id` is masked for the active role.
+    fn has_column_masks(&self, id: &GlobalId) -> bool {
+        let Some(item_masks) = self.scx.catalog.get_item_column_masks(id) else {
+            return false;
+        };
+        let role_ids = self
+            .scx
+            .catalog
+            .collect_role_membership(self.scx.catalog.active_role_id());
+        item_masks
+            .values()
+            .any(|masks| masks.keys().any(|role_id| role_ids.contains(role_id)))
+    }
+
+    /// Returns an item that the item `id` reads from, directly or through other items, and whose
+    /// columns are masked for the active role, if there is one.
+    ///
+    /// Masks are not applied to the definitions of views and materialized views, which are planned
+    /// once on behalf of their owner, so roles with masks must not read masked items through them.
+    fn masked_dependency(&self, id: &GlobalId) -> Option<GlobalId> {
+        // System items cannot be masked and cannot depend on user items.
+        if id.is_system() {
+            return None;
+        }
+        let mut seen = BTreeSet::new();
+        let mut stack: Vec<_> = self.scx.get_item(id).uses().into_iter().collect();
+        while let Some(dep) = stack.pop() {
+            if dep == *id || dep.is_system() || !seen.insert(dep) {
+                continue;
+            }
+            if self.has_column_masks(&dep) {
+                return Some(dep);
+            }
+            stack.extend(self.scx.get_item(&dep).uses());
+        }
+        None
+    }
+
+    /// Replaces the columns of `expr`, which reads the item `id` described by `desc`, with their
+    /// masking expressions for the active role.
+    ///
+    /// Masks are only applied to queries that are planned on behalf of the active role. Queries
+    /// that are maintained, like views, are planned again on startup by a different role, so they
+    /// are not allowed to read masked columns at all.
+    fn mask_columns(
+        &self,
+        id: &GlobalId,
+        desc: &RelationDesc,
+        expr: HirRelationExpr,
+    ) -> Result<HirRelationExpr, PlanError> {
+        let masks = self.column_masks(id, desc);
+        if masks.is_empty() {
+            return Ok(expr);
+        }
+        if !matches!(
+            self.lifetime,
+            QueryLifetime::OneShot | QueryLifetime::Subscribe
+        ) {
+            let item = self.scx.get_item(id);
+            sql_bail!(
+                "cannot reference {} in a maintained object because it has masked columns",
+                self.scx.catalog.resolve_full_name(item.name())
+            );
+        }
+
+        let arity = desc.arity();
+        let mut projection: Vec<_> = (0..arity).collect();
+        let mut scalars = Vec::with_capacity(masks.len());
+        for (i, mask) in masks {
+            let mask = mz_sql_parser::parser::parse_expr(mask)?;
+            let (mask, _) = crate::names::resolve(self.scx.catalog, mask)?;
+            let typ = &desc.typ().column_types[i].scalar_type;
+            scalars.push(plan_column_mask_expr(self.scx, &mask, desc, typ)?);
+            projection[i] = arity + scalars.len() - 1;
+        }
+        Ok(expr.map(scalars).project(projection))
+    }
+
     pub fn humanize_scalar_type(&self, typ: &ScalarType) -> String {
         self.scx.humanize_scalar_type(typ)
     }
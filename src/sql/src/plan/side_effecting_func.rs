@@ -35,6 +35,7 @@ use enum_kinds::EnumKind;
 use mz_ore::cast::ReinterpretCast;
 use mz_ore::collections::CollectionExt;
 use mz_ore::result::ResultExt;
+use mz_pgrepr::oid;
 use mz_repr::RelationType;
 use mz_repr::{ColumnType, Datum, RelationDesc, RowArena, ScalarType};
 use mz_sql_parser::ast::{CteBlock, Expr, Function, FunctionArgs, Select, SelectItem, SetExpr};
@@ -62,6 +63,11 @@ pub enum SideEffectingFunc {
         // The ID of the connection to cancel.
         connection_id: u32,
     },
+    /// The `mz_cancel_background_purification` function.
+    CancelBackgroundPurification {
+        // The ID of the background purification to cancel.
+        id: u64,
+    },
 }
 
 /// Describes a `SELECT` if it contains calls to side-effecting functions.
@@ -188,7 +194,10 @@ fn extract_sef_call(
     };
 
     // Check whether the implementation is a known side-effecting function.
-    let Some(sef_impl) = PG_CATALOG_SEF_BUILTINS.get(&func_impl.oid) else {
+    let Some(sef_impl) = PG_CATALOG_SEF_BUILTINS
+        .get(&func_impl.oid)
+        .or_else(|| MZ_INTERNAL_SEF_BUILTINS.get(&func_impl.oid))
+    else {
         return Ok(None);
     };
 
@@ -273,9 +282,19 @@ pub static PG_CATALOG_SEF_BUILTINS: Lazy<BTreeMap<u32, SideEffectingFuncImpl>> =
         .collect()
 });
 
+/// A map of the side-effecting functions in the `mz_internal` schema, keyed by
+/// OID.
+pub static MZ_INTERNAL_SEF_BUILTINS: Lazy<BTreeMap<u32, SideEffectingFuncImpl>> = Lazy::new(|| {
+    [MZ_CANCEL_BACKGROUND_PURIFICATION]
+        .into_iter()
+        .map(|f| (f.oid, f))
+        .collect()
+});
+
 // Implementations of each side-effecting function follow.
 //
-// If you add a new side-effecting function, be sure to add it to the map above.
+// If you add a new side-effecting function, be sure to add it to one of the
+// maps above.
 
 const PG_CANCEL_BACKEND: SideEffectingFuncImpl = SideEffectingFuncImpl {
     name: "pg_cancel_backend",
@@ -288,3 +307,15 @@ const PG_CANCEL_BACKEND: SideEffectingFuncImpl = SideEffectingFuncImpl {
         }
     },
 };
+
+const MZ_CANCEL_BACKGROUND_PURIFICATION: SideEffectingFuncImpl = SideEffectingFuncImpl {
+    name: "mz_cancel_background_purification",
+    oid: oid::FUNC_MZ_CANCEL_BACKGROUND_PURIFICATION_OID,
+    param_types: &[ScalarType::UInt64],
+    return_type: ScalarType::Bool.nullable(false),
+    plan_fn: |datums| -> SideEffectingFunc {
+        SideEffectingFunc::CancelBackgroundPurification {
+            id: datums[0].unwrap_uint64(),
+        }
+    },
+};
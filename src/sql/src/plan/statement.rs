@@ -118,6 +118,8 @@ pub fn describe(
     let desc = match stmt {
         // DDL statements.
         Statement::AlterCluster(stmt) => ddl::describe_alter_cluster_set_options(&scx, stmt)?,
+        Statement::AlterClusterReplica(stmt) => ddl::describe_alter_cluster_replica(&scx, stmt)?,
+        Statement::AlterColumnMask(stmt) => ddl::describe_alter_column_mask(&scx, stmt)?,
         Statement::AlterConnection(stmt) => ddl::describe_alter_connection(&scx, stmt)?,
         Statement::AlterIndex(stmt) => ddl::describe_alter_index_options(&scx, stmt)?,
         Statement::AlterObjectRename(stmt) => ddl::describe_alter_object_rename(&scx, stmt)?,
@@ -133,12 +135,16 @@ pub fn describe(
         Statement::Comment(stmt) => ddl::describe_comment(&scx, stmt)?,
         Statement::CreateCluster(stmt) => ddl::describe_create_cluster(&scx, stmt)?,
         Statement::CreateClusterReplica(stmt) => ddl::describe_create_cluster_replica(&scx, stmt)?,
+        Statement::CreateClusterReplicaSize(stmt) => {
+            ddl::describe_create_cluster_replica_size(&scx, stmt)?
+        }
         Statement::CreateConnection(stmt) => ddl::describe_create_connection(&scx, stmt)?,
         Statement::CreateDatabase(stmt) => ddl::describe_create_database(&scx, stmt)?,
         Statement::CreateIndex(stmt) => ddl::describe_create_index(&scx, stmt)?,
         Statement::CreateRole(stmt) => ddl::describe_create_role(&scx, stmt)?,
         Statement::CreateSchema(stmt) => ddl::describe_create_schema(&scx, stmt)?,
         Statement::CreateSecret(stmt) => ddl::describe_create_secret(&scx, stmt)?,
+        Statement::CreateServiceAccount(stmt) => ddl::describe_create_service_account(&scx, stmt)?,
         Statement::CreateSink(stmt) => ddl::describe_create_sink(&scx, stmt)?,
         Statement::CreateWebhookSource(stmt) => ddl::describe_create_webhook_source(&scx, stmt)?,
         Statement::CreateSource(stmt) => ddl::describe_create_source(&scx, stmt)?,
@@ -151,6 +157,11 @@ pub fn describe(
         }
         Statement::DropObjects(stmt) => ddl::describe_drop_objects(&scx, stmt)?,
         Statement::DropOwned(stmt) => ddl::describe_drop_owned(&scx, stmt)?,
+        Statement::DropClusterReplicaSize(stmt) => {
+            ddl::describe_drop_cluster_replica_size(&scx, stmt)?
+        }
+        Statement::DropServiceAccount(stmt) => ddl::describe_drop_service_account(&scx, stmt)?,
+        Statement::UndropObject(stmt) => ddl::describe_undrop_object(&scx, stmt)?,
 
         // `ACL` statements.
         Statement::AlterOwner(stmt) => acl::describe_alter_owner(&scx, stmt)?,
@@ -212,6 +223,7 @@ pub fn describe(
         Statement::ExplainPlan(stmt) => dml::describe_explain_plan(&scx, stmt)?,
         Statement::ExplainTimestamp(stmt) => dml::describe_explain_timestamp(&scx, stmt)?,
         Statement::ExplainSinkSchema(stmt) => dml::describe_explain_schema(&scx, stmt)?,
+        Statement::ExplainCreateSource(stmt) => dml::describe_explain_create_source(&scx, stmt)?,
         Statement::Insert(stmt) => dml::describe_insert(&scx, stmt)?,
         Statement::Select(stmt) => dml::describe_select(&scx, stmt)?,
         Statement::Subscribe(stmt) => dml::describe_subscribe(&scx, stmt)?,
@@ -294,6 +306,8 @@ pub fn plan(
     let plan = match stmt {
         // DDL statements.
         Statement::AlterCluster(stmt) => ddl::plan_alter_cluster(scx, stmt),
+        Statement::AlterClusterReplica(stmt) => ddl::plan_alter_cluster_replica(scx, stmt),
+        Statement::AlterColumnMask(stmt) => ddl::plan_alter_column_mask(scx, stmt),
         Statement::AlterConnection(stmt) => ddl::plan_alter_connection(scx, stmt),
         Statement::AlterIndex(stmt) => ddl::plan_alter_index_options(scx, stmt),
         Statement::AlterObjectRename(stmt) => ddl::plan_alter_object_rename(scx, stmt),
@@ -309,12 +323,16 @@ pub fn plan(
         Statement::Comment(stmt) => ddl::plan_comment(scx, stmt),
         Statement::CreateCluster(stmt) => ddl::plan_create_cluster(scx, stmt),
         Statement::CreateClusterReplica(stmt) => ddl::plan_create_cluster_replica(scx, stmt),
+        Statement::CreateClusterReplicaSize(stmt) => {
+            ddl::plan_create_cluster_replica_size(scx, stmt)
+        }
         Statement::CreateConnection(stmt) => ddl::plan_create_connection(scx, stmt),
         Statement::CreateDatabase(stmt) => ddl::plan_create_database(scx, stmt),
         Statement::CreateIndex(stmt) => ddl::plan_create_index(scx, stmt),
         Statement::CreateRole(stmt) => ddl::plan_create_role(scx, stmt),
         Statement::CreateSchema(stmt) => ddl::plan_create_schema(scx, stmt),
         Statement::CreateSecret(stmt) => ddl::plan_create_secret(scx, stmt),
+        Statement::CreateServiceAccount(stmt) => ddl::plan_create_service_account(scx, stmt),
         Statement::CreateSink(stmt) => ddl::plan_create_sink(scx, stmt),
         Statement::CreateWebhookSource(stmt) => ddl::plan_create_webhook_source(scx, stmt),
         Statement::CreateSource(stmt) => ddl::plan_create_source(scx, stmt),
@@ -327,6 +345,9 @@ pub fn plan(
         }
         Statement::DropObjects(stmt) => ddl::plan_drop_objects(scx, stmt),
         Statement::DropOwned(stmt) => ddl::plan_drop_owned(scx, stmt),
+        Statement::DropClusterReplicaSize(stmt) => ddl::plan_drop_cluster_replica_size(scx, stmt),
+        Statement::DropServiceAccount(stmt) => ddl::plan_drop_service_account(scx, stmt),
+        Statement::UndropObject(stmt) => ddl::plan_undrop_object(scx, stmt),
 
         // `ACL` statements.
         Statement::AlterOwner(stmt) => acl::plan_alter_owner(scx, stmt),
@@ -343,6 +364,7 @@ pub fn plan(
         Statement::ExplainPlan(stmt) => dml::plan_explain_plan(scx, stmt, params),
         Statement::ExplainTimestamp(stmt) => dml::plan_explain_timestamp(scx, stmt, params),
         Statement::ExplainSinkSchema(stmt) => dml::plan_explain_schema(scx, stmt),
+        Statement::ExplainCreateSource(stmt) => dml::plan_explain_create_source(scx, stmt),
         Statement::Insert(stmt) => dml::plan_insert(scx, stmt, params),
         Statement::Select(stmt) => dml::plan_select(scx, stmt, params, None),
         Statement::Subscribe(stmt) => dml::plan_subscribe(scx, stmt, params, None),
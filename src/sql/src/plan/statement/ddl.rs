@@ -17,41 +17,54 @@ use std::fmt::Write;
 use std::iter;
 use std::time::Duration;
 
+use chrono::{NaiveTime, Timelike};
 use itertools::{Either, Itertools};
-use mz_controller_types::{ClusterId, ReplicaId, DEFAULT_REPLICA_LOGGING_INTERVAL};
+use mz_controller_types::{
+    ClusterId, ClusterPriority, ReplicaAvailabilityZoneConstraint, ReplicaId,
+    DEFAULT_REPLICA_LOGGING_INTERVAL,
+};
 use mz_expr::{CollectionPlan, UnmaterializableFunc};
 use mz_interchange::avro::{AvroSchemaGenerator, AvroSchemaOptions, DocTarget};
+use mz_interchange::grok::{self, GrokPattern};
 use mz_ore::cast::{CastFrom, TryCastFrom};
-use mz_ore::collections::HashSet;
+use mz_ore::collections::{CollectionExt, HashSet};
+use mz_ore::now::EpochMillis;
 use mz_ore::str::StrExt;
 use mz_proto::RustType;
 use mz_repr::adt::mz_acl_item::{MzAclItem, PrivilegeMap};
+use mz_repr::adt::numeric::Numeric;
 use mz_repr::adt::system::Oid;
 use mz_repr::role_id::RoleId;
 use mz_repr::{strconv, ColumnName, ColumnType, GlobalId, RelationDesc, RelationType, ScalarType};
 use mz_sql_parser::ast::display::comma_separated;
 use mz_sql_parser::ast::{
-    AlterClusterAction, AlterClusterStatement, AlterConnectionAction, AlterConnectionOption,
-    AlterConnectionOptionName, AlterRoleOption, AlterRoleStatement, AlterSetClusterStatement,
-    AlterSinkAction, AlterSinkStatement, AlterSourceAction, AlterSourceAddSubsourceOption,
-    AlterSourceAddSubsourceOptionName, AlterSourceStatement, AlterSystemResetAllStatement,
-    AlterSystemResetStatement, AlterSystemSetStatement, CommentObjectType, CommentStatement,
-    CreateConnectionOption, CreateConnectionOptionName, CreateConnectionType, CreateTypeListOption,
-    CreateTypeListOptionName, CreateTypeMapOption, CreateTypeMapOptionName, DeferredItemName,
-    DocOnIdentifier, DocOnSchema, DropOwnedStatement, MaterializedViewOption,
-    MaterializedViewOptionName, SetRoleVar, UnresolvedItemName, UnresolvedObjectName,
-    UnresolvedSchemaName, Value,
+    AlterClusterAction, AlterClusterStatement, AlterColumnMaskAction, AlterColumnMaskStatement,
+    AlterConnectionAction, AlterConnectionOption, AlterConnectionOptionName, AlterRoleOption,
+    AlterRoleStatement, AlterSetClusterStatement, AlterSinkAction, AlterSinkStatement,
+    AlterSourceAction, AlterSourceAddSubsourceOption, AlterSourceAddSubsourceOptionName,
+    AlterSourceStatement, AlterSystemResetAllStatement, AlterSystemResetStatement,
+    AlterSystemSetStatement, CommentObjectType, CommentStatement, ConnectionOption,
+    CreateConnectionOption, CreateConnectionOptionName, CreateConnectionType,
+    CreateServiceAccountStatement, CreateTypeListOption, CreateTypeListOptionName,
+    CreateTypeMapOption, CreateTypeMapOptionName, DeferredItemName, DocOnIdentifier, DocOnSchema,
+    DropOwnedStatement, DropServiceAccountStatement, MaterializedViewOption,
+    MaterializedViewOptionName, SetRoleVar, UndropObjectStatement, UnresolvedItemName,
+    UnresolvedObjectName, UnresolvedSchemaName, Value,
 };
 use mz_sql_parser::ident;
 use mz_storage_types::connections::inline::{ConnectionAccess, ReferencedConnection};
-use mz_storage_types::connections::Connection;
+use mz_storage_types::connections::{Connection, StringOrSecret};
 use mz_storage_types::sinks::{
-    KafkaSinkConnection, KafkaSinkConnectionRetention, KafkaSinkFormat, SinkEnvelope,
-    StorageSinkConnection,
+    HttpSinkConnection, IcebergCatalog, IcebergSinkConnection, KafkaSinkConnection,
+    KafkaSinkConnectionRetention, KafkaSinkDebeziumSource, KafkaSinkFormat, KafkaSinkHeaders,
+    KafkaSinkTopicRouting, PostgresSinkConnection, PostgresSinkWriteMode, RedisDataStructure,
+    RedisSinkConnection, S3SinkConnection, S3SinkFormat, SinkEnvelope, StorageSinkConnection,
+    S3_SINK_METADATA_COLUMNS,
 };
 use mz_storage_types::sources::encoding::{
-    included_column_desc, AvroEncoding, ColumnSpec, CsvEncoding, DataEncoding, DataEncodingInner,
-    ProtobufEncoding, RegexEncoding, SourceDataEncoding, SourceDataEncodingInner,
+    included_column_desc, AvroEncoding, CharacterEncoding, ColumnSpec, CsvEncoding, DataEncoding,
+    DataEncodingInner, GrokEncoding, ProtobufEncoding, RegexEncoding, SourceDataEncoding,
+    SourceDataEncodingInner, TextEncoding,
 };
 use mz_storage_types::sources::{
     GenericSourceConnection, KafkaMetadataKind, KafkaSourceConnection, KeyEnvelope, LoadGenerator,
@@ -60,12 +73,17 @@ use mz_storage_types::sources::{
     TestScriptSourceConnection, Timeline, UnplannedSourceEnvelope, UpsertStyle,
 };
 use prost::Message;
+use serde::de::IntoDeserializer;
+use serde::Deserialize;
 
 use crate::ast::display::AstDisplay;
 use crate::ast::{
-    AlterConnectionStatement, AlterIndexAction, AlterIndexStatement, AlterObjectRenameStatement,
-    AlterObjectSwapStatement, AlterSecretStatement, AvroSchema, AvroSchemaOption,
-    AvroSchemaOptionName, ClusterOption, ClusterOptionName, ColumnOption,
+    AlterClusterReplicaAction, AlterClusterReplicaOption, AlterClusterReplicaOptionName,
+    AlterClusterReplicaStatement, AlterConnectionStatement, AlterIndexAction, AlterIndexStatement,
+    AlterObjectRenameStatement, AlterObjectSwapStatement, AlterSecretStatement, AvroSchema,
+    AvroSchemaOption, AvroSchemaOptionName, ClusterOption, ClusterOptionName,
+    ClusterReplicaSizeOption, ClusterReplicaSizeOptionName, ClusterScheduleDefinition,
+    ColumnOption, CreateClusterLike, CreateClusterLikeObjects, CreateClusterReplicaSizeStatement,
     CreateClusterReplicaStatement, CreateClusterStatement, CreateConnectionStatement,
     CreateDatabaseStatement, CreateIndexStatement, CreateMaterializedViewStatement,
     CreateRoleStatement, CreateSchemaStatement, CreateSecretStatement, CreateSinkConnection,
@@ -75,11 +93,16 @@ use crate::ast::{
     CreateTableStatement, CreateTypeAs, CreateTypeStatement, CreateViewStatement,
     CreateWebhookSourceStatement, CsrConfigOption, CsrConfigOptionName, CsrConnection,
     CsrConnectionAvro, CsrConnectionProtobuf, CsrSeedProtobuf, CsvColumns, DbzMode,
-    DropObjectsStatement, Envelope, Expr, Format, Ident, IfExistsBehavior, IndexOption,
-    IndexOptionName, KafkaConfigOptionName, KeyConstraint, LoadGeneratorOption,
-    LoadGeneratorOptionName, PgConfigOption, PgConfigOptionName, ProtobufSchema, QualifiedReplica,
-    ReferencedSubsources, ReplicaDefinition, ReplicaOption, ReplicaOptionName, RoleAttribute,
+    DropClusterReplicaSizeStatement, DropObjectsStatement, Envelope, Expr, Format,
+    HttpSinkConfigOption, HttpSinkConfigOptionName, IcebergSinkConfigOption,
+    IcebergSinkConfigOptionName, Ident, IfExistsBehavior, IndexOption, IndexOptionName,
+    KafkaConfigOptionName, KafkaSinkKey, KeyConstraint, LoadGeneratorOption,
+    LoadGeneratorOptionName, PgConfigOption, PgConfigOptionName, PgSinkConfigOption,
+    PgSinkConfigOptionName, ProtobufSchema, QualifiedReplica, RedisSinkConfigOption,
+    RedisSinkConfigOptionName, ReferencedSubsources, ReplicaDefinition, ReplicaOption,
+    ReplicaOptionName, RoleAttribute, S3SinkConfigOption, S3SinkConfigOptionName,
     SourceIncludeMetadata, Statement, TableConstraint, UnresolvedDatabaseName, ViewDefinition,
+    WithOptionValue,
 };
 use crate::catalog::{
     CatalogCluster, CatalogDatabase, CatalogError, CatalogItem, CatalogItemType,
@@ -89,34 +112,40 @@ use crate::kafka_util::{self, KafkaConfigOptionExtracted, KafkaStartOffsetType};
 use crate::names::{
     Aug, CommentObjectId, DatabaseId, ObjectId, PartialItemName, QualifiedItemName,
     RawDatabaseSpecifier, ResolvedClusterName, ResolvedColumnName, ResolvedDataType,
-    ResolvedDatabaseSpecifier, ResolvedItemName, SchemaSpecifier, SystemObjectId,
+    ResolvedDatabaseSpecifier, ResolvedItemName, ResolvedRoleName, SchemaSpecifier, SystemObjectId,
 };
 use crate::normalize::{self, ident};
 use crate::plan::error::PlanError;
 use crate::plan::expr::ColumnRef;
 use crate::plan::query::{scalar_type_from_catalog, ExprContext, QueryLifetime};
 use crate::plan::scope::Scope;
-use crate::plan::statement::ddl::connection::{INALTERABLE_OPTIONS, MUTUALLY_EXCLUSIVE_SETS};
+use crate::plan::statement::ddl::connection::{
+    INALTERABLE_OPTIONS, MUTUALLY_EXCLUSIVE_SETS, SECRET_OPTIONS,
+};
 use crate::plan::statement::{scl, StatementContext, StatementDesc};
 use crate::plan::typeconv::{plan_cast, CastContext};
 use crate::plan::with_options::{OptionalDuration, TryFromValue};
 use crate::plan::{
     plan_utils, query, transform_ast, AlterClusterPlan, AlterClusterRenamePlan,
-    AlterClusterReplicaRenamePlan, AlterClusterSwapPlan, AlterConnectionPlan,
-    AlterIndexResetOptionsPlan, AlterIndexSetOptionsPlan, AlterItemRenamePlan, AlterNoopPlan,
-    AlterOptionParameter, AlterRolePlan, AlterSchemaRenamePlan, AlterSchemaSwapPlan,
-    AlterSecretPlan, AlterSetClusterPlan, AlterSinkPlan, AlterSourcePlan, AlterSystemResetAllPlan,
-    AlterSystemResetPlan, AlterSystemSetPlan, CommentPlan, ComputeReplicaConfig,
-    ComputeReplicaIntrospectionConfig, CreateClusterManagedPlan, CreateClusterPlan,
-    CreateClusterReplicaPlan, CreateClusterUnmanagedPlan, CreateClusterVariant,
+    AlterClusterReplicaPlan, AlterClusterReplicaRenamePlan, AlterClusterSwapPlan,
+    AlterColumnMaskPlan, AlterConnectionPlan, AlterDatabaseRenamePlan, AlterIndexResetOptionsPlan,
+    AlterIndexSetOptionsPlan, AlterItemRenamePlan, AlterNoopPlan, AlterOptionParameter,
+    AlterRolePlan, AlterSchemaRenamePlan, AlterSchemaSwapPlan, AlterSecretPlan,
+    AlterSetClusterPlan, AlterSinkPlan, AlterSourcePlan, AlterSystemResetAllPlan,
+    AlterSystemResetPlan, AlterSystemSetPlan, ClusterScheduleEntry, ColumnMaskAction, CommentPlan,
+    ComputeReplicaConfig, ComputeReplicaIntrospectionConfig, CreateClusterLikePlan,
+    CreateClusterManagedPlan, CreateClusterPlan, CreateClusterReplicaPlan,
+    CreateClusterReplicaSizePlan, CreateClusterUnmanagedPlan, CreateClusterVariant,
     CreateConnectionPlan, CreateDatabasePlan, CreateIndexPlan, CreateMaterializedViewPlan,
-    CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan, CreateSourcePlan,
-    CreateTablePlan, CreateTypePlan, CreateViewPlan, DataSourceDesc, DropObjectsPlan,
-    DropOwnedPlan, FullItemName, HirScalarExpr, Index, Ingestion, MaterializedView, Params, Plan,
+    CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateServiceAccountPlan, CreateSinkPlan,
+    CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, DataSourceDesc,
+    DropClusterReplicaSizePlan, DropObjectsPlan, DropOwnedPlan, DropServiceAccountPlan,
+    FullItemName, HirScalarExpr, Index, Ingestion, MaterializedView, Params, Plan,
     PlanClusterOption, PlanNotice, QueryContext, ReplicaConfig, Secret, Sink, Source,
-    SourceSinkClusterConfig, Table, Type, VariableValue, View, WebhookHeaderFilters,
-    WebhookHeaders, WebhookValidation,
+    SourceSinkClusterConfig, Table, Type, UndropObjectPlan, VariableValue, View,
+    WebhookHeaderFilters, WebhookHeaders, WebhookValidation,
 };
+use crate::rbac;
 use crate::session::vars;
 
 mod connection;
@@ -429,7 +458,7 @@ pub fn plan_create_webhook_source(
     let body_scalar_type = match body_format {
         Format::Bytes => ScalarType::Bytes,
         Format::Json => ScalarType::Jsonb,
-        Format::Text => ScalarType::String,
+        Format::Text { .. } => ScalarType::String,
         // TODO(parkmycar): Make an issue to support more types, or change this to NeverSupported.
         ty => {
             return Err(PlanError::Unsupported {
@@ -548,8 +577,14 @@ pub fn plan_create_webhook_source(
 
 pub fn plan_create_source(
     scx: &StatementContext,
-    stmt: CreateSourceStatement<Aug>,
+    mut stmt: CreateSourceStatement<Aug>,
 ) -> Result<Plan, PlanError> {
+    let has_size = stmt
+        .with_options
+        .iter()
+        .any(|o| o.name == CreateSourceOptionName::Size);
+    default_source_sink_cluster(scx, &mut stmt.in_cluster, has_size)?;
+
     let CreateSourceStatement {
         name,
         in_cluster,
@@ -563,6 +598,7 @@ pub fn plan_create_source(
         with_options,
         referenced_subsources,
         progress_subsource,
+        in_background: _,
     } = &stmt;
 
     let envelope = envelope.clone().unwrap_or(Envelope::None);
@@ -675,7 +711,7 @@ pub fn plan_create_source(
             if !include_metadata.is_empty()
                 && !matches!(
                     envelope,
-                    Envelope::Upsert | Envelope::None | Envelope::Debezium(DbzMode::Plain)
+                    Envelope::Upsert | Envelope::None | Envelope::Debezium(_)
                 )
             {
                 // TODO(guswynn): should this be `bail_unsupported!`?
@@ -1123,6 +1159,7 @@ pub fn plan_create_source(
                 DbzMode::Plain => UnplannedSourceEnvelope::Upsert {
                     style: UpsertStyle::Debezium { after_idx },
                 },
+                DbzMode::Full => bail_unsupported!("ENVELOPE DEBEZIUM FULL sources"),
             }
         }
         mz_sql_parser::ast::Envelope::Upsert => {
@@ -1597,10 +1634,7 @@ fn get_encoding(
         && matches!(envelope, Envelope::None);
     let encoding = encoding.into_source_data_encoding(force_nullable_keys);
 
-    let requires_keyvalue = matches!(
-        envelope,
-        Envelope::Debezium(DbzMode::Plain) | Envelope::Upsert
-    );
+    let requires_keyvalue = matches!(envelope, Envelope::Debezium(_) | Envelope::Upsert);
     let is_keyvalue = matches!(encoding, SourceDataEncoding::KeyValue { .. });
     if requires_keyvalue && !is_keyvalue {
         sql_bail!("ENVELOPE [DEBEZIUM] UPSERT requires that KEY FORMAT be specified");
@@ -1609,6 +1643,30 @@ fn get_encoding(
     Ok(encoding)
 }
 
+/// Fills in the `IN CLUSTER` clause of a source or sink that specifies neither
+/// a cluster nor a size with the `default_source_sink_cluster` system variable,
+/// if it is set.
+///
+/// The clause is recorded in the statement, rather than only in the plan, so
+/// that the item remains in the same cluster if the variable later changes.
+fn default_source_sink_cluster(
+    scx: &StatementContext,
+    in_cluster: &mut Option<ResolvedClusterName>,
+    has_size: bool,
+) -> Result<(), PlanError> {
+    if in_cluster.is_some() || has_size {
+        return Ok(());
+    }
+    if let Some(name) = scx.catalog.system_vars().default_source_sink_cluster() {
+        let cluster = scx.catalog.resolve_cluster(Some(name))?;
+        *in_cluster = Some(ResolvedClusterName {
+            id: cluster.id(),
+            print_name: None,
+        });
+    }
+    Ok(())
+}
+
 fn source_sink_cluster_config(
     scx: &StatementContext,
     ty: &'static str,
@@ -1757,8 +1815,15 @@ fn get_encoding_inner(
                         }
                     };
 
-                    if !options.is_empty() {
-                        sql_bail!("Protobuf CSR connections do not support any options");
+                    // The schemas are fetched during purification, so the
+                    // only relevant option is the one that controls how.
+                    if options
+                        .iter()
+                        .any(|o| o.name != CsrConfigOptionName::IncludeDeletedSubjects)
+                    {
+                        sql_bail!(
+                            "Protobuf CSR connections only support the INCLUDE DELETED SUBJECTS option"
+                        );
                     }
 
                     let value = DataEncodingInner::Protobuf(ProtobufEncoding {
@@ -1798,7 +1863,12 @@ fn get_encoding_inner(
             regex: mz_repr::adt::regex::Regex::new(regex.clone(), false)
                 .map_err(|e| sql_err!("parsing regex: {e}"))?,
         }),
-        Format::Csv { columns, delimiter } => {
+        Format::Grok(pattern) => DataEncodingInner::Grok(plan_grok(pattern)?),
+        Format::Csv {
+            columns,
+            delimiter,
+            encoding,
+        } => {
             let columns = match columns {
                 CsvColumns::Header { names } => {
                     if names.is_empty() {
@@ -1814,13 +1884,43 @@ fn get_encoding_inner(
                 columns,
                 delimiter: u8::try_from(*delimiter)
                     .map_err(|_| sql_err!("CSV delimiter must be an ASCII character"))?,
+                encoding: plan_character_encoding(encoding.as_deref())?,
             })
         }
         Format::Json => DataEncodingInner::Json,
-        Format::Text => DataEncodingInner::Text,
+        Format::Text { encoding } => DataEncodingInner::Text(TextEncoding {
+            encoding: plan_character_encoding(encoding.as_deref())?,
+        }),
+        Format::Parquet => bail_unsupported!("FORMAT PARQUET for sources"),
     }))
 }
 
+/// Resolves the `ENCODING` option of a text-based format, which defaults to
+/// UTF-8.
+pub(crate) fn plan_character_encoding(
+    encoding: Option<&str>,
+) -> Result<CharacterEncoding, PlanError> {
+    match encoding {
+        None => Ok(CharacterEncoding::default()),
+        Some(name) => match CharacterEncoding::from_name(name) {
+            Some(encoding) => Ok(encoding),
+            None => sql_bail!(
+                "unsupported ENCODING {}; supported encodings are {}",
+                name.quoted(),
+                CharacterEncoding::SUPPORTED.join(", ")
+            ),
+        },
+    }
+}
+
+/// Expands a `GROK` pattern into the regular expression and columns it
+/// describes.
+pub(crate) fn plan_grok(pattern: &str) -> Result<GrokEncoding, PlanError> {
+    let GrokPattern { regex, desc } =
+        grok::compile(pattern).map_err(|e| sql_err!("invalid GROK pattern: {e}"))?;
+    Ok(GrokEncoding { regex, desc })
+}
+
 /// Extract the key envelope, if it is requested
 fn get_key_envelope(
     included_items: &[SourceIncludeMetadata],
@@ -1868,11 +1968,12 @@ fn get_unnamed_key_envelope(
         DataEncodingInner::RowCodec(_) => {
             sql_bail!("{} sources cannot use INCLUDE KEY", key.op_name())
         }
-        DataEncodingInner::Bytes | DataEncodingInner::Json | DataEncodingInner::Text => false,
+        DataEncodingInner::Bytes | DataEncodingInner::Json | DataEncodingInner::Text(_) => false,
         DataEncodingInner::Avro(_)
         | DataEncodingInner::Csv(_)
         | DataEncodingInner::Protobuf(_)
-        | DataEncodingInner::Regex { .. } => true,
+        | DataEncodingInner::Regex { .. }
+        | DataEncodingInner::Grok(_) => true,
     };
 
     if is_composite {
@@ -2187,10 +2288,86 @@ pub fn describe_create_sink(
 
 generate_extracted_config!(CreateSinkOption, (Size, String), (Snapshot, bool));
 
+/// Plans the `KEY` of a sink that reads from the relation `from` with
+/// description `desc`, returning the indices of the key's columns.
+///
+/// Upsert sinks require the key to be a unique key of `from`, unless the key is
+/// declared `NOT ENFORCED`.
+fn plan_sink_key(
+    scx: &StatementContext,
+    from: &ResolvedItemName,
+    desc: &RelationDesc,
+    key: &KafkaSinkKey,
+    envelope: SinkEnvelope,
+) -> Result<Vec<usize>, PlanError> {
+    let key_columns = key
+        .key_columns
+        .iter()
+        .cloned()
+        .map(normalize::column_name)
+        .collect::<Vec<_>>();
+    let mut uniq = BTreeSet::new();
+    for col in key_columns.iter() {
+        if !uniq.insert(col) {
+            sql_bail!("Repeated column name in sink key: {}", col);
+        }
+    }
+    let indices = key_columns
+        .iter()
+        .map(|col| -> Result<usize, PlanError> {
+            let name_idx = desc
+                .get_by_name(col)
+                .map(|(idx, _type)| idx)
+                .ok_or_else(|| sql_err!("No such column: {}", col))?;
+            if desc.get_unambiguous_name(name_idx).is_none() {
+                sql_bail!("Ambiguous column: {}", col);
+            }
+            Ok(name_idx)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let is_valid_key = desc
+        .typ()
+        .keys
+        .iter()
+        .any(|key_columns| key_columns.iter().all(|column| indices.contains(column)));
+
+    if !is_valid_key && envelope == SinkEnvelope::Upsert {
+        if key.not_enforced {
+            scx.catalog
+                .add_notice(PlanNotice::UpsertSinkKeyNotEnforced {
+                    key: key_columns.clone(),
+                    name: from.full_name_str(),
+                })
+        } else {
+            return Err(PlanError::UpsertSinkWithInvalidKey {
+                name: from.full_name_str(),
+                desired_key: key_columns.iter().map(|c| c.to_string()).collect(),
+                valid_keys: desc
+                    .typ()
+                    .keys
+                    .iter()
+                    .map(|key| {
+                        key.iter()
+                            .map(|col| desc.get_name(*col).as_str().into())
+                            .collect()
+                    })
+                    .collect(),
+            });
+        }
+    }
+    Ok(indices)
+}
+
 pub fn plan_create_sink(
     scx: &StatementContext,
-    stmt: CreateSinkStatement<Aug>,
+    mut stmt: CreateSinkStatement<Aug>,
 ) -> Result<Plan, PlanError> {
+    let has_size = stmt
+        .with_options
+        .iter()
+        .any(|o| o.name == CreateSinkOptionName::Size);
+    default_source_sink_cluster(scx, &mut stmt.in_cluster, has_size)?;
+
     let create_sql = normalize::create_statement(scx, Statement::CreateSink(stmt.clone()))?;
 
     let CreateSinkStatement {
@@ -2221,9 +2398,10 @@ pub fn plan_create_sink(
         )?;
     }
 
+    let debezium_full = matches!(envelope, Some(Envelope::Debezium(DbzMode::Full)));
     let envelope = match envelope {
         None => sql_bail!("ENVELOPE clause is required"),
-        Some(Envelope::Debezium(mz_sql_parser::ast::DbzMode::Plain)) => SinkEnvelope::Debezium,
+        Some(Envelope::Debezium(_)) => SinkEnvelope::Debezium,
         Some(Envelope::Upsert) => SinkEnvelope::Upsert,
         Some(Envelope::CdcV2) => bail_unsupported!("CDCv2 sinks"),
         Some(Envelope::None) => bail_unsupported!("\"ENVELOPE NONE\" sinks"),
@@ -2247,66 +2425,14 @@ pub fn plan_create_sink(
     let from = scx.get_item_by_resolved_name(&from)?;
     let desc = from.desc(&scx.catalog.resolve_full_name(from.name()))?;
     let key_indices = match &connection {
-        CreateSinkConnection::Kafka { key, .. } => {
-            if let Some(key) = key.clone() {
-                let key_columns = key
-                    .key_columns
-                    .into_iter()
-                    .map(normalize::column_name)
-                    .collect::<Vec<_>>();
-                let mut uniq = BTreeSet::new();
-                for col in key_columns.iter() {
-                    if !uniq.insert(col) {
-                        sql_bail!("Repeated column name in sink key: {}", col);
-                    }
-                }
-                let indices = key_columns
-                    .iter()
-                    .map(|col| -> anyhow::Result<usize> {
-                        let name_idx = desc
-                            .get_by_name(col)
-                            .map(|(idx, _type)| idx)
-                            .ok_or_else(|| sql_err!("No such column: {}", col))?;
-                        if desc.get_unambiguous_name(name_idx).is_none() {
-                            sql_err!("Ambiguous column: {}", col);
-                        }
-                        Ok(name_idx)
-                    })
-                    .collect::<Result<Vec<_>, _>>()?;
-                let is_valid_key =
-                    desc.typ().keys.iter().any(|key_columns| {
-                        key_columns.iter().all(|column| indices.contains(column))
-                    });
-
-                if !is_valid_key && envelope == SinkEnvelope::Upsert {
-                    if key.not_enforced {
-                        scx.catalog
-                            .add_notice(PlanNotice::UpsertSinkKeyNotEnforced {
-                                key: key_columns.clone(),
-                                name: name.item.clone(),
-                            })
-                    } else {
-                        return Err(PlanError::UpsertSinkWithInvalidKey {
-                            name: from_name.full_name_str(),
-                            desired_key: key_columns.iter().map(|c| c.to_string()).collect(),
-                            valid_keys: desc
-                                .typ()
-                                .keys
-                                .iter()
-                                .map(|key| {
-                                    key.iter()
-                                        .map(|col| desc.get_name(*col).as_str().into())
-                                        .collect()
-                                })
-                                .collect(),
-                        });
-                    }
-                }
-                Some(indices)
-            } else {
-                None
-            }
-        }
+        CreateSinkConnection::Kafka { key, .. }
+        | CreateSinkConnection::Postgres { key, .. }
+        | CreateSinkConnection::Http { key, .. }
+        | CreateSinkConnection::Redis { key, .. } => match key {
+            Some(key) => Some(plan_sink_key(scx, from_name, &desc, key, envelope)?),
+            None => None,
+        },
+        CreateSinkConnection::S3 { .. } | CreateSinkConnection::Iceberg { .. } => None,
     };
 
     // pick the first valid natural relation key, if any
@@ -2327,16 +2453,95 @@ pub fn plan_create_sink(
         return Err(PlanError::UpsertSinkWithoutKey);
     }
 
+    // The `source` block of the full Debezium envelope describes the sinked
+    // relation, as Debezium describes the upstream table.
+    let debezium_source = if debezium_full {
+        if !matches!(connection, CreateSinkConnection::Kafka { .. }) {
+            bail_unsupported!("ENVELOPE DEBEZIUM FULL for non-Kafka sinks");
+        }
+        let from_name = scx.catalog.resolve_full_name(from.name());
+        Some(KafkaSinkDebeziumSource {
+            name: full_name.to_string(),
+            db: match from_name.database {
+                RawDatabaseSpecifier::Name(db) => db,
+                RawDatabaseSpecifier::Ambient => String::new(),
+            },
+            schema: from_name.schema,
+            table: from_name.item,
+        })
+    } else {
+        None
+    };
+
     let connection_builder = match connection {
-        CreateSinkConnection::Kafka { connection, .. } => kafka_sink_builder(
+        CreateSinkConnection::Kafka {
+            connection,
+            headers,
+            ..
+        } => kafka_sink_builder(
             scx,
             connection,
+            headers,
             format,
             relation_key_indices,
             key_desc_and_indices,
             desc.into_owned(),
             envelope,
             from.id(),
+            debezium_source,
+        )?,
+        CreateSinkConnection::Postgres {
+            connection,
+            options,
+            ..
+        } => postgres_sink_builder(
+            scx,
+            connection,
+            options,
+            format,
+            relation_key_indices,
+            key_desc_and_indices,
+            desc.into_owned(),
+            envelope,
+        )?,
+        CreateSinkConnection::S3 {
+            connection,
+            options,
+        } => s3_sink_builder(
+            scx,
+            connection,
+            options,
+            format,
+            desc.into_owned(),
+            envelope,
+        )?,
+        CreateSinkConnection::Http { options, .. } => http_sink_builder(
+            scx,
+            options,
+            format,
+            relation_key_indices,
+            key_desc_and_indices,
+            desc.into_owned(),
+        )?,
+        CreateSinkConnection::Redis { options, .. } => redis_sink_builder(
+            scx,
+            options,
+            format,
+            relation_key_indices,
+            key_desc_and_indices,
+            desc.into_owned(),
+            envelope,
+        )?,
+        CreateSinkConnection::Iceberg {
+            connection,
+            options,
+        } => iceberg_sink_builder(
+            scx,
+            connection,
+            options,
+            format,
+            desc.into_owned(),
+            envelope,
         )?,
     };
 
@@ -2387,6 +2592,32 @@ fn key_constraint_err(desc: &RelationDesc, user_keys: &[ColumnName]) -> PlanErro
     )
 }
 
+/// How to handle enum symbols in decoded Avro data that are not present in
+/// the reader schema.
+#[derive(Debug, PartialEq, Clone)]
+pub enum UnknownEnumSymbolPolicy {
+    /// Fail to decode the record. This is the default.
+    Error,
+    /// Decode the unknown symbol as the named symbol, which must be present
+    /// in every enum in the reader schema.
+    Default(String),
+}
+
+impl TryFrom<Option<WithOptionValue<Aug>>> for UnknownEnumSymbolPolicy {
+    type Error = PlanError;
+    fn try_from(v: Option<WithOptionValue<Aug>>) -> Result<Self, PlanError> {
+        match v {
+            Some(WithOptionValue::Ident(ident)) if normalize::ident_ref(&ident) == "error" => {
+                Ok(UnknownEnumSymbolPolicy::Error)
+            }
+            Some(WithOptionValue::Value(Value::String(symbol))) => {
+                Ok(UnknownEnumSymbolPolicy::Default(symbol))
+            }
+            _ => sql_bail!("expected ERROR or a string literal symbol"),
+        }
+    }
+}
+
 /// Creating this by hand instead of using generate_extracted_config! macro
 /// because the macro doesn't support parameterized enums. See <https://github.com/MaterializeInc/materialize/issues/22213>
 #[derive(Debug, Default, PartialEq, Clone)]
@@ -2397,6 +2628,8 @@ pub struct CsrConfigOptionExtracted {
     pub(crate) null_defaults: bool,
     pub(crate) value_doc_options: BTreeMap<DocTarget, String>,
     pub(crate) key_doc_options: BTreeMap<DocTarget, String>,
+    pub(crate) include_deleted_subjects: bool,
+    pub(crate) unknown_enum_symbol: Option<UnknownEnumSymbolPolicy>,
 }
 
 impl std::convert::TryFrom<Vec<CsrConfigOption<Aug>>> for CsrConfigOptionExtracted {
@@ -2428,6 +2661,15 @@ impl std::convert::TryFrom<Vec<CsrConfigOption<Aug>>> for CsrConfigOptionExtract
                     extracted.null_defaults =
                         <bool>::try_from_value(option.value).map_err(better_error)?;
                 }
+                CsrConfigOptionName::IncludeDeletedSubjects => {
+                    extracted.include_deleted_subjects =
+                        <bool>::try_from_value(option.value).map_err(better_error)?;
+                }
+                CsrConfigOptionName::UnknownEnumSymbol => {
+                    extracted.unknown_enum_symbol = Some(
+                        UnknownEnumSymbolPolicy::try_from(option.value).map_err(better_error)?,
+                    );
+                }
                 CsrConfigOptionName::AvroDocOn(doc_on) => {
                     let value = String::try_from_value(option.value.ok_or_else(|| {
                         PlanError::InvalidOptionValue {
@@ -2484,12 +2726,14 @@ fn kafka_sink_builder(
         connection,
         options,
     }: mz_sql_parser::ast::KafkaConnection<Aug>,
+    headers: Option<mz_sql_parser::ast::KafkaSinkHeaders>,
     format: Option<Format<Aug>>,
     relation_key_indices: Option<Vec<usize>>,
     key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
     value_desc: RelationDesc,
     envelope: SinkEnvelope,
     sink_from: GlobalId,
+    debezium_source: Option<KafkaSinkDebeziumSource>,
 ) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
     // Get Kafka connection.
     let connection_item = scx.get_item_by_resolved_name(&connection)?;
@@ -2531,11 +2775,83 @@ fn kafka_sink_builder(
         compression_type,
         retention_ms,
         retention_bytes,
+        progress_topic,
+        progress_topic_replication_factor,
+        dead_letter_topic,
+        delete_mode,
+        transactional_id_prefix,
+        transaction_timeout_ms,
+        topic_from_column,
+        topic_allowlist,
+        max_messages_per_second,
+        max_bytes_per_second,
         ..
     } = extracted_options;
 
     let topic_name = topic.ok_or_else(|| sql_err!("KAFKA CONNECTION must specify TOPIC"))?;
 
+    let headers = match headers {
+        Some(headers) => {
+            scx.require_feature_flag(&vars::ENABLE_KAFKA_SINK_HEADERS)?;
+            Some(plan_kafka_sink_headers(scx, &value_desc, headers)?)
+        }
+        None => None,
+    };
+
+    // The type of the column is validated during purification.
+    let topic_routing = match topic_from_column {
+        Some(column) => {
+            let name = normalize::column_name(column);
+            let (index, _) = value_desc
+                .get_by_name(&name)
+                .ok_or_else(|| sql_err!("No such column: {}", name))?;
+            if value_desc.get_unambiguous_name(index).is_none() {
+                sql_bail!("Ambiguous column: {}", name);
+            }
+            // Deletions in the upsert envelope only carry their key.
+            let in_key = key_desc_and_indices
+                .as_ref()
+                .map_or(false, |(_desc, indices)| indices.contains(&index));
+            if envelope == SinkEnvelope::Upsert && !in_key {
+                sql_bail!(
+                    "TOPIC FROM COLUMN {} must be part of the KEY of an ENVELOPE UPSERT sink",
+                    name
+                );
+            }
+            if let Some(allowlist) = &topic_allowlist {
+                if allowlist.iter().any(|topic| topic.is_empty()) {
+                    sql_bail!("TOPIC ALLOWLIST must not contain empty topics");
+                }
+            }
+            Some(KafkaSinkTopicRouting {
+                index,
+                allowlist: topic_allowlist,
+            })
+        }
+        None if topic_allowlist.is_some() => {
+            sql_bail!("TOPIC ALLOWLIST requires TOPIC FROM COLUMN")
+        }
+        None => None,
+    };
+
+    // Columns that are only emitted as headers are not part of the value, and
+    // so not part of its schema either.
+    let value_desc = match &headers {
+        Some(headers) if headers.exclude_from_value => {
+            let (names, types): (Vec<_>, Vec<_>) = value_desc
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| !headers.indices.contains(idx))
+                .map(|(_, (name, ty))| (name.clone(), ty.clone()))
+                .unzip();
+            if names.is_empty() {
+                sql_bail!("HEADERS FROM ... EXCLUDE FROM VALUE cannot exclude every column");
+            }
+            RelationDesc::new(RelationType::new(types), names)
+        }
+        _ => value_desc,
+    };
+
     let format = match format {
         Some(Format::Avro(AvroSchema::Csr {
             csr_connection:
@@ -2579,9 +2895,19 @@ fn kafka_sink_builder(
                 null_defaults,
                 key_doc_options,
                 value_doc_options,
+                include_deleted_subjects,
+                unknown_enum_symbol,
                 ..
             } = options.try_into()?;
 
+            if include_deleted_subjects {
+                sql_bail!("INCLUDE DELETED SUBJECTS option does not make sense with sinks");
+            }
+
+            if unknown_enum_symbol.is_some() {
+                sql_bail!("UNKNOWN ENUM SYMBOL option does not make sense with sinks");
+            }
+
             if key_desc_and_indices.is_none() && avro_key_fullname.is_some() {
                 sql_bail!("Cannot specify AVRO KEY FULLNAME without a corresponding KEY field");
             }
@@ -2601,6 +2927,7 @@ fn kafka_sink_builder(
                 avro_value_fullname,
                 set_null_defaults: null_defaults,
                 is_debezium: matches!(envelope, SinkEnvelope::Debezium),
+                is_debezium_full: debezium_source.is_some(),
                 sink_from: Some(sink_from),
                 value_doc_options,
                 key_doc_options,
@@ -2625,6 +2952,54 @@ fn kafka_sink_builder(
             }
         }
         Some(Format::Json) => KafkaSinkFormat::Json,
+        Some(Format::Csv {
+            columns,
+            delimiter,
+            encoding,
+        }) => {
+            // A Debezium value nests the row under `before` and `after`, which
+            // has no flat CSV representation.
+            if envelope != SinkEnvelope::Upsert {
+                bail_unsupported!("FORMAT CSV for Kafka sinks with an envelope other than UPSERT");
+            }
+            if plan_character_encoding(encoding.as_deref())? != CharacterEncoding::default() {
+                bail_unsupported!("ENCODING for Kafka sinks");
+            }
+            // Keys are written as a record of the key columns with the same
+            // delimiter as values, and without a header, so that equal keys
+            // always encode to equal bytes.
+            let delimiter = u8::try_from(delimiter)
+                .map_err(|_| sql_err!("CSV delimiter must be an ASCII character"))?;
+            if matches!(delimiter, b'"' | b'\n' | b'\r') {
+                sql_bail!("CSV delimiter cannot be a double quote or a line break");
+            }
+            let arity = value_desc.arity();
+            let header = match columns {
+                CsvColumns::Count(n) => {
+                    if usize::cast_from(n) != arity {
+                        sql_bail!("CSV sink has {} columns, but {} were specified", arity, n);
+                    }
+                    None
+                }
+                CsvColumns::Header { names } if names.is_empty() => Some(
+                    value_desc
+                        .iter_names()
+                        .map(|name| name.to_string())
+                        .collect(),
+                ),
+                CsvColumns::Header { names } => {
+                    if names.len() != arity {
+                        sql_bail!(
+                            "CSV sink has {} columns, but the header names {}",
+                            arity,
+                            names.len()
+                        );
+                    }
+                    Some(names.into_iter().map(|n| n.into_string()).collect())
+                }
+            };
+            KafkaSinkFormat::Csv { delimiter, header }
+        }
         Some(format) => bail_unsupported!(format!("sink format {:?}", format)),
         None => bail_unsupported!("sink without format"),
     };
@@ -2649,6 +3024,54 @@ fn kafka_sink_builder(
         sql_bail!("RETENTION BYTES for sink topics must be greater than or equal to -1");
     }
 
+    match &progress_topic {
+        Some(progress_topic) if progress_topic.is_empty() => {
+            sql_bail!("PROGRESS TOPIC must not be empty")
+        }
+        Some(progress_topic) if *progress_topic == topic_name => {
+            sql_bail!("PROGRESS TOPIC must differ from TOPIC")
+        }
+        _ => (),
+    }
+
+    if progress_topic_replication_factor == 0 || progress_topic_replication_factor < -1 {
+        sql_bail!(
+            "PROGRESS TOPIC REPLICATION FACTOR must be a positive integer or -1 for broker default"
+        );
+    }
+
+    match &dead_letter_topic {
+        Some(dead_letter_topic) if dead_letter_topic.is_empty() => {
+            sql_bail!("DEAD LETTER TOPIC must not be empty")
+        }
+        Some(dead_letter_topic) if *dead_letter_topic == topic_name => {
+            sql_bail!("DEAD LETTER TOPIC must differ from TOPIC")
+        }
+        Some(dead_letter_topic) if Some(dead_letter_topic) == progress_topic.as_ref() => {
+            sql_bail!("DEAD LETTER TOPIC must differ from PROGRESS TOPIC")
+        }
+        _ => (),
+    }
+
+    if transactional_id_prefix.as_deref() == Some("") {
+        sql_bail!("TRANSACTIONAL ID PREFIX must not be empty");
+    }
+
+    let transaction_timeout = match transaction_timeout_ms {
+        Some(ms) => match u64::try_from(ms) {
+            Ok(ms) if ms > 0 => Some(Duration::from_millis(ms)),
+            _ => sql_bail!("TRANSACTION TIMEOUT MS must be a positive integer"),
+        },
+        None => None,
+    };
+
+    if max_messages_per_second == Some(0) {
+        sql_bail!("MAX MESSAGES PER SECOND must be a positive integer");
+    }
+    if max_bytes_per_second == Some(0) {
+        sql_bail!("MAX BYTES PER SECOND must be a positive integer");
+    }
+
     let retention = KafkaSinkConnectionRetention {
         duration: retention_ms,
         bytes: retention_bytes,
@@ -2667,53 +3090,613 @@ fn kafka_sink_builder(
         value_desc,
         retention,
         compression_type,
+        headers,
+        progress_topic,
+        progress_topic_replication_factor,
+        dead_letter_topic,
+        delete_mode,
+        debezium_source,
+        transactional_id_prefix,
+        transaction_timeout,
+        topic_routing,
+        max_messages_per_second,
+        max_bytes_per_second,
     }))
 }
 
-pub fn describe_create_index(
-    _: &StatementContext,
-    _: CreateIndexStatement<Aug>,
-) -> Result<StatementDesc, PlanError> {
-    Ok(StatementDesc::new(None))
+/// Resolves the `HEADERS FROM` columns of a Kafka sink against the sinked
+/// relation with description `desc`.
+///
+/// Purification already validates the columns, but the sink relies on every
+/// header column being text or bytea, so their types are checked again here.
+fn plan_kafka_sink_headers(
+    scx: &StatementContext,
+    desc: &RelationDesc,
+    headers: mz_sql_parser::ast::KafkaSinkHeaders,
+) -> Result<KafkaSinkHeaders, PlanError> {
+    let mut names = vec![];
+    let mut indices = vec![];
+    for column in headers.header_columns {
+        let name = normalize::column_name(column);
+        let (idx, ty) = desc
+            .get_by_name(&name)
+            .ok_or_else(|| sql_err!("No such column: {}", name))?;
+        if desc.get_unambiguous_name(idx).is_none() {
+            sql_bail!("Ambiguous column: {}", name);
+        }
+        if indices.contains(&idx) {
+            sql_bail!("Repeated column name in sink headers: {}", name);
+        }
+        if !matches!(
+            ty.scalar_type,
+            ScalarType::String
+                | ScalarType::Char { .. }
+                | ScalarType::VarChar { .. }
+                | ScalarType::Bytes
+        ) {
+            return Err(PlanError::InvalidKafkaSinkHeaderColumnType {
+                column: name.to_string(),
+                ty: scx.humanize_scalar_type(&ty.scalar_type),
+            });
+        }
+        names.push(name.to_string());
+        indices.push(idx);
+    }
+    Ok(KafkaSinkHeaders {
+        names,
+        indices,
+        exclude_from_value: headers.exclude_from_value,
+    })
 }
 
-pub fn plan_create_index(
-    scx: &StatementContext,
-    mut stmt: CreateIndexStatement<Aug>,
-) -> Result<Plan, PlanError> {
-    let CreateIndexStatement {
-        name,
-        on_name,
-        in_cluster,
-        key_parts,
-        with_options,
-        if_not_exists,
-    } = &mut stmt;
-    let on = scx.get_item_by_resolved_name(on_name)?;
+generate_extracted_config!(
+    PgSinkConfigOption,
+    (Table, String),
+    (CreateTable, bool, Default(false)),
+    (WriteMode, String, Default("upsert".to_string()))
+);
 
-    if CatalogItemType::View != on.item_type()
-        && CatalogItemType::MaterializedView != on.item_type()
-        && CatalogItemType::Source != on.item_type()
-        && CatalogItemType::Table != on.item_type()
-    {
-        sql_bail!(
-            "index cannot be created on {} because it is a {}",
-            on_name.full_name_str(),
-            on.item_type()
-        )
+/// Splits the `TABLE` option of a Postgres sink into its schema and table
+/// names, defaulting to the `public` schema.
+pub(crate) fn plan_pg_sink_table(table: Option<String>) -> Result<(String, String), PlanError> {
+    let table = table.ok_or_else(|| sql_err!("POSTGRES CONNECTION must specify TABLE"))?;
+    let parts: Vec<_> = table.split('.').collect();
+    match parts.as_slice() {
+        [table] if !table.is_empty() => Ok(("public".to_string(), table.to_string())),
+        [schema, table] if !schema.is_empty() && !table.is_empty() => {
+            Ok((schema.to_string(), table.to_string()))
+        }
+        _ => sql_bail!(
+            "invalid TABLE {}: must be of the form [schema.]table",
+            table.quoted()
+        ),
     }
+}
 
-    let on_desc = on.desc(&scx.catalog.resolve_full_name(on.name()))?;
+/// Resolves the `WRITE MODE` option of a Postgres sink.
+pub(crate) fn plan_pg_sink_write_mode(
+    write_mode: &str,
+) -> Result<PostgresSinkWriteMode, PlanError> {
+    match write_mode.to_lowercase().as_str() {
+        "upsert" => Ok(PostgresSinkWriteMode::Upsert),
+        "copy" => Ok(PostgresSinkWriteMode::Copy),
+        _ => sql_bail!(
+            "invalid WRITE MODE {}: must be UPSERT or COPY",
+            write_mode.quoted()
+        ),
+    }
+}
 
-    let filled_key_parts = match key_parts {
-        Some(kp) => kp.to_vec(),
-        None => {
-            // `key_parts` is None if we're creating a "default" index.
-            on.desc(&scx.catalog.resolve_full_name(on.name()))?
-                .typ()
-                .default_key()
-                .iter()
-                .map(|i| match on_desc.get_unambiguous_name(*i) {
+fn postgres_sink_builder(
+    scx: &StatementContext,
+    connection: ResolvedItemName,
+    options: Vec<PgSinkConfigOption<Aug>>,
+    format: Option<Format<Aug>>,
+    relation_key_indices: Option<Vec<usize>>,
+    key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    value_desc: RelationDesc,
+    envelope: SinkEnvelope,
+) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_POSTGRES_SINK)?;
+
+    let connection_item = scx.get_item_by_resolved_name(&connection)?;
+    let connection_id = connection_item.id();
+    match connection_item.connection()? {
+        Connection::Postgres(_) => (),
+        _ => sql_bail!(
+            "{} is not a postgres connection",
+            scx.catalog.resolve_full_name(connection_item.name())
+        ),
+    };
+
+    if format.is_some() {
+        sql_bail!("FORMAT cannot be specified for POSTGRES sinks");
+    }
+
+    if envelope != SinkEnvelope::Upsert {
+        bail_unsupported!("POSTGRES sinks with an envelope other than UPSERT");
+    }
+
+    let PgSinkConfigOptionExtracted {
+        table, write_mode, ..
+    } = options.try_into()?;
+    let (schema, table) = plan_pg_sink_table(table)?;
+    let write_mode = plan_pg_sink_write_mode(&write_mode)?;
+
+    Ok(StorageSinkConnection::Postgres(PostgresSinkConnection {
+        connection_id,
+        connection: connection_id,
+        schema,
+        table,
+        relation_key_indices,
+        key_desc_and_indices,
+        value_desc,
+        write_mode,
+    }))
+}
+
+generate_extracted_config!(
+    S3SinkConfigOption,
+    (Bucket, String),
+    (Prefix, String, Default(String::new())),
+    (MaxFileSize, u64, Default(DEFAULT_S3_SINK_MAX_FILE_SIZE)),
+    (
+        RotationInterval,
+        Duration,
+        Default(DEFAULT_S3_SINK_ROTATION_INTERVAL)
+    )
+);
+
+/// The default size, in bytes, at which an S3 sink starts a new file.
+const DEFAULT_S3_SINK_MAX_FILE_SIZE: u64 = 128 << 20;
+
+/// The default longest time an S3 sink buffers updates before writing a file.
+const DEFAULT_S3_SINK_ROTATION_INTERVAL: Duration = Duration::from_secs(600);
+
+fn s3_sink_builder(
+    scx: &StatementContext,
+    connection: ResolvedItemName,
+    options: Vec<S3SinkConfigOption<Aug>>,
+    format: Option<Format<Aug>>,
+    value_desc: RelationDesc,
+    envelope: SinkEnvelope,
+) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_S3_SINK)?;
+
+    let connection_item = scx.get_item_by_resolved_name(&connection)?;
+    let connection_id = connection_item.id();
+    match connection_item.connection()? {
+        Connection::Aws(_) => (),
+        _ => sql_bail!(
+            "{} is not an AWS connection",
+            scx.catalog.resolve_full_name(connection_item.name())
+        ),
+    };
+
+    if envelope != SinkEnvelope::Debezium {
+        bail_unsupported!("S3 sinks with an envelope other than DEBEZIUM");
+    }
+
+    let arity = value_desc.arity() + S3_SINK_METADATA_COLUMNS.len();
+    let format = match format {
+        Some(Format::Parquet) => S3SinkFormat::Parquet,
+        Some(Format::Csv {
+            columns,
+            delimiter,
+            encoding,
+        }) => {
+            if plan_character_encoding(encoding.as_deref())? != CharacterEncoding::default() {
+                bail_unsupported!("ENCODING for S3 sinks");
+            }
+            let header = match columns {
+                CsvColumns::Count(n) => {
+                    if usize::cast_from(n) != arity {
+                        sql_bail!("CSV sink has {} columns, but {} were specified", arity, n);
+                    }
+                    None
+                }
+                CsvColumns::Header { names } if names.is_empty() => Some(
+                    value_desc
+                        .iter_names()
+                        .map(|name| name.as_str())
+                        .chain(S3_SINK_METADATA_COLUMNS)
+                        .map(|name| name.to_string())
+                        .collect(),
+                ),
+                CsvColumns::Header { names } => {
+                    if names.len() != arity {
+                        sql_bail!(
+                            "CSV sink has {} columns, but the header names {}",
+                            arity,
+                            names.len()
+                        );
+                    }
+                    Some(names.into_iter().map(|n| n.into_string()).collect())
+                }
+            };
+            S3SinkFormat::Csv {
+                delimiter: u8::try_from(delimiter)
+                    .map_err(|_| sql_err!("CSV delimiter must be an ASCII character"))?,
+                header,
+            }
+        }
+        Some(format) => bail_unsupported!(format!("S3 sinks with FORMAT {}", format)),
+        None => sql_bail!("S3 sinks must specify FORMAT PARQUET or FORMAT CSV"),
+    };
+
+    let S3SinkConfigOptionExtracted {
+        bucket,
+        prefix,
+        max_file_size,
+        rotation_interval,
+        ..
+    } = options.try_into()?;
+    let bucket = bucket.ok_or_else(|| sql_err!("S3 CONNECTION must specify BUCKET"))?;
+    if max_file_size == 0 {
+        sql_bail!("MAX FILE SIZE must be greater than zero");
+    }
+    if rotation_interval.is_zero() {
+        sql_bail!("ROTATION INTERVAL must be greater than zero");
+    }
+
+    Ok(StorageSinkConnection::S3(S3SinkConnection {
+        connection_id,
+        connection: connection_id,
+        bucket,
+        prefix,
+        format,
+        max_file_size,
+        rotation_interval,
+        value_desc,
+    }))
+}
+
+/// The default maximum number of changes in each request of an HTTP sink.
+const DEFAULT_HTTP_SINK_BATCH_SIZE: u64 = 1000;
+
+/// The default number of times an HTTP sink retries a failed request.
+const DEFAULT_HTTP_SINK_MAX_RETRIES: u64 = 10;
+
+/// The default time an HTTP sink waits before retrying a failed request.
+const DEFAULT_HTTP_SINK_RETRY_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Creating this by hand instead of using generate_extracted_config! macro
+/// because the macro doesn't support parameterized enums. See <https://github.com/MaterializeInc/materialize/issues/22213>
+#[derive(Debug, PartialEq, Clone)]
+pub struct HttpSinkConfigOptionExtracted {
+    seen: BTreeSet<HttpSinkConfigOptionName>,
+    pub(crate) url: Option<StringOrSecret>,
+    pub(crate) headers: BTreeMap<String, StringOrSecret>,
+    pub(crate) batch_size: u64,
+    pub(crate) max_retries: u64,
+    pub(crate) retry_backoff: Duration,
+}
+
+impl Default for HttpSinkConfigOptionExtracted {
+    fn default() -> Self {
+        HttpSinkConfigOptionExtracted {
+            seen: BTreeSet::new(),
+            url: None,
+            headers: BTreeMap::new(),
+            batch_size: DEFAULT_HTTP_SINK_BATCH_SIZE,
+            max_retries: DEFAULT_HTTP_SINK_MAX_RETRIES,
+            retry_backoff: DEFAULT_HTTP_SINK_RETRY_BACKOFF,
+        }
+    }
+}
+
+impl std::convert::TryFrom<Vec<HttpSinkConfigOption<Aug>>> for HttpSinkConfigOptionExtracted {
+    type Error = crate::plan::PlanError;
+    fn try_from(
+        v: Vec<HttpSinkConfigOption<Aug>>,
+    ) -> Result<HttpSinkConfigOptionExtracted, Self::Error> {
+        let mut extracted = HttpSinkConfigOptionExtracted::default();
+        for option in v {
+            if !extracted.seen.insert(option.name.clone()) {
+                return Err(PlanError::Unstructured({
+                    format!("{} specified more than once", option.name)
+                }));
+            }
+            let option_name = option.name.clone();
+            let better_error = |e: PlanError| {
+                PlanError::Unstructured(format!("invalid {}: {}", option_name.to_ast_string(), e))
+            };
+            match option.name {
+                HttpSinkConfigOptionName::Url => {
+                    extracted.url = <Option<StringOrSecret>>::try_from_value(option.value)
+                        .map_err(better_error)?;
+                }
+                HttpSinkConfigOptionName::Header(name) => {
+                    if http::HeaderName::from_bytes(name.as_bytes()).is_err() {
+                        sql_bail!("invalid HEADER name {}", name.quoted());
+                    }
+                    // Header names are case insensitive.
+                    if extracted
+                        .headers
+                        .keys()
+                        .any(|other| other.eq_ignore_ascii_case(&name))
+                    {
+                        sql_bail!("HEADER {} specified more than once", name.quoted());
+                    }
+                    let value = <Option<StringOrSecret>>::try_from_value(option.value)
+                        .map_err(better_error)?
+                        .ok_or_else(|| sql_err!("HEADER {} must have a value", name.quoted()))?;
+                    extracted.headers.insert(name, value);
+                }
+                HttpSinkConfigOptionName::BatchSize => {
+                    extracted.batch_size =
+                        <u64>::try_from_value(option.value).map_err(better_error)?;
+                }
+                HttpSinkConfigOptionName::MaxRetries => {
+                    extracted.max_retries =
+                        <u64>::try_from_value(option.value).map_err(better_error)?;
+                }
+                HttpSinkConfigOptionName::RetryBackoff => {
+                    extracted.retry_backoff =
+                        <Duration>::try_from_value(option.value).map_err(better_error)?;
+                }
+            }
+        }
+        Ok(extracted)
+    }
+}
+
+fn http_sink_builder(
+    scx: &StatementContext,
+    options: Vec<HttpSinkConfigOption<Aug>>,
+    format: Option<Format<Aug>>,
+    relation_key_indices: Option<Vec<usize>>,
+    key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    value_desc: RelationDesc,
+) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_HTTP_SINK)?;
+
+    match format {
+        None | Some(Format::Json) => (),
+        Some(format) => bail_unsupported!(format!("HTTP sinks with FORMAT {}", format)),
+    }
+
+    let HttpSinkConfigOptionExtracted {
+        url,
+        headers,
+        batch_size,
+        max_retries,
+        retry_backoff,
+        ..
+    } = options.try_into()?;
+    let url = url.ok_or_else(|| sql_err!("HTTP sink must specify URL"))?;
+    if let StringOrSecret::String(url) = &url {
+        if let Err(e) = reqwest::Url::parse(url) {
+            sql_bail!("invalid URL {}: {}", url.quoted(), e);
+        }
+    }
+    if batch_size == 0 {
+        sql_bail!("BATCH SIZE must be greater than zero");
+    }
+
+    Ok(StorageSinkConnection::Http(HttpSinkConnection {
+        url,
+        headers,
+        batch_size,
+        max_retries,
+        retry_backoff,
+        key_desc_and_indices,
+        relation_key_indices,
+        value_desc,
+    }))
+}
+
+generate_extracted_config!(
+    RedisSinkConfigOption,
+    (Url, StringOrSecret),
+    (Password, StringOrSecret),
+    (KeyPrefix, String, Default(String::new())),
+    (DataStructure, String, Default("string".to_string()))
+);
+
+fn redis_sink_builder(
+    scx: &StatementContext,
+    options: Vec<RedisSinkConfigOption<Aug>>,
+    format: Option<Format<Aug>>,
+    relation_key_indices: Option<Vec<usize>>,
+    key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    value_desc: RelationDesc,
+    envelope: SinkEnvelope,
+) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_REDIS_SINK)?;
+
+    if envelope != SinkEnvelope::Upsert {
+        bail_unsupported!("Redis sinks with an envelope other than UPSERT");
+    }
+    if let Some(format) = format {
+        bail_unsupported!(format!("Redis sinks with FORMAT {}", format));
+    }
+
+    let RedisSinkConfigOptionExtracted {
+        url,
+        password,
+        key_prefix,
+        data_structure,
+        ..
+    } = options.try_into()?;
+    let url = url.ok_or_else(|| sql_err!("Redis sink must specify URL"))?;
+    if let StringOrSecret::String(url) = &url {
+        if let Err(e) = redis::IntoConnectionInfo::into_connection_info(url.as_str()) {
+            sql_bail!("invalid URL {}: {}", url.quoted(), e);
+        }
+    }
+    let data_structure = match data_structure.to_lowercase().as_str() {
+        "string" => RedisDataStructure::String,
+        "hash" => RedisDataStructure::Hash,
+        _ => sql_bail!(
+            "invalid DATA STRUCTURE {}: must be STRING or HASH",
+            data_structure.quoted()
+        ),
+    };
+
+    Ok(StorageSinkConnection::Redis(RedisSinkConnection {
+        url,
+        password,
+        key_prefix,
+        data_structure,
+        key_desc_and_indices,
+        relation_key_indices,
+        value_desc,
+    }))
+}
+
+generate_extracted_config!(
+    IcebergSinkConfigOption,
+    (Catalog, String),
+    (CatalogUrl, String),
+    (CatalogToken, StringOrSecret),
+    (Namespace, String),
+    (Table, String),
+    (
+        CommitInterval,
+        Duration,
+        Default(DEFAULT_ICEBERG_SINK_COMMIT_INTERVAL)
+    )
+);
+
+/// The default longest time an Iceberg sink buffers updates before
+/// committing them.
+const DEFAULT_ICEBERG_SINK_COMMIT_INTERVAL: Duration = Duration::from_secs(60);
+
+fn iceberg_sink_builder(
+    scx: &StatementContext,
+    connection: ResolvedItemName,
+    options: Vec<IcebergSinkConfigOption<Aug>>,
+    format: Option<Format<Aug>>,
+    value_desc: RelationDesc,
+    envelope: SinkEnvelope,
+) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_ICEBERG_SINK)?;
+
+    let connection_item = scx.get_item_by_resolved_name(&connection)?;
+    let connection_id = connection_item.id();
+    match connection_item.connection()? {
+        Connection::Aws(_) => (),
+        _ => sql_bail!(
+            "{} is not an AWS connection",
+            scx.catalog.resolve_full_name(connection_item.name())
+        ),
+    };
+
+    if envelope != SinkEnvelope::Debezium {
+        bail_unsupported!("Iceberg sinks with an envelope other than DEBEZIUM");
+    }
+    if let Some(format) = format {
+        bail_unsupported!(format!("Iceberg sinks with FORMAT {}", format));
+    }
+
+    // Iceberg columns are resolved by name, so the names must be unique.
+    let mut names = BTreeSet::new();
+    for name in value_desc
+        .iter_names()
+        .map(|name| name.as_str())
+        .chain(S3_SINK_METADATA_COLUMNS)
+    {
+        if !names.insert(name) {
+            sql_bail!(
+                "Iceberg sink has more than one column named {}",
+                name.quoted()
+            );
+        }
+    }
+
+    let IcebergSinkConfigOptionExtracted {
+        catalog,
+        catalog_url,
+        catalog_token,
+        namespace,
+        table,
+        commit_interval,
+        ..
+    } = options.try_into()?;
+    let catalog = match catalog.map(|c| c.to_lowercase()).as_deref() {
+        Some("glue") => {
+            if catalog_url.is_some() || catalog_token.is_some() {
+                sql_bail!("CATALOG URL and CATALOG TOKEN are only valid for REST catalogs");
+            }
+            IcebergCatalog::Glue
+        }
+        Some("rest") => {
+            let url =
+                catalog_url.ok_or_else(|| sql_err!("REST catalogs must specify CATALOG URL"))?;
+            if let Err(e) = reqwest::Url::parse(&url) {
+                sql_bail!("invalid CATALOG URL {}: {}", url.quoted(), e);
+            }
+            IcebergCatalog::Rest {
+                url,
+                token: catalog_token,
+            }
+        }
+        Some(catalog) => sql_bail!("invalid CATALOG {}: must be GLUE or REST", catalog.quoted()),
+        None => sql_bail!("ICEBERG CONNECTION must specify CATALOG"),
+    };
+    let namespace =
+        namespace.ok_or_else(|| sql_err!("ICEBERG CONNECTION must specify NAMESPACE"))?;
+    let table = table.ok_or_else(|| sql_err!("ICEBERG CONNECTION must specify TABLE"))?;
+    if commit_interval.is_zero() {
+        sql_bail!("COMMIT INTERVAL must be greater than zero");
+    }
+
+    Ok(StorageSinkConnection::Iceberg(IcebergSinkConnection {
+        connection_id,
+        connection: connection_id,
+        catalog,
+        namespace,
+        table,
+        commit_interval,
+        value_desc,
+    }))
+}
+
+pub fn describe_create_index(
+    _: &StatementContext,
+    _: CreateIndexStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_index(
+    scx: &StatementContext,
+    mut stmt: CreateIndexStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let CreateIndexStatement {
+        name,
+        on_name,
+        in_cluster,
+        key_parts,
+        with_options,
+        if_not_exists,
+    } = &mut stmt;
+    let on = scx.get_item_by_resolved_name(on_name)?;
+
+    if CatalogItemType::View != on.item_type()
+        && CatalogItemType::MaterializedView != on.item_type()
+        && CatalogItemType::Source != on.item_type()
+        && CatalogItemType::Table != on.item_type()
+    {
+        sql_bail!(
+            "index cannot be created on {} because it is a {}",
+            on_name.full_name_str(),
+            on.item_type()
+        )
+    }
+
+    let on_desc = on.desc(&scx.catalog.resolve_full_name(on.name()))?;
+
+    let filled_key_parts = match key_parts {
+        Some(kp) => kp.to_vec(),
+        None => {
+            // `key_parts` is None if we're creating a "default" index.
+            on.desc(&scx.catalog.resolve_full_name(on.name()))?
+                .typ()
+                .default_key()
+                .iter()
+                .map(|i| match on_desc.get_unambiguous_name(*i) {
                     Some(n) => Expr::Identifier(vec![n.clone().into()]),
                     _ => Expr::Value(Value::Number((i + 1).to_string())),
                 })
@@ -2945,10 +3928,26 @@ pub enum PlannedAlterRoleOption {
 #[derive(Debug)]
 pub struct PlannedRoleAttributes {
     pub inherit: Option<bool>,
+    /// The new password of the role, or `Some(None)` if the role's password should be removed.
+    pub password: Option<Option<String>>,
+    /// The new connection limit of the role, or `Some(None)` if the limit should be removed.
+    pub connection_limit: Option<Option<u32>>,
+    /// The new default statement timeout of the role, or `Some(None)` if the default should be
+    /// removed.
+    pub statement_timeout: Option<Option<Duration>>,
+    /// The new expiration time of the role, in milliseconds since the Unix epoch, or `Some(None)`
+    /// if the role should never expire.
+    pub valid_until: Option<Option<EpochMillis>>,
 }
 
 fn plan_role_attributes(options: Vec<RoleAttribute>) -> Result<PlannedRoleAttributes, PlanError> {
-    let mut planned_attributes = PlannedRoleAttributes { inherit: None };
+    let mut planned_attributes = PlannedRoleAttributes {
+        inherit: None,
+        password: None,
+        connection_limit: None,
+        statement_timeout: None,
+        valid_until: None,
+    };
 
     for option in options {
         match option {
@@ -2963,6 +3962,23 @@ fn plan_role_attributes(options: Vec<RoleAttribute>) -> Result<PlannedRoleAttrib
             {
                 sql_bail!("conflicting or redundant options");
             }
+            RoleAttribute::Password(_) if planned_attributes.password.is_some() => {
+                sql_bail!("conflicting or redundant options");
+            }
+            RoleAttribute::Password(Some(password)) if password.is_empty() => {
+                sql_bail!("empty string is not a valid password");
+            }
+            RoleAttribute::ConnectionLimit(_) if planned_attributes.connection_limit.is_some() => {
+                sql_bail!("conflicting or redundant options");
+            }
+            RoleAttribute::StatementTimeout(_)
+                if planned_attributes.statement_timeout.is_some() =>
+            {
+                sql_bail!("conflicting or redundant options");
+            }
+            RoleAttribute::ValidUntil(_) if planned_attributes.valid_until.is_some() => {
+                sql_bail!("conflicting or redundant options");
+            }
             RoleAttribute::CreateCluster | RoleAttribute::NoCreateCluster => {
                 bail_never_supported!(
                     "CREATECLUSTER attribute",
@@ -2987,6 +4003,42 @@ fn plan_role_attributes(options: Vec<RoleAttribute>) -> Result<PlannedRoleAttrib
 
             RoleAttribute::Inherit => planned_attributes.inherit = Some(true),
             RoleAttribute::NoInherit => planned_attributes.inherit = Some(false),
+            RoleAttribute::Password(password) => planned_attributes.password = Some(password),
+            RoleAttribute::ConnectionLimit(limit) => {
+                // As in PostgreSQL, -1 means that there is no limit.
+                let limit = match limit {
+                    -1 => None,
+                    limit => Some(
+                        u32::try_from(limit)
+                            .map_err(|_| sql_err!("invalid connection limit: {limit}"))?,
+                    ),
+                };
+                planned_attributes.connection_limit = Some(limit);
+            }
+            RoleAttribute::StatementTimeout(timeout) => {
+                let timeout = timeout
+                    .map(|timeout| {
+                        <Duration as vars::Value>::parse(
+                            &vars::STATEMENT_TIMEOUT,
+                            vars::VarInput::Flat(&timeout),
+                        )
+                    })
+                    .transpose()?;
+                planned_attributes.statement_timeout = Some(timeout);
+            }
+            RoleAttribute::ValidUntil(valid_until) => {
+                // As in PostgreSQL, 'infinity' means that the role never expires.
+                let valid_until = match valid_until {
+                    Some(valid_until) if !valid_until.trim().eq_ignore_ascii_case("infinity") => {
+                        let ts = strconv::parse_timestamptz(&valid_until)?;
+                        Some(EpochMillis::try_from(ts.timestamp_millis()).map_err(|_| {
+                            sql_err!("invalid role expiration time: {}", valid_until.quoted())
+                        })?)
+                    }
+                    _ => None,
+                };
+                planned_attributes.valid_until = Some(valid_until);
+            }
         }
     }
     if planned_attributes.inherit == Some(false) {
@@ -3042,6 +4094,72 @@ pub fn plan_create_role(
     }))
 }
 
+pub fn describe_create_service_account(
+    _: &StatementContext,
+    _: CreateServiceAccountStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_service_account(
+    scx: &StatementContext,
+    CreateServiceAccountStatement {
+        name,
+        role,
+        token_secret,
+        scopes,
+        valid_until,
+    }: CreateServiceAccountStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    if role.id.is_system() || role.id.is_public() {
+        sql_bail!(
+            "cannot create a service account for role {}",
+            role.name.quoted()
+        );
+    }
+
+    let secret = scx.get_item_by_resolved_name(&token_secret)?;
+    if secret.item_type() != CatalogItemType::Secret {
+        sql_bail!(
+            "{} is a {} not a secret",
+            scx.catalog.resolve_full_name(secret.name()),
+            secret.item_type()
+        )
+    }
+
+    for scope in &scopes {
+        if !rbac::SERVICE_ACCOUNT_SCOPES.contains(&scope.as_str()) {
+            sql_bail!(
+                "invalid service account scope {}; valid scopes are: {}",
+                scope.quoted(),
+                rbac::SERVICE_ACCOUNT_SCOPES.iter().join(", ")
+            );
+        }
+    }
+
+    // As with roles, 'infinity' means that the service account never expires.
+    let expires_at = match valid_until {
+        Some(valid_until) if !valid_until.trim().eq_ignore_ascii_case("infinity") => {
+            let ts = strconv::parse_timestamptz(&valid_until)?;
+            Some(EpochMillis::try_from(ts.timestamp_millis()).map_err(|_| {
+                sql_err!(
+                    "invalid service account expiration time: {}",
+                    valid_until.quoted()
+                )
+            })?)
+        }
+        _ => None,
+    };
+
+    Ok(Plan::CreateServiceAccount(CreateServiceAccountPlan {
+        name: normalize::ident(name),
+        role_id: role.id,
+        token_secret_id: secret.id(),
+        scopes,
+        expires_at,
+    }))
+}
+
 pub fn describe_create_cluster(
     _: &StatementContext,
     _: CreateClusterStatement<Aug>,
@@ -3057,28 +4175,128 @@ generate_extracted_config!(
     (IntrospectionDebugging, bool),
     (IntrospectionInterval, OptionalDuration),
     (Managed, bool),
+    (MemoryQuota, String),
+    (Priority, String),
     (Replicas, Vec<ReplicaDefinition<Aug>>),
     (ReplicationFactor, u32),
+    (Schedule, Vec<ClusterScheduleDefinition<Aug>>),
     (Size, String)
 );
 
+/// Plans the `PRIORITY` option of a cluster.
+fn plan_cluster_priority(
+    scx: &StatementContext,
+    priority: String,
+) -> Result<ClusterPriority, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_CLUSTER_PRIORITIES_AND_QUOTAS)?;
+    match priority.to_lowercase().as_str() {
+        "low" => Ok(ClusterPriority::Low),
+        "normal" => Ok(ClusterPriority::Normal),
+        "high" => Ok(ClusterPriority::High),
+        _ => sql_bail!(
+            "invalid PRIORITY {}: must be one of 'low', 'normal', or 'high'",
+            priority.quoted()
+        ),
+    }
+}
+
+/// Plans the `MEMORY QUOTA` option of a cluster, returning the quota in bytes.
+fn plan_cluster_memory_quota(
+    scx: &StatementContext,
+    memory_quota: String,
+) -> Result<u64, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_CLUSTER_PRIORITIES_AND_QUOTAS)?;
+    // Quotas are parsed like the memory limits of replica sizes, so that the
+    // two are specified the same way.
+    let memory_quota = mz_orchestrator::MemoryLimit::deserialize(memory_quota.into_deserializer())
+        .map_err(|e: serde::de::value::Error| sql_err!("invalid MEMORY QUOTA: {e}"))?;
+    Ok(memory_quota.0.as_u64())
+}
+
+/// Plans the `SCHEDULE` option of a managed cluster. The returned entries are
+/// sorted by their time of day.
+fn plan_cluster_schedule(
+    scx: &StatementContext,
+    definitions: Vec<ClusterScheduleDefinition<Aug>>,
+) -> Result<Vec<ClusterScheduleEntry>, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_CLUSTER_SCHEDULES)?;
+
+    let mut schedule: Vec<ClusterScheduleEntry> = vec![];
+    for ClusterScheduleDefinition { at, options } in definitions {
+        let time = NaiveTime::parse_from_str(&at, "%H:%M").map_err(|_| {
+            sql_err!(
+                "invalid SCHEDULE time {}: must be of the form HH:MM",
+                at.quoted()
+            )
+        })?;
+        let minute_of_day = time.hour() * 60 + time.minute();
+        if schedule.iter().any(|e| e.minute_of_day == minute_of_day) {
+            sql_bail!("SCHEDULE contains multiple entries AT {}", at.quoted());
+        }
+
+        let ClusterOptionExtracted {
+            replication_factor,
+            seen,
+            size,
+            ..
+        }: ClusterOptionExtracted = options.try_into()?;
+        if let Some(option) = seen.iter().find(|o| {
+            !matches!(
+                o,
+                ClusterOptionName::ReplicationFactor | ClusterOptionName::Size
+            )
+        }) {
+            sql_bail!(
+                "SCHEDULE entries do not support {}; only SIZE and REPLICATION FACTOR are supported",
+                option.to_ast_string()
+            );
+        }
+        schedule.push(ClusterScheduleEntry {
+            minute_of_day,
+            size,
+            replication_factor,
+        });
+    }
+    schedule.sort();
+    Ok(schedule)
+}
+
 pub fn plan_create_cluster(
     scx: &StatementContext,
-    CreateClusterStatement { name, options }: CreateClusterStatement<Aug>,
+    CreateClusterStatement {
+        name,
+        options,
+        like,
+    }: CreateClusterStatement<Aug>,
 ) -> Result<Plan, PlanError> {
+    if let Some(like) = like {
+        return plan_create_cluster_like(scx, name, like);
+    }
+
     let ClusterOptionExtracted {
         availability_zones,
         idle_arrangement_merge_effort,
         introspection_debugging,
         introspection_interval,
         managed,
+        memory_quota,
+        priority,
         replicas,
         replication_factor,
+        schedule,
         seen: _,
         size,
         disk,
     }: ClusterOptionExtracted = options.try_into()?;
 
+    let priority = priority
+        .map(|priority| plan_cluster_priority(scx, priority))
+        .transpose()?
+        .unwrap_or_default();
+    let memory_quota = memory_quota
+        .map(|memory_quota| plan_cluster_memory_quota(scx, memory_quota))
+        .transpose()?;
+
     let managed = managed.unwrap_or_else(|| replicas.is_none());
 
     if managed {
@@ -3108,6 +4326,11 @@ pub fn plan_create_cluster(
             scx.require_feature_flag(&vars::ENABLE_DISK_CLUSTER_REPLICAS)?;
         }
 
+        let schedule = match schedule {
+            Some(schedule) => plan_cluster_schedule(scx, schedule)?,
+            None => vec![],
+        };
+
         Ok(Plan::CreateCluster(CreateClusterPlan {
             name: normalize::ident(name),
             variant: CreateClusterVariant::Managed(CreateClusterManagedPlan {
@@ -3116,7 +4339,10 @@ pub fn plan_create_cluster(
                 availability_zones,
                 compute,
                 disk,
+                schedule,
             }),
+            priority,
+            memory_quota,
         }))
     } else {
         let Some(replica_defs) = replicas else {
@@ -3143,21 +4369,76 @@ pub fn plan_create_cluster(
         if disk.is_some() {
             sql_bail!("DISK not supported for unmanaged clusters");
         }
+        if schedule.is_some() {
+            sql_bail!("SCHEDULE not supported for unmanaged clusters");
+        }
         let mut replicas = vec![];
         for ReplicaDefinition { name, options } in replica_defs {
-            replicas.push((normalize::ident(name), plan_replica_config(scx, options)?));
+            replicas.push((
+                normalize::ident(name),
+                plan_replica_config(scx, None, options)?,
+            ));
         }
 
         Ok(Plan::CreateCluster(CreateClusterPlan {
             name: normalize::ident(name),
             variant: CreateClusterVariant::Unmanaged(CreateClusterUnmanagedPlan { replicas }),
+            priority,
+            memory_quota,
         }))
     }
 }
 
+/// Plans `CREATE CLUSTER ... LIKE`, which clones the configuration, and
+/// optionally the indexes and materialized views, of a managed cluster.
+fn plan_create_cluster_like(
+    scx: &StatementContext,
+    name: Ident,
+    CreateClusterLike { cluster, including }: CreateClusterLike,
+) -> Result<Plan, PlanError> {
+    let cluster = scx
+        .catalog
+        .resolve_cluster(Some(&normalize::ident(cluster)))?;
+    if !cluster.is_managed() {
+        sql_bail!(
+            "cannot create a cluster LIKE unmanaged cluster {}",
+            cluster.name().quoted()
+        );
+    }
+
+    let item_types: Vec<_> = including
+        .into_iter()
+        .map(|objects| match objects {
+            CreateClusterLikeObjects::Indexes => CatalogItemType::Index,
+            CreateClusterLikeObjects::MaterializedViews => CatalogItemType::MaterializedView,
+        })
+        .collect();
+    let items = cluster
+        .bound_objects()
+        .iter()
+        .filter(|id| id.is_user())
+        .filter(|id| item_types.contains(&scx.catalog.get_item(id).item_type()))
+        .copied()
+        .collect();
+
+    Ok(Plan::CreateCluster(CreateClusterPlan {
+        name: normalize::ident(name),
+        variant: CreateClusterVariant::Like(CreateClusterLikePlan {
+            cluster_id: cluster.id(),
+            items,
+        }),
+        // Sequencing clones the priority and memory quota of the cluster,
+        // along with the rest of its configuration.
+        priority: ClusterPriority::default(),
+        memory_quota: None,
+    }))
+}
+
 generate_extracted_config!(
     ReplicaOption,
     (AvailabilityZone, String),
+    (AvailabilityZoneAffinity, Vec<Ident>),
+    (AvailabilityZoneAntiAffinity, Vec<Ident>),
     (BilledAs, String),
     (ComputeAddresses, Vec<String>),
     (ComputectlAddresses, Vec<String>),
@@ -3172,12 +4453,20 @@ generate_extracted_config!(
     (Workers, u16)
 );
 
+/// Plans the configuration of a replica with `options`.
+///
+/// `cluster` is the existing cluster that the replica is added to, against
+/// whose replicas availability zone constraints are resolved. Replicas defined
+/// alongside their cluster cannot have availability zone constraints.
 fn plan_replica_config(
     scx: &StatementContext,
+    cluster: Option<&dyn CatalogCluster>,
     options: Vec<ReplicaOption<Aug>>,
 ) -> Result<ReplicaConfig, PlanError> {
     let ReplicaOptionExtracted {
         availability_zone,
+        availability_zone_affinity,
+        availability_zone_anti_affinity,
         billed_as,
         compute_addresses,
         computectl_addresses,
@@ -3203,6 +4492,45 @@ fn plan_replica_config(
         scx.require_feature_flag(&vars::ENABLE_DISK_CLUSTER_REPLICAS)?;
     }
 
+    let resolve_replicas = |option: ReplicaOptionName, names: Vec<Ident>| {
+        let Some(cluster) = cluster else {
+            sql_bail!(
+                "{} is only supported in CREATE CLUSTER REPLICA",
+                option.to_ast_string()
+            );
+        };
+        names
+            .into_iter()
+            .map(|name| {
+                let name = normalize::ident(name);
+                match cluster.replica_ids().get(&name) {
+                    Some(id) => Ok(*id),
+                    None => sql_bail!(
+                        "{}: unknown replica {} in cluster {}",
+                        option.to_ast_string(),
+                        name.quoted(),
+                        cluster.name().quoted()
+                    ),
+                }
+            })
+            .collect::<Result<Vec<_>, PlanError>>()
+    };
+    let mut availability_zone_constraints = vec![];
+    if let Some(names) = availability_zone_affinity {
+        let ids = resolve_replicas(ReplicaOptionName::AvailabilityZoneAffinity, names)?;
+        availability_zone_constraints.extend(
+            ids.into_iter()
+                .map(ReplicaAvailabilityZoneConstraint::Affinity),
+        );
+    }
+    if let Some(names) = availability_zone_anti_affinity {
+        let ids = resolve_replicas(ReplicaOptionName::AvailabilityZoneAntiAffinity, names)?;
+        availability_zone_constraints.extend(
+            ids.into_iter()
+                .map(ReplicaAvailabilityZoneConstraint::AntiAffinity),
+        );
+    }
+
     match (
         size,
         availability_zone,
@@ -3226,6 +4554,7 @@ fn plan_replica_config(
             Ok(ReplicaConfig::Managed {
                 size,
                 availability_zone,
+                availability_zone_constraints,
                 compute,
                 disk,
                 billed_as,
@@ -3282,6 +4611,10 @@ fn plan_replica_config(
                 sql_bail!("DISK can't be specified for unmanaged clusters");
             }
 
+            if !availability_zone_constraints.is_empty() {
+                sql_bail!("AVAILABILITY ZONE AFFINITY can't be specified for unmanaged replicas");
+            }
+
             Ok(ReplicaConfig::Unmanaged {
                 storagectl_addrs,
                 storage_addrs,
@@ -3346,7 +4679,7 @@ pub fn plan_create_cluster_replica(
     }
     ensure_cluster_is_not_linked(scx, cluster.id())?;
 
-    let config = plan_replica_config(scx, options)?;
+    let config = plan_replica_config(scx, Some(cluster), options)?;
 
     if let ReplicaConfig::Managed { internal: true, .. } = &config {
         if MANAGED_REPLICA_PATTERN.is_match(name.as_str()) {
@@ -3363,6 +4696,102 @@ pub fn plan_create_cluster_replica(
     }))
 }
 
+pub fn describe_create_cluster_replica_size(
+    _: &StatementContext,
+    _: CreateClusterReplicaSizeStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+generate_extracted_config!(
+    ClusterReplicaSizeOption,
+    (CpuLimit, f64),
+    (CreditsPerHour, Numeric),
+    (Disabled, bool, Default(false)),
+    (DiskLimit, String),
+    (MemoryLimit, String),
+    (Scale, u16, Default(1)),
+    (Workers, u64)
+);
+
+pub fn plan_create_cluster_replica_size(
+    _: &StatementContext,
+    CreateClusterReplicaSizeStatement { name, options }: CreateClusterReplicaSizeStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let ClusterReplicaSizeOptionExtracted {
+        seen: _,
+        cpu_limit,
+        credits_per_hour,
+        disabled,
+        disk_limit,
+        memory_limit,
+        scale,
+        workers,
+    } = options.try_into()?;
+
+    let Some(workers) = workers else {
+        sql_bail!("WORKERS option is required");
+    };
+    let Some(credits_per_hour) = credits_per_hour else {
+        sql_bail!("CREDITS PER HOUR option is required");
+    };
+    if workers == 0 {
+        sql_bail!("WORKERS must be greater than 0");
+    }
+    if scale == 0 {
+        sql_bail!("SCALE must be greater than 0");
+    }
+    if credits_per_hour.is_negative() {
+        sql_bail!("CREDITS PER HOUR must not be negative");
+    }
+
+    // The limits are parsed the same way as in the `--cluster-replica-sizes`
+    // map, so that sizes defined either way accept the same values.
+    let invalid = |option: &str, e: serde::de::value::Error| sql_err!("invalid {option}: {e}");
+    let memory_limit = memory_limit
+        .map(|v| mz_orchestrator::MemoryLimit::deserialize(v.into_deserializer()))
+        .transpose()
+        .map_err(|e| invalid("MEMORY LIMIT", e))?;
+    let disk_limit = disk_limit
+        .map(|v| mz_orchestrator::DiskLimit::deserialize(v.into_deserializer()))
+        .transpose()
+        .map_err(|e| invalid("DISK LIMIT", e))?;
+    let cpu_limit = cpu_limit
+        .map(|v| mz_orchestrator::CpuLimit::deserialize(v.into_deserializer()))
+        .transpose()
+        .map_err(|e| invalid("CPU LIMIT", e))?;
+
+    Ok(Plan::CreateClusterReplicaSize(
+        CreateClusterReplicaSizePlan {
+            name,
+            memory_limit,
+            cpu_limit,
+            disk_limit,
+            scale,
+            workers: usize::cast_from(workers),
+            credits_per_hour,
+            disabled,
+        },
+    ))
+}
+
+pub fn describe_drop_cluster_replica_size(
+    _: &StatementContext,
+    _: DropClusterReplicaSizeStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_drop_cluster_replica_size(
+    _: &StatementContext,
+    DropClusterReplicaSizeStatement { if_exists, name }: DropClusterReplicaSizeStatement,
+) -> Result<Plan, PlanError> {
+    Ok(Plan::DropClusterReplicaSize(DropClusterReplicaSizePlan {
+        name,
+        if_exists,
+    }))
+}
+
 pub fn describe_create_secret(
     _: &StatementContext,
     _: CreateSecretStatement<Aug>,
@@ -3842,6 +5271,45 @@ pub fn describe_alter_index_options(
     Ok(StatementDesc::new(None))
 }
 
+pub fn describe_drop_service_account(
+    _: &StatementContext,
+    _: DropServiceAccountStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_drop_service_account(
+    _: &StatementContext,
+    DropServiceAccountStatement { if_exists, name }: DropServiceAccountStatement,
+) -> Result<Plan, PlanError> {
+    Ok(Plan::DropServiceAccount(DropServiceAccountPlan {
+        name: normalize::ident(name),
+        if_exists,
+    }))
+}
+
+pub fn describe_undrop_object(
+    _: &StatementContext,
+    _: UndropObjectStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_undrop_object(
+    scx: &StatementContext,
+    UndropObjectStatement { object_type, name }: UndropObjectStatement,
+) -> Result<Plan, PlanError> {
+    let object_type = object_type.into();
+    // Only views are kept in the trash when they are dropped. All other items
+    // may hold on to storage, compute, or external resources, which are
+    // released when they are dropped.
+    if object_type != ObjectType::View {
+        sql_bail!("cannot undrop {object_type}: only views can be restored once dropped");
+    }
+    let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name)?)?;
+    Ok(Plan::UndropObject(UndropObjectPlan { object_type, name }))
+}
+
 pub fn describe_drop_owned(
     _: &StatementContext,
     _: DropOwnedStatement<Aug>,
@@ -4197,13 +5665,20 @@ pub fn plan_alter_cluster(
                 introspection_debugging,
                 introspection_interval,
                 managed,
+                memory_quota,
+                priority,
                 replicas: replica_defs,
                 replication_factor,
+                schedule,
                 seen: _,
                 size,
                 disk,
             }: ClusterOptionExtracted = set_options.try_into()?;
 
+            let schedule = schedule
+                .map(|schedule| plan_cluster_schedule(scx, schedule))
+                .transpose()?;
+
             match managed.unwrap_or_else(|| cluster.is_managed()) {
                 true => {
                     if replica_defs.is_some() {
@@ -4214,6 +5689,15 @@ pub fn plan_alter_cluster(
                             sql_bail!("cannot create more than one replica of a cluster containing sources or sinks");
                         }
                     }
+                    if let Some(schedule) = &schedule {
+                        if is_storage_cluster(scx, cluster)
+                            && schedule
+                                .iter()
+                                .any(|entry| entry.replication_factor.unwrap_or(0) > 1)
+                        {
+                            sql_bail!("cannot schedule more than one replica of a cluster containing sources or sinks");
+                        }
+                    }
                 }
                 false => {
                     if availability_zones.is_some() {
@@ -4239,6 +5723,9 @@ pub fn plan_alter_cluster(
                     if disk.is_some() {
                         sql_bail!("DISK not supported for unmanaged clusters");
                     }
+                    if schedule.is_some() {
+                        sql_bail!("SCHEDULE not supported for unmanaged clusters");
+                    }
                 }
             }
 
@@ -4246,7 +5733,10 @@ pub fn plan_alter_cluster(
             for ReplicaDefinition { name, options } in
                 replica_defs.into_iter().flat_map(Vec::into_iter)
             {
-                replicas.push((normalize::ident(name), plan_replica_config(scx, options)?));
+                replicas.push((
+                    normalize::ident(name),
+                    plan_replica_config(scx, None, options)?,
+                ));
             }
 
             if let Some(managed) = managed {
@@ -4281,6 +5771,16 @@ pub fn plan_alter_cluster(
             if !replicas.is_empty() {
                 options.replicas = AlterOptionParameter::Set(replicas);
             }
+            if let Some(schedule) = schedule {
+                options.schedule = AlterOptionParameter::Set(schedule);
+            }
+            if let Some(priority) = priority {
+                options.priority = AlterOptionParameter::Set(plan_cluster_priority(scx, priority)?);
+            }
+            if let Some(memory_quota) = memory_quota {
+                options.memory_quota =
+                    AlterOptionParameter::Set(plan_cluster_memory_quota(scx, memory_quota)?);
+            }
         }
         AlterClusterAction::ResetOptions(reset_options) => {
             use AlterOptionParameter::Reset;
@@ -4293,8 +5793,11 @@ pub fn plan_alter_cluster(
                     IntrospectionDebugging => options.introspection_debugging = Reset,
                     IdleArrangementMergeEffort => options.idle_arrangement_merge_effort = Reset,
                     Managed => options.managed = Reset,
+                    MemoryQuota => options.memory_quota = Reset,
+                    Priority => options.priority = Reset,
                     Replicas => options.replicas = Reset,
                     ReplicationFactor => options.replication_factor = Reset,
+                    Schedule => options.schedule = Reset,
                     Size => options.size = Reset,
                 }
             }
@@ -4307,6 +5810,72 @@ pub fn plan_alter_cluster(
     }))
 }
 
+generate_extracted_config!(AlterClusterReplicaOption, (Draining, bool));
+
+pub fn describe_alter_cluster_replica(
+    _: &StatementContext,
+    _: AlterClusterReplicaStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_cluster_replica(
+    scx: &mut StatementContext,
+    AlterClusterReplicaStatement {
+        name,
+        action,
+        if_exists,
+    }: AlterClusterReplicaStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_CLUSTER_REPLICA_DRAINING)?;
+
+    let (cluster, replica_id) = match resolve_cluster_replica(scx, &name, if_exists)? {
+        Some(entry) => entry,
+        None => {
+            scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+                name: name.to_ast_string(),
+                object_type: ObjectType::ClusterReplica,
+            });
+
+            return Ok(Plan::AlterNoop(AlterNoopPlan {
+                object_type: ObjectType::ClusterReplica,
+            }));
+        }
+    };
+
+    let mut draining = AlterOptionParameter::Unchanged;
+    match action {
+        AlterClusterReplicaAction::SetOptions(set_options) => {
+            let AlterClusterReplicaOptionExtracted {
+                draining: set_draining,
+                seen: _,
+            } = set_options.try_into()?;
+            if let Some(set_draining) = set_draining {
+                draining = AlterOptionParameter::Set(set_draining);
+            }
+        }
+        AlterClusterReplicaAction::ResetOptions(reset_options) => {
+            for option in reset_options {
+                match option {
+                    AlterClusterReplicaOptionName::Draining => {
+                        draining = AlterOptionParameter::Reset
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(Plan::AlterClusterReplica(AlterClusterReplicaPlan {
+        cluster_id: cluster.id(),
+        replica_id,
+        name: QualifiedReplica {
+            cluster: Ident::new(cluster.name())?,
+            replica: name.replica,
+        },
+        draining,
+    }))
+}
+
 pub fn describe_alter_set_cluster(
     _: &StatementContext,
     _: AlterSetClusterStatement<Aug>,
@@ -4382,6 +5951,111 @@ pub fn plan_alter_item_set_cluster(
     }
 }
 
+pub fn describe_alter_column_mask(
+    _: &StatementContext,
+    _: AlterColumnMaskStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_column_mask(
+    scx: &StatementContext,
+    AlterColumnMaskStatement {
+        object_type,
+        if_exists,
+        name,
+        column,
+        action,
+    }: AlterColumnMaskStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let object_type = object_type.into();
+
+    match object_type {
+        ObjectType::Table | ObjectType::View | ObjectType::MaterializedView => {}
+        _ => bail_never_supported!(format!("ALTER {object_type} ALTER COLUMN ... MASK")),
+    }
+
+    let Some(entry) = resolve_item(scx, name.clone(), if_exists)? else {
+        scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+            name: name.to_ast_string(),
+            object_type,
+        });
+
+        return Ok(Plan::AlterNoop(AlterNoopPlan { object_type }));
+    };
+
+    let catalog_object_type: ObjectType = entry.item_type().into();
+    if catalog_object_type != object_type {
+        sql_bail!("Cannot modify {} as {object_type}", entry.item_type());
+    }
+    let full_name = scx.catalog.resolve_full_name(entry.name());
+    if entry.id().is_system() {
+        sql_bail!("cannot mask columns of system {object_type} {}", full_name);
+    }
+    if entry.name().qualifiers.schema_spec == SchemaSpecifier::Temporary {
+        sql_bail!(
+            "cannot mask columns of temporary {object_type} {}",
+            full_name
+        );
+    }
+
+    let desc = entry.desc(&full_name)?;
+    let column = normalize::column_name(column);
+    let Some((_, typ)) = desc.get_by_name(&column) else {
+        sql_bail!(
+            "column {} of {object_type} {} does not exist",
+            column.as_str().quoted(),
+            full_name
+        );
+    };
+
+    let role_ids = |roles: Vec<ResolvedRoleName>| -> Result<BTreeSet<RoleId>, PlanError> {
+        roles
+            .into_iter()
+            .map(|role| {
+                // Masks for these roles would apply to every role, including the ones that plan
+                // the catalog's views on startup.
+                if role.id.is_system() || role.id.is_public() {
+                    sql_bail!("cannot mask columns for role {}", role.name.quoted());
+                }
+                Ok(role.id)
+            })
+            .collect()
+    };
+
+    let action = match action {
+        AlterColumnMaskAction::Set { mut expr, roles } => {
+            transform_ast::transform(scx, &mut expr)?;
+            if crate::names::visit_dependencies(&expr)
+                .0
+                .iter()
+                .any(|id| !id.is_system())
+            {
+                sql_bail!("column masks cannot depend on user objects");
+            }
+            let _ = query::plan_column_mask_expr(scx, &expr, desc.as_ref(), &typ.scalar_type)?;
+            ColumnMaskAction::Set {
+                expr: expr.to_ast_string_stable(),
+                role_ids: role_ids(roles)?,
+            }
+        }
+        AlterColumnMaskAction::Drop { roles } => ColumnMaskAction::Drop {
+            role_ids: if roles.is_empty() {
+                None
+            } else {
+                Some(role_ids(roles)?)
+            },
+        },
+    };
+
+    Ok(Plan::AlterColumnMask(AlterColumnMaskPlan {
+        id: entry.id(),
+        object_type,
+        column,
+        action,
+    }))
+}
+
 pub fn describe_alter_object_rename(
     _: &StatementContext,
     _: AlterObjectRenameStatement,
@@ -4420,6 +6094,9 @@ pub fn plan_alter_object_rename(
         (ObjectType::Schema, UnresolvedObjectName::Schema(name)) => {
             plan_alter_schema_rename(scx, name, to_item_name, if_exists)
         }
+        (ObjectType::Database, UnresolvedObjectName::Database(name)) => {
+            plan_alter_database_rename(scx, name, to_item_name, if_exists)
+        }
         (object_type, name) => {
             unreachable!("parser set the wrong object type '{object_type:?}' for name {name:?}")
         }
@@ -4448,6 +6125,7 @@ pub fn plan_alter_schema_rename(
     {
         return Err(PlanError::Catalog(CatalogError::SchemaAlreadyExists(
             to_schema_name.clone().into_string(),
+            None,
         )));
     }
 
@@ -4463,6 +6141,43 @@ pub fn plan_alter_schema_rename(
     }))
 }
 
+pub fn plan_alter_database_rename(
+    scx: &mut StatementContext,
+    name: UnresolvedDatabaseName,
+    to_database_name: Ident,
+    if_exists: bool,
+) -> Result<Plan, PlanError> {
+    let Some(database) = resolve_database(scx, &name, if_exists)? else {
+        let object_type = ObjectType::Database;
+        scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+            name: name.to_ast_string(),
+            object_type,
+        });
+        return Ok(Plan::AlterNoop(AlterNoopPlan { object_type }));
+    };
+
+    // Make sure the name is unique.
+    let to_database_name = normalize::ident(to_database_name);
+    if scx.catalog.resolve_database(&to_database_name).is_ok() {
+        return Err(PlanError::Catalog(CatalogError::DatabaseAlreadyExists(
+            to_database_name,
+            None,
+        )));
+    }
+
+    // Sessions refer to their active database by name, so renaming it out from under this
+    // session would leave it without one.
+    if scx.catalog.active_database() == Some(&database.id()) {
+        sql_bail!("current database cannot be renamed");
+    }
+
+    Ok(Plan::AlterDatabaseRename(AlterDatabaseRenamePlan {
+        id: database.id(),
+        name: database.name().to_string(),
+        to_name: to_database_name,
+    }))
+}
+
 pub fn plan_alter_schema_swap<F>(
     scx: &mut StatementContext,
     name_a: UnresolvedSchemaName,
@@ -4817,6 +6532,42 @@ pub fn plan_alter_connection(
         }));
     }
 
+    if let Some(rotated_options) = actions.iter().find_map(|action| match action {
+        AlterConnectionAction::RotateSecrets(options) => Some(options),
+        _ => None,
+    }) {
+        if actions.len() > 1 {
+            sql_bail!(
+                "cannot specify any other actions alongside ALTER CONNECTION...ROTATE SECRETS"
+            );
+        }
+
+        if !with_options.is_empty() {
+            sql_bail!(
+                "ALTER CONNECTION...ROTATE SECRETS does not support WITH ({})",
+                with_options.iter().map(|o| o.to_ast_string()).join(", ")
+            );
+        }
+
+        // Checks that the rotated connection plans; purification has already validated it
+        // against the upstream system, so it must not be validated again.
+        plan_rotated_connection(scx, entry, rotated_options)?;
+
+        let set_options = rotated_options
+            .iter()
+            .map(|option| (option.name.clone(), option.value.clone()))
+            .collect();
+
+        return Ok(Plan::AlterConnection(AlterConnectionPlan {
+            id: entry.id(),
+            action: crate::plan::AlterConnectionAction::AlterOptions {
+                set_options,
+                drop_options: BTreeSet::new(),
+                validate: false,
+            },
+        }));
+    }
+
     let options = AlterConnectionOptionExtracted::try_from(with_options)?;
     if options.validate.is_some() {
         scx.require_feature_flag(&vars::ENABLE_CONNECTION_VALIDATION_SYNTAX)?;
@@ -4847,7 +6598,9 @@ pub fn plan_alter_connection(
         .map(|action: &AlterConnectionAction<Aug>| match action {
             AlterConnectionAction::SetOption(option) => option.name.clone(),
             AlterConnectionAction::DropOption(name) => name.clone(),
-            AlterConnectionAction::RotateKeys => unreachable!(),
+            AlterConnectionAction::RotateKeys | AlterConnectionAction::RotateSecrets(_) => {
+                unreachable!()
+            }
         })
         .collect();
 
@@ -4864,7 +6617,9 @@ pub fn plan_alter_connection(
         actions.into_iter().partition_map(|action| match action {
             AlterConnectionAction::SetOption(option) => Either::Left(option),
             AlterConnectionAction::DropOption(name) => Either::Right(name),
-            AlterConnectionAction::RotateKeys => unreachable!(),
+            AlterConnectionAction::RotateKeys | AlterConnectionAction::RotateSecrets(_) => {
+                unreachable!()
+            }
         });
 
     let set_options: BTreeMap<_, _> = set_options_vec
@@ -4938,6 +6693,51 @@ pub fn plan_alter_connection(
     }))
 }
 
+/// Plans the connection that `ALTER CONNECTION...ROTATE SECRETS` produces by replacing the
+/// options of the connection `item` with `rotated_options`.
+pub(crate) fn plan_rotated_connection(
+    scx: &StatementContext,
+    item: &dyn CatalogItem,
+    rotated_options: &[ConnectionOption<Aug>],
+) -> Result<Connection<ReferencedConnection>, PlanError> {
+    let connection_type = match item.connection()? {
+        Connection::Kafka(_) => CreateConnectionType::Kafka,
+        Connection::Csr(_) => CreateConnectionType::Csr,
+        Connection::Postgres(_) => CreateConnectionType::Postgres,
+        Connection::Aws(_) | Connection::AwsPrivatelink(_) | Connection::Ssh(_) => sql_bail!(
+            "{} does not support ALTER CONNECTION...ROTATE SECRETS",
+            scx.catalog.resolve_full_name(item.name())
+        ),
+    };
+
+    for option in rotated_options {
+        if !SECRET_OPTIONS.contains(&option.name) {
+            sql_bail!("cannot ROTATE SECRETS option {}", option.name);
+        }
+        if !matches!(option.value, Some(WithOptionValue::Secret(_))) {
+            sql_bail!("ROTATE SECRETS option {} must be a SECRET", option.name);
+        }
+    }
+
+    let rotated_names: BTreeSet<_> = rotated_options
+        .iter()
+        .map(|option| option.name.clone())
+        .collect();
+    connection::validate_options_per_connection_type(connection_type, rotated_names.clone())?;
+
+    let stmt = match crate::parse::parse(item.create_sql())?.into_element().ast {
+        Statement::CreateConnection(stmt) => stmt,
+        _ => unreachable!("proved type is connection"),
+    };
+    let (mut stmt, _) = crate::names::resolve(scx.catalog, stmt)?;
+    stmt.values
+        .retain(|option| !rotated_names.contains(&option.name));
+    stmt.values.extend(rotated_options.iter().cloned());
+
+    connection::ConnectionOptionExtracted::try_from(stmt.values)?
+        .try_into_connection(scx, connection_type)
+}
+
 pub fn describe_alter_sink(
     _: &StatementContext,
     _: AlterSinkStatement<Aug>,
@@ -4980,7 +6780,7 @@ pub fn plan_alter_sink(
     let id = entry.id();
 
     let mut size = AlterOptionParameter::Unchanged;
-    match action {
+    let action = match action {
         AlterSinkAction::SetOptions(options) => {
             let CreateSinkOptionExtracted {
                 size: size_opt,
@@ -4994,6 +6794,8 @@ pub fn plan_alter_sink(
             if let Some(_) = snapshot {
                 sql_bail!("Cannot modify the SNAPSHOT of a SINK.");
             }
+
+            crate::plan::AlterSinkAction::Resize(size)
         }
         AlterSinkAction::ResetOptions(reset) => {
             for name in reset {
@@ -5006,10 +6808,69 @@ pub fn plan_alter_sink(
                     }
                 }
             }
+
+            crate::plan::AlterSinkAction::Resize(size)
+        }
+        AlterSinkAction::ChangeRelation(new_from) => {
+            let from = scx.get_item_by_resolved_name(&new_from)?;
+            let from_name = scx.catalog.resolve_full_name(from.name());
+            let from_desc = from.desc(&from_name)?;
+
+            // Recover the relation the sink currently reads from its
+            // definition.
+            let stmt = crate::parse::parse(entry.create_sql())?.into_element().ast;
+            let (stmt, _) = crate::names::resolve(scx.catalog, stmt)?;
+            let Statement::CreateSink(CreateSinkStatement {
+                from: current,
+                connection,
+                envelope,
+                ..
+            }) = stmt
+            else {
+                sql_bail!(
+                    "internal error: sink {} has unexpected definition",
+                    scx.catalog.resolve_full_name(entry.name())
+                );
+            };
+            let current = scx.get_item_by_resolved_name(&current)?;
+            let current_desc = current.desc(&scx.catalog.resolve_full_name(current.name()))?;
+
+            // The sink's key, envelope, and encoding are all planned against
+            // the columns of the current relation, so the new relation must
+            // present the same columns. Kafka sinks may additionally gain
+            // columns after them, which evolves the schema of the values they
+            // write; the compatibility of the new schema with the one in the
+            // schema registry is checked during purification.
+            let compatible = match &connection {
+                CreateSinkConnection::Kafka { .. } => from_desc.extends(&current_desc),
+                _ => from_desc.extends(&current_desc) && from_desc.arity() == current_desc.arity(),
+            };
+            if !compatible {
+                sql_bail!(
+                    "cannot change sink {} to read from {}: relation schema does not match the sink's current relation {}",
+                    scx.catalog.resolve_full_name(entry.name()),
+                    from_name,
+                    scx.catalog.resolve_full_name(current.name())
+                );
+            }
+
+            // The sink's key must also be a unique key of the new relation.
+            let key = match &connection {
+                CreateSinkConnection::Kafka { key, .. }
+                | CreateSinkConnection::Postgres { key, .. }
+                | CreateSinkConnection::Http { key, .. }
+                | CreateSinkConnection::Redis { key, .. } => key.as_ref(),
+                CreateSinkConnection::S3 { .. } | CreateSinkConnection::Iceberg { .. } => None,
+            };
+            if let (Some(key), Some(Envelope::Upsert)) = (key, envelope) {
+                plan_sink_key(scx, &new_from, &from_desc, key, SinkEnvelope::Upsert)?;
+            }
+
+            crate::plan::AlterSinkAction::ChangeRelation { from: from.id() }
         }
     };
 
-    Ok(Plan::AlterSink(AlterSinkPlan { id, size }))
+    Ok(Plan::AlterSink(AlterSinkPlan { id, action }))
 }
 
 pub fn describe_alter_source(
@@ -10,22 +10,29 @@
 //! Data definition language (DDL) utilities for CONNECTION objects.
 
 use std::collections::{BTreeMap, BTreeSet};
+use std::net::IpAddr;
+use std::time::Duration;
 
 use array_concat::concat_arrays;
+use ipnet::IpNet;
 use itertools::Itertools;
 use mz_ore::str::StrExt;
+use mz_repr::GlobalId;
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::ConnectionOptionName::*;
 use mz_sql_parser::ast::{
     ConnectionOption, ConnectionOptionName, CreateConnectionType, KafkaBroker,
     KafkaBrokerAwsPrivatelinkOption, KafkaBrokerAwsPrivatelinkOptionName, KafkaBrokerTunnel,
 };
-use mz_storage_types::connections::aws::{AwsAssumeRole, AwsConfig, AwsCredentials};
+use mz_storage_types::connections::aws::{
+    AwsAssumeRole, AwsConfig, AwsConnectionReference, AwsCredentials,
+};
 use mz_storage_types::connections::inline::ReferencedConnection;
 use mz_storage_types::connections::{
     AwsPrivatelink, AwsPrivatelinkConnection, CsrConnection, CsrConnectionHttpAuth,
-    KafkaConnection, KafkaSaslConfig, KafkaTlsConfig, PostgresConnection, SshConnection, SshTunnel,
-    StringOrSecret, TlsIdentity, Tunnel,
+    CsrConnectionOauth, CsrConnectionProxy, KafkaConnection, KafkaSaslConfig, KafkaSaslOauthConfig,
+    KafkaTlsConfig, PostgresConnection, SshConnection, SshTunnel, StringOrSecret, TlsIdentity,
+    Tunnel,
 };
 
 use crate::names::Aug;
@@ -37,15 +44,29 @@ generate_extracted_config!(
     ConnectionOption,
     (AccessKeyId, StringOrSecret),
     (AvailabilityZones, Vec<String>),
+    (AwsConnection, with_options::Object),
     (AwsPrivatelink, with_options::Object),
     (Broker, Vec<KafkaBroker<Aug>>),
+    (BrokerAddressTtl, Duration),
+    (BrokerReconnectBackoff, Duration),
     (Brokers, Vec<KafkaBroker<Aug>>),
     (Database, String),
+    (DefaultGroupIdPrefix, String),
+    (DefaultTopicMetadataRefreshIntervalMs, i32),
+    (EgressAllowlist, Vec<String>),
     (Endpoint, String),
     (Host, String),
+    (Hosts, Vec<String>),
+    (OauthClientId, StringOrSecret),
+    (OauthClientSecret, with_options::Secret),
+    (OauthScope, String),
+    (OauthTokenEndpoint, String),
     (Password, with_options::Secret),
     (Port, u16),
     (ProgressTopic, String),
+    (ProxyPassword, with_options::Secret),
+    (ProxyUrl, String),
+    (ProxyUser, StringOrSecret),
     (Region, String),
     (RoleArn, String),
     (SaslMechanisms, String),
@@ -74,7 +95,27 @@ generate_extracted_config!(
 pub(crate) const INALTERABLE_OPTIONS: &[ConnectionOptionName] = &[ProgressTopic];
 
 /// Options of which only one may be specified.
-pub(crate) const MUTUALLY_EXCLUSIVE_SETS: &[&[ConnectionOptionName]] = &[&[Broker, Brokers]];
+pub(crate) const MUTUALLY_EXCLUSIVE_SETS: &[&[ConnectionOptionName]] =
+    &[&[Broker, Brokers], &[Host, Hosts]];
+
+/// Options whose value may be a secret, and so may be replaced using ALTER CONNECTION ... ROTATE
+/// SECRETS.
+pub(crate) const SECRET_OPTIONS: &[ConnectionOptionName] = &[
+    AccessKeyId,
+    OauthClientId,
+    OauthClientSecret,
+    Password,
+    ProxyPassword,
+    ProxyUser,
+    SaslPassword,
+    SaslUsername,
+    SecretAccessKey,
+    SslCertificate,
+    SslCertificateAuthority,
+    SslKey,
+    Token,
+    User,
+];
 
 pub(super) fn validate_options_per_connection_type(
     t: CreateConnectionType,
@@ -94,8 +135,15 @@ pub(super) fn validate_options_per_connection_type(
         CreateConnectionType::AwsPrivatelink => &[AvailabilityZones, Port, ServiceName],
         CreateConnectionType::Csr => &[
             AwsPrivatelink,
+            OauthClientId,
+            OauthClientSecret,
+            OauthScope,
+            OauthTokenEndpoint,
             Password,
             Port,
+            ProxyPassword,
+            ProxyUrl,
+            ProxyUser,
             SshTunnel,
             SslCertificate,
             SslCertificateAuthority,
@@ -104,8 +152,18 @@ pub(super) fn validate_options_per_connection_type(
             User,
         ],
         CreateConnectionType::Kafka => &[
+            AwsConnection,
             Broker,
+            BrokerAddressTtl,
+            BrokerReconnectBackoff,
             Brokers,
+            DefaultGroupIdPrefix,
+            DefaultTopicMetadataRefreshIntervalMs,
+            EgressAllowlist,
+            OauthClientId,
+            OauthClientSecret,
+            OauthScope,
+            OauthTokenEndpoint,
             ProgressTopic,
             SshTunnel,
             SslKey,
@@ -117,8 +175,10 @@ pub(super) fn validate_options_per_connection_type(
             SecurityProtocol,
         ],
         CreateConnectionType::Postgres => &[
+            AwsConnection,
             AwsPrivatelink,
             Database,
+            EgressAllowlist,
             Host,
             Password,
             Port,
@@ -129,7 +189,7 @@ pub(super) fn validate_options_per_connection_type(
             SslMode,
             User,
         ],
-        CreateConnectionType::Ssh => &[Host, Port, User],
+        CreateConnectionType::Ssh => &[Host, Hosts, Port, User],
     };
 
     for o in permitted_options {
@@ -216,15 +276,52 @@ impl ConnectionOptionExtracted {
                     )?;
                 }
 
-                let (tls, sasl) = plan_kafka_security(&self)?;
+                let (tls, sasl) = plan_kafka_security(scx, &self)?;
+
+                if let Some(interval) = self.default_topic_metadata_refresh_interval_ms {
+                    if !(0..=3_600_000).contains(&interval) {
+                        sql_bail!(
+                            "DEFAULT TOPIC METADATA REFRESH INTERVAL MS must be within [0, 3,600,000]"
+                        );
+                    }
+                }
+
+                let mut options = BTreeMap::new();
+                if let Some(ttl) = self.broker_address_ttl {
+                    if ttl > Duration::from_secs(24 * 60 * 60) {
+                        sql_bail!("BROKER ADDRESS TTL must be at most 1 day");
+                    }
+                    // librdkafka re-resolves a broker's address whenever it
+                    // reconnects after its cached address expires, so a TTL
+                    // of zero re-resolves on every reconnection.
+                    options.insert(
+                        "broker.address.ttl".into(),
+                        StringOrSecret::String(ttl.as_millis().to_string()),
+                    );
+                }
+                if let Some(backoff) = self.broker_reconnect_backoff {
+                    if backoff < Duration::from_millis(100)
+                        || backoff > Duration::from_secs(60 * 60)
+                    {
+                        sql_bail!("BROKER RECONNECT BACKOFF must be within [100ms, 1h]");
+                    }
+                    options.insert(
+                        "reconnect.backoff.max.ms".into(),
+                        StringOrSecret::String(backoff.as_millis().to_string()),
+                    );
+                }
 
                 Connection::Kafka(KafkaConnection {
                     brokers: self.get_brokers(scx)?,
                     default_tunnel: scx.build_tunnel_definition(self.ssh_tunnel, None)?,
                     progress_topic: self.progress_topic,
-                    options: BTreeMap::new(),
+                    options,
                     tls,
                     sasl,
+                    default_group_id_prefix: self.default_group_id_prefix,
+                    default_topic_metadata_refresh_interval_ms: self
+                        .default_topic_metadata_refresh_interval_ms,
+                    egress_allowlist: plan_egress_allowlist(self.egress_allowlist)?,
                 })
             }
             CreateConnectionType::Csr => {
@@ -253,6 +350,73 @@ impl ConnectionOptionExtracted {
                     username,
                     password: self.password.map(|secret| secret.into()),
                 });
+                let oauth = match (
+                    self.oauth_token_endpoint,
+                    self.oauth_client_id,
+                    self.oauth_client_secret,
+                ) {
+                    (None, None, None) => {
+                        if self.oauth_scope.is_some() {
+                            sql_bail!(
+                                "invalid CONNECTION: OAUTH SCOPE requires OAUTH TOKEN ENDPOINT, OAUTH CLIENT ID, and OAUTH CLIENT SECRET"
+                            );
+                        }
+                        None
+                    }
+                    (Some(token_endpoint), Some(client_id), Some(client_secret)) => {
+                        let _: reqwest::Url = token_endpoint
+                            .parse()
+                            .map_err(|e| sql_err!("parsing OAuth token endpoint: {e}"))?;
+                        if http_auth.is_some() {
+                            sql_bail!(
+                                "invalid CONNECTION: cannot specify both USER and OAuth client credentials"
+                            );
+                        }
+                        Some(CsrConnectionOauth {
+                            token_endpoint,
+                            client_id,
+                            client_secret: client_secret.into(),
+                            scope: self.oauth_scope,
+                        })
+                    }
+                    _ => sql_bail!(
+                        "invalid CONNECTION: OAuth authentication requires OAUTH TOKEN ENDPOINT, OAUTH CLIENT ID, and OAUTH CLIENT SECRET"
+                    ),
+                };
+
+                let proxy = match self.proxy_url {
+                    None => {
+                        if self.proxy_user.is_some() || self.proxy_password.is_some() {
+                            sql_bail!(
+                                "invalid CONNECTION: PROXY USER and PROXY PASSWORD require PROXY URL"
+                            );
+                        }
+                        None
+                    }
+                    Some(proxy_url) => {
+                        let parsed: reqwest::Url = proxy_url
+                            .parse()
+                            .map_err(|e| sql_err!("parsing proxy url: {e}"))?;
+                        if !matches!(parsed.scheme(), "http" | "https") {
+                            sql_bail!(
+                                "invalid CONNECTION: PROXY URL must use the http or https scheme"
+                            );
+                        }
+                        if self.ssh_tunnel.is_some() || self.aws_privatelink.is_some() {
+                            sql_bail!(
+                                "invalid CONNECTION: cannot specify PROXY URL with SSH TUNNEL or AWS PRIVATELINK"
+                            );
+                        }
+                        if self.proxy_password.is_some() && self.proxy_user.is_none() {
+                            sql_bail!("invalid CONNECTION: PROXY PASSWORD requires PROXY USER");
+                        }
+                        Some(CsrConnectionProxy {
+                            url: proxy_url,
+                            username: self.proxy_user,
+                            password: self.proxy_password.map(|secret| secret.into()),
+                        })
+                    }
+                };
 
                 let tunnel = scx.build_tunnel_definition(self.ssh_tunnel, self.aws_privatelink)?;
 
@@ -261,6 +425,8 @@ impl ConnectionOptionExtracted {
                     tls_root_cert: self.ssl_certificate_authority,
                     tls_identity,
                     http_auth,
+                    oauth,
+                    proxy,
                     tunnel,
                 })
             }
@@ -288,6 +454,34 @@ impl ConnectionOptionExtracted {
                     Some(m) => sql_bail!("invalid CONNECTION: unknown SSL MODE {}", m.quoted()),
                 };
 
+                let aws_connection = match self.aws_connection {
+                    None => None,
+                    Some(aws_connection) => {
+                        let id = GlobalId::from(aws_connection);
+                        let item = scx.catalog.get_item(&id);
+                        match item.connection()? {
+                            Connection::Aws(_) => {}
+                            _ => sql_bail!("{} is not an AWS connection", item.name().item),
+                        }
+                        if self.password.is_some() {
+                            sql_bail!(
+                                "invalid CONNECTION: cannot specify both PASSWORD and AWS CONNECTION"
+                            );
+                        }
+                        // RDS and Aurora only accept IAM authentication over
+                        // TLS, as the token is sent in place of a password.
+                        if tls_mode == tokio_postgres::config::SslMode::Disable {
+                            sql_bail!(
+                                "invalid CONNECTION: AWS CONNECTION requires SSL MODE 'require', 'verify-ca', or 'verify-full'"
+                            );
+                        }
+                        Some(AwsConnectionReference {
+                            connection_id: id,
+                            connection: id,
+                        })
+                    }
+                };
+
                 let tunnel = scx.build_tunnel_definition(self.ssh_tunnel, self.aws_privatelink)?;
 
                 Connection::Postgres(PostgresConnection {
@@ -306,24 +500,45 @@ impl ConnectionOptionExtracted {
                     user: self
                         .user
                         .ok_or_else(|| sql_err!("USER option is required"))?,
+                    aws_connection,
+                    egress_allowlist: plan_egress_allowlist(self.egress_allowlist)?,
                 })
             }
-            CreateConnectionType::Ssh => Connection::Ssh(SshConnection {
-                host: self
-                    .host
-                    .ok_or_else(|| sql_err!("HOST option is required"))?,
-                port: self.port.unwrap_or(22_u16),
-                user: match self
-                    .user
-                    .ok_or_else(|| sql_err!("USER option is required"))?
-                {
-                    StringOrSecret::String(user) => user,
-                    StringOrSecret::Secret(_) => {
-                        sql_bail!("SSH connections do not support supplying USER value as SECRET")
+            CreateConnectionType::Ssh => {
+                // Connections to the bastion hosts are attempted in the order
+                // they are listed, so the first host is the primary.
+                let (host, fallback_hosts) = match (self.host, self.hosts) {
+                    (Some(_), Some(_)) => {
+                        sql_bail!("invalid CONNECTION: cannot set HOST and HOSTS")
                     }
-                },
-                public_keys: None,
-            }),
+                    (None, None) => sql_bail!("HOST option is required"),
+                    (Some(host), None) => (host, vec![]),
+                    (None, Some(hosts)) => {
+                        let mut hosts = hosts.into_iter();
+                        let Some(host) = hosts.next() else {
+                            sql_bail!("invalid CONNECTION: HOSTS must not be empty");
+                        };
+                        (host, hosts.collect())
+                    }
+                };
+                Connection::Ssh(SshConnection {
+                    host,
+                    fallback_hosts,
+                    port: self.port.unwrap_or(22_u16),
+                    user: match self
+                        .user
+                        .ok_or_else(|| sql_err!("USER option is required"))?
+                    {
+                        StringOrSecret::String(user) => user,
+                        StringOrSecret::Secret(_) => {
+                            sql_bail!(
+                                "SSH connections do not support supplying USER value as SECRET"
+                            )
+                        }
+                    },
+                    public_keys: None,
+                })
+            }
         };
 
         Ok(connection)
@@ -420,16 +635,55 @@ Instead, specify BROKERS using multiple strings, e.g. BROKERS ('kafka:9092', 'ka
     }
 }
 
+/// Plans the networks of an `EGRESS ALLOWLIST`, each of which is specified
+/// either in CIDR notation or as a single IP address, in canonical CIDR
+/// notation.
+fn plan_egress_allowlist(allowlist: Option<Vec<String>>) -> Result<Vec<String>, PlanError> {
+    let Some(allowlist) = allowlist else {
+        return Ok(vec![]);
+    };
+    if allowlist.is_empty() {
+        sql_bail!("invalid CONNECTION: EGRESS ALLOWLIST must not be empty");
+    }
+    allowlist
+        .into_iter()
+        .map(|network| {
+            let parsed = network
+                .parse::<IpNet>()
+                .or_else(|_| network.parse::<IpAddr>().map(IpNet::from))
+                .map_err(|_| {
+                    sql_err!(
+                        "invalid EGRESS ALLOWLIST entry {}: must be an IP address or a network in CIDR notation",
+                        network.quoted()
+                    )
+                })?;
+            Ok(parsed.trunc().to_string())
+        })
+        .collect()
+}
+
 fn plan_kafka_security(
+    scx: &StatementContext,
     v: &ConnectionOptionExtracted,
-) -> Result<(Option<KafkaTlsConfig>, Option<KafkaSaslConfig>), PlanError> {
-    const SASL_CONFIGS: [ConnectionOptionName; 3] = [
+) -> Result<
+    (
+        Option<KafkaTlsConfig>,
+        Option<KafkaSaslConfig<ReferencedConnection>>,
+    ),
+    PlanError,
+> {
+    const SASL_CONFIGS: [ConnectionOptionName; 8] = [
+        ConnectionOptionName::AwsConnection,
+        ConnectionOptionName::OauthClientId,
+        ConnectionOptionName::OauthClientSecret,
+        ConnectionOptionName::OauthScope,
+        ConnectionOptionName::OauthTokenEndpoint,
         ConnectionOptionName::SaslMechanisms,
         ConnectionOptionName::SaslUsername,
         ConnectionOptionName::SaslPassword,
     ];
 
-    const ALL_CONFIGS: [ConnectionOptionName; 6] = concat_arrays!(
+    const ALL_CONFIGS: [ConnectionOptionName; 11] = concat_arrays!(
         [
             ConnectionOptionName::SslKey,
             ConnectionOptionName::SslCertificate,
@@ -493,34 +747,99 @@ fn plan_kafka_security(
     let sasl = match security_protocol {
         SecurityProtocol::SaslPlaintext | SecurityProtocol::SaslSsl => {
             outstanding.remove(&ConnectionOptionName::SaslMechanisms);
-            outstanding.remove(&ConnectionOptionName::SaslUsername);
-            outstanding.remove(&ConnectionOptionName::SaslPassword);
             let Some(mechanism) = &v.sasl_mechanisms else {
                 // TODO(benesch): support a less confusing `SASL MECHANISM`
                 // alias, as only a single mechanism that can be specified.
                 sql_bail!("SASL MECHANISMS must be specified");
             };
-            let Some(username) = &v.sasl_username else {
-                sql_bail!("SASL USERNAME must be specified");
-            };
-            let Some(password) = &v.sasl_password else {
-                sql_bail!("SASL PASSWORD must be specified");
-            };
-            Some(KafkaSaslConfig {
-                // librdkafka requires SASL mechanisms to be upper case (PLAIN,
-                // SCRAM-SHA-256). For usability, we automatically uppercase the
-                // mechanism that user provides. This avoids a frustrating
-                // interaction with identifier case folding. Consider `SASL
-                // MECHANISMS = PLAIN`. Identifier case folding results in a
-                // SASL mechanism of `plain` (note the lowercase), which
-                // Materialize previously rejected with an error of "SASL
-                // mechanism must be uppercase." This was deeply frustarting for
-                // users who were not familiar with identifier case folding
-                // rules. See #22205.
-                mechanism: mechanism.to_uppercase(),
-                username: username.clone(),
-                password: (*password).into(),
-            })
+            // librdkafka requires SASL mechanisms to be upper case (PLAIN,
+            // SCRAM-SHA-256). For usability, we automatically uppercase the
+            // mechanism that user provides. This avoids a frustrating
+            // interaction with identifier case folding. Consider `SASL
+            // MECHANISMS = PLAIN`. Identifier case folding results in a SASL
+            // mechanism of `plain` (note the lowercase), which Materialize
+            // previously rejected with an error of "SASL mechanism must be
+            // uppercase." This was deeply frustarting for users who were not
+            // familiar with identifier case folding rules. See #22205.
+            let mechanism = mechanism.to_uppercase();
+            if mechanism == "AWS_MSK_IAM" {
+                // librdkafka does not support AWS IAM natively. Instead, the
+                // client signs a token with the credentials of the AWS
+                // connection and presents it via the `OAUTHBEARER` mechanism.
+                outstanding.remove(&ConnectionOptionName::AwsConnection);
+                let Some(aws_connection) = v.aws_connection else {
+                    sql_bail!(
+                        "AWS CONNECTION must be specified with SASL MECHANISMS 'AWS_MSK_IAM'"
+                    );
+                };
+                let id = GlobalId::from(aws_connection);
+                let item = scx.catalog.get_item(&id);
+                match item.connection()? {
+                    Connection::Aws(_) => {}
+                    _ => sql_bail!("{} is not an AWS connection", item.name().item),
+                }
+                Some(KafkaSaslConfig {
+                    mechanism: "OAUTHBEARER".into(),
+                    username: None,
+                    password: None,
+                    aws: Some(AwsConnectionReference {
+                        connection_id: id,
+                        connection: id,
+                    }),
+                    oauth: None,
+                })
+            } else if mechanism == "OAUTHBEARER" {
+                outstanding.remove(&ConnectionOptionName::OauthClientId);
+                outstanding.remove(&ConnectionOptionName::OauthClientSecret);
+                outstanding.remove(&ConnectionOptionName::OauthScope);
+                outstanding.remove(&ConnectionOptionName::OauthTokenEndpoint);
+                let Some(token_endpoint) = &v.oauth_token_endpoint else {
+                    sql_bail!(
+                        "OAUTH TOKEN ENDPOINT must be specified with SASL MECHANISMS 'OAUTHBEARER'"
+                    );
+                };
+                let _: reqwest::Url = token_endpoint
+                    .parse()
+                    .map_err(|e| sql_err!("parsing OAuth token endpoint: {e}"))?;
+                let Some(client_id) = &v.oauth_client_id else {
+                    sql_bail!(
+                        "OAUTH CLIENT ID must be specified with SASL MECHANISMS 'OAUTHBEARER'"
+                    );
+                };
+                let Some(client_secret) = &v.oauth_client_secret else {
+                    sql_bail!(
+                        "OAUTH CLIENT SECRET must be specified with SASL MECHANISMS 'OAUTHBEARER'"
+                    );
+                };
+                Some(KafkaSaslConfig {
+                    mechanism,
+                    username: None,
+                    password: None,
+                    aws: None,
+                    oauth: Some(KafkaSaslOauthConfig {
+                        token_endpoint: token_endpoint.clone(),
+                        client_id: client_id.clone(),
+                        client_secret: (*client_secret).into(),
+                        scope: v.oauth_scope.clone(),
+                    }),
+                })
+            } else {
+                outstanding.remove(&ConnectionOptionName::SaslUsername);
+                outstanding.remove(&ConnectionOptionName::SaslPassword);
+                let Some(username) = &v.sasl_username else {
+                    sql_bail!("SASL USERNAME must be specified");
+                };
+                let Some(password) = &v.sasl_password else {
+                    sql_bail!("SASL PASSWORD must be specified");
+                };
+                Some(KafkaSaslConfig {
+                    mechanism,
+                    username: Some(username.clone()),
+                    password: Some((*password).into()),
+                    aws: None,
+                    oauth: None,
+                })
+            }
         }
         _ => None,
     };
@@ -22,8 +22,9 @@ use mz_repr::adt::numeric::NumericMaxScale;
 use mz_repr::explain::{ExplainConfig, ExplainFormat};
 use mz_repr::{RelationDesc, ScalarType};
 use mz_sql_parser::ast::{
-    ExplainSinkSchemaFor, ExplainSinkSchemaStatement, ExplainTimestampStatement, Expr,
-    IfExistsBehavior, OrderByExpr, SubscribeOutput, UnresolvedItemName,
+    ExplainCreateSourceStatement, ExplainSinkSchemaFor, ExplainSinkSchemaStatement,
+    ExplainTimestampStatement, Expr, IfExistsBehavior, OrderByExpr, Statement, SubscribeOutput,
+    UnresolvedItemName,
 };
 use mz_sql_parser::ident;
 use mz_storage_types::sinks::{KafkaSinkConnection, KafkaSinkFormat, StorageSinkConnection};
@@ -43,11 +44,13 @@ use crate::plan::scope::Scope;
 use crate::plan::statement::{ddl, StatementContext, StatementDesc};
 use crate::plan::with_options::TryFromValue;
 use crate::plan::{
-    self, side_effecting_func, CreateSinkPlan, ExplainSinkSchemaPlan, ExplainTimestampPlan,
+    self, side_effecting_func, CreateSinkPlan, CreateSourcePlan, ExplainCreateSourcePlan,
+    ExplainSinkSchemaPlan, ExplainTimestampPlan,
 };
 use crate::plan::{
-    query, CopyFormat, CopyFromPlan, ExplainPlanPlan, InsertPlan, MutationKind, Params, Plan,
-    PlanError, QueryContext, ReadThenWritePlan, SelectPlan, SubscribeFrom, SubscribePlan,
+    query, CopyFormat, CopyFromPlan, ExplainPlanPlan, HirRelationExpr, InsertPlan, MutationKind,
+    Params, Plan, PlanError, QueryContext, ReadThenWritePlan, SelectPlan, SubscribeFrom,
+    SubscribePlan,
 };
 use crate::session::vars;
 
@@ -270,6 +273,15 @@ pub fn describe_explain_schema(
     Ok(StatementDesc::new(Some(relation_desc)))
 }
 
+pub fn describe_explain_create_source(
+    _: &StatementContext,
+    ExplainCreateSourceStatement { .. }: ExplainCreateSourceStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    let mut relation_desc = RelationDesc::empty();
+    relation_desc = relation_desc.with_column("Statement", ScalarType::String.nullable(false));
+    Ok(StatementDesc::new(Some(relation_desc)))
+}
+
 pub fn plan_explain_plan(
     scx: &StatementContext,
     ExplainPlanStatement {
@@ -472,6 +484,24 @@ pub fn plan_explain_schema(
     }
 }
 
+/// Plans the purified `CREATE SOURCE` statement of an `EXPLAIN CREATE SOURCE`,
+/// which validates it like `CREATE SOURCE` would, but creates nothing.
+pub fn plan_explain_create_source(
+    scx: &StatementContext,
+    ExplainCreateSourceStatement { statement }: ExplainCreateSourceStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let create_sql = Statement::CreateSource(statement.clone()).to_ast_string();
+    match ddl::plan_create_source(scx, statement)? {
+        Plan::CreateSource(CreateSourcePlan { name, .. }) => {
+            Ok(Plan::ExplainCreateSource(ExplainCreateSourcePlan {
+                name,
+                statements: vec![create_sql],
+            }))
+        }
+        _ => unreachable!("plan_create_source returns a CreateSourcePlan"),
+    }
+}
+
 pub fn plan_explain_timestamp(
     scx: &StatementContext,
     ExplainTimestampStatement { format, query }: ExplainTimestampStatement<Aug>,
@@ -624,12 +654,33 @@ pub fn plan_subscribe(
                     entry.item_type(),
                 ),
             };
+            // Plan the relation like a query that reads it, which applies the column masks of
+            // the active role, if any, and only subscribe to it directly if nothing is masked.
+            let qcx = QueryContext::root(scx, QueryLifetime::Subscribe);
+            let (expr, _) = qcx.resolve_table_name(name.clone())?;
             let item_name = match name {
                 ResolvedItemName::Item { full_name, .. } => Some(full_name.into()),
                 _ => None,
             };
             let scope = Scope::from_source(item_name, desc.iter().map(|(name, _type)| name));
-            (SubscribeFrom::Id(entry.id()), desc.into_owned(), scope)
+            match expr {
+                HirRelationExpr::Get { .. } => {
+                    (SubscribeFrom::Id(entry.id()), desc.into_owned(), scope)
+                }
+                expr => {
+                    let typ = expr.typ(&[], &scx.param_types.borrow());
+                    let desc = RelationDesc::new(typ, desc.iter_names());
+                    let expr = expr.lower(scx.catalog.system_vars())?;
+                    (
+                        SubscribeFrom::Query {
+                            expr,
+                            desc: desc.clone(),
+                        },
+                        desc,
+                        scope,
+                    )
+                }
+            }
         }
         SubscribeRelation::Query(query) => {
             let query = plan_query(scx, query, params, QueryLifetime::Subscribe)?;
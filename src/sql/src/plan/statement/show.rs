@@ -32,8 +32,8 @@ use crate::ast::{
 };
 use crate::catalog::{CatalogItemType, SessionCatalog};
 use crate::names::{
-    self, Aug, NameSimplifier, ResolvedClusterName, ResolvedDatabaseName, ResolvedIds,
-    ResolvedItemName, ResolvedRoleName, ResolvedSchemaName,
+    self, Aug, NameSimplifier, RawDatabaseSpecifier, ResolvedClusterName, ResolvedDatabaseName,
+    ResolvedIds, ResolvedItemName, ResolvedObjectName, ResolvedRoleName, ResolvedSchemaName,
 };
 use crate::parse;
 use crate::plan::scope::Scope;
@@ -299,6 +299,14 @@ pub fn show_objects<'a>(
             assert!(from.is_none(), "parser should reject from");
             show_role_membership(scx, role, filter)
         }
+        ShowObjectType::EffectivePrivileges {
+            role,
+            object_type,
+            object_name,
+        } => {
+            assert!(from.is_none(), "parser should reject from");
+            show_effective_privileges(scx, role, object_type, object_name, filter)
+        }
     }
 }
 
@@ -779,6 +787,93 @@ pub fn show_role_membership<'a>(
     )
 }
 
+pub fn show_effective_privileges<'a>(
+    scx: &'a StatementContext<'a>,
+    role: ResolvedRoleName,
+    object_type: SystemObjectType,
+    object_name: Option<ResolvedObjectName>,
+    filter: Option<ShowStatementFilter<Aug>>,
+) -> Result<ShowSelect<'a>, PlanError> {
+    fn database_filter(database: RawDatabaseSpecifier) -> String {
+        match database {
+            RawDatabaseSpecifier::Ambient => "database IS NULL".to_string(),
+            RawDatabaseSpecifier::Name(name) => format!("database = {}", Value::String(name)),
+        }
+    }
+
+    let role_filter = format!("role = {}", Value::String(role.name));
+    let mut query_filter = vec!["source = 'grant'".to_string()];
+    match object_name {
+        None => {
+            assert_eq!(
+                object_type,
+                SystemObjectType::System,
+                "parser should require an object name"
+            );
+            query_filter.push("object_type = 'system'".to_string());
+        }
+        Some(ResolvedObjectName::Cluster(cluster)) => {
+            let name = scx.catalog.get_cluster(cluster.id).name().to_string();
+            query_filter.push("object_type = 'cluster'".to_string());
+            query_filter.push(format!("name = {}", Value::String(name)));
+        }
+        Some(ResolvedObjectName::Database(database)) => {
+            let ResolvedDatabaseName::Database { name, .. } = database else {
+                unreachable!("should have been handled in name resolution")
+            };
+            query_filter.push("object_type = 'database'".to_string());
+            query_filter.push(format!("name = {}", Value::String(name)));
+        }
+        Some(ResolvedObjectName::Schema(schema)) => {
+            let ResolvedSchemaName::Schema { full_name, .. } = schema else {
+                unreachable!("should have been handled in name resolution")
+            };
+            query_filter.push("object_type = 'schema'".to_string());
+            query_filter.push(database_filter(full_name.database));
+            query_filter.push(format!("name = {}", Value::String(full_name.schema)));
+        }
+        Some(ResolvedObjectName::Item(item)) => {
+            let item = scx.get_item_by_resolved_name(&item)?;
+            let full_name = scx.catalog.resolve_full_name(item.name());
+            // Items of every type share a namespace within a schema, so the
+            // name alone identifies the item.
+            query_filter
+                .push("object_type NOT IN ('system', 'cluster', 'database', 'schema')".to_string());
+            query_filter.push(database_filter(full_name.database));
+            query_filter.push(format!("schema = {}", Value::String(full_name.schema)));
+            query_filter.push(format!("name = {}", Value::String(full_name.item)));
+        }
+        Some(ResolvedObjectName::ClusterReplica(_) | ResolvedObjectName::Role(_)) => {
+            unreachable!("parser should reject {object_type}")
+        }
+    }
+
+    // Superusers hold every privilege on every object, which is reported regardless of the
+    // object.
+    let query = format!(
+        "SELECT grantor, grantee, database, schema, name, object_type, privilege_type
+        FROM mz_internal.mz_show_effective_privileges
+        WHERE {role_filter} AND (source = 'superuser' OR ({}))",
+        itertools::join(query_filter, " AND "),
+    );
+
+    ShowSelect::new(
+        scx,
+        query,
+        filter,
+        None,
+        Some(&[
+            "grantor",
+            "grantee",
+            "database",
+            "schema",
+            "name",
+            "object_type",
+            "privilege_type",
+        ]),
+    )
+}
+
 /// An intermediate result when planning a `SHOW` query.
 ///
 /// Can be interrogated for its columns, or converted into a proper [`Plan`].
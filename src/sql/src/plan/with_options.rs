@@ -9,8 +9,9 @@
 
 //! Provides tooling to handle `WITH` options.
 
+use mz_repr::adt::numeric::Numeric;
 use mz_repr::{strconv, GlobalId};
-use mz_sql_parser::ast::{Ident, KafkaBroker, ReplicaDefinition};
+use mz_sql_parser::ast::{ClusterScheduleDefinition, Ident, KafkaBroker, ReplicaDefinition};
 use mz_storage_types::connections::StringOrSecret;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
@@ -376,6 +377,24 @@ impl ImpliedValue for u64 {
     }
 }
 
+impl TryFromValue<Value> for Numeric {
+    fn try_from_value(v: Value) -> Result<Self, PlanError> {
+        match v {
+            Value::Number(v) => Ok(strconv::parse_numeric(&v)?.0),
+            _ => sql_bail!("cannot use value as number"),
+        }
+    }
+    fn name() -> String {
+        "numeric".to_string()
+    }
+}
+
+impl ImpliedValue for Numeric {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide a numeric value")
+    }
+}
+
 impl<V: TryFromValue<WithOptionValue<Aug>>> TryFromValue<WithOptionValue<Aug>> for Vec<V> {
     fn try_from_value(v: WithOptionValue<Aug>) -> Result<Self, PlanError> {
         match v {
@@ -432,6 +451,7 @@ impl<V: TryFromValue<Value>, T: AstInfo + std::fmt::Debug> TryFromValue<WithOpti
             | WithOptionValue::Secret(_)
             | WithOptionValue::DataType(_)
             | WithOptionValue::ClusterReplicas(_)
+            | WithOptionValue::ClusterSchedule(_)
             | WithOptionValue::ConnectionKafkaBroker(_) => sql_bail!(
                 "incompatible value types: cannot convert {} to {}",
                 match v {
@@ -441,6 +461,7 @@ impl<V: TryFromValue<Value>, T: AstInfo + std::fmt::Debug> TryFromValue<WithOpti
                     WithOptionValue::Secret(_) => "secrets",
                     WithOptionValue::DataType(_) => "data types",
                     WithOptionValue::ClusterReplicas(_) => "cluster replicas",
+                    WithOptionValue::ClusterSchedule(_) => "cluster schedules",
                     WithOptionValue::ConnectionKafkaBroker(_) => "connection kafka brokers",
                     _ => unreachable!(),
                 },
@@ -483,6 +504,24 @@ impl ImpliedValue for Vec<ReplicaDefinition<Aug>> {
     }
 }
 
+impl TryFromValue<WithOptionValue<Aug>> for Vec<ClusterScheduleDefinition<Aug>> {
+    fn try_from_value(v: WithOptionValue<Aug>) -> Result<Self, PlanError> {
+        match v {
+            WithOptionValue::ClusterSchedule(entries) => Ok(entries),
+            _ => sql_bail!("cannot use value as cluster schedule"),
+        }
+    }
+    fn name() -> String {
+        "cluster schedule".to_string()
+    }
+}
+
+impl ImpliedValue for Vec<ClusterScheduleDefinition<Aug>> {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide a cluster schedule")
+    }
+}
+
 impl TryFromValue<WithOptionValue<Aug>> for Vec<KafkaBroker<Aug>> {
     fn try_from_value(v: WithOptionValue<Aug>) -> Result<Self, PlanError> {
         let mut out = vec![];
@@ -11,40 +11,63 @@
 //!
 //! See the [crate-level documentation](crate) for details.
 
+use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet};
 use std::iter;
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::anyhow;
-use mz_ccsr::{Client, GetByIdError, GetBySubjectError, Schema as CcsrSchema};
-use mz_kafka_util::client::{MzClientContext, DEFAULT_FETCH_METADATA_TIMEOUT};
+use aws_sdk_s3::primitives::ByteStream;
+use maplit::btreemap;
+use mz_ccsr::{
+    Client, CompatibilityError, GetByIdError, GetBySubjectError, Schema as CcsrSchema, SchemaType,
+    Subject,
+};
+use mz_iceberg_util::IcebergError;
+use mz_interchange::avro::{AvroSchemaGenerator, AvroSchemaOptions};
+use mz_kafka_util::client::{
+    MzClientContext, TunnelingClientContext, DEFAULT_FETCH_METADATA_TIMEOUT,
+};
+use mz_ore::collections::CollectionExt;
 use mz_ore::error::ErrorExt;
 use mz_ore::iter::IteratorExt;
 use mz_ore::str::StrExt;
+use mz_ore::task;
 use mz_postgres_util::replication::WalLevel;
 use mz_proto::RustType;
-use mz_repr::{strconv, GlobalId};
+use mz_repr::{strconv, GlobalId, RelationDesc, RelationType, ScalarType};
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{
+    AlterConnectionAction, AlterConnectionStatement, AlterSinkAction, AlterSinkStatement,
     AlterSourceAction, AlterSourceAddSubsourceOptionName, AlterSourceStatement, AvroDocOn,
     CreateSinkConnection, CreateSinkStatement, CreateSubsourceOption, CreateSubsourceOptionName,
     CsrConfigOption, CsrConfigOptionName, CsrConnection, CsrSeedAvro, CsrSeedProtobuf,
     CsrSeedProtobufSchema, DbzMode, DeferredItemName, DocOnIdentifier, DocOnSchema, Envelope,
-    Ident, KafkaConfigOption, KafkaConfigOptionName, KafkaConnection, KafkaSourceConnection,
-    PgConfigOption, PgConfigOptionName, RawItemName, ReaderSchemaSelectionStrategy, Statement,
-    UnresolvedItemName,
+    ExplainCreateSourceStatement, Ident, KafkaConfigOption, KafkaConfigOptionName, KafkaConnection,
+    KafkaSinkHeaders, KafkaSourceConnection, PgConfigOption, PgConfigOptionName, RawItemName,
+    ReaderSchemaSelectionStrategy, Statement, UnresolvedItemName,
 };
 use mz_storage_types::connections::inline::IntoInlineConnection;
 use mz_storage_types::connections::{Connection, ConnectionContext};
 use mz_storage_types::errors::ContextCreationError;
+use mz_storage_types::sinks::{
+    iceberg_column_type, IcebergCatalog, KafkaSinkCompressionType, KafkaSinkDeleteMode,
+    DEFAULT_TRANSACTIONAL_ID_PREFIX, DEFAULT_TRANSACTION_TIMEOUT,
+    ICEBERG_SINK_METADATA_COLUMN_TYPES, S3_SINK_METADATA_COLUMNS,
+};
 use mz_storage_types::sources::{
     GenericSourceConnection, PostgresSourcePublicationDetails, SourceConnection,
 };
 use prost::Message;
 use protobuf_native::compiler::{SourceTreeDescriptorDatabase, VirtualSourceTree};
 use protobuf_native::MessageLite;
-use rdkafka::admin::AdminClient;
+use rdkafka::admin::{AdminClient, AdminOptions, NewTopic, ResourceSpecifier, TopicReplication};
+use rdkafka::client::ClientContext;
+use rdkafka::error::{KafkaError, RDKafkaErrorCode};
+use rdkafka::metadata::{Metadata, MetadataBroker, MetadataTopic};
+use rdkafka::producer::{Producer, ThreadedProducer};
 use tracing::info;
 use uuid::Uuid;
 
@@ -57,18 +80,45 @@ use crate::catalog::{CatalogItemType, ErsatzCatalog, SessionCatalog};
 use crate::kafka_util::KafkaConfigOptionExtracted;
 use crate::names::{Aug, ResolvedColumnName, ResolvedItemName};
 use crate::plan::error::PlanError;
-use crate::plan::statement::ddl::load_generator_ast_to_generator;
-use crate::plan::StatementContext;
+use crate::plan::statement::ddl::{
+    load_generator_ast_to_generator, plan_character_encoding, plan_grok, plan_pg_sink_table,
+    plan_pg_sink_write_mode, plan_rotated_connection, CsrConfigOptionExtracted,
+    HttpSinkConfigOptionExtracted, IcebergSinkConfigOptionExtracted, PgSinkConfigOptionExtracted,
+    RedisSinkConfigOptionExtracted, S3SinkConfigOptionExtracted, UnknownEnumSymbolPolicy,
+};
+use crate::plan::{PlanNotice, StatementContext};
 use crate::{kafka_util, normalize};
 
 use self::error::{
-    CsrPurificationError, KafkaSinkPurificationError, KafkaSourcePurificationError,
-    LoadGeneratorSourcePurificationError, PgSourcePurificationError,
-    TestScriptSourcePurificationError,
+    CsrPurificationError, HttpSinkPurificationError, IcebergSinkPurificationError,
+    KafkaSinkPurificationError, KafkaSourcePurificationError, LoadGeneratorSourcePurificationError,
+    PgSinkPurificationError, PgSourcePurificationError, RedisSinkPurificationError,
+    S3SinkPurificationError, TestScriptSourcePurificationError,
 };
+use self::postgres::{PublicationInfoCache, PublicationTableFilter};
 
+mod egress;
 pub(crate) mod error;
 mod postgres;
+mod tls;
+
+/// How long to wait for the endpoint of an HTTP sink to respond to the
+/// preflight request.
+const HTTP_SINK_PREFLIGHT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long to wait for the server of a Redis sink to accept a connection.
+const REDIS_SINK_CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The number of existing keys under the prefix of a Redis sink whose type is
+/// checked against the sink's data structure.
+const REDIS_SINK_KEY_SAMPLE_SIZE: usize = 100;
+
+/// The table property that maps the column names of data files without field
+/// IDs, like those Iceberg sinks write, to field IDs.
+const ICEBERG_NAME_MAPPING_PROPERTY: &str = "schema.name-mapping.default";
+
+/// The internal topic in which Kafka brokers store the state of transactions.
+const KAFKA_TRANSACTION_STATE_TOPIC: &str = "__transaction_state";
 
 fn subsource_gen<'a, T>(
     selected_subsources: &mut Vec<CreateSourceSubsource<Aug>>,
@@ -136,6 +186,23 @@ fn subsource_name_gen(
     Ok(UnresolvedItemName::from(partial))
 }
 
+/// Reports whether the statement must be purified by [`purify_statement`]
+/// before it is planned.
+pub fn requires_purification(stmt: &Statement<Aug>) -> bool {
+    match stmt {
+        Statement::CreateSource(_)
+        | Statement::ExplainCreateSource(_)
+        | Statement::AlterSource(_)
+        | Statement::CreateSink(_)
+        | Statement::AlterSink(_) => true,
+        Statement::AlterConnection(stmt) => stmt
+            .actions
+            .iter()
+            .any(|action| matches!(action, AlterConnectionAction::RotateSecrets(_))),
+        _ => false,
+    }
+}
+
 /// Purifies a statement, removing any dependencies on external state.
 ///
 /// See the section on [purification](crate#purification) in the crate
@@ -152,17 +219,50 @@ pub async fn purify_statement(
     ),
     PlanError,
 > {
+    let mut publication_cache = PublicationInfoCache::default();
     match stmt {
         Statement::CreateSource(stmt) => {
-            purify_create_source(catalog, now, stmt, connection_context).await
+            purify_create_source(
+                catalog,
+                now,
+                stmt,
+                connection_context,
+                &mut publication_cache,
+            )
+            .await
+        }
+        Statement::ExplainCreateSource(ExplainCreateSourceStatement { statement }) => {
+            let (subsources, statement) = purify_create_source(
+                catalog,
+                now,
+                statement,
+                connection_context,
+                &mut publication_cache,
+            )
+            .await?;
+            let Statement::CreateSource(statement) = statement else {
+                unreachable!("purify_create_source returns a CREATE SOURCE statement")
+            };
+            Ok((
+                subsources,
+                Statement::ExplainCreateSource(ExplainCreateSourceStatement { statement }),
+            ))
         }
         Statement::AlterSource(stmt) => {
-            purify_alter_source(catalog, stmt, connection_context).await
+            purify_alter_source(catalog, stmt, connection_context, &mut publication_cache).await
         }
         Statement::CreateSink(stmt) => {
             let r = purify_create_sink(catalog, stmt, connection_context).await?;
             Ok((vec![], r))
         }
+        Statement::AlterSink(stmt) => {
+            let r = purify_alter_sink(catalog, stmt, connection_context).await?;
+            Ok((vec![], r))
+        }
+        Statement::AlterConnection(stmt) => {
+            let r = purify_alter_connection(catalog, stmt, connection_context).await?;
+            Ok((vec![], r))
+        }
         o => unreachable!("{:?} does not need to be purified", o),
     }
 }
@@ -265,41 +365,872 @@ pub(crate) fn add_materialize_comments(
     Ok(())
 }
 
+/// Checks that the columns an Iceberg sink writes for a relation with
+/// description `desc` match the columns of `schema`.
+fn validate_iceberg_sink_schema(
+    desc: &RelationDesc,
+    schema: &mz_iceberg_util::Schema,
+) -> Result<(), IcebergSinkPurificationError> {
+    let written: Vec<_> = desc
+        .iter()
+        .map(|(name, ty)| {
+            (
+                name.as_str(),
+                iceberg_column_type(&ty.scalar_type),
+                ty.nullable,
+            )
+        })
+        .chain(
+            S3_SINK_METADATA_COLUMNS
+                .into_iter()
+                .zip(ICEBERG_SINK_METADATA_COLUMN_TYPES)
+                .map(|(name, ty)| (name, ty, false)),
+        )
+        .collect();
+
+    for (name, expected, nullable) in &written {
+        let field = schema
+            .fields
+            .iter()
+            .find(|field| field.name == *name)
+            .ok_or_else(|| IcebergSinkPurificationError::MissingColumn(name.to_string()))?;
+        let found = match &field.field_type {
+            serde_json::Value::String(ty) => ty.clone(),
+            ty => ty.to_string(),
+        };
+        if found != *expected {
+            Err(IcebergSinkPurificationError::ColumnTypeMismatch {
+                column: name.to_string(),
+                found,
+                expected: expected.to_string(),
+            })?;
+        }
+        if field.required && *nullable {
+            Err(IcebergSinkPurificationError::NullableColumn(
+                name.to_string(),
+            ))?;
+        }
+    }
+
+    for field in &schema.fields {
+        if field.required && !written.iter().any(|(name, ..)| *name == field.name) {
+            Err(IcebergSinkPurificationError::UnwrittenColumn(
+                field.name.clone(),
+            ))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the `DELETE MODE` of a Kafka sink can be expressed in the sink's
+/// envelope.
+fn validate_kafka_sink_delete_mode(
+    envelope: Option<&Envelope>,
+    has_key: bool,
+    delete_mode: KafkaSinkDeleteMode,
+) -> Result<(), KafkaSinkPurificationError> {
+    match (envelope, delete_mode) {
+        // An upsert value is the row itself, so a deletion can only be
+        // represented by the absence of a value.
+        (Some(Envelope::Upsert), KafkaSinkDeleteMode::Record) => {
+            Err(KafkaSinkPurificationError::UnsupportedDeleteMode {
+                delete_mode: "record",
+                envelope: "UPSERT",
+            })
+        }
+        // A tombstone only identifies the deleted row by its key.
+        (_, KafkaSinkDeleteMode::Tombstone) if !has_key => {
+            Err(KafkaSinkPurificationError::TombstoneWithoutKey)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks that the `HEADERS FROM` columns of a Kafka sink exist in the relation
+/// with description `desc` and can be emitted as header values.
+fn validate_kafka_sink_headers(
+    scx: &StatementContext,
+    desc: &RelationDesc,
+    headers: &KafkaSinkHeaders,
+) -> Result<(), KafkaSinkPurificationError> {
+    for column in &headers.header_columns {
+        let name = normalize::column_name(column.clone());
+        let (_, ty) = desc
+            .get_by_name(&name)
+            .ok_or_else(|| KafkaSinkPurificationError::NoSuchHeaderColumn(name.to_string()))?;
+        if !matches!(
+            ty.scalar_type,
+            ScalarType::String
+                | ScalarType::Char { .. }
+                | ScalarType::VarChar { .. }
+                | ScalarType::Bytes
+        ) {
+            Err(KafkaSinkPurificationError::InvalidHeaderColumnType {
+                column: name.to_string(),
+                ty: scx.humanize_scalar_type(&ty.scalar_type),
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the `TOPIC FROM COLUMN` column of a Kafka sink exists in the
+/// relation with description `desc` and contains topic names.
+fn validate_kafka_sink_topic_column(
+    scx: &StatementContext,
+    desc: &RelationDesc,
+    column: &Ident,
+) -> Result<(), KafkaSinkPurificationError> {
+    let name = normalize::column_name(column.clone());
+    let (_, ty) = desc
+        .get_by_name(&name)
+        .ok_or_else(|| KafkaSinkPurificationError::NoSuchTopicColumn(name.to_string()))?;
+    if !matches!(
+        ty.scalar_type,
+        ScalarType::String | ScalarType::Char { .. } | ScalarType::VarChar { .. }
+    ) {
+        Err(KafkaSinkPurificationError::InvalidTopicColumnType {
+            column: name.to_string(),
+            ty: scx.humanize_scalar_type(&ty.scalar_type),
+        })?;
+    }
+    Ok(())
+}
+
+/// Checks the topic options of a Kafka sink against the limits of the cluster
+/// described by `metadata` and, if the sink's topic already exists, against
+/// the topic's configuration, which the sink does not alter.
+///
+/// Options that do not match the configuration of an existing topic are
+/// reported as a notice, or as an error if the
+/// `kafka_sink_topic_config_mismatch_error` system variable is set.
+async fn validate_kafka_sink_topic<C: ClientContext>(
+    catalog: &dyn SessionCatalog,
+    client: &AdminClient<C>,
+    metadata: &KafkaTopicMetadata,
+    options: &KafkaConfigOptionExtracted,
+) -> Result<(), KafkaSinkPurificationError> {
+    let admin_error = |e| {
+        KafkaSinkPurificationError::AdminClientError(Arc::new(ContextCreationError::KafkaError(e)))
+    };
+
+    let brokers = metadata.brokers().len();
+    for (option, replication_factor) in [
+        ("REPLICATION FACTOR", options.replication_factor),
+        (
+            "PROGRESS TOPIC REPLICATION FACTOR",
+            options.progress_topic_replication_factor,
+        ),
+    ] {
+        if usize::try_from(replication_factor).map_or(false, |factor| factor > brokers) {
+            Err(KafkaSinkPurificationError::ReplicationFactorTooLarge {
+                option,
+                replication_factor,
+                brokers,
+            })?;
+        }
+    }
+
+    if let Some(progress_topic) = &options.progress_topic {
+        validate_kafka_sink_progress_topic(client, metadata, progress_topic).await?;
+    }
+
+    if let Some(dead_letter_topic) = &options.dead_letter_topic {
+        validate_kafka_sink_dead_letter_topic(client, metadata, dead_letter_topic).await?;
+    }
+
+    let topic = match options.topic.as_ref() {
+        Some(topic) => topic,
+        None => return Ok(()),
+    };
+    let existing = match metadata.topic(topic) {
+        Some(existing) => existing,
+        None => return Ok(()),
+    };
+
+    let strict = catalog
+        .system_vars()
+        .kafka_sink_topic_config_mismatch_error();
+    let report = |setting: &'static str, expected: String, actual: String| {
+        if strict {
+            return Err(KafkaSinkPurificationError::TopicConfigMismatch {
+                topic: topic.clone(),
+                setting,
+                expected,
+                actual,
+            });
+        }
+        catalog.add_notice(PlanNotice::KafkaSinkTopicConfigMismatch {
+            topic: topic.clone(),
+            setting: setting.to_string(),
+            expected,
+            actual,
+        });
+        Ok(())
+    };
+
+    if options.partition_count != -1 {
+        let actual = existing.partitions().len();
+        if usize::try_from(options.partition_count).ok() != Some(actual) {
+            report(
+                "PARTITION COUNT",
+                options.partition_count.to_string(),
+                actual.to_string(),
+            )?;
+        }
+    }
+
+    if options.replication_factor != -1 {
+        // All partitions of a topic are created with the same number of
+        // replicas, unless they are reassigned by hand.
+        let actual = existing
+            .partitions()
+            .first()
+            .map_or(0, |partition| partition.replicas().len());
+        if usize::try_from(options.replication_factor).ok() != Some(actual) {
+            report(
+                "REPLICATION FACTOR",
+                options.replication_factor.to_string(),
+                actual.to_string(),
+            )?;
+        }
+    }
+
+    let retention = [
+        ("RETENTION MS", "retention.ms", options.retention_ms),
+        (
+            "RETENTION BYTES",
+            "retention.bytes",
+            options.retention_bytes,
+        ),
+    ];
+    if retention.iter().all(|(_, _, value)| value.is_none()) {
+        return Ok(());
+    }
+
+    let configs = client
+        .describe_configs(
+            &[ResourceSpecifier::Topic(topic)],
+            &AdminOptions::new().request_timeout(Some(DEFAULT_FETCH_METADATA_TIMEOUT)),
+        )
+        .await
+        .map_err(admin_error)
+        .and_then(|configs| {
+            configs
+                .into_iter()
+                .next()
+                .ok_or_else(|| KafkaSinkPurificationError::MissingTopicConfig(topic.clone()))?
+                .map_err(|e| admin_error(KafkaError::AdminOp(e)))
+        });
+    let config = match configs {
+        Ok(config) => config,
+        // The check is advisory unless mismatches are errors, so a topic
+        // whose configuration we cannot read is used as is.
+        Err(e) if !strict => {
+            info!("unable to read the configuration of sink topic {topic}: {e}");
+            return Ok(());
+        }
+        Err(e) => return Err(e),
+    };
+
+    for (setting, name, expected) in retention {
+        let expected = match expected {
+            Some(expected) => expected,
+            None => continue,
+        };
+        let actual = config.get(name).and_then(|entry| entry.value.clone());
+        match actual {
+            Some(actual) if actual.parse::<i64>().ok() == Some(expected) => (),
+            Some(actual) => report(setting, expected.to_string(), actual)?,
+            None if strict => Err(KafkaSinkPurificationError::MissingTopicConfig(
+                topic.clone(),
+            ))?,
+            None => (),
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the progress topic of a Kafka sink, if it already exists, has
+/// the single partition and compaction cleanup policy that sinks require of
+/// it.
+async fn validate_kafka_sink_progress_topic<C: ClientContext>(
+    client: &AdminClient<C>,
+    metadata: &KafkaTopicMetadata,
+    topic: &str,
+) -> Result<(), KafkaSinkPurificationError> {
+    let Some(existing) = metadata.topic(topic) else {
+        return Ok(());
+    };
+
+    let partitions = existing.partitions().len();
+    if partitions != 1 {
+        Err(KafkaSinkPurificationError::ProgressTopicPartitionCount {
+            topic: topic.to_string(),
+            partitions,
+        })?;
+    }
+
+    let admin_error = |e| {
+        KafkaSinkPurificationError::AdminClientError(Arc::new(ContextCreationError::KafkaError(e)))
+    };
+    let configs = client
+        .describe_configs(
+            &[ResourceSpecifier::Topic(topic)],
+            &AdminOptions::new().request_timeout(Some(DEFAULT_FETCH_METADATA_TIMEOUT)),
+        )
+        .await
+        .map_err(admin_error)?;
+    let cleanup_policy = configs
+        .into_iter()
+        .next()
+        .ok_or_else(|| KafkaSinkPurificationError::MissingTopicConfig(topic.to_string()))?
+        .map_err(|e| admin_error(KafkaError::AdminOp(e)))?
+        .get("cleanup.policy")
+        .and_then(|entry| entry.value.clone())
+        .ok_or_else(|| KafkaSinkPurificationError::MissingTopicConfig(topic.to_string()))?;
+    // A `delete` policy would eventually drop the latest progress record of a
+    // sink, which would then produce its output again.
+    if cleanup_policy != "compact" {
+        Err(KafkaSinkPurificationError::ProgressTopicNotCompacted {
+            topic: topic.to_string(),
+            cleanup_policy,
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Checks that the dead letter topic of a Kafka sink, if it already exists, is
+/// accessible to the sink.
+///
+/// Like the sink's topic, a dead letter topic that does not exist yet is
+/// created by the sink.
+async fn validate_kafka_sink_dead_letter_topic<C: ClientContext>(
+    client: &AdminClient<C>,
+    metadata: &KafkaTopicMetadata,
+    topic: &str,
+) -> Result<(), KafkaSinkPurificationError> {
+    let Some(existing) = metadata.topic(topic) else {
+        return Ok(());
+    };
+
+    if let Some(error) = existing.error() {
+        Err(KafkaSinkPurificationError::DeadLetterTopicInaccessible {
+            topic: topic.to_string(),
+            error: RDKafkaErrorCode::from(error).to_string(),
+        })?;
+    }
+
+    // Describing the topic's configuration fails if we lack permissions on
+    // it, rather than only once the sink first fails to encode a row.
+    let configs = client
+        .describe_configs(
+            &[ResourceSpecifier::Topic(topic)],
+            &AdminOptions::new().request_timeout(Some(DEFAULT_FETCH_METADATA_TIMEOUT)),
+        )
+        .await
+        .map_err(|e| {
+            KafkaSinkPurificationError::AdminClientError(Arc::new(
+                ContextCreationError::KafkaError(e),
+            ))
+        })?;
+    match configs.into_iter().next() {
+        Some(Ok(_)) => Ok(()),
+        Some(Err(e)) => Err(KafkaSinkPurificationError::DeadLetterTopicInaccessible {
+            topic: topic.to_string(),
+            error: e.to_string(),
+        }),
+        None => Err(KafkaSinkPurificationError::MissingTopicConfig(
+            topic.to_string(),
+        )),
+    }
+}
+
+/// Checks that the principal of a Kafka sink's connection has the permissions
+/// the sink needs once it runs: creating those of its `topics` that do not
+/// exist yet, and producing transactionally.
+///
+/// Kafka's admin API does not describe the ACLs of a principal, so we instead
+/// validate the creation of missing topics without creating them, and
+/// initialize transactions with a transactional ID with the same
+/// `transactional_id_prefix` as the sink's. The broker expires that
+/// transactional ID after `transactional.id.expiration.ms`.
+async fn validate_kafka_sink_permissions<C: ClientContext>(
+    client: &AdminClient<C>,
+    metadata: &KafkaTopicMetadata,
+    connection: &mz_storage_types::connections::KafkaConnection,
+    connection_context: &ConnectionContext,
+    topics: &[&str],
+    transactional_id_prefix: &str,
+) -> Result<(), KafkaSinkPurificationError> {
+    let admin_error = |e| {
+        KafkaSinkPurificationError::AdminClientError(Arc::new(ContextCreationError::KafkaError(e)))
+    };
+    let is_authorization_error = |code| {
+        matches!(
+            code,
+            RDKafkaErrorCode::TopicAuthorizationFailed
+                | RDKafkaErrorCode::ClusterAuthorizationFailed
+                | RDKafkaErrorCode::TransactionalIdAuthorizationFailed
+        )
+    };
+
+    let mut missing_topics = vec![];
+    for topic in topics {
+        match metadata.topic(topic) {
+            Some(existing) => {
+                if let Some(error) = existing.error() {
+                    if is_authorization_error(RDKafkaErrorCode::from(error)) {
+                        Err(KafkaSinkPurificationError::TopicPermissionDenied {
+                            topic: topic.to_string(),
+                            operation: "describe",
+                        })?;
+                    }
+                }
+            }
+            // Let the broker choose the partition count and replication
+            // factor, which are not what we are validating here.
+            None => missing_topics.push(NewTopic::new(topic, -1, TopicReplication::Fixed(-1))),
+        }
+    }
+
+    if !missing_topics.is_empty() {
+        let results = client
+            .create_topics(
+                &missing_topics,
+                &AdminOptions::new()
+                    .validate_only(true)
+                    .request_timeout(Some(DEFAULT_FETCH_METADATA_TIMEOUT)),
+            )
+            .await
+            .map_err(admin_error)?;
+        for result in results {
+            if let Err((topic, code)) = result {
+                if is_authorization_error(code) {
+                    Err(KafkaSinkPurificationError::TopicPermissionDenied {
+                        topic,
+                        operation: "create",
+                    })?;
+                }
+            }
+        }
+    }
+
+    let producer: ThreadedProducer<_> = connection
+        .create_with_context(
+            connection_context,
+            MzClientContext::default(),
+            &btreemap! {
+                "transactional.id" => format!(
+                    "{transactional_id_prefix}purification-{}",
+                    Uuid::new_v4()
+                ),
+            },
+        )
+        .await
+        .map_err(|e| KafkaSinkPurificationError::AdminClientError(Arc::new(e)))?;
+    let result = task::spawn_blocking(
+        || "kafka_sink_purification_init_transactions",
+        move || producer.init_transactions(DEFAULT_FETCH_METADATA_TIMEOUT),
+    )
+    .await
+    .unwrap_or(Err(KafkaError::Canceled));
+    match result {
+        Ok(()) => Ok(()),
+        Err(e) if e.rdkafka_error_code().map_or(false, is_authorization_error) => Err(
+            KafkaSinkPurificationError::TransactionPermissionDenied(e.to_string()),
+        ),
+        Err(e) => Err(admin_error(e)),
+    }
+}
+
+/// Checks that the brokers of the cluster described by `metadata` support the
+/// transactions of a Kafka sink whose transactions time out after
+/// `transaction_timeout`.
+///
+/// Brokers reject producers whose transaction timeout exceeds their
+/// `transaction.max.timeout.ms`, and cannot create the transaction state log
+/// until the cluster has `transaction.state.log.replication.factor` brokers, in
+/// which case producers wait for the log indefinitely.
+async fn validate_kafka_sink_transactions<C: ClientContext>(
+    client: &AdminClient<C>,
+    metadata: &KafkaTopicMetadata,
+    transaction_timeout: Duration,
+) -> Result<(), KafkaSinkPurificationError> {
+    let admin_error = |e| {
+        KafkaSinkPurificationError::AdminClientError(Arc::new(ContextCreationError::KafkaError(e)))
+    };
+    // Brokers only describe the transaction state log to principals that are
+    // authorized to describe it, so assume that it does not exist otherwise.
+    let has_transaction_state_log = metadata
+        .topic(KAFKA_TRANSACTION_STATE_TOPIC)
+        .map_or(false, |t| t.error().is_none());
+    let brokers = metadata.brokers().len();
+
+    for broker in metadata.brokers() {
+        let configs = client
+            .describe_configs(
+                &[ResourceSpecifier::Broker(broker.id())],
+                &AdminOptions::new().request_timeout(Some(DEFAULT_FETCH_METADATA_TIMEOUT)),
+            )
+            .await
+            .map_err(admin_error)?;
+        let Some(config) = configs
+            .into_iter()
+            .next()
+            .transpose()
+            .map_err(|e| admin_error(KafkaError::AdminOp(e)))?
+        else {
+            continue;
+        };
+        // Brokers that do not report these configurations, like Redpanda,
+        // are assumed to support transactions.
+        let get = |name| {
+            config
+                .get(name)
+                .and_then(|entry| entry.value.as_deref())
+                .and_then(|value| value.parse::<u64>().ok())
+        };
+
+        if let Some(max_timeout_ms) = get("transaction.max.timeout.ms") {
+            if transaction_timeout > Duration::from_millis(max_timeout_ms) {
+                Err(KafkaSinkPurificationError::TransactionTimeoutTooLarge {
+                    transaction_timeout,
+                    broker: broker.id(),
+                    max_timeout_ms,
+                })?;
+            }
+        }
+
+        if !has_transaction_state_log {
+            if let Some(replication_factor) = get("transaction.state.log.replication.factor") {
+                if usize::try_from(replication_factor).map_or(false, |factor| factor > brokers) {
+                    Err(KafkaSinkPurificationError::TransactionStateLogUnavailable {
+                        replication_factor,
+                        brokers,
+                    })?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the brokers of the cluster described by `metadata` accept
+/// messages compressed with `compression_type`.
+///
+/// Brokers only accept zstd-compressed messages as of Kafka 2.1. Brokers that
+/// do not report their protocol version, like Redpanda, are assumed to support
+/// every compression type.
+async fn validate_kafka_sink_compression<C: ClientContext>(
+    client: &AdminClient<C>,
+    metadata: &KafkaTopicMetadata,
+    compression_type: &KafkaSinkCompressionType,
+) -> Result<(), KafkaSinkPurificationError> {
+    if *compression_type != KafkaSinkCompressionType::Zstd {
+        return Ok(());
+    }
+
+    let admin_error = |e| {
+        KafkaSinkPurificationError::AdminClientError(Arc::new(ContextCreationError::KafkaError(e)))
+    };
+    for broker in metadata.brokers() {
+        let configs = client
+            .describe_configs(
+                &[ResourceSpecifier::Broker(broker.id())],
+                &AdminOptions::new().request_timeout(Some(DEFAULT_FETCH_METADATA_TIMEOUT)),
+            )
+            .await
+            .map_err(admin_error)?;
+        let version = configs
+            .into_iter()
+            .next()
+            .transpose()
+            .map_err(|e| admin_error(KafkaError::AdminOp(e)))?
+            .and_then(|config| {
+                config
+                    .get("inter.broker.protocol.version")
+                    .and_then(|entry| entry.value.clone())
+            });
+        let Some(version) = version else {
+            continue;
+        };
+        // Versions look like `3.5-IV2`.
+        let mut parts = version
+            .split('-')
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .map(|part| part.parse::<u64>().ok());
+        let major_minor = (parts.next().flatten(), parts.next().flatten());
+        if let (Some(major), Some(minor)) = major_minor {
+            if (major, minor) < (2, 1) {
+                Err(KafkaSinkPurificationError::UnsupportedCompressionType {
+                    compression_type: compression_type.to_librdkafka_option().into(),
+                    broker: broker.id(),
+                    version,
+                })?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Adds the default options of the Kafka connection named by `connection` to
+/// `options`, except for those that `options` already specifies.
+///
+/// Writing the defaults into the statement means that later changes to the
+/// connection's defaults do not affect existing sources and sinks.
+fn inherit_kafka_connection_defaults(
+    catalog: &dyn SessionCatalog,
+    connection: &ResolvedItemName,
+    options: &mut Vec<KafkaConfigOption<Aug>>,
+) -> Result<(), PlanError> {
+    let scx = StatementContext::new(None, catalog);
+    // Connections of the wrong type are reported by the caller.
+    let Connection::Kafka(connection) = scx.get_item_by_resolved_name(connection)?.connection()?
+    else {
+        return Ok(());
+    };
+    let defaults = [
+        (
+            KafkaConfigOptionName::GroupIdPrefix,
+            connection
+                .default_group_id_prefix
+                .clone()
+                .map(Value::String),
+        ),
+        (
+            KafkaConfigOptionName::TopicMetadataRefreshIntervalMs,
+            connection
+                .default_topic_metadata_refresh_interval_ms
+                .map(|ms| Value::Number(ms.to_string())),
+        ),
+    ];
+    for (name, value) in defaults {
+        if let Some(value) = value {
+            if !options.iter().any(|option| option.name == name) {
+                options.push(KafkaConfigOption {
+                    name,
+                    value: Some(WithOptionValue::Value(value)),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The metadata of the Kafka topics that purification inspects.
+///
+/// Fetching the metadata of every topic of a cluster is slow on clusters with
+/// thousands of topics, so the metadata of each topic is fetched on its own.
+/// Every metadata response describes all brokers of the cluster.
+struct KafkaTopicMetadata {
+    metadata: Vec<Metadata>,
+}
+
+impl KafkaTopicMetadata {
+    /// Returns the brokers of the cluster.
+    fn brokers(&self) -> &[MetadataBroker] {
+        self.metadata
+            .first()
+            .map(|metadata| metadata.brokers())
+            .unwrap_or_default()
+    }
+
+    /// Returns the metadata of `topic`, or `None` if the topic does not exist
+    /// or its metadata was not fetched.
+    fn topic(&self, topic: &str) -> Option<&MetadataTopic> {
+        self.metadata
+            .iter()
+            .flat_map(|metadata| metadata.topics())
+            .filter(|t| t.name() == topic)
+            .find(|t| {
+                !matches!(
+                    t.error().map(RDKafkaErrorCode::from),
+                    Some(
+                        RDKafkaErrorCode::UnknownTopic | RDKafkaErrorCode::UnknownTopicOrPartition
+                    )
+                )
+            })
+    }
+}
+
+/// Connects an admin client to the Kafka cluster of `connection` and fetches
+/// the metadata of `topics`, which fails if the cluster is unreachable or
+/// rejects the connection's credentials.
+///
+/// The metadata of all topics is only fetched if `topics` is empty, as the
+/// brokers of the cluster are otherwise unknown.
+async fn connect_kafka_admin_client(
+    connection: &mz_storage_types::connections::KafkaConnection,
+    connection_context: &ConnectionContext,
+    topics: &[&str],
+) -> Result<
+    (
+        AdminClient<TunnelingClientContext<MzClientContext>>,
+        KafkaTopicMetadata,
+    ),
+    PlanError,
+> {
+    connection
+        .validate_oauth_credentials(connection_context)
+        .await
+        .map_err(|e| {
+            KafkaSinkPurificationError::OauthTokenError(e.display_with_causes().to_string())
+        })?;
+
+    let client: AdminClient<_> = connection
+        .create_with_context(
+            connection_context,
+            MzClientContext::default(),
+            &BTreeMap::new(),
+        )
+        .await
+        .map_err(|e| {
+            // anyhow doesn't support Clone, so not trivial to move into PlanError
+            KafkaSinkPurificationError::AdminClientError(Arc::new(e))
+        })?;
+
+    let fetch_metadata = |topic: Option<&str>| {
+        client
+            .inner()
+            .fetch_metadata(topic, DEFAULT_FETCH_METADATA_TIMEOUT)
+            .map_err(|e| {
+                KafkaSinkPurificationError::AdminClientError(Arc::new(
+                    ContextCreationError::KafkaError(e),
+                ))
+            })
+    };
+    let metadata = if topics.is_empty() {
+        vec![fetch_metadata(None)?]
+    } else {
+        topics
+            .iter()
+            .map(|topic| fetch_metadata(Some(*topic)))
+            .collect::<Result<_, _>>()?
+    };
+    let metadata = KafkaTopicMetadata { metadata };
+
+    if metadata.brokers().len() == 0 {
+        Err(KafkaSinkPurificationError::ZeroBrokers)?;
+    }
+
+    Ok((client, metadata))
+}
+
+/// Connects to the schema registry of `connection` and lists its subjects,
+/// which fails if the registry is unreachable or rejects the connection's
+/// credentials.
+async fn list_csr_subjects(
+    connection: &mz_storage_types::connections::CsrConnection,
+    connection_context: &ConnectionContext,
+) -> Result<(mz_ccsr::Client, BTreeSet<String>), PlanError> {
+    let client = connection
+        .connect(connection_context)
+        .await
+        .map_err(|e| CsrPurificationError::ClientError(Arc::new(e)))?;
+    client
+        .authenticate()
+        .await
+        .map_err(|e| CsrPurificationError::OauthTokenError(Arc::new(e)))?;
+
+    let subjects = client
+        .list_subjects()
+        .await
+        .map_err(|e| CsrPurificationError::ListSubjectsError(Arc::new(e)))?
+        .into_iter()
+        .collect();
+
+    Ok((client, subjects))
+}
+
+/// Connects to the PostgreSQL server of `connection` and fetches its
+/// `wal_level`, which fails if the server is unreachable or rejects the
+/// connection's credentials.
+///
+/// Returns the client as well, so that callers can issue further queries
+/// without connecting again.
+async fn get_pg_wal_level(
+    connection: &mz_storage_types::connections::PostgresConnection,
+    connection_context: &ConnectionContext,
+) -> Result<(mz_postgres_util::Config, tokio_postgres::Client, WalLevel), PlanError> {
+    let config = connection.config(connection_context).await?;
+    let client = config
+        .connect(
+            "postgres_purification",
+            &connection_context.ssh_tunnel_manager,
+        )
+        .await?;
+
+    let wal_level = mz_postgres_util::get_wal_level(&client).await?;
+
+    Ok((config, client, wal_level))
+}
+
 /// Checks that the sink described in the statement can connect to its external
 /// resources.
 ///
-/// We must not leave any state behind in the Kafka broker, so just ensure that
-/// we can connect. This means we don't ensure that we can create the topic and
-/// introduces TOCTOU errors, but creating an inoperable sink is infinitely
-/// preferable to leaking state in users' environments.
+/// We must not leave any state behind in the Kafka broker, so we only validate
+/// that topics could be created rather than creating them, and only initialize
+/// transactions with a transactional ID the broker expires. This introduces
+/// TOCTOU errors, but creating an inoperable sink is infinitely preferable to
+/// leaking state in users' environments. For the same reason,
+/// the object S3 sinks write to check that their prefix is writeable is
+/// deleted immediately, and Iceberg sinks only read the metadata of their
+/// table.
 async fn purify_create_sink(
     catalog: impl SessionCatalog,
     mut stmt: CreateSinkStatement<Aug>,
     connection_context: ConnectionContext,
 ) -> Result<Statement<Aug>, PlanError> {
     add_materialize_comments(&catalog, &mut stmt)?;
+    if let CreateSinkConnection::Kafka {
+        connection: KafkaConnection {
+            connection,
+            options,
+        },
+        ..
+    } = &mut stmt.connection
+    {
+        inherit_kafka_connection_defaults(&catalog, connection, options)?;
+    }
     // General purification
     let CreateSinkStatement {
         connection, format, ..
     } = &stmt;
 
-    match &connection {
+    let (topic, has_key) = match &connection {
         CreateSinkConnection::Kafka {
             connection:
                 KafkaConnection {
                     connection,
                     options,
                 },
-            key: _,
+            key,
+            headers,
         } => {
             let scx = StatementContext::new(None, &catalog);
-            let mut connection = {
+
+            if let Some(headers) = headers {
+                let from = scx.get_item_by_resolved_name(&stmt.from)?;
+                let desc = from.desc(&scx.catalog.resolve_full_name(from.name()))?;
+                validate_kafka_sink_headers(&scx, &desc, headers)?;
+            }
+            let connection_name = connection.full_item_name().clone();
+            let (connection_id, mut connection) = {
                 let item = scx.get_item_by_resolved_name(connection)?;
                 // Get Kafka connection
                 match item.connection()? {
-                    Connection::Kafka(connection) => {
-                        connection.clone().into_inline_connection(scx.catalog)
-                    }
+                    Connection::Kafka(connection) => (
+                        item.id(),
+                        connection.clone().into_inline_connection(scx.catalog),
+                    ),
                     _ => sql_bail!(
                         "{} is not a kafka connection",
                         scx.catalog.resolve_full_name(item.name())
@@ -307,38 +1238,454 @@ async fn purify_create_sink(
                 }
             };
 
+            egress::validate_kafka_egress(&connection_name, &connection).await?;
+            tls::check_kafka_certificates(
+                &catalog,
+                &*connection_context.secrets_reader,
+                &connection_name,
+                &connection,
+            )
+            .await?;
+
             let extracted_options: KafkaConfigOptionExtracted = options.clone().try_into()?;
 
+            if let Some(column) = &extracted_options.topic_from_column {
+                let from = scx.get_item_by_resolved_name(&stmt.from)?;
+                let desc = from.desc(&scx.catalog.resolve_full_name(from.name()))?;
+                validate_kafka_sink_topic_column(&scx, &desc, column)?;
+            }
+
+            if let Some(delete_mode) = extracted_options.delete_mode {
+                validate_kafka_sink_delete_mode(
+                    stmt.envelope.as_ref(),
+                    key.is_some(),
+                    delete_mode,
+                )?;
+            }
+
             for (k, v) in kafka_util::LibRdKafkaConfig::try_from(&extracted_options)?.0 {
                 connection.options.insert(k, v);
             }
 
-            let client: AdminClient<_> = connection
-                .create_with_context(
-                    &connection_context,
-                    MzClientContext::default(),
-                    &BTreeMap::new(),
+            // The sink creates its topics, including the progress topic of
+            // the connection if it does not override it and the topics it
+            // routes messages to, when it first runs.
+            let progress_topic = match &extracted_options.progress_topic {
+                Some(progress_topic) => Cow::Borrowed(progress_topic.as_str()),
+                None => connection.progress_topic(&connection_context, connection_id),
+            };
+            let topics = extracted_options
+                .topic
+                .as_deref()
+                .into_iter()
+                .chain(iter::once(&*progress_topic))
+                .chain(extracted_options.dead_letter_topic.as_deref())
+                .chain(
+                    extracted_options
+                        .topic_allowlist
+                        .iter()
+                        .flatten()
+                        .map(|topic| topic.as_str()),
                 )
-                .await
-                .map_err(|e| {
-                    // anyhow doesn't support Clone, so not trivial to move into PlanError
-                    KafkaSinkPurificationError::AdminClientError(Arc::new(e))
+                .collect::<Vec<_>>();
+
+            let (client, metadata) = connect_kafka_admin_client(
+                &connection,
+                &connection_context,
+                &topics
+                    .iter()
+                    .copied()
+                    .chain(iter::once(KAFKA_TRANSACTION_STATE_TOPIC))
+                    .collect::<Vec<_>>(),
+            )
+            .await?;
+
+            validate_kafka_sink_topic(&catalog, &client, &metadata, &extracted_options).await?;
+            validate_kafka_sink_transactions(
+                &client,
+                &metadata,
+                extracted_options
+                    .transaction_timeout_ms
+                    .and_then(|ms| u64::try_from(ms).ok())
+                    .map_or(DEFAULT_TRANSACTION_TIMEOUT, Duration::from_millis),
+            )
+            .await?;
+            validate_kafka_sink_permissions(
+                &client,
+                &metadata,
+                &connection,
+                &connection_context,
+                &topics,
+                extracted_options
+                    .transactional_id_prefix
+                    .as_deref()
+                    .unwrap_or(DEFAULT_TRANSACTIONAL_ID_PREFIX),
+            )
+            .await?;
+            validate_kafka_sink_compression(
+                &client,
+                &metadata,
+                &extracted_options.compression_type,
+            )
+            .await?;
+
+            (extracted_options.topic, key.is_some())
+        }
+        CreateSinkConnection::Postgres {
+            connection,
+            options,
+            key,
+        } => {
+            let scx = StatementContext::new(None, &catalog);
+            let connection_name = connection.full_item_name().clone();
+            let connection = {
+                let item = scx.get_item_by_resolved_name(connection)?;
+                match item.connection()? {
+                    Connection::Postgres(connection) => {
+                        connection.clone().into_inline_connection(scx.catalog)
+                    }
+                    _ => Err(PgSinkPurificationError::NotPgConnection(
+                        scx.catalog.resolve_full_name(item.name()),
+                    ))?,
+                }
+            };
+            egress::validate_postgres_egress(&connection_name, &connection).await?;
+            tls::check_postgres_certificates(
+                &catalog,
+                &*connection_context.secrets_reader,
+                &connection_name,
+                &connection,
+            )
+            .await?;
+
+            let PgSinkConfigOptionExtracted {
+                table,
+                create_table,
+                write_mode,
+                ..
+            } = options.clone().try_into()?;
+            let (schema, table) = plan_pg_sink_table(table)?;
+            let write_mode = plan_pg_sink_write_mode(&write_mode)?;
+
+            let from = scx.get_item_by_resolved_name(&stmt.from)?;
+            let desc = from.desc(&scx.catalog.resolve_full_name(from.name()))?;
+            // Missing keys and unknown key columns are reported during
+            // planning.
+            let key_columns = key
+                .iter()
+                .flat_map(|key| key.key_columns.iter().cloned())
+                .map(normalize::column_name)
+                .collect::<Vec<_>>();
+
+            if !key_columns.is_empty()
+                && key_columns
+                    .iter()
+                    .all(|col| desc.get_by_name(col).is_some())
+            {
+                let config = connection.config(&connection_context).await?;
+
+                postgres::validate_sink_table(
+                    &config,
+                    &connection_context.ssh_tunnel_manager,
+                    &schema,
+                    &table,
+                    &desc,
+                    &key_columns,
+                    create_table,
+                    write_mode,
+                )
+                .await?;
+            }
+
+            (None, key.is_some())
+        }
+        CreateSinkConnection::S3 {
+            connection,
+            options,
+        } => {
+            let scx = StatementContext::new(None, &catalog);
+            let (connection_id, connection) = {
+                let item = scx.get_item_by_resolved_name(connection)?;
+                match item.connection()? {
+                    Connection::Aws(connection) => (item.id(), connection.clone()),
+                    _ => Err(S3SinkPurificationError::NotAwsConnection(
+                        scx.catalog.resolve_full_name(item.name()),
+                    ))?,
+                }
+            };
+
+            let S3SinkConfigOptionExtracted { bucket, prefix, .. } = options.clone().try_into()?;
+            // A missing bucket is reported during planning.
+            if let Some(bucket) = bucket {
+                let sdk_config = connection
+                    .load(
+                        connection_context.aws_external_id_prefix.as_ref(),
+                        Some(&connection_id),
+                        &*connection_context.secrets_reader,
+                    )
+                    .await;
+                let client = mz_aws_s3_util::new_client(&sdk_config);
+
+                client
+                    .head_bucket()
+                    .bucket(&bucket)
+                    .send()
+                    .await
+                    .map_err(|e| S3SinkPurificationError::BucketAccess {
+                        bucket: bucket.clone(),
+                        cause: e.display_with_causes().to_string(),
+                    })?;
+
+                let prefix_error = |e: String| S3SinkPurificationError::PrefixNotWriteable {
+                    bucket: bucket.clone(),
+                    prefix: prefix.clone(),
+                    cause: e,
+                };
+                let probe = format!("{}materialize-sink-probe-{}", prefix, Uuid::new_v4());
+                client
+                    .put_object()
+                    .bucket(&bucket)
+                    .key(&probe)
+                    .body(ByteStream::from_static(&[]))
+                    .send()
+                    .await
+                    .map_err(|e| prefix_error(e.display_with_causes().to_string()))?;
+                client
+                    .delete_object()
+                    .bucket(&bucket)
+                    .key(&probe)
+                    .send()
+                    .await
+                    .map_err(|e| prefix_error(e.display_with_causes().to_string()))?;
+            }
+
+            (None, false)
+        }
+        CreateSinkConnection::Http { options, key } => {
+            let HttpSinkConfigOptionExtracted { url, headers, .. } = options.clone().try_into()?;
+            // A missing URL is reported during planning.
+            if let Some(url) = url {
+                let secrets_reader = &*connection_context.secrets_reader;
+                let url = url.get_string(secrets_reader).await?;
+                let url = reqwest::Url::parse(&url)
+                    .map_err(|e| HttpSinkPurificationError::InvalidUrl(e.to_string()))?;
+
+                // Send an empty batch, which receivers must accept anyway, to
+                // check that the endpoint is reachable and accepts the
+                // credentials in the headers.
+                let mut request = reqwest::Client::new()
+                    .post(url)
+                    .timeout(HTTP_SINK_PREFLIGHT_TIMEOUT)
+                    .header(http::header::CONTENT_TYPE, "application/json")
+                    .body("[]");
+                for (name, value) in headers {
+                    request = request.header(name, value.get_string(secrets_reader).await?);
+                }
+                let response = request.send().await.map_err(|e| {
+                    HttpSinkPurificationError::ConnectionFailed(
+                        e.without_url().display_with_causes().to_string(),
+                    )
                 })?;
 
-            let metadata = client
-                .inner()
-                .fetch_metadata(None, DEFAULT_FETCH_METADATA_TIMEOUT)
-                .map_err(|e| {
-                    KafkaSinkPurificationError::AdminClientError(Arc::new(
-                        ContextCreationError::KafkaError(e),
-                    ))
+                let status = response.status();
+                if status == http::StatusCode::UNAUTHORIZED || status == http::StatusCode::FORBIDDEN
+                {
+                    Err(HttpSinkPurificationError::Unauthorized(status.as_u16()))?;
+                } else if !status.is_success() {
+                    Err(HttpSinkPurificationError::ErrorStatus(status.as_u16()))?;
+                }
+            }
+
+            (None, key.is_some())
+        }
+        CreateSinkConnection::Redis { options, key } => {
+            let RedisSinkConfigOptionExtracted {
+                url,
+                password,
+                key_prefix,
+                data_structure,
+                ..
+            } = options.clone().try_into()?;
+            // A missing URL is reported during planning.
+            if let Some(url) = url {
+                let secrets_reader = &*connection_context.secrets_reader;
+                let url = url.get_string(secrets_reader).await?;
+                let mut info = redis::IntoConnectionInfo::into_connection_info(url.as_str())
+                    .map_err(|e| RedisSinkPurificationError::InvalidUrl(e.to_string()))?;
+                if let Some(password) = password {
+                    info.redis.password = Some(password.get_string(secrets_reader).await?);
+                }
+                let client = redis::Client::open(info)
+                    .map_err(|e| RedisSinkPurificationError::InvalidUrl(e.to_string()))?;
+                let redis_error = |e: redis::RedisError| {
+                    let is_auth_error = e.kind() == redis::ErrorKind::AuthenticationFailed
+                        || matches!(e.code(), Some("NOAUTH" | "WRONGPASS"));
+                    if is_auth_error {
+                        RedisSinkPurificationError::AuthenticationFailed(e.to_string())
+                    } else {
+                        RedisSinkPurificationError::ConnectionFailed(e.to_string())
+                    }
+                };
+
+                // Connecting authenticates with the password, if any, and the
+                // PING fails if the server requires a password that was not
+                // provided.
+                let mut conn =
+                    tokio::time::timeout(REDIS_SINK_CONNECT_TIMEOUT, client.get_async_connection())
+                        .await
+                        .map_err(|_| {
+                            RedisSinkPurificationError::ConnectionFailed(
+                                "timed out connecting to server".into(),
+                            )
+                        })?
+                        .map_err(redis_error)?;
+                redis::cmd("PING")
+                    .query_async::<_, String>(&mut conn)
+                    .await
+                    .map_err(redis_error)?;
+
+                // Keys without a prefix may belong to anything, so only keys
+                // under a prefix are expected to match the data structure.
+                // Invalid data structures are reported during planning.
+                let expected = data_structure.to_lowercase();
+                if !key_prefix.is_empty() && matches!(expected.as_str(), "string" | "hash") {
+                    let mut pattern = String::new();
+                    for c in key_prefix.chars() {
+                        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+                            pattern.push('\\');
+                        }
+                        pattern.push(c);
+                    }
+                    pattern.push('*');
+                    let (_cursor, keys): (u64, Vec<String>) = redis::cmd("SCAN")
+                        .arg(0)
+                        .arg("MATCH")
+                        .arg(&pattern)
+                        .arg("COUNT")
+                        .arg(REDIS_SINK_KEY_SAMPLE_SIZE)
+                        .query_async(&mut conn)
+                        .await
+                        .map_err(redis_error)?;
+                    for key in keys {
+                        let found: String = redis::cmd("TYPE")
+                            .arg(&key)
+                            .query_async(&mut conn)
+                            .await
+                            .map_err(redis_error)?;
+                        // The key may have been deleted since the scan.
+                        if found != expected && found != "none" {
+                            Err(RedisSinkPurificationError::WrongDataStructure {
+                                key,
+                                found,
+                                expected: expected.clone(),
+                            })?;
+                        }
+                    }
+                }
+            }
+
+            (None, key.is_some())
+        }
+        CreateSinkConnection::Iceberg {
+            connection,
+            options,
+        } => {
+            let scx = StatementContext::new(None, &catalog);
+            let (connection_id, connection) = {
+                let item = scx.get_item_by_resolved_name(connection)?;
+                match item.connection()? {
+                    Connection::Aws(connection) => (item.id(), connection.clone()),
+                    _ => Err(IcebergSinkPurificationError::NotAwsConnection(
+                        scx.catalog.resolve_full_name(item.name()),
+                    ))?,
+                }
+            };
+
+            let IcebergSinkConfigOptionExtracted {
+                catalog: iceberg_catalog,
+                catalog_url,
+                catalog_token,
+                namespace,
+                table,
+                ..
+            } = options.clone().try_into()?;
+            // Invalid catalogs and missing options are reported during
+            // planning.
+            let iceberg_catalog = match iceberg_catalog.map(|c| c.to_lowercase()).as_deref() {
+                Some("glue") => Some(IcebergCatalog::Glue),
+                Some("rest") => catalog_url.map(|url| IcebergCatalog::Rest {
+                    url,
+                    token: catalog_token,
+                }),
+                _ => None,
+            };
+            if let (Some(iceberg_catalog), Some(namespace), Some(table)) =
+                (iceberg_catalog, namespace, table)
+            {
+                let sdk_config = connection
+                    .load(
+                        connection_context.aws_external_id_prefix.as_ref(),
+                        Some(&connection_id),
+                        &*connection_context.secrets_reader,
+                    )
+                    .await;
+                let iceberg_catalog = iceberg_catalog
+                    .connect(&sdk_config, &*connection_context.secrets_reader)
+                    .await
+                    .map_err(|e| {
+                        IcebergSinkPurificationError::CatalogUnreachable(
+                            e.display_with_causes().to_string(),
+                        )
+                    })?;
+
+                let name = format!("{}.{}", namespace, table);
+                let table = iceberg_catalog
+                    .load_table(&namespace, &table)
+                    .await
+                    .map_err(|e| match e {
+                        IcebergError::TableNotFound { .. } => {
+                            IcebergSinkPurificationError::TableNotFound(name.clone())
+                        }
+                        IcebergError::NotIcebergTable { .. } => {
+                            IcebergSinkPurificationError::NotIcebergTable(name.clone())
+                        }
+                        IcebergError::UnsupportedFormatVersion(version) => {
+                            IcebergSinkPurificationError::UnsupportedFormatVersion {
+                                table: name.clone(),
+                                version,
+                            }
+                        }
+                        e => IcebergSinkPurificationError::TableAccess {
+                            table: name.clone(),
+                            cause: e.display_with_causes().to_string(),
+                        },
+                    })?;
+
+                if table.metadata.is_partitioned() {
+                    Err(IcebergSinkPurificationError::PartitionedTable(name))?;
+                }
+                if !table
+                    .metadata
+                    .properties
+                    .contains_key(ICEBERG_NAME_MAPPING_PROPERTY)
+                {
+                    Err(IcebergSinkPurificationError::MissingNameMapping(name))?;
+                }
+                let schema = table.metadata.current_schema().ok_or_else(|| {
+                    IcebergSinkPurificationError::TableAccess {
+                        table: name,
+                        cause: "table has no current schema".into(),
+                    }
                 })?;
 
-            if metadata.brokers().len() == 0 {
-                Err(KafkaSinkPurificationError::ZeroBrokers)?;
+                let from = scx.get_item_by_resolved_name(&stmt.from)?;
+                let desc = from.desc(&scx.catalog.resolve_full_name(from.name()))?;
+                validate_iceberg_sink_schema(&desc, schema)?;
             }
+
+            (None, false)
         }
-    }
+    };
 
     if let Some(format) = format {
         match format {
@@ -348,6 +1695,8 @@ async fn purify_create_sink(
             | Format::Protobuf(ProtobufSchema::Csr {
                 csr_connection: CsrConnectionProtobuf { connection, .. },
             }) => {
+                let connection_options = &connection.options;
+                let connection_name = connection.connection.full_item_name().clone();
                 let connection = {
                     let scx = StatementContext::new(None, &catalog);
                     let item = scx.get_item_by_resolved_name(&connection.connection)?;
@@ -362,15 +1711,51 @@ async fn purify_create_sink(
                     }
                 };
 
-                let client = connection
-                    .connect(&connection_context)
-                    .await
-                    .map_err(|e| CsrPurificationError::ClientError(Arc::new(e)))?;
+                tls::check_csr_certificates(
+                    &catalog,
+                    &*connection_context.secrets_reader,
+                    &connection_name,
+                    &connection,
+                )
+                .await?;
 
-                client
-                    .list_subjects()
-                    .await
-                    .map_err(|e| CsrPurificationError::ListSubjectsError(Arc::new(e)))?;
+                let (client, subjects) =
+                    list_csr_subjects(&connection, &connection_context).await?;
+
+                // Publishing a schema to a subject that only exists in
+                // soft-deleted state fails in ways that are hard to diagnose
+                // once the sink is running, so check for it up front.
+                if let Some(topic) = topic {
+                    let mut sink_subjects = vec![format!("{}-value", topic)];
+                    if has_key {
+                        sink_subjects.push(format!("{}-key", topic));
+                    }
+                    if sink_subjects.iter().any(|s| !subjects.contains(s)) {
+                        let all_subjects: BTreeSet<_> = client
+                            .list_subjects_including_deleted()
+                            .await
+                            .map_err(|e| CsrPurificationError::ListSubjectsError(Arc::new(e)))?
+                            .into_iter()
+                            .collect();
+                        if let Some(subject) = sink_subjects
+                            .into_iter()
+                            .find(|s| !subjects.contains(s) && all_subjects.contains(s))
+                        {
+                            Err(CsrPurificationError::SinkSubjectSoftDeleted(subject))?;
+                        }
+                    }
+
+                    if let Format::Avro(_) = format {
+                        validate_sink_avro_schemas(
+                            &catalog,
+                            &stmt,
+                            connection_options,
+                            &client,
+                            &topic,
+                        )
+                        .await?;
+                    }
+                }
             }
             Format::Avro(AvroSchema::InlineSchema { .. })
             | Format::Bytes
@@ -378,7 +1763,9 @@ async fn purify_create_sink(
             | Format::Json
             | Format::Protobuf(ProtobufSchema::InlineSchema { .. })
             | Format::Regex(..)
-            | Format::Text => {}
+            | Format::Grok(..)
+            | Format::Text { .. }
+            | Format::Parquet => {}
         }
     }
 
@@ -390,6 +1777,7 @@ async fn purify_create_source(
     now: u64,
     mut stmt: CreateSourceStatement<Aug>,
     connection_context: ConnectionContext,
+    publication_cache: &mut PublicationInfoCache,
 ) -> Result<
     (
         Vec<(GlobalId, CreateSubsourceStatement<Aug>)>,
@@ -455,7 +1843,10 @@ async fn purify_create_source(
                 ))?;
             }
 
+            inherit_kafka_connection_defaults(&catalog, connection, base_with_options)?;
+
             let scx = StatementContext::new(None, &catalog);
+            let connection_name = connection.full_item_name().clone();
             let mut connection = {
                 let item = scx.get_item_by_resolved_name(connection)?;
                 // Get Kafka connection
@@ -469,6 +1860,15 @@ async fn purify_create_source(
                 }
             };
 
+            egress::validate_kafka_egress(&connection_name, &connection).await?;
+            tls::check_kafka_certificates(
+                &catalog,
+                &*connection_context.secrets_reader,
+                &connection_name,
+                &connection,
+            )
+            .await?;
+
             let extracted_options: KafkaConfigOptionExtracted =
                 base_with_options.clone().try_into()?;
 
@@ -483,6 +1883,15 @@ async fn purify_create_source(
                 .topic
                 .ok_or(KafkaSourcePurificationError::ConnectionMissingTopic)?;
 
+            connection
+                .validate_oauth_credentials(&connection_context)
+                .await
+                .map_err(|e| {
+                    KafkaSourcePurificationError::OauthTokenError(
+                        e.display_with_causes().to_string(),
+                    )
+                })?;
+
             let consumer = connection
                 .create_with_context(
                     &connection_context,
@@ -547,6 +1956,7 @@ async fn purify_create_source(
             options,
         } => {
             let scx = StatementContext::new(None, &catalog);
+            let connection_name = connection.full_item_name().clone();
             let connection = {
                 let item = scx.get_item_by_resolved_name(connection)?;
                 match item.connection().map_err(PlanError::from)? {
@@ -558,6 +1968,14 @@ async fn purify_create_source(
                     ))?,
                 }
             };
+            egress::validate_postgres_egress(&connection_name, &connection).await?;
+            tls::check_postgres_certificates(
+                &catalog,
+                &*connection_context.secrets_reader,
+                &connection_name,
+                &connection,
+            )
+            .await?;
             let crate::plan::statement::PgConfigOptionExtracted {
                 publication,
                 mut text_columns,
@@ -571,53 +1989,55 @@ async fn purify_create_source(
                 Err(PgSourcePurificationError::UserSpecifiedDetails)?;
             }
 
-            // verify that we can connect upstream and snapshot publication metadata
-            let config = connection
-                .config(&*connection_context.secrets_reader)
-                .await?;
-
-            let wal_level =
-                mz_postgres_util::get_wal_level(&connection_context.ssh_tunnel_manager, &config)
-                    .await?;
+            // verify that we can connect upstream and snapshot publication
+            // metadata. All of the checks below reuse this connection.
+            let (config, client, wal_level) =
+                get_pg_wal_level(&connection, &connection_context).await?;
 
             if wal_level < WalLevel::Logical {
                 Err(PgSourcePurificationError::InsufficientWalLevel { wal_level })?;
             }
 
-            let max_wal_senders = mz_postgres_util::get_max_wal_senders(
-                &connection_context.ssh_tunnel_manager,
-                &config,
-            )
-            .await?;
+            let max_wal_senders = mz_postgres_util::get_max_wal_senders(&client).await?;
 
             if max_wal_senders < 1 {
                 Err(PgSourcePurificationError::ReplicationDisabled)?;
             }
 
-            let available_replication_slots = mz_postgres_util::available_replication_slots(
-                &connection_context.ssh_tunnel_manager,
-                &config,
-            )
-            .await?;
+            let available_replication_slots =
+                mz_postgres_util::available_replication_slots(&client).await?;
 
             // We need 1 replication slot for the snapshots and 1 for the continuing replication
             if available_replication_slots < 2 {
                 Err(PgSourcePurificationError::InsufficientReplicationSlotsAvailable { count: 2 })?;
             }
 
-            let publication_tables = mz_postgres_util::publication_info(
-                &connection_context.ssh_tunnel_manager,
-                &config,
-                &publication,
-                None,
-            )
-            .await?;
+            // Only fetch the schemas of the tables the statement refers to.
+            let table_filter = match referenced_subsources
+                .as_ref()
+                .ok_or(PgSourcePurificationError::RequiresReferencedSubsources)?
+            {
+                ReferencedSubsources::All => PublicationTableFilter::All,
+                ReferencedSubsources::SubsetSchemas(schemas) => PublicationTableFilter::Schemas(
+                    schemas.iter().map(|s| s.as_str().to_string()).collect(),
+                ),
+                ReferencedSubsources::SubsetTables(subsources) => {
+                    PublicationTableFilter::for_references(
+                        subsources.iter().map(|subsource| &subsource.reference),
+                        &text_columns,
+                    )
+                }
+            };
 
-            if publication_tables.is_empty() {
-                Err(PgSourcePurificationError::EmptyPublication(
-                    publication.to_string(),
-                ))?;
-            }
+            let publication_tables = publication_cache
+                .publication_info(
+                    &config,
+                    &client,
+                    &publication,
+                    table_filter,
+                    &connection_context.ssh_tunnel_manager,
+                )
+                .await?;
 
             let publication_catalog = postgres::derive_catalog_from_publication_tables(
                 &connection.database,
@@ -641,14 +2061,11 @@ async fn purify_create_source(
                     }
                 }
                 ReferencedSubsources::SubsetSchemas(schemas) => {
-                    let available_schemas: BTreeSet<_> = mz_postgres_util::get_schemas(
-                        &connection_context.ssh_tunnel_manager,
-                        &config,
-                    )
-                    .await?
-                    .into_iter()
-                    .map(|s| s.name)
-                    .collect();
+                    let available_schemas: BTreeSet<_> = mz_postgres_util::get_schemas(&client)
+                        .await?
+                        .into_iter()
+                        .map(|s| s.name)
+                        .collect();
 
                     let requested_schemas: BTreeSet<_> =
                         schemas.iter().map(|s| s.as_str().to_string()).collect();
@@ -697,10 +2114,13 @@ async fn purify_create_source(
                 );
             }
 
+            // Report all problems with the requested subsources at once.
+            let mut violations = vec![];
             postgres::validate_requested_subsources(
                 &config,
+                &client,
                 &validated_requested_subsources,
-                &connection_context.ssh_tunnel_manager,
+                &mut violations,
             )
             .await?;
 
@@ -732,7 +2152,9 @@ async fn purify_create_source(
                 text_cols_dict,
                 get_transient_subsource_id,
                 &publication_tables,
+                &mut violations,
             )?;
+            postgres::check_subsource_violations(violations)?;
 
             *referenced_subsources = Some(ReferencedSubsources::SubsetTables(targeted_subsources));
             subsources.extend(new_subsources);
@@ -894,6 +2316,121 @@ async fn purify_create_source(
     Ok((subsources, Statement::CreateSource(stmt)))
 }
 
+/// Equivalent to `purify_create_sink` but for `AlterSinkStatement`.
+///
+/// Changing the relation a sink reads from re-purifies the sink's definition
+/// against the new relation, which re-validates its Kafka topics and checks
+/// that the schemas generated for any columns the new relation adds are
+/// compatible with those in the schema registry. The statement itself is
+/// returned unmodified.
+async fn purify_alter_sink(
+    catalog: impl SessionCatalog,
+    stmt: AlterSinkStatement<Aug>,
+    connection_context: ConnectionContext,
+) -> Result<Statement<Aug>, PlanError> {
+    let AlterSinkStatement {
+        sink_name,
+        if_exists,
+        action,
+    } = &stmt;
+
+    // If there's no further work to do here, early return.
+    let AlterSinkAction::ChangeRelation(from) = action else {
+        return Ok(Statement::AlterSink(stmt));
+    };
+
+    let create_sink_stmt = {
+        let scx = StatementContext::new(None, &catalog);
+        let item = match scx.resolve_item(RawItemName::Name(sink_name.clone())) {
+            Ok(item) => item,
+            Err(_) if *if_exists => return Ok(Statement::AlterSink(stmt)),
+            Err(e) => return Err(e),
+        };
+        if item.item_type() != CatalogItemType::Sink {
+            sql_bail!(
+                "\"{}\" is a {} not a sink",
+                scx.catalog.resolve_full_name(item.name()),
+                item.item_type()
+            )
+        }
+
+        let create_sql = crate::parse::parse(item.create_sql())?.into_element().ast;
+        match crate::names::resolve(scx.catalog, create_sql)? {
+            (Statement::CreateSink(mut create_sink_stmt), _) => {
+                create_sink_stmt.from = from.clone();
+                create_sink_stmt
+            }
+            _ => unreachable!("proved type is sink"),
+        }
+    };
+
+    purify_create_sink(catalog, create_sink_stmt, connection_context).await?;
+
+    Ok(Statement::AlterSink(stmt))
+}
+
+/// Equivalent to `purify_create_sink` but for `AlterConnectionStatement`.
+///
+/// Rotating the secrets of a connection validates the connection with the new
+/// secrets against the upstream system, so that the secrets are only swapped
+/// in the catalog if the connection would continue to work. The statement
+/// itself is returned unmodified.
+async fn purify_alter_connection(
+    catalog: impl SessionCatalog,
+    stmt: AlterConnectionStatement<Aug>,
+    connection_context: ConnectionContext,
+) -> Result<Statement<Aug>, PlanError> {
+    let AlterConnectionStatement {
+        name,
+        if_exists,
+        actions,
+        ..
+    } = &stmt;
+
+    // If there's no further work to do here, early return.
+    let Some(rotated_options) = actions.iter().find_map(|action| match action {
+        AlterConnectionAction::RotateSecrets(options) => Some(options),
+        _ => None,
+    }) else {
+        return Ok(Statement::AlterConnection(stmt));
+    };
+
+    let connection = {
+        let scx = StatementContext::new(None, &catalog);
+        let item = match scx.resolve_item(RawItemName::Name(name.clone())) {
+            Ok(item) => item,
+            Err(_) if *if_exists => return Ok(Statement::AlterConnection(stmt)),
+            Err(e) => return Err(e),
+        };
+        if item.item_type() != CatalogItemType::Connection {
+            sql_bail!(
+                "\"{}\" is a {} not a connection",
+                scx.catalog.resolve_full_name(item.name()),
+                item.item_type()
+            )
+        }
+
+        plan_rotated_connection(&scx, item, rotated_options)?.into_inline_connection(&catalog)
+    };
+
+    match &connection {
+        Connection::Kafka(connection) => {
+            connect_kafka_admin_client(connection, &connection_context, &[]).await?;
+        }
+        Connection::Csr(connection) => {
+            list_csr_subjects(connection, &connection_context).await?;
+        }
+        Connection::Postgres(connection) => {
+            get_pg_wal_level(connection, &connection_context).await?;
+        }
+        Connection::Aws(_) | Connection::AwsPrivatelink(_) | Connection::Ssh(_) => {
+            unreachable!("only Kafka, CSR, and PostgreSQL connections support ROTATE SECRETS")
+        }
+    }
+
+    Ok(Statement::AlterConnection(stmt))
+}
+
 /// Equivalent to `purify_create_source` but for `AlterSourceStatement`.
 ///
 /// On success, returns the `GlobalId` and `CreateSubsourceStatement`s for any
@@ -904,6 +2441,7 @@ async fn purify_alter_source(
     catalog: impl SessionCatalog,
     mut stmt: AlterSourceStatement<Aug>,
     connection_context: ConnectionContext,
+    publication_cache: &mut PublicationInfoCache,
 ) -> Result<
     (
         Vec<(GlobalId, CreateSubsourceStatement<Aug>)>,
@@ -983,34 +2521,41 @@ async fn purify_alter_source(
     // Get PostgresConnection for generating subsources.
     let pg_connection = &pg_source_connection.connection;
 
-    let config = pg_connection
-        .config(&*connection_context.secrets_reader)
+    let config = pg_connection.config(&connection_context).await?;
+    // All of the checks below reuse this connection.
+    let client = config
+        .connect(
+            "postgres_purification",
+            &connection_context.ssh_tunnel_manager,
+        )
         .await?;
 
-    let available_replication_slots = mz_postgres_util::available_replication_slots(
-        &connection_context.ssh_tunnel_manager,
-        &config,
-    )
-    .await?;
+    let available_replication_slots =
+        mz_postgres_util::available_replication_slots(&client).await?;
 
     // We need 1 additional replication slot for the snapshots
     if available_replication_slots < 1 {
         Err(PgSourcePurificationError::InsufficientReplicationSlotsAvailable { count: 1 })?;
     }
 
-    let mut publication_tables = mz_postgres_util::publication_info(
-        &connection_context.ssh_tunnel_manager,
-        &config,
-        &pg_source_connection.publication,
-        None,
-    )
-    .await?;
-
-    if publication_tables.is_empty() {
-        Err(PgSourcePurificationError::EmptyPublication(
-            pg_source_connection.publication.to_string(),
-        ))?;
-    }
+    // Only fetch the schemas of the tables being added. The schemas of the
+    // tables the source already ingests are carried over from its current
+    // details below.
+    let table_filter = PublicationTableFilter::for_references(
+        targeted_subsources
+            .iter()
+            .map(|subsource| &subsource.reference),
+        &text_columns,
+    );
+    let mut publication_tables = publication_cache
+        .publication_info(
+            &config,
+            &client,
+            &pg_source_connection.publication,
+            table_filter,
+            &connection_context.ssh_tunnel_manager,
+        )
+        .await?;
 
     let publication_catalog = postgres::derive_catalog_from_publication_tables(
         &pg_connection.database,
@@ -1039,18 +2584,21 @@ async fn purify_alter_source(
         );
     }
 
+    // Report all problems with the requested subsources at once.
+    let mut violations = vec![];
     for (upstream_name, _, _) in validated_requested_subsources.iter() {
         if current_subsources.contains_key(upstream_name) {
-            Err(PgSourcePurificationError::SubsourceAlreadyReferredTo {
+            violations.push(PgSourcePurificationError::SubsourceAlreadyReferredTo {
                 name: upstream_name.clone(),
-            })?;
+            });
         }
     }
 
     postgres::validate_requested_subsources(
         &config,
+        &client,
         &validated_requested_subsources,
-        &connection_context.ssh_tunnel_manager,
+        &mut violations,
     )
     .await?;
     let mut subsource_id_counter = 0;
@@ -1087,7 +2635,9 @@ async fn purify_alter_source(
         text_cols_dict,
         get_transient_subsource_id,
         &publication_tables,
+        &mut violations,
     )?;
+    postgres::check_subsource_violations(violations)?;
 
     *targeted_subsources = named_subsources;
 
@@ -1153,18 +2703,43 @@ async fn purify_source_format(
         sql_bail!("Kafka sources are the only source type that can provide KEY/VALUE formats")
     }
 
+    // Shared by the key and value formats so that they do not each construct
+    // their own client and re-fetch the same subjects.
+    let mut csr_cache = CsrClientCache::default();
+
     match format {
         CreateSourceFormat::None => {}
         CreateSourceFormat::Bare(format) => {
-            purify_source_format_single(catalog, format, connection, envelope, connection_context)
-                .await?;
+            purify_source_format_single(
+                catalog,
+                format,
+                connection,
+                envelope,
+                connection_context,
+                &mut csr_cache,
+            )
+            .await?;
         }
 
         CreateSourceFormat::KeyValue { key, value: val } => {
-            purify_source_format_single(catalog, key, connection, envelope, connection_context)
-                .await?;
-            purify_source_format_single(catalog, val, connection, envelope, connection_context)
-                .await?;
+            purify_source_format_single(
+                catalog,
+                key,
+                connection,
+                envelope,
+                connection_context,
+                &mut csr_cache,
+            )
+            .await?;
+            purify_source_format_single(
+                catalog,
+                val,
+                connection,
+                envelope,
+                connection_context,
+                &mut csr_cache,
+            )
+            .await?;
         }
     }
     Ok(())
@@ -1176,6 +2751,7 @@ async fn purify_source_format_single(
     connection: &mut CreateSourceConnection<Aug>,
     envelope: &Option<Envelope>,
     connection_context: &ConnectionContext,
+    csr_cache: &mut CsrClientCache,
 ) -> Result<(), PlanError> {
     match format {
         Format::Avro(schema) => match schema {
@@ -1186,6 +2762,7 @@ async fn purify_source_format_single(
                     csr_connection,
                     envelope,
                     connection_context,
+                    csr_cache,
                 )
                 .await?
             }
@@ -1199,12 +2776,27 @@ async fn purify_source_format_single(
                     csr_connection,
                     envelope,
                     connection_context,
+                    csr_cache,
                 )
                 .await?;
             }
             ProtobufSchema::InlineSchema { .. } => {}
         },
-        Format::Bytes | Format::Regex(_) | Format::Json | Format::Text | Format::Csv { .. } => (),
+        Format::Text { encoding } | Format::Csv { encoding, .. } => {
+            // Reject unsupported encodings before the source is created, and
+            // record the canonical name of supported ones.
+            if let Some(name) = encoding {
+                *name = plan_character_encoding(Some(name.as_str()))?
+                    .name()
+                    .to_string();
+            }
+        }
+        Format::Grok(pattern) => {
+            // Compile the pattern now so that mistakes in it are reported
+            // before the source is created.
+            plan_grok(pattern)?;
+        }
+        Format::Bytes | Format::Regex(_) | Format::Json | Format::Parquet => (),
     }
     Ok(())
 }
@@ -1215,6 +2807,7 @@ async fn purify_csr_connection_proto(
     csr_connection: &mut CsrConnectionProtobuf<Aug>,
     envelope: &Option<Envelope>,
     connection_context: &ConnectionContext,
+    csr_cache: &mut CsrClientCache,
 ) -> Result<(), PlanError> {
     let topic = if let CreateSourceConnection::Kafka(KafkaSourceConnection {
         connection: KafkaConnection { options, .. },
@@ -1234,29 +2827,34 @@ async fn purify_csr_connection_proto(
         seed,
         connection: CsrConnection {
             connection,
-            options: _,
+            options,
         },
     } = csr_connection;
     match seed {
         None => {
-            let scx = StatementContext::new(None, &*catalog);
-
-            let ccsr_connection = match scx.get_item_by_resolved_name(connection)?.connection()? {
-                Connection::Csr(connection) => connection.clone().into_inline_connection(catalog),
-                _ => sql_bail!("{} is not a schema registry connection", connection),
-            };
-
-            let ccsr_client = ccsr_connection
-                .connect(connection_context)
-                .await
-                .map_err(|e| CsrPurificationError::ClientError(Arc::new(e)))?;
+            let CsrConfigOptionExtracted {
+                include_deleted_subjects,
+                ..
+            } = options.clone().try_into()?;
+            let ccsr_client = csr_cache
+                .client(catalog, connection, connection_context)
+                .await?;
 
-            let value = compile_proto(&format!("{}-value", topic), &ccsr_client).await?;
-            let key = compile_proto(&format!("{}-key", topic), &ccsr_client)
-                .await
-                .ok();
+            let value_subject = format!("{}-value", topic);
+            let value = compile_proto(&value_subject, ccsr_client, include_deleted_subjects)
+                .await?
+                .ok_or_else(|| PlanError::FetchingCsrSchemaFailed {
+                    schema_lookup: format!("subject {}", value_subject.quoted()),
+                    cause: Arc::new(GetBySubjectError::SubjectNotFound),
+                })?;
+            let key = compile_proto(
+                &format!("{}-key", topic),
+                ccsr_client,
+                include_deleted_subjects,
+            )
+            .await?;
 
-            if matches!(envelope, Some(Envelope::Debezium(DbzMode::Plain))) && key.is_none() {
+            if matches!(envelope, Some(Envelope::Debezium(_))) && key.is_none() {
                 sql_bail!("Key schema is required for ENVELOPE DEBEZIUM");
             }
 
@@ -1274,6 +2872,7 @@ async fn purify_csr_connection_avro(
     csr_connection: &mut CsrConnectionAvro<Aug>,
     envelope: &Option<Envelope>,
     connection_context: &ConnectionContext,
+    csr_cache: &mut CsrClientCache,
 ) -> Result<(), PlanError> {
     let topic = if let CreateSourceConnection::Kafka(KafkaSourceConnection {
         connection: KafkaConnection { options, .. },
@@ -1290,36 +2889,52 @@ async fn purify_csr_connection_avro(
     };
 
     let CsrConnectionAvro {
-        connection: CsrConnection { connection, .. },
+        connection: CsrConnection {
+            connection,
+            options,
+        },
         seed,
         key_strategy,
         value_strategy,
     } = csr_connection;
     if seed.is_none() {
-        let scx = StatementContext::new(None, &*catalog);
-        let csr_connection = match scx.get_item_by_resolved_name(connection)?.connection()? {
-            Connection::Csr(connection) => connection.clone().into_inline_connection(catalog),
-            _ => sql_bail!("{} is not a schema registry connection", connection),
-        };
-        let ccsr_client = csr_connection
-            .connect(connection_context)
-            .await
-            .map_err(|e| CsrPurificationError::ClientError(Arc::new(e)))?;
+        let CsrConfigOptionExtracted {
+            include_deleted_subjects,
+            unknown_enum_symbol,
+            ..
+        } = options.clone().try_into()?;
+
+        let ccsr_client = csr_cache
+            .client(catalog, connection, connection_context)
+            .await?;
 
         let Schema {
             key_schema,
             value_schema,
         } = get_remote_csr_schema(
-            &ccsr_client,
+            ccsr_client,
             key_strategy.clone().unwrap_or_default(),
             value_strategy.clone().unwrap_or_default(),
             topic,
+            include_deleted_subjects,
         )
         .await?;
-        if matches!(envelope, Some(Envelope::Debezium(DbzMode::Plain))) && key_schema.is_none() {
+        if matches!(envelope, Some(Envelope::Debezium(_))) && key_schema.is_none() {
             sql_bail!("Key schema is required for ENVELOPE DEBEZIUM");
         }
 
+        // Record the unknown enum symbol policy in the seeded reader schemas,
+        // where Avro schema resolution will apply it.
+        let (key_schema, value_schema) = match unknown_enum_symbol {
+            None | Some(UnknownEnumSymbolPolicy::Error) => (key_schema, value_schema),
+            Some(UnknownEnumSymbolPolicy::Default(symbol)) => (
+                key_schema
+                    .map(|schema| set_avro_enum_defaults(&schema, &symbol))
+                    .transpose()?,
+                set_avro_enum_defaults(&value_schema, &symbol)?,
+            ),
+        };
+
         *seed = Some(CsrSeedAvro {
             key_schema,
             value_schema,
@@ -1329,6 +2944,182 @@ async fn purify_csr_connection_avro(
     Ok(())
 }
 
+/// Schema registry clients and the responses fetched through them, cached for
+/// the duration of a single purification pass.
+///
+/// Statements may consult the same schema registry connection several times,
+/// e.g. once for the key and once for the value format, which would otherwise
+/// construct a new client and issue a new round trip each time.
+#[derive(Debug, Default)]
+struct CsrClientCache {
+    clients: BTreeMap<GlobalId, CachedCsrClient>,
+}
+
+impl CsrClientCache {
+    /// Returns the cached client for the schema registry connection named by
+    /// `connection`, connecting to the registry if this is the first use of the
+    /// connection in this pass.
+    async fn client(
+        &mut self,
+        catalog: &dyn SessionCatalog,
+        connection: &ResolvedItemName,
+        connection_context: &ConnectionContext,
+    ) -> Result<&mut CachedCsrClient, PlanError> {
+        let id = *connection.item_id();
+        if !self.clients.contains_key(&id) {
+            let scx = StatementContext::new(None, &*catalog);
+            let csr_connection = match scx.get_item_by_resolved_name(connection)?.connection()? {
+                Connection::Csr(connection) => connection.clone().into_inline_connection(catalog),
+                _ => sql_bail!("{} is not a schema registry connection", connection),
+            };
+            tls::check_csr_certificates(
+                catalog,
+                &*connection_context.secrets_reader,
+                connection.full_item_name(),
+                &csr_connection,
+            )
+            .await?;
+            let client = csr_connection
+                .connect(connection_context)
+                .await
+                .map_err(|e| CsrPurificationError::ClientError(Arc::new(e)))?;
+            // Obtain an access token up front if the connection uses OAuth,
+            // so that rejected client credentials are reported as such rather
+            // than as a failure of whichever lookup happens to run first.
+            client
+                .authenticate()
+                .await
+                .map_err(|e| CsrPurificationError::OauthTokenError(Arc::new(e)))?;
+            // Likewise, check that the registry is reachable through the
+            // proxy, if any, so that a misconfigured proxy is reported as such
+            // rather than as a failure to fetch a particular schema.
+            if let Some(proxy) = &csr_connection.proxy {
+                client.list_subjects().await.map_err(|e| {
+                    CsrPurificationError::ProxyConnectionError {
+                        url: proxy.url.clone(),
+                        error: Arc::new(e),
+                    }
+                })?;
+            }
+            self.clients.insert(id, CachedCsrClient::new(client));
+        }
+        Ok(self.clients.get_mut(&id).expect("inserted above"))
+    }
+}
+
+/// A schema registry client that remembers the successful responses to its
+/// lookups. Errors are never cached.
+#[derive(Debug)]
+struct CachedCsrClient {
+    client: Client,
+    schemas_by_subject: BTreeMap<(String, bool), Option<CcsrSchema>>,
+    schemas_by_id: BTreeMap<i32, Option<CcsrSchema>>,
+    subjects_and_references: BTreeMap<(String, bool), (Subject, Vec<Subject>)>,
+}
+
+impl CachedCsrClient {
+    fn new(client: Client) -> Self {
+        CachedCsrClient {
+            client,
+            schemas_by_subject: BTreeMap::new(),
+            schemas_by_id: BTreeMap::new(),
+            subjects_and_references: BTreeMap::new(),
+        }
+    }
+
+    /// Like [`Client::get_schema_by_subject`], but returns `None` if the
+    /// subject does not exist.
+    ///
+    /// If `include_deleted` is set, the latest soft-deleted version of the
+    /// subject is considered as well.
+    async fn get_schema_by_subject(
+        &mut self,
+        subject: &str,
+        include_deleted: bool,
+    ) -> Result<Option<CcsrSchema>, GetBySubjectError> {
+        let key = (subject.to_owned(), include_deleted);
+        if let Some(schema) = self.schemas_by_subject.get(&key) {
+            return Ok(schema.clone());
+        }
+        let res = if include_deleted {
+            self.client.get_subject_including_deleted(subject).await
+        } else {
+            self.client.get_subject(subject).await
+        };
+        let schema = match res {
+            Ok(subject) => Some(subject.schema),
+            Err(GetBySubjectError::SubjectNotFound) => None,
+            Err(e) => return Err(e),
+        };
+        self.schemas_by_subject.insert(key, schema.clone());
+        Ok(schema)
+    }
+
+    /// Reports whether `subject` is absent from the registry's live subjects
+    /// but still present in soft-deleted state.
+    async fn is_soft_deleted(&mut self, subject: &str) -> Result<bool, GetBySubjectError> {
+        Ok(self.get_schema_by_subject(subject, false).await?.is_none()
+            && self.get_schema_by_subject(subject, true).await?.is_some())
+    }
+
+    /// Returns an error if `subject` has been soft deleted, so that users can
+    /// distinguish it from a subject that never existed, as only the former
+    /// can be fixed with the `INCLUDE DELETED SUBJECTS` option.
+    async fn check_not_soft_deleted(&mut self, subject: &str) -> Result<(), PlanError> {
+        let soft_deleted = self.is_soft_deleted(subject).await.map_err(|e| {
+            PlanError::FetchingCsrSchemaFailed {
+                schema_lookup: format!("subject {}", subject.quoted()),
+                cause: Arc::new(e),
+            }
+        })?;
+        if soft_deleted {
+            Err(CsrPurificationError::SourceSubjectSoftDeleted(
+                subject.to_owned(),
+            ))?;
+        }
+        Ok(())
+    }
+
+    /// Like [`Client::get_schema_by_id`], but returns `None` if the schema does
+    /// not exist.
+    async fn get_schema_by_id(&mut self, id: i32) -> Result<Option<CcsrSchema>, GetByIdError> {
+        if let Some(schema) = self.schemas_by_id.get(&id) {
+            return Ok(schema.clone());
+        }
+        let schema = match self.client.get_schema_by_id(id).await {
+            Ok(schema) => Some(schema),
+            Err(GetByIdError::SchemaNotFound) => None,
+            Err(e) => return Err(e),
+        };
+        self.schemas_by_id.insert(id, schema.clone());
+        Ok(schema)
+    }
+
+    /// See [`Client::get_subject_and_references`].
+    ///
+    /// If `include_deleted` is set, the latest soft-deleted version of the
+    /// subject is considered as well.
+    async fn get_subject_and_references(
+        &mut self,
+        subject: &str,
+        include_deleted: bool,
+    ) -> Result<(Subject, Vec<Subject>), GetBySubjectError> {
+        let key = (subject.to_owned(), include_deleted);
+        if let Some(subjects) = self.subjects_and_references.get(&key) {
+            return Ok(subjects.clone());
+        }
+        let subjects = if include_deleted {
+            self.client
+                .get_subject_and_references_including_deleted(subject)
+                .await?
+        } else {
+            self.client.get_subject_and_references(subject).await?
+        };
+        self.subjects_and_references.insert(key, subjects.clone());
+        Ok(subjects)
+    }
+}
+
 #[derive(Debug)]
 pub struct Schema {
     pub key_schema: Option<String>,
@@ -1336,15 +3127,15 @@ pub struct Schema {
 }
 
 async fn get_schema_with_strategy(
-    client: &Client,
+    client: &mut CachedCsrClient,
     strategy: ReaderSchemaSelectionStrategy,
     subject: &str,
+    include_deleted: bool,
 ) -> Result<Option<String>, PlanError> {
     match strategy {
         ReaderSchemaSelectionStrategy::Latest => {
-            match client.get_schema_by_subject(subject).await {
-                Ok(CcsrSchema { raw, .. }) => Ok(Some(raw)),
-                Err(GetBySubjectError::SubjectNotFound) => Ok(None),
+            match client.get_schema_by_subject(subject, include_deleted).await {
+                Ok(schema) => Ok(schema.map(|CcsrSchema { raw, .. }| raw)),
                 Err(e) => Err(PlanError::FetchingCsrSchemaFailed {
                     schema_lookup: format!("subject {}", subject.quoted()),
                     cause: Arc::new(e),
@@ -1353,8 +3144,7 @@ async fn get_schema_with_strategy(
         }
         ReaderSchemaSelectionStrategy::Inline(raw) => Ok(Some(raw)),
         ReaderSchemaSelectionStrategy::ById(id) => match client.get_schema_by_id(id).await {
-            Ok(CcsrSchema { raw, .. }) => Ok(Some(raw)),
-            Err(GetByIdError::SchemaNotFound) => Ok(None),
+            Ok(schema) => Ok(schema.map(|CcsrSchema { raw, .. }| raw)),
             Err(e) => Err(PlanError::FetchingCsrSchemaFailed {
                 schema_lookup: format!("ID {}", id),
                 cause: Arc::new(e),
@@ -1363,18 +3153,252 @@ async fn get_schema_with_strategy(
     }
 }
 
+/// Checks that the Avro schemas a Kafka sink will publish to `topic`'s subjects
+/// are compatible with the schemas already registered for those subjects.
+///
+/// The sink would otherwise only discover the incompatibility when it attempts
+/// to publish its schemas after being created.
+async fn validate_sink_avro_schemas(
+    catalog: &impl SessionCatalog,
+    stmt: &CreateSinkStatement<Aug>,
+    options: &[CsrConfigOption<Aug>],
+    client: &Client,
+    topic: &str,
+) -> Result<(), PlanError> {
+    let scx = StatementContext::new(None, catalog);
+    let from = scx.get_item_by_resolved_name(&stmt.from)?;
+    let desc = from.desc(&scx.catalog.resolve_full_name(from.name()))?;
+
+    let key = match &stmt.connection {
+        CreateSinkConnection::Kafka { key, .. }
+        | CreateSinkConnection::Postgres { key, .. }
+        | CreateSinkConnection::Http { key, .. }
+        | CreateSinkConnection::Redis { key, .. } => key.as_ref(),
+        CreateSinkConnection::S3 { .. } | CreateSinkConnection::Iceberg { .. } => None,
+    };
+    let key_desc = match key {
+        Some(key) => {
+            let mut names = vec![];
+            let mut types = vec![];
+            for column in &key.key_columns {
+                let name = normalize::column_name(column.clone());
+                // Invalid keys are reported during planning.
+                let Some((_, typ)) = desc.get_by_name(&name) else {
+                    return Ok(());
+                };
+                names.push(name);
+                types.push(typ.clone());
+            }
+            Some(RelationDesc::new(RelationType::new(types), names))
+        }
+        None => None,
+    };
+
+    let CsrConfigOptionExtracted {
+        avro_key_fullname,
+        avro_value_fullname,
+        null_defaults,
+        key_doc_options,
+        value_doc_options,
+        ..
+    } = options.to_vec().try_into()?;
+    let options = AvroSchemaOptions {
+        avro_key_fullname,
+        avro_value_fullname,
+        set_null_defaults: null_defaults,
+        is_debezium: matches!(stmt.envelope, Some(Envelope::Debezium(_))),
+        is_debezium_full: matches!(stmt.envelope, Some(Envelope::Debezium(DbzMode::Full))),
+        sink_from: Some(from.id()),
+        value_doc_options,
+        key_doc_options,
+    };
+    // Invalid combinations of options are reported during planning.
+    let Ok(generator) = AvroSchemaGenerator::new(key_desc, desc.into_owned(), options) else {
+        return Ok(());
+    };
+
+    let mut schemas = vec![(
+        format!("{}-value", topic),
+        generator.value_writer_schema().to_string(),
+    )];
+    if let Some(key_schema) = generator.key_writer_schema() {
+        schemas.push((format!("{}-key", topic), key_schema.to_string()));
+    }
+    for (subject, schema) in schemas {
+        match client
+            .check_compatibility(&subject, &schema, SchemaType::Avro, &[])
+            .await
+        {
+            Ok(true) | Err(CompatibilityError::SubjectNotFound) => {}
+            Ok(false) => {
+                let mismatches = match client.get_schema_by_subject(&subject).await {
+                    Ok(registered) => diff_avro_record_columns(&registered.raw, &schema),
+                    Err(_) => vec![],
+                };
+                Err(CsrPurificationError::IncompatibleSinkSchema {
+                    subject,
+                    mismatches,
+                })?;
+            }
+            Err(e) => Err(CsrPurificationError::CheckCompatibilityError(Arc::new(e)))?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Describes how the columns of the `generated` Avro record schema differ from
+/// those of the `registered` one.
+fn diff_avro_record_columns(registered: &str, generated: &str) -> Vec<String> {
+    fn columns(schema: &str) -> BTreeMap<String, serde_json::Value> {
+        let Ok(schema) = serde_json::from_str::<serde_json::Value>(schema) else {
+            return BTreeMap::new();
+        };
+        let fields = |record: &serde_json::Value| {
+            record
+                .get("fields")
+                .and_then(|fields| fields.as_array())
+                .cloned()
+                .unwrap_or_default()
+        };
+        let mut columns = fields(&schema);
+        // Debezium envelopes nest the columns in a record that is defined
+        // inline in the nullable `before` field.
+        let row = columns
+            .iter()
+            .find(|field| field["name"] == "before")
+            .and_then(|before| before["type"].as_array())
+            .and_then(|variants| variants.iter().find(|v| v["type"] == "record"));
+        if let Some(row) = row {
+            columns = fields(row);
+        }
+        columns
+            .into_iter()
+            .filter_map(|field| {
+                let name = field.get("name")?.as_str()?.to_string();
+                Some((name, field.get("type")?.clone()))
+            })
+            .collect()
+    }
+
+    let registered = columns(registered);
+    let generated = columns(generated);
+    let mut mismatches = vec![];
+    for (name, registered_type) in &registered {
+        match generated.get(name) {
+            None => mismatches.push(format!(
+                "column {} is in the registered schema but not the relation",
+                name.quoted()
+            )),
+            Some(generated_type) if generated_type != registered_type => mismatches.push(format!(
+                "column {} has type {} in the registered schema but {} in the relation",
+                name.quoted(),
+                registered_type,
+                generated_type
+            )),
+            Some(_) => {}
+        }
+    }
+    for name in generated.keys() {
+        if !registered.contains_key(name) {
+            mismatches.push(format!(
+                "column {} is in the relation but not the registered schema",
+                name.quoted()
+            ));
+        }
+    }
+    mismatches
+}
+
+/// Sets `symbol` as the default of every enum in the Avro `schema`, so that
+/// symbols unknown to the reader schema resolve to it rather than erroring.
+fn set_avro_enum_defaults(schema: &str, symbol: &str) -> Result<String, PlanError> {
+    fn visit(value: &mut serde_json::Value, symbol: &str) -> Result<(), PlanError> {
+        match value {
+            serde_json::Value::Object(object) => {
+                if object.get("type").and_then(|t| t.as_str()) == Some("enum") {
+                    let has_symbol = object
+                        .get("symbols")
+                        .and_then(|symbols| symbols.as_array())
+                        .map_or(false, |symbols| {
+                            symbols.iter().any(|s| s.as_str() == Some(symbol))
+                        });
+                    if !has_symbol {
+                        let enum_name = object
+                            .get("name")
+                            .and_then(|name| name.as_str())
+                            .unwrap_or_default()
+                            .to_string();
+                        Err(CsrPurificationError::UnknownEnumSymbolNotFound {
+                            enum_name,
+                            symbol: symbol.to_string(),
+                        })?;
+                    }
+                    object.insert("default".into(), symbol.into());
+                }
+                for value in object.values_mut() {
+                    visit(value, symbol)?;
+                }
+            }
+            serde_json::Value::Array(values) => {
+                for value in values {
+                    visit(value, symbol)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    let mut value: serde_json::Value =
+        serde_json::from_str(schema).map_err(|e| anyhow!("unable to parse Avro schema: {}", e))?;
+    visit(&mut value, symbol)?;
+    Ok(value.to_string())
+}
+
 async fn get_remote_csr_schema(
-    ccsr_client: &mz_ccsr::Client,
+    ccsr_client: &mut CachedCsrClient,
     key_strategy: ReaderSchemaSelectionStrategy,
     value_strategy: ReaderSchemaSelectionStrategy,
     topic: String,
+    include_deleted_subjects: bool,
 ) -> Result<Schema, PlanError> {
     let value_schema_name = format!("{}-value", topic);
-    let value_schema =
-        get_schema_with_strategy(ccsr_client, value_strategy, &value_schema_name).await?;
-    let value_schema = value_schema.ok_or_else(|| anyhow!("No value schema found"))?;
+    let value_schema = get_schema_with_strategy(
+        ccsr_client,
+        value_strategy.clone(),
+        &value_schema_name,
+        include_deleted_subjects,
+    )
+    .await?;
+    let value_schema = match value_schema {
+        Some(value_schema) => value_schema,
+        None => {
+            if !include_deleted_subjects && value_strategy == ReaderSchemaSelectionStrategy::Latest
+            {
+                ccsr_client
+                    .check_not_soft_deleted(&value_schema_name)
+                    .await?;
+            }
+            Err(anyhow!("No value schema found"))?
+        }
+    };
     let subject = format!("{}-key", topic);
-    let key_schema = get_schema_with_strategy(ccsr_client, key_strategy, &subject).await?;
+    let key_schema = get_schema_with_strategy(
+        ccsr_client,
+        key_strategy.clone(),
+        &subject,
+        include_deleted_subjects,
+    )
+    .await?;
+    // The key schema is optional, but silently ignoring a soft-deleted one
+    // would change the shape of the source.
+    if key_schema.is_none()
+        && !include_deleted_subjects
+        && key_strategy == ReaderSchemaSelectionStrategy::Latest
+    {
+        ccsr_client.check_not_soft_deleted(&subject).await?;
+    }
     Ok(Schema {
         key_schema,
         value_schema,
@@ -1382,17 +3406,31 @@ async fn get_remote_csr_schema(
 }
 
 /// Collect protobuf message descriptor from CSR and compile the descriptor.
+///
+/// Returns `None` if the subject does not exist.
 async fn compile_proto(
     subject_name: &String,
-    ccsr_client: &Client,
-) -> Result<CsrSeedProtobufSchema, PlanError> {
-    let (primary_subject, dependency_subjects) = ccsr_client
-        .get_subject_and_references(subject_name)
+    ccsr_client: &mut CachedCsrClient,
+    include_deleted_subjects: bool,
+) -> Result<Option<CsrSeedProtobufSchema>, PlanError> {
+    let (primary_subject, dependency_subjects) = match ccsr_client
+        .get_subject_and_references(subject_name, include_deleted_subjects)
         .await
-        .map_err(|e| PlanError::FetchingCsrSchemaFailed {
-            schema_lookup: format!("subject {}", subject_name.quoted()),
-            cause: Arc::new(e),
-        })?;
+    {
+        Ok(subjects) => subjects,
+        Err(GetBySubjectError::SubjectNotFound) => {
+            if !include_deleted_subjects {
+                ccsr_client.check_not_soft_deleted(subject_name).await?;
+            }
+            return Ok(None);
+        }
+        Err(e) => {
+            return Err(PlanError::FetchingCsrSchemaFailed {
+                schema_lookup: format!("subject {}", subject_name.quoted()),
+                cause: Arc::new(e),
+            })
+        }
+    };
 
     // Compile .proto files into a file descriptor set.
     let mut source_tree = VirtualSourceTree::new();
@@ -1423,8 +3461,57 @@ async fn compile_proto(
     let mut schema = String::new();
     strconv::format_bytes(&mut schema, bytes);
 
-    Ok(CsrSeedProtobufSchema {
+    Ok(Some(CsrSeedProtobufSchema {
         schema,
         message_name,
-    })
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::str::FromStr;
+
+    use mz_avro::error::Error as AvroError;
+    use mz_avro::schema::resolve_schemas;
+    use mz_avro::types::Value;
+    use mz_avro::{from_avro_datum, to_avro_datum, Schema};
+
+    use super::set_avro_enum_defaults;
+
+    /// Decodes a record whose enum is written with a symbol that is unknown to
+    /// `reader_schema`.
+    fn decode_unknown_symbol(reader_schema: &str) -> Result<Value, AvroError> {
+        let writer_schema = Schema::from_str(
+            r#"{"type": "record", "name": "r", "fields": [
+                {"name": "e", "type": {"type": "enum", "name": "E", "symbols": ["A", "B", "C"]}}
+            ]}"#,
+        )
+        .unwrap();
+        let reader_schema = Schema::from_str(reader_schema).unwrap();
+        let value = Value::Record(vec![("e".into(), Value::Enum(2, "C".into()))]);
+        let encoded = to_avro_datum(&writer_schema, value).unwrap();
+        let resolved_schema = resolve_schemas(&writer_schema, &reader_schema).unwrap();
+        from_avro_datum(&resolved_schema, &mut Cursor::new(encoded))
+    }
+
+    #[mz_ore::test]
+    fn test_unknown_enum_symbol_policy() {
+        let reader_schema = r#"{"type": "record", "name": "r", "fields": [
+            {"name": "e", "type": {"type": "enum", "name": "E", "symbols": ["A", "B"]}}
+        ]}"#;
+
+        // `UNKNOWN ENUM SYMBOL ERROR` leaves the reader schema as is.
+        assert!(decode_unknown_symbol(reader_schema).is_err());
+
+        // `UNKNOWN ENUM SYMBOL 'B'` decodes unknown symbols as `B`.
+        let reader_schema_with_default = set_avro_enum_defaults(reader_schema, "B").unwrap();
+        assert_eq!(
+            decode_unknown_symbol(&reader_schema_with_default).unwrap(),
+            Value::Record(vec![("e".into(), Value::Enum(1, "B".into()))])
+        );
+
+        // The symbol must belong to every enum.
+        assert!(set_avro_enum_defaults(reader_schema, "C").is_err());
+    }
 }
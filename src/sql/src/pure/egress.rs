@@ -0,0 +1,128 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Egress allowlist validation for SQL purification.
+
+use std::iter;
+
+use ipnet::IpNet;
+use mz_ore::error::ErrorExt;
+use mz_storage_types::connections::{KafkaConnection, PostgresConnection, Tunnel};
+use tokio::net;
+
+use crate::names::FullItemName;
+use crate::plan::PlanError;
+
+use super::error::EgressPolicyError;
+
+/// A host and port to which a source or sink opens network connections.
+type EgressTarget = (String, u16);
+
+/// Returns the targets to which connecting to `host:port` through `tunnel`
+/// opens network connections.
+///
+/// Connecting through an SSH tunnel only opens network connections to the
+/// bastion hosts. Traffic to AWS PrivateLink endpoints does not leave the
+/// cloud provider's network, so is not subject to egress allowlists.
+fn tunnel_egress_targets(tunnel: &Tunnel, host: &str, port: u16) -> Vec<EgressTarget> {
+    match tunnel {
+        Tunnel::Direct => vec![(host.into(), port)],
+        Tunnel::Ssh(ssh_tunnel) => {
+            let ssh = &ssh_tunnel.connection;
+            iter::once(&ssh.host)
+                .chain(&ssh.fallback_hosts)
+                .map(|host| (host.clone(), ssh.port))
+                .collect()
+        }
+        Tunnel::AwsPrivatelink(_) => vec![],
+    }
+}
+
+/// Returns the targets to which sources and sinks using `connection` open
+/// network connections to bootstrap their Kafka clients.
+fn kafka_egress_targets(connection: &KafkaConnection) -> Result<Vec<EgressTarget>, PlanError> {
+    let mut targets = vec![];
+    for broker in &connection.brokers {
+        let (host, port) = match broker.address.split_once(':') {
+            Some((host, port)) => {
+                let port = port
+                    .parse()
+                    .map_err(|_| sql_err!("invalid broker address: {}", broker.address))?;
+                (host, port)
+            }
+            None => (broker.address.as_str(), 9092),
+        };
+        let tunnel = match &broker.tunnel {
+            Tunnel::Direct => &connection.default_tunnel,
+            tunnel => tunnel,
+        };
+        targets.extend(tunnel_egress_targets(tunnel, host, port));
+    }
+    Ok(targets)
+}
+
+/// Checks that every address to which `targets` resolve is contained in one of
+/// the networks of `allowlist`, the egress allowlist of the connection named
+/// `connection_name`. An empty allowlist permits all addresses.
+async fn validate_egress_targets(
+    connection_name: &FullItemName,
+    allowlist: &[String],
+    targets: Vec<EgressTarget>,
+) -> Result<(), PlanError> {
+    if allowlist.is_empty() {
+        return Ok(());
+    }
+    let allowlist = allowlist
+        .iter()
+        .map(|network| {
+            network
+                .parse::<IpNet>()
+                .map_err(|e| sql_err!("invalid EGRESS ALLOWLIST entry {network}: {e}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    for (host, port) in targets {
+        let addrs = net::lookup_host((host.as_str(), port)).await.map_err(|e| {
+            EgressPolicyError::UnresolvableHost {
+                connection: connection_name.clone(),
+                host: host.clone(),
+                cause: e.to_string_with_causes(),
+            }
+        })?;
+        for addr in addrs {
+            if !allowlist.iter().any(|network| network.contains(&addr.ip())) {
+                Err(EgressPolicyError::AddressNotAllowed {
+                    connection: connection_name.clone(),
+                    host: host.clone(),
+                    addr: addr.ip(),
+                })?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks the addresses of the brokers of `connection`, the Kafka connection
+/// named `connection_name`, against its egress allowlist.
+pub(super) async fn validate_kafka_egress(
+    connection_name: &FullItemName,
+    connection: &KafkaConnection,
+) -> Result<(), PlanError> {
+    let targets = kafka_egress_targets(connection)?;
+    validate_egress_targets(connection_name, &connection.egress_allowlist, targets).await
+}
+
+/// Checks the address of the server of `connection`, the PostgreSQL
+/// connection named `connection_name`, against its egress allowlist.
+pub(super) async fn validate_postgres_egress(
+    connection_name: &FullItemName,
+    connection: &PostgresConnection,
+) -> Result<(), PlanError> {
+    let targets = tunnel_egress_targets(&connection.tunnel, &connection.host, connection.port);
+    validate_egress_targets(connection_name, &connection.egress_allowlist, targets).await
+}
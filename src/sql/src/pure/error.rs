@@ -7,9 +7,12 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::net::IpAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
-use mz_ccsr::ListError;
+use itertools::Itertools;
+use mz_ccsr::{AuthenticateError, CompatibilityError, ListError};
 use mz_repr::adt::system::Oid;
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{ReferencedSubsources, UnresolvedItemName};
@@ -67,6 +70,10 @@ pub enum PgSourcePurificationError {
     },
     #[error("replication disabled on server")]
     ReplicationDisabled,
+    #[error("{} problems with the requested subsources", .errors.len())]
+    InvalidSubsources {
+        errors: Vec<PgSourcePurificationError>,
+    },
 }
 
 impl PgSourcePurificationError {
@@ -123,6 +130,13 @@ impl PgSourcePurificationError {
                 count,
                 if *count == 1 { "" } else { "s" }
             )),
+            Self::InvalidSubsources { errors } => Some(itertools::join(
+                errors.iter().map(|e| match e.detail() {
+                    Some(detail) => format!("{e}: {detail}"),
+                    None => e.to_string(),
+                }),
+                "\n",
+            )),
             _ => None,
         }
     }
@@ -150,6 +164,10 @@ impl PgSourcePurificationError {
                 "you might be able to wait for other sources to finish snapshotting and try again".into()
             ),
             Self::ReplicationDisabled => Some("set max_wal_senders to a value > 0".into()),
+            Self::InvalidSubsources { errors } => {
+                let hints: Vec<_> = errors.iter().filter_map(|e| e.hint()).unique().collect();
+                (!hints.is_empty()).then(|| hints.join("\n"))
+            }
             _ => None,
         }
     }
@@ -166,12 +184,15 @@ pub enum KafkaSourcePurificationError {
     NotKafkaConnection(FullItemName),
     #[error("failed to create and connect Kafka consumer")]
     KafkaConsumerError(String),
+    #[error("unable to fetch OAuth token for Kafka connection")]
+    OauthTokenError(String),
 }
 
 impl KafkaSourcePurificationError {
     pub fn detail(&self) -> Option<String> {
         match self {
             Self::KafkaConsumerError(e) => Some(e.clone()),
+            Self::OauthTokenError(e) => Some(e.clone()),
             _ => None,
         }
     }
@@ -232,24 +253,416 @@ pub enum KafkaSinkPurificationError {
     NotKafkaConnection(FullItemName),
     #[error("admin client errored")]
     AdminClientError(Arc<ContextCreationError>),
+    #[error("unable to fetch OAuth token for Kafka connection")]
+    OauthTokenError(String),
     #[error("zero brokers discovered in metadata request")]
     ZeroBrokers,
+    #[error("{option} {replication_factor} exceeds the number of brokers")]
+    ReplicationFactorTooLarge {
+        option: &'static str,
+        replication_factor: i32,
+        brokers: usize,
+    },
+    #[error("topic {} has {setting} {actual}, but the sink specifies {expected}", .topic.quoted())]
+    TopicConfigMismatch {
+        topic: String,
+        setting: &'static str,
+        expected: String,
+        actual: String,
+    },
+    #[error("unable to read the configuration of topic {}", .0.quoted())]
+    MissingTopicConfig(String),
+    #[error("progress topic {} has {partitions} partitions, but must have exactly one", .topic.quoted())]
+    ProgressTopicPartitionCount { topic: String, partitions: usize },
+    #[error("progress topic {} has cleanup policy {}, but must be compacted", .topic.quoted(), .cleanup_policy.quoted())]
+    ProgressTopicNotCompacted {
+        topic: String,
+        cleanup_policy: String,
+    },
+    #[error("unable to access dead letter topic {}", .topic.quoted())]
+    DeadLetterTopicInaccessible { topic: String, error: String },
+    #[error("insufficient permissions to {operation} topic {}", .topic.quoted())]
+    TopicPermissionDenied {
+        topic: String,
+        operation: &'static str,
+    },
+    #[error("insufficient permissions to produce to Kafka transactionally")]
+    TransactionPermissionDenied(String),
+    #[error("TRANSACTION TIMEOUT MS {} exceeds the maximum transaction timeout of broker {broker}", .transaction_timeout.as_millis())]
+    TransactionTimeoutTooLarge {
+        transaction_timeout: Duration,
+        broker: i32,
+        max_timeout_ms: u64,
+    },
+    #[error("transactions are unavailable because the transaction state log cannot be created")]
+    TransactionStateLogUnavailable {
+        replication_factor: u64,
+        brokers: usize,
+    },
+    #[error("broker {broker} does not support COMPRESSION TYPE {compression_type}")]
+    UnsupportedCompressionType {
+        compression_type: String,
+        broker: i32,
+        version: String,
+    },
+    #[error("DELETE MODE {} is not supported with ENVELOPE {envelope}", .delete_mode.quoted())]
+    UnsupportedDeleteMode {
+        delete_mode: &'static str,
+        envelope: &'static str,
+    },
+    #[error("DELETE MODE \"tombstone\" requires a KEY")]
+    TombstoneWithoutKey,
+    #[error("TOPIC FROM COLUMN column {} does not exist", .0.quoted())]
+    NoSuchTopicColumn(String),
+    #[error("TOPIC FROM COLUMN column {} has unsupported type {ty}", .column.quoted())]
+    InvalidTopicColumnType { column: String, ty: String },
+    #[error("HEADERS FROM column {} does not exist", .0.quoted())]
+    NoSuchHeaderColumn(String),
+    #[error("HEADERS FROM column {} has unsupported type {ty}", .column.quoted())]
+    InvalidHeaderColumnType { column: String, ty: String },
 }
 
 impl KafkaSinkPurificationError {
     pub fn detail(&self) -> Option<String> {
         match self {
             Self::AdminClientError(e) => Some(e.to_string_with_causes()),
+            Self::OauthTokenError(e) => Some(e.clone()),
+            Self::ReplicationFactorTooLarge { brokers, .. } => {
+                Some(format!("The Kafka cluster has {brokers} brokers."))
+            }
+            Self::UnsupportedCompressionType { version, .. } => Some(format!(
+                "The broker's inter.broker.protocol.version is {version}, \
+                but zstd compression requires 2.1 or later."
+            )),
+            Self::DeadLetterTopicInaccessible { error, .. } => Some(error.clone()),
+            Self::TransactionPermissionDenied(error) => Some(error.clone()),
+            Self::TransactionTimeoutTooLarge { max_timeout_ms, .. } => Some(format!(
+                "The broker's transaction.max.timeout.ms is {max_timeout_ms}."
+            )),
+            Self::TransactionStateLogUnavailable {
+                replication_factor,
+                brokers,
+            } => Some(format!(
+                "The brokers' transaction.state.log.replication.factor is \
+                {replication_factor}, but the Kafka cluster has {brokers} brokers."
+            )),
             _ => None,
         }
     }
 
     pub fn hint(&self) -> Option<String> {
-        None
+        match self {
+            Self::TopicConfigMismatch { .. } => Some(
+                "Sinks do not alter the configuration of existing topics. \
+                Omit the option or change the topic's configuration to match."
+                    .into(),
+            ),
+            Self::ProgressTopicPartitionCount { .. } | Self::ProgressTopicNotCompacted { .. } => {
+                Some(
+                    "Specify a PROGRESS TOPIC that does not exist yet, or a topic with a single \
+                    partition and cleanup.policy=compact."
+                        .into(),
+                )
+            }
+            Self::MissingTopicConfig(_) => {
+                Some("Do we have DescribeConfigs permissions on the topic?".into())
+            }
+            Self::DeadLetterTopicInaccessible { .. } => {
+                Some("Do we have Describe and Write permissions on the topic?".into())
+            }
+            Self::UnsupportedCompressionType { .. } => {
+                Some("Use a different COMPRESSION TYPE, like lz4.".into())
+            }
+            Self::TopicPermissionDenied { .. } => Some(
+                "Sinks need Describe, Create, and Write permissions on their topics, \
+                including the progress topic."
+                    .into(),
+            ),
+            Self::TransactionPermissionDenied(_) => Some(
+                "Sinks need Write permissions on transactional IDs prefixed with their \
+                TRANSACTIONAL ID PREFIX, \"mz-producer-\" by default, and IdempotentWrite \
+                permissions on the cluster."
+                    .into(),
+            ),
+            Self::TransactionTimeoutTooLarge { .. } => Some(
+                "Lower TRANSACTION TIMEOUT MS, or raise transaction.max.timeout.ms on the brokers."
+                    .into(),
+            ),
+            Self::TransactionStateLogUnavailable { .. } => Some(
+                "Lower transaction.state.log.replication.factor on the brokers, or add brokers."
+                    .into(),
+            ),
+            Self::UnsupportedDeleteMode { .. } => {
+                Some("ENVELOPE UPSERT supports DELETE MODE 'tombstone' and 'drop'.".into())
+            }
+            Self::TombstoneWithoutKey => {
+                Some("Specify a KEY for the sink, or use DELETE MODE 'record' or 'drop'.".into())
+            }
+            Self::InvalidTopicColumnType { .. } => {
+                Some("Topic names must be text. Cast the column in the sinked relation.".into())
+            }
+            Self::InvalidHeaderColumnType { .. } => Some(
+                "Header values must be text or bytea. Cast the column in the sinked relation."
+                    .into(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Logical errors detectable during purification for a POSTGRES SINK.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum PgSinkPurificationError {
+    #[error("{0} is not a POSTGRES CONNECTION")]
+    NotPgConnection(FullItemName),
+    #[error("table {} does not exist", .0.quoted())]
+    TableDoesNotExist(String),
+    #[error("table {} is incompatible with the sink", .table.quoted())]
+    IncompatibleTable {
+        table: String,
+        mismatches: Vec<String>,
+    },
+    #[error("table {} has no unique constraint on the sink key", .table.quoted())]
+    MissingKeyConstraint { table: String, key: Vec<String> },
+    #[error("column {} has type {}, which cannot be written to PostgreSQL", .column.quoted(), .ty.quoted())]
+    UnsupportedType { column: String, ty: String },
+    #[error("insufficient privileges")]
+    UserLacksPrivilegesOnTable {
+        user: String,
+        table: String,
+        privileges: Vec<String>,
+    },
+    #[error("insufficient privileges")]
+    UserLacksCreateOnSchema { user: String, schema: String },
+}
+
+impl PgSinkPurificationError {
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Self::IncompatibleTable { mismatches, .. } => Some(mismatches.join("; ")),
+            Self::MissingKeyConstraint { key, .. } => {
+                Some(format!("sink key: ({})", key.join(", ")))
+            }
+            Self::UserLacksPrivilegesOnTable {
+                user,
+                table,
+                privileges,
+            } => Some(format!(
+                "user {} lacks {} privileges for table {}",
+                user,
+                privileges.join(", "),
+                table
+            )),
+            Self::UserLacksCreateOnSchema { user, schema } => Some(format!(
+                "user {} lacks CREATE privileges for schema {}",
+                user, schema
+            )),
+            _ => None,
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::TableDoesNotExist(_) => Some(
+                "Create the table upstream, or specify the CREATE TABLE option to have Materialize create it."
+                    .into(),
+            ),
+            Self::MissingKeyConstraint { .. } => Some(
+                "Add a PRIMARY KEY or UNIQUE constraint on exactly the KEY columns to the upstream table."
+                    .into(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Logical errors detectable during purification for an S3 SINK.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum S3SinkPurificationError {
+    #[error("{0} is not an AWS CONNECTION")]
+    NotAwsConnection(FullItemName),
+    #[error("unable to access bucket {}", .bucket.quoted())]
+    BucketAccess { bucket: String, cause: String },
+    #[error("unable to write to prefix {} in bucket {}", .prefix.quoted(), .bucket.quoted())]
+    PrefixNotWriteable {
+        bucket: String,
+        prefix: String,
+        cause: String,
+    },
+}
+
+impl S3SinkPurificationError {
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Self::BucketAccess { cause, .. } | Self::PrefixNotWriteable { cause, .. } => {
+                Some(cause.clone())
+            }
+            _ => None,
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::BucketAccess { .. } => Some(
+                "Check that the bucket exists in the connection's region and that the connection's credentials can access it."
+                    .into(),
+            ),
+            Self::PrefixNotWriteable { .. } => Some(
+                "The connection's credentials must be allowed to put and delete objects under the prefix."
+                    .into(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Logical errors detectable during purification for an HTTP SINK.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum HttpSinkPurificationError {
+    #[error("invalid URL: {0}")]
+    InvalidUrl(String),
+    #[error("unable to connect to endpoint")]
+    ConnectionFailed(String),
+    #[error("endpoint rejected credentials with status {0}")]
+    Unauthorized(u16),
+    #[error("endpoint responded with status {0}")]
+    ErrorStatus(u16),
+}
+
+impl HttpSinkPurificationError {
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Self::ConnectionFailed(cause) => Some(cause.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::Unauthorized(_) => {
+                Some("Check the values of the HEADER options used for authentication.".into())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Logical errors detectable during purification for a REDIS SINK.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum RedisSinkPurificationError {
+    #[error("invalid URL: {0}")]
+    InvalidUrl(String),
+    #[error("unable to connect to server")]
+    ConnectionFailed(String),
+    #[error("server rejected credentials")]
+    AuthenticationFailed(String),
+    #[error(
+        "key {} is a {found}, but DATA STRUCTURE is {expected}",
+        .key.quoted()
+    )]
+    WrongDataStructure {
+        key: String,
+        found: String,
+        expected: String,
+    },
+}
+
+impl RedisSinkPurificationError {
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Self::ConnectionFailed(cause) | Self::AuthenticationFailed(cause) => {
+                Some(cause.clone())
+            }
+            _ => None,
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::AuthenticationFailed(_) => {
+                Some("Check the PASSWORD option and any credentials in the URL.".into())
+            }
+            Self::WrongDataStructure { .. } => Some(
+                "Use a KEY PREFIX that no other application writes to, or remove the existing keys."
+                    .into(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Logical errors detectable during purification for an ICEBERG SINK.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum IcebergSinkPurificationError {
+    #[error("{0} is not an AWS CONNECTION")]
+    NotAwsConnection(FullItemName),
+    #[error("unable to connect to catalog")]
+    CatalogUnreachable(String),
+    #[error("table {} does not exist", .0.quoted())]
+    TableNotFound(String),
+    #[error("unable to load table {}", .table.quoted())]
+    TableAccess { table: String, cause: String },
+    #[error("{} is not an Iceberg table", .0.quoted())]
+    NotIcebergTable(String),
+    #[error("table {} uses Iceberg format version {version}", .table.quoted())]
+    UnsupportedFormatVersion { table: String, version: u64 },
+    #[error("table {} is partitioned", .0.quoted())]
+    PartitionedTable(String),
+    #[error("table {} has no default name mapping", .0.quoted())]
+    MissingNameMapping(String),
+    #[error("table has no column {}", .0.quoted())]
+    MissingColumn(String),
+    #[error(
+        "column {} has type {found}, but the sink writes {expected}",
+        .column.quoted()
+    )]
+    ColumnTypeMismatch {
+        column: String,
+        found: String,
+        expected: String,
+    },
+    #[error("column {} is required, but the sink may write NULL to it", .0.quoted())]
+    NullableColumn(String),
+    #[error("column {} is required, but the sink does not write it", .0.quoted())]
+    UnwrittenColumn(String),
+}
+
+impl IcebergSinkPurificationError {
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Self::CatalogUnreachable(cause) | Self::TableAccess { cause, .. } => {
+                Some(cause.clone())
+            }
+            _ => None,
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::UnsupportedFormatVersion { .. } => {
+                Some("Upgrade the table to format version 2.".into())
+            }
+            Self::PartitionedTable(_) => {
+                Some("Iceberg sinks only support unpartitioned tables.".into())
+            }
+            Self::MissingNameMapping(_) => Some(
+                "Iceberg sinks write files without field IDs. Set the table property schema.name-mapping.default to map column names to field IDs."
+                    .into(),
+            ),
+            Self::MissingColumn(_) | Self::ColumnTypeMismatch { .. } => Some(
+                "The table must have a column of the corresponding type for each column of the sinked relation and for mz_timestamp and mz_diff."
+                    .into(),
+            ),
+            Self::NullableColumn(_) | Self::UnwrittenColumn(_) => {
+                Some("Make the column optional in the table.".into())
+            }
+            _ => None,
+        }
     }
 }
 
 use mz_ore::error::ErrorExt;
+use mz_ore::str::StrExt;
 
 /// Logical errors detectable during purification for a KAFKA SOURCE.
 #[derive(Debug, Clone, thiserror::Error)]
@@ -260,6 +673,23 @@ pub enum CsrPurificationError {
     ClientError(Arc<CsrConnectError>),
     #[error("list subjects failed")]
     ListSubjectsError(Arc<ListError>),
+    #[error("unable to obtain OAuth access token for schema registry")]
+    OauthTokenError(Arc<AuthenticateError>),
+    #[error("unable to reach schema registry through proxy {}", .url.quoted())]
+    ProxyConnectionError { url: String, error: Arc<ListError> },
+    #[error("subject {} exists only in soft-deleted state", .0.quoted())]
+    SourceSubjectSoftDeleted(String),
+    #[error("subject {} exists only in soft-deleted state", .0.quoted())]
+    SinkSubjectSoftDeleted(String),
+    #[error("checking schema compatibility failed")]
+    CheckCompatibilityError(Arc<CompatibilityError>),
+    #[error("sink schema is incompatible with the schema registered for subject {}", .subject.quoted())]
+    IncompatibleSinkSchema {
+        subject: String,
+        mismatches: Vec<String>,
+    },
+    #[error("enum {} does not contain UNKNOWN ENUM SYMBOL {}", .enum_name.quoted(), .symbol.quoted())]
+    UnknownEnumSymbolNotFound { enum_name: String, symbol: String },
 }
 
 impl CsrPurificationError {
@@ -267,11 +697,112 @@ impl CsrPurificationError {
         match self {
             Self::ClientError(e) => Some(e.to_string_with_causes()),
             Self::ListSubjectsError(e) => Some(e.to_string_with_causes()),
+            Self::OauthTokenError(e) => Some(e.to_string_with_causes()),
+            Self::ProxyConnectionError { error, .. } => Some(error.to_string_with_causes()),
+            Self::CheckCompatibilityError(e) => Some(e.to_string_with_causes()),
+            Self::IncompatibleSinkSchema { mismatches, .. } if !mismatches.is_empty() => {
+                Some(mismatches.join("; "))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::SourceSubjectSoftDeleted(_) => Some(
+                "Specify the INCLUDE DELETED SUBJECTS option to use the soft-deleted schema."
+                    .into(),
+            ),
+            Self::SinkSubjectSoftDeleted(_) => Some(
+                "Permanently delete the subject from the schema registry, or sink to a different TOPIC."
+                    .into(),
+            ),
+            Self::UnknownEnumSymbolNotFound { .. } => Some(
+                "The UNKNOWN ENUM SYMBOL must be one of the symbols of every enum in the schema."
+                    .into(),
+            ),
+            Self::ProxyConnectionError { .. } => Some(
+                "Check that the proxy is reachable and permits connections to the schema registry."
+                    .into(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Logical errors detectable during purification when checking the addresses
+/// to which a source or sink would connect against the `EGRESS ALLOWLIST` of
+/// its connection.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum EgressPolicyError {
+    #[error("unable to resolve {} to check it against the EGRESS ALLOWLIST of {connection}", .host.quoted())]
+    UnresolvableHost {
+        connection: FullItemName,
+        host: String,
+        cause: String,
+    },
+    #[error("{} resolves to {addr}, which the EGRESS ALLOWLIST of {connection} does not permit", .host.quoted())]
+    AddressNotAllowed {
+        connection: FullItemName,
+        host: String,
+        addr: IpAddr,
+    },
+}
+
+impl EgressPolicyError {
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Self::UnresolvableHost { cause, .. } => Some(cause.clone()),
             _ => None,
         }
     }
 
     pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::AddressNotAllowed { .. } => Some(
+                "Add a network containing the address to the EGRESS ALLOWLIST of the connection using ALTER CONNECTION."
+                    .into(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Logical errors detectable during purification when checking the expiry of
+/// the TLS certificates that a source or sink's connection uses.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum TlsCertificateError {
+    #[error(
+        "TLS certificate {subject} of {connection} {} at {not_after}",
+        expiry_verb(.expired)
+    )]
+    Expiring {
+        connection: String,
+        subject: String,
+        not_after: String,
+        expired: bool,
+    },
+}
+
+fn expiry_verb(expired: &bool) -> &'static str {
+    if *expired {
+        "expired"
+    } else {
+        "expires"
+    }
+}
+
+impl TlsCertificateError {
+    pub fn detail(&self) -> Option<String> {
         None
     }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::Expiring { .. } => Some(
+                "Replace the certificate using ALTER CONNECTION, or lower tls_certificate_expiry_window."
+                    .into(),
+            ),
+        }
+    }
 }
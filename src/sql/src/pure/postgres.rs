@@ -12,10 +12,12 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use itertools::Itertools;
+use mz_ore::str::StrExt;
+use mz_pgrepr::oid::FIRST_MATERIALIZE_OID;
 use mz_postgres_util::desc::PostgresTableDesc;
-use mz_postgres_util::Config;
+use mz_postgres_util::{Config, PostgresError};
 use mz_repr::adt::system::Oid;
-use mz_repr::GlobalId;
+use mz_repr::{ColumnName, GlobalId, RelationDesc, ScalarType};
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{
     ColumnDef, CreateSubsourceOption, CreateSubsourceOptionName, CreateSubsourceStatement,
@@ -23,13 +25,119 @@ use mz_sql_parser::ast::{
 };
 use mz_sql_parser::ast::{CreateSourceSubsource, UnresolvedItemName};
 use mz_ssh_util::tunnel_manager::SshTunnelManager;
+use mz_storage_types::sinks::PostgresSinkWriteMode;
+use tokio_postgres::Client;
 
 use crate::catalog::ErsatzCatalog;
 use crate::names::{Aug, PartialItemName};
 use crate::normalize;
 use crate::plan::{PlanError, StatementContext};
 
-use super::error::PgSourcePurificationError;
+use super::error::{PgSinkPurificationError, PgSourcePurificationError};
+
+/// Restricts which of a publication's tables a [`PublicationInfoCache`]
+/// fetches the schemas of.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum PublicationTableFilter {
+    /// All tables in the publication.
+    All,
+    /// The tables in the named schemas.
+    Schemas(BTreeSet<String>),
+    /// The tables with the given names, in any schema.
+    ///
+    /// Filtering by name alone, rather than by fully qualified name, retains
+    /// every table that an unqualified or ambiguous reference could resolve
+    /// to, so resolving references against the filtered tables produces the
+    /// same results and errors as resolving them against all tables.
+    Tables(BTreeSet<String>),
+}
+
+impl PublicationTableFilter {
+    /// Returns a filter that retains every table that any of the subsource
+    /// `references` or the tables of the `text_columns` could refer to.
+    pub(super) fn for_references<'a>(
+        references: impl IntoIterator<Item = &'a UnresolvedItemName>,
+        text_columns: &[UnresolvedItemName],
+    ) -> Self {
+        let reference_tables = references
+            .into_iter()
+            .filter_map(|reference| reference.0.last());
+        // The last component of a text column's name is the column itself.
+        let text_column_tables = text_columns
+            .iter()
+            .filter_map(|column| column.0.iter().rev().nth(1));
+        PublicationTableFilter::Tables(
+            reference_tables
+                .chain(text_column_tables)
+                .map(|table| table.as_str().to_string())
+                .collect(),
+        )
+    }
+
+    fn matches(&self, schema: &str, table: &str) -> bool {
+        match self {
+            PublicationTableFilter::All => true,
+            PublicationTableFilter::Schemas(schemas) => schemas.contains(schema),
+            PublicationTableFilter::Tables(tables) => tables.contains(table),
+        }
+    }
+}
+
+/// Caches the schemas of upstream publications' tables for the duration of a
+/// single purification pass, so that repeatedly inspecting a publication does
+/// not repeatedly scan the upstream catalog.
+///
+/// Entries are keyed by publication name only, so a cache must not be shared
+/// between statements that use different connections.
+#[derive(Debug, Default)]
+pub(super) struct PublicationInfoCache {
+    tables: BTreeMap<(String, PublicationTableFilter), Vec<PostgresTableDesc>>,
+}
+
+impl PublicationInfoCache {
+    /// Returns the schemas of the tables in `publication` that pass `filter`.
+    ///
+    /// # Errors
+    ///
+    /// In addition to any errors communicating with the upstream database,
+    /// returns an error if the publication contains no tables.
+    pub(super) async fn publication_info(
+        &mut self,
+        config: &Config,
+        client: &Client,
+        publication: &str,
+        filter: PublicationTableFilter,
+        ssh_tunnel_manager: &SshTunnelManager,
+    ) -> Result<Vec<PostgresTableDesc>, PlanError> {
+        let key = (publication.to_string(), filter);
+        if let Some(tables) = self.tables.get(&key) {
+            return Ok(tables.clone());
+        }
+
+        // Every filter selects a subset of all tables, so if all of the
+        // publication's tables are cached, filter those instead.
+        let all_key = (publication.to_string(), PublicationTableFilter::All);
+        let tables = match self.tables.get(&all_key) {
+            Some(tables) => tables
+                .iter()
+                .filter(|table| key.1.matches(&table.namespace, &table.name))
+                .cloned()
+                .collect(),
+            None => mz_postgres_util::publication_info_filtered(
+                ssh_tunnel_manager,
+                config,
+                client,
+                publication,
+                |schema, table| key.1.matches(schema, table),
+            )
+            .await?
+            .ok_or_else(|| PgSourcePurificationError::EmptyPublication(publication.to_string()))?,
+        };
+
+        self.tables.insert(key, tables.clone());
+        Ok(tables)
+    }
+}
 
 pub(super) fn derive_catalog_from_publication_tables<'a>(
     database: &'a str,
@@ -50,51 +158,69 @@ pub(super) fn derive_catalog_from_publication_tables<'a>(
     Ok(ErsatzCatalog(tables_by_name))
 }
 
+/// Fails with all of the `violations` found while validating the requested
+/// subsources of a source, if there are any, so that users can fix all of them
+/// in a single iteration.
+pub(super) fn check_subsource_violations(
+    mut violations: Vec<PgSourcePurificationError>,
+) -> Result<(), PlanError> {
+    match violations.len() {
+        0 => Ok(()),
+        1 => Err(violations.remove(0).into()),
+        _ => Err(PgSourcePurificationError::InvalidSubsources { errors: violations }.into()),
+    }
+}
+
+/// Validates the `requested_subsources` against the upstream database, which
+/// `client` is connected to using `config`, adding the problems it finds with
+/// them to `violations`.
+///
+/// Only fails if the upstream database cannot be queried.
 pub(super) async fn validate_requested_subsources(
     config: &Config,
+    client: &Client,
     requested_subsources: &[(UnresolvedItemName, UnresolvedItemName, &PostgresTableDesc)],
-    ssh_tunnel_manager: &SshTunnelManager,
+    violations: &mut Vec<PgSourcePurificationError>,
 ) -> Result<(), PlanError> {
     // This condition would get caught during the catalog transaction, but produces a
     // vague, non-contextual error. Instead, error here so we can suggest to the user
     // how to fix the problem.
-    if let Some(name) = requested_subsources
+    for name in requested_subsources
         .iter()
         .map(|(_, subsource_name, _)| subsource_name)
         .duplicates()
-        .next()
-        .cloned()
     {
         let mut upstream_references: Vec<_> = requested_subsources
-            .into_iter()
-            .filter_map(|(u, t, _)| if t == &name { Some(u.clone()) } else { None })
+            .iter()
+            .filter_map(|(u, t, _)| if t == name { Some(u.clone()) } else { None })
             .collect();
 
         upstream_references.sort();
 
-        Err(PgSourcePurificationError::SubsourceNameConflict {
-            name,
+        violations.push(PgSourcePurificationError::SubsourceNameConflict {
+            name: name.clone(),
             upstream_references,
-        })?;
+        });
     }
 
     // We technically could allow multiple subsources to ingest the same upstream table, but
     // it is almost certainly an error on the user's end.
-    if let Some(name) = requested_subsources
+    for name in requested_subsources
         .iter()
         .map(|(referenced_name, _, _)| referenced_name)
         .duplicates()
-        .next()
-        .cloned()
     {
         let mut target_names: Vec<_> = requested_subsources
-            .into_iter()
-            .filter_map(|(u, t, _)| if u == &name { Some(t.clone()) } else { None })
+            .iter()
+            .filter_map(|(u, t, _)| if u == name { Some(t.clone()) } else { None })
             .collect();
 
         target_names.sort();
 
-        Err(PgSourcePurificationError::SubsourceDuplicateReference { name, target_names })?;
+        violations.push(PgSourcePurificationError::SubsourceDuplicateReference {
+            name: name.clone(),
+            target_names,
+        });
     }
 
     // Ensure that we have select permissions on all tables; we have to do this before we
@@ -105,15 +231,24 @@ pub(super) async fn validate_requested_subsources(
         .map(|(UnresolvedItemName(inner), _, _)| [inner[1].as_str(), inner[2].as_str()])
         .collect();
 
-    privileges::check_table_privileges(config, tables_to_check_permissions, ssh_tunnel_manager)
-        .await?;
-
     let oids: Vec<_> = requested_subsources
         .iter()
         .map(|(_, _, table_desc)| table_desc.oid)
         .collect();
 
-    replica_identity::check_replica_identity_full(config, oids, ssh_tunnel_manager).await?;
+    // The checks are independent of one another, so run them concurrently.
+    // Their queries are pipelined over the same connection.
+    let (privileges, replica_identity) = futures::join!(
+        privileges::check_table_privileges(config, client, tables_to_check_permissions),
+        replica_identity::check_replica_identity_full(client, oids),
+    );
+    for result in [privileges, replica_identity] {
+        match result {
+            Ok(()) => {}
+            Err(PlanError::PgSourcePurification(e)) => violations.push(e),
+            Err(e) => return Err(e),
+        }
+    }
 
     Ok(())
 }
@@ -192,6 +327,9 @@ pub(super) fn generate_text_columns(
     Ok(text_cols_dict)
 }
 
+/// Generates the `CREATE SUBSOURCE` statements for the
+/// `validated_requested_subsources`, adding the problems it finds with their
+/// columns to `violations`.
 pub(crate) fn generate_targeted_subsources<F>(
     scx: &StatementContext,
     validated_requested_subsources: Vec<(
@@ -202,6 +340,7 @@ pub(crate) fn generate_targeted_subsources<F>(
     mut text_cols_dict: BTreeMap<u32, BTreeSet<String>>,
     mut get_transient_subsource_id: F,
     publication_tables: &[PostgresTableDesc],
+    violations: &mut Vec<PgSourcePurificationError>,
 ) -> Result<
     (
         Vec<CreateSourceSubsource<Aug>>,
@@ -325,9 +464,9 @@ where
 
     if !unsupported_cols.is_empty() {
         unsupported_cols.sort();
-        Err(PgSourcePurificationError::UnrecognizedTypes {
+        violations.push(PgSourcePurificationError::UnrecognizedTypes {
             cols: unsupported_cols,
-        })?;
+        });
     }
 
     // If any any item was not removed from the text_cols dict, it wasn't being
@@ -349,9 +488,9 @@ where
 
     if !dangling_text_column_refs.is_empty() {
         dangling_text_column_refs.sort();
-        Err(PgSourcePurificationError::DanglingTextColumns {
+        violations.push(PgSourcePurificationError::DanglingTextColumns {
             items: dangling_text_column_refs,
-        })?;
+        });
     }
 
     targeted_subsources.sort();
@@ -359,24 +498,163 @@ where
     Ok((targeted_subsources, subsources))
 }
 
+/// Validates that the upstream table `schema.table` can receive the output of
+/// a sink with the given `desc` and `key`, optionally creating the table if it
+/// does not exist.
+///
+/// Compatibility requires that every sink column exists upstream with the same
+/// type, that every upstream `NOT NULL` column is written by the sink, and that
+/// the table has a unique constraint comprising exactly the key columns, which
+/// the sink relies on to upsert rows.
+pub(super) async fn validate_sink_table(
+    config: &Config,
+    ssh_tunnel_manager: &SshTunnelManager,
+    schema: &str,
+    table: &str,
+    desc: &RelationDesc,
+    key: &[ColumnName],
+    create_table: bool,
+    write_mode: PostgresSinkWriteMode,
+) -> Result<(), PlanError> {
+    let qualified_name = format!("{}.{}", schema, table);
+
+    // Map each sink column onto its PostgreSQL type.
+    let mut columns = Vec::with_capacity(desc.arity());
+    for (name, ty) in desc.iter() {
+        let pg_ty = mz_pgrepr::Type::from(&ty.scalar_type);
+        if pg_ty.oid() >= FIRST_MATERIALIZE_OID
+            || matches!(ty.scalar_type, ScalarType::Record { .. })
+        {
+            Err(PgSinkPurificationError::UnsupportedType {
+                column: name.to_string(),
+                ty: pg_ty.name().to_string(),
+            })?;
+        }
+        columns.push((name.as_str(), pg_ty, ty.nullable));
+    }
+
+    let upstream = mz_postgres_util::table_info(ssh_tunnel_manager, config, schema, table).await?;
+
+    let Some(upstream) = upstream else {
+        if !create_table {
+            return Err(PgSinkPurificationError::TableDoesNotExist(qualified_name).into());
+        }
+        privileges::check_create_privilege(config, schema, ssh_tunnel_manager).await?;
+
+        let mut defs = columns
+            .iter()
+            .map(|(name, pg_ty, nullable)| {
+                format!(
+                    "{} pg_catalog.{}{}",
+                    quote_ident(name),
+                    quote_ident(pg_ty.catalog_name()),
+                    if *nullable { "" } else { " NOT NULL" }
+                )
+            })
+            .collect::<Vec<_>>();
+        defs.push(format!(
+            "PRIMARY KEY ({})",
+            key.iter().map(|col| quote_ident(col.as_str())).join(", ")
+        ));
+        let client = config
+            .connect("create_sink_table", ssh_tunnel_manager)
+            .await?;
+        client
+            .batch_execute(&format!(
+                "CREATE TABLE IF NOT EXISTS {}.{} ({})",
+                quote_ident(schema),
+                quote_ident(table),
+                defs.join(", ")
+            ))
+            .await
+            .map_err(PostgresError::from)?;
+        return Ok(());
+    };
+
+    let mut mismatches = vec![];
+    for (name, pg_ty, nullable) in &columns {
+        match upstream.columns.iter().find(|c| c.name == *name) {
+            None => mismatches.push(format!("column {} does not exist upstream", name.quoted())),
+            Some(c) if c.type_oid != pg_ty.oid() => mismatches.push(format!(
+                "column {} has upstream type OID {}, but the sink writes {}",
+                name.quoted(),
+                c.type_oid,
+                pg_ty.name()
+            )),
+            Some(c) if *nullable && !c.nullable => mismatches.push(format!(
+                "column {} is NOT NULL upstream, but is nullable in the sink",
+                name.quoted()
+            )),
+            Some(_) => {}
+        }
+    }
+    for c in &upstream.columns {
+        if !c.nullable && !columns.iter().any(|(name, _, _)| *name == c.name) {
+            mismatches.push(format!(
+                "upstream column {} is NOT NULL, but is not written by the sink",
+                c.name.quoted()
+            ));
+        }
+    }
+    if !mismatches.is_empty() {
+        Err(PgSinkPurificationError::IncompatibleTable {
+            table: qualified_name.clone(),
+            mismatches,
+        })?;
+    }
+
+    // The sink upserts with `ON CONFLICT (<key>)`, which requires a unique
+    // constraint on exactly the key columns. Sinks that write with `COPY`
+    // replace rows by key instead, which needs no constraint.
+    if write_mode == PostgresSinkWriteMode::Copy {
+        return privileges::check_sink_table_privileges(config, schema, table, ssh_tunnel_manager)
+            .await;
+    }
+    let key_names: BTreeSet<_> = key.iter().map(|col| col.as_str()).collect();
+    let has_key_constraint = upstream.keys.iter().any(|k| {
+        let cols: Option<BTreeSet<_>> = k
+            .cols
+            .iter()
+            .map(|attnum| {
+                upstream
+                    .columns
+                    .iter()
+                    .find(|c| c.col_num == Some(*attnum))
+                    .map(|c| c.name.as_str())
+            })
+            .collect();
+        cols.as_ref() == Some(&key_names)
+    });
+    if !has_key_constraint {
+        Err(PgSinkPurificationError::MissingKeyConstraint {
+            table: qualified_name,
+            key: key.iter().map(|col| col.to_string()).collect(),
+        })?;
+    }
+
+    privileges::check_sink_table_privileges(config, schema, table, ssh_tunnel_manager).await
+}
+
+/// Quotes `ident` for use as an identifier in a PostgreSQL statement.
+fn quote_ident(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
 mod privileges {
     use postgres_array::{Array, Dimension};
 
     use mz_postgres_util::{Config, PostgresError};
+    use tokio_postgres::Client;
 
     use super::SshTunnelManager;
     use crate::plan::PlanError;
-    use crate::pure::PgSourcePurificationError;
+    use crate::pure::{PgSinkPurificationError, PgSourcePurificationError};
 
     async fn check_schema_privileges(
         config: &Config,
+        client: &Client,
         schemas: Vec<&str>,
-        ssh_tunnel_manager: &SshTunnelManager,
     ) -> Result<(), PlanError> {
-        let client = config
-            .connect("check_schema_privileges", ssh_tunnel_manager)
-            .await?;
-
         let schemas_len = schemas.len();
 
         let schemas = Array::from_parts(
@@ -434,19 +712,18 @@ mod privileges {
     ///
     /// - `USAGE` privileges on the schemas references in `tables`.
     ///
+    /// The checks are run over `client`, which must be connected using
+    /// `config`.
+    ///
     /// # Panics
     /// If `config` does not specify a user.
     pub async fn check_table_privileges(
         config: &Config,
+        client: &Client,
         tables: Vec<[&str; 2]>,
-        ssh_tunnel_manager: &SshTunnelManager,
     ) -> Result<(), PlanError> {
         let schemas = tables.iter().map(|t| t[0]).collect();
-        check_schema_privileges(config, schemas, ssh_tunnel_manager).await?;
-
-        let client = config
-            .connect("check_table_privileges", ssh_tunnel_manager)
-            .await?;
+        check_schema_privileges(config, client, schemas).await?;
 
         let tables_len = tables.len();
 
@@ -511,28 +788,112 @@ mod privileges {
             })?
         }
     }
+
+    /// Ensure that the user specified in `config` has the `INSERT`, `UPDATE`,
+    /// and `DELETE` privileges a sink requires on `schema.table`.
+    ///
+    /// # Panics
+    /// If `config` does not specify a user.
+    pub async fn check_sink_table_privileges(
+        config: &Config,
+        schema: &str,
+        table: &str,
+        ssh_tunnel_manager: &SshTunnelManager,
+    ) -> Result<(), PlanError> {
+        let client = config
+            .connect("check_sink_table_privileges", ssh_tunnel_manager)
+            .await?;
+
+        check_schema_privileges(config, &client, vec![schema]).await?;
+
+        let user = config.get_user().expect("connection specifies user");
+
+        // Passing several privileges to `has_table_privilege` checks whether
+        // the user holds any of them, so each must be checked separately.
+        let row = client
+            .query_one(
+                "
+            WITH
+                data AS (SELECT format('%I.%I', $2::text, $3::text) AS t)
+            SELECT
+                has_table_privilege($1::text, t, 'insert') AS i,
+                has_table_privilege($1::text, t, 'update') AS u,
+                has_table_privilege($1::text, t, 'delete') AS d
+            FROM
+                data;",
+                &[&user, &schema, &table],
+            )
+            .await
+            .map_err(PostgresError::from)?;
+
+        let privileges: Vec<String> = [("i", "INSERT"), ("u", "UPDATE"), ("d", "DELETE")]
+            .into_iter()
+            .filter(|(col, _)| !row.get::<_, bool>(*col))
+            .map(|(_, privilege)| privilege.to_string())
+            .collect();
+
+        if privileges.is_empty() {
+            Ok(())
+        } else {
+            Err(PgSinkPurificationError::UserLacksPrivilegesOnTable {
+                user: user.to_string(),
+                table: format!("{}.{}", schema, table),
+                privileges,
+            })?
+        }
+    }
+
+    /// Ensure that the user specified in `config` may create tables in
+    /// `schema`.
+    ///
+    /// # Panics
+    /// If `config` does not specify a user.
+    pub async fn check_create_privilege(
+        config: &Config,
+        schema: &str,
+        ssh_tunnel_manager: &SshTunnelManager,
+    ) -> Result<(), PlanError> {
+        let client = config
+            .connect("check_create_privilege", ssh_tunnel_manager)
+            .await?;
+
+        let user = config.get_user().expect("connection specifies user");
+
+        let has_privilege: bool = client
+            .query_one(
+                "SELECT has_schema_privilege($1::text, $2::text, 'create') AS p;",
+                &[&user, &schema],
+            )
+            .await
+            .map_err(PostgresError::from)?
+            .get("p");
+
+        if has_privilege {
+            Ok(())
+        } else {
+            Err(PgSinkPurificationError::UserLacksCreateOnSchema {
+                user: user.to_string(),
+                schema: schema.to_string(),
+            })?
+        }
+    }
 }
 
 mod replica_identity {
     use postgres_array::{Array, Dimension};
     use tokio_postgres::types::Oid;
 
-    use mz_postgres_util::{Config, PostgresError};
+    use mz_postgres_util::PostgresError;
+    use tokio_postgres::Client;
 
-    use super::SshTunnelManager;
     use crate::plan::PlanError;
     use crate::pure::PgSourcePurificationError;
 
     /// Ensures that all provided OIDs are tables with `REPLICA IDENTITY FULL`.
     pub async fn check_replica_identity_full(
-        config: &Config,
+        client: &Client,
         oids: Vec<Oid>,
-        ssh_tunnel_manager: &SshTunnelManager,
     ) -> Result<(), PlanError> {
-        let client = config
-            .connect("check_replica_identity_full", ssh_tunnel_manager)
-            .await?;
-
         let oids_len = oids.len();
 
         let oids = Array::from_parts(
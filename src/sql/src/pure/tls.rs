@@ -0,0 +1,164 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! TLS certificate expiry checks for SQL purification.
+
+use mz_secrets::SecretsReader;
+use mz_storage_types::connections::{
+    CsrConnection, KafkaConnection, PostgresConnection, StringOrSecret,
+};
+use openssl::asn1::Asn1Time;
+use openssl::nid::Nid;
+use openssl::x509::X509;
+
+use crate::catalog::SessionCatalog;
+use crate::names::FullItemName;
+use crate::plan::{PlanError, PlanNotice};
+
+use super::error::TlsCertificateError;
+
+/// Returns the PEM-encoded certificates, or chains of certificates, that
+/// `root_cert` and `identity_cert` hold.
+fn certificates<'a>(
+    root_cert: Option<&'a StringOrSecret>,
+    identity_cert: Option<&'a StringOrSecret>,
+) -> Vec<&'a StringOrSecret> {
+    root_cert.into_iter().chain(identity_cert).collect()
+}
+
+/// Returns a human-readable name for `cert`: its common name, if it has one,
+/// or else its serial number.
+fn certificate_name(cert: &X509) -> String {
+    let common_name = cert
+        .subject_name()
+        .entries_by_nid(Nid::COMMONNAME)
+        .next()
+        .and_then(|entry| entry.data().as_utf8().ok())
+        .map(|cn| cn.to_string());
+    match common_name {
+        Some(cn) => format!("CN={cn}"),
+        None => match cert.serial_number().to_bn().and_then(|bn| bn.to_hex_str()) {
+            Ok(serial) => format!("with serial number {serial}"),
+            Err(_) => "without a common name".into(),
+        },
+    }
+}
+
+/// Checks that none of the certificates in `certificates`, which the
+/// connection named `connection_name` uses, expire within the window set by
+/// the `tls_certificate_expiry_window` system variable.
+///
+/// Certificates that expire within the window are reported as a notice, or as
+/// an error if the `tls_certificate_expiry_error` system variable is set.
+/// Certificates that cannot be parsed are skipped; the client that uses them
+/// reports them when connecting.
+async fn check_certificates(
+    catalog: &dyn SessionCatalog,
+    secrets_reader: &dyn SecretsReader,
+    connection_name: &FullItemName,
+    certificates: Vec<&StringOrSecret>,
+) -> Result<(), PlanError> {
+    let system_vars = catalog.system_vars();
+    let window = system_vars.tls_certificate_expiry_window();
+    let deadline = Asn1Time::seconds_from_now(
+        window
+            .as_secs()
+            .try_into()
+            .map_err(|_| sql_err!("tls_certificate_expiry_window is too large"))?,
+    )
+    .map_err(|e| sql_err!("computing TLS certificate expiry deadline: {e}"))?;
+    let now = Asn1Time::days_from_now(0)
+        .map_err(|e| sql_err!("computing TLS certificate expiry deadline: {e}"))?;
+
+    for pem in certificates {
+        let pem = pem.get_string(secrets_reader).await?;
+        let Ok(chain) = X509::stack_from_pem(pem.as_bytes()) else {
+            continue;
+        };
+        for cert in chain {
+            let not_after = cert.not_after();
+            if not_after > &*deadline {
+                continue;
+            }
+            let connection = connection_name.to_string();
+            let subject = certificate_name(&cert);
+            let expired = not_after < &*now;
+            let not_after = not_after.to_string();
+            if system_vars.tls_certificate_expiry_error() {
+                Err(TlsCertificateError::Expiring {
+                    connection,
+                    subject,
+                    not_after,
+                    expired,
+                })?;
+            }
+            catalog.add_notice(PlanNotice::TlsCertificateExpiring {
+                connection,
+                subject,
+                not_after,
+                expired,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Checks the expiry of the TLS certificates that `connection`, the Kafka
+/// connection named `connection_name`, uses.
+pub(super) async fn check_kafka_certificates(
+    catalog: &dyn SessionCatalog,
+    secrets_reader: &dyn SecretsReader,
+    connection_name: &FullItemName,
+    connection: &KafkaConnection,
+) -> Result<(), PlanError> {
+    let Some(tls) = &connection.tls else {
+        return Ok(());
+    };
+    let certificates = certificates(
+        tls.root_cert.as_ref(),
+        tls.identity.as_ref().map(|identity| &identity.cert),
+    );
+    check_certificates(catalog, secrets_reader, connection_name, certificates).await
+}
+
+/// Checks the expiry of the TLS certificates that `connection`, the schema
+/// registry connection named `connection_name`, uses.
+pub(super) async fn check_csr_certificates(
+    catalog: &dyn SessionCatalog,
+    secrets_reader: &dyn SecretsReader,
+    connection_name: &FullItemName,
+    connection: &CsrConnection,
+) -> Result<(), PlanError> {
+    let certificates = certificates(
+        connection.tls_root_cert.as_ref(),
+        connection
+            .tls_identity
+            .as_ref()
+            .map(|identity| &identity.cert),
+    );
+    check_certificates(catalog, secrets_reader, connection_name, certificates).await
+}
+
+/// Checks the expiry of the TLS certificates that `connection`, the
+/// PostgreSQL connection named `connection_name`, uses.
+pub(super) async fn check_postgres_certificates(
+    catalog: &dyn SessionCatalog,
+    secrets_reader: &dyn SecretsReader,
+    connection_name: &FullItemName,
+    connection: &PostgresConnection,
+) -> Result<(), PlanError> {
+    let certificates = certificates(
+        connection.tls_root_cert.as_ref(),
+        connection
+            .tls_identity
+            .as_ref()
+            .map(|identity| &identity.cert),
+    );
+    check_certificates(catalog, secrets_reader, connection_name, certificates).await
+}
@@ -128,6 +128,10 @@ pub enum UnauthorizedError {
     /// The active role was dropped while a user was logged in.
     #[error("role {0} was concurrently dropped")]
     ConcurrentRoleDrop(RoleId),
+    /// The action requires a scope that the token of the session's service account is not
+    /// limited to.
+    #[error("permission denied to {action}")]
+    ServiceAccountScope { action: String, scope: String },
 }
 
 impl UnauthorizedError {
@@ -146,6 +150,10 @@ impl UnauthorizedError {
             UnauthorizedError::ConcurrentRoleDrop(_) => {
                 Some("Please disconnect and re-connect with a valid role.".into())
             }
+            UnauthorizedError::ServiceAccountScope { scope, .. } => Some(format!(
+                "The token of the service account does not have the {} scope",
+                scope.quoted()
+            )),
             UnauthorizedError::Ownership { .. }
             | UnauthorizedError::RoleMembership { .. }
             | UnauthorizedError::Privilege { .. } => None,
@@ -290,6 +298,8 @@ pub fn check_plan(
     catalog: &impl SessionCatalog,
     // Map from connection IDs to authenticated roles. The roles may have been dropped concurrently.
     active_conns: &BTreeMap<u32, RoleId>,
+    // Map from background purification IDs to the IDs of the connections that issued them.
+    background_purifications: &BTreeMap<u64, u32>,
     role_metadata: &RoleMetadata,
     session_vars: &SessionVars,
     plan: &Plan,
@@ -302,6 +312,7 @@ pub fn check_plan(
         catalog,
         plan,
         active_conns,
+        background_purifications,
         target_cluster_id,
         role_metadata.current_role,
     );
@@ -309,6 +320,122 @@ pub fn check_plan(
     rbac_requirements.validate(catalog, role_metadata, session_vars, resolved_ids)
 }
 
+/// The scopes that the token of a service account can be limited to.
+pub const SERVICE_ACCOUNT_SCOPES: [&str; 3] = ["read", "write", "ddl"];
+
+/// Checks that a session of a service account whose token is limited to `scopes` is allowed to
+/// execute `plan`. An empty set of scopes places no restriction on the session.
+///
+/// Unlike privileges, scopes are checked regardless of whether RBAC is enabled.
+pub fn check_service_account_scopes(
+    scopes: &BTreeSet<String>,
+    plan: &Plan,
+) -> Result<(), UnauthorizedError> {
+    if scopes.is_empty() {
+        return Ok(());
+    }
+    match service_account_scope(plan) {
+        Some(scope) if !scopes.contains(scope) => Err(UnauthorizedError::ServiceAccountScope {
+            action: plan.name().to_string(),
+            scope: scope.to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the scope of [`SERVICE_ACCOUNT_SCOPES`] that is required to execute `plan`, or `None`
+/// if every service account may execute it.
+fn service_account_scope(plan: &Plan) -> Option<&'static str> {
+    match plan {
+        // Statements that only affect the session, or that execute other statements, which are
+        // checked separately.
+        Plan::DiscardTemp
+        | Plan::DiscardAll
+        | Plan::EmptyQuery
+        | Plan::ShowAllVariables
+        | Plan::ShowVariable(_)
+        | Plan::SetVariable(_)
+        | Plan::ResetVariable(_)
+        | Plan::SetTransaction(_)
+        | Plan::StartTransaction(_)
+        | Plan::CommitTransaction(_)
+        | Plan::AbortTransaction(_)
+        | Plan::Declare(_)
+        | Plan::Fetch(_)
+        | Plan::Close(_)
+        | Plan::Prepare(_)
+        | Plan::Execute(_)
+        | Plan::Deallocate(_)
+        | Plan::Raise(_) => None,
+        Plan::Select(_)
+        | Plan::Subscribe(_)
+        | Plan::ShowCreate(_)
+        | Plan::ShowColumns(_)
+        | Plan::InspectShard(_)
+        | Plan::ExplainPlan(_)
+        | Plan::ExplainTimestamp(_)
+        | Plan::ExplainSinkSchema(_)
+        | Plan::ExplainCreateSource(_) => Some("read"),
+        Plan::Insert(_) | Plan::ReadThenWrite(_) | Plan::CopyFrom(_) => Some("write"),
+        Plan::CreateConnection(_)
+        | Plan::CreateDatabase(_)
+        | Plan::CreateSchema(_)
+        | Plan::CreateRole(_)
+        | Plan::CreateCluster(_)
+        | Plan::CreateClusterReplica(_)
+        | Plan::CreateClusterReplicaSize(_)
+        | Plan::CreateSource(_)
+        | Plan::CreateSources(_)
+        | Plan::CreateSecret(_)
+        | Plan::CreateServiceAccount(_)
+        | Plan::CreateSink(_)
+        | Plan::CreateTable(_)
+        | Plan::CreateView(_)
+        | Plan::CreateMaterializedView(_)
+        | Plan::CreateIndex(_)
+        | Plan::CreateType(_)
+        | Plan::Comment(_)
+        | Plan::DropObjects(_)
+        | Plan::DropOwned(_)
+        | Plan::DropClusterReplicaSize(_)
+        | Plan::DropServiceAccount(_)
+        | Plan::UndropObject(_)
+        | Plan::AlterCluster(_)
+        | Plan::AlterClusterSwap(_)
+        | Plan::AlterNoop(_)
+        | Plan::AlterIndexSetOptions(_)
+        | Plan::AlterIndexResetOptions(_)
+        | Plan::AlterSetCluster(_)
+        | Plan::AlterColumnMask(_)
+        | Plan::AlterSink(_)
+        | Plan::AlterConnection(_)
+        | Plan::AlterSource(_)
+        | Plan::PurifiedAlterSource { .. }
+        | Plan::AlterClusterRename(_)
+        | Plan::AlterClusterReplica(_)
+        | Plan::AlterClusterReplicaRename(_)
+        | Plan::AlterItemRename(_)
+        | Plan::AlterItemSwap(_)
+        | Plan::AlterDatabaseRename(_)
+        | Plan::AlterSchemaRename(_)
+        | Plan::AlterSchemaSwap(_)
+        | Plan::AlterSecret(_)
+        | Plan::AlterSystemSet(_)
+        | Plan::AlterSystemReset(_)
+        | Plan::AlterSystemResetAll(_)
+        | Plan::AlterRole(_)
+        | Plan::AlterOwner(_)
+        | Plan::GrantRole(_)
+        | Plan::RevokeRole(_)
+        | Plan::GrantPrivileges(_)
+        | Plan::RevokePrivileges(_)
+        | Plan::AlterDefaultPrivileges(_)
+        | Plan::ReassignOwned(_)
+        | Plan::SideEffectingFunc(_)
+        | Plan::ValidateConnection(_) => Some("ddl"),
+    }
+}
+
 /// Returns true if RBAC is turned on for a session, false otherwise.
 pub fn is_rbac_enabled_for_session(system_vars: &SystemVars, session_vars: &SessionVars) -> bool {
     let server_enabled = system_vars.enable_rbac_checks();
@@ -324,6 +451,7 @@ fn generate_rbac_requirements(
     catalog: &impl SessionCatalog,
     plan: &Plan,
     active_conns: &BTreeMap<u32, RoleId>,
+    background_purifications: &BTreeMap<u64, u32>,
     target_cluster_id: Option<ClusterId>,
     role_id: RoleId,
 ) -> RbacRequirements {
@@ -379,20 +507,54 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
-        Plan::CreateCluster(plan::CreateClusterPlan {
+        Plan::CreateCluster(plan::CreateClusterPlan { name: _, variant }) => {
+            let mut privileges = vec![(SystemObjectId::System, AclMode::CREATE_CLUSTER, role_id)];
+            let mut ownership = Vec::new();
+            if let plan::CreateClusterVariant::Like(plan::CreateClusterLikePlan {
+                cluster_id: _,
+                items,
+            }) = variant
+            {
+                // Cloned items keep the owner of the item they clone.
+                for id in items {
+                    let item = catalog.get_item(id);
+                    ownership.push(ObjectId::Item(*id));
+                    privileges.push((
+                        SystemObjectId::Object(item.name().qualifiers.clone().into()),
+                        AclMode::CREATE,
+                        role_id,
+                    ));
+                }
+            }
+            RbacRequirements {
+                ownership,
+                privileges,
+                item_usage: &CREATE_ITEM_USAGE,
+                ..Default::default()
+            }
+        }
+        Plan::CreateClusterReplica(plan::CreateClusterReplicaPlan {
+            cluster_id,
             name: _,
-            variant: _,
+            config: _,
         }) => RbacRequirements {
-            privileges: vec![(SystemObjectId::System, AclMode::CREATE_CLUSTER, role_id)],
+            ownership: vec![ObjectId::Cluster(*cluster_id)],
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
-        Plan::CreateClusterReplica(plan::CreateClusterReplicaPlan {
-            cluster_id,
+        // Replica sizes determine the resources that the environment consumes,
+        // so only superusers may define them.
+        Plan::CreateClusterReplicaSize(plan::CreateClusterReplicaSizePlan {
             name: _,
-            config: _,
+            memory_limit: _,
+            cpu_limit: _,
+            disk_limit: _,
+            scale: _,
+            workers: _,
+            credits_per_hour: _,
+            disabled: _,
         }) => RbacRequirements {
-            ownership: vec![ObjectId::Cluster(*cluster_id)],
+            superuser_action: Some("CREATE CLUSTER REPLICA SIZE".to_string()),
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
@@ -454,6 +616,20 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
+        Plan::CreateServiceAccount(plan::CreateServiceAccountPlan {
+            name: _,
+            role_id: _,
+            token_secret_id,
+            scopes: _,
+            expires_at: _,
+        }) => RbacRequirements {
+            // Owning the secret prevents roles from exposing secrets that they can't read as
+            // service account tokens.
+            ownership: vec![ObjectId::Item(*token_secret_id)],
+            privileges: vec![(SystemObjectId::System, AclMode::CREATE_ROLE, role_id)],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
         Plan::CreateSink(plan::CreateSinkPlan {
             name,
             sink,
@@ -644,6 +820,36 @@ fn generate_rbac_requirements(
             role_membership: role_ids.into_iter().cloned().collect(),
             ..Default::default()
         },
+        Plan::DropClusterReplicaSize(plan::DropClusterReplicaSizePlan {
+            name: _,
+            if_exists: _,
+        }) => RbacRequirements {
+            superuser_action: Some("DROP CLUSTER REPLICA SIZE".to_string()),
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
+        Plan::DropServiceAccount(plan::DropServiceAccountPlan {
+            name: _,
+            if_exists: _,
+        }) => RbacRequirements {
+            privileges: vec![(SystemObjectId::System, AclMode::CREATE_ROLE, role_id)],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
+        Plan::UndropObject(plan::UndropObjectPlan {
+            object_type: _,
+            name,
+        }) => RbacRequirements {
+            // The restored object keeps its owner and privileges, so restoring
+            // it only requires the privilege to create it in the first place.
+            privileges: vec![(
+                SystemObjectId::Object(name.qualifiers.clone().into()),
+                AclMode::CREATE,
+                role_id,
+            )],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
         Plan::ShowCreate(plan::ShowCreatePlan { id, row: _ }) => RbacRequirements {
             privileges: vec![(
                 SystemObjectId::Object(catalog.get_item(id).name().qualifiers.clone().into()),
@@ -668,6 +874,7 @@ fn generate_rbac_requirements(
                 catalog,
                 &Plan::Select(select_plan.clone()),
                 active_conns,
+                background_purifications,
                 target_cluster_id,
                 role_id,
             )
@@ -777,6 +984,18 @@ fn generate_rbac_requirements(
                 ..Default::default()
             }
         }
+        Plan::ExplainCreateSource(plan::ExplainCreateSourcePlan {
+            name,
+            statements: _,
+        }) => RbacRequirements {
+            privileges: vec![(
+                SystemObjectId::Object(name.qualifiers.clone().into()),
+                AclMode::USAGE,
+                role_id,
+            )],
+            item_usage: &EMPTY_ITEM_USAGE,
+            ..Default::default()
+        },
         Plan::ExplainTimestamp(plan::ExplainTimestampPlan {
             format: _,
             raw_plan,
@@ -880,9 +1099,27 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
-        Plan::AlterSink(plan::AlterSinkPlan { id, size: _ }) => RbacRequirements {
+        Plan::AlterSink(plan::AlterSinkPlan { id, action }) => {
+            let privileges = match action {
+                plan::AlterSinkAction::Resize(_) => Vec::new(),
+                plan::AlterSinkAction::ChangeRelation { from } => {
+                    generate_read_privileges(catalog, iter::once(*from), role_id)
+                }
+            };
+            RbacRequirements {
+                privileges,
+                ownership: vec![ObjectId::Item(*id)],
+                item_usage: &CREATE_ITEM_USAGE,
+                ..Default::default()
+            }
+        }
+        Plan::AlterColumnMask(plan::AlterColumnMaskPlan {
+            id,
+            object_type: _,
+            column: _,
+            action: _,
+        }) => RbacRequirements {
             ownership: vec![ObjectId::Item(*id)],
-            item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
         Plan::AlterConnection(plan::AlterConnectionPlan { id, action: _ }) => RbacRequirements {
@@ -947,6 +1184,15 @@ fn generate_rbac_requirements(
             ownership: vec![ObjectId::Cluster(*id_a), ObjectId::Cluster(*id_b)],
             ..Default::default()
         },
+        Plan::AlterClusterReplica(plan::AlterClusterReplicaPlan {
+            cluster_id,
+            replica_id,
+            name: _,
+            draining: _,
+        }) => RbacRequirements {
+            ownership: vec![ObjectId::ClusterReplica((*cluster_id, *replica_id))],
+            ..Default::default()
+        },
         Plan::AlterClusterReplicaRename(plan::AlterClusterReplicaRenamePlan {
             cluster_id,
             replica_id,
@@ -975,6 +1221,15 @@ fn generate_rbac_requirements(
             ownership: vec![ObjectId::Item(*id_a), ObjectId::Item(*id_b)],
             ..Default::default()
         },
+        Plan::AlterDatabaseRename(plan::AlterDatabaseRenamePlan {
+            id,
+            name: _,
+            to_name: _,
+        }) => RbacRequirements {
+            ownership: vec![ObjectId::Database(*id)],
+            privileges: vec![(SystemObjectId::System, AclMode::CREATE_DB, role_id)],
+            ..Default::default()
+        },
         Plan::AlterSchemaRename(plan::AlterSchemaRenamePlan {
             cur_schema_spec,
             new_schema_name: _,
@@ -1284,6 +1539,17 @@ fn generate_rbac_requirements(
                         None => BTreeSet::new(),
                     }
                 }
+                // Canceling a background purification requires the same
+                // privileges as canceling the connection that issued it.
+                SideEffectingFunc::CancelBackgroundPurification { id } => {
+                    match background_purifications
+                        .get(id)
+                        .and_then(|connection_id| active_conns.get(connection_id))
+                    {
+                        Some(authenticated_role) => BTreeSet::from([*authenticated_role]),
+                        None => BTreeSet::new(),
+                    }
+                }
             };
             RbacRequirements {
                 role_membership,
@@ -339,7 +339,7 @@ static SEARCH_PATH: Lazy<ServerVar<Vec<Ident>>> = Lazy::new(|| ServerVar {
     internal: false,
 });
 
-const STATEMENT_TIMEOUT: ServerVar<Duration> = ServerVar {
+pub const STATEMENT_TIMEOUT: ServerVar<Duration> = ServerVar {
     name: UncasedStr::new("statement_timeout"),
     value: &Duration::from_secs(10),
     description:
@@ -726,6 +726,15 @@ const DISK_CLUSTER_REPLICAS_DEFAULT: ServerVar<bool> = ServerVar {
     internal: true,
 };
 
+/// The cluster that sources and sinks are created in when they specify neither
+/// `IN CLUSTER` nor `SIZE`.
+pub const DEFAULT_SOURCE_SINK_CLUSTER: ServerVar<Option<String>> = ServerVar {
+    name: UncasedStr::new("default_source_sink_cluster"),
+    value: &None,
+    description: "Sets the cluster that new sources and sinks are created in when no cluster or size is specified (Materialize).",
+    internal: false,
+};
+
 const UNSAFE_NEW_TRANSACTION_WALL_TIME: ServerVar<Option<CheckedTimestamp<DateTime<Utc>>>> = ServerVar {
     name: UncasedStr::new("unsafe_new_transaction_wall_time"),
     value: &None,
@@ -1042,6 +1051,34 @@ const PG_SOURCE_SNAPSHOT_STATEMENT_TIMEOUT: ServerVar<Duration> = ServerVar {
     internal: true,
 };
 
+/// The window within which the expiry of a TLS certificate used by the
+/// connection of a new source or sink is reported.
+const TLS_CERTIFICATE_EXPIRY_WINDOW: ServerVar<Duration> = ServerVar {
+    name: UncasedStr::new("tls_certificate_expiry_window"),
+    value: &Duration::from_secs(30 * 24 * 60 * 60),
+    description: "Reports TLS certificates used by the connections of new sources and sinks that expire within this window (Materialize).",
+    internal: true,
+};
+
+/// Whether TLS certificates that expire within `tls_certificate_expiry_window`
+/// are reported as errors rather than as notices.
+const TLS_CERTIFICATE_EXPIRY_ERROR: ServerVar<bool> = ServerVar {
+    name: UncasedStr::new("tls_certificate_expiry_error"),
+    value: &false,
+    description: "Whether to reject new sources and sinks whose connections use TLS certificates that expire within `tls_certificate_expiry_window`, rather than emitting a notice (Materialize).",
+    internal: true,
+};
+
+/// Whether topic options of new Kafka sinks that do not match the
+/// configuration of an existing topic are reported as errors rather than as
+/// notices.
+const KAFKA_SINK_TOPIC_CONFIG_MISMATCH_ERROR: ServerVar<bool> = ServerVar {
+    name: UncasedStr::new("kafka_sink_topic_config_mismatch_error"),
+    value: &false,
+    description: "Whether to reject new Kafka sinks whose topic options do not match the configuration of their existing topic, rather than emitting a notice (Materialize).",
+    internal: true,
+};
+
 /// Controls the connection timeout to Cockroach.
 ///
 /// Used by persist as [`mz_persist_client::cfg::DynamicConfig::consensus_connect_timeout`].
@@ -1283,6 +1320,42 @@ static UNSAFE_MOCK_AUDIT_EVENT_TIMESTAMP: ServerVar<Option<mz_repr::Timestamp>>
     internal: true,
 };
 
+static AUDIT_LOG_RETENTION_PERIOD: ServerVar<Option<Duration>> = ServerVar {
+    name: UncasedStr::new("audit_log_retention_period"),
+    value: &None,
+    description: "The time to retain audit log events. If unset, events are retained regardless of their age (Materialize).",
+    internal: true,
+};
+
+static AUDIT_LOG_MAX_EVENTS: ServerVar<Option<usize>> = ServerVar {
+    name: UncasedStr::new("audit_log_max_events"),
+    value: &None,
+    description: "The maximum number of audit log events to retain. If unset, events are retained regardless of their number (Materialize).",
+    internal: true,
+};
+
+static AUDIT_LOG_SESSION_EVENTS: ServerVar<bool> = ServerVar {
+    name: UncasedStr::new("audit_log_session_events"),
+    value: &false,
+    description:
+        "Whether to record logins, failed logins, and logouts in the audit log (Materialize).",
+    internal: false,
+};
+
+pub static ITEM_TRASH_RETENTION: ServerVar<Option<Duration>> = ServerVar {
+    name: UncasedStr::new("item_trash_retention"),
+    value: &None,
+    description: "How long dropped views can be restored with UNDROP. If unset, dropped views are removed immediately (Materialize).",
+    internal: false,
+};
+
+static STORAGE_USAGE_ROLLUP_AGE: ServerVar<Option<Duration>> = ServerVar {
+    name: UncasedStr::new("storage_usage_rollup_age"),
+    value: &None,
+    description: "The age after which storage usage events are rolled up into daily aggregates. If unset, events are never rolled up (Materialize).",
+    internal: true,
+};
+
 pub const ENABLE_RBAC_CHECKS: ServerVar<bool> = ServerVar {
     name: UncasedStr::new("enable_rbac_checks"),
     value: &true,
@@ -1500,6 +1573,21 @@ pub const WEBHOOK_CONCURRENT_REQUEST_LIMIT: ServerVar<usize> = ServerVar {
     internal: true,
 };
 
+pub const MAX_CONCURRENT_PURIFICATIONS: ServerVar<usize> = ServerVar {
+    name: UncasedStr::new("max_concurrent_purifications"),
+    value: &64,
+    description: "Maximum number of statements that are purified concurrently.",
+    internal: true,
+};
+
+pub const MAX_CONCURRENT_PURIFICATIONS_PER_ROLE: ServerVar<usize> = ServerVar {
+    name: UncasedStr::new("max_concurrent_purifications_per_role"),
+    value: &8,
+    description: "Maximum number of statements that are purified concurrently on behalf of a \
+        single role.",
+    internal: true,
+};
+
 pub const ENABLE_COLUMNATION_LGALLOC: ServerVar<bool> = ServerVar {
     name: UncasedStr::new("enable_columnation_lgalloc"),
     value: &false,
@@ -1863,6 +1951,48 @@ feature_flags!(
         internal: true,
         enable_for_item_parsing: true,
     },
+    {
+        name: enable_postgres_sink,
+        desc: "CREATE SINK ... INTO POSTGRES CONNECTION",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_s3_sink,
+        desc: "CREATE SINK ... INTO S3 CONNECTION",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_http_sink,
+        desc: "CREATE SINK ... INTO HTTP",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_redis_sink,
+        desc: "CREATE SINK ... INTO REDIS",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_iceberg_sink,
+        desc: "CREATE SINK ... INTO ICEBERG CONNECTION",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_kafka_sink_headers,
+        desc: "HEADERS FROM for Kafka sinks",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: true,
+    },
     {
         name: enable_raise_statement,
         desc: "RAISE statement",
@@ -2073,6 +2203,41 @@ feature_flags!(
         internal: true,
         enable_for_item_parsing: false,
     },
+    {
+        name: enable_cluster_schedules,
+        desc: "the SCHEDULE option for managed clusters",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_cluster_priorities_and_quotas,
+        desc: "setting the PRIORITY or MEMORY QUOTA of clusters",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_graceful_cluster_reconfiguration,
+        desc: "graceful reconfiguration of managed clusters",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_cluster_replica_draining,
+        desc: "the DRAINING option for cluster replicas",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_background_purification,
+        desc: "CREATE SOURCE ... IN BACKGROUND",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: false,
+    },
 );
 
 /// Represents the input to a variable.
@@ -2737,6 +2902,7 @@ impl SystemVars {
             .with_var(&MAX_COPY_FROM_SIZE)
             .with_var(&ALLOWED_CLUSTER_REPLICA_SIZES)
             .with_var(&DISK_CLUSTER_REPLICAS_DEFAULT)
+            .with_var(&DEFAULT_SOURCE_SINK_CLUSTER)
             .with_var(&upsert_rocksdb::UPSERT_ROCKSDB_AUTO_SPILL_TO_DISK)
             .with_var(&upsert_rocksdb::UPSERT_ROCKSDB_AUTO_SPILL_THRESHOLD_BYTES)
             .with_var(&upsert_rocksdb::UPSERT_ROCKSDB_COMPACTION_STYLE)
@@ -2791,6 +2957,11 @@ impl SystemVars {
             .with_var(&PERSIST_ROLLUP_THRESHOLD)
             .with_var(&METRICS_RETENTION)
             .with_var(&UNSAFE_MOCK_AUDIT_EVENT_TIMESTAMP)
+            .with_var(&AUDIT_LOG_RETENTION_PERIOD)
+            .with_var(&AUDIT_LOG_MAX_EVENTS)
+            .with_var(&AUDIT_LOG_SESSION_EVENTS)
+            .with_var(&ITEM_TRASH_RETENTION)
+            .with_var(&STORAGE_USAGE_ROLLUP_AGE)
             .with_var(&ENABLE_RBAC_CHECKS)
             .with_var(&PG_SOURCE_CONNECT_TIMEOUT)
             .with_var(&PG_SOURCE_KEEPALIVES_IDLE)
@@ -2798,6 +2969,9 @@ impl SystemVars {
             .with_var(&PG_SOURCE_KEEPALIVES_RETRIES)
             .with_var(&PG_SOURCE_TCP_USER_TIMEOUT)
             .with_var(&PG_SOURCE_SNAPSHOT_STATEMENT_TIMEOUT)
+            .with_var(&TLS_CERTIFICATE_EXPIRY_WINDOW)
+            .with_var(&TLS_CERTIFICATE_EXPIRY_ERROR)
+            .with_var(&KAFKA_SINK_TOPIC_CONFIG_MISMATCH_ERROR)
             .with_var(&ENABLE_LAUNCHDARKLY)
             .with_var(&MAX_CONNECTIONS)
             .with_var(&KEEP_N_SOURCE_STATUS_HISTORY_ENTRIES)
@@ -2842,6 +3016,8 @@ impl SystemVars {
             .with_var(&OPTIMIZER_ONESHOT_STATS_TIMEOUT)
             .with_var(&PRIVATELINK_STATUS_UPDATE_QUOTA_PER_MINUTE)
             .with_var(&WEBHOOK_CONCURRENT_REQUEST_LIMIT)
+            .with_var(&MAX_CONCURRENT_PURIFICATIONS)
+            .with_var(&MAX_CONCURRENT_PURIFICATIONS_PER_ROLE)
             .with_var(&ENABLE_COLUMNATION_LGALLOC)
             .with_var(&TIMESTAMP_ORACLE_IMPL);
 
@@ -2970,6 +3146,28 @@ impl SystemVars {
             .and_then(|v| v.is_default(input))
     }
 
+    /// Checks whether `input` is a valid value for the configuration parameter
+    /// named `name`, without modifying any configuration parameter.
+    ///
+    /// The value is validated exactly as [`SystemVars::set`] would validate
+    /// it, including any constraints on the parameter.
+    ///
+    /// # Errors
+    ///
+    /// The call will return an error:
+    /// 1. If `name` does not refer to a valid [`SystemVars`] field.
+    /// 2. If `input` does not represent a valid [`SystemVars`] value for
+    ///    `name`.
+    pub fn check(&self, name: &str, input: VarInput) -> Result<(), VarError> {
+        let mut var = self
+            .vars
+            .get(UncasedStr::new(name))
+            .ok_or_else(|| VarError::UnknownParameter(name.into()))?
+            .clone_var();
+        var.set(input)?;
+        Ok(())
+    }
+
     /// Sets the configuration parameter named `name` to the value represented
     /// by `value`.
     ///
@@ -3162,6 +3360,11 @@ impl SystemVars {
         *self.expect_value(&DISK_CLUSTER_REPLICAS_DEFAULT)
     }
 
+    /// Returns the `default_source_sink_cluster` configuration parameter.
+    pub fn default_source_sink_cluster(&self) -> Option<&str> {
+        self.expect_value(&DEFAULT_SOURCE_SINK_CLUSTER).as_deref()
+    }
+
     pub fn upsert_rocksdb_auto_spill_to_disk(&self) -> bool {
         *self.expect_value(&upsert_rocksdb::UPSERT_ROCKSDB_AUTO_SPILL_TO_DISK)
     }
@@ -3335,6 +3538,21 @@ impl SystemVars {
         *self.expect_value(&PG_SOURCE_SNAPSHOT_STATEMENT_TIMEOUT)
     }
 
+    /// Returns the `tls_certificate_expiry_window` configuration parameter.
+    pub fn tls_certificate_expiry_window(&self) -> Duration {
+        *self.expect_value(&TLS_CERTIFICATE_EXPIRY_WINDOW)
+    }
+
+    /// Returns the `tls_certificate_expiry_error` configuration parameter.
+    pub fn tls_certificate_expiry_error(&self) -> bool {
+        *self.expect_value(&TLS_CERTIFICATE_EXPIRY_ERROR)
+    }
+
+    /// Returns the `kafka_sink_topic_config_mismatch_error` configuration parameter.
+    pub fn kafka_sink_topic_config_mismatch_error(&self) -> bool {
+        *self.expect_value(&KAFKA_SINK_TOPIC_CONFIG_MISMATCH_ERROR)
+    }
+
     /// Returns the `crdb_connect_timeout` configuration parameter.
     pub fn crdb_connect_timeout(&self) -> Duration {
         *self.expect_value(&CRDB_CONNECT_TIMEOUT)
@@ -3452,6 +3670,31 @@ impl SystemVars {
         *self.expect_value(&UNSAFE_MOCK_AUDIT_EVENT_TIMESTAMP)
     }
 
+    /// Returns the `audit_log_retention_period` configuration parameter.
+    pub fn audit_log_retention_period(&self) -> Option<Duration> {
+        *self.expect_value(&AUDIT_LOG_RETENTION_PERIOD)
+    }
+
+    /// Returns the `audit_log_max_events` configuration parameter.
+    pub fn audit_log_max_events(&self) -> Option<usize> {
+        *self.expect_value(&AUDIT_LOG_MAX_EVENTS)
+    }
+
+    /// Returns the `audit_log_session_events` configuration parameter.
+    pub fn audit_log_session_events(&self) -> bool {
+        *self.expect_value(&AUDIT_LOG_SESSION_EVENTS)
+    }
+
+    /// Returns the `item_trash_retention` configuration parameter.
+    pub fn item_trash_retention(&self) -> Option<Duration> {
+        *self.expect_value(&ITEM_TRASH_RETENTION)
+    }
+
+    /// Returns the `storage_usage_rollup_age` configuration parameter.
+    pub fn storage_usage_rollup_age(&self) -> Option<Duration> {
+        *self.expect_value(&STORAGE_USAGE_ROLLUP_AGE)
+    }
+
     /// Returns the `enable_rbac_checks` configuration parameter.
     pub fn enable_rbac_checks(&self) -> bool {
         *self.expect_value(&ENABLE_RBAC_CHECKS)
@@ -3615,6 +3858,16 @@ impl SystemVars {
         *self.expect_value(&WEBHOOK_CONCURRENT_REQUEST_LIMIT)
     }
 
+    /// Returns the `max_concurrent_purifications` configuration parameter.
+    pub fn max_concurrent_purifications(&self) -> usize {
+        *self.expect_value(&MAX_CONCURRENT_PURIFICATIONS)
+    }
+
+    /// Returns the `max_concurrent_purifications_per_role` configuration parameter.
+    pub fn max_concurrent_purifications_per_role(&self) -> usize {
+        *self.expect_value(&MAX_CONCURRENT_PURIFICATIONS_PER_ROLE)
+    }
+
     /// Returns the `enable_columnation_lgalloc` configuration parameter.
     pub fn enable_columnation_lgalloc(&self) -> bool {
         *self.expect_value(&ENABLE_COLUMNATION_LGALLOC)
@@ -5309,6 +5562,12 @@ pub fn is_http_config_var(name: &str) -> bool {
     name == WEBHOOK_CONCURRENT_REQUEST_LIMIT.name()
 }
 
+/// Returns whether the named variable configures the purification worker pool.
+pub fn is_purification_config_var(name: &str) -> bool {
+    name == MAX_CONCURRENT_PURIFICATIONS.name()
+        || name == MAX_CONCURRENT_PURIFICATIONS_PER_ROLE.name()
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum ClientEncoding {
     Utf8,
@@ -49,6 +49,9 @@ const KEEPALIVE_IDLE: Duration = Duration::from_secs(10);
 pub struct SshTunnelConfig {
     /// The hostname of the SSH bastion server.
     pub host: String,
+    /// The hostnames of additional SSH bastion servers to try, in order, when
+    /// `host` is unreachable.
+    pub fallback_hosts: Vec<String>,
     /// The port to connect to.
     pub port: u16,
     /// The name of the user to connect as.
@@ -61,6 +64,7 @@ impl fmt::Debug for SshTunnelConfig {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Tunnel")
             .field("host", &self.host)
+            .field("fallback_hosts", &self.fallback_hosts)
             .field("port", &self.port)
             .field("user", &self.user)
             // Omit keys from debug output.
@@ -78,6 +82,12 @@ pub enum SshTunnelStatus {
 }
 
 impl SshTunnelConfig {
+    /// Returns the hostnames of the SSH bastion servers, in the order in which
+    /// connections to them are attempted.
+    pub fn hosts(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.host.as_str()).chain(self.fallback_hosts.iter().map(|h| h.as_str()))
+    }
+
     /// Establishes a connection to the specified host and port via the
     /// configured SSH tunnel.
     ///
@@ -98,7 +108,7 @@ impl SshTunnelConfig {
         // We could probably move this into the look and use the above channel to report this
         // initial connection error, but this is simpler and easier to read!
         info!(%tunnel_id, "connecting to ssh tunnel");
-        let mut session = match connect(self).await {
+        let (mut session, bastion) = match connect_any(self).await {
             Ok(s) => s,
             Err(e) => {
                 warn!(%tunnel_id, "failed to connect to ssh tunnel: {}", e.display_with_causes());
@@ -112,15 +122,17 @@ impl SshTunnelConfig {
                 return Err(e);
             }
         };
-        info!(%tunnel_id, %local_port, "connected to ssh tunnel");
+        info!(%tunnel_id, %local_port, %bastion, "connected to ssh tunnel");
         let local_port = Arc::new(AtomicU16::new(local_port));
         let status = Arc::new(Mutex::new(SshTunnelStatus::Running));
+        let bastion = Arc::new(Mutex::new(bastion));
 
         let join_handle = task::spawn(|| format!("ssh_session_{remote_host}:{remote_port}"), {
             let config = self.clone();
             let remote_host = remote_host.to_string();
             let local_port = Arc::clone(&local_port);
             let status = Arc::clone(&status);
+            let bastion = Arc::clone(&bastion);
             async move {
                 scopeguard::defer! {
                     info!(%tunnel_id, "terminating ssh tunnel");
@@ -134,7 +146,7 @@ impl SshTunnelConfig {
                     interval.tick().await;
                     if let Err(e) = session.check().await {
                         warn!(%tunnel_id, "ssh tunnel unhealthy: {}", e.display_with_causes());
-                        let s = match connect(&config).await {
+                        let (s, b) = match connect_any(&config).await {
                             Ok(s) => s,
                             Err(e) => {
                                 warn!(%tunnel_id, "reconnection to ssh tunnel failed: {}", e.display_with_causes());
@@ -152,8 +164,12 @@ impl SshTunnelConfig {
                                 continue;
                             }
                         };
+                        if *bastion.lock().expect("poisoned") != b {
+                            info!(%tunnel_id, bastion = %b, "ssh tunnel failed over to another bastion");
+                        }
                         session = s;
                         local_port.store(lp, Ordering::SeqCst);
+                        *bastion.lock().expect("poisoned") = b;
                         *status.lock().expect("poisoned") = SshTunnelStatus::Running;
                     }
                 }
@@ -163,14 +179,17 @@ impl SshTunnelConfig {
         Ok(SshTunnelHandle {
             local_port,
             status,
+            bastion,
             _join_handle: join_handle.abort_on_drop(),
         })
     }
 
     /// Validates the SSH configuration by establishing a connection to the intermediate SSH
     /// bastion host. It does not set up a port forwarding tunnel.
+    ///
+    /// Validation succeeds if any of the bastion hosts is reachable.
     pub async fn validate(&self) -> Result<(), anyhow::Error> {
-        connect(self).await?;
+        connect_any(self).await?;
         Ok(())
     }
 }
@@ -180,6 +199,7 @@ impl SshTunnelConfig {
 pub struct SshTunnelHandle {
     local_port: Arc<AtomicU16>,
     status: Arc<Mutex<SshTunnelStatus>>,
+    bastion: Arc<Mutex<String>>,
     _join_handle: AbortOnDropHandle<()>,
 }
 
@@ -200,9 +220,45 @@ impl SshTunnelHandle {
     pub fn check_status(&self) -> SshTunnelStatus {
         self.status.lock().expect("poisoned").clone()
     }
+
+    /// Returns the hostname of the SSH bastion server through which the
+    /// tunnel is currently routed.
+    pub fn bastion(&self) -> String {
+        self.bastion.lock().expect("poisoned").clone()
+    }
+}
+
+/// Connects to the first reachable bastion host of `config`, trying them in
+/// order.
+///
+/// Returns the session and the hostname of the bastion host it is connected
+/// to.
+async fn connect_any(config: &SshTunnelConfig) -> Result<(Session, String), anyhow::Error> {
+    if config.fallback_hosts.is_empty() {
+        let session = connect(config, &config.host).await?;
+        return Ok((session, config.host.clone()));
+    }
+
+    let mut errors = vec![];
+    for host in config.hosts() {
+        match connect(config, host).await {
+            Ok(session) => return Ok((session, host.to_string())),
+            Err(e) => {
+                warn!(
+                    "ssh bastion {}@{}:{} unreachable: {}",
+                    config.user,
+                    host,
+                    config.port,
+                    e.display_with_causes()
+                );
+                errors.push(format!("{host}: {}", e.display_with_causes()));
+            }
+        }
+    }
+    bail!("no ssh bastion reachable: {}", errors.join("; "))
 }
 
-async fn connect(config: &SshTunnelConfig) -> Result<Session, anyhow::Error> {
+async fn connect(config: &SshTunnelConfig, host: &str) -> Result<Session, anyhow::Error> {
     let tempdir = tempfile::Builder::new()
         .prefix("ssh-tunnel-key")
         .tempdir()?;
@@ -227,7 +283,7 @@ async fn connect(config: &SshTunnelConfig) -> Result<Session, anyhow::Error> {
         .keyfile(&path)
         .server_alive_interval(KEEPALIVE_IDLE)
         .connect_timeout(CONNECT_TIMEOUT)
-        .connect_mux(config.host.clone())
+        .connect_mux(host.to_string())
         .await?;
 
     // Delete the private key for safety: since `ssh` still has an open
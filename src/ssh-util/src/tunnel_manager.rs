@@ -116,7 +116,11 @@ impl SshTunnelManager {
 
                     info!(
                         "reusing existing ssh tunnel ({}:{} via {}@{}:{})",
-                        remote_host, remote_port, config.user, config.host, config.port,
+                        remote_host,
+                        remote_port,
+                        config.user,
+                        handle.bastion(),
+                        config.port,
                     );
                     return Ok(handle);
                 }
@@ -285,20 +285,26 @@ macro_rules! for_collections {
                 $macro!(catalog::CLUSTER_COLLECTION);
                 $macro!(catalog::CLUSTER_INTROSPECTION_SOURCE_INDEX_COLLECTION);
                 $macro!(catalog::CLUSTER_REPLICA_COLLECTION);
+                $macro!(catalog::CLUSTER_REPLICA_SIZES_COLLECTION);
+                $macro!(catalog::COLUMN_MASKS_COLLECTION);
                 $macro!(catalog::COMMENTS_COLLECTION);
                 $macro!(catalog::CONFIG_COLLECTION);
                 $macro!(catalog::DATABASES_COLLECTION);
                 $macro!(catalog::DEFAULT_PRIVILEGES_COLLECTION);
+                $macro!(catalog::DEPENDENCIES_COLLECTION);
                 $macro!(catalog::ID_ALLOCATOR_COLLECTION);
                 $macro!(catalog::ITEM_COLLECTION);
                 $macro!(catalog::ROLES_COLLECTION);
                 $macro!(catalog::SCHEMAS_COLLECTION);
+                $macro!(catalog::SERVICE_ACCOUNTS_COLLECTION);
                 $macro!(catalog::SETTING_COLLECTION);
                 $macro!(catalog::STORAGE_USAGE_COLLECTION);
                 $macro!(catalog::SYSTEM_CONFIGURATION_COLLECTION);
                 $macro!(catalog::SYSTEM_GID_MAPPING_COLLECTION);
                 $macro!(catalog::SYSTEM_PRIVILEGES_COLLECTION);
+                $macro!(catalog::TAGS_COLLECTION);
                 $macro!(catalog::TIMESTAMP_COLLECTION);
+                $macro!(catalog::TRASHED_ITEMS_COLLECTION);
             }
             Usage::Storage => {
                 $macro!(storage::METADATA_COLLECTION);
@@ -587,25 +587,76 @@ where
     }
 }
 
+/// An error from writing to a [`TableTransaction`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableError<K> {
+    /// The key of an inserted entry already exists.
+    DuplicateKey(K),
+    /// A value violates the uniqueness constraint with the value of an existing entry.
+    UniquenessViolation {
+        /// The field that the two values may not share, as reported by the uniqueness check.
+        field: &'static str,
+        /// The key of the existing entry.
+        existing_key: K,
+    },
+}
+
+impl<K> TableError<K> {
+    /// Returns the key of the existing entry that the write conflicts with.
+    pub fn into_existing_key(self) -> K {
+        match self {
+            TableError::DuplicateKey(key) => key,
+            TableError::UniquenessViolation { existing_key, .. } => existing_key,
+        }
+    }
+}
+
+impl<K> fmt::Display for TableError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableError::DuplicateKey(_) => f.write_str("duplicate key"),
+            TableError::UniquenessViolation { field, .. } => {
+                write!(f, "uniqueness violation on {field}")
+            }
+        }
+    }
+}
+
+impl<K: Debug> std::error::Error for TableError<K> {}
+
+impl<K> From<TableError<K>> for StashError {
+    fn from(e: TableError<K>) -> Self {
+        e.to_string().into()
+    }
+}
+
 /// TableTransaction emulates some features of a typical SQL transaction over
 /// table for a [`StashCollection`].
 ///
 /// It supports:
 /// - uniqueness constraints
 /// - transactional reads and writes (including read-your-writes before commit)
+/// - an optional secondary index
 ///
 /// `K` is the primary key type. Multiple entries with the same key are disallowed.
 /// `V` is the an arbitrary value type.
+/// `I` is the secondary index key type, see [`TableTransaction::new_with_index`].
 ///
 /// To finalize, add the results of [`TableTransaction::pending()`] to an
 /// [`AppendBatch`].
 #[derive(Debug, PartialEq, Eq)]
-pub struct TableTransaction<K, V> {
+pub struct TableTransaction<K, V, I = ()> {
     initial: BTreeMap<K, V>,
     // The desired state of keys after commit. `None` means the value will be
     // deleted.
     pending: BTreeMap<K, Option<V>>,
-    uniqueness_violation: fn(a: &V, b: &V) -> bool,
+    uniqueness_violation: fn(a: &V, b: &V) -> Option<&'static str>,
+    index_key: fn(k: &K, v: &V) -> I,
+    // The keys viewable in the current transaction, grouped by their index key.
+    index: BTreeMap<I, BTreeSet<K>>,
+    // Whether verifying the uniqueness constraint of writes is deferred until
+    // `verify_deferred` is called.
+    deferred: bool,
 }
 
 impl<K, V> TableTransaction<K, V>
@@ -614,7 +665,8 @@ where
     V: Ord + Clone,
 {
     /// Create a new TableTransaction with initial data. `uniqueness_violation` is a function
-    /// whether there is a uniqueness violation among two values.
+    /// that returns the name of the field that two values may not share if they violate the
+    /// uniqueness constraint, otherwise `None`.
     ///
     /// Internally the [`Stash`] serializes data as protobuf. All fields in a proto message are
     /// optional, which makes using them in Rust cumbersome. Generic parameters `KP` and `VP` are
@@ -622,7 +674,31 @@ where
     /// over.
     pub fn new<KP, VP>(
         initial: BTreeMap<KP, VP>,
-        uniqueness_violation: fn(a: &V, b: &V) -> bool,
+        uniqueness_violation: fn(a: &V, b: &V) -> Option<&'static str>,
+    ) -> Result<Self, TryFromProtoError>
+    where
+        K: RustType<KP>,
+        V: RustType<VP>,
+    {
+        Self::new_with_index(initial, uniqueness_violation, |_k, _v| ())
+    }
+}
+
+impl<K, V, I> TableTransaction<K, V, I>
+where
+    K: Ord + Eq + Clone,
+    V: Ord + Clone,
+    I: Ord + Clone,
+{
+    /// Like [`TableTransaction::new`], but additionally indexes entries by `index_key`, which
+    /// allows them to be looked up with [`TableTransaction::items_by_index`].
+    ///
+    /// Two values may only violate the uniqueness constraint if they have the same index key,
+    /// which allows uniqueness to be checked against only the entries sharing an index key.
+    pub fn new_with_index<KP, VP>(
+        initial: BTreeMap<KP, VP>,
+        uniqueness_violation: fn(a: &V, b: &V) -> Option<&'static str>,
+        index_key: fn(k: &K, v: &V) -> I,
     ) -> Result<Self, TryFromProtoError>
     where
         K: RustType<KP>,
@@ -633,11 +709,16 @@ where
             .map(RustType::from_proto)
             .collect::<Result<_, _>>()?;
 
-        Ok(Self {
+        let mut table = Self {
             initial,
             pending: BTreeMap::new(),
             uniqueness_violation,
-        })
+            index_key,
+            index: BTreeMap::new(),
+            deferred: false,
+        };
+        table.rebuild_index();
+        Ok(table)
     }
 
     /// Consumes and returns the pending changes and their diffs. `Diff` is
@@ -648,11 +729,32 @@ where
         V: RustType<VP>,
     {
         soft_assert!(self.verify().is_ok());
+        Self::diffs(&self.initial, self.pending)
+    }
+
+    /// Returns the pending changes and their diffs without consuming the
+    /// transaction. `Diff` is guaranteed to be 1 or -1.
+    pub fn peek_pending<KP, VP>(&self) -> Vec<(KP, VP, Diff)>
+    where
+        K: RustType<KP>,
+        V: RustType<VP>,
+    {
+        Self::diffs(&self.initial, self.pending.clone())
+    }
+
+    fn diffs<KP, VP>(
+        initial: &BTreeMap<K, V>,
+        pending: BTreeMap<K, Option<V>>,
+    ) -> Vec<(KP, VP, Diff)>
+    where
+        K: RustType<KP>,
+        V: RustType<VP>,
+    {
         // Pending describes the desired final state for some keys. K,V pairs should be
         // retracted if they already exist and were deleted or are being updated.
-        self.pending
+        pending
             .into_iter()
-            .map(|(k, v)| match self.initial.get(&k) {
+            .map(|(k, v)| match initial.get(&k) {
                 Some(initial_v) => {
                     let mut diffs = vec![(k.clone(), initial_v.clone(), -1)];
                     if let Some(v) = v {
@@ -673,13 +775,74 @@ where
             .collect()
     }
 
-    fn verify(&self) -> Result<(), StashError> {
-        // Compare each value to each other value and ensure they are unique.
-        let items = self.items();
-        for (i, vi) in items.values().enumerate() {
-            for (j, vj) in items.values().enumerate() {
-                if i != j && (self.uniqueness_violation)(vi, vj) {
-                    return Err("uniqueness violation".into());
+    /// Returns an error if any two values viewable in the current transaction
+    /// violate the uniqueness constraint.
+    pub fn verify(&self) -> Result<(), TableError<K>> {
+        // Compare each value to each other value with the same index key and
+        // ensure they are unique.
+        for keys in self.index.values() {
+            let entries: Vec<_> = keys
+                .iter()
+                .filter_map(|k| self.get(k).map(|v| (k, v)))
+                .collect();
+            for (i, (_ki, vi)) in entries.iter().enumerate() {
+                for (j, (kj, vj)) in entries.iter().enumerate() {
+                    if i == j {
+                        continue;
+                    }
+                    if let Some(field) = (self.uniqueness_violation)(vi, vj) {
+                        return Err(TableError::UniquenessViolation {
+                            field,
+                            existing_key: (*kj).clone(),
+                        });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Defers verifying the uniqueness constraint of writes until
+    /// [`TableTransaction::verify_deferred`] is called. This allows the
+    /// constraint to be temporarily violated within the transaction, e.g. to
+    /// swap the values of two keys.
+    pub fn defer_verification(&mut self) {
+        self.deferred = true;
+    }
+
+    /// Returns an error if any two values viewable in the current transaction
+    /// violate the uniqueness constraint and verification was deferred with
+    /// [`TableTransaction::defer_verification`]. Otherwise, writes are verified
+    /// immediately again from now on.
+    pub fn verify_deferred(&mut self) -> Result<(), TableError<K>> {
+        if self.deferred {
+            self.verify()?;
+            self.deferred = false;
+        }
+        Ok(())
+    }
+
+    /// Returns an error if `v` violates the uniqueness constraint with the value
+    /// of any key other than `k` viewable in the current transaction.
+    ///
+    /// Always succeeds if verification is deferred.
+    fn verify_value(&self, k: &K, v: &V) -> Result<(), TableError<K>> {
+        if self.deferred {
+            return Ok(());
+        }
+        let Some(keys) = self.index.get(&(self.index_key)(k, v)) else {
+            return Ok(());
+        };
+        for other_k in keys {
+            if other_k == k {
+                continue;
+            }
+            if let Some(other_v) = self.get(other_k) {
+                if let Some(field) = (self.uniqueness_violation)(other_v, v) {
+                    return Err(TableError::UniquenessViolation {
+                        field,
+                        existing_key: other_k.clone(),
+                    });
                 }
             }
         }
@@ -717,6 +880,11 @@ where
         }
     }
 
+    /// Returns the number of keys with pending changes.
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
     /// Returns the items viewable in the current transaction.
     pub fn items(&self) -> BTreeMap<K, V> {
         let mut items = BTreeMap::new();
@@ -726,34 +894,129 @@ where
         items
     }
 
+    /// Returns the items viewable in the current transaction whose index key is
+    /// `index_key`.
+    pub fn items_by_index(&self, index_key: &I) -> BTreeMap<K, V> {
+        self.index
+            .get(index_key)
+            .into_iter()
+            .flatten()
+            .filter_map(|k| self.get(k).map(|v| (k.clone(), v.clone())))
+            .collect()
+    }
+
+    /// Iterates over the items viewable in the current transaction whose index
+    /// key is `index_key` in key order and applies `f` on all key, value pairs.
+    pub fn for_values_by_index<F: FnMut(&K, &V)>(&self, index_key: &I, mut f: F) {
+        for k in self.index.get(index_key).into_iter().flatten() {
+            if let Some(v) = self.get(k) {
+                f(k, v);
+            }
+        }
+    }
+
+    /// Sets the pending value of `k` to `v`, keeping the index up to date. Does not
+    /// verify uniqueness.
+    fn write(&mut self, k: K, v: Option<V>) {
+        if let Some(prev) = self.get(&k) {
+            let prev_index_key = (self.index_key)(&k, prev);
+            if let Entry::Occupied(mut e) = self.index.entry(prev_index_key) {
+                e.get_mut().remove(&k);
+                if e.get().is_empty() {
+                    e.remove();
+                }
+            }
+        }
+        if let Some(v) = &v {
+            self.index
+                .entry((self.index_key)(&k, v))
+                .or_default()
+                .insert(k.clone());
+        }
+        self.pending.insert(k, v);
+    }
+
+    /// Recomputes the index from the items viewable in the current transaction.
+    fn rebuild_index(&mut self) {
+        let index_key = self.index_key;
+        let mut index: BTreeMap<I, BTreeSet<K>> = BTreeMap::new();
+        self.for_values(|k, v| {
+            index.entry(index_key(k, v)).or_default().insert(k.clone());
+        });
+        self.index = index;
+    }
+
     /// Iterates over the items viewable in the current transaction, and provides a
     /// map where additional pending items can be inserted, which will be appended
     /// to current pending items. Does not verify uniqueness.
     fn for_values_mut<F: FnMut(&mut BTreeMap<K, Option<V>>, &K, &V)>(&mut self, mut f: F) {
         let mut pending = BTreeMap::new();
         self.for_values(|k, v| f(&mut pending, k, v));
-        self.pending.extend(pending);
+        for (k, v) in pending {
+            self.write(k, v);
+        }
     }
 
     /// Inserts a new k,v pair.
     ///
     /// Returns an error if the uniqueness check failed or the key already exists.
-    pub fn insert(&mut self, k: K, v: V) -> Result<(), StashError> {
-        let mut violation = None;
-        self.for_values(|for_k, for_v| {
-            if &k == for_k {
-                violation = Some("duplicate key".to_string());
+    pub fn insert(&mut self, k: K, v: V) -> Result<(), TableError<K>> {
+        if self.get(&k).is_some() {
+            return Err(TableError::DuplicateKey(k));
+        }
+        self.verify_value(&k, &v)?;
+        self.write(k, Some(v));
+        soft_assert!(self.deferred || self.verify().is_ok());
+        Ok(())
+    }
+
+    /// Inserts many new k,v pairs.
+    ///
+    /// Unlike calling [`Self::insert`] for each pair, uniqueness is verified once
+    /// for all of the pairs, and runtime is logarithmic with respect to the number
+    /// of items viewable in the current transaction for each pair, plus the number
+    /// of items sharing an index key with each value.
+    ///
+    /// Returns an error if the uniqueness check failed or any key already exists,
+    /// in which case none of the pairs are inserted.
+    pub fn insert_many(
+        &mut self,
+        kvs: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<(), TableError<K>> {
+        let mut restores = BTreeMap::new();
+        let mut res = Ok(());
+        for (k, v) in kvs {
+            if self.get(&k).is_some() {
+                res = Err(TableError::DuplicateKey(k));
+                break;
             }
-            if (self.uniqueness_violation)(for_v, &v) {
-                violation = Some("uniqueness violation".to_string());
+            // Save the pending value for the key so we can restore it in case of
+            // failure.
+            restores.insert(k.clone(), self.pending.get(&k).cloned());
+            self.write(k, Some(v));
+        }
+
+        // Check for uniqueness violation.
+        if res.is_ok() {
+            res = self.verify_keys(restores.keys());
+        }
+        if res.is_err() {
+            for (k, restore) in restores {
+                // Revert self.pending to the state it was in before calling this
+                // function.
+                match restore {
+                    Some(v) => {
+                        self.pending.insert(k, v);
+                    }
+                    None => {
+                        self.pending.remove(&k);
+                    }
+                }
             }
-        });
-        if let Some(violation) = violation {
-            return Err(violation.into());
+            self.rebuild_index();
         }
-        self.pending.insert(k, Some(v));
-        soft_assert!(self.verify().is_ok());
-        Ok(())
+        soft_assert!(self.deferred || self.verify().is_ok());
+        res
     }
 
     /// Updates k, v pairs. `f` is a function that can return `Some(V)` if the
@@ -761,68 +1024,81 @@ where
     /// entries.
     ///
     /// Returns an error if the uniqueness check failed.
-    pub fn update<F: Fn(&K, &V) -> Option<V>>(&mut self, f: F) -> Result<Diff, StashError> {
-        let mut changed = 0;
+    pub fn update<F: Fn(&K, &V) -> Option<V>>(&mut self, f: F) -> Result<Diff, TableError<K>> {
         // Keep a copy of pending in case of uniqueness violation.
         let pending = self.pending.clone();
+        let mut changed = Vec::new();
         self.for_values_mut(|p, k, v| {
             if let Some(next) = f(k, v) {
-                changed += 1;
+                changed.push(k.clone());
                 p.insert(k.clone(), Some(next));
             }
         });
         // Check for uniqueness violation.
-        if let Err(err) = self.verify() {
+        if let Err(err) = self.verify_keys(&changed) {
             self.pending = pending;
+            self.rebuild_index();
             Err(err)
         } else {
-            Ok(changed)
+            Ok(changed.len().try_into().expect("must fit"))
         }
     }
 
-    /// Set the value for a key. Returns the previous entry if the key existed,
-    /// otherwise None.
+    /// Updates the value of each key in `kvs` that exists to its new value. Returns the
+    /// number of changed entries.
+    ///
+    /// Unlike [`Self::update`], runtime is logarithmic with respect to the number of items
+    /// viewable in the current transaction for each key, plus the number of items sharing an
+    /// index key with each new value.
     ///
     /// Returns an error if the uniqueness check failed.
+    pub fn update_by_keys(&mut self, kvs: BTreeMap<K, V>) -> Result<Diff, TableError<K>> {
+        let kvs: BTreeMap<_, _> = kvs
+            .into_iter()
+            .filter(|(k, _v)| self.get(k).is_some())
+            .map(|(k, v)| (k, Some(v)))
+            .collect();
+        let changed = kvs.len().try_into().expect("must fit");
+        self.set_many(kvs)?;
+        Ok(changed)
+    }
+
+    /// Updates the value of `k` to `v` if `k` exists. Returns whether `k` existed.
     ///
-    /// DO NOT call this function in a loop, use [`Self::set_many`] instead.
-    pub fn set(&mut self, k: K, v: Option<V>) -> Result<Option<V>, StashError> {
-        // Save the pending value for the key so we can restore it in case of
-        // uniqueness violation.
-        let restore = self.pending.get(&k).cloned();
-
-        let prev = match self.pending.entry(k.clone()) {
-            // key hasn't been set in this txn yet.
-            Entry::Vacant(e) => {
-                let initial = self.initial.get(&k);
-                if initial != v.as_ref() {
-                    // Provided value and initial value are different. Set key.
-                    e.insert(v);
-                }
-                // Return the initial txn's value of k.
-                initial.cloned()
-            }
-            // key has been set in this txn. Set it and return the previous
-            // pending value.
-            Entry::Occupied(mut e) => e.insert(v),
-        };
+    /// Returns an error if the uniqueness check failed.
+    pub fn update_by_key(&mut self, k: K, v: V) -> Result<bool, TableError<K>> {
+        Ok(self.update_by_keys(BTreeMap::from([(k, v)]))? > 0)
+    }
 
-        // Check for uniqueness violation.
-        if let Err(err) = self.verify() {
-            // Revert self.pending to the state it was in before calling this
-            // function.
-            match restore {
-                Some(v) => {
-                    self.pending.insert(k, v);
-                }
-                None => {
-                    self.pending.remove(&k);
-                }
+    /// Returns an error if the value of any key in `keys` violates the uniqueness
+    /// constraint.
+    fn verify_keys<'a>(&self, keys: impl IntoIterator<Item = &'a K>) -> Result<(), TableError<K>>
+    where
+        K: 'a,
+    {
+        for k in keys {
+            if let Some(v) = self.get(k) {
+                self.verify_value(k, v)?;
             }
-            Err(err)
-        } else {
-            Ok(prev)
         }
+        Ok(())
+    }
+
+    /// Set the value for a key. Returns the previous entry if the key existed,
+    /// otherwise None.
+    ///
+    /// Returns an error if the uniqueness check failed.
+    pub fn set(&mut self, k: K, v: Option<V>) -> Result<Option<V>, TableError<K>> {
+        if let Some(v) = &v {
+            self.verify_value(&k, v)?;
+        }
+        let prev = self.get(&k).cloned();
+        // Keys that haven't been set in this txn yet only need to be set if the
+        // provided value and initial value are different.
+        if self.pending.contains_key(&k) || self.initial.get(&k) != v.as_ref() {
+            self.write(k, v);
+        }
+        Ok(prev)
     }
 
     /// Set the values for many keys. Returns the previous entry for each key if the key existed,
@@ -832,7 +1108,7 @@ where
     pub fn set_many(
         &mut self,
         kvs: BTreeMap<K, Option<V>>,
-    ) -> Result<BTreeMap<K, Option<V>>, StashError> {
+    ) -> Result<BTreeMap<K, Option<V>>, TableError<K>> {
         let mut prevs = BTreeMap::new();
         let mut restores = BTreeMap::new();
 
@@ -842,26 +1118,17 @@ where
             let restore = self.pending.get(&k).cloned();
             restores.insert(k.clone(), restore);
 
-            let prev = match self.pending.entry(k.clone()) {
-                // key hasn't been set in this txn yet.
-                Entry::Vacant(e) => {
-                    let initial = self.initial.get(&k);
-                    if initial != v.as_ref() {
-                        // Provided value and initial value are different. Set key.
-                        e.insert(v);
-                    }
-                    // Return the initial txn's value of k.
-                    initial.cloned()
-                }
-                // key has been set in this txn. Set it and return the previous
-                // pending value.
-                Entry::Occupied(mut e) => e.insert(v),
-            };
+            let prev = self.get(&k).cloned();
+            // Keys that haven't been set in this txn yet only need to be set if the
+            // provided value and initial value are different.
+            if self.pending.contains_key(&k) || self.initial.get(&k) != v.as_ref() {
+                self.write(k.clone(), v);
+            }
             prevs.insert(k, prev);
         }
 
         // Check for uniqueness violation.
-        if let Err(err) = self.verify() {
+        if let Err(err) = self.verify_keys(prevs.keys()) {
             for (k, restore) in restores {
                 // Revert self.pending to the state it was in before calling this
                 // function.
@@ -874,6 +1141,7 @@ where
                     }
                 }
             }
+            self.rebuild_index();
             Err(err)
         } else {
             Ok(prevs)
@@ -890,7 +1158,50 @@ where
                 p.insert(k.clone(), None);
             }
         });
-        soft_assert!(self.verify().is_ok());
+        soft_assert!(self.deferred || self.verify().is_ok());
+        deleted
+    }
+
+    /// Deletes the items with keys in `ks`. Returns the keys and values of the deleted
+    /// entries.
+    ///
+    /// Unlike [`Self::delete`], runtime is logarithmic with respect to the number of items
+    /// viewable in the current transaction for each key.
+    pub fn delete_by_keys(&mut self, ks: impl IntoIterator<Item = K>) -> Vec<(K, V)> {
+        let mut deleted = Vec::new();
+        for k in ks {
+            if let Some(v) = self.get(&k).cloned() {
+                self.write(k.clone(), None);
+                deleted.push((k, v));
+            }
+        }
+        soft_assert!(self.deferred || self.verify().is_ok());
         deleted
     }
+
+    /// Deletes the item with key `k`. Returns the deleted value, if `k` existed.
+    pub fn delete_by_key(&mut self, k: K) -> Option<V> {
+        self.delete_by_keys([k]).into_iter().next().map(|(_k, v)| v)
+    }
+
+    /// Returns a snapshot of the pending changes, which can later be restored
+    /// with [`TableTransaction::rollback_to_savepoint`].
+    pub fn savepoint(&self) -> TableSavepoint<K, V> {
+        TableSavepoint {
+            pending: self.pending.clone(),
+        }
+    }
+
+    /// Discards all changes made since `savepoint` was taken.
+    pub fn rollback_to_savepoint(&mut self, savepoint: TableSavepoint<K, V>) {
+        self.pending = savepoint.pending;
+        self.rebuild_index();
+        soft_assert!(self.deferred || self.verify().is_ok());
+    }
+}
+
+/// The pending changes of a [`TableTransaction`] at some point in time.
+#[derive(Debug, Clone)]
+pub struct TableSavepoint<K, V> {
+    pending: BTreeMap<K, Option<V>>,
 }
@@ -79,7 +79,7 @@ use std::time::Duration;
 
 use crate::{
     AppendBatch, Data, DebugStashFactory, Stash, StashCollection, StashError, StashFactory,
-    TableTransaction, Timestamp, TypedCollection, INSERT_BATCH_SPLIT_SIZE,
+    TableError, TableTransaction, Timestamp, TypedCollection, INSERT_BATCH_SPLIT_SIZE,
 };
 use futures::Future;
 use mz_ore::assert_contains;
@@ -827,8 +827,8 @@ where
 
 async fn test_stash_table(stash: &mut Stash) {
     const TABLE: TypedCollection<Vec<u8>, String> = TypedCollection::new("table");
-    fn uniqueness_violation(a: &String, b: &String) -> bool {
-        a == b
+    fn uniqueness_violation(a: &String, b: &String) -> Option<&'static str> {
+        (a == b).then_some("value")
     }
     let collection = get(&TABLE, stash).await.unwrap();
 
@@ -889,11 +889,11 @@ async fn test_stash_table(stash: &mut Stash) {
         ])
     );
     assert_eq!(
-        table
-            .update(|_k, _v| Some("v1".to_string()))
-            .unwrap_err()
-            .to_string(),
-        "stash error: uniqueness violation"
+        table.update(|_k, _v| Some("v1".to_string())).unwrap_err(),
+        TableError::UniquenessViolation {
+            field: "value",
+            existing_key: 3i64.to_le_bytes().to_vec(),
+        }
     );
     let pending = table.pending();
     assert_eq!(
@@ -922,13 +922,22 @@ async fn test_stash_table(stash: &mut Stash) {
         .insert(1i64.to_le_bytes().to_vec(), "v3".to_string())
         .unwrap();
     // Uniqueness violation in value.
-    table
-        .insert(5i64.to_le_bytes().to_vec(), "v3".to_string())
-        .unwrap_err();
+    assert_eq!(
+        table
+            .insert(5i64.to_le_bytes().to_vec(), "v3".to_string())
+            .unwrap_err(),
+        TableError::UniquenessViolation {
+            field: "value",
+            existing_key: 1i64.to_le_bytes().to_vec(),
+        }
+    );
     // Key already exists, expect error.
-    table
-        .insert(1i64.to_le_bytes().to_vec(), "v5".to_string())
-        .unwrap_err();
+    assert_eq!(
+        table
+            .insert(1i64.to_le_bytes().to_vec(), "v5".to_string())
+            .unwrap_err(),
+        TableError::DuplicateKey(1i64.to_le_bytes().to_vec())
+    );
     assert_eq!(table.delete(|k, _v| k == &1i64.to_le_bytes()).len(), 1);
     // Both the inserts work now because the key and uniqueness violation are gone.
     table
@@ -1094,8 +1103,8 @@ async fn test_stash_table(stash: &mut Stash) {
 
 #[mz_ore::test]
 fn test_table() {
-    fn uniqueness_violation(a: &String, b: &String) -> bool {
-        a == b
+    fn uniqueness_violation(a: &String, b: &String) -> Option<&'static str> {
+        (a == b).then_some("value")
     }
     let mut table = TableTransaction::new(
         BTreeMap::from([(1i64.to_le_bytes().to_vec(), "a".to_string())]),
@@ -1110,3 +1119,260 @@ fn test_table() {
         .insert(3i64.to_le_bytes().to_vec(), "c".to_string())
         .unwrap();
 }
+
+#[mz_ore::test]
+fn test_table_savepoint() {
+    fn uniqueness_violation(a: &String, b: &String) -> Option<&'static str> {
+        (a == b).then_some("value")
+    }
+    let mut table = TableTransaction::new(
+        BTreeMap::from([(1i64.to_le_bytes().to_vec(), "a".to_string())]),
+        uniqueness_violation,
+    )
+    .unwrap();
+
+    table
+        .insert(2i64.to_le_bytes().to_vec(), "b".to_string())
+        .unwrap();
+    let savepoint = table.savepoint();
+    table
+        .insert(3i64.to_le_bytes().to_vec(), "c".to_string())
+        .unwrap();
+    table.delete(|k, _v| k == &1i64.to_le_bytes().to_vec());
+    assert_eq!(table.items().len(), 2);
+
+    table.rollback_to_savepoint(savepoint);
+    assert_eq!(
+        table.items(),
+        BTreeMap::from([
+            (1i64.to_le_bytes().to_vec(), "a".to_string()),
+            (2i64.to_le_bytes().to_vec(), "b".to_string()),
+        ])
+    );
+    // Values that were rolled back can be inserted again.
+    table
+        .insert(3i64.to_le_bytes().to_vec(), "c".to_string())
+        .unwrap();
+}
+
+#[mz_ore::test]
+fn test_table_peek_pending() {
+    fn uniqueness_violation(a: &String, b: &String) -> Option<&'static str> {
+        (a == b).then_some("value")
+    }
+    let mut table = TableTransaction::new(
+        BTreeMap::from([(1i64.to_le_bytes().to_vec(), "a".to_string())]),
+        uniqueness_violation,
+    )
+    .unwrap();
+
+    table
+        .insert(2i64.to_le_bytes().to_vec(), "b".to_string())
+        .unwrap();
+    table.delete(|k, _v| k == &1i64.to_le_bytes().to_vec());
+    table.verify().unwrap();
+
+    let peeked = table.peek_pending::<Vec<u8>, String>();
+    assert_eq!(peeked, table.pending::<Vec<u8>, String>());
+    assert_eq!(
+        peeked,
+        vec![
+            (1i64.to_le_bytes().to_vec(), "a".to_string(), -1),
+            (2i64.to_le_bytes().to_vec(), "b".to_string(), 1),
+        ]
+    );
+}
+
+#[mz_ore::test]
+fn test_table_index() {
+    fn uniqueness_violation(a: &String, b: &String) -> Option<&'static str> {
+        (a == b).then_some("value")
+    }
+    fn index_key(_k: &Vec<u8>, v: &String) -> char {
+        v.chars().next().unwrap()
+    }
+    let mut table = TableTransaction::new_with_index(
+        BTreeMap::from([
+            (1i64.to_le_bytes().to_vec(), "a1".to_string()),
+            (2i64.to_le_bytes().to_vec(), "a2".to_string()),
+        ]),
+        uniqueness_violation,
+        index_key,
+    )
+    .unwrap();
+
+    table
+        .insert(3i64.to_le_bytes().to_vec(), "b1".to_string())
+        .unwrap();
+    assert_eq!(
+        table.items_by_index(&'a'),
+        BTreeMap::from([
+            (1i64.to_le_bytes().to_vec(), "a1".to_string()),
+            (2i64.to_le_bytes().to_vec(), "a2".to_string()),
+        ])
+    );
+    assert_eq!(
+        table.items_by_index(&'b'),
+        BTreeMap::from([(3i64.to_le_bytes().to_vec(), "b1".to_string())])
+    );
+    assert!(table.items_by_index(&'c').is_empty());
+
+    // Uniqueness violation.
+    table
+        .update_by_key(2i64.to_le_bytes().to_vec(), "b1".to_string())
+        .unwrap_err();
+    // Unknown key.
+    assert!(!table
+        .update_by_key(4i64.to_le_bytes().to_vec(), "c1".to_string())
+        .unwrap());
+    assert!(table
+        .update_by_key(2i64.to_le_bytes().to_vec(), "b2".to_string())
+        .unwrap());
+    assert_eq!(
+        table.items_by_index(&'a'),
+        BTreeMap::from([(1i64.to_le_bytes().to_vec(), "a1".to_string())])
+    );
+    assert_eq!(table.items_by_index(&'b').len(), 2);
+    let mut values = Vec::new();
+    table.for_values_by_index(&'b', |_, v| values.push(v.clone()));
+    assert_eq!(values, vec!["b2".to_string(), "b1".to_string()]);
+
+    let savepoint = table.savepoint();
+    assert_eq!(
+        table.delete_by_key(3i64.to_le_bytes().to_vec()),
+        Some("b1".to_string())
+    );
+    assert_eq!(table.delete_by_key(3i64.to_le_bytes().to_vec()), None);
+    assert_eq!(
+        table.items_by_index(&'b'),
+        BTreeMap::from([(2i64.to_le_bytes().to_vec(), "b2".to_string())])
+    );
+    table.rollback_to_savepoint(savepoint);
+    assert_eq!(table.items_by_index(&'b').len(), 2);
+
+    let pending = table.pending::<Vec<u8>, String>();
+    assert_eq!(
+        pending,
+        vec![
+            (2i64.to_le_bytes().to_vec(), "a2".to_string(), -1),
+            (2i64.to_le_bytes().to_vec(), "b2".to_string(), 1),
+            (3i64.to_le_bytes().to_vec(), "b1".to_string(), 1),
+        ]
+    );
+}
+
+#[mz_ore::test]
+fn test_table_insert_many() {
+    fn uniqueness_violation(a: &String, b: &String) -> Option<&'static str> {
+        (a == b).then_some("value")
+    }
+    let mut table = TableTransaction::new(
+        BTreeMap::from([(1i64.to_le_bytes().to_vec(), "a".to_string())]),
+        uniqueness_violation,
+    )
+    .unwrap();
+
+    table
+        .insert_many([
+            (2i64.to_le_bytes().to_vec(), "b".to_string()),
+            (3i64.to_le_bytes().to_vec(), "c".to_string()),
+        ])
+        .unwrap();
+    assert_eq!(table.items().len(), 3);
+
+    // Existing key.
+    table
+        .insert_many([
+            (4i64.to_le_bytes().to_vec(), "d".to_string()),
+            (1i64.to_le_bytes().to_vec(), "e".to_string()),
+        ])
+        .unwrap_err();
+    // Duplicate key.
+    table
+        .insert_many([
+            (4i64.to_le_bytes().to_vec(), "d".to_string()),
+            (4i64.to_le_bytes().to_vec(), "e".to_string()),
+        ])
+        .unwrap_err();
+    // Uniqueness violation with an existing value.
+    table
+        .insert_many([
+            (4i64.to_le_bytes().to_vec(), "d".to_string()),
+            (5i64.to_le_bytes().to_vec(), "a".to_string()),
+        ])
+        .unwrap_err();
+    // Uniqueness violation between new values.
+    table
+        .insert_many([
+            (4i64.to_le_bytes().to_vec(), "d".to_string()),
+            (5i64.to_le_bytes().to_vec(), "d".to_string()),
+        ])
+        .unwrap_err();
+    // Failed inserts leave the table unchanged.
+    assert_eq!(
+        table.items(),
+        BTreeMap::from([
+            (1i64.to_le_bytes().to_vec(), "a".to_string()),
+            (2i64.to_le_bytes().to_vec(), "b".to_string()),
+            (3i64.to_le_bytes().to_vec(), "c".to_string()),
+        ])
+    );
+
+    let pending = table.pending::<Vec<u8>, String>();
+    assert_eq!(
+        pending,
+        vec![
+            (2i64.to_le_bytes().to_vec(), "b".to_string(), 1),
+            (3i64.to_le_bytes().to_vec(), "c".to_string(), 1),
+        ]
+    );
+}
+
+#[mz_ore::test]
+fn test_table_deferred_verification() {
+    fn uniqueness_violation(a: &String, b: &String) -> Option<&'static str> {
+        (a == b).then_some("value")
+    }
+    let mut table = TableTransaction::new(
+        BTreeMap::from([
+            (1i64.to_le_bytes().to_vec(), "a".to_string()),
+            (2i64.to_le_bytes().to_vec(), "b".to_string()),
+        ]),
+        uniqueness_violation,
+    )
+    .unwrap();
+
+    // Swapping two values requires temporarily violating the constraint.
+    assert!(table
+        .update_by_key(1i64.to_le_bytes().to_vec(), "b".to_string())
+        .is_err());
+    table.defer_verification();
+    table
+        .update_by_key(1i64.to_le_bytes().to_vec(), "b".to_string())
+        .unwrap();
+    table
+        .set(2i64.to_le_bytes().to_vec(), Some("a".to_string()))
+        .unwrap();
+    table.verify_deferred().unwrap();
+    assert_eq!(
+        table.items(),
+        BTreeMap::from([
+            (1i64.to_le_bytes().to_vec(), "b".to_string()),
+            (2i64.to_le_bytes().to_vec(), "a".to_string()),
+        ])
+    );
+
+    // Writes are verified immediately again.
+    assert!(table
+        .insert(3i64.to_le_bytes().to_vec(), "a".to_string())
+        .is_err());
+
+    // Violations that remain are reported once verified.
+    table.defer_verification();
+    table
+        .insert(3i64.to_le_bytes().to_vec(), "a".to_string())
+        .unwrap();
+    assert!(table.verify_deferred().is_err());
+    table.delete_by_key(3i64.to_le_bytes().to_vec());
+    table.verify_deferred().unwrap();
+}
@@ -10,6 +10,7 @@
 use std::cmp;
 use std::collections::btree_map::Entry;
 use std::collections::BTreeMap;
+use std::num::NonZeroI64;
 use std::sync::{Arc, Mutex};
 
 use futures::{
@@ -145,6 +146,21 @@ impl<'a> Transaction<'a> {
         res
     }
 
+    /// Returns the epoch of the stash as of this transaction.
+    ///
+    /// Unlike [`Stash::epoch`], this reads the epoch from the fence table, so it reflects any
+    /// stash that was opened since.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn epoch(&self) -> Result<NonZeroI64, StashError> {
+        let row = self
+            .client
+            .query_one("SELECT epoch FROM fence", &[])
+            .await?;
+        let epoch = NonZeroI64::new(row.get("epoch"))
+            .ok_or_else(|| StashError::from("fence epoch is zero"))?;
+        Ok(epoch)
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn collection<K, V>(&self, name: &str) -> Result<StashCollection<K, V>, StashError>
     where
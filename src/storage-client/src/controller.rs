@@ -331,6 +331,31 @@ pub trait StorageController: Debug {
         exports: BTreeMap<GlobalId, StorageSinkConnection>,
     ) -> Result<(), StorageError>;
 
+    /// Check that the identified export can be altered to read from the
+    /// collection `from` with the given `connection`.
+    ///
+    /// Note that this check is optimistic and its return of `Ok(())` does not guarantee that
+    /// subsequent calls to `alter_export_from` are guaranteed to succeed.
+    fn check_alter_export_from(
+        &self,
+        id: GlobalId,
+        from: GlobalId,
+        connection: &StorageSinkConnection,
+    ) -> Result<(), StorageError>;
+
+    /// Alter the identified export to read from the collection `from`, whose
+    /// description is `from_desc`, with the given `connection`.
+    ///
+    /// The export resumes from its current read capability, which `from` must
+    /// be readable at.
+    async fn alter_export_from(
+        &mut self,
+        id: GlobalId,
+        from: GlobalId,
+        from_desc: RelationDesc,
+        connection: StorageSinkConnection,
+    ) -> Result<(), StorageError>;
+
     /// Drops the read capability for the sources and allows their resources to be reclaimed.
     fn drop_sources(&mut self, identifiers: Vec<GlobalId>) -> Result<(), StorageError>;
 
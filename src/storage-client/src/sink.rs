@@ -374,9 +374,7 @@ pub async fn build_kafka(
         &progress_topic,
         TopicConfig {
             partition_count: 1,
-            // TODO: introduce and use `PROGRESS TOPIC REPLICATION FACTOR`
-            // on Kafka connections.
-            replication_factor: -1,
+            replication_factor: connection.progress_topic_replication_factor,
             cleanup_policy: TopicCleanupPolicy::Compaction,
         },
     )
@@ -397,6 +395,45 @@ pub async fn build_kafka(
     .await
     .check_ssh_status(admin_client.inner().context())
     .add_context("error registering kafka topic for sink")?;
+    if let Some(dead_letter_topic) = &connection.dead_letter_topic {
+        // Rows are only written to the dead letter topic when they cannot be
+        // encoded, so it uses the broker defaults rather than the settings of
+        // the data topic.
+        ensure_kafka_topic(
+            &admin_client,
+            dead_letter_topic,
+            TopicConfig {
+                partition_count: -1,
+                replication_factor: -1,
+                cleanup_policy: TopicCleanupPolicy::Retention(
+                    KafkaSinkConnectionRetention::default(),
+                ),
+            },
+        )
+        .await
+        .check_ssh_status(admin_client.inner().context())
+        .add_context("error registering kafka dead letter topic for sink")?;
+    }
+    // Topics that messages are routed to use the settings of the data topic,
+    // as they contain the same kind of data.
+    let allowlist = connection
+        .topic_routing
+        .iter()
+        .flat_map(|routing| routing.allowlist.iter().flatten());
+    for topic in allowlist {
+        ensure_kafka_topic(
+            &admin_client,
+            topic,
+            TopicConfig {
+                partition_count: connection.partition_count,
+                replication_factor: connection.replication_factor,
+                cleanup_policy: TopicCleanupPolicy::Retention(connection.retention),
+            },
+        )
+        .await
+        .check_ssh_status(admin_client.inner().context())
+        .add_context("error registering kafka routed topic for sink")?;
+    }
 
     Ok(latest_ts)
 }
@@ -1319,6 +1319,126 @@ where
         Ok(())
     }
 
+    fn check_alter_export_from(
+        &self,
+        id: GlobalId,
+        from: GlobalId,
+        connection: &StorageSinkConnection,
+    ) -> Result<(), StorageError> {
+        let export = self.export(id)?;
+        export
+            .description
+            .sink
+            .connection
+            .alter_compatible(id, connection)?;
+
+        // The export resumes from its read capability, so the new collection
+        // must not have been compacted beyond it.
+        let from_collection = self.collection(from)?;
+        if !PartialOrder::less_equal(&from_collection.implied_capability, &export.read_capability) {
+            return Err(StorageError::ReadBeforeSince(from));
+        }
+
+        Ok(())
+    }
+
+    async fn alter_export_from(
+        &mut self,
+        id: GlobalId,
+        from: GlobalId,
+        from_desc: RelationDesc,
+        connection: StorageSinkConnection,
+    ) -> Result<(), StorageError> {
+        self.check_alter_export_from(id, from, &connection)?;
+
+        let mut export = self.export(id)?.clone();
+        export.description.sink.from = from;
+        export.description.sink.from_desc = from_desc;
+        export.description.sink.connection = connection;
+
+        // Get export data
+        let value = MetadataExportFetcher::get_stash_collection()
+            .peek_key_one(&mut self.stash, id.into_proto())
+            .await?
+            .expect("known to exist");
+
+        let mut durable_export_data = DurableExportMetadata::from_proto(value)
+            .map_err(|e| StorageError::IOError(e.into()))?;
+
+        let from_storage_metadata = self.collection(from)?.collection_metadata.clone();
+
+        let read_capability = export.read_capability.to_owned();
+
+        // Downgrade this since--this mostly informs us whether or not we
+        // want to read the snapshot. For more details, see the
+        // implementation of `downgrade`.
+        durable_export_data
+            .initial_as_of
+            .downgrade(&read_capability);
+
+        let status_id = if let Some(status_collection_id) = export.description.sink.status_id {
+            Some(
+                self.collection(status_collection_id)?
+                    .collection_metadata
+                    .data_shard,
+            )
+        } else {
+            None
+        };
+
+        let cmd = RunSinkCommand {
+            id,
+            description: StorageSinkDesc {
+                from,
+                from_desc: export.description.sink.from_desc.clone(),
+                connection: export.description.sink.connection.clone(),
+                envelope: export.description.sink.envelope,
+                as_of: durable_export_data.initial_as_of,
+                status_id,
+                from_storage_metadata,
+            },
+        };
+
+        // Fetch the client for this exports's cluster.
+        let instance_id = export.description.instance_id;
+        let client = self.clients.get_mut(&instance_id).ok_or_else(|| {
+            StorageError::ExportInstanceMissing {
+                storage_instance_id: instance_id,
+                export_id: id,
+            }
+        })?;
+
+        client.send(StorageCommand::RunSinks(vec![cmd]));
+
+        // Move the export's read holds from its previous dependencies to the
+        // new collection.
+        let mut storage_read_updates = BTreeMap::new();
+        for time in read_capability.iter() {
+            for dependency in &export.storage_dependencies {
+                storage_read_updates
+                    .entry(*dependency)
+                    .or_insert_with(ChangeBatch::new)
+                    .update(time.clone(), -1);
+            }
+            storage_read_updates
+                .entry(from)
+                .or_insert_with(ChangeBatch::new)
+                .update(time.clone(), 1);
+        }
+        export.storage_dependencies = vec![from];
+
+        // Update state only after all possible errors have occurred.
+        *self.export_mut(id).expect("export known to exist") = export;
+        self.update_read_capabilities(&mut storage_read_updates);
+
+        self.sink_statistics
+            .lock()
+            .expect("poisoned")
+            .insert(id, statistics::StatsInitState(BTreeMap::new()));
+
+        Ok(())
+    }
+
     fn drop_sources(&mut self, identifiers: Vec<GlobalId>) -> Result<(), StorageError> {
         self.validate_collection_ids(identifiers.iter().cloned())?;
         self.drop_sources_unvalidated(identifiers);
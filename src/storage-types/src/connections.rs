@@ -22,6 +22,7 @@ use mz_kafka_util::client::{
     BrokerRewrite, MzClientContext, MzKafkaError, TunnelingClientContext,
     DEFAULT_FETCH_METADATA_TIMEOUT,
 };
+use mz_kafka_util::oauth::OauthClientCredentials;
 use mz_proto::tokio_postgres::any_ssl_mode;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::url::any_url;
@@ -43,7 +44,7 @@ use tokio::runtime::Handle;
 use tokio_postgres::config::SslMode;
 use url::Url;
 
-use crate::connections::aws::AwsConfig;
+use crate::connections::aws::{AwsConfig, AwsConnectionReference};
 use crate::errors::{ContextCreationError, CsrConnectError};
 
 pub mod aws;
@@ -262,6 +263,13 @@ impl Connection<InlinedConnection> {
             o => unreachable!("{o:?} is not a Kafka connection"),
         }
     }
+
+    pub fn unwrap_aws(self) -> <InlinedConnection as ConnectionAccess>::Aws {
+        match self {
+            Self::Aws(conn) => conn,
+            o => unreachable!("{o:?} is not an AWS connection"),
+        }
+    }
 }
 
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
@@ -277,31 +285,92 @@ pub struct KafkaTlsConfig {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
-pub struct KafkaSaslConfig {
+pub struct KafkaSaslConfig<C: ConnectionAccess = InlinedConnection> {
     pub mechanism: String,
-    pub username: StringOrSecret,
-    pub password: GlobalId,
+    pub username: Option<StringOrSecret>,
+    pub password: Option<GlobalId>,
+    /// The AWS connection whose credentials sign the tokens of the
+    /// `OAUTHBEARER` mechanism when authenticating with AWS IAM.
+    pub aws: Option<AwsConnectionReference<C>>,
+    /// The client credentials with which the tokens of the `OAUTHBEARER`
+    /// mechanism are requested from an OAuth authorization server.
+    pub oauth: Option<KafkaSaslOauthConfig>,
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<KafkaSaslConfig, R>
+    for KafkaSaslConfig<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> KafkaSaslConfig {
+        let KafkaSaslConfig {
+            mechanism,
+            username,
+            password,
+            aws,
+            oauth,
+        } = self;
+
+        KafkaSaslConfig {
+            mechanism,
+            username,
+            password,
+            aws: aws.map(|aws| aws.into_inline_connection(r)),
+            oauth,
+        }
+    }
 }
 
-impl Arbitrary for KafkaSaslConfig {
+impl<C: ConnectionAccess> Arbitrary for KafkaSaslConfig<C> {
     type Strategy = BoxedStrategy<Self>;
     type Parameters = ();
 
     fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
         (
             any::<String>(),
-            StringOrSecret::arbitrary(),
-            GlobalId::arbitrary(),
+            any::<Option<StringOrSecret>>(),
+            any::<Option<GlobalId>>(),
+            any::<Option<AwsConnectionReference<C>>>(),
+            any::<Option<KafkaSaslOauthConfig>>(),
         )
-            .prop_map(|(mechanism, username, password)| KafkaSaslConfig {
-                mechanism,
-                username,
-                password,
-            })
+            .prop_map(
+                |(mechanism, username, password, aws, oauth)| KafkaSaslConfig {
+                    mechanism,
+                    username,
+                    password,
+                    aws,
+                    oauth,
+                },
+            )
             .boxed()
     }
 }
 
+/// The client credentials with which a Kafka connection requests tokens for the
+/// `OAUTHBEARER` SASL mechanism from an OAuth authorization server.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct KafkaSaslOauthConfig {
+    /// The URL of the authorization server's token endpoint.
+    pub token_endpoint: String,
+    pub client_id: StringOrSecret,
+    pub client_secret: GlobalId,
+    /// The scope to request, if any.
+    pub scope: Option<String>,
+}
+
+impl KafkaSaslOauthConfig {
+    /// Reads the secrets of the client credentials.
+    async fn load(
+        &self,
+        secrets_reader: &dyn SecretsReader,
+    ) -> Result<OauthClientCredentials, anyhow::Error> {
+        Ok(OauthClientCredentials {
+            token_endpoint: self.token_endpoint.clone(),
+            client_id: self.client_id.get_string(secrets_reader).await?,
+            client_secret: secrets_reader.read_string(self.client_secret).await?,
+            scope: self.scope.clone(),
+        })
+    }
+}
+
 /// Specifies a Kafka broker in a [`KafkaConnection`].
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct KafkaBroker<C: ConnectionAccess = InlinedConnection> {
@@ -351,7 +420,16 @@ pub struct KafkaConnection<C: ConnectionAccess = InlinedConnection> {
     pub progress_topic: Option<String>,
     pub options: BTreeMap<String, StringOrSecret>,
     pub tls: Option<KafkaTlsConfig>,
-    pub sasl: Option<KafkaSaslConfig>,
+    pub sasl: Option<KafkaSaslConfig<C>>,
+    /// The `GROUP ID PREFIX` that sources and sinks using this connection
+    /// inherit unless they specify their own.
+    pub default_group_id_prefix: Option<String>,
+    /// The `TOPIC METADATA REFRESH INTERVAL MS` that sources and sinks using
+    /// this connection inherit unless they specify their own.
+    pub default_topic_metadata_refresh_interval_ms: Option<i32>,
+    /// The networks, in CIDR notation, to which sources and sinks using this
+    /// connection may connect. If empty, any network is permitted.
+    pub egress_allowlist: Vec<String>,
 }
 
 impl<R: ConnectionResolver> IntoInlineConnection<KafkaConnection, R>
@@ -365,6 +443,9 @@ impl<R: ConnectionResolver> IntoInlineConnection<KafkaConnection, R>
             options,
             tls,
             sasl,
+            default_group_id_prefix,
+            default_topic_metadata_refresh_interval_ms,
+            egress_allowlist,
         } = self;
 
         let brokers = brokers
@@ -378,7 +459,10 @@ impl<R: ConnectionResolver> IntoInlineConnection<KafkaConnection, R>
             default_tunnel: default_tunnel.into_inline_connection(&r),
             options,
             tls,
-            sasl,
+            sasl: sasl.map(|sasl| sasl.into_inline_connection(&r)),
+            default_group_id_prefix,
+            default_topic_metadata_refresh_interval_ms,
+            egress_allowlist,
         }
     }
 }
@@ -409,6 +493,23 @@ impl<C: ConnectionAccess> KafkaConnection<C> {
 }
 
 impl KafkaConnection {
+    /// Requests a token from the OAuth authorization server of the connection,
+    /// if it authenticates with OAuth, which checks that the server accepts the
+    /// connection's client credentials.
+    pub async fn validate_oauth_credentials(
+        &self,
+        connection_context: &ConnectionContext,
+    ) -> Result<(), anyhow::Error> {
+        if let Some(oauth) = self.sasl.as_ref().and_then(|sasl| sasl.oauth.as_ref()) {
+            let credentials = oauth
+                .load(&*connection_context.secrets_reader)
+                .await
+                .context("reading OAuth client credentials")?;
+            mz_kafka_util::oauth::fetch_token(&credentials).await?;
+        }
+        Ok(())
+    }
+
     /// Creates a Kafka client for the connection.
     pub async fn create_with_context<C, T>(
         &self,
@@ -450,11 +551,12 @@ impl KafkaConnection {
         }
         if let Some(sasl) = &self.sasl {
             options.insert("sasl.mechanisms".into(), (&sasl.mechanism).into());
-            options.insert("sasl.username".into(), sasl.username.clone());
-            options.insert(
-                "sasl.password".into(),
-                StringOrSecret::Secret(sasl.password),
-            );
+            if let Some(username) = &sasl.username {
+                options.insert("sasl.username".into(), username.clone());
+            }
+            if let Some(password) = sasl.password {
+                options.insert("sasl.password".into(), StringOrSecret::Secret(password));
+            }
         }
 
         // We don't override any socket timeouts (like `socket.timeout.ms`) as we allow rdkafka
@@ -483,6 +585,25 @@ impl KafkaConnection {
             connection_context.ssh_tunnel_manager.clone(),
         );
 
+        if let Some(aws) = self.sasl.as_ref().and_then(|sasl| sasl.aws.as_ref()) {
+            let sdk_config = aws
+                .connection
+                .load(
+                    connection_context.aws_external_id_prefix.as_ref(),
+                    Some(&aws.connection_id),
+                    &*connection_context.secrets_reader,
+                )
+                .await;
+            context.set_aws_config(sdk_config);
+        }
+        if let Some(oauth) = self.sasl.as_ref().and_then(|sasl| sasl.oauth.as_ref()) {
+            let credentials = oauth
+                .load(&*connection_context.secrets_reader)
+                .await
+                .context("reading OAuth client credentials")?;
+            context.set_oauth_credentials(credentials);
+        }
+
         match &self.default_tunnel {
             Tunnel::Direct => {
                 // By default, don't offer a default override for broker address lookup.
@@ -499,6 +620,7 @@ impl KafkaConnection {
 
                 context.set_default_ssh_tunnel(SshTunnelConfig {
                     host: ssh_tunnel.connection.host.clone(),
+                    fallback_hosts: ssh_tunnel.connection.fallback_hosts.clone(),
                     port: ssh_tunnel.connection.port,
                     user: ssh_tunnel.connection.user.clone(),
                     key_pair,
@@ -547,6 +669,7 @@ impl KafkaConnection {
                             addr,
                             SshTunnelConfig {
                                 host: ssh_tunnel.connection.host.clone(),
+                                fallback_hosts: ssh_tunnel.connection.fallback_hosts.clone(),
                                 port: ssh_tunnel.connection.port,
                                 user: ssh_tunnel.connection.user.clone(),
                                 key_pair: SshKeyPair::from_bytes(
@@ -571,6 +694,10 @@ impl KafkaConnection {
         _id: GlobalId,
         connection_context: &ConnectionContext,
     ) -> Result<(), anyhow::Error> {
+        // Failures to fetch OAuth tokens are only logged by librdkafka, so
+        // check the client credentials up front to report them.
+        self.validate_oauth_credentials(connection_context).await?;
+
         let (context, error_rx) = MzClientContext::with_errors();
         let consumer: BaseConsumer<_> = self
             .create_with_context(connection_context, context, &BTreeMap::new())
@@ -635,20 +762,44 @@ impl RustType<ProtoKafkaConnectionSaslConfig> for KafkaSaslConfig {
     fn into_proto(&self) -> ProtoKafkaConnectionSaslConfig {
         ProtoKafkaConnectionSaslConfig {
             mechanism: self.mechanism.into_proto(),
-            username: Some(self.username.into_proto()),
-            password: Some(self.password.into_proto()),
+            username: self.username.into_proto(),
+            password: self.password.into_proto(),
+            aws: self.aws.into_proto(),
+            oauth: self.oauth.into_proto(),
         }
     }
 
     fn from_proto(proto: ProtoKafkaConnectionSaslConfig) -> Result<Self, TryFromProtoError> {
         Ok(KafkaSaslConfig {
             mechanism: proto.mechanism,
-            username: proto
-                .username
-                .into_rust_if_some("ProtoKafkaConnectionSaslConfig::username")?,
-            password: proto
-                .password
-                .into_rust_if_some("ProtoKafkaConnectionSaslConfig::password")?,
+            username: proto.username.into_rust()?,
+            password: proto.password.into_rust()?,
+            aws: proto.aws.into_rust()?,
+            oauth: proto.oauth.into_rust()?,
+        })
+    }
+}
+
+impl RustType<ProtoKafkaConnectionSaslOauthConfig> for KafkaSaslOauthConfig {
+    fn into_proto(&self) -> ProtoKafkaConnectionSaslOauthConfig {
+        ProtoKafkaConnectionSaslOauthConfig {
+            token_endpoint: self.token_endpoint.into_proto(),
+            client_id: Some(self.client_id.into_proto()),
+            client_secret: Some(self.client_secret.into_proto()),
+            scope: self.scope.clone(),
+        }
+    }
+
+    fn from_proto(proto: ProtoKafkaConnectionSaslOauthConfig) -> Result<Self, TryFromProtoError> {
+        Ok(KafkaSaslOauthConfig {
+            token_endpoint: proto.token_endpoint,
+            client_id: proto
+                .client_id
+                .into_rust_if_some("ProtoKafkaConnectionSaslOauthConfig::client_id")?,
+            client_secret: proto
+                .client_secret
+                .into_rust_if_some("ProtoKafkaConnectionSaslOauthConfig::client_secret")?,
+            scope: proto.scope,
         })
     }
 }
@@ -666,6 +817,10 @@ impl RustType<ProtoKafkaConnection> for KafkaConnection {
                 .collect(),
             tls: self.tls.into_proto(),
             sasl: self.sasl.into_proto(),
+            default_group_id_prefix: self.default_group_id_prefix.clone(),
+            default_topic_metadata_refresh_interval_ms: self
+                .default_topic_metadata_refresh_interval_ms,
+            egress_allowlist: self.egress_allowlist.clone(),
         }
     }
 
@@ -683,6 +838,10 @@ impl RustType<ProtoKafkaConnection> for KafkaConnection {
                 .collect::<Result<_, _>>()?,
             tls: proto.tls.into_rust()?,
             sasl: proto.sasl.into_rust()?,
+            default_group_id_prefix: proto.default_group_id_prefix,
+            default_topic_metadata_refresh_interval_ms: proto
+                .default_topic_metadata_refresh_interval_ms,
+            egress_allowlist: proto.egress_allowlist,
         })
     }
 }
@@ -699,6 +858,10 @@ pub struct CsrConnection<C: ConnectionAccess = InlinedConnection> {
     pub tls_identity: Option<TlsIdentity>,
     /// Optional HTTP authentication credentials for the schema registry.
     pub http_auth: Option<CsrConnectionHttpAuth>,
+    /// Optional OAuth client credentials for the schema registry.
+    pub oauth: Option<CsrConnectionOauth>,
+    /// An optional HTTP(S) proxy through which to route traffic.
+    pub proxy: Option<CsrConnectionProxy>,
     /// A tunnel through which to route traffic.
     pub tunnel: Tunnel<C>,
 }
@@ -712,6 +875,8 @@ impl<R: ConnectionResolver> IntoInlineConnection<CsrConnection, R>
             tls_root_cert,
             tls_identity,
             http_auth,
+            oauth,
+            proxy,
             tunnel,
         } = self;
         CsrConnection {
@@ -719,6 +884,8 @@ impl<R: ConnectionResolver> IntoInlineConnection<CsrConnection, R>
             tls_root_cert,
             tls_identity,
             http_auth,
+            oauth,
+            proxy,
             tunnel: tunnel.into_inline_connection(r),
         }
     }
@@ -781,6 +948,47 @@ impl CsrConnection {
             client_config = client_config.auth(username, password);
         }
 
+        if let Some(oauth) = &self.oauth {
+            let token_endpoint: Url = oauth
+                .token_endpoint
+                .parse()
+                .context("parsing OAuth token endpoint")?;
+            let client_id = oauth
+                .client_id
+                .get_string(&*connection_context.secrets_reader)
+                .await?;
+            let client_secret = connection_context
+                .secrets_reader
+                .read_string(oauth.client_secret)
+                .await?;
+            client_config = client_config.oauth(mz_ccsr::Oauth {
+                token_endpoint,
+                client_id,
+                client_secret,
+                scope: oauth.scope.clone(),
+            });
+        }
+
+        if let Some(proxy) = &self.proxy {
+            let mut ccsr_proxy = mz_ccsr::Proxy::all(&proxy.url).context("parsing proxy URL")?;
+            if let Some(username) = &proxy.username {
+                let username = username
+                    .get_string(&*connection_context.secrets_reader)
+                    .await?;
+                let password = match proxy.password {
+                    None => String::new(),
+                    Some(password) => {
+                        connection_context
+                            .secrets_reader
+                            .read_string(password)
+                            .await?
+                    }
+                };
+                ccsr_proxy = ccsr_proxy.basic_auth(&username, &password);
+            }
+            client_config = client_config.proxy(ccsr_proxy);
+        }
+
         // `net::lookup_host` requires a port but the port will be ignored when
         // passed to `resolve_to_addrs`. We use a dummy port that will be easy
         // to spot in the logs to make it obvious if some component downstream
@@ -884,6 +1092,8 @@ impl RustType<ProtoCsrConnection> for CsrConnection {
             tls_identity: self.tls_identity.into_proto(),
             http_auth: self.http_auth.into_proto(),
             tunnel: Some(self.tunnel.into_proto()),
+            oauth: self.oauth.into_proto(),
+            proxy: self.proxy.into_proto(),
         }
     }
 
@@ -893,6 +1103,8 @@ impl RustType<ProtoCsrConnection> for CsrConnection {
             tls_root_cert: proto.tls_root_cert.into_rust()?,
             tls_identity: proto.tls_identity.into_rust()?,
             http_auth: proto.http_auth.into_rust()?,
+            oauth: proto.oauth.into_rust()?,
+            proxy: proto.proxy.into_rust()?,
             tunnel: proto
                 .tunnel
                 .into_rust_if_some("ProtoCsrConnection::tunnel")?,
@@ -910,15 +1122,21 @@ impl<C: ConnectionAccess> Arbitrary for CsrConnection<C> {
             any::<Option<StringOrSecret>>(),
             any::<Option<TlsIdentity>>(),
             any::<Option<CsrConnectionHttpAuth>>(),
+            any::<Option<CsrConnectionOauth>>(),
+            any::<Option<CsrConnectionProxy>>(),
             any::<Tunnel<C>>(),
         )
             .prop_map(
-                |(url, tls_root_cert, tls_identity, http_auth, tunnel)| CsrConnection {
-                    url,
-                    tls_root_cert,
-                    tls_identity,
-                    http_auth,
-                    tunnel,
+                |(url, tls_root_cert, tls_identity, http_auth, oauth, proxy, tunnel)| {
+                    CsrConnection {
+                        url,
+                        tls_root_cert,
+                        tls_identity,
+                        http_auth,
+                        oauth,
+                        proxy,
+                        tunnel,
+                    }
                 },
             )
             .boxed()
@@ -978,6 +1196,73 @@ impl RustType<ProtoCsrConnectionHttpAuth> for CsrConnectionHttpAuth {
     }
 }
 
+/// OAuth 2.0 client credentials in a [`CsrConnection`].
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct CsrConnectionOauth {
+    /// The URL of the authorization server's token endpoint.
+    pub token_endpoint: String,
+    /// The client ID.
+    pub client_id: StringOrSecret,
+    /// The ID of the secret containing the client secret.
+    pub client_secret: GlobalId,
+    /// The scope to request, if any.
+    pub scope: Option<String>,
+}
+
+impl RustType<ProtoCsrConnectionOauth> for CsrConnectionOauth {
+    fn into_proto(&self) -> ProtoCsrConnectionOauth {
+        ProtoCsrConnectionOauth {
+            token_endpoint: self.token_endpoint.clone(),
+            client_id: Some(self.client_id.into_proto()),
+            client_secret: Some(self.client_secret.into_proto()),
+            scope: self.scope.clone(),
+        }
+    }
+
+    fn from_proto(proto: ProtoCsrConnectionOauth) -> Result<Self, TryFromProtoError> {
+        Ok(CsrConnectionOauth {
+            token_endpoint: proto.token_endpoint,
+            client_id: proto
+                .client_id
+                .into_rust_if_some("ProtoCsrConnectionOauth::client_id")?,
+            client_secret: proto
+                .client_secret
+                .into_rust_if_some("ProtoCsrConnectionOauth::client_secret")?,
+            scope: proto.scope,
+        })
+    }
+}
+
+/// An HTTP(S) proxy in a [`CsrConnection`].
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct CsrConnectionProxy {
+    /// The URL of the proxy.
+    pub url: String,
+    /// The username with which to authenticate to the proxy, if any.
+    pub username: Option<StringOrSecret>,
+    /// The ID of the secret containing the password with which to
+    /// authenticate to the proxy, if any.
+    pub password: Option<GlobalId>,
+}
+
+impl RustType<ProtoCsrConnectionProxy> for CsrConnectionProxy {
+    fn into_proto(&self) -> ProtoCsrConnectionProxy {
+        ProtoCsrConnectionProxy {
+            url: self.url.clone(),
+            username: self.username.into_proto(),
+            password: self.password.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoCsrConnectionProxy) -> Result<Self, TryFromProtoError> {
+        Ok(CsrConnectionProxy {
+            url: proto.url,
+            username: proto.username.into_rust()?,
+            password: proto.password.into_rust()?,
+        })
+    }
+}
+
 /// A connection to a PostgreSQL server.
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct PostgresConnection<C: ConnectionAccess = InlinedConnection> {
@@ -1000,6 +1285,12 @@ pub struct PostgresConnection<C: ConnectionAccess = InlinedConnection> {
     pub tls_root_cert: Option<StringOrSecret>,
     /// An optional TLS client certificate for authentication.
     pub tls_identity: Option<TlsIdentity>,
+    /// An optional AWS connection with which to authenticate using AWS IAM,
+    /// in place of a password.
+    pub aws_connection: Option<AwsConnectionReference<C>>,
+    /// The networks, in CIDR notation, to which sources and sinks using this
+    /// connection may connect. If empty, any network is permitted.
+    pub egress_allowlist: Vec<String>,
 }
 
 impl<R: ConnectionResolver> IntoInlineConnection<PostgresConnection, R>
@@ -1016,6 +1307,8 @@ impl<R: ConnectionResolver> IntoInlineConnection<PostgresConnection, R>
             tls_mode,
             tls_root_cert,
             tls_identity,
+            aws_connection,
+            egress_allowlist,
         } = self;
 
         PostgresConnection {
@@ -1024,10 +1317,12 @@ impl<R: ConnectionResolver> IntoInlineConnection<PostgresConnection, R>
             database,
             user,
             password,
-            tunnel: tunnel.into_inline_connection(r),
+            tunnel: tunnel.into_inline_connection(&r),
             tls_mode,
             tls_root_cert,
             tls_identity,
+            aws_connection: aws_connection.map(|aws| aws.into_inline_connection(&r)),
+            egress_allowlist,
         }
     }
 }
@@ -1041,8 +1336,9 @@ impl<C: ConnectionAccess> PostgresConnection<C> {
 impl PostgresConnection<InlinedConnection> {
     pub async fn config(
         &self,
-        secrets_reader: &dyn mz_secrets::SecretsReader,
+        connection_context: &ConnectionContext,
     ) -> Result<mz_postgres_util::Config, anyhow::Error> {
+        let secrets_reader = &*connection_context.secrets_reader;
         let mut config = tokio_postgres::Config::new();
         config
             .host(&self.host)
@@ -1075,6 +1371,7 @@ impl PostgresConnection<InlinedConnection> {
                 mz_postgres_util::TunnelConfig::Ssh {
                     config: SshTunnelConfig {
                         host: connection.host.clone(),
+                        fallback_hosts: connection.fallback_hosts.clone(),
                         port: connection.port,
                         user: connection.user.clone(),
                         key_pair,
@@ -1089,7 +1386,20 @@ impl PostgresConnection<InlinedConnection> {
             }
         };
 
-        Ok(mz_postgres_util::Config::new(config, tunnel)?)
+        let mut config = mz_postgres_util::Config::new(config, tunnel)?;
+        if let Some(aws) = &self.aws_connection {
+            let sdk_config = aws
+                .connection
+                .load(
+                    connection_context.aws_external_id_prefix.as_ref(),
+                    Some(&aws.connection_id),
+                    secrets_reader,
+                )
+                .await;
+            config = config.aws_auth(sdk_config);
+        }
+
+        Ok(config)
     }
 
     async fn validate(
@@ -1097,7 +1407,7 @@ impl PostgresConnection<InlinedConnection> {
         _id: GlobalId,
         connection_context: &ConnectionContext,
     ) -> Result<(), anyhow::Error> {
-        let config = self.config(&*connection_context.secrets_reader).await?;
+        let config = self.config(connection_context).await?;
         config
             .connect(
                 "connection validation",
@@ -1120,6 +1430,8 @@ impl RustType<ProtoPostgresConnection> for PostgresConnection {
             tls_root_cert: self.tls_root_cert.into_proto(),
             tls_identity: self.tls_identity.into_proto(),
             tunnel: Some(self.tunnel.into_proto()),
+            aws_connection: self.aws_connection.into_proto(),
+            egress_allowlist: self.egress_allowlist.clone(),
         }
     }
 
@@ -1140,6 +1452,8 @@ impl RustType<ProtoPostgresConnection> for PostgresConnection {
                 .into_rust_if_some("ProtoPostgresConnection::tls_mode")?,
             tls_root_cert: proto.tls_root_cert.into_rust()?,
             tls_identity: proto.tls_identity.into_rust()?,
+            aws_connection: proto.aws_connection.into_rust()?,
+            egress_allowlist: proto.egress_allowlist,
         })
     }
 }
@@ -1159,6 +1473,8 @@ impl<C: ConnectionAccess> Arbitrary for PostgresConnection<C> {
             any_ssl_mode(),
             any::<Option<StringOrSecret>>(),
             any::<Option<TlsIdentity>>(),
+            any::<Option<AwsConnectionReference<C>>>(),
+            any::<Vec<String>>(),
         )
             .prop_map(
                 |(
@@ -1171,6 +1487,8 @@ impl<C: ConnectionAccess> Arbitrary for PostgresConnection<C> {
                     tls_mode,
                     tls_root_cert,
                     tls_identity,
+                    aws_connection,
+                    egress_allowlist,
                 )| {
                     PostgresConnection {
                         host,
@@ -1182,6 +1500,8 @@ impl<C: ConnectionAccess> Arbitrary for PostgresConnection<C> {
                         tls_mode,
                         tls_root_cert,
                         tls_identity,
+                        aws_connection,
+                        egress_allowlist,
                     }
                 },
             )
@@ -1237,6 +1557,8 @@ impl RustType<ProtoTunnel> for Tunnel<InlinedConnection> {
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct SshConnection {
     pub host: String,
+    /// Additional bastion hosts to try, in order, when `host` is unreachable.
+    pub fallback_hosts: Vec<String>,
     pub port: u16,
     pub user: String,
     pub public_keys: Option<(String, String)>,
@@ -1269,12 +1591,14 @@ impl RustType<ProtoSshConnection> for SshConnection {
             port: self.port.into_proto(),
             user: self.user.into_proto(),
             public_keys: self.public_keys.into_proto(),
+            fallback_hosts: self.fallback_hosts.clone(),
         }
     }
 
     fn from_proto(proto: ProtoSshConnection) -> Result<Self, TryFromProtoError> {
         Ok(SshConnection {
             host: proto.host,
+            fallback_hosts: proto.fallback_hosts,
             port: proto.port.into_rust()?,
             user: proto.user,
             public_keys: proto.public_keys.into_rust()?,
@@ -1371,6 +1695,7 @@ impl SshTunnel<InlinedConnection> {
             .connect(
                 SshTunnelConfig {
                     host: self.connection.host.clone(),
+                    fallback_hosts: self.connection.fallback_hosts.clone(),
                     port: self.connection.port,
                     user: self.connection.user.clone(),
                     key_pair: SshKeyPair::from_bytes(
@@ -1397,6 +1722,7 @@ impl SshConnection {
         let key_pair = SshKeyPair::from_bytes(&secret)?;
         let config = SshTunnelConfig {
             host: self.host.clone(),
+            fallback_hosts: self.fallback_hosts.clone(),
             port: self.port,
             user: self.user.clone(),
             key_pair,
@@ -17,6 +17,10 @@ use mz_secrets::SecretsReader;
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 
+use crate::connections::inline::{
+    ConnectionAccess, ConnectionResolver, InlinedConnection, IntoInlineConnection,
+    ReferencedConnection,
+};
 use crate::connections::{ConnectionContext, StringOrSecret};
 
 include!(concat!(
@@ -94,6 +98,51 @@ impl RustType<ProtoAwsCredentials> for AwsCredentials {
     }
 }
 
+/// A reference to an AWS connection from another connection.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash)]
+pub struct AwsConnectionReference<C: ConnectionAccess = InlinedConnection> {
+    /// The ID of the AWS connection.
+    pub connection_id: GlobalId,
+    /// The AWS connection.
+    pub connection: C::Aws,
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<AwsConnectionReference, R>
+    for AwsConnectionReference<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> AwsConnectionReference {
+        let AwsConnectionReference {
+            connection,
+            connection_id,
+        } = self;
+
+        AwsConnectionReference {
+            connection: r.resolve_connection(connection).unwrap_aws(),
+            connection_id,
+        }
+    }
+}
+
+impl RustType<ProtoAwsConnectionReference> for AwsConnectionReference<InlinedConnection> {
+    fn into_proto(&self) -> ProtoAwsConnectionReference {
+        ProtoAwsConnectionReference {
+            connection_id: Some(self.connection_id.into_proto()),
+            connection: Some(self.connection.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoAwsConnectionReference) -> Result<Self, TryFromProtoError> {
+        Ok(AwsConnectionReference {
+            connection_id: proto
+                .connection_id
+                .into_rust_if_some("ProtoAwsConnectionReference::connection_id")?,
+            connection: proto
+                .connection
+                .into_rust_if_some("ProtoAwsConnectionReference::connection")?,
+        })
+    }
+}
+
 /// A role for Materialize to assume when performing AWS API calls.
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash)]
 pub struct AwsAssumeRole {
@@ -10,19 +10,24 @@
 //! Types and traits related to reporting changing collections out of `dataflow`.
 
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::fmt::Debug;
+use std::time::Duration;
 
+use aws_types::SdkConfig;
+use mz_iceberg_util::IcebergError;
 use mz_ore::cast::CastFrom;
 use mz_persist_client::ShardId;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
-use mz_repr::{GlobalId, RelationDesc};
+use mz_repr::{GlobalId, RelationDesc, ScalarType};
+use mz_secrets::SecretsReader;
 use proptest::prelude::{any, Arbitrary, BoxedStrategy, Strategy};
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 use timely::progress::frontier::Antichain;
 use timely::PartialOrder;
 
-use crate::connections::ConnectionContext;
+use crate::connections::{ConnectionContext, StringOrSecret};
 use crate::controller::{CollectionMetadata, StorageError};
 
 use crate::connections::inline::{
@@ -63,29 +68,27 @@ impl<S: Debug + StorageSinkDescFillState + PartialEq, T: Debug + PartialEq + Par
             return Ok(());
         }
         let StorageSinkDesc {
-            from,
+            // `ALTER SINK ... SET FROM` may change the sinked relation, and
+            // with it the collection it is read from.
+            from: _,
             from_desc,
             connection,
             envelope,
             // The as of of the descriptions may differ.
             as_of: _,
             status_id,
-            from_storage_metadata,
+            from_storage_metadata: _,
         } = self;
 
         let compatibility_checks = [
-            (from == &other.from, "from"),
-            (from_desc == &other.from_desc, "from_desc"),
+            // The new relation may only append columns to the old one.
+            (other.from_desc.extends(from_desc), "from_desc"),
             (
                 connection.alter_compatible(id, &other.connection).is_ok(),
                 "connection",
             ),
             (envelope == &other.envelope, "envelope"),
             (status_id == &other.status_id, "status_id"),
-            (
-                from_storage_metadata == &other.from_storage_metadata,
-                "from_storage_metadata",
-            ),
         ];
 
         for (compatible, field) in compatibility_checks {
@@ -284,6 +287,11 @@ impl RustType<ProtoSinkAsOf> for SinkAsOf<mz_repr::Timestamp> {
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum StorageSinkConnection<C: ConnectionAccess = InlinedConnection> {
     Kafka(KafkaSinkConnection<C>),
+    Postgres(PostgresSinkConnection<C>),
+    S3(S3SinkConnection<C>),
+    Http(HttpSinkConnection),
+    Redis(RedisSinkConnection),
+    Iceberg(IcebergSinkConnection<C>),
 }
 
 impl<C: ConnectionAccess> StorageSinkConnection<C> {
@@ -303,6 +311,29 @@ impl<C: ConnectionAccess> StorageSinkConnection<C> {
             (StorageSinkConnection::Kafka(s), StorageSinkConnection::Kafka(o)) => {
                 s.alter_compatible(id, o)?
             }
+            (StorageSinkConnection::Postgres(s), StorageSinkConnection::Postgres(o)) => {
+                s.alter_compatible(id, o)?
+            }
+            (StorageSinkConnection::S3(s), StorageSinkConnection::S3(o)) => {
+                s.alter_compatible(id, o)?
+            }
+            (StorageSinkConnection::Http(s), StorageSinkConnection::Http(o)) => {
+                s.alter_compatible(id, o)?
+            }
+            (StorageSinkConnection::Redis(s), StorageSinkConnection::Redis(o)) => {
+                s.alter_compatible(id, o)?
+            }
+            (StorageSinkConnection::Iceberg(s), StorageSinkConnection::Iceberg(o)) => {
+                s.alter_compatible(id, o)?
+            }
+            _ => {
+                tracing::warn!(
+                    "StorageSinkConnection incompatible:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+                return Err(StorageError::InvalidAlter { id });
+            }
         }
 
         Ok(())
@@ -315,6 +346,11 @@ impl<R: ConnectionResolver> IntoInlineConnection<StorageSinkConnection, R>
     fn into_inline_connection(self, r: R) -> StorageSinkConnection {
         match self {
             Self::Kafka(conn) => StorageSinkConnection::Kafka(conn.into_inline_connection(r)),
+            Self::Postgres(conn) => StorageSinkConnection::Postgres(conn.into_inline_connection(r)),
+            Self::S3(conn) => StorageSinkConnection::S3(conn.into_inline_connection(r)),
+            Self::Http(conn) => StorageSinkConnection::Http(conn),
+            Self::Redis(conn) => StorageSinkConnection::Redis(conn),
+            Self::Iceberg(conn) => StorageSinkConnection::Iceberg(conn.into_inline_connection(r)),
         }
     }
 }
@@ -326,6 +362,11 @@ impl RustType<ProtoStorageSinkConnection> for StorageSinkConnection {
         ProtoStorageSinkConnection {
             kind: Some(match self {
                 Self::Kafka(conn) => KafkaV2(conn.into_proto()),
+                Self::Postgres(conn) => Postgres(conn.into_proto()),
+                Self::S3(conn) => S3(conn.into_proto()),
+                Self::Http(conn) => Http(conn.into_proto()),
+                Self::Redis(conn) => Redis(conn.into_proto()),
+                Self::Iceberg(conn) => Iceberg(conn.into_proto()),
             }),
         }
     }
@@ -338,6 +379,11 @@ impl RustType<ProtoStorageSinkConnection> for StorageSinkConnection {
 
         Ok(match kind {
             KafkaV2(proto) => Self::Kafka(proto.into_rust()?),
+            Postgres(proto) => Self::Postgres(proto.into_rust()?),
+            S3(proto) => Self::S3(proto.into_rust()?),
+            Http(proto) => Self::Http(proto.into_rust()?),
+            Redis(proto) => Self::Redis(proto.into_rust()?),
+            Iceberg(proto) => Self::Iceberg(proto.into_rust()?),
         })
     }
 }
@@ -348,6 +394,10 @@ impl<C: ConnectionAccess> StorageSinkConnection<C> {
         use StorageSinkConnection::*;
         match self {
             Kafka(KafkaSinkConnection { connection_id, .. }) => Some(*connection_id),
+            Postgres(PostgresSinkConnection { connection_id, .. }) => Some(*connection_id),
+            S3(S3SinkConnection { connection_id, .. }) => Some(*connection_id),
+            Iceberg(IcebergSinkConnection { connection_id, .. }) => Some(*connection_id),
+            Http(_) | Redis(_) => None,
         }
     }
 
@@ -356,6 +406,11 @@ impl<C: ConnectionAccess> StorageSinkConnection<C> {
         use StorageSinkConnection::*;
         match self {
             Kafka(_) => "kafka",
+            Postgres(_) => "postgres",
+            S3(_) => "s3",
+            Http(_) => "http",
+            Redis(_) => "redis",
+            Iceberg(_) => "iceberg",
         }
     }
 }
@@ -419,6 +474,28 @@ impl KafkaSinkCompressionType {
     }
 }
 
+/// How a Kafka sink emits the deletion of a row.
+#[derive(Arbitrary, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum KafkaSinkDeleteMode {
+    /// Emit a tombstone, i.e. a message with the row's key and no value.
+    Tombstone,
+    /// Emit the envelope's record of the deletion, e.g. a Debezium record
+    /// whose `after` field is null.
+    Record,
+    /// Emit nothing.
+    Drop,
+}
+
+impl KafkaSinkDeleteMode {
+    /// Returns the delete mode an envelope uses when none is specified.
+    pub fn default_for(envelope: SinkEnvelope) -> Self {
+        match envelope {
+            SinkEnvelope::Upsert => KafkaSinkDeleteMode::Tombstone,
+            SinkEnvelope::Debezium => KafkaSinkDeleteMode::Record,
+        }
+    }
+}
+
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct KafkaSinkConnection<C: ConnectionAccess = InlinedConnection> {
     pub connection_id: GlobalId,
@@ -435,13 +512,77 @@ pub struct KafkaSinkConnection<C: ConnectionAccess = InlinedConnection> {
     pub fuel: usize,
     pub retention: KafkaSinkConnectionRetention,
     pub compression_type: KafkaSinkCompressionType,
+    /// The columns to emit as message headers, if any.
+    pub headers: Option<KafkaSinkHeaders>,
+    /// The progress topic of the sink, which overrides the progress topic of
+    /// the connection.
+    pub progress_topic: Option<String>,
+    /// The replication factor of the progress topic, if the sink creates it.
+    ///
+    /// Use `-1` to indicate broker default.
+    pub progress_topic_replication_factor: i32,
+    /// The topic to which rows that cannot be encoded are written, if any.
+    ///
+    /// Without a dead letter topic, the sink halts when it cannot encode a
+    /// row.
+    pub dead_letter_topic: Option<String>,
+    /// How the sink emits deleted rows, if it overrides the default of its
+    /// envelope.
+    pub delete_mode: Option<KafkaSinkDeleteMode>,
+    /// The `source` block of the full Debezium envelope, if the sink emits
+    /// it. Only set for the Debezium envelope.
+    pub debezium_source: Option<KafkaSinkDebeziumSource>,
+    /// The prefix of the transactional ID of the sink's producer, if it
+    /// overrides [`DEFAULT_TRANSACTIONAL_ID_PREFIX`].
+    pub transactional_id_prefix: Option<String>,
+    /// The `transaction.timeout.ms` of the sink's producer, if it overrides
+    /// [`DEFAULT_TRANSACTION_TIMEOUT`].
+    pub transaction_timeout: Option<Duration>,
+    /// The column from which the topic of each message is taken, if any.
+    pub topic_routing: Option<KafkaSinkTopicRouting>,
+    /// The maximum number of messages the sink produces per second, if
+    /// limited.
+    pub max_messages_per_second: Option<u32>,
+    /// The maximum number of key and value bytes the sink produces per
+    /// second, if limited.
+    pub max_bytes_per_second: Option<u32>,
+}
+
+/// The prefix of the transactional ID of a Kafka sink's producer, unless the
+/// sink specifies `TRANSACTIONAL ID PREFIX`.
+pub const DEFAULT_TRANSACTIONAL_ID_PREFIX: &str = "mz-producer-";
+
+/// The transaction timeout of a Kafka sink's producer, unless the sink
+/// specifies `TRANSACTION TIMEOUT MS`.
+pub const DEFAULT_TRANSACTION_TIMEOUT: Duration = Duration::from_secs(10);
+
+impl<C: ConnectionAccess> KafkaSinkConnection<C> {
+    /// Returns the transactional ID of the producer of the sink with ID
+    /// `sink_id`.
+    pub fn transactional_id(&self, sink_id: GlobalId) -> String {
+        let prefix = self
+            .transactional_id_prefix
+            .as_deref()
+            .unwrap_or(DEFAULT_TRANSACTIONAL_ID_PREFIX);
+        format!("{prefix}{sink_id}-0")
+    }
+
+    /// Returns the transaction timeout of the sink's producer.
+    pub fn transaction_timeout(&self) -> Duration {
+        self.transaction_timeout
+            .unwrap_or(DEFAULT_TRANSACTION_TIMEOUT)
+    }
 }
 
 impl KafkaSinkConnection {
     /// Returns the name of the progress topic to use for the sink.
     pub fn progress_topic(&self, connection_context: &ConnectionContext) -> Cow<str> {
-        self.connection
-            .progress_topic(connection_context, self.connection_id)
+        match &self.progress_topic {
+            Some(progress_topic) => Cow::Borrowed(progress_topic),
+            None => self
+                .connection
+                .progress_topic(connection_context, self.connection_id),
+        }
     }
 }
 
@@ -472,6 +613,20 @@ impl<C: ConnectionAccess> KafkaSinkConnection<C> {
             fuel,
             retention,
             compression_type,
+            headers,
+            progress_topic,
+            progress_topic_replication_factor,
+            dead_letter_topic,
+            delete_mode,
+            // The source metadata follows the sinked relation, which
+            // `ALTER SINK ... SET FROM` may change.
+            debezium_source: _,
+            transactional_id_prefix,
+            transaction_timeout,
+            topic_routing,
+            // Rate limits only affect how quickly the sink writes.
+            max_messages_per_second: _,
+            max_bytes_per_second: _,
         } = self;
 
         let compatibility_checks = [
@@ -485,7 +640,8 @@ impl<C: ConnectionAccess> KafkaSinkConnection<C> {
                 key_desc_and_indices == &other.key_desc_and_indices,
                 "key_desc_and_indices",
             ),
-            (value_desc == &other.value_desc, "value_desc"),
+            // Values gain the columns appended to the sinked relation.
+            (other.value_desc.extends(value_desc), "value_desc"),
             (topic == &other.topic, "topic"),
             (partition_count == &other.partition_count, "partition_count"),
             (
@@ -498,6 +654,26 @@ impl<C: ConnectionAccess> KafkaSinkConnection<C> {
                 compression_type == &other.compression_type,
                 "compression_type",
             ),
+            (headers == &other.headers, "headers"),
+            (progress_topic == &other.progress_topic, "progress_topic"),
+            (
+                progress_topic_replication_factor == &other.progress_topic_replication_factor,
+                "progress_topic_replication_factor",
+            ),
+            (
+                dead_letter_topic == &other.dead_letter_topic,
+                "dead_letter_topic",
+            ),
+            (delete_mode == &other.delete_mode, "delete_mode"),
+            (
+                transactional_id_prefix == &other.transactional_id_prefix,
+                "transactional_id_prefix",
+            ),
+            (
+                transaction_timeout == &other.transaction_timeout,
+                "transaction_timeout",
+            ),
+            (topic_routing == &other.topic_routing, "topic_routing"),
         ];
         for (compatible, field) in compatibility_checks {
             if !compatible {
@@ -532,6 +708,17 @@ impl<R: ConnectionResolver> IntoInlineConnection<KafkaSinkConnection, R>
             fuel,
             retention,
             compression_type,
+            headers,
+            progress_topic,
+            progress_topic_replication_factor,
+            dead_letter_topic,
+            delete_mode,
+            debezium_source,
+            transactional_id_prefix,
+            transaction_timeout,
+            topic_routing,
+            max_messages_per_second,
+            max_bytes_per_second,
         } = self;
         KafkaSinkConnection {
             connection_id,
@@ -546,13 +733,24 @@ impl<R: ConnectionResolver> IntoInlineConnection<KafkaSinkConnection, R>
             fuel,
             retention,
             compression_type,
+            headers,
+            progress_topic,
+            progress_topic_replication_factor,
+            dead_letter_topic,
+            delete_mode,
+            debezium_source,
+            transactional_id_prefix,
+            transaction_timeout,
+            topic_routing,
+            max_messages_per_second,
+            max_bytes_per_second,
         }
     }
 }
 
 impl RustType<ProtoKafkaSinkConnectionV2> for KafkaSinkConnection {
     fn into_proto(&self) -> ProtoKafkaSinkConnectionV2 {
-        use crate::sinks::proto_kafka_sink_connection_v2::CompressionType;
+        use crate::sinks::proto_kafka_sink_connection_v2::{CompressionType, DeleteMode};
         ProtoKafkaSinkConnectionV2 {
             connection_id: Some(self.connection_id.into_proto()),
             connection: Some(self.connection.into_proto()),
@@ -572,11 +770,26 @@ impl RustType<ProtoKafkaSinkConnectionV2> for KafkaSinkConnection {
                 KafkaSinkCompressionType::Lz4 => CompressionType::Lz4(()),
                 KafkaSinkCompressionType::Zstd => CompressionType::Zstd(()),
             }),
+            headers: self.headers.into_proto(),
+            progress_topic: self.progress_topic.clone(),
+            progress_topic_replication_factor: self.progress_topic_replication_factor,
+            dead_letter_topic: self.dead_letter_topic.clone(),
+            delete_mode: self.delete_mode.map(|mode| match mode {
+                KafkaSinkDeleteMode::Tombstone => DeleteMode::Tombstone(()),
+                KafkaSinkDeleteMode::Record => DeleteMode::Record(()),
+                KafkaSinkDeleteMode::Drop => DeleteMode::Drop(()),
+            }),
+            debezium_source: self.debezium_source.into_proto(),
+            transactional_id_prefix: self.transactional_id_prefix.clone(),
+            transaction_timeout: self.transaction_timeout.into_proto(),
+            topic_routing: self.topic_routing.into_proto(),
+            max_messages_per_second: self.max_messages_per_second,
+            max_bytes_per_second: self.max_bytes_per_second,
         }
     }
 
     fn from_proto(proto: ProtoKafkaSinkConnectionV2) -> Result<Self, TryFromProtoError> {
-        use crate::sinks::proto_kafka_sink_connection_v2::CompressionType;
+        use crate::sinks::proto_kafka_sink_connection_v2::{CompressionType, DeleteMode};
         Ok(KafkaSinkConnection {
             connection_id: proto
                 .connection_id
@@ -611,94 +824,254 @@ impl RustType<ProtoKafkaSinkConnectionV2> for KafkaSinkConnection {
                     ))
                 }
             },
+            headers: proto.headers.into_rust()?,
+            progress_topic: proto.progress_topic,
+            progress_topic_replication_factor: proto.progress_topic_replication_factor,
+            dead_letter_topic: proto.dead_letter_topic,
+            delete_mode: proto.delete_mode.map(|mode| match mode {
+                DeleteMode::Tombstone(()) => KafkaSinkDeleteMode::Tombstone,
+                DeleteMode::Record(()) => KafkaSinkDeleteMode::Record,
+                DeleteMode::Drop(()) => KafkaSinkDeleteMode::Drop,
+            }),
+            debezium_source: proto.debezium_source.into_rust()?,
+            transactional_id_prefix: proto.transactional_id_prefix,
+            transaction_timeout: proto.transaction_timeout.into_rust()?,
+            topic_routing: proto.topic_routing.into_rust()?,
+            max_messages_per_second: proto.max_messages_per_second,
+            max_bytes_per_second: proto.max_bytes_per_second,
         })
     }
 }
 
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PostgresSinkConnection<C: ConnectionAccess = InlinedConnection> {
+    pub connection_id: GlobalId,
+    pub connection: C::Pg,
+    /// The schema of the upstream table.
+    pub schema: String,
+    /// The name of the upstream table.
+    pub table: String,
+    /// A natural key of the sinked relation (view or source).
+    pub relation_key_indices: Option<Vec<usize>>,
+    /// The user-specified key for the sink, which must match a unique
+    /// constraint on the upstream table unless the sink writes with
+    /// [`PostgresSinkWriteMode::Copy`].
+    pub key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    pub value_desc: RelationDesc,
+    /// How the sink applies updates to the upstream table.
+    pub write_mode: PostgresSinkWriteMode,
+}
+
+/// How a Postgres sink applies the updates of each timestamp to the upstream
+/// table.
 #[derive(Arbitrary, Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
-pub struct KafkaSinkConnectionRetention {
-    pub duration: Option<i64>,
-    pub bytes: Option<i64>,
+pub enum PostgresSinkWriteMode {
+    /// Upsert each row with `INSERT ... ON CONFLICT`, and delete each deleted
+    /// row with its own `DELETE`.
+    #[default]
+    Upsert,
+    /// Stream the rows into a temporary staging table with `COPY ... FROM
+    /// STDIN`, then replace the rows of the table that have the same keys
+    /// with those of the staging table.
+    ///
+    /// This only relies on `COPY` and temporary tables, so it works with
+    /// servers that speak the PostgreSQL wire protocol but do not support
+    /// `ON CONFLICT`, and does not need a unique constraint on the key.
+    Copy,
 }
 
-impl RustType<ProtoKafkaSinkConnectionRetention> for KafkaSinkConnectionRetention {
-    fn into_proto(&self) -> ProtoKafkaSinkConnectionRetention {
-        ProtoKafkaSinkConnectionRetention {
-            duration: self.duration,
-            bytes: self.bytes,
+impl<C: ConnectionAccess> PostgresSinkConnection<C> {
+    /// Determines if `self` is compatible with another `StorageSinkConnection`,
+    /// in such a way that it is possible to turn `self` into `other` through a
+    /// valid series of transformations (e.g. no transformation or `ALTER
+    /// CONNECTION`).
+    pub fn alter_compatible(
+        &self,
+        id: GlobalId,
+        other: &PostgresSinkConnection<C>,
+    ) -> Result<(), StorageError> {
+        if self == other {
+            return Ok(());
         }
-    }
+        let PostgresSinkConnection {
+            connection_id,
+            // The details of the Postgres connection itself may change
+            connection: _,
+            schema,
+            table,
+            relation_key_indices,
+            key_desc_and_indices,
+            value_desc,
+            write_mode,
+        } = self;
 
-    fn from_proto(proto: ProtoKafkaSinkConnectionRetention) -> Result<Self, TryFromProtoError> {
-        Ok(KafkaSinkConnectionRetention {
-            duration: proto.duration,
-            bytes: proto.bytes,
-        })
+        let compatibility_checks = [
+            (connection_id == &other.connection_id, "connection_id"),
+            (schema == &other.schema, "schema"),
+            (table == &other.table, "table"),
+            (
+                relation_key_indices == &other.relation_key_indices,
+                "relation_key_indices",
+            ),
+            (
+                key_desc_and_indices == &other.key_desc_and_indices,
+                "key_desc_and_indices",
+            ),
+            (value_desc == &other.value_desc, "value_desc"),
+            (write_mode == &other.write_mode, "write_mode"),
+        ];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "PostgresSinkConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(StorageError::InvalidAlter { id });
+            }
+        }
+
+        Ok(())
     }
 }
 
-#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
-pub enum KafkaSinkFormat<C: ConnectionAccess = InlinedConnection> {
-    Avro {
-        key_schema: Option<String>,
-        value_schema: String,
-        csr_connection: C::Csr,
-    },
-    Json,
+impl<R: ConnectionResolver> IntoInlineConnection<PostgresSinkConnection, R>
+    for PostgresSinkConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> PostgresSinkConnection {
+        let PostgresSinkConnection {
+            connection_id,
+            connection,
+            schema,
+            table,
+            relation_key_indices,
+            key_desc_and_indices,
+            value_desc,
+            write_mode,
+        } = self;
+        PostgresSinkConnection {
+            connection_id,
+            connection: r.resolve_connection(connection).unwrap_pg(),
+            schema,
+            table,
+            relation_key_indices,
+            key_desc_and_indices,
+            value_desc,
+            write_mode,
+        }
+    }
 }
 
-impl<C: ConnectionAccess> KafkaSinkFormat<C> {
-    pub fn get_format_name(&self) -> &str {
-        match self {
-            Self::Avro { .. } => "avro",
-            Self::Json => "json",
+impl RustType<ProtoPostgresSinkConnection> for PostgresSinkConnection {
+    fn into_proto(&self) -> ProtoPostgresSinkConnection {
+        use crate::sinks::proto_postgres_sink_connection::WriteMode;
+        ProtoPostgresSinkConnection {
+            connection_id: Some(self.connection_id.into_proto()),
+            connection: Some(self.connection.into_proto()),
+            schema: self.schema.clone(),
+            table: self.table.clone(),
+            key_desc_and_indices: self.key_desc_and_indices.into_proto(),
+            relation_key_indices: self.relation_key_indices.into_proto(),
+            value_desc: Some(self.value_desc.into_proto()),
+            write_mode: Some(match self.write_mode {
+                PostgresSinkWriteMode::Upsert => WriteMode::Upsert(()),
+                PostgresSinkWriteMode::Copy => WriteMode::Copy(()),
+            }),
         }
     }
 
-    fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), StorageError> {
+    fn from_proto(proto: ProtoPostgresSinkConnection) -> Result<Self, TryFromProtoError> {
+        use crate::sinks::proto_postgres_sink_connection::WriteMode;
+        Ok(PostgresSinkConnection {
+            connection_id: proto
+                .connection_id
+                .into_rust_if_some("ProtoPostgresSinkConnection::connection_id")?,
+            connection: proto
+                .connection
+                .into_rust_if_some("ProtoPostgresSinkConnection::connection")?,
+            schema: proto.schema,
+            table: proto.table,
+            key_desc_and_indices: proto.key_desc_and_indices.into_rust()?,
+            relation_key_indices: proto.relation_key_indices.into_rust()?,
+            value_desc: proto
+                .value_desc
+                .into_rust_if_some("ProtoPostgresSinkConnection::value_desc")?,
+            // Sinks created before the write mode was introduced upsert.
+            write_mode: match proto.write_mode {
+                None | Some(WriteMode::Upsert(())) => PostgresSinkWriteMode::Upsert,
+                Some(WriteMode::Copy(())) => PostgresSinkWriteMode::Copy,
+            },
+        })
+    }
+}
+
+/// The columns S3 and Iceberg sinks append to each row of the sinked
+/// collection to describe the change: the timestamp at which it happened and
+/// whether the row was inserted (`1`) or deleted (`-1`).
+pub const S3_SINK_METADATA_COLUMNS: [&str; 2] = ["mz_timestamp", "mz_diff"];
+
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct S3SinkConnection<C: ConnectionAccess = InlinedConnection> {
+    pub connection_id: GlobalId,
+    pub connection: C::Aws,
+    pub bucket: String,
+    /// The prefix of the keys of all files the sink writes.
+    pub prefix: String,
+    pub format: S3SinkFormat,
+    /// The size, in bytes, at which the sink starts a new file.
+    pub max_file_size: u64,
+    /// The longest time the sink buffers updates before writing a file.
+    pub rotation_interval: Duration,
+    pub value_desc: RelationDesc,
+}
+
+impl<C: ConnectionAccess> S3SinkConnection<C> {
+    /// Determines if `self` is compatible with another `StorageSinkConnection`,
+    /// in such a way that it is possible to turn `self` into `other` through a
+    /// valid series of transformations (e.g. no transformation or `ALTER
+    /// CONNECTION`).
+    pub fn alter_compatible(
+        &self,
+        id: GlobalId,
+        other: &S3SinkConnection<C>,
+    ) -> Result<(), StorageError> {
         if self == other {
             return Ok(());
         }
+        let S3SinkConnection {
+            connection_id,
+            // The details of the AWS connection itself may change
+            connection: _,
+            bucket,
+            prefix,
+            format,
+            max_file_size,
+            rotation_interval,
+            value_desc,
+        } = self;
 
-        match (self, other) {
+        let compatibility_checks = [
+            (connection_id == &other.connection_id, "connection_id"),
+            (bucket == &other.bucket, "bucket"),
+            (prefix == &other.prefix, "prefix"),
+            (format == &other.format, "format"),
+            (max_file_size == &other.max_file_size, "max_file_size"),
             (
-                Self::Avro {
-                    key_schema,
-                    value_schema,
-                    // Connections may change
-                    csr_connection: _,
-                },
-                Self::Avro {
-                    key_schema: other_key_schema,
-                    value_schema: other_value_schema,
-                    csr_connection: _,
-                },
-            ) => {
-                let compatibility_checks = [
-                    (key_schema == other_key_schema, "key_schema"),
-                    (value_schema == other_value_schema, "value_schema"),
-                ];
-                for (compatible, field) in compatibility_checks {
-                    if !compatible {
-                        tracing::warn!(
-                            "KafkaSinkAvroFormatState::Avro incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
-                            self,
-                            other
-                        );
+                rotation_interval == &other.rotation_interval,
+                "rotation_interval",
+            ),
+            (value_desc == &other.value_desc, "value_desc"),
+        ];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "S3SinkConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
 
-                        return Err(StorageError::InvalidAlter { id });
-                    }
-                }
-            }
-            (s, o) => {
-                if s != o {
-                    tracing::warn!(
-                        "KafkaSinkFormat incompatible\nself:\n{:#?}\n\nother:{:#?}",
-                        s,
-                        o
-                    );
-                    return Err(StorageError::InvalidAlter { id });
-                }
+                return Err(StorageError::InvalidAlter { id });
             }
         }
 
@@ -706,40 +1079,860 @@ impl<C: ConnectionAccess> KafkaSinkFormat<C> {
     }
 }
 
-impl<R: ConnectionResolver> IntoInlineConnection<KafkaSinkFormat, R>
-    for KafkaSinkFormat<ReferencedConnection>
+impl<R: ConnectionResolver> IntoInlineConnection<S3SinkConnection, R>
+    for S3SinkConnection<ReferencedConnection>
 {
-    fn into_inline_connection(self, r: R) -> KafkaSinkFormat {
-        match self {
-            Self::Avro {
-                key_schema,
-                value_schema,
-                csr_connection,
-            } => KafkaSinkFormat::Avro {
-                key_schema,
-                value_schema,
-                csr_connection: r.resolve_connection(csr_connection).unwrap_csr(),
-            },
-            Self::Json => KafkaSinkFormat::Json,
+    fn into_inline_connection(self, r: R) -> S3SinkConnection {
+        let S3SinkConnection {
+            connection_id,
+            connection,
+            bucket,
+            prefix,
+            format,
+            max_file_size,
+            rotation_interval,
+            value_desc,
+        } = self;
+        S3SinkConnection {
+            connection_id,
+            connection: r.resolve_connection(connection).unwrap_aws(),
+            bucket,
+            prefix,
+            format,
+            max_file_size,
+            rotation_interval,
+            value_desc,
         }
     }
 }
 
-impl RustType<ProtoKafkaSinkFormat> for KafkaSinkFormat {
-    fn into_proto(&self) -> ProtoKafkaSinkFormat {
-        use proto_kafka_sink_format::Kind;
-        ProtoKafkaSinkFormat {
-            kind: Some(match self {
-                Self::Avro {
-                    key_schema,
-                    value_schema,
-                    csr_connection,
-                } => Kind::Avro(proto_kafka_sink_format::ProtoKafkaSinkAvroFormat {
-                    key_schema: key_schema.clone(),
-                    value_schema: value_schema.clone(),
-                    csr_connection: Some(csr_connection.into_proto()),
+impl RustType<ProtoS3SinkConnection> for S3SinkConnection {
+    fn into_proto(&self) -> ProtoS3SinkConnection {
+        ProtoS3SinkConnection {
+            connection_id: Some(self.connection_id.into_proto()),
+            connection: Some(self.connection.into_proto()),
+            bucket: self.bucket.clone(),
+            prefix: self.prefix.clone(),
+            format: Some(self.format.into_proto()),
+            max_file_size: self.max_file_size,
+            rotation_interval: Some(self.rotation_interval.into_proto()),
+            value_desc: Some(self.value_desc.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoS3SinkConnection) -> Result<Self, TryFromProtoError> {
+        Ok(S3SinkConnection {
+            connection_id: proto
+                .connection_id
+                .into_rust_if_some("ProtoS3SinkConnection::connection_id")?,
+            connection: proto
+                .connection
+                .into_rust_if_some("ProtoS3SinkConnection::connection")?,
+            bucket: proto.bucket,
+            prefix: proto.prefix,
+            format: proto
+                .format
+                .into_rust_if_some("ProtoS3SinkConnection::format")?,
+            max_file_size: proto.max_file_size,
+            rotation_interval: proto
+                .rotation_interval
+                .into_rust_if_some("ProtoS3SinkConnection::rotation_interval")?,
+            value_desc: proto
+                .value_desc
+                .into_rust_if_some("ProtoS3SinkConnection::value_desc")?,
+        })
+    }
+}
+
+/// The format of the files written by an S3 sink.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum S3SinkFormat {
+    Parquet,
+    Csv {
+        delimiter: u8,
+        /// The names to write in a header line at the start of each file, if
+        /// any.
+        header: Option<Vec<String>>,
+    },
+}
+
+impl S3SinkFormat {
+    /// The extension of the files written in this format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            S3SinkFormat::Parquet => "parquet",
+            S3SinkFormat::Csv { .. } => "csv",
+        }
+    }
+}
+
+impl RustType<ProtoS3SinkFormat> for S3SinkFormat {
+    fn into_proto(&self) -> ProtoS3SinkFormat {
+        use proto_s3_sink_format::Kind;
+        ProtoS3SinkFormat {
+            kind: Some(match self {
+                Self::Parquet => Kind::Parquet(()),
+                Self::Csv { delimiter, header } => {
+                    Kind::Csv(proto_s3_sink_format::ProtoS3SinkCsvFormat {
+                        delimiter: u32::from(*delimiter),
+                        header: header.clone().unwrap_or_default(),
+                    })
+                }
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoS3SinkFormat) -> Result<Self, TryFromProtoError> {
+        use proto_s3_sink_format::Kind;
+        let kind = proto
+            .kind
+            .ok_or_else(|| TryFromProtoError::missing_field("ProtoS3SinkFormat::kind"))?;
+
+        Ok(match kind {
+            Kind::Parquet(()) => Self::Parquet,
+            Kind::Csv(proto) => Self::Csv {
+                delimiter: u8::try_from(proto.delimiter)?,
+                header: (!proto.header.is_empty()).then_some(proto.header),
+            },
+        })
+    }
+}
+
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct HttpSinkConnection {
+    /// The endpoint to which the sink POSTs batches of changes.
+    pub url: StringOrSecret,
+    /// Headers to send with each request, e.g. for authentication.
+    pub headers: BTreeMap<String, StringOrSecret>,
+    /// The maximum number of changes in each request.
+    pub batch_size: u64,
+    /// The number of times to retry a failed request before halting the sink.
+    pub max_retries: u64,
+    /// The time to wait before the first retry of a failed request, which
+    /// doubles with each subsequent retry.
+    pub retry_backoff: Duration,
+    pub key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    pub relation_key_indices: Option<Vec<usize>>,
+    pub value_desc: RelationDesc,
+}
+
+impl HttpSinkConnection {
+    /// Determines if `self` is compatible with another `StorageSinkConnection`,
+    /// in such a way that it is possible to turn `self` into `other` through a
+    /// valid series of transformations (e.g. no transformation or `ALTER
+    /// CONNECTION`).
+    pub fn alter_compatible(
+        &self,
+        id: GlobalId,
+        other: &HttpSinkConnection,
+    ) -> Result<(), StorageError> {
+        if self == other {
+            return Ok(());
+        }
+        let HttpSinkConnection {
+            url,
+            headers,
+            batch_size,
+            max_retries,
+            retry_backoff,
+            key_desc_and_indices,
+            relation_key_indices,
+            value_desc,
+        } = self;
+
+        let compatibility_checks = [
+            (url == &other.url, "url"),
+            (headers == &other.headers, "headers"),
+            (batch_size == &other.batch_size, "batch_size"),
+            (max_retries == &other.max_retries, "max_retries"),
+            (retry_backoff == &other.retry_backoff, "retry_backoff"),
+            (
+                key_desc_and_indices == &other.key_desc_and_indices,
+                "key_desc_and_indices",
+            ),
+            (
+                relation_key_indices == &other.relation_key_indices,
+                "relation_key_indices",
+            ),
+            (value_desc == &other.value_desc, "value_desc"),
+        ];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "HttpSinkConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(StorageError::InvalidAlter { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl RustType<ProtoHttpSinkConnection> for HttpSinkConnection {
+    fn into_proto(&self) -> ProtoHttpSinkConnection {
+        ProtoHttpSinkConnection {
+            url: Some(self.url.into_proto()),
+            headers: self
+                .headers
+                .iter()
+                .map(|(k, v)| (k.clone(), v.into_proto()))
+                .collect(),
+            batch_size: self.batch_size,
+            max_retries: self.max_retries,
+            retry_backoff: Some(self.retry_backoff.into_proto()),
+            key_desc_and_indices: self.key_desc_and_indices.into_proto(),
+            relation_key_indices: self.relation_key_indices.into_proto(),
+            value_desc: Some(self.value_desc.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoHttpSinkConnection) -> Result<Self, TryFromProtoError> {
+        Ok(HttpSinkConnection {
+            url: proto
+                .url
+                .into_rust_if_some("ProtoHttpSinkConnection::url")?,
+            headers: proto
+                .headers
+                .into_iter()
+                .map(|(k, v)| StringOrSecret::from_proto(v).map(|v| (k, v)))
+                .collect::<Result<_, _>>()?,
+            batch_size: proto.batch_size,
+            max_retries: proto.max_retries,
+            retry_backoff: proto
+                .retry_backoff
+                .into_rust_if_some("ProtoHttpSinkConnection::retry_backoff")?,
+            key_desc_and_indices: proto.key_desc_and_indices.into_rust()?,
+            relation_key_indices: proto.relation_key_indices.into_rust()?,
+            value_desc: proto
+                .value_desc
+                .into_rust_if_some("ProtoHttpSinkConnection::value_desc")?,
+        })
+    }
+}
+
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RedisSinkConnection {
+    /// The `redis://` or `rediss://` URL of the server.
+    pub url: StringOrSecret,
+    /// The password with which to authenticate, if not part of the URL.
+    pub password: Option<StringOrSecret>,
+    /// The prefix of the names of all keys that the sink maintains.
+    pub key_prefix: String,
+    /// How the sink stores the value of each row.
+    pub data_structure: RedisDataStructure,
+    pub key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    pub relation_key_indices: Option<Vec<usize>>,
+    pub value_desc: RelationDesc,
+}
+
+impl RedisSinkConnection {
+    /// Opens a connection to the Redis server.
+    pub async fn connect(
+        &self,
+        secrets_reader: &dyn SecretsReader,
+    ) -> Result<redis::aio::Connection, anyhow::Error> {
+        let url = self.url.get_string(secrets_reader).await?;
+        let mut info = redis::IntoConnectionInfo::into_connection_info(url.as_str())?;
+        if let Some(password) = &self.password {
+            info.redis.password = Some(password.get_string(secrets_reader).await?);
+        }
+        let client = redis::Client::open(info)?;
+        Ok(client.get_async_connection().await?)
+    }
+
+    /// Determines if `self` is compatible with another `StorageSinkConnection`,
+    /// in such a way that it is possible to turn `self` into `other` through a
+    /// valid series of transformations (e.g. no transformation or `ALTER
+    /// CONNECTION`).
+    pub fn alter_compatible(
+        &self,
+        id: GlobalId,
+        other: &RedisSinkConnection,
+    ) -> Result<(), StorageError> {
+        if self == other {
+            return Ok(());
+        }
+        let RedisSinkConnection {
+            url,
+            password,
+            key_prefix,
+            data_structure,
+            key_desc_and_indices,
+            relation_key_indices,
+            value_desc,
+        } = self;
+
+        let compatibility_checks = [
+            (url == &other.url, "url"),
+            (password == &other.password, "password"),
+            (key_prefix == &other.key_prefix, "key_prefix"),
+            (data_structure == &other.data_structure, "data_structure"),
+            (
+                key_desc_and_indices == &other.key_desc_and_indices,
+                "key_desc_and_indices",
+            ),
+            (
+                relation_key_indices == &other.relation_key_indices,
+                "relation_key_indices",
+            ),
+            (value_desc == &other.value_desc, "value_desc"),
+        ];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "RedisSinkConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(StorageError::InvalidAlter { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl RustType<ProtoRedisSinkConnection> for RedisSinkConnection {
+    fn into_proto(&self) -> ProtoRedisSinkConnection {
+        ProtoRedisSinkConnection {
+            url: Some(self.url.into_proto()),
+            password: self.password.into_proto(),
+            key_prefix: self.key_prefix.clone(),
+            data_structure: Some(self.data_structure.into_proto()),
+            key_desc_and_indices: self.key_desc_and_indices.into_proto(),
+            relation_key_indices: self.relation_key_indices.into_proto(),
+            value_desc: Some(self.value_desc.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoRedisSinkConnection) -> Result<Self, TryFromProtoError> {
+        Ok(RedisSinkConnection {
+            url: proto
+                .url
+                .into_rust_if_some("ProtoRedisSinkConnection::url")?,
+            password: proto.password.into_rust()?,
+            key_prefix: proto.key_prefix,
+            data_structure: proto
+                .data_structure
+                .into_rust_if_some("ProtoRedisSinkConnection::data_structure")?,
+            key_desc_and_indices: proto.key_desc_and_indices.into_rust()?,
+            relation_key_indices: proto.relation_key_indices.into_rust()?,
+            value_desc: proto
+                .value_desc
+                .into_rust_if_some("ProtoRedisSinkConnection::value_desc")?,
+        })
+    }
+}
+
+/// How a Redis sink stores the value of each row.
+#[derive(Arbitrary, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum RedisDataStructure {
+    /// A string holding the row encoded as a JSON object.
+    String,
+    /// A hash with a field for each non-null column of the row.
+    Hash,
+}
+
+impl RedisDataStructure {
+    /// Returns the name of the Redis type that holds the value of each row,
+    /// as reported by the `TYPE` command.
+    pub fn redis_type(&self) -> &'static str {
+        match self {
+            RedisDataStructure::String => "string",
+            RedisDataStructure::Hash => "hash",
+        }
+    }
+}
+
+impl RustType<ProtoRedisDataStructure> for RedisDataStructure {
+    fn into_proto(&self) -> ProtoRedisDataStructure {
+        use proto_redis_data_structure::Kind;
+        ProtoRedisDataStructure {
+            kind: Some(match self {
+                RedisDataStructure::String => Kind::String(()),
+                RedisDataStructure::Hash => Kind::Hash(()),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoRedisDataStructure) -> Result<Self, TryFromProtoError> {
+        use proto_redis_data_structure::Kind;
+        let kind = proto
+            .kind
+            .ok_or_else(|| TryFromProtoError::missing_field("ProtoRedisDataStructure::kind"))?;
+
+        Ok(match kind {
+            Kind::String(()) => Self::String,
+            Kind::Hash(()) => Self::Hash,
+        })
+    }
+}
+
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct IcebergSinkConnection<C: ConnectionAccess = InlinedConnection> {
+    pub connection_id: GlobalId,
+    /// The AWS connection used for Glue catalogs and for the files of the
+    /// table.
+    pub connection: C::Aws,
+    pub catalog: IcebergCatalog,
+    pub namespace: String,
+    pub table: String,
+    /// The longest time the sink buffers updates before committing them to
+    /// the table.
+    pub commit_interval: Duration,
+    pub value_desc: RelationDesc,
+}
+
+impl<C: ConnectionAccess> IcebergSinkConnection<C> {
+    /// Determines if `self` is compatible with another `StorageSinkConnection`,
+    /// in such a way that it is possible to turn `self` into `other` through a
+    /// valid series of transformations (e.g. no transformation or `ALTER
+    /// CONNECTION`).
+    pub fn alter_compatible(
+        &self,
+        id: GlobalId,
+        other: &IcebergSinkConnection<C>,
+    ) -> Result<(), StorageError> {
+        if self == other {
+            return Ok(());
+        }
+        let IcebergSinkConnection {
+            connection_id,
+            // The details of the AWS connection itself may change
+            connection: _,
+            catalog,
+            namespace,
+            table,
+            commit_interval,
+            value_desc,
+        } = self;
+
+        let compatibility_checks = [
+            (connection_id == &other.connection_id, "connection_id"),
+            (catalog == &other.catalog, "catalog"),
+            (namespace == &other.namespace, "namespace"),
+            (table == &other.table, "table"),
+            (commit_interval == &other.commit_interval, "commit_interval"),
+            (value_desc == &other.value_desc, "value_desc"),
+        ];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "IcebergSinkConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(StorageError::InvalidAlter { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<IcebergSinkConnection, R>
+    for IcebergSinkConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> IcebergSinkConnection {
+        let IcebergSinkConnection {
+            connection_id,
+            connection,
+            catalog,
+            namespace,
+            table,
+            commit_interval,
+            value_desc,
+        } = self;
+        IcebergSinkConnection {
+            connection_id,
+            connection: r.resolve_connection(connection).unwrap_aws(),
+            catalog,
+            namespace,
+            table,
+            commit_interval,
+            value_desc,
+        }
+    }
+}
+
+impl RustType<ProtoIcebergSinkConnection> for IcebergSinkConnection {
+    fn into_proto(&self) -> ProtoIcebergSinkConnection {
+        ProtoIcebergSinkConnection {
+            connection_id: Some(self.connection_id.into_proto()),
+            connection: Some(self.connection.into_proto()),
+            catalog: Some(self.catalog.into_proto()),
+            namespace: self.namespace.clone(),
+            table: self.table.clone(),
+            commit_interval: Some(self.commit_interval.into_proto()),
+            value_desc: Some(self.value_desc.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoIcebergSinkConnection) -> Result<Self, TryFromProtoError> {
+        Ok(IcebergSinkConnection {
+            connection_id: proto
+                .connection_id
+                .into_rust_if_some("ProtoIcebergSinkConnection::connection_id")?,
+            connection: proto
+                .connection
+                .into_rust_if_some("ProtoIcebergSinkConnection::connection")?,
+            catalog: proto
+                .catalog
+                .into_rust_if_some("ProtoIcebergSinkConnection::catalog")?,
+            namespace: proto.namespace,
+            table: proto.table,
+            commit_interval: proto
+                .commit_interval
+                .into_rust_if_some("ProtoIcebergSinkConnection::commit_interval")?,
+            value_desc: proto
+                .value_desc
+                .into_rust_if_some("ProtoIcebergSinkConnection::value_desc")?,
+        })
+    }
+}
+
+/// The catalog that tracks the table of an Iceberg sink.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum IcebergCatalog {
+    /// The AWS Glue catalog of the account of the AWS connection.
+    Glue,
+    /// A catalog that implements the Iceberg REST catalog API.
+    Rest {
+        url: String,
+        /// The bearer token with which to authenticate, if any.
+        token: Option<StringOrSecret>,
+    },
+}
+
+impl IcebergCatalog {
+    /// Connects to the catalog. `sdk_config` is used to access Glue and the
+    /// files of tables.
+    pub async fn connect(
+        &self,
+        sdk_config: &SdkConfig,
+        secrets_reader: &dyn SecretsReader,
+    ) -> Result<mz_iceberg_util::Catalog, IcebergError> {
+        let io = mz_iceberg_util::FileIo::new(sdk_config);
+        match self {
+            IcebergCatalog::Glue => Ok(mz_iceberg_util::Catalog::glue(sdk_config, io)),
+            IcebergCatalog::Rest { url, token } => {
+                let token = match token {
+                    Some(token) => Some(token.get_string(secrets_reader).await?),
+                    None => None,
+                };
+                mz_iceberg_util::Catalog::rest(url, token, io).await
+            }
+        }
+    }
+}
+
+impl RustType<ProtoIcebergCatalog> for IcebergCatalog {
+    fn into_proto(&self) -> ProtoIcebergCatalog {
+        use proto_iceberg_catalog::{Kind, ProtoIcebergRestCatalog};
+        ProtoIcebergCatalog {
+            kind: Some(match self {
+                IcebergCatalog::Glue => Kind::Glue(()),
+                IcebergCatalog::Rest { url, token } => Kind::Rest(ProtoIcebergRestCatalog {
+                    url: url.clone(),
+                    token: token.into_proto(),
+                }),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoIcebergCatalog) -> Result<Self, TryFromProtoError> {
+        use proto_iceberg_catalog::Kind;
+        let kind = proto
+            .kind
+            .ok_or_else(|| TryFromProtoError::missing_field("ProtoIcebergCatalog::kind"))?;
+
+        Ok(match kind {
+            Kind::Glue(()) => Self::Glue,
+            Kind::Rest(proto) => Self::Rest {
+                url: proto.url,
+                token: proto.token.into_rust()?,
+            },
+        })
+    }
+}
+
+/// Returns the type of the Iceberg column that an Iceberg sink writes a column
+/// of type `ty` to.
+///
+/// Types without an equivalent Iceberg type, including unsigned types that
+/// don't fit into a wider signed type, are written in their PostgreSQL text
+/// encoding.
+pub fn iceberg_column_type(ty: &ScalarType) -> &'static str {
+    match ty {
+        ScalarType::Bool => "boolean",
+        ScalarType::Int16 | ScalarType::Int32 | ScalarType::UInt16 => "int",
+        ScalarType::Int64 | ScalarType::UInt32 => "long",
+        ScalarType::Float32 => "float",
+        ScalarType::Float64 => "double",
+        ScalarType::Bytes => "binary",
+        _ => "string",
+    }
+}
+
+/// The Iceberg types of [`S3_SINK_METADATA_COLUMNS`] in Iceberg sinks.
+pub const ICEBERG_SINK_METADATA_COLUMN_TYPES: [&str; 2] = ["long", "long"];
+
+/// Columns of the sinked relation that a Kafka sink emits as message headers.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct KafkaSinkHeaders {
+    /// The names of the headers, which are the names of the columns.
+    pub names: Vec<String>,
+    /// The indices of the columns in the sinked relation. The columns are
+    /// either `text` or `bytea`.
+    pub indices: Vec<usize>,
+    /// Whether the columns are omitted from the value of each message, in
+    /// which case the `value_desc` of the sink does not contain them.
+    pub exclude_from_value: bool,
+}
+
+impl RustType<ProtoKafkaSinkHeaders> for KafkaSinkHeaders {
+    fn into_proto(&self) -> ProtoKafkaSinkHeaders {
+        ProtoKafkaSinkHeaders {
+            names: self.names.clone(),
+            indices: self.indices.into_proto(),
+            exclude_from_value: self.exclude_from_value,
+        }
+    }
+
+    fn from_proto(proto: ProtoKafkaSinkHeaders) -> Result<Self, TryFromProtoError> {
+        Ok(KafkaSinkHeaders {
+            names: proto.names,
+            indices: proto.indices.into_rust()?,
+            exclude_from_value: proto.exclude_from_value,
+        })
+    }
+}
+
+/// How a Kafka sink routes each message to a topic taken from a column of the
+/// sinked relation.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct KafkaSinkTopicRouting {
+    /// The index of the `text` column in the sinked relation. Messages whose
+    /// column is null are sent to the topic of the sink.
+    pub index: usize,
+    /// The topics messages may be sent to, besides the topic of the sink, if
+    /// they are restricted. The sink creates these topics if they do not
+    /// exist; other topics must exist before messages are sent to them.
+    pub allowlist: Option<Vec<String>>,
+}
+
+impl RustType<ProtoKafkaSinkTopicRouting> for KafkaSinkTopicRouting {
+    fn into_proto(&self) -> ProtoKafkaSinkTopicRouting {
+        ProtoKafkaSinkTopicRouting {
+            index: self.index.into_proto(),
+            allowlist: self
+                .allowlist
+                .clone()
+                .map(|topics| proto_kafka_sink_topic_routing::ProtoTopicAllowlist { topics }),
+        }
+    }
+
+    fn from_proto(proto: ProtoKafkaSinkTopicRouting) -> Result<Self, TryFromProtoError> {
+        Ok(KafkaSinkTopicRouting {
+            index: proto.index.into_rust()?,
+            allowlist: proto.allowlist.map(|allowlist| allowlist.topics),
+        })
+    }
+}
+
+/// The fixed fields of the `source` block that a Kafka sink emits with the full
+/// Debezium envelope.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct KafkaSinkDebeziumSource {
+    /// The name of the sink, which Debezium calls the logical name of the
+    /// connector.
+    pub name: String,
+    /// The database of the sinked relation.
+    pub db: String,
+    /// The schema of the sinked relation.
+    pub schema: String,
+    /// The name of the sinked relation.
+    pub table: String,
+}
+
+impl RustType<ProtoKafkaSinkDebeziumSource> for KafkaSinkDebeziumSource {
+    fn into_proto(&self) -> ProtoKafkaSinkDebeziumSource {
+        ProtoKafkaSinkDebeziumSource {
+            name: self.name.clone(),
+            db: self.db.clone(),
+            schema: self.schema.clone(),
+            table: self.table.clone(),
+        }
+    }
+
+    fn from_proto(proto: ProtoKafkaSinkDebeziumSource) -> Result<Self, TryFromProtoError> {
+        Ok(KafkaSinkDebeziumSource {
+            name: proto.name,
+            db: proto.db,
+            schema: proto.schema,
+            table: proto.table,
+        })
+    }
+}
+
+#[derive(Arbitrary, Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct KafkaSinkConnectionRetention {
+    pub duration: Option<i64>,
+    pub bytes: Option<i64>,
+}
+
+impl RustType<ProtoKafkaSinkConnectionRetention> for KafkaSinkConnectionRetention {
+    fn into_proto(&self) -> ProtoKafkaSinkConnectionRetention {
+        ProtoKafkaSinkConnectionRetention {
+            duration: self.duration,
+            bytes: self.bytes,
+        }
+    }
+
+    fn from_proto(proto: ProtoKafkaSinkConnectionRetention) -> Result<Self, TryFromProtoError> {
+        Ok(KafkaSinkConnectionRetention {
+            duration: proto.duration,
+            bytes: proto.bytes,
+        })
+    }
+}
+
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum KafkaSinkFormat<C: ConnectionAccess = InlinedConnection> {
+    Avro {
+        key_schema: Option<String>,
+        value_schema: String,
+        csr_connection: C::Csr,
+    },
+    Json,
+    /// Keys and values are each written as a single CSV record.
+    Csv {
+        delimiter: u8,
+        /// The names to write in a header line before the record of each
+        /// value, if any.
+        header: Option<Vec<String>>,
+    },
+}
+
+impl<C: ConnectionAccess> KafkaSinkFormat<C> {
+    pub fn get_format_name(&self) -> &str {
+        match self {
+            Self::Avro { .. } => "avro",
+            Self::Json => "json",
+            Self::Csv { .. } => "csv",
+        }
+    }
+
+    fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), StorageError> {
+        if self == other {
+            return Ok(());
+        }
+
+        match (self, other) {
+            (
+                Self::Avro {
+                    key_schema,
+                    // The value schema evolves with the columns of the sinked
+                    // relation. Its compatibility with the registered schema
+                    // is checked during purification, and again by the schema
+                    // registry when the sink publishes it.
+                    value_schema: _,
+                    // Connections may change
+                    csr_connection: _,
+                },
+                Self::Avro {
+                    key_schema: other_key_schema,
+                    value_schema: _,
+                    csr_connection: _,
+                },
+            ) => {
+                let compatibility_checks = [(key_schema == other_key_schema, "key_schema")];
+                for (compatible, field) in compatibility_checks {
+                    if !compatible {
+                        tracing::warn!(
+                            "KafkaSinkAvroFormatState::Avro incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                            self,
+                            other
+                        );
+
+                        return Err(StorageError::InvalidAlter { id });
+                    }
+                }
+            }
+            (
+                Self::Csv {
+                    delimiter,
+                    // The header names the columns of the sinked relation.
+                    header: _,
+                },
+                Self::Csv {
+                    delimiter: other_delimiter,
+                    header: _,
+                },
+            ) if delimiter == other_delimiter => {}
+            (s, o) => {
+                if s != o {
+                    tracing::warn!(
+                        "KafkaSinkFormat incompatible\nself:\n{:#?}\n\nother:{:#?}",
+                        s,
+                        o
+                    );
+                    return Err(StorageError::InvalidAlter { id });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<KafkaSinkFormat, R>
+    for KafkaSinkFormat<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> KafkaSinkFormat {
+        match self {
+            Self::Avro {
+                key_schema,
+                value_schema,
+                csr_connection,
+            } => KafkaSinkFormat::Avro {
+                key_schema,
+                value_schema,
+                csr_connection: r.resolve_connection(csr_connection).unwrap_csr(),
+            },
+            Self::Json => KafkaSinkFormat::Json,
+            Self::Csv { delimiter, header } => KafkaSinkFormat::Csv { delimiter, header },
+        }
+    }
+}
+
+impl RustType<ProtoKafkaSinkFormat> for KafkaSinkFormat {
+    fn into_proto(&self) -> ProtoKafkaSinkFormat {
+        use proto_kafka_sink_format::Kind;
+        ProtoKafkaSinkFormat {
+            kind: Some(match self {
+                Self::Avro {
+                    key_schema,
+                    value_schema,
+                    csr_connection,
+                } => Kind::Avro(proto_kafka_sink_format::ProtoKafkaSinkAvroFormat {
+                    key_schema: key_schema.clone(),
+                    value_schema: value_schema.clone(),
+                    csr_connection: Some(csr_connection.into_proto()),
                 }),
                 Self::Json => Kind::Json(()),
+                Self::Csv { delimiter, header } => {
+                    Kind::Csv(proto_kafka_sink_format::ProtoKafkaSinkCsvFormat {
+                        delimiter: u32::from(*delimiter),
+                        header: header.clone().unwrap_or_default(),
+                    })
+                }
             }),
         }
     }
@@ -759,6 +1952,10 @@ impl RustType<ProtoKafkaSinkFormat> for KafkaSinkFormat {
                     .into_rust_if_some("ProtoKafkaSinkAvroFormat::csr_connection")?,
             },
             Kind::Json(()) => Self::Json,
+            Kind::Csv(proto) => Self::Csv {
+                delimiter: u8::try_from(proto.delimiter)?,
+                header: (!proto.header.is_empty()).then_some(proto.header),
+            },
         })
     }
 }
@@ -9,12 +9,15 @@
 
 //! Types and traits related to the *decoding* of data for sources.
 
+use std::borrow::Cow;
+use std::str::Utf8Error;
+
 use anyhow::Context;
 use mz_interchange::{avro, protobuf};
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::adt::regex::any_regex;
 use mz_repr::{ColumnType, RelationDesc, ScalarType};
-use proptest::prelude::{Arbitrary, BoxedStrategy, Strategy};
+use proptest::prelude::{any, Arbitrary, BoxedStrategy, Strategy};
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 
@@ -168,8 +171,9 @@ pub enum DataEncodingInner<C: ConnectionAccess = InlinedConnection> {
     Regex(RegexEncoding),
     Bytes,
     Json,
-    Text,
+    Text(TextEncoding),
     RowCodec(RelationDesc),
+    Grok(GrokEncoding),
 }
 
 impl<R: ConnectionResolver> IntoInlineConnection<DataEncodingInner, R>
@@ -183,8 +187,9 @@ impl<R: ConnectionResolver> IntoInlineConnection<DataEncodingInner, R>
             Self::Regex(conn) => DataEncodingInner::Regex(conn),
             Self::Bytes => DataEncodingInner::Bytes,
             Self::Json => DataEncodingInner::Json,
-            Self::Text => DataEncodingInner::Text,
+            Self::Text(conn) => DataEncodingInner::Text(conn),
             Self::RowCodec(conn) => DataEncodingInner::RowCodec(conn),
+            Self::Grok(conn) => DataEncodingInner::Grok(conn),
         }
     }
 }
@@ -199,9 +204,10 @@ impl RustType<ProtoDataEncodingInner> for DataEncodingInner {
                 DataEncodingInner::Csv(e) => Kind::Csv(e.into_proto()),
                 DataEncodingInner::Regex(e) => Kind::Regex(e.into_proto()),
                 DataEncodingInner::Bytes => Kind::Bytes(()),
-                DataEncodingInner::Text => Kind::Text(()),
+                DataEncodingInner::Text(e) => Kind::Text(e.into_proto()),
                 DataEncodingInner::RowCodec(e) => Kind::RowCodec(e.into_proto()),
                 DataEncodingInner::Json => Kind::Json(()),
+                DataEncodingInner::Grok(e) => Kind::Grok(e.into_proto()),
             }),
         }
     }
@@ -217,9 +223,10 @@ impl RustType<ProtoDataEncodingInner> for DataEncodingInner {
             Kind::Csv(e) => DataEncodingInner::Csv(e.into_rust()?),
             Kind::Regex(e) => DataEncodingInner::Regex(e.into_rust()?),
             Kind::Bytes(()) => DataEncodingInner::Bytes,
-            Kind::Text(()) => DataEncodingInner::Text,
+            Kind::Text(e) => DataEncodingInner::Text(e.into_rust()?),
             Kind::RowCodec(e) => DataEncodingInner::RowCodec(e.into_rust()?),
             Kind::Json(()) => DataEncodingInner::Json,
+            Kind::Grok(e) => DataEncodingInner::Grok(e.into_rust()?),
         })
     }
 }
@@ -333,10 +340,11 @@ impl<C: ConnectionAccess> DataEncoding<C> {
                         desc.with_column(name, ScalarType::String.nullable(false))
                     }),
             },
-            DataEncodingInner::Text => {
+            DataEncodingInner::Text(_) => {
                 RelationDesc::empty().with_column("text", ScalarType::String.nullable(false))
             }
             DataEncodingInner::RowCodec(desc) => desc.clone(),
+            DataEncodingInner::Grok(GrokEncoding { desc, .. }) => desc.clone(),
         };
 
         if self.force_nullable_columns {
@@ -357,8 +365,9 @@ impl<C: ConnectionAccess> DataEncoding<C> {
             DataEncodingInner::Protobuf(_) => "Protobuf",
             DataEncodingInner::Regex { .. } => "Regex",
             DataEncodingInner::Csv(_) => "Csv",
-            DataEncodingInner::Text => "Text",
+            DataEncodingInner::Text(_) => "Text",
             DataEncodingInner::RowCodec(_) => "RowCodec",
+            DataEncodingInner::Grok(_) => "Grok",
         }
     }
 }
@@ -437,6 +446,7 @@ impl RustType<ProtoProtobufEncoding> for ProtobufEncoding {
 pub struct CsvEncoding {
     pub columns: ColumnSpec,
     pub delimiter: u8,
+    pub encoding: CharacterEncoding,
 }
 
 impl RustType<ProtoCsvEncoding> for CsvEncoding {
@@ -444,6 +454,7 @@ impl RustType<ProtoCsvEncoding> for CsvEncoding {
         ProtoCsvEncoding {
             columns: Some(self.columns.into_proto()),
             delimiter: self.delimiter.into_proto(),
+            encoding: Some(self.encoding.into_proto()),
         }
     }
 
@@ -453,6 +464,102 @@ impl RustType<ProtoCsvEncoding> for CsvEncoding {
                 .columns
                 .into_rust_if_some("ProtoCsvEncoding::columns")?,
             delimiter: proto.delimiter.into_rust()?,
+            // Encodings created before the ENCODING option existed are UTF-8.
+            encoding: proto.encoding.into_rust()?.unwrap_or_default(),
+        })
+    }
+}
+
+/// Arguments necessary to define how to decode from text format
+#[derive(Arbitrary, Clone, Debug, Default, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TextEncoding {
+    pub encoding: CharacterEncoding,
+}
+
+impl RustType<ProtoTextEncoding> for TextEncoding {
+    fn into_proto(&self) -> ProtoTextEncoding {
+        ProtoTextEncoding {
+            encoding: Some(self.encoding.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoTextEncoding) -> Result<Self, TryFromProtoError> {
+        Ok(TextEncoding {
+            // Encodings created before the ENCODING option existed are UTF-8.
+            encoding: proto.encoding.into_rust()?.unwrap_or_default(),
+        })
+    }
+}
+
+/// The character encoding of the bytes consumed by text-based formats.
+#[derive(Arbitrary, Clone, Copy, Debug, Default, Serialize, Deserialize, Eq, PartialEq)]
+pub enum CharacterEncoding {
+    #[default]
+    Utf8,
+    /// ISO 8859-1, whose code points map one-to-one onto the first 256
+    /// Unicode code points.
+    Latin1,
+}
+
+impl CharacterEncoding {
+    /// The canonical names of all supported encodings.
+    pub const SUPPORTED: &'static [&'static str] = &["utf8", "latin1"];
+
+    /// Looks up an encoding by its canonical name or one of its aliases,
+    /// ignoring case.
+    pub fn from_name(name: &str) -> Option<CharacterEncoding> {
+        match name.to_lowercase().as_str() {
+            "utf8" | "utf-8" | "unicode" => Some(CharacterEncoding::Utf8),
+            "latin1" | "latin-1" | "iso-8859-1" | "iso8859-1" | "iso88591" => {
+                Some(CharacterEncoding::Latin1)
+            }
+            _ => None,
+        }
+    }
+
+    /// The canonical name of the encoding.
+    pub fn name(&self) -> &'static str {
+        match self {
+            CharacterEncoding::Utf8 => "utf8",
+            CharacterEncoding::Latin1 => "latin1",
+        }
+    }
+
+    /// Decodes `bytes` in this encoding into a string, transcoding them to
+    /// UTF-8 if necessary.
+    pub fn decode<'a>(&self, bytes: &'a [u8]) -> Result<Cow<'a, str>, Utf8Error> {
+        match self {
+            CharacterEncoding::Utf8 => std::str::from_utf8(bytes).map(Cow::Borrowed),
+            // ASCII means the same thing in both encodings, so skip the copy.
+            CharacterEncoding::Latin1 if bytes.is_ascii() => Ok(Cow::Borrowed(
+                std::str::from_utf8(bytes).expect("ASCII is UTF-8"),
+            )),
+            CharacterEncoding::Latin1 => {
+                Ok(Cow::Owned(bytes.iter().map(|b| char::from(*b)).collect()))
+            }
+        }
+    }
+}
+
+impl RustType<ProtoCharacterEncoding> for CharacterEncoding {
+    fn into_proto(&self) -> ProtoCharacterEncoding {
+        use proto_character_encoding::Kind;
+        ProtoCharacterEncoding {
+            kind: Some(match self {
+                CharacterEncoding::Utf8 => Kind::Utf8(()),
+                CharacterEncoding::Latin1 => Kind::Latin1(()),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoCharacterEncoding) -> Result<Self, TryFromProtoError> {
+        use proto_character_encoding::Kind;
+        let kind = proto
+            .kind
+            .ok_or_else(|| TryFromProtoError::missing_field("ProtoCharacterEncoding::kind"))?;
+        Ok(match kind {
+            Kind::Utf8(()) => CharacterEncoding::Utf8,
+            Kind::Latin1(()) => CharacterEncoding::Latin1,
         })
     }
 }
@@ -539,3 +646,41 @@ impl RustType<ProtoRegexEncoding> for RegexEncoding {
         })
     }
 }
+
+/// Encoding in grok format.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GrokEncoding {
+    /// The regular expression that the grok pattern expands to. The `i`th
+    /// column of `desc` is captured by the group named
+    /// `mz_interchange::grok::field_group_name(i)`.
+    pub regex: mz_repr::adt::regex::Regex,
+    /// The columns derived from the fields of the grok pattern.
+    pub desc: RelationDesc,
+}
+
+impl Arbitrary for GrokEncoding {
+    type Strategy = BoxedStrategy<Self>;
+    type Parameters = ();
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any_regex(), any::<RelationDesc>())
+            .prop_map(|(regex, desc)| GrokEncoding { regex, desc })
+            .boxed()
+    }
+}
+
+impl RustType<ProtoGrokEncoding> for GrokEncoding {
+    fn into_proto(&self) -> ProtoGrokEncoding {
+        ProtoGrokEncoding {
+            regex: Some(self.regex.into_proto()),
+            desc: Some(self.desc.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoGrokEncoding) -> Result<Self, TryFromProtoError> {
+        Ok(GrokEncoding {
+            regex: proto.regex.into_rust_if_some("ProtoGrokEncoding::regex")?,
+            desc: proto.desc.into_rust_if_some("ProtoGrokEncoding::desc")?,
+        })
+    }
+}
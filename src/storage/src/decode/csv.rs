@@ -9,7 +9,7 @@
 
 use mz_repr::{Datum, Row};
 use mz_storage_types::errors::DecodeErrorKind;
-use mz_storage_types::sources::encoding::CsvEncoding;
+use mz_storage_types::sources::encoding::{CharacterEncoding, CsvEncoding};
 
 #[derive(Debug)]
 pub struct CsvDecoderState {
@@ -21,6 +21,7 @@ pub struct CsvDecoderState {
     ends: Vec<usize>,
     ends_cursor: usize,
     csv_reader: csv_core::Reader,
+    encoding: CharacterEncoding,
     row_buf: Row,
     events_error: usize,
     events_success: usize,
@@ -32,7 +33,11 @@ impl CsvDecoderState {
     }
 
     pub fn new(format: CsvEncoding) -> Self {
-        let CsvEncoding { columns, delimiter } = format;
+        let CsvEncoding {
+            columns,
+            delimiter,
+            encoding,
+        } = format;
         let n_cols = columns.arity();
 
         let header_names = columns.into_header_names();
@@ -45,6 +50,7 @@ impl CsvDecoderState {
             ends: vec![0],
             ends_cursor: 1,
             csv_reader: csv_core::ReaderBuilder::new().delimiter(delimiter).build(),
+            encoding,
             row_buf: Row::default(),
             events_error: 0,
             events_success: 0,
@@ -94,13 +100,21 @@ impl CsvDecoderState {
                                 ends_valid
                             )))
                         } else {
-                            match std::str::from_utf8(&self.output[0..self.output_cursor]) {
-                                Ok(output) => {
+                            // Decode each field separately, as transcoding
+                            // invalidates the field boundaries in `ends`.
+                            let output = &self.output[0..self.output_cursor];
+                            let fields = (0..self.n_cols)
+                                .map(|i| {
+                                    self.encoding
+                                        .decode(&output[self.ends[i]..self.ends[i + 1]])
+                                })
+                                .collect::<Result<Vec<_>, _>>();
+                            match fields {
+                                Ok(fields) => {
                                     self.events_success += 1;
                                     let mut row_packer = self.row_buf.packer();
-                                    row_packer.extend((0..self.n_cols).map(|i| {
-                                        Datum::String(&output[self.ends[i]..self.ends[i + 1]])
-                                    }));
+                                    row_packer
+                                        .extend(fields.iter().map(|field| Datum::String(field)));
                                     self.output_cursor = 0;
                                     self.ends_cursor = 1;
                                     Ok(Some(self.row_buf.clone()))
@@ -0,0 +1,76 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use mz_interchange::grok::field_group_name;
+use mz_repr::{strconv, Datum, RelationDesc, Row, ScalarType};
+use mz_storage_types::errors::DecodeErrorKind;
+use regex::Regex;
+
+#[derive(Debug)]
+pub struct GrokDecoderState {
+    regex: Regex,
+    /// For each output column, the index of the capture group that holds it
+    /// and the type to which it is parsed.
+    fields: Vec<(usize, ScalarType)>,
+    row_buf: Row,
+}
+
+impl GrokDecoderState {
+    pub fn new(regex: Regex, desc: &RelationDesc) -> Self {
+        let fields = desc
+            .iter_types()
+            .enumerate()
+            .map(|(i, ty)| {
+                let name = field_group_name(i);
+                let group = regex
+                    .capture_names()
+                    .position(|n| n == Some(name.as_str()))
+                    .expect("grok regex has a capture group for every column");
+                (group, ty.scalar_type.clone())
+            })
+            .collect();
+        Self {
+            regex,
+            fields,
+            row_buf: Row::default(),
+        }
+    }
+
+    pub fn decode(&mut self, bytes: &[u8]) -> Result<Option<Row>, DecodeErrorKind> {
+        let s = std::str::from_utf8(bytes)
+            .map_err(|_| DecodeErrorKind::Text("Failed to decode UTF-8".to_string()))?;
+        let captures = match self.regex.captures(s) {
+            Some(captures) => captures,
+            None => return Ok(None),
+        };
+        let mut packer = self.row_buf.packer();
+        for (group, ty) in &self.fields {
+            let datum = match captures.get(*group) {
+                None => Datum::Null,
+                Some(m) => match ty {
+                    ScalarType::Int64 => {
+                        Datum::Int64(strconv::parse_int64(m.as_str()).map_err(|e| {
+                            DecodeErrorKind::Text(format!("Failed to decode grok field: {}", e))
+                        })?)
+                    }
+                    ScalarType::Float64 => Datum::Float64(
+                        strconv::parse_float64(m.as_str())
+                            .map_err(|e| {
+                                DecodeErrorKind::Text(format!("Failed to decode grok field: {}", e))
+                            })?
+                            .into(),
+                    ),
+                    _ => Datum::String(m.as_str()),
+                },
+            };
+            packer.push(datum);
+        }
+        Ok(Some(self.row_buf.clone()))
+    }
+}
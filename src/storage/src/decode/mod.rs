@@ -27,7 +27,8 @@ use mz_repr::{Datum, Diff, Row, Timestamp};
 use mz_storage_types::connections::{ConnectionContext, CsrConnection};
 use mz_storage_types::errors::{CsrConnectError, DecodeError, DecodeErrorKind};
 use mz_storage_types::sources::encoding::{
-    AvroEncoding, DataEncoding, DataEncodingInner, RegexEncoding,
+    AvroEncoding, CharacterEncoding, DataEncoding, DataEncodingInner, GrokEncoding, RegexEncoding,
+    TextEncoding,
 };
 use mz_timely_util::builder_async::{
     Event as AsyncEvent, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
@@ -41,6 +42,7 @@ use tracing::error;
 
 use crate::decode::avro::AvroDecoderState;
 use crate::decode::csv::CsvDecoderState;
+use crate::decode::grok::GrokDecoderState;
 use crate::decode::protobuf::ProtobufDecoderState;
 use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
 use crate::metrics::decode::DecodeMetricDefs;
@@ -48,6 +50,7 @@ use crate::source::types::{DecodeResult, SourceOutput};
 
 mod avro;
 mod csv;
+mod grok;
 mod protobuf;
 
 /// Decode delimited CDCv2 messages.
@@ -156,9 +159,10 @@ pub fn render_decode_cdcv2<G: Scope<Timestamp = Timestamp>>(
 #[derive(Debug)]
 pub(crate) enum PreDelimitedFormat {
     Bytes,
-    Text,
+    Text(CharacterEncoding),
     Json,
     Regex(Regex, Row),
+    Grok(GrokDecoderState),
     Protobuf(ProtobufDecoderState),
 }
 
@@ -180,10 +184,11 @@ impl PreDelimitedFormat {
                 })?;
                 Ok(Some(j.into_row()))
             }
-            PreDelimitedFormat::Text => {
-                let s = std::str::from_utf8(bytes)
+            PreDelimitedFormat::Text(encoding) => {
+                let s = encoding
+                    .decode(bytes)
                     .map_err(|_| DecodeErrorKind::Text("Failed to decode UTF-8".to_string()))?;
-                Ok(Some(Row::pack(Some(Datum::String(s)))))
+                Ok(Some(Row::pack(Some(Datum::String(&s)))))
             }
             PreDelimitedFormat::Regex(regex, row_buf) => {
                 let s = std::str::from_utf8(bytes)
@@ -200,6 +205,7 @@ impl PreDelimitedFormat {
                 );
                 Ok(Some(row_buf.clone()))
             }
+            PreDelimitedFormat::Grok(grok) => grok.decode(bytes),
             PreDelimitedFormat::Protobuf(pb) => pb.get_value(bytes).transpose(),
         }
     }
@@ -320,15 +326,19 @@ async fn get_decoder(
                 metrics,
             }
         }
-        DataEncodingInner::Text
+        DataEncodingInner::Text(_)
         | DataEncodingInner::Bytes
         | DataEncodingInner::Json
         | DataEncodingInner::Protobuf(_)
-        | DataEncodingInner::Regex(_) => {
+        | DataEncodingInner::Regex(_)
+        | DataEncodingInner::Grok(_) => {
             let after_delimiting = match encoding.inner {
                 DataEncodingInner::Regex(RegexEncoding { regex }) => {
                     PreDelimitedFormat::Regex(regex.regex, Default::default())
                 }
+                DataEncodingInner::Grok(GrokEncoding { regex, desc }) => {
+                    PreDelimitedFormat::Grok(GrokDecoderState::new(regex.regex, &desc))
+                }
                 DataEncodingInner::Protobuf(encoding) => {
                     PreDelimitedFormat::Protobuf(ProtobufDecoderState::new(encoding).expect(
                         "Failed to create protobuf decoder, even though we validated ccsr \
@@ -337,7 +347,9 @@ async fn get_decoder(
                 }
                 DataEncodingInner::Bytes => PreDelimitedFormat::Bytes,
                 DataEncodingInner::Json => PreDelimitedFormat::Json,
-                DataEncodingInner::Text => PreDelimitedFormat::Text,
+                DataEncodingInner::Text(TextEncoding { encoding }) => {
+                    PreDelimitedFormat::Text(encoding)
+                }
                 _ => unreachable!(),
             };
             let inner = if is_connection_delimited {
@@ -51,8 +51,12 @@ pub enum StatusNamespace {
     /// `Running`.
     Generator,
     TestScript,
+    Http,
+    Iceberg,
     Kafka,
     Postgres,
+    Redis,
+    S3,
     Ssh,
     Upsert,
     Decode,
@@ -71,8 +75,12 @@ impl fmt::Display for StatusNamespace {
         match self {
             Generator => write!(f, "generator"),
             TestScript => write!(f, "testscript"),
+            Http => write!(f, "http"),
+            Iceberg => write!(f, "iceberg"),
             Kafka => write!(f, "kafka"),
             Postgres => write!(f, "postgres"),
+            Redis => write!(f, "redis"),
+            S3 => write!(f, "s3"),
             Ssh => write!(f, "ssh"),
             Upsert => write!(f, "upsert"),
             Decode => write!(f, "decode"),
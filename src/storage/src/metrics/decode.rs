@@ -41,8 +41,9 @@ impl DecodeMetricDefs {
             | DataDecoderInner::PreDelimited(format) => match format {
                 PreDelimitedFormat::Bytes => "raw",
                 PreDelimitedFormat::Json => "json",
-                PreDelimitedFormat::Text => "text",
+                PreDelimitedFormat::Text(_) => "text",
                 PreDelimitedFormat::Regex(..) => "regex",
+                PreDelimitedFormat::Grok(..) => "grok",
                 PreDelimitedFormat::Protobuf(..) => "protobuf",
             },
         };
@@ -0,0 +1,140 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! CSV encoding for sinks.
+
+use bytes::BytesMut;
+use itertools::Itertools;
+use mz_interchange::encode::Encode;
+use mz_repr::{RelationDesc, Row, ScalarType};
+
+use crate::sink::parquet::encode_text;
+
+/// Encodes the keys and values of a Kafka sink as CSV records.
+///
+/// Each key and value is a single record whose fields are the text encodings
+/// of its columns. If the sink has a header, it is written as a record of its
+/// own at the start of every value, as each message may be consumed on its
+/// own.
+pub(super) struct CsvEncoder {
+    key_types: Vec<ScalarType>,
+    value_types: Vec<ScalarType>,
+    delimiter: u8,
+    header: Option<Vec<String>>,
+}
+
+impl CsvEncoder {
+    pub(super) fn new(
+        key_desc: Option<RelationDesc>,
+        value_desc: RelationDesc,
+        delimiter: u8,
+        header: Option<Vec<String>>,
+    ) -> Self {
+        let types = |desc: &RelationDesc| -> Vec<ScalarType> {
+            desc.iter_types().map(|ty| ty.scalar_type.clone()).collect()
+        };
+        CsvEncoder {
+            key_types: key_desc.as_ref().map(types).unwrap_or_default(),
+            value_types: types(&value_desc),
+            delimiter,
+            header,
+        }
+    }
+}
+
+impl Encode for CsvEncoder {
+    fn get_format_name(&self) -> &str {
+        "csv"
+    }
+
+    fn encode_key_unchecked(&self, row: Row) -> Vec<u8> {
+        let mut out = vec![];
+        write_csv_row(
+            &mut out,
+            self.delimiter,
+            &row,
+            &self.key_types,
+            &mut BytesMut::new(),
+        );
+        out
+    }
+
+    fn encode_value_unchecked(&self, row: Row) -> Vec<u8> {
+        let mut out = vec![];
+        if let Some(header) = &self.header {
+            write_csv_record(
+                &mut out,
+                self.delimiter,
+                header.iter().map(|name| Some(name.as_str())),
+            );
+        }
+        write_csv_row(
+            &mut out,
+            self.delimiter,
+            &row,
+            &self.value_types,
+            &mut BytesMut::new(),
+        );
+        out
+    }
+}
+
+/// Writes `row`, whose columns have types `types`, as a CSV record to `out`.
+///
+/// `buf` is scratch space for the text encoding of each datum.
+pub(super) fn write_csv_row(
+    out: &mut Vec<u8>,
+    delimiter: u8,
+    row: &Row,
+    types: &[ScalarType],
+    buf: &mut BytesMut,
+) {
+    let fields: Vec<_> = row
+        .iter()
+        .zip_eq(types)
+        .map(|(datum, ty)| encode_text(datum, ty, buf))
+        .collect();
+    write_csv_record(out, delimiter, fields.iter().map(|f| f.as_deref()));
+}
+
+/// Writes a CSV record to `out`.
+///
+/// `NULL`s are written as empty fields, and fields that could otherwise be
+/// confused with them or that contain special characters are quoted.
+pub(super) fn write_csv_record<'a>(
+    out: &mut Vec<u8>,
+    delimiter: u8,
+    fields: impl Iterator<Item = Option<&'a str>>,
+) {
+    for (i, field) in fields.enumerate() {
+        if i > 0 {
+            out.push(delimiter);
+        }
+        let Some(field) = field else {
+            continue;
+        };
+        let needs_quotes = field.is_empty()
+            || field
+                .bytes()
+                .any(|b| b == delimiter || matches!(b, b'"' | b'\n' | b'\r'));
+        if needs_quotes {
+            out.push(b'"');
+            for b in field.bytes() {
+                if b == b'"' {
+                    out.push(b'"');
+                }
+                out.push(b);
+            }
+            out.push(b'"');
+        } else {
+            out.extend_from_slice(field.as_bytes());
+        }
+    }
+    out.push(b'\n');
+}
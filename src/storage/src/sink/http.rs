@@ -0,0 +1,342 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A sink that POSTs batches of changes to a collection to an HTTP endpoint.
+//!
+//! All updates are exchanged to a single worker, which buffers them until the
+//! input frontier passes their timestamp. Closed timestamps are then sent in
+//! timestamp order as JSON arrays of at most the configured batch size, where
+//! each element has the `key` and `value` of a change, encoded like the JSON
+//! format of Kafka sinks, and its `mz_timestamp`.
+//!
+//! Failed requests are retried with exponential backoff, except for client
+//! errors that retrying cannot fix, and the sink halts once it runs out of
+//! retries. Batches sent before a restart may be sent again, i.e. writes are
+//! at-least-once.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::future;
+use std::rc::Rc;
+
+use anyhow::{anyhow, Context};
+use differential_dataflow::{Collection, Hashable};
+use mz_interchange::encode::Encode;
+use mz_interchange::json::JsonEncoder;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_ore::retry::{Retry, RetryResult};
+use mz_repr::{Diff, GlobalId, Row, Timestamp};
+use mz_storage_client::client::SinkStatisticsUpdate;
+use mz_storage_types::connections::ConnectionContext;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{
+    HttpSinkConnection, MetadataFilled, SinkAsOf, SinkEnvelope, StorageSinkDesc,
+};
+use mz_timely_util::builder_async::{
+    Event, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
+use reqwest::StatusCode;
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::{Scope, Stream};
+use timely::progress::{Antichain, Timestamp as _};
+use tracing::{info, warn};
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::render::sinks::SinkRender;
+use crate::statistics::{SinkStatisticsMetrics, StorageStatistics};
+use crate::storage_state::StorageState;
+
+impl<G> SinkRender<G> for HttpSinkConnection
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    fn uses_keys(&self) -> bool {
+        true
+    }
+
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        self.key_desc_and_indices
+            .as_ref()
+            .map(|(_desc, indices)| indices.as_slice())
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        self.relation_key_indices.as_deref()
+    }
+
+    fn render_continuous_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<MetadataFilled, Timestamp>,
+        sink_id: GlobalId,
+        sinked_collection: Collection<G, (Option<Row>, Option<Row>), Diff>,
+        _err_collection: Collection<G, DataflowError, Diff>,
+    ) -> (Stream<G, HealthStatusMessage>, Vec<PressOnDropButton>)
+    where
+        G: Scope<Timestamp = Timestamp>,
+    {
+        // Only the active worker will ever send requests, so all other
+        // workers report an empty frontier. See the Kafka sink for details.
+        let peers = sinked_collection.inner.scope().peers();
+        let worker_index = sinked_collection.inner.scope().index();
+        let active_write_worker = (usize::cast_from(sink_id.hashed()) % peers) == worker_index;
+
+        let shared_frontier = Rc::new(RefCell::new(if active_write_worker {
+            Antichain::from_elem(Timestamp::minimum())
+        } else {
+            Antichain::new()
+        }));
+
+        let encoder = JsonEncoder::new(
+            self.key_desc_and_indices
+                .as_ref()
+                .map(|(desc, _indices)| desc.clone()),
+            self.value_desc.clone(),
+            matches!(sink.envelope, SinkEnvelope::Debezium),
+        );
+
+        let (health, token) = http(
+            sinked_collection,
+            sink_id,
+            self.clone(),
+            encoder,
+            sink.as_of.clone(),
+            Rc::clone(&shared_frontier),
+            storage_state
+                .sink_statistics
+                .get(&sink_id)
+                .expect("statistics initialized")
+                .clone(),
+            storage_state.connection_context.clone(),
+        );
+
+        storage_state
+            .sink_write_frontiers
+            .insert(sink_id, shared_frontier);
+
+        (health, vec![token])
+    }
+}
+
+/// Encodes a batch of changes as a JSON array.
+///
+/// The key and value of each change are already JSON-encoded, so they are
+/// spliced into the array rather than parsed again.
+fn encode_batch<'a>(
+    changes: impl Iterator<Item = &'a (Option<Vec<u8>>, Option<Vec<u8>>)>,
+    ts: Timestamp,
+) -> Vec<u8> {
+    let mut body = vec![b'['];
+    for (i, (key, value)) in changes.enumerate() {
+        if i > 0 {
+            body.push(b',');
+        }
+        body.extend_from_slice(b"{\"key\":");
+        body.extend_from_slice(key.as_deref().unwrap_or(b"null"));
+        body.extend_from_slice(b",\"value\":");
+        body.extend_from_slice(value.as_deref().unwrap_or(b"null"));
+        body.extend_from_slice(format!(",\"mz_timestamp\":{}}}", ts).as_bytes());
+    }
+    body.push(b']');
+    body
+}
+
+/// Reports whether a request that failed with `status` may succeed if retried.
+fn is_retryable(status: StatusCode) -> bool {
+    status.is_server_error()
+        || status == StatusCode::REQUEST_TIMEOUT
+        || status == StatusCode::TOO_MANY_REQUESTS
+}
+
+fn http<G>(
+    collection: Collection<G, (Option<Row>, Option<Row>), Diff>,
+    id: GlobalId,
+    connection: HttpSinkConnection,
+    encoder: JsonEncoder,
+    as_of: SinkAsOf,
+    write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+    sink_statistics: StorageStatistics<SinkStatisticsUpdate, SinkStatisticsMetrics>,
+    connection_context: ConnectionContext,
+) -> (Stream<G, HealthStatusMessage>, PressOnDropButton)
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    let name = format!("http-{}", id);
+    let scope = collection.inner.scope();
+    let worker_id = scope.index();
+    let worker_count = scope.peers();
+    let mut builder = AsyncOperatorBuilder::new(name.clone(), scope);
+
+    // We want exactly one worker to send all of the requests.
+    let hashed_id = id.hashed();
+    let is_active_worker = usize::cast_from(hashed_id) % worker_count == worker_id;
+
+    let mut input = builder.new_input(&collection.inner, Exchange::new(move |_| hashed_id));
+    let (mut health_output, health_stream) = builder.new_output();
+
+    let button = builder.build(move |caps| async move {
+        let [health_cap]: [_; 1] = caps.try_into().unwrap();
+
+        if !is_active_worker {
+            return;
+        }
+
+        let result: Result<(), anyhow::Error> = async {
+            let secrets_reader = &*connection_context.secrets_reader;
+            let url = reqwest::Url::parse(&connection.url.get_string(secrets_reader).await?)
+                .context("parsing URL")?;
+            let mut headers = HeaderMap::new();
+            headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+            for (name, value) in &connection.headers {
+                let value = value.get_string(secrets_reader).await?;
+                headers.insert(
+                    HeaderName::from_bytes(name.as_bytes())?,
+                    HeaderValue::from_str(&value)
+                        .with_context(|| format!("invalid value for header {}", name))?,
+                );
+            }
+            let client = reqwest::Client::builder()
+                .default_headers(headers)
+                .build()?;
+            let batch_size = usize::cast_from(connection.batch_size);
+            let max_tries = usize::cast_from(connection.max_retries).saturating_add(1);
+
+            health_output
+                .give(
+                    &health_cap,
+                    HealthStatusMessage {
+                        index: 0,
+                        namespace: StatusNamespace::Http,
+                        update: HealthStatusUpdate::running(),
+                    },
+                )
+                .await;
+
+            let mut pending_rows: BTreeMap<Timestamp, Vec<(Option<Vec<u8>>, Option<Vec<u8>>)>> =
+                BTreeMap::new();
+
+            while let Some(event) = input.next_mut().await {
+                match event {
+                    Event::Data(_, rows) => {
+                        for ((key, value), time, diff) in rows.drain(..) {
+                            let should_emit = if as_of.strict {
+                                as_of.frontier.less_than(&time)
+                            } else {
+                                as_of.frontier.less_equal(&time)
+                            };
+                            if !should_emit || diff == 0 {
+                                continue;
+                            }
+                            let key = key.map(|key| encoder.encode_key_unchecked(key));
+                            let value = value.map(|value| encoder.encode_value_unchecked(value));
+                            pending_rows.entry(time).or_default().push((key, value));
+                        }
+                    }
+                    Event::Progress(frontier) => {
+                        while let Some(entry) = pending_rows.first_entry() {
+                            if frontier.less_equal(entry.key()) {
+                                break;
+                            }
+                            let (ts, changes) = entry.remove_entry();
+
+                            for batch in changes.chunks(batch_size) {
+                                let body = encode_batch(batch.iter(), ts);
+                                let count_for_stats = u64::cast_from(batch.len());
+                                let size_for_stats = u64::cast_from(body.len());
+                                sink_statistics.inc_messages_staged_by(count_for_stats);
+                                sink_statistics.inc_bytes_staged_by(size_for_stats);
+
+                                info!("{}: sending {:?} changes at {:?}", id, batch.len(), ts);
+                                Retry::default()
+                                    .initial_backoff(connection.retry_backoff)
+                                    .max_tries(max_tries)
+                                    .retry_async(|state| {
+                                        let request = client.post(url.clone()).body(body.clone());
+                                        async move {
+                                            let result = match request.send().await {
+                                                Ok(response) => {
+                                                    let status = response.status();
+                                                    if status.is_success() {
+                                                        return RetryResult::Ok(());
+                                                    } else if is_retryable(status) {
+                                                        RetryResult::RetryableErr(anyhow!(
+                                                            "endpoint responded with status {}",
+                                                            status
+                                                        ))
+                                                    } else {
+                                                        RetryResult::FatalErr(anyhow!(
+                                                            "endpoint responded with status {}",
+                                                            status
+                                                        ))
+                                                    }
+                                                }
+                                                Err(e) => RetryResult::RetryableErr(
+                                                    anyhow::Error::new(e.without_url())
+                                                        .context("sending request"),
+                                                ),
+                                            };
+                                            if let RetryResult::RetryableErr(e) = &result {
+                                                if let Some(backoff) = state.next_backoff {
+                                                    warn!(
+                                                        "{}: request failed, retrying in {:?}: {}",
+                                                        id,
+                                                        backoff,
+                                                        e.display_with_causes()
+                                                    );
+                                                }
+                                            }
+                                            result
+                                        }
+                                    })
+                                    .await?;
+
+                                sink_statistics.inc_messages_committed_by(count_for_stats);
+                                sink_statistics.inc_bytes_committed_by(size_for_stats);
+                            }
+                        }
+
+                        // Everything before the input frontier has been
+                        // sent, except for timestamps that are still pending.
+                        let mut new_frontier = frontier.to_owned();
+                        new_frontier.extend(pending_rows.keys().next().cloned());
+                        let mut write_frontier = write_frontier.borrow_mut();
+                        write_frontier.clear();
+                        write_frontier.extend(new_frontier);
+                    }
+                }
+            }
+            Ok(())
+        }
+        .await;
+
+        if let Err(error) = result {
+            health_output
+                .give(
+                    &health_cap,
+                    HealthStatusMessage {
+                        index: 0,
+                        namespace: StatusNamespace::Http,
+                        update: HealthStatusUpdate::halting(
+                            format!("{}", error.display_with_causes()),
+                            None,
+                        ),
+                    },
+                )
+                .await;
+
+            // Make sure to never return, preventing the sink from writing
+            // out anything it might regret in the future.
+            future::pending::<()>().await;
+        }
+    });
+
+    (health_stream, button.press_on_drop())
+}
@@ -0,0 +1,490 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A sink that appends the changes to a collection to an Apache Iceberg table.
+//!
+//! All updates are exchanged to a single worker, which buffers them until the
+//! input frontier passes their timestamp. Closed timestamps are collected into
+//! a batch, which is written as a Parquet data file and committed to the table
+//! in a new snapshot once the commit interval has elapsed. Each row of the
+//! table is a row of the collection followed by the `mz_timestamp` and
+//! `mz_diff` of the change.
+//!
+//! Every snapshot the sink commits records the ID of the sink and the upper of
+//! the batch it contains in its summary. A restarted sink finds the latest of
+//! these snapshots and skips the changes before its upper, so that changes are
+//! committed exactly once.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::future;
+use std::rc::Rc;
+use std::time::Instant;
+
+use anyhow::{anyhow, Context};
+use differential_dataflow::{Collection, Hashable};
+use itertools::Itertools;
+use mz_iceberg_util::{Catalog, DataFile, TableMetadata};
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_repr::{Datum, Diff, GlobalId, Row, ScalarType, Timestamp};
+use mz_storage_client::client::SinkStatisticsUpdate;
+use mz_storage_types::connections::ConnectionContext;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{
+    iceberg_column_type, IcebergSinkConnection, MetadataFilled, SinkAsOf, StorageSinkDesc,
+    S3_SINK_METADATA_COLUMNS,
+};
+use mz_timely_util::builder_async::{
+    Event, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::{Scope, Stream};
+use timely::progress::{Antichain, Timestamp as _};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::render::sinks::SinkRender;
+use crate::sink::parquet::{self, ParquetColumn};
+use crate::statistics::{SinkStatisticsMetrics, StorageStatistics};
+use crate::storage_state::StorageState;
+
+/// The snapshot summary property that holds the ID of the sink that committed
+/// the snapshot.
+const SINK_ID_PROPERTY: &str = "materialize.sink-id";
+
+/// The snapshot summary property that holds the upper of the changes that the
+/// sink had committed as of the snapshot.
+const SINK_UPPER_PROPERTY: &str = "materialize.sink-upper";
+
+impl<G> SinkRender<G> for IcebergSinkConnection
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    fn uses_keys(&self) -> bool {
+        false
+    }
+
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        None
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        None
+    }
+
+    fn render_continuous_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<MetadataFilled, Timestamp>,
+        sink_id: GlobalId,
+        sinked_collection: Collection<G, (Option<Row>, Option<Row>), Diff>,
+        _err_collection: Collection<G, DataflowError, Diff>,
+    ) -> (Stream<G, HealthStatusMessage>, Vec<PressOnDropButton>)
+    where
+        G: Scope<Timestamp = Timestamp>,
+    {
+        // Only the active worker will ever commit to the table, so all other
+        // workers report an empty frontier. See the Kafka sink for details.
+        let peers = sinked_collection.inner.scope().peers();
+        let worker_index = sinked_collection.inner.scope().index();
+        let active_write_worker = (usize::cast_from(sink_id.hashed()) % peers) == worker_index;
+
+        let shared_frontier = Rc::new(RefCell::new(if active_write_worker {
+            Antichain::from_elem(Timestamp::minimum())
+        } else {
+            Antichain::new()
+        }));
+
+        let (health, token) = iceberg(
+            sinked_collection,
+            sink_id,
+            self.clone(),
+            sink.as_of.clone(),
+            Rc::clone(&shared_frontier),
+            storage_state
+                .sink_statistics
+                .get(&sink_id)
+                .expect("statistics initialized")
+                .clone(),
+            storage_state.connection_context.clone(),
+        );
+
+        storage_state
+            .sink_write_frontiers
+            .insert(sink_id, shared_frontier);
+
+        (health, vec![token])
+    }
+}
+
+/// The changes that have been closed but not yet committed.
+struct Batch {
+    rows: Vec<Row>,
+    /// The first timestamp of the changes in the batch.
+    lower: Option<Timestamp>,
+    opened_at: Instant,
+}
+
+impl Batch {
+    fn new() -> Self {
+        Batch {
+            rows: vec![],
+            lower: None,
+            opened_at: Instant::now(),
+        }
+    }
+}
+
+/// Encodes the rows of a sink into Parquet data files whose columns have the
+/// Iceberg types the sink was validated against.
+struct DataFileEncoder {
+    names: Vec<String>,
+    types: Vec<ScalarType>,
+    /// The location under which the table stores its data files.
+    data_location: String,
+}
+
+impl DataFileEncoder {
+    fn new(connection: &IcebergSinkConnection, metadata: &TableMetadata) -> Self {
+        let names = connection
+            .value_desc
+            .iter_names()
+            .map(|name| name.as_str())
+            .chain(S3_SINK_METADATA_COLUMNS)
+            .map(|name| name.to_string())
+            .collect();
+        // The metadata columns are packed as `long`s by `pack_row`.
+        let types = connection
+            .value_desc
+            .iter_types()
+            .map(|ty| ty.scalar_type.clone())
+            .chain([ScalarType::Int64, ScalarType::Int64])
+            .collect();
+        DataFileEncoder {
+            names,
+            types,
+            data_location: format!("{}/data", metadata.location.trim_end_matches('/')),
+        }
+    }
+
+    /// Returns a new, unique path for a data file of the sink with ID `id`.
+    fn new_path(&self, id: GlobalId) -> String {
+        format!(
+            "{}/materialize-{}-{}.parquet",
+            self.data_location,
+            id,
+            Uuid::new_v4()
+        )
+    }
+
+    /// Packs the values of a change into a row of a data file.
+    ///
+    /// Unsigned types are widened to the next larger signed type, as
+    /// Iceberg has no unsigned types.
+    fn pack_row(
+        &self,
+        row_buf: &mut Row,
+        values: impl Iterator<Item = Datum<'_>>,
+        time: Timestamp,
+        diff: Diff,
+    ) -> Result<(), anyhow::Error> {
+        let mut packer = row_buf.packer();
+        for datum in values {
+            packer.push(match datum {
+                Datum::UInt16(v) => Datum::Int32(i32::from(v)),
+                Datum::UInt32(v) => Datum::Int64(i64::from(v)),
+                datum => datum,
+            });
+        }
+        let time = i64::try_from(u64::from(time))
+            .map_err(|_| anyhow!("timestamp {} does not fit into an Iceberg long", time))?;
+        packer.push(Datum::Int64(time));
+        packer.push(Datum::Int64(diff));
+        Ok(())
+    }
+
+    fn encode(&self, rows: &[Row]) -> Result<Vec<u8>, anyhow::Error> {
+        let columns = self
+            .types
+            .iter()
+            .map(|ty| match (iceberg_column_type(ty), ty) {
+                ("string", _) => ParquetColumn::text(ty),
+                (_, ScalarType::UInt16) => ParquetColumn::new(&ScalarType::Int32),
+                (_, ScalarType::UInt32) => ParquetColumn::new(&ScalarType::Int64),
+                _ => ParquetColumn::new(ty),
+            })
+            .collect();
+        parquet::encode(&self.names, columns, rows)
+    }
+}
+
+/// Returns the upper of the changes that the sink with ID `id` has committed
+/// to the table, if it has committed any.
+fn committed_upper(id: GlobalId, metadata: &TableMetadata) -> Option<Timestamp> {
+    let id = id.to_string();
+    metadata
+        .ancestry()
+        .find(|snapshot| snapshot.summary.get(SINK_ID_PROPERTY) == Some(&id))
+        .and_then(|snapshot| snapshot.summary.get(SINK_UPPER_PROPERTY))
+        .and_then(|upper| upper.parse::<u64>().ok())
+        .map(Timestamp::from)
+}
+
+/// Writes the rows of `batch` to a data file and commits it to the table,
+/// along with `upper`.
+async fn commit_batch(
+    catalog: &Catalog,
+    id: GlobalId,
+    connection: &IcebergSinkConnection,
+    encoder: &DataFileEncoder,
+    batch: Batch,
+    upper: Option<Timestamp>,
+    sink_statistics: &StorageStatistics<SinkStatisticsUpdate, SinkStatisticsMetrics>,
+) -> Result<(), anyhow::Error> {
+    let body = encoder.encode(&batch.rows)?;
+    let size_for_stats = u64::cast_from(body.len());
+    let file = DataFile {
+        path: encoder.new_path(id),
+        record_count: i64::try_from(batch.rows.len()).expect("row count fits in i64"),
+        file_size_in_bytes: i64::try_from(body.len()).expect("file size fits in i64"),
+    };
+
+    info!(
+        "{}: writing {:?} rows to {}",
+        id,
+        batch.rows.len(),
+        file.path
+    );
+    catalog
+        .io()
+        .write(&file.path, body)
+        .await
+        .with_context(|| format!("writing {}", file.path))?;
+
+    // An empty upper means that the sink has committed all changes, which no
+    // timestamp can represent.
+    let upper = upper.map_or(u64::MAX, u64::from);
+    let properties = BTreeMap::from([
+        (SINK_ID_PROPERTY.to_string(), id.to_string()),
+        (SINK_UPPER_PROPERTY.to_string(), upper.to_string()),
+    ]);
+    mz_iceberg_util::append_data_file(
+        catalog,
+        &connection.namespace,
+        &connection.table,
+        &file,
+        properties,
+    )
+    .await
+    .with_context(|| {
+        format!(
+            "committing to table {}.{}",
+            connection.namespace, connection.table
+        )
+    })?;
+    sink_statistics.inc_messages_committed_by(u64::cast_from(batch.rows.len()));
+    sink_statistics.inc_bytes_committed_by(size_for_stats);
+    Ok(())
+}
+
+fn iceberg<G>(
+    collection: Collection<G, (Option<Row>, Option<Row>), Diff>,
+    id: GlobalId,
+    connection: IcebergSinkConnection,
+    as_of: SinkAsOf,
+    write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+    sink_statistics: StorageStatistics<SinkStatisticsUpdate, SinkStatisticsMetrics>,
+    connection_context: ConnectionContext,
+) -> (Stream<G, HealthStatusMessage>, PressOnDropButton)
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    let name = format!("iceberg-{}", id);
+    let scope = collection.inner.scope();
+    let worker_id = scope.index();
+    let worker_count = scope.peers();
+    let mut builder = AsyncOperatorBuilder::new(name.clone(), scope);
+
+    // We want exactly one worker to commit to the table.
+    let hashed_id = id.hashed();
+    let is_active_worker = usize::cast_from(hashed_id) % worker_count == worker_id;
+
+    let mut input = builder.new_input(&collection.inner, Exchange::new(move |_| hashed_id));
+    let (mut health_output, health_stream) = builder.new_output();
+
+    let button = builder.build(move |caps| async move {
+        let [health_cap]: [_; 1] = caps.try_into().unwrap();
+
+        if !is_active_worker {
+            return;
+        }
+
+        let result: Result<(), anyhow::Error> = async {
+            let sdk_config = connection
+                .connection
+                .load(
+                    connection_context.aws_external_id_prefix.as_ref(),
+                    Some(&connection.connection_id),
+                    &*connection_context.secrets_reader,
+                )
+                .await;
+            let catalog = connection
+                .catalog
+                .connect(&sdk_config, &*connection_context.secrets_reader)
+                .await
+                .context("connecting to catalog")?;
+            let table = catalog
+                .load_table(&connection.namespace, &connection.table)
+                .await
+                .with_context(|| {
+                    format!(
+                        "loading table {}.{}",
+                        connection.namespace, connection.table
+                    )
+                })?;
+            let encoder = DataFileEncoder::new(&connection, &table.metadata);
+
+            // Changes before the upper of the last commit of a previous
+            // incarnation of the sink are already in the table.
+            let resume_upper = committed_upper(id, &table.metadata);
+            if let Some(upper) = resume_upper {
+                info!("{}: resuming after committed upper {}", id, upper);
+            }
+
+            health_output
+                .give(
+                    &health_cap,
+                    HealthStatusMessage {
+                        index: 0,
+                        namespace: StatusNamespace::Iceberg,
+                        update: HealthStatusUpdate::running(),
+                    },
+                )
+                .await;
+
+            let mut pending_rows: BTreeMap<Timestamp, Vec<Row>> = BTreeMap::new();
+            let mut batch = Batch::new();
+            let mut row_buf = Row::default();
+
+            while let Some(event) = input.next_mut().await {
+                match event {
+                    Event::Data(_, rows) => {
+                        for ((_key, value), time, diff) in rows.drain(..) {
+                            let should_emit = if as_of.strict {
+                                as_of.frontier.less_than(&time)
+                            } else {
+                                as_of.frontier.less_equal(&time)
+                            };
+                            let committed = resume_upper.map_or(false, |upper| time < upper);
+                            if !should_emit || committed || diff == 0 {
+                                continue;
+                            }
+                            // The Debezium envelope packs the state of the row
+                            // before and after the change into two lists.
+                            let value = value.expect("debezium envelope always produces a value");
+                            let (before, after) = value
+                                .iter()
+                                .collect_tuple()
+                                .expect("debezium rows have two columns");
+                            for (datum, diff) in [(before, -diff), (after, diff)] {
+                                if datum.is_null() {
+                                    continue;
+                                }
+                                encoder.pack_row(
+                                    &mut row_buf,
+                                    datum.unwrap_list().iter(),
+                                    time,
+                                    diff,
+                                )?;
+                                pending_rows.entry(time).or_default().push(row_buf.clone());
+                            }
+                        }
+                    }
+                    Event::Progress(frontier) => {
+                        while let Some(entry) = pending_rows.first_entry() {
+                            if frontier.less_equal(entry.key()) {
+                                break;
+                            }
+                            let (ts, rows) = entry.remove_entry();
+
+                            let count_for_stats = u64::cast_from(rows.len());
+                            let size_for_stats = rows
+                                .iter()
+                                .map(|row| u64::cast_from(row.byte_len()))
+                                .sum::<u64>();
+                            if batch.rows.is_empty() {
+                                batch = Batch::new();
+                            }
+                            batch.lower.get_or_insert(ts);
+                            batch.rows.extend(rows);
+                            sink_statistics.inc_messages_staged_by(count_for_stats);
+                            sink_statistics.inc_bytes_staged_by(size_for_stats);
+                        }
+
+                        // The commit interval is only checked when the input
+                        // makes progress, which it does at least once per
+                        // timestamp interval.
+                        if !batch.rows.is_empty()
+                            && (frontier.is_empty()
+                                || batch.opened_at.elapsed() >= connection.commit_interval)
+                        {
+                            let full = std::mem::replace(&mut batch, Batch::new());
+                            commit_batch(
+                                &catalog,
+                                id,
+                                &connection,
+                                &encoder,
+                                full,
+                                frontier.as_option().copied(),
+                                &sink_statistics,
+                            )
+                            .await?;
+                        }
+
+                        // Everything before the input frontier has been
+                        // committed, except for timestamps that are still
+                        // pending or in the current batch.
+                        let mut new_frontier = frontier.to_owned();
+                        new_frontier.extend(batch.lower);
+                        new_frontier.extend(pending_rows.keys().next().cloned());
+                        let mut write_frontier = write_frontier.borrow_mut();
+                        write_frontier.clear();
+                        write_frontier.extend(new_frontier);
+                    }
+                }
+            }
+            Ok(())
+        }
+        .await;
+
+        if let Err(error) = result {
+            health_output
+                .give(
+                    &health_cap,
+                    HealthStatusMessage {
+                        index: 0,
+                        namespace: StatusNamespace::Iceberg,
+                        update: HealthStatusUpdate::halting(
+                            format!("{}", error.display_with_causes()),
+                            None,
+                        ),
+                    },
+                )
+                .await;
+
+            // Make sure to never return, preventing the sink from writing
+            // out anything it might regret in the future.
+            future::pending::<()>().await;
+        }
+    });
+
+    (health_stream, button.press_on_drop())
+}
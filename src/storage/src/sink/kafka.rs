@@ -12,6 +12,7 @@ use std::collections::{BTreeMap, VecDeque};
 use std::fmt::Debug;
 use std::future;
 use std::future::Future;
+use std::num::NonZeroU32;
 use std::rc::Rc;
 use std::sync::Arc;
 use std::time::Duration;
@@ -19,16 +20,19 @@ use std::time::Duration;
 use anyhow::{anyhow, Context};
 use differential_dataflow::{Collection, Hashable};
 use futures::{StreamExt, TryFutureExt};
+use governor::{DefaultDirectRateLimiter, Quota, RateLimiter};
 use maplit::btreemap;
 use mz_interchange::avro::{AvroEncoder, AvroSchemaGenerator, AvroSchemaOptions};
 use mz_interchange::encode::Encode;
+use mz_interchange::envelopes::{self, DbzSource};
 use mz_interchange::json::JsonEncoder;
 use mz_kafka_util::client::{MzClientContext, TunnelingClientContext};
 use mz_ore::cast::CastFrom;
 use mz_ore::error::ErrorExt;
 use mz_ore::retry::{Retry, RetryResult};
+use mz_ore::str::StrExt;
 use mz_ore::task;
-use mz_repr::{Diff, GlobalId, Row, Timestamp};
+use mz_repr::{Datum, Diff, GlobalId, Row, RowPacker, Timestamp};
 use mz_ssh_util::tunnel::SshTunnelStatus;
 use mz_storage_client::client::SinkStatisticsUpdate;
 use mz_storage_client::sink::progress_key::ProgressKey;
@@ -36,7 +40,8 @@ use mz_storage_client::sink::ProgressRecord;
 use mz_storage_types::connections::ConnectionContext;
 use mz_storage_types::errors::{ContextCreationError, ContextCreationErrorExt, DataflowError};
 use mz_storage_types::sinks::{
-    KafkaSinkConnection, KafkaSinkFormat, MetadataFilled, SinkAsOf, SinkEnvelope, StorageSinkDesc,
+    KafkaSinkConnection, KafkaSinkDebeziumSource, KafkaSinkDeleteMode, KafkaSinkFormat,
+    KafkaSinkHeaders, MetadataFilled, SinkAsOf, SinkEnvelope, StorageSinkDesc,
 };
 use mz_timely_util::builder_async::{
     Event, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
@@ -58,6 +63,7 @@ use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespac
 use crate::metrics::sink::SinkMetrics;
 use crate::metrics::StorageMetrics;
 use crate::render::sinks::SinkRender;
+use crate::sink::csv::CsvEncoder;
 use crate::statistics::{SinkStatisticsMetrics, StorageStatistics};
 use crate::storage_state::StorageState;
 
@@ -296,9 +302,11 @@ impl KafkaTxProducer {
                     // different settings for this value to see if it makes a
                     // big difference.
                     "queue.buffering.max.ms" => format!("{}", 10),
-                    "transactional.id" => format!("mz-producer-{sink_id}-0"),
-                    // Time out transactions after 10 seconds
-                    "transaction.timeout.ms" => format!("{}", 10_000),
+                    "transactional.id" => connection.transactional_id(sink_id),
+                    // Time out transactions after the timeout of the sink,
+                    // 10 seconds by default.
+                    "transaction.timeout.ms" =>
+                        connection.transaction_timeout().as_millis().to_string(),
                 },
             )
             .await?;
@@ -436,6 +444,15 @@ struct HealthOutputHandle {
 struct KafkaSinkState {
     name: String,
     topic: String,
+    /// The names of the headers that are emitted from columns.
+    header_names: Vec<String>,
+    /// The topic to which rows that cannot be encoded are written, if any.
+    dead_letter_topic: Option<String>,
+    /// Limits the number of messages produced per second, if configured.
+    message_rate_limiter: Option<DefaultDirectRateLimiter>,
+    /// Limits the number of bytes produced per second, if configured, along
+    /// with the limit itself.
+    byte_rate_limiter: Option<(DefaultDirectRateLimiter, NonZeroU32)>,
     metrics: Arc<SinkMetrics>,
     producer: KafkaTxProducer,
     pending_rows: BTreeMap<Timestamp, Vec<EncodedRow>>,
@@ -525,6 +542,19 @@ impl KafkaSinkState {
             KafkaSinkState {
                 name: sink_name,
                 topic: connection.topic,
+                dead_letter_topic: connection.dead_letter_topic,
+                message_rate_limiter: connection
+                    .max_messages_per_second
+                    .and_then(NonZeroU32::new)
+                    .map(|limit| RateLimiter::direct(Quota::per_second(limit))),
+                byte_rate_limiter: connection
+                    .max_bytes_per_second
+                    .and_then(NonZeroU32::new)
+                    .map(|limit| (RateLimiter::direct(Quota::per_second(limit)), limit)),
+                header_names: connection
+                    .headers
+                    .map(|headers| headers.names)
+                    .unwrap_or_default(),
                 metrics,
                 producer,
                 pending_rows: BTreeMap::new(),
@@ -541,6 +571,25 @@ impl KafkaSinkState {
         )
     }
 
+    /// Waits until producing a message of `size` bytes is within the rate
+    /// limits of the sink.
+    async fn throttle(&self, size: u64) {
+        if let Some(limiter) = &self.message_rate_limiter {
+            limiter.until_ready().await;
+        }
+        if let Some((limiter, limit)) = &self.byte_rate_limiter {
+            // The limiter can hold at most a second's worth of bytes, so
+            // larger messages wait for that much to be available instead.
+            let size = u32::try_from(size).unwrap_or(u32::MAX).min(limit.get());
+            if let Some(size) = NonZeroU32::new(size) {
+                limiter
+                    .until_n_ready(size)
+                    .await
+                    .expect("size within burst capacity");
+            }
+        }
+    }
+
     async fn send<'a, K, P>(&self, mut record: BaseRecord<'a, K, P>)
     where
         K: ToBytes + ?Sized,
@@ -837,10 +886,21 @@ async fn halt_on_err<T>(
     }
 }
 
+/// The values of the headers of a message that are emitted from columns, in
+/// the order of [`KafkaSinkHeaders::names`]. `None` is emitted as a header
+/// without a value.
+type HeaderValues = Vec<Option<Vec<u8>>>;
+
 #[derive(Debug)]
 struct EncodedRow {
     key: Option<Vec<u8>>,
     value: Option<Vec<u8>>,
+    headers: HeaderValues,
+    /// The topic the row is routed to, if it overrides the topic of the sink.
+    topic: Option<String>,
+    /// The error that prevented the row from being encoded in the format of
+    /// the sink, if any. Such rows are written to the dead letter topic.
+    error: Option<String>,
     count: usize,
 }
 
@@ -906,7 +966,20 @@ where
 /// Updates that are not beyond the given [`SinkAsOf`] and/or the `gate_ts` in
 /// [`KafkaSinkConnection`] will be discarded without producing them.
 fn produce_to_kafka<G>(
-    stream: Stream<G, ((Option<Vec<u8>>, Option<Vec<u8>>), Timestamp, Diff)>,
+    stream: Stream<
+        G,
+        (
+            (
+                Option<Vec<u8>>,
+                Option<Vec<u8>>,
+                HeaderValues,
+                Option<String>,
+                Option<String>,
+            ),
+            Timestamp,
+            Diff,
+        ),
+    >,
     id: GlobalId,
     name: String,
     connection: KafkaSinkConnection,
@@ -992,7 +1065,7 @@ where
                 Event::Data(_, rows) => {
                     // Queue all pending rows waiting to be sent to kafka
                     assert!(is_active_worker);
-                    for ((key, value), time, diff) in rows.drain(..) {
+                    for ((key, value, headers, topic, error), time, diff) in rows.drain(..) {
                         let should_emit = if as_of.strict {
                             as_of.frontier.less_than(&time)
                         } else {
@@ -1014,7 +1087,14 @@ where
                             usize::try_from(diff).expect("can't sink negative multiplicities");
 
                         let rows = s.pending_rows.entry(time).or_default();
-                        rows.push(EncodedRow { key, value, count });
+                        rows.push(EncodedRow {
+                            key,
+                            value,
+                            headers,
+                            topic,
+                            error,
+                            count,
+                        });
                         s.metrics.rows_queued.inc();
                     }
                 }
@@ -1053,7 +1133,14 @@ where
                         let count_for_stats = u64::cast_from(rows.len());
                         let mut total_size_for_stats = 0;
                         for encoded_row in rows {
-                            let record = BaseRecord::to(&s.topic);
+                            let topic = match &encoded_row.error {
+                                Some(_) => s
+                                    .dead_letter_topic
+                                    .as_ref()
+                                    .expect("only encoded with a dead letter topic"),
+                                None => encoded_row.topic.as_ref().unwrap_or(&s.topic),
+                            };
+                            let record = BaseRecord::to(topic);
                             let record = match encoded_row.value.as_ref() {
                                 Some(r) => record.payload(r),
                                 None => record,
@@ -1064,16 +1151,30 @@ where
                             };
 
                             let ts_bytes = ts.to_string().into_bytes();
-                            let record = record.headers(OwnedHeaders::new().insert(Header {
+                            let mut headers = OwnedHeaders::new().insert(Header {
                                 key: "materialize-timestamp",
                                 value: Some(&ts_bytes),
-                            }));
+                            });
+                            for (name, value) in s.header_names.iter().zip(&encoded_row.headers) {
+                                headers = headers.insert(Header {
+                                    key: name,
+                                    value: value.as_deref(),
+                                });
+                            }
+                            if let Some(error) = &encoded_row.error {
+                                headers = headers.insert(Header {
+                                    key: "materialize-error",
+                                    value: Some(error.as_bytes()),
+                                });
+                            }
+                            let record = record.headers(headers);
 
                             let size_for_stats =
                                 u64::cast_from(record.payload.as_ref().map_or(0, |p| p.len()))
                                     + u64::cast_from(record.key.as_ref().map_or(0, |k| k.len()));
                             total_size_for_stats += size_for_stats;
 
+                            s.throttle(size_for_stats).await;
                             s.send(record).await;
                             sink_statistics.inc_messages_staged_by(1);
                             sink_statistics.inc_bytes_staged_by(size_for_stats);
@@ -1188,7 +1289,20 @@ fn encode_stream<G>(
     connection_cx: ConnectionContext,
     envelope: SinkEnvelope,
 ) -> (
-    Stream<G, ((Option<Vec<u8>>, Option<Vec<u8>>), Timestamp, Diff)>,
+    Stream<
+        G,
+        (
+            (
+                Option<Vec<u8>>,
+                Option<Vec<u8>>,
+                HeaderValues,
+                Option<String>,
+                Option<String>,
+            ),
+            Timestamp,
+            Diff,
+        ),
+    >,
     Stream<G, HealthStatusMessage>,
     PressOnDropButton,
 )
@@ -1232,6 +1346,20 @@ where
             .as_ref()
             .map(|(desc, _indices)| desc.clone());
         let value_desc = connection.value_desc.clone();
+        let headers = connection.headers.clone();
+        let delete_mode = connection
+            .delete_mode
+            .unwrap_or_else(|| KafkaSinkDeleteMode::default_for(envelope));
+
+        // Rows that cannot be encoded in the format of the sink are written
+        // to the dead letter topic as JSON, which can represent any row.
+        let dead_letter_encoder = connection.dead_letter_topic.is_some().then(|| {
+            JsonEncoder::new(
+                key_desc.clone(),
+                value_desc.clone(),
+                matches!(envelope, SinkEnvelope::Debezium),
+            )
+        });
 
         let encoder: Box<dyn Encode> = match connection.format {
             KafkaSinkFormat::Avro {
@@ -1269,6 +1397,7 @@ where
 
                 let options = AvroSchemaOptions {
                     is_debezium: matches!(envelope, SinkEnvelope::Debezium),
+                    is_debezium_full: connection.debezium_source.is_some(),
                     ..Default::default()
                 };
 
@@ -1280,11 +1409,29 @@ where
                     value_schema_id,
                 ))
             }
-            KafkaSinkFormat::Json => Box::new(JsonEncoder::new(
-                key_desc,
-                value_desc,
-                matches!(envelope, SinkEnvelope::Debezium),
-            )),
+            KafkaSinkFormat::Json => {
+                let encoder = JsonEncoder::new(
+                    key_desc,
+                    value_desc,
+                    matches!(envelope, SinkEnvelope::Debezium),
+                );
+                match connection.debezium_source {
+                    Some(_) => Box::new(encoder.with_debezium_full()),
+                    None => Box::new(encoder),
+                }
+            }
+            KafkaSinkFormat::Csv { delimiter, header } => {
+                Box::new(CsvEncoder::new(key_desc, value_desc, delimiter, header))
+            }
+        };
+
+        // Upsert values of deletions are empty, so the topic of upserts is
+        // taken from the key, which planning ensures contains the column.
+        let topic_key_index = match (&connection.topic_routing, &connection.key_desc_and_indices) {
+            (Some(routing), Some((_desc, indices))) if envelope == SinkEnvelope::Upsert => {
+                indices.iter().position(|idx| *idx == routing.index)
+            }
+            _ => None,
         };
 
         while let Some(event) = input.next_mut().await {
@@ -1302,10 +1449,94 @@ where
                         continue;
                     }
 
-                    let key = key.map(|key| encoder.encode_key_unchecked(key));
-                    let value = value.map(|value| encoder.encode_value_unchecked(value));
+                    // The topic is taken before the value is changed by the
+                    // headers and delete mode of the sink.
+                    let topic = connection.topic_routing.as_ref().and_then(|routing| {
+                        routed_topic(
+                            key.as_ref(),
+                            value.as_ref(),
+                            topic_key_index,
+                            routing.index,
+                            envelope,
+                        )
+                    });
+
+                    let (value, header_values) = match (&headers, value) {
+                        (Some(headers), Some(value)) => {
+                            let (value, header_values) = split_headers(value, headers, envelope);
+                            (Some(value), header_values)
+                        }
+                        (_, value) => (value, vec![]),
+                    };
 
-                    output.give(&cap, ((key, value), time, diff)).await;
+                    let value = match delete_mode {
+                        KafkaSinkDeleteMode::Record => value,
+                        _ if !is_deletion(value.as_ref(), envelope) => value,
+                        KafkaSinkDeleteMode::Tombstone => None,
+                        KafkaSinkDeleteMode::Drop => continue,
+                    };
+
+                    let value = match (&connection.debezium_source, value) {
+                        (Some(source), Some(value)) => {
+                            let snapshot = !as_of.strict && !as_of.frontier.less_than(&time);
+                            Some(append_debezium_source(value, source, time, snapshot))
+                        }
+                        (_, value) => value,
+                    };
+
+                    // Only keep the unencoded row around if it can be written to
+                    // the dead letter topic.
+                    let unencoded = dead_letter_encoder
+                        .is_some()
+                        .then(|| (key.clone(), value.clone()));
+                    let allowlist = connection
+                        .topic_routing
+                        .as_ref()
+                        .and_then(|routing| routing.allowlist.as_ref());
+                    let encoded = match (&topic, allowlist) {
+                        (Some(topic), Some(allowlist)) if !allowlist.contains(topic) => {
+                            Err(anyhow::anyhow!(
+                                "topic {} is not in the TOPIC ALLOWLIST",
+                                topic.quoted()
+                            ))
+                        }
+                        _ => key
+                            .map(|key| encoder.encode_key(key))
+                            .transpose()
+                            .and_then(|key| {
+                                let value =
+                                    value.map(|value| encoder.encode_value(value)).transpose()?;
+                                Ok((key, value))
+                            }),
+                    };
+
+                    let (key, value, error) = match (encoded, &dead_letter_encoder, unencoded) {
+                        (Ok((key, value)), _, _) => (key, value, None),
+                        (Err(e), Some(dead_letter_encoder), Some((key, value))) => {
+                            warn!("{}: writing row to dead letter topic: {:#}", sink_id, e);
+                            let key = key.map(|key| dead_letter_encoder.encode_key_unchecked(key));
+                            let value = value
+                                .map(|value| dead_letter_encoder.encode_value_unchecked(value));
+                            (key, value, Some(format!("{:#}", e)))
+                        }
+                        (Err(e), _, _) => {
+                            halt_on_err(
+                                &healthchecker,
+                                Err(ContextCreationError::Other(
+                                    e.context("error encoding row for sink"),
+                                )),
+                                None,
+                            )
+                            .await
+                        }
+                    };
+
+                    output
+                        .give(
+                            &cap,
+                            ((key, value, header_values, topic, error), time, diff),
+                        )
+                        .await;
                 }
             }
         }
@@ -1313,3 +1544,157 @@ where
 
     (stream, health_stream, button.press_on_drop())
 }
+
+/// Returns whether a `value` produced by the sink envelope represents the
+/// deletion of a row.
+///
+/// Upsert deletions have no value, and Debezium deletions have no row after the
+/// update.
+fn is_deletion(value: Option<&Row>, envelope: SinkEnvelope) -> bool {
+    match (envelope, value) {
+        (_, None) => true,
+        (SinkEnvelope::Upsert, Some(_)) => false,
+        (SinkEnvelope::Debezium, Some(value)) => value.iter().nth(1) == Some(Datum::Null),
+    }
+}
+
+/// Returns the topic that a message of the sink envelope is routed to, or
+/// `None` if it is sent to the topic of the sink.
+///
+/// The topic is taken from the column at `key_index` of upsert keys, and from
+/// the column at `index` of the row after a Debezium update, or before it for
+/// deletions.
+fn routed_topic(
+    key: Option<&Row>,
+    value: Option<&Row>,
+    key_index: Option<usize>,
+    index: usize,
+    envelope: SinkEnvelope,
+) -> Option<String> {
+    let datum = match envelope {
+        SinkEnvelope::Upsert => key?.iter().nth(key_index?)?,
+        SinkEnvelope::Debezium => {
+            let mut states = value?.iter();
+            let before = states.next()?;
+            let after = states.next()?;
+            let state = if after.is_null() { before } else { after };
+            if state.is_null() {
+                return None;
+            }
+            state.unwrap_list().iter().nth(index)?
+        }
+    };
+    match datum {
+        Datum::String(topic) => Some(topic.to_string()),
+        _ => None,
+    }
+}
+
+/// Appends the `source`, `op`, and `ts_ms` fields of the full Debezium envelope
+/// to a Debezium `value` of an update at `time`.
+fn append_debezium_source(
+    value: Row,
+    source: &KafkaSinkDebeziumSource,
+    time: Timestamp,
+    snapshot: bool,
+) -> Row {
+    let mut datums = value.iter();
+    let has_before = datums.next() != Some(Datum::Null);
+    let has_after = datums.next() != Some(Datum::Null);
+    let source = DbzSource {
+        name: &source.name,
+        db: &source.db,
+        schema: &source.schema,
+        table: &source.table,
+        ts_ms: i64::try_from(u64::from(time)).expect("timestamp fits in i64"),
+        snapshot,
+    };
+
+    let mut row = Row::default();
+    let mut packer = row.packer();
+    packer.extend_by_row(&value);
+    envelopes::dbz_source_format(
+        &mut packer,
+        &source,
+        envelopes::dbz_op(has_before, has_after, snapshot),
+    );
+    row
+}
+
+/// Extracts the values of the header columns from a `value` produced by the
+/// sink envelope, and removes the columns from it if they are excluded from
+/// the value.
+///
+/// Debezium values contain the row before and after the update. Header values
+/// are taken from the row after the update, or before it for deletions.
+fn split_headers(
+    value: Row,
+    headers: &KafkaSinkHeaders,
+    envelope: SinkEnvelope,
+) -> (Row, HeaderValues) {
+    let mut row = Row::default();
+    let values = match envelope {
+        SinkEnvelope::Upsert => {
+            let datums = value.unpack();
+            if headers.exclude_from_value {
+                pack_value_columns(&mut row.packer(), &datums, &headers.indices);
+            }
+            header_values(&datums, &headers.indices)
+        }
+        SinkEnvelope::Debezium => {
+            let states: Vec<Option<Vec<Datum>>> = value
+                .iter()
+                .map(|state| (!state.is_null()).then(|| state.unwrap_list().iter().collect()))
+                .collect();
+            let [before, after] = &states[..] else {
+                unreachable!("debezium value with {} fields", states.len());
+            };
+            if headers.exclude_from_value {
+                let mut packer = row.packer();
+                for state in [before, after] {
+                    match state {
+                        Some(datums) => packer.push_list_with(|packer| {
+                            pack_value_columns(packer, datums, &headers.indices)
+                        }),
+                        None => packer.push(Datum::Null),
+                    }
+                }
+            }
+            after
+                .as_ref()
+                .or(before.as_ref())
+                .map_or_else(Vec::new, |datums| header_values(datums, &headers.indices))
+        }
+    };
+
+    if headers.exclude_from_value {
+        (row, values)
+    } else {
+        (value, values)
+    }
+}
+
+/// Returns the values of the columns at `indices` of a row as header values.
+fn header_values(datums: &[Datum], indices: &[usize]) -> HeaderValues {
+    indices
+        .iter()
+        .map(|&idx| match datums[idx] {
+            Datum::String(s) => Some(s.as_bytes().to_vec()),
+            Datum::Bytes(b) => Some(b.to_vec()),
+            Datum::Null => None,
+            // The types of header columns are validated during planning.
+            datum => unreachable!("header column of unexpected type: {datum:?}"),
+        })
+        .collect()
+}
+
+/// Packs the columns of a row that are not at `header_indices`.
+fn pack_value_columns(packer: &mut RowPacker, datums: &[Datum], header_indices: &[usize]) {
+    packer.extend(
+        datums
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !header_indices.contains(idx))
+            .map(|(_, datum)| datum),
+    )
+}
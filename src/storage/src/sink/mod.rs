@@ -9,4 +9,11 @@
 
 //! Moving data to external systems
 
+mod csv;
+mod http;
+mod iceberg;
 mod kafka;
+mod parquet;
+mod postgres;
+mod redis;
+mod s3;
@@ -0,0 +1,156 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Parquet encoding for sinks that write files.
+
+use arrow2::array::{
+    Array, MutableArray, MutableBinaryArray, MutableBooleanArray, MutablePrimitiveArray,
+    MutableUtf8Array,
+};
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{Field, Schema};
+use arrow2::io::parquet::write::{
+    CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version, WriteOptions,
+};
+use bytes::BytesMut;
+use itertools::Itertools;
+use mz_repr::{Datum, Row, ScalarType};
+
+/// Encodes `rows` as a Parquet file whose columns are named `names`, writing
+/// each datum to the corresponding column of `columns`.
+pub(super) fn encode(
+    names: &[String],
+    mut columns: Vec<ParquetColumn>,
+    rows: &[Row],
+) -> Result<Vec<u8>, anyhow::Error> {
+    let mut buf = BytesMut::new();
+    for row in rows {
+        for (column, datum) in columns.iter_mut().zip_eq(row.iter()) {
+            column.push(datum, &mut buf);
+        }
+    }
+    let arrays: Vec<_> = columns.into_iter().map(ParquetColumn::finish).collect();
+    let schema = Schema::from(
+        names
+            .iter()
+            .zip_eq(arrays.iter())
+            .map(|(name, array)| Field::new(name, array.data_type().clone(), true))
+            .collect::<Vec<_>>(),
+    );
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_pagesize_limit: None, // use default limit
+    };
+    let encodings = schema
+        .fields
+        .iter()
+        .map(|_| vec![Encoding::Plain])
+        .collect();
+    let row_groups = RowGroupIterator::try_new(
+        [Ok(Chunk::new(arrays))].into_iter(),
+        &schema,
+        options,
+        encodings,
+    )?;
+
+    let mut file = vec![];
+    let mut writer = FileWriter::try_new(&mut file, schema, options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    Ok(file)
+}
+
+/// A column of a Parquet file that is being built.
+///
+/// Types with a native Arrow representation are written as such, and all
+/// others in their PostgreSQL text encoding.
+pub(super) enum ParquetColumn {
+    Boolean(MutableBooleanArray),
+    Int16(MutablePrimitiveArray<i16>),
+    Int32(MutablePrimitiveArray<i32>),
+    Int64(MutablePrimitiveArray<i64>),
+    UInt16(MutablePrimitiveArray<u16>),
+    UInt32(MutablePrimitiveArray<u32>),
+    UInt64(MutablePrimitiveArray<u64>),
+    Float32(MutablePrimitiveArray<f32>),
+    Float64(MutablePrimitiveArray<f64>),
+    Binary(MutableBinaryArray<i32>),
+    Text(MutableUtf8Array<i32>, ScalarType),
+}
+
+impl ParquetColumn {
+    pub(super) fn new(ty: &ScalarType) -> Self {
+        match ty {
+            ScalarType::Bool => ParquetColumn::Boolean(MutableBooleanArray::new()),
+            ScalarType::Int16 => ParquetColumn::Int16(MutablePrimitiveArray::new()),
+            ScalarType::Int32 => ParquetColumn::Int32(MutablePrimitiveArray::new()),
+            ScalarType::Int64 => ParquetColumn::Int64(MutablePrimitiveArray::new()),
+            ScalarType::UInt16 => ParquetColumn::UInt16(MutablePrimitiveArray::new()),
+            ScalarType::UInt32 => ParquetColumn::UInt32(MutablePrimitiveArray::new()),
+            ScalarType::UInt64 => ParquetColumn::UInt64(MutablePrimitiveArray::new()),
+            ScalarType::Float32 => ParquetColumn::Float32(MutablePrimitiveArray::new()),
+            ScalarType::Float64 => ParquetColumn::Float64(MutablePrimitiveArray::new()),
+            ScalarType::Bytes => ParquetColumn::Binary(MutableBinaryArray::new()),
+            ty => ParquetColumn::Text(MutableUtf8Array::new(), ty.clone()),
+        }
+    }
+
+    /// Returns a column that holds values of type `ty` in their text
+    /// encoding, even if the type has a native Arrow representation.
+    pub(super) fn text(ty: &ScalarType) -> Self {
+        ParquetColumn::Text(MutableUtf8Array::new(), ty.clone())
+    }
+
+    pub(super) fn push(&mut self, datum: Datum, buf: &mut BytesMut) {
+        let value = (!datum.is_null()).then_some(datum);
+        match self {
+            ParquetColumn::Boolean(a) => a.push(value.map(|d| d.unwrap_bool())),
+            ParquetColumn::Int16(a) => a.push(value.map(|d| d.unwrap_int16())),
+            ParquetColumn::Int32(a) => a.push(value.map(|d| d.unwrap_int32())),
+            ParquetColumn::Int64(a) => a.push(value.map(|d| d.unwrap_int64())),
+            ParquetColumn::UInt16(a) => a.push(value.map(|d| d.unwrap_uint16())),
+            ParquetColumn::UInt32(a) => a.push(value.map(|d| d.unwrap_uint32())),
+            ParquetColumn::UInt64(a) => a.push(value.map(|d| d.unwrap_uint64())),
+            ParquetColumn::Float32(a) => a.push(value.map(|d| d.unwrap_float32())),
+            ParquetColumn::Float64(a) => a.push(value.map(|d| d.unwrap_float64())),
+            ParquetColumn::Binary(a) => a.push(value.map(|d| d.unwrap_bytes())),
+            ParquetColumn::Text(a, ty) => a.push(encode_text(datum, ty, buf)),
+        }
+    }
+
+    pub(super) fn finish(self) -> Box<dyn Array> {
+        match self {
+            ParquetColumn::Boolean(mut a) => a.as_box(),
+            ParquetColumn::Int16(mut a) => a.as_box(),
+            ParquetColumn::Int32(mut a) => a.as_box(),
+            ParquetColumn::Int64(mut a) => a.as_box(),
+            ParquetColumn::UInt16(mut a) => a.as_box(),
+            ParquetColumn::UInt32(mut a) => a.as_box(),
+            ParquetColumn::UInt64(mut a) => a.as_box(),
+            ParquetColumn::Float32(mut a) => a.as_box(),
+            ParquetColumn::Float64(mut a) => a.as_box(),
+            ParquetColumn::Binary(mut a) => a.as_box(),
+            ParquetColumn::Text(mut a, _) => a.as_box(),
+        }
+    }
+}
+
+/// Encodes `datum` in PostgreSQL's text format.
+pub(super) fn encode_text(datum: Datum, ty: &ScalarType, buf: &mut BytesMut) -> Option<String> {
+    mz_pgrepr::Value::from_datum(datum, ty).map(|value| {
+        buf.clear();
+        value.encode_text(buf);
+        String::from_utf8(buf.to_vec()).expect("text encoding produces valid UTF-8")
+    })
+}
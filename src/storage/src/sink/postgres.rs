@@ -0,0 +1,562 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A sink that upserts the rows of a collection into a PostgreSQL table.
+//!
+//! All updates are exchanged to a single worker, which buffers them until the
+//! input frontier passes their timestamp and then applies each timestamp in
+//! its own transaction. Rows with a value are written with `INSERT ... ON
+//! CONFLICT DO UPDATE`, which relies on the unique constraint on the key
+//! columns that is validated during purification, and rows without one are
+//! deleted.
+//!
+//! Sinks with `WRITE MODE 'copy'` instead stream all rows of a timestamp into
+//! a temporary staging table with `COPY ... FROM STDIN`, delete the rows of
+//! the table whose keys appear in the staging table, and then insert the
+//! staged rows that have a value. This needs neither `ON CONFLICT` nor a
+//! unique constraint, so it works with any server that speaks the PostgreSQL
+//! wire protocol and supports `COPY` and temporary tables.
+//!
+//! The first transaction the sink commits after (re)starting at a non-strict
+//! `as_of` contains the full snapshot of the collection, so it replaces the
+//! contents of the table. Writes are therefore at-least-once, but the table
+//! converges to the contents of the collection.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::future;
+use std::iter;
+use std::rc::Rc;
+
+use anyhow::Context;
+use bytes::{Bytes, BytesMut};
+use differential_dataflow::{Collection, Hashable};
+use futures::SinkExt;
+use itertools::Itertools;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_repr::{Datum, Diff, GlobalId, RelationType, Row, ScalarType, Timestamp};
+use mz_storage_client::client::SinkStatisticsUpdate;
+use mz_storage_types::connections::ConnectionContext;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{
+    MetadataFilled, PostgresSinkConnection, PostgresSinkWriteMode, SinkAsOf, StorageSinkDesc,
+};
+use mz_timely_util::builder_async::{
+    Event, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::{Scope, Stream};
+use timely::progress::{Antichain, Timestamp as _};
+use tokio_postgres::types::ToSql;
+use tracing::info;
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::render::sinks::SinkRender;
+use crate::statistics::{SinkStatisticsMetrics, StorageStatistics};
+use crate::storage_state::StorageState;
+
+impl<G> SinkRender<G> for PostgresSinkConnection
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    fn uses_keys(&self) -> bool {
+        true
+    }
+
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        self.key_desc_and_indices
+            .as_ref()
+            .map(|(_desc, indices)| indices.as_slice())
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        self.relation_key_indices.as_deref()
+    }
+
+    fn render_continuous_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<MetadataFilled, Timestamp>,
+        sink_id: GlobalId,
+        sinked_collection: Collection<G, (Option<Row>, Option<Row>), Diff>,
+        _err_collection: Collection<G, DataflowError, Diff>,
+    ) -> (Stream<G, HealthStatusMessage>, Vec<PressOnDropButton>)
+    where
+        G: Scope<Timestamp = Timestamp>,
+    {
+        // Only the active worker will ever write to the table, so all other
+        // workers report an empty frontier. See the Kafka sink for details.
+        let peers = sinked_collection.inner.scope().peers();
+        let worker_index = sinked_collection.inner.scope().index();
+        let active_write_worker = (usize::cast_from(sink_id.hashed()) % peers) == worker_index;
+
+        let shared_frontier = Rc::new(RefCell::new(if active_write_worker {
+            Antichain::from_elem(Timestamp::minimum())
+        } else {
+            Antichain::new()
+        }));
+
+        let (health, token) = postgres(
+            sinked_collection,
+            sink_id,
+            self.clone(),
+            sink.as_of.clone(),
+            Rc::clone(&shared_frontier),
+            storage_state
+                .sink_statistics
+                .get(&sink_id)
+                .expect("statistics initialized")
+                .clone(),
+            storage_state.connection_context.clone(),
+        );
+
+        storage_state
+            .sink_write_frontiers
+            .insert(sink_id, shared_frontier);
+
+        (health, vec![token])
+    }
+}
+
+/// The statements the sink uses to apply updates to the upstream table.
+struct SinkStatements {
+    upsert: String,
+    delete: String,
+    truncate: String,
+    /// Creates the staging table of [`PostgresSinkWriteMode::Copy`], whose
+    /// first column records whether the row was deleted and whose remaining
+    /// columns are those of the sink, named by position to avoid clashes.
+    create_staging: String,
+    clear_staging: String,
+    copy_staging: String,
+    /// Deletes the rows of the table whose keys are staged.
+    apply_staged_deletes: String,
+    /// Inserts the staged rows that were not deleted.
+    apply_staged_inserts: String,
+}
+
+/// The name of the temporary table sinks with [`PostgresSinkWriteMode::Copy`]
+/// stage rows in.
+const STAGING_TABLE: &str = "mz_sink_staging";
+
+impl SinkStatements {
+    fn new(connection: &PostgresSinkConnection) -> Self {
+        let (key_desc, _) = connection
+            .key_desc_and_indices
+            .as_ref()
+            .expect("postgres sinks require a key");
+        let table = format!(
+            "{}.{}",
+            quote_ident(&connection.schema),
+            quote_ident(&connection.table)
+        );
+
+        // Parameters are always sent as text and then cast to the column's
+        // type, which lets us rely on PostgreSQL's input functions rather
+        // than binary encodings.
+        let cast = |idx: usize, ty: &ScalarType| {
+            format!(
+                "CAST(${}::pg_catalog.text AS pg_catalog.{})",
+                idx + 1,
+                quote_ident(mz_pgrepr::Type::from(ty).catalog_name())
+            )
+        };
+
+        let columns = connection
+            .value_desc
+            .iter_names()
+            .map(|name| quote_ident(name.as_str()))
+            .join(", ");
+        let values = connection
+            .value_desc
+            .iter_types()
+            .enumerate()
+            .map(|(i, ty)| cast(i, &ty.scalar_type))
+            .join(", ");
+        let key_columns = key_desc
+            .iter_names()
+            .map(|name| quote_ident(name.as_str()))
+            .join(", ");
+        let updates = connection
+            .value_desc
+            .iter_names()
+            .filter(|name| key_desc.get_by_name(name).is_none())
+            .map(|name| {
+                let name = quote_ident(name.as_str());
+                format!("{name} = EXCLUDED.{name}")
+            })
+            .collect::<Vec<_>>();
+        let on_conflict = if updates.is_empty() {
+            "DO NOTHING".to_string()
+        } else {
+            format!("DO UPDATE SET {}", updates.join(", "))
+        };
+
+        let predicate = key_desc
+            .iter()
+            .enumerate()
+            .map(|(i, (name, ty))| {
+                format!(
+                    "{} IS NOT DISTINCT FROM {}",
+                    quote_ident(name.as_str()),
+                    cast(i, &ty.scalar_type)
+                )
+            })
+            .join(" AND ");
+
+        let staged = |idx: usize| format!("c{idx}");
+        let staging_defs = connection
+            .value_desc
+            .iter_types()
+            .enumerate()
+            .map(|(i, ty)| {
+                format!(
+                    "{} pg_catalog.{}",
+                    staged(i),
+                    quote_ident(mz_pgrepr::Type::from(&ty.scalar_type).catalog_name())
+                )
+            })
+            .join(", ");
+        let staged_columns = (0..connection.value_desc.arity()).map(staged).join(", ");
+        let staged_key_predicate = key_desc
+            .iter_names()
+            .map(|name| {
+                let (idx, _) = connection
+                    .value_desc
+                    .get_by_name(name)
+                    .expect("key columns are columns of the sinked relation");
+                format!(
+                    "{STAGING_TABLE}.{} IS NOT DISTINCT FROM {table}.{}",
+                    staged(idx),
+                    quote_ident(name.as_str())
+                )
+            })
+            .join(" AND ");
+
+        SinkStatements {
+            upsert: format!(
+                "INSERT INTO {table} ({columns}) VALUES ({values}) \
+                ON CONFLICT ({key_columns}) {on_conflict}"
+            ),
+            delete: format!("DELETE FROM {table} WHERE {predicate}"),
+            truncate: format!("DELETE FROM {table}"),
+            create_staging: format!(
+                "CREATE TEMPORARY TABLE IF NOT EXISTS {STAGING_TABLE} \
+                (deleted pg_catalog.bool, {staging_defs})"
+            ),
+            clear_staging: format!("DELETE FROM {STAGING_TABLE}"),
+            copy_staging: format!("COPY {STAGING_TABLE} FROM STDIN"),
+            apply_staged_deletes: format!(
+                "DELETE FROM {table} WHERE EXISTS \
+                (SELECT 1 FROM {STAGING_TABLE} WHERE {staged_key_predicate})"
+            ),
+            apply_staged_inserts: format!(
+                "INSERT INTO {table} ({columns}) \
+                SELECT {staged_columns} FROM {STAGING_TABLE} WHERE NOT deleted"
+            ),
+        }
+    }
+}
+
+/// Quotes `ident` for use as an identifier in a PostgreSQL statement.
+fn quote_ident(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+/// Packs the row the sink stages for the update of `key` to `value`: whether
+/// the row was deleted, followed by either the value or, for deleted rows, the
+/// key columns at their positions in the value and `NULL`s elsewhere.
+fn staging_row(key: &Row, value: Option<&Row>, key_indices: &[usize], arity: usize) -> Row {
+    let mut row = Row::default();
+    let mut packer = row.packer();
+    packer.push(Datum::from(value.is_none()));
+    match value {
+        Some(value) => packer.extend(value.iter()),
+        None => {
+            let key: Vec<_> = key.iter().collect();
+            for idx in 0..arity {
+                match key_indices.iter().position(|k| *k == idx) {
+                    Some(pos) => packer.push(key[pos]),
+                    None => packer.push(Datum::Null),
+                }
+            }
+        }
+    }
+    row
+}
+
+/// Encodes each datum in `row` in PostgreSQL's text format.
+fn encode_params<'a>(
+    row: &Row,
+    types: impl Iterator<Item = &'a ScalarType>,
+    buf: &mut BytesMut,
+) -> Vec<Option<String>> {
+    row.iter()
+        .zip_eq(types)
+        .map(|(datum, ty)| {
+            mz_pgrepr::Value::from_datum(datum, ty).map(|value| {
+                buf.clear();
+                value.encode_text(buf);
+                String::from_utf8(buf.to_vec()).expect("text encoding produces valid UTF-8")
+            })
+        })
+        .collect()
+}
+
+fn postgres<G>(
+    collection: Collection<G, (Option<Row>, Option<Row>), Diff>,
+    id: GlobalId,
+    connection: PostgresSinkConnection,
+    as_of: SinkAsOf,
+    write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+    sink_statistics: StorageStatistics<SinkStatisticsUpdate, SinkStatisticsMetrics>,
+    connection_context: ConnectionContext,
+) -> (Stream<G, HealthStatusMessage>, PressOnDropButton)
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    let name = format!("postgres-{}", id);
+    let scope = collection.inner.scope();
+    let worker_id = scope.index();
+    let worker_count = scope.peers();
+    let mut builder = AsyncOperatorBuilder::new(name.clone(), scope);
+
+    // We want exactly one worker to write all of the data to the table.
+    let hashed_id = id.hashed();
+    let is_active_worker = usize::cast_from(hashed_id) % worker_count == worker_id;
+
+    let mut input = builder.new_input(&collection.inner, Exchange::new(move |_| hashed_id));
+    let (mut health_output, health_stream) = builder.new_output();
+
+    let button = builder.build(move |caps| async move {
+        let [health_cap]: [_; 1] = caps.try_into().unwrap();
+
+        if !is_active_worker {
+            return;
+        }
+
+        let result: Result<(), anyhow::Error> = async {
+            let config = connection.connection.config(&connection_context).await?;
+            let mut client = config
+                .connect("postgres sink", &connection_context.ssh_tunnel_manager)
+                .await?;
+
+            let statements = SinkStatements::new(&connection);
+            // Sinks that write with `COPY` never prepare the `ON CONFLICT`
+            // statement, which the server may not support.
+            let prepared = match connection.write_mode {
+                PostgresSinkWriteMode::Upsert => Some((
+                    client.prepare(&statements.upsert).await?,
+                    client.prepare(&statements.delete).await?,
+                )),
+                PostgresSinkWriteMode::Copy => {
+                    client.batch_execute(&statements.create_staging).await?;
+                    None
+                }
+            };
+
+            let (key_types, key_indices) = connection
+                .key_desc_and_indices
+                .as_ref()
+                .map(|(desc, indices)| (desc.typ().column_types.clone(), indices.clone()))
+                .unwrap_or_default();
+            let value_types = connection.value_desc.typ().column_types.clone();
+            let staging_type = RelationType::new(
+                iter::once(ScalarType::Bool.nullable(false))
+                    .chain(
+                        value_types
+                            .iter()
+                            .map(|ty| ty.scalar_type.clone().nullable(true)),
+                    )
+                    .collect(),
+            );
+
+            health_output
+                .give(
+                    &health_cap,
+                    HealthStatusMessage {
+                        index: 0,
+                        namespace: StatusNamespace::Postgres,
+                        update: HealthStatusUpdate::running(),
+                    },
+                )
+                .await;
+
+            // The snapshot, if any, is the first timestamp the sink writes.
+            let mut replace_contents = !as_of.strict;
+            let mut pending_rows: BTreeMap<Timestamp, Vec<(Row, Option<Row>)>> = BTreeMap::new();
+            let mut buf = BytesMut::new();
+
+            while let Some(event) = input.next_mut().await {
+                match event {
+                    Event::Data(_, rows) => {
+                        for ((key, value), time, diff) in rows.drain(..) {
+                            let should_emit = if as_of.strict {
+                                as_of.frontier.less_than(&time)
+                            } else {
+                                as_of.frontier.less_equal(&time)
+                            };
+                            if !should_emit || diff == 0 {
+                                continue;
+                            }
+                            let key = key.expect("postgres sinks require a key");
+                            pending_rows.entry(time).or_default().push((key, value));
+                        }
+                    }
+                    Event::Progress(frontier) => {
+                        while let Some(entry) = pending_rows.first_entry() {
+                            if frontier.less_equal(entry.key()) {
+                                break;
+                            }
+                            let (ts, rows) = entry.remove_entry();
+
+                            info!(
+                                "{}: beginning transaction for {:?} with {:?} rows",
+                                id,
+                                ts,
+                                rows.len()
+                            );
+                            let tx = client.transaction().await?;
+                            if replace_contents {
+                                tx.batch_execute(&statements.truncate).await?;
+                            }
+
+                            let count_for_stats = u64::cast_from(rows.len());
+                            let mut total_size_for_stats = 0;
+                            match &prepared {
+                                Some((upsert, delete)) => {
+                                    for (key, value) in rows {
+                                        let (statement, params) = match value {
+                                            Some(value) => (
+                                                upsert,
+                                                encode_params(
+                                                    &value,
+                                                    value_types.iter().map(|ty| &ty.scalar_type),
+                                                    &mut buf,
+                                                ),
+                                            ),
+                                            None => (
+                                                delete,
+                                                encode_params(
+                                                    &key,
+                                                    key_types.iter().map(|ty| &ty.scalar_type),
+                                                    &mut buf,
+                                                ),
+                                            ),
+                                        };
+                                        let size_for_stats = params
+                                            .iter()
+                                            .map(|p| {
+                                                u64::cast_from(p.as_ref().map_or(0, |p| p.len()))
+                                            })
+                                            .sum::<u64>();
+                                        total_size_for_stats += size_for_stats;
+
+                                        let params = params
+                                            .iter()
+                                            .map(|p| p as &(dyn ToSql + Sync))
+                                            .collect::<Vec<_>>();
+                                        tx.execute(statement, &params).await.with_context(
+                                            || {
+                                                format!(
+                                                    "writing to {}.{}",
+                                                    connection.schema, connection.table
+                                                )
+                                            },
+                                        )?;
+                                        sink_statistics.inc_messages_staged_by(1);
+                                        sink_statistics.inc_bytes_staged_by(size_for_stats);
+                                    }
+                                }
+                                None => {
+                                    tx.batch_execute(&statements.clear_staging).await?;
+                                    let mut data = vec![];
+                                    for (key, value) in rows {
+                                        let len = data.len();
+                                        let row = staging_row(
+                                            &key,
+                                            value.as_ref(),
+                                            &key_indices,
+                                            value_types.len(),
+                                        );
+                                        mz_pgcopy::encode_copy_row_text(
+                                            row,
+                                            &staging_type,
+                                            &mut data,
+                                        )?;
+                                        let size_for_stats = u64::cast_from(data.len() - len);
+                                        total_size_for_stats += size_for_stats;
+                                        sink_statistics.inc_messages_staged_by(1);
+                                        sink_statistics.inc_bytes_staged_by(size_for_stats);
+                                    }
+
+                                    let sink = tx.copy_in(&statements.copy_staging).await?;
+                                    tokio::pin!(sink);
+                                    sink.send(Bytes::from(data)).await?;
+                                    sink.finish().await?;
+                                    for statement in [
+                                        &statements.apply_staged_deletes,
+                                        &statements.apply_staged_inserts,
+                                    ] {
+                                        tx.batch_execute(statement).await.with_context(|| {
+                                            format!(
+                                                "writing to {}.{}",
+                                                connection.schema, connection.table
+                                            )
+                                        })?;
+                                    }
+                                }
+                            }
+
+                            info!("{}: committing transaction for {:?}", id, ts);
+                            tx.commit().await?;
+                            replace_contents = false;
+                            sink_statistics.inc_messages_committed_by(count_for_stats);
+                            sink_statistics.inc_bytes_committed_by(total_size_for_stats);
+                        }
+
+                        // Everything before the input frontier has been
+                        // committed, except for timestamps that are still
+                        // pending.
+                        let mut new_frontier = frontier.to_owned();
+                        new_frontier.extend(pending_rows.keys().next().cloned());
+                        let mut write_frontier = write_frontier.borrow_mut();
+                        write_frontier.clear();
+                        write_frontier.extend(new_frontier);
+                    }
+                }
+            }
+            Ok(())
+        }
+        .await;
+
+        if let Err(error) = result {
+            health_output
+                .give(
+                    &health_cap,
+                    HealthStatusMessage {
+                        index: 0,
+                        namespace: StatusNamespace::Postgres,
+                        update: HealthStatusUpdate::halting(
+                            format!("{}", error.display_with_causes()),
+                            None,
+                        ),
+                    },
+                )
+                .await;
+
+            // Make sure to never return, preventing the sink from writing
+            // out anything it might regret in the future.
+            future::pending::<()>().await;
+        }
+    });
+
+    (health_stream, button.press_on_drop())
+}
@@ -0,0 +1,363 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A sink that maintains a Redis key for each row of an upsert-keyed collection.
+//!
+//! The name of each key is the sink's key prefix followed by the key columns of
+//! the row in PostgreSQL's text format, separated by colons. Depending on the
+//! sink's data structure, the value is either a string holding the row encoded
+//! as a JSON object, or a hash with a field for each non-null column of the row.
+//! Rows without a value delete their key.
+//!
+//! All updates are exchanged to a single worker, which buffers them until the
+//! input frontier passes their timestamp and then applies each timestamp in a
+//! single `MULTI`/`EXEC` transaction.
+//!
+//! The first transaction the sink commits after (re)starting at a non-strict
+//! `as_of` contains the full snapshot of the collection, so it also deletes all
+//! other keys under the prefix, if there is one. Writes are therefore
+//! at-least-once, but the keys converge to the contents of the collection.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::future;
+use std::rc::Rc;
+
+use anyhow::Context;
+use bytes::BytesMut;
+use differential_dataflow::{Collection, Hashable};
+use mz_interchange::encode::Encode;
+use mz_interchange::json::JsonEncoder;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_repr::{ColumnType, Datum, Diff, GlobalId, Row, Timestamp};
+use mz_storage_client::client::SinkStatisticsUpdate;
+use mz_storage_types::connections::ConnectionContext;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{
+    MetadataFilled, RedisDataStructure, RedisSinkConnection, SinkAsOf, StorageSinkDesc,
+};
+use mz_timely_util::builder_async::{
+    Event, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::{Scope, Stream};
+use timely::progress::{Antichain, Timestamp as _};
+use tracing::info;
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::render::sinks::SinkRender;
+use crate::statistics::{SinkStatisticsMetrics, StorageStatistics};
+use crate::storage_state::StorageState;
+
+impl<G> SinkRender<G> for RedisSinkConnection
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    fn uses_keys(&self) -> bool {
+        true
+    }
+
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        self.key_desc_and_indices
+            .as_ref()
+            .map(|(_desc, indices)| indices.as_slice())
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        self.relation_key_indices.as_deref()
+    }
+
+    fn render_continuous_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<MetadataFilled, Timestamp>,
+        sink_id: GlobalId,
+        sinked_collection: Collection<G, (Option<Row>, Option<Row>), Diff>,
+        _err_collection: Collection<G, DataflowError, Diff>,
+    ) -> (Stream<G, HealthStatusMessage>, Vec<PressOnDropButton>)
+    where
+        G: Scope<Timestamp = Timestamp>,
+    {
+        // Only the active worker will ever write to Redis, so all other
+        // workers report an empty frontier. See the Kafka sink for details.
+        let peers = sinked_collection.inner.scope().peers();
+        let worker_index = sinked_collection.inner.scope().index();
+        let active_write_worker = (usize::cast_from(sink_id.hashed()) % peers) == worker_index;
+
+        let shared_frontier = Rc::new(RefCell::new(if active_write_worker {
+            Antichain::from_elem(Timestamp::minimum())
+        } else {
+            Antichain::new()
+        }));
+
+        let (health, token) = redis(
+            sinked_collection,
+            sink_id,
+            self.clone(),
+            sink.as_of.clone(),
+            Rc::clone(&shared_frontier),
+            storage_state
+                .sink_statistics
+                .get(&sink_id)
+                .expect("statistics initialized")
+                .clone(),
+            storage_state.connection_context.clone(),
+        );
+
+        storage_state
+            .sink_write_frontiers
+            .insert(sink_id, shared_frontier);
+
+        (health, vec![token])
+    }
+}
+
+/// Encodes `datum` in PostgreSQL's text format, or returns `None` if it is
+/// null.
+fn encode_text(datum: Datum, ty: &ColumnType, buf: &mut BytesMut) -> Option<String> {
+    mz_pgrepr::Value::from_datum(datum, &ty.scalar_type).map(|value| {
+        buf.clear();
+        value.encode_text(buf);
+        String::from_utf8(buf.to_vec()).expect("text encoding produces valid UTF-8")
+    })
+}
+
+/// Returns the name of the Redis key that holds the row with the given key.
+///
+/// Null key columns are encoded as empty strings.
+fn key_name(prefix: &str, key: &Row, key_types: &[ColumnType], buf: &mut BytesMut) -> String {
+    let mut name = prefix.to_string();
+    for (i, (datum, ty)) in key.iter().zip(key_types).enumerate() {
+        if i > 0 {
+            name.push(':');
+        }
+        if let Some(text) = encode_text(datum, ty, buf) {
+            name.push_str(&text);
+        }
+    }
+    name
+}
+
+/// Escapes the glob-style metacharacters in `s` for use in a `MATCH` pattern.
+fn escape_glob(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn redis<G>(
+    collection: Collection<G, (Option<Row>, Option<Row>), Diff>,
+    id: GlobalId,
+    connection: RedisSinkConnection,
+    as_of: SinkAsOf,
+    write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+    sink_statistics: StorageStatistics<SinkStatisticsUpdate, SinkStatisticsMetrics>,
+    connection_context: ConnectionContext,
+) -> (Stream<G, HealthStatusMessage>, PressOnDropButton)
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    let name = format!("redis-{}", id);
+    let scope = collection.inner.scope();
+    let worker_id = scope.index();
+    let worker_count = scope.peers();
+    let mut builder = AsyncOperatorBuilder::new(name.clone(), scope);
+
+    // We want exactly one worker to write all of the keys.
+    let hashed_id = id.hashed();
+    let is_active_worker = usize::cast_from(hashed_id) % worker_count == worker_id;
+
+    let mut input = builder.new_input(&collection.inner, Exchange::new(move |_| hashed_id));
+    let (mut health_output, health_stream) = builder.new_output();
+
+    let button = builder.build(move |caps| async move {
+        let [health_cap]: [_; 1] = caps.try_into().unwrap();
+
+        if !is_active_worker {
+            return;
+        }
+
+        let result: Result<(), anyhow::Error> = async {
+            let mut conn = connection
+                .connect(&*connection_context.secrets_reader)
+                .await
+                .context("connecting to Redis")?;
+
+            let key_types = connection
+                .key_desc_and_indices
+                .as_ref()
+                .map(|(desc, _)| desc.typ().column_types.clone())
+                .expect("redis sinks require a key");
+            let value_names = connection
+                .value_desc
+                .iter_names()
+                .map(|name| name.as_str().to_string())
+                .collect::<Vec<_>>();
+            let value_types = connection.value_desc.typ().column_types.clone();
+            let encoder = JsonEncoder::new(None, connection.value_desc.clone(), false);
+
+            health_output
+                .give(
+                    &health_cap,
+                    HealthStatusMessage {
+                        index: 0,
+                        namespace: StatusNamespace::Redis,
+                        update: HealthStatusUpdate::running(),
+                    },
+                )
+                .await;
+
+            // The snapshot, if any, is the first timestamp the sink writes.
+            let mut replace_contents = !as_of.strict && !connection.key_prefix.is_empty();
+            let mut pending_rows: BTreeMap<Timestamp, Vec<(Row, Option<Row>)>> = BTreeMap::new();
+            let mut buf = BytesMut::new();
+
+            while let Some(event) = input.next_mut().await {
+                match event {
+                    Event::Data(_, rows) => {
+                        for ((key, value), time, diff) in rows.drain(..) {
+                            let should_emit = if as_of.strict {
+                                as_of.frontier.less_than(&time)
+                            } else {
+                                as_of.frontier.less_equal(&time)
+                            };
+                            if !should_emit || diff == 0 {
+                                continue;
+                            }
+                            let key = key.expect("redis sinks require a key");
+                            pending_rows.entry(time).or_default().push((key, value));
+                        }
+                    }
+                    Event::Progress(frontier) => {
+                        while let Some(entry) = pending_rows.first_entry() {
+                            if frontier.less_equal(entry.key()) {
+                                break;
+                            }
+                            let (ts, rows) = entry.remove_entry();
+
+                            let mut pipe = redis::pipe();
+                            pipe.atomic();
+                            if replace_contents {
+                                // SCAN cannot run inside a transaction, so
+                                // the stale keys are collected beforehand.
+                                let pattern = format!("{}*", escape_glob(&connection.key_prefix));
+                                let mut stale = vec![];
+                                let mut keys = redis::AsyncCommands::scan_match::<_, String>(
+                                    &mut conn, pattern,
+                                )
+                                .await?;
+                                while let Some(key) = keys.next_item().await {
+                                    stale.push(key);
+                                }
+                                drop(keys);
+                                info!("{}: deleting {:?} existing keys", id, stale.len());
+                                for key in stale {
+                                    pipe.del(key).ignore();
+                                }
+                            }
+
+                            let count_for_stats = u64::cast_from(rows.len());
+                            let mut total_size_for_stats = 0;
+                            for (key, value) in rows {
+                                let name =
+                                    key_name(&connection.key_prefix, &key, &key_types, &mut buf);
+                                let mut size_for_stats = u64::cast_from(name.len());
+                                match (value, connection.data_structure) {
+                                    (None, _) => {
+                                        pipe.del(name).ignore();
+                                    }
+                                    (Some(value), RedisDataStructure::String) => {
+                                        let value = encoder.encode_value_unchecked(value);
+                                        size_for_stats += u64::cast_from(value.len());
+                                        pipe.set(name, value).ignore();
+                                    }
+                                    (Some(value), RedisDataStructure::Hash) => {
+                                        let fields = value
+                                            .iter()
+                                            .zip(&value_names)
+                                            .zip(&value_types)
+                                            .filter_map(|((datum, name), ty)| {
+                                                encode_text(datum, ty, &mut buf)
+                                                    .map(|text| (name.as_str(), text))
+                                            })
+                                            .collect::<Vec<_>>();
+                                        size_for_stats += fields
+                                            .iter()
+                                            .map(|(name, text)| {
+                                                u64::cast_from(name.len() + text.len())
+                                            })
+                                            .sum::<u64>();
+                                        // Replace the hash rather than merge
+                                        // into it, so that columns that are
+                                        // now null lose their fields. HSET
+                                        // requires at least one field.
+                                        pipe.del(&name).ignore();
+                                        if !fields.is_empty() {
+                                            pipe.hset_multiple(name, &fields).ignore();
+                                        }
+                                    }
+                                }
+                                total_size_for_stats += size_for_stats;
+                                sink_statistics.inc_messages_staged_by(1);
+                                sink_statistics.inc_bytes_staged_by(size_for_stats);
+                            }
+
+                            info!("{}: committing transaction for {:?}", id, ts);
+                            pipe.query_async::<_, ()>(&mut conn)
+                                .await
+                                .context("writing to Redis")?;
+                            replace_contents = false;
+                            sink_statistics.inc_messages_committed_by(count_for_stats);
+                            sink_statistics.inc_bytes_committed_by(total_size_for_stats);
+                        }
+
+                        // Everything before the input frontier has been
+                        // committed, except for timestamps that are still
+                        // pending.
+                        let mut new_frontier = frontier.to_owned();
+                        new_frontier.extend(pending_rows.keys().next().cloned());
+                        let mut write_frontier = write_frontier.borrow_mut();
+                        write_frontier.clear();
+                        write_frontier.extend(new_frontier);
+                    }
+                }
+            }
+            Ok(())
+        }
+        .await;
+
+        if let Err(error) = result {
+            health_output
+                .give(
+                    &health_cap,
+                    HealthStatusMessage {
+                        index: 0,
+                        namespace: StatusNamespace::Redis,
+                        update: HealthStatusUpdate::halting(
+                            format!("{}", error.display_with_causes()),
+                            None,
+                        ),
+                    },
+                )
+                .await;
+
+            // Make sure to never return, preventing the sink from writing
+            // out anything it might regret in the future.
+            future::pending::<()>().await;
+        }
+    });
+
+    (health_stream, button.press_on_drop())
+}
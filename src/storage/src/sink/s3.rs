@@ -0,0 +1,427 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A sink that writes the changes to a collection as Parquet or CSV files in
+//! an S3 bucket.
+//!
+//! All updates are exchanged to a single worker, which buffers them until the
+//! input frontier passes their timestamp. Closed timestamps are appended to
+//! the current file, which is uploaded once it reaches the configured maximum
+//! size or has been open for longer than the rotation interval. Each row of a
+//! file is a row of the collection followed by the `mz_timestamp` and
+//! `mz_diff` of the change.
+//!
+//! Files are named after the range of timestamps they contain, and the write
+//! frontier only advances past a timestamp once the file containing it has
+//! been uploaded. A restarted sink may therefore write some changes again,
+//! i.e. writes are at-least-once.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::future;
+use std::rc::Rc;
+use std::time::Instant;
+
+use anyhow::Context;
+use aws_sdk_s3::primitives::ByteStream;
+use aws_sdk_s3::Client;
+use bytes::BytesMut;
+use differential_dataflow::{Collection, Hashable};
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_repr::{Datum, Diff, GlobalId, Row, ScalarType, Timestamp};
+use mz_storage_client::client::SinkStatisticsUpdate;
+use mz_storage_types::connections::ConnectionContext;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{
+    MetadataFilled, S3SinkConnection, S3SinkFormat, SinkAsOf, StorageSinkDesc,
+    S3_SINK_METADATA_COLUMNS,
+};
+use mz_timely_util::builder_async::{
+    Event, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::{Scope, Stream};
+use timely::progress::{Antichain, Timestamp as _};
+use tracing::info;
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::render::sinks::SinkRender;
+use crate::sink::csv::{write_csv_record, write_csv_row};
+use crate::sink::parquet::{self, ParquetColumn};
+use crate::statistics::{SinkStatisticsMetrics, StorageStatistics};
+use crate::storage_state::StorageState;
+
+impl<G> SinkRender<G> for S3SinkConnection
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    fn uses_keys(&self) -> bool {
+        false
+    }
+
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        None
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        None
+    }
+
+    fn render_continuous_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<MetadataFilled, Timestamp>,
+        sink_id: GlobalId,
+        sinked_collection: Collection<G, (Option<Row>, Option<Row>), Diff>,
+        _err_collection: Collection<G, DataflowError, Diff>,
+    ) -> (Stream<G, HealthStatusMessage>, Vec<PressOnDropButton>)
+    where
+        G: Scope<Timestamp = Timestamp>,
+    {
+        // Only the active worker will ever write files, so all other workers
+        // report an empty frontier. See the Kafka sink for details.
+        let peers = sinked_collection.inner.scope().peers();
+        let worker_index = sinked_collection.inner.scope().index();
+        let active_write_worker = (usize::cast_from(sink_id.hashed()) % peers) == worker_index;
+
+        let shared_frontier = Rc::new(RefCell::new(if active_write_worker {
+            Antichain::from_elem(Timestamp::minimum())
+        } else {
+            Antichain::new()
+        }));
+
+        let (health, token) = s3(
+            sinked_collection,
+            sink_id,
+            self.clone(),
+            sink.as_of.clone(),
+            Rc::clone(&shared_frontier),
+            storage_state
+                .sink_statistics
+                .get(&sink_id)
+                .expect("statistics initialized")
+                .clone(),
+            storage_state.connection_context.clone(),
+        );
+
+        storage_state
+            .sink_write_frontiers
+            .insert(sink_id, shared_frontier);
+
+        (health, vec![token])
+    }
+}
+
+/// The changes that have been closed but not yet uploaded.
+struct FileBuffer {
+    rows: Vec<Row>,
+    /// An estimate of the size of the encoded file, in bytes.
+    size: u64,
+    /// The first and last timestamp of the changes in the file.
+    bounds: Option<(Timestamp, Timestamp)>,
+    opened_at: Instant,
+}
+
+impl FileBuffer {
+    fn new() -> Self {
+        FileBuffer {
+            rows: vec![],
+            size: 0,
+            bounds: None,
+            opened_at: Instant::now(),
+        }
+    }
+
+    /// Appends all changes at `ts`, which must be later than any timestamp
+    /// already in the file.
+    fn extend(&mut self, ts: Timestamp, rows: Vec<Row>) {
+        self.size += rows
+            .iter()
+            .map(|row| u64::cast_from(row.byte_len()))
+            .sum::<u64>();
+        self.rows.extend(rows);
+        self.bounds = match self.bounds {
+            Some((lower, _)) => Some((lower, ts)),
+            None => Some((ts, ts)),
+        };
+    }
+}
+
+/// Encodes the rows of a sink into files of its format.
+struct FileEncoder {
+    format: S3SinkFormat,
+    names: Vec<String>,
+    types: Vec<ScalarType>,
+}
+
+impl FileEncoder {
+    fn new(connection: &S3SinkConnection) -> Self {
+        let names = connection
+            .value_desc
+            .iter_names()
+            .map(|name| name.as_str())
+            .chain(S3_SINK_METADATA_COLUMNS)
+            .map(|name| name.to_string())
+            .collect();
+        let types = connection
+            .value_desc
+            .iter_types()
+            .map(|ty| ty.scalar_type.clone())
+            .chain([ScalarType::UInt64, ScalarType::Int64])
+            .collect();
+        FileEncoder {
+            format: connection.format.clone(),
+            names,
+            types,
+        }
+    }
+
+    fn encode(&self, rows: &[Row]) -> Result<Vec<u8>, anyhow::Error> {
+        match &self.format {
+            S3SinkFormat::Parquet => self.encode_parquet(rows),
+            S3SinkFormat::Csv { delimiter, header } => {
+                Ok(self.encode_csv(rows, *delimiter, header.as_deref()))
+            }
+        }
+    }
+
+    fn encode_parquet(&self, rows: &[Row]) -> Result<Vec<u8>, anyhow::Error> {
+        let columns = self.types.iter().map(ParquetColumn::new).collect();
+        parquet::encode(&self.names, columns, rows)
+    }
+
+    fn encode_csv(&self, rows: &[Row], delimiter: u8, header: Option<&[String]>) -> Vec<u8> {
+        let mut file = vec![];
+        if let Some(header) = header {
+            write_csv_record(
+                &mut file,
+                delimiter,
+                header.iter().map(|name| Some(name.as_str())),
+            );
+        }
+        let mut buf = BytesMut::new();
+        for row in rows {
+            write_csv_row(&mut file, delimiter, row, &self.types, &mut buf);
+        }
+        file
+    }
+}
+
+/// Uploads `file` to the bucket of the sink.
+async fn write_file(
+    client: &Client,
+    id: GlobalId,
+    connection: &S3SinkConnection,
+    encoder: &FileEncoder,
+    file: FileBuffer,
+    sink_statistics: &StorageStatistics<SinkStatisticsUpdate, SinkStatisticsMetrics>,
+) -> Result<(), anyhow::Error> {
+    let (lower, upper) = file.bounds.expect("only non-empty files are written");
+    let key = format!(
+        "{}{}/{}-{}.{}",
+        connection.prefix,
+        id,
+        lower,
+        upper,
+        connection.format.extension()
+    );
+    let body = encoder.encode(&file.rows)?;
+    let size_for_stats = u64::cast_from(body.len());
+
+    info!("{}: writing {:?} rows to {}", id, file.rows.len(), key);
+    client
+        .put_object()
+        .bucket(&connection.bucket)
+        .key(&key)
+        .body(ByteStream::from(body))
+        .send()
+        .await
+        .with_context(|| format!("writing s3://{}/{}", connection.bucket, key))?;
+    sink_statistics.inc_messages_committed_by(u64::cast_from(file.rows.len()));
+    sink_statistics.inc_bytes_committed_by(size_for_stats);
+    Ok(())
+}
+
+fn s3<G>(
+    collection: Collection<G, (Option<Row>, Option<Row>), Diff>,
+    id: GlobalId,
+    connection: S3SinkConnection,
+    as_of: SinkAsOf,
+    write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+    sink_statistics: StorageStatistics<SinkStatisticsUpdate, SinkStatisticsMetrics>,
+    connection_context: ConnectionContext,
+) -> (Stream<G, HealthStatusMessage>, PressOnDropButton)
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    let name = format!("s3-{}", id);
+    let scope = collection.inner.scope();
+    let worker_id = scope.index();
+    let worker_count = scope.peers();
+    let mut builder = AsyncOperatorBuilder::new(name.clone(), scope);
+
+    // We want exactly one worker to write all of the files.
+    let hashed_id = id.hashed();
+    let is_active_worker = usize::cast_from(hashed_id) % worker_count == worker_id;
+
+    let mut input = builder.new_input(&collection.inner, Exchange::new(move |_| hashed_id));
+    let (mut health_output, health_stream) = builder.new_output();
+
+    let button = builder.build(move |caps| async move {
+        let [health_cap]: [_; 1] = caps.try_into().unwrap();
+
+        if !is_active_worker {
+            return;
+        }
+
+        let result: Result<(), anyhow::Error> = async {
+            let sdk_config = connection
+                .connection
+                .load(
+                    connection_context.aws_external_id_prefix.as_ref(),
+                    Some(&connection.connection_id),
+                    &*connection_context.secrets_reader,
+                )
+                .await;
+            let client = mz_aws_s3_util::new_client(&sdk_config);
+            let encoder = FileEncoder::new(&connection);
+
+            health_output
+                .give(
+                    &health_cap,
+                    HealthStatusMessage {
+                        index: 0,
+                        namespace: StatusNamespace::S3,
+                        update: HealthStatusUpdate::running(),
+                    },
+                )
+                .await;
+
+            let mut pending_rows: BTreeMap<Timestamp, Vec<Row>> = BTreeMap::new();
+            let mut file = FileBuffer::new();
+            let mut row_buf = Row::default();
+
+            while let Some(event) = input.next_mut().await {
+                match event {
+                    Event::Data(_, rows) => {
+                        for ((_key, value), time, diff) in rows.drain(..) {
+                            let should_emit = if as_of.strict {
+                                as_of.frontier.less_than(&time)
+                            } else {
+                                as_of.frontier.less_equal(&time)
+                            };
+                            if !should_emit || diff == 0 {
+                                continue;
+                            }
+                            // The Debezium envelope packs the state of the row
+                            // before and after the change into two lists.
+                            let value = value.expect("debezium envelope always produces a value");
+                            let (before, after) = value
+                                .iter()
+                                .collect_tuple()
+                                .expect("debezium rows have two columns");
+                            for (datum, diff) in [(before, -diff), (after, diff)] {
+                                if datum.is_null() {
+                                    continue;
+                                }
+                                let mut packer = row_buf.packer();
+                                packer.extend(datum.unwrap_list().iter());
+                                packer.push(Datum::UInt64(u64::from(time)));
+                                packer.push(Datum::Int64(diff));
+                                pending_rows.entry(time).or_default().push(row_buf.clone());
+                            }
+                        }
+                    }
+                    Event::Progress(frontier) => {
+                        while let Some(entry) = pending_rows.first_entry() {
+                            if frontier.less_equal(entry.key()) {
+                                break;
+                            }
+                            let (ts, rows) = entry.remove_entry();
+
+                            let count_for_stats = u64::cast_from(rows.len());
+                            let size_for_stats = rows
+                                .iter()
+                                .map(|row| u64::cast_from(row.byte_len()))
+                                .sum::<u64>();
+                            if file.rows.is_empty() {
+                                file = FileBuffer::new();
+                            }
+                            file.extend(ts, rows);
+                            sink_statistics.inc_messages_staged_by(count_for_stats);
+                            sink_statistics.inc_bytes_staged_by(size_for_stats);
+
+                            // Files always contain whole timestamps, so that
+                            // the ranges of timestamps in files are disjoint.
+                            if file.size >= connection.max_file_size {
+                                let full = std::mem::replace(&mut file, FileBuffer::new());
+                                write_file(
+                                    &client,
+                                    id,
+                                    &connection,
+                                    &encoder,
+                                    full,
+                                    &sink_statistics,
+                                )
+                                .await?;
+                            }
+                        }
+
+                        // The rotation interval is only checked when the
+                        // input makes progress, which it does at least once
+                        // per timestamp interval.
+                        if !file.rows.is_empty()
+                            && (frontier.is_empty()
+                                || file.opened_at.elapsed() >= connection.rotation_interval)
+                        {
+                            let full = std::mem::replace(&mut file, FileBuffer::new());
+                            write_file(&client, id, &connection, &encoder, full, &sink_statistics)
+                                .await?;
+                        }
+
+                        // Everything before the input frontier has been
+                        // uploaded, except for timestamps that are still
+                        // pending or buffered in the current file.
+                        let mut new_frontier = frontier.to_owned();
+                        new_frontier.extend(file.bounds.map(|(lower, _)| lower));
+                        new_frontier.extend(pending_rows.keys().next().cloned());
+                        let mut write_frontier = write_frontier.borrow_mut();
+                        write_frontier.clear();
+                        write_frontier.extend(new_frontier);
+                    }
+                }
+            }
+            Ok(())
+        }
+        .await;
+
+        if let Err(error) = result {
+            health_output
+                .give(
+                    &health_cap,
+                    HealthStatusMessage {
+                        index: 0,
+                        namespace: StatusNamespace::S3,
+                        update: HealthStatusUpdate::halting(
+                            format!("{}", error.display_with_causes()),
+                            None,
+                        ),
+                    },
+                )
+                .await;
+
+            // Make sure to never return, preventing the sink from writing
+            // out anything it might regret in the future.
+            future::pending::<()>().await;
+        }
+    });
+
+    (health_stream, button.press_on_drop())
+}
@@ -177,7 +177,7 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
             // Determine the slot lsn.
             let connection_config = connection
                 .connection
-                .config(&*context.secrets_reader)
+                .config(&context)
                 .await?
                 .tcp_timeouts(config.params.pg_source_tcp_timeouts.clone());
 
@@ -247,7 +247,7 @@ pub(crate) fn render<G: Scope<Timestamp = MzOffset>>(
 
             let connection_config = connection
                 .connection
-                .config(&*context.secrets_reader)
+                .config(&context)
                 .await?
                 .tcp_timeouts(config.params.pg_source_tcp_timeouts.clone());
             let task_name = format!("timely-{worker_id} PG snapshotter");
@@ -61,6 +61,7 @@ mod persist;
 mod postgres;
 mod protobuf;
 mod psql;
+mod s3;
 mod schema_registry;
 mod set;
 mod skip_if;
@@ -280,6 +281,14 @@ impl State {
             "testdrive.materialize-internal-sql-addr".into(),
             self.materialize_internal_sql_addr.clone(),
         );
+        self.cmd_vars.insert(
+            "testdrive.materialize-http-addr".into(),
+            self.materialize_http_addr.clone(),
+        );
+        self.cmd_vars.insert(
+            "testdrive.materialize-internal-http-addr".into(),
+            self.materialize_internal_http_addr.clone(),
+        );
         self.cmd_vars.insert(
             "testdrive.materialize-user".into(),
             self.materialize_user.clone(),
@@ -677,6 +686,8 @@ impl Run for PosCommand {
                         protobuf::run_compile_descriptors(builtin, state).await
                     }
                     "psql-execute" => psql::run_execute(builtin, state).await,
+                    "s3-create-bucket" => s3::run_create_bucket(builtin, state).await,
+                    "s3-verify-data" => s3::run_verify_data(builtin, state).await,
                     "schema-registry-publish" => schema_registry::run_publish(builtin, state).await,
                     "schema-registry-verify" => schema_registry::run_verify(builtin, state).await,
                     "schema-registry-wait" => schema_registry::run_wait(builtin, state).await,
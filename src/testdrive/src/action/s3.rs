@@ -0,0 +1,121 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::str;
+
+use anyhow::{bail, Context};
+use aws_sdk_s3::types::{BucketLocationConstraint, CreateBucketConfiguration};
+use mz_ore::retry::Retry;
+
+use crate::action::{ControlFlow, State};
+use crate::parser::BuiltinCommand;
+
+pub async fn run_create_bucket(
+    mut cmd: BuiltinCommand,
+    state: &mut State,
+) -> Result<ControlFlow, anyhow::Error> {
+    let bucket = format!("testdrive-{}-{}", cmd.args.string("bucket")?, state.seed);
+    cmd.args.done()?;
+
+    println!("Creating S3 bucket {}", bucket);
+
+    let client = mz_aws_s3_util::new_client(&state.aws_config);
+    let mut request = client.create_bucket().bucket(&bucket);
+    // Buckets outside of us-east-1 must state their region.
+    if state.aws_region() != "us-east-1" {
+        request = request.create_bucket_configuration(
+            CreateBucketConfiguration::builder()
+                .location_constraint(BucketLocationConstraint::from(state.aws_region()))
+                .build(),
+        );
+    }
+    request
+        .send()
+        .await
+        .with_context(|| format!("creating S3 bucket {}", bucket))?;
+    Ok(ControlFlow::Continue)
+}
+
+pub async fn run_verify_data(
+    mut cmd: BuiltinCommand,
+    state: &mut State,
+) -> Result<ControlFlow, anyhow::Error> {
+    let bucket = format!("testdrive-{}-{}", cmd.args.string("bucket")?, state.seed);
+    let key_prefix = cmd.args.opt_string("key-prefix").unwrap_or_default();
+    let sort_rows = cmd.args.opt_bool("sort-rows")?.unwrap_or(false);
+    cmd.args.done()?;
+
+    let mut expected = cmd.input;
+    if sort_rows {
+        expected.sort();
+    }
+
+    println!(
+        "Verifying the contents of the objects under s3://{}/{}",
+        bucket, key_prefix
+    );
+
+    let client = mz_aws_s3_util::new_client(&state.aws_config);
+    // Sinks upload their objects asynchronously, so retry until the contents
+    // of the objects match.
+    Retry::default()
+        .max_duration(state.default_timeout)
+        .retry_async(|_| async {
+            let objects = client
+                .list_objects_v2()
+                .bucket(&bucket)
+                .prefix(&key_prefix)
+                .send()
+                .await
+                .with_context(|| format!("listing S3 bucket {}", bucket))?;
+
+            let mut actual = vec![];
+            for key in objects.contents().unwrap_or_default().iter() {
+                let key = key.key().unwrap_or_default();
+                let body = client
+                    .get_object()
+                    .bucket(&bucket)
+                    .key(key)
+                    .send()
+                    .await
+                    .with_context(|| format!("reading s3://{}/{}", bucket, key))?
+                    .body
+                    .collect()
+                    .await
+                    .with_context(|| format!("reading s3://{}/{}", bucket, key))?
+                    .into_bytes();
+                let body = str::from_utf8(&body)
+                    .with_context(|| format!("decoding s3://{}/{}", bucket, key))?;
+                for line in body.lines() {
+                    let line = match &state.regex {
+                        Some(regex) => regex
+                            .replace_all(line, state.regex_replacement.as_str())
+                            .into_owned(),
+                        None => line.to_string(),
+                    };
+                    actual.push(line);
+                }
+            }
+            if sort_rows {
+                actual.sort();
+            }
+
+            if actual != expected {
+                bail!(
+                    "objects did not match\nexpected:\n{}\n\nactual:\n{}",
+                    expected.join("\n"),
+                    actual.join("\n")
+                );
+            }
+            Ok(())
+        })
+        .await?;
+
+    Ok(ControlFlow::Continue)
+}
@@ -128,6 +128,38 @@ def test_create_cluster_replica_zone_mixed(mz: MaterializeApplication) -> None:
     mz.environmentd.sql("DROP CLUSTER antiaffinity_cluster1 CASCADE")
 
 
+def test_create_cluster_replica_zone_anti_affinity(mz: MaterializeApplication) -> None:
+    """Test that the AVAILABILITY ZONE ANTI AFFINITY argument to CREATE CLUSTER REPLICA is observed."""
+    mz.environmentd.sql(
+        """
+        CREATE CLUSTER antiaffinity_cluster1 REPLICAS ();
+        CREATE CLUSTER REPLICA antiaffinity_cluster1.antiaffinity_replica1 SIZE '1' , AVAILABILITY ZONE '3';
+        CREATE CLUSTER REPLICA antiaffinity_cluster1.antiaffinity_replica2 SIZE '1' , AVAILABILITY ZONE ANTI AFFINITY (antiaffinity_replica1);
+        CREATE CLUSTER REPLICA antiaffinity_cluster1.antiaffinity_replica3 SIZE '1' , AVAILABILITY ZONE ANTI AFFINITY (antiaffinity_replica1, antiaffinity_replica2);
+        """
+    )
+
+    assert zones_used(mz) == 3
+
+    mz.environmentd.sql("DROP CLUSTER antiaffinity_cluster1 CASCADE")
+
+
+def test_create_cluster_replica_zone_affinity(mz: MaterializeApplication) -> None:
+    """Test that the AVAILABILITY ZONE AFFINITY argument to CREATE CLUSTER REPLICA is observed."""
+    mz.environmentd.sql(
+        """
+        CREATE CLUSTER antiaffinity_cluster1 REPLICAS ();
+        CREATE CLUSTER REPLICA antiaffinity_cluster1.antiaffinity_replica1 SIZE '1' , AVAILABILITY ZONE '2';
+        CREATE CLUSTER REPLICA antiaffinity_cluster1.antiaffinity_replica2 SIZE '1' , AVAILABILITY ZONE AFFINITY (antiaffinity_replica1);
+        CREATE CLUSTER REPLICA antiaffinity_cluster1.antiaffinity_replica3 SIZE '1' , AVAILABILITY ZONE AFFINITY (antiaffinity_replica1);
+        """
+    )
+
+    assert zones_used(mz) == 1
+
+    mz.environmentd.sql("DROP CLUSTER antiaffinity_cluster1 CASCADE")
+
+
 def test_managed_set_azs(mz: MaterializeApplication) -> None:
     """Test that the AVAILABILITY ZONE argument to CREATE CLUSTER REPLICA is observed."""
 
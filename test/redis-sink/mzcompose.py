@@ -0,0 +1,72 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+"""
+Tests Redis sinks against a Redis server.
+"""
+
+import time
+
+from materialize.mzcompose.composition import Composition
+from materialize.mzcompose.services.materialized import Materialized
+from materialize.mzcompose.services.redis import Redis
+from materialize.mzcompose.services.testdrive import Testdrive
+
+SERVICES = [
+    Materialized(),
+    Testdrive(),
+    Redis(),
+]
+
+
+def redis_cli(c: Composition, *args: str) -> str:
+    return c.exec("redis", "redis-cli", "--raw", *args, capture=True).stdout.strip()
+
+
+def wait_for_redis(c: Composition, expected: str, *args: str) -> None:
+    """Waits for the output of a redis-cli command to match `expected`, as
+    sinks write to Redis asynchronously."""
+    actual = ""
+    for _ in range(60):
+        actual = redis_cli(c, *args)
+        if actual == expected:
+            return
+        time.sleep(1)
+    raise AssertionError(
+        f"redis-cli {' '.join(args)} returned {actual!r}, expected {expected!r}"
+    )
+
+
+def workflow_default(c: Composition) -> None:
+    c.up("materialized", "redis")
+
+    # A string key under the prefix of a sink that writes hashes.
+    redis_cli(c, "SET", "wrong:1", "not a hash")
+
+    c.run("testdrive", "redis-sink.td")
+
+    # The sinks of redis-sink.td write the rows (1, 'one') and (2, 'two').
+    wait_for_redis(c, '{"a":1,"b":"one"}', "GET", "strings:1")
+    wait_for_redis(c, '{"a":2,"b":"two"}', "GET", "strings:2")
+    wait_for_redis(c, "a\n1\nb\none", "HGETALL", "hashes:1")
+
+    # Updates overwrite keys, and deletes remove them.
+    c.sql(
+        """
+        UPDATE t SET b = 'uno' WHERE a = 1;
+        DELETE FROM t WHERE a = 2;
+        """
+    )
+    wait_for_redis(c, '{"a":1,"b":"uno"}', "GET", "strings:1")
+    wait_for_redis(c, "0", "EXISTS", "strings:2")
+    wait_for_redis(c, "a\n1\nb\nuno", "HGETALL", "hashes:1")
+    wait_for_redis(c, "0", "EXISTS", "hashes:2")
+
+    # Keys outside of the prefixes of the sinks are left alone.
+    wait_for_redis(c, "not a hash", "GET", "wrong:1")
@@ -262,6 +262,18 @@ def workflow_default(c: Composition, parser: WorkflowArgumentParser) -> None:
                   WHERE collection_timestamp = ( SELECT MAX(collection_timestamp) FROM mz_storage_usage )
                   AND object_id = ( SELECT id FROM mz_objects WHERE name = 'obj' );
                 <SIZE> true
+
+                # The per-schema aggregate matches the usage of the objects in the schema.
+                > SELECT by_schema.size_bytes = by_object.size_bytes
+                  FROM mz_internal.mz_storage_usage_by_schema AS by_schema, (
+                    SELECT sum(size_bytes)::uint8 AS size_bytes, collection_timestamp, schema_id
+                    FROM mz_storage_usage JOIN mz_objects ON object_id = id
+                    GROUP BY collection_timestamp, schema_id
+                  ) AS by_object
+                  WHERE by_schema.schema_id = by_object.schema_id
+                  AND by_schema.collection_timestamp = by_object.collection_timestamp
+                  AND by_schema.schema_id = ( SELECT schema_id FROM mz_objects WHERE name = 'obj' );
+                true
                 """
             )
         )